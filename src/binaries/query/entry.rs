@@ -30,6 +30,7 @@ use common_tracing::set_panic_hook;
 use databend_query::api::HttpService;
 use databend_query::api::RpcService;
 use databend_query::clusters::ClusterDiscovery;
+use databend_query::interpreters::QueryLogPersister;
 use databend_query::local;
 use databend_query::metrics::MetricService;
 use databend_query::servers::FlightSQLServer;
@@ -37,6 +38,7 @@ use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
 use databend_query::servers::MySQLHandler;
 use databend_query::servers::MySQLTlsConfig;
+use databend_query::servers::PostgresHandler;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
 use databend_query::GlobalServices;
@@ -149,6 +151,24 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         );
     }
 
+    // PostgreSQL handler.
+    {
+        let hostname = conf.query.postgres_handler_host.clone();
+        let listening = format!("{}:{}", hostname, conf.query.postgres_handler_port);
+        let tcp_keepalive_timeout_secs = conf.query.mysql_handler_tcp_keepalive_timeout_secs;
+
+        let mut handler = PostgresHandler::create(tcp_keepalive_timeout_secs)?;
+        let listening = handler.start(listening.parse()?).await?;
+        shutdown_handle.add_service(handler);
+
+        info!(
+            "Listening for PostgreSQL compatibility protocol: {}, Usage: psql -h{} -p{} -Uroot",
+            listening,
+            listening.ip(),
+            listening.port(),
+        );
+    }
+
     // ClickHouse HTTP handler.
     {
         let hostname = conf.query.clickhouse_http_handler_host.clone();
@@ -231,6 +251,16 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         );
     }
 
+    // Query log persistence.
+    if conf.query.query_log_persist_enabled {
+        QueryLogPersister::create(conf).start();
+        info!("Persisting query log into system_history.query_log");
+    }
+    if let Some(persister) = QueryLogPersister::try_create_external(conf)? {
+        info!("Streaming query log to {}", persister.sink_name());
+        persister.start();
+    }
+
     // Print information to users.
     println!("Databend Query");
     println!();
@@ -296,6 +326,15 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         "    connect via: mysql -u${{USER}} -p${{PASSWORD}} -h{} -P{}",
         conf.query.mysql_handler_host, conf.query.mysql_handler_port
     );
+    println!("PostgreSQL");
+    println!(
+        "    listened at {}:{}",
+        conf.query.postgres_handler_host, conf.query.postgres_handler_port
+    );
+    println!(
+        "    connect via: psql -U${{USER}} -h{} -p{}",
+        conf.query.postgres_handler_host, conf.query.postgres_handler_port
+    );
     println!("Clickhouse(http)");
     println!(
         "    listened at {}:{}",
@@ -14,30 +14,28 @@
 
 use std::io::Write;
 
-use arrow::array::Array;
-use arrow::chunk::Chunk;
 use arrow::datatypes::DataType;
 use arrow::datatypes::Schema;
+use arrow::error::Error;
 use arrow::error::Result;
 use arrow::io::parquet::write::to_parquet_schema;
-use arrow::io::parquet::write::RowGroupIterator;
+use arrow::io::parquet::write::RowGroupIter;
 use parquet2::metadata::KeyValue;
 use parquet2::metadata::ThriftFileMetaData;
 use parquet2::write::FileWriter;
 use parquet2::write::WriteOptions;
 
 // a simple wrapper for code reuse
-pub fn write_parquet_file<W: Write, A, I>(
+pub fn write_parquet_file<W: Write, R>(
     writer: &mut W,
-    row_groups: RowGroupIterator<A, I>,
+    row_groups: R,
     schema: Schema,
     options: WriteOptions,
     created_by: Option<String>,
 ) -> Result<(u64, ThriftFileMetaData)>
 where
     W: Write,
-    A: AsRef<dyn Array> + 'static + Send + Sync,
-    I: Iterator<Item = Result<Chunk<A>>>,
+    R: Iterator<Item = Result<RowGroupIter<'static, Error>>>,
 {
     // add extension data type to parquet meta.
     let mut key_values = Vec::new();
@@ -194,7 +194,13 @@ build_exceptions! {
     // Cloud control error codes
     CloudControlConnectError(1701),
     CloudControlNotEnabled(1702),
-    IllegalCloudControlMessageFormat(1703)
+    IllegalCloudControlMessageFormat(1703),
+
+    // Transaction error codes.
+    CurrentTransactionIsAborted(1801),
+
+    // Query queue error codes.
+    QueryQueueTimeout(1901),
 }
 
 // Meta service errors [2001, 3000].
@@ -225,6 +231,12 @@ build_exceptions! {
     NetworkPolicyAlreadyExists(2208),
     IllegalNetworkPolicy(2209),
     NetworkPolicyIsUsedByUser(2210),
+    UnknownRowAccessPolicy(2211),
+    RowAccessPolicyAlreadyExists(2212),
+    IllegalRowAccessPolicy(2213),
+    UnknownSequence(2214),
+    SequenceAlreadyExists(2215),
+    IllegalSequence(2216),
 
     // Meta api error codes.
     DatabaseAlreadyExists(2301),
@@ -331,6 +343,7 @@ build_exceptions! {
     StorageInsecure(3903),
     DeprecatedIndexFormat(3904),
     InvalidOperation(3905),
+    ColumnEncryptionError(3906),
     StorageOther(4000),
     UnresolvableConflict(4001),
 }
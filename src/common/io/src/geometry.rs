@@ -0,0 +1,55 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use geo::Geometry;
+use geozero::wkb::Wkb;
+use geozero::wkt::WktStr;
+use geozero::CoordDimensions;
+use geozero::ToGeo;
+use geozero::ToWkb;
+use geozero::ToWkt;
+
+/// Parses the geometry in the WKT format, such as `POINT(1 2)`, into WKB.
+pub fn parse_to_wkb(buf: &[u8]) -> Result<Vec<u8>> {
+    let wkt = std::str::from_utf8(buf).map_err(|e| ErrorCode::BadBytes(e.to_string()))?;
+    let geo = WktStr(wkt.trim())
+        .to_geo()
+        .map_err(|e| ErrorCode::BadBytes(format!("Invalid Geometry value: {:?}, {e}", wkt)))?;
+    geo_to_wkb(&geo)
+}
+
+/// Checks the bytes are a valid geometry in the WKB format.
+pub fn check_wkb(wkb: &[u8]) -> Result<()> {
+    wkb_to_geo(wkb).map(|_| ())
+}
+
+pub fn wkb_to_geo(wkb: &[u8]) -> Result<Geometry<f64>> {
+    Wkb(wkb.to_vec())
+        .to_geo()
+        .map_err(|e| ErrorCode::BadBytes(format!("Invalid WKB value: {e}")))
+}
+
+pub fn geo_to_wkb(geo: &Geometry<f64>) -> Result<Vec<u8>> {
+    geo.to_wkb(CoordDimensions::xy())
+        .map_err(|e| ErrorCode::BadBytes(format!("Cannot encode Geometry to WKB: {e}")))
+}
+
+/// Displays the geometry stored in WKB in the WKT format.
+pub fn wkb_to_wkt(wkb: &[u8]) -> Result<String> {
+    Wkb(wkb.to_vec())
+        .to_wkt()
+        .map_err(|e| ErrorCode::BadBytes(format!("Invalid WKB value: {e}")))
+}
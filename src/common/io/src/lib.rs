@@ -35,6 +35,7 @@ pub mod cursor_ext;
 mod decimal;
 mod escape;
 mod format_settings;
+mod geometry;
 mod position;
 mod serialization;
 mod stat_buffer;
@@ -44,3 +45,8 @@ pub use decimal::display_decimal_128;
 pub use decimal::display_decimal_256;
 pub use escape::escape_string;
 pub use escape::escape_string_with_quote;
+pub use geometry::check_wkb;
+pub use geometry::geo_to_wkb;
+pub use geometry::parse_to_wkb;
+pub use geometry::wkb_to_geo;
+pub use geometry::wkb_to_wkt;
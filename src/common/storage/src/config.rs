@@ -24,6 +24,8 @@ use common_meta_app::storage::StorageParams;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::StorageIOPolicy;
+
 /// Config for storage backend.
 ///
 /// # TODO(xuanwo)
@@ -47,6 +49,7 @@ pub struct StorageConfig {
     pub num_cpus: u64,
     pub allow_insecure: bool,
     pub params: StorageParams,
+    pub io_policy: StorageIOPolicy,
 }
 
 // TODO: This config should be moved out of common-storage crate.
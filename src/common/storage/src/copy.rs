@@ -20,6 +20,10 @@ use serde::Deserialize;
 use serde::Serialize;
 use thiserror::Error;
 
+/// Upper bound of malformed rows kept per file, so a badly broken file
+/// can not blow up the memory of a tolerant load.
+pub const MAX_KEPT_ERRORS_PER_FILE: usize = 1000;
+
 #[derive(Default, Clone, Serialize, Deserialize)]
 pub struct CopyStatus {
     /// Key is file path.
@@ -43,6 +47,31 @@ impl CopyStatus {
             self.add_chunk(&k, v);
         }
     }
+
+    /// The malformed rows tolerated by `ON_ERROR`, ordered by file and line.
+    pub fn error_records(&self) -> Vec<CopyErrorRecord> {
+        let mut records = vec![];
+        for entry in self.files.iter() {
+            if let Some(info) = &entry.value().error {
+                records.extend(info.errors.iter().map(|e| CopyErrorRecord {
+                    file: entry.key().clone(),
+                    // line starts from 0, make it 1-based like the copy result.
+                    line: e.line + 1,
+                    error: e.error.to_string(),
+                }));
+            }
+        }
+        records.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+        records
+    }
+}
+
+/// One malformed row of a load, as written to the copy error file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CopyErrorRecord {
+    pub file: String,
+    pub line: usize,
+    pub error: String,
 }
 
 #[derive(Default, Clone, Serialize, Deserialize)]
@@ -55,15 +84,21 @@ impl FileStatus {
     pub fn add_error(&mut self, error: FileParseError, line: usize) {
         match &mut self.error {
             None => {
+                let first_error = FileErrorInfo { error, line };
                 self.error = Some(FileErrorsInfo {
                     num_errors: 1,
-                    first_error: FileErrorInfo { error, line },
+                    errors: vec![first_error.clone()],
+                    first_error,
                 });
             }
             Some(info) => {
+                let error = FileErrorInfo { error, line };
                 info.num_errors += 1;
+                if info.errors.len() < MAX_KEPT_ERRORS_PER_FILE {
+                    info.errors.push(error.clone());
+                }
                 if info.first_error.line > line {
-                    info.first_error = FileErrorInfo { error, line };
+                    info.first_error = error;
                 }
             }
         };
@@ -83,11 +118,16 @@ impl FileStatus {
 pub struct FileErrorsInfo {
     pub num_errors: usize,
     pub first_error: FileErrorInfo,
+    /// The malformed rows of the file, at most [`MAX_KEPT_ERRORS_PER_FILE`].
+    #[serde(default)]
+    pub errors: Vec<FileErrorInfo>,
 }
 
 impl FileErrorsInfo {
     fn merge(&mut self, other: FileErrorsInfo) {
         self.num_errors += other.num_errors;
+        let remain = MAX_KEPT_ERRORS_PER_FILE.saturating_sub(self.errors.len());
+        self.errors.extend(other.errors.into_iter().take(remain));
         if self.first_error.line > other.first_error.line {
             self.first_error = other.first_error;
         }
@@ -43,6 +43,10 @@ pub mod metrics;
 pub use crate::metrics::StorageMetrics;
 pub use crate::metrics::StorageMetricsLayer;
 
+mod policy_layer;
+pub use policy_layer::PolicyLayer;
+pub use policy_layer::StorageIOPolicy;
+
 mod runtime_layer;
 
 mod column_node;
@@ -66,10 +70,19 @@ pub use stage::StageFilesInfo;
 pub use stage::STDIN_FD;
 
 mod copy;
+mod histogram;
 mod statistics;
 
+pub use copy::CopyErrorRecord;
 pub use copy::CopyStatus;
 pub use copy::FileParseError;
 pub use copy::FileStatus;
+pub use copy::MAX_KEPT_ERRORS_PER_FILE;
+pub use histogram::Histogram;
+pub use histogram::HistogramBucket;
 pub use statistics::Datum;
 pub use statistics::F64;
+
+mod temp_files;
+pub use temp_files::TempFileInfo;
+pub use temp_files::TempFilesRegistry;
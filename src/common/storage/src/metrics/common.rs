@@ -13,12 +13,19 @@
 // limitations under the License.
 
 use common_metrics::register_counter;
+use common_metrics::register_counter_family;
 use common_metrics::Counter;
+use common_metrics::Family;
+use common_metrics::VecLabels;
 use lazy_static::lazy_static;
+use opendal::ErrorKind;
 
 lazy_static! {
     static ref OMIT_FILTER_ROWGROUPS: Counter = register_counter("omit_filter_rowgroups");
     static ref OMIT_FILTER_ROWS: Counter = register_counter("omit_filter_rows");
+    static ref STORAGE_IO_ERRORS: Family<VecLabels, Counter> =
+        register_counter_family("storage_io_errors");
+    static ref STORAGE_HEDGED_READS: Counter = register_counter("storage_hedged_reads");
 }
 
 pub fn metrics_inc_omit_filter_rowgroups(c: u64) {
@@ -28,3 +35,13 @@ pub fn metrics_inc_omit_filter_rowgroups(c: u64) {
 pub fn metrics_inc_omit_filter_rows(c: u64) {
     OMIT_FILTER_ROWS.inc_by(c);
 }
+
+/// Count the failed attempts of storage operations by the operation and the error kind.
+pub fn metrics_inc_storage_io_errors(op: &'static str, kind: ErrorKind) {
+    let labels = &vec![("op", op.to_string()), ("kind", kind.to_string())];
+    STORAGE_IO_ERRORS.get_or_create(labels).inc();
+}
+
+pub fn metrics_inc_storage_hedged_reads() {
+    STORAGE_HEDGED_READS.inc();
+}
@@ -50,8 +50,10 @@ use opendal::Builder;
 use opendal::Operator;
 use storage_encryption::get_storage_encryption_handler;
 
+use crate::policy_layer::PolicyLayer;
 use crate::runtime_layer::RuntimeLayer;
 use crate::StorageConfig;
+use crate::StorageIOPolicy;
 
 /// init_operator will init an opendal operator based on storage config.
 pub fn init_operator(cfg: &StorageParams) -> Result<Operator> {
@@ -87,6 +89,7 @@ pub fn init_operator(cfg: &StorageParams) -> Result<Operator> {
 
 pub fn build_operator<B: Builder>(builder: B) -> Result<Operator> {
     let ob = Operator::new(builder)?;
+    let policy = StorageIOPolicy::instance();
 
     let op = ob
         // NOTE
@@ -98,14 +101,22 @@ pub fn build_operator<B: Builder>(builder: B) -> Result<Operator> {
         .layer(
             TimeoutLayer::new()
                 // Return timeout error if the operation failed to finish in
-                // 60s
-                .with_timeout(Duration::from_secs(60))
+                // the timeout of the policy, 60s by default.
+                .with_timeout(Duration::from_secs(policy.timeout_secs))
                 // Return timeout error if the request speed is less than
                 // 1 KiB/s.
                 .with_speed(1024),
         )
-        // Add retry
-        .layer(RetryLayer::new().with_jitter())
+        // Record errors by kind and hedge ranged reads, for each attempt
+        .layer(PolicyLayer::new(&policy))
+        // Add retry with exponential backoff
+        .layer(
+            RetryLayer::new()
+                .with_max_times(policy.retry_max_times as usize)
+                .with_min_delay(Duration::from_millis(policy.retry_min_delay_ms))
+                .with_max_delay(Duration::from_millis(policy.retry_max_delay_ms))
+                .with_jitter(),
+        )
         // Add logging
         .layer(LoggingLayer::default())
         // Add tracing
@@ -405,6 +416,7 @@ impl DataOperator {
 
     #[async_backtrace::framed]
     pub async fn init(conf: &StorageConfig) -> common_exception::Result<()> {
+        StorageIOPolicy::init(conf.io_policy.clone());
         GlobalInstance::set(Self::try_create(&conf.params).await?);
 
         Ok(())
@@ -0,0 +1,220 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use common_base::base::tokio;
+use futures::future::select;
+use futures::future::Either;
+use opendal::raw::Accessor;
+use opendal::raw::Layer;
+use opendal::raw::LayeredAccessor;
+use opendal::raw::OpCreateDir;
+use opendal::raw::OpDelete;
+use opendal::raw::OpList;
+use opendal::raw::OpRead;
+use opendal::raw::OpStat;
+use opendal::raw::OpWrite;
+use opendal::raw::RpCreateDir;
+use opendal::raw::RpDelete;
+use opendal::raw::RpList;
+use opendal::raw::RpRead;
+use opendal::raw::RpStat;
+use opendal::raw::RpWrite;
+use opendal::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::metrics::common::metrics_inc_storage_hedged_reads;
+use crate::metrics::common::metrics_inc_storage_io_errors;
+
+static STORAGE_IO_POLICY: OnceLock<StorageIOPolicy> = OnceLock::new();
+
+/// Retry, timeout and hedged read policy of the storage operations.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageIOPolicy {
+    /// Max retry times of a failed temporary error, the delay grows exponentially.
+    pub retry_max_times: u64,
+    pub retry_min_delay_ms: u64,
+    pub retry_max_delay_ms: u64,
+    /// Timeout of a single attempt of an operation.
+    pub timeout_secs: u64,
+    /// Send a second request for a ranged read which has not responded within this delay,
+    /// and take the first response. 0 means hedged read is disabled.
+    pub hedged_read_delay_ms: u64,
+}
+
+impl Default for StorageIOPolicy {
+    fn default() -> Self {
+        Self {
+            retry_max_times: 3,
+            retry_min_delay_ms: 1000,
+            retry_max_delay_ms: 60000,
+            timeout_secs: 60,
+            hedged_read_delay_ms: 0,
+        }
+    }
+}
+
+impl StorageIOPolicy {
+    /// Set the policy of the operators built later, only the first call takes effect.
+    pub fn init(policy: StorageIOPolicy) {
+        let _ = STORAGE_IO_POLICY.set(policy);
+    }
+
+    /// The policy used to build operators, the default one if not initialized.
+    pub fn instance() -> StorageIOPolicy {
+        STORAGE_IO_POLICY.get().cloned().unwrap_or_default()
+    }
+
+    pub fn hedged_read_delay(&self) -> Option<Duration> {
+        (self.hedged_read_delay_ms > 0).then(|| Duration::from_millis(self.hedged_read_delay_ms))
+    }
+}
+
+/// PolicyLayer records the errors of every attempt by error kind,
+/// and hedges the ranged reads if enabled.
+#[derive(Clone, Debug)]
+pub struct PolicyLayer {
+    hedged_read_delay: Option<Duration>,
+}
+
+impl PolicyLayer {
+    pub fn new(policy: &StorageIOPolicy) -> Self {
+        PolicyLayer {
+            hedged_read_delay: policy.hedged_read_delay(),
+        }
+    }
+}
+
+impl<A: Accessor> Layer<A> for PolicyLayer {
+    type LayeredAccessor = PolicyAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        PolicyAccessor {
+            inner: Arc::new(inner),
+            hedged_read_delay: self.hedged_read_delay,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PolicyAccessor<A> {
+    inner: Arc<A>,
+    hedged_read_delay: Option<Duration>,
+}
+
+fn record<T>(op: &'static str, res: Result<T>) -> Result<T> {
+    if let Err(err) = &res {
+        metrics_inc_storage_io_errors(op, err.kind());
+    }
+    res
+}
+
+impl<A: Accessor> PolicyAccessor<A> {
+    async fn hedged_read(
+        &self,
+        path: &str,
+        args: OpRead,
+        delay: Duration,
+    ) -> Result<(RpRead, A::Reader)> {
+        let mut first = Box::pin(self.inner.read(path, args.clone()));
+        let first = match tokio::time::timeout(delay, &mut first).await {
+            Ok(res) => return res,
+            Err(_) => first,
+        };
+
+        metrics_inc_storage_hedged_reads();
+        let second = Box::pin(self.inner.read(path, args));
+        // Take the first success, or wait for the other one if the first finished one failed.
+        match select(first, second).await {
+            Either::Left((Ok(v), _)) | Either::Right((Ok(v), _)) => Ok(v),
+            Either::Left((Err(err), second)) => {
+                metrics_inc_storage_io_errors("read", err.kind());
+                second.await
+            }
+            Either::Right((Err(err), first)) => {
+                metrics_inc_storage_io_errors("read", err.kind());
+                first.await
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for PolicyAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = A::Writer;
+    type BlockingWriter = A::BlockingWriter;
+    type Pager = A::Pager;
+    type BlockingPager = A::BlockingPager;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    #[async_backtrace::framed]
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        record("create_dir", self.inner.create_dir(path, args).await)
+    }
+
+    #[async_backtrace::framed]
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let res = match self.hedged_read_delay {
+            // Only hedge the ranged reads, which are the block fetches.
+            Some(delay) if args.range().size().is_some() => {
+                self.hedged_read(path, args, delay).await
+            }
+            _ => self.inner.read(path, args).await,
+        };
+        record("read", res)
+    }
+
+    #[async_backtrace::framed]
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        record("write", self.inner.write(path, args).await)
+    }
+
+    #[async_backtrace::framed]
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        record("stat", self.inner.stat(path, args).await)
+    }
+
+    #[async_backtrace::framed]
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        record("delete", self.inner.delete(path, args).await)
+    }
+
+    #[async_backtrace::framed]
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Pager)> {
+        record("list", self.inner.list(path, args).await)
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        record("read", self.inner.blocking_read(path, args))
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        record("write", self.inner.blocking_write(path, args))
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingPager)> {
+        record("list", self.inner.blocking_list(path, args))
+    }
+}
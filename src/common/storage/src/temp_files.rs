@@ -0,0 +1,62 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::GlobalInstance;
+use common_exception::Result;
+use dashmap::DashMap;
+
+/// Which query and operator created a temporary file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TempFileInfo {
+    pub query_id: String,
+    /// The kind of the operator spilling the data, such as `Aggregate` or `HashJoinBuild`.
+    pub spill_type: String,
+}
+
+/// Registry of the temporary files written by the queries of this node.
+///
+/// The files themselves are listed from the storage, the registry only tells
+/// which query they belong to, see `system.temp_files`.
+pub struct TempFilesRegistry {
+    files: DashMap<String, TempFileInfo>,
+}
+
+impl TempFilesRegistry {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(TempFilesRegistry {
+            files: DashMap::new(),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<TempFilesRegistry> {
+        GlobalInstance::get()
+    }
+
+    /// Records the temporary file written at `location`.
+    pub fn register(&self, location: &str, info: TempFileInfo) {
+        self.files.insert(location.to_string(), info);
+    }
+
+    pub fn get(&self, location: &str) -> Option<TempFileInfo> {
+        self.files.get(location).map(|info| info.value().clone())
+    }
+
+    /// Forgets the files for which `f` returns false, e.g. the files removed from the storage.
+    pub fn retain(&self, mut f: impl FnMut(&str) -> bool) {
+        self.files.retain(|location, _| f(location));
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_storage::CopyErrorRecord;
+use common_storage::CopyStatus;
+use common_storage::FileParseError;
+use common_storage::FileStatus;
+use common_storage::MAX_KEPT_ERRORS_PER_FILE;
+
+fn mismatch(file: usize) -> FileParseError {
+    FileParseError::NumberOfColumnsMismatch { table: 3, file }
+}
+
+#[test]
+fn test_copy_status_error_records() {
+    let status = CopyStatus::default();
+
+    let mut chunk = FileStatus::default();
+    chunk.add_error(mismatch(4), 5);
+    chunk.add_error(mismatch(2), 1);
+    status.add_chunk("b.csv", chunk);
+
+    let mut chunk = FileStatus::default();
+    chunk.add_error(mismatch(1), 3);
+    status.add_chunk("a.csv", chunk);
+
+    // another chunk of the same file, e.g. loaded by another node.
+    let mut chunk = FileStatus::default();
+    chunk.add_error(mismatch(5), 0);
+    status.add_chunk("b.csv", chunk);
+
+    let b = status.files.get("b.csv").unwrap();
+    let info = b.error.as_ref().unwrap();
+    assert_eq!(info.num_errors, 3);
+    assert_eq!(info.first_error.line, 0);
+
+    let lines = status
+        .error_records()
+        .into_iter()
+        .map(|r| (r.file, r.line))
+        .collect::<Vec<_>>();
+    assert_eq!(lines, vec![
+        ("a.csv".to_string(), 4),
+        ("b.csv".to_string(), 1),
+        ("b.csv".to_string(), 2),
+        ("b.csv".to_string(), 6),
+    ]);
+
+    assert_eq!(status.error_records()[0], CopyErrorRecord {
+        file: "a.csv".to_string(),
+        line: 4,
+        error: mismatch(1).to_string(),
+    });
+}
+
+#[test]
+fn test_copy_status_caps_kept_errors() {
+    let status = CopyStatus::default();
+    for _ in 0..2 {
+        let mut chunk = FileStatus::default();
+        for line in 0..MAX_KEPT_ERRORS_PER_FILE {
+            chunk.add_error(mismatch(1), line);
+        }
+        status.add_chunk("a.csv", chunk);
+    }
+
+    let a = status.files.get("a.csv").unwrap();
+    let info = a.error.as_ref().unwrap();
+    assert_eq!(info.num_errors, 2 * MAX_KEPT_ERRORS_PER_FILE);
+    assert_eq!(info.errors.len(), MAX_KEPT_ERRORS_PER_FILE);
+}
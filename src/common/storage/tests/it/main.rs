@@ -13,3 +13,5 @@
 // limitations under the License.
 
 mod column_node;
+mod copy;
+mod policy_layer;
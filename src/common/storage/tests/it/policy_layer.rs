@@ -0,0 +1,58 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_storage::PolicyLayer;
+use common_storage::StorageIOPolicy;
+use opendal::services::Memory;
+use opendal::ErrorKind;
+use opendal::Operator;
+
+#[test]
+fn test_storage_io_policy_default() {
+    let policy = StorageIOPolicy::default();
+    assert_eq!(policy.retry_max_times, 3);
+    assert_eq!(policy.timeout_secs, 60);
+    assert_eq!(policy.hedged_read_delay(), None);
+
+    let policy = StorageIOPolicy {
+        hedged_read_delay_ms: 20,
+        ..Default::default()
+    };
+    assert_eq!(policy.hedged_read_delay(), Some(Duration::from_millis(20)));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_policy_layer_read() -> anyhow::Result<()> {
+    let policy = StorageIOPolicy {
+        hedged_read_delay_ms: 1,
+        ..Default::default()
+    };
+    let op = Operator::new(Memory::default())?
+        .layer(PolicyLayer::new(&policy))
+        .finish();
+
+    op.write("block", b"0123456789".to_vec()).await?;
+    // ranged reads are hedged
+    let data = op.read_with("block").range(2..6).await?;
+    assert_eq!(data, b"2345");
+    let data = op.read("block").await?;
+    assert_eq!(data, b"0123456789");
+
+    let err = op.read_with("missing").range(0..4).await.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+    Ok(())
+}
@@ -146,6 +146,8 @@ use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
 use common_meta_app::schema::UpdateIndexReply;
 use common_meta_app::schema::UpdateIndexReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReply;
@@ -2844,6 +2846,101 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         }
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        loop {
+            let mut txn_req = TxnRequest {
+                condition: vec![],
+                if_then: vec![],
+                else_then: vec![],
+            };
+
+            for update in req.update_table_metas.iter() {
+                let tbid = TableId {
+                    table_id: update.table_id,
+                };
+                let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                    get_pb_value(self, &tbid).await?;
+
+                if tb_meta_seq == 0 || table_meta.is_none() {
+                    return Err(KVAppError::AppError(AppError::UnknownTableId(
+                        UnknownTableId::new(update.table_id, "update_multi_table_meta"),
+                    )));
+                }
+                if update.seq.match_seq(tb_meta_seq).is_err() {
+                    return Err(KVAppError::AppError(AppError::from(
+                        TableVersionMismatched::new(
+                            update.table_id,
+                            update.seq,
+                            tb_meta_seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+
+                // table is not changed
+                txn_req.condition.push(txn_cond_seq(&tbid, Eq, tb_meta_seq));
+                // tb_id -> tb_meta
+                txn_req
+                    .if_then
+                    .push(txn_op_put(&tbid, serialize_struct(&update.new_table_meta)?));
+
+                if let Some(copied_files) = &update.copied_files {
+                    let (conditions, match_operations) =
+                        build_upsert_table_copied_file_info_conditions(
+                            &tbid,
+                            copied_files,
+                            tb_meta_seq,
+                            copied_files.fail_if_duplicated,
+                        )?;
+                    txn_req.condition.extend(conditions);
+                    txn_req.if_then.extend(match_operations)
+                }
+
+                if let Some(deduplicated_label) = update.deduplicated_label.clone() {
+                    txn_req
+                        .if_then
+                        .push(build_upsert_table_deduplicated_label(deduplicated_label))
+                }
+            }
+
+            let (succ, _) = send_txn(self, txn_req).await?;
+
+            debug!(succ = succ; "update_multi_table_meta");
+
+            if succ {
+                return Ok(UpdateMultiTableMetaReply {});
+            }
+            // One of the tables or copied files is changed since it is read,
+            // the next round reports the version mismatch if any, or retries.
+            for update in req.update_table_metas.iter() {
+                let copied_files_conflict = update
+                    .copied_files
+                    .as_ref()
+                    .map(|v| v.fail_if_duplicated)
+                    .unwrap_or(false);
+                if copied_files_conflict {
+                    let tbid = TableId {
+                        table_id: update.table_id,
+                    };
+                    let (tb_meta_seq, _): (_, Option<TableMeta>) =
+                        get_pb_value(self, &tbid).await?;
+                    if update.seq.match_seq(tb_meta_seq).is_ok() {
+                        return Err(KVAppError::AppError(AppError::from(
+                            DuplicatedUpsertFiles::new(update.table_id, "update_multi_table_meta"),
+                        )));
+                    }
+                }
+            }
+        }
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn set_table_column_mask_policy(
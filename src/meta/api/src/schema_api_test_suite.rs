@@ -97,6 +97,7 @@ use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::TruncateTableReq;
 use common_meta_app::schema::UndropDatabaseReq;
 use common_meta_app::schema::UndropTableReq;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
@@ -284,6 +285,9 @@ impl SchemaApiTestSuite {
             .await?;
         suite.table_rename(&b.build().await).await?;
         suite.table_update_meta(&b.build().await).await?;
+        suite
+            .table_update_multi_table_meta(&b.build().await)
+            .await?;
         suite.table_update_mask_policy(&b.build().await).await?;
         suite.table_upsert_option(&b.build().await).await?;
         suite.table_list(&b.build().await).await?;
@@ -2044,6 +2048,119 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    async fn table_update_multi_table_meta<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_names = ["tb1", "tb2"];
+
+        let table_meta = || TableMeta {
+            schema: Arc::new(TableSchema::new(vec![TableField::new(
+                "number",
+                TableDataType::Number(NumberDataType::UInt64),
+            )])),
+            engine: "JSON".to_string(),
+            options: Default::default(),
+            created_on: Utc::now(),
+            ..TableMeta::default()
+        };
+
+        info!("--- prepare db and tables");
+        {
+            let plan = CreateDatabaseReq {
+                if_not_exists: false,
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    ..DatabaseMeta::default()
+                },
+            };
+            mt.create_database(plan).await?;
+
+            for tbl_name in tbl_names {
+                let req = CreateTableReq {
+                    if_not_exists: false,
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: tbl_name.to_string(),
+                    },
+                    table_meta: table_meta(),
+                };
+                mt.create_table(req).await?;
+            }
+        }
+
+        let statistics = |data_bytes| TableStatistics {
+            data_bytes,
+            ..Default::default()
+        };
+
+        info!("--- update multi table meta, normal case");
+        {
+            let mut update_table_metas = vec![];
+            for (i, tbl_name) in tbl_names.iter().enumerate() {
+                let table = mt.get_table((tenant, db_name, *tbl_name).into()).await?;
+                let mut new_table_meta = table.meta.clone();
+                new_table_meta.statistics = statistics(i as u64 + 1);
+                update_table_metas.push(UpdateTableMetaReq {
+                    table_id: table.ident.table_id,
+                    seq: MatchSeq::Exact(table.ident.seq),
+                    new_table_meta,
+                    copied_files: None,
+                    deduplicated_label: None,
+                });
+            }
+            mt.update_multi_table_meta(UpdateMultiTableMetaReq {
+                update_table_metas: update_table_metas.clone(),
+            })
+            .await?;
+
+            for (tbl_name, req) in tbl_names.iter().zip(update_table_metas) {
+                let table = mt.get_table((tenant, db_name, *tbl_name).into()).await?;
+                assert_eq!(table.meta, req.new_table_meta);
+            }
+        }
+
+        info!("--- update multi table meta: version mismatch of one table updates none");
+        {
+            let mut update_table_metas = vec![];
+            for (i, tbl_name) in tbl_names.iter().enumerate() {
+                let table = mt.get_table((tenant, db_name, *tbl_name).into()).await?;
+                let mut new_table_meta = table.meta.clone();
+                new_table_meta.statistics = statistics(10);
+                let seq = if i == 1 {
+                    table.ident.seq + 1
+                } else {
+                    table.ident.seq
+                };
+                update_table_metas.push(UpdateTableMetaReq {
+                    table_id: table.ident.table_id,
+                    seq: MatchSeq::Exact(seq),
+                    new_table_meta,
+                    copied_files: None,
+                    deduplicated_label: None,
+                });
+            }
+            let res = mt
+                .update_multi_table_meta(UpdateMultiTableMetaReq { update_table_metas })
+                .await;
+
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::TABLE_VERSION_MISMATCHED, err.code());
+
+            for (i, tbl_name) in tbl_names.iter().enumerate() {
+                let table = mt.get_table((tenant, db_name, *tbl_name).into()).await?;
+                assert_eq!(table.meta.statistics, statistics(i as u64 + 1));
+            }
+        }
+
+        Ok(())
+    }
+
     #[minitrace::trace]
     async fn table_update_meta<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
         let tenant = "tenant1";
@@ -109,6 +109,8 @@ pub enum FileFormatParams {
     Json(JsonFileFormatParams),
     Xml(XmlFileFormatParams),
     Parquet(ParquetFileFormatParams),
+    Orc(OrcFileFormatParams),
+    Avro(AvroFileFormatParams),
 }
 
 impl FileFormatParams {
@@ -120,6 +122,8 @@ impl FileFormatParams {
             FileFormatParams::Json(_) => StageFileFormatType::Json,
             FileFormatParams::Xml(_) => StageFileFormatType::Xml,
             FileFormatParams::Parquet(_) => StageFileFormatType::Parquet,
+            FileFormatParams::Orc(_) => StageFileFormatType::Orc,
+            FileFormatParams::Avro(_) => StageFileFormatType::Avro,
         }
     }
 
@@ -137,6 +141,10 @@ impl FileFormatParams {
                 Ok(FileFormatParams::Json(JsonFileFormatParams::default()))
             }
             StageFileFormatType::Xml => Ok(FileFormatParams::Xml(XmlFileFormatParams::default())),
+            StageFileFormatType::Orc => Ok(FileFormatParams::Orc(OrcFileFormatParams::default())),
+            StageFileFormatType::Avro => {
+                Ok(FileFormatParams::Avro(AvroFileFormatParams::default()))
+            }
             _ => Err(ErrorCode::IllegalFileFormat(format!(
                 "Unsupported file format type: {:?}",
                 format_type
@@ -152,6 +160,8 @@ impl FileFormatParams {
             FileFormatParams::Json(v) => v.compression,
             FileFormatParams::Xml(v) => v.compression,
             FileFormatParams::Parquet(_) => StageFileCompression::None,
+            FileFormatParams::Orc(_) => StageFileCompression::None,
+            FileFormatParams::Avro(_) => StageFileCompression::None,
         }
     }
 
@@ -177,6 +187,8 @@ impl FileFormatParams {
                 FileFormatParams::NdJson(NdJsonFileFormatParams { compression })
             }
             StageFileFormatType::Parquet => FileFormatParams::Parquet(ParquetFileFormatParams {}),
+            StageFileFormatType::Orc => FileFormatParams::Orc(OrcFileFormatParams {}),
+            StageFileFormatType::Avro => FileFormatParams::Avro(AvroFileFormatParams {}),
             StageFileFormatType::Csv => {
                 let default = CsvFileFormatParams::default();
                 let compression = ast.take_compression()?;
@@ -427,6 +439,12 @@ impl NdJsonFileFormatParams {
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ParquetFileFormatParams {}
 
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcFileFormatParams {}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AvroFileFormatParams {}
+
 impl Display for FileFormatParams {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -472,6 +490,12 @@ impl Display for FileFormatParams {
             FileFormatParams::Parquet(_) => {
                 write!(f, "TYPE = PARQUET")
             }
+            FileFormatParams::Orc(_) => {
+                write!(f, "TYPE = ORC")
+            }
+            FileFormatParams::Avro(_) => {
+                write!(f, "TYPE = AVRO")
+            }
         }
     }
 }
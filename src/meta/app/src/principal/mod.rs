@@ -18,6 +18,8 @@ mod file_format;
 mod network_policy;
 mod principal_identity;
 mod role_info;
+mod row_access_policy;
+mod sequence;
 mod user_auth;
 mod user_defined_file_format;
 mod user_defined_function;
@@ -34,12 +36,16 @@ pub use network_policy::NetworkPolicy;
 pub use principal_identity::PrincipalIdentity;
 pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
+pub use row_access_policy::RowAccessPolicy;
+pub use sequence::Sequence;
 pub use user_auth::AuthInfo;
 pub use user_auth::AuthType;
 pub use user_auth::PasswordHashMethod;
 pub use user_defined_file_format::UserDefinedFileFormat;
+pub use user_defined_function::LambdaUDAF;
 pub use user_defined_function::LambdaUDF;
 pub use user_defined_function::UDFDefinition;
+pub use user_defined_function::UDFScript;
 pub use user_defined_function::UDFServer;
 pub use user_defined_function::UserDefinedFunction;
 pub use user_grant::GrantEntry;
@@ -0,0 +1,29 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// A sequence hands out monotonically increasing values, starting from `start` and
+/// stepping by `increment`. `current` is the next value that has not been allocated
+/// to any query node yet.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct Sequence {
+    pub name: String,
+    pub start: u64,
+    pub increment: u64,
+    pub current: u64,
+    pub comment: String,
+    pub create_on: DateTime<Utc>,
+}
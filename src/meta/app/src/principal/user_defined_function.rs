@@ -32,10 +32,35 @@ pub struct UDFServer {
     pub return_type: DataType,
 }
 
+/// A UDF whose code is stored in the definition and run by the query node,
+/// only `wasm` is supported as the language now.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UDFScript {
+    pub code: String,
+    pub handler: String,
+    pub language: String,
+    pub arg_types: Vec<DataType>,
+    pub return_type: DataType,
+}
+
+/// A user-defined aggregate function built from SQL lambdas: the state starts
+/// from `init`, every row is folded into it by `accumulate`, partial states
+/// are combined by `merge` and the result is produced by `finalize`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LambdaUDAF {
+    pub state_type: DataType,
+    pub init: String,
+    pub accumulate: LambdaUDF,
+    pub merge: LambdaUDF,
+    pub finalize: LambdaUDF,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum UDFDefinition {
     LambdaUDF(LambdaUDF),
     UDFServer(UDFServer),
+    UDFScript(UDFScript),
+    LambdaUDAF(LambdaUDAF),
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -83,31 +108,63 @@ impl UserDefinedFunction {
             }),
         }
     }
+
+    pub fn create_udf_script(
+        name: &str,
+        code: &str,
+        handler: &str,
+        language: &str,
+        arg_types: Vec<DataType>,
+        return_type: DataType,
+        description: &str,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            description: description.to_string(),
+            definition: UDFDefinition::UDFScript(UDFScript {
+                code: code.to_string(),
+                handler: handler.to_string(),
+                language: language.to_string(),
+                arg_types,
+                return_type,
+            }),
+        }
+    }
 }
 
 impl Display for UDFDefinition {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        write!(f, " (")?;
         match self {
-            UDFDefinition::LambdaUDF(LambdaUDF {
-                parameters,
-                definition,
+            UDFDefinition::LambdaUDF(lambda_udf) => {
+                write!(f, " {lambda_udf}")?;
+            }
+            UDFDefinition::UDFServer(UDFServer {
+                address,
+                arg_types,
+                return_type,
+                handler,
+                language,
             }) => {
-                for (i, item) in parameters.iter().enumerate() {
+                write!(f, " (")?;
+                for (i, item) in arg_types.iter().enumerate() {
                     if i > 0 {
                         write!(f, ", ")?;
                     }
                     write!(f, "{item}")?;
                 }
-                write!(f, ") -> {definition}")?;
+                write!(
+                    f,
+                    ") RETURNS {return_type} LANGUAGE {language} HANDLER = {handler} ADDRESS = {address}"
+                )?;
             }
-            UDFDefinition::UDFServer(UDFServer {
-                address,
+            UDFDefinition::UDFScript(UDFScript {
+                code,
                 arg_types,
                 return_type,
                 handler,
                 language,
             }) => {
+                write!(f, " (")?;
                 for (i, item) in arg_types.iter().enumerate() {
                     if i > 0 {
                         write!(f, ", ")?;
@@ -116,7 +173,19 @@ impl Display for UDFDefinition {
                 }
                 write!(
                     f,
-                    ") RETURNS {return_type} LANGUAGE {language} HANDLER = {handler} ADDRESS = {address}"
+                    ") RETURNS {return_type} LANGUAGE {language} HANDLER = {handler} AS {code}"
+                )?;
+            }
+            UDFDefinition::LambdaUDAF(LambdaUDAF {
+                state_type,
+                init,
+                accumulate,
+                merge,
+                finalize,
+            }) => {
+                write!(
+                    f,
+                    " STATE {state_type} INIT = {init} ACCUMULATE = {accumulate} MERGE = {merge} FINALIZE = {finalize}"
                 )?;
             }
         }
@@ -66,6 +66,8 @@ pub enum UserPrivilegeType {
     CreateDataMask = 1 << 16,
     // Privilege to Own a databend object such as database/table.
     Ownership = 1 << 17,
+    // Privilege to read the original values of columns protected by masking policies.
+    Unmask = 1 << 18,
     // TODO: remove this later
     Set = 1 << 4,
 }
@@ -89,6 +91,7 @@ const ALL_PRIVILEGES: BitFlags<UserPrivilegeType> = make_bitflags!(
         | Set
         | CreateDataMask
         | Ownership
+        | Unmask
     }
 );
 
@@ -113,6 +116,7 @@ impl std::fmt::Display for UserPrivilegeType {
             UserPrivilegeType::Set => "SET",
             UserPrivilegeType::CreateDataMask => "CREATE DATAMASK",
             UserPrivilegeType::Ownership => "OWNERSHIP",
+            UserPrivilegeType::Unmask => "UNMASK",
         })
     }
 }
@@ -141,6 +145,12 @@ impl UserPrivilegeSet {
         (database_privs.privileges | privs).into()
     }
 
+    /// The privileges which can only be granted explicitly on the global grant object, they are
+    /// not included in `GRANT ALL` nor in the builtin account_admin role.
+    pub fn explicit_privileges_on_global() -> Self {
+        make_bitflags!(UserPrivilegeType::{ Unmask }).into()
+    }
+
     /// The available privileges on database object contains ALL the available privileges to a table.
     /// Currently the privileges available to a database and a table are the same, it might becomes
     /// some differences in the future.
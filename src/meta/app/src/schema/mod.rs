@@ -94,6 +94,8 @@ pub use table::TruncateTableReply;
 pub use table::TruncateTableReq;
 pub use table::UndropTableReply;
 pub use table::UndropTableReq;
+pub use table::UpdateMultiTableMetaReply;
+pub use table::UpdateMultiTableMetaReq;
 pub use table::UpdateTableMetaReply;
 pub use table::UpdateTableMetaReq;
 pub use table::UpsertTableCopiedFileReply;
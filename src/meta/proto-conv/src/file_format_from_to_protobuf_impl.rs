@@ -243,6 +243,16 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::XmlFileFormatParams::from_pb(p)?,
                 ))
             }
+            Some(pb::file_format_params::Format::Orc(p)) => {
+                Ok(mt::principal::FileFormatParams::Orc(
+                    mt::principal::OrcFileFormatParams::from_pb(p)?,
+                ))
+            }
+            Some(pb::file_format_params::Format::Avro(p)) => {
+                Ok(mt::principal::FileFormatParams::Avro(
+                    mt::principal::AvroFileFormatParams::from_pb(p)?,
+                ))
+            }
             None => Err(Incompatible {
                 reason: "FileFormatParams.format cannot be None".to_string(),
             }),
@@ -281,6 +291,16 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::XmlFileFormatParams::to_pb(p)?,
                 )),
             }),
+            Self::Orc(p) => Ok(Self::PB {
+                format: Some(pb::file_format_params::Format::Orc(
+                    mt::principal::OrcFileFormatParams::to_pb(p)?,
+                )),
+            }),
+            Self::Avro(p) => Ok(Self::PB {
+                format: Some(pb::file_format_params::Format::Avro(
+                    mt::principal::AvroFileFormatParams::to_pb(p)?,
+                )),
+            }),
         }
     }
 }
@@ -305,6 +325,46 @@ impl FromToProto for mt::principal::ParquetFileFormatParams {
     }
 }
 
+impl FromToProto for mt::principal::OrcFileFormatParams {
+    type PB = pb::OrcFileFormatParams;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: pb::OrcFileFormatParams) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(mt::principal::OrcFileFormatParams {})
+    }
+
+    fn to_pb(&self) -> Result<pb::OrcFileFormatParams, Incompatible> {
+        Ok(pb::OrcFileFormatParams {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+        })
+    }
+}
+
+impl FromToProto for mt::principal::AvroFileFormatParams {
+    type PB = pb::AvroFileFormatParams;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: pb::AvroFileFormatParams) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(mt::principal::AvroFileFormatParams {})
+    }
+
+    fn to_pb(&self) -> Result<pb::AvroFileFormatParams, Incompatible> {
+        Ok(pb::AvroFileFormatParams {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+        })
+    }
+}
+
 impl FromToProto for mt::principal::NdJsonFileFormatParams {
     type PB = pb::NdJsonFileFormatParams;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -260,6 +260,9 @@ impl FromToProto for ex::TableDataType {
                         ex::TableDataType::Decimal(ex::types::decimal::DecimalDataType::from_pb(x)?)
                     }
                     Dt24::EmptyMapT(_) => ex::TableDataType::EmptyMap,
+                    Dt24::GeometryT(_) => ex::TableDataType::Geometry,
+                    Dt24::TimeT(_) => ex::TableDataType::Time,
+                    Dt24::BinaryT(_) => ex::TableDataType::Binary,
                 };
                 Ok(x)
             }
@@ -320,6 +323,9 @@ impl FromToProto for ex::TableDataType {
                 new_pb_dt24(Dt24::TupleT(x))
             }
             TableDataType::Variant => new_pb_dt24(Dt24::VariantT(pb::Empty {})),
+            TableDataType::Geometry => new_pb_dt24(Dt24::GeometryT(pb::Empty {})),
+            TableDataType::Time => new_pb_dt24(Dt24::TimeT(pb::Empty {})),
+            TableDataType::Binary => new_pb_dt24(Dt24::BinaryT(pb::Empty {})),
         };
         Ok(x)
     }
@@ -104,6 +104,110 @@ impl FromToProto for mt::UDFServer {
     }
 }
 
+impl FromToProto for mt::UDFScript {
+    type PB = pb::UdfScript;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::UdfScript) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let mut arg_types = Vec::with_capacity(p.arg_types.len());
+        for arg_type in p.arg_types {
+            let arg_type = DataType::from(&TableDataType::from_pb(arg_type)?);
+            arg_types.push(arg_type);
+        }
+        let return_type = DataType::from(&TableDataType::from_pb(p.return_type.ok_or_else(
+            || Incompatible {
+                reason: "UdfScript.return_type can not be None".to_string(),
+            },
+        )?)?);
+
+        Ok(mt::UDFScript {
+            code: p.code,
+            arg_types,
+            return_type,
+            handler: p.handler,
+            language: p.language,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::UdfScript, Incompatible> {
+        let mut arg_types = Vec::with_capacity(self.arg_types.len());
+        for arg_type in self.arg_types.iter() {
+            let arg_type = infer_schema_type(arg_type)
+                .map_err(|e| Incompatible {
+                    reason: format!("Convert DataType to TableDataType failed: {}", e.message()),
+                })?
+                .to_pb()?;
+            arg_types.push(arg_type);
+        }
+        let return_type = infer_schema_type(&self.return_type)
+            .map_err(|e| Incompatible {
+                reason: format!("Convert DataType to TableDataType failed: {}", e.message()),
+            })?
+            .to_pb()?;
+
+        Ok(pb::UdfScript {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            code: self.code.clone(),
+            handler: self.handler.clone(),
+            language: self.language.clone(),
+            arg_types,
+            return_type: Some(return_type),
+        })
+    }
+}
+
+impl FromToProto for mt::LambdaUDAF {
+    type PB = pb::LambdaUdaf;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::LambdaUdaf) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let state_type =
+            DataType::from(&TableDataType::from_pb(p.state_type.ok_or_else(|| {
+                Incompatible {
+                    reason: "LambdaUdaf.state_type can not be None".to_string(),
+                }
+            })?)?);
+        let lambda = |lambda: Option<pb::LambdaUdf>, name: &str| {
+            mt::LambdaUDF::from_pb(lambda.ok_or_else(|| Incompatible {
+                reason: format!("LambdaUdaf.{name} can not be None"),
+            })?)
+        };
+
+        Ok(mt::LambdaUDAF {
+            state_type,
+            init: p.init,
+            accumulate: lambda(p.accumulate, "accumulate")?,
+            merge: lambda(p.merge, "merge")?,
+            finalize: lambda(p.finalize, "finalize")?,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::LambdaUdaf, Incompatible> {
+        let state_type = infer_schema_type(&self.state_type)
+            .map_err(|e| Incompatible {
+                reason: format!("Convert DataType to TableDataType failed: {}", e.message()),
+            })?
+            .to_pb()?;
+
+        Ok(pb::LambdaUdaf {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            state_type: Some(state_type),
+            init: self.init.clone(),
+            accumulate: Some(self.accumulate.to_pb()?),
+            merge: Some(self.merge.to_pb()?),
+            finalize: Some(self.finalize.to_pb()?),
+        })
+    }
+}
+
 impl FromToProto for mt::UserDefinedFunction {
     type PB = pb::UserDefinedFunction;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -118,6 +222,12 @@ impl FromToProto for mt::UserDefinedFunction {
             Some(pb::user_defined_function::Definition::UdfServer(udf_server)) => {
                 mt::UDFDefinition::UDFServer(mt::UDFServer::from_pb(udf_server)?)
             }
+            Some(pb::user_defined_function::Definition::UdfScript(udf_script)) => {
+                mt::UDFDefinition::UDFScript(mt::UDFScript::from_pb(udf_script)?)
+            }
+            Some(pb::user_defined_function::Definition::LambdaUdaf(lambda_udaf)) => {
+                mt::UDFDefinition::LambdaUDAF(mt::LambdaUDAF::from_pb(lambda_udaf)?)
+            }
             None => {
                 return Err(Incompatible {
                     reason: "UserDefinedFunction.definition cannot be None".to_string(),
@@ -140,6 +250,12 @@ impl FromToProto for mt::UserDefinedFunction {
             mt::UDFDefinition::UDFServer(udf_server) => {
                 pb::user_defined_function::Definition::UdfServer(udf_server.to_pb()?)
             }
+            mt::UDFDefinition::UDFScript(udf_script) => {
+                pb::user_defined_function::Definition::UdfScript(udf_script.to_pb()?)
+            }
+            mt::UDFDefinition::LambdaUDAF(lambda_udaf) => {
+                pb::user_defined_function::Definition::LambdaUdaf(lambda_udaf.to_pb()?)
+            }
         };
 
         Ok(pb::UserDefinedFunction {
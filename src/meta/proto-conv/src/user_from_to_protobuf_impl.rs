@@ -47,6 +47,9 @@ impl FromToProto for mt::principal::AuthInfo {
             Some(pb::auth_info::Info::Jwt(pb::auth_info::Jwt {})) => {
                 Ok(mt::principal::AuthInfo::JWT)
             }
+            Some(pb::auth_info::Info::Ldap(pb::auth_info::Ldap {})) => {
+                Ok(mt::principal::AuthInfo::Ldap)
+            }
             Some(pb::auth_info::Info::Password(pb::auth_info::Password {
                 hash_value,
                 hash_method,
@@ -68,6 +71,9 @@ impl FromToProto for mt::principal::AuthInfo {
                 Some(pb::auth_info::Info::None(pb::auth_info::None {}))
             }
             mt::principal::AuthInfo::JWT => Some(pb::auth_info::Info::Jwt(pb::auth_info::Jwt {})),
+            mt::principal::AuthInfo::Ldap => {
+                Some(pb::auth_info::Info::Ldap(pb::auth_info::Ldap {}))
+            }
             mt::principal::AuthInfo::Password {
                 hash_value,
                 hash_method,
@@ -375,3 +381,84 @@ impl FromToProto for mt::principal::NetworkPolicy {
         })
     }
 }
+
+impl FromToProto for mt::principal::RowAccessPolicy {
+    type PB = pb::RowAccessPolicy;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::RowAccessPolicy) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(mt::principal::RowAccessPolicy {
+            name: p.name,
+            args: p
+                .args
+                .into_iter()
+                .map(|arg| (arg.name, arg.data_type))
+                .collect(),
+            body: p.body,
+            comment: p.comment,
+            create_on: DateTime::<Utc>::from_pb(p.create_on)?,
+            update_on: match p.update_on {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::RowAccessPolicy, Incompatible> {
+        Ok(pb::RowAccessPolicy {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            args: self
+                .args
+                .iter()
+                .map(|(name, data_type)| pb::row_access_policy::Arg {
+                    name: name.clone(),
+                    data_type: data_type.clone(),
+                })
+                .collect(),
+            body: self.body.clone(),
+            comment: self.comment.clone(),
+            create_on: self.create_on.to_pb()?,
+            update_on: match &self.update_on {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+        })
+    }
+}
+
+impl FromToProto for mt::principal::Sequence {
+    type PB = pb::Sequence;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::Sequence) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(mt::principal::Sequence {
+            name: p.name,
+            start: p.start,
+            increment: p.increment,
+            current: p.current,
+            comment: p.comment,
+            create_on: DateTime::<Utc>::from_pb(p.create_on)?,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::Sequence, Incompatible> {
+        Ok(pb::Sequence {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            start: self.start,
+            increment: self.increment,
+            current: self.current,
+            comment: self.comment.clone(),
+            create_on: self.create_on.to_pb()?,
+        })
+    }
+}
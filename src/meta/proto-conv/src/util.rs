@@ -91,6 +91,16 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (59, "2023-08-17: Add: user.proto/CsvFileFormatParams add field `allow_column_count_mismatch`", ),
     (60, "2023-08-17: Add: user.proto/CopyOptions add field `return_failed_only`", ),
     (61, "2023-10-19: Add: config.proto/OssStorageConfig add SSE options"),
+    (62, "2023-10-24: Add: udf.proto/UserDefinedFunction add UDFScript"),
+    (63, "2023-10-26: Add: datatype.proto/DataType add geometry_t"),
+    (64, "2023-10-30: Add: file_format.proto/FileFormatParams add OrcFileFormatParams"),
+    (65, "2023-11-02: Add: user.proto/RowAccessPolicy"),
+    (66, "2023-11-03: Add: file_format.proto/FileFormatParams add AvroFileFormatParams"),
+    (67, "2023-11-06: Add: user.proto/Sequence"),
+    (68, "2023-11-08: Add: datatype.proto/DataType add time_t"),
+    (69, "2023-11-10: Add: udf.proto/UserDefinedFunction add LambdaUDAF"),
+    (70, "2023-11-13: Add: datatype.proto/DataType add binary_t"),
+    (71, "2023-11-14: Add: user.proto/AuthInfo add ldap"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
@@ -66,3 +66,13 @@ mod v058_udf;
 mod v059_csv_format_params;
 mod v060_copy_options;
 mod v061_oss_sse_options;
+mod v062_udf_script;
+mod v063_geometry;
+mod v064_orc_file_format_params;
+mod v065_row_access_policy;
+mod v066_avro_file_format_params;
+mod v067_sequence;
+mod v068_time;
+mod v069_lambda_udaf;
+mod v070_binary;
+mod v071_ldap_auth_info;
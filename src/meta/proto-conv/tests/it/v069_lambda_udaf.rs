@@ -0,0 +1,69 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_meta_app::principal::LambdaUDAF;
+use common_meta_app::principal::LambdaUDF;
+use common_meta_app::principal::UDFDefinition;
+use common_meta_app::principal::UserDefinedFunction;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v69_lambda_udaf() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 6, 109, 121, 95, 115, 117, 109, 18, 21, 84, 104, 105, 115, 32, 105, 115, 32, 97, 32,
+        100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 50, 88, 10, 17, 154, 2, 8, 66, 0,
+        160, 6, 69, 168, 6, 24, 160, 6, 69, 168, 6, 24, 18, 1, 48, 26, 19, 10, 1, 115, 10, 1, 120,
+        18, 5, 115, 32, 43, 32, 120, 160, 6, 69, 168, 6, 24, 34, 23, 10, 2, 115, 49, 10, 2, 115,
+        50, 18, 7, 115, 49, 32, 43, 32, 115, 50, 160, 6, 69, 168, 6, 24, 42, 12, 10, 1, 115, 18, 1,
+        115, 160, 6, 69, 168, 6, 24, 160, 6, 69, 168, 6, 24, 160, 6, 69, 168, 6, 24,
+    ];
+
+    let want = || UserDefinedFunction {
+        name: "my_sum".to_string(),
+        description: "This is a description".to_string(),
+        definition: UDFDefinition::LambdaUDAF(LambdaUDAF {
+            state_type: DataType::Number(NumberDataType::Int64),
+            init: "0".to_string(),
+            accumulate: LambdaUDF {
+                parameters: vec!["s".to_string(), "x".to_string()],
+                definition: "s + x".to_string(),
+            },
+            merge: LambdaUDF {
+                parameters: vec!["s1".to_string(), "s2".to_string()],
+                definition: "s1 + s2".to_string(),
+            },
+            finalize: LambdaUDF {
+                parameters: vec!["s".to_string()],
+                definition: "s".to_string(),
+            },
+        }),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 69, want())
+}
@@ -79,6 +79,23 @@ pub struct NodeInfo {
     pub version: u32,
     pub flight_address: String,
     pub binary_version: String,
+    /// Resource usage of the node, refreshed by every heartbeat.
+    pub resources: NodeResources,
+}
+
+/// Resource usage of a query node.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Default)]
+#[serde(default)]
+pub struct NodeResources {
+    /// CPU usage of the process, in percent of all the cpus of the node.
+    pub cpu_usage: u32,
+    /// Memory tracked by the process, in bytes.
+    pub memory_usage: u64,
+    pub running_queries: u64,
+    /// Bytes of the table data caches, both in memory and on disk.
+    pub data_cache_size: u64,
+    /// Items of the metadata caches, e.g. snapshot, segment and bloom index caches.
+    pub meta_cache_items: u64,
 }
 
 impl NodeInfo {
@@ -94,6 +111,7 @@ impl NodeInfo {
             version: 0,
             flight_address,
             binary_version,
+            resources: NodeResources::default(),
         }
     }
 
@@ -253,6 +253,8 @@ pub enum Expr {
         unit: IntervalKind,
         date: Box<Expr>,
     },
+    /// A user-defined variable of the session, such as `@x`
+    UserVariable { span: Span, name: Identifier },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -312,6 +314,7 @@ pub enum TypeName {
     },
     Date,
     Timestamp,
+    Time,
     String,
     Array(Box<TypeName>),
     Map {
@@ -324,6 +327,8 @@ pub enum TypeName {
         fields_type: Vec<TypeName>,
     },
     Variant,
+    Geometry,
+    Binary,
     Nullable(Box<TypeName>),
 }
 
@@ -557,7 +562,8 @@ impl Expr {
             | Expr::Interval { span, .. }
             | Expr::DateAdd { span, .. }
             | Expr::DateSub { span, .. }
-            | Expr::DateTrunc { span, .. } => *span,
+            | Expr::DateTrunc { span, .. }
+            | Expr::UserVariable { span, .. } => *span,
         }
     }
 
@@ -799,6 +805,9 @@ impl Display for TypeName {
             TypeName::Timestamp => {
                 write!(f, "TIMESTAMP")?;
             }
+            TypeName::Time => {
+                write!(f, "TIME")?;
+            }
             TypeName::String => {
                 write!(f, "STRING")?;
             }
@@ -842,6 +851,12 @@ impl Display for TypeName {
             TypeName::Variant => {
                 write!(f, "VARIANT")?;
             }
+            TypeName::Geometry => {
+                write!(f, "GEOMETRY")?;
+            }
+            TypeName::Binary => {
+                write!(f, "BINARY")?;
+            }
             TypeName::Nullable(ty) => {
                 write!(f, "{} NULL", ty)?;
             }
@@ -1263,6 +1278,9 @@ impl Display for Expr {
             Expr::DateTrunc { unit, date, .. } => {
                 write!(f, "DATE_TRUNC({unit}, {date})")?;
             }
+            Expr::UserVariable { name, .. } => {
+                write!(f, "@{name}")?;
+            }
         }
 
         Ok(())
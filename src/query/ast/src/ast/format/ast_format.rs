@@ -95,6 +95,125 @@ impl AstFormatVisitor {
     pub fn new() -> Self {
         Self { children: vec![] }
     }
+
+    fn format_group_by(&mut self, group_by: &GroupBy) -> Option<FormatTreeNode<AstFormatContext>> {
+        match group_by {
+            GroupBy::Normal(exprs) => {
+                let mut group_by_list_children = Vec::with_capacity(exprs.len());
+                for group_by in exprs.iter() {
+                    self.visit_expr(group_by);
+                    group_by_list_children.push(self.children.pop().unwrap());
+                }
+                let group_by_list_name = "GroupByList".to_string();
+                let group_by_list_format_ctx = AstFormatContext::with_children(
+                    group_by_list_name,
+                    group_by_list_children.len(),
+                );
+                let group_by_list_node =
+                    FormatTreeNode::with_children(group_by_list_format_ctx, group_by_list_children);
+                Some(group_by_list_node)
+            }
+            GroupBy::All => None,
+            GroupBy::GroupingSets(sets) => {
+                let mut grouping_sets = Vec::with_capacity(sets.len());
+                for set in sets.iter() {
+                    let mut grouping_set = Vec::with_capacity(set.len());
+                    for expr in set.iter() {
+                        self.visit_expr(expr);
+                        grouping_set.push(self.children.pop().unwrap());
+                    }
+                    let name = "GroupingSet".to_string();
+                    let grouping_set_format_ctx =
+                        AstFormatContext::with_children(name, grouping_set.len());
+                    let grouping_set_node =
+                        FormatTreeNode::with_children(grouping_set_format_ctx, grouping_set);
+                    grouping_sets.push(grouping_set_node);
+                }
+                let group_by_list_name = "GroupByList".to_string();
+                let group_by_list_format_ctx =
+                    AstFormatContext::with_children(group_by_list_name, grouping_sets.len());
+                let group_by_list_node =
+                    FormatTreeNode::with_children(group_by_list_format_ctx, grouping_sets);
+                Some(group_by_list_node)
+            }
+            GroupBy::Rollup(exprs) => {
+                let mut rollup_list_children = Vec::with_capacity(exprs.len());
+                for group_by in exprs.iter() {
+                    self.visit_expr(group_by);
+                    rollup_list_children.push(self.children.pop().unwrap());
+                }
+                let rollup_list_name = "GroupByRollUpList".to_string();
+                let rollup_list_format_ctx =
+                    AstFormatContext::with_children(rollup_list_name, rollup_list_children.len());
+                let rollup_list_node =
+                    FormatTreeNode::with_children(rollup_list_format_ctx, rollup_list_children);
+                Some(rollup_list_node)
+            }
+            GroupBy::Cube(exprs) => {
+                let mut cube_list_children = Vec::with_capacity(exprs.len());
+                for group_by in exprs.iter() {
+                    self.visit_expr(group_by);
+                    cube_list_children.push(self.children.pop().unwrap());
+                }
+                let cube_list_name = "GroupByCubeList".to_string();
+                let cube_list_format_ctx =
+                    AstFormatContext::with_children(cube_list_name, cube_list_children.len());
+                let cube_list_node =
+                    FormatTreeNode::with_children(cube_list_format_ctx, cube_list_children);
+                Some(cube_list_node)
+            }
+            GroupBy::Combined(items) => {
+                let mut item_children = Vec::with_capacity(items.len());
+                for item in items.iter() {
+                    if let Some(item_node) = self.format_group_by(item) {
+                        item_children.push(item_node);
+                    }
+                }
+                let combined_list_name = "GroupByCombinedList".to_string();
+                let combined_list_format_ctx =
+                    AstFormatContext::with_children(combined_list_name, item_children.len());
+                Some(FormatTreeNode::with_children(
+                    combined_list_format_ctx,
+                    item_children,
+                ))
+            }
+        }
+    }
+
+    fn format_udaf_definition(
+        &mut self,
+        state_type: &TypeName,
+        init: &Expr,
+        lambdas: [(&str, &Lambda); 3],
+    ) -> Vec<FormatTreeNode<AstFormatContext>> {
+        let mut children = Vec::with_capacity(5);
+        let state_type_format_ctx = AstFormatContext::new(format!("UdafStateType {state_type}"));
+        children.push(FormatTreeNode::new(state_type_format_ctx));
+
+        self.visit_expr(init);
+        let init_child = self.children.pop().unwrap();
+        let init_format_ctx = AstFormatContext::with_children("UdafInit".to_string(), 1);
+        children.push(FormatTreeNode::with_children(init_format_ctx, vec![
+            init_child,
+        ]));
+
+        for (name, lambda) in lambdas {
+            let mut lambda_children = Vec::with_capacity(lambda.params.len() + 1);
+            for param in lambda.params.iter() {
+                self.visit_identifier(param);
+                lambda_children.push(self.children.pop().unwrap());
+            }
+            self.visit_expr(&lambda.expr);
+            lambda_children.push(self.children.pop().unwrap());
+            let lambda_format_ctx =
+                AstFormatContext::with_children(format!("Udaf{name}"), lambda_children.len());
+            children.push(FormatTreeNode::with_children(
+                lambda_format_ctx,
+                lambda_children,
+            ));
+        }
+        children
+    }
 }
 
 impl<'ast> Visitor<'ast> for AstFormatVisitor {
@@ -653,6 +772,12 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_user_variable(&mut self, _span: Span, name: &'ast Identifier) {
+        let format_ctx = AstFormatContext::new(format!("UserVariable @{name}"));
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_query(&mut self, query: &'ast Query) {
         let mut children = Vec::new();
         if let Some(with) = &query.with {
@@ -1003,6 +1128,16 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_set_user_variable(&mut self, variable: &'ast Identifier, value: &'ast Expr) {
+        self.visit_expr(value);
+        let child = self.children.pop().unwrap();
+
+        let name = format!("SetUserVariable @{}", variable);
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_unset_variable(&mut self, stmt: &'ast UnSetStmt) {
         let name = format!("UnSet {}", stmt);
         let format_ctx = AstFormatContext::new(name);
@@ -1532,6 +1667,24 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let action_name = format!(
+                    "Action AddRowAccessPolicy {} on {}",
+                    policy,
+                    columns
+                        .iter()
+                        .map(|c| c.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                let action_name = format!("Action DropRowAccessPolicy {}", policy);
+                let action_format_ctx = AstFormatContext::new(action_name);
+                FormatTreeNode::new(action_format_ctx)
+            }
         };
 
         let name = "AlterTable".to_string();
@@ -1666,6 +1819,50 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_materialized_view(&mut self, stmt: &'ast CreateMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let view_child = self.children.pop().unwrap();
+        self.visit_query(&stmt.query);
+        let query_child = self.children.pop().unwrap();
+
+        let name = "CreateMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![view_child, query_child]);
+        self.children.push(node);
+    }
+
+    fn visit_refresh_materialized_view(&mut self, stmt: &'ast RefreshMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let child = self.children.pop().unwrap();
+
+        let name = "RefreshMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_stream(&mut self, stmt: &'ast CreateStreamStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let stream_child = self.children.pop().unwrap();
+        self.visit_table_ref(&None, &stmt.table_database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "CreateStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![stream_child, table_child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_stream(&mut self, stmt: &'ast DropStreamStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
+        let child = self.children.pop().unwrap();
+
+        let name = "DropStream".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_create_index(&mut self, stmt: &'ast CreateIndexStmt) {
         self.visit_index_ref(&stmt.index_name);
         let index_child = self.children.pop().unwrap();
@@ -1704,6 +1901,40 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_bloom_index(&mut self, stmt: &'ast CreateBloomIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let mut paths_children = Vec::with_capacity(stmt.paths.len());
+        for path in stmt.paths.iter() {
+            self.visit_expr(path);
+            paths_children.push(self.children.pop().unwrap());
+        }
+        let paths_name = "Paths".to_string();
+        let paths_ctx = AstFormatContext::with_children(paths_name, paths_children.len());
+        let paths_child = FormatTreeNode::with_children(paths_ctx, paths_children);
+
+        let name = "CreateBloomIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 3);
+        let node =
+            FormatTreeNode::with_children(format_ctx, vec![index_child, table_child, paths_child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_bloom_index(&mut self, stmt: &'ast DropBloomIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+
+        let name = "DropBloomIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![index_child, table_child]);
+        self.children.push(node);
+    }
+
     fn visit_create_virtual_column(&mut self, stmt: &'ast CreateVirtualColumnStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
         let table_child = self.children.pop().unwrap();
@@ -2058,6 +2289,57 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     AstFormatContext::new(format!("UdfServerAddress {address}"));
                 children.push(FormatTreeNode::new(address_format_ctx));
             }
+            UDFDefinition::UDFScript {
+                arg_types,
+                return_type,
+                code,
+                handler,
+                language,
+            } => {
+                if !arg_types.is_empty() {
+                    let mut arg_types_children = Vec::with_capacity(arg_types.len());
+                    for arg_type in arg_types.iter() {
+                        let type_format_ctx = AstFormatContext::new(format!("DataType {arg_type}"));
+                        arg_types_children.push(FormatTreeNode::new(type_format_ctx));
+                    }
+                    let arg_format_ctx = AstFormatContext::with_children(
+                        "UdfArgTypes".to_string(),
+                        arg_types_children.len(),
+                    );
+                    children.push(FormatTreeNode::with_children(
+                        arg_format_ctx,
+                        arg_types_children,
+                    ));
+                }
+
+                let return_type_format_ctx =
+                    AstFormatContext::new(format!("UdfReturnType {return_type}"));
+                children.push(FormatTreeNode::new(return_type_format_ctx));
+
+                let handler_format_ctx =
+                    AstFormatContext::new(format!("UdfScriptHandler {handler}"));
+                children.push(FormatTreeNode::new(handler_format_ctx));
+
+                let language_format_ctx =
+                    AstFormatContext::new(format!("UdfScriptLanguage {language}"));
+                children.push(FormatTreeNode::new(language_format_ctx));
+
+                let code_format_ctx = AstFormatContext::new(format!("UdfScriptCode {code}"));
+                children.push(FormatTreeNode::new(code_format_ctx));
+            }
+            UDFDefinition::LambdaUDAF {
+                state_type,
+                init,
+                accumulate,
+                merge,
+                finalize,
+            } => {
+                children.extend(self.format_udaf_definition(state_type, init, [
+                    ("Accumulate", accumulate),
+                    ("Merge", merge),
+                    ("Finalize", finalize),
+                ]));
+            }
         }
 
         if let Some(description) = &stmt.description {
@@ -2154,6 +2436,57 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     AstFormatContext::new(format!("UdfServerAddress {address}"));
                 children.push(FormatTreeNode::new(address_format_ctx));
             }
+            UDFDefinition::UDFScript {
+                arg_types,
+                return_type,
+                code,
+                handler,
+                language,
+            } => {
+                if !arg_types.is_empty() {
+                    let mut arg_types_children = Vec::with_capacity(arg_types.len());
+                    for arg_type in arg_types.iter() {
+                        let type_format_ctx = AstFormatContext::new(format!("DataType {arg_type}"));
+                        arg_types_children.push(FormatTreeNode::new(type_format_ctx));
+                    }
+                    let arg_format_ctx = AstFormatContext::with_children(
+                        "UdfArgTypes".to_string(),
+                        arg_types_children.len(),
+                    );
+                    children.push(FormatTreeNode::with_children(
+                        arg_format_ctx,
+                        arg_types_children,
+                    ));
+                }
+
+                let return_type_format_ctx =
+                    AstFormatContext::new(format!("UdfReturnType {return_type}"));
+                children.push(FormatTreeNode::new(return_type_format_ctx));
+
+                let handler_format_ctx =
+                    AstFormatContext::new(format!("UdfScriptHandler {handler}"));
+                children.push(FormatTreeNode::new(handler_format_ctx));
+
+                let language_format_ctx =
+                    AstFormatContext::new(format!("UdfScriptLanguage {language}"));
+                children.push(FormatTreeNode::new(language_format_ctx));
+
+                let code_format_ctx = AstFormatContext::new(format!("UdfScriptCode {code}"));
+                children.push(FormatTreeNode::new(code_format_ctx));
+            }
+            UDFDefinition::LambdaUDAF {
+                state_type,
+                init,
+                accumulate,
+                merge,
+                finalize,
+            } => {
+                children.extend(self.format_udaf_definition(state_type, init, [
+                    ("Accumulate", accumulate),
+                    ("Merge", merge),
+                    ("Finalize", finalize),
+                ]));
+            }
         }
 
         if let Some(description) = &stmt.description {
@@ -2460,6 +2793,56 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_row_access_policy(&mut self, stmt: &'ast CreateRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_row_access_policy(&mut self, stmt: &'ast DropRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_desc_row_access_policy(&mut self, stmt: &'ast DescRowAccessPolicyStmt) {
+        let ctx = AstFormatContext::new(format!("RowAccessPolicyName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DescRowAccessPolicy".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_create_sequence(&mut self, stmt: &'ast CreateSequenceStmt) {
+        let ctx = AstFormatContext::new(format!("SequenceName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateSequence".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_sequence(&mut self, stmt: &'ast DropSequenceStmt) {
+        let ctx = AstFormatContext::new(format!("SequenceName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropSequence".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_create_network_policy(&mut self, stmt: &'ast CreateNetworkPolicyStmt) {
         let ctx = AstFormatContext::new(format!("NetworkPolicyName {}", stmt.name));
         let child = FormatTreeNode::new(ctx);
@@ -2622,75 +3005,8 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             children.push(selection_node);
         }
         if let Some(group_by) = &stmt.group_by {
-            match group_by {
-                GroupBy::Normal(exprs) => {
-                    let mut group_by_list_children = Vec::with_capacity(exprs.len());
-                    for group_by in exprs.iter() {
-                        self.visit_expr(group_by);
-                        group_by_list_children.push(self.children.pop().unwrap());
-                    }
-                    let group_by_list_name = "GroupByList".to_string();
-                    let group_by_list_format_ctx = AstFormatContext::with_children(
-                        group_by_list_name,
-                        group_by_list_children.len(),
-                    );
-                    let group_by_list_node = FormatTreeNode::with_children(
-                        group_by_list_format_ctx,
-                        group_by_list_children,
-                    );
-                    children.push(group_by_list_node);
-                }
-                GroupBy::All => {}
-                GroupBy::GroupingSets(sets) => {
-                    let mut grouping_sets = Vec::with_capacity(sets.len());
-                    for set in sets.iter() {
-                        let mut grouping_set = Vec::with_capacity(set.len());
-                        for expr in set.iter() {
-                            self.visit_expr(expr);
-                            grouping_set.push(self.children.pop().unwrap());
-                        }
-                        let name = "GroupingSet".to_string();
-                        let grouping_set_format_ctx =
-                            AstFormatContext::with_children(name, grouping_set.len());
-                        let grouping_set_node =
-                            FormatTreeNode::with_children(grouping_set_format_ctx, grouping_set);
-                        grouping_sets.push(grouping_set_node);
-                    }
-                    let group_by_list_name = "GroupByList".to_string();
-                    let group_by_list_format_ctx =
-                        AstFormatContext::with_children(group_by_list_name, grouping_sets.len());
-                    let group_by_list_node =
-                        FormatTreeNode::with_children(group_by_list_format_ctx, grouping_sets);
-                    children.push(group_by_list_node);
-                }
-                GroupBy::Rollup(exprs) => {
-                    let mut rollup_list_children = Vec::with_capacity(exprs.len());
-                    for group_by in exprs.iter() {
-                        self.visit_expr(group_by);
-                        rollup_list_children.push(self.children.pop().unwrap());
-                    }
-                    let rollup_list_name = "GroupByRollUpList".to_string();
-                    let rollup_list_format_ctx = AstFormatContext::with_children(
-                        rollup_list_name,
-                        rollup_list_children.len(),
-                    );
-                    let rollup_list_node =
-                        FormatTreeNode::with_children(rollup_list_format_ctx, rollup_list_children);
-                    children.push(rollup_list_node);
-                }
-                GroupBy::Cube(exprs) => {
-                    let mut cube_list_children = Vec::with_capacity(exprs.len());
-                    for group_by in exprs.iter() {
-                        self.visit_expr(group_by);
-                        cube_list_children.push(self.children.pop().unwrap());
-                    }
-                    let cube_list_name = "GroupByCubeList".to_string();
-                    let cube_list_format_ctx =
-                        AstFormatContext::with_children(cube_list_name, cube_list_children.len());
-                    let cube_list_node =
-                        FormatTreeNode::with_children(cube_list_format_ctx, cube_list_children);
-                    children.push(cube_list_node);
-                }
+            if let Some(group_by_node) = self.format_group_by(group_by) {
+                children.push(group_by_node);
             }
         }
 
@@ -2826,6 +3142,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             }
             TableReference::TableFunction {
                 span: _,
+                lateral,
                 name,
                 params,
                 named_params,
@@ -2845,7 +3162,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     );
                     children.push(node);
                 }
-                let func_name = format!("TableFunction {}", name);
+                let func_name = if *lateral {
+                    format!("LateralTableFunction {}", name)
+                } else {
+                    format!("TableFunction {}", name)
+                };
                 let format_ctx = if let Some(alias) = alias {
                     AstFormatContext::with_children_alias(
                         func_name,
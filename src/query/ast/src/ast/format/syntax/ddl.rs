@@ -225,6 +225,19 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             }
             doc
         }
+        AlterTableAction::AddRowAccessPolicy { policy, columns } => RcDoc::line()
+            .append(RcDoc::text(format!("ADD ROW ACCESS POLICY {policy} ON ")))
+            .append(parenthesized(
+                interweave_comma(
+                    columns
+                        .into_iter()
+                        .map(|column| RcDoc::text(column.to_string())),
+                )
+                .group(),
+            )),
+        AlterTableAction::DropRowAccessPolicy { policy } => {
+            RcDoc::line().append(RcDoc::text(format!("DROP ROW ACCESS POLICY {policy}")))
+        }
     }
 }
 
@@ -265,12 +265,36 @@ fn pretty_group_by(group_by: Option<GroupBy>) -> RcDoc<'static> {
                 )
                 .append(RcDoc::line())
                 .append(RcDoc::text(")")),
+            GroupBy::Combined(items) => RcDoc::line()
+                .append(RcDoc::text("GROUP BY").append(RcDoc::line().nest(NEST_FACTOR)))
+                .append(
+                    interweave_comma(items.into_iter().map(pretty_group_item))
+                        .nest(NEST_FACTOR)
+                        .group(),
+                ),
         }
     } else {
         RcDoc::nil()
     }
 }
 
+fn pretty_group_item(item: GroupBy) -> RcDoc<'static> {
+    match item {
+        GroupBy::GroupingSets(sets) => RcDoc::text("GROUPING SETS (")
+            .append(inline_comma(sets.into_iter().map(pretty_group_set)))
+            .append(RcDoc::text(")")),
+        GroupBy::Rollup(exprs) => RcDoc::text("ROLLUP (")
+            .append(inline_comma(exprs.into_iter().map(pretty_expr)))
+            .append(RcDoc::text(")")),
+        GroupBy::Cube(exprs) => RcDoc::text("CUBE (")
+            .append(inline_comma(exprs.into_iter().map(pretty_expr)))
+            .append(RcDoc::text(")")),
+        GroupBy::Normal(exprs) => inline_comma(exprs.into_iter().map(pretty_expr)),
+        GroupBy::All => RcDoc::text("ALL"),
+        GroupBy::Combined(items) => inline_comma(items.into_iter().map(pretty_group_item)),
+    }
+}
+
 fn pretty_having(having: Option<Expr>) -> RcDoc<'static> {
     if let Some(having) = having {
         RcDoc::line()
@@ -358,6 +382,7 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
         }),
         TableReference::TableFunction {
             span: _,
+            lateral,
             name,
             params,
             named_params,
@@ -368,7 +393,8 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             } else {
                 RcDoc::nil()
             };
-            RcDoc::text(name.to_string())
+            RcDoc::text(if lateral { "LATERAL " } else { "" })
+                .append(RcDoc::text(name.to_string()))
                 .append(RcDoc::text("("))
                 .append(inline_comma(params.into_iter().map(pretty_expr)))
                 .append(separator)
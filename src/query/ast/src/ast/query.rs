@@ -109,6 +109,52 @@ pub enum GroupBy {
     Cube(Vec<Expr>),
     /// GROUP BY ROLLUP ( expr [, expr]* )
     Rollup(Vec<Expr>),
+    /// GROUP BY GroupItem [, GroupItem]*
+    ///
+    /// GroupItem := expr | GROUPING SETS (..) | CUBE (..) | ROLLUP (..)
+    ///
+    /// The grouping sets are the cross product of the grouping sets of each item,
+    /// e.g. `a, ROLLUP (b)` is `GROUPING SETS ((a, b), (a))`.
+    Combined(Vec<GroupBy>),
+}
+
+impl Display for GroupBy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GroupBy::Normal(exprs) => {
+                write_comma_separated_list(f, exprs)?;
+            }
+            GroupBy::All => {
+                write!(f, "ALL")?;
+            }
+            GroupBy::GroupingSets(sets) => {
+                write!(f, "GROUPING SETS (")?;
+                for (i, set) in sets.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "(")?;
+                    write_comma_separated_list(f, set)?;
+                    write!(f, ")")?;
+                }
+                write!(f, ")")?;
+            }
+            GroupBy::Cube(exprs) => {
+                write!(f, "CUBE (")?;
+                write_comma_separated_list(f, exprs)?;
+                write!(f, ")")?;
+            }
+            GroupBy::Rollup(exprs) => {
+                write!(f, "ROLLUP (")?;
+                write_comma_separated_list(f, exprs)?;
+                write!(f, ")")?;
+            }
+            GroupBy::Combined(items) => {
+                write_comma_separated_list(f, items)?;
+            }
+        }
+        Ok(())
+    }
 }
 
 /// A relational set expression, like `SELECT ... FROM ... {UNION|EXCEPT|INTERSECT} SELECT ... FROM ...`
@@ -245,9 +291,11 @@ pub enum TableReference {
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
     },
-    // `TABLE(expr)[ AS alias ]`
+    // `[LATERAL] TABLE(expr)[ AS alias ]`
     TableFunction {
         span: Span,
+        /// Whether the table function is allowed to reference the columns of the preceding tables.
+        lateral: bool,
         name: Identifier,
         params: Vec<Expr>,
         named_params: Vec<(String, Expr)>,
@@ -444,11 +492,15 @@ impl Display for TableReference {
             }
             TableReference::TableFunction {
                 span: _,
+                lateral,
                 name,
                 params,
                 named_params,
                 alias,
             } => {
+                if *lateral {
+                    write!(f, "LATERAL ")?;
+                }
                 write!(f, "{name}(")?;
                 write_comma_separated_list(f, params)?;
                 if !params.is_empty() && !named_params.is_empty() {
@@ -604,38 +656,8 @@ impl Display for SelectStmt {
         }
 
         // GROUP BY clause
-        if self.group_by.is_some() {
-            write!(f, " GROUP BY ")?;
-            match self.group_by.as_ref().unwrap() {
-                GroupBy::Normal(exprs) => {
-                    write_comma_separated_list(f, exprs)?;
-                }
-                GroupBy::All => {
-                    write!(f, "ALL")?;
-                }
-                GroupBy::GroupingSets(sets) => {
-                    write!(f, "GROUPING SETS (")?;
-                    for (i, set) in sets.iter().enumerate() {
-                        if i > 0 {
-                            write!(f, ", ")?;
-                        }
-                        write!(f, "(")?;
-                        write_comma_separated_list(f, set)?;
-                        write!(f, ")")?;
-                    }
-                    write!(f, ")")?;
-                }
-                GroupBy::Cube(exprs) => {
-                    write!(f, "CUBE (")?;
-                    write_comma_separated_list(f, exprs)?;
-                    write!(f, ")")?;
-                }
-                GroupBy::Rollup(exprs) => {
-                    write!(f, "ROLLUP (")?;
-                    write_comma_separated_list(f, exprs)?;
-                    write!(f, ")")?;
-                }
-            }
+        if let Some(group_by) = &self.group_by {
+            write!(f, " GROUP BY {group_by}")?;
         }
 
         // HAVING clause
@@ -15,6 +15,9 @@
 use std::fmt::Display;
 use std::fmt::Formatter;
 
+use crate::ast::write_comma_separated_list;
+use crate::ast::write_dot_separated_list;
+use crate::ast::Expr;
 use crate::ast::Identifier;
 use crate::ast::Query;
 
@@ -80,3 +83,61 @@ impl Display for RefreshIndexStmt {
         Ok(())
     }
 }
+
+/// `CREATE INDEX <index> ON <table> (<path>, ...)` builds secondary bloom filters
+/// on the json paths or map keys of the table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateBloomIndexStmt {
+    pub if_not_exists: bool,
+    pub index_name: Identifier,
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub paths: Vec<Expr>,
+}
+
+impl Display for CreateBloomIndexStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CREATE INDEX ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{} ON ", self.index_name)?;
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        write!(f, " (")?;
+        write_comma_separated_list(f, &self.paths)?;
+        write!(f, ")")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropBloomIndexStmt {
+    pub if_exists: bool,
+    pub index_name: Identifier,
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+}
+
+impl Display for DropBloomIndexStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DROP INDEX ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{} ON ", self.index_name)?;
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )
+    }
+}
@@ -19,6 +19,7 @@ use std::fmt::Formatter;
 use crate::ast::write_comma_separated_list;
 use crate::ast::write_comma_separated_map;
 use crate::ast::write_dot_separated_list;
+use crate::ast::Expr;
 use crate::ast::Hint;
 use crate::ast::Identifier;
 use crate::ast::Query;
@@ -109,3 +110,102 @@ impl Display for InsertSource {
         }
     }
 }
+
+/// `INSERT [OVERWRITE] { ALL | FIRST } ... <query>`, which inserts the rows of the
+/// query into several tables.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsertMultiTableStmt {
+    pub hints: Option<Hint>,
+    pub overwrite: bool,
+    pub is_first: bool,
+    /// The unconditional targets of `INSERT ALL INTO ... INTO ...`.
+    pub into_clauses: Vec<IntoClause>,
+    pub when_clauses: Vec<WhenClause>,
+    pub else_clause: Option<ElseClause>,
+    pub source: Query,
+}
+
+impl Display for InsertMultiTableStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "INSERT ")?;
+        if let Some(hints) = &self.hints {
+            write!(f, "{} ", hints)?;
+        }
+        if self.overwrite {
+            write!(f, "OVERWRITE ")?;
+        }
+        if self.is_first {
+            write!(f, "FIRST")?;
+        } else {
+            write!(f, "ALL")?;
+        }
+        for into_clause in &self.into_clauses {
+            write!(f, " {into_clause}")?;
+        }
+        for when_clause in &self.when_clauses {
+            write!(f, " {when_clause}")?;
+        }
+        if let Some(else_clause) = &self.else_clause {
+            write!(f, " {else_clause}")?;
+        }
+        write!(f, " {}", self.source)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntoClause {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+    pub columns: Vec<Identifier>,
+}
+
+impl Display for IntoClause {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "INTO ")?;
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        if !self.columns.is_empty() {
+            write!(f, " (")?;
+            write_comma_separated_list(f, &self.columns)?;
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WhenClause {
+    pub condition: Expr,
+    pub into_clauses: Vec<IntoClause>,
+}
+
+impl Display for WhenClause {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "WHEN {} THEN", self.condition)?;
+        for into_clause in &self.into_clauses {
+            write!(f, " {into_clause}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElseClause {
+    pub into_clauses: Vec<IntoClause>,
+}
+
+impl Display for ElseClause {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ELSE")?;
+        for into_clause in &self.into_clauses {
+            write!(f, " {into_clause}")?;
+        }
+        Ok(())
+    }
+}
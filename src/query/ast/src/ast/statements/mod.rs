@@ -28,10 +28,13 @@ mod merge_into;
 mod network_policy;
 mod presign;
 mod replace;
+mod row_access_policy;
+mod sequence;
 mod share;
 mod show;
 mod stage;
 mod statement;
+mod stream;
 mod table;
 mod task;
 mod udf;
@@ -57,10 +60,13 @@ pub use merge_into::*;
 pub use network_policy::*;
 pub use presign::*;
 pub use replace::*;
+pub use row_access_policy::*;
+pub use sequence::*;
 pub use share::*;
 pub use show::*;
 pub use stage::*;
 pub use statement::*;
+pub use stream::*;
 pub use table::*;
 pub use task::*;
 pub use udf::*;
@@ -58,6 +58,12 @@ pub enum Statement {
     },
     ShowIndexes,
 
+    // Transactions
+    Begin,
+    Commit,
+    Abort,
+    FlushQueryCache,
+
     KillStmt {
         kill_target: KillTarget,
         object_id: String,
@@ -71,12 +77,18 @@ pub enum Statement {
 
     UnSetVariable(UnSetStmt),
 
+    SetUserVariable {
+        variable: Identifier,
+        value: Box<Expr>,
+    },
+
     SetRole {
         is_default: bool,
         role_name: String,
     },
 
     Insert(InsertStmt),
+    InsertMultiTable(InsertMultiTableStmt),
     Replace(ReplaceStmt),
     MergeInto(MergeIntoStmt),
     Delete(DeleteStmt),
@@ -113,6 +125,7 @@ pub enum Statement {
     AlterTable(AlterTableStmt),
     RenameTable(RenameTableStmt),
     TruncateTable(TruncateTableStmt),
+    DropTableCache(DropTableCacheStmt),
     OptimizeTable(OptimizeTableStmt),
     VacuumTable(VacuumTableStmt),
     VacuumDropTable(VacuumDropTableStmt),
@@ -125,11 +138,19 @@ pub enum Statement {
     CreateView(CreateViewStmt),
     AlterView(AlterViewStmt),
     DropView(DropViewStmt),
+    CreateMaterializedView(CreateMaterializedViewStmt),
+    RefreshMaterializedView(RefreshMaterializedViewStmt),
+
+    // Streams
+    CreateStream(CreateStreamStmt),
+    DropStream(DropStreamStmt),
 
     // Indexes
     CreateIndex(CreateIndexStmt),
     DropIndex(DropIndexStmt),
     RefreshIndex(RefreshIndexStmt),
+    CreateBloomIndex(CreateBloomIndexStmt),
+    DropBloomIndex(DropBloomIndexStmt),
 
     // VirtualColumns
     CreateVirtualColumn(CreateVirtualColumnStmt),
@@ -219,6 +240,15 @@ pub enum Statement {
     DropDatamaskPolicy(DropDatamaskPolicyStmt),
     DescDatamaskPolicy(DescDatamaskPolicyStmt),
 
+    // row access policy
+    CreateRowAccessPolicy(CreateRowAccessPolicyStmt),
+    DropRowAccessPolicy(DropRowAccessPolicyStmt),
+    DescRowAccessPolicy(DescRowAccessPolicyStmt),
+
+    // sequence
+    CreateSequence(CreateSequenceStmt),
+    DropSequence(DropSequenceStmt),
+
     // network policy
     CreateNetworkPolicy(CreateNetworkPolicyStmt),
     AlterNetworkPolicy(AlterNetworkPolicyStmt),
@@ -298,6 +328,7 @@ impl Display for Statement {
             }
             Statement::Query(query) => write!(f, "{query}")?,
             Statement::Insert(insert) => write!(f, "{insert}")?,
+            Statement::InsertMultiTable(insert) => write!(f, "{insert}")?,
             Statement::Replace(replace) => write!(f, "{replace}")?,
             Statement::MergeInto(merge_into) => write!(f, "{merge_into}")?,
             Statement::Delete(delete) => write!(f, "{delete}")?,
@@ -314,6 +345,10 @@ impl Display for Statement {
             Statement::ShowMetrics => write!(f, "SHOW METRICS")?,
             Statement::ShowEngines => write!(f, "SHOW ENGINES")?,
             Statement::ShowIndexes => write!(f, "SHOW INDEXES")?,
+            Statement::Begin => write!(f, "BEGIN")?,
+            Statement::Commit => write!(f, "COMMIT")?,
+            Statement::Abort => write!(f, "ROLLBACK")?,
+            Statement::FlushQueryCache => write!(f, "ALTER SYSTEM FLUSH QUERY CACHE")?,
             Statement::ShowFunctions { limit } => {
                 write!(f, "SHOW FUNCTIONS")?;
                 if let Some(limit) = limit {
@@ -349,6 +384,9 @@ impl Display for Statement {
                 write!(f, "{variable} = {value}")?;
             }
             Statement::UnSetVariable(unset) => write!(f, "{unset}")?,
+            Statement::SetUserVariable { variable, value } => {
+                write!(f, "SET @{variable} = {value}")?;
+            }
             Statement::SetRole {
                 is_default,
                 role_name,
@@ -384,6 +422,7 @@ impl Display for Statement {
             Statement::AlterTable(stmt) => write!(f, "{stmt}")?,
             Statement::RenameTable(stmt) => write!(f, "{stmt}")?,
             Statement::TruncateTable(stmt) => write!(f, "{stmt}")?,
+            Statement::DropTableCache(stmt) => write!(f, "{stmt}")?,
             Statement::OptimizeTable(stmt) => write!(f, "{stmt}")?,
             Statement::VacuumTable(stmt) => write!(f, "{stmt}")?,
             Statement::VacuumDropTable(stmt) => write!(f, "{stmt}")?,
@@ -392,9 +431,15 @@ impl Display for Statement {
             Statement::CreateView(stmt) => write!(f, "{stmt}")?,
             Statement::AlterView(stmt) => write!(f, "{stmt}")?,
             Statement::DropView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::RefreshMaterializedView(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateStream(stmt) => write!(f, "{stmt}")?,
+            Statement::DropStream(stmt) => write!(f, "{stmt}")?,
             Statement::CreateIndex(stmt) => write!(f, "{stmt}")?,
             Statement::DropIndex(stmt) => write!(f, "{stmt}")?,
             Statement::RefreshIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateBloomIndex(stmt) => write!(f, "{stmt}")?,
+            Statement::DropBloomIndex(stmt) => write!(f, "{stmt}")?,
             Statement::CreateVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::AlterVirtualColumn(stmt) => write!(f, "{stmt}")?,
             Statement::DropVirtualColumn(stmt) => write!(f, "{stmt}")?,
@@ -513,6 +558,11 @@ impl Display for Statement {
             Statement::CreateDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DropDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DescDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DescRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateSequence(stmt) => write!(f, "{stmt}")?,
+            Statement::DropSequence(stmt) => write!(f, "{stmt}")?,
             Statement::CreateNetworkPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::AlterNetworkPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DropNetworkPolicy(stmt) => write!(f, "{stmt}")?,
@@ -196,7 +196,7 @@ impl Display for AttachTableStmt {
                 .chain(Some(&self.table)),
         )?;
 
-        write!(f, " FROM {}", self.uri_location)?;
+        write!(f, " {}", self.uri_location)?;
 
         if self.read_only {
             write!(f, " READ_ONLY")?;
@@ -357,6 +357,13 @@ pub enum AlterTableAction {
     SetOptions {
         set_options: BTreeMap<String, String>,
     },
+    AddRowAccessPolicy {
+        policy: Identifier,
+        columns: Vec<Identifier>,
+    },
+    DropRowAccessPolicy {
+        policy: Identifier,
+    },
 }
 
 impl Display for AlterTableAction {
@@ -411,6 +418,14 @@ impl Display for AlterTableAction {
             AlterTableAction::RevertTo { point } => {
                 write!(f, "REVERT TO {}", point)?;
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                write!(f, "ADD ROW ACCESS POLICY {policy} ON (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")?;
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                write!(f, "DROP ROW ACCESS POLICY {policy}")?;
+            }
         };
         Ok(())
     }
@@ -489,6 +504,27 @@ impl Display for TruncateTableStmt {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropTableCacheStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub table: Identifier,
+}
+
+impl Display for DropTableCacheStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP CACHE FOR TABLE ")?;
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.table)),
+        )?;
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct VacuumTableStmt {
     pub catalog: Option<Identifier>,
@@ -611,6 +647,7 @@ pub enum Engine {
     Fuse,
     View,
     Random,
+    Delta,
 }
 
 impl Display for Engine {
@@ -621,6 +658,7 @@ impl Display for Engine {
             Engine::Fuse => write!(f, "FUSE"),
             Engine::View => write!(f, "VIEW"),
             Engine::Random => write!(f, "RANDOM"),
+            Engine::Delta => write!(f, "DELTA"),
         }
     }
 }
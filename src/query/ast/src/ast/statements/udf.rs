@@ -18,6 +18,7 @@ use std::fmt::Formatter;
 use crate::ast::write_comma_separated_list;
 use crate::ast::Expr;
 use crate::ast::Identifier;
+use crate::ast::Lambda;
 use crate::ast::TypeName;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -33,6 +34,26 @@ pub enum UDFDefinition {
         handler: String,
         language: String,
     },
+    UDFScript {
+        arg_types: Vec<TypeName>,
+        return_type: TypeName,
+        code: String,
+        handler: String,
+        language: String,
+    },
+    LambdaUDAF {
+        state_type: TypeName,
+        init: Box<Expr>,
+        accumulate: Lambda,
+        merge: Lambda,
+        finalize: Lambda,
+    },
+}
+
+impl UDFDefinition {
+    pub fn is_aggregate(&self) -> bool {
+        matches!(self, UDFDefinition::LambdaUDAF { .. })
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -75,6 +96,38 @@ impl Display for UDFDefinition {
                     ") RETURNS {return_type} LANGUAGE {language} HANDLER = {handler} ADDRESS = {address}"
                 )?;
             }
+            UDFDefinition::UDFScript {
+                arg_types,
+                return_type,
+                code,
+                handler,
+                language,
+            } => {
+                write!(f, "(")?;
+                write_comma_separated_list(f, arg_types)?;
+                write!(
+                    f,
+                    ") RETURNS {return_type} LANGUAGE {language} HANDLER = {handler} AS {code}"
+                )?;
+            }
+            UDFDefinition::LambdaUDAF {
+                state_type,
+                init,
+                accumulate,
+                merge,
+                finalize,
+            } => {
+                write!(f, "STATE {state_type} INIT = {init}")?;
+                for (name, lambda) in [
+                    ("ACCUMULATE", accumulate),
+                    ("MERGE", merge),
+                    ("FINALIZE", finalize),
+                ] {
+                    write!(f, " {name} = (")?;
+                    write_comma_separated_list(f, &lambda.params)?;
+                    write!(f, ") -> {}", lambda.expr)?;
+                }
+            }
         }
         Ok(())
     }
@@ -82,7 +135,11 @@ impl Display for UDFDefinition {
 
 impl Display for CreateUDFStmt {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        write!(f, "CREATE FUNCTION")?;
+        write!(f, "CREATE")?;
+        if self.definition.is_aggregate() {
+            write!(f, " AGGREGATE")?;
+        }
+        write!(f, " FUNCTION")?;
         if self.if_not_exists {
             write!(f, " IF NOT EXISTS")?;
         }
@@ -96,7 +153,11 @@ impl Display for CreateUDFStmt {
 
 impl Display for AlterUDFStmt {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
-        write!(f, "ALTER FUNCTION")?;
+        write!(f, "ALTER")?;
+        if self.definition.is_aggregate() {
+            write!(f, " AGGREGATE")?;
+        }
+        write!(f, " FUNCTION")?;
         write!(f, " {} {}", self.udf_name, self.definition)?;
         if let Some(description) = &self.description {
             write!(f, " DESC = '{description}'")?;
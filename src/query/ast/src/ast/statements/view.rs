@@ -103,3 +103,49 @@ impl Display for DropViewStmt {
         )
     }
 }
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateMaterializedViewStmt {
+    pub if_not_exists: bool,
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub view: Identifier,
+    pub query: Box<Query>,
+}
+
+impl Display for CreateMaterializedViewStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE MATERIALIZED VIEW ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.view)),
+        )?;
+        write!(f, " AS {}", self.query)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefreshMaterializedViewStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Option<Identifier>,
+    pub view: Identifier,
+}
+
+impl Display for RefreshMaterializedViewStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "REFRESH MATERIALIZED VIEW ")?;
+        write_dot_separated_list(
+            f,
+            self.catalog
+                .iter()
+                .chain(&self.database)
+                .chain(Some(&self.view)),
+        )
+    }
+}
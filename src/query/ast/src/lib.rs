@@ -30,6 +30,7 @@ pub use visitors::walk_query_mut;
 pub use visitors::walk_select_target;
 pub use visitors::walk_select_target_mut;
 pub use visitors::walk_statement_mut;
+pub use visitors::walk_table_reference;
 pub use visitors::walk_table_reference_mut;
 pub use visitors::Visitor;
 pub use visitors::VisitorMut;
@@ -353,6 +353,9 @@ pub enum ExprElement {
         unit: IntervalKind,
         date: Expr,
     },
+    UserVariable {
+        name: Identifier,
+    },
 }
 
 struct ExprParser;
@@ -579,6 +582,10 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 unit,
                 date: Box::new(date),
             },
+            ExprElement::UserVariable { name } => Expr::UserVariable {
+                span: transform_span(elem.span.0),
+                name,
+            },
             _ => unreachable!(),
         };
         Ok(expr)
@@ -868,19 +875,26 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         },
     );
 
+    let lambda_params = alt((
+        map(ident, |param| vec![param]),
+        map(
+            rule! { "(" ~ #comma_separated_list1(ident) ~ ")" },
+            |(_, params, _)| params,
+        ),
+    ));
     let function_call_with_lambda = map(
         rule! {
             #function_name
-            ~ "(" ~ #subexpr(0) ~ "," ~ #ident ~ "->" ~ #subexpr(0) ~ ")"
+            ~ "(" ~ #subexpr(0) ~ "," ~ #lambda_params ~ "->" ~ #subexpr(0) ~ ")"
         },
-        |(name, _, arg, _, param, _, expr, _)| ExprElement::FunctionCall {
+        |(name, _, arg, _, params, _, expr, _)| ExprElement::FunctionCall {
             distinct: false,
             name,
             args: vec![arg],
             params: vec![],
             window: None,
             lambda: Some(Lambda {
-                params: vec![param],
+                params,
                 expr: Box::new(expr),
             }),
         },
@@ -1024,6 +1038,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         },
         |(_, _, unit, _, date, _)| ExprElement::DateTrunc { unit, date },
     );
+    let user_variable = map(user_variable, |name| ExprElement::UserVariable { name });
 
     let date_expr = map(
         rule! {
@@ -1096,6 +1111,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #tuple : "`(<expr> [, ...])`"
             | #column_ref : "<column>"
             | #map_access : "[<key>] | .<key> | :<key>"
+            | #user_variable : "@<variable>"
             | #literal : "<literal>"
             | #array : "`[...]`"
             | #map_expr : "`{...}`"
@@ -1385,11 +1401,17 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         rule! { ( DATETIME | TIMESTAMP ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
         |(_, _)| TypeName::Timestamp,
     );
+    let ty_time = value(TypeName::Time, rule! { TIME });
     let ty_string = value(
         TypeName::String,
-        rule! { ( STRING | VARCHAR | CHAR | CHARACTER | TEXT | BINARY | VARBINARY ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
+        rule! { ( STRING | VARCHAR | CHAR | CHARACTER | TEXT ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
+    );
+    let ty_binary = value(
+        TypeName::Binary,
+        rule! { ( BINARY | VARBINARY ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
     );
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
+    let ty_geometry = value(TypeName::Geometry, rule! { GEOMETRY });
     map(
         alt((
             rule! {
@@ -1415,8 +1437,11 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             rule! {
             ( #ty_date
             | #ty_datetime
+            | #ty_time
             | #ty_string
+            | #ty_binary
             | #ty_variant
+            | #ty_geometry
             | #ty_nullable
             ) ~ NULL? : "type name" },
         )),
@@ -19,6 +19,7 @@ pub mod expr;
 mod parser;
 pub mod query;
 pub mod quote;
+mod row_access_policy;
 mod share;
 mod stage;
 pub mod statement;
@@ -527,8 +527,9 @@ pub enum TableReferenceElement {
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
     },
-    // `TABLE(expr)[ AS alias ]`
+    // `[LATERAL] TABLE(expr)[ AS alias ]`
     TableFunction {
+        lateral: bool,
         name: Identifier,
         params: Vec<TableFunctionParam>,
         alias: Option<TableAlias>,
@@ -615,9 +616,10 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     );
     let table_function = map(
         rule! {
-            #function_name ~ "(" ~ #comma_separated_list0(table_function_param) ~ ")" ~ #table_alias?
+            LATERAL? ~ #function_name ~ "(" ~ #comma_separated_list0(table_function_param) ~ ")" ~ #table_alias?
         },
-        |(name, _, params, _, alias)| TableReferenceElement::TableFunction {
+        |(lateral, name, _, params, _, alias)| TableReferenceElement::TableFunction {
+            lateral: lateral.is_some(),
             name,
             params,
             alias,
@@ -708,6 +710,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 unpivot,
             },
             TableReferenceElement::TableFunction {
+                lateral,
                 name,
                 params,
                 alias,
@@ -728,6 +731,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                     .collect();
                 TableReference::TableFunction {
                     span: transform_span(input.span.0),
+                    lateral,
                     name,
                     params: normal_params,
                     named_params,
@@ -819,10 +823,6 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
 }
 
 pub fn group_by_items(i: Input) -> IResult<GroupBy> {
-    let normal = map(rule! { ^#comma_separated_list1(expr) }, |groups| {
-        GroupBy::Normal(groups)
-    });
-
     let all = map(rule! { ALL }, |_| GroupBy::All);
 
     let cube = map(
@@ -845,7 +845,25 @@ pub fn group_by_items(i: Input) -> IResult<GroupBy> {
         rule! { GROUPING ~ SETS ~ "(" ~ ^#comma_separated_list1(group_set) ~ ")"  },
         |(_, _, _, sets, _)| GroupBy::GroupingSets(sets),
     );
-    rule!(#all | #group_sets | #cube | #rollup | #normal)(i)
+    let normal = map(rule! { #expr }, |group| GroupBy::Normal(vec![group]));
+    let group_item = alt((group_sets, cube, rollup, normal));
+    let items = map(rule! { ^#comma_separated_list1(group_item) }, |items| {
+        if items.len() == 1 {
+            return items.into_iter().next().unwrap();
+        }
+        if items.iter().all(|item| matches!(item, GroupBy::Normal(_))) {
+            let groups = items
+                .into_iter()
+                .flat_map(|item| match item {
+                    GroupBy::Normal(groups) => groups,
+                    _ => unreachable!(),
+                })
+                .collect();
+            return GroupBy::Normal(groups);
+        }
+        GroupBy::Combined(items)
+    });
+    rule!(#all | #items)(i)
 }
 
 pub fn window_frame_bound(i: Input) -> IResult<WindowFrameBound> {
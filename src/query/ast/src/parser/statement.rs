@@ -37,6 +37,7 @@ use crate::parser::data_mask::data_mask_policy;
 use crate::parser::expr::subexpr;
 use crate::parser::expr::*;
 use crate::parser::query::*;
+use crate::parser::row_access_policy::row_access_policy;
 use crate::parser::share::share_endpoint_uri_location;
 use crate::parser::stage::*;
 use crate::parser::token::*;
@@ -212,6 +213,27 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let insert_multi_table = map(
+        rule! {
+            INSERT ~ #hint? ~ OVERWRITE? ~ ( ALL | FIRST )
+            ~ #into_clause*
+            ~ #when_clause*
+            ~ ( ELSE ~ ^#into_clause+ )?
+            ~ #query
+        },
+        |(_, opt_hints, opt_overwrite, kind, into_clauses, when_clauses, opt_else, source)| {
+            Statement::InsertMultiTable(InsertMultiTableStmt {
+                hints: opt_hints,
+                overwrite: opt_overwrite.is_some(),
+                is_first: kind.kind == FIRST,
+                into_clauses,
+                when_clauses,
+                else_clause: opt_else.map(|(_, into_clauses)| ElseClause { into_clauses }),
+                source,
+            })
+        },
+    );
+
     let replace = map(
         rule! {
             REPLACE ~ #hint? ~ INTO?
@@ -332,6 +354,15 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
     );
     let show_indexes = value(Statement::ShowIndexes, rule! { SHOW ~ INDEXES });
 
+    let begin = value(Statement::Begin, rule! { BEGIN ~ TRANSACTION? });
+    let commit = value(Statement::Commit, rule! { COMMIT });
+    let abort = value(Statement::Abort, rule! { ROLLBACK });
+
+    let flush_query_cache = value(
+        Statement::FlushQueryCache,
+        rule! { ALTER ~ SYSTEM ~ FLUSH ~ QUERY ~ CACHE },
+    );
+
     // kill query 199;
     let kill_stmt = map(
         rule! {
@@ -354,6 +385,16 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let set_user_variable = map(
+        rule! {
+            SET ~ VARIABLE? ~ #user_variable ~ "=" ~ #subexpr(0)
+        },
+        |(_, _, variable, _, value)| Statement::SetUserVariable {
+            variable,
+            value: Box::new(value),
+        },
+    );
+
     let unset_variable = map(
         rule! {
             UNSET ~ #unset_source
@@ -743,6 +784,18 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let drop_table_cache = map(
+        rule! {
+            DROP ~ CACHE ~ FOR ~ TABLE ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, _, (catalog, database, table))| {
+            Statement::DropTableCache(DropTableCacheStmt {
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
     let optimize_table = map(
         rule! {
             OPTIMIZE ~ TABLE ~ #dot_separated_idents_1_to_3 ~ #optimize_table_action ~ ( LIMIT ~ #literal_u64 )?
@@ -843,6 +896,80 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let create_materialized_view = map(
+        rule! {
+            CREATE ~ MATERIALIZED ~ VIEW ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #dot_separated_idents_1_to_3
+            ~ AS ~ #query
+        },
+        |(_, _, _, opt_if_not_exists, (catalog, database, view), _, query)| {
+            Statement::CreateMaterializedView(CreateMaterializedViewStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                view,
+                query: Box::new(query),
+            })
+        },
+    );
+    let refresh_materialized_view = map(
+        rule! {
+            REFRESH ~ MATERIALIZED ~ VIEW ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, (catalog, database, view))| {
+            Statement::RefreshMaterializedView(RefreshMaterializedViewStmt {
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
+    let create_stream = map(
+        rule! {
+            CREATE ~ STREAM ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #dot_separated_idents_1_to_3
+            ~ ON ~ TABLE ~ #dot_separated_idents_1_to_2
+            ~ ( APPEND_ONLY ~ "=" ~ #literal_bool )?
+            ~ ( COMMENT ~ "=" ~ #literal_string )?
+        },
+        |(
+            _,
+            _,
+            opt_if_not_exists,
+            (catalog, database, stream),
+            _,
+            _,
+            (table_database, table),
+            opt_append_only,
+            opt_comment,
+        )| {
+            Statement::CreateStream(CreateStreamStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                stream,
+                table_database,
+                table,
+                append_only: opt_append_only
+                    .map(|(_, _, append_only)| append_only)
+                    .unwrap_or_default(),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_stream = map(
+        rule! {
+            DROP ~ STREAM ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, opt_if_exists, (catalog, database, stream))| {
+            Statement::DropStream(DropStreamStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                stream,
+            })
+        },
+    );
     let alter_view = map(
         rule! {
             ALTER ~ VIEW
@@ -892,6 +1019,40 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let create_bloom_index = map(
+        rule! {
+            CREATE ~ INDEX ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident
+            ~ ON ~ #dot_separated_idents_1_to_3
+            ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, _, opt_if_not_exists, index_name, _, (catalog, database, table), _, paths, _)| {
+            Statement::CreateBloomIndex(CreateBloomIndexStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+                paths,
+            })
+        },
+    );
+
+    let drop_bloom_index = map(
+        rule! {
+            DROP ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident ~ ON ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, opt_if_exists, index_name, _, (catalog, database, table))| {
+            Statement::DropBloomIndex(DropBloomIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+            })
+        },
+    );
+
     let refresh_index = map(
         rule! {
             REFRESH ~ AGGREGATING ~ INDEX ~ #ident ~ ( LIMIT ~ #literal_u64 )?
@@ -1081,6 +1242,21 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             })
         },
     );
+    let create_udaf = map(
+        rule! {
+            CREATE ~ AGGREGATE ~ FUNCTION ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident ~ #udaf_definition
+            ~ ( DESC ~ ^"=" ~ ^#literal_string )?
+        },
+        |(_, _, _, opt_if_not_exists, udf_name, definition, opt_description)| {
+            Statement::CreateUDF(CreateUDFStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                udf_name,
+                description: opt_description.map(|(_, _, description)| description),
+                definition,
+            })
+        },
+    );
     let drop_udf = map(
         rule! {
             DROP ~ FUNCTION ~ ( IF ~ ^EXISTS )? ~ #ident
@@ -1105,6 +1281,21 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    let alter_udaf = map(
+        rule! {
+            ALTER ~ AGGREGATE ~ FUNCTION
+            ~ #ident ~ #udaf_definition
+            ~ ( DESC ~ ^"=" ~ ^#literal_string )?
+        },
+        |(_, _, _, udf_name, definition, opt_description)| {
+            Statement::AlterUDF(AlterUDFStmt {
+                udf_name,
+                description: opt_description.map(|(_, _, description)| description),
+                definition,
+            })
+        },
+    );
+
     // stages
     let create_stage = map_res(
         rule! {
@@ -1399,6 +1590,43 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         },
     );
 
+    // row access policy
+    let create_row_access_policy = map(
+        rule! {
+            CREATE ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident ~ #row_access_policy
+        },
+        |(_, _, _, _, opt_if_not_exists, name, policy)| {
+            let stmt = CreateRowAccessPolicyStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                policy,
+            };
+            Statement::CreateRowAccessPolicy(stmt)
+        },
+    );
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, _, _, opt_if_exists, name)| {
+            let stmt = DropRowAccessPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            };
+            Statement::DropRowAccessPolicy(stmt)
+        },
+    );
+    let describe_row_access_policy = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ ROW ~ ACCESS ~ POLICY ~ #ident
+        },
+        |(_, _, _, _, name)| {
+            Statement::DescRowAccessPolicy(DescRowAccessPolicyStmt {
+                name: name.to_string(),
+            })
+        },
+    );
+
     let create_network_policy = map(
         rule! {
             CREATE ~ NETWORK ~ POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
@@ -1500,6 +1728,35 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
         rule! { SHOW ~ NETWORK ~ POLICIES },
     );
 
+    let create_sequence = map(
+        rule! {
+            CREATE ~ SEQUENCE ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident
+            ~ ( START ~ WITH? ~ #literal_u64 )?
+            ~ ( INCREMENT ~ BY? ~ #literal_u64 )?
+            ~ ( COMMENT ~ Eq ~ #literal_string )?
+        },
+        |(_, _, opt_if_not_exists, name, opt_start, opt_increment, opt_comment)| {
+            Statement::CreateSequence(CreateSequenceStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                start: opt_start.map(|(_, _, start)| start),
+                increment: opt_increment.map(|(_, _, increment)| increment),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+            })
+        },
+    );
+    let drop_sequence = map(
+        rule! {
+            DROP ~ SEQUENCE ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            Statement::DropSequence(DropSequenceStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            })
+        },
+    );
+
     let statement_body = alt((
         rule!(
             #map(query, |query| Statement::Query(Box::new(query)))
@@ -1532,14 +1789,32 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #describe_network_policy: "`DESC NETWORK POLICY name`"
             | #show_network_policies: "`SHOW NETWORK POLICIES`"
         ),
+        // row access policy
+        rule!(
+            #create_row_access_policy: "`CREATE ROW ACCESS POLICY [IF NOT EXISTS] <policy_name> AS (<arg_name> <arg_type> [, ...]) RETURNS BOOLEAN -> <expr> [ COMMENT = '<string_literal>' ]`"
+            | #drop_row_access_policy: "`DROP ROW ACCESS POLICY [IF EXISTS] <policy_name>`"
+            | #describe_row_access_policy: "`DESC ROW ACCESS POLICY <policy_name>`"
+        ),
+        // sequence
+        rule!(
+            #create_sequence: "`CREATE SEQUENCE [IF NOT EXISTS] <name> [START [WITH] <number>] [INCREMENT [BY] <number>] [COMMENT = '<string_literal>']`"
+            | #drop_sequence: "`DROP SEQUENCE [IF EXISTS] <name>`"
+        ),
         rule!(
-            #insert : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
+            #insert_multi_table : "`INSERT [OVERWRITE] {ALL | FIRST} [INTO <table> [(<column>, ...)] ...] [WHEN <condition> THEN INTO <table> [(<column>, ...)] ...] [ELSE INTO <table> [(<column>, ...)] ...] <query>`"
+            | #insert : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
             | #replace : "`REPLACE INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
             | #merge : "`MERGE INTO <target_table> USING <source> ON <join_expr> { matchedClause | notMatchedClause } [ ... ]`"
         ),
         rule!(
             #set_variable : "`SET <variable> = <value>`"
+            | #set_user_variable : "`SET [VARIABLE] @<variable> = <value>`"
             | #unset_variable : "`UNSET <variable>`"
+            | #begin : "`BEGIN [TRANSACTION]`"
+            | #commit : "`COMMIT`"
+            | #abort : "`ROLLBACK`"
+            | #flush_query_cache : "`ALTER SYSTEM FLUSH QUERY CACHE`"
+            | #drop_table_cache : "`DROP CACHE FOR TABLE [<database>.]<table>`"
         ),
         rule!(
             #show_tables : "`SHOW [FULL] TABLES [FROM <database>] [<show_limit>]`"
@@ -1567,11 +1842,17 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             #create_view : "`CREATE VIEW [IF NOT EXISTS] [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
             | #alter_view : "`ALTER VIEW [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
+            | #create_materialized_view : "`CREATE MATERIALIZED VIEW [IF NOT EXISTS] [<database>.]<view> AS SELECT ...`"
+            | #refresh_materialized_view : "`REFRESH MATERIALIZED VIEW [<database>.]<view>`"
+            | #create_stream : "`CREATE STREAM [IF NOT EXISTS] [<database>.]<stream> ON TABLE [<database>.]<table> [APPEND_ONLY = true|false] [COMMENT = '<comment>']`"
+            | #drop_stream : "`DROP STREAM [IF EXISTS] [<database>.]<stream>`"
         ),
         rule!(
             #create_index: "`CREATE AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP AGGREGATING INDEX [IF EXISTS] <index>`"
             | #refresh_index: "`REFRESH AGGREGATING INDEX <index> [LIMIT <limit>]`"
+            | #create_bloom_index: "`CREATE INDEX [IF NOT EXISTS] <index> ON [<database>.]<table> (<path>, ...)`"
+            | #drop_bloom_index: "`DROP INDEX [IF EXISTS] <index> ON [<database>.]<table>`"
         ),
         rule!(
             #create_virtual_column: "`CREATE VIRTUAL COLUMN (expr, ...) FOR [<database>.]<table>`"
@@ -1587,9 +1868,11 @@ pub fn statement(i: Input) -> IResult<StatementMsg> {
             | #show_roles : "`SHOW ROLES`"
             | #create_role : "`CREATE ROLE [IF NOT EXISTS] <role_name>`"
             | #drop_role : "`DROP ROLE [IF EXISTS] <role_name>`"
-            | #create_udf : "`CREATE FUNCTION [IF NOT EXISTS] <name> {AS (<parameter>, ...) -> <definition expr> | (<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address>} [DESC = <description>]`"
+            | #create_udf : "`CREATE FUNCTION [IF NOT EXISTS] <name> {AS (<parameter>, ...) -> <definition expr> | (<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> {ADDRESS=<udf_server_address> | AS <code>}} [DESC = <description>]`"
             | #drop_udf : "`DROP FUNCTION [IF EXISTS] <udf_name>`"
             | #alter_udf : "`ALTER FUNCTION <udf_name> (<parameter>, ...) -> <definition_expr> [DESC = <description>]`"
+                    | #create_udaf : "`CREATE AGGREGATE FUNCTION [IF NOT EXISTS] <name> STATE <state_type> INIT = <init expr> ACCUMULATE = (<state>, <parameter>, ...) -> <expr> MERGE = (<state>, <state>) -> <expr> FINALIZE = (<state>) -> <expr> [DESC = <description>]`"
+            | #alter_udaf : "`ALTER AGGREGATE FUNCTION <name> STATE <state_type> INIT = <init expr> ACCUMULATE = (<state>, <parameter>, ...) -> <expr> MERGE = (<state>, <state>) -> <expr> FINALIZE = (<state>) -> <expr> [DESC = <description>]`"
         ),
         rule!(
             #create_stage: "`CREATE STAGE [ IF NOT EXISTS ] <stage_name>
@@ -1714,6 +1997,35 @@ pub fn insert_source(i: Input) -> IResult<InsertSource> {
     )(i)
 }
 
+pub fn into_clause(i: Input) -> IResult<IntoClause> {
+    map(
+        rule! {
+            INTO ~ #dot_separated_idents_1_to_3
+            ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
+        },
+        |(_, (catalog, database, table), opt_columns)| IntoClause {
+            catalog,
+            database,
+            table,
+            columns: opt_columns
+                .map(|(_, columns, _)| columns)
+                .unwrap_or_default(),
+        },
+    )(i)
+}
+
+pub fn when_clause(i: Input) -> IResult<WhenClause> {
+    map(
+        rule! {
+            WHEN ~ ^#expr ~ ^THEN ~ ^#into_clause+
+        },
+        |(_, condition, _, into_clauses)| WhenClause {
+            condition,
+            into_clauses,
+        },
+    )(i)
+}
+
 pub fn merge_source(i: Input) -> IResult<MergeSource> {
     let streaming_v2 = map(
         rule! {
@@ -1956,6 +2268,7 @@ pub fn priv_type(i: Input) -> IResult<UserPrivilegeType> {
         value(UserPrivilegeType::Drop, rule! { DROP }),
         value(UserPrivilegeType::Create, rule! { CREATE }),
         value(UserPrivilegeType::Ownership, rule! { OWNERSHIP }),
+        value(UserPrivilegeType::Unmask, rule! { UNMASK }),
     ))(i)
 }
 
@@ -2298,6 +2611,23 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, _, set_options, _)| AlterTableAction::SetOptions { set_options },
     );
 
+    let add_row_access_policy = map(
+        rule! {
+            ADD ~ ROW ~ ACCESS ~ POLICY ~ #ident ~ ON ~ "(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, _, policy, _, _, columns, _)| AlterTableAction::AddRowAccessPolicy {
+            policy,
+            columns,
+        },
+    );
+
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ POLICY ~ #ident
+        },
+        |(_, _, _, _, policy)| AlterTableAction::DropRowAccessPolicy { policy },
+    );
+
     rule!(
         #rename_table
         | #rename_column
@@ -2309,6 +2639,8 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         | #recluster_table
         | #revert_table
         | #set_table_options
+        | #add_row_access_policy
+        | #drop_row_access_policy
     )(i)
 }
 
@@ -2611,6 +2943,7 @@ pub fn engine(i: Input) -> IResult<Engine> {
         value(Engine::Fuse, rule! { FUSE }),
         value(Engine::View, rule! { VIEW }),
         value(Engine::Random, rule! { RANDOM }),
+        value(Engine::Delta, rule! { DELTA }),
     ));
 
     map(
@@ -2719,6 +3052,7 @@ pub fn auth_type(i: Input) -> IResult<AuthType> {
         value(AuthType::Sha256Password, rule! { SHA256_PASSWORD }),
         value(AuthType::DoubleSha1Password, rule! { DOUBLE_SHA1_PASSWORD }),
         value(AuthType::JWT, rule! { JWT }),
+        value(AuthType::Ldap, rule! { LDAP }),
     ))(i)
 }
 
@@ -2842,12 +3176,65 @@ pub fn udf_definition(i: Input) -> IResult<UDFDefinition> {
         },
     );
 
+    let udf_script = map(
+        rule! {
+            "(" ~ #comma_separated_list0(udf_arg_type) ~ ")"
+            ~ RETURNS ~ #udf_arg_type
+            ~ LANGUAGE ~ #ident
+            ~ HANDLER ~ ^"=" ~ ^#literal_string
+            ~ AS ~ ^#literal_string
+        },
+        |(_, arg_types, _, _, return_type, _, language, _, _, handler, _, code)| {
+            UDFDefinition::UDFScript {
+                arg_types,
+                return_type,
+                code,
+                handler,
+                language: language.to_string(),
+            }
+        },
+    );
+
     rule!(
         #udf_server: "(<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address>"
+        | #udf_script: "(<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> AS <code>"
         | #lambda_udf: "AS (<parameter>, ...) -> <definition expr>"
     )(i)
 }
 
+pub fn udaf_definition(i: Input) -> IResult<UDFDefinition> {
+    let lambda = |i| {
+        map(
+            rule! {
+                "(" ~ #comma_separated_list1(ident) ~ ")" ~ "->" ~ #expr
+            },
+            |(_, params, _, _, expr)| Lambda {
+                params,
+                expr: Box::new(expr),
+            },
+        )(i)
+    };
+
+    map(
+        rule! {
+            STATE ~ ^#type_name
+            ~ INIT ~ ^"=" ~ ^#expr
+            ~ ACCUMULATE ~ ^"=" ~ ^#lambda
+            ~ MERGE ~ ^"=" ~ ^#lambda
+            ~ FINALIZE ~ ^"=" ~ ^#lambda
+        },
+        |(_, state_type, _, _, init, _, _, accumulate, _, _, merge, _, _, finalize)| {
+            UDFDefinition::LambdaUDAF {
+                state_type,
+                init: Box::new(init),
+                accumulate,
+                merge,
+                finalize,
+            }
+        },
+    )(i)
+}
+
 pub fn merge_update_expr(i: Input) -> IResult<MergeUpdateExpr> {
     map(
         rule! { ( #dot_separated_idents_1_to_3 ~ "=" ~ ^#expr ) },
@@ -151,7 +151,7 @@ pub enum TokenKind {
     #[regex(r#"'([^'\\]|\\.|'')*'"#)]
     QuotedString,
 
-    #[regex(r#"@([^\s`;'"()]|\\\s|\\'|\\"|\\\\)+"#)]
+    #[regex(r#"@([^\s`;'"(),]|\\\s|\\'|\\"|\\\\)+"#)]
     AtString,
 
     #[regex(r"[xX]'[a-fA-F0-9]*'")]
@@ -293,6 +293,10 @@ pub enum TokenKind {
     // 2. Search in this file to see if the new keyword is a commented
     //    out reserved keyword. If so, uncomment the keyword in the
     //    reserved list.
+    #[token("ACCESS", ignore(ascii_case))]
+    ACCESS,
+    #[token("ACCUMULATE", ignore(ascii_case))]
+    ACCUMULATE,
     #[token("ALL", ignore(ascii_case))]
     ALL,
     #[token("ALLOWED_IP_LIST", ignore(ascii_case))]
@@ -301,10 +305,14 @@ pub enum TokenKind {
     ADD,
     #[token("AFTER", ignore(ascii_case))]
     AFTER,
+    #[token("AGGREGATE", ignore(ascii_case))]
+    AGGREGATE,
     #[token("AGGREGATING", ignore(ascii_case))]
     AGGREGATING,
     #[token("ANY", ignore(ascii_case))]
     ANY,
+    #[token("APPEND_ONLY", ignore(ascii_case))]
+    APPEND_ONLY,
     #[token("ARGS", ignore(ascii_case))]
     ARGS,
     #[token("AUTO", ignore(ascii_case))]
@@ -333,6 +341,8 @@ pub enum TokenKind {
     ANTI,
     #[token("BEFORE", ignore(ascii_case))]
     BEFORE,
+    #[token("BEGIN", ignore(ascii_case))]
+    BEGIN,
     #[token("BETWEEN", ignore(ascii_case))]
     BETWEEN,
     #[token("BIGINT", ignore(ascii_case))]
@@ -355,6 +365,8 @@ pub enum TokenKind {
     BROTLI,
     #[token("BZ2", ignore(ascii_case))]
     BZ2,
+    #[token("CACHE", ignore(ascii_case))]
+    CACHE,
     #[token("CALL", ignore(ascii_case))]
     CALL,
     #[token("CASE", ignore(ascii_case))]
@@ -373,6 +385,8 @@ pub enum TokenKind {
     COMMENT,
     #[token("COMMENTS", ignore(ascii_case))]
     COMMENTS,
+    #[token("COMMIT", ignore(ascii_case))]
+    COMMIT,
     #[token("COMPACT", ignore(ascii_case))]
     COMPACT,
     #[token("CONNECTION", ignore(ascii_case))]
@@ -441,6 +455,8 @@ pub enum TokenKind {
     DEFLATE,
     #[token("DELETE", ignore(ascii_case))]
     DELETE,
+    #[token("DELTA", ignore(ascii_case))]
+    DELTA,
     #[token("DESC", ignore(ascii_case))]
     DESC,
     #[token("DESCRIBE", ignore(ascii_case))]
@@ -515,6 +531,8 @@ pub enum TokenKind {
     FILES,
     #[token("FINAL", ignore(ascii_case))]
     FINAL,
+    #[token("FINALIZE", ignore(ascii_case))]
+    FINALIZE,
     #[token("FLASHBACK", ignore(ascii_case))]
     FLASHBACK,
     #[token("FLOAT", ignore(ascii_case))]
@@ -523,6 +541,8 @@ pub enum TokenKind {
     FLOAT32,
     #[token("FLOAT64", ignore(ascii_case))]
     FLOAT64,
+    #[token("FLUSH", ignore(ascii_case))]
+    FLUSH,
     #[token("FOR", ignore(ascii_case))]
     FOR,
     #[token("FORCE", ignore(ascii_case))]
@@ -553,6 +573,8 @@ pub enum TokenKind {
     FUSE,
     #[token("GENERATED", ignore(ascii_case))]
     GENERATED,
+    #[token("GEOMETRY", ignore(ascii_case))]
+    GEOMETRY,
     #[token("GLOBAL", ignore(ascii_case))]
     GLOBAL,
     #[token("GRAPH", ignore(ascii_case))]
@@ -581,8 +603,12 @@ pub enum TokenKind {
     IF,
     #[token("IN", ignore(ascii_case))]
     IN,
+    #[token("INCREMENT", ignore(ascii_case))]
+    INCREMENT,
     #[token("INDEX", ignore(ascii_case))]
     INDEX,
+    #[token("INIT", ignore(ascii_case))]
+    INIT,
     #[token("INNER", ignore(ascii_case))]
     INNER,
     #[token("INSERT", ignore(ascii_case))]
@@ -628,6 +654,10 @@ pub enum TokenKind {
     /// L2DISTANCE op, from https://github.com/pgvector/pgvector
     #[token("<->")]
     L2DISTANCE,
+    #[token("LATERAL", ignore(ascii_case))]
+    LATERAL,
+    #[token("LDAP", ignore(ascii_case))]
+    LDAP,
     #[token("LEADING", ignore(ascii_case))]
     LEADING,
     #[token("LEFT", ignore(ascii_case))]
@@ -776,6 +806,8 @@ pub enum TokenKind {
     GRANT,
     #[token("ROLE", ignore(ascii_case))]
     ROLE,
+    #[token("ROLLBACK", ignore(ascii_case))]
+    ROLLBACK,
     #[token("PRECEDING", ignore(ascii_case))]
     PRECEDING,
     #[token("PRECISION", ignore(ascii_case))]
@@ -820,6 +852,8 @@ pub enum TokenKind {
     UNPIVOT,
     #[token("SEGMENT", ignore(ascii_case))]
     SEGMENT,
+    #[token("SEQUENCE", ignore(ascii_case))]
+    SEQUENCE,
     #[token("SET", ignore(ascii_case))]
     SET,
     #[token("UNSET", ignore(ascii_case))]
@@ -854,8 +888,14 @@ pub enum TokenKind {
     SPLIT_SIZE,
     #[token("STAGE", ignore(ascii_case))]
     STAGE,
+    #[token("START", ignore(ascii_case))]
+    START,
+    #[token("STATE", ignore(ascii_case))]
+    STATE,
     #[token("SYNTAX", ignore(ascii_case))]
     SYNTAX,
+    #[token("SYSTEM", ignore(ascii_case))]
+    SYSTEM,
     #[token("USAGE", ignore(ascii_case))]
     USAGE,
     #[token("UPDATE", ignore(ascii_case))]
@@ -872,6 +912,8 @@ pub enum TokenKind {
     STATUS,
     #[token("STORED", ignore(ascii_case))]
     STORED,
+    #[token("STREAM", ignore(ascii_case))]
+    STREAM,
     #[token("STRING", ignore(ascii_case))]
     STRING,
     #[token("SUBSTRING", ignore(ascii_case))]
@@ -898,6 +940,8 @@ pub enum TokenKind {
     TENANT,
     #[token("THEN", ignore(ascii_case))]
     THEN,
+    #[token("TIME", ignore(ascii_case))]
+    TIME,
     #[token("TIMESTAMP", ignore(ascii_case))]
     TIMESTAMP,
     #[token("TIMEZONE_HOUR", ignore(ascii_case))]
@@ -914,6 +958,8 @@ pub enum TokenKind {
     TOKEN,
     #[token("TRAILING", ignore(ascii_case))]
     TRAILING,
+    #[token("TRANSACTION", ignore(ascii_case))]
+    TRANSACTION,
     #[token("TRANSIENT", ignore(ascii_case))]
     TRANSIENT,
     #[token("TRIM", ignore(ascii_case))]
@@ -944,6 +990,8 @@ pub enum TokenKind {
     UINT8,
     #[token("UNDROP", ignore(ascii_case))]
     UNDROP,
+    #[token("UNMASK", ignore(ascii_case))]
+    UNMASK,
     #[token("UNSIGNED", ignore(ascii_case))]
     UNSIGNED,
     #[token("URL", ignore(ascii_case))]
@@ -966,6 +1014,8 @@ pub enum TokenKind {
     VARBINARY,
     #[token("VARCHAR", ignore(ascii_case))]
     VARCHAR,
+    #[token("VARIABLE", ignore(ascii_case))]
+    VARIABLE,
     #[token("VARIANT", ignore(ascii_case))]
     VARIANT,
     #[token("VIEW", ignore(ascii_case))]
@@ -84,6 +84,27 @@ pub fn function_name(i: Input) -> IResult<Identifier> {
     non_reserved_identifier(|token| token.is_reserved_function_name())(i)
 }
 
+/// Parse a user-defined variable, such as `@x`.
+pub fn user_variable(i: Input) -> IResult<Identifier> {
+    match_token(AtString)(i).and_then(|(i2, token)| {
+        let name = &token.text()[1..];
+        let is_ident = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if is_ident {
+            Ok((i2, Identifier {
+                span: transform_span(&[token.clone()]),
+                name: name.to_lowercase(),
+                quote: None,
+            }))
+        } else {
+            Err(nom::Err::Error(Error::from_error_kind(
+                i,
+                ErrorKind::ExpectToken(AtString),
+            )))
+        }
+    })
+}
+
 /// Parse input into stage name.
 ///
 /// Possible value:
@@ -19,6 +19,7 @@ use common_meta_app::principal::UserIdentity;
 
 use super::walk::walk_cte;
 use super::walk::walk_expr;
+use super::walk::walk_group_by;
 use super::walk::walk_identifier;
 use super::walk::walk_join_condition;
 use super::walk::walk_query;
@@ -367,6 +368,10 @@ pub trait Visitor<'ast>: Sized {
         walk_expr(self, date);
     }
 
+    fn visit_user_variable(&mut self, _span: Span, name: &'ast Identifier) {
+        self.visit_identifier(name);
+    }
+
     fn visit_statement(&mut self, statement: &'ast Statement) {
         walk_statement(self, statement);
     }
@@ -410,9 +415,12 @@ pub trait Visitor<'ast>: Sized {
     ) {
     }
 
+    fn visit_set_user_variable(&mut self, _variable: &'ast Identifier, _value: &'ast Expr) {}
+
     fn visit_set_role(&mut self, _is_default: bool, _role_name: &'ast str) {}
 
     fn visit_insert(&mut self, _insert: &'ast InsertStmt) {}
+    fn visit_insert_multi_table(&mut self, _insert: &'ast InsertMultiTableStmt) {}
     fn visit_replace(&mut self, _replace: &'ast ReplaceStmt) {}
     fn visit_merge_into(&mut self, _merge_into: &'ast MergeIntoStmt) {}
     fn visit_insert_source(&mut self, _insert_source: &'ast InsertSource) {}
@@ -487,11 +495,23 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &'ast DropViewStmt) {}
 
+    fn visit_create_materialized_view(&mut self, _stmt: &'ast CreateMaterializedViewStmt) {}
+
+    fn visit_refresh_materialized_view(&mut self, _stmt: &'ast RefreshMaterializedViewStmt) {}
+
+    fn visit_create_stream(&mut self, _stmt: &'ast CreateStreamStmt) {}
+
+    fn visit_drop_stream(&mut self, _stmt: &'ast DropStreamStmt) {}
+
     fn visit_create_index(&mut self, _stmt: &'ast CreateIndexStmt) {}
 
     fn visit_drop_index(&mut self, _stmt: &'ast DropIndexStmt) {}
     fn visit_refresh_index(&mut self, _stmt: &'ast RefreshIndexStmt) {}
 
+    fn visit_create_bloom_index(&mut self, _stmt: &'ast CreateBloomIndexStmt) {}
+
+    fn visit_drop_bloom_index(&mut self, _stmt: &'ast DropBloomIndexStmt) {}
+
     fn visit_create_virtual_column(&mut self, _stmt: &'ast CreateVirtualColumnStmt) {}
 
     fn visit_alter_virtual_column(&mut self, _stmt: &'ast AlterVirtualColumnStmt) {}
@@ -582,6 +602,16 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_desc_data_mask_policy(&mut self, _stmt: &'ast DescDatamaskPolicyStmt) {}
 
+    fn visit_create_row_access_policy(&mut self, _stmt: &'ast CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &'ast DropRowAccessPolicyStmt) {}
+
+    fn visit_desc_row_access_policy(&mut self, _stmt: &'ast DescRowAccessPolicyStmt) {}
+
+    fn visit_create_sequence(&mut self, _stmt: &'ast CreateSequenceStmt) {}
+
+    fn visit_drop_sequence(&mut self, _stmt: &'ast DropSequenceStmt) {}
+
     fn visit_create_network_policy(&mut self, _stmt: &'ast CreateNetworkPolicyStmt) {}
 
     fn visit_alter_network_policy(&mut self, _stmt: &'ast AlterNetworkPolicyStmt) {}
@@ -649,20 +679,8 @@ pub trait Visitor<'ast>: Sized {
             walk_expr(self, selection);
         }
 
-        match group_by {
-            Some(GroupBy::Normal(exprs)) => {
-                for expr in exprs {
-                    walk_expr(self, expr);
-                }
-            }
-            Some(GroupBy::GroupingSets(sets)) => {
-                for set in sets {
-                    for expr in set {
-                        walk_expr(self, expr);
-                    }
-                }
-            }
-            _ => {}
+        if let Some(group_by) = group_by {
+            walk_group_by(self, group_by);
         }
 
         if let Some(having) = having {
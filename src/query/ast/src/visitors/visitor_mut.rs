@@ -19,6 +19,7 @@ use common_meta_app::principal::UserIdentity;
 
 use super::walk_mut::walk_cte_mut;
 use super::walk_mut::walk_expr_mut;
+use super::walk_mut::walk_group_by_mut;
 use super::walk_mut::walk_identifier_mut;
 use super::walk_mut::walk_join_condition_mut;
 use super::walk_mut::walk_query_mut;
@@ -380,6 +381,10 @@ pub trait VisitorMut: Sized {
         Self::visit_expr(self, date);
     }
 
+    fn visit_user_variable(&mut self, _span: Span, name: &mut Identifier) {
+        Self::visit_identifier(self, name);
+    }
+
     fn visit_statement(&mut self, statement: &mut Statement) {
         walk_statement_mut(self, statement);
     }
@@ -425,9 +430,12 @@ pub trait VisitorMut: Sized {
 
     fn visit_unset_variable(&mut self, _stmt: &mut UnSetStmt) {}
 
+    fn visit_set_user_variable(&mut self, _variable: &mut Identifier, _value: &mut Box<Expr>) {}
+
     fn visit_set_role(&mut self, _is_default: bool, _role_name: &mut String) {}
 
     fn visit_insert(&mut self, _insert: &mut InsertStmt) {}
+    fn visit_insert_multi_table(&mut self, _insert: &mut InsertMultiTableStmt) {}
     fn visit_replace(&mut self, _replace: &mut ReplaceStmt) {}
     fn visit_merge_into(&mut self, _merge_into: &mut MergeIntoStmt) {}
     fn visit_insert_source(&mut self, _insert_source: &mut InsertSource) {}
@@ -502,11 +510,23 @@ pub trait VisitorMut: Sized {
 
     fn visit_drop_view(&mut self, _stmt: &mut DropViewStmt) {}
 
+    fn visit_create_materialized_view(&mut self, _stmt: &mut CreateMaterializedViewStmt) {}
+
+    fn visit_refresh_materialized_view(&mut self, _stmt: &mut RefreshMaterializedViewStmt) {}
+
+    fn visit_create_stream(&mut self, _stmt: &mut CreateStreamStmt) {}
+
+    fn visit_drop_stream(&mut self, _stmt: &mut DropStreamStmt) {}
+
     fn visit_create_index(&mut self, _stmt: &mut CreateIndexStmt) {}
 
     fn visit_drop_index(&mut self, _stmt: &mut DropIndexStmt) {}
     fn visit_refresh_index(&mut self, _stmt: &mut RefreshIndexStmt) {}
 
+    fn visit_create_bloom_index(&mut self, _stmt: &mut CreateBloomIndexStmt) {}
+
+    fn visit_drop_bloom_index(&mut self, _stmt: &mut DropBloomIndexStmt) {}
+
     fn visit_create_virtual_column(&mut self, _stmt: &mut CreateVirtualColumnStmt) {}
 
     fn visit_alter_virtual_column(&mut self, _stmt: &mut AlterVirtualColumnStmt) {}
@@ -597,6 +617,16 @@ pub trait VisitorMut: Sized {
 
     fn visit_desc_data_mask_policy(&mut self, _stmt: &mut DescDatamaskPolicyStmt) {}
 
+    fn visit_create_row_access_policy(&mut self, _stmt: &mut CreateRowAccessPolicyStmt) {}
+
+    fn visit_drop_row_access_policy(&mut self, _stmt: &mut DropRowAccessPolicyStmt) {}
+
+    fn visit_desc_row_access_policy(&mut self, _stmt: &mut DescRowAccessPolicyStmt) {}
+
+    fn visit_create_sequence(&mut self, _stmt: &mut CreateSequenceStmt) {}
+
+    fn visit_drop_sequence(&mut self, _stmt: &mut DropSequenceStmt) {}
+
     fn visit_create_network_policy(&mut self, _stmt: &mut CreateNetworkPolicyStmt) {}
 
     fn visit_alter_network_policy(&mut self, _stmt: &mut AlterNetworkPolicyStmt) {}
@@ -664,20 +694,8 @@ pub trait VisitorMut: Sized {
             Self::visit_expr(self, selection);
         }
 
-        match group_by {
-            Some(GroupBy::Normal(exprs)) => {
-                for expr in exprs {
-                    Self::visit_expr(self, expr);
-                }
-            }
-            Some(GroupBy::GroupingSets(sets)) => {
-                for set in sets {
-                    for expr in set {
-                        Self::visit_expr(self, expr);
-                    }
-                }
-            }
-            _ => {}
+        if let Some(group_by) = group_by {
+            walk_group_by_mut(self, group_by);
         }
 
         if let Some(having) = having {
@@ -141,6 +141,7 @@ pub fn walk_expr_mut<V: VisitorMut>(visitor: &mut V, expr: &mut Expr) {
             unit,
         } => visitor.visit_date_sub(*span, unit, interval, date),
         Expr::DateTrunc { span, unit, date } => visitor.visit_date_trunc(*span, unit, date),
+        Expr::UserVariable { span, name } => visitor.visit_user_variable(*span, name),
     }
 }
 
@@ -198,6 +199,29 @@ pub fn walk_set_expr_mut<V: VisitorMut>(visitor: &mut V, set_expr: &mut SetExpr)
     }
 }
 
+pub fn walk_group_by_mut<V: VisitorMut>(visitor: &mut V, group_by: &mut GroupBy) {
+    match group_by {
+        GroupBy::Normal(exprs) | GroupBy::Cube(exprs) | GroupBy::Rollup(exprs) => {
+            for expr in exprs {
+                visitor.visit_expr(expr);
+            }
+        }
+        GroupBy::GroupingSets(sets) => {
+            for set in sets {
+                for expr in set {
+                    visitor.visit_expr(expr);
+                }
+            }
+        }
+        GroupBy::Combined(items) => {
+            for item in items {
+                walk_group_by_mut(visitor, item);
+            }
+        }
+        GroupBy::All => {}
+    }
+}
+
 pub fn walk_select_target_mut<V: VisitorMut>(visitor: &mut V, target: &mut SelectTarget) {
     match target {
         SelectTarget::AliasedExpr { expr, alias } => {
@@ -317,6 +341,7 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::ExplainAnalyze { query } => visitor.visit_statement(&mut *query),
         Statement::Query(query) => visitor.visit_query(&mut *query),
         Statement::Insert(insert) => visitor.visit_insert(insert),
+        Statement::InsertMultiTable(insert) => visitor.visit_insert_multi_table(insert),
         Statement::Replace(replace) => visitor.visit_replace(replace),
         Statement::MergeInto(merge_into) => visitor.visit_merge_into(merge_into),
         Statement::Delete(delete) => visitor.visit_delete(delete),
@@ -340,6 +365,9 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
             value,
         } => visitor.visit_set_variable(*is_global, variable, value),
         Statement::UnSetVariable(stmt) => visitor.visit_unset_variable(stmt),
+        Statement::SetUserVariable { variable, value } => {
+            visitor.visit_set_user_variable(variable, value)
+        }
         Statement::SetRole {
             is_default,
             role_name,
@@ -375,9 +403,15 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::CreateView(stmt) => visitor.visit_create_view(stmt),
         Statement::AlterView(stmt) => visitor.visit_alter_view(stmt),
         Statement::DropView(stmt) => visitor.visit_drop_view(stmt),
+        Statement::CreateMaterializedView(stmt) => visitor.visit_create_materialized_view(stmt),
+        Statement::RefreshMaterializedView(stmt) => visitor.visit_refresh_materialized_view(stmt),
+        Statement::CreateStream(stmt) => visitor.visit_create_stream(stmt),
+        Statement::DropStream(stmt) => visitor.visit_drop_stream(stmt),
         Statement::CreateIndex(stmt) => visitor.visit_create_index(stmt),
         Statement::DropIndex(stmt) => visitor.visit_drop_index(stmt),
         Statement::RefreshIndex(stmt) => visitor.visit_refresh_index(stmt),
+        Statement::CreateBloomIndex(stmt) => visitor.visit_create_bloom_index(stmt),
+        Statement::DropBloomIndex(stmt) => visitor.visit_drop_bloom_index(stmt),
         Statement::CreateVirtualColumn(stmt) => visitor.visit_create_virtual_column(stmt),
         Statement::AlterVirtualColumn(stmt) => visitor.visit_alter_virtual_column(stmt),
         Statement::DropVirtualColumn(stmt) => visitor.visit_drop_virtual_column(stmt),
@@ -443,7 +477,15 @@ pub fn walk_statement_mut<V: VisitorMut>(visitor: &mut V, statement: &mut Statem
         Statement::CreateDatamaskPolicy(stmt) => visitor.visit_create_data_mask_policy(stmt),
         Statement::DropDatamaskPolicy(stmt) => visitor.visit_drop_data_mask_policy(stmt),
         Statement::DescDatamaskPolicy(stmt) => visitor.visit_desc_data_mask_policy(stmt),
+        Statement::CreateRowAccessPolicy(stmt) => visitor.visit_create_row_access_policy(stmt),
+        Statement::DropRowAccessPolicy(stmt) => visitor.visit_drop_row_access_policy(stmt),
+        Statement::DescRowAccessPolicy(stmt) => visitor.visit_desc_row_access_policy(stmt),
+        Statement::CreateSequence(stmt) => visitor.visit_create_sequence(stmt),
+        Statement::DropSequence(stmt) => visitor.visit_drop_sequence(stmt),
         Statement::AttachTable(_) => {}
+        Statement::DropTableCache(_) => {}
+        Statement::FlushQueryCache => {}
+        Statement::Begin | Statement::Commit | Statement::Abort => {}
         Statement::CreateNetworkPolicy(stmt) => visitor.visit_create_network_policy(stmt),
         Statement::AlterNetworkPolicy(stmt) => visitor.visit_alter_network_policy(stmt),
         Statement::DropNetworkPolicy(stmt) => visitor.visit_drop_network_policy(stmt),
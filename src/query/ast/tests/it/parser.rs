@@ -132,6 +132,7 @@ fn test_statement() {
         r#"ALTER USER u1 WITH UNSET NETWORK POLICY;"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH DEFAULT_ROLE='role123', TENANTSETTING"#,
         r#"CREATE USER u1 IDENTIFIED BY '123456' WITH SET NETWORK POLICY='policy1'"#,
+        r#"CREATE USER u1 IDENTIFIED WITH ldap WITH DEFAULT_ROLE='role123'"#,
         r#"DROP database if exists db1;"#,
         r#"select distinct a, count(*) from t where a = 1 and b - 1 < a group by a having a = 1;"#,
         r#"select * from t4;"#,
@@ -481,6 +482,16 @@ fn test_statement() {
         "--各环节转各环节转各环节转各环节转各\n  select 34343",
         "-- 96477300355	31379974136	3.074486292973661\nselect 34343",
         "-- xxxxx\n  select 34343;",
+        r#"BEGIN"#,
+        r#"BEGIN TRANSACTION"#,
+        r#"COMMIT"#,
+        r#"ROLLBACK"#,
+        r#"ALTER SYSTEM FLUSH QUERY CACHE"#,
+        r#"DROP CACHE FOR TABLE db.t"#,
+        r#"CREATE SEQUENCE IF NOT EXISTS seq1 START WITH 10 INCREMENT BY 2 COMMENT = 'order ids'"#,
+        r#"DROP SEQUENCE IF EXISTS seq1"#,
+        r#"SELECT * FROM t GROUP BY a, ROLLUP (b, c)"#,
+        r#"ATTACH TABLE t 's3://testbucket/admin/data/1/2' READ_ONLY"#,
     ];
 
     for case in cases {
@@ -740,6 +751,7 @@ fn test_expr() {
         r#"COUNT() OVER (ORDER BY hire_date ROWS 3 PRECEDING)"#,
         r#"ARRAY_APPLY([1,2,3], x -> x + 1)"#,
         r#"ARRAY_FILTER(col, y -> y % 2 = 0)"#,
+        r#"ARRAY_REDUCE(col, (acc, x) -> acc + x)"#,
     ];
 
     for case in cases {
@@ -20,8 +20,10 @@ pub mod cluster_info;
 pub mod database;
 pub mod plan;
 pub mod query_kind;
+pub mod runtime_filter_info;
 pub mod statistics;
 pub mod table;
 pub mod table_args;
 pub mod table_context;
 pub mod table_function;
+pub mod txn;
@@ -93,6 +93,9 @@ pub struct PushDownInfo {
     pub lazy_materialization: bool,
     /// Aggregating index information.
     pub agg_index: Option<AggIndexInfo>,
+    /// The id used to look up the runtime filters of this scan, see
+    /// [`TableContext::get_runtime_filter`](crate::table_context::TableContext::get_runtime_filter).
+    pub runtime_filter_scan_id: Option<usize>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -0,0 +1,48 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::Expr;
+
+/// Filters collected from the build side of a hash join at runtime,
+/// used to skip the blocks of the probe side table scan that can not match.
+///
+/// The expressions refer to the columns of the scanned table by name.
+#[derive(Clone, Debug, Default)]
+pub struct RuntimeFilterInfo {
+    /// `column >= min and column <= max` of the build keys,
+    /// checked against the column statistics of blocks.
+    pub min_max: Vec<Expr<String>>,
+    /// `column = v1 or column = v2 ...` of the build keys if there are few distinct values,
+    /// checked against the bloom index of blocks.
+    pub inlist: Vec<Expr<String>>,
+}
+
+impl RuntimeFilterInfo {
+    pub fn add_min_max(&mut self, expr: Expr<String>) {
+        self.min_max.push(expr);
+    }
+
+    pub fn add_inlist(&mut self, expr: Expr<String>) {
+        self.inlist.push(expr);
+    }
+
+    pub fn merge(&mut self, other: RuntimeFilterInfo) {
+        self.min_max.extend(other.min_max);
+        self.inlist.extend(other.inlist);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min_max.is_empty() && self.inlist.is_empty()
+    }
+}
@@ -27,6 +27,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_expression::FunctionContext;
+use common_expression::Scalar;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::OnErrorMode;
@@ -51,7 +52,9 @@ use crate::plan::DataSourcePlan;
 use crate::plan::PartInfoPtr;
 use crate::plan::Partitions;
 use crate::query_kind::QueryKind;
+use crate::runtime_filter_info::RuntimeFilterInfo;
 use crate::table::Table;
+use crate::txn::TxnManagerRef;
 
 pub type MaterializedCtesBlocks = Arc<RwLock<HashMap<(usize, usize), Arc<RwLock<Vec<DataBlock>>>>>>;
 
@@ -91,6 +94,15 @@ impl Display for ProcessInfoState {
     }
 }
 
+/// A query waiting in the query queue for its turn to run.
+#[derive(Debug, Clone)]
+pub struct QueuedQueryInfo {
+    pub query_id: String,
+    pub user: String,
+    pub query_text: String,
+    pub queued_time: SystemTime,
+}
+
 #[derive(Debug, Clone)]
 pub struct StageAttachment {
     pub location: String,
@@ -164,11 +176,14 @@ pub trait TableContext: Send + Sync {
     fn get_shard_settings(&self) -> Arc<Settings>;
     fn get_cluster(&self) -> Arc<Cluster>;
     fn get_processes_info(&self) -> Vec<ProcessInfo>;
+    fn get_queued_queries(&self) -> Vec<QueuedQueryInfo>;
     fn get_stage_attachment(&self) -> Option<StageAttachment>;
     fn get_last_query_id(&self, index: i32) -> String;
     fn get_query_id_history(&self) -> HashSet<String>;
     fn get_result_cache_key(&self, query_id: &str) -> Option<String>;
     fn set_query_id_result_cache(&self, query_id: String, result_cache_key: String);
+    fn set_variable(&self, name: String, value: Scalar);
+    fn get_variable(&self, name: &str) -> Option<Scalar>;
     fn get_on_error_map(&self) -> Option<Arc<DashMap<String, HashMap<u16, InputError>>>>;
     fn set_on_error_map(&self, map: Arc<DashMap<String, HashMap<u16, InputError>>>);
     fn get_on_error_mode(&self) -> Option<OnErrorMode>;
@@ -208,6 +223,12 @@ pub trait TableContext: Send + Sync {
 
     fn get_materialized_ctes(&self) -> MaterializedCtesBlocks;
 
+    /// Add runtime filters for the table scan with `scan_id`,
+    /// the filters are merged with the ones set before.
+    fn set_runtime_filter(&self, scan_id: usize, filter: RuntimeFilterInfo);
+
+    fn get_runtime_filter(&self, scan_id: usize) -> Option<RuntimeFilterInfo>;
+
     fn add_segment_location(&self, segment_loc: Location) -> Result<()>;
 
     fn get_segment_locations(&self) -> Result<Vec<Location>>;
@@ -218,4 +239,7 @@ pub trait TableContext: Send + Sync {
 
     /// Get license key from context, return empty if license is not found or error happened.
     fn get_license_key(&self) -> String;
+
+    /// Get the transaction manager of the current session.
+    fn txn_mgr(&self) -> TxnManagerRef;
 }
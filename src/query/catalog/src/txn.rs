@@ -0,0 +1,149 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
+use common_meta_app::schema::UpdateTableMetaReq;
+use parking_lot::Mutex;
+
+pub type TxnManagerRef = Arc<Mutex<TxnManager>>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TxnState {
+    /// Every statement is committed once it finishes.
+    AutoCommit,
+    /// In an explicit transaction started by `BEGIN`.
+    Active,
+    /// A statement of the explicit transaction failed,
+    /// the transaction can only be ended by `COMMIT` or `ROLLBACK`.
+    Fail,
+}
+
+/// The table metas mutated in an explicit transaction,
+/// they are only visible to the session until the transaction is committed.
+#[derive(Default)]
+struct TxnBuffer {
+    // table id -> (staged table info, update request)
+    mutated_tables: HashMap<u64, (TableInfo, UpdateTableMetaReq)>,
+}
+
+impl TxnBuffer {
+    fn upsert_table_meta(&mut self, table_info: &TableInfo, req: UpdateTableMetaReq) {
+        let mut staged_table_info = table_info.clone();
+        staged_table_info.meta = req.new_table_meta.clone();
+
+        let Some((staged, staged_req)) = self.mutated_tables.get_mut(&req.table_id) else {
+            self.mutated_tables
+                .insert(req.table_id, (staged_table_info, req));
+            return;
+        };
+        *staged = staged_table_info;
+
+        // Keep the seq of the first mutation, the commit fails if the table
+        // has been changed by others since the transaction read it.
+        let UpdateTableMetaReq {
+            new_table_meta,
+            copied_files,
+            deduplicated_label,
+            ..
+        } = req;
+        staged_req.new_table_meta = new_table_meta;
+        if let Some(files) = copied_files {
+            match &mut staged_req.copied_files {
+                Some(staged_files) => {
+                    staged_files.file_info.extend(files.file_info);
+                    staged_files.expire_at = files.expire_at;
+                    staged_files.fail_if_duplicated |= files.fail_if_duplicated;
+                }
+                None => staged_req.copied_files = Some(files),
+            }
+        }
+        if deduplicated_label.is_some() {
+            staged_req.deduplicated_label = deduplicated_label;
+        }
+    }
+}
+
+/// Tracks the state of the explicit transaction of a session.
+pub struct TxnManager {
+    state: TxnState,
+    txn_buffer: TxnBuffer,
+}
+
+impl TxnManager {
+    pub fn init() -> TxnManagerRef {
+        Arc::new(Mutex::new(TxnManager {
+            state: TxnState::AutoCommit,
+            txn_buffer: TxnBuffer::default(),
+        }))
+    }
+
+    pub fn begin(&mut self) {
+        if let TxnState::AutoCommit = self.state {
+            self.state = TxnState::Active
+        }
+    }
+
+    pub fn set_fail(&mut self) {
+        if let TxnState::Active = self.state {
+            self.state = TxnState::Fail;
+        }
+    }
+
+    pub fn state(&self) -> TxnState {
+        self.state
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.state == TxnState::Active
+    }
+
+    pub fn is_fail(&self) -> bool {
+        self.state == TxnState::Fail
+    }
+
+    /// Stages the new table meta instead of committing it to the meta service.
+    pub fn upsert_table_meta(&mut self, table_info: &TableInfo, req: UpdateTableMetaReq) {
+        self.txn_buffer.upsert_table_meta(table_info, req);
+    }
+
+    /// Returns the staged table info if the table is mutated in the transaction.
+    pub fn get_table_from_buffer(&self, table_id: u64) -> Option<TableInfo> {
+        self.txn_buffer
+            .mutated_tables
+            .get(&table_id)
+            .map(|(table_info, _)| table_info.clone())
+    }
+
+    /// Builds the request which publishes all the staged table metas at once.
+    pub fn req(&self) -> UpdateMultiTableMetaReq {
+        UpdateMultiTableMetaReq {
+            update_table_metas: self
+                .txn_buffer
+                .mutated_tables
+                .values()
+                .map(|(_, req)| req.clone())
+                .collect(),
+        }
+    }
+
+    /// Ends the transaction and discards all the staged table metas.
+    pub fn clear(&mut self) {
+        self.state = TxnState::AutoCommit;
+        self.txn_buffer = TxnBuffer::default();
+    }
+}
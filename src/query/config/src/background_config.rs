@@ -63,6 +63,24 @@ pub struct BackgroundCompactionConfig {
     #[clap(long, value_name = "VALUE")]
     pub block_limit: Option<u64>,
 
+    // A table is considered for compaction only if it has more blocks than this.
+    #[clap(long, value_name = "VALUE", default_value = "500")]
+    pub compact_block_count_threshold: u64,
+
+    // Compact segments if the average number of blocks per segment is less than this.
+    #[clap(long, value_name = "VALUE", default_value = "500")]
+    pub compact_segment_density_threshold: u64,
+
+    // Compact blocks if the average uncompressed size of blocks (in MB) is less than this.
+    #[clap(long, value_name = "VALUE", default_value = "50")]
+    pub compact_block_size_threshold_mb: u64,
+
+    // Only run compaction when the node is idle, i.e. the number of running queries
+    // is not greater than this.
+    // None represent the compaction runs regardless of the running queries
+    #[clap(long, value_name = "VALUE")]
+    pub compact_max_running_queries: Option<u64>,
+
     #[clap(flatten)]
     pub scheduled_config: BackgroundScheduledConfig,
 }
@@ -114,6 +132,10 @@ pub struct InnerBackgroundCompactionConfig {
     pub target_tables: Option<Vec<String>>,
     pub segment_limit: Option<u64>,
     pub block_limit: Option<u64>,
+    pub block_count_threshold: u64,
+    pub segment_density_threshold: u64,
+    pub block_size_threshold_mb: u64,
+    pub max_running_queries: Option<u64>,
     pub params: BackgroundJobParams,
 }
 
@@ -150,6 +172,10 @@ impl TryInto<InnerBackgroundCompactionConfig> for BackgroundCompactionConfig {
         Ok(InnerBackgroundCompactionConfig {
             segment_limit: self.segment_limit,
             block_limit: self.block_limit,
+            block_count_threshold: self.compact_block_count_threshold,
+            segment_density_threshold: self.compact_segment_density_threshold,
+            block_size_threshold_mb: self.compact_block_size_threshold_mb,
+            max_running_queries: self.compact_max_running_queries,
             enable: self.enable_compaction,
             target_tables: self.target_tables,
             params: {
@@ -196,6 +222,10 @@ impl From<InnerBackgroundCompactionConfig> for BackgroundCompactionConfig {
             target_tables: inner.target_tables,
             segment_limit: inner.segment_limit,
             block_limit: inner.block_limit,
+            compact_block_count_threshold: inner.block_count_threshold,
+            compact_segment_density_threshold: inner.segment_density_threshold,
+            compact_block_size_threshold_mb: inner.block_size_threshold_mb,
+            compact_max_running_queries: inner.max_running_queries,
             scheduled_config: Default::default(), // it would be set later
         };
         match inner.params.job_type {
@@ -238,6 +268,10 @@ impl Default for BackgroundCompactionConfig {
             target_tables: None,
             segment_limit: None,
             block_limit: None,
+            compact_block_count_threshold: 500,
+            compact_segment_density_threshold: 500,
+            compact_block_size_threshold_mb: 50,
+            compact_max_running_queries: None,
             scheduled_config: Default::default(),
         }
     }
@@ -249,6 +283,16 @@ impl Debug for BackgroundCompactionConfig {
             .field("mode", &self.compact_mode)
             .field("segment_limit", &self.segment_limit)
             .field("block_limit", &self.block_limit)
+            .field("block_count_threshold", &self.compact_block_count_threshold)
+            .field(
+                "segment_density_threshold",
+                &self.compact_segment_density_threshold,
+            )
+            .field(
+                "block_size_threshold_mb",
+                &self.compact_block_size_threshold_mb,
+            )
+            .field("max_running_queries", &self.compact_max_running_queries)
             .field("fixed_config", &self.scheduled_config)
             .finish()
     }
@@ -281,6 +325,10 @@ impl Default for InnerBackgroundConfig {
                 target_tables: None,
                 segment_limit: None,
                 block_limit: None,
+                block_count_threshold: 500,
+                segment_density_threshold: 500,
+                block_size_threshold_mb: 50,
+                max_running_queries: None,
                 params: Default::default(),
             },
         }
@@ -300,6 +348,10 @@ impl Debug for InnerBackgroundCompactionConfig {
         f.debug_struct("InnerBackgroundCompactionConfig")
             .field("segment_limit", &self.segment_limit)
             .field("block_limit", &self.block_limit)
+            .field("block_count_threshold", &self.block_count_threshold)
+            .field("segment_density_threshold", &self.segment_density_threshold)
+            .field("block_size_threshold_mb", &self.block_size_threshold_mb)
+            .field("max_running_queries", &self.max_running_queries)
             .field("params", &self.params)
             .finish()
     }
@@ -43,6 +43,7 @@ use common_meta_app::storage::StorageS3Config as InnerStorageS3Config;
 use common_meta_app::storage::StorageWebhdfsConfig as InnerStorageWebhdfsConfig;
 use common_meta_app::tenant::TenantQuota;
 use common_storage::StorageConfig as InnerStorageConfig;
+use common_storage::StorageIOPolicy;
 use common_tracing::Config as InnerLogConfig;
 use common_tracing::FileConfig as InnerFileLogConfig;
 use common_tracing::QueryLogConfig as InnerQueryLogConfig;
@@ -267,6 +268,47 @@ pub struct StorageConfig {
     #[clap(long = "storage-allow-insecure")]
     pub allow_insecure: bool,
 
+    /// Max retry times of a storage operation failed with a temporary error.
+    #[clap(
+        long = "storage-io-retry-max-times",
+        value_name = "VALUE",
+        default_value = "3"
+    )]
+    pub io_retry_max_times: u64,
+
+    /// The delay before the first retry, doubled for each following retry.
+    #[clap(
+        long = "storage-io-retry-min-delay-ms",
+        value_name = "VALUE",
+        default_value = "1000"
+    )]
+    pub io_retry_min_delay_ms: u64,
+
+    /// The max delay between two retries.
+    #[clap(
+        long = "storage-io-retry-max-delay-ms",
+        value_name = "VALUE",
+        default_value = "60000"
+    )]
+    pub io_retry_max_delay_ms: u64,
+
+    /// Timeout of a single attempt of a storage operation.
+    #[clap(
+        long = "storage-io-timeout-secs",
+        value_name = "VALUE",
+        default_value = "60"
+    )]
+    pub io_timeout_secs: u64,
+
+    /// Send a hedged request for a block read which has not responded within this delay,
+    /// 0 means hedged read is disabled.
+    #[clap(
+        long = "storage-io-hedged-read-delay-ms",
+        value_name = "VALUE",
+        default_value = "0"
+    )]
+    pub io_hedged_read_delay_ms: u64,
+
     // Fs storage backend config.
     #[clap(flatten)]
     pub fs: FsStorageConfig,
@@ -316,6 +358,11 @@ impl From<InnerStorageConfig> for StorageConfig {
             storage_num_cpus: inner.num_cpus,
             typ: "".to_string(),
             allow_insecure: inner.allow_insecure,
+            io_retry_max_times: inner.io_policy.retry_max_times,
+            io_retry_min_delay_ms: inner.io_policy.retry_min_delay_ms,
+            io_retry_max_delay_ms: inner.io_policy.retry_max_delay_ms,
+            io_timeout_secs: inner.io_policy.timeout_secs,
+            io_hedged_read_delay_ms: inner.io_policy.hedged_read_delay_ms,
             // use default for each config instead of using `..Default::default`
             // using `..Default::default` is calling `Self::default`
             // and `Self::default` relies on `InnerStorage::into()`
@@ -401,6 +448,13 @@ impl TryInto<InnerStorageConfig> for StorageConfig {
         Ok(InnerStorageConfig {
             num_cpus: self.storage_num_cpus,
             allow_insecure: self.allow_insecure,
+            io_policy: StorageIOPolicy {
+                retry_max_times: self.io_retry_max_times,
+                retry_min_delay_ms: self.io_retry_min_delay_ms,
+                retry_max_delay_ms: self.io_retry_max_delay_ms,
+                timeout_secs: self.io_timeout_secs,
+                hedged_read_delay_ms: self.io_hedged_read_delay_ms,
+            },
             params: {
                 match self.typ.as_str() {
                     "azblob" => StorageParams::Azblob(self.azblob.try_into()?),
@@ -1406,6 +1460,19 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "256")]
     pub max_active_sessions: u64,
 
+    /// The max number of queries running concurrently on this node, 0 means no limit.
+    /// Queries beyond the limit wait in the query queue.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub max_running_queries: u64,
+
+    /// The max number of queries of a single user running concurrently on this node, 0 means no limit.
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub max_running_queries_per_user: u64,
+
+    /// Seconds a query may wait in the query queue before it fails.
+    #[clap(long, value_name = "VALUE", default_value = "60")]
+    pub query_queue_timeout_secs: u64,
+
     /// The max total memory in bytes that can be used by this process.
     #[clap(long, value_name = "VALUE", default_value = "0")]
     pub max_server_memory_usage: u64,
@@ -1442,6 +1509,12 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "8900")]
     pub flight_sql_handler_port: u16,
 
+    #[clap(long, value_name = "VALUE", default_value = "127.0.0.1")]
+    pub postgres_handler_host: String,
+
+    #[clap(long, value_name = "VALUE", default_value = "15432")]
+    pub postgres_handler_port: u16,
+
     #[clap(long, value_name = "VALUE", default_value = "127.0.0.1:9090")]
     pub flight_api_address: String,
 
@@ -1502,6 +1575,32 @@ pub struct QueryConfig {
 
     #[clap(long, value_name = "VALUE", default_value = "10000")]
     pub max_query_log_size: usize,
+
+    /// Persist query log events into the fuse table `system_history.query_log`,
+    /// so they outlive the in-memory `system.query_log` queue.
+    #[clap(long)]
+    pub query_log_persist_enabled: bool,
+
+    /// Interval in seconds between two flushes of the persisted query log.
+    #[clap(long, value_name = "VALUE", default_value = "10")]
+    pub query_log_persist_interval_secs: u64,
+
+    /// Stream query log events to an external sink: "table", "ndjson" or "otlp".
+    /// Empty disables the sink.
+    #[clap(long, value_name = "VALUE", default_value = "")]
+    pub query_log_sink_type: String,
+
+    /// Destination of the query log sink:
+    /// - table: `<database>.<table>`, created if not exists
+    /// - ndjson: path prefix in the data storage, default `_query_log`
+    /// - otlp: OTLP/HTTP logs endpoint, e.g. `http://collector:4318/v1/logs`
+    #[clap(long, value_name = "VALUE", default_value = "")]
+    pub query_log_sink_target: String,
+
+    /// Interval in seconds between two flushes of the query log sink.
+    #[clap(long, value_name = "VALUE", default_value = "10")]
+    pub query_log_sink_interval_secs: u64,
+
     /// Parquet file with smaller size will be read as a whole file, instead of column by column.
     /// For example:
     /// parquet_fast_read_bytes = 52428800
@@ -1527,6 +1626,50 @@ pub struct QueryConfig {
     #[clap(skip)]
     pub jwt_key_files: Vec<String>,
 
+    /// The required `iss` claim of jwt, empty means not checked.
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub jwt_issuer: String,
+
+    /// The required `aud` claim of jwt, empty means not checked.
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub jwt_audience: String,
+
+    /// The jwt claim holding the user name, like `email` or `preferred_username` of OIDC.
+    #[clap(long, value_name = "VALUE", default_value = "sub")]
+    pub jwt_user_claim: String,
+
+    /// The jwt claim holding the roles granted to the session, a role name or an array of them.
+    /// Empty means no roles are taken from the claims.
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub jwt_roles_claim: String,
+
+    /// The LDAP server url for users with auth type ldap, like `ldap://127.0.0.1:389`.
+    /// Empty means LDAP authentication is disabled.
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub ldap_url: String,
+
+    /// The DN to bind as when verifying a password, `{user}` is replaced by the user name.
+    /// For example `uid={user},ou=people,dc=example,dc=com`.
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub ldap_bind_dn_template: String,
+
+    /// The base DN to search the groups of a user in, empty means no group-to-role mapping.
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub ldap_group_search_base: String,
+
+    /// The filter to search the groups of a user, `{dn}` and `{user}` are replaced
+    /// by the bind DN and the user name.
+    #[clap(long, value_name = "VALUE", default_value = "(member={dn})")]
+    pub ldap_group_search_filter: String,
+
+    /// Map LDAP groups to roles, each entry is `<group cn>=<role name>`.
+    #[clap(skip)]
+    pub ldap_group_role_mapping: Vec<String>,
+
+    /// The max number of idle connections kept to the LDAP server.
+    #[clap(long, value_name = "VALUE", default_value = "8")]
+    pub ldap_pool_size: u64,
+
     #[clap(long, value_name = "VALUE", default_value = "auto")]
     pub default_storage_format: String,
 
@@ -1672,6 +1815,9 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             mysql_tls_server_cert: self.mysql_tls_server_cert,
             mysql_tls_server_key: self.mysql_tls_server_key,
             max_active_sessions: self.max_active_sessions,
+            max_running_queries: self.max_running_queries,
+            max_running_queries_per_user: self.max_running_queries_per_user,
+            query_queue_timeout_secs: self.query_queue_timeout_secs,
             max_server_memory_usage: self.max_server_memory_usage,
             max_memory_limit_enabled: self.max_memory_limit_enabled,
             clickhouse_http_handler_host: self.clickhouse_http_handler_host,
@@ -1682,6 +1828,8 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             flight_api_address: self.flight_api_address,
             flight_sql_handler_host: self.flight_sql_handler_host,
             flight_sql_handler_port: self.flight_sql_handler_port,
+            postgres_handler_host: self.postgres_handler_host,
+            postgres_handler_port: self.postgres_handler_port,
             admin_api_address: self.admin_api_address,
             metric_api_address: self.metric_api_address,
             http_handler_tls_server_cert: self.http_handler_tls_server_cert,
@@ -1700,12 +1848,27 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             table_engine_memory_enabled: self.table_engine_memory_enabled,
             wait_timeout_mills: self.wait_timeout_mills,
             max_query_log_size: self.max_query_log_size,
+            query_log_persist_enabled: self.query_log_persist_enabled,
+            query_log_persist_interval_secs: self.query_log_persist_interval_secs,
+            query_log_sink_type: self.query_log_sink_type,
+            query_log_sink_target: self.query_log_sink_target,
+            query_log_sink_interval_secs: self.query_log_sink_interval_secs,
             databend_enterprise_license: self.databend_enterprise_license,
             management_mode: self.management_mode,
             parquet_fast_read_bytes: self.parquet_fast_read_bytes,
             max_storage_io_requests: self.max_storage_io_requests,
             jwt_key_file: self.jwt_key_file,
             jwt_key_files: self.jwt_key_files,
+            jwt_issuer: self.jwt_issuer,
+            jwt_audience: self.jwt_audience,
+            jwt_user_claim: self.jwt_user_claim,
+            jwt_roles_claim: self.jwt_roles_claim,
+            ldap_url: self.ldap_url,
+            ldap_bind_dn_template: self.ldap_bind_dn_template,
+            ldap_group_search_base: self.ldap_group_search_base,
+            ldap_group_search_filter: self.ldap_group_search_filter,
+            ldap_group_role_mapping: self.ldap_group_role_mapping,
+            ldap_pool_size: self.ldap_pool_size,
             default_storage_format: self.default_storage_format,
             default_compression: self.default_compression,
             idm: InnerIDMConfig {
@@ -1744,6 +1907,9 @@ impl From<InnerQueryConfig> for QueryConfig {
             mysql_tls_server_cert: inner.mysql_tls_server_cert,
             mysql_tls_server_key: inner.mysql_tls_server_key,
             max_active_sessions: inner.max_active_sessions,
+            max_running_queries: inner.max_running_queries,
+            max_running_queries_per_user: inner.max_running_queries_per_user,
+            query_queue_timeout_secs: inner.query_queue_timeout_secs,
             max_server_memory_usage: inner.max_server_memory_usage,
             max_memory_limit_enabled: inner.max_memory_limit_enabled,
 
@@ -1759,6 +1925,8 @@ impl From<InnerQueryConfig> for QueryConfig {
             flight_api_address: inner.flight_api_address,
             flight_sql_handler_host: inner.flight_sql_handler_host,
             flight_sql_handler_port: inner.flight_sql_handler_port,
+            postgres_handler_host: inner.postgres_handler_host,
+            postgres_handler_port: inner.postgres_handler_port,
             admin_api_address: inner.admin_api_address,
             metric_api_address: inner.metric_api_address,
             http_handler_tls_server_cert: inner.http_handler_tls_server_cert,
@@ -1777,12 +1945,27 @@ impl From<InnerQueryConfig> for QueryConfig {
             table_engine_memory_enabled: inner.table_engine_memory_enabled,
             wait_timeout_mills: inner.wait_timeout_mills,
             max_query_log_size: inner.max_query_log_size,
+            query_log_persist_enabled: inner.query_log_persist_enabled,
+            query_log_persist_interval_secs: inner.query_log_persist_interval_secs,
+            query_log_sink_type: inner.query_log_sink_type,
+            query_log_sink_target: inner.query_log_sink_target,
+            query_log_sink_interval_secs: inner.query_log_sink_interval_secs,
             databend_enterprise_license: inner.databend_enterprise_license,
             management_mode: inner.management_mode,
             parquet_fast_read_bytes: inner.parquet_fast_read_bytes,
             max_storage_io_requests: inner.max_storage_io_requests,
             jwt_key_file: inner.jwt_key_file,
             jwt_key_files: inner.jwt_key_files,
+            jwt_issuer: inner.jwt_issuer,
+            jwt_audience: inner.jwt_audience,
+            jwt_user_claim: inner.jwt_user_claim,
+            jwt_roles_claim: inner.jwt_roles_claim,
+            ldap_url: inner.ldap_url,
+            ldap_bind_dn_template: inner.ldap_bind_dn_template,
+            ldap_group_search_base: inner.ldap_group_search_base,
+            ldap_group_search_filter: inner.ldap_group_search_filter,
+            ldap_group_role_mapping: inner.ldap_group_role_mapping,
+            ldap_pool_size: inner.ldap_pool_size,
             default_storage_format: inner.default_storage_format,
             default_compression: inner.default_compression,
             users: users_from_inner(inner.idm.users),
@@ -2310,6 +2493,7 @@ impl TryInto<AuthInfo> for UserAuthConfig {
         match auth_type {
             AuthType::NoPassword => check_no_auth_string(self.auth_string, AuthInfo::None),
             AuthType::JWT => check_no_auth_string(self.auth_string, AuthInfo::JWT),
+            AuthType::Ldap => check_no_auth_string(self.auth_string, AuthInfo::Ldap),
             AuthType::Sha256Password | AuthType::DoubleSha1Password => {
                 let password_type = auth_type.get_password_type().expect("must success");
                 match self.auth_string {
@@ -159,6 +159,10 @@ pub struct QueryConfig {
     pub mysql_tls_server_cert: String,
     pub mysql_tls_server_key: String,
     pub max_active_sessions: u64,
+    /// Max queries running concurrently, the others wait in the query queue. 0 means no limit.
+    pub max_running_queries: u64,
+    pub max_running_queries_per_user: u64,
+    pub query_queue_timeout_secs: u64,
     pub max_server_memory_usage: u64,
     pub max_memory_limit_enabled: bool,
     pub clickhouse_http_handler_host: String,
@@ -169,6 +173,8 @@ pub struct QueryConfig {
     pub flight_api_address: String,
     pub flight_sql_handler_host: String,
     pub flight_sql_handler_port: u16,
+    pub postgres_handler_host: String,
+    pub postgres_handler_port: u16,
     pub admin_api_address: String,
     pub metric_api_address: String,
     pub http_handler_tls_server_cert: String,
@@ -191,6 +197,13 @@ pub struct QueryConfig {
     pub table_engine_memory_enabled: bool,
     pub wait_timeout_mills: u64,
     pub max_query_log_size: usize,
+    /// Persist query log events into the fuse table `system_history.query_log`.
+    pub query_log_persist_enabled: bool,
+    pub query_log_persist_interval_secs: u64,
+    /// External query log sink type: "table", "ndjson" or "otlp", empty to disable.
+    pub query_log_sink_type: String,
+    pub query_log_sink_target: String,
+    pub query_log_sink_interval_secs: u64,
     pub databend_enterprise_license: Option<String>,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
     pub management_mode: bool,
@@ -200,6 +213,17 @@ pub struct QueryConfig {
 
     pub jwt_key_file: String,
     pub jwt_key_files: Vec<String>,
+    pub jwt_issuer: String,
+    pub jwt_audience: String,
+    pub jwt_user_claim: String,
+    pub jwt_roles_claim: String,
+    pub ldap_url: String,
+    pub ldap_bind_dn_template: String,
+    pub ldap_group_search_base: String,
+    pub ldap_group_search_filter: String,
+    /// `<group cn>=<role name>` entries.
+    pub ldap_group_role_mapping: Vec<String>,
+    pub ldap_pool_size: u64,
     pub default_storage_format: String,
     pub default_compression: String,
     pub idm: IDMConfig,
@@ -238,6 +262,9 @@ impl Default for QueryConfig {
             mysql_tls_server_cert: "".to_string(),
             mysql_tls_server_key: "".to_string(),
             max_active_sessions: 256,
+            max_running_queries: 0,
+            max_running_queries_per_user: 0,
+            query_queue_timeout_secs: 60,
             max_server_memory_usage: 0,
             max_memory_limit_enabled: false,
             clickhouse_http_handler_host: "127.0.0.1".to_string(),
@@ -248,6 +275,8 @@ impl Default for QueryConfig {
             flight_api_address: "127.0.0.1:9090".to_string(),
             flight_sql_handler_host: "127.0.0.1".to_string(),
             flight_sql_handler_port: 8900,
+            postgres_handler_host: "127.0.0.1".to_string(),
+            postgres_handler_port: 15432,
             admin_api_address: "127.0.0.1:8080".to_string(),
             metric_api_address: "127.0.0.1:7070".to_string(),
             api_tls_server_cert: "".to_string(),
@@ -265,12 +294,27 @@ impl Default for QueryConfig {
             table_engine_memory_enabled: true,
             wait_timeout_mills: 5000,
             max_query_log_size: 10_000,
+            query_log_persist_enabled: false,
+            query_log_persist_interval_secs: 10,
+            query_log_sink_type: "".to_string(),
+            query_log_sink_target: "".to_string(),
+            query_log_sink_interval_secs: 10,
             databend_enterprise_license: None,
             management_mode: false,
             parquet_fast_read_bytes: None,
             max_storage_io_requests: None,
             jwt_key_file: "".to_string(),
             jwt_key_files: Vec::new(),
+            jwt_issuer: "".to_string(),
+            jwt_audience: "".to_string(),
+            jwt_user_claim: "sub".to_string(),
+            jwt_roles_claim: "".to_string(),
+            ldap_url: "".to_string(),
+            ldap_bind_dn_template: "".to_string(),
+            ldap_group_search_base: "".to_string(),
+            ldap_group_search_filter: "(member={dn})".to_string(),
+            ldap_group_role_mapping: Vec::new(),
+            ldap_pool_size: 8,
             default_storage_format: "auto".to_string(),
             default_compression: "auto".to_string(),
             idm: IDMConfig::default(),
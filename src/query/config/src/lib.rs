@@ -35,6 +35,7 @@ mod mask;
 mod obsolete;
 mod version;
 
+pub use background_config::InnerBackgroundCompactionConfig;
 pub use config::CacheStorageTypeConfig;
 pub use config::Commands;
 pub use config::Config;
@@ -25,6 +25,8 @@ use common_base::base::tokio::sync::mpsc::Sender;
 use common_base::base::tokio::sync::Mutex;
 use common_base::base::tokio::time::Instant;
 use common_base::base::uuid::Uuid;
+use common_catalog::table_context::ProcessInfoState;
+use common_config::InnerBackgroundCompactionConfig;
 use common_config::InnerConfig;
 use common_exception::Result;
 use common_meta_api::BackgroundApi;
@@ -46,6 +48,7 @@ use common_meta_store::MetaStore;
 use common_users::UserApiProvider;
 use databend_query::sessions::QueryContext;
 use databend_query::sessions::Session;
+use databend_query::sessions::SessionManager;
 use databend_query::table_functions::SuggestedBackgroundTasksSource;
 use log::as_debug;
 use log::debug;
@@ -55,10 +58,6 @@ use log::info;
 use crate::background_service::job::Job;
 use crate::background_service::session::create_session;
 
-const BLOCK_COUNT: u64 = 500;
-const PER_SEGMENT_BLOCK: u64 = 500;
-const PER_BLOCK_SIZE: u64 = 50; // MB
-
 const EXPIRE_SEC: u64 = 60 * 60 * 24 * 7; // 7 days
 
 #[derive(Clone)]
@@ -114,7 +113,11 @@ impl Job for CompactionJob {
 }
 
 // continue to compact
-pub fn should_continue_compaction(old: &TableStatistics, new: &TableStatistics) -> (bool, bool) {
+pub fn should_continue_compaction(
+    conf: &InnerBackgroundCompactionConfig,
+    old: &TableStatistics,
+    new: &TableStatistics,
+) -> (bool, bool) {
     if old.number_of_blocks.is_none()
         || old.number_of_segments.is_none()
         || new.number_of_blocks.is_none()
@@ -127,13 +130,13 @@ pub fn should_continue_compaction(old: &TableStatistics, new: &TableStatistics)
     let new_segment_density =
         new.number_of_blocks.unwrap() as f64 / new.number_of_segments.unwrap() as f64;
     let should_continue_seg_compact = new_segment_density != old_segment_density
-        && new.number_of_blocks.unwrap() > BLOCK_COUNT
-        && new_segment_density < PER_SEGMENT_BLOCK as f64;
+        && new.number_of_blocks.unwrap() > conf.block_count_threshold
+        && new_segment_density < conf.segment_density_threshold as f64;
     let old_block_density = old.data_bytes as f64 / old.number_of_blocks.unwrap() as f64;
     let new_block_density = new.data_bytes as f64 / new.number_of_blocks.unwrap() as f64;
     let should_continue_blk_compact = new_block_density != old_block_density
-        && new.number_of_blocks.unwrap() > BLOCK_COUNT
-        && new_block_density < PER_BLOCK_SIZE as f64 * 1024.0 * 1024.0;
+        && new.number_of_blocks.unwrap() > conf.block_count_threshold
+        && new_block_density < conf.block_size_threshold_mb as f64 * 1024.0 * 1024.0;
     (should_continue_seg_compact, should_continue_blk_compact)
 }
 
@@ -192,6 +195,10 @@ impl CompactionJob {
                 let db_id = db_ids.value(i);
                 let tb_name = String::from_utf8_lossy(tb_names.value(i)).to_string();
                 let tb_id = tb_ids.value(i);
+                if !Self::is_idle(&self.conf) {
+                    info!(job = "compaction", background = true, database = db_name.clone(), table = tb_name.clone(); "skip compact, the node is busy");
+                    continue;
+                }
                 match self
                     .compact_table(
                         session.clone(),
@@ -231,6 +238,22 @@ impl CompactionJob {
         Ok(())
     }
 
+    /// The compaction only runs when the node is idle, to avoid competing for resources
+    /// with the running queries.
+    pub fn is_idle(conf: &InnerConfig) -> bool {
+        match conf.background.compaction.max_running_queries {
+            None => true,
+            Some(max_running_queries) => {
+                let running_queries = SessionManager::instance()
+                    .processes_info()
+                    .iter()
+                    .filter(|info| info.state == ProcessInfoState::Query)
+                    .count();
+                running_queries as u64 <= max_running_queries
+            }
+        }
+    }
+
     fn set_task_status(info: &mut BackgroundTaskInfo, state: BackgroundTaskState) {
         info.task_state = state;
         info.last_updated = Some(Utc::now());
@@ -282,9 +305,9 @@ impl CompactionJob {
             session.clone(),
             database.clone(),
             table.clone(),
-            BLOCK_COUNT,
-            PER_SEGMENT_BLOCK,
-            PER_BLOCK_SIZE,
+            self.conf.background.compaction.block_count_threshold,
+            self.conf.background.compaction.segment_density_threshold,
+            self.conf.background.compaction.block_size_threshold_mb,
         )
         .await?;
         let (seg, blk, stats) = if !self.conf.background.compaction.has_target_tables() {
@@ -345,9 +368,9 @@ impl CompactionJob {
                     session.clone(),
                     database.clone(),
                     table.clone(),
-                    BLOCK_COUNT,
-                    PER_SEGMENT_BLOCK,
-                    PER_BLOCK_SIZE,
+                    self.conf.background.compaction.block_count_threshold,
+                    self.conf.background.compaction.segment_density_threshold,
+                    self.conf.background.compaction.block_size_threshold_mb,
                 )
                 .await?;
                 Self::set_task_stats(&mut info, new_stats.clone(), start.elapsed());
@@ -388,9 +411,9 @@ impl CompactionJob {
             session.clone(),
             database.clone(),
             table.clone(),
-            BLOCK_COUNT,
-            PER_SEGMENT_BLOCK,
-            PER_BLOCK_SIZE,
+            self.conf.background.compaction.block_count_threshold,
+            self.conf.background.compaction.segment_density_threshold,
+            self.conf.background.compaction.block_size_threshold_mb,
         )
         .await?;
         if !seg && !blk {
@@ -411,12 +434,12 @@ impl CompactionJob {
                     session.clone(),
                     database.clone(),
                     table.clone(),
-                    BLOCK_COUNT,
-                    PER_SEGMENT_BLOCK,
-                    PER_BLOCK_SIZE,
+                    self.conf.background.compaction.block_count_threshold,
+                    self.conf.background.compaction.segment_density_threshold,
+                    self.conf.background.compaction.block_size_threshold_mb,
                 )
                 .await?;
-                if !should_continue_compaction(&old, &new).0 {
+                if !should_continue_compaction(&self.conf.background.compaction, &old, &new).0 {
                     old = new;
                     break;
                 }
@@ -437,12 +460,12 @@ impl CompactionJob {
                     session.clone(),
                     database.clone(),
                     table.clone(),
-                    BLOCK_COUNT,
-                    PER_SEGMENT_BLOCK,
-                    PER_BLOCK_SIZE,
+                    self.conf.background.compaction.block_count_threshold,
+                    self.conf.background.compaction.segment_density_threshold,
+                    self.conf.background.compaction.block_size_threshold_mb,
                 )
                 .await?;
-                if !should_continue_compaction(&old, &new).1 {
+                if !should_continue_compaction(&self.conf.background.compaction, &old, &new).1 {
                     break;
                 }
                 old = new;
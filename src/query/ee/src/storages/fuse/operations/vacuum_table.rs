@@ -24,6 +24,7 @@ use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_sql::BloomIndexColumns;
 use common_storages_fuse::io::MetaReaders;
 use common_storages_fuse::io::SnapshotLiteExtended;
 use common_storages_fuse::io::SnapshotsIO;
@@ -41,6 +42,7 @@ pub struct SnapshotReferencedFiles {
     pub segments: HashSet<String>,
     pub blocks: HashSet<String>,
     pub blocks_index: HashSet<String>,
+    pub inverted_index: HashSet<String>,
 }
 
 impl SnapshotReferencedFiles {
@@ -55,6 +57,9 @@ impl SnapshotReferencedFiles {
         for file in &self.blocks_index {
             files.push(file.clone());
         }
+        for file in &self.inverted_index {
+            files.push(file.clone());
+        }
         files
     }
 }
@@ -129,10 +134,25 @@ pub async fn get_snapshot_referenced_files(
     segments_vec.into_iter().for_each(|(location, _)| {
         segments.insert(location);
     });
+    // inverted index files are only generated for the tables with inverted index columns.
+    let inverted_index = if matches!(fuse_table.inverted_index_cols(), BloomIndexColumns::None) {
+        HashSet::new()
+    } else {
+        locations_referenced
+            .block_location
+            .iter()
+            .map(|location| {
+                TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                    location,
+                )
+            })
+            .collect()
+    };
     Ok(Some(SnapshotReferencedFiles {
         segments,
         blocks: locations_referenced.block_location,
         blocks_index: locations_referenced.bloom_location,
+        inverted_index,
     }))
 }
 
@@ -266,6 +286,33 @@ pub async fn do_gc_orphan_files(
     );
     ctx.set_status_info(&status);
 
+    // 5. Purge orphan inverted index files.
+    // 5.1 Get orphan inverted index files to be purged
+    let inverted_index_locations_to_be_purged =
+        get_orphan_files_to_be_purged(fuse_table, referenced_files.inverted_index, retention_time)
+            .await?;
+    let status = format!(
+        "gc orphan: read inverted_index_locations_to_be_purged:{}, cost:{} sec",
+        inverted_index_locations_to_be_purged.len(),
+        start.elapsed().as_secs()
+    );
+    ctx.set_status_info(&status);
+
+    // 5.2 Delete all the orphan inverted index files to be purged
+    let purged_file_num = inverted_index_locations_to_be_purged.len();
+    fuse_table
+        .try_purge_location_files(
+            ctx.clone(),
+            HashSet::from_iter(inverted_index_locations_to_be_purged.into_iter()),
+        )
+        .await?;
+    let status = format!(
+        "gc orphan: purged inverted index files:{}, cost:{} sec",
+        purged_file_num,
+        start.elapsed().as_secs()
+    );
+    ctx.set_status_info(&status);
+
     Ok(())
 }
 
@@ -334,6 +381,22 @@ pub async fn do_dry_run_orphan_files(
     ctx.set_status_info(&status);
 
     purge_files.extend(index_locations_to_be_purged);
+    if purge_files.len() >= dry_run_limit {
+        return Ok(());
+    }
+
+    // 5. Get purge orphan inverted index files.
+    let inverted_index_locations_to_be_purged =
+        get_orphan_files_to_be_purged(fuse_table, referenced_files.inverted_index, retention_time)
+            .await?;
+    let status = format!(
+        "dry_run orphan: read inverted_index_locations_to_be_purged:{}, cost:{} sec",
+        inverted_index_locations_to_be_purged.len(),
+        start.elapsed().as_secs()
+    );
+    ctx.set_status_info(&status);
+
+    purge_files.extend(inverted_index_locations_to_be_purged);
 
     Ok(())
 }
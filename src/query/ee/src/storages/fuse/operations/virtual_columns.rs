@@ -45,23 +45,27 @@ use jsonb::jsonpath::Mode as SelectorMode;
 use jsonb::jsonpath::Selector;
 use opendal::Operator;
 use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::Location;
 
 #[async_backtrace::framed]
 pub async fn do_refresh_virtual_column(
     fuse_table: &FuseTable,
     ctx: Arc<dyn TableContext>,
     virtual_columns: Vec<String>,
+    segment_locs: Option<Vec<Location>>,
 ) -> Result<()> {
     if virtual_columns.is_empty() {
         return Ok(());
     }
 
-    let snapshot_opt = fuse_table.read_table_snapshot().await?;
-    let snapshot = if let Some(val) = snapshot_opt {
-        val
-    } else {
-        // no snapshot
-        return Ok(());
+    // Only the blocks of the given segments need to be materialized, e.g. the newly written ones.
+    let segment_locs = match segment_locs {
+        Some(segment_locs) => segment_locs,
+        None => match fuse_table.read_table_snapshot().await? {
+            Some(snapshot) => snapshot.segments.clone(),
+            // no snapshot
+            None => return Ok(()),
+        },
     };
 
     let table_schema = &fuse_table.get_table_info().meta.schema;
@@ -105,7 +109,7 @@ pub async fn do_refresh_virtual_column(
 
     let operator = fuse_table.get_operator_ref();
 
-    for (location, ver) in &snapshot.segments {
+    for (location, ver) in &segment_locs {
         let segment_info = segment_reader
             .read(&LoadParams {
                 location: location.to_string(),
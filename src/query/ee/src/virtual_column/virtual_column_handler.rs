@@ -27,6 +27,7 @@ use common_meta_app::schema::UpdateVirtualColumnReply;
 use common_meta_app::schema::UpdateVirtualColumnReq;
 use common_meta_app::schema::VirtualColumnMeta;
 use common_storages_fuse::FuseTable;
+use storages_common_table_meta::meta::Location;
 use virtual_column::VirtualColumnHandler;
 use virtual_column::VirtualColumnHandlerWrapper;
 
@@ -77,8 +78,9 @@ impl VirtualColumnHandler for RealVirtualColumnHandler {
         fuse_table: &FuseTable,
         ctx: Arc<dyn TableContext>,
         virtual_columns: Vec<String>,
+        segment_locs: Option<Vec<Location>>,
     ) -> Result<()> {
-        do_refresh_virtual_column(fuse_table, ctx, virtual_columns).await
+        do_refresh_virtual_column(fuse_table, ctx, virtual_columns, segment_locs).await
     }
 }
 
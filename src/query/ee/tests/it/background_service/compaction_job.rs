@@ -15,6 +15,8 @@
 use core::default::Default;
 
 use common_base::base::tokio;
+use common_config::InnerBackgroundCompactionConfig;
+use common_config::InnerConfig;
 use common_exception::Result;
 use common_meta_app::schema::TableStatistics;
 use enterprise_query::background_service::should_continue_compaction;
@@ -116,6 +118,7 @@ async fn test_get_target_table_from_configs() -> Result<()> {
 
 #[tokio::test(flavor = "multi_thread")]
 async fn test_should_continue_compaction() -> Result<()> {
+    let conf = InnerConfig::default().background.compaction;
     let old = TableStatistics {
         number_of_blocks: None,
         ..Default::default()
@@ -124,7 +127,10 @@ async fn test_should_continue_compaction() -> Result<()> {
         number_of_blocks: Some(100),
         ..Default::default()
     };
-    assert_eq!(should_continue_compaction(&old, &new), (false, false));
+    assert_eq!(
+        should_continue_compaction(&conf, &old, &new),
+        (false, false)
+    );
     let old = TableStatistics {
         number_of_blocks: Some(100),
         number_of_segments: Some(10),
@@ -135,7 +141,10 @@ async fn test_should_continue_compaction() -> Result<()> {
         number_of_segments: Some(1),
         ..Default::default()
     };
-    assert_eq!(should_continue_compaction(&old, &new), (false, false));
+    assert_eq!(
+        should_continue_compaction(&conf, &old, &new),
+        (false, false)
+    );
     let old = TableStatistics {
         number_of_blocks: Some(1002),
         number_of_segments: Some(100),
@@ -146,7 +155,7 @@ async fn test_should_continue_compaction() -> Result<()> {
         number_of_segments: Some(90),
         ..Default::default()
     };
-    assert_eq!(should_continue_compaction(&old, &new), (true, false));
+    assert_eq!(should_continue_compaction(&conf, &old, &new), (true, false));
     let old = TableStatistics {
         number_of_blocks: Some(10000),
         number_of_segments: Some(900),
@@ -159,7 +168,7 @@ async fn test_should_continue_compaction() -> Result<()> {
         data_bytes: 50 * 50 * 1024 * 1024,
         ..Default::default()
     };
-    assert_eq!(should_continue_compaction(&old, &new), (true, true));
+    assert_eq!(should_continue_compaction(&conf, &old, &new), (true, true));
     let old = TableStatistics {
         number_of_blocks: Some(10000),
         number_of_segments: Some(10),
@@ -172,6 +181,29 @@ async fn test_should_continue_compaction() -> Result<()> {
         data_bytes: 50 * 100 * 1001 * 1024 * 1024,
         ..Default::default()
     };
-    assert_eq!(should_continue_compaction(&old, &new), (false, false));
+    assert_eq!(
+        should_continue_compaction(&conf, &old, &new),
+        (false, false)
+    );
+
+    // The thresholds are configurable.
+    let old = TableStatistics {
+        number_of_blocks: Some(1002),
+        number_of_segments: Some(100),
+        ..Default::default()
+    };
+    let new = TableStatistics {
+        number_of_blocks: Some(1001),
+        number_of_segments: Some(90),
+        ..Default::default()
+    };
+    let conf = InnerBackgroundCompactionConfig {
+        block_count_threshold: 2000,
+        ..conf
+    };
+    assert_eq!(
+        should_continue_compaction(&conf, &old, &new),
+        (false, false)
+    );
     Ok(())
 }
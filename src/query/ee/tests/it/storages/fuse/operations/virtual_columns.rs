@@ -42,7 +42,7 @@ async fn test_fuse_do_refresh_virtual_column() -> Result<()> {
     let dal = fuse_table.get_operator_ref();
 
     let virtual_columns = vec!["v:a".to_string(), "v:b".to_string()];
-    do_refresh_virtual_column(fuse_table, table_ctx, virtual_columns).await?;
+    do_refresh_virtual_column(fuse_table, table_ctx, virtual_columns, None).await?;
 
     let snapshot_opt = fuse_table.read_table_snapshot().await?;
     let snapshot = snapshot_opt.unwrap();
@@ -27,6 +27,7 @@ use common_meta_app::schema::UpdateVirtualColumnReply;
 use common_meta_app::schema::UpdateVirtualColumnReq;
 use common_meta_app::schema::VirtualColumnMeta;
 use common_storages_fuse::FuseTable;
+use storages_common_table_meta::meta::Location;
 
 #[async_trait::async_trait]
 pub trait VirtualColumnHandler: Sync + Send {
@@ -54,11 +55,14 @@ pub trait VirtualColumnHandler: Sync + Send {
         req: ListVirtualColumnsReq,
     ) -> Result<Vec<VirtualColumnMeta>>;
 
+    /// Materializes the virtual columns of the blocks in `segment_locs`,
+    /// or of all the blocks of the table if `segment_locs` is `None`.
     async fn do_refresh_virtual_column(
         &self,
         fuse_table: &FuseTable,
         ctx: Arc<dyn TableContext>,
         virtual_columns: Vec<String>,
+        segment_locs: Option<Vec<Location>>,
     ) -> Result<()>;
 }
 
@@ -113,9 +117,10 @@ impl VirtualColumnHandlerWrapper {
         fuse_table: &FuseTable,
         ctx: Arc<dyn TableContext>,
         virtual_columns: Vec<String>,
+        segment_locs: Option<Vec<Location>>,
     ) -> Result<()> {
         self.handler
-            .do_refresh_virtual_column(fuse_table, ctx, virtual_columns)
+            .do_refresh_virtual_column(fuse_table, ctx, virtual_columns, segment_locs)
             .await
     }
 }
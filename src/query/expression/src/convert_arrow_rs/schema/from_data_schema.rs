@@ -23,9 +23,11 @@ use crate::infer_schema_type;
 use crate::types::DataType;
 use crate::DataField;
 use crate::DataSchema;
+use crate::ARROW_EXT_TYPE_BINARY;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
 use crate::ARROW_EXT_TYPE_VARIANT;
 use crate::EXTENSION_KEY;
 
@@ -47,6 +49,8 @@ impl From<&DataField> for ArrowField {
             DataType::EmptyMap => Some(ARROW_EXT_TYPE_EMPTY_MAP.to_string()),
             DataType::Variant => Some(ARROW_EXT_TYPE_VARIANT.to_string()),
             DataType::Bitmap => Some(ARROW_EXT_TYPE_BITMAP.to_string()),
+            DataType::Geometry => Some(ARROW_EXT_TYPE_GEOMETRY.to_string()),
+            DataType::Binary => Some(ARROW_EXT_TYPE_BINARY.to_string()),
             _ => None,
         };
 
@@ -26,9 +26,11 @@ use crate::types::NumberDataType;
 use crate::with_number_type;
 use crate::TableDataType;
 use crate::TableField;
+use crate::ARROW_EXT_TYPE_BINARY;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
 use crate::ARROW_EXT_TYPE_VARIANT;
 use crate::EXTENSION_KEY;
 
@@ -40,6 +42,8 @@ impl From<&TableField> for ArrowField {
             TableDataType::EmptyMap => Some(ARROW_EXT_TYPE_EMPTY_MAP.to_string()),
             TableDataType::Variant => Some(ARROW_EXT_TYPE_VARIANT.to_string()),
             TableDataType::Bitmap => Some(ARROW_EXT_TYPE_BITMAP.to_string()),
+            TableDataType::Geometry => Some(ARROW_EXT_TYPE_GEOMETRY.to_string()),
+            TableDataType::Binary => Some(ARROW_EXT_TYPE_BINARY.to_string()),
             _ => None,
         };
 
@@ -72,6 +76,7 @@ impl From<&TableDataType> for ArrowDataType {
             }
             TableDataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
             TableDataType::Date => ArrowDataType::Date32,
+            TableDataType::Time => ArrowDataType::Time64(TimeUnit::Microsecond),
             TableDataType::Nullable(ty) => ty.as_ref().into(),
             TableDataType::Array(ty) => {
                 let arrow_ty = ty.as_ref().into();
@@ -116,6 +121,8 @@ impl From<&TableDataType> for ArrowDataType {
 
             TableDataType::Bitmap => ArrowDataType::LargeBinary,
             TableDataType::Variant => ArrowDataType::LargeBinary,
+            TableDataType::Geometry => ArrowDataType::LargeBinary,
+            TableDataType::Binary => ArrowDataType::LargeBinary,
         }
     }
 }
@@ -23,9 +23,11 @@ use crate::types::NumberDataType;
 use crate::TableDataType;
 use crate::TableField;
 use crate::TableSchema;
+use crate::ARROW_EXT_TYPE_BINARY;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
 use crate::ARROW_EXT_TYPE_VARIANT;
 use crate::EXTENSION_KEY;
 
@@ -66,6 +68,8 @@ impl TryFrom<&ArrowField> for TableDataType {
             Some(ARROW_EXT_TYPE_EMPTY_MAP) => Some(TableDataType::EmptyMap),
             Some(ARROW_EXT_TYPE_VARIANT) => Some(TableDataType::Variant),
             Some(ARROW_EXT_TYPE_BITMAP) => Some(TableDataType::Bitmap),
+            Some(ARROW_EXT_TYPE_GEOMETRY) => Some(TableDataType::Geometry),
+            Some(ARROW_EXT_TYPE_BINARY) => Some(TableDataType::Binary),
             _ => None,
         };
 
@@ -95,6 +99,7 @@ impl TryFrom<&ArrowField> for TableDataType {
             ArrowDataType::Float64 => TableDataType::Number(NumberDataType::Float64),
             ArrowDataType::Timestamp(_unit, _tz) => TableDataType::Timestamp,
             ArrowDataType::Date32 | ArrowDataType::Date64 => TableDataType::Date,
+            ArrowDataType::Time32(_) | ArrowDataType::Time64(_) => TableDataType::Time,
             ArrowDataType::Utf8
             | ArrowDataType::LargeUtf8
             | ArrowDataType::Binary
@@ -14,6 +14,7 @@
 
 use std::collections::HashMap;
 use std::ops::Not;
+use std::sync::Arc;
 
 use common_arrow::arrow::bitmap;
 use common_arrow::arrow::bitmap::Bitmap;
@@ -40,6 +41,7 @@ use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::NullableType;
 use crate::udf_client::UDFFlightClient;
+use crate::udf_wasm::run_wasm_udf;
 use crate::utils::variant_transform::contains_variant;
 use crate::utils::variant_transform::transform_variant;
 use crate::values::Column;
@@ -54,6 +56,7 @@ use crate::FunctionContext;
 use crate::FunctionDomain;
 use crate::FunctionEval;
 use crate::FunctionRegistry;
+use crate::UDFType;
 
 pub struct Evaluator<'a> {
     input_columns: &'a DataBlock,
@@ -169,13 +172,20 @@ impl<'a> Evaluator<'a> {
                 ctx.render_error(*span, id.params(), &args, &function.signature.name)?;
                 Ok(result)
             }
-            Expr::UDFServerCall {
+            Expr::UDFCall {
                 func_name,
-                server_addr,
+                udf_type,
                 return_type,
                 args,
                 ..
-            } => self.run_udf_server_call(func_name, server_addr, return_type, args, validity),
+            } => match udf_type {
+                UDFType::Server(server_addr) => {
+                    self.run_udf_server_call(func_name, server_addr, return_type, args, validity)
+                }
+                UDFType::Wasm(module) => {
+                    self.run_udf_wasm_call(func_name, module, return_type, args, validity)
+                }
+            },
         };
 
         #[cfg(debug_assertions)]
@@ -305,6 +315,27 @@ impl<'a> Evaluator<'a> {
         }
     }
 
+    fn run_udf_wasm_call(
+        &self,
+        func_name: &str,
+        module: &Arc<Vec<u8>>,
+        return_type: &DataType,
+        args: &[Expr],
+        validity: Option<Bitmap>,
+    ) -> Result<Value<AnyType>> {
+        let inputs = args
+            .iter()
+            .map(|expr| self.partial_run(expr, validity.clone()))
+            .collect::<Result<Vec<_>>>()?;
+        run_wasm_udf(
+            module,
+            func_name,
+            &inputs,
+            return_type,
+            self.input_columns.num_rows(),
+        )
+    }
+
     fn run_cast(
         &self,
         span: Span,
@@ -1330,7 +1361,7 @@ impl<'a, Index: ColumnIndex> ConstantFolder<'a, Index> {
 
                 (func_expr, func_domain)
             }
-            Expr::UDFServerCall { .. } => (expr.clone(), None),
+            Expr::UDFCall { .. } => (expr.clone(), None),
         };
 
         debug_assert_eq!(expr.data_type(), new_expr.data_type());
@@ -35,6 +35,15 @@ impl ColumnIndex for usize {}
 
 impl ColumnIndex for String {}
 
+/// How a user-defined function is executed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UDFType {
+    /// Sent to the UDF server at the address through the arrow flight protocol.
+    Server(String),
+    /// Run by the embedded wasm runtime, holding the module bytes.
+    Wasm(Arc<Vec<u8>>),
+}
+
 /// An unchecked expression that is directly discarded from SQL or constructed by the planner.
 /// It can be type-checked and then converted to an evaluable [`Expr`].
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -63,10 +72,11 @@ pub enum RawExpr<Index: ColumnIndex = usize> {
         params: Vec<usize>,
         args: Vec<RawExpr<Index>>,
     },
-    UDFServerCall {
+    /// Call to a user-defined function, which is not registered in the function registry.
+    UDFCall {
         span: Span,
         func_name: String,
-        server_addr: String,
+        udf_type: UDFType,
         arg_types: Vec<DataType>,
         return_type: DataType,
         args: Vec<RawExpr<Index>>,
@@ -110,11 +120,11 @@ pub enum Expr<Index: ColumnIndex = usize> {
         args: Vec<Expr<Index>>,
         return_type: DataType,
     },
-    UDFServerCall {
+    UDFCall {
         #[educe(Hash(ignore), PartialEq(ignore), Eq(ignore))]
         span: Span,
         func_name: String,
-        server_addr: String,
+        udf_type: UDFType,
         return_type: DataType,
         args: Vec<Expr<Index>>,
     },
@@ -157,11 +167,11 @@ pub enum RemoteExpr<Index: ColumnIndex = usize> {
         args: Vec<RemoteExpr<Index>>,
         return_type: DataType,
     },
-    UDFServerCall {
+    UDFCall {
         #[educe(Hash(ignore), PartialEq(ignore), Eq(ignore))]
         span: Span,
         func_name: String,
-        server_addr: String,
+        udf_type: UDFType,
         return_type: DataType,
         args: Vec<RemoteExpr<Index>>,
     },
@@ -177,7 +187,7 @@ impl<Index: ColumnIndex> RawExpr<Index> {
                 RawExpr::Cast { expr, .. } => walk(expr, buf),
                 RawExpr::FunctionCall { args, .. } => args.iter().for_each(|expr| walk(expr, buf)),
                 RawExpr::Constant { .. } => (),
-                RawExpr::UDFServerCall { args, .. } => args.iter().for_each(|expr| walk(expr, buf)),
+                RawExpr::UDFCall { args, .. } => args.iter().for_each(|expr| walk(expr, buf)),
             }
         }
 
@@ -228,17 +238,17 @@ impl<Index: ColumnIndex> RawExpr<Index> {
                 params: params.clone(),
                 args: args.iter().map(|expr| expr.project_column_ref(f)).collect(),
             },
-            RawExpr::UDFServerCall {
+            RawExpr::UDFCall {
                 span,
                 func_name,
-                server_addr,
+                udf_type,
                 arg_types,
                 return_type,
                 args,
-            } => RawExpr::UDFServerCall {
+            } => RawExpr::UDFCall {
                 span: *span,
                 func_name: func_name.clone(),
-                server_addr: server_addr.clone(),
+                udf_type: udf_type.clone(),
                 arg_types: arg_types.clone(),
                 return_type: return_type.clone(),
                 args: args.iter().map(|expr| expr.project_column_ref(f)).collect(),
@@ -254,7 +264,7 @@ impl<Index: ColumnIndex> Expr<Index> {
             Expr::ColumnRef { span, .. } => *span,
             Expr::Cast { span, .. } => *span,
             Expr::FunctionCall { span, .. } => *span,
-            Expr::UDFServerCall { span, .. } => *span,
+            Expr::UDFCall { span, .. } => *span,
         }
     }
 
@@ -264,7 +274,7 @@ impl<Index: ColumnIndex> Expr<Index> {
             Expr::ColumnRef { data_type, .. } => data_type,
             Expr::Cast { dest_type, .. } => dest_type,
             Expr::FunctionCall { return_type, .. } => return_type,
-            Expr::UDFServerCall { return_type, .. } => return_type,
+            Expr::UDFCall { return_type, .. } => return_type,
         }
     }
 
@@ -277,7 +287,7 @@ impl<Index: ColumnIndex> Expr<Index> {
                 Expr::Cast { expr, .. } => walk(expr, buf),
                 Expr::FunctionCall { args, .. } => args.iter().for_each(|expr| walk(expr, buf)),
                 Expr::Constant { .. } => (),
-                Expr::UDFServerCall { args, .. } => args.iter().for_each(|expr| walk(expr, buf)),
+                Expr::UDFCall { args, .. } => args.iter().for_each(|expr| walk(expr, buf)),
             }
         }
 
@@ -337,16 +347,16 @@ impl<Index: ColumnIndex> Expr<Index> {
                 args: args.iter().map(|expr| expr.project_column_ref(f)).collect(),
                 return_type: return_type.clone(),
             },
-            Expr::UDFServerCall {
+            Expr::UDFCall {
                 span,
                 func_name,
-                server_addr,
+                udf_type,
                 return_type,
                 args,
-            } => Expr::UDFServerCall {
+            } => Expr::UDFCall {
                 span: *span,
                 func_name: func_name.clone(),
-                server_addr: server_addr.clone(),
+                udf_type: udf_type.clone(),
                 return_type: return_type.clone(),
                 args: args.iter().map(|expr| expr.project_column_ref(f)).collect(),
             },
@@ -400,16 +410,16 @@ impl<Index: ColumnIndex> Expr<Index> {
                 args: args.iter().map(Expr::as_remote_expr).collect(),
                 return_type: return_type.clone(),
             },
-            Expr::UDFServerCall {
+            Expr::UDFCall {
                 span,
                 func_name,
-                server_addr,
+                udf_type,
                 return_type,
                 args,
-            } => RemoteExpr::UDFServerCall {
+            } => RemoteExpr::UDFCall {
                 span: *span,
                 func_name: func_name.clone(),
-                server_addr: server_addr.clone(),
+                udf_type: udf_type.clone(),
                 return_type: return_type.clone(),
                 args: args.iter().map(Expr::as_remote_expr).collect(),
             },
@@ -428,7 +438,7 @@ impl<Index: ColumnIndex> Expr<Index> {
                     .non_deterministic
                     && args.iter().all(|arg| arg.is_deterministic(registry))
             }
-            Expr::UDFServerCall { .. } => false,
+            Expr::UDFCall { .. } => false,
         }
     }
 }
@@ -484,16 +494,16 @@ impl<Index: ColumnIndex> RemoteExpr<Index> {
                     return_type: return_type.clone(),
                 }
             }
-            RemoteExpr::UDFServerCall {
+            RemoteExpr::UDFCall {
                 span,
                 func_name,
-                server_addr,
+                udf_type,
                 return_type,
                 args,
-            } => Expr::UDFServerCall {
+            } => Expr::UDFCall {
                 span: *span,
                 func_name: func_name.clone(),
-                server_addr: server_addr.clone(),
+                udf_type: udf_type.clone(),
                 return_type: return_type.clone(),
                 args: args.iter().map(|arg| arg.as_expr(fn_registry)).collect(),
             },
@@ -101,6 +101,15 @@ pub struct FunctionContext {
     pub openai_api_version: String,
     pub openai_api_embedding_model: String,
     pub openai_api_completion_model: String,
+
+    pub sequence_generator: Option<Arc<dyn SequenceGenerator>>,
+}
+
+/// Hands out the values of sequence objects, which live outside of the expression
+/// evaluation, e.g. in the meta service.
+pub trait SequenceGenerator: Send + Sync {
+    /// Returns the next `count` values of the sequence `name`.
+    fn next_values(&self, name: &str, count: u64) -> Result<Vec<u64>>;
 }
 
 #[derive(Clone)]
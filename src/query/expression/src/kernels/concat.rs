@@ -33,8 +33,10 @@ use crate::types::string::StringColumn;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::ArrayType;
+use crate::types::BinaryType;
 use crate::types::BitmapType;
 use crate::types::BooleanType;
+use crate::types::GeometryType;
 use crate::types::MapType;
 use crate::types::NumberType;
 use crate::types::StringType;
@@ -270,6 +272,20 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            Column::Time(_) => {
+                let builder = Self::concat_primitive_types(
+                    columns.map(|col| col.into_time().unwrap()),
+                    capacity,
+                );
+                Column::Time(builder.into())
+            }
+            Column::Interval(_) => {
+                let builder = Self::concat_primitive_types(
+                    columns.map(|col| col.into_interval().unwrap()),
+                    capacity,
+                );
+                Column::Interval(builder.into())
+            }
             Column::Array(col) => {
                 let mut offsets = Vec::with_capacity(capacity + 1);
                 offsets.push(0);
@@ -327,6 +343,14 @@ impl Column {
                 columns.map(|col| col.into_variant().unwrap()),
                 capacity,
             )),
+            Column::Geometry(_) => GeometryType::upcast_column(Self::concat_string_types(
+                columns.map(|col| col.into_geometry().unwrap()),
+                capacity,
+            )),
+            Column::Binary(_) => BinaryType::upcast_column(Self::concat_string_types(
+                columns.map(|col| col.into_binary().unwrap()),
+                capacity,
+            )),
         }
     }
 
@@ -52,7 +52,11 @@ impl DataBlock {
         if hash_key_types.len() == 1
             && matches!(
                 hash_key_types[0],
-                DataType::String | DataType::Variant | DataType::Bitmap
+                DataType::String
+                    | DataType::Variant
+                    | DataType::Bitmap
+                    | DataType::Geometry
+                    | DataType::Binary
             )
         {
             return Ok(HashMethodKind::SingleString(
@@ -47,7 +47,11 @@ impl HashMethod for HashMethodDictionarySerializer {
         let mut serialize_columns = Vec::new();
         for (group_column, _) in group_columns {
             match group_column {
-                Column::String(v) | Column::Variant(v) | Column::Bitmap(v) => {
+                Column::String(v)
+                | Column::Variant(v)
+                | Column::Bitmap(v)
+                | Column::Geometry(v)
+                | Column::Binary(v) => {
                     debug_assert_eq!(v.len(), num_rows);
                     dictionary_columns.push(v.clone());
                 }
@@ -45,7 +45,9 @@ impl HashMethod for HashMethodSingleString {
         match keys_state {
             KeysState::Column(Column::String(col))
             | KeysState::Column(Column::Variant(col))
-            | KeysState::Column(Column::Bitmap(col)) => Ok(col.iter()),
+            | KeysState::Column(Column::Bitmap(col))
+            | KeysState::Column(Column::Geometry(col))
+            | KeysState::Column(Column::Binary(col)) => Ok(col.iter()),
             _ => unreachable!(),
         }
     }
@@ -57,7 +59,9 @@ impl HashMethod for HashMethodSingleString {
         match keys_state {
             KeysState::Column(Column::String(col))
             | KeysState::Column(Column::Variant(col))
-            | KeysState::Column(Column::Bitmap(col)) => {
+            | KeysState::Column(Column::Bitmap(col))
+            | KeysState::Column(Column::Geometry(col))
+            | KeysState::Column(Column::Binary(col)) => {
                 let mut hashes = Vec::with_capacity(col.len());
                 hashes.extend(col.iter().map(|key| key.fast_hash()));
                 Ok((col.iter(), hashes))
@@ -76,7 +76,11 @@ pub unsafe fn serialize_column_binary(column: &Column, row: usize, row_space: &m
             })
         }
         Column::Boolean(v) => store_advance::<bool>(&v.get_bit(row), row_space),
-        Column::String(v) | Column::Bitmap(v) | Column::Variant(v) => {
+        Column::String(v)
+        | Column::Bitmap(v)
+        | Column::Variant(v)
+        | Column::Geometry(v)
+        | Column::Binary(v) => {
             let value = unsafe { v.index_unchecked(row) };
             let len = value.len();
             store_advance::<u64>(&(len as u64), row_space);
@@ -84,6 +88,13 @@ pub unsafe fn serialize_column_binary(column: &Column, row: usize, row_space: &m
         }
         Column::Timestamp(v) => store_advance::<i64>(&v[row], row_space),
         Column::Date(v) => store_advance::<i32>(&v[row], row_space),
+        Column::Time(v) => store_advance::<i64>(&v[row], row_space),
+        Column::Interval(v) => {
+            let value = &v[row];
+            store_advance::<i32>(&value.months, row_space);
+            store_advance::<i32>(&value.days, row_space);
+            store_advance::<i64>(&value.nanos, row_space);
+        }
         Column::Array(array) | Column::Map(array) => {
             let data = array.index(row).unwrap();
             store_advance::<u64>(&(data.len() as u64), row_space);
@@ -18,6 +18,7 @@ mod group_by;
 mod group_by_hash;
 mod scatter;
 mod sort;
+pub mod string;
 mod take;
 mod take_chunks;
 mod take_compact;
@@ -0,0 +1,130 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Kernels working on the raw data and offsets buffers of [`StringColumn`].
+//!
+//! The loops here are written over fixed-width chunks without branches on the
+//! content, so that they are auto-vectorized into SIMD instructions.
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_arrow::arrow::buffer::Buffer;
+
+use crate::types::string::StringColumn;
+
+const CHUNK_SIZE: usize = 64;
+
+/// Returns true if all the bytes are ASCII.
+#[inline]
+pub fn is_ascii(data: &[u8]) -> bool {
+    let chunks = data.chunks_exact(CHUNK_SIZE);
+    let remainder = chunks.remainder();
+    for chunk in chunks {
+        if chunk.iter().fold(0u8, |acc, b| acc | b) >= 0x80 {
+            return false;
+        }
+    }
+    remainder.iter().fold(0u8, |acc, b| acc | b) < 0x80
+}
+
+/// Returns the bytes referenced by the offsets, and the offsets rebased to start from zero.
+fn referenced_data(col: &StringColumn) -> (&[u8], Buffer<u64>) {
+    let offsets = col.offsets();
+    let start = offsets[0];
+    let end = offsets[offsets.len() - 1];
+    let data = &col.data()[start as usize..end as usize];
+    let offsets = if start == 0 {
+        offsets.clone()
+    } else {
+        offsets.iter().map(|offset| offset - start).collect()
+    };
+    (data, offsets)
+}
+
+/// Converts the column to uppercase if it only contains ASCII characters,
+/// otherwise returns `None` and the caller should fall back to the UTF-8 path.
+pub fn ascii_to_uppercase(col: &StringColumn) -> Option<StringColumn> {
+    map_ascii(col, |b| b.to_ascii_uppercase())
+}
+
+/// Converts the column to lowercase if it only contains ASCII characters,
+/// otherwise returns `None` and the caller should fall back to the UTF-8 path.
+pub fn ascii_to_lowercase(col: &StringColumn) -> Option<StringColumn> {
+    map_ascii(col, |b| b.to_ascii_lowercase())
+}
+
+#[inline]
+fn map_ascii(col: &StringColumn, f: impl Fn(u8) -> u8) -> Option<StringColumn> {
+    let (data, offsets) = referenced_data(col);
+    if !is_ascii(data) {
+        return None;
+    }
+    // The case mapping of ASCII characters never changes the length,
+    // so the offsets can be shared with the input.
+    let data: Vec<u8> = data.iter().map(|b| f(*b)).collect();
+    Some(StringColumn::new(data.into(), offsets))
+}
+
+/// Builds a new column from a sub-slice of each row, such as `substr`.
+///
+/// The output is never longer than the input, so the buffers are allocated once.
+pub fn map_sub_slices(col: &StringColumn, f: impl Fn(&[u8]) -> &[u8]) -> StringColumn {
+    let offsets = col.offsets();
+    let data = col.data().as_slice();
+    let mut new_data = Vec::with_capacity((offsets[offsets.len() - 1] - offsets[0]) as usize);
+    let mut new_offsets = Vec::with_capacity(offsets.len());
+    new_offsets.push(0u64);
+    for window in offsets.windows(2) {
+        let row = &data[window[0] as usize..window[1] as usize];
+        new_data.extend_from_slice(f(row));
+        new_offsets.push(new_data.len() as u64);
+    }
+    StringColumn::new(new_data.into(), new_offsets.into())
+}
+
+/// Returns a bitmap of the rows that start with `prefix`.
+pub fn starts_with(col: &StringColumn, prefix: &[u8]) -> Bitmap {
+    let len = prefix.len() as u64;
+    filter_rows(col, |data, start, end| {
+        end - start >= len && &data[start as usize..(start + len) as usize] == prefix
+    })
+}
+
+/// Returns a bitmap of the rows that end with `suffix`.
+pub fn ends_with(col: &StringColumn, suffix: &[u8]) -> Bitmap {
+    let len = suffix.len() as u64;
+    filter_rows(col, |data, start, end| {
+        end - start >= len && &data[(end - len) as usize..end as usize] == suffix
+    })
+}
+
+/// Returns a bitmap of the rows that equal to `value`.
+pub fn equals(col: &StringColumn, value: &[u8]) -> Bitmap {
+    let len = value.len() as u64;
+    filter_rows(col, |data, start, end| {
+        end - start == len && &data[start as usize..end as usize] == value
+    })
+}
+
+/// Evaluates the predicate on the offsets of each row, without building the row slices
+/// for the rows whose length can't match.
+#[inline]
+fn filter_rows(col: &StringColumn, f: impl Fn(&[u8], u64, u64) -> bool) -> Bitmap {
+    let data = col.data().as_slice();
+    let mut builder = MutableBitmap::with_capacity(col.len());
+    for window in col.offsets().windows(2) {
+        builder.push(f(data, window[0], window[1]));
+    }
+    builder.into()
+}
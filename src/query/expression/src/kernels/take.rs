@@ -22,8 +22,10 @@ use crate::kernels::utils::copy_advance_aligned;
 use crate::kernels::utils::set_vec_len_by_ptr;
 use crate::types::array::ArrayColumn;
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::binary::BinaryType;
 use crate::types::bitmap::BitmapType;
 use crate::types::decimal::DecimalColumn;
+use crate::types::geometry::GeometryType;
 use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
@@ -134,6 +136,14 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            Column::Time(column) => {
+                let builder = Self::take_primitive_types(column, indices);
+                Column::Time(builder.into())
+            }
+            Column::Interval(column) => {
+                let builder = Self::take_primitive_types(column, indices);
+                Column::Interval(builder.into())
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(indices.len() + 1);
                 offsets.push(0);
@@ -184,6 +194,16 @@ impl Column {
                 indices,
                 string_items_buf.as_mut(),
             )),
+            Column::Geometry(column) => GeometryType::upcast_column(Self::take_string_types(
+                column,
+                indices,
+                string_items_buf.as_mut(),
+            )),
+            Column::Binary(column) => BinaryType::upcast_column(Self::take_string_types(
+                column,
+                indices,
+                string_items_buf.as_mut(),
+            )),
         }
     }
 
@@ -24,9 +24,11 @@ use crate::kernels::take::BIT_MASK;
 use crate::kernels::utils::copy_advance_aligned;
 use crate::kernels::utils::set_vec_len_by_ptr;
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::binary::BinaryType;
 use crate::types::bitmap::BitmapType;
 use crate::types::decimal::DecimalColumn;
 use crate::types::decimal::DecimalColumnVec;
+use crate::types::geometry::GeometryType;
 use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableColumnVec;
@@ -38,10 +40,12 @@ use crate::types::ArrayType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::IntervalType;
 use crate::types::MapType;
 use crate::types::NumberColumnVec;
 use crate::types::NumberType;
 use crate::types::StringType;
+use crate::types::TimeType;
 use crate::types::TimestampType;
 use crate::types::ValueType;
 use crate::types::VariantType;
@@ -277,6 +281,14 @@ impl Column {
                 let builder = DateType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<DateType>(columns, builder, indices)
             }
+            Column::Time(_) => {
+                let builder = TimeType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<TimeType>(columns, builder, indices)
+            }
+            Column::Interval(_) => {
+                let builder = IntervalType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<IntervalType>(columns, builder, indices)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(result_size + 1);
                 offsets.push(0);
@@ -370,6 +382,14 @@ impl Column {
                 let builder = VariantType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<VariantType>(columns, builder, indices)
             }
+            Column::Geometry(_) => {
+                let builder = GeometryType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<GeometryType>(columns, builder, indices)
+            }
+            Column::Binary(_) => {
+                let builder = BinaryType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<BinaryType>(columns, builder, indices)
+            }
         }
     }
 
@@ -490,6 +510,20 @@ impl Column {
                     .collect_vec();
                 ColumnVec::Date(columns)
             }
+            Column::Time(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| TimeType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Time(columns)
+            }
+            Column::Interval(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| IntervalType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Interval(columns)
+            }
             Column::Array(_) => {
                 let columns = columns
                     .iter()
@@ -571,6 +605,20 @@ impl Column {
                     .collect_vec();
                 ColumnVec::Variant(columns)
             }
+            Column::Geometry(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| GeometryType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Geometry(columns)
+            }
+            Column::Binary(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| BinaryType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Binary(columns)
+            }
         }
     }
 
@@ -630,6 +678,14 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            ColumnVec::Time(columns) => {
+                let builder = Self::take_block_vec_primitive_types(columns, indices);
+                Column::Time(builder.into())
+            }
+            ColumnVec::Interval(columns) => {
+                let builder = Self::take_block_vec_primitive_types(columns, indices);
+                Column::Interval(builder.into())
+            }
             ColumnVec::Array(columns) => {
                 let data_type = data_type.as_array().unwrap();
                 let mut offsets = Vec::with_capacity(result_size + 1);
@@ -705,6 +761,12 @@ impl Column {
             ColumnVec::Variant(columns) => VariantType::upcast_column(
                 Self::take_block_vec_string_types(columns, indices, string_items_buf.as_mut()),
             ),
+            ColumnVec::Geometry(columns) => GeometryType::upcast_column(
+                Self::take_block_vec_string_types(columns, indices, string_items_buf.as_mut()),
+            ),
+            ColumnVec::Binary(columns) => BinaryType::upcast_column(
+                Self::take_block_vec_string_types(columns, indices, string_items_buf.as_mut()),
+            ),
         }
     }
 
@@ -20,8 +20,10 @@ use crate::kernels::utils::set_vec_len_by_ptr;
 use crate::kernels::utils::store_advance_aligned;
 use crate::types::array::ArrayColumn;
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::binary::BinaryType;
 use crate::types::bitmap::BitmapType;
 use crate::types::decimal::DecimalColumn;
+use crate::types::geometry::GeometryType;
 use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
@@ -130,6 +132,14 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            Column::Time(column) => {
+                let builder = Self::take_compacted_primitive_types(column, indices, num_rows);
+                Column::Time(builder.into())
+            }
+            Column::Interval(column) => {
+                let builder = Self::take_compacted_primitive_types(column, indices, num_rows);
+                Column::Interval(builder.into())
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(num_rows + 1);
                 offsets.push(0);
@@ -179,6 +189,12 @@ impl Column {
             Column::Variant(column) => VariantType::upcast_column(Self::take_compact_string_types(
                 column, indices, num_rows,
             )),
+            Column::Geometry(column) => GeometryType::upcast_column(
+                Self::take_compact_string_types(column, indices, num_rows),
+            ),
+            Column::Binary(column) => BinaryType::upcast_column(Self::take_compact_string_types(
+                column, indices, num_rows,
+            )),
         }
     }
 
@@ -35,6 +35,7 @@ use crate::types::DecimalDataType;
 use crate::types::NumberDataType;
 use crate::types::NumberType;
 use crate::types::StringType;
+use crate::types::TimeType;
 use crate::types::TimestampType;
 use crate::types::ValueType;
 use crate::with_decimal_type;
@@ -99,6 +100,7 @@ pub enum Domain {
     String(StringDomain),
     Timestamp(SimpleDomain<i64>),
     Date(SimpleDomain<i32>),
+    Time(SimpleDomain<i64>),
     Nullable(NullableDomain<AnyType>),
     /// `Array(None)` means that the array is empty, thus there is no inner domain information.
     Array(Option<Box<Domain>>),
@@ -177,6 +179,7 @@ impl Domain {
             },
             DataType::Timestamp => Domain::Timestamp(TimestampType::full_domain()),
             DataType::Date => Domain::Date(DateType::full_domain()),
+            DataType::Time => Domain::Time(TimeType::full_domain()),
             DataType::Null => Domain::Nullable(NullableDomain {
                 has_null: true,
                 value: None,
@@ -202,7 +205,11 @@ impl Domain {
                 };
                 Domain::Map(Some(inner_domain))
             }
-            DataType::Bitmap | DataType::Variant => Domain::Undefined,
+            DataType::Interval
+            | DataType::Bitmap
+            | DataType::Variant
+            | DataType::Geometry
+            | DataType::Binary => Domain::Undefined,
             DataType::Generic(_) => unreachable!(),
         }
     }
@@ -254,6 +261,10 @@ impl Domain {
                 min: this.min.min(other.min),
                 max: this.max.max(other.max),
             }),
+            (Domain::Time(this), Domain::Time(other)) => Domain::Time(SimpleDomain {
+                min: this.min.min(other.min),
+                max: this.max.max(other.max),
+            }),
             (
                 Domain::Nullable(NullableDomain {
                     has_null: true,
@@ -395,6 +406,7 @@ impl Domain {
                 Some(Scalar::Timestamp(*min))
             }
             Domain::Date(SimpleDomain { min, max }) if min == max => Some(Scalar::Date(*min)),
+            Domain::Time(SimpleDomain { min, max }) if min == max => Some(Scalar::Time(*min)),
             Domain::Nullable(NullableDomain {
                 has_null: true,
                 value: None,
@@ -61,7 +61,10 @@ impl RowConverter {
             | DataType::EmptyArray
             | DataType::EmptyMap
             | DataType::Map(_)
+            | DataType::Interval
             | DataType::Bitmap
+            | DataType::Geometry
+            | DataType::Binary
             | DataType::Tuple(_)
             | DataType::Generic(_) => false,
             DataType::Nullable(inner) => Self::support_data_type(inner.as_ref()),
@@ -117,7 +120,7 @@ impl RowConverter {
                         .iter_mut()
                         .for_each(|x| *x += i256::ENCODED_LEN as u64),
                 },
-                DataType::Timestamp => lengths
+                DataType::Timestamp | DataType::Time => lengths
                     .iter_mut()
                     .for_each(|x| *x += i64::ENCODED_LEN as u64),
                 DataType::Date => lengths
@@ -229,7 +232,9 @@ fn encode_column(out: &mut StringColumnBuilder, column: &Column, asc: bool, null
                 }
             })
         }
-        Column::Timestamp(col) => fixed::encode(out, col, validity, asc, nulls_first),
+        Column::Timestamp(col) | Column::Time(col) => {
+            fixed::encode(out, col, validity, asc, nulls_first)
+        }
         Column::Date(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::String(col) => variable::encode(out, col.iter(), validity, asc, nulls_first),
         Column::Variant(col) => variable::encode(out, col.iter(), validity, asc, nulls_first),
@@ -19,6 +19,7 @@ use std::sync::Arc;
 
 use common_arrow::arrow::datatypes::DataType as ArrowDataType;
 use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::datatypes::IntervalUnit;
 use common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use common_arrow::arrow::datatypes::TimeUnit;
 use common_exception::ErrorCode;
@@ -35,9 +36,11 @@ use crate::with_number_type;
 use crate::BlockMetaInfo;
 use crate::BlockMetaInfoDowncast;
 use crate::Scalar;
+use crate::ARROW_EXT_TYPE_BINARY;
 use crate::ARROW_EXT_TYPE_BITMAP;
 use crate::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use crate::ARROW_EXT_TYPE_EMPTY_MAP;
+use crate::ARROW_EXT_TYPE_GEOMETRY;
 use crate::ARROW_EXT_TYPE_VARIANT;
 
 // Column id of TableField
@@ -126,6 +129,7 @@ pub enum TableDataType {
     Decimal(DecimalDataType),
     Timestamp,
     Date,
+    Time,
     Nullable(Box<TableDataType>),
     Array(Box<TableDataType>),
     Map(Box<TableDataType>),
@@ -135,6 +139,8 @@ pub enum TableDataType {
         fields_type: Vec<TableDataType>,
     },
     Variant,
+    Geometry,
+    Binary,
 }
 
 impl DataSchema {
@@ -1106,6 +1112,7 @@ impl From<&TableDataType> for DataType {
             TableDataType::Decimal(ty) => DataType::Decimal(*ty),
             TableDataType::Timestamp => DataType::Timestamp,
             TableDataType::Date => DataType::Date,
+            TableDataType::Time => DataType::Time,
             TableDataType::Nullable(ty) => DataType::Nullable(Box::new((&**ty).into())),
             TableDataType::Array(ty) => DataType::Array(Box::new((&**ty).into())),
             TableDataType::Map(ty) => DataType::Map(Box::new((&**ty).into())),
@@ -1114,6 +1121,8 @@ impl From<&TableDataType> for DataType {
                 DataType::Tuple(fields_type.iter().map(Into::into).collect())
             }
             TableDataType::Variant => DataType::Variant,
+            TableDataType::Geometry => DataType::Geometry,
+            TableDataType::Binary => DataType::Binary,
         }
     }
 }
@@ -1367,6 +1376,7 @@ impl From<&ArrowField> for TableDataType {
 
             ArrowDataType::Timestamp(_, _) => TableDataType::Timestamp,
             ArrowDataType::Date32 | ArrowDataType::Date64 => TableDataType::Date,
+            ArrowDataType::Time32(_) | ArrowDataType::Time64(_) => TableDataType::Time,
             ArrowDataType::Map(f, _) => {
                 let inner_ty = f.as_ref().into();
                 TableDataType::Map(Box::new(inner_ty))
@@ -1384,6 +1394,8 @@ impl From<&ArrowField> for TableDataType {
                 ARROW_EXT_TYPE_EMPTY_ARRAY => TableDataType::EmptyArray,
                 ARROW_EXT_TYPE_EMPTY_MAP => TableDataType::EmptyMap,
                 ARROW_EXT_TYPE_BITMAP => TableDataType::Bitmap,
+                ARROW_EXT_TYPE_GEOMETRY => TableDataType::Geometry,
+                ARROW_EXT_TYPE_BINARY => TableDataType::Binary,
                 _ => unimplemented!("data_type: {:?}", f.data_type()),
             },
             // this is safe, because we define the datatype firstly
@@ -1470,6 +1482,8 @@ impl From<&DataType> for ArrowDataType {
             }
             DataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
             DataType::Date => ArrowDataType::Date32,
+            DataType::Time => ArrowDataType::Time64(TimeUnit::Microsecond),
+            DataType::Interval => ArrowDataType::Interval(IntervalUnit::MonthDayNano),
             DataType::Nullable(ty) => ty.as_ref().into(),
             DataType::Array(ty) => {
                 let arrow_ty = ty.as_ref().into();
@@ -1517,6 +1531,16 @@ impl From<&DataType> for ArrowDataType {
                 Box::new(ArrowDataType::LargeBinary),
                 None,
             ),
+            DataType::Geometry => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_GEOMETRY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
+            DataType::Binary => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BINARY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
             _ => unreachable!(),
         }
     }
@@ -1549,6 +1573,7 @@ impl From<&TableDataType> for ArrowDataType {
             }
             TableDataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
             TableDataType::Date => ArrowDataType::Date32,
+            TableDataType::Time => ArrowDataType::Time64(TimeUnit::Microsecond),
             TableDataType::Nullable(ty) => ty.as_ref().into(),
             TableDataType::Array(ty) => {
                 let arrow_ty = ty.as_ref().into();
@@ -1600,6 +1625,16 @@ impl From<&TableDataType> for ArrowDataType {
                 Box::new(ArrowDataType::LargeBinary),
                 None,
             ),
+            TableDataType::Geometry => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_GEOMETRY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
+            TableDataType::Binary => ArrowDataType::Extension(
+                ARROW_EXT_TYPE_BINARY.to_string(),
+                Box::new(ArrowDataType::LargeBinary),
+                None,
+            ),
         }
     }
 }
@@ -1622,6 +1657,7 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         DataType::Timestamp => Ok(TableDataType::Timestamp),
         DataType::Decimal(x) => Ok(TableDataType::Decimal(*x)),
         DataType::Date => Ok(TableDataType::Date),
+        DataType::Time => Ok(TableDataType::Time),
         DataType::Nullable(inner_type) => Ok(TableDataType::Nullable(Box::new(infer_schema_type(
             inner_type,
         )?))),
@@ -1633,6 +1669,8 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         }
         DataType::Bitmap => Ok(TableDataType::Bitmap),
         DataType::Variant => Ok(TableDataType::Variant),
+        DataType::Geometry => Ok(TableDataType::Geometry),
+        DataType::Binary => Ok(TableDataType::Binary),
         DataType::Tuple(fields) => {
             let fields_type = fields
                 .iter()
@@ -1648,7 +1686,7 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
                 fields_type,
             })
         }
-        DataType::Generic(_) => Err(ErrorCode::SemanticError(format!(
+        DataType::Interval | DataType::Generic(_) => Err(ErrorCode::SemanticError(format!(
             "Cannot create table with type: {}",
             data_type
         ))),
@@ -126,10 +126,10 @@ pub fn check<Index: ColumnIndex>(
 
             check_function(*span, name, params, &args_expr, fn_registry)
         }
-        RawExpr::UDFServerCall {
+        RawExpr::UDFCall {
             span,
             func_name,
-            server_addr,
+            udf_type,
             arg_types,
             return_type,
             args,
@@ -172,10 +172,10 @@ pub fn check<Index: ColumnIndex>(
                 arg_types
             );
 
-            Ok(Expr::UDFServerCall {
+            Ok(Expr::UDFCall {
                 span: *span,
                 func_name: func_name.clone(),
-                server_addr: server_addr.clone(),
+                udf_type: udf_type.clone(),
                 return_type: return_type.clone(),
                 args: checked_args,
             })
@@ -748,10 +748,12 @@ pub const ALL_SIMPLE_CAST_FUNCTIONS: &[&str] = &[
     "to_float64",
     "to_timestamp",
     "to_date",
+    "to_time",
     "to_variant",
     "to_boolean",
     "to_decimal",
     "to_bitmap",
+    "to_binary",
 ];
 
 pub fn is_simple_cast_function(name: &str) -> bool {
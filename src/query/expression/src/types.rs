@@ -14,6 +14,7 @@
 
 pub mod any;
 pub mod array;
+pub mod binary;
 pub mod bitmap;
 pub mod boolean;
 pub mod date;
@@ -21,12 +22,15 @@ pub mod decimal;
 pub mod empty_array;
 pub mod empty_map;
 pub mod generic;
+pub mod geometry;
+pub mod interval;
 pub mod map;
 pub mod null;
 pub mod nullable;
 pub mod number;
 pub mod number_class;
 pub mod string;
+pub mod time;
 pub mod timestamp;
 pub mod variant;
 
@@ -40,6 +44,7 @@ use serde::Serialize;
 
 pub use self::any::AnyType;
 pub use self::array::ArrayType;
+pub use self::binary::BinaryType;
 pub use self::bitmap::BitmapType;
 pub use self::boolean::BooleanType;
 pub use self::date::DateType;
@@ -48,12 +53,15 @@ pub use self::decimal::DecimalSize;
 pub use self::empty_array::EmptyArrayType;
 pub use self::empty_map::EmptyMapType;
 pub use self::generic::GenericType;
+pub use self::geometry::GeometryType;
+pub use self::interval::IntervalType;
 pub use self::map::MapType;
 pub use self::null::NullType;
 pub use self::nullable::NullableType;
 pub use self::number::*;
 pub use self::number_class::*;
 pub use self::string::StringType;
+pub use self::time::TimeType;
 pub use self::timestamp::TimestampType;
 pub use self::variant::VariantType;
 use crate::property::Domain;
@@ -75,12 +83,16 @@ pub enum DataType {
     Decimal(DecimalDataType),
     Timestamp,
     Date,
+    Time,
+    Interval,
     Nullable(Box<DataType>),
     Array(Box<DataType>),
     Map(Box<DataType>),
     Bitmap,
     Tuple(Vec<DataType>),
     Variant,
+    Geometry,
+    Binary,
 
     // Used internally for generic types
     Generic(usize),
@@ -190,7 +202,11 @@ impl DataType {
     #[inline]
     pub fn is_string_column(&self) -> bool {
         match self {
-            DataType::String | DataType::Bitmap | DataType::Variant => true,
+            DataType::String
+            | DataType::Bitmap
+            | DataType::Variant
+            | DataType::Geometry
+            | DataType::Binary => true,
             DataType::Nullable(ty) => ty.is_string_column(),
             _ => false,
         }
@@ -209,6 +225,7 @@ impl DataType {
             | DataType::Number(NumberDataType::Float32)
             | DataType::Number(NumberDataType::Int32) => Ok(4),
             DataType::Timestamp
+            | DataType::Time
             | DataType::Number(NumberDataType::UInt64)
             | DataType::Number(NumberDataType::Float64)
             | DataType::Number(NumberDataType::Int64) => Ok(8),
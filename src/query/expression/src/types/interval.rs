@@ -0,0 +1,374 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::Range;
+
+use common_arrow::arrow::buffer::Buffer;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::property::Domain;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const NANOS_PER_MICRO: i64 = 1_000;
+pub const NANOS_PER_MILLI: i64 = 1_000_000;
+pub const NANOS_PER_SEC: i64 = 1_000_000_000;
+pub const NANOS_PER_MINUTE: i64 = 60 * NANOS_PER_SEC;
+pub const NANOS_PER_HOUR: i64 = 60 * NANOS_PER_MINUTE;
+
+/// A time span made up of independent months, days and nanoseconds parts.
+///
+/// The parts are kept apart because their lengths are not fixed: a month may have
+/// 28 to 31 days and a day may have 23 to 25 hours across a daylight saving change.
+/// They are only resolved when the interval is applied to a date or timestamp.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub struct MonthsDaysNanos {
+    pub months: i32,
+    pub days: i32,
+    pub nanos: i64,
+}
+
+impl MonthsDaysNanos {
+    pub fn new(months: i32, days: i32, nanos: i64) -> Self {
+        MonthsDaysNanos {
+            months,
+            days,
+            nanos,
+        }
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(MonthsDaysNanos {
+            months: self.months.checked_add(other.months)?,
+            days: self.days.checked_add(other.days)?,
+            nanos: self.nanos.checked_add(other.nanos)?,
+        })
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(MonthsDaysNanos {
+            months: self.months.checked_sub(other.months)?,
+            days: self.days.checked_sub(other.days)?,
+            nanos: self.nanos.checked_sub(other.nanos)?,
+        })
+    }
+
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(MonthsDaysNanos {
+            months: self.months.checked_neg()?,
+            days: self.days.checked_neg()?,
+            nanos: self.nanos.checked_neg()?,
+        })
+    }
+}
+
+impl Display for MonthsDaysNanos {
+    /// Formats the interval like `1 year 2 months 3 days 04:05:06.789`, the zero parts
+    /// are omitted.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut parts = Vec::with_capacity(4);
+        let mut push_part = |value: i32, unit: &str| {
+            if value != 0 {
+                let plural = if value.abs() == 1 { "" } else { "s" };
+                parts.push(format!("{value} {unit}{plural}"));
+            }
+        };
+        push_part(self.months / 12, "year");
+        push_part(self.months % 12, "month");
+        push_part(self.days, "day");
+
+        if self.nanos != 0 || parts.is_empty() {
+            let sign = if self.nanos < 0 { "-" } else { "" };
+            let nanos = self.nanos.unsigned_abs();
+            let secs = nanos / NANOS_PER_SEC as u64;
+            let mut time = format!(
+                "{sign}{:02}:{:02}:{:02}",
+                secs / 3600,
+                secs / 60 % 60,
+                secs % 60
+            );
+            let frac = nanos % NANOS_PER_SEC as u64;
+            if frac != 0 {
+                let frac = format!("{frac:09}");
+                time.push('.');
+                time.push_str(frac.trim_end_matches('0'));
+            }
+            parts.push(time);
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalType;
+
+impl ValueType for IntervalType {
+    type Scalar = MonthsDaysNanos;
+    type ScalarRef<'a> = MonthsDaysNanos;
+    type Column = Buffer<MonthsDaysNanos>;
+    type Domain = ();
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, MonthsDaysNanos>>;
+    type ColumnBuilder = Vec<MonthsDaysNanos>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: MonthsDaysNanos) -> MonthsDaysNanos {
+        long
+    }
+
+    fn to_owned_scalar<'a>(scalar: Self::ScalarRef<'a>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref<'a>(scalar: &'a Self::Scalar) -> Self::ScalarRef<'a> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::Interval(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column<'a>(col: &'a Column) -> Option<Self::Column> {
+        match col {
+            Column::Interval(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
+        if domain.is_undefined() {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn try_downcast_builder<'a>(
+        builder: &'a mut ColumnBuilder,
+    ) -> Option<&'a mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Interval(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Interval(col)
+    }
+
+    fn upcast_domain(_domain: Self::Domain) -> Domain {
+        Domain::Undefined
+    }
+
+    fn column_len<'a>(col: &'a Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column<'a>(col: &'a Self::Column, index: usize) -> Option<Self::ScalarRef<'a>> {
+        col.get(index).cloned()
+    }
+
+    unsafe fn index_column_unchecked<'a>(
+        col: &'a Self::Column,
+        index: usize,
+    ) -> Self::ScalarRef<'a> {
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column<'a>(col: &'a Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column<'a>(col: &'a Self::Column) -> Self::ColumnIterator<'a> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+}
+
+impl ArgType for IntervalType {
+    fn data_type() -> DataType {
+        DataType::Interval
+    }
+
+    fn full_domain() -> Self::Domain {}
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
+
+/// Parse an interval from text like `1 year 2 months 3 days 04:05:06.789`.
+///
+/// The text is a list of `<quantity> <unit>` pairs, optionally followed by a
+/// `[-]HH:MM:SS[.fraction]` time part. Units may be singular or plural.
+pub fn string_to_interval(interval_str: impl AsRef<[u8]>) -> Result<MonthsDaysNanos, String> {
+    let text = std::str::from_utf8(interval_str.as_ref()).map_err(|e| e.to_string())?;
+    let invalid = || format!("invalid interval: {text:?}");
+
+    let mut interval = MonthsDaysNanos::default();
+    let mut has_part = false;
+    let mut tokens = text.split_whitespace();
+    while let Some(token) = tokens.next() {
+        has_part = true;
+        if token.contains(':') {
+            let nanos = parse_time_part(token).ok_or_else(invalid)?;
+            interval.nanos = interval.nanos.checked_add(nanos).ok_or_else(invalid)?;
+            continue;
+        }
+
+        let quantity: i64 = token.parse().map_err(|_| invalid())?;
+        let unit = tokens.next().ok_or_else(invalid)?.to_ascii_lowercase();
+        let part = match unit.as_str() {
+            "year" | "years" | "y" => MonthsDaysNanos::new(to_i32(quantity.checked_mul(12))?, 0, 0),
+            "month" | "months" | "mon" | "mons" => {
+                MonthsDaysNanos::new(to_i32(Some(quantity))?, 0, 0)
+            }
+            "week" | "weeks" | "w" => MonthsDaysNanos::new(0, to_i32(quantity.checked_mul(7))?, 0),
+            "day" | "days" | "d" => MonthsDaysNanos::new(0, to_i32(Some(quantity))?, 0),
+            "hour" | "hours" | "h" => MonthsDaysNanos::new(0, 0, to_i64(quantity, NANOS_PER_HOUR)?),
+            "minute" | "minutes" | "min" | "mins" | "m" => {
+                MonthsDaysNanos::new(0, 0, to_i64(quantity, NANOS_PER_MINUTE)?)
+            }
+            "second" | "seconds" | "sec" | "secs" | "s" => {
+                MonthsDaysNanos::new(0, 0, to_i64(quantity, NANOS_PER_SEC)?)
+            }
+            "millisecond" | "milliseconds" | "ms" => {
+                MonthsDaysNanos::new(0, 0, to_i64(quantity, NANOS_PER_MILLI)?)
+            }
+            "microsecond" | "microseconds" | "us" => {
+                MonthsDaysNanos::new(0, 0, to_i64(quantity, NANOS_PER_MICRO)?)
+            }
+            "nanosecond" | "nanoseconds" | "ns" => MonthsDaysNanos::new(0, 0, quantity),
+            _ => return Err(invalid()),
+        };
+        interval = interval.checked_add(&part).ok_or_else(invalid)?;
+    }
+
+    if !has_part {
+        return Err(invalid());
+    }
+    Ok(interval)
+}
+
+fn to_i32(value: Option<i64>) -> Result<i32, String> {
+    value
+        .and_then(|v| i32::try_from(v).ok())
+        .ok_or_else(|| "interval is out of range".to_string())
+}
+
+fn to_i64(quantity: i64, nanos_per_unit: i64) -> Result<i64, String> {
+    quantity
+        .checked_mul(nanos_per_unit)
+        .ok_or_else(|| "interval is out of range".to_string())
+}
+
+/// Parse `[-]HH:MM:SS[.fraction]` or `[-]HH:MM` into nanoseconds.
+fn parse_time_part(text: &str) -> Option<i64> {
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(text) => (true, text),
+        None => (false, text),
+    };
+    let (text, frac) = match text.split_once('.') {
+        Some((text, frac)) => (text, Some(frac)),
+        None => (text, None),
+    };
+
+    let mut parts = text.split(':');
+    let hours: i64 = parts.next()?.parse().ok()?;
+    let minutes: i64 = parts.next()?.parse().ok()?;
+    let seconds: i64 = match parts.next() {
+        Some(seconds) => seconds.parse().ok()?,
+        None if frac.is_none() => 0,
+        None => return None,
+    };
+    if parts.next().is_some() || hours < 0 || !(0..60).contains(&minutes) {
+        return None;
+    }
+    if !(0..60).contains(&seconds) {
+        return None;
+    }
+
+    let mut nanos = hours
+        .checked_mul(NANOS_PER_HOUR)?
+        .checked_add(minutes * NANOS_PER_MINUTE + seconds * NANOS_PER_SEC)?;
+    if let Some(frac) = frac {
+        if frac.is_empty() || frac.len() > 9 || !frac.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let frac_nanos: i64 = format!("{frac:0<9}").parse().ok()?;
+        nanos = nanos.checked_add(frac_nanos)?;
+    }
+    Some(if negative { -nanos } else { nanos })
+}
@@ -0,0 +1,287 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::Range;
+
+use common_arrow::arrow::buffer::Buffer;
+
+use super::number::SimpleDomain;
+use crate::property::Domain;
+use crate::types::timestamp::MICROS_IN_A_SEC;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const MICROS_IN_A_MINUTE: i64 = 60 * MICROS_IN_A_SEC;
+pub const MICROS_IN_AN_HOUR: i64 = 60 * MICROS_IN_A_MINUTE;
+pub const MICROS_IN_A_DAY: i64 = 24 * MICROS_IN_AN_HOUR;
+
+/// Minimum valid time `00:00:00.000000`, represented by the microsecs offset from midnight.
+pub const TIME_MIN: i64 = 0;
+/// Maximum valid time `23:59:59.999999`, represented by the microsecs offset from midnight.
+pub const TIME_MAX: i64 = MICROS_IN_A_DAY - 1;
+
+/// Check if the time value is valid.
+#[inline]
+pub fn check_time(micros: i64) -> Result<i64, String> {
+    if (TIME_MIN..=TIME_MAX).contains(&micros) {
+        Ok(micros)
+    } else {
+        Err("time is out of range".to_string())
+    }
+}
+
+/// Wraps the microsecs offset around a day, so that `23:00:00 + 2 hours` is `01:00:00`.
+#[inline]
+pub fn wrap_time(micros: i64) -> i64 {
+    micros.rem_euclid(MICROS_IN_A_DAY)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeType;
+
+impl ValueType for TimeType {
+    type Scalar = i64;
+    type ScalarRef<'a> = i64;
+    type Column = Buffer<i64>;
+    type Domain = SimpleDomain<i64>;
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, i64>>;
+    type ColumnBuilder = Vec<i64>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: i64) -> i64 {
+        long
+    }
+
+    fn to_owned_scalar<'a>(scalar: Self::ScalarRef<'a>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref<'a>(scalar: &'a Self::Scalar) -> Self::ScalarRef<'a> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::Time(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column<'a>(col: &'a Column) -> Option<Self::Column> {
+        match col {
+            Column::Time(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<SimpleDomain<i64>> {
+        domain.as_time().map(SimpleDomain::clone)
+    }
+
+    fn try_downcast_builder<'a>(
+        builder: &'a mut ColumnBuilder,
+    ) -> Option<&'a mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Time(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Time(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Time(col)
+    }
+
+    fn upcast_domain(domain: SimpleDomain<i64>) -> Domain {
+        Domain::Time(domain)
+    }
+
+    fn column_len<'a>(col: &'a Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column<'a>(col: &'a Self::Column, index: usize) -> Option<Self::ScalarRef<'a>> {
+        col.get(index).cloned()
+    }
+
+    unsafe fn index_column_unchecked<'a>(
+        col: &'a Self::Column,
+        index: usize,
+    ) -> Self::ScalarRef<'a> {
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column<'a>(col: &'a Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column<'a>(col: &'a Self::Column) -> Self::ColumnIterator<'a> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+}
+
+impl ArgType for TimeType {
+    fn data_type() -> DataType {
+        DataType::Time
+    }
+
+    fn full_domain() -> Self::Domain {
+        SimpleDomain {
+            min: TIME_MIN,
+            max: TIME_MAX,
+        }
+    }
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
+
+/// Parses a time of day like `12:34`, `12:34:56` or `12:34:56.789`.
+pub fn string_to_time(time_str: impl AsRef<[u8]>) -> Result<i64, String> {
+    let err = || {
+        format!(
+            "cannot parse `{}` to type `TIME`",
+            String::from_utf8_lossy(time_str.as_ref())
+        )
+    };
+    let s = std::str::from_utf8(time_str.as_ref())
+        .map_err(|_| err())?
+        .trim();
+
+    let (hms, frac) = match s.split_once('.') {
+        Some((hms, frac)) => (hms, Some(frac)),
+        None => (s, None),
+    };
+    let parts = hms.split(':').collect::<Vec<_>>();
+    if !(2..=3).contains(&parts.len()) {
+        return Err(err());
+    }
+
+    let parse_part = |part: &str, max: i64| -> Result<i64, String> {
+        if part.is_empty() || part.len() > 2 || !part.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(err());
+        }
+        let v = part.parse::<i64>().map_err(|_| err())?;
+        if v > max {
+            return Err(err());
+        }
+        Ok(v)
+    };
+    let hours = parse_part(parts[0], 23)?;
+    let minutes = parse_part(parts[1], 59)?;
+    let seconds = match parts.get(2) {
+        Some(part) => parse_part(part, 59)?,
+        None => 0,
+    };
+
+    let micros = match frac {
+        Some(frac) => {
+            if frac.is_empty() || !frac.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(err());
+            }
+            // Only microsecond precision is kept.
+            let digits = &frac[..frac.len().min(6)];
+            digits.parse::<i64>().map_err(|_| err())? * 10_i64.pow(6 - digits.len() as u32)
+        }
+        None => 0,
+    };
+
+    Ok(hours * MICROS_IN_AN_HOUR
+        + minutes * MICROS_IN_A_MINUTE
+        + seconds * MICROS_IN_A_SEC
+        + micros)
+}
+
+pub fn time_to_string(micros: i64) -> impl Display {
+    TimeDisplay(micros)
+}
+
+struct TimeDisplay(i64);
+
+impl Display for TimeDisplay {
+    /// Formats the time like `12:34:56`, the fraction part is only shown if it's not zero.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let micros = self.0;
+        let secs = micros / MICROS_IN_A_SEC;
+        write!(
+            f,
+            "{:02}:{:02}:{:02}",
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60
+        )?;
+        let frac = micros % MICROS_IN_A_SEC;
+        if frac != 0 {
+            write!(f, ".{:06}", frac)?;
+        }
+        Ok(())
+    }
+}
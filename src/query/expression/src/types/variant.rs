@@ -14,10 +14,12 @@
 
 use std::ops::Range;
 
+use common_io::wkb_to_wkt;
 use roaring::RoaringTreemap;
 
 use super::date::date_to_string;
 use super::number::NumberScalar;
+use super::time::time_to_string;
 use super::timestamp::timestamp_to_string;
 use crate::date_helper::TzLUT;
 use crate::property::Domain;
@@ -196,6 +198,8 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
         ScalarRef::String(s) => jsonb::Value::String(String::from_utf8_lossy(s)),
         ScalarRef::Timestamp(ts) => timestamp_to_string(ts, inner_tz).to_string().into(),
         ScalarRef::Date(d) => date_to_string(d, inner_tz).to_string().into(),
+        ScalarRef::Time(t) => time_to_string(t).to_string().into(),
+        ScalarRef::Interval(i) => i.to_string().into(),
         ScalarRef::Array(col) => {
             let items = cast_scalars_to_variants(col.iter(), tz);
             jsonb::build_array(items.iter(), buf).expect("failed to build jsonb array");
@@ -251,6 +255,10 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
             buf.extend_from_slice(bytes);
             return;
         }
+        ScalarRef::Geometry(bytes) => wkb_to_wkt(bytes)
+            .expect("failed to convert geometry to wkt")
+            .into(),
+        ScalarRef::Binary(bytes) => hex::encode_upper(bytes).into(),
     };
     value.write_to_vec(buf);
 }
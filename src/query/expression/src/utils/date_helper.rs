@@ -30,6 +30,8 @@ use num_traits::AsPrimitive;
 use once_cell::sync::Lazy;
 
 use crate::types::date::check_date;
+use crate::types::interval::MonthsDaysNanos;
+use crate::types::interval::NANOS_PER_MICRO;
 use crate::types::timestamp::check_timestamp;
 use crate::types::timestamp::MICROS_IN_A_SEC;
 
@@ -380,6 +382,45 @@ impl AddTimesImpl {
     }
 }
 
+pub struct AddIntervalImpl;
+
+impl AddIntervalImpl {
+    /// Add the interval to the date, the result is a timestamp as the interval may
+    /// contain a time part. The date is taken as the midnight of the time zone.
+    pub fn eval_date(date: i32, tz: TzLUT, interval: MonthsDaysNanos) -> Result<i64, String> {
+        let epoch_offset = tz
+            .tz
+            .from_utc_datetime(
+                &NaiveDate::from_ymd_opt(1970, 1, 1)
+                    .unwrap()
+                    .and_hms_micro_opt(0, 0, 0, 0)
+                    .unwrap(),
+            )
+            .naive_local()
+            .timestamp_micros();
+        let us = (date as i64) * 24 * 3600 * MICROS_IN_A_SEC - epoch_offset;
+        Self::eval_timestamp(us, tz, interval)
+    }
+
+    /// Add the interval to the timestamp.
+    ///
+    /// The months and days parts are applied on the calendar of the time zone, then the
+    /// nanoseconds part is added as an exact duration.
+    pub fn eval_timestamp(us: i64, tz: TzLUT, interval: MonthsDaysNanos) -> Result<i64, String> {
+        let mut us = us;
+        if interval.months != 0 {
+            us = AddMonthsImpl::eval_timestamp(us, tz, interval.months)?;
+        }
+        if interval.days != 0 {
+            us = AddDaysImpl::eval_timestamp(us, interval.days)?;
+        }
+        let us = us
+            .checked_add(interval.nanos / NANOS_PER_MICRO)
+            .ok_or_else(|| "timestamp is out of range".to_string())?;
+        check_timestamp(us)
+    }
+}
+
 #[inline]
 pub fn today_date() -> i32 {
     let now = Utc::now();
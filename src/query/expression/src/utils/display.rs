@@ -21,6 +21,7 @@ use comfy_table::Cell;
 use comfy_table::Table;
 use common_io::display_decimal_128;
 use common_io::display_decimal_256;
+use common_io::wkb_to_wkt;
 use itertools::Itertools;
 use num_traits::FromPrimitive;
 use roaring::RoaringTreemap;
@@ -49,6 +50,7 @@ use crate::types::number::NumberScalar;
 use crate::types::number::SimpleDomain;
 use crate::types::string::StringColumn;
 use crate::types::string::StringDomain;
+use crate::types::time::time_to_string;
 use crate::types::timestamp::timestamp_to_string;
 use crate::types::AnyType;
 use crate::types::DataType;
@@ -126,6 +128,8 @@ impl<'a> Debug for ScalarRef<'a> {
             },
             ScalarRef::Timestamp(t) => write!(f, "{t:?}"),
             ScalarRef::Date(d) => write!(f, "{d:?}"),
+            ScalarRef::Time(t) => write!(f, "{t:?}"),
+            ScalarRef::Interval(i) => write!(f, "{i:?}"),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
                 write!(f, "{{")?;
@@ -158,6 +162,8 @@ impl<'a> Debug for ScalarRef<'a> {
                 write!(f, ")")
             }
             ScalarRef::Variant(s) => write!(f, "0x{}", &hex::encode(s)),
+            ScalarRef::Geometry(s) => write!(f, "0x{}", &hex::encode(s)),
+            ScalarRef::Binary(s) => write!(f, "0x{}", &hex::encode(s)),
         }
     }
 }
@@ -174,12 +180,16 @@ impl Debug for Column {
             Column::String(col) => write!(f, "{col:?}"),
             Column::Timestamp(col) => write!(f, "{col:?}"),
             Column::Date(col) => write!(f, "{col:?}"),
+            Column::Time(col) => write!(f, "{col:?}"),
+            Column::Interval(col) => write!(f, "{col:?}"),
             Column::Array(col) => write!(f, "{col:?}"),
             Column::Map(col) => write!(f, "{col:?}"),
             Column::Bitmap(col) => write!(f, "{col:?}"),
             Column::Nullable(col) => write!(f, "{col:?}"),
             Column::Tuple(fields) => f.debug_tuple("Tuple").field(fields).finish(),
             Column::Variant(col) => write!(f, "{col:?}"),
+            Column::Geometry(col) => write!(f, "{col:?}"),
+            Column::Binary(col) => write!(f, "{col:?}"),
         }
     }
 }
@@ -205,6 +215,8 @@ impl<'a> Display for ScalarRef<'a> {
             },
             ScalarRef::Timestamp(t) => write!(f, "'{}'", timestamp_to_string(*t, Tz::UTC)),
             ScalarRef::Date(d) => write!(f, "'{}'", date_to_string(*d as i64, Tz::UTC)),
+            ScalarRef::Time(t) => write!(f, "'{}'", time_to_string(*t)),
+            ScalarRef::Interval(i) => write!(f, "'{i}'"),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
                 write!(f, "{{")?;
@@ -243,6 +255,11 @@ impl<'a> Display for ScalarRef<'a> {
                 let value = jsonb::to_string(s);
                 write!(f, "{value}")
             }
+            ScalarRef::Geometry(s) => match wkb_to_wkt(s) {
+                Ok(wkt) => write!(f, "{wkt}"),
+                Err(_) => write!(f, "0x{}", &hex::encode(s)),
+            },
+            ScalarRef::Binary(s) => write!(f, "{}", hex::encode_upper(s)),
         }
     }
 }
@@ -437,7 +454,7 @@ impl<Index: ColumnIndex> Display for RawExpr<Index> {
                 }
                 write!(f, ")")
             }
-            RawExpr::UDFServerCall {
+            RawExpr::UDFCall {
                 func_name, args, ..
             } => {
                 write!(f, "{}(", func_name)?;
@@ -462,6 +479,8 @@ impl Display for DataType {
             DataType::Decimal(decimal) => write!(f, "{decimal}"),
             DataType::Timestamp => write!(f, "Timestamp"),
             DataType::Date => write!(f, "Date"),
+            DataType::Time => write!(f, "Time"),
+            DataType::Interval => write!(f, "Interval"),
             DataType::Null => write!(f, "NULL"),
             DataType::Nullable(inner) => write!(f, "{inner} NULL"),
             DataType::EmptyArray => write!(f, "Array(Nothing)"),
@@ -488,6 +507,8 @@ impl Display for DataType {
                 write!(f, ")")
             }
             DataType::Variant => write!(f, "Variant"),
+            DataType::Geometry => write!(f, "Geometry"),
+            DataType::Binary => write!(f, "Binary"),
             DataType::Generic(index) => write!(f, "T{index}"),
         }
     }
@@ -502,6 +523,7 @@ impl Display for TableDataType {
             TableDataType::Decimal(decimal) => write!(f, "{decimal}"),
             TableDataType::Timestamp => write!(f, "Timestamp"),
             TableDataType::Date => write!(f, "Date"),
+            TableDataType::Time => write!(f, "Time"),
             TableDataType::Null => write!(f, "NULL"),
             TableDataType::Nullable(inner) => write!(f, "{inner} NULL"),
             TableDataType::EmptyArray => write!(f, "Array(Nothing)"),
@@ -534,6 +556,8 @@ impl Display for TableDataType {
                 write!(f, ")")
             }
             TableDataType::Variant => write!(f, "Variant"),
+            TableDataType::Geometry => write!(f, "Geometry"),
+            TableDataType::Binary => write!(f, "Binary"),
         }
     }
 }
@@ -652,7 +676,7 @@ impl<Index: ColumnIndex> Display for Expr<Index> {
                 }
                 write!(f, ")")
             }
-            Expr::UDFServerCall {
+            Expr::UDFCall {
                 func_name, args, ..
             } => {
                 write!(f, "{}(", func_name)?;
@@ -796,7 +820,7 @@ impl<Index: ColumnIndex> Expr<Index> {
                         s
                     }
                 },
-                Expr::UDFServerCall {
+                Expr::UDFCall {
                     func_name, args, ..
                 } => {
                     let mut s = String::new();
@@ -968,6 +992,7 @@ impl Display for Domain {
             Domain::String(domain) => write!(f, "{domain}"),
             Domain::Timestamp(domain) => write!(f, "{domain}"),
             Domain::Date(domain) => write!(f, "{domain}"),
+            Domain::Time(domain) => write!(f, "{domain}"),
             Domain::Nullable(domain) => write!(f, "{domain}"),
             Domain::Array(None) => write!(f, "[]"),
             Domain::Array(Some(domain)) => write!(f, "[{domain}]"),
@@ -0,0 +1,238 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime of the user-defined functions written in WebAssembly.
+//!
+//! The module must not import anything, and exports the handler function taking one wasm
+//! value per argument and returning one wasm value. The SQL types are passed as:
+//!
+//! - `BOOLEAN`, `INT8`, `INT16`, `INT32`, `UINT8`, `UINT16`, `UINT32`, `DATE`: `i32`
+//! - `INT64`, `UINT64`, `TIMESTAMP`: `i64`
+//! - `FLOAT32`: `f32`
+//! - `FLOAT64`: `f64`
+//!
+//! The handler is called once per row, a row with `NULL` in any argument returns `NULL`
+//! without calling the handler.
+
+use std::sync::Arc;
+
+use base64::engine::general_purpose;
+use base64::prelude::*;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use dashmap::DashMap;
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+use ordered_float::OrderedFloat;
+use wasmtime::Engine;
+use wasmtime::Func;
+use wasmtime::Instance;
+use wasmtime::Module;
+use wasmtime::Store;
+use wasmtime::Val;
+use wasmtime::ValType;
+
+use crate::types::number::NumberScalar;
+use crate::types::AnyType;
+use crate::types::DataType;
+use crate::types::NumberDataType;
+use crate::values::ScalarRef;
+use crate::values::Value;
+use crate::ColumnBuilder;
+use crate::Scalar;
+
+const WASM_MAGIC: &[u8] = b"\0asm";
+
+static WASM_ENGINE: Lazy<Engine> = Lazy::new(Engine::default);
+
+// Compiled modules, keyed by the module bytes.
+static WASM_MODULES: Lazy<DashMap<Arc<Vec<u8>>, Module>> = Lazy::new(DashMap::new);
+
+/// Decodes the code of `CREATE FUNCTION ... LANGUAGE wasm AS '<code>'`, which is either
+/// a base64 encoded binary module or a module in the WebAssembly text format.
+pub fn decode_wasm_code(code: &str) -> Vec<u8> {
+    match general_purpose::STANDARD.decode(code.trim()) {
+        Ok(bytes) if bytes.starts_with(WASM_MAGIC) => bytes,
+        _ => code.as_bytes().to_vec(),
+    }
+}
+
+/// Checks the module exports `handler` of the signature matching the SQL types.
+pub fn check_wasm_udf(
+    module: &Arc<Vec<u8>>,
+    handler: &str,
+    arg_types: &[DataType],
+    return_type: &DataType,
+) -> Result<()> {
+    let module = compile(module)?;
+    let mut store = Store::new(&WASM_ENGINE, ());
+    let func = instantiate(&mut store, &module, handler)?;
+    let ty = func.ty(&store);
+
+    let mut expected_params = Vec::with_capacity(arg_types.len());
+    for arg_type in arg_types {
+        expected_params.push(wasm_type(arg_type)?);
+    }
+    let expected_result = wasm_type(return_type)?;
+    let params = ty.params().collect::<Vec<_>>();
+    let results = ty.results().collect::<Vec<_>>();
+    if params != expected_params || results != [expected_result] {
+        return Err(ErrorCode::UDFSchemaMismatch(format!(
+            "WASM UDF handler '{handler}' has signature ({}) -> ({}), but ({}) -> ({}) is expected",
+            params.iter().join(", "),
+            results.iter().join(", "),
+            expected_params.iter().join(", "),
+            expected_result,
+        )));
+    }
+    Ok(())
+}
+
+/// Evaluates the handler over the rows of the arguments.
+pub fn run_wasm_udf(
+    module: &Arc<Vec<u8>>,
+    handler: &str,
+    args: &[Value<AnyType>],
+    return_type: &DataType,
+    num_rows: usize,
+) -> Result<Value<AnyType>> {
+    let module = compile(module)?;
+    let mut store = Store::new(&WASM_ENGINE, ());
+    let func = instantiate(&mut store, &module, handler)?;
+    let inner_return_type = return_type.remove_nullable();
+
+    let mut builder = ColumnBuilder::with_capacity(return_type, num_rows);
+    let mut params = Vec::with_capacity(args.len());
+    let mut results = [Val::I32(0)];
+    for row in 0..num_rows {
+        params.clear();
+        for arg in args {
+            let scalar = arg.index(row).unwrap();
+            if scalar == ScalarRef::Null {
+                break;
+            }
+            params.push(to_wasm_val(&scalar)?);
+        }
+        if params.len() < args.len() {
+            builder.push(ScalarRef::Null);
+            continue;
+        }
+
+        func.call(&mut store, &params, &mut results)
+            .map_err(|err| {
+                ErrorCode::UDFDataError(format!("WASM UDF handler '{handler}' failed: {err}"))
+            })?;
+        let scalar = from_wasm_val(&results[0], &inner_return_type)?;
+        builder.push(scalar.as_ref());
+    }
+    Ok(Value::Column(builder.build()))
+}
+
+fn compile(module: &Arc<Vec<u8>>) -> Result<Module> {
+    if let Some(compiled) = WASM_MODULES.get(module) {
+        return Ok(compiled.clone());
+    }
+    let compiled = Module::new(&WASM_ENGINE, module.as_slice())
+        .map_err(|err| ErrorCode::UDFDataError(format!("Invalid WASM module: {err}")))?;
+    WASM_MODULES.insert(module.clone(), compiled.clone());
+    Ok(compiled)
+}
+
+fn instantiate(store: &mut Store<()>, module: &Module, handler: &str) -> Result<Func> {
+    // Nothing is imported, so the module is not able to access anything out of the sandbox.
+    let instance = Instance::new(&mut *store, module, &[])
+        .map_err(|err| ErrorCode::UDFDataError(format!("Cannot instantiate WASM module: {err}")))?;
+    instance.get_func(&mut *store, handler).ok_or_else(|| {
+        ErrorCode::UDFDataError(format!("WASM module does not export function '{handler}'"))
+    })
+}
+
+fn wasm_type(data_type: &DataType) -> Result<ValType> {
+    match data_type.remove_nullable() {
+        DataType::Boolean | DataType::Date => Ok(ValType::I32),
+        DataType::Timestamp => Ok(ValType::I64),
+        DataType::Number(ty) => match ty {
+            NumberDataType::Int8
+            | NumberDataType::Int16
+            | NumberDataType::Int32
+            | NumberDataType::UInt8
+            | NumberDataType::UInt16
+            | NumberDataType::UInt32 => Ok(ValType::I32),
+            NumberDataType::Int64 | NumberDataType::UInt64 => Ok(ValType::I64),
+            NumberDataType::Float32 => Ok(ValType::F32),
+            NumberDataType::Float64 => Ok(ValType::F64),
+        },
+        ty => Err(ErrorCode::UDFSchemaMismatch(format!(
+            "Type {ty} is not supported by WASM UDF"
+        ))),
+    }
+}
+
+fn to_wasm_val(scalar: &ScalarRef) -> Result<Val> {
+    let val = match scalar {
+        ScalarRef::Boolean(v) => Val::I32(*v as i32),
+        ScalarRef::Date(v) => Val::I32(*v),
+        ScalarRef::Timestamp(v) => Val::I64(*v),
+        ScalarRef::Number(NumberScalar::Int8(v)) => Val::I32(*v as i32),
+        ScalarRef::Number(NumberScalar::Int16(v)) => Val::I32(*v as i32),
+        ScalarRef::Number(NumberScalar::Int32(v)) => Val::I32(*v),
+        ScalarRef::Number(NumberScalar::UInt8(v)) => Val::I32(*v as i32),
+        ScalarRef::Number(NumberScalar::UInt16(v)) => Val::I32(*v as i32),
+        ScalarRef::Number(NumberScalar::UInt32(v)) => Val::I32(*v as i32),
+        ScalarRef::Number(NumberScalar::Int64(v)) => Val::I64(*v),
+        ScalarRef::Number(NumberScalar::UInt64(v)) => Val::I64(*v as i64),
+        ScalarRef::Number(NumberScalar::Float32(v)) => Val::F32(v.0.to_bits()),
+        ScalarRef::Number(NumberScalar::Float64(v)) => Val::F64(v.0.to_bits()),
+        other => {
+            return Err(ErrorCode::UDFDataError(format!(
+                "Value {other} is not supported by WASM UDF"
+            )));
+        }
+    };
+    Ok(val)
+}
+
+fn from_wasm_val(val: &Val, data_type: &DataType) -> Result<Scalar> {
+    let scalar = match (data_type, val) {
+        (DataType::Boolean, Val::I32(v)) => Scalar::Boolean(*v != 0),
+        (DataType::Date, Val::I32(v)) => Scalar::Date(*v),
+        (DataType::Timestamp, Val::I64(v)) => Scalar::Timestamp(*v),
+        (DataType::Number(ty), val) => Scalar::Number(match (ty, val) {
+            (NumberDataType::Int8, Val::I32(v)) => NumberScalar::Int8(*v as i8),
+            (NumberDataType::Int16, Val::I32(v)) => NumberScalar::Int16(*v as i16),
+            (NumberDataType::Int32, Val::I32(v)) => NumberScalar::Int32(*v),
+            (NumberDataType::UInt8, Val::I32(v)) => NumberScalar::UInt8(*v as u8),
+            (NumberDataType::UInt16, Val::I32(v)) => NumberScalar::UInt16(*v as u16),
+            (NumberDataType::UInt32, Val::I32(v)) => NumberScalar::UInt32(*v as u32),
+            (NumberDataType::Int64, Val::I64(v)) => NumberScalar::Int64(*v),
+            (NumberDataType::UInt64, Val::I64(v)) => NumberScalar::UInt64(*v as u64),
+            (NumberDataType::Float32, Val::F32(v)) => {
+                NumberScalar::Float32(OrderedFloat(f32::from_bits(*v)))
+            }
+            (NumberDataType::Float64, Val::F64(v)) => {
+                NumberScalar::Float64(OrderedFloat(f64::from_bits(*v)))
+            }
+            _ => return Err(mismatch(val, data_type)),
+        }),
+        _ => return Err(mismatch(val, data_type)),
+    };
+    Ok(scalar)
+}
+
+fn mismatch(val: &Val, data_type: &DataType) -> ErrorCode {
+    ErrorCode::UDFSchemaMismatch(format!(
+        "WASM UDF returns {}, which can not be converted to {data_type}",
+        val.ty()
+    ))
+}
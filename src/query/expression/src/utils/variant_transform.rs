@@ -37,7 +37,11 @@ pub fn contains_variant(data_type: &DataType) -> bool {
         | DataType::Decimal(_)
         | DataType::Timestamp
         | DataType::Date
+        | DataType::Time
+        | DataType::Interval
         | DataType::Bitmap
+        | DataType::Geometry
+        | DataType::Binary
         | DataType::Generic(_) => false,
         DataType::Nullable(ty) => contains_variant(ty.as_ref()),
         DataType::Array(ty) => contains_variant(ty.as_ref()),
@@ -74,9 +78,13 @@ fn transform_scalar(scalar: ScalarRef<'_>, decode: bool) -> Result<Scalar> {
         | ScalarRef::Decimal(_)
         | ScalarRef::Timestamp(_)
         | ScalarRef::Date(_)
+        | ScalarRef::Time(_)
+        | ScalarRef::Interval(_)
         | ScalarRef::Boolean(_)
         | ScalarRef::String(_)
-        | ScalarRef::Bitmap(_) => scalar.to_owned(),
+        | ScalarRef::Bitmap(_)
+        | ScalarRef::Geometry(_)
+        | ScalarRef::Binary(_) => scalar.to_owned(),
         ScalarRef::Array(col) => Scalar::Array(transform_column(&col, decode)?),
         ScalarRef::Map(col) => Scalar::Map(transform_column(&col, decode)?),
         ScalarRef::Tuple(scalars) => {
@@ -25,10 +25,13 @@ use common_arrow::arrow::bitmap::MutableBitmap;
 use common_arrow::arrow::buffer::Buffer;
 use common_arrow::arrow::compute::cast as arrow_cast;
 use common_arrow::arrow::datatypes::DataType as ArrowType;
+use common_arrow::arrow::datatypes::IntervalUnit;
 use common_arrow::arrow::datatypes::TimeUnit;
 use common_arrow::arrow::offset::OffsetsBuffer;
 use common_arrow::arrow::trusted_len::TrustedLen;
+use common_arrow::arrow::types::months_days_ns;
 use common_exception::Result;
+use common_io::parse_to_wkb;
 use common_io::prelude::BinaryRead;
 use enum_as_inner::EnumAsInner;
 use ethnum::i256;
@@ -44,6 +47,7 @@ use serde::Serializer;
 use crate::property::Domain;
 use crate::types::array::ArrayColumn;
 use crate::types::array::ArrayColumnBuilder;
+use crate::types::binary::BinaryType;
 use crate::types::bitmap::BitmapType;
 use crate::types::boolean::BooleanDomain;
 use crate::types::date::DATE_MAX;
@@ -54,6 +58,9 @@ use crate::types::decimal::DecimalColumnBuilder;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalScalar;
 use crate::types::decimal::DecimalSize;
+use crate::types::geometry::GeometryType;
+use crate::types::interval::MonthsDaysNanos;
+use crate::types::interval::NANOS_PER_HOUR;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableColumnBuilder;
 use crate::types::nullable::NullableColumnVec;
@@ -67,6 +74,9 @@ use crate::types::number::F64;
 use crate::types::string::StringColumn;
 use crate::types::string::StringColumnBuilder;
 use crate::types::string::StringDomain;
+use crate::types::time::check_time;
+use crate::types::time::TIME_MAX;
+use crate::types::time::TIME_MIN;
 use crate::types::timestamp::check_timestamp;
 use crate::types::timestamp::TIMESTAMP_MAX;
 use crate::types::timestamp::TIMESTAMP_MIN;
@@ -106,6 +116,8 @@ pub enum Scalar {
     Decimal(DecimalScalar),
     Timestamp(i64),
     Date(i32),
+    Time(i64),
+    Interval(MonthsDaysNanos),
     Boolean(bool),
     String(Vec<u8>),
     Array(Column),
@@ -113,6 +125,8 @@ pub enum Scalar {
     Bitmap(Vec<u8>),
     Tuple(Vec<Scalar>),
     Variant(Vec<u8>),
+    Geometry(Vec<u8>),
+    Binary(Vec<u8>),
 }
 
 #[derive(Clone, Default, Eq, EnumAsInner)]
@@ -127,11 +141,15 @@ pub enum ScalarRef<'a> {
     String(&'a [u8]),
     Timestamp(i64),
     Date(i32),
+    Time(i64),
+    Interval(MonthsDaysNanos),
     Array(Column),
     Map(Column),
     Bitmap(&'a [u8]),
     Tuple(Vec<ScalarRef<'a>>),
     Variant(&'a [u8]),
+    Geometry(&'a [u8]),
+    Binary(&'a [u8]),
 }
 
 #[derive(Clone, EnumAsInner)]
@@ -145,12 +163,16 @@ pub enum Column {
     String(StringColumn),
     Timestamp(Buffer<i64>),
     Date(Buffer<i32>),
+    Time(Buffer<i64>),
+    Interval(Buffer<MonthsDaysNanos>),
     Array(Box<ArrayColumn<AnyType>>),
     Map(Box<ArrayColumn<AnyType>>),
     Bitmap(StringColumn),
     Nullable(Box<NullableColumn<AnyType>>),
     Tuple(Vec<Column>),
     Variant(StringColumn),
+    Geometry(StringColumn),
+    Binary(StringColumn),
 }
 
 #[derive(Clone, EnumAsInner, Debug, PartialEq)]
@@ -164,12 +186,16 @@ pub enum ColumnVec {
     String(Vec<StringColumn>),
     Timestamp(Vec<Buffer<i64>>),
     Date(Vec<Buffer<i32>>),
+    Time(Vec<Buffer<i64>>),
+    Interval(Vec<Buffer<MonthsDaysNanos>>),
     Array(Vec<ArrayColumn<AnyType>>),
     Map(Vec<ArrayColumn<KvPair<AnyType, AnyType>>>),
     Bitmap(Vec<StringColumn>),
     Nullable(Box<NullableColumnVec>),
     Tuple(Vec<ColumnVec>),
     Variant(Vec<StringColumn>),
+    Geometry(Vec<StringColumn>),
+    Binary(Vec<StringColumn>),
 }
 
 #[derive(Debug, Clone, EnumAsInner)]
@@ -183,12 +209,16 @@ pub enum ColumnBuilder {
     String(StringColumnBuilder),
     Timestamp(Vec<i64>),
     Date(Vec<i32>),
+    Time(Vec<i64>),
+    Interval(Vec<MonthsDaysNanos>),
     Array(Box<ArrayColumnBuilder<AnyType>>),
     Map(Box<ArrayColumnBuilder<AnyType>>),
     Bitmap(StringColumnBuilder),
     Nullable(Box<NullableColumnBuilder<AnyType>>),
     Tuple(Vec<ColumnBuilder>),
     Variant(StringColumnBuilder),
+    Geometry(StringColumnBuilder),
+    Binary(StringColumnBuilder),
 }
 
 impl<'a, T: ValueType> ValueRef<'a, T> {
@@ -328,11 +358,15 @@ impl Scalar {
             Scalar::String(s) => ScalarRef::String(s.as_slice()),
             Scalar::Timestamp(t) => ScalarRef::Timestamp(*t),
             Scalar::Date(d) => ScalarRef::Date(*d),
+            Scalar::Time(t) => ScalarRef::Time(*t),
+            Scalar::Interval(i) => ScalarRef::Interval(*i),
             Scalar::Array(col) => ScalarRef::Array(col.clone()),
             Scalar::Map(col) => ScalarRef::Map(col.clone()),
             Scalar::Bitmap(b) => ScalarRef::Bitmap(b.as_slice()),
             Scalar::Tuple(fields) => ScalarRef::Tuple(fields.iter().map(Scalar::as_ref).collect()),
             Scalar::Variant(s) => ScalarRef::Variant(s.as_slice()),
+            Scalar::Geometry(s) => ScalarRef::Geometry(s.as_slice()),
+            Scalar::Binary(s) => ScalarRef::Binary(s.as_slice()),
         }
     }
 
@@ -358,6 +392,8 @@ impl Scalar {
             DataType::Decimal(ty) => Scalar::Decimal(ty.default_scalar()),
             DataType::Timestamp => Scalar::Timestamp(0),
             DataType::Date => Scalar::Date(0),
+            DataType::Time => Scalar::Time(0),
+            DataType::Interval => Scalar::Interval(MonthsDaysNanos::default()),
             DataType::Nullable(_) => Scalar::Null,
             DataType::Array(ty) => {
                 let builder = ColumnBuilder::with_capacity(ty, 0);
@@ -377,6 +413,9 @@ impl Scalar {
             }
             DataType::Tuple(tys) => Scalar::Tuple(tys.iter().map(Scalar::default_value).collect()),
             DataType::Variant => Scalar::Variant(vec![]),
+            // WKB of `GEOMETRYCOLLECTION EMPTY`.
+            DataType::Geometry => Scalar::Geometry(vec![1, 7, 0, 0, 0, 0, 0, 0, 0]),
+            DataType::Binary => Scalar::Binary(vec![]),
 
             _ => unimplemented!(),
         }
@@ -388,6 +427,7 @@ impl Scalar {
             Scalar::Decimal(d) => d.is_positive(),
             Scalar::Timestamp(t) => *t > 0,
             Scalar::Date(d) => *d > 0,
+            Scalar::Time(t) => *t > 0,
             _ => unreachable!("is_positive() called on non-numeric scalar"),
         }
     }
@@ -405,6 +445,8 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::String(s) => Scalar::String(s.to_vec()),
             ScalarRef::Timestamp(t) => Scalar::Timestamp(*t),
             ScalarRef::Date(d) => Scalar::Date(*d),
+            ScalarRef::Time(t) => Scalar::Time(*t),
+            ScalarRef::Interval(i) => Scalar::Interval(*i),
             ScalarRef::Array(col) => Scalar::Array(col.clone()),
             ScalarRef::Map(col) => Scalar::Map(col.clone()),
             ScalarRef::Bitmap(b) => Scalar::Bitmap(b.to_vec()),
@@ -412,6 +454,8 @@ impl<'a> ScalarRef<'a> {
                 Scalar::Tuple(fields.iter().map(ScalarRef::to_owned).collect())
             }
             ScalarRef::Variant(s) => Scalar::Variant(s.to_vec()),
+            ScalarRef::Geometry(s) => Scalar::Geometry(s.to_vec()),
+            ScalarRef::Binary(s) => Scalar::Binary(s.to_vec()),
         }
     }
 
@@ -449,6 +493,7 @@ impl<'a> ScalarRef<'a> {
             }),
             ScalarRef::Timestamp(t) => Domain::Timestamp(SimpleDomain { min: *t, max: *t }),
             ScalarRef::Date(d) => Domain::Date(SimpleDomain { min: *d, max: *d }),
+            ScalarRef::Time(t) => Domain::Time(SimpleDomain { min: *t, max: *t }),
             ScalarRef::Array(array) => {
                 if array.len() == 0 {
                     Domain::Array(None)
@@ -480,7 +525,11 @@ impl<'a> ScalarRef<'a> {
                         .collect(),
                 )
             }
-            ScalarRef::Bitmap(_) | ScalarRef::Variant(_) => Domain::Undefined,
+            ScalarRef::Interval(_)
+            | ScalarRef::Bitmap(_)
+            | ScalarRef::Variant(_)
+            | ScalarRef::Geometry(_)
+            | ScalarRef::Binary(_) => Domain::Undefined,
         }
     }
 
@@ -503,11 +552,15 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::String(s) => s.len(),
             ScalarRef::Timestamp(_) => 8,
             ScalarRef::Date(_) => 4,
+            ScalarRef::Time(_) => 8,
+            ScalarRef::Interval(_) => 16,
             ScalarRef::Array(col) => col.memory_size(),
             ScalarRef::Map(col) => col.memory_size(),
             ScalarRef::Bitmap(b) => b.len(),
             ScalarRef::Tuple(scalars) => scalars.iter().map(|s| s.memory_size()).sum(),
             ScalarRef::Variant(buf) => buf.len(),
+            ScalarRef::Geometry(buf) => buf.len(),
+            ScalarRef::Binary(buf) => buf.len(),
         }
     }
 
@@ -528,6 +581,8 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::String(_) => DataType::String,
             ScalarRef::Timestamp(_) => DataType::Timestamp,
             ScalarRef::Date(_) => DataType::Date,
+            ScalarRef::Time(_) => DataType::Time,
+            ScalarRef::Interval(_) => DataType::Interval,
             ScalarRef::Array(array) => DataType::Array(Box::new(array.data_type())),
             ScalarRef::Map(col) => DataType::Map(Box::new(col.data_type())),
             ScalarRef::Bitmap(_) => DataType::Bitmap,
@@ -539,6 +594,8 @@ impl<'a> ScalarRef<'a> {
                 DataType::Tuple(inner)
             }
             ScalarRef::Variant(_) => DataType::Variant,
+            ScalarRef::Geometry(_) => DataType::Geometry,
+            ScalarRef::Binary(_) => DataType::Binary,
         }
     }
 }
@@ -555,6 +612,8 @@ impl PartialOrd for Scalar {
             (Scalar::String(s1), Scalar::String(s2)) => s1.partial_cmp(s2),
             (Scalar::Timestamp(t1), Scalar::Timestamp(t2)) => t1.partial_cmp(t2),
             (Scalar::Date(d1), Scalar::Date(d2)) => d1.partial_cmp(d2),
+            (Scalar::Time(t1), Scalar::Time(t2)) => t1.partial_cmp(t2),
+            (Scalar::Interval(i1), Scalar::Interval(i2)) => i1.partial_cmp(i2),
             (Scalar::Array(a1), Scalar::Array(a2)) => a1.partial_cmp(a2),
             (Scalar::Map(m1), Scalar::Map(m2)) => m1.partial_cmp(m2),
             (Scalar::Bitmap(b1), Scalar::Bitmap(b2)) => b1.partial_cmp(b2),
@@ -562,6 +621,8 @@ impl PartialOrd for Scalar {
             (Scalar::Variant(v1), Scalar::Variant(v2)) => {
                 jsonb::compare(v1.as_slice(), v2.as_slice()).ok()
             }
+            (Scalar::Geometry(g1), Scalar::Geometry(g2)) => g1.partial_cmp(g2),
+            (Scalar::Binary(g1), Scalar::Binary(g2)) => g1.partial_cmp(g2),
             _ => None,
         }
     }
@@ -591,11 +652,15 @@ impl PartialOrd for ScalarRef<'_> {
             (ScalarRef::String(s1), ScalarRef::String(s2)) => s1.partial_cmp(s2),
             (ScalarRef::Timestamp(t1), ScalarRef::Timestamp(t2)) => t1.partial_cmp(t2),
             (ScalarRef::Date(d1), ScalarRef::Date(d2)) => d1.partial_cmp(d2),
+            (ScalarRef::Time(t1), ScalarRef::Time(t2)) => t1.partial_cmp(t2),
+            (ScalarRef::Interval(i1), ScalarRef::Interval(i2)) => i1.partial_cmp(i2),
             (ScalarRef::Array(a1), ScalarRef::Array(a2)) => a1.partial_cmp(a2),
             (ScalarRef::Map(m1), ScalarRef::Map(m2)) => m1.partial_cmp(m2),
             (ScalarRef::Bitmap(b1), ScalarRef::Bitmap(b2)) => b1.partial_cmp(b2),
             (ScalarRef::Tuple(t1), ScalarRef::Tuple(t2)) => t1.partial_cmp(t2),
             (ScalarRef::Variant(v1), ScalarRef::Variant(v2)) => jsonb::compare(v1, v2).ok(),
+            (ScalarRef::Geometry(g1), ScalarRef::Geometry(g2)) => g1.partial_cmp(g2),
+            (ScalarRef::Binary(g1), ScalarRef::Binary(g2)) => g1.partial_cmp(g2),
             _ => None,
         }
     }
@@ -631,6 +696,8 @@ impl Hash for ScalarRef<'_> {
             ScalarRef::String(v) => v.hash(state),
             ScalarRef::Timestamp(v) => v.hash(state),
             ScalarRef::Date(v) => v.hash(state),
+            ScalarRef::Time(v) => v.hash(state),
+            ScalarRef::Interval(v) => v.hash(state),
             ScalarRef::Array(v) => {
                 let str = serialize_column(v);
                 str.hash(state);
@@ -644,6 +711,8 @@ impl Hash for ScalarRef<'_> {
                 v.hash(state);
             }
             ScalarRef::Variant(v) => v.hash(state),
+            ScalarRef::Geometry(v) => v.hash(state),
+            ScalarRef::Binary(v) => v.hash(state),
         }
     }
 }
@@ -672,6 +741,10 @@ impl PartialOrd for Column {
                 col1.iter().partial_cmp(col2.iter())
             }
             (Column::Date(col1), Column::Date(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Time(col1), Column::Time(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Interval(col1), Column::Interval(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
             (Column::Array(col1), Column::Array(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Map(col1), Column::Map(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Bitmap(col1), Column::Bitmap(col2)) => col1.iter().partial_cmp(col2.iter()),
@@ -682,6 +755,10 @@ impl PartialOrd for Column {
             (Column::Variant(col1), Column::Variant(col2)) => col1
                 .iter()
                 .partial_cmp_by(col2.iter(), |v1, v2| jsonb::compare(v1, v2).ok()),
+            (Column::Geometry(col1), Column::Geometry(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
+            (Column::Binary(col1), Column::Binary(col2)) => col1.iter().partial_cmp(col2.iter()),
             _ => None,
         }
     }
@@ -698,6 +775,8 @@ pub const ARROW_EXT_TYPE_EMPTY_ARRAY: &str = "EmptyArray";
 pub const ARROW_EXT_TYPE_EMPTY_MAP: &str = "EmptyMap";
 pub const ARROW_EXT_TYPE_VARIANT: &str = "Variant";
 pub const ARROW_EXT_TYPE_BITMAP: &str = "Bitmap";
+pub const ARROW_EXT_TYPE_GEOMETRY: &str = "Geometry";
+pub const ARROW_EXT_TYPE_BINARY: &str = "Binary";
 
 impl Column {
     pub fn len(&self) -> usize {
@@ -711,12 +790,16 @@ impl Column {
             Column::String(col) => col.len(),
             Column::Timestamp(col) => col.len(),
             Column::Date(col) => col.len(),
+            Column::Time(col) => col.len(),
+            Column::Interval(col) => col.len(),
             Column::Array(col) => col.len(),
             Column::Map(col) => col.len(),
             Column::Bitmap(col) => col.len(),
             Column::Nullable(col) => col.len(),
             Column::Tuple(fields) => fields[0].len(),
             Column::Variant(col) => col.len(),
+            Column::Geometry(col) => col.len(),
+            Column::Binary(col) => col.len(),
         }
     }
 
@@ -731,6 +814,8 @@ impl Column {
             Column::String(col) => Some(ScalarRef::String(col.index(index)?)),
             Column::Timestamp(col) => Some(ScalarRef::Timestamp(col.get(index).cloned()?)),
             Column::Date(col) => Some(ScalarRef::Date(col.get(index).cloned()?)),
+            Column::Time(col) => Some(ScalarRef::Time(col.get(index).cloned()?)),
+            Column::Interval(col) => Some(ScalarRef::Interval(col.get(index).cloned()?)),
             Column::Array(col) => Some(ScalarRef::Array(col.index(index)?)),
             Column::Map(col) => Some(ScalarRef::Map(col.index(index)?)),
             Column::Bitmap(col) => Some(ScalarRef::Bitmap(col.index(index)?)),
@@ -742,6 +827,8 @@ impl Column {
                     .collect::<Option<Vec<_>>>()?,
             )),
             Column::Variant(col) => Some(ScalarRef::Variant(col.index(index)?)),
+            Column::Geometry(col) => Some(ScalarRef::Geometry(col.index(index)?)),
+            Column::Binary(col) => Some(ScalarRef::Binary(col.index(index)?)),
         }
     }
 
@@ -759,6 +846,8 @@ impl Column {
             Column::String(col) => ScalarRef::String(col.index_unchecked(index)),
             Column::Timestamp(col) => ScalarRef::Timestamp(*col.get_unchecked(index)),
             Column::Date(col) => ScalarRef::Date(*col.get_unchecked(index)),
+            Column::Time(col) => ScalarRef::Time(*col.get_unchecked(index)),
+            Column::Interval(col) => ScalarRef::Interval(*col.get_unchecked(index)),
             Column::Array(col) => ScalarRef::Array(col.index_unchecked(index)),
             Column::Map(col) => ScalarRef::Map(col.index_unchecked(index)),
             Column::Bitmap(col) => ScalarRef::Bitmap(col.index_unchecked(index)),
@@ -770,6 +859,8 @@ impl Column {
                     .collect::<Vec<_>>(),
             ),
             Column::Variant(col) => ScalarRef::Variant(col.index_unchecked(index)),
+            Column::Geometry(col) => ScalarRef::Geometry(col.index_unchecked(index)),
+            Column::Binary(col) => ScalarRef::Binary(col.index_unchecked(index)),
         }
     }
 
@@ -808,6 +899,12 @@ impl Column {
             Column::Date(col) => {
                 Column::Date(col.clone().sliced(range.start, range.end - range.start))
             }
+            Column::Time(col) => {
+                Column::Time(col.clone().sliced(range.start, range.end - range.start))
+            }
+            Column::Interval(col) => {
+                Column::Interval(col.clone().sliced(range.start, range.end - range.start))
+            }
             Column::Array(col) => Column::Array(Box::new(col.slice(range))),
             Column::Map(col) => Column::Map(Box::new(col.slice(range))),
             Column::Bitmap(col) => Column::Bitmap(col.slice(range)),
@@ -819,6 +916,8 @@ impl Column {
                     .collect(),
             ),
             Column::Variant(col) => Column::Variant(col.slice(range)),
+            Column::Geometry(col) => Column::Geometry(col.slice(range)),
+            Column::Binary(col) => Column::Binary(col.slice(range)),
         }
     }
 
@@ -868,6 +967,13 @@ impl Column {
                     max: *max,
                 })
             }
+            Column::Time(col) => {
+                let (min, max) = col.iter().minmax().into_option().unwrap();
+                Domain::Time(SimpleDomain {
+                    min: *min,
+                    max: *max,
+                })
+            }
             Column::Array(col) => {
                 if col.len() == 0 || col.values.len() == 0 {
                     Domain::Array(None)
@@ -901,7 +1007,11 @@ impl Column {
                 let domains = fields.iter().map(|col| col.domain()).collect::<Vec<_>>();
                 Domain::Tuple(domains)
             }
-            Column::Bitmap(_) | Column::Variant(_) => Domain::Undefined,
+            Column::Interval(_)
+            | Column::Bitmap(_)
+            | Column::Variant(_)
+            | Column::Geometry(_)
+            | Column::Binary(_) => Domain::Undefined,
         }
     }
 
@@ -921,6 +1031,8 @@ impl Column {
             Column::String(_) => DataType::String,
             Column::Timestamp(_) => DataType::Timestamp,
             Column::Date(_) => DataType::Date,
+            Column::Time(_) => DataType::Time,
+            Column::Interval(_) => DataType::Interval,
             Column::Array(array) => {
                 let inner = array.values.data_type();
                 DataType::Array(Box::new(inner))
@@ -939,6 +1051,8 @@ impl Column {
                 DataType::Tuple(inner)
             }
             Column::Variant(_) => DataType::Variant,
+            Column::Geometry(_) => DataType::Geometry,
+            Column::Binary(_) => DataType::Binary,
         }
     }
 
@@ -1100,6 +1214,26 @@ impl Column {
                 )
                 .unwrap(),
             ),
+            Column::Time(col) => Box::new(
+                common_arrow::arrow::array::PrimitiveArray::<i64>::try_new(
+                    arrow_type,
+                    col.clone(),
+                    None,
+                )
+                .unwrap(),
+            ),
+            Column::Interval(col) => {
+                let values = col
+                    .iter()
+                    .map(|v| months_days_ns::new(v.months, v.days, v.nanos))
+                    .collect::<Buffer<_>>();
+                Box::new(
+                    common_arrow::arrow::array::PrimitiveArray::<months_days_ns>::try_new(
+                        arrow_type, values, None,
+                    )
+                    .unwrap(),
+                )
+            }
             Column::Array(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets.iter().map(|offset| *offset as i64).collect();
@@ -1165,7 +1299,7 @@ impl Column {
                 )
                 .unwrap(),
             ),
-            Column::Variant(col) => {
+            Column::Variant(col) | Column::Geometry(col) | Column::Binary(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets().iter().map(|offset| *offset as i64).collect();
                 Box::new(
@@ -1345,6 +1479,8 @@ impl Column {
                 if data_type.is_variant() {
                     // Variant column from udf server is converted to LargeBinary, we restore it back here.
                     Column::Variant(StringColumn::new(arrow_col.values().clone(), offsets))
+                } else if data_type.is_binary() {
+                    Column::Binary(StringColumn::new(arrow_col.values().clone(), offsets))
                 } else {
                     Column::String(StringColumn::new(arrow_col.values().clone(), offsets))
                 }
@@ -1362,10 +1498,12 @@ impl Column {
                     .map(|x| *x as u64)
                     .collect::<Vec<_>>();
 
-                Column::String(StringColumn::new(
-                    arrow_col.values().clone(),
-                    offsets.into(),
-                ))
+                let column = StringColumn::new(arrow_col.values().clone(), offsets.into());
+                if data_type.is_binary() {
+                    Column::Binary(column)
+                } else {
+                    Column::String(column)
+                }
             }
 
             ArrowDataType::FixedSizeBinary(size) => {
@@ -1444,6 +1582,31 @@ impl Column {
                 };
                 Column::Timestamp(values)
             }
+            ArrowDataType::Time32(unit) => {
+                let values = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::Int32Array>()
+                    .expect("fail to read from arrow: array should be `Int32Array`")
+                    .values();
+                let factor = match unit {
+                    TimeUnit::Second => 1_000_000,
+                    _ => 1_000,
+                };
+                Column::Time(values.iter().map(|x| *x as i64 * factor).collect())
+            }
+            ArrowDataType::Time64(unit) => {
+                let values = arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::Int64Array>()
+                    .expect("fail to read from arrow: array should be `Int64Array`")
+                    .values();
+                match unit {
+                    TimeUnit::Nanosecond => {
+                        Column::Time(values.iter().map(|x| x / 1_000).collect())
+                    }
+                    _ => Column::Time(values.clone()),
+                }
+            }
             ArrowDataType::Date32 => Column::Date(
                 arrow_col
                     .as_any()
@@ -1452,6 +1615,16 @@ impl Column {
                     .values()
                     .clone(),
             ),
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano) => Column::Interval(
+                arrow_col
+                    .as_any()
+                    .downcast_ref::<common_arrow::arrow::array::MonthsDaysNsArray>()
+                    .expect("fail to read from arrow: array should be `MonthsDaysNsArray`")
+                    .values()
+                    .iter()
+                    .map(|v| MonthsDaysNanos::new(v.months(), v.days(), v.ns()))
+                    .collect(),
+            ),
             ArrowDataType::Extension(name, box ty, None) if name == ARROW_EXT_TYPE_VARIANT => {
                 match ty {
                     ArrowDataType::LargeBinary => {
@@ -1627,6 +1800,74 @@ impl Column {
                     ),
                 }
             }
+            ArrowDataType::Extension(name, box ty, None) if name == ARROW_EXT_TYPE_GEOMETRY => {
+                match ty {
+                    ArrowDataType::LargeBinary => {
+                        let arrow_col = arrow_col
+                            .as_any()
+                            .downcast_ref::<common_arrow::arrow::array::BinaryArray<i64>>()
+                            .expect("fail to read from arrow: array should be `BinaryArray<i64>`");
+                        let offsets = arrow_col.offsets().clone().into_inner();
+
+                        let offsets =
+                            unsafe { std::mem::transmute::<Buffer<i64>, Buffer<u64>>(offsets) };
+                        Column::Geometry(StringColumn::new(arrow_col.values().clone(), offsets))
+                    }
+                    ArrowDataType::Binary => {
+                        let arrow_col = arrow_col
+                            .as_any()
+                            .downcast_ref::<common_arrow::arrow::array::BinaryArray<i32>>()
+                            .expect("fail to read from arrow: array should be `BinaryArray<i32>`");
+                        let offsets = arrow_col
+                            .offsets()
+                            .buffer()
+                            .iter()
+                            .map(|x| *x as u64)
+                            .collect::<Vec<_>>();
+                        Column::Geometry(StringColumn::new(
+                            arrow_col.values().clone(),
+                            offsets.into(),
+                        ))
+                    }
+                    _ => unreachable!(
+                        "fail to read from arrow: array should be `BinaryArray<i32>` or `BinaryArray<i64>`"
+                    ),
+                }
+            }
+            ArrowDataType::Extension(name, box ty, None) if name == ARROW_EXT_TYPE_BINARY => {
+                match ty {
+                    ArrowDataType::LargeBinary => {
+                        let arrow_col = arrow_col
+                            .as_any()
+                            .downcast_ref::<common_arrow::arrow::array::BinaryArray<i64>>()
+                            .expect("fail to read from arrow: array should be `BinaryArray<i64>`");
+                        let offsets = arrow_col.offsets().clone().into_inner();
+
+                        let offsets =
+                            unsafe { std::mem::transmute::<Buffer<i64>, Buffer<u64>>(offsets) };
+                        Column::Binary(StringColumn::new(arrow_col.values().clone(), offsets))
+                    }
+                    ArrowDataType::Binary => {
+                        let arrow_col = arrow_col
+                            .as_any()
+                            .downcast_ref::<common_arrow::arrow::array::BinaryArray<i32>>()
+                            .expect("fail to read from arrow: array should be `BinaryArray<i32>`");
+                        let offsets = arrow_col
+                            .offsets()
+                            .buffer()
+                            .iter()
+                            .map(|x| *x as u64)
+                            .collect::<Vec<_>>();
+                        Column::Binary(StringColumn::new(
+                            arrow_col.values().clone(),
+                            offsets.into(),
+                        ))
+                    }
+                    _ => unreachable!(
+                        "fail to read from arrow: array should be `BinaryArray<i32>` or `BinaryArray<i64>`"
+                    ),
+                }
+            }
             ty => unimplemented!("unsupported arrow type {ty:?}"),
         };
 
@@ -1696,6 +1937,23 @@ impl Column {
                     .map(|_| SmallRng::from_entropy().gen_range(DATE_MIN..=DATE_MAX))
                     .collect::<Vec<i32>>(),
             ),
+            DataType::Time => TimeType::from_data(
+                (0..len)
+                    .map(|_| SmallRng::from_entropy().gen_range(TIME_MIN..=TIME_MAX))
+                    .collect::<Vec<i64>>(),
+            ),
+            DataType::Interval => IntervalType::from_data(
+                (0..len)
+                    .map(|_| {
+                        let mut rng = SmallRng::from_entropy();
+                        MonthsDaysNanos::new(
+                            rng.gen_range(-1200..=1200),
+                            rng.gen_range(-366..=366),
+                            rng.gen_range(-NANOS_PER_HOUR * 24..=NANOS_PER_HOUR * 24),
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            ),
             DataType::Nullable(ty) => Column::Nullable(Box::new(NullableColumn {
                 column: Column::random(ty, len),
                 validity: Bitmap::from(
@@ -1753,6 +2011,19 @@ impl Column {
                 }
                 VariantType::from_data(data)
             }
+            DataType::Geometry => {
+                let mut data = Vec::with_capacity(len);
+                for _ in 0..len {
+                    let (x, y): (f64, f64) = SmallRng::from_entropy().gen();
+                    let wkt = format!("POINT({x} {y})");
+                    data.push(parse_to_wkb(wkt.as_bytes()).unwrap());
+                }
+                GeometryType::from_data(data)
+            }
+            DataType::Binary => BinaryType::from_data((0..len).map(|_| {
+                let data: [u8; 8] = SmallRng::from_entropy().gen();
+                data.to_vec()
+            })),
             DataType::Generic(_) => unreachable!(),
         }
     }
@@ -1808,12 +2079,16 @@ impl Column {
             Column::String(col) => col.memory_size(),
             Column::Timestamp(col) => col.len() * 8,
             Column::Date(col) => col.len() * 4,
+            Column::Time(col) => col.len() * 8,
+            Column::Interval(col) => col.len() * 16,
             Column::Array(col) => col.values.memory_size() + col.offsets.len() * 8,
             Column::Map(col) => col.values.memory_size() + col.offsets.len() * 8,
             Column::Bitmap(col) => col.memory_size(),
             Column::Nullable(c) => c.column.memory_size() + c.validity.as_slice().0.len(),
             Column::Tuple(fields) => fields.iter().map(|f| f.memory_size()).sum(),
             Column::Variant(col) => col.memory_size(),
+            Column::Geometry(col) => col.memory_size(),
+            Column::Binary(col) => col.memory_size(),
         }
     }
 
@@ -1829,11 +2104,18 @@ impl Column {
             Column::Number(NumberColumn::Int8(col)) => col.len(),
             Column::Number(NumberColumn::Int16(col)) => col.len() * 2,
             Column::Number(NumberColumn::Int32(col)) | Column::Date(col) => col.len() * 4,
-            Column::Number(NumberColumn::Int64(col)) | Column::Timestamp(col) => col.len() * 8,
+            Column::Number(NumberColumn::Int64(col))
+            | Column::Timestamp(col)
+            | Column::Time(col) => col.len() * 8,
             Column::Decimal(DecimalColumn::Decimal128(col, _)) => col.len() * 16,
             Column::Decimal(DecimalColumn::Decimal256(col, _)) => col.len() * 32,
+            Column::Interval(col) => col.len() * 16,
             Column::Boolean(c) => c.len(),
-            Column::String(col) | Column::Bitmap(col) | Column::Variant(col) => col.memory_size(),
+            Column::String(col)
+            | Column::Bitmap(col)
+            | Column::Variant(col)
+            | Column::Geometry(col)
+            | Column::Binary(col) => col.memory_size(),
             Column::Array(col) | Column::Map(col) => col.values.serialize_size() + col.len() * 8,
             Column::Nullable(c) => c.column.serialize_size() + c.len(),
             Column::Tuple(fields) => fields.iter().map(|f| f.serialize_size()).sum(),
@@ -1906,6 +2188,8 @@ impl ColumnBuilder {
             Column::String(col) => ColumnBuilder::String(StringColumnBuilder::from_column(col)),
             Column::Timestamp(col) => ColumnBuilder::Timestamp(buffer_into_mut(col)),
             Column::Date(col) => ColumnBuilder::Date(buffer_into_mut(col)),
+            Column::Time(col) => ColumnBuilder::Time(buffer_into_mut(col)),
+            Column::Interval(col) => ColumnBuilder::Interval(buffer_into_mut(col)),
             Column::Array(box col) => {
                 ColumnBuilder::Array(Box::new(ArrayColumnBuilder::from_column(col)))
             }
@@ -1923,6 +2207,8 @@ impl ColumnBuilder {
                     .collect(),
             ),
             Column::Variant(col) => ColumnBuilder::Variant(StringColumnBuilder::from_column(col)),
+            Column::Geometry(col) => ColumnBuilder::Geometry(StringColumnBuilder::from_column(col)),
+            Column::Binary(col) => ColumnBuilder::Binary(StringColumnBuilder::from_column(col)),
         }
     }
 
@@ -1965,6 +2251,8 @@ impl ColumnBuilder {
             ScalarRef::String(s) => ColumnBuilder::String(StringColumnBuilder::repeat(s, n)),
             ScalarRef::Timestamp(d) => ColumnBuilder::Timestamp(vec![*d; n]),
             ScalarRef::Date(d) => ColumnBuilder::Date(vec![*d; n]),
+            ScalarRef::Time(t) => ColumnBuilder::Time(vec![*t; n]),
+            ScalarRef::Interval(i) => ColumnBuilder::Interval(vec![*i; n]),
             ScalarRef::Array(col) => {
                 ColumnBuilder::Array(Box::new(ArrayColumnBuilder::repeat(col, n)))
             }
@@ -1984,6 +2272,8 @@ impl ColumnBuilder {
                 )
             }
             ScalarRef::Variant(s) => ColumnBuilder::Variant(StringColumnBuilder::repeat(s, n)),
+            ScalarRef::Geometry(s) => ColumnBuilder::Geometry(StringColumnBuilder::repeat(s, n)),
+            ScalarRef::Binary(s) => ColumnBuilder::Binary(StringColumnBuilder::repeat(s, n)),
         }
     }
 
@@ -1998,12 +2288,16 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => builder.len(),
             ColumnBuilder::Timestamp(builder) => builder.len(),
             ColumnBuilder::Date(builder) => builder.len(),
+            ColumnBuilder::Time(builder) => builder.len(),
+            ColumnBuilder::Interval(builder) => builder.len(),
             ColumnBuilder::Array(builder) => builder.len(),
             ColumnBuilder::Map(builder) => builder.len(),
             ColumnBuilder::Bitmap(builder) => builder.len(),
             ColumnBuilder::Nullable(builder) => builder.len(),
             ColumnBuilder::Tuple(fields) => fields[0].len(),
             ColumnBuilder::Variant(builder) => builder.len(),
+            ColumnBuilder::Geometry(builder) => builder.len(),
+            ColumnBuilder::Binary(builder) => builder.len(),
         }
     }
 
@@ -2032,12 +2326,16 @@ impl ColumnBuilder {
             ColumnBuilder::String(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::Timestamp(col) => col.len() * 8,
             ColumnBuilder::Date(col) => col.len() * 4,
+            ColumnBuilder::Time(col) => col.len() * 8,
+            ColumnBuilder::Interval(col) => col.len() * 16,
             ColumnBuilder::Array(col) => col.builder.memory_size() + col.offsets.len() * 8,
             ColumnBuilder::Map(col) => col.builder.memory_size() + col.offsets.len() * 8,
             ColumnBuilder::Bitmap(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::Nullable(c) => c.builder.memory_size() + c.validity.as_slice().len(),
             ColumnBuilder::Tuple(fields) => fields.iter().map(|f| f.memory_size()).sum(),
             ColumnBuilder::Variant(col) => col.data.len() + col.offsets.len() * 8,
+            ColumnBuilder::Geometry(col) => col.data.len() + col.offsets.len() * 8,
+            ColumnBuilder::Binary(col) => col.data.len() + col.offsets.len() * 8,
         }
     }
 
@@ -2057,6 +2355,8 @@ impl ColumnBuilder {
             ColumnBuilder::String(_) => DataType::String,
             ColumnBuilder::Timestamp(_) => DataType::Timestamp,
             ColumnBuilder::Date(_) => DataType::Date,
+            ColumnBuilder::Time(_) => DataType::Time,
+            ColumnBuilder::Interval(_) => DataType::Interval,
             ColumnBuilder::Array(col) => {
                 let inner = col.builder.data_type();
                 DataType::Array(Box::new(inner))
@@ -2071,6 +2371,8 @@ impl ColumnBuilder {
                 DataType::Tuple(fields.iter().map(|f| f.data_type()).collect::<Vec<_>>())
             }
             ColumnBuilder::Variant(_) => DataType::Variant,
+            ColumnBuilder::Geometry(_) => DataType::Geometry,
+            ColumnBuilder::Binary(_) => DataType::Binary,
         }
     }
 
@@ -2102,6 +2404,8 @@ impl ColumnBuilder {
             }
             DataType::Timestamp => ColumnBuilder::Timestamp(Vec::with_capacity(capacity)),
             DataType::Date => ColumnBuilder::Date(Vec::with_capacity(capacity)),
+            DataType::Time => ColumnBuilder::Time(Vec::with_capacity(capacity)),
+            DataType::Interval => ColumnBuilder::Interval(Vec::with_capacity(capacity)),
             DataType::Nullable(ty) => ColumnBuilder::Nullable(Box::new(NullableColumnBuilder {
                 builder: Self::with_capacity_hint(ty, capacity, enable_datasize_hint),
                 validity: MutableBitmap::with_capacity(capacity),
@@ -2141,6 +2445,14 @@ impl ColumnBuilder {
                 let data_capacity = if enable_datasize_hint { 0 } else { capacity };
                 ColumnBuilder::Variant(StringColumnBuilder::with_capacity(capacity, data_capacity))
             }
+            DataType::Geometry => {
+                let data_capacity = if enable_datasize_hint { 0 } else { capacity };
+                ColumnBuilder::Geometry(StringColumnBuilder::with_capacity(capacity, data_capacity))
+            }
+            DataType::Binary => {
+                let data_capacity = if enable_datasize_hint { 0 } else { capacity };
+                ColumnBuilder::Binary(StringColumnBuilder::with_capacity(capacity, data_capacity))
+            }
             DataType::Generic(_) => {
                 unreachable!("unable to initialize column builder for generic type")
             }
@@ -2163,6 +2475,8 @@ impl ColumnBuilder {
                 builder.push(value);
             }
             (ColumnBuilder::Date(builder), ScalarRef::Date(value)) => builder.push(value),
+            (ColumnBuilder::Time(builder), ScalarRef::Time(value)) => builder.push(value),
+            (ColumnBuilder::Interval(builder), ScalarRef::Interval(value)) => builder.push(value),
             (ColumnBuilder::Array(builder), ScalarRef::Array(value)) => {
                 builder.push(value);
             }
@@ -2189,6 +2503,14 @@ impl ColumnBuilder {
                 builder.put_slice(value);
                 builder.commit_row();
             }
+            (ColumnBuilder::Geometry(builder), ScalarRef::Geometry(value)) => {
+                builder.put_slice(value);
+                builder.commit_row();
+            }
+            (ColumnBuilder::Binary(builder), ScalarRef::Binary(value)) => {
+                builder.put_slice(value);
+                builder.commit_row();
+            }
             (builder, scalar) => unreachable!("unable to push {scalar:?} to {builder:?}"),
         }
     }
@@ -2204,6 +2526,8 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => builder.commit_row(),
             ColumnBuilder::Timestamp(builder) => builder.push(0),
             ColumnBuilder::Date(builder) => builder.push(0),
+            ColumnBuilder::Time(builder) => builder.push(0),
+            ColumnBuilder::Interval(builder) => builder.push(MonthsDaysNanos::default()),
             ColumnBuilder::Array(builder) => builder.push_default(),
             ColumnBuilder::Map(builder) => builder.push_default(),
             ColumnBuilder::Bitmap(builder) => builder.commit_row(),
@@ -2217,6 +2541,11 @@ impl ColumnBuilder {
                 builder.put_slice(JSONB_NULL);
                 builder.commit_row();
             }
+            ColumnBuilder::Geometry(builder) => {
+                builder.put_slice(&[1, 7, 0, 0, 0, 0, 0, 0, 0]);
+                builder.commit_row();
+            }
+            ColumnBuilder::Binary(builder) => builder.commit_row(),
         }
     }
 
@@ -2243,7 +2572,9 @@ impl ColumnBuilder {
             }
             ColumnBuilder::String(builder)
             | ColumnBuilder::Variant(builder)
-            | ColumnBuilder::Bitmap(builder) => {
+            | ColumnBuilder::Bitmap(builder)
+            | ColumnBuilder::Geometry(builder)
+            | ColumnBuilder::Binary(builder) => {
                 let offset = reader.read_scalar::<u64>()? as usize;
                 builder.data.resize(offset + builder.data.len(), 0);
                 let last = *builder.offsets.last().unwrap() as usize;
@@ -2255,10 +2586,21 @@ impl ColumnBuilder {
                 check_timestamp(value)?;
                 builder.push(value);
             }
+            ColumnBuilder::Time(builder) => {
+                let value: i64 = reader.read_scalar()?;
+                check_time(value)?;
+                builder.push(value);
+            }
             ColumnBuilder::Date(builder) => {
                 let value: i32 = reader.read_scalar()?;
                 builder.push(value);
             }
+            ColumnBuilder::Interval(builder) => {
+                let months: i32 = reader.read_scalar()?;
+                let days: i32 = reader.read_scalar()?;
+                let nanos: i64 = reader.read_scalar()?;
+                builder.push(MonthsDaysNanos::new(months, days, nanos));
+            }
             ColumnBuilder::Array(builder) => {
                 let len = reader.read_scalar::<u64>()?;
                 for _ in 0..len {
@@ -2329,7 +2671,9 @@ impl ColumnBuilder {
             }
             ColumnBuilder::String(builder)
             | ColumnBuilder::Variant(builder)
-            | ColumnBuilder::Bitmap(builder) => {
+            | ColumnBuilder::Bitmap(builder)
+            | ColumnBuilder::Geometry(builder)
+            | ColumnBuilder::Binary(builder) => {
                 for row in 0..rows {
                     let reader = &reader[step * row..];
                     builder.put_slice(reader);
@@ -2345,6 +2689,14 @@ impl ColumnBuilder {
                     builder.push(value);
                 }
             }
+            ColumnBuilder::Time(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    let value: i64 = reader.read_scalar()?;
+                    check_time(value)?;
+                    builder.push(value);
+                }
+            }
             ColumnBuilder::Date(builder) => {
                 for row in 0..rows {
                     let mut reader = &reader[step * row..];
@@ -2352,6 +2704,15 @@ impl ColumnBuilder {
                     builder.push(value);
                 }
             }
+            ColumnBuilder::Interval(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    let months: i32 = reader.read_scalar()?;
+                    let days: i32 = reader.read_scalar()?;
+                    let nanos: i64 = reader.read_scalar()?;
+                    builder.push(MonthsDaysNanos::new(months, days, nanos));
+                }
+            }
             ColumnBuilder::Array(builder) => {
                 for row in 0..rows {
                     let mut reader = &reader[step * row..];
@@ -2424,6 +2785,8 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => builder.pop().map(Scalar::String),
             ColumnBuilder::Timestamp(builder) => builder.pop().map(Scalar::Timestamp),
             ColumnBuilder::Date(builder) => builder.pop().map(Scalar::Date),
+            ColumnBuilder::Time(builder) => builder.pop().map(Scalar::Time),
+            ColumnBuilder::Interval(builder) => builder.pop().map(Scalar::Interval),
             ColumnBuilder::Array(builder) => builder.pop().map(Scalar::Array),
             ColumnBuilder::Map(builder) => builder.pop().map(Scalar::Map),
             ColumnBuilder::Bitmap(builder) => builder.pop().map(Scalar::Bitmap),
@@ -2441,6 +2804,8 @@ impl ColumnBuilder {
                 }
             }
             ColumnBuilder::Variant(builder) => builder.pop().map(Scalar::Variant),
+            ColumnBuilder::Geometry(builder) => builder.pop().map(Scalar::Geometry),
+            ColumnBuilder::Binary(builder) => builder.pop().map(Scalar::Binary),
         }
     }
 
@@ -2470,12 +2835,24 @@ impl ColumnBuilder {
             (ColumnBuilder::Variant(builder), Column::Variant(other)) => {
                 builder.append_column(other);
             }
+            (ColumnBuilder::Geometry(builder), Column::Geometry(other)) => {
+                builder.append_column(other);
+            }
+            (ColumnBuilder::Binary(builder), Column::Binary(other)) => {
+                builder.append_column(other);
+            }
             (ColumnBuilder::Timestamp(builder), Column::Timestamp(other)) => {
                 builder.extend_from_slice(other);
             }
             (ColumnBuilder::Date(builder), Column::Date(other)) => {
                 builder.extend_from_slice(other);
             }
+            (ColumnBuilder::Time(builder), Column::Time(other)) => {
+                builder.extend_from_slice(other);
+            }
+            (ColumnBuilder::Interval(builder), Column::Interval(other)) => {
+                builder.extend_from_slice(other);
+            }
             (ColumnBuilder::Array(builder), Column::Array(other)) => {
                 builder.append_column(other.as_ref());
             }
@@ -2513,6 +2890,8 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => Column::String(builder.build()),
             ColumnBuilder::Timestamp(builder) => Column::Timestamp(builder.into()),
             ColumnBuilder::Date(builder) => Column::Date(builder.into()),
+            ColumnBuilder::Time(builder) => Column::Time(builder.into()),
+            ColumnBuilder::Interval(builder) => Column::Interval(builder.into()),
             ColumnBuilder::Array(builder) => Column::Array(Box::new(builder.build())),
             ColumnBuilder::Map(builder) => Column::Map(Box::new(builder.build())),
             ColumnBuilder::Bitmap(builder) => Column::Bitmap(builder.build()),
@@ -2522,6 +2901,8 @@ impl ColumnBuilder {
                 Column::Tuple(fields.into_iter().map(|field| field.build()).collect())
             }
             ColumnBuilder::Variant(builder) => Column::Variant(builder.build()),
+            ColumnBuilder::Geometry(builder) => Column::Geometry(builder.build()),
+            ColumnBuilder::Binary(builder) => Column::Binary(builder.build()),
         }
     }
 
@@ -2537,6 +2918,8 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => Scalar::String(builder.build_scalar()),
             ColumnBuilder::Timestamp(builder) => Scalar::Timestamp(builder[0]),
             ColumnBuilder::Date(builder) => Scalar::Date(builder[0]),
+            ColumnBuilder::Time(builder) => Scalar::Time(builder[0]),
+            ColumnBuilder::Interval(builder) => Scalar::Interval(builder[0]),
             ColumnBuilder::Array(builder) => Scalar::Array(builder.build_scalar()),
             ColumnBuilder::Map(builder) => Scalar::Map(builder.build_scalar()),
             ColumnBuilder::Bitmap(builder) => Scalar::Bitmap(builder.build_scalar()),
@@ -2548,6 +2931,8 @@ impl ColumnBuilder {
                     .collect(),
             ),
             ColumnBuilder::Variant(builder) => Scalar::Variant(builder.build_scalar()),
+            ColumnBuilder::Geometry(builder) => Scalar::Geometry(builder.build_scalar()),
+            ColumnBuilder::Binary(builder) => Scalar::Binary(builder.build_scalar()),
         }
     }
 }
@@ -446,3 +446,42 @@ pub fn test_take_compact() -> common_exception::Result<()> {
 
     Ok(())
 }
+
+#[test]
+pub fn test_string_kernels() {
+    use common_expression::string;
+    use common_expression::types::string::StringColumn;
+    use common_expression::types::ValueType;
+
+    let col = StringType::from_data(vec!["Abc", "", "dobrý", "xabcx", "ab"]);
+    let col = StringType::try_downcast_column(&col).unwrap();
+    // Slice the column, so that the offsets don't start from zero.
+    let sliced = col.slice(3..5);
+
+    let rows = |col: &StringColumn| {
+        col.iter()
+            .map(|v| String::from_utf8(v.to_vec()).unwrap())
+            .collect::<Vec<_>>()
+    };
+
+    assert!(string::ascii_to_uppercase(&col).is_none());
+    let upper = string::ascii_to_uppercase(&sliced).unwrap();
+    assert_eq!(rows(&upper), vec!["XABCX", "AB"]);
+    let lower = string::ascii_to_lowercase(&col.slice(0..2)).unwrap();
+    assert_eq!(rows(&lower), vec!["abc", ""]);
+
+    let sub = string::map_sub_slices(&sliced, |s| &s[..s.len().min(2)]);
+    assert_eq!(rows(&sub), vec!["xa", "ab"]);
+
+    let to_vec = |bitmap: common_arrow::arrow::bitmap::Bitmap| bitmap.iter().collect::<Vec<_>>();
+    assert_eq!(to_vec(string::starts_with(&col, b"ab")), vec![
+        false, false, false, false, true
+    ]);
+    assert_eq!(to_vec(string::ends_with(&col, b"bcx")), vec![
+        false, false, false, true, false
+    ]);
+    assert_eq!(to_vec(string::ends_with(&sliced, b"")), vec![true, true]);
+    assert_eq!(to_vec(string::equals(&col, b"")), vec![
+        false, true, false, false, false
+    ]);
+}
@@ -34,6 +34,7 @@ use common_expression::types::decimal::DecimalSize;
 use common_expression::types::nullable::NullableColumnBuilder;
 use common_expression::types::number::Number;
 use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::time::string_to_time;
 use common_expression::types::timestamp::check_timestamp;
 use common_expression::types::AnyType;
 use common_expression::types::NumberColumnBuilder;
@@ -53,6 +54,7 @@ use common_io::cursor_ext::ReadBytesExt;
 use common_io::cursor_ext::ReadCheckPointExt;
 use common_io::cursor_ext::ReadNumberExt;
 use common_io::parse_bitmap;
+use common_io::parse_to_wkb;
 use common_io::prelude::FormatSettings;
 use jsonb::parse_value;
 use lexical_core::FromLexical;
@@ -137,12 +139,15 @@ impl FastFieldDecoderValues {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, reader, positions),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, reader, positions),
+            ColumnBuilder::Time(c) => self.read_time(c, reader, positions),
             ColumnBuilder::String(c) => self.read_string(c, reader, positions),
             ColumnBuilder::Array(c) => self.read_array(c, reader, positions),
             ColumnBuilder::Map(c) => self.read_map(c, reader, positions),
             ColumnBuilder::Bitmap(c) => self.read_bitmap(c, reader, positions),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, reader, positions),
             ColumnBuilder::Variant(c) => self.read_variant(c, reader, positions),
+            ColumnBuilder::Geometry(c) => self.read_geometry(c, reader, positions),
+            ColumnBuilder::Binary(c) => self.read_binary(c, reader, positions),
             _ => unimplemented!(),
         }
     }
@@ -425,6 +430,48 @@ impl FastFieldDecoderValues {
         Ok(())
     }
 
+    fn read_time<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<i64>,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        let time = string_to_time(&buf).map_err(ErrorCode::BadBytes)?;
+        column.push(time);
+        Ok(())
+    }
+
+    fn read_geometry<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        let geom = parse_to_wkb(&buf)?;
+        column.put_slice(&geom);
+        column.commit_row();
+        Ok(())
+    }
+
+    fn read_binary<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        let bytes = hex::decode(&buf)
+            .map_err(|e| ErrorCode::BadBytes(format!("Invalid hex string: {e}")))?;
+        column.put_slice(&bytes);
+        column.commit_row();
+        Ok(())
+    }
+
     fn read_variant<R: AsRef<[u8]>>(
         &self,
         column: &mut StringColumnBuilder,
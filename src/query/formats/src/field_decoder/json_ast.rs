@@ -29,6 +29,8 @@ use common_expression::types::decimal::DecimalSize;
 use common_expression::types::nullable::NullableColumnBuilder;
 use common_expression::types::number::Number;
 use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::time::check_time;
+use common_expression::types::time::string_to_time;
 use common_expression::types::timestamp::check_timestamp;
 use common_expression::types::AnyType;
 use common_expression::types::NumberColumnBuilder;
@@ -39,6 +41,7 @@ use common_io::cursor_ext::BufferReadDateTimeExt;
 use common_io::cursor_ext::DateTimeResType;
 use common_io::cursor_ext::ReadNumberExt;
 use common_io::parse_bitmap;
+use common_io::parse_to_wkb;
 use lexical_core::FromLexical;
 use num::cast::AsPrimitive;
 use roaring::RoaringTreemap;
@@ -87,12 +90,15 @@ impl FieldJsonAstDecoder {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, value),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, value),
+            ColumnBuilder::Time(c) => self.read_time(c, value),
             ColumnBuilder::String(c) => self.read_string(c, value),
             ColumnBuilder::Array(c) => self.read_array(c, value),
             ColumnBuilder::Map(c) => self.read_map(c, value),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, value),
             ColumnBuilder::Bitmap(c) => self.read_bitmap(c, value),
             ColumnBuilder::Variant(c) => self.read_variant(c, value),
+            ColumnBuilder::Geometry(c) => self.read_geometry(c, value),
+            ColumnBuilder::Binary(c) => self.read_binary(c, value),
             _ => unimplemented!(),
         }
     }
@@ -269,6 +275,44 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_time(&self, column: &mut Vec<i64>, value: &Value) -> Result<()> {
+        let time = match value {
+            Value::String(v) => string_to_time(v),
+            Value::Number(number) => match number.as_i64() {
+                Some(n) => check_time(n),
+                None => return Err(ErrorCode::BadArguments("Incorrect time value")),
+            },
+            _ => return Err(ErrorCode::BadBytes("Incorrect time value")),
+        };
+        column.push(time.map_err(ErrorCode::BadBytes)?);
+        Ok(())
+    }
+
+    fn read_geometry(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                let geom = parse_to_wkb(v.as_bytes())?;
+                column.put_slice(&geom);
+                column.commit_row();
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect Geometry value")),
+        }
+    }
+
+    fn read_binary(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                let bytes = hex::decode(v.as_bytes())
+                    .map_err(|e| ErrorCode::BadBytes(format!("Invalid hex string: {e}")))?;
+                column.put_slice(&bytes);
+                column.commit_row();
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect Binary value")),
+        }
+    }
+
     fn read_variant(&self, column: &mut StringColumnBuilder, value: &Value) -> Result<()> {
         let v = jsonb::Value::from(value);
         v.write_to_vec(&mut column.data);
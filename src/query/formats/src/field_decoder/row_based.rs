@@ -29,6 +29,7 @@ use common_expression::types::decimal::DecimalSize;
 use common_expression::types::nullable::NullableColumnBuilder;
 use common_expression::types::number::Number;
 use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::time::string_to_time;
 use common_expression::types::timestamp::check_timestamp;
 use common_expression::types::AnyType;
 use common_expression::types::NumberColumnBuilder;
@@ -41,6 +42,7 @@ use common_io::cursor_ext::ReadBytesExt;
 use common_io::cursor_ext::ReadCheckPointExt;
 use common_io::cursor_ext::ReadNumberExt;
 use common_io::parse_bitmap;
+use common_io::parse_to_wkb;
 use jsonb::parse_value;
 use lexical_core::FromLexical;
 
@@ -87,12 +89,15 @@ pub trait FieldDecoderRowBased: FieldDecoder {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, reader, raw),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, reader, raw),
+            ColumnBuilder::Time(c) => self.read_time(c, reader, raw),
             ColumnBuilder::String(c) => self.read_string(c, reader, raw),
             ColumnBuilder::Array(c) => self.read_array(c, reader, raw),
             ColumnBuilder::Map(c) => self.read_map(c, reader, raw),
             ColumnBuilder::Bitmap(c) => self.read_bitmap(c, reader, raw),
             ColumnBuilder::Tuple(fields) => self.read_tuple(fields, reader, raw),
             ColumnBuilder::Variant(c) => self.read_variant(c, reader, raw),
+            ColumnBuilder::Geometry(c) => self.read_geometry(c, reader, raw),
+            ColumnBuilder::Binary(c) => self.read_binary(c, reader, raw),
             _ => unimplemented!(),
         }
     }
@@ -271,6 +276,48 @@ pub trait FieldDecoderRowBased: FieldDecoder {
         Ok(())
     }
 
+    fn read_time<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<i64>,
+        reader: &mut Cursor<R>,
+        raw: bool,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, raw)?;
+        let time = string_to_time(&buf).map_err(ErrorCode::BadBytes)?;
+        column.push(time);
+        Ok(())
+    }
+
+    fn read_geometry<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+        raw: bool,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, raw)?;
+        let geom = parse_to_wkb(&buf)?;
+        column.put_slice(&geom);
+        column.commit_row();
+        Ok(())
+    }
+
+    fn read_binary<R: AsRef<[u8]>>(
+        &self,
+        column: &mut StringColumnBuilder,
+        reader: &mut Cursor<R>,
+        raw: bool,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, raw)?;
+        let bytes = hex::decode(&buf)
+            .map_err(|e| ErrorCode::BadBytes(format!("Invalid hex string: {e}")))?;
+        column.put_slice(&bytes);
+        column.commit_row();
+        Ok(())
+    }
+
     fn read_variant<R: AsRef<[u8]>>(
         &self,
         column: &mut StringColumnBuilder,
@@ -17,7 +17,6 @@ use common_expression::types::ValueType;
 use common_expression::Column;
 use common_io::constants::FALSE_BYTES_LOWER;
 use common_io::constants::INF_BYTES_LOWER;
-use common_io::constants::NULL_BYTES_ESCAPE;
 use common_io::constants::TRUE_BYTES_LOWER;
 use common_meta_app::principal::CsvFileFormatParams;
 
@@ -30,22 +29,27 @@ pub struct FieldEncoderCSV {
     pub nested: FieldEncoderValues,
     pub common_settings: CommonSettings,
     pub quote_char: u8,
+    pub escape_char: u8,
 }
 
 impl FieldEncoderCSV {
     pub fn create(params: &CsvFileFormatParams, options_ext: &FileFormatOptionsExt) -> Self {
+        let quote_char = params.quote.as_bytes()[0];
+        // Without an escape char, the quote char is escaped by doubling it.
+        let escape_char = params.escape.as_bytes().first().cloned();
         FieldEncoderCSV {
             nested: FieldEncoderValues::create(options_ext),
             common_settings: CommonSettings {
                 true_bytes: TRUE_BYTES_LOWER.as_bytes().to_vec(),
                 false_bytes: FALSE_BYTES_LOWER.as_bytes().to_vec(),
-                null_bytes: NULL_BYTES_ESCAPE.as_bytes().to_vec(),
+                null_bytes: params.null_display.as_bytes().to_vec(),
                 nan_bytes: params.nan_display.as_bytes().to_vec(),
                 inf_bytes: INF_BYTES_LOWER.as_bytes().to_vec(),
                 timezone: options_ext.timezone,
                 disable_variant_check: options_ext.disable_variant_check,
             },
-            quote_char: params.quote.as_bytes()[0],
+            quote_char,
+            escape_char: escape_char.unwrap_or(quote_char),
         }
     }
 }
@@ -59,7 +63,7 @@ impl FieldEncoderRowBased for FieldEncoderCSV {
         if raw {
             out_buf.extend_from_slice(in_buf);
         } else {
-            write_csv_string(in_buf, out_buf, self.quote_char);
+            write_csv_string(in_buf, out_buf, self.quote_char, self.escape_char);
         }
     }
 
@@ -94,17 +98,17 @@ impl FieldEncoderRowBased for FieldEncoderCSV {
     }
 }
 
-pub fn write_csv_string(bytes: &[u8], buf: &mut Vec<u8>, quote: u8) {
+pub fn write_csv_string(bytes: &[u8], buf: &mut Vec<u8>, quote: u8, escape: u8) {
     buf.push(quote);
     let mut start = 0;
 
     for (i, &byte) in bytes.iter().enumerate() {
-        if byte == quote {
+        if byte == quote || byte == escape {
             if start < i {
                 buf.extend_from_slice(&bytes[start..i]);
             }
-            buf.push(quote);
-            buf.push(quote);
+            buf.push(escape);
+            buf.push(byte);
             start = i + 1;
         }
     }
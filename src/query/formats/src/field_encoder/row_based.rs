@@ -17,12 +17,15 @@ use common_arrow::arrow::buffer::Buffer;
 use common_expression::types::array::ArrayColumn;
 use common_expression::types::date::date_to_string;
 use common_expression::types::decimal::DecimalColumn;
+use common_expression::types::interval::MonthsDaysNanos;
 use common_expression::types::nullable::NullableColumn;
 use common_expression::types::number::NumberColumn;
 use common_expression::types::string::StringColumn;
+use common_expression::types::time::time_to_string;
 use common_expression::types::timestamp::timestamp_to_string;
 use common_expression::types::ValueType;
 use common_expression::Column;
+use common_io::wkb_to_wkt;
 use lexical_core::ToLexical;
 use micromarshal::Marshal;
 use micromarshal::Unmarshal;
@@ -59,6 +62,8 @@ pub trait FieldEncoderRowBased {
             Column::Decimal(c) => self.write_decimal(c, row_index, out_buf),
             Column::Date(c) => self.write_date(c, row_index, out_buf, raw),
             Column::Timestamp(c) => self.write_timestamp(c, row_index, out_buf, raw),
+            Column::Time(c) => self.write_time(c, row_index, out_buf, raw),
+            Column::Interval(c) => self.write_interval(c, row_index, out_buf, raw),
             Column::String(c) => self.write_string(c, row_index, out_buf, raw),
             Column::Nullable(box c) => self.write_nullable(c, row_index, out_buf, raw),
             Column::Array(box c) => self.write_array(c, row_index, out_buf, raw),
@@ -66,6 +71,8 @@ pub trait FieldEncoderRowBased {
             Column::Bitmap(b) => self.write_bitmap(b, row_index, out_buf, raw),
             Column::Tuple(fields) => self.write_tuple(fields, row_index, out_buf, raw),
             Column::Variant(c) => self.write_variant(c, row_index, out_buf, raw),
+            Column::Geometry(c) => self.write_geometry(c, row_index, out_buf, raw),
+            Column::Binary(c) => self.write_binary(c, row_index, out_buf, raw),
         }
     }
 
@@ -165,6 +172,23 @@ pub trait FieldEncoderRowBased {
         self.write_string_inner(s.as_bytes(), out_buf, raw);
     }
 
+    fn write_time(&self, column: &Buffer<i64>, row_index: usize, out_buf: &mut Vec<u8>, raw: bool) {
+        let v = unsafe { column.get_unchecked(row_index) };
+        let s = time_to_string(*v).to_string();
+        self.write_string_inner(s.as_bytes(), out_buf, raw);
+    }
+
+    fn write_interval(
+        &self,
+        column: &Buffer<MonthsDaysNanos>,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        raw: bool,
+    ) {
+        let v = unsafe { column.get_unchecked(row_index) };
+        self.write_string_inner(v.to_string().as_bytes(), out_buf, raw);
+    }
+
     fn write_bitmap(
         &self,
         _column: &StringColumn,
@@ -188,6 +212,31 @@ pub trait FieldEncoderRowBased {
         self.write_string_inner(s.as_bytes(), out_buf, raw);
     }
 
+    fn write_geometry(
+        &self,
+        column: &StringColumn,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        raw: bool,
+    ) {
+        let v = unsafe { column.index_unchecked(row_index) };
+        match wkb_to_wkt(v) {
+            Ok(wkt) => self.write_string_inner(wkt.as_bytes(), out_buf, raw),
+            Err(_) => self.write_string_inner(v, out_buf, raw),
+        }
+    }
+
+    fn write_binary(
+        &self,
+        column: &StringColumn,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        raw: bool,
+    ) {
+        let v = unsafe { column.index_unchecked(row_index) };
+        self.write_string_inner(hex::encode_upper(v).as_bytes(), out_buf, raw);
+    }
+
     fn write_array<T: ValueType>(
         &self,
         column: &ArrayColumn<T>,
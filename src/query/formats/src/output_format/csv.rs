@@ -34,6 +34,7 @@ pub struct CSVOutputFormatBase<const WITH_NAMES: bool, const WITH_TYPES: bool> {
     field_delimiter: u8,
     record_delimiter: Vec<u8>,
     quote: u8,
+    escape: u8,
 }
 
 impl<const WITH_NAMES: bool, const WITH_TYPES: bool> CSVOutputFormatBase<WITH_NAMES, WITH_TYPES> {
@@ -45,10 +46,11 @@ impl<const WITH_NAMES: bool, const WITH_TYPES: bool> CSVOutputFormatBase<WITH_NA
         let field_encoder = FieldEncoderCSV::create(params, options_ext);
         Self {
             schema,
-            field_encoder,
             field_delimiter: params.field_delimiter.as_bytes()[0],
             record_delimiter: params.record_delimiter.as_bytes().to_vec(),
-            quote: params.quote.as_bytes()[0],
+            quote: field_encoder.quote_char,
+            escape: field_encoder.escape_char,
+            field_encoder,
         }
     }
 
@@ -60,7 +62,7 @@ impl<const WITH_NAMES: bool, const WITH_TYPES: bool> CSVOutputFormatBase<WITH_NA
             if col_index != 0 {
                 buf.push(fd);
             }
-            write_csv_string(v.as_bytes(), &mut buf, self.quote);
+            write_csv_string(v.as_bytes(), &mut buf, self.quote, self.escape);
         }
 
         buf.extend_from_slice(&self.record_delimiter);
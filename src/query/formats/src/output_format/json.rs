@@ -14,10 +14,12 @@
 
 use common_expression::date_helper::DateConverter;
 use common_expression::types::number::NumberScalar;
+use common_expression::types::time::time_to_string;
 use common_expression::DataBlock;
 use common_expression::ScalarRef;
 use common_expression::TableSchemaRef;
 use common_io::prelude::FormatSettings;
+use common_io::wkb_to_wkt;
 use roaring::RoaringTreemap;
 use serde_json::Map as JsonMap;
 use serde_json::Value as JsonValue;
@@ -96,6 +98,8 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let dt = DateConverter::to_timestamp(&v, format.timezone);
             serde_json::to_value(dt.format("%Y-%m-%d %H:%M:%S").to_string()).unwrap()
         }
+        ScalarRef::Time(v) => JsonValue::String(time_to_string(v).to_string()),
+        ScalarRef::Interval(v) => JsonValue::String(v.to_string()),
         ScalarRef::EmptyArray => JsonValue::Array(vec![]),
         ScalarRef::EmptyMap => JsonValue::Object(JsonMap::new()),
         ScalarRef::String(x) => JsonValue::String(String::from_utf8_lossy(x).to_string()),
@@ -140,6 +144,11 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let b = jsonb::from_slice(x).unwrap();
             b.into()
         }
+        ScalarRef::Geometry(x) => match wkb_to_wkt(x) {
+            Ok(wkt) => JsonValue::String(wkt),
+            Err(_) => JsonValue::Null,
+        },
+        ScalarRef::Binary(x) => JsonValue::String(hex::encode_upper(x)),
     }
 }
 
@@ -17,6 +17,7 @@ use std::collections::BTreeMap;
 use common_exception::Result;
 use common_expression::types::number::Int32Type;
 use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
 use common_expression::FromOptData;
 use common_expression::TableDataType;
 use common_expression::TableField;
@@ -111,6 +112,39 @@ fn test_null() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_csv_null_display_and_escape() -> Result<()> {
+    let (schema, block) = gen_schema_and_block(
+        vec![
+            TableField::new(
+                "c1",
+                TableDataType::Number(NumberDataType::Int32).wrap_nullable(),
+            ),
+            TableField::new("c2", TableDataType::String.wrap_nullable()),
+        ],
+        vec![
+            Int32Type::from_opt_data(vec![Some(1i32), None]),
+            StringType::from_opt_data(vec![None, Some("a\"b".as_bytes().to_vec())]),
+        ],
+    );
+
+    let settings = Settings::create("default".to_string());
+    let mut options = BTreeMap::<String, String>::new();
+    options.insert("type".to_string(), "csv".to_string());
+    options.insert("null_display".to_string(), "null".to_string());
+    options.insert("escape".to_string(), "\\".to_string());
+    let params = FileFormatParams::try_from_ast(FileFormatOptionsAst::new(options.clone()), false)?;
+    let mut options = FileFormatOptionsExt::create_from_settings(&settings, false)?;
+    let mut output_format = options.get_output_format(schema, params)?;
+    let buffer = output_format.serialize_block(&block)?;
+
+    let csv_block = String::from_utf8(buffer)?;
+    let expect = "1,null\nnull,\"a\\\"b\"\n";
+    assert_eq!(&csv_block, expect);
+
+    Ok(())
+}
+
 #[test]
 fn test_data_block_nullable() -> Result<()> {
     test_data_block(true)
@@ -29,6 +29,7 @@ use common_exception::Result;
 use common_expression::type_check::check_number;
 use common_expression::types::decimal::DecimalType;
 use common_expression::types::*;
+use common_expression::with_integer_mapped_type;
 use common_expression::with_number_mapped_type;
 use common_expression::Column;
 use common_expression::ColumnBuilder;
@@ -199,6 +200,12 @@ impl BitmapAggState {
             }
         }
     }
+
+    fn insert(&mut self, value: u64) {
+        self.rb
+            .get_or_insert_with(RoaringTreemap::new)
+            .insert(value);
+    }
 }
 
 impl<OP, AGG> AggregateFunction for AggregateBitmapFunction<OP, AGG>
@@ -339,6 +346,156 @@ impl<OP, AGG> fmt::Display for AggregateBitmapFunction<OP, AGG> {
     }
 }
 
+/// Builds a bitmap from the integer values, such as `bitmap_agg(uid)`.
+#[derive(Clone)]
+struct AggregateBitmapAggFunction<T> {
+    display_name: String,
+    _t: PhantomData<T>,
+}
+
+impl<T> AggregateBitmapAggFunction<T>
+where T: Number + TryInto<u64>
+{
+    fn try_create(display_name: &str) -> Result<Arc<dyn AggregateFunction>> {
+        let func = AggregateBitmapAggFunction::<T> {
+            display_name: display_name.to_string(),
+            _t: PhantomData,
+        };
+        Ok(Arc::new(func))
+    }
+
+    fn to_u64(&self, value: T) -> Result<u64> {
+        value.try_into().map_err(|_| {
+            ErrorCode::BadArguments(format!(
+                "{} does not support negative value {:?}",
+                self.display_name, value
+            ))
+        })
+    }
+}
+
+impl<T> AggregateFunction for AggregateBitmapAggFunction<T>
+where T: Number + TryInto<u64>
+{
+    fn name(&self) -> &str {
+        "AggregateBitmapAggFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(BitmapType::data_type())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(BitmapAggState::new);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<BitmapAggState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column = NumberType::<T>::try_downcast_column(&columns[0]).unwrap();
+        let state = place.get::<BitmapAggState>();
+
+        if let Some(validity) = validity {
+            for (value, valid) in column.iter().zip(validity.iter()) {
+                if valid {
+                    state.insert(self.to_u64(*value)?);
+                }
+            }
+        } else {
+            for value in column.iter() {
+                state.insert(self.to_u64(*value)?);
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let column = NumberType::<T>::try_downcast_column(&columns[0]).unwrap();
+
+        for (value, place) in column.iter().zip(places.iter()) {
+            let addr = place.next(offset);
+            let state = addr.get::<BitmapAggState>();
+            state.insert(self.to_u64(*value)?);
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let column = NumberType::<T>::try_downcast_column(&columns[0]).unwrap();
+        let state = place.get::<BitmapAggState>();
+        if let Some(value) = NumberType::<T>::index_column(&column, row) {
+            state.insert(self.to_u64(value)?);
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<BitmapAggState>();
+        // flag indicate where bitmap is none
+        let flag: u8 = if state.rb.is_some() { 1 } else { 0 };
+        writer.write_scalar(&flag)?;
+        if let Some(rb) = &state.rb {
+            rb.serialize_into(writer)?;
+        }
+        Ok(())
+    }
+
+    fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<BitmapAggState>();
+
+        let flag = reader[0];
+        reader.consume(1);
+        if flag == 1 {
+            let rb = RoaringTreemap::deserialize_from(reader)?;
+            state.add::<BitmapOrOp>(rb);
+        }
+        Ok(())
+    }
+
+    fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<BitmapAggState>();
+        let other = rhs.get::<BitmapAggState>();
+
+        if let Some(rb) = other.rb.take() {
+            state.add::<BitmapOrOp>(rb);
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        BitmapRawResult::merge_result(place, builder)
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<BitmapAggState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl<T> fmt::Display for AggregateBitmapAggFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
 struct AggregateBitmapIntersectCountFunction<T>
 where
     T: ValueType + Send + Sync,
@@ -544,6 +701,24 @@ pub fn try_create_aggregate_bitmap_function<const OP_TYPE: u8, const AGG_TYPE: u
     })
 }
 
+pub fn try_create_aggregate_bitmap_agg_function(
+    display_name: &str,
+    _params: Vec<Scalar>,
+    argument_types: Vec<DataType>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    assert_unary_arguments(display_name, argument_types.len())?;
+    let data_type = argument_types[0].clone();
+    with_integer_mapped_type!(|NUM| match data_type {
+        DataType::Number(NumberDataType::NUM) => {
+            AggregateBitmapAggFunction::<NUM>::try_create(display_name)
+        }
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            display_name, data_type
+        ))),
+    })
+}
+
 pub fn try_create_aggregate_bitmap_intersect_count_function(
     display_name: &str,
     params: Vec<Scalar>,
@@ -721,3 +896,14 @@ pub fn aggregate_bitmap_intersect_count_function_desc() -> AggregateFunctionDesc
         features,
     )
 }
+
+pub fn aggregate_bitmap_agg_function_desc() -> AggregateFunctionDescription {
+    let features = super::aggregate_function_factory::AggregateFunctionFeatures {
+        is_decomposable: true,
+        ..Default::default()
+    };
+    AggregateFunctionDescription::creator_with_features(
+        Box::new(try_create_aggregate_bitmap_agg_function),
+        features,
+    )
+}
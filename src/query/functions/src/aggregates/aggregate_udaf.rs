@@ -0,0 +1,226 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::RemoteExpr;
+use common_expression::Scalar;
+use common_expression::Value;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::deserialize_state;
+use super::serialize_state;
+use super::StateAddr;
+use crate::aggregates::assert_unary_params;
+use crate::aggregates::AggregateFunction;
+use crate::BUILTIN_FUNCTIONS;
+
+/// The name under which the compiled lambdas of user-defined aggregate
+/// functions are executed.
+pub const LAMBDA_UDAF_FUNC_NAME: &str = "_lambda_udaf";
+
+/// The lambdas of a user-defined aggregate function, compiled by the planner.
+///
+/// The columns of `accumulate` are the state followed by the arguments, the
+/// columns of `merge` are the two states and the column of `finalize` is the
+/// state.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LambdaUDAFExprs {
+    pub state_type: DataType,
+    pub init: Scalar,
+    pub accumulate: RemoteExpr,
+    pub merge: RemoteExpr,
+    pub finalize: RemoteExpr,
+}
+
+impl LambdaUDAFExprs {
+    /// Encode the lambdas into the single parameter of `_lambda_udaf`.
+    pub fn to_params(&self) -> Result<Vec<Scalar>> {
+        let mut buf = vec![];
+        serialize_state(&mut buf, self)?;
+        Ok(vec![Scalar::String(buf)])
+    }
+
+    fn from_params(display_name: &str, params: &[Scalar]) -> Result<Self> {
+        assert_unary_params(display_name, params.len())?;
+        match &params[0] {
+            Scalar::String(buf) => deserialize_state(&mut buf.as_slice()),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "The parameter of aggregate function {display_name} must be the compiled lambdas"
+            ))),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateLambdaUDAFFunction {
+    display_name: String,
+    arguments: Vec<DataType>,
+    state_type: DataType,
+    return_type: DataType,
+    init: Scalar,
+    accumulate: Expr,
+    merge: Expr,
+    finalize: Expr,
+    func_ctx: FunctionContext,
+}
+
+impl AggregateLambdaUDAFFunction {
+    fn try_create(
+        display_name: &str,
+        params: Vec<Scalar>,
+        arguments: Vec<DataType>,
+    ) -> Result<Arc<dyn AggregateFunction>> {
+        let exprs = LambdaUDAFExprs::from_params(display_name, &params)?;
+        let accumulate = exprs.accumulate.as_expr(&BUILTIN_FUNCTIONS);
+        let merge = exprs.merge.as_expr(&BUILTIN_FUNCTIONS);
+        let finalize = exprs.finalize.as_expr(&BUILTIN_FUNCTIONS);
+
+        let func = AggregateLambdaUDAFFunction {
+            display_name: display_name.to_string(),
+            arguments,
+            state_type: exprs.state_type,
+            return_type: finalize.data_type().clone(),
+            init: exprs.init,
+            accumulate,
+            merge,
+            finalize,
+            func_ctx: FunctionContext::default(),
+        };
+        Ok(Arc::new(func))
+    }
+
+    /// Evaluate a lambda on a single row made of `inputs`.
+    fn eval(&self, expr: &Expr, inputs: Vec<BlockEntry>) -> Result<Scalar> {
+        let block = DataBlock::new(inputs, 1);
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let value = evaluator.run(expr)?;
+        Ok(value.index(0).unwrap().to_owned())
+    }
+
+    fn state_entry(&self, state: &Scalar) -> BlockEntry {
+        BlockEntry::new(self.state_type.clone(), Value::Scalar(state.clone()))
+    }
+
+    fn merge_scalar(&self, state: &mut Scalar, other: &Scalar) -> Result<()> {
+        *state = self.eval(&self.merge, vec![
+            self.state_entry(state),
+            self.state_entry(other),
+        ])?;
+        Ok(())
+    }
+}
+
+impl AggregateFunction for AggregateLambdaUDAFFunction {
+    fn name(&self) -> &str {
+        "AggregateLambdaUDAFFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(self.return_type.clone())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(|| self.init.clone());
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<Scalar>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        for row in 0..input_rows {
+            if validity.map_or(true, |validity| validity.get_bit(row)) {
+                self.accumulate_row(place, columns, row)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let state = place.get::<Scalar>();
+        let mut inputs = Vec::with_capacity(columns.len() + 1);
+        inputs.push(self.state_entry(state));
+        for (column, data_type) in columns.iter().zip(self.arguments.iter()) {
+            let value = column.index(row).unwrap().to_owned();
+            inputs.push(BlockEntry::new(data_type.clone(), Value::Scalar(value)));
+        }
+        *state = self.eval(&self.accumulate, inputs)?;
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<Scalar>();
+        serialize_state(writer, state)
+    }
+
+    fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<Scalar>();
+        let rhs: Scalar = deserialize_state(reader)?;
+        self.merge_scalar(state, &rhs)
+    }
+
+    fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<Scalar>();
+        let other = rhs.get::<Scalar>();
+        self.merge_scalar(state, other)
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<Scalar>();
+        let result = self.eval(&self.finalize, vec![self.state_entry(state)])?;
+        builder.push(result.as_ref());
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<Scalar>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl fmt::Display for AggregateLambdaUDAFFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub fn aggregate_lambda_udaf_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(AggregateLambdaUDAFFunction::try_create))
+}
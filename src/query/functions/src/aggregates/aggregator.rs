@@ -16,6 +16,7 @@ use super::aggregate_approx_count_distinct::aggregate_approx_count_distinct_func
 use super::aggregate_arg_min_max::aggregate_arg_max_function_desc;
 use super::aggregate_arg_min_max::aggregate_arg_min_function_desc;
 use super::aggregate_avg::aggregate_avg_function_desc;
+use super::aggregate_bitmap::aggregate_bitmap_agg_function_desc;
 use super::aggregate_bitmap::aggregate_bitmap_and_count_function_desc;
 use super::aggregate_bitmap::aggregate_bitmap_intersect_count_function_desc;
 use super::aggregate_bitmap::aggregate_bitmap_intersect_function_desc;
@@ -52,6 +53,8 @@ use crate::aggregates::aggregate_retention::aggregate_retention_function_desc;
 use crate::aggregates::aggregate_skewness::aggregate_skewness_function_desc;
 use crate::aggregates::aggregate_string_agg::aggregate_string_agg_function_desc;
 use crate::aggregates::aggregate_sum::aggregate_sum_function_desc;
+use crate::aggregates::aggregate_udaf::aggregate_lambda_udaf_function_desc;
+use crate::aggregates::aggregate_udaf::LAMBDA_UDAF_FUNC_NAME;
 
 pub struct Aggregators;
 
@@ -134,6 +137,9 @@ impl Aggregators {
             "intersect_count",
             aggregate_bitmap_intersect_count_function_desc(),
         );
+        factory.register("bitmap_agg", aggregate_bitmap_agg_function_desc());
+
+        factory.register(LAMBDA_UDAF_FUNC_NAME, aggregate_lambda_udaf_function_desc());
     }
 
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
@@ -43,6 +43,7 @@ mod aggregate_skewness;
 mod aggregate_stddev;
 mod aggregate_string_agg;
 mod aggregate_sum;
+mod aggregate_udaf;
 mod aggregate_window_funnel;
 mod aggregator;
 mod aggregator_common;
@@ -72,5 +73,7 @@ pub use aggregate_retention::AggregateRetentionFunction;
 pub use aggregate_skewness::AggregateSkewnessFunction;
 pub use aggregate_string_agg::AggregateStringAggFunction;
 pub use aggregate_sum::AggregateSumFunction;
+pub use aggregate_udaf::LambdaUDAFExprs;
+pub use aggregate_udaf::LAMBDA_UDAF_FUNC_NAME;
 pub use aggregator::Aggregators;
 pub use aggregator_common::*;
@@ -0,0 +1,125 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use base64::engine::general_purpose;
+use base64::prelude::*;
+use common_expression::error_to_null;
+use common_expression::types::BinaryType;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // The text form of a binary value is an uppercase hex string,
+    // so `to_binary(string)` and casting a string to binary decode hex.
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "to_binary",
+        |_, _| FunctionDomain::MayThrow,
+        eval_hex_to_binary,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "try_to_binary",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_hex_to_binary),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "from_hex",
+        |_, _| FunctionDomain::MayThrow,
+        eval_hex_to_binary,
+    );
+
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, BinaryType, _, _>(
+        "to_binary",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<StringType, StringType, BinaryType>(
+            |val, format, output, ctx| {
+                let format = String::from_utf8_lossy(format).to_ascii_lowercase();
+                let decoded = match format.as_str() {
+                    "hex" => decode_hex(val),
+                    "base64" => general_purpose::STANDARD
+                        .decode(val)
+                        .map_err(|e| format!("Invalid base64 string: {e}")),
+                    "utf-8" | "utf8" => Ok(val.to_vec()),
+                    _ => Err(format!(
+                        "Unsupported binary format '{format}', expected one of 'hex', 'base64', 'utf-8'"
+                    )),
+                };
+                match decoded {
+                    Ok(bytes) => output.put_slice(&bytes),
+                    Err(err) => ctx.set_error(output.len(), err),
+                }
+                output.commit_row();
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        eval_binary_to_hex,
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_hex",
+        |_, _| FunctionDomain::Full,
+        eval_binary_to_hex,
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "to_base64",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, _| {
+            base64::write::EncoderWriter::new(&mut output.data, &general_purpose::STANDARD)
+                .write_all(val)
+                .unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_1_arg::<BinaryType, NumberType<u64>, _, _>(
+        "length",
+        |_, _| FunctionDomain::Full,
+        |val, _| val.len() as u64,
+    );
+}
+
+fn decode_hex(val: &[u8]) -> Result<Vec<u8>, String> {
+    hex::decode(val).map_err(|e| format!("Invalid hex string: {e}"))
+}
+
+fn eval_hex_to_binary(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<BinaryType> {
+    vectorize_with_builder_1_arg::<StringType, BinaryType>(|val, output, ctx| {
+        match decode_hex(val) {
+            Ok(bytes) => output.put_slice(&bytes),
+            Err(err) => ctx.set_error(output.len(), err),
+        }
+        output.commit_row();
+    })(val, ctx)
+}
+
+fn eval_binary_to_hex(val: ValueRef<BinaryType>, ctx: &mut EvalContext) -> Value<StringType> {
+    vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, _| {
+        output.put_str(&hex::encode_upper(val));
+        output.commit_row();
+    })(val, ctx)
+}
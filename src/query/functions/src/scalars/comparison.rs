@@ -22,6 +22,7 @@ use common_expression::types::string::StringDomain;
 use common_expression::types::AnyType;
 use common_expression::types::ArgType;
 use common_expression::types::ArrayType;
+use common_expression::types::BinaryType;
 use common_expression::types::BooleanType;
 use common_expression::types::DataType;
 use common_expression::types::DateType;
@@ -30,6 +31,7 @@ use common_expression::types::GenericType;
 use common_expression::types::NumberClass;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
+use common_expression::types::TimeType;
 use common_expression::types::TimestampType;
 use common_expression::types::ValueType;
 use common_expression::types::VariantType;
@@ -58,6 +60,8 @@ pub fn register(registry: &mut FunctionRegistry) {
     register_string_cmp(registry);
     register_date_cmp(registry);
     register_timestamp_cmp(registry);
+    register_time_cmp(registry);
+    register_binary_cmp(registry);
     register_number_cmp(registry);
     register_boolean_cmp(registry);
     register_array_cmp(registry);
@@ -169,6 +173,43 @@ fn register_timestamp_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, TimestampType);
 }
 
+fn register_time_cmp(registry: &mut FunctionRegistry) {
+    register_simple_domain_type_cmp!(registry, TimeType);
+}
+
+fn register_binary_cmp(registry: &mut FunctionRegistry) {
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "eq",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs == rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "noteq",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs != rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "gt",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs > rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "gte",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs >= rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "lt",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs < rhs,
+    );
+    registry.register_2_arg::<BinaryType, BinaryType, BooleanType, _, _>(
+        "lte",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs <= rhs,
+    );
+}
+
 fn register_boolean_cmp(registry: &mut FunctionRegistry) {
     registry.register_2_arg::<BooleanType, BooleanType, BooleanType, _, _>(
         "eq",
@@ -594,9 +635,25 @@ fn vectorize_like(
             Value::Scalar(func(arg1, arg2, ctx, &pattern_type))
         }
         (ValueRef::Column(arg1), ValueRef::Scalar(arg2)) => {
-            let arg1_iter = StringType::iter_column(&arg1);
-
             let pattern_type = check_pattern_type(arg2, false);
+            // faster path for prefix and suffix matching on the raw buffers of the column
+            match pattern_type {
+                PatternType::OrdinalStr => {
+                    return Value::Column(common_expression::string::equals(&arg1, arg2));
+                }
+                PatternType::EndOfPercent => {
+                    return Value::Column(common_expression::string::starts_with(
+                        &arg1,
+                        &arg2[..arg2.len() - 1],
+                    ));
+                }
+                PatternType::StartOfPercent => {
+                    return Value::Column(common_expression::string::ends_with(&arg1, &arg2[1..]));
+                }
+                _ => {}
+            }
+
+            let arg1_iter = StringType::iter_column(&arg1);
             // faster path for memmem to have a single instance of Finder
             if pattern_type == PatternType::SurroundByPercent && arg2.len() > 2 {
                 let finder = memmem::Finder::new(&arg2[1..arg2.len() - 1]);
@@ -466,8 +466,10 @@ macro_rules! register_decimal_compare_op {
 fn domain_plus<T: Decimal>(
     lhs: &SimpleDomain<T>,
     rhs: &SimpleDomain<T>,
-    precision: u8,
+    _scales: (u8, u8),
+    size: DecimalSize,
 ) -> Option<SimpleDomain<T>> {
+    let precision = size.precision;
     // For plus, the scale of the two operands must be the same.
     let min = T::min_for_precision(precision);
     let max = T::max_for_precision(precision);
@@ -487,8 +489,10 @@ fn domain_plus<T: Decimal>(
 fn domain_minus<T: Decimal>(
     lhs: &SimpleDomain<T>,
     rhs: &SimpleDomain<T>,
-    precision: u8,
+    _scales: (u8, u8),
+    size: DecimalSize,
 ) -> Option<SimpleDomain<T>> {
+    let precision = size.precision;
     // For minus, the scale of the two operands must be the same.
     let min = T::min_for_precision(precision);
     let max = T::max_for_precision(precision);
@@ -508,8 +512,10 @@ fn domain_minus<T: Decimal>(
 fn domain_mul<T: Decimal>(
     lhs: &SimpleDomain<T>,
     rhs: &SimpleDomain<T>,
-    precision: u8,
+    _scales: (u8, u8),
+    size: DecimalSize,
 ) -> Option<SimpleDomain<T>> {
+    let precision = size.precision;
     let min = T::min_for_precision(precision);
     let max = T::max_for_precision(precision);
 
@@ -538,12 +544,46 @@ fn domain_mul<T: Decimal>(
 
 #[inline(always)]
 fn domain_div<T: Decimal>(
-    _lhs: &SimpleDomain<T>,
-    _rhs: &SimpleDomain<T>,
-    _precision: u8,
+    lhs: &SimpleDomain<T>,
+    rhs: &SimpleDomain<T>,
+    scales: (u8, u8),
+    size: DecimalSize,
 ) -> Option<SimpleDomain<T>> {
-    // For div, we cannot determine the domain.
-    None
+    // The divisor domain must not contain zero, otherwise the division may throw.
+    let zero = T::zero();
+    if rhs.min <= zero && rhs.max >= zero {
+        return None;
+    }
+
+    let (scale_a, scale_b) = scales;
+    let (scale_mul, scale_div) = if scale_b + size.scale > scale_a {
+        (scale_b + size.scale - scale_a, 0)
+    } else {
+        (0, scale_b + size.scale - scale_a)
+    };
+    let multiplier = T::e(scale_mul as u32);
+    let div = T::e(scale_div as u32);
+
+    let min = T::min_for_precision(size.precision);
+    let max = T::max_for_precision(size.precision);
+
+    // The quotient is monotonic in both operands when the divisor keeps its sign,
+    // so the bounds are reached at the corners of the two domains.
+    let quotient = |a: T, b: T| {
+        a.checked_mul(multiplier)?
+            .checked_div(b)?
+            .checked_div(div)
+            .filter(|&m| m >= min && m <= max)
+    };
+    let a = quotient(lhs.min, rhs.min)?;
+    let b = quotient(lhs.min, rhs.max)?;
+    let c = quotient(lhs.max, rhs.min)?;
+    let d = quotient(lhs.max, rhs.max)?;
+
+    Some(SimpleDomain {
+        min: a.min(b).min(c).min(d),
+        max: a.max(b).max(c).max(d),
+    })
 }
 
 macro_rules! register_decimal_binary_op {
@@ -608,14 +648,14 @@ macro_rules! register_decimal_binary_op {
                         {
                             match (lhs, rhs) {
                                 (
-                                    DecimalDomain::Decimal128(d1, _),
-                                    DecimalDomain::Decimal128(d2, _),
-                                ) => $domain_op(&d1, &d2, size.precision)
+                                    DecimalDomain::Decimal128(d1, s1),
+                                    DecimalDomain::Decimal128(d2, s2),
+                                ) => $domain_op(&d1, &d2, (s1.scale, s2.scale), size)
                                     .map(|d| DecimalDomain::Decimal128(d, size)),
                                 (
-                                    DecimalDomain::Decimal256(d1, _),
-                                    DecimalDomain::Decimal256(d2, _),
-                                ) => $domain_op(&d1, &d2, size.precision)
+                                    DecimalDomain::Decimal256(d1, s1),
+                                    DecimalDomain::Decimal256(d2, s2),
+                                ) => $domain_op(&d1, &d2, (s1.scale, s2.scale), size)
                                     .map(|d| DecimalDomain::Decimal256(d, size)),
                                 _ => {
                                     unreachable!("unreachable decimal domain {:?} /{:?}", lhs, rhs)
@@ -0,0 +1,149 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::types::geometry::GeometryType;
+use common_expression::types::BooleanType;
+use common_expression::types::Float64Type;
+use common_expression::types::StringType;
+use common_expression::types::F64;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_io::geo_to_wkb;
+use common_io::parse_to_wkb;
+use common_io::wkb_to_geo;
+use common_io::wkb_to_wkt;
+use geo::Contains;
+use geo::EuclideanDistance;
+use geo::Geometry;
+use geo::Point;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "to_geometry",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, GeometryType>(|s, builder, ctx| {
+            match parse_to_wkb(s) {
+                Ok(wkb) => builder.put_slice(&wkb),
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_aliases("to_geometry", &["st_geometryfromwkt", "st_geomfromwkt"]);
+
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "st_geometryfromwkb",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, GeometryType>(|s, builder, ctx| {
+            let wkb = match hex::decode(s) {
+                Ok(wkb) => wkb,
+                Err(e) => {
+                    ctx.set_error(builder.len(), format!("Invalid WKB hex string: {e}"));
+                    builder.commit_row();
+                    return;
+                }
+            };
+            match wkb_to_geo(&wkb).and_then(|geo| geo_to_wkb(&geo)) {
+                Ok(wkb) => builder.put_slice(&wkb),
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_aliases("st_geometryfromwkb", &["st_geomfromwkb"]);
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, StringType>(|wkb, builder, ctx| {
+            match wkb_to_wkt(wkb) {
+                Ok(wkt) => builder.put_str(&wkt),
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "st_aswkt",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, StringType>(|wkb, builder, ctx| {
+            match wkb_to_wkt(wkb) {
+                Ok(wkt) => builder.put_str(&wkt),
+                Err(e) => ctx.set_error(builder.len(), e.to_string()),
+            }
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_aliases("st_aswkt", &["st_astext"]);
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "st_aswkb",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<GeometryType, StringType>(|wkb, builder, _| {
+            builder.put_str(&hex::encode_upper(wkb));
+            builder.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<Float64Type, Float64Type, GeometryType, _, _>(
+        "st_point",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<Float64Type, Float64Type, GeometryType>(
+            |x, y, builder, ctx| {
+                let point = Geometry::Point(Point::new(x.0, y.0));
+                match geo_to_wkb(&point) {
+                    Ok(wkb) => builder.put_slice(&wkb),
+                    Err(e) => ctx.set_error(builder.len(), e.to_string()),
+                }
+                builder.commit_row();
+            },
+        ),
+    );
+
+    registry.register_aliases("st_point", &["st_makepoint"]);
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, Float64Type, _, _>(
+        "st_distance",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, Float64Type>(
+            |a, b, builder, ctx| match (wkb_to_geo(a), wkb_to_geo(b)) {
+                (Ok(a), Ok(b)) => builder.push(F64::from(a.euclidean_distance(&b))),
+                (Err(e), _) | (_, Err(e)) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                    builder.push(F64::from(0.0));
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_contains",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |a, b, builder, ctx| match (wkb_to_geo(a), wkb_to_geo(b)) {
+                (Ok(a), Ok(b)) => builder.push(a.contains(&b)),
+                (Err(e), _) | (_, Err(e)) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                    builder.push(false);
+                }
+            },
+        ),
+    );
+}
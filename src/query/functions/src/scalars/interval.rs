@@ -0,0 +1,272 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use common_expression::types::interval::string_to_interval;
+use common_expression::types::interval::MonthsDaysNanos;
+use common_expression::types::interval::NANOS_PER_HOUR;
+use common_expression::types::interval::NANOS_PER_MICRO;
+use common_expression::types::interval::NANOS_PER_MINUTE;
+use common_expression::types::interval::NANOS_PER_SEC;
+use common_expression::types::DateType;
+use common_expression::types::Int64Type;
+use common_expression::types::IntervalType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::utils::date_helper::AddIntervalImpl;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // to_interval(string), to_string(interval)
+    register_string_to_interval(registry);
+    // to_years(int64), to_months(int64), ..., to_microseconds(int64)
+    register_number_to_interval(registry);
+    // interval +/- interval, -interval
+    register_interval_arith(registry);
+    // [date | timestamp] +/- interval
+    register_datetime_interval_arith(registry);
+}
+
+fn register_string_to_interval(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "to_interval",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, IntervalType>(|val, output, ctx| {
+            match string_to_interval(val) {
+                Ok(interval) => output.push(interval),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(MonthsDaysNanos::default());
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<IntervalType, StringType>(|val, output, _| {
+            write!(output.data, "{val}").unwrap();
+            output.commit_row();
+        }),
+    );
+}
+
+fn register_number_to_interval(registry: &mut FunctionRegistry) {
+    fn months(value: i64, factor: i64) -> Option<MonthsDaysNanos> {
+        let months = i32::try_from(value.checked_mul(factor)?).ok()?;
+        Some(MonthsDaysNanos::new(months, 0, 0))
+    }
+
+    fn days(value: i64) -> Option<MonthsDaysNanos> {
+        Some(MonthsDaysNanos::new(0, i32::try_from(value).ok()?, 0))
+    }
+
+    fn nanos(value: i64, factor: i64) -> Option<MonthsDaysNanos> {
+        Some(MonthsDaysNanos::new(0, 0, value.checked_mul(factor)?))
+    }
+
+    register_number_to_interval_unit(registry, "to_years", |v| months(v, 12));
+    register_number_to_interval_unit(registry, "to_months", |v| months(v, 1));
+    register_number_to_interval_unit(registry, "to_days", days);
+    register_number_to_interval_unit(registry, "to_hours", |v| nanos(v, NANOS_PER_HOUR));
+    register_number_to_interval_unit(registry, "to_minutes", |v| nanos(v, NANOS_PER_MINUTE));
+    register_number_to_interval_unit(registry, "to_seconds", |v| nanos(v, NANOS_PER_SEC));
+    register_number_to_interval_unit(registry, "to_microseconds", |v| nanos(v, NANOS_PER_MICRO));
+}
+
+fn register_number_to_interval_unit(
+    registry: &mut FunctionRegistry,
+    name: &str,
+    to_interval: fn(i64) -> Option<MonthsDaysNanos>,
+) {
+    registry.register_passthrough_nullable_1_arg::<Int64Type, IntervalType, _, _>(
+        name,
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<Int64Type, IntervalType>(move |val, output, ctx| {
+            match to_interval(val) {
+                Some(interval) => output.push(interval),
+                None => {
+                    ctx.set_error(output.len(), "interval is out of range");
+                    output.push(MonthsDaysNanos::default());
+                }
+            }
+        }),
+    );
+}
+
+fn register_interval_arith(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |a, b, output, ctx| match a.checked_add(&b) {
+                Some(interval) => output.push(interval),
+                None => {
+                    ctx.set_error(output.len(), "interval is out of range");
+                    output.push(MonthsDaysNanos::default());
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |a, b, output, ctx| match a.checked_sub(&b) {
+                Some(interval) => output.push(interval),
+                None => {
+                    ctx.set_error(output.len(), "interval is out of range");
+                    output.push(MonthsDaysNanos::default());
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<IntervalType, IntervalType>(|a, output, ctx| {
+            match a.checked_neg() {
+                Some(interval) => output.push(interval),
+                None => {
+                    ctx.set_error(output.len(), "interval is out of range");
+                    output.push(MonthsDaysNanos::default());
+                }
+            }
+        }),
+    );
+}
+
+fn register_datetime_interval_arith(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<DateType, IntervalType, TimestampType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, IntervalType, TimestampType>(
+            |date, interval, output, ctx| match AddIntervalImpl::eval_date(
+                date,
+                ctx.func_ctx.tz,
+                interval,
+            ) {
+                Ok(ts) => output.push(ts),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, DateType, TimestampType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, DateType, TimestampType>(
+            |interval, date, output, ctx| match AddIntervalImpl::eval_date(
+                date,
+                ctx.func_ctx.tz,
+                interval,
+            ) {
+                Ok(ts) => output.push(ts),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "plus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| match AddIntervalImpl::eval_timestamp(
+                    ts,
+                    ctx.func_ctx.tz,
+                    interval,
+                ) {
+                    Ok(ts) => output.push(ts),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<IntervalType, TimestampType, TimestampType, _, _>(
+            "plus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<IntervalType, TimestampType, TimestampType>(
+                |interval, ts, output, ctx| match AddIntervalImpl::eval_timestamp(
+                    ts,
+                    ctx.func_ctx.tz,
+                    interval,
+                ) {
+                    Ok(ts) => output.push(ts),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_2_arg::<DateType, IntervalType, TimestampType, _, _>(
+        "minus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, IntervalType, TimestampType>(
+            |date, interval, output, ctx| {
+                let result = interval
+                    .checked_neg()
+                    .ok_or_else(|| "interval is out of range".to_string())
+                    .and_then(|neg| AddIntervalImpl::eval_date(date, ctx.func_ctx.tz, neg));
+                match result {
+                    Ok(ts) => output.push(ts),
+                    Err(err) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(0);
+                    }
+                }
+            },
+        ),
+    );
+
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "minus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| {
+                    let result = interval
+                        .checked_neg()
+                        .ok_or_else(|| "interval is out of range".to_string())
+                        .and_then(|neg| AddIntervalImpl::eval_timestamp(ts, ctx.func_ctx.tz, neg));
+                    match result {
+                        Ok(ts) => output.push(ts),
+                        Err(err) => {
+                            ctx.set_error(output.len(), err);
+                            output.push(0);
+                        }
+                    }
+                },
+            ),
+        );
+}
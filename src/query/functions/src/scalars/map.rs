@@ -22,6 +22,7 @@ use common_expression::types::GenericType;
 use common_expression::types::MapType;
 use common_expression::types::NullType;
 use common_expression::types::NullableType;
+use common_expression::vectorize_with_builder_1_arg;
 use common_expression::vectorize_with_builder_2_arg;
 use common_expression::FunctionDomain;
 use common_expression::FunctionRegistry;
@@ -114,4 +115,40 @@ pub fn register(registry: &mut FunctionRegistry) {
             }
         ),
     );
+
+    registry.register_passthrough_nullable_1_arg::<EmptyMapType, EmptyArrayType, _, _>(
+        "map_keys",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<EmptyMapType, EmptyArrayType>(|_, output, _| {
+            *output += 1;
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<GenericType<0>>, _, _>(
+        "map_keys",
+        |_, domain| FunctionDomain::Domain(domain.as_ref().map(|(key_domain, _)| key_domain.clone())),
+        vectorize_with_builder_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<GenericType<0>>>(
+            |map, output, _| {
+                output.push(map.keys);
+            }
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<EmptyMapType, EmptyArrayType, _, _>(
+        "map_values",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<EmptyMapType, EmptyArrayType>(|_, output, _| {
+            *output += 1;
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<GenericType<1>>, _, _>(
+        "map_values",
+        |_, domain| FunctionDomain::Domain(domain.as_ref().map(|(_, val_domain)| val_domain.clone())),
+        vectorize_with_builder_1_arg::<MapType<GenericType<0>, GenericType<1>>, ArrayType<GenericType<1>>>(
+            |map, output, _| {
+                output.push(map.values);
+            }
+        ),
+    );
 }
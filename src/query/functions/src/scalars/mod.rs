@@ -17,6 +17,7 @@ use common_expression::FunctionRegistry;
 mod arithmetic;
 mod arithmetic_modulo;
 mod array;
+mod binary;
 mod bitmap;
 mod boolean;
 mod comparison;
@@ -25,12 +26,15 @@ mod datetime;
 mod decimal;
 mod geo;
 mod geo_h3;
+mod geometry;
 mod hash;
+mod interval;
 mod map;
 mod math;
 mod other;
 mod string;
 mod string_multi_args;
+mod time;
 mod tuple;
 mod variant;
 mod vector;
@@ -39,6 +43,7 @@ pub use comparison::check_pattern_type;
 pub use comparison::is_like_pattern_escape;
 pub use comparison::PatternType;
 pub use comparison::ALL_COMP_FUNC_NAMES;
+pub use string::tokenize_text;
 
 pub fn register(registry: &mut FunctionRegistry) {
     variant::register(registry);
@@ -55,9 +60,13 @@ pub fn register(registry: &mut FunctionRegistry) {
     tuple::register(registry);
     geo::register(registry);
     geo_h3::register(registry);
+    geometry::register(registry);
     hash::register(registry);
     other::register(registry);
     decimal::register(registry);
     vector::register(registry);
     bitmap::register(registry);
+    interval::register(registry);
+    time::register(registry);
+    binary::register(registry);
 }
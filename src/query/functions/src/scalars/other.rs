@@ -26,9 +26,11 @@ use common_expression::types::nullable::NullableColumn;
 use common_expression::types::number::Float64Type;
 use common_expression::types::number::Int64Type;
 use common_expression::types::number::UInt32Type;
+use common_expression::types::number::UInt64Type;
 use common_expression::types::number::UInt8Type;
 use common_expression::types::number::F64;
 use common_expression::types::string::StringColumn;
+use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::ArgType;
 use common_expression::types::DataType;
 use common_expression::types::DateType;
@@ -73,11 +75,17 @@ pub fn register(registry: &mut FunctionRegistry) {
     register_inet_ntoa(registry);
     register_run_diff(registry);
     register_grouping(registry);
+    register_zorder(registry);
+    register_nextval(registry);
 
     registry.properties.insert(
         "rand".to_string(),
         FunctionProperty::default().non_deterministic(),
     );
+    registry.properties.insert(
+        "nextval".to_string(),
+        FunctionProperty::default().non_deterministic(),
+    );
 
     registry.register_passthrough_nullable_1_arg::<Float64Type, StringType, _, _>(
         "humanize_size",
@@ -343,6 +351,53 @@ fn register_run_diff(registry: &mut FunctionRegistry) {
     );
 }
 
+/// `nextval(name)` returns the next value of the sequence `name` for each row.
+fn register_nextval(registry: &mut FunctionRegistry) {
+    registry.register_1_arg_core::<StringType, UInt64Type, _, _>(
+        "nextval",
+        |_, _| FunctionDomain::MayThrow,
+        |name, ctx| {
+            let num_rows = ctx.num_rows;
+            let Some(generator) = ctx.func_ctx.sequence_generator.clone() else {
+                ctx.set_error(0, "nextval is not supported in this context");
+                return Value::Column(vec![0; num_rows].into());
+            };
+
+            let next_values = |name: &[u8], count: usize| {
+                let name = std::str::from_utf8(name).map_err(|e| e.to_string())?;
+                generator
+                    .next_values(name, count as u64)
+                    .map_err(|e| e.message())
+            };
+
+            let mut values = Vec::with_capacity(num_rows);
+            match name {
+                ValueRef::Scalar(name) => {
+                    if num_rows > 0 {
+                        match next_values(name, num_rows) {
+                            Ok(next) => values = next,
+                            Err(err) => ctx.set_error(0, err),
+                        }
+                    }
+                }
+                ValueRef::Column(col) => {
+                    for (row, name) in col.iter().enumerate() {
+                        match next_values(name, 1) {
+                            Ok(next) => values.extend(next),
+                            Err(err) => {
+                                ctx.set_error(row, err);
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+            values.resize(num_rows, 0);
+            Value::Column(values.into())
+        },
+    );
+}
+
 fn register_grouping(registry: &mut FunctionRegistry) {
     registry.register_function_factory("grouping", |params, arg_type| {
         if arg_type.len() != 1 {
@@ -388,3 +443,124 @@ pub fn compute_grouping(cols: &[usize], grouping_id: u32) -> u32 {
     }
     grouping
 }
+
+/// `zorder(a, b, ...)` computes the Z-order (Morton) key of the arguments by
+/// interleaving the bits of their order-preserving 64-bit representations.
+///
+/// Using it as a cluster key, e.g. `CLUSTER BY (zorder(a, b))`, makes the
+/// recluster pipeline sort rows along the space-filling curve, so the column
+/// statistics of each block stay narrow on every input column and range
+/// predicates over any of them can prune blocks.
+fn register_zorder(registry: &mut FunctionRegistry) {
+    registry.register_function_factory("zorder", |_, args_type| {
+        if args_type.is_empty() || args_type.len() > 8 {
+            return None;
+        }
+        let supported = args_type.iter().all(|ty| {
+            matches!(
+                ty.remove_nullable(),
+                DataType::Null
+                    | DataType::Boolean
+                    | DataType::Number(_)
+                    | DataType::String
+                    | DataType::Date
+                    | DataType::Timestamp
+            )
+        });
+        if !supported {
+            return None;
+        }
+
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: "zorder".to_string(),
+                args_type: args_type.to_vec(),
+                return_type: DataType::String,
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::Full),
+                eval: Box::new(|args, ctx| {
+                    let is_scalar = args.iter().all(|arg| arg.as_scalar().is_some());
+                    let num_rows = if is_scalar { 1 } else { ctx.num_rows };
+
+                    let mut builder =
+                        StringColumnBuilder::with_capacity(num_rows, num_rows * args.len() * 8);
+                    let mut keys = Vec::with_capacity(args.len());
+                    for row in 0..num_rows {
+                        keys.clear();
+                        for arg in args {
+                            let val = unsafe { arg.index_unchecked(row) };
+                            keys.push(zorder_key(val));
+                        }
+                        interleave_bits(&keys, &mut builder.data);
+                        builder.commit_row();
+                    }
+
+                    if is_scalar {
+                        Value::Scalar(Scalar::String(builder.build_scalar()))
+                    } else {
+                        Value::Column(Column::String(builder.build()))
+                    }
+                }),
+            },
+        }))
+    })
+}
+
+/// Maps a scalar to an unsigned integer whose natural order matches the order
+/// of the scalar. NULLs are ordered first.
+fn zorder_key(val: ScalarRef) -> u64 {
+    const SIGN: u64 = 1 << 63;
+    match val {
+        ScalarRef::Null => 0,
+        ScalarRef::Boolean(v) => v as u64,
+        // Integers share one key space regardless of their width or signedness.
+        ScalarRef::Number(NumberScalar::UInt8(v)) => (v as u64) ^ SIGN,
+        ScalarRef::Number(NumberScalar::UInt16(v)) => (v as u64) ^ SIGN,
+        ScalarRef::Number(NumberScalar::UInt32(v)) => (v as u64) ^ SIGN,
+        ScalarRef::Number(NumberScalar::UInt64(v)) => v.min(i64::MAX as u64) ^ SIGN,
+        ScalarRef::Number(NumberScalar::Int8(v)) => (v as i64 as u64) ^ SIGN,
+        ScalarRef::Number(NumberScalar::Int16(v)) => (v as i64 as u64) ^ SIGN,
+        ScalarRef::Number(NumberScalar::Int32(v)) => (v as i64 as u64) ^ SIGN,
+        ScalarRef::Number(NumberScalar::Int64(v)) => (v as u64) ^ SIGN,
+        ScalarRef::Number(NumberScalar::Float32(v)) => float_key((v.0 as f64).to_bits()),
+        ScalarRef::Number(NumberScalar::Float64(v)) => float_key(v.0.to_bits()),
+        ScalarRef::Date(v) => (v as i64 as u64) ^ SIGN,
+        ScalarRef::Timestamp(v) => (v as u64) ^ SIGN,
+        ScalarRef::String(v) => {
+            // Only the first 8 bytes take part in the key, like cluster statistics do.
+            let mut buf = [0u8; 8];
+            let len = v.len().min(8);
+            buf[..len].copy_from_slice(&v[..len]);
+            u64::from_be_bytes(buf)
+        }
+        _ => unreachable!("unsupported zorder argument {val:?}"),
+    }
+}
+
+#[inline]
+fn float_key(bits: u64) -> u64 {
+    if bits >> 63 == 1 {
+        !bits
+    } else {
+        bits | (1 << 63)
+    }
+}
+
+/// Interleaves the bits of `keys` from the most significant bit downward and
+/// appends the result to `out` in big-endian order.
+fn interleave_bits(keys: &[u64], out: &mut Vec<u8>) {
+    let mut byte = 0u8;
+    let mut filled = 0;
+    for bit in (0..64).rev() {
+        for key in keys {
+            byte = (byte << 1) | ((key >> bit) & 1) as u8;
+            filled += 1;
+            if filled == 8 {
+                out.push(byte);
+                byte = 0;
+                filled = 0;
+            }
+        }
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::io::Write;
 
 use base64::engine::general_purpose;
@@ -23,6 +24,7 @@ use common_expression::types::number::UInt64Type;
 use common_expression::types::string::StringColumn;
 use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::ArrayType;
+use common_expression::types::BooleanType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
 use common_expression::vectorize_with_builder_1_arg;
@@ -48,8 +50,8 @@ pub fn register(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
         "upper",
         |_, _| FunctionDomain::Full,
-        vectorize_string_to_string(
-            |col| col.data().len(),
+        vectorize_string_case(
+            common_expression::string::ascii_to_uppercase,
             |val, output, _| {
                 for (start, end, ch) in val.char_indices() {
                     if ch == '\u{FFFD}' {
@@ -71,8 +73,8 @@ pub fn register(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
         "lower",
         |_, _| FunctionDomain::Full,
-        vectorize_string_to_string(
-            |col| col.data().len(),
+        vectorize_string_case(
+            common_expression::string::ascii_to_lowercase,
             |val, output, _| {
                 for (start, end, ch) in val.char_indices() {
                     if ch == '\u{FFFD}' {
@@ -267,6 +269,16 @@ pub fn register(registry: &mut FunctionRegistry) {
         },
     );
 
+    // Full text search: true if every term of the query also appears in the text.
+    registry.register_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "match",
+        |_, _, _| FunctionDomain::Full,
+        |text, query, _| {
+            let terms = tokenize_text(text).collect::<HashSet<_>>();
+            tokenize_text(query).all(|term| terms.contains(&term))
+        },
+    );
+
     let find_at = |str: &[u8], substr: &[u8], pos: u64| {
         if substr.is_empty() {
             // the same behavior as MySQL, Postgres and Clickhouse
@@ -713,21 +725,33 @@ pub fn register(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_2_arg::<StringType, NumberType<i64>, StringType, _, _>(
         "substr",
         |_, _, _| FunctionDomain::Full,
-        vectorize_with_builder_2_arg::<StringType, NumberType<i64>, StringType>(
-            |s, pos, output, _| {
-                output.put_slice(substr(s, pos, s.len() as u64));
-                output.commit_row();
-            },
-        ),
+        |s, pos, ctx| match (s, pos) {
+            (ValueRef::Column(col), ValueRef::Scalar(pos)) => {
+                Value::Column(common_expression::string::map_sub_slices(&col, |s| {
+                    substr(s, pos, s.len() as u64)
+                }))
+            }
+            (s, pos) => vectorize_with_builder_2_arg::<StringType, NumberType<i64>, StringType>(
+                |s, pos, output, _| {
+                    output.put_slice(substr(s, pos, s.len() as u64));
+                    output.commit_row();
+                },
+            )(s, pos, ctx),
+        },
     );
 
     registry.register_passthrough_nullable_3_arg::<StringType, NumberType<i64>, NumberType<u64>, StringType, _, _>(
         "substr",
         |_, _, _, _| FunctionDomain::Full,
-        vectorize_with_builder_3_arg::<StringType, NumberType<i64>, NumberType<u64>, StringType>(|s, pos, len, output, _| {
-            output.put_slice(substr(s, pos, len));
-            output.commit_row();
-        }),
+        |s, pos, len, ctx| match (s, pos, len) {
+            (ValueRef::Column(col), ValueRef::Scalar(pos), ValueRef::Scalar(len)) => Value::Column(
+                common_expression::string::map_sub_slices(&col, |s| substr(s, pos, len)),
+            ),
+            (s, pos, len) => vectorize_with_builder_3_arg::<StringType, NumberType<i64>, NumberType<u64>, StringType>(|s, pos, len, output, _| {
+                output.put_slice(substr(s, pos, len));
+                output.commit_row();
+            })(s, pos, len, ctx),
+        },
     );
 
     registry.register_passthrough_nullable_2_arg::<StringType, NumberType<i64>, StringType, _, _>(
@@ -913,6 +937,16 @@ pub(crate) mod soundex {
 }
 
 #[inline]
+/// Split the text into lowercase terms, any ASCII character that is not alphanumeric is a separator.
+///
+/// The fuse inverted index relies on this to build its posting lists, so that the terms
+/// of the index always agree with the terms seen by the `match` function.
+pub fn tokenize_text(text: &[u8]) -> impl Iterator<Item = String> + '_ {
+    text.split(|b| b.is_ascii() && !b.is_ascii_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(|term| String::from_utf8_lossy(term).to_lowercase())
+}
+
 fn substr(str: &[u8], pos: i64, len: u64) -> &[u8] {
     if pos > 0 && pos <= str.len() as i64 {
         let l = str.len();
@@ -955,6 +989,23 @@ fn substr_utf8(builder: &mut StringColumnBuilder, str: &str, pos: i64, len: u64)
     builder.commit_row();
 }
 
+/// Case mapping function of String, which uses the `ascii` kernel on the raw buffers
+/// if the column only contains ASCII characters, and falls back to `func` for each row otherwise.
+fn vectorize_string_case(
+    ascii: fn(&StringColumn) -> Option<StringColumn>,
+    func: impl Fn(&[u8], &mut StringColumnBuilder, &mut EvalContext) + Copy,
+) -> impl Fn(ValueRef<StringType>, &mut EvalContext) -> Value<StringType> + Copy {
+    let fallback = vectorize_string_to_string(|col| col.data().len(), func);
+    move |arg1, ctx| {
+        if let ValueRef::Column(col) = &arg1 {
+            if let Some(col) = ascii(col) {
+                return Value::Column(col);
+            }
+        }
+        fallback(arg1, ctx)
+    }
+}
+
 /// String to String scalar function with estimated output column capacity.
 pub fn vectorize_string_to_string(
     estimate_bytes: impl Fn(&StringColumn) -> usize + Copy,
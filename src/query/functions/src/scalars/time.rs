@@ -0,0 +1,197 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use chrono::NaiveDate;
+use chrono::TimeZone;
+use chrono::Timelike;
+use chrono_tz::Tz;
+use common_expression::error_to_null;
+use common_expression::types::interval::MonthsDaysNanos;
+use common_expression::types::interval::NANOS_PER_MICRO;
+use common_expression::types::nullable::NullableDomain;
+use common_expression::types::number::SimpleDomain;
+use common_expression::types::time::string_to_time;
+use common_expression::types::time::time_to_string;
+use common_expression::types::time::wrap_time;
+use common_expression::types::time::MICROS_IN_A_DAY;
+use common_expression::types::timestamp::MICROS_IN_A_SEC;
+use common_expression::types::ArgType;
+use common_expression::types::IntervalType;
+use common_expression::types::StringType;
+use common_expression::types::TimeType;
+use common_expression::types::TimestampType;
+use common_expression::utils::date_helper::DateConverter;
+use common_expression::vectorize_with_builder_1_arg;
+use common_expression::vectorize_with_builder_2_arg;
+use common_expression::EvalContext;
+use common_expression::FunctionDomain;
+use common_expression::FunctionRegistry;
+use common_expression::Value;
+use common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // to_time(string), to_string(time)
+    register_string_to_time(registry);
+    // to_time(timestamp), to_timestamp(time)
+    register_timestamp_to_time(registry);
+    // time +/- interval, time - time
+    register_time_interval_arith(registry);
+}
+
+fn register_string_to_time(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, TimeType, _, _>(
+        "to_time",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_time,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, TimeType, _, _>(
+        "try_to_time",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_time),
+    );
+
+    fn eval_string_to_time(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<TimeType> {
+        vectorize_with_builder_1_arg::<StringType, TimeType>(
+            |val, output, ctx| match string_to_time(val) {
+                Ok(time) => output.push(time),
+                Err(err) => {
+                    ctx.set_error(output.len(), err);
+                    output.push(0);
+                }
+            },
+        )(val, ctx)
+    }
+
+    registry.register_passthrough_nullable_1_arg::<TimeType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<TimeType, StringType>(|val, output, _| {
+            write!(output.data, "{}", time_to_string(val)).unwrap();
+            output.commit_row();
+        }),
+    );
+}
+
+fn register_timestamp_to_time(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<TimestampType, TimeType, _, _>(
+        "to_time",
+        |_, _| FunctionDomain::Full,
+        eval_timestamp_to_time,
+    );
+    registry.register_combine_nullable_1_arg::<TimestampType, TimeType, _, _>(
+        "try_to_time",
+        |_, _| {
+            FunctionDomain::Domain(NullableDomain {
+                has_null: false,
+                value: Some(Box::new(TimeType::full_domain())),
+            })
+        },
+        error_to_null(eval_timestamp_to_time),
+    );
+
+    fn eval_timestamp_to_time(
+        val: ValueRef<TimestampType>,
+        ctx: &mut EvalContext,
+    ) -> Value<TimeType> {
+        vectorize_with_builder_1_arg::<TimestampType, TimeType>(|val, output, ctx| {
+            let tz = ctx.func_ctx.tz.tz;
+            output.push(calc_timestamp_to_time(val, tz));
+        })(val, ctx)
+    }
+
+    /// The time of day of the timestamp in the time zone.
+    fn calc_timestamp_to_time(val: i64, tz: Tz) -> i64 {
+        let time = val.to_timestamp(tz).naive_local().time();
+        time.num_seconds_from_midnight() as i64 * MICROS_IN_A_SEC
+            + (time.nanosecond() / 1_000) as i64
+    }
+
+    registry.register_passthrough_nullable_1_arg::<TimeType, TimestampType, _, _>(
+        "to_timestamp",
+        |ctx, domain| {
+            let tz = ctx.tz.tz;
+            FunctionDomain::Domain(SimpleDomain {
+                min: calc_time_to_timestamp(domain.min, tz),
+                max: calc_time_to_timestamp(domain.max, tz),
+            })
+        },
+        vectorize_with_builder_1_arg::<TimeType, TimestampType>(|val, output, ctx| {
+            let tz = ctx.func_ctx.tz.tz;
+            output.push(calc_time_to_timestamp(val, tz));
+        }),
+    );
+
+    /// The time is taken as the time of day of `1970-01-01` in the time zone.
+    fn calc_time_to_timestamp(val: i64, tz: Tz) -> i64 {
+        let epoch_offset = tz
+            .from_utc_datetime(
+                &NaiveDate::from_ymd_opt(1970, 1, 1)
+                    .unwrap()
+                    .and_hms_micro_opt(0, 0, 0, 0)
+                    .unwrap(),
+            )
+            .naive_local()
+            .timestamp_micros();
+        val - epoch_offset
+    }
+}
+
+fn register_time_interval_arith(registry: &mut FunctionRegistry) {
+    // The months and days parts of the interval are whole days, they never change the
+    // time of day, so only the nanoseconds part is applied and the result wraps around
+    // midnight, e.g. `23:00:00 + INTERVAL 2 HOUR` is `01:00:00`.
+    fn add_micros(time: i64, micros: i64) -> i64 {
+        wrap_time(time + micros % MICROS_IN_A_DAY)
+    }
+
+    registry.register_passthrough_nullable_2_arg::<TimeType, IntervalType, TimeType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<TimeType, IntervalType, TimeType>(
+            |time, interval, output, _| {
+                output.push(add_micros(time, interval.nanos / NANOS_PER_MICRO))
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, TimeType, TimeType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<IntervalType, TimeType, TimeType>(
+            |interval, time, output, _| {
+                output.push(add_micros(time, interval.nanos / NANOS_PER_MICRO))
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<TimeType, IntervalType, TimeType, _, _>(
+        "minus",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<TimeType, IntervalType, TimeType>(
+            |time, interval, output, _| {
+                output.push(add_micros(time, -(interval.nanos / NANOS_PER_MICRO)))
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<TimeType, TimeType, IntervalType, _, _>(
+        "minus",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<TimeType, TimeType, IntervalType>(|a, b, output, _| {
+            output.push(MonthsDaysNanos::new(0, 0, (a - b) * NANOS_PER_MICRO))
+        }),
+    );
+}
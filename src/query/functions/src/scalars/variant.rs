@@ -23,6 +23,7 @@ use common_arrow::arrow::bitmap::MutableBitmap;
 use common_arrow::arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
 use common_expression::types::date::string_to_date;
 use common_expression::types::nullable::NullableColumn;
+use common_expression::types::nullable::NullableColumnBuilder;
 use common_expression::types::nullable::NullableDomain;
 use common_expression::types::number::*;
 use common_expression::types::string::StringColumnBuilder;
@@ -68,11 +69,12 @@ use jsonb::get_by_index;
 use jsonb::get_by_keypath;
 use jsonb::get_by_name;
 use jsonb::get_by_path;
-use jsonb::get_by_path_array;
-use jsonb::get_by_path_first;
 use jsonb::is_array;
 use jsonb::is_object;
 use jsonb::jsonpath::parse_json_path;
+use jsonb::jsonpath::JsonPath;
+use jsonb::jsonpath::Mode as SelectorMode;
+use jsonb::jsonpath::Selector;
 use jsonb::keypath::parse_key_paths;
 use jsonb::object_keys;
 use jsonb::parse_value;
@@ -437,75 +439,13 @@ pub fn register(registry: &mut FunctionRegistry) {
     registry.register_combine_nullable_2_arg::<VariantType, StringType, VariantType, _, _>(
         "json_path_query_array",
         |_, _, _| FunctionDomain::MayThrow,
-        vectorize_with_builder_2_arg::<VariantType, StringType, NullableType<VariantType>>(
-            |val, path, output, ctx| {
-                if let Some(validity) = &ctx.validity {
-                    if !validity.get_bit(output.len()) {
-                        output.push_null();
-                        return;
-                    }
-                }
-                match parse_json_path(path) {
-                    Ok(json_path) => {
-                        get_by_path_array(
-                            val,
-                            json_path,
-                            &mut output.builder.data,
-                            &mut output.builder.offsets,
-                        );
-                        if output.builder.offsets.len() == output.len() + 1 {
-                            output.push_null();
-                        } else {
-                            output.validity.push(true);
-                        }
-                    }
-                    Err(_) => {
-                        ctx.set_error(
-                            output.len(),
-                            format!("Invalid JSON Path '{}'", &String::from_utf8_lossy(path),),
-                        );
-                        output.push_null();
-                    }
-                }
-            },
-        ),
+        vectorize_json_path(|json_path| Selector::new(json_path, SelectorMode::Array)),
     );
 
     registry.register_combine_nullable_2_arg::<VariantType, StringType, VariantType, _, _>(
         "json_path_query_first",
         |_, _, _| FunctionDomain::MayThrow,
-        vectorize_with_builder_2_arg::<VariantType, StringType, NullableType<VariantType>>(
-            |val, path, output, ctx| {
-                if let Some(validity) = &ctx.validity {
-                    if !validity.get_bit(output.len()) {
-                        output.push_null();
-                        return;
-                    }
-                }
-                match parse_json_path(path) {
-                    Ok(json_path) => {
-                        get_by_path_first(
-                            val,
-                            json_path,
-                            &mut output.builder.data,
-                            &mut output.builder.offsets,
-                        );
-                        if output.builder.offsets.len() == output.len() + 1 {
-                            output.push_null();
-                        } else {
-                            output.validity.push(true);
-                        }
-                    }
-                    Err(_) => {
-                        ctx.set_error(
-                            output.len(),
-                            format!("Invalid JSON Path '{}'", &String::from_utf8_lossy(path),),
-                        );
-                        output.push_null();
-                    }
-                }
-            },
-        ),
+        vectorize_json_path(|json_path| Selector::new(json_path, SelectorMode::First)),
     );
 
     registry.register_passthrough_nullable_2_arg::<VariantType, StringType, BooleanType, _, _>(
@@ -1171,6 +1111,82 @@ pub fn register(registry: &mut FunctionRegistry) {
     });
 }
 
+/// Evaluates a json path function with the selector built by `build_selector`.
+/// The json path is parsed and compiled only once if it is a constant.
+fn vectorize_json_path(
+    build_selector: for<'p> fn(JsonPath<'p>) -> Selector<'p>,
+) -> impl for<'a> Fn(
+    ValueRef<'a, VariantType>,
+    ValueRef<'a, StringType>,
+    &mut EvalContext,
+) -> Value<NullableType<VariantType>>
++ Copy
++ Send
++ Sync {
+    move |val, path, ctx| match path {
+        ValueRef::Scalar(path) => match parse_json_path(path) {
+            Ok(json_path) => {
+                let selector = build_selector(json_path);
+                vectorize_with_builder_1_arg::<VariantType, NullableType<VariantType>>(
+                    |val, output, ctx| {
+                        if let Some(validity) = &ctx.validity {
+                            if !validity.get_bit(output.len()) {
+                                output.push_null();
+                                return;
+                            }
+                        }
+                        select_json_path(&selector, val, output);
+                    },
+                )(val, ctx)
+            }
+            Err(_) => {
+                ctx.set_error(
+                    0,
+                    format!("Invalid JSON Path '{}'", &String::from_utf8_lossy(path)),
+                );
+                vectorize_with_builder_1_arg::<VariantType, NullableType<VariantType>>(
+                    |_, output, _| output.push_null(),
+                )(val, ctx)
+            }
+        },
+        path => vectorize_with_builder_2_arg::<VariantType, StringType, NullableType<VariantType>>(
+            move |val, path, output, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(output.len()) {
+                        output.push_null();
+                        return;
+                    }
+                }
+                match parse_json_path(path) {
+                    Ok(json_path) => {
+                        select_json_path(&build_selector(json_path), val, output);
+                    }
+                    Err(_) => {
+                        ctx.set_error(
+                            output.len(),
+                            format!("Invalid JSON Path '{}'", &String::from_utf8_lossy(path)),
+                        );
+                        output.push_null();
+                    }
+                }
+            },
+        )(val, path, ctx),
+    }
+}
+
+fn select_json_path(
+    selector: &Selector<'_>,
+    val: &[u8],
+    output: &mut NullableColumnBuilder<VariantType>,
+) {
+    selector.select(val, &mut output.builder.data, &mut output.builder.offsets);
+    if output.builder.offsets.len() == output.len() + 1 {
+        output.push_null();
+    } else {
+        output.validity.push(true);
+    }
+}
+
 fn json_array_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value<AnyType> {
     let (columns, len) = prepare_args_columns(args, ctx);
     let cap = len.unwrap_or(1);
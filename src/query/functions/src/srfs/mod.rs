@@ -18,7 +18,13 @@ use common_expression::types::nullable::NullableColumn;
 use common_expression::types::string::StringColumnBuilder;
 use common_expression::types::AnyType;
 use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::types::VariantType;
 use common_expression::Column;
+use common_expression::FromData;
+use common_expression::FromOptData;
 use common_expression::Function;
 use common_expression::FunctionEval;
 use common_expression::FunctionKind;
@@ -241,6 +247,46 @@ pub fn register(registry: &mut FunctionRegistry) {
             },
         }))
     });
+
+    registry.properties.insert(
+        "flatten".to_string(),
+        FunctionProperty::default().kind(FunctionKind::SRF),
+    );
+    registry.register_function_factory("flatten", |_, args_type| {
+        if args_type.len() != 1 {
+            return None;
+        }
+        if args_type[0].remove_nullable() != DataType::Variant && args_type[0] != DataType::Null {
+            return None;
+        }
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: "flatten".to_string(),
+                args_type: args_type.to_vec(),
+                return_type: DataType::Tuple(vec![DataType::Nullable(Box::new(DataType::Tuple(
+                    vec![
+                        DataType::Nullable(Box::new(DataType::Number(NumberDataType::UInt64))),
+                        DataType::Nullable(Box::new(DataType::String)),
+                        DataType::Variant,
+                    ],
+                )))]),
+            },
+            eval: FunctionEval::SRF {
+                eval: Box::new(|args, ctx, max_nums_per_row| {
+                    let arg = args[0].clone().to_owned();
+                    (0..ctx.num_rows)
+                        .map(|row| match arg.index(row).unwrap() {
+                            ScalarRef::Null => {
+                                (Value::Scalar(Scalar::Tuple(vec![Scalar::Null])), 0)
+                            }
+                            ScalarRef::Variant(val) => flatten_variant(val, row, max_nums_per_row),
+                            _ => unreachable!(),
+                        })
+                        .collect()
+                }),
+            },
+        }))
+    });
 }
 
 fn build_unnest(
@@ -396,3 +442,37 @@ fn unnest_variant_obj(
         _ => (Value::Scalar(Scalar::Tuple(vec![Scalar::Null])), 0),
     }
 }
+
+/// Explode the elements of a json array or the fields of a json object into rows of
+/// `(index, key, value)`. Scalar values and empty containers produce no rows.
+fn flatten_variant(
+    val: &[u8],
+    row: usize,
+    max_nums_per_row: &mut [usize],
+) -> (Value<AnyType>, usize) {
+    let (indexes, keys, vals) = if let Some(vals) = array_values(val) {
+        let indexes = (0..vals.len() as u64).map(Some).collect::<Vec<_>>();
+        let keys = vec![None; vals.len()];
+        (indexes, keys, vals)
+    } else if let Some(fields) = object_each(val) {
+        let indexes = vec![None; fields.len()];
+        let (keys, vals): (Vec<_>, Vec<_>) = fields.into_iter().map(|(k, v)| (Some(k), v)).unzip();
+        (indexes, keys, vals)
+    } else {
+        return (Value::Scalar(Scalar::Tuple(vec![Scalar::Null])), 0);
+    };
+
+    let len = vals.len();
+    if len == 0 {
+        return (Value::Scalar(Scalar::Tuple(vec![Scalar::Null])), 0);
+    }
+    max_nums_per_row[row] = std::cmp::max(max_nums_per_row[row], len);
+
+    let tuple_col = Column::Tuple(vec![
+        UInt64Type::from_opt_data(indexes),
+        StringType::from_opt_data(keys),
+        VariantType::from_data(vals),
+    ])
+    .wrap_nullable(None);
+    (Value::Column(Column::Tuple(vec![tuple_col])), len)
+}
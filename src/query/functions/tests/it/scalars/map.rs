@@ -27,6 +27,7 @@ fn test_map() {
 
     test_create(file);
     test_get(file);
+    test_map_keys_values(file);
 }
 
 fn test_create(file: &mut impl Write) {
@@ -82,3 +83,10 @@ fn test_get(file: &mut impl Write) {
         ("v2", StringType::from_data(vec!["v3", "v4"])),
     ]);
 }
+
+fn test_map_keys_values(file: &mut impl Write) {
+    run_ast(file, "map_keys({})", &[]);
+    run_ast(file, "map_keys({'a':1,'b':2})", &[]);
+    run_ast(file, "map_values({})", &[]);
+    run_ast(file, "map_values({'a':1,'b':2})", &[]);
+}
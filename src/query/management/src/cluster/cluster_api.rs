@@ -27,7 +27,7 @@ pub trait ClusterApi: Sync + Send {
     // Drop the tenant's cluster one node by node.id.
     async fn drop_node(&self, node_id: String, seq: MatchSeq) -> Result<()>;
 
-    // Keep the tenant's cluster node alive.
+    // Keep the tenant's cluster node alive, and refresh its node info.
     async fn heartbeat(&self, node: &NodeInfo, seq: MatchSeq) -> Result<u64>;
 
     async fn get_local_addr(&self) -> Result<Option<String>>;
@@ -143,11 +143,13 @@ impl ClusterApi for ClusterMgr {
     #[minitrace::trace]
     async fn heartbeat(&self, node: &NodeInfo, seq: MatchSeq) -> Result<u64> {
         let meta = Some(self.new_lift_time());
+        // Rewrite the node info to publish its latest resource usage.
+        let value = Operation::Update(serde_json::to_vec(node)?);
         let node_key = format!("{}/{}", self.cluster_prefix, escape_for_key(&node.id)?);
 
-        let upsert_meta =
-            self.metastore
-                .upsert_kv(UpsertKVReq::new(&node_key, seq, Operation::AsIs, meta));
+        let upsert_meta = self
+            .metastore
+            .upsert_kv(UpsertKVReq::new(&node_key, seq, value, meta));
 
         match upsert_meta.await? {
             UpsertKVReply {
@@ -19,6 +19,8 @@ mod file_format;
 mod network_policy;
 mod quota;
 mod role;
+mod row_access_policy;
+mod sequence;
 mod serde;
 mod setting;
 mod stage;
@@ -35,6 +37,10 @@ pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
 pub use role::RoleApi;
 pub use role::RoleMgr;
+pub use row_access_policy::RowAccessPolicyApi;
+pub use row_access_policy::RowAccessPolicyMgr;
+pub use sequence::SequenceApi;
+pub use sequence::SequenceMgr;
 pub use serde::deserialize_struct;
 pub use serde::serialize_struct;
 pub use setting::SettingApi;
@@ -0,0 +1,33 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_app::principal::RowAccessPolicy;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+#[async_trait::async_trait]
+pub trait RowAccessPolicyApi: Sync + Send {
+    async fn add_row_access_policy(&self, row_access_policy: RowAccessPolicy) -> Result<u64>;
+
+    async fn drop_row_access_policy(&self, name: &str, seq: MatchSeq) -> Result<()>;
+
+    async fn get_row_access_policy(
+        &self,
+        name: &str,
+        seq: MatchSeq,
+    ) -> Result<SeqV<RowAccessPolicy>>;
+
+    async fn get_row_access_policies(&self) -> Result<Vec<RowAccessPolicy>>;
+}
@@ -0,0 +1,151 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::principal::RowAccessPolicy;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use crate::row_access_policy::row_access_policy_api::RowAccessPolicyApi;
+use crate::serde::deserialize_struct;
+use crate::serde::serialize_struct;
+
+static ROW_ACCESS_POLICY_API_KEY_PREFIX: &str = "__fd_row_access_policies";
+
+pub struct RowAccessPolicyMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    row_access_policy_prefix: String,
+}
+
+impl RowAccessPolicyMgr {
+    pub fn create(
+        kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+        tenant: &str,
+    ) -> Result<Self, ErrorCode> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty (while create row access policy)",
+            ));
+        }
+
+        Ok(RowAccessPolicyMgr {
+            kv_api,
+            row_access_policy_prefix: format!("{}/{}", ROW_ACCESS_POLICY_API_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn make_row_access_policy_key(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.row_access_policy_prefix,
+            escape_for_key(name)?
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl RowAccessPolicyApi for RowAccessPolicyMgr {
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn add_row_access_policy(&self, row_access_policy: RowAccessPolicy) -> Result<u64> {
+        let match_seq = MatchSeq::Exact(0);
+        let key = self.make_row_access_policy_key(row_access_policy.name.as_str())?;
+        let value = Operation::Update(serialize_struct(
+            &row_access_policy,
+            ErrorCode::IllegalRowAccessPolicy,
+            || "",
+        )?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api.upsert_kv(UpsertKVReq::new(&key, match_seq, value, None));
+
+        let res = upsert_kv.await?.added_or_else(|v| {
+            ErrorCode::RowAccessPolicyAlreadyExists(format!(
+                "RowAccessPolicy already exists, seq [{}]",
+                v.seq
+            ))
+        })?;
+
+        Ok(res.seq)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn drop_row_access_policy(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.make_row_access_policy_key(name)?;
+        let kv_api = self.kv_api.clone();
+        let res = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownRowAccessPolicy(format!(
+                "Unknown RowAccessPolicy {}",
+                name
+            )))
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_row_access_policy(
+        &self,
+        name: &str,
+        seq: MatchSeq,
+    ) -> Result<SeqV<RowAccessPolicy>> {
+        let key = self.make_row_access_policy_key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value = res.ok_or_else(|| {
+            ErrorCode::UnknownRowAccessPolicy(format!("Unknown RowAccessPolicy {}", name))
+        })?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(SeqV::new(
+                seq_value.seq,
+                deserialize_struct(&seq_value.data, ErrorCode::IllegalRowAccessPolicy, || "")?,
+            )),
+            Err(_) => Err(ErrorCode::UnknownRowAccessPolicy(format!(
+                "Unknown RowAccessPolicy {}",
+                name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_row_access_policies(&self) -> Result<Vec<RowAccessPolicy>> {
+        let values = self
+            .kv_api
+            .prefix_list_kv(&self.row_access_policy_prefix)
+            .await?;
+
+        let mut row_access_policies = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let row_access_policy =
+                deserialize_struct(&value.data, ErrorCode::IllegalRowAccessPolicy, || "")?;
+            row_access_policies.push(row_access_policy);
+        }
+        Ok(row_access_policies)
+    }
+}
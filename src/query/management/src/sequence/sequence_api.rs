@@ -0,0 +1,34 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_meta_app::principal::Sequence;
+use common_meta_types::MatchSeq;
+use common_meta_types::SeqV;
+
+#[async_trait::async_trait]
+pub trait SequenceApi: Sync + Send {
+    async fn add_sequence(&self, sequence: Sequence) -> Result<u64>;
+
+    async fn drop_sequence(&self, name: &str, seq: MatchSeq) -> Result<()>;
+
+    async fn get_sequence(&self, name: &str, seq: MatchSeq) -> Result<SeqV<Sequence>>;
+
+    async fn get_sequences(&self) -> Result<Vec<Sequence>>;
+
+    /// Reserve `count` consecutive values of the sequence.
+    ///
+    /// Returns the first reserved value and the increment between two values.
+    async fn allocate_sequence(&self, name: &str, count: u64) -> Result<(u64, u64)>;
+}
@@ -0,0 +1,185 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::escape_for_key;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_api::TXN_MAX_RETRY_TIMES;
+use common_meta_app::app_error::TxnRetryMaxTimes;
+use common_meta_app::principal::Sequence;
+use common_meta_kvapi::kvapi;
+use common_meta_kvapi::kvapi::UpsertKVReq;
+use common_meta_types::MatchSeq;
+use common_meta_types::MatchSeqExt;
+use common_meta_types::MetaError;
+use common_meta_types::Operation;
+use common_meta_types::SeqV;
+
+use crate::sequence::sequence_api::SequenceApi;
+use crate::serde::deserialize_struct;
+use crate::serde::serialize_struct;
+
+static SEQUENCE_API_KEY_PREFIX: &str = "__fd_sequences";
+
+pub struct SequenceMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    sequence_prefix: String,
+}
+
+impl SequenceMgr {
+    pub fn create(
+        kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+        tenant: &str,
+    ) -> Result<Self, ErrorCode> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty (while create sequence)",
+            ));
+        }
+
+        Ok(SequenceMgr {
+            kv_api,
+            sequence_prefix: format!("{}/{}", SEQUENCE_API_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn make_sequence_key(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            self.sequence_prefix,
+            escape_for_key(name)?
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl SequenceApi for SequenceMgr {
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn add_sequence(&self, sequence: Sequence) -> Result<u64> {
+        let match_seq = MatchSeq::Exact(0);
+        let key = self.make_sequence_key(sequence.name.as_str())?;
+        let value = Operation::Update(serialize_struct(
+            &sequence,
+            ErrorCode::IllegalSequence,
+            || "",
+        )?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api.upsert_kv(UpsertKVReq::new(&key, match_seq, value, None));
+
+        let res = upsert_kv.await?.added_or_else(|v| {
+            ErrorCode::SequenceAlreadyExists(format!("Sequence already exists, seq [{}]", v.seq))
+        })?;
+
+        Ok(res.seq)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn drop_sequence(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.make_sequence_key(name)?;
+        let kv_api = self.kv_api.clone();
+        let res = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownSequence(format!(
+                "Unknown Sequence {}",
+                name
+            )))
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_sequence(&self, name: &str, seq: MatchSeq) -> Result<SeqV<Sequence>> {
+        let key = self.make_sequence_key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value =
+            res.ok_or_else(|| ErrorCode::UnknownSequence(format!("Unknown Sequence {}", name)))?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(SeqV::new(
+                seq_value.seq,
+                deserialize_struct(&seq_value.data, ErrorCode::IllegalSequence, || "")?,
+            )),
+            Err(_) => Err(ErrorCode::UnknownSequence(format!(
+                "Unknown Sequence {}",
+                name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_sequences(&self) -> Result<Vec<Sequence>> {
+        let values = self.kv_api.prefix_list_kv(&self.sequence_prefix).await?;
+
+        let mut sequences = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let sequence = deserialize_struct(&value.data, ErrorCode::IllegalSequence, || "")?;
+            sequences.push(sequence);
+        }
+        Ok(sequences)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn allocate_sequence(&self, name: &str, count: u64) -> Result<(u64, u64)> {
+        let key = self.make_sequence_key(name)?;
+
+        let mut retry = 0;
+        while retry < TXN_MAX_RETRY_TIMES {
+            retry += 1;
+
+            let seq_value = self.get_sequence(name, MatchSeq::GE(0)).await?;
+            let mut sequence = seq_value.data;
+            let first = sequence.current;
+            sequence.current = count
+                .checked_mul(sequence.increment)
+                .and_then(|step| first.checked_add(step))
+                .ok_or_else(|| {
+                    ErrorCode::IllegalSequence(format!("Sequence {} is exhausted", name))
+                })?;
+
+            // Only succeed if no other node allocated from the sequence in the meantime.
+            let value = Operation::Update(serialize_struct(
+                &sequence,
+                ErrorCode::IllegalSequence,
+                || "",
+            )?);
+            let res = self
+                .kv_api
+                .upsert_kv(UpsertKVReq::new(
+                    &key,
+                    MatchSeq::Exact(seq_value.seq),
+                    value,
+                    None,
+                ))
+                .await?;
+            if res.is_changed() {
+                return Ok((first, sequence.increment));
+            }
+        }
+
+        Err(ErrorCode::TxnRetryMaxTimes(
+            TxnRetryMaxTimes::new("allocate_sequence", TXN_MAX_RETRY_TIMES).to_string(),
+        ))
+    }
+}
@@ -24,6 +24,7 @@ use common_meta_kvapi::kvapi::KVApi;
 use common_meta_store::MetaStore;
 use common_meta_types::MatchSeq;
 use common_meta_types::NodeInfo;
+use common_meta_types::NodeResources;
 use common_meta_types::SeqV;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
@@ -129,6 +130,8 @@ async fn test_successfully_heartbeat_node() -> Result<()> {
     assert!(value.unwrap().meta.unwrap().expire_at.unwrap() - current_time >= 60);
 
     let current_time = current_seconds_time();
+    let mut node_info = node_info;
+    node_info.resources.running_queries = 3;
     cluster_api.heartbeat(&node_info, MatchSeq::GE(1)).await?;
 
     let value = kv_api
@@ -136,6 +139,9 @@ async fn test_successfully_heartbeat_node() -> Result<()> {
         .await?;
 
     assert!(value.unwrap().meta.unwrap().expire_at.unwrap() - current_time >= 60);
+
+    let nodes = cluster_api.get_nodes().await?;
+    assert_eq!(nodes, vec![node_info]);
     Ok(())
 }
 
@@ -153,6 +159,7 @@ fn create_test_node_info() -> NodeInfo {
         version: 0,
         flight_address: String::from("ip:port"),
         binary_version: "binary_version".to_string(),
+        resources: NodeResources::default(),
     }
 }
 
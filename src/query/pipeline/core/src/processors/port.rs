@@ -18,6 +18,7 @@ use std::sync::Arc;
 
 use common_exception::Result;
 use common_expression::DataBlock;
+use common_profile::ProcessorMetrics;
 
 use crate::processors::UpdateTrigger;
 use crate::unsafe_cell_wrap::UnSafeCellWrap;
@@ -181,7 +182,13 @@ impl InputPort {
             let unset_flags = HAS_DATA | NEED_DATA;
             match self.shared.swap(std::ptr::null_mut(), 0, unset_flags) {
                 address if address.is_null() => None,
-                address => Some((*Box::from_raw(address)).0),
+                address => {
+                    let data = (*Box::from_raw(address)).0;
+                    if let Ok(block) = &data {
+                        ProcessorMetrics::record_input(block.num_rows(), block.memory_size());
+                    }
+                    Some(data)
+                }
             }
         }
     }
@@ -219,6 +226,9 @@ impl OutputPort {
         unsafe {
             UpdateTrigger::update_output(&self.update_trigger);
 
+            if let Ok(block) = &data {
+                ProcessorMetrics::record_output(block.num_rows(), block.memory_size());
+            }
             let data = Box::into_raw(Box::new(SharedData(data)));
             self.shared.swap(data, HAS_DATA, HAS_DATA);
         }
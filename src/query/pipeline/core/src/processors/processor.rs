@@ -18,6 +18,7 @@ use std::sync::Arc;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_profile::ProcessorMetrics;
 use futures::future::BoxFuture;
 use futures::FutureExt;
 use minitrace::prelude::*;
@@ -84,6 +85,7 @@ pub trait Processor: Send {
 pub struct ProcessorPtr {
     id: Arc<UnsafeCell<NodeIndex>>,
     inner: Arc<UnsafeCell<Box<dyn Processor>>>,
+    metrics: Arc<ProcessorMetrics>,
 }
 
 unsafe impl Send for ProcessorPtr {}
@@ -95,9 +97,14 @@ impl ProcessorPtr {
         ProcessorPtr {
             id: Arc::new(UnsafeCell::new(node_index(0))),
             inner: Arc::new(UnsafeCell::new(inner)),
+            metrics: Arc::new(ProcessorMetrics::default()),
         }
     }
 
+    pub fn metrics(&self) -> &Arc<ProcessorMetrics> {
+        &self.metrics
+    }
+
     /// # Safety
     pub unsafe fn as_any(&mut self) -> &mut dyn Any {
         (*self.inner.get()).as_any()
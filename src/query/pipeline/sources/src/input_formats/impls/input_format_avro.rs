@@ -0,0 +1,573 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use apache_avro::types::Value as AvroValue;
+use apache_avro::Reader;
+use apache_avro::Schema as AvroSchema;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::decimal::DecimalScalar;
+use common_expression::types::decimal::DecimalSize;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::types::DecimalDataType;
+use common_expression::types::NumberDataType;
+use common_expression::BlockEntry;
+use common_expression::BlockMetaInfo;
+use common_expression::ColumnBuilder;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::Value;
+use common_formats::FieldJsonAstDecoder;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::StageInfo;
+use common_pipeline_core::Pipeline;
+use common_settings::Settings;
+use common_storage::FileStatus;
+use common_storage::StageFileInfo;
+use log::debug;
+use log::info;
+use opendal::Operator;
+
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::input_split::FileInfo;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+
+const AVRO_MAGIC: &[u8] = b"Obj\x01";
+const AVRO_SYNC_SIZE: usize = 16;
+
+pub struct InputFormatAvro;
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatAvro {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        file_infos: Vec<StageFileInfo>,
+        _stage_info: &StageInfo,
+        _op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        // the data blocks of an Avro file can only be located by walking from its header,
+        // so each file is read as a whole
+        let mut infos = Vec::with_capacity(file_infos.len());
+        for info in file_infos {
+            let size = info.size as usize;
+            let file = Arc::new(FileInfo {
+                path: info.path,
+                size,
+                num_splits: 1,
+                compress_alg: None,
+            });
+            infos.push(Arc::new(SplitInfo {
+                file,
+                seq_in_file: 0,
+                offset: 0,
+                size,
+                num_file_splits: 1,
+                format_info: None,
+            }));
+        }
+        Ok(infos)
+    }
+
+    fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        AvroFormatPipe::execute_copy_with_aligner(ctx, pipeline)
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        AvroFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct AvroFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for AvroFormatPipe {
+    type SplitMeta = ();
+    type ReadBatch = Vec<u8>;
+    type RowBatch = AvroFileInMemory;
+    type AligningState = AvroAligningState;
+    type BlockBuilder = AvroBlockBuilder;
+
+    fn try_create_align_state(
+        _ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<AvroAligningState> {
+        Ok(AvroAligningState {
+            split_info: split_info.clone(),
+            buffers: vec![],
+        })
+    }
+
+    fn try_create_block_builder(ctx: &Arc<InputContext>) -> Result<AvroBlockBuilder> {
+        Ok(AvroBlockBuilder {
+            ctx: ctx.clone(),
+            func_ctx: ctx.table_context.get_function_context()?,
+            field_decoder: FieldJsonAstDecoder::create(&ctx.file_format_options_ext),
+        })
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct AvroFileInMemory {
+    pub path: String,
+    pub num_rows: usize,
+    pub data: Vec<u8>,
+}
+
+impl RowBatchTrait for AvroFileInMemory {
+    fn size(&self) -> usize {
+        self.data.len()
+    }
+
+    fn rows(&self) -> usize {
+        self.num_rows
+    }
+}
+
+#[typetag::serde(name = "row_batch_avro")]
+impl BlockMetaInfo for AvroFileInMemory {
+    fn equals(&self, _info: &Box<dyn BlockMetaInfo>) -> bool {
+        unreachable!("AvroFileInMemory as BlockMetaInfo is not expected to be compared.")
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        unreachable!("AvroFileInMemory as BlockMetaInfo is not expected to be cloned.")
+    }
+}
+
+impl Debug for AvroFileInMemory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AvroFileInMemory")
+    }
+}
+
+impl AvroFileInMemory {
+    fn to_blocks(
+        &self,
+        ctx: &InputContext,
+        func_ctx: &FunctionContext,
+        field_decoder: &FieldJsonAstDecoder,
+    ) -> Result<Vec<DataBlock>> {
+        let reader = Reader::new(&self.data[..]).map_err(|e| self.avro_error(e))?;
+        let fields = self.get_used_fields(ctx, reader.writer_schema())?;
+        let max_rows = ctx.block_compact_thresholds.max_rows_per_block.max(1);
+
+        let mut blocks = vec![];
+        let mut builders = Self::create_builders(&fields, max_rows);
+        let mut num_rows = 0;
+        for value in reader {
+            let mut values = match value.map_err(|e| self.avro_error(e))? {
+                AvroValue::Record(fields) => fields.into_iter().map(|(_, v)| v).collect::<Vec<_>>(),
+                _ => {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "expect records in Avro file {}",
+                        self.path
+                    )));
+                }
+            };
+            for (field, builder) in fields.iter().zip(builders.iter_mut()) {
+                if let (Some((index, data_type)), Some(builder)) = (field, builder) {
+                    let value = std::mem::replace(&mut values[*index], AvroValue::Null);
+                    push_value(builder, data_type, value, field_decoder).map_err(|e| {
+                        e.add_message_back(format!(" when reading Avro file {}", self.path))
+                    })?;
+                }
+            }
+            num_rows += 1;
+            if num_rows == max_rows {
+                let builders =
+                    std::mem::replace(&mut builders, Self::create_builders(&fields, max_rows));
+                blocks.push(self.build_block(ctx, func_ctx, builders, num_rows)?);
+                num_rows = 0;
+            }
+        }
+        if num_rows > 0 {
+            blocks.push(self.build_block(ctx, func_ctx, builders, num_rows)?);
+        }
+        Ok(blocks)
+    }
+
+    // the field index and type in the Avro records of each field of the schema,
+    // None if not in the projection or not in the file.
+    fn get_used_fields(
+        &self,
+        ctx: &InputContext,
+        avro_schema: &AvroSchema,
+    ) -> Result<Vec<Option<(usize, DataType)>>> {
+        let record = match avro_schema {
+            AvroSchema::Record(record) => record,
+            _ => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "the schema of Avro file {} is not a record",
+                    self.path
+                )));
+            }
+        };
+        let mut fields = Vec::with_capacity(ctx.schema.num_fields());
+        for (i, f) in ctx.schema.fields().iter().enumerate() {
+            if let Some(projection) = &ctx.projection {
+                if !projection.contains(&i) {
+                    fields.push(None);
+                    continue;
+                }
+            }
+            match record
+                .fields
+                .iter()
+                .rposition(|field| field.name.eq_ignore_ascii_case(f.name()))
+            {
+                Some(index) => {
+                    let data_type = avro_data_type(&record.fields[index].schema)?;
+                    fields.push(Some((index, data_type)));
+                }
+                // the fields missing in the file are filled with the default values
+                None => fields.push(None),
+            }
+        }
+        Ok(fields)
+    }
+
+    fn create_builders(
+        fields: &[Option<(usize, DataType)>],
+        capacity: usize,
+    ) -> Vec<Option<ColumnBuilder>> {
+        fields
+            .iter()
+            .map(|field| {
+                field
+                    .as_ref()
+                    .map(|(_, data_type)| ColumnBuilder::with_capacity(data_type, capacity))
+            })
+            .collect()
+    }
+
+    fn build_block(
+        &self,
+        ctx: &InputContext,
+        func_ctx: &FunctionContext,
+        builders: Vec<Option<ColumnBuilder>>,
+        num_rows: usize,
+    ) -> Result<DataBlock> {
+        let mut entries = Vec::with_capacity(builders.len());
+        for (i, (field, builder)) in ctx.schema.fields().iter().zip(builders).enumerate() {
+            let data_type: DataType = field.data_type().into();
+            match builder {
+                Some(builder) => {
+                    let column = builder.build();
+                    entries.push(BlockEntry::new(column.data_type(), Value::Column(column)));
+                }
+                None => {
+                    let value = match &ctx.default_values {
+                        Some(values) => values[i].clone(),
+                        None => Scalar::default_value(&data_type),
+                    };
+                    entries.push(BlockEntry::new(data_type, Value::Scalar(value)));
+                }
+            }
+        }
+        let block = DataBlock::new(entries, num_rows);
+
+        // cast the columns to the types of the schema
+        let evaluator = Evaluator::new(&block, func_ctx, &BUILTIN_FUNCTIONS);
+        let mut entries = Vec::with_capacity(block.num_columns());
+        for (i, field) in ctx.schema.fields().iter().enumerate() {
+            let data_type: DataType = field.data_type().into();
+            let entry = block.get_by_offset(i);
+            if entry.data_type == data_type {
+                entries.push(entry.clone());
+                continue;
+            }
+            let expr = Expr::Cast {
+                span: None,
+                is_try: false,
+                expr: Box::new(Expr::ColumnRef {
+                    span: None,
+                    id: i,
+                    data_type: entry.data_type.clone(),
+                    display_name: field.name().clone(),
+                }),
+                dest_type: data_type.clone(),
+            };
+            let value = evaluator.run(&expr).map_err(|e| {
+                e.add_message_back(format!(
+                    " when casting column {} of Avro file {}",
+                    field.name(),
+                    self.path
+                ))
+            })?;
+            entries.push(BlockEntry::new(data_type, value));
+        }
+        Ok(DataBlock::new(entries, num_rows))
+    }
+
+    fn avro_error(&self, e: apache_avro::Error) -> ErrorCode {
+        ErrorCode::BadBytes(format!("invalid Avro file {}: {}", self.path, e))
+    }
+}
+
+/// The type of the values decoded from an Avro schema, logical types are mapped to the
+/// corresponding types and complex types are read as variant.
+fn avro_data_type(schema: &AvroSchema) -> Result<DataType> {
+    let data_type = match schema {
+        AvroSchema::Null => DataType::Null,
+        AvroSchema::Boolean => DataType::Boolean,
+        AvroSchema::Int | AvroSchema::TimeMillis => DataType::Number(NumberDataType::Int32),
+        AvroSchema::Long | AvroSchema::TimeMicros => DataType::Number(NumberDataType::Int64),
+        AvroSchema::Float => DataType::Number(NumberDataType::Float32),
+        AvroSchema::Double => DataType::Number(NumberDataType::Float64),
+        AvroSchema::Bytes
+        | AvroSchema::String
+        | AvroSchema::Enum(_)
+        | AvroSchema::Fixed(_)
+        | AvroSchema::Uuid => DataType::String,
+        AvroSchema::Date => DataType::Date,
+        AvroSchema::TimestampMillis
+        | AvroSchema::TimestampMicros
+        | AvroSchema::LocalTimestampMillis
+        | AvroSchema::LocalTimestampMicros => DataType::Timestamp,
+        AvroSchema::Decimal(decimal) => {
+            if decimal.precision > 38 || decimal.scale > decimal.precision {
+                return Err(ErrorCode::BadBytes(format!(
+                    "unsupported Avro decimal({}, {})",
+                    decimal.precision, decimal.scale
+                )));
+            }
+            DataType::Decimal(DecimalDataType::Decimal128(DecimalSize {
+                precision: decimal.precision as u8,
+                scale: decimal.scale as u8,
+            }))
+        }
+        // the optional fields are written as the union of null and the type
+        AvroSchema::Union(union) => {
+            let variants = union.variants();
+            let types = variants
+                .iter()
+                .filter(|v| !matches!(v, AvroSchema::Null))
+                .collect::<Vec<_>>();
+            match types.as_slice() {
+                [ty] if variants.len() == 2 => avro_data_type(ty)?.wrap_nullable(),
+                _ => DataType::Nullable(Box::new(DataType::Variant)),
+            }
+        }
+        _ => DataType::Variant,
+    };
+    Ok(data_type)
+}
+
+fn push_value(
+    builder: &mut ColumnBuilder,
+    data_type: &DataType,
+    value: AvroValue,
+    field_decoder: &FieldJsonAstDecoder,
+) -> Result<()> {
+    if data_type.remove_nullable() == DataType::Variant {
+        let json = serde_json::Value::try_from(value)
+            .map_err(|e| ErrorCode::BadBytes(format!("invalid Avro value: {}", e)))?;
+        return field_decoder.read_field(builder, &json);
+    }
+    let scalar = to_scalar(value, &data_type.remove_nullable())?;
+    builder.push(scalar.as_ref());
+    Ok(())
+}
+
+fn to_scalar(value: AvroValue, data_type: &DataType) -> Result<Scalar> {
+    let scalar = match value {
+        AvroValue::Null => Scalar::Null,
+        AvroValue::Union(_, value) => to_scalar(*value, data_type)?,
+        AvroValue::Boolean(v) => Scalar::Boolean(v),
+        AvroValue::Int(v) | AvroValue::TimeMillis(v) => Scalar::Number(NumberScalar::Int32(v)),
+        AvroValue::Long(v) | AvroValue::TimeMicros(v) => Scalar::Number(NumberScalar::Int64(v)),
+        AvroValue::Float(v) => Scalar::Number(NumberScalar::Float32(v.into())),
+        AvroValue::Double(v) => Scalar::Number(NumberScalar::Float64(v.into())),
+        AvroValue::String(v) | AvroValue::Enum(_, v) => Scalar::String(v.into_bytes()),
+        AvroValue::Bytes(v) | AvroValue::Fixed(_, v) => Scalar::String(v),
+        AvroValue::Uuid(v) => Scalar::String(v.to_string().into_bytes()),
+        AvroValue::Date(v) => Scalar::Date(v),
+        AvroValue::TimestampMillis(v) | AvroValue::LocalTimestampMillis(v) => {
+            Scalar::Timestamp(v * 1000)
+        }
+        AvroValue::TimestampMicros(v) | AvroValue::LocalTimestampMicros(v) => Scalar::Timestamp(v),
+        AvroValue::Decimal(v) => {
+            let size = match data_type {
+                DataType::Decimal(decimal) => decimal.size(),
+                _ => unreachable!("Avro decimal values are only decoded as decimal type"),
+            };
+            let bytes = Vec::<u8>::try_from(&v)
+                .map_err(|e| ErrorCode::BadBytes(format!("invalid Avro decimal: {}", e)))?;
+            Scalar::Decimal(DecimalScalar::Decimal128(
+                decimal_from_be_bytes(&bytes)?,
+                size,
+            ))
+        }
+        other => {
+            return Err(ErrorCode::BadBytes(format!(
+                "unsupported Avro value {:?}",
+                other
+            )));
+        }
+    };
+    Ok(scalar)
+}
+
+// Avro decimals are the two's-complement big-endian representation of the unscaled value.
+fn decimal_from_be_bytes(bytes: &[u8]) -> Result<i128> {
+    if bytes.len() > 16 {
+        return Err(ErrorCode::BadBytes(format!(
+            "Avro decimal of {} bytes overflows decimal128",
+            bytes.len()
+        )));
+    }
+    let fill = match bytes.first() {
+        Some(b) if b & 0x80 != 0 => 0xff,
+        _ => 0,
+    };
+    let mut buf = [fill; 16];
+    buf[16 - bytes.len()..].copy_from_slice(bytes);
+    Ok(i128::from_be_bytes(buf))
+}
+
+/// Count the rows of an Avro object container file by walking the headers of its data blocks.
+fn count_rows(data: &[u8]) -> Result<usize> {
+    if !data.starts_with(AVRO_MAGIC) {
+        return Err(ErrorCode::BadBytes("magic bytes of Avro file not found"));
+    }
+    let mut pos = AVRO_MAGIC.len();
+    // the file metadata is a map of bytes, which is encoded as blocks of key value pairs
+    loop {
+        let mut count = read_long(data, &mut pos)?;
+        if count == 0 {
+            break;
+        }
+        if count < 0 {
+            // a negative count is followed by the size of the block
+            count = -count;
+            read_long(data, &mut pos)?;
+        }
+        for _ in 0..count * 2 {
+            let len = read_long(data, &mut pos)?;
+            skip_bytes(data, &mut pos, len)?;
+        }
+    }
+    skip_bytes(data, &mut pos, AVRO_SYNC_SIZE as i64)?;
+
+    let mut num_rows = 0;
+    while pos < data.len() {
+        let count = read_long(data, &mut pos)?;
+        let size = read_long(data, &mut pos)?;
+        skip_bytes(data, &mut pos, size)?;
+        skip_bytes(data, &mut pos, AVRO_SYNC_SIZE as i64)?;
+        num_rows += count.max(0) as usize;
+    }
+    Ok(num_rows)
+}
+
+// read a zigzag encoded variable-length long.
+fn read_long(data: &[u8], pos: &mut usize) -> Result<i64> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        if shift >= 64 {
+            return Err(ErrorCode::BadBytes("invalid long value in Avro file"));
+        }
+        let byte = *data
+            .get(*pos)
+            .ok_or_else(|| ErrorCode::BadBytes("unexpected end of Avro file"))?;
+        *pos += 1;
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+    }
+    Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+}
+
+fn skip_bytes(data: &[u8], pos: &mut usize, len: i64) -> Result<()> {
+    if len < 0 || *pos + len as usize > data.len() {
+        return Err(ErrorCode::BadBytes("unexpected end of Avro file"));
+    }
+    *pos += len as usize;
+    Ok(())
+}
+
+pub struct AvroBlockBuilder {
+    ctx: Arc<InputContext>,
+    func_ctx: FunctionContext,
+    field_decoder: FieldJsonAstDecoder,
+}
+
+impl BlockBuilderTrait for AvroBlockBuilder {
+    type Pipe = AvroFormatPipe;
+
+    fn deserialize(&mut self, batch: Option<AvroFileInMemory>) -> Result<Vec<DataBlock>> {
+        match batch {
+            Some(file) => {
+                let blocks = file.to_blocks(&self.ctx, &self.func_ctx, &self.field_decoder)?;
+                self.ctx
+                    .table_context
+                    .add_file_status(&file.path, FileStatus {
+                        num_rows_loaded: blocks.iter().map(|b| b.num_rows()).sum(),
+                        error: None,
+                    })?;
+                Ok(blocks)
+            }
+            None => Ok(vec![]),
+        }
+    }
+}
+
+pub struct AvroAligningState {
+    split_info: Arc<SplitInfo>,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl AligningStateTrait for AvroAligningState {
+    type Pipe = AvroFormatPipe;
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<AvroFileInMemory>> {
+        if let Some(data) = read_batch {
+            self.buffers.push(data);
+            return Ok(vec![]);
+        }
+
+        let data = self.buffers.concat();
+        self.buffers.clear();
+        let path = &self.split_info.file.path;
+        debug!("aligning avro file {} of {} bytes", path, data.len());
+        if data.is_empty() {
+            return Ok(vec![]);
+        }
+        let num_rows =
+            count_rows(&data).map_err(|e| e.add_message_back(format!(" (file: {})", path)))?;
+        info!("align avro file {} of {} rows", path, num_rows);
+        Ok(vec![AvroFileInMemory {
+            path: path.clone(),
+            num_rows,
+            data,
+        }])
+    }
+}
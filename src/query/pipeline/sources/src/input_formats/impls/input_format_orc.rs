@@ -0,0 +1,377 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::decimal::Decimal128Type;
+use common_expression::types::BooleanType;
+use common_expression::types::DataType;
+use common_expression::types::DateType;
+use common_expression::types::DecimalSize;
+use common_expression::types::Float32Type;
+use common_expression::types::Float64Type;
+use common_expression::types::Int64Type;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::BlockEntry;
+use common_expression::BlockMetaInfo;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FromData;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::Value;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::StageInfo;
+use common_pipeline_core::Pipeline;
+use common_settings::Settings;
+use common_storage::FileStatus;
+use common_storage::StageFileInfo;
+use log::debug;
+use log::info;
+use opendal::Operator;
+
+use super::orc::ColumnValues;
+use super::orc::CompressionKind;
+use super::orc::DecodedColumn;
+use super::orc::OrcFileMeta;
+use super::orc::OrcType;
+use super::orc::StripeInformation;
+use super::orc::StripeReader;
+use super::orc::TypeKind;
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::input_split::FileInfo;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+
+pub struct InputFormatOrc;
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatOrc {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        file_infos: Vec<StageFileInfo>,
+        _stage_info: &StageInfo,
+        _op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        // the meta of an ORC file is at its tail, so each file is read as a whole
+        let mut infos = Vec::with_capacity(file_infos.len());
+        for info in file_infos {
+            let size = info.size as usize;
+            let file = Arc::new(FileInfo {
+                path: info.path,
+                size,
+                num_splits: 1,
+                compress_alg: None,
+            });
+            infos.push(Arc::new(SplitInfo {
+                file,
+                seq_in_file: 0,
+                offset: 0,
+                size,
+                num_file_splits: 1,
+                format_info: None,
+            }));
+        }
+        Ok(infos)
+    }
+
+    fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        OrcFormatPipe::execute_copy_with_aligner(ctx, pipeline)
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        OrcFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct OrcFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for OrcFormatPipe {
+    type SplitMeta = ();
+    type ReadBatch = Vec<u8>;
+    type RowBatch = OrcStripeInMemory;
+    type AligningState = OrcAligningState;
+    type BlockBuilder = OrcBlockBuilder;
+
+    fn try_create_align_state(
+        ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<OrcAligningState> {
+        Ok(OrcAligningState {
+            ctx: ctx.clone(),
+            split_info: split_info.clone(),
+            buffers: vec![],
+        })
+    }
+
+    fn try_create_block_builder(ctx: &Arc<InputContext>) -> Result<OrcBlockBuilder> {
+        Ok(OrcBlockBuilder {
+            ctx: ctx.clone(),
+            func_ctx: ctx.table_context.get_function_context()?,
+        })
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct OrcStripeInMemory {
+    pub path: String,
+    pub compression: CompressionKind,
+    pub stripe: StripeInformation,
+    // the ORC column id and type of each field of the schema, None if not in the projection.
+    pub fields: Vec<Option<(u32, OrcType)>>,
+    pub data: Vec<u8>,
+}
+
+impl RowBatchTrait for OrcStripeInMemory {
+    fn size(&self) -> usize {
+        self.data.len()
+    }
+
+    fn rows(&self) -> usize {
+        self.stripe.number_of_rows as usize
+    }
+}
+
+#[typetag::serde(name = "row_batch_orc")]
+impl BlockMetaInfo for OrcStripeInMemory {
+    fn equals(&self, _info: &Box<dyn BlockMetaInfo>) -> bool {
+        unreachable!("OrcStripeInMemory as BlockMetaInfo is not expected to be compared.")
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        unreachable!("OrcStripeInMemory as BlockMetaInfo is not expected to be cloned.")
+    }
+}
+
+impl Debug for OrcStripeInMemory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "OrcStripeInMemory")
+    }
+}
+
+impl OrcStripeInMemory {
+    fn to_block(&self, ctx: &InputContext, func_ctx: &FunctionContext) -> Result<DataBlock> {
+        let reader = StripeReader::try_create(self.compression, &self.stripe, &self.data)?;
+        let num_rows = reader.num_rows();
+
+        let mut entries = Vec::with_capacity(self.fields.len());
+        for (field, orc_field) in ctx.schema.fields().iter().zip(self.fields.iter()) {
+            let data_type: DataType = field.data_type().into();
+            match orc_field {
+                Some((column, ty)) => {
+                    let column = to_column(reader.read_column(*column, ty)?, ty)?;
+                    entries.push(BlockEntry::new(column.data_type(), Value::Column(column)));
+                }
+                None => entries.push(BlockEntry::new(
+                    data_type.clone(),
+                    Value::Scalar(Scalar::default_value(&data_type)),
+                )),
+            }
+        }
+        let block = DataBlock::new(entries, num_rows);
+
+        // cast the columns to the types of the schema
+        let evaluator = Evaluator::new(&block, func_ctx, &BUILTIN_FUNCTIONS);
+        let mut entries = Vec::with_capacity(block.num_columns());
+        for (i, field) in ctx.schema.fields().iter().enumerate() {
+            let data_type: DataType = field.data_type().into();
+            let entry = block.get_by_offset(i);
+            if entry.data_type == data_type {
+                entries.push(entry.clone());
+                continue;
+            }
+            let expr = Expr::Cast {
+                span: None,
+                is_try: false,
+                expr: Box::new(Expr::ColumnRef {
+                    span: None,
+                    id: i,
+                    data_type: entry.data_type.clone(),
+                    display_name: field.name().clone(),
+                }),
+                dest_type: data_type.clone(),
+            };
+            let value = evaluator.run(&expr).map_err(|e| {
+                e.add_message_back(format!(
+                    " when casting column {} of ORC file {}",
+                    field.name(),
+                    self.path
+                ))
+            })?;
+            entries.push(BlockEntry::new(data_type, value));
+        }
+        Ok(DataBlock::new(entries, num_rows))
+    }
+}
+
+fn to_column(decoded: DecodedColumn, ty: &OrcType) -> Result<Column> {
+    let column = match decoded.values {
+        ColumnValues::Boolean(values) => BooleanType::from_data(values),
+        ColumnValues::Int(values) if ty.kind == TypeKind::Date => {
+            DateType::from_data(values.into_iter().map(|v| v as i32).collect::<Vec<_>>())
+        }
+        ColumnValues::Int(values) => Int64Type::from_data(values),
+        ColumnValues::Float(values) => Float32Type::from_data(values),
+        ColumnValues::Double(values) => Float64Type::from_data(values),
+        ColumnValues::Binary(values) => StringType::from_data(values),
+        ColumnValues::Timestamp(values) => TimestampType::from_data(values),
+        ColumnValues::Decimal(values) => {
+            // precision 0 means the type is written without the precision
+            let precision = match ty.precision {
+                0 => 38,
+                p => p,
+            };
+            if precision > 38 || ty.scale > precision {
+                return Err(ErrorCode::BadBytes(format!(
+                    "invalid ORC decimal({}, {})",
+                    precision, ty.scale
+                )));
+            }
+            Decimal128Type::from_data_with_size(values, DecimalSize {
+                precision: precision as u8,
+                scale: ty.scale as u8,
+            })
+        }
+    };
+    Ok(match decoded.validity {
+        Some(validity) => column.wrap_nullable(Some(Bitmap::from(validity))),
+        None => column,
+    })
+}
+
+pub struct OrcBlockBuilder {
+    ctx: Arc<InputContext>,
+    func_ctx: FunctionContext,
+}
+
+impl BlockBuilderTrait for OrcBlockBuilder {
+    type Pipe = OrcFormatPipe;
+
+    fn deserialize(&mut self, batch: Option<OrcStripeInMemory>) -> Result<Vec<DataBlock>> {
+        match batch {
+            Some(stripe) => {
+                let block = stripe.to_block(&self.ctx, &self.func_ctx)?;
+                self.ctx
+                    .table_context
+                    .add_file_status(&stripe.path, FileStatus {
+                        num_rows_loaded: block.num_rows(),
+                        error: None,
+                    })?;
+                Ok(vec![block])
+            }
+            None => Ok(vec![]),
+        }
+    }
+}
+
+pub struct OrcAligningState {
+    ctx: Arc<InputContext>,
+    split_info: Arc<SplitInfo>,
+    buffers: Vec<Vec<u8>>,
+}
+
+impl AligningStateTrait for OrcAligningState {
+    type Pipe = OrcFormatPipe;
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<OrcStripeInMemory>> {
+        if let Some(data) = read_batch {
+            self.buffers.push(data);
+            return Ok(vec![]);
+        }
+
+        let file_in_memory = self.buffers.concat();
+        self.buffers.clear();
+        let path = &self.split_info.file.path;
+        debug!(
+            "aligning orc file {} of {} bytes",
+            path,
+            file_in_memory.len()
+        );
+        let file_meta = OrcFileMeta::try_from_bytes(&file_in_memory)?;
+        let fields = self.get_used_fields(&file_meta)?;
+
+        let mut stripes = Vec::with_capacity(file_meta.stripes.len());
+        for stripe in file_meta.stripes.iter() {
+            let start = stripe.offset as usize;
+            let end = start + stripe.total_length() as usize;
+            if end > file_in_memory.len() {
+                return Err(ErrorCode::BadBytes(format!(
+                    "stripe at offset {} exceeds the size of ORC file {}",
+                    start, path
+                )));
+            }
+            stripes.push(OrcStripeInMemory {
+                path: path.clone(),
+                compression: file_meta.compression,
+                stripe: stripe.clone(),
+                fields: fields.clone(),
+                data: file_in_memory[start..end].to_vec(),
+            });
+        }
+        info!(
+            "align orc file {} of {} rows to {} stripes",
+            path,
+            file_meta.num_rows,
+            stripes.len()
+        );
+        Ok(stripes)
+    }
+}
+
+impl OrcAligningState {
+    fn get_used_fields(&self, file_meta: &OrcFileMeta) -> Result<Vec<Option<(u32, OrcType)>>> {
+        let orc_fields = file_meta.top_level_fields()?;
+        let mut fields = Vec::with_capacity(self.ctx.schema.num_fields());
+        for (i, f) in self.ctx.schema.fields().iter().enumerate() {
+            if let Some(projection) = &self.ctx.projection {
+                if !projection.contains(&i) {
+                    fields.push(None);
+                    continue;
+                }
+            }
+            match orc_fields
+                .iter()
+                .filter(|(name, _)| name.eq_ignore_ascii_case(f.name()))
+                .last()
+            {
+                Some((_, column)) => {
+                    let ty = file_meta.column_type(*column)?;
+                    fields.push(Some((*column, ty.clone())));
+                }
+                None => {
+                    return Err(ErrorCode::TableSchemaMismatch(format!(
+                        "schema field size mismatch, expected to find column: {}",
+                        f.name()
+                    )));
+                }
+            }
+        }
+        Ok(fields)
+    }
+}
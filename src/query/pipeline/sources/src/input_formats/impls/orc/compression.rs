@@ -0,0 +1,113 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Read;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CompressionKind {
+    None,
+    Zlib,
+    Snappy,
+    Lzo,
+    Lz4,
+    Zstd,
+}
+
+impl CompressionKind {
+    pub fn try_from_proto(v: u64) -> Result<Self> {
+        Ok(match v {
+            0 => CompressionKind::None,
+            1 => CompressionKind::Zlib,
+            2 => CompressionKind::Snappy,
+            3 => CompressionKind::Lzo,
+            4 => CompressionKind::Lz4,
+            5 => CompressionKind::Zstd,
+            _ => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "unknown ORC compression kind {v}"
+                )));
+            }
+        })
+    }
+}
+
+/// Decompresses a stream, the footer or the metadata of an ORC file.
+///
+/// A compressed stream is a sequence of chunks, each one starts with a 3 bytes little-endian
+/// header `chunk_length << 1 | is_original`, the chunk is stored as is if `is_original` is set.
+pub fn decompress(kind: CompressionKind, data: &[u8]) -> Result<Vec<u8>> {
+    if kind == CompressionKind::None {
+        return Ok(data.to_vec());
+    }
+
+    let mut out = Vec::with_capacity(data.len() * 2);
+    let mut pos = 0;
+    while pos < data.len() {
+        if pos + 3 > data.len() {
+            return Err(ErrorCode::BadBytes(
+                "truncated ORC compression chunk header",
+            ));
+        }
+        let header =
+            data[pos] as usize | (data[pos + 1] as usize) << 8 | (data[pos + 2] as usize) << 16;
+        pos += 3;
+        let is_original = header & 1 == 1;
+        let len = header >> 1;
+        if pos + len > data.len() {
+            return Err(ErrorCode::BadBytes("truncated ORC compression chunk"));
+        }
+        let chunk = &data[pos..pos + len];
+        pos += len;
+
+        if is_original {
+            out.extend_from_slice(chunk);
+            continue;
+        }
+        match kind {
+            CompressionKind::Zlib => {
+                flate2::read::DeflateDecoder::new(chunk)
+                    .read_to_end(&mut out)
+                    .map_err(|e| {
+                        ErrorCode::BadBytes(format!("fail to decompress ORC zlib chunk: {e}"))
+                    })?;
+            }
+            CompressionKind::Snappy => {
+                let decompressed =
+                    snap::raw::Decoder::new()
+                        .decompress_vec(chunk)
+                        .map_err(|e| {
+                            ErrorCode::BadBytes(format!("fail to decompress ORC snappy chunk: {e}"))
+                        })?;
+                out.extend_from_slice(&decompressed);
+            }
+            CompressionKind::Zstd => {
+                let decompressed = zstd::stream::decode_all(chunk).map_err(|e| {
+                    ErrorCode::BadBytes(format!("fail to decompress ORC zstd chunk: {e}"))
+                })?;
+                out.extend_from_slice(&decompressed);
+            }
+            other => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "ORC compression {other:?} is not supported"
+                )));
+            }
+        }
+    }
+    Ok(out)
+}
@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A minimal reader of the ORC files, which decodes the flat (non nested) columns of the
+//! stripes. See <https://orc.apache.org/specification/ORCv1/>.
+
+mod compression;
+mod protobuf;
+mod rle;
+mod stripe;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+pub use compression::CompressionKind;
+pub use protobuf::OrcType;
+pub use protobuf::StripeInformation;
+pub use protobuf::TypeKind;
+pub use stripe::ColumnValues;
+pub use stripe::DecodedColumn;
+pub use stripe::StripeReader;
+
+use self::compression::decompress;
+use self::protobuf::Footer;
+use self::protobuf::PostScript;
+
+const ORC_MAGIC: &[u8] = b"ORC";
+
+pub struct OrcFileMeta {
+    pub compression: CompressionKind,
+    pub stripes: Vec<StripeInformation>,
+    pub types: Vec<OrcType>,
+    pub num_rows: u64,
+}
+
+impl OrcFileMeta {
+    /// Reads the meta from the tail of the file: `footer | postscript | postscript length`.
+    pub fn try_from_bytes(data: &[u8]) -> Result<Self> {
+        if data.len() <= ORC_MAGIC.len() || !data.starts_with(ORC_MAGIC) {
+            return Err(ErrorCode::BadBytes("not an ORC file"));
+        }
+        let postscript_end = data.len() - 1;
+        let postscript_length = data[postscript_end] as usize;
+        if postscript_length + ORC_MAGIC.len() > postscript_end {
+            return Err(ErrorCode::BadBytes("invalid ORC postscript length"));
+        }
+        let postscript_start = postscript_end - postscript_length;
+        let postscript = PostScript::decode(&data[postscript_start..postscript_end])?;
+
+        let footer_length = postscript.footer_length as usize;
+        if footer_length + ORC_MAGIC.len() > postscript_start {
+            return Err(ErrorCode::BadBytes("invalid ORC footer length"));
+        }
+        let footer_start = postscript_start - footer_length;
+        let footer = Footer::decode(&decompress(
+            postscript.compression,
+            &data[footer_start..postscript_start],
+        )?)?;
+
+        Ok(Self {
+            compression: postscript.compression,
+            stripes: footer.stripes,
+            types: footer.types,
+            num_rows: footer.number_of_rows,
+        })
+    }
+
+    /// The names and the column ids of the top level fields.
+    pub fn top_level_fields(&self) -> Result<Vec<(String, u32)>> {
+        match self.types.first() {
+            Some(root) if root.kind == TypeKind::Struct => Ok(root
+                .field_names
+                .iter()
+                .cloned()
+                .zip(root.subtypes.iter().copied())
+                .collect()),
+            _ => Err(ErrorCode::BadBytes(
+                "the root type of an ORC file must be a struct",
+            )),
+        }
+    }
+
+    pub fn column_type(&self, column: u32) -> Result<&OrcType> {
+        self.types
+            .get(column as usize)
+            .ok_or_else(|| ErrorCode::BadBytes(format!("missing type of ORC column {column}")))
+    }
+}
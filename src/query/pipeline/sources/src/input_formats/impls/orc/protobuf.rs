@@ -0,0 +1,436 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A minimal protobuf reader for the ORC file tail and stripe footers.
+//!
+//! Only the messages and fields needed to read the data are decoded, unknown fields are skipped.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::compression::CompressionKind;
+
+enum WireValue<'a> {
+    Varint(u64),
+    Fixed64,
+    Bytes(&'a [u8]),
+    Fixed32,
+}
+
+struct ProtoReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ProtoReader<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    fn read_varint(&mut self) -> Result<u64> {
+        read_varint(self.buf, &mut self.pos)
+    }
+
+    fn read_slice(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.pos + len > self.buf.len() {
+            return Err(ErrorCode::BadBytes("truncated ORC metadata"));
+        }
+        let slice = &self.buf[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(slice)
+    }
+
+    fn next_field(&mut self) -> Result<Option<(u64, WireValue<'a>)>> {
+        if self.pos >= self.buf.len() {
+            return Ok(None);
+        }
+        let key = self.read_varint()?;
+        let value = match key & 0x7 {
+            0 => WireValue::Varint(self.read_varint()?),
+            1 => {
+                self.read_slice(8)?;
+                WireValue::Fixed64
+            }
+            2 => {
+                let len = self.read_varint()? as usize;
+                WireValue::Bytes(self.read_slice(len)?)
+            }
+            5 => {
+                self.read_slice(4)?;
+                WireValue::Fixed32
+            }
+            wire_type => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "unsupported protobuf wire type {wire_type} in ORC metadata"
+                )));
+            }
+        };
+        Ok(Some((key >> 3, value)))
+    }
+}
+
+pub fn read_varint(buf: &[u8], pos: &mut usize) -> Result<u64> {
+    let mut result = 0u64;
+    let mut shift = 0;
+    loop {
+        let byte = *buf
+            .get(*pos)
+            .ok_or_else(|| ErrorCode::BadBytes("truncated varint in ORC file"))?;
+        *pos += 1;
+        if shift < 64 {
+            result |= ((byte & 0x7f) as u64) << shift;
+        }
+        if byte & 0x80 == 0 {
+            return Ok(result);
+        }
+        shift += 7;
+    }
+}
+
+fn as_u64(value: &WireValue) -> u64 {
+    match value {
+        WireValue::Varint(v) => *v,
+        _ => 0,
+    }
+}
+
+fn as_string(value: &WireValue) -> String {
+    match value {
+        WireValue::Bytes(b) => String::from_utf8_lossy(b).to_string(),
+        _ => String::new(),
+    }
+}
+
+/// Reads a repeated uint32 field, which may be packed or not.
+fn extend_u32(value: &WireValue, out: &mut Vec<u32>) -> Result<()> {
+    match value {
+        WireValue::Varint(v) => out.push(*v as u32),
+        WireValue::Bytes(b) => {
+            let mut pos = 0;
+            while pos < b.len() {
+                out.push(read_varint(b, &mut pos)? as u32);
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn message<'a>(value: &WireValue<'a>) -> &'a [u8] {
+    match value {
+        WireValue::Bytes(b) => b,
+        _ => &[],
+    }
+}
+
+pub struct PostScript {
+    pub footer_length: u64,
+    pub compression: CompressionKind,
+}
+
+impl PostScript {
+    pub fn decode(buf: &[u8]) -> Result<Self> {
+        let mut ps = PostScript {
+            footer_length: 0,
+            compression: CompressionKind::None,
+        };
+        let mut reader = ProtoReader::new(buf);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => ps.footer_length = as_u64(&value),
+                2 => ps.compression = CompressionKind::try_from_proto(as_u64(&value))?,
+                _ => {}
+            }
+        }
+        Ok(ps)
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct StripeInformation {
+    pub offset: u64,
+    pub index_length: u64,
+    pub data_length: u64,
+    pub footer_length: u64,
+    pub number_of_rows: u64,
+}
+
+impl StripeInformation {
+    fn decode(buf: &[u8]) -> Result<Self> {
+        let mut stripe = StripeInformation::default();
+        let mut reader = ProtoReader::new(buf);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => stripe.offset = as_u64(&value),
+                2 => stripe.index_length = as_u64(&value),
+                3 => stripe.data_length = as_u64(&value),
+                4 => stripe.footer_length = as_u64(&value),
+                5 => stripe.number_of_rows = as_u64(&value),
+                _ => {}
+            }
+        }
+        Ok(stripe)
+    }
+
+    pub fn total_length(&self) -> u64 {
+        self.index_length + self.data_length + self.footer_length
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TypeKind {
+    Boolean,
+    Byte,
+    Short,
+    Int,
+    Long,
+    Float,
+    Double,
+    String,
+    Binary,
+    Timestamp,
+    List,
+    Map,
+    Struct,
+    Union,
+    Decimal,
+    Date,
+    Varchar,
+    Char,
+    TimestampInstant,
+}
+
+impl TypeKind {
+    fn try_from_proto(v: u64) -> Result<Self> {
+        Ok(match v {
+            0 => TypeKind::Boolean,
+            1 => TypeKind::Byte,
+            2 => TypeKind::Short,
+            3 => TypeKind::Int,
+            4 => TypeKind::Long,
+            5 => TypeKind::Float,
+            6 => TypeKind::Double,
+            7 => TypeKind::String,
+            8 => TypeKind::Binary,
+            9 => TypeKind::Timestamp,
+            10 => TypeKind::List,
+            11 => TypeKind::Map,
+            12 => TypeKind::Struct,
+            13 => TypeKind::Union,
+            14 => TypeKind::Decimal,
+            15 => TypeKind::Date,
+            16 => TypeKind::Varchar,
+            17 => TypeKind::Char,
+            18 => TypeKind::TimestampInstant,
+            _ => {
+                return Err(ErrorCode::BadBytes(format!("unknown ORC type kind {v}")));
+            }
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct OrcType {
+    pub kind: TypeKind,
+    pub subtypes: Vec<u32>,
+    pub field_names: Vec<String>,
+    pub precision: u32,
+    pub scale: u32,
+}
+
+impl OrcType {
+    fn decode(buf: &[u8]) -> Result<Self> {
+        let mut ty = OrcType {
+            kind: TypeKind::Struct,
+            subtypes: vec![],
+            field_names: vec![],
+            precision: 0,
+            scale: 0,
+        };
+        let mut reader = ProtoReader::new(buf);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => ty.kind = TypeKind::try_from_proto(as_u64(&value))?,
+                2 => extend_u32(&value, &mut ty.subtypes)?,
+                3 => ty.field_names.push(as_string(&value)),
+                5 => ty.precision = as_u64(&value) as u32,
+                6 => ty.scale = as_u64(&value) as u32,
+                _ => {}
+            }
+        }
+        Ok(ty)
+    }
+}
+
+pub struct Footer {
+    pub stripes: Vec<StripeInformation>,
+    pub types: Vec<OrcType>,
+    pub number_of_rows: u64,
+}
+
+impl Footer {
+    pub fn decode(buf: &[u8]) -> Result<Self> {
+        let mut footer = Footer {
+            stripes: vec![],
+            types: vec![],
+            number_of_rows: 0,
+        };
+        let mut reader = ProtoReader::new(buf);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                3 => footer
+                    .stripes
+                    .push(StripeInformation::decode(message(&value))?),
+                4 => footer.types.push(OrcType::decode(message(&value))?),
+                6 => footer.number_of_rows = as_u64(&value),
+                _ => {}
+            }
+        }
+        Ok(footer)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StreamKind {
+    Present,
+    Data,
+    Length,
+    DictionaryData,
+    Secondary,
+    Other,
+}
+
+impl StreamKind {
+    fn from_proto(v: u64) -> Self {
+        match v {
+            0 => StreamKind::Present,
+            1 => StreamKind::Data,
+            2 => StreamKind::Length,
+            3 => StreamKind::DictionaryData,
+            5 => StreamKind::Secondary,
+            _ => StreamKind::Other,
+        }
+    }
+}
+
+pub struct Stream {
+    pub kind: StreamKind,
+    pub column: u32,
+    pub length: u64,
+}
+
+impl Stream {
+    fn decode(buf: &[u8]) -> Result<Self> {
+        let mut stream = Stream {
+            kind: StreamKind::Present,
+            column: 0,
+            length: 0,
+        };
+        let mut reader = ProtoReader::new(buf);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => stream.kind = StreamKind::from_proto(as_u64(&value)),
+                2 => stream.column = as_u64(&value) as u32,
+                3 => stream.length = as_u64(&value),
+                _ => {}
+            }
+        }
+        Ok(stream)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EncodingKind {
+    Direct,
+    Dictionary,
+    DirectV2,
+    DictionaryV2,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ColumnEncoding {
+    pub kind: EncodingKind,
+    pub dictionary_size: u32,
+}
+
+impl ColumnEncoding {
+    fn decode(buf: &[u8]) -> Result<Self> {
+        let mut encoding = ColumnEncoding {
+            kind: EncodingKind::Direct,
+            dictionary_size: 0,
+        };
+        let mut reader = ProtoReader::new(buf);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => {
+                    encoding.kind = match as_u64(&value) {
+                        0 => EncodingKind::Direct,
+                        1 => EncodingKind::Dictionary,
+                        2 => EncodingKind::DirectV2,
+                        3 => EncodingKind::DictionaryV2,
+                        v => {
+                            return Err(ErrorCode::BadBytes(format!(
+                                "unknown ORC column encoding {v}"
+                            )));
+                        }
+                    }
+                }
+                2 => encoding.dictionary_size = as_u64(&value) as u32,
+                _ => {}
+            }
+        }
+        Ok(encoding)
+    }
+
+    pub fn is_v2(&self) -> bool {
+        matches!(
+            self.kind,
+            EncodingKind::DirectV2 | EncodingKind::DictionaryV2
+        )
+    }
+
+    pub fn is_dictionary(&self) -> bool {
+        matches!(
+            self.kind,
+            EncodingKind::Dictionary | EncodingKind::DictionaryV2
+        )
+    }
+}
+
+pub struct StripeFooter {
+    pub streams: Vec<Stream>,
+    pub columns: Vec<ColumnEncoding>,
+}
+
+impl StripeFooter {
+    pub fn decode(buf: &[u8]) -> Result<Self> {
+        let mut footer = StripeFooter {
+            streams: vec![],
+            columns: vec![],
+        };
+        let mut reader = ProtoReader::new(buf);
+        while let Some((field, value)) = reader.next_field()? {
+            match field {
+                1 => footer.streams.push(Stream::decode(message(&value))?),
+                2 => footer
+                    .columns
+                    .push(ColumnEncoding::decode(message(&value))?),
+                _ => {}
+            }
+        }
+        Ok(footer)
+    }
+}
@@ -0,0 +1,285 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Run length decoders of the ORC streams.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::protobuf::read_varint;
+
+fn truncated() -> ErrorCode {
+    ErrorCode::BadBytes("truncated ORC run length encoded stream")
+}
+
+struct ByteReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn byte(&mut self) -> Result<u8> {
+        let b = *self.data.get(self.pos).ok_or_else(truncated)?;
+        self.pos += 1;
+        Ok(b)
+    }
+
+    fn varint(&mut self, signed: bool) -> Result<i64> {
+        let v = read_varint(self.data, &mut self.pos)?;
+        Ok(if signed { zigzag_decode(v) } else { v as i64 })
+    }
+
+    fn big_endian(&mut self, width: usize) -> Result<u64> {
+        let mut v = 0u64;
+        for _ in 0..width {
+            v = (v << 8) | self.byte()? as u64;
+        }
+        Ok(v)
+    }
+
+    /// Unpacks `count` big-endian bit packed values of `width` bits, the values are byte aligned
+    /// at the end.
+    fn unpack(&mut self, width: usize, count: usize, out: &mut Vec<u64>) -> Result<()> {
+        let mut current = 0u64;
+        let mut bits_left = 0;
+        for _ in 0..count {
+            let mut value = 0u64;
+            let mut need = width;
+            while need > 0 {
+                if bits_left == 0 {
+                    current = self.byte()? as u64;
+                    bits_left = 8;
+                }
+                let take = need.min(bits_left);
+                let part = (current >> (bits_left - take)) & ((1u64 << take) - 1);
+                value = (value << take) | part;
+                bits_left -= take;
+                need -= take;
+            }
+            out.push(value);
+        }
+        Ok(())
+    }
+}
+
+#[inline]
+fn zigzag_decode(v: u64) -> i64 {
+    ((v >> 1) as i64) ^ -((v & 1) as i64)
+}
+
+/// Decodes a byte run length encoded stream.
+pub fn decode_bytes(data: &[u8], num_values: usize) -> Result<Vec<u8>> {
+    let mut reader = ByteReader::new(data);
+    let mut out = Vec::with_capacity(num_values);
+    while out.len() < num_values {
+        let control = reader.byte()?;
+        if control < 0x80 {
+            let value = reader.byte()?;
+            let run = control as usize + 3;
+            out.extend(std::iter::repeat(value).take(run));
+        } else {
+            let literals = 0x100 - control as usize;
+            for _ in 0..literals {
+                out.push(reader.byte()?);
+            }
+        }
+    }
+    out.truncate(num_values);
+    Ok(out)
+}
+
+/// Decodes a boolean stream, which is a byte run length encoded bitmap with the most
+/// significant bit first.
+pub fn decode_booleans(data: &[u8], num_values: usize) -> Result<Vec<bool>> {
+    let bytes = decode_bytes(data, (num_values + 7) / 8)?;
+    let mut out = Vec::with_capacity(num_values);
+    for byte in bytes {
+        for i in 0..8 {
+            out.push(byte & (0x80 >> i) != 0);
+        }
+    }
+    out.truncate(num_values);
+    Ok(out)
+}
+
+/// Decodes an integer run length encoded stream, of version 1 or version 2.
+/// The unsigned values are returned as their bit pattern in `i64`.
+pub fn decode_integers(data: &[u8], num_values: usize, signed: bool, v2: bool) -> Result<Vec<i64>> {
+    let mut reader = ByteReader::new(data);
+    let mut out = Vec::with_capacity(num_values);
+    while out.len() < num_values {
+        if v2 {
+            decode_v2_run(&mut reader, signed, &mut out)?;
+        } else {
+            decode_v1_run(&mut reader, signed, &mut out)?;
+        }
+    }
+    out.truncate(num_values);
+    Ok(out)
+}
+
+fn decode_v1_run(reader: &mut ByteReader, signed: bool, out: &mut Vec<i64>) -> Result<()> {
+    let control = reader.byte()?;
+    if control < 0x80 {
+        let run = control as i64 + 3;
+        let delta = reader.byte()? as i8 as i64;
+        let base = reader.varint(signed)?;
+        for i in 0..run {
+            out.push(base.wrapping_add(i * delta));
+        }
+    } else {
+        let literals = 0x100 - control as usize;
+        for _ in 0..literals {
+            out.push(reader.varint(signed)?);
+        }
+    }
+    Ok(())
+}
+
+fn decode_bit_width(code: u8) -> usize {
+    match code {
+        0..=23 => code as usize + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+fn closest_fixed_bits(width: usize) -> usize {
+    match width {
+        0 => 1,
+        1..=24 => width,
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+fn decode_v2_run(reader: &mut ByteReader, signed: bool, out: &mut Vec<i64>) -> Result<()> {
+    let header = reader.byte()?;
+    let to_value = |v: u64| if signed { zigzag_decode(v) } else { v as i64 };
+    match header >> 6 {
+        // SHORT_REPEAT
+        0 => {
+            let width = ((header >> 3) & 0x07) as usize + 1;
+            let count = (header & 0x07) as usize + 3;
+            let value = to_value(reader.big_endian(width)?);
+            out.extend(std::iter::repeat(value).take(count));
+        }
+        // DIRECT
+        1 => {
+            let width = decode_bit_width((header >> 1) & 0x1f);
+            let len = (((header & 0x01) as usize) << 8 | reader.byte()? as usize) + 1;
+            let mut values = Vec::with_capacity(len);
+            reader.unpack(width, len, &mut values)?;
+            out.extend(values.into_iter().map(to_value));
+        }
+        // PATCHED_BASE
+        2 => {
+            let width = decode_bit_width((header >> 1) & 0x1f);
+            let len = (((header & 0x01) as usize) << 8 | reader.byte()? as usize) + 1;
+            let third = reader.byte()?;
+            let base_width = ((third >> 5) & 0x07) as usize + 1;
+            let patch_width = decode_bit_width(third & 0x1f);
+            let fourth = reader.byte()?;
+            let patch_gap_width = ((fourth >> 5) & 0x07) as usize + 1;
+            let patch_list_len = (fourth & 0x1f) as usize;
+
+            // the most significant bit of the base value is the sign bit
+            let base = reader.big_endian(base_width)?;
+            let sign_mask = 1u64 << (base_width * 8 - 1);
+            let base = if base & sign_mask != 0 {
+                -((base & !sign_mask) as i64)
+            } else {
+                base as i64
+            };
+
+            let mut values = Vec::with_capacity(len);
+            reader.unpack(width, len, &mut values)?;
+            let mut patches = Vec::with_capacity(patch_list_len);
+            reader.unpack(
+                closest_fixed_bits(patch_width + patch_gap_width),
+                patch_list_len,
+                &mut patches,
+            )?;
+
+            let patch_mask = if patch_width >= 64 {
+                u64::MAX
+            } else {
+                (1u64 << patch_width) - 1
+            };
+            let mut pos = 0;
+            for entry in patches {
+                let gap = (entry >> patch_width) as usize;
+                let patch = entry & patch_mask;
+                pos += gap;
+                // a gap of 255 without patch only moves the position forward
+                if gap == 255 && patch == 0 {
+                    continue;
+                }
+                let value = values.get_mut(pos).ok_or_else(|| {
+                    ErrorCode::BadBytes("invalid patch position in ORC patched base run")
+                })?;
+                *value |= patch << width;
+            }
+            out.extend(values.into_iter().map(|v| base.wrapping_add(v as i64)));
+        }
+        // DELTA
+        _ => {
+            let code = (header >> 1) & 0x1f;
+            let width = if code == 0 { 0 } else { decode_bit_width(code) };
+            let len = (((header & 0x01) as usize) << 8 | reader.byte()? as usize) + 1;
+            let base = reader.varint(signed)?;
+            let delta_base = reader.varint(true)?;
+
+            out.push(base);
+            if len > 1 {
+                let mut prev = base.wrapping_add(delta_base);
+                out.push(prev);
+                if width == 0 {
+                    for _ in 2..len {
+                        prev = prev.wrapping_add(delta_base);
+                        out.push(prev);
+                    }
+                } else {
+                    let mut deltas = Vec::with_capacity(len - 2);
+                    reader.unpack(width, len - 2, &mut deltas)?;
+                    for delta in deltas {
+                        prev = if delta_base < 0 {
+                            prev.wrapping_sub(delta as i64)
+                        } else {
+                            prev.wrapping_add(delta as i64)
+                        };
+                        out.push(prev);
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,359 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+use super::compression::decompress;
+use super::compression::CompressionKind;
+use super::protobuf::ColumnEncoding;
+use super::protobuf::OrcType;
+use super::protobuf::StreamKind;
+use super::protobuf::StripeFooter;
+use super::protobuf::StripeInformation;
+use super::protobuf::TypeKind;
+use super::rle::decode_booleans;
+use super::rle::decode_bytes;
+use super::rle::decode_integers;
+
+/// Seconds from the unix epoch to 2015-01-01 00:00:00, the epoch of the ORC timestamps.
+const ORC_EPOCH_SECONDS: i64 = 1_420_070_400;
+
+/// The values of a column, nulls are filled with the default value.
+pub enum ColumnValues {
+    Boolean(Vec<bool>),
+    /// Values of BYTE, SHORT, INT, LONG and DATE (days since the unix epoch).
+    Int(Vec<i64>),
+    Float(Vec<f32>),
+    Double(Vec<f64>),
+    /// Values of STRING, VARCHAR, CHAR and BINARY.
+    Binary(Vec<Vec<u8>>),
+    /// Microseconds since the unix epoch.
+    Timestamp(Vec<i64>),
+    /// Values rescaled to the scale of the column type.
+    Decimal(Vec<i128>),
+}
+
+pub struct DecodedColumn {
+    pub values: ColumnValues,
+    pub validity: Option<Vec<bool>>,
+}
+
+/// Reads the columns of a stripe, `data` holds the whole stripe.
+pub struct StripeReader<'a> {
+    compression: CompressionKind,
+    num_rows: usize,
+    encodings: Vec<ColumnEncoding>,
+    streams: HashMap<(u32, StreamKind), &'a [u8]>,
+}
+
+impl<'a> StripeReader<'a> {
+    pub fn try_create(
+        compression: CompressionKind,
+        stripe: &StripeInformation,
+        data: &'a [u8],
+    ) -> Result<Self> {
+        let footer_start = (stripe.index_length + stripe.data_length) as usize;
+        let footer_end = footer_start + stripe.footer_length as usize;
+        if footer_end > data.len() {
+            return Err(ErrorCode::BadBytes(format!(
+                "ORC stripe of {} bytes is shorter than expected {} bytes",
+                data.len(),
+                footer_end
+            )));
+        }
+        let footer =
+            StripeFooter::decode(&decompress(compression, &data[footer_start..footer_end])?)?;
+
+        // the streams are stored one after another in the order of the stripe footer
+        let mut streams = HashMap::with_capacity(footer.streams.len());
+        let mut offset = 0;
+        for stream in &footer.streams {
+            let end = offset + stream.length as usize;
+            if end > footer_start {
+                return Err(ErrorCode::BadBytes(format!(
+                    "stream of ORC column {} exceeds the stripe data",
+                    stream.column
+                )));
+            }
+            if stream.kind != StreamKind::Other {
+                streams.insert((stream.column, stream.kind), &data[offset..end]);
+            }
+            offset = end;
+        }
+
+        Ok(Self {
+            compression,
+            num_rows: stripe.number_of_rows as usize,
+            encodings: footer.columns,
+            streams,
+        })
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    fn stream(&self, column: u32, kind: StreamKind) -> Result<Vec<u8>> {
+        match self.streams.get(&(column, kind)) {
+            Some(data) => decompress(self.compression, data),
+            None => Ok(vec![]),
+        }
+    }
+
+    pub fn read_column(&self, column: u32, ty: &OrcType) -> Result<DecodedColumn> {
+        let validity = match self.streams.contains_key(&(column, StreamKind::Present)) {
+            true => Some(decode_booleans(
+                &self.stream(column, StreamKind::Present)?,
+                self.num_rows,
+            )?),
+            false => None,
+        };
+        let num_values = match &validity {
+            Some(validity) => validity.iter().filter(|v| **v).count(),
+            None => self.num_rows,
+        };
+        let encoding = *self.encodings.get(column as usize).ok_or_else(|| {
+            ErrorCode::BadBytes(format!("missing encoding of ORC column {column}"))
+        })?;
+        let v2 = encoding.is_v2();
+        let validity_ref = validity.as_deref();
+
+        let values = match ty.kind {
+            TypeKind::Boolean => {
+                let data = self.stream(column, StreamKind::Data)?;
+                ColumnValues::Boolean(expand(decode_booleans(&data, num_values)?, validity_ref))
+            }
+            TypeKind::Byte => {
+                let data = self.stream(column, StreamKind::Data)?;
+                let values = decode_bytes(&data, num_values)?
+                    .into_iter()
+                    .map(|v| v as i8 as i64)
+                    .collect();
+                ColumnValues::Int(expand(values, validity_ref))
+            }
+            TypeKind::Short | TypeKind::Int | TypeKind::Long | TypeKind::Date => {
+                let data = self.stream(column, StreamKind::Data)?;
+                let values = decode_integers(&data, num_values, true, v2)?;
+                ColumnValues::Int(expand(values, validity_ref))
+            }
+            TypeKind::Float => {
+                let data = self.stream(column, StreamKind::Data)?;
+                let values = read_fixed::<4>(&data, num_values)?
+                    .map(f32::from_le_bytes)
+                    .collect();
+                ColumnValues::Float(expand(values, validity_ref))
+            }
+            TypeKind::Double => {
+                let data = self.stream(column, StreamKind::Data)?;
+                let values = read_fixed::<8>(&data, num_values)?
+                    .map(f64::from_le_bytes)
+                    .collect();
+                ColumnValues::Double(expand(values, validity_ref))
+            }
+            TypeKind::String | TypeKind::Varchar | TypeKind::Char | TypeKind::Binary => {
+                let values = self.read_binary(column, encoding, num_values)?;
+                ColumnValues::Binary(expand(values, validity_ref))
+            }
+            TypeKind::Timestamp | TypeKind::TimestampInstant => {
+                let values = self.read_timestamps(column, v2, num_values)?;
+                ColumnValues::Timestamp(expand(values, validity_ref))
+            }
+            TypeKind::Decimal => {
+                let values = self.read_decimals(column, v2, ty.scale, num_values)?;
+                ColumnValues::Decimal(expand(values, validity_ref))
+            }
+            kind => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "ORC type {kind:?} is not supported"
+                )));
+            }
+        };
+        Ok(DecodedColumn { values, validity })
+    }
+
+    fn read_binary(
+        &self,
+        column: u32,
+        encoding: ColumnEncoding,
+        num_values: usize,
+    ) -> Result<Vec<Vec<u8>>> {
+        let v2 = encoding.is_v2();
+        let length_data = self.stream(column, StreamKind::Length)?;
+        if encoding.is_dictionary() {
+            let dictionary_size = encoding.dictionary_size as usize;
+            let lengths = decode_integers(&length_data, dictionary_size, false, v2)?;
+            let dictionary_data = self.stream(column, StreamKind::DictionaryData)?;
+            let dictionary = split_by_lengths(&dictionary_data, &lengths)?;
+            let indexes = decode_integers(
+                &self.stream(column, StreamKind::Data)?,
+                num_values,
+                false,
+                v2,
+            )?;
+            indexes
+                .into_iter()
+                .map(|index| {
+                    dictionary.get(index as usize).cloned().ok_or_else(|| {
+                        ErrorCode::BadBytes(format!(
+                            "dictionary index {index} of ORC column {column} out of range"
+                        ))
+                    })
+                })
+                .collect()
+        } else {
+            let lengths = decode_integers(&length_data, num_values, false, v2)?;
+            split_by_lengths(&self.stream(column, StreamKind::Data)?, &lengths)
+        }
+    }
+
+    fn read_timestamps(&self, column: u32, v2: bool, num_values: usize) -> Result<Vec<i64>> {
+        let seconds = decode_integers(
+            &self.stream(column, StreamKind::Data)?,
+            num_values,
+            true,
+            v2,
+        )?;
+        let nanos = decode_integers(
+            &self.stream(column, StreamKind::Secondary)?,
+            num_values,
+            false,
+            v2,
+        )?;
+        Ok(seconds
+            .into_iter()
+            .zip(nanos)
+            .map(|(seconds, nanos)| {
+                let nanos = decode_nanos(nanos as u64);
+                let mut seconds = seconds + ORC_EPOCH_SECONDS;
+                // the writers truncate the negative seconds toward zero
+                if seconds < 0 && nanos > 999_999 {
+                    seconds -= 1;
+                }
+                seconds * 1_000_000 + nanos / 1_000
+            })
+            .collect())
+    }
+
+    fn read_decimals(
+        &self,
+        column: u32,
+        v2: bool,
+        scale: u32,
+        num_values: usize,
+    ) -> Result<Vec<i128>> {
+        let data = self.stream(column, StreamKind::Data)?;
+        let scales = decode_integers(
+            &self.stream(column, StreamKind::Secondary)?,
+            num_values,
+            true,
+            v2,
+        )?;
+        let mut pos = 0;
+        let mut values = Vec::with_capacity(num_values);
+        for value_scale in scales {
+            let value = read_i128_varint(&data, &mut pos)?;
+            let diff = scale as i64 - value_scale;
+            let value = if diff >= 0 {
+                value.checked_mul(10i128.pow(diff as u32))
+            } else {
+                value.checked_div(10i128.pow((-diff) as u32))
+            }
+            .ok_or_else(|| {
+                ErrorCode::BadBytes(format!("decimal of ORC column {column} overflows"))
+            })?;
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+/// Scatters the non-null values to their rows.
+fn expand<T: Default>(values: Vec<T>, validity: Option<&[bool]>) -> Vec<T> {
+    match validity {
+        None => values,
+        Some(validity) => {
+            let mut values = values.into_iter();
+            validity
+                .iter()
+                .map(|valid| match valid {
+                    true => values.next().unwrap_or_default(),
+                    false => T::default(),
+                })
+                .collect()
+        }
+    }
+}
+
+fn read_fixed<const N: usize>(
+    data: &[u8],
+    num_values: usize,
+) -> Result<impl Iterator<Item = [u8; N]> + '_> {
+    if data.len() < num_values * N {
+        return Err(ErrorCode::BadBytes("truncated ORC floating point stream"));
+    }
+    Ok(data[..num_values * N]
+        .chunks_exact(N)
+        .map(|c| c.try_into().unwrap()))
+}
+
+fn split_by_lengths(data: &[u8], lengths: &[i64]) -> Result<Vec<Vec<u8>>> {
+    let mut offset = 0;
+    let mut values = Vec::with_capacity(lengths.len());
+    for length in lengths {
+        let end = offset + *length as usize;
+        if end > data.len() {
+            return Err(ErrorCode::BadBytes("truncated ORC string stream"));
+        }
+        values.push(data[offset..end].to_vec());
+        offset = end;
+    }
+    Ok(values)
+}
+
+/// The nanoseconds are stored with the trailing zeros removed, the count of the removed
+/// zeros minus one is kept in the lowest 3 bits.
+fn decode_nanos(v: u64) -> i64 {
+    let zeros = v & 0x07;
+    let mut nanos = (v >> 3) as i64;
+    if zeros != 0 {
+        for _ in 0..=zeros {
+            nanos *= 10;
+        }
+    }
+    nanos
+}
+
+/// Reads an unbounded zigzag encoded base 128 varint.
+fn read_i128_varint(data: &[u8], pos: &mut usize) -> Result<i128> {
+    let mut result = 0u128;
+    let mut shift = 0;
+    loop {
+        let byte = *data
+            .get(*pos)
+            .ok_or_else(|| ErrorCode::BadBytes("truncated ORC decimal stream"))?;
+        *pos += 1;
+        if shift >= 128 {
+            return Err(ErrorCode::BadBytes("ORC decimal exceeds 128 bits"));
+        }
+        result |= ((byte & 0x7f) as u128) << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+    }
+    Ok(((result >> 1) as i128) ^ -((result & 1) as i128))
+}
@@ -45,8 +45,10 @@ use common_storage::FileStatus;
 use dashmap::DashMap;
 use opendal::Operator;
 
+use crate::input_formats::impls::InputFormatAvro;
 use crate::input_formats::impls::InputFormatCSV;
 use crate::input_formats::impls::InputFormatNDJson;
+use crate::input_formats::impls::InputFormatOrc;
 use crate::input_formats::impls::InputFormatParquet;
 use crate::input_formats::impls::InputFormatTSV;
 use crate::input_formats::impls::InputFormatXML;
@@ -157,6 +159,8 @@ impl InputContext {
             FileFormatParams::NdJson(_) => Ok(Arc::new(InputFormatNDJson::create())),
             FileFormatParams::Parquet(_) => Ok(Arc::new(InputFormatParquet {})),
             FileFormatParams::Xml(_) => Ok(Arc::new(InputFormatXML::create())),
+            FileFormatParams::Orc(_) => Ok(Arc::new(InputFormatOrc {})),
+            FileFormatParams::Avro(_) => Ok(Arc::new(InputFormatAvro {})),
             format => Err(ErrorCode::Internal(format!(
                 "Unsupported file format: {:?}",
                 format
@@ -434,6 +438,7 @@ impl InputContext {
                 {
                     Err(e.to_error_code(&self.on_error_mode, file_path, line))
                 } else {
+                    file_status.add_error(e, line);
                     Ok(())
                 }
             }
@@ -15,6 +15,8 @@
 use std::sync::Arc;
 use std::time::Instant;
 
+use common_base::runtime::MemStat;
+use common_base::runtime::ThreadTracker;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_pipeline_core::processors::port::InputPort;
@@ -37,6 +39,7 @@ pub struct ProcessorProfileWrapper<T> {
     proc_profs: SharedProcessorProfiles,
 
     prof: ProcessorProfile,
+    mem_stat: Option<Arc<MemStat>>,
 }
 
 impl<T> ProcessorProfileWrapper<T>
@@ -52,6 +55,7 @@ where T: Processor + 'static
             prof_id,
             proc_profs,
             prof: ProcessorProfile::default(),
+            mem_stat: None,
         })
     }
 }
@@ -71,6 +75,7 @@ where T: Processor + 'static
     fn event(&mut self) -> Result<Event> {
         match self.inner.event()? {
             Event::Finished => {
+                self.prof.memory_usage = peak_memory_usage(&self.mem_stat);
                 self.proc_profs
                     .lock()
                     .unwrap()
@@ -82,6 +87,7 @@ where T: Processor + 'static
     }
 
     fn process(&mut self) -> Result<()> {
+        let _guard = ThreadTracker::enter(Some(tracked_mem_stat(&mut self.mem_stat)));
         let instant = Instant::now();
         self.inner.process()?;
         let elapsed = instant.elapsed();
@@ -116,6 +122,7 @@ pub struct TransformProfileWrapper<T> {
     proc_profs: SharedProcessorProfiles,
 
     prof: ProcessorProfile,
+    mem_stat: Option<Arc<MemStat>>,
 }
 
 impl<T> TransformProfileWrapper<T>
@@ -133,6 +140,7 @@ where T: Transform + 'static
             prof_id,
             proc_profs,
             prof: ProcessorProfile::default(),
+            mem_stat: None,
         }))
     }
 }
@@ -146,6 +154,7 @@ where T: Transform + 'static
         let input_rows = data.num_rows();
         let input_bytes = data.memory_size();
 
+        let _guard = ThreadTracker::enter(Some(tracked_mem_stat(&mut self.mem_stat)));
         let instant = Instant::now();
         let res = self.inner.transform(data)?;
         let elapsed = instant.elapsed();
@@ -157,11 +166,13 @@ where T: Transform + 'static
                 input_bytes,
                 output_rows: res.num_rows(),
                 output_bytes: res.memory_size(),
+                memory_usage: 0,
             };
         Ok(res)
     }
 
     fn on_finish(&mut self) -> Result<()> {
+        self.prof.memory_usage = peak_memory_usage(&self.mem_stat);
         self.proc_profs
             .lock()
             .unwrap()
@@ -170,6 +181,21 @@ where T: Transform + 'static
     }
 }
 
+/// The memory stat that tracks the allocations of a profiled processor, it is
+/// created at the first run so that it reports to the memory stat of the query.
+fn tracked_mem_stat(mem_stat: &mut Option<Arc<MemStat>>) -> Arc<MemStat> {
+    mem_stat
+        .get_or_insert_with(|| MemStat::create("ProcessorProfile".to_string()))
+        .clone()
+}
+
+fn peak_memory_usage(mem_stat: &Option<Arc<MemStat>>) -> usize {
+    mem_stat
+        .as_ref()
+        .map(|mem_stat| mem_stat.get_peak_memory_usage().max(0) as usize)
+        .unwrap_or_default()
+}
+
 /// A stub transform for collecting profile information
 /// at some point of the pipeline.
 /// For example, we can profiling the output data of a
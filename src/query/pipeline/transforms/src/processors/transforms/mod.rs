@@ -27,6 +27,7 @@ pub mod transform_sort;
 mod transform_sort_merge;
 mod transform_sort_merge_limit;
 pub mod transform_sort_partial;
+pub mod transform_sort_spill;
 pub use transform::*;
 pub use transform_accumulating::*;
 pub use transform_accumulating_async::*;
@@ -37,3 +38,4 @@ pub use transform_dummy::*;
 pub use transform_sort::*;
 pub use transform_sort_merge::sort_merge;
 pub use transform_sort_partial::*;
+pub use transform_sort_spill::*;
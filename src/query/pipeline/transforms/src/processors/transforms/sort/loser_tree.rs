@@ -0,0 +1,105 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::cmp::Ordering;
+
+/// A loser tree to merge `k` sorted streams, which needs `log(k)` comparisons to find the next
+/// winner after the current winner is advanced, while a binary heap needs `2 * log(k)`.
+///
+/// Each leaf holds the current value of a stream, `None` means the stream is exhausted and it
+/// loses to any value. `tree[0]` is the index of the winner, `tree[1..k]` are the indices of the
+/// losers of the internal nodes.
+pub struct LoserTree<T: Ord> {
+    tree: Vec<usize>,
+    values: Vec<Option<T>>,
+}
+
+impl<T: Ord> LoserTree<T> {
+    pub fn create(values: Vec<Option<T>>) -> Self {
+        let k = values.len();
+        // `k` is a virtual leaf that wins all the others, it's replaced while building the tree.
+        let mut tree = Self {
+            tree: vec![k; k],
+            values,
+        };
+        for leaf in (0..k).rev() {
+            tree.replay(leaf);
+        }
+        tree
+    }
+
+    /// The index of the stream of the winner.
+    #[inline]
+    pub fn winner(&self) -> usize {
+        self.tree[0]
+    }
+
+    /// Returns `None` if all the streams are exhausted.
+    #[inline]
+    pub fn peek(&self) -> Option<&T> {
+        let winner = *self.tree.first()?;
+        self.values[winner].as_ref()
+    }
+
+    /// The caller must call [`LoserTree::adjust`] after the winner is changed.
+    #[inline]
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        let winner = *self.tree.first()?;
+        self.values[winner].as_mut()
+    }
+
+    /// Replace the value of the winner and find the new winner.
+    pub fn update(&mut self, value: Option<T>) {
+        let winner = self.winner();
+        self.values[winner] = value;
+        self.replay(winner);
+    }
+
+    /// Find the new winner after the value of the winner is changed by [`LoserTree::peek_mut`].
+    #[inline]
+    pub fn adjust(&mut self) {
+        self.replay(self.winner());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.peek().is_none()
+    }
+
+    fn replay(&mut self, leaf: usize) {
+        let k = self.values.len();
+        let mut winner = leaf;
+        let mut node = (leaf + k) / 2;
+        while node > 0 {
+            if self.beats(self.tree[node], winner) {
+                std::mem::swap(&mut self.tree[node], &mut winner);
+            }
+            node /= 2;
+        }
+        if k > 0 {
+            self.tree[0] = winner;
+        }
+    }
+
+    fn beats(&self, a: usize, b: usize) -> bool {
+        let k = self.values.len();
+        if a == k || b == k {
+            return a == k;
+        }
+        match (&self.values[a], &self.values[b]) {
+            (Some(x), Some(y)) => x.cmp(y).then(a.cmp(&b)) == Ordering::Less,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => a < b,
+        }
+    }
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_exception::Result;
 use common_expression::DataSchemaRef;
 use common_expression::SortColumnDescription;
@@ -22,9 +24,19 @@ use common_profile::SharedProcessorProfiles;
 use super::transform_multi_sort_merge::try_add_multi_sort_merge;
 use super::transform_sort_merge::try_create_transform_sort_merge;
 use super::transform_sort_merge_limit::try_create_transform_sort_merge_limit;
+use super::transform_sort_spill::try_create_transform_sort_spill;
+use super::transform_sort_spill::SortSpiller;
 use super::TransformSortPartial;
 use crate::processors::profile_wrapper::ProcessorProfileWrapper;
 
+/// The parameters to spill the sorted runs of the sort without limit.
+#[derive(Clone)]
+pub struct SortSpillParams {
+    /// Spill the buffered blocks of a processor if their size exceeds the threshold.
+    pub spilling_bytes_threshold: usize,
+    pub create_spiller: Arc<dyn Fn() -> Result<Box<dyn SortSpiller>> + Send + Sync>,
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn build_full_sort_pipeline(
     pipeline: &mut Pipeline,
@@ -35,6 +47,7 @@ pub fn build_full_sort_pipeline(
     final_block_size: usize,
     prof_info: Option<(u32, SharedProcessorProfiles)>,
     after_exchange: bool,
+    spill_params: Option<SortSpillParams>,
 ) -> Result<()> {
     // Partial sort
     if limit.is_none() || !after_exchange {
@@ -54,15 +67,26 @@ pub fn build_full_sort_pipeline(
         })?;
     }
 
-    build_merge_sort_pipeline(
-        pipeline,
-        input_schema,
-        sort_desc,
-        limit,
-        partial_block_size,
-        final_block_size,
-        prof_info,
-    )
+    match spill_params {
+        Some(spill_params) if limit.is_none() => build_spill_merge_sort_pipeline(
+            pipeline,
+            input_schema,
+            sort_desc,
+            partial_block_size,
+            final_block_size,
+            prof_info,
+            spill_params,
+        ),
+        _ => build_merge_sort_pipeline(
+            pipeline,
+            input_schema,
+            sort_desc,
+            limit,
+            partial_block_size,
+            final_block_size,
+            prof_info,
+        ),
+    }
 }
 
 pub fn build_merge_sort_pipeline(
@@ -115,3 +139,45 @@ pub fn build_merge_sort_pipeline(
 
     Ok(())
 }
+
+fn build_spill_merge_sort_pipeline(
+    pipeline: &mut Pipeline,
+    input_schema: DataSchemaRef,
+    sort_desc: Vec<SortColumnDescription>,
+    partial_block_size: usize,
+    final_block_size: usize,
+    prof_info: Option<(u32, SharedProcessorProfiles)>,
+    spill_params: SortSpillParams,
+) -> Result<()> {
+    // Merge sort, spill the sorted runs if the blocks exceed the threshold.
+    let need_multi_merge = pipeline.output_len() > 1;
+    pipeline.add_transform(|input, output| {
+        let transform = try_create_transform_sort_spill(
+            input,
+            output,
+            input_schema.clone(),
+            partial_block_size,
+            sort_desc.clone(),
+            need_multi_merge,
+            spill_params.spilling_bytes_threshold,
+            (spill_params.create_spiller)()?,
+        )?;
+
+        if let Some((plan_id, prof)) = &prof_info {
+            Ok(ProcessorPtr::create(ProcessorProfileWrapper::create(
+                transform,
+                *plan_id,
+                prof.clone(),
+            )))
+        } else {
+            Ok(ProcessorPtr::create(transform))
+        }
+    })?;
+
+    if need_multi_merge {
+        // Multi-pipelines merge sort
+        try_add_multi_sort_merge(pipeline, input_schema, final_block_size, None, sort_desc)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,475 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::any::Any;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::row::RowConverter as CommonRowConverter;
+use common_expression::types::string::StringColumn;
+use common_expression::types::DataType;
+use common_expression::types::DateType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberType;
+use common_expression::types::StringType;
+use common_expression::types::TimestampType;
+use common_expression::with_number_mapped_type;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::SortColumnDescription;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::Processor;
+
+use super::sort::Cursor;
+use super::sort::LoserTree;
+use super::sort::RowConverter;
+use super::sort::Rows;
+use super::sort::SimpleRowConverter;
+use super::sort::SimpleRows;
+use super::transform_sort_merge::SortMergeCompactor;
+use super::Compactor;
+
+/// Write the sorted runs of [`TransformSortSpill`] to the storage and read them back.
+#[async_trait::async_trait]
+pub trait SortSpiller: Send {
+    /// Spill the block and return the location of it.
+    async fn spill(&mut self, block: DataBlock) -> Result<String>;
+
+    async fn restore(&mut self, location: &str) -> Result<DataBlock>;
+}
+
+/// Merge sort blocks without limit, and spill the sorted runs to the storage if the blocks
+/// can't be held in memory.
+///
+/// The partially sorted blocks are buffered until their size exceeds `spilling_bytes_threshold`,
+/// then they are merged into a sorted run and spilled. After all the input is consumed, the runs
+/// are read back block by block and merged by a [`LoserTree`].
+///
+/// If nothing is spilled, it works the same as [`super::transform_sort_merge`].
+pub struct TransformSortSpill<R, Converter> {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+
+    state: State,
+    compactor: SortMergeCompactor<R, Converter>,
+    spiller: Box<dyn SortSpiller>,
+
+    sort_desc: Vec<SortColumnDescription>,
+    block_size: usize,
+    spilling_bytes_threshold: usize,
+    /// If the next transform is [`super::transform_multi_sort_merge::MultiSortMergeProcessor`],
+    /// the order column is kept in the output blocks.
+    gen_order_col: bool,
+
+    buffer: Vec<DataBlock>,
+    buffer_bytes: usize,
+    output_blocks: VecDeque<DataBlock>,
+
+    /// The locations of the blocks of each run which are not read yet.
+    runs: Vec<VecDeque<String>>,
+    /// The blocks of each run which are being merged, the order column is the last column.
+    blocks: Vec<VecDeque<DataBlock>>,
+    /// The runs whose current block is finished and need to read the next one.
+    pending_runs: Vec<usize>,
+    tree: Option<LoserTree<Cursor<R>>>,
+    /// The accumulated rows for the next output data block.
+    ///
+    /// Data format: (run_index, block_index, row_index)
+    in_progress_rows: Vec<(usize, usize, usize)>,
+}
+
+enum State {
+    /// Collect the partially sorted blocks.
+    Collect,
+    /// Merge the buffered blocks into a sorted run.
+    SortRun,
+    /// Write the sorted run to the storage.
+    SpillRun(Vec<DataBlock>),
+    /// Nothing is spilled, merge the buffered blocks as the output.
+    MergeInMemory,
+    /// Read the next blocks of the pending runs.
+    Restore,
+    /// Merge the runs.
+    MergeRuns,
+    Finished,
+}
+
+impl<R, Converter> TransformSortSpill<R, Converter>
+where
+    R: Rows + Send + 'static,
+    Converter: RowConverter<R> + Send + 'static,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        schema: DataSchemaRef,
+        block_size: usize,
+        sort_desc: Vec<SortColumnDescription>,
+        gen_order_col: bool,
+        spilling_bytes_threshold: usize,
+        spiller: Box<dyn SortSpiller>,
+    ) -> Result<Box<dyn Processor>> {
+        // The order column is always generated, because it's needed to merge the spilled runs.
+        let compactor =
+            SortMergeCompactor::try_create(schema, block_size, sort_desc.clone(), true)?;
+        Ok(Box::new(Self {
+            input,
+            output,
+            state: State::Collect,
+            compactor,
+            spiller,
+            sort_desc,
+            block_size,
+            spilling_bytes_threshold,
+            gen_order_col,
+            buffer: vec![],
+            buffer_bytes: 0,
+            output_blocks: VecDeque::new(),
+            runs: vec![],
+            blocks: vec![],
+            pending_runs: vec![],
+            tree: None,
+            in_progress_rows: vec![],
+        }))
+    }
+
+    fn sort_buffer(&mut self) -> Result<Vec<DataBlock>> {
+        self.buffer_bytes = 0;
+        let blocks = std::mem::take(&mut self.buffer);
+        self.compactor.compact_final(blocks)
+    }
+
+    fn strip_order_col(&self, block: DataBlock) -> Result<DataBlock> {
+        if self.gen_order_col {
+            Ok(block)
+        } else {
+            block.pop_columns(1)
+        }
+    }
+
+    fn create_cursor(&self, run: usize, block: &DataBlock) -> Result<Cursor<R>> {
+        let order_col = block
+            .columns()
+            .last()
+            .unwrap()
+            .value
+            .as_column()
+            .unwrap()
+            .clone();
+        let rows = R::from_column(order_col, &self.sort_desc)
+            .ok_or_else(|| ErrorCode::BadDataValueType("Order column type mismatched."))?;
+        Ok(Cursor::new(run, rows))
+    }
+
+    /// Merge the runs until an output block is generated or a run needs to read the next block.
+    fn merge_runs(&mut self) -> Result<()> {
+        let tree = self.tree.as_mut().unwrap();
+        while let Some(cursor) = tree.peek_mut() {
+            let run = cursor.input_index;
+            let block_index = self.blocks[run].len() - 1;
+            self.in_progress_rows
+                .push((run, block_index, cursor.advance()));
+
+            if !cursor.is_finished() {
+                tree.adjust();
+            } else if self.runs[run].is_empty() {
+                tree.update(None);
+            } else {
+                // The cursor of the run will be replaced after the next block is read.
+                self.pending_runs.push(run);
+                self.state = State::Restore;
+                break;
+            }
+
+            if self.in_progress_rows.len() == self.block_size {
+                break;
+            }
+        }
+
+        let exhausted = tree.is_empty();
+        if self.in_progress_rows.len() == self.block_size
+            || (exhausted && !self.in_progress_rows.is_empty())
+        {
+            let block = self.build_block()?;
+            self.output_blocks.push_back(block);
+        }
+        if exhausted {
+            self.state = State::Finished;
+        }
+        Ok(())
+    }
+
+    fn build_block(&mut self) -> Result<DataBlock> {
+        let mut blocks_num_pre_sum = Vec::with_capacity(self.blocks.len());
+        let mut len = 0;
+        for blocks in self.blocks.iter() {
+            blocks_num_pre_sum.push(len);
+            len += blocks.len();
+        }
+
+        // Compute the slices of the output block.
+        let mut indices: Vec<(usize, usize, usize)> = Vec::new();
+        for (run, block_index, row_index) in self.in_progress_rows.iter() {
+            let index = blocks_num_pre_sum[*run] + block_index;
+            match indices.last_mut() {
+                Some((last_index, start, len))
+                    if *last_index == index && *start + *len == *row_index =>
+                {
+                    *len += 1
+                }
+                _ => indices.push((index, *row_index, 1)),
+            }
+        }
+
+        let candidate_blocks = self.blocks.iter().flatten().cloned().collect::<Vec<_>>();
+        let output = DataBlock::take_by_slices_limit_from_blocks(&candidate_blocks, &indices, None);
+
+        self.in_progress_rows.clear();
+        // A run reads the next block only if the previous one is finished,
+        // so all the blocks except the last one of each run are drained.
+        for blocks in self.blocks.iter_mut() {
+            if blocks.len() > 1 {
+                blocks.drain(0..(blocks.len() - 1));
+            }
+        }
+
+        self.strip_order_col(output)
+    }
+}
+
+#[async_trait::async_trait]
+impl<R, Converter> Processor for TransformSortSpill<R, Converter>
+where
+    R: Rows + Send + 'static,
+    Converter: RowConverter<R> + Send + 'static,
+{
+    fn name(&self) -> String {
+        String::from("TransformSortSpill")
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(block) = self.output_blocks.pop_front() {
+            self.output.push_data(Ok(block));
+            return Ok(Event::NeedConsume);
+        }
+
+        match &self.state {
+            State::Collect => {
+                if self.input.has_data() {
+                    let block = self.input.pull_data().unwrap()?;
+                    if !block.is_empty() {
+                        self.buffer_bytes += block.memory_size();
+                        self.buffer.push(block);
+                    }
+                    if self.buffer_bytes >= self.spilling_bytes_threshold {
+                        self.state = State::SortRun;
+                        return Ok(Event::Sync);
+                    }
+                }
+
+                if self.input.is_finished() {
+                    if !self.buffer.is_empty() {
+                        self.state = match self.runs.is_empty() {
+                            true => State::MergeInMemory,
+                            false => State::SortRun,
+                        };
+                        return Ok(Event::Sync);
+                    }
+                    if self.runs.is_empty() {
+                        self.output.finish();
+                        return Ok(Event::Finished);
+                    }
+                    // All the runs are spilled, start to merge them.
+                    self.blocks = vec![VecDeque::new(); self.runs.len()];
+                    self.pending_runs = (0..self.runs.len()).collect();
+                    self.state = State::Restore;
+                    return Ok(Event::Async);
+                }
+
+                self.input.set_need_data();
+                Ok(Event::NeedData)
+            }
+            State::SortRun | State::MergeInMemory | State::MergeRuns => Ok(Event::Sync),
+            State::SpillRun(_) | State::Restore => Ok(Event::Async),
+            State::Finished => {
+                self.output.finish();
+                Ok(Event::Finished)
+            }
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Collect) {
+            State::SortRun => {
+                let run = self.sort_buffer()?;
+                self.state = State::SpillRun(run);
+            }
+            State::MergeInMemory => {
+                for block in self.sort_buffer()? {
+                    let block = self.strip_order_col(block)?;
+                    self.output_blocks.push_back(block);
+                }
+                self.state = State::Finished;
+            }
+            State::MergeRuns => {
+                self.state = State::MergeRuns;
+                self.merge_runs()?;
+            }
+            _ => return Err(ErrorCode::Internal("It's a bug.")),
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Collect) {
+            State::SpillRun(blocks) => {
+                let mut locations = VecDeque::with_capacity(blocks.len());
+                for block in blocks {
+                    locations.push_back(self.spiller.spill(block.convert_to_full()).await?);
+                }
+                if !locations.is_empty() {
+                    self.runs.push(locations);
+                }
+            }
+            State::Restore => {
+                let mut cursors = Vec::with_capacity(self.pending_runs.len());
+                for run in std::mem::take(&mut self.pending_runs) {
+                    let location = self.runs[run].pop_front().unwrap();
+                    let block = self.spiller.restore(&location).await?;
+                    cursors.push(self.create_cursor(run, &block)?);
+                    self.blocks[run].push_back(block);
+                }
+                match self.tree.as_mut() {
+                    // The winner is the run which reads the next block.
+                    Some(tree) => tree.update(cursors.pop()),
+                    None => {
+                        self.tree = Some(LoserTree::create(cursors.into_iter().map(Some).collect()))
+                    }
+                }
+                self.state = State::MergeRuns;
+            }
+            _ => return Err(ErrorCode::Internal("It's a bug.")),
+        }
+        Ok(())
+    }
+}
+
+type SimpleDateSpill = TransformSortSpill<SimpleRows<DateType>, SimpleRowConverter<DateType>>;
+type SimpleTimestampSpill =
+    TransformSortSpill<SimpleRows<TimestampType>, SimpleRowConverter<TimestampType>>;
+type SimpleStringSpill = TransformSortSpill<SimpleRows<StringType>, SimpleRowConverter<StringType>>;
+type CommonSpill = TransformSortSpill<StringColumn, CommonRowConverter>;
+
+#[allow(clippy::too_many_arguments)]
+pub fn try_create_transform_sort_spill(
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    output_schema: DataSchemaRef,
+    block_size: usize,
+    sort_desc: Vec<SortColumnDescription>,
+    gen_order_col: bool,
+    spilling_bytes_threshold: usize,
+    spiller: Box<dyn SortSpiller>,
+) -> Result<Box<dyn Processor>> {
+    if sort_desc.len() == 1 {
+        let sort_type = output_schema.field(sort_desc[0].offset).data_type();
+        match sort_type {
+            DataType::Number(num_ty) => with_number_mapped_type!(|NUM_TYPE| match num_ty {
+                NumberDataType::NUM_TYPE => TransformSortSpill::<
+                    SimpleRows<NumberType<NUM_TYPE>>,
+                    SimpleRowConverter<NumberType<NUM_TYPE>>,
+                >::try_create(
+                    input,
+                    output,
+                    output_schema,
+                    block_size,
+                    sort_desc,
+                    gen_order_col,
+                    spilling_bytes_threshold,
+                    spiller,
+                ),
+            }),
+            DataType::Date => SimpleDateSpill::try_create(
+                input,
+                output,
+                output_schema,
+                block_size,
+                sort_desc,
+                gen_order_col,
+                spilling_bytes_threshold,
+                spiller,
+            ),
+            DataType::Timestamp => SimpleTimestampSpill::try_create(
+                input,
+                output,
+                output_schema,
+                block_size,
+                sort_desc,
+                gen_order_col,
+                spilling_bytes_threshold,
+                spiller,
+            ),
+            DataType::String => SimpleStringSpill::try_create(
+                input,
+                output,
+                output_schema,
+                block_size,
+                sort_desc,
+                gen_order_col,
+                spilling_bytes_threshold,
+                spiller,
+            ),
+            _ => CommonSpill::try_create(
+                input,
+                output,
+                output_schema,
+                block_size,
+                sort_desc,
+                gen_order_col,
+                spilling_bytes_threshold,
+                spiller,
+            ),
+        }
+    } else {
+        CommonSpill::try_create(
+            input,
+            output,
+            output_schema,
+            block_size,
+            sort_desc,
+            gen_order_col,
+            spilling_bytes_threshold,
+            spiller,
+        )
+    }
+}
@@ -21,6 +21,7 @@ use dashmap::mapref::entry::Entry;
 use dashmap::DashMap;
 
 use crate::prof::QueryProfile;
+use crate::ProcessorMetricsEntry;
 
 /// Default capacity of the LRU cache of query profiles.
 const DEFAULT_QUERY_PROFILE_LIMIT: usize = 20;
@@ -29,13 +30,17 @@ const DEFAULT_QUERY_PROFILE_LIMIT: usize = 20;
 /// This is a singleton in every databend-query process.
 pub struct QueryProfileManager {
     /// The LRU cache of query profiles.
-    profiles: Lru,
+    profiles: Lru<Arc<QueryProfile>>,
+
+    /// The LRU cache of processor metrics of the running and finished queries.
+    processor_metrics: Lru<Arc<Mutex<Vec<ProcessorMetricsEntry>>>>,
 }
 
 impl QueryProfileManager {
     fn new(capacity: usize) -> Self {
         QueryProfileManager {
             profiles: Lru::new(capacity),
+            processor_metrics: Lru::new(capacity),
         }
     }
 
@@ -62,6 +67,24 @@ impl QueryProfileManager {
     pub fn list_all(&self) -> Vec<Arc<QueryProfile>> {
         self.profiles.list_all()
     }
+
+    /// Registers the processors of an executing graph of the query.
+    /// A query may be executed with several graphs, their processors are accumulated.
+    pub fn register_processors(&self, query_id: &str, processors: Vec<ProcessorMetricsEntry>) {
+        let entries = self
+            .processor_metrics
+            .get_or_insert_with(query_id, Default::default);
+        entries.lock().unwrap().extend(processors);
+    }
+
+    /// Lists the processor metrics of all the queries, as (query id, processors).
+    pub fn list_processor_metrics(&self) -> Vec<(String, Vec<ProcessorMetricsEntry>)> {
+        self.processor_metrics
+            .list_all_with_key()
+            .into_iter()
+            .map(|(query_id, entries)| (query_id, entries.lock().unwrap().clone()))
+            .collect()
+    }
 }
 
 impl Default for QueryProfileManager {
@@ -72,7 +95,7 @@ impl Default for QueryProfileManager {
 
 /// An LRU cache of query profiles. The expired query profiles
 /// will be removed.
-struct Lru {
+struct Lru<V> {
     /// The maximum number of query profiles to keep in memory.
     /// If the number of query profiles exceeds this number,
     /// the oldest one will be removed.
@@ -81,13 +104,13 @@ struct Lru {
     /// The query profiles.
     /// The key is the query ID.
     /// The value is the query profile.
-    profiles: DashMap<String, Arc<QueryProfile>>,
+    profiles: DashMap<String, V>,
 
     /// An LRU list of query IDs.
     lru: Mutex<VecDeque<String>>,
 }
 
-impl Lru {
+impl<V: Clone> Lru<V> {
     /// Creates a new LRU cache.
     pub fn new(capacity: usize) -> Self {
         Lru {
@@ -100,13 +123,13 @@ impl Lru {
     /// Gets the query profile by the query ID.
     /// Notice that this method required to acquire the shared lock of the LRU list.
     /// So don't call this method when the lock is already acquired.
-    pub fn get(&self, query_id: &str) -> Option<Arc<QueryProfile>> {
+    pub fn get(&self, query_id: &str) -> Option<V> {
         self.profiles.get(query_id).map(|v| v.value().clone())
     }
 
     /// Inserts a query profile.
     /// This operation is thread-safe.
-    pub fn insert(&self, query_id: String, query_profile: Arc<QueryProfile>) {
+    pub fn insert(&self, query_id: String, query_profile: V) {
         // Lock the LRU list to ensure the consistency between the LRU list and the query profiles.
         let mut lru = self.lru.lock().unwrap();
 
@@ -115,20 +138,47 @@ impl Lru {
             return;
         }
 
+        self.evict(&mut lru);
+        self.profiles.insert(query_id.clone(), query_profile);
+        lru.push_back(query_id);
+    }
+
+    /// Gets the value of the query, inserts the one created by `f` if absent.
+    /// This operation is thread-safe.
+    pub fn get_or_insert_with(&self, query_id: &str, f: impl FnOnce() -> V) -> V {
+        let mut lru = self.lru.lock().unwrap();
+
+        if let Some(value) = self.profiles.get(query_id) {
+            return value.value().clone();
+        }
+
+        self.evict(&mut lru);
+        let value = f();
+        self.profiles.insert(query_id.to_string(), value.clone());
+        lru.push_back(query_id.to_string());
+        value
+    }
+
+    fn evict(&self, lru: &mut VecDeque<String>) {
         if self.profiles.len() >= self.capacity {
             if let Some(query_id) = lru.pop_front() {
                 self.profiles.remove(&query_id);
             }
         }
-
-        self.profiles.insert(query_id.clone(), query_profile);
-        lru.push_back(query_id);
     }
 
     /// Lists all query profiles.
     /// Notice that this method required to acquire the shared lock of the LRU list.
     /// So don't call this method when the lock is already acquired.
-    pub fn list_all(&self) -> Vec<Arc<QueryProfile>> {
+    pub fn list_all(&self) -> Vec<V> {
         self.profiles.iter().map(|v| v.value().clone()).collect()
     }
+
+    /// Lists all the values along with the query IDs.
+    pub fn list_all_with_key(&self) -> Vec<(String, V)> {
+        self.profiles
+            .iter()
+            .map(|v| (v.key().clone(), v.value().clone()))
+            .collect()
+    }
 }
@@ -12,8 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Mutex;
 use std::time::Duration;
@@ -45,6 +49,9 @@ pub struct ProcessorProfile {
     pub output_rows: usize,
     /// Byte size of the output data
     pub output_bytes: usize,
+    /// Peak memory tracked while the processor was running, including
+    /// the memory of the data it outputs.
+    pub memory_usage: usize,
 }
 
 impl std::ops::Add for ProcessorProfile {
@@ -58,6 +65,7 @@ impl std::ops::Add for ProcessorProfile {
             input_bytes: self.input_bytes + rhs.input_bytes,
             output_rows: self.output_rows + rhs.output_rows,
             output_bytes: self.output_bytes + rhs.output_bytes,
+            memory_usage: self.memory_usage + rhs.memory_usage,
         }
     }
 }
@@ -86,3 +94,94 @@ where K: std::hash::Hash + Eq + PartialEq + Clone + Debug
         self.spans.get(k)
     }
 }
+
+thread_local! {
+    static CURRENT_PROCESSOR_METRICS: RefCell<Option<Arc<ProcessorMetrics>>> = RefCell::new(None);
+}
+
+/// Runtime metrics of a processor, collected by the pipeline scheduler.
+///
+/// Unlike [`ProcessorProfile`], the metrics are always collected and can be
+/// read while the query is still running.
+#[derive(Default, Debug)]
+pub struct ProcessorMetrics {
+    cpu_time_ns: AtomicU64,
+    wait_time_ns: AtomicU64,
+    input_rows: AtomicUsize,
+    input_bytes: AtomicUsize,
+    output_rows: AtomicUsize,
+    output_bytes: AtomicUsize,
+}
+
+impl ProcessorMetrics {
+    pub fn add_cpu_time(&self, elapsed: Duration) {
+        self.cpu_time_ns
+            .fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    pub fn add_wait_time(&self, elapsed: Duration) {
+        self.wait_time_ns
+            .fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    /// Attribute the data moved through ports in the current thread to the metrics,
+    /// until the returned guard is dropped.
+    pub fn track(metrics: &Arc<ProcessorMetrics>) -> ProcessorMetricsGuard {
+        let saved =
+            CURRENT_PROCESSOR_METRICS.with(|current| current.borrow_mut().replace(metrics.clone()));
+        ProcessorMetricsGuard { saved }
+    }
+
+    /// Record the data pulled from an input port by the tracked processor.
+    pub fn record_input(rows: usize, bytes: usize) {
+        CURRENT_PROCESSOR_METRICS.with(|current| {
+            if let Some(metrics) = current.borrow().as_ref() {
+                metrics.input_rows.fetch_add(rows, Ordering::Relaxed);
+                metrics.input_bytes.fetch_add(bytes, Ordering::Relaxed);
+            }
+        })
+    }
+
+    /// Record the data pushed to an output port by the tracked processor.
+    pub fn record_output(rows: usize, bytes: usize) {
+        CURRENT_PROCESSOR_METRICS.with(|current| {
+            if let Some(metrics) = current.borrow().as_ref() {
+                metrics.output_rows.fetch_add(rows, Ordering::Relaxed);
+                metrics.output_bytes.fetch_add(bytes, Ordering::Relaxed);
+            }
+        })
+    }
+
+    pub fn snapshot(&self) -> ProcessorProfile {
+        ProcessorProfile {
+            cpu_time: Duration::from_nanos(self.cpu_time_ns.load(Ordering::Relaxed)),
+            wait_time: Duration::from_nanos(self.wait_time_ns.load(Ordering::Relaxed)),
+            input_rows: self.input_rows.load(Ordering::Relaxed),
+            input_bytes: self.input_bytes.load(Ordering::Relaxed),
+            output_rows: self.output_rows.load(Ordering::Relaxed),
+            output_bytes: self.output_bytes.load(Ordering::Relaxed),
+            memory_usage: 0,
+        }
+    }
+}
+
+pub struct ProcessorMetricsGuard {
+    saved: Option<Arc<ProcessorMetrics>>,
+}
+
+impl Drop for ProcessorMetricsGuard {
+    fn drop(&mut self) {
+        CURRENT_PROCESSOR_METRICS.with(|current| {
+            *current.borrow_mut() = self.saved.take();
+        })
+    }
+}
+
+/// Metrics of a processor in the executing graph of a query.
+#[derive(Clone, Debug)]
+pub struct ProcessorMetricsEntry {
+    /// ID of the processor in the executing graph
+    pub id: usize,
+    pub name: String,
+    pub metrics: Arc<ProcessorMetrics>,
+}
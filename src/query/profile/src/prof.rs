@@ -75,6 +75,8 @@ pub enum OperatorType {
     RuntimeFilter,
     Insert,
     ConstantTableScan,
+    RecursiveCte,
+    RecursiveCteScan,
 }
 
 impl Display for OperatorType {
@@ -99,6 +101,8 @@ impl Display for OperatorType {
             OperatorType::Insert => write!(f, "Insert"),
             OperatorType::CteScan => write!(f, "CteScan"),
             OperatorType::ConstantTableScan => write!(f, "ConstantTableScan"),
+            OperatorType::RecursiveCte => write!(f, "RecursiveCte"),
+            OperatorType::RecursiveCteScan => write!(f, "RecursiveCteScan"),
         }
     }
 }
@@ -36,6 +36,7 @@ use parking_lot::Mutex;
 use parking_lot::ReentrantMutex;
 use tonic::Status;
 
+use crate::api::rpc::exchange::exchange_params::exchange_compression;
 use crate::api::rpc::exchange::exchange_params::ExchangeParams;
 use crate::api::rpc::exchange::exchange_params::MergeExchangeParams;
 use crate::api::rpc::exchange::exchange_params::ShuffleExchangeParams;
@@ -43,6 +44,8 @@ use crate::api::rpc::exchange::exchange_sink::ExchangeSink;
 use crate::api::rpc::exchange::exchange_transform::ExchangeTransform;
 use crate::api::rpc::exchange::statistics_receiver::StatisticsReceiver;
 use crate::api::rpc::exchange::statistics_sender::StatisticsSender;
+use crate::api::rpc::flight_client::FlightCredits;
+use crate::api::rpc::flight_client::FlightCreditsStream;
 use crate::api::rpc::flight_client::FlightExchange;
 use crate::api::rpc::flight_client::FlightReceiver;
 use crate::api::rpc::flight_client::FlightSender;
@@ -113,7 +116,12 @@ impl DataExchangeManager {
                 targets_exchanges.insert(
                     (connection_info.source.id.clone(), *fragment),
                     flight_client
-                        .do_get(&packet.query_id, target, *fragment)
+                        .do_get(
+                            &packet.query_id,
+                            target,
+                            *fragment,
+                            packet.exchange_credit_bytes,
+                        )
                         .await?,
                 );
             }
@@ -233,15 +241,21 @@ impl DataExchangeManager {
         query: String,
         target: String,
         fragment: usize,
-    ) -> Result<Receiver<Result<FlightData, Status>>> {
+        credit_bytes: usize,
+    ) -> Result<FlightCreditsStream> {
         let queries_coordinator_guard = self.queries_coordinator.lock();
         let queries_coordinator = unsafe { &mut *queries_coordinator_guard.deref().get() };
 
         match queries_coordinator.entry(query) {
-            Entry::Occupied(mut v) => v.get_mut().add_fragment_exchange(target, fragment),
-            Entry::Vacant(v) => v
-                .insert(QueryCoordinator::create())
-                .add_fragment_exchange(target, fragment),
+            Entry::Occupied(mut v) => {
+                v.get_mut()
+                    .add_fragment_exchange(target, fragment, credit_bytes)
+            }
+            Entry::Vacant(v) => v.insert(QueryCoordinator::create()).add_fragment_exchange(
+                target,
+                fragment,
+                credit_bytes,
+            ),
         }
     }
 
@@ -445,10 +459,10 @@ impl QueryCoordinator {
         target: String,
     ) -> Result<Receiver<Result<FlightData, Status>>> {
         let (tx, rx) = async_channel::bounded(8);
-        match self
-            .statistics_exchanges
-            .insert(target, FlightExchange::create_sender(tx))
-        {
+        match self.statistics_exchanges.insert(
+            target,
+            FlightExchange::create_sender(tx, FlightCredits::unlimited()),
+        ) {
             None => Ok(rx),
             Some(_) => Err(ErrorCode::Internal(
                 "statistics exchanges can only have one",
@@ -475,13 +489,15 @@ impl QueryCoordinator {
         &mut self,
         target: String,
         fragment: usize,
-    ) -> Result<Receiver<Result<FlightData, Status>>> {
+        credit_bytes: usize,
+    ) -> Result<FlightCreditsStream> {
         let (tx, rx) = async_channel::bounded(8);
+        let credits = FlightCredits::create(credit_bytes);
         self.fragment_exchanges.insert(
             (target, fragment, FLIGHT_SENDER),
-            FlightExchange::create_sender(tx),
+            FlightExchange::create_sender(tx, credits.clone()),
         );
-        Ok(rx)
+        Ok(FlightCreditsStream::create(rx, credits))
     }
 
     pub fn add_fragment_exchanges(
@@ -703,9 +719,7 @@ impl QueryCoordinator {
             }
         }
 
-        let query_id = info.query_ctx.get_id();
-        let executor_settings =
-            ExecutorSettings::try_create(&info.query_ctx.get_settings(), query_id)?;
+        let executor_settings = ExecutorSettings::try_create(&info.query_ctx)?;
 
         let executor = PipelineCompleteExecutor::from_pipelines(pipelines, executor_settings)?;
 
@@ -729,7 +743,8 @@ impl QueryCoordinator {
             StatisticsSender::spawn_sender(&query_id, ctx, request_server_exchange);
 
         let span = if let Some(parent) = SpanContext::current_local_parent() {
-            Span::root("Distributed-Executor", parent)
+            let query_id = query_id.clone();
+            Span::root("Distributed-Executor", parent).with_property(|| ("query_id", query_id))
         } else {
             Span::noop()
         };
@@ -771,6 +786,8 @@ impl FragmentCoordinator {
         exchange_injector: Arc<dyn ExchangeInjector>,
     ) -> Result<ExchangeParams> {
         if let Some(data_exchange) = &self.data_exchange {
+            let compression = exchange_compression(&info.query_ctx.get_settings())?;
+
             return match data_exchange {
                 DataExchange::Merge(exchange) => {
                     Ok(ExchangeParams::MergeExchange(MergeExchangeParams {
@@ -780,6 +797,7 @@ impl FragmentCoordinator {
                         query_id: info.query_id.to_string(),
                         destination_id: exchange.destination_id.clone(),
                         ignore_exchange: exchange.ignore_exchange,
+                        compression,
                     }))
                 }
                 DataExchange::Broadcast(exchange) => {
@@ -792,6 +810,7 @@ impl FragmentCoordinator {
                         destination_ids: exchange.destination_ids.to_owned(),
                         shuffle_scatter: exchange_injector
                             .flight_scatter(&info.query_ctx, data_exchange)?,
+                        compression,
                     }))
                 }
                 DataExchange::ShuffleDataExchange(exchange) => {
@@ -804,6 +823,7 @@ impl FragmentCoordinator {
                         destination_ids: exchange.destination_ids.to_owned(),
                         shuffle_scatter: exchange_injector
                             .flight_scatter(&info.query_ctx, data_exchange)?,
+                        compression,
                     }))
                 }
             };
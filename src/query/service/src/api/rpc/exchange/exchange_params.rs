@@ -15,11 +15,13 @@
 use std::sync::Arc;
 
 use common_arrow::arrow::io::ipc::write::default_ipc_fields;
+use common_arrow::arrow::io::ipc::write::Compression;
 use common_arrow::arrow::io::ipc::write::WriteOptions;
 use common_arrow::arrow::io::ipc::IpcField;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataSchemaRef;
+use common_settings::Settings;
 
 use crate::api::rpc::flight_scatter::FlightScatter;
 use crate::api::ExchangeInjector;
@@ -40,6 +42,7 @@ pub struct ShuffleExchangeParams {
     pub destination_ids: Vec<String>,
     pub shuffle_scatter: Arc<Box<dyn FlightScatter>>,
     pub exchange_injector: Arc<dyn ExchangeInjector>,
+    pub compression: Option<Compression>,
 }
 
 #[derive(Clone)]
@@ -50,6 +53,15 @@ pub struct MergeExchangeParams {
     pub schema: DataSchemaRef,
     pub ignore_exchange: bool,
     pub exchange_injector: Arc<dyn ExchangeInjector>,
+    pub compression: Option<Compression>,
+}
+
+pub fn exchange_compression(settings: &Settings) -> Result<Option<Compression>> {
+    match settings.get_exchange_compression()?.as_str() {
+        "lz4" => Ok(Some(Compression::LZ4)),
+        "zstd" => Ok(Some(Compression::ZSTD)),
+        _ => Ok(None),
+    }
 }
 
 pub enum ExchangeParams {
@@ -64,7 +76,9 @@ impl MergeExchangeParams {
         Ok(SerializeParams {
             ipc_fields,
             local_executor_pos: 0,
-            options: WriteOptions { compression: None },
+            options: WriteOptions {
+                compression: self.compression,
+            },
         })
     }
 }
@@ -79,7 +93,9 @@ impl ShuffleExchangeParams {
                 return Ok(SerializeParams {
                     ipc_fields,
                     local_executor_pos: index,
-                    options: WriteOptions { compression: None },
+                    options: WriteOptions {
+                        compression: self.compression,
+                    },
                 });
             }
         }
@@ -107,7 +107,9 @@ impl TransformExchangeSerializer {
             output,
             TransformExchangeSerializer {
                 ipc_fields,
-                options: WriteOptions { compression: None },
+                options: WriteOptions {
+                    compression: params.compression,
+                },
             },
         )))
     }
@@ -141,7 +143,9 @@ impl TransformScatterExchangeSerializer {
             output,
             TransformScatterExchangeSerializer {
                 ipc_fields,
-                options: WriteOptions { compression: None },
+                options: WriteOptions {
+                    compression: params.compression,
+                },
                 local_pos: params
                     .destination_ids
                     .iter()
@@ -14,7 +14,10 @@
 
 use std::convert::TryInto;
 use std::error::Error;
+use std::pin::Pin;
 use std::sync::Arc;
+use std::task::Context;
+use std::task::Poll;
 
 use async_channel::Receiver;
 use async_channel::Sender;
@@ -24,11 +27,13 @@ use common_arrow::arrow_format::flight::data::Ticket;
 use common_arrow::arrow_format::flight::service::flight_service_client::FlightServiceClient;
 use common_base::base::tokio;
 use common_base::base::tokio::sync::Notify;
+use common_base::base::tokio::sync::Semaphore;
 use common_base::base::tokio::time::Duration;
 use common_base::runtime::GlobalIORuntime;
 use common_base::runtime::TrySpawn;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use futures::Stream;
 use futures::StreamExt;
 use futures_util::future::Either;
 use tonic::transport::channel::Channel;
@@ -89,12 +94,14 @@ impl FlightClient {
         query_id: &str,
         target: &str,
         fragment: usize,
+        credit_bytes: u64,
     ) -> Result<FlightExchange> {
         let request = RequestBuilder::create(Ticket::default())
             .with_metadata("x-type", "exchange_fragment")?
             .with_metadata("x-target", target)?
             .with_metadata("x-query-id", query_id)?
             .with_metadata("x-fragment-id", &fragment.to_string())?
+            .with_metadata("x-credit-bytes", &credit_bytes.to_string())?
             .build();
         let request = common_tracing::inject_span_to_tonic_request(request);
 
@@ -207,11 +214,15 @@ impl FlightReceiver {
 
 pub struct FlightSender {
     tx: Sender<Result<FlightData, Status>>,
+    credits: FlightCredits,
 }
 
 impl FlightSender {
     pub fn create(tx: Sender<Result<FlightData, Status>>) -> FlightSender {
-        FlightSender { tx }
+        FlightSender {
+            tx,
+            credits: FlightCredits::unlimited(),
+        }
     }
 
     pub fn is_closed(&self) -> bool {
@@ -220,7 +231,15 @@ impl FlightSender {
 
     #[async_backtrace::framed]
     pub async fn send(&self, data: DataPacket) -> Result<()> {
-        if let Err(_cause) = self.tx.send(Ok(FlightData::try_from(data)?)).await {
+        let flight_data = FlightData::try_from(data)?;
+
+        if !self.credits.acquire(flight_data_size(&flight_data)).await {
+            return Err(ErrorCode::AbortedQuery(
+                "Aborted query, because the remote flight channel is closed.",
+            ));
+        }
+
+        if let Err(_cause) = self.tx.send(Ok(flight_data)).await {
             return Err(ErrorCode::AbortedQuery(
                 "Aborted query, because the remote flight channel is closed.",
             ));
@@ -240,12 +259,18 @@ pub enum FlightExchange {
         notify: Arc<Notify>,
         receiver: Receiver<Result<FlightData>>,
     },
-    Sender(Sender<Result<FlightData, Status>>),
+    Sender {
+        sender: Sender<Result<FlightData, Status>>,
+        credits: FlightCredits,
+    },
 }
 
 impl FlightExchange {
-    pub fn create_sender(sender: Sender<Result<FlightData, Status>>) -> FlightExchange {
-        FlightExchange::Sender(sender)
+    pub fn create_sender(
+        sender: Sender<Result<FlightData, Status>>,
+        credits: FlightCredits,
+    ) -> FlightExchange {
+        FlightExchange::Sender { sender, credits }
     }
 
     pub fn create_receiver(
@@ -257,7 +282,10 @@ impl FlightExchange {
 
     pub fn convert_to_sender(self) -> FlightSender {
         match self {
-            FlightExchange::Sender(tx) => FlightSender { tx },
+            FlightExchange::Sender { sender, credits } => FlightSender {
+                tx: sender,
+                credits,
+            },
             _ => unreachable!(),
         }
     }
@@ -273,6 +301,107 @@ impl FlightExchange {
     }
 }
 
+fn flight_data_size(flight_data: &FlightData) -> usize {
+    flight_data.data_body.len() + flight_data.data_header.len() + flight_data.app_metadata.len()
+}
+
+/// Byte credits granted by the receiver of a fragment exchange.
+///
+/// The sender spends credits before queueing a packet and they are given back once the
+/// packet is pulled by the grpc stream of the receiver, so a slow receiver stalls its
+/// senders instead of piling up packets in memory. Zero capacity means no limit.
+#[derive(Clone)]
+pub struct FlightCredits {
+    capacity: usize,
+    semaphore: Option<Arc<Semaphore>>,
+}
+
+impl FlightCredits {
+    pub fn create(capacity: usize) -> FlightCredits {
+        let capacity = capacity.min(u32::MAX as usize);
+        FlightCredits {
+            capacity,
+            semaphore: match capacity {
+                0 => None,
+                _ => Some(Arc::new(Semaphore::new(capacity))),
+            },
+        }
+    }
+
+    pub fn unlimited() -> FlightCredits {
+        FlightCredits::create(0)
+    }
+
+    // A packet larger than the whole window is still sent, it takes all the credits.
+    fn permits(&self, bytes: usize) -> u32 {
+        bytes.clamp(1, self.capacity) as u32
+    }
+
+    // Returns false if the receiver is gone.
+    #[async_backtrace::framed]
+    async fn acquire(&self, bytes: usize) -> bool {
+        match &self.semaphore {
+            None => true,
+            Some(semaphore) => match semaphore.acquire_many(self.permits(bytes)).await {
+                Ok(permit) => {
+                    permit.forget();
+                    true
+                }
+                Err(_) => false,
+            },
+        }
+    }
+
+    fn release(&self, bytes: usize) {
+        if let Some(semaphore) = &self.semaphore {
+            semaphore.add_permits(self.permits(bytes) as usize);
+        }
+    }
+
+    fn close(&self) {
+        if let Some(semaphore) = &self.semaphore {
+            semaphore.close();
+        }
+    }
+}
+
+/// The stream of a fragment exchange served to the receiver, it gives the credits back
+/// to the sender for each packet pulled by the receiver.
+pub struct FlightCreditsStream {
+    credits: FlightCredits,
+    rx: Receiver<Result<FlightData, Status>>,
+}
+
+impl FlightCreditsStream {
+    pub fn create(
+        rx: Receiver<Result<FlightData, Status>>,
+        credits: FlightCredits,
+    ) -> FlightCreditsStream {
+        FlightCreditsStream { credits, rx }
+    }
+}
+
+impl Stream for FlightCreditsStream {
+    type Item = Result<FlightData, Status>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let poll = self.rx.poll_next_unpin(cx);
+
+        if let Poll::Ready(Some(Ok(flight_data))) = &poll {
+            self.credits.release(flight_data_size(flight_data));
+        }
+
+        poll
+    }
+}
+
+impl Drop for FlightCreditsStream {
+    fn drop(&mut self) {
+        // Wake up the senders waiting for credits, the receiver will not pull anymore.
+        self.credits.close();
+    }
+}
+
 #[allow(dead_code)]
 fn match_for_io_error(err_status: &Status) -> Option<&std::io::Error> {
     let mut err: &(dyn Error + 'static) = err_status;
@@ -112,7 +112,11 @@ impl FlightService for DatabendQueryFlightService {
 
     #[async_backtrace::framed]
     async fn do_get(&self, request: Request<Ticket>) -> Response<Self::DoGetStream> {
-        let root = common_tracing::start_trace_for_remote_request(full_name!(), &request);
+        let root = common_tracing::start_trace_for_remote_request(full_name!(), &request)
+            .with_property(|| {
+                let query_id = request.get_metadata("x-query-id").unwrap_or_default();
+                ("query_id", query_id)
+            });
         let _guard = root.set_local_parent();
 
         match request.get_metadata("x-type")?.as_str() {
@@ -130,10 +134,18 @@ impl FlightService for DatabendQueryFlightService {
                     .get_metadata("x-fragment-id")?
                     .parse::<usize>()
                     .unwrap();
+                let credit_bytes = request
+                    .get_metadata("x-credit-bytes")?
+                    .parse::<usize>()
+                    .unwrap_or(0);
 
                 Ok(RawResponse::new(Box::pin(
-                    DataExchangeManager::instance()
-                        .handle_exchange_fragment(query_id, target, fragment)?,
+                    DataExchangeManager::instance().handle_exchange_fragment(
+                        query_id,
+                        target,
+                        fragment,
+                        credit_bytes,
+                    )?,
                 )))
             }
             exchange_type => Err(Status::unimplemented(format!(
@@ -193,7 +205,10 @@ impl FlightService for DatabendQueryFlightService {
                                     &init_query_fragments_plan.executor_packet,
                                 )
                             }
-                            .in_span(Span::enter_with_local_parent(full_name!())),
+                            .in_span(
+                                Span::enter_with_local_parent(full_name!())
+                                    .with_property(|| ("query_id", query_id.clone())),
+                            ),
                         ),
                     )
                     .await
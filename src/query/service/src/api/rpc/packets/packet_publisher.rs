@@ -41,6 +41,7 @@ pub struct InitNodesChannelPacket {
     pub executor: Arc<NodeInfo>,
     pub fragment_connections_info: Vec<ConnectionInfo>,
     pub statistics_connections_info: Vec<ConnectionInfo>,
+    pub exchange_credit_bytes: u64,
 }
 
 impl InitNodesChannelPacket {
@@ -49,12 +50,14 @@ impl InitNodesChannelPacket {
         executor: Arc<NodeInfo>,
         fragment_connections_info: Vec<ConnectionInfo>,
         statistics_connections_info: Vec<ConnectionInfo>,
+        exchange_credit_bytes: u64,
     ) -> InitNodesChannelPacket {
         InitNodesChannelPacket {
             query_id,
             executor,
             fragment_connections_info,
             statistics_connections_info,
+            exchange_credit_bytes,
         }
     }
 }
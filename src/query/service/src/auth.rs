@@ -22,12 +22,14 @@ use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::UserIdentity;
 use common_meta_app::principal::UserInfo;
 use common_users::JwtAuthenticator;
+use common_users::LdapAuthenticator;
 use common_users::UserApiProvider;
 
 use crate::sessions::Session;
 
 pub struct AuthMgr {
     jwt_auth: Option<JwtAuthenticator>,
+    ldap_auth: Option<LdapAuthenticator>,
 }
 
 pub enum Credential {
@@ -44,7 +46,7 @@ pub enum Credential {
 
 impl AuthMgr {
     pub fn init(cfg: &InnerConfig) -> Result<()> {
-        GlobalInstance::set(AuthMgr::create(cfg));
+        GlobalInstance::set(AuthMgr::create(cfg)?);
         Ok(())
     }
 
@@ -52,13 +54,27 @@ impl AuthMgr {
         GlobalInstance::get()
     }
 
-    fn create(cfg: &InnerConfig) -> Arc<AuthMgr> {
-        Arc::new(AuthMgr {
+    fn create(cfg: &InnerConfig) -> Result<Arc<AuthMgr>> {
+        Ok(Arc::new(AuthMgr {
             jwt_auth: JwtAuthenticator::create(
                 cfg.query.jwt_key_file.clone(),
                 cfg.query.jwt_key_files.clone(),
-            ),
-        })
+            )
+            .map(|auth| {
+                auth.with_issuer(cfg.query.jwt_issuer.clone())
+                    .with_audience(cfg.query.jwt_audience.clone())
+                    .with_user_claim(cfg.query.jwt_user_claim.clone())
+                    .with_roles_claim(cfg.query.jwt_roles_claim.clone())
+            }),
+            ldap_auth: LdapAuthenticator::create(
+                cfg.query.ldap_url.clone(),
+                cfg.query.ldap_bind_dn_template.clone(),
+                cfg.query.ldap_group_search_base.clone(),
+                cfg.query.ldap_group_search_filter.clone(),
+                cfg.query.ldap_group_role_mapping.clone(),
+                cfg.query.ldap_pool_size,
+            )?,
+        }))
     }
 
     #[async_backtrace::framed]
@@ -74,11 +90,8 @@ impl AuthMgr {
                     .as_ref()
                     .ok_or_else(|| ErrorCode::AuthenticateFailure("jwt auth not configured."))?;
                 let jwt = jwt_auth.parse_jwt_claims(t.as_str()).await?;
-                let user_name = jwt.subject.ok_or_else(|| {
-                    ErrorCode::AuthenticateFailure(
-                        "jwt auth not configured correctly, user name is missing.",
-                    )
-                })?;
+                let user_name = jwt_auth.user_name(&jwt)?;
+                let claim_roles = jwt_auth.roles(&jwt);
 
                 // setup tenant if the JWT claims contain extra.tenant_id
                 if let Some(tenant) = jwt.custom.tenant_id {
@@ -89,7 +102,7 @@ impl AuthMgr {
                 let identity = UserIdentity::new(&user_name, "%");
 
                 // create a new user for this identity if not exists
-                let user = match user_api
+                let mut user = match user_api
                     .get_user_with_client_ip(&tenant, identity.clone(), client_ip.as_deref())
                     .await
                 {
@@ -120,6 +133,10 @@ impl AuthMgr {
                         user_info
                     }
                 };
+                // roles from the claims are granted for this session only
+                for role in claim_roles {
+                    user.grants.grant_role(role);
+                }
 
                 session.set_authed_user(user, jwt.custom.role).await?;
             }
@@ -148,6 +165,21 @@ impl AuthMgr {
                             }
                         }
                     },
+                    AuthInfo::Ldap => {
+                        let ldap_auth = self.ldap_auth.as_ref().ok_or_else(|| {
+                            ErrorCode::AuthenticateFailure("ldap auth not configured.")
+                        })?;
+                        let p = p
+                            .as_ref()
+                            .ok_or_else(|| ErrorCode::AuthenticateFailure("password required"))?;
+                        // roles mapped from the LDAP groups are granted for this session only
+                        let roles = ldap_auth.authenticate(n, p).await?;
+                        let mut user = user;
+                        for role in roles {
+                            user.grants.grant_role(role);
+                        }
+                        user
+                    }
                     _ => return Err(ErrorCode::AuthenticateFailure("wrong auth type")),
                 };
                 session.set_authed_user(user, None).await?;
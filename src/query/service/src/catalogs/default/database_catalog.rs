@@ -74,6 +74,8 @@ use common_meta_app::schema::UndropTableReply;
 use common_meta_app::schema::UndropTableReq;
 use common_meta_app::schema::UpdateIndexReply;
 use common_meta_app::schema::UpdateIndexReq;
+use common_meta_app::schema::UpdateMultiTableMetaReply;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
 use common_meta_app::schema::UpdateTableMetaReply;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpdateVirtualColumnReply;
@@ -505,6 +507,14 @@ impl Catalog for DatabaseCatalog {
             .await
     }
 
+    #[async_backtrace::framed]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply> {
+        self.mutable_catalog.update_multi_table_meta(req).await
+    }
+
     #[async_backtrace::framed]
     async fn set_table_column_mask_policy(
         &self,
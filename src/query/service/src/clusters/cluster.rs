@@ -53,6 +53,7 @@ use rand::thread_rng;
 use rand::Rng;
 
 use crate::api::FlightClient;
+use crate::clusters::node_resources::NodeResourcesCollector;
 
 pub struct ClusterDiscovery {
     local_id: String,
@@ -379,7 +380,7 @@ impl ClusterHeartbeat {
         }
     }
 
-    fn heartbeat_loop(&self, node: NodeInfo) -> impl Future<Output = ()> + 'static {
+    fn heartbeat_loop(&self, mut node: NodeInfo) -> impl Future<Output = ()> + 'static {
         let shutdown = self.shutdown.clone();
         let shutdown_notify = self.shutdown_notify.clone();
         let cluster_api = self.cluster_api.clone();
@@ -389,6 +390,7 @@ impl ClusterHeartbeat {
 
         async move {
             let mut shutdown_notified = Box::pin(shutdown_notify.notified());
+            let mut resources_collector = NodeResourcesCollector::create(node.cpu_nums);
 
             while !shutdown.load(Ordering::Relaxed) {
                 let mills = {
@@ -404,6 +406,7 @@ impl ClusterHeartbeat {
                     }
                     Either::Right((_, new_shutdown_notified)) => {
                         shutdown_notified = new_shutdown_notified;
+                        node.resources = resources_collector.collect();
                         let heartbeat = cluster_api.heartbeat(&node, MatchSeq::GE(1));
                         if let Err(failure) = heartbeat.await {
                             super::metrics::metric_incr_cluster_heartbeat_count(
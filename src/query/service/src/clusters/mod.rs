@@ -14,6 +14,7 @@
 
 mod cluster;
 mod metrics;
+mod node_resources;
 
 pub use cluster::Cluster;
 pub use cluster::ClusterDiscovery;
@@ -0,0 +1,108 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_base::runtime::GLOBAL_MEM_STAT;
+use common_catalog::table_context::ProcessInfoState;
+use common_meta_types::NodeResources;
+use storages_common_cache::CacheAccessor;
+use storages_common_cache_manager::CacheManager;
+use sysinfo::get_current_pid;
+use sysinfo::Pid;
+use sysinfo::ProcessExt;
+use sysinfo::System;
+use sysinfo::SystemExt;
+
+use crate::sessions::SessionManager;
+
+/// Collects the resource usage of the local node for the cluster heartbeat.
+pub struct NodeResourcesCollector {
+    system: System,
+    pid: Option<Pid>,
+    num_cpus: u64,
+}
+
+impl NodeResourcesCollector {
+    pub fn create(num_cpus: u64) -> NodeResourcesCollector {
+        NodeResourcesCollector {
+            system: System::new(),
+            pid: get_current_pid().ok(),
+            num_cpus: num_cpus.max(1),
+        }
+    }
+
+    pub fn collect(&mut self) -> NodeResources {
+        NodeResources {
+            cpu_usage: self.cpu_usage(),
+            memory_usage: GLOBAL_MEM_STAT.get_memory_usage().max(0) as u64,
+            running_queries: Self::running_queries(),
+            data_cache_size: Self::data_cache_size(),
+            meta_cache_items: Self::meta_cache_items(),
+        }
+    }
+
+    // The cpu usage is measured between two refreshes, the first collection always reports 0.
+    fn cpu_usage(&mut self) -> u32 {
+        let Some(pid) = self.pid else {
+            return 0;
+        };
+
+        if !self.system.refresh_process(pid) {
+            return 0;
+        }
+
+        match self.system.process(pid) {
+            None => 0,
+            Some(process) => (process.cpu_usage() / self.num_cpus as f32).round() as u32,
+        }
+    }
+
+    fn running_queries() -> u64 {
+        SessionManager::instance()
+            .processes_info()
+            .iter()
+            .filter(|process| process.state == ProcessInfoState::Query)
+            .count() as u64
+    }
+
+    fn data_cache_size() -> u64 {
+        let cache_manager = CacheManager::instance();
+        let table_data_cache_size = cache_manager
+            .get_table_data_cache()
+            .map(|cache| cache.size())
+            .unwrap_or_default();
+        let column_array_cache_size = cache_manager
+            .get_table_data_array_cache()
+            .map(|cache| cache.size())
+            .unwrap_or_default();
+        table_data_cache_size + column_array_cache_size
+    }
+
+    fn meta_cache_items() -> u64 {
+        let cache_manager = CacheManager::instance();
+        let items = [
+            cache_manager.get_table_snapshot_cache().map(|c| c.len()),
+            cache_manager.get_snapshot_history_cache().map(|c| c.len()),
+            cache_manager
+                .get_table_snapshot_statistics_cache()
+                .map(|c| c.len()),
+            cache_manager.get_table_segment_cache().map(|c| c.len()),
+            cache_manager
+                .get_bloom_index_filter_cache()
+                .map(|c| c.len()),
+            cache_manager.get_bloom_index_meta_cache().map(|c| c.len()),
+            cache_manager.get_prune_partitions_cache().map(|c| c.len()),
+            cache_manager.get_file_meta_data_cache().map(|c| c.len()),
+        ];
+        items.into_iter().flatten().sum::<usize>() as u64
+    }
+}
@@ -38,17 +38,22 @@ use common_storages_system::FunctionsTable;
 use common_storages_system::IndexesTable;
 use common_storages_system::MallocStatsTable;
 use common_storages_system::MallocStatsTotalsTable;
+use common_storages_system::MemoryBreakdownTable;
 use common_storages_system::MetricsTable;
 use common_storages_system::OneTable;
 use common_storages_system::ProcessesTable;
+use common_storages_system::ProcessorProfileTable;
 use common_storages_system::QueryCacheTable;
 use common_storages_system::QueryLogTable;
 use common_storages_system::QueryProfileTable;
+use common_storages_system::QueryQueueTable;
 use common_storages_system::QuerySummaryTable;
+use common_storages_system::RoleGrantsTable;
 use common_storages_system::RolesTable;
 use common_storages_system::SettingsTable;
 use common_storages_system::StagesTable;
 use common_storages_system::TableFunctionsTable;
+use common_storages_system::TableStatisticsTable;
 use common_storages_system::TablesTableWithHistory;
 use common_storages_system::TablesTableWithoutHistory;
 use common_storages_system::TasksTable;
@@ -103,6 +108,7 @@ impl SystemDatabase {
             )),
             EnginesTable::create(sys_db_meta.next_table_id()),
             RolesTable::create(sys_db_meta.next_table_id()),
+            RoleGrantsTable::create(sys_db_meta.next_table_id()),
             StagesTable::create(sys_db_meta.next_table_id()),
             BuildOptionsTable::create(sys_db_meta.next_table_id()),
             CatalogsTable::create(sys_db_meta.next_table_id()),
@@ -115,8 +121,12 @@ impl SystemDatabase {
             BackgroundJobTable::create(sys_db_meta.next_table_id()),
             BacktraceTable::create(sys_db_meta.next_table_id()),
             TempFilesTable::create(sys_db_meta.next_table_id()),
+            TableStatisticsTable::create(sys_db_meta.next_table_id()),
             QuerySummaryTable::create(sys_db_meta.next_table_id()),
             TasksTable::create(sys_db_meta.next_table_id()),
+            ProcessorProfileTable::create(sys_db_meta.next_table_id()),
+            QueryQueueTable::create(sys_db_meta.next_table_id()),
+            MemoryBreakdownTable::create(sys_db_meta.next_table_id()),
         ];
 
         let disable_tables = Self::disable_system_tables();
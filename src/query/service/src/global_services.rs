@@ -28,6 +28,7 @@ use common_profile::QueryProfileManager;
 use common_sharing::ShareEndpointManager;
 use common_storage::DataOperator;
 use common_storage::ShareTableConfig;
+use common_storage::TempFilesRegistry;
 use common_storages_hive::HiveCreator;
 use common_storages_iceberg::IcebergCreator;
 use common_tracing::GlobalLogger;
@@ -39,6 +40,7 @@ use crate::api::DataExchangeManager;
 use crate::auth::AuthMgr;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
+use crate::interpreters::AsyncInsertQueue;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::SessionManager;
 
@@ -100,6 +102,7 @@ impl GlobalServices {
         HttpQueryManager::init(&config).await?;
         DataExchangeManager::init()?;
         SessionManager::init(&config)?;
+        AsyncInsertQueue::init()?;
         AuthMgr::init(&config)?;
         UserApiProvider::init(
             config.meta.to_meta_grpc_client_conf(),
@@ -113,6 +116,7 @@ impl GlobalServices {
         QueryProfileManager::init();
 
         DataOperator::init(&config.storage).await?;
+        TempFilesRegistry::init()?;
         ShareTableConfig::init(
             &config.query.share_endpoint_address,
             &config.query.share_endpoint_auth_token_file,
@@ -66,6 +66,7 @@ impl AccessChecker for ManagementModeAccess {
 
                 // Set
                 | Plan::SetVariable(_)
+                | Plan::SetUserVariable(_)
 
                 // Database.
                 | Plan::CreateDatabase(_)
@@ -76,6 +77,8 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::DropTable(_)
                 | Plan::DropView(_)
                 | Plan::CreateView(_)
+                | Plan::CreateStream(_)
+                | Plan::DropStream(_)
 
                 // User.
                 | Plan::AlterUser(_)
@@ -99,6 +102,12 @@ impl AccessChecker for ManagementModeAccess {
                 | Plan::CreateNetworkPolicy(_)
                 | Plan::AlterNetworkPolicy(_)
                 | Plan::DropNetworkPolicy(_)
+                // Row access policy.
+                | Plan::CreateRowAccessPolicy(_)
+                | Plan::DropRowAccessPolicy(_)
+                // Sequence.
+                | Plan::CreateSequence(_)
+                | Plan::DropSequence(_)
 
                 // UDF
                 | Plan::CreateUDF(_)
@@ -293,6 +293,32 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::CreateBloomIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                        true,
+                    )
+                    .await?;
+            }
+            Plan::DropBloomIndex(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.table.clone(),
+                        ),
+                        vec![UserPrivilegeType::Alter],
+                        true,
+                    )
+                    .await?;
+            }
             Plan::AddTableColumn(plan) => {
                 session
                     .validate_privilege(
@@ -459,6 +485,21 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::InsertMultiTable(plan) => {
+                for target in plan.targets() {
+                    session
+                        .validate_privilege(
+                            &GrantObject::Table(
+                                target.catalog.clone(),
+                                target.database.clone(),
+                                target.table.clone(),
+                            ),
+                            vec![UserPrivilegeType::Insert],
+                            true,
+                        )
+                        .await?;
+                }
+            }
             Plan::Replace(plan) => {
                 session
                     .validate_privilege(
@@ -538,6 +579,57 @@ impl AccessChecker for PrivilegeAccess {
                     )
                     .await?;
             }
+            Plan::CreateMaterializedView(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Create],
+                        true,
+                    )
+                    .await?;
+            }
+            Plan::RefreshMaterializedView(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.database.clone(),
+                            plan.view_name.clone(),
+                        ),
+                        vec![UserPrivilegeType::Insert],
+                        true,
+                    )
+                    .await?;
+            }
+            Plan::CreateStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Create],
+                        true,
+                    )
+                    .await?;
+                session
+                    .validate_privilege(
+                        &GrantObject::Table(
+                            plan.catalog.clone(),
+                            plan.table_database.clone(),
+                            plan.table_name.clone(),
+                        ),
+                        vec![UserPrivilegeType::Select],
+                        true,
+                    )
+                    .await?;
+            }
+            Plan::DropStream(plan) => {
+                session
+                    .validate_privilege(
+                        &GrantObject::Database(plan.catalog.clone(), plan.database.clone()),
+                        vec![UserPrivilegeType::Drop],
+                        true,
+                    )
+                    .await?;
+            }
             Plan::CreateUser(_) => {
                 session
                     .validate_privilege(
@@ -588,7 +680,11 @@ impl AccessChecker for PrivilegeAccess {
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Grant], false)
                     .await?;
             }
-            Plan::SetVariable(_) | Plan::UnSetVariable(_) | Plan::Kill(_) => {
+            Plan::SetVariable(_)
+            | Plan::UnSetVariable(_)
+            | Plan::Kill(_)
+            | Plan::FlushQueryCache
+            | Plan::DropTableCache(_) => {
                 session
                     .validate_privilege(&GrantObject::Global, vec![UserPrivilegeType::Super], false)
                     .await?;
@@ -641,6 +737,13 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::DropNetworkPolicy(_)
             | Plan::DescNetworkPolicy(_)
             | Plan::ShowNetworkPolicies(_)
+            | Plan::CreateRowAccessPolicy(_)
+            | Plan::DropRowAccessPolicy(_)
+            | Plan::DescRowAccessPolicy(_)
+            | Plan::AddTableRowAccessPolicy(_)
+            | Plan::DropTableRowAccessPolicy(_)
+            | Plan::CreateSequence(_)
+            | Plan::DropSequence(_)
             | Plan::CreateTask(_)   // TODO: need to build ownership info for task
             | Plan::ShowTasks(_)    // TODO: need to build ownership info for task
             | Plan::DescribeTask(_) // TODO: need to build ownership info for task
@@ -663,6 +766,8 @@ impl AccessChecker for PrivilegeAccess {
             // Note: No need to check privileges
             // SET ROLE & SHOW ROLES is a session-local statement (have same semantic with the SET ROLE in postgres), no need to check privileges
             Plan::SetRole(_) => {}
+            // The user variables are session-local too.
+            Plan::SetUserVariable(_) => {}
             Plan::ShowRoles(_) => {}
             Plan::Presign(_) => {}
             Plan::ExplainAst { .. } => {}
@@ -670,6 +775,9 @@ impl AccessChecker for PrivilegeAccess {
             // just used in clickhouse-sqlalchemy, no need to check
             Plan::ExistsTable(_) => {}
             Plan::DescDatamaskPolicy(_) => {}
+            Plan::Begin => {}
+            Plan::Commit => {}
+            Plan::Abort => {}
         }
 
         Ok(())
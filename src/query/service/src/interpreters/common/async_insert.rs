@@ -0,0 +1,195 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio::time::sleep;
+use common_base::base::GlobalInstance;
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::table::AppendMode;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_meta_app::principal::UserInfo;
+use common_pipeline_sources::OneBlockSource;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+
+use crate::pipelines::builders::build_append2table_with_commit_pipeline;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::Pipeline;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// The target of async inserts, inserts are merged only if they have the same target.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct AsyncInsertKey {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    /// Names of the inserted columns.
+    pub columns: Vec<String>,
+}
+
+struct AsyncInsertBuffer {
+    // Identifies the buffer that a scheduled flush was created for.
+    id: u64,
+    user: UserInfo,
+    schema: DataSchemaRef,
+    blocks: Vec<DataBlock>,
+    bytes: usize,
+}
+
+/// Buffers the small `INSERT ... VALUES` statements of each table, and writes them
+/// as merged blocks once the buffered data exceeds `async_insert_max_data_size`,
+/// or has been buffered for `async_insert_busy_timeout_ms`.
+///
+/// The inserts return as soon as they are buffered, so the buffered data is lost
+/// if the node shuts down before it is flushed, and flush failures are only logged.
+pub struct AsyncInsertQueue {
+    next_buffer_id: AtomicU64,
+    buffers: Mutex<HashMap<AsyncInsertKey, AsyncInsertBuffer>>,
+}
+
+impl AsyncInsertQueue {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(AsyncInsertQueue {
+            next_buffer_id: AtomicU64::new(0),
+            buffers: Mutex::new(HashMap::new()),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<AsyncInsertQueue> {
+        GlobalInstance::get()
+    }
+
+    pub fn push(
+        self: &Arc<Self>,
+        ctx: &QueryContext,
+        key: AsyncInsertKey,
+        schema: DataSchemaRef,
+        block: DataBlock,
+    ) -> Result<()> {
+        let settings = ctx.get_settings();
+        let max_data_size = settings.get_async_insert_max_data_size()? as usize;
+        let busy_timeout = Duration::from_millis(settings.get_async_insert_busy_timeout_ms()?);
+        let user = ctx.get_current_user()?;
+
+        let full_buffer = {
+            let mut buffers = self.buffers.lock();
+            let buffer = match buffers.entry(key.clone()) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    let id = self.next_buffer_id.fetch_add(1, Ordering::Relaxed);
+                    self.schedule_flush(key.clone(), id, busy_timeout);
+                    entry.insert(AsyncInsertBuffer {
+                        id,
+                        user,
+                        schema,
+                        blocks: vec![],
+                        bytes: 0,
+                    })
+                }
+            };
+
+            buffer.bytes += block.memory_size();
+            buffer.blocks.push(block);
+            if buffer.bytes >= max_data_size {
+                buffers.remove(&key)
+            } else {
+                None
+            }
+        };
+
+        if let Some(buffer) = full_buffer {
+            Self::spawn_flush(key, buffer);
+        }
+        Ok(())
+    }
+
+    fn schedule_flush(self: &Arc<Self>, key: AsyncInsertKey, id: u64, busy_timeout: Duration) {
+        let queue = self.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            sleep(busy_timeout).await;
+            let buffer = {
+                let mut buffers = queue.buffers.lock();
+                match buffers.get(&key) {
+                    // The buffer may have been flushed for its size already.
+                    Some(buffer) if buffer.id == id => buffers.remove(&key),
+                    _ => None,
+                }
+            };
+
+            if let Some(buffer) = buffer {
+                Self::spawn_flush(key, buffer);
+            }
+        });
+    }
+
+    fn spawn_flush(key: AsyncInsertKey, buffer: AsyncInsertBuffer) {
+        GlobalIORuntime::instance().spawn(async move {
+            let rows = buffer.blocks.iter().map(|b| b.num_rows()).sum::<usize>();
+            match Self::flush(&key, buffer).await {
+                Ok(_) => info!(
+                    "flushed {} rows of async inserts into {}.{}",
+                    rows, key.database, key.table
+                ),
+                Err(cause) => warn!(
+                    "flush {} rows of async inserts into {}.{} failed: {:?}",
+                    rows, key.database, key.table, cause
+                ),
+            }
+        });
+    }
+
+    #[async_backtrace::framed]
+    async fn flush(key: &AsyncInsertKey, buffer: AsyncInsertBuffer) -> Result<()> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+        session.set_authed_user(buffer.user, None).await?;
+        let ctx = session.create_query_context().await?;
+
+        let table = ctx
+            .get_table(&key.catalog, &key.database, &key.table)
+            .await?;
+        let block = DataBlock::concat(&buffer.blocks)?;
+
+        let mut pipeline = Pipeline::create();
+        pipeline.add_source(|output| OneBlockSource::create(output, block.clone()), 1)?;
+        build_append2table_with_commit_pipeline(
+            ctx.clone(),
+            &mut pipeline,
+            table,
+            buffer.schema,
+            None,
+            false,
+            AppendMode::Normal,
+        )?;
+
+        let settings = ExecutorSettings::try_create(&ctx)?;
+        let executor = PipelineCompleteExecutor::try_create(pipeline, settings)?;
+        ctx.set_executor(executor.get_inner())?;
+        executor.execute()
+    }
+}
@@ -0,0 +1,422 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_ast::ast::Identifier;
+use common_ast::ast::Statement;
+use common_ast::ast::TableReference;
+use common_ast::parser::parse_sql;
+use common_ast::parser::tokenize_sql;
+use common_ast::walk_table_reference_mut;
+use common_ast::VisitorMut;
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_pipeline_core::Pipeline;
+use common_sql::executor::DistributedInsertSelect;
+use common_sql::executor::PhysicalPlan;
+use common_sql::executor::PhysicalPlanBuilder;
+use common_sql::normalize_identifier;
+use common_sql::optimizer::SExpr;
+use common_sql::plans::Plan;
+use common_sql::plans::RelOperator;
+use common_sql::NameResolutionContext;
+use common_sql::Planner;
+use common_storages_fuse::FuseTable;
+use common_storages_stream::StreamTable;
+use common_storages_stream::OPT_KEY_DATABASE_NAME;
+use common_storages_stream::OPT_KEY_OFFSET;
+use common_storages_stream::OPT_KEY_TABLE_ID;
+use common_storages_stream::OPT_KEY_TABLE_NAME;
+use common_storages_stream::STREAM_ENGINE;
+use log::info;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_OFFSET;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_DATABASE;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::PipelineBuildResult;
+use crate::schedulers::build_query_pipeline_without_render_result_set;
+use crate::sessions::QueryContext;
+
+pub struct RefreshMaterializedViewDesc {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
+/// Refreshes the materialized views over the table written by the pipeline once it finishes.
+pub async fn hook_refresh_materialized_view(
+    ctx: Arc<QueryContext>,
+    pipeline: &mut Pipeline,
+    desc: RefreshMaterializedViewDesc,
+) -> Result<()> {
+    if pipeline.is_empty() {
+        return Ok(());
+    }
+
+    if !ctx
+        .get_settings()
+        .get_enable_refresh_materialized_view_after_write()?
+    {
+        return Ok(());
+    }
+
+    pipeline.set_on_finished(move |err| {
+        if err.is_none() {
+            info!(
+                "execute pipeline finished successfully, starting run refresh materialized view job."
+            );
+            match GlobalIORuntime::instance().block_on(refresh_materialized_views(ctx, desc)) {
+                Ok(_) => info!("execute refresh materialized view job successfully."),
+                Err(e) => info!("execute refresh materialized view job failed: {:?}", e),
+            }
+        }
+        Ok(())
+    });
+
+    Ok(())
+}
+
+/// Refreshes the materialized views in the database of the table, which are maintained
+/// incrementally from the table.
+async fn refresh_materialized_views(
+    ctx: Arc<QueryContext>,
+    desc: RefreshMaterializedViewDesc,
+) -> Result<()> {
+    let table_id = ctx
+        .get_table(&desc.catalog, &desc.database, &desc.table)
+        .await?
+        .get_id()
+        .to_string();
+    let catalog = ctx.get_catalog(&desc.catalog).await?;
+    let views = catalog
+        .list_tables(&ctx.get_tenant(), &desc.database)
+        .await?
+        .into_iter()
+        .filter(|table| {
+            table
+                .options()
+                .get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID)
+                == Some(&table_id)
+        })
+        .collect::<Vec<_>>();
+
+    for view in views {
+        // The tables cached by the query are the ones before it writes, the refresh
+        // reads the latest ones in a new context.
+        let ctx = ctx.get_current_session().create_query_context().await?;
+        let mut build_res = build_refresh_materialized_view_pipeline(
+            ctx.clone(),
+            &desc.catalog,
+            &desc.database,
+            view.name(),
+            false,
+        )
+        .await?;
+        if build_res.main_pipeline.is_empty() {
+            continue;
+        }
+
+        build_res.set_max_threads(ctx.get_settings().get_max_threads()? as usize);
+        let settings = ExecutorSettings::try_create(&ctx)?;
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+        let complete_executor = PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
+        ctx.set_executor(complete_executor.get_inner())?;
+        complete_executor.execute()?;
+    }
+    Ok(())
+}
+
+/// Builds the pipeline refreshing the materialized view.
+///
+/// If the query of the view reads a single fuse table row by row, and the table is only
+/// appended since the last refresh, only the appended rows are read and inserted into the
+/// view. Otherwise, or if `full` is true, the view is overwritten by the result of its query.
+pub async fn build_refresh_materialized_view_pipeline(
+    ctx: Arc<QueryContext>,
+    catalog: &str,
+    database: &str,
+    view: &str,
+    full: bool,
+) -> Result<PipelineBuildResult> {
+    let table = ctx.get_table(catalog, database, view).await?;
+    let query = table
+        .options()
+        .get(OPT_KEY_MATERIALIZED_VIEW_QUERY)
+        .cloned()
+        .ok_or_else(|| {
+            ErrorCode::UnknownView(format!("{}.{} is not a materialized view", database, view))
+        })?;
+    let view_table = FuseTable::try_from_table(table.as_ref())?;
+    let mut table_info = view_table.get_table_info().clone();
+
+    let incremental = if full {
+        None
+    } else {
+        prepare_incremental_refresh(ctx.clone(), catalog, &table_info, &query).await?
+    };
+    let (sql, overwrite) = match incremental {
+        Some(IncrementalRefresh::UpToDate) => return Ok(PipelineBuildResult::create()),
+        Some(IncrementalRefresh::Changes { sql, offset }) => {
+            set_offset(&mut table_info.meta.options, offset);
+            (sql, false)
+        }
+        None => (query, true),
+    };
+
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, _) = planner.plan_sql(&sql).await?;
+    let Plan::Query {
+        s_expr,
+        metadata,
+        bind_context,
+        ..
+    } = plan
+    else {
+        return Err(ErrorCode::Internal(
+            "The query of materialized view must be a query",
+        ));
+    };
+
+    // The source of the view may be changed after a full refresh, e.g. it is re-created.
+    if overwrite {
+        let options = &mut table_info.meta.options;
+        options.remove(OPT_KEY_MATERIALIZED_VIEW_SOURCE_DATABASE);
+        options.remove(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE);
+        options.remove(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID);
+        options.remove(OPT_KEY_MATERIALIZED_VIEW_OFFSET);
+
+        let source = {
+            let metadata = metadata.read();
+            match metadata.tables() {
+                [entry] if is_row_wise(&s_expr) => Some((
+                    entry.database().to_string(),
+                    entry.name().to_string(),
+                    entry.table(),
+                )),
+                _ => None,
+            }
+        };
+        if let Some((source_database, source_name, source)) = source {
+            if let Ok(fuse_table) = FuseTable::try_from_table(source.as_ref()) {
+                let offset = fuse_table.snapshot_loc().await?;
+                options.insert(
+                    OPT_KEY_MATERIALIZED_VIEW_SOURCE_DATABASE.to_string(),
+                    source_database,
+                );
+                options.insert(
+                    OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE.to_string(),
+                    source_name,
+                );
+                options.insert(
+                    OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID.to_string(),
+                    source.get_id().to_string(),
+                );
+                set_offset(options, offset);
+            }
+        }
+    }
+
+    let mut builder = PhysicalPlanBuilder::new(metadata.clone(), ctx.clone(), false);
+    let select_plan = builder.build(&s_expr, bind_context.column_set()).await?;
+    let catalog_info = ctx.get_catalog(catalog).await?.info();
+    // The offset is committed together with the data inserted into the view.
+    let new_table = FuseTable::try_create(table_info)?;
+    let insert_plan = PhysicalPlan::DistributedInsertSelect(Box::new(DistributedInsertSelect {
+        plan_id: select_plan.get_id(),
+        input: Box::new(select_plan),
+        catalog_info,
+        table_info: new_table.get_table_info().clone(),
+        select_schema: bind_context.output_schema(),
+        select_column_bindings: bind_context.columns.clone(),
+        insert_schema: Arc::new(new_table.schema().into()),
+        cast_needed: true,
+    }));
+    let mut build_res =
+        build_query_pipeline_without_render_result_set(&ctx, &insert_plan, false).await?;
+
+    // Aborts if the view is refreshed by others at the same time, otherwise the same
+    // changes may be inserted twice.
+    let prev_snapshot_id = view_table
+        .read_table_snapshot()
+        .await?
+        .map(|snapshot| snapshot.snapshot_id);
+    new_table.commit_insertion(
+        ctx.clone(),
+        &mut build_res.main_pipeline,
+        None,
+        overwrite,
+        prev_snapshot_id,
+    )?;
+
+    Ok(build_res)
+}
+
+enum IncrementalRefresh {
+    /// The source table is not changed since the last refresh.
+    UpToDate,
+    /// The query reading the appended rows, and the offset after reading them.
+    Changes { sql: String, offset: Option<String> },
+}
+
+async fn prepare_incremental_refresh(
+    ctx: Arc<QueryContext>,
+    catalog: &str,
+    view_info: &TableInfo,
+    query: &str,
+) -> Result<Option<IncrementalRefresh>> {
+    let options = view_info.options();
+    let (Some(source_database), Some(source_name), Some(source_id)) = (
+        options.get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_DATABASE),
+        options.get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE),
+        options.get(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID),
+    ) else {
+        return Ok(None);
+    };
+
+    let Ok(source) = ctx.get_table(catalog, source_database, source_name).await else {
+        return Ok(None);
+    };
+    if source.get_id().to_string() != *source_id {
+        return Ok(None);
+    }
+    let Ok(fuse_table) = FuseTable::try_from_table(source.as_ref()) else {
+        return Ok(None);
+    };
+
+    let last_offset = options.get(OPT_KEY_MATERIALIZED_VIEW_OFFSET).cloned();
+    let offset = fuse_table.snapshot_loc().await?;
+    if offset == last_offset {
+        return Ok(Some(IncrementalRefresh::UpToDate));
+    }
+    let changes = fuse_table
+        .changed_blocks(ctx.clone(), last_offset.clone())
+        .await?;
+    if !changes.deleted.is_empty() {
+        // The rows in the view derived from the deleted rows are unknown.
+        return Ok(None);
+    }
+
+    // Reads the changes by an internal stream which is only known by the query context.
+    let stream_name = format!("_materialized_view_source_{}", view_info.ident.table_id);
+    let settings = ctx.get_settings();
+    let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+    let tokens = tokenize_sql(query)?;
+    let (mut stmt, _) = parse_sql(&tokens, settings.get_sql_dialect()?)?;
+    let mut rewriter = SourceTableRewriter {
+        name_resolution_ctx: &name_resolution_ctx,
+        database: source_database,
+        table: source_name,
+        stream: &stream_name,
+        replaced: 0,
+    };
+    let Statement::Query(query) = &mut stmt else {
+        return Ok(None);
+    };
+    rewriter.visit_query(query);
+    // The source table is read through a view.
+    if rewriter.replaced != 1 {
+        return Ok(None);
+    }
+
+    let mut stream_options = BTreeMap::new();
+    stream_options.insert(OPT_KEY_DATABASE_NAME.to_string(), source_database.clone());
+    stream_options.insert(OPT_KEY_TABLE_NAME.to_string(), source_name.clone());
+    stream_options.insert(OPT_KEY_TABLE_ID.to_string(), source_id.clone());
+    if let Some(last_offset) = last_offset {
+        stream_options.insert(OPT_KEY_OFFSET.to_string(), last_offset);
+    }
+    let stream_info = TableInfo {
+        desc: format!("'{}'.'{}'", source_database, stream_name),
+        name: stream_name.clone(),
+        meta: TableMeta {
+            schema: source.schema(),
+            catalog: catalog.to_string(),
+            engine: STREAM_ENGINE.to_string(),
+            options: stream_options,
+            ..Default::default()
+        },
+        tenant: ctx.get_tenant(),
+        ..Default::default()
+    };
+    let stream: Arc<dyn Table> = StreamTable::try_create(stream_info)?.into();
+    ctx.attach_table(catalog, source_database, &stream_name, stream);
+
+    Ok(Some(IncrementalRefresh::Changes {
+        sql: stmt.to_string(),
+        offset,
+    }))
+}
+
+fn set_offset(options: &mut BTreeMap<String, String>, offset: Option<String>) {
+    match offset {
+        Some(offset) => {
+            options.insert(OPT_KEY_MATERIALIZED_VIEW_OFFSET.to_string(), offset);
+        }
+        None => {
+            options.remove(OPT_KEY_MATERIALIZED_VIEW_OFFSET);
+        }
+    }
+}
+
+/// Whether each output row of the plan is derived from one row of the scanned table.
+fn is_row_wise(s_expr: &SExpr) -> bool {
+    matches!(
+        s_expr.plan(),
+        RelOperator::Scan(_)
+            | RelOperator::Filter(_)
+            | RelOperator::EvalScalar(_)
+            | RelOperator::Exchange(_)
+    ) && s_expr.children().iter().all(|child| is_row_wise(child))
+}
+
+/// Replaces the references to the source table with the internal stream of its changes.
+struct SourceTableRewriter<'a> {
+    name_resolution_ctx: &'a NameResolutionContext,
+    database: &'a str,
+    table: &'a str,
+    stream: &'a str,
+    replaced: usize,
+}
+
+impl VisitorMut for SourceTableRewriter<'_> {
+    fn visit_table_reference(&mut self, table_ref: &mut TableReference) {
+        if let TableReference::Table {
+            database: Some(database),
+            table,
+            ..
+        } = table_ref
+        {
+            if normalize_identifier(database, self.name_resolution_ctx).name == self.database
+                && normalize_identifier(table, self.name_resolution_ctx).name == self.table
+            {
+                *table = Identifier::from_name(self.stream);
+                self.replaced += 1;
+            }
+            return;
+        }
+        walk_table_reference_mut(self, table_ref);
+    }
+}
@@ -12,25 +12,44 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod async_insert;
 mod compact_hook;
 mod grant;
+mod materialized_view;
 mod metrics;
 mod query_log;
+mod query_log_persister;
+mod query_log_sink;
 mod refresh_aggregating_index;
+mod refresh_virtual_column;
+mod stream;
 mod table;
 mod task;
 mod util;
 
+pub use async_insert::AsyncInsertKey;
+pub use async_insert::AsyncInsertQueue;
 pub use compact_hook::*;
 pub use grant::validate_grant_object_exists;
+pub use materialized_view::build_refresh_materialized_view_pipeline;
+pub use materialized_view::hook_refresh_materialized_view;
+pub use materialized_view::RefreshMaterializedViewDesc;
 pub use query_log::InterpreterQueryLog;
+pub use query_log_persister::QueryLogPersister;
+pub use query_log_sink::*;
 pub use refresh_aggregating_index::hook_refresh_agg_index;
 pub use refresh_aggregating_index::RefreshAggIndexDesc;
+pub use refresh_virtual_column::hook_refresh_virtual_column;
+pub use refresh_virtual_column::RefreshVirtualColumnDesc;
+pub use stream::hook_consume_streams;
+pub use table::check_referenced_cluster_key;
 pub use table::check_referenced_computed_columns;
+pub use table::rename_column_in_cluster_key;
 pub use task::get_client_config;
 pub use task::make_schedule_options;
 pub use task::make_warehouse_options;
 pub use util::check_deduplicate_label;
 pub use util::create_push_down_filters;
+pub use util::gen_query_result_cache_key;
 
 pub use self::metrics::*;
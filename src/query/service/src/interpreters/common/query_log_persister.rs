@@ -0,0 +1,103 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use common_base::base::tokio::time::sleep;
+use common_base::runtime::GlobalIORuntime;
+use common_config::InnerConfig;
+use common_exception::Result;
+use common_storages_system::QueryLogElement;
+use common_storages_system::QueryLogQueue;
+use common_storages_system::SystemLogElement;
+use log::info;
+use log::warn;
+
+use crate::interpreters::common::query_log_sink::create_query_log_sink;
+use crate::interpreters::common::query_log_sink::QueryLogSink;
+use crate::interpreters::common::query_log_sink::TableSink;
+
+const HISTORY_DATABASE: &str = "system_history";
+
+/// Periodically ships the events of `system.query_log` to a [`QueryLogSink`].
+///
+/// [`QueryLogPersister::create`] appends them into the fuse table
+/// `system_history.query_log`, so query activity can be retained and analyzed
+/// beyond the capacity of the in-memory log queue;
+/// [`QueryLogPersister::try_create_external`] streams them to the sink set in
+/// the server config, e.g. for audit pipelines spanning several clusters.
+///
+/// Every node ships only the events it logged itself.
+pub struct QueryLogPersister {
+    sink: Box<dyn QueryLogSink>,
+    interval: Duration,
+    // Sequence of the last event that has been shipped.
+    cursor: u64,
+}
+
+impl QueryLogPersister {
+    pub fn create(conf: &InnerConfig) -> Self {
+        QueryLogPersister {
+            sink: Box::new(TableSink::create(
+                conf,
+                HISTORY_DATABASE,
+                QueryLogElement::TABLE_NAME,
+            )),
+            interval: Duration::from_secs(conf.query.query_log_persist_interval_secs.max(1)),
+            cursor: 0,
+        }
+    }
+
+    pub fn try_create_external(conf: &InnerConfig) -> Result<Option<Self>> {
+        Ok(create_query_log_sink(conf)?.map(|sink| QueryLogPersister {
+            sink,
+            interval: Duration::from_secs(conf.query.query_log_sink_interval_secs.max(1)),
+            cursor: 0,
+        }))
+    }
+
+    pub fn sink_name(&self) -> String {
+        self.sink.name()
+    }
+
+    pub fn start(mut self) {
+        GlobalIORuntime::instance().spawn(async move {
+            loop {
+                sleep(self.interval).await;
+                if let Err(cause) = self.flush().await {
+                    warn!(
+                        "ship query log to {} failed (retry later): {:?}",
+                        self.sink.name(),
+                        cause
+                    );
+                }
+            }
+        });
+    }
+
+    #[async_backtrace::framed]
+    async fn flush(&mut self) -> Result<()> {
+        let (events, latest) = QueryLogQueue::instance()?.events_since(self.cursor);
+        if !events.is_empty() {
+            self.sink.write(&events).await?;
+            info!(
+                "shipped {} query log events to {}",
+                events.len(),
+                self.sink.name()
+            );
+        }
+        self.cursor = latest;
+        Ok(())
+    }
+}
@@ -0,0 +1,346 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::AppendMode;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataSchema;
+use common_meta_app::principal::UserInfo;
+use common_pipeline_sources::OneBlockSource;
+use common_sql::Planner;
+use common_storage::DataOperator;
+use common_storages_system::build_log_data_block;
+use common_storages_system::QueryLogElement;
+use common_storages_system::SystemLogElement;
+use common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
+use futures_util::TryStreamExt;
+use opendal::Operator;
+use serde::Serialize;
+use serde_json::json;
+use serde_json::Value;
+
+use crate::interpreters::InterpreterFactory;
+use crate::pipelines::builders::build_append2table_with_commit_pipeline;
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelineCompleteExecutor;
+use crate::pipelines::Pipeline;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// A destination that query log events are shipped to.
+#[async_trait::async_trait]
+pub trait QueryLogSink: Send + Sync + 'static {
+    fn name(&self) -> String;
+
+    async fn write(&mut self, events: &[QueryLogElement]) -> Result<()>;
+}
+
+/// Create the external sink configured by `query_log_sink_type` and
+/// `query_log_sink_target`, or `None` if no sink is configured.
+pub fn create_query_log_sink(conf: &InnerConfig) -> Result<Option<Box<dyn QueryLogSink>>> {
+    let target = conf.query.query_log_sink_target.trim();
+    let sink: Box<dyn QueryLogSink> = match conf.query.query_log_sink_type.as_str() {
+        "" => return Ok(None),
+        "table" => {
+            let (database, table) = target.split_once('.').ok_or_else(|| {
+                ErrorCode::InvalidConfig(format!(
+                    "query_log_sink_target must be '<database>.<table>' for the table sink, got '{target}'"
+                ))
+            })?;
+            Box::new(TableSink::create(conf, database, table))
+        }
+        "ndjson" => Box::new(NdjsonSink::create(conf, target)),
+        "otlp" => {
+            if target.is_empty() {
+                return Err(ErrorCode::InvalidConfig(
+                    "query_log_sink_target must be an OTLP/HTTP logs endpoint for the otlp sink",
+                ));
+            }
+            Box::new(OtlpSink::create(conf, target))
+        }
+        other => {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "unknown query_log_sink_type '{other}', expect one of: table, ndjson, otlp"
+            )));
+        }
+    };
+    Ok(Some(sink))
+}
+
+/// Appends events into a fuse table, creating the table (with the schema of
+/// `system.query_log`) on first use.
+pub struct TableSink {
+    user: UserInfo,
+    database: String,
+    table: String,
+    table_prepared: bool,
+}
+
+impl TableSink {
+    pub fn create(conf: &InnerConfig, database: &str, table: &str) -> Self {
+        let user = UserInfo::new_no_auth(
+            &format!(
+                "{}-{}-query-log",
+                conf.query.tenant_id, conf.query.cluster_id
+            ),
+            "0.0.0.0",
+        );
+
+        TableSink {
+            user,
+            database: database.to_string(),
+            table: table.to_string(),
+            table_prepared: false,
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn prepare_table(&self, ctx: Arc<QueryContext>) -> Result<()> {
+        let database = &self.database;
+        let table = &self.table;
+        let source = QueryLogElement::TABLE_NAME;
+        let statements = [
+            format!("CREATE DATABASE IF NOT EXISTS {database}"),
+            format!(
+                "CREATE TABLE IF NOT EXISTS {database}.{table} AS SELECT * FROM system.{source} LIMIT 0"
+            ),
+        ];
+
+        for sql in statements {
+            let mut planner = Planner::new(ctx.clone());
+            let (plan, _) = planner.plan_sql(&sql).await?;
+            let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+            let stream = interpreter.execute(ctx.clone()).await?;
+            stream.try_collect::<Vec<_>>().await?;
+        }
+        Ok(())
+    }
+
+    fn append_events(
+        ctx: Arc<QueryContext>,
+        table: Arc<dyn Table>,
+        events: &[QueryLogElement],
+    ) -> Result<()> {
+        let block = build_log_data_block(events)?;
+        let source_schema = Arc::new(DataSchema::from(QueryLogElement::schema()));
+
+        let mut pipeline = Pipeline::create();
+        pipeline.add_source(|output| OneBlockSource::create(output, block.clone()), 1)?;
+        build_append2table_with_commit_pipeline(
+            ctx.clone(),
+            &mut pipeline,
+            table,
+            source_schema,
+            None,
+            false,
+            AppendMode::Normal,
+        )?;
+
+        let settings = ExecutorSettings::try_create(&ctx)?;
+        let executor = PipelineCompleteExecutor::try_create(pipeline, settings)?;
+        ctx.set_executor(executor.get_inner())?;
+        executor.execute()
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryLogSink for TableSink {
+    fn name(&self) -> String {
+        format!("table {}.{}", self.database, self.table)
+    }
+
+    #[async_backtrace::framed]
+    async fn write(&mut self, events: &[QueryLogElement]) -> Result<()> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+        session
+            .set_authed_user(
+                self.user.clone(),
+                Some(BUILTIN_ROLE_ACCOUNT_ADMIN.to_string()),
+            )
+            .await?;
+        let ctx = session.create_query_context().await?;
+
+        if !self.table_prepared {
+            self.prepare_table(ctx.clone()).await?;
+            self.table_prepared = true;
+        }
+        let table = ctx
+            .get_table(&ctx.get_current_catalog(), &self.database, &self.table)
+            .await?;
+        Self::append_events(ctx, table, events)
+    }
+}
+
+/// Writes every flushed batch as one newline-delimited JSON file under
+/// `<prefix>/<node_id>/` of the data storage.
+pub struct NdjsonSink {
+    operator: Operator,
+    prefix: String,
+    node_id: String,
+}
+
+impl NdjsonSink {
+    pub fn create(conf: &InnerConfig, prefix: &str) -> Self {
+        let prefix = prefix.trim_matches('/');
+        let prefix = if prefix.is_empty() {
+            "_query_log".to_string()
+        } else {
+            prefix.to_string()
+        };
+
+        NdjsonSink {
+            operator: DataOperator::instance().operator(),
+            prefix,
+            node_id: conf.query.node_id.clone(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryLogSink for NdjsonSink {
+    fn name(&self) -> String {
+        format!("ndjson {}/", self.prefix)
+    }
+
+    #[async_backtrace::framed]
+    async fn write(&mut self, events: &[QueryLogElement]) -> Result<()> {
+        let path = format!(
+            "{}/{}/{}_{}.ndjson",
+            self.prefix,
+            self.node_id,
+            chrono::Utc::now().timestamp_millis(),
+            uuid::Uuid::new_v4().simple()
+        );
+        self.operator.write(&path, encode_ndjson(events)?).await?;
+        Ok(())
+    }
+}
+
+/// Encodes `events` as newline-delimited JSON, one event per line.
+pub fn encode_ndjson<T: Serialize>(events: &[T]) -> Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    for event in events {
+        serde_json::to_writer(&mut buf, event)?;
+        buf.push(b'\n');
+    }
+    Ok(buf)
+}
+
+/// Exports events as OTLP log records (JSON over HTTP) to a collector.
+pub struct OtlpSink {
+    client: reqwest::Client,
+    endpoint: String,
+    resource: Vec<(&'static str, String)>,
+}
+
+impl OtlpSink {
+    pub fn create(conf: &InnerConfig, endpoint: &str) -> Self {
+        OtlpSink {
+            client: reqwest::Client::new(),
+            endpoint: endpoint.to_string(),
+            resource: vec![
+                ("service.name", "databend-query".to_string()),
+                ("databend.tenant_id", conf.query.tenant_id.clone()),
+                ("databend.cluster_id", conf.query.cluster_id.clone()),
+                ("databend.node_id", conf.query.node_id.clone()),
+            ],
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryLogSink for OtlpSink {
+    fn name(&self) -> String {
+        format!("otlp {}", self.endpoint)
+    }
+
+    #[async_backtrace::framed]
+    async fn write(&mut self, events: &[QueryLogElement]) -> Result<()> {
+        let records = events
+            .iter()
+            .map(|event| {
+                Ok(OtlpLogRecord {
+                    time_unix_nano: event.event_time.saturating_mul(1000),
+                    query_id: event.query_id.clone(),
+                    body: serde_json::to_string(event)?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let payload = build_otlp_logs_request(&self.resource, &records);
+
+        let response = self
+            .client
+            .post(&self.endpoint)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| ErrorCode::Internal(format!("export query log to otlp: {e}")))?;
+        if !response.status().is_success() {
+            return Err(ErrorCode::Internal(format!(
+                "export query log to otlp: collector returned {}",
+                response.status()
+            )));
+        }
+        Ok(())
+    }
+}
+
+pub struct OtlpLogRecord {
+    pub time_unix_nano: i64,
+    pub query_id: String,
+    pub body: String,
+}
+
+/// Builds an OTLP `ExportLogsServiceRequest` in its JSON encoding.
+pub fn build_otlp_logs_request(
+    resource: &[(&'static str, String)],
+    records: &[OtlpLogRecord],
+) -> Value {
+    let attributes = resource
+        .iter()
+        .map(|(key, value)| json!({"key": key, "value": {"stringValue": value}}))
+        .collect::<Vec<_>>();
+    let log_records = records
+        .iter()
+        .map(|record| {
+            json!({
+                // 64-bit integers are encoded as strings in OTLP/JSON.
+                "timeUnixNano": record.time_unix_nano.to_string(),
+                "severityText": "INFO",
+                "body": {"stringValue": record.body},
+                "attributes": [
+                    {"key": "query_id", "value": {"stringValue": record.query_id}},
+                ],
+            })
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "resourceLogs": [{
+            "resource": {"attributes": attributes},
+            "scopeLogs": [{
+                "scope": {"name": "databend.query_log"},
+                "logRecords": log_records,
+            }],
+        }],
+    })
+}
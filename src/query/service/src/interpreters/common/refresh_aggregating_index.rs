@@ -160,9 +160,8 @@ async fn refresh_agg_index(ctx: Arc<QueryContext>, desc: RefreshAggIndexDesc) ->
             }
 
             let settings = ctx_cloned.get_settings();
-            let query_id = ctx_cloned.get_id();
             build_res.set_max_threads(settings.get_max_threads()? as usize);
-            let settings = ExecutorSettings::try_create(&settings, query_id)?;
+            let settings = ExecutorSettings::try_create(&ctx_cloned)?;
 
             if build_res.main_pipeline.is_complete_pipeline()? {
                 let mut pipelines = build_res.sources_pipelines;
@@ -0,0 +1,109 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_license::license::Feature::VirtualColumn;
+use common_license::license_manager::get_license_manager;
+use common_meta_app::schema::ListVirtualColumnsReq;
+use common_pipeline_core::Pipeline;
+use common_storages_fuse::FuseTable;
+use log::info;
+use virtual_column::get_virtual_column_handler;
+
+use crate::sessions::QueryContext;
+
+pub struct RefreshVirtualColumnDesc {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
+/// Materializes the virtual columns of the blocks written by the pipeline once it finishes,
+/// so that queries extracting the paths of the virtual columns can read them directly.
+pub async fn hook_refresh_virtual_column(
+    ctx: Arc<QueryContext>,
+    pipeline: &mut Pipeline,
+    desc: RefreshVirtualColumnDesc,
+) -> Result<()> {
+    if pipeline.is_empty() {
+        return Ok(());
+    }
+
+    if !ctx
+        .get_settings()
+        .get_enable_refresh_virtual_column_after_write()?
+    {
+        return Ok(());
+    }
+
+    // Virtual column is an enterprise feature, there is nothing to refresh without the license.
+    let license_manager = get_license_manager();
+    if license_manager
+        .manager
+        .check_enterprise_enabled(ctx.get_license_key(), VirtualColumn)
+        .is_err()
+    {
+        return Ok(());
+    }
+
+    pipeline.set_on_finished(move |err| {
+        if err.is_none() {
+            info!(
+                "execute pipeline finished successfully, starting run refresh virtual column job."
+            );
+            match GlobalIORuntime::instance().block_on(refresh_virtual_column(ctx, desc)) {
+                Ok(_) => info!("execute refresh virtual column job successfully."),
+                Err(e) => info!("execute refresh virtual column job failed: {:?}", e),
+            }
+        }
+        Ok(())
+    });
+
+    Ok(())
+}
+
+async fn refresh_virtual_column(
+    ctx: Arc<QueryContext>,
+    desc: RefreshVirtualColumnDesc,
+) -> Result<()> {
+    let segment_locs = ctx.get_segment_locations()?;
+    if segment_locs.is_empty() {
+        return Ok(());
+    }
+
+    let table = ctx
+        .get_table(&desc.catalog, &desc.database, &desc.table)
+        .await?;
+    let catalog = ctx.get_catalog(&desc.catalog).await?;
+    let handler = get_virtual_column_handler();
+    let res = handler
+        .do_list_virtual_columns(catalog, ListVirtualColumnsReq {
+            tenant: ctx.get_tenant(),
+            table_id: Some(table.get_id()),
+        })
+        .await?;
+    if res.is_empty() {
+        return Ok(());
+    }
+
+    let virtual_columns = res[0].virtual_columns.clone();
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    handler
+        .do_refresh_virtual_column(fuse_table, ctx.clone(), virtual_columns, Some(segment_locs))
+        .await
+}
@@ -0,0 +1,101 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_pipeline_core::Pipeline;
+use common_sql::MetadataRef;
+use common_storages_fuse::FuseTable;
+use common_storages_stream::StreamTable;
+use common_storages_stream::OPT_KEY_OFFSET;
+use common_storages_stream::STREAM_ENGINE;
+use log::info;
+
+use crate::sessions::QueryContext;
+
+/// Moves the offsets of the streams read by the query forward after the pipeline
+/// succeeds, so that the consumed changes are not read again.
+pub fn hook_consume_streams(
+    ctx: Arc<QueryContext>,
+    pipeline: &mut Pipeline,
+    metadata: &MetadataRef,
+) -> Result<()> {
+    if pipeline.is_empty() {
+        return Ok(());
+    }
+
+    let mut streams: HashMap<u64, (String, Arc<dyn Table>)> = HashMap::new();
+    for entry in metadata.read().tables() {
+        let table = entry.table();
+        if table.engine() == STREAM_ENGINE {
+            streams
+                .entry(table.get_id())
+                .or_insert_with(|| (entry.database().to_string(), table));
+        }
+    }
+    if streams.is_empty() {
+        return Ok(());
+    }
+
+    pipeline.set_on_finished(move |may_error| match may_error {
+        None => GlobalIORuntime::instance().block_on(async move {
+            for (database, table) in streams.into_values() {
+                consume_stream(ctx.clone(), &database, table).await?;
+            }
+            Ok(())
+        }),
+        Some(error_code) => Err(error_code.clone()),
+    });
+    Ok(())
+}
+
+async fn consume_stream(
+    ctx: Arc<QueryContext>,
+    database: &str,
+    table: Arc<dyn Table>,
+) -> Result<()> {
+    let stream = StreamTable::try_from_table(table.as_ref())?;
+    // The source table is the one read by the query, see `StreamTable::source_table`.
+    let source = stream.source_table(ctx.clone()).await?;
+    let offset = FuseTable::try_from_table(source.as_ref())?
+        .snapshot_loc()
+        .await?;
+    if offset.as_deref() == stream.offset() {
+        return Ok(());
+    }
+
+    let table_info = stream.get_table_info();
+    info!(
+        "move the offset of stream {} to {:?}",
+        table_info.desc, offset
+    );
+    // Fails if the stream is consumed by another query at the same time.
+    let req = UpsertTableOptionReq {
+        table_id: table_info.ident.table_id,
+        seq: MatchSeq::Exact(table_info.ident.seq),
+        options: HashMap::from([(OPT_KEY_OFFSET.to_string(), offset)]),
+    };
+    let catalog = ctx.get_catalog(table_info.catalog()).await?;
+    catalog
+        .upsert_table_option(&ctx.get_tenant(), database, req)
+        .await?;
+    Ok(())
+}
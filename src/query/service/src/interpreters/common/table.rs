@@ -14,9 +14,17 @@
 
 use std::sync::Arc;
 
+use common_ast::ast::ColumnID;
+use common_ast::ast::Identifier;
+use common_ast::parser::parse_comma_separated_exprs;
+use common_ast::parser::tokenize_sql;
+use common_ast::walk_expr_mut;
+use common_ast::Dialect;
+use common_ast::VisitorMut;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_exception::Span;
 use common_expression::ComputedExpr;
 use common_expression::DataSchemaRef;
 use common_sql::parse_computed_expr;
@@ -55,3 +63,78 @@ pub fn check_referenced_computed_columns(
     }
     Ok(())
 }
+
+/// Check that `column` is not referenced by the cluster key `cluster_key`.
+pub fn check_referenced_cluster_key(cluster_key: &str, column: &str) -> Result<()> {
+    let mut rewriter = ClusterKeyColumnRewriter {
+        old_name: column,
+        new_name: None,
+        referenced: false,
+    };
+    rewrite_cluster_key(cluster_key, &mut rewriter)?;
+    if rewriter.referenced {
+        return Err(ErrorCode::InvalidClusterKeys(format!(
+            "column `{}` is referenced by cluster key `{}`, alter or drop the cluster key first",
+            column, cluster_key
+        )));
+    }
+    Ok(())
+}
+
+/// Rewrite the references of column `old_name` in the cluster key `cluster_key`
+/// to `new_name`. Returns `None` if the cluster key doesn't reference the column.
+pub fn rename_column_in_cluster_key(
+    cluster_key: &str,
+    old_name: &str,
+    new_name: &str,
+) -> Result<Option<String>> {
+    let mut rewriter = ClusterKeyColumnRewriter {
+        old_name,
+        new_name: Some(new_name),
+        referenced: false,
+    };
+    let cluster_key = rewrite_cluster_key(cluster_key, &mut rewriter)?;
+    Ok(rewriter.referenced.then_some(cluster_key))
+}
+
+fn rewrite_cluster_key(
+    cluster_key: &str,
+    rewriter: &mut ClusterKeyColumnRewriter,
+) -> Result<String> {
+    // Cluster keys are stored in the form of `(expr, ...)` and parsed with the MySQL dialect.
+    let tokens = tokenize_sql(cluster_key)?;
+    let mut exprs = parse_comma_separated_exprs(&tokens, Dialect::MySQL)?;
+    for expr in exprs.iter_mut() {
+        walk_expr_mut(rewriter, expr);
+    }
+    Ok(exprs
+        .iter()
+        .map(|expr| format!("{:#}", expr))
+        .collect::<Vec<_>>()
+        .join(", "))
+}
+
+struct ClusterKeyColumnRewriter<'a> {
+    old_name: &'a str,
+    new_name: Option<&'a str>,
+    referenced: bool,
+}
+
+impl<'a> VisitorMut for ClusterKeyColumnRewriter<'a> {
+    fn visit_column_ref(
+        &mut self,
+        _span: Span,
+        _database: &mut Option<Identifier>,
+        _table: &mut Option<Identifier>,
+        column: &mut ColumnID,
+    ) {
+        if let ColumnID::Name(ident) = column {
+            if ident.name == self.old_name {
+                self.referenced = true;
+                if let Some(new_name) = self.new_name {
+                    *ident = Identifier::from_name_with_quoted(new_name, Some('`'));
+                }
+            }
+        }
+    }
+}
@@ -20,6 +20,7 @@ use common_exception::Result;
 use common_expression::type_check::check_function;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_kvapi::kvapi::KVApi;
+use common_storages_result_cache::gen_result_cache_key;
 use common_users::UserApiProvider;
 
 use crate::sql::executor::cast_expr_to_non_null_boolean;
@@ -66,3 +67,22 @@ pub fn create_push_down_filters(scalar: &ScalarExpr) -> Result<Filters> {
         inverted_filter: remote_inverted_filter,
     })
 }
+
+/// Generates the key of the query result cache.
+///
+/// Besides the formatted SQL, the settings that change the results of the same SQL
+/// are also part of the key, to avoid hitting the cache written under other settings.
+pub fn gen_query_result_cache_key(
+    ctx: Arc<dyn TableContext>,
+    formatted_ast: &str,
+) -> Result<String> {
+    let settings = ctx.get_settings();
+    let raw = format!(
+        "{}\ntimezone={}\nsql_dialect={:?}\ncollation={}",
+        formatted_ast,
+        settings.get_timezone()?,
+        settings.get_sql_dialect()?,
+        settings.get_collation()?,
+    );
+    Ok(gen_result_cache_key(&raw))
+}
@@ -20,6 +20,8 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::SendableDataBlockStream;
 use log::error;
+use minitrace::full_name;
+use minitrace::prelude::*;
 
 use crate::interpreters::InterpreterMetrics;
 use crate::interpreters::InterpreterQueryLog;
@@ -43,68 +45,12 @@ pub trait Interpreter: Sync + Send {
 
     /// The core of the databend processor which will execute the logical plan and get the DataBlock
     #[async_backtrace::framed]
-    #[minitrace::trace]
     async fn execute(&self, ctx: Arc<QueryContext>) -> Result<SendableDataBlockStream> {
-        ctx.set_status_info("building pipeline");
-        InterpreterMetrics::record_query_start(&ctx);
-        log_query_start(&ctx);
-
-        if let Err(err) = ctx.check_aborting() {
-            log_query_finished(&ctx, Some(err.clone()));
-            return Err(err);
-        }
-        let mut build_res = match self.execute2().await {
-            Ok(build_res) => build_res,
-            Err(build_error) => {
-                InterpreterMetrics::record_query_error(&ctx);
-                log_query_finished(&ctx, Some(build_error.clone()));
-                return Err(build_error);
-            }
-        };
-
-        if build_res.main_pipeline.is_empty() {
-            InterpreterMetrics::record_query_finished(&ctx, None);
-            log_query_finished(&ctx, None);
-
-            return Ok(Box::pin(DataBlockStream::create(None, vec![])));
-        }
-
-        let query_ctx = ctx.clone();
-        build_res.main_pipeline.set_on_finished(move |may_error| {
-            InterpreterMetrics::record_query_finished(&query_ctx, may_error.clone());
-            log_query_finished(&query_ctx, may_error.clone());
-
-            match may_error {
-                None => Ok(()),
-                Some(error) => Err(error.clone()),
-            }
-        });
-
-        ctx.set_status_info("executing pipeline");
-
-        let settings = ctx.get_settings();
+        // Tag the span with the query id, so that the trace of a query can be looked up by its id.
         let query_id = ctx.get_id();
-        build_res.set_max_threads(settings.get_max_threads()? as usize);
-        let settings = ExecutorSettings::try_create(&settings, query_id)?;
-
-        if build_res.main_pipeline.is_complete_pipeline()? {
-            let mut pipelines = build_res.sources_pipelines;
-            pipelines.push(build_res.main_pipeline);
-
-            let complete_executor = PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
-
-            ctx.set_executor(complete_executor.get_inner())?;
-            complete_executor.execute()?;
-            self.inject_result()
-        } else {
-            let pulling_executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
-
-            ctx.set_executor(pulling_executor.get_inner())?;
-            Ok(Box::pin(ProgressStream::try_create(
-                Box::pin(PullingExecutorStream::create(pulling_executor)?),
-                ctx.get_result_progress(),
-            )?))
-        }
+        let span =
+            Span::enter_with_local_parent(full_name!()).with_property(|| ("query_id", query_id));
+        execute_interpreter(self, ctx).in_span(span).await
     }
 
     /// The core of the databend processor which will execute the logical plan and build the pipeline
@@ -124,6 +70,83 @@ pub trait Interpreter: Sync + Send {
 
 pub type InterpreterPtr = Arc<dyn Interpreter>;
 
+async fn execute_interpreter<I: Interpreter + ?Sized>(
+    interpreter: &I,
+    ctx: Arc<QueryContext>,
+) -> Result<SendableDataBlockStream> {
+    InterpreterMetrics::record_query_start(&ctx);
+    log_query_start(&ctx);
+
+    if let Err(err) = ctx.check_aborting() {
+        log_query_finished(&ctx, Some(err.clone()));
+        return Err(err);
+    }
+
+    // The permit is held until the query finished, queries beyond the limits wait here.
+    let permit = match SessionManager::instance().acquire_query_permit(&ctx).await {
+        Ok(permit) => permit,
+        Err(err) => {
+            InterpreterMetrics::record_query_error(&ctx);
+            log_query_finished(&ctx, Some(err.clone()));
+            return Err(err);
+        }
+    };
+    ctx.set_status_info("building pipeline");
+
+    let mut build_res = match interpreter.execute2().await {
+        Ok(build_res) => build_res,
+        Err(build_error) => {
+            InterpreterMetrics::record_query_error(&ctx);
+            log_query_finished(&ctx, Some(build_error.clone()));
+            return Err(build_error);
+        }
+    };
+
+    if build_res.main_pipeline.is_empty() {
+        InterpreterMetrics::record_query_finished(&ctx, None);
+        log_query_finished(&ctx, None);
+
+        return Ok(Box::pin(DataBlockStream::create(None, vec![])));
+    }
+
+    let query_ctx = ctx.clone();
+    build_res.main_pipeline.set_on_finished(move |may_error| {
+        drop(permit);
+        InterpreterMetrics::record_query_finished(&query_ctx, may_error.clone());
+        log_query_finished(&query_ctx, may_error.clone());
+
+        match may_error {
+            None => Ok(()),
+            Some(error) => Err(error.clone()),
+        }
+    });
+
+    ctx.set_status_info("executing pipeline");
+
+    let settings = ctx.get_settings();
+    build_res.set_max_threads(settings.get_max_threads()? as usize);
+    let settings = ExecutorSettings::try_create(&ctx)?;
+
+    if build_res.main_pipeline.is_complete_pipeline()? {
+        let mut pipelines = build_res.sources_pipelines;
+        pipelines.push(build_res.main_pipeline);
+
+        let complete_executor = PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
+
+        ctx.set_executor(complete_executor.get_inner())?;
+        complete_executor.execute()?;
+        interpreter.inject_result()
+    } else {
+        let pulling_executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
+
+        ctx.set_executor(pulling_executor.get_inner())?;
+        Ok(Box::pin(ProgressStream::try_create(
+            Box::pin(PullingExecutorStream::create(pulling_executor)?),
+            ctx.get_result_progress(),
+        )?))
+    }
+}
+
 fn log_query_start(ctx: &QueryContext) {
     let now = SystemTime::now();
     let session = ctx.get_current_session();
@@ -141,6 +164,11 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>) {
     let now = SystemTime::now();
     let session = ctx.get_current_session();
 
+    if error.is_some() {
+        // A failed statement aborts the explicit transaction it runs in.
+        ctx.txn_mgr().lock().set_fail();
+    }
+
     session.get_status().write().query_finish();
     if session.get_type().is_user_session() {
         SessionManager::instance().status.write().query_finish(now)
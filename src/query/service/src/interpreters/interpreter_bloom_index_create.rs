@@ -0,0 +1,105 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::table::TableExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::CreateBloomIndexPlan;
+use common_sql::BloomIndexPaths;
+use common_storages_fuse::FuseTable;
+use storages_common_index::BloomIndex;
+use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_PATHS;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateBloomIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateBloomIndexPlan,
+}
+
+impl CreateBloomIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateBloomIndexPlan) -> Result<Self> {
+        Ok(CreateBloomIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateBloomIndexInterpreter {
+    fn name(&self) -> &str {
+        "CreateBloomIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(&tenant, &self.plan.database, &self.plan.table)
+            .await?;
+        table.check_mutable()?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref()).map_err(|_| {
+            ErrorCode::UnsupportedIndex(format!(
+                "CREATE INDEX on table {}.{} of engine {} is not supported",
+                self.plan.database,
+                self.plan.table,
+                table.engine()
+            ))
+        })?;
+
+        let mut index_paths = fuse_table.bloom_index_paths();
+        if index_paths.contains_index(&self.plan.index_name) {
+            return if self.plan.if_not_exists {
+                Ok(PipelineBuildResult::create())
+            } else {
+                Err(ErrorCode::IndexAlreadyExists(format!(
+                    "Index '{}' already exists on table {}.{}",
+                    self.plan.index_name, self.plan.database, self.plan.table
+                )))
+            };
+        }
+
+        let schema = table.schema();
+        for path in self.plan.paths.iter() {
+            BloomIndexPaths::verify_path(path, &schema, BloomIndex::supported_type)?;
+        }
+        index_paths
+            .indexes
+            .push((self.plan.index_name.clone(), self.plan.paths.clone()));
+
+        // The filters are built for the blocks written after the index is created.
+        let mut options_map = HashMap::new();
+        options_map.insert(
+            OPT_KEY_BLOOM_INDEX_PATHS.to_string(),
+            Some(index_paths.to_string()),
+        );
+        let req = UpsertTableOptionReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+            options: options_map,
+        };
+        catalog
+            .upsert_table_option(&tenant, &self.plan.database, req)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
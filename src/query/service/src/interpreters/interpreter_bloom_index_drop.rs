@@ -0,0 +1,93 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_catalog::table::TableExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpsertTableOptionReq;
+use common_meta_types::MatchSeq;
+use common_sql::plans::DropBloomIndexPlan;
+use common_storages_fuse::FuseTable;
+use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_PATHS;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropBloomIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropBloomIndexPlan,
+}
+
+impl DropBloomIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropBloomIndexPlan) -> Result<Self> {
+        Ok(DropBloomIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropBloomIndexInterpreter {
+    fn name(&self) -> &str {
+        "DropBloomIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let table = catalog
+            .get_table(&tenant, &self.plan.database, &self.plan.table)
+            .await?;
+        table.check_mutable()?;
+
+        let mut index_paths = match FuseTable::try_from_table(table.as_ref()) {
+            Ok(fuse_table) => fuse_table.bloom_index_paths(),
+            Err(_) => Default::default(),
+        };
+        if !index_paths.contains_index(&self.plan.index_name) {
+            return if self.plan.if_exists {
+                Ok(PipelineBuildResult::create())
+            } else {
+                Err(ErrorCode::UnknownIndex(format!(
+                    "Unknown index '{}' on table {}.{}",
+                    self.plan.index_name, self.plan.database, self.plan.table
+                )))
+            };
+        }
+
+        // The filters already written are kept in the index files, they are no longer used.
+        index_paths
+            .indexes
+            .retain(|(name, _)| *name != self.plan.index_name);
+        let mut options_map = HashMap::new();
+        options_map.insert(
+            OPT_KEY_BLOOM_INDEX_PATHS.to_string(),
+            Some(index_paths.to_string()),
+        );
+        let req = UpsertTableOptionReq {
+            table_id: table.get_id(),
+            seq: MatchSeq::Exact(table.get_table_info().ident.seq),
+            options: options_map,
+        };
+        catalog
+            .upsert_table_option(&tenant, &self.plan.database, req)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -42,12 +42,18 @@ use log::info;
 use crate::interpreters::common::check_deduplicate_label;
 use crate::interpreters::common::hook_compact;
 use crate::interpreters::common::hook_refresh_agg_index;
+use crate::interpreters::common::hook_refresh_materialized_view;
+use crate::interpreters::common::hook_refresh_virtual_column;
 use crate::interpreters::common::CompactHookTraceCtx;
 use crate::interpreters::common::CompactTargetTableDescription;
 use crate::interpreters::common::RefreshAggIndexDesc;
+use crate::interpreters::common::RefreshMaterializedViewDesc;
+use crate::interpreters::common::RefreshVirtualColumnDesc;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreter;
 use crate::pipelines::builders::build_commit_data_pipeline;
+use crate::pipelines::builders::build_copy_from_kafka_pipeline;
+use crate::pipelines::builders::collect_kafka_ranges;
 use crate::pipelines::PipelineBuildResult;
 use crate::schedulers::build_query_pipeline_without_render_result_set;
 use crate::sessions::QueryContext;
@@ -279,12 +285,41 @@ impl Interpreter for CopyIntoTableInterpreter {
         if self.plan.no_file_to_copy {
             return Ok(PipelineBuildResult::create());
         }
-        let (physical_plan, files) = self.build_physical_plan(&self.plan).await?;
-        let mut build_res =
-            build_query_pipeline_without_render_result_set(&self.ctx, &physical_plan, false)
+        let mut build_res = match &self.plan.kafka_info {
+            Some(kafka_info) => {
+                let ranges = collect_kafka_ranges(&self.ctx, &self.plan, kafka_info).await?;
+                if ranges.iter().all(|r| r.is_empty()) {
+                    return Ok(PipelineBuildResult::create());
+                }
+                let mut build_res = PipelineBuildResult::create();
+                build_copy_from_kafka_pipeline(
+                    &self.ctx,
+                    &mut build_res.main_pipeline,
+                    &self.plan,
+                    kafka_info,
+                    ranges,
+                )
                 .await?;
-        build_commit_data_pipeline(&self.ctx, &mut build_res.main_pipeline, &self.plan, &files)
-            .await?;
+                build_res
+            }
+            None => {
+                let (physical_plan, files) = self.build_physical_plan(&self.plan).await?;
+                let mut build_res = build_query_pipeline_without_render_result_set(
+                    &self.ctx,
+                    &physical_plan,
+                    false,
+                )
+                .await?;
+                build_commit_data_pipeline(
+                    &self.ctx,
+                    &mut build_res.main_pipeline,
+                    &self.plan,
+                    &files,
+                )
+                .await?;
+                build_res
+            }
+        };
 
         // Compact if 'enable_recluster_after_write' on.
         {
@@ -324,6 +359,38 @@ impl Interpreter for CopyIntoTableInterpreter {
             .await?;
         }
 
+        // materialize virtual columns if `enable_refresh_virtual_column_after_write` on.
+        {
+            let refresh_virtual_column_desc = RefreshVirtualColumnDesc {
+                catalog: self.plan.catalog_info.name_ident.catalog_name.clone(),
+                database: self.plan.database_name.clone(),
+                table: self.plan.table_name.clone(),
+            };
+
+            hook_refresh_virtual_column(
+                self.ctx.clone(),
+                &mut build_res.main_pipeline,
+                refresh_virtual_column_desc,
+            )
+            .await?;
+        }
+
+        // refresh materialized views if `enable_refresh_materialized_view_after_write` on.
+        {
+            let refresh_materialized_view_desc = RefreshMaterializedViewDesc {
+                catalog: self.plan.catalog_info.name_ident.catalog_name.clone(),
+                database: self.plan.database_name.clone(),
+                table: self.plan.table_name.clone(),
+            };
+
+            hook_refresh_materialized_view(
+                self.ctx.clone(),
+                &mut build_res.main_pipeline,
+                refresh_materialized_view_desc,
+            )
+            .await?;
+        }
+
         Ok(build_res)
     }
 
@@ -66,6 +66,8 @@ use storages_common_table_meta::meta::TableSnapshot;
 use table_lock::TableLockHandlerWrapper;
 
 use crate::interpreters::common::create_push_down_filters;
+use crate::interpreters::common::hook_refresh_agg_index;
+use crate::interpreters::common::RefreshAggIndexDesc;
 use crate::interpreters::Interpreter;
 use crate::interpreters::SelectInterpreter;
 use crate::pipelines::executor::ExecutorSettings;
@@ -215,7 +217,8 @@ impl Interpreter for DeleteInterpreter {
             )
             .await?
         {
-            // Safe to unwrap, because if filters is None, fast_delete will do truncate and return None.
+            // Safe to unwrap, because if filters is None (no predicate, or the binder found it always
+            // true), fast_delete will do truncate and return None.
             let filters = filters.unwrap();
             let cluster = self.ctx.get_cluster();
             let partitions = if cluster.is_empty() || snapshot.segments.len() < cluster.nodes.len()
@@ -266,6 +269,22 @@ impl Interpreter for DeleteInterpreter {
                     .await?;
         }
 
+        // generate sync aggregating indexes if `enable_refresh_aggregating_index_after_write` on.
+        {
+            let refresh_agg_index_desc = RefreshAggIndexDesc {
+                catalog: catalog_name.to_string(),
+                database: db_name.to_string(),
+                table: tbl_name.to_string(),
+            };
+
+            hook_refresh_agg_index(
+                self.ctx.clone(),
+                &mut build_res.main_pipeline,
+                refresh_agg_index_desc,
+            )
+            .await?;
+        }
+
         if build_res.main_pipeline.is_empty() {
             heartbeat.shutdown().await?;
         } else {
@@ -388,9 +407,7 @@ pub async fn subquery_filter(
     .await?;
 
     // Execute pipeline
-    let settings = ctx.get_settings();
-    let query_id = ctx.get_id();
-    let settings = ExecutorSettings::try_create(&settings, query_id)?;
+    let settings = ExecutorSettings::try_create(&ctx)?;
     let pulling_executor = PipelinePullingExecutor::from_pipelines(pipeline, settings)?;
     ctx.set_executor(pulling_executor.get_inner())?;
     let stream_blocks = PullingExecutorStream::create(pulling_executor)?
@@ -447,7 +464,7 @@ pub fn replace_subquery(
                 replace_subquery(filters, arg)?;
             }
         }
-        ScalarExpr::UDFServerCall(udf) => {
+        ScalarExpr::UDFCall(udf) => {
             for arg in &mut udf.arguments {
                 replace_subquery(filters, arg)?;
             }
@@ -27,11 +27,11 @@ use common_profile::SharedProcessorProfiles;
 use common_sql::executor::ProfileHelper;
 use common_sql::optimizer::ColumnSet;
 use common_sql::MetadataRef;
-use common_storages_result_cache::gen_result_cache_key;
 use common_storages_result_cache::ResultCacheReader;
 use common_users::UserApiProvider;
 
 use super::InterpreterFactory;
+use crate::interpreters::common::gen_query_result_cache_key;
 use crate::interpreters::Interpreter;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
@@ -186,7 +186,8 @@ impl ExplainInterpreter {
         formatted_ast: &Option<String>,
     ) -> Result<Vec<DataBlock>> {
         if self.ctx.get_settings().get_enable_query_result_cache()? && self.ctx.get_cacheable() {
-            let key = gen_result_cache_key(formatted_ast.as_ref().unwrap());
+            let key =
+                gen_query_result_cache_key(self.ctx.clone(), formatted_ast.as_ref().unwrap())?;
             let kv_store = UserApiProvider::instance().get_meta_store_client();
             let cache_reader = ResultCacheReader::create(
                 self.ctx.clone(),
@@ -297,7 +298,7 @@ impl ExplainInterpreter {
         let settings = self.ctx.get_settings();
         let query_id = self.ctx.get_id();
         build_res.set_max_threads(settings.get_max_threads()? as usize);
-        let settings = ExecutorSettings::try_create(&settings, query_id.clone())?;
+        let settings = ExecutorSettings::try_create(&self.ctx)?;
 
         // Drain the data
         if build_res.main_pipeline.is_complete_pipeline()? {
@@ -15,6 +15,9 @@
 use std::sync::Arc;
 
 use common_ast::ast::ExplainKind;
+use common_catalog::table_context::TableContext;
+use common_catalog::txn::TxnState;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use log::error;
 
@@ -68,9 +71,25 @@ impl InterpreterFactory {
             error!("Access.denied(v2): {:?}", e);
             e
         })?;
+        Self::check_txn_state(&ctx, plan)?;
         Self::get_inner(ctx, plan)
     }
 
+    fn check_txn_state(ctx: &QueryContext, plan: &Plan) -> Result<()> {
+        let state = ctx.txn_mgr().lock().state();
+        match state {
+            TxnState::AutoCommit => Ok(()),
+            TxnState::Active if plan.allowed_in_txn() => Ok(()),
+            TxnState::Active => Err(ErrorCode::Unimplemented(
+                "only queries and DML statements are supported in an explicit transaction",
+            )),
+            TxnState::Fail if matches!(plan, Plan::Commit | Plan::Abort) => Ok(()),
+            TxnState::Fail => Err(ErrorCode::CurrentTransactionIsAborted(
+                "current transaction is aborted, commands ignored until end of transaction block",
+            )),
+        }
+    }
+
     pub fn get_inner(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
         match plan {
             Plan::Query {
@@ -196,6 +215,9 @@ impl InterpreterFactory {
             Plan::ReclusterTable(recluster_table) => Ok(Arc::new(
                 ReclusterTableInterpreter::try_create(ctx, *recluster_table.clone())?,
             )),
+            Plan::DropTableCache(drop_table_cache) => Ok(Arc::new(
+                DropTableCacheInterpreter::try_create(ctx, *drop_table_cache.clone())?,
+            )),
             Plan::TruncateTable(truncate_table) => Ok(Arc::new(
                 TruncateTableInterpreter::try_create(ctx, *truncate_table.clone())?,
             )),
@@ -231,6 +253,28 @@ impl InterpreterFactory {
                 ctx,
                 *drop_view.clone(),
             )?)),
+            Plan::CreateMaterializedView(create_materialized_view) => {
+                Ok(Arc::new(CreateMaterializedViewInterpreter::try_create(
+                    ctx,
+                    *create_materialized_view.clone(),
+                )?))
+            }
+            Plan::RefreshMaterializedView(refresh_materialized_view) => {
+                Ok(Arc::new(RefreshMaterializedViewInterpreter::try_create(
+                    ctx,
+                    *refresh_materialized_view.clone(),
+                )?))
+            }
+
+            // Streams
+            Plan::CreateStream(create_stream) => Ok(Arc::new(CreateStreamInterpreter::try_create(
+                ctx,
+                *create_stream.clone(),
+            )?)),
+            Plan::DropStream(drop_stream) => Ok(Arc::new(DropStreamInterpreter::try_create(
+                ctx,
+                *drop_stream.clone(),
+            )?)),
 
             // Indexes
             Plan::CreateIndex(index) => Ok(Arc::new(CreateIndexInterpreter::try_create(
@@ -246,6 +290,14 @@ impl InterpreterFactory {
                 ctx,
                 *index.clone(),
             )?)),
+            Plan::CreateBloomIndex(index) => Ok(Arc::new(CreateBloomIndexInterpreter::try_create(
+                ctx,
+                *index.clone(),
+            )?)),
+            Plan::DropBloomIndex(index) => Ok(Arc::new(DropBloomIndexInterpreter::try_create(
+                ctx,
+                *index.clone(),
+            )?)),
             // Virtual columns
             Plan::CreateVirtualColumn(create_virtual_column) => Ok(Arc::new(
                 CreateVirtualColumnInterpreter::try_create(ctx, *create_virtual_column.clone())?,
@@ -274,6 +326,9 @@ impl InterpreterFactory {
             )?)),
 
             Plan::Insert(insert) => InsertInterpreter::try_create(ctx, *insert.clone()),
+            Plan::InsertMultiTable(insert) => {
+                InsertMultiTableInterpreter::try_create(ctx, *insert.clone())
+            }
 
             Plan::Replace(replace) => ReplaceInterpreter::try_create(ctx, *replace.clone()),
             Plan::MergeInto(merge_into) => {
@@ -373,6 +428,9 @@ impl InterpreterFactory {
                 ctx,
                 *unset_variable.clone(),
             )?)),
+            Plan::SetUserVariable(set_user_variable) => Ok(Arc::new(
+                SetUserVariableInterpreter::try_create(ctx, *set_user_variable.clone())?,
+            )),
             Plan::UseDatabase(p) => Ok(Arc::new(UseDatabaseInterpreter::try_create(
                 ctx,
                 *p.clone(),
@@ -433,6 +491,31 @@ impl InterpreterFactory {
                 *p.clone(),
             )?)),
 
+            Plan::CreateRowAccessPolicy(p) => Ok(Arc::new(
+                CreateRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropRowAccessPolicy(p) => Ok(Arc::new(
+                DropRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DescRowAccessPolicy(p) => Ok(Arc::new(
+                DescRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::AddTableRowAccessPolicy(p) => Ok(Arc::new(
+                AddTableRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::DropTableRowAccessPolicy(p) => Ok(Arc::new(
+                DropTableRowAccessPolicyInterpreter::try_create(ctx, *p.clone())?,
+            )),
+
+            Plan::CreateSequence(p) => Ok(Arc::new(CreateSequenceInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropSequence(p) => Ok(Arc::new(DropSequenceInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+
             Plan::CreateNetworkPolicy(p) => Ok(Arc::new(
                 CreateNetworkPolicyInterpreter::try_create(ctx, *p.clone())?,
             )),
@@ -467,6 +550,12 @@ impl InterpreterFactory {
                 *p.clone(),
             )?)),
             Plan::ShowTasks(p) => Ok(Arc::new(ShowTasksInterpreter::try_create(ctx, *p.clone())?)),
+
+            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
+            Plan::Abort => Ok(Arc::new(AbortInterpreter::try_create(ctx)?)),
+
+            Plan::FlushQueryCache => Ok(Arc::new(FlushQueryCacheInterpreter::try_create(ctx)?)),
         }
     }
 }
@@ -15,12 +15,14 @@
 use std::str::FromStr;
 use std::sync::Arc;
 
+use common_base::base::ProgressValues;
 use common_catalog::table::AppendMode;
 use common_catalog::table::TableExt;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataSchema;
 use common_meta_app::principal::StageFileFormatType;
+use common_pipeline_sources::AsyncSource;
 use common_pipeline_sources::AsyncSourcer;
 use common_sql::executor::DistributedInsertSelect;
 use common_sql::executor::PhysicalPlan;
@@ -31,8 +33,15 @@ use common_sql::plans::Plan;
 use common_sql::NameResolutionContext;
 
 use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::hook_consume_streams;
 use crate::interpreters::common::hook_refresh_agg_index;
+use crate::interpreters::common::hook_refresh_materialized_view;
+use crate::interpreters::common::hook_refresh_virtual_column;
+use crate::interpreters::common::AsyncInsertKey;
+use crate::interpreters::common::AsyncInsertQueue;
 use crate::interpreters::common::RefreshAggIndexDesc;
+use crate::interpreters::common::RefreshMaterializedViewDesc;
+use crate::interpreters::common::RefreshVirtualColumnDesc;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
 use crate::pipelines::builders::build_append2table_with_commit_pipeline;
@@ -53,6 +62,54 @@ impl InsertInterpreter {
         Ok(Arc::new(InsertInterpreter { ctx, plan }))
     }
 
+    fn is_async_insert(&self) -> Result<bool> {
+        let settings = self.ctx.get_settings();
+        Ok(matches!(self.plan.source, InsertInputSource::Values { .. })
+            && !self.plan.overwrite
+            && settings.get_enable_async_insert()?
+            && settings.get_deduplicate_label()?.is_none())
+    }
+
+    /// Parses the values and hands them over to the async insert queue,
+    /// the data is written to the table when the queue flushes.
+    #[async_backtrace::framed]
+    async fn async_insert(&self, data: &str, start: usize) -> Result<PipelineBuildResult> {
+        let name_resolution_ctx = NameResolutionContext {
+            deny_column_reference: true,
+            ..Default::default()
+        };
+        let mut source = ValueSource::new(
+            data.to_string(),
+            self.ctx.clone(),
+            name_resolution_ctx,
+            self.plan.schema(),
+            start,
+        );
+
+        if let Some(block) = source.generate().await? {
+            self.ctx.get_write_progress().incr(&ProgressValues {
+                rows: block.num_rows(),
+                bytes: block.memory_size(),
+            });
+
+            let key = AsyncInsertKey {
+                catalog: self.plan.catalog.clone(),
+                database: self.plan.database.clone(),
+                table: self.plan.table.clone(),
+                columns: self
+                    .plan
+                    .schema
+                    .fields()
+                    .iter()
+                    .map(|f| f.name().clone())
+                    .collect(),
+            };
+            AsyncInsertQueue::instance().push(&self.ctx, key, self.plan.schema(), block)?;
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+
     fn check_schema_cast(&self, plan: &Plan) -> Result<bool> {
         let output_schema = &self.plan.schema;
         let select_schema = plan.schema();
@@ -91,6 +148,12 @@ impl Interpreter for InsertInterpreter {
         // check mutability
         table.check_mutable()?;
 
+        if self.is_async_insert()? {
+            if let InsertInputSource::Values { data, start } = &self.plan.source {
+                return self.async_insert(data, *start).await;
+            }
+        }
+
         let mut build_res = PipelineBuildResult::create();
 
         match &self.plan.source {
@@ -169,7 +232,7 @@ impl Interpreter for InsertInterpreter {
             }
             InsertInputSource::SelectPlan(plan) => {
                 let table1 = table.clone();
-                let (mut select_plan, select_column_bindings) = match plan.as_ref() {
+                let (mut select_plan, select_column_bindings, metadata) = match plan.as_ref() {
                     Plan::Query {
                         s_expr,
                         metadata,
@@ -181,6 +244,7 @@ impl Interpreter for InsertInterpreter {
                         (
                             builder1.build(s_expr, bind_context.column_set()).await?,
                             bind_context.columns.clone(),
+                            metadata.clone(),
                         )
                     }
                     _ => unreachable!(),
@@ -241,6 +305,8 @@ impl Interpreter for InsertInterpreter {
                     None,
                 )?;
 
+                hook_consume_streams(self.ctx.clone(), &mut build_res.main_pipeline, &metadata)?;
+
                 let refresh_agg_index_desc = RefreshAggIndexDesc {
                     catalog: self.plan.catalog.clone(),
                     database: self.plan.database.clone(),
@@ -254,6 +320,19 @@ impl Interpreter for InsertInterpreter {
                 )
                 .await?;
 
+                let refresh_materialized_view_desc = RefreshMaterializedViewDesc {
+                    catalog: self.plan.catalog.clone(),
+                    database: self.plan.database.clone(),
+                    table: self.plan.table.clone(),
+                };
+
+                hook_refresh_materialized_view(
+                    self.ctx.clone(),
+                    &mut build_res.main_pipeline,
+                    refresh_materialized_view_desc,
+                )
+                .await?;
+
                 return Ok(build_res);
             }
         };
@@ -287,6 +366,32 @@ impl Interpreter for InsertInterpreter {
         )
         .await?;
 
+        let refresh_virtual_column_desc = RefreshVirtualColumnDesc {
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+            table: self.plan.table.clone(),
+        };
+
+        hook_refresh_virtual_column(
+            self.ctx.clone(),
+            &mut build_res.main_pipeline,
+            refresh_virtual_column_desc,
+        )
+        .await?;
+
+        let refresh_materialized_view_desc = RefreshMaterializedViewDesc {
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+            table: self.plan.table.clone(),
+        };
+
+        hook_refresh_materialized_view(
+            self.ctx.clone(),
+            &mut build_res.main_pipeline,
+            refresh_materialized_view_desc,
+        )
+        .await?;
+
         Ok(build_res)
     }
 }
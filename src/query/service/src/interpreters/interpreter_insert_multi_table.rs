@@ -0,0 +1,209 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::table::AppendMode;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::Pipeline;
+use common_sql::executor::PhysicalPlanBuilder;
+use common_sql::plans::InsertMultiTable;
+use common_sql::plans::InsertTarget;
+use common_sql::plans::Plan;
+use common_storages_fuse::operations::common::PendingCommitMeta;
+use common_storages_fuse::FuseTable;
+
+use crate::interpreters::common::check_deduplicate_label;
+use crate::interpreters::common::hook_consume_streams;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterPtr;
+use crate::pipelines::builders::build_append2table_without_commit_pipeline;
+use crate::pipelines::processors::transforms::MultiTableInsertRouteSink;
+use crate::pipelines::processors::transforms::MultiTableInsertSource;
+use crate::pipelines::processors::TransformCastSchema;
+use crate::pipelines::PipelineBuildResult;
+use crate::schedulers::build_query_pipeline;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Inserts the rows of a query into several tables, and commits all of them at once.
+///
+/// The rows of the query are routed by [`MultiTableInsertRouteSink`] to the pipelines of
+/// the target tables, each of which appends the rows without committing them. After all
+/// the pipelines are finished, the tables are committed in one meta transaction.
+pub struct InsertMultiTableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: InsertMultiTable,
+}
+
+impl InsertMultiTableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: InsertMultiTable) -> Result<InterpreterPtr> {
+        Ok(Arc::new(InsertMultiTableInterpreter { ctx, plan }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for InsertMultiTableInterpreter {
+    fn name(&self) -> &str {
+        "InsertMultiTableInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        if check_deduplicate_label(self.ctx.clone()).await? {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let (mut build_res, metadata) = match &self.plan.input_source {
+            Plan::Query {
+                s_expr,
+                metadata,
+                bind_context,
+                ..
+            } => {
+                let mut builder =
+                    PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone(), false);
+                let physical_plan = builder.build(s_expr, bind_context.column_set()).await?;
+                let build_res =
+                    build_query_pipeline(&self.ctx, &bind_context.columns, &physical_plan, false)
+                        .await?;
+                (build_res, metadata.clone())
+            }
+            _ => unreachable!(),
+        };
+
+        let num_source_columns = self.plan.num_source_columns();
+        let source_schema: DataSchemaRef = Arc::new(DataSchema::new(
+            self.plan.input_source.schema().fields()[..num_source_columns].to_vec(),
+        ));
+        let func_ctx = self.ctx.get_function_context()?;
+        let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
+
+        // Build a pipeline for each of the target tables, the rows of the clauses
+        // into the same table are appended together.
+        let mut senders = HashMap::new();
+        let mut commits = Vec::new();
+        for target in self.plan.targets() {
+            if senders.contains_key(&target.table_id) {
+                continue;
+            }
+            let table = self
+                .ctx
+                .get_table(&target.catalog, &target.database, &target.table)
+                .await?;
+            table.check_mutable()?;
+            let fuse_table = FuseTable::try_from_table(table.as_ref()).map_err(|_| {
+                ErrorCode::Unimplemented(format!(
+                    "INSERT ALL/FIRST into table {}.{} of engine {} is not supported",
+                    target.database,
+                    target.table,
+                    table.engine()
+                ))
+            })?;
+
+            let (tx, rx) = async_channel::unbounded();
+            let mut pipeline = Pipeline::create();
+            pipeline.add_source(
+                |output| MultiTableInsertSource::create(self.ctx.clone(), output, rx.clone()),
+                max_threads,
+            )?;
+
+            let insert_schema: DataSchemaRef = Arc::new(target.schema.clone().into());
+            pipeline.add_transform(|input, output| {
+                TransformCastSchema::try_create(
+                    input,
+                    output,
+                    source_schema.clone(),
+                    insert_schema.clone(),
+                    func_ctx.clone(),
+                )
+            })?;
+            build_append2table_without_commit_pipeline(
+                self.ctx.clone(),
+                &mut pipeline,
+                table.clone(),
+                insert_schema,
+                AppendMode::Normal,
+            )?;
+
+            let commit_meta = PendingCommitMeta::default();
+            fuse_table.do_pending_commit(self.ctx.clone(), &mut pipeline, commit_meta.clone())?;
+
+            build_res.sources_pipelines.push(pipeline);
+            senders.insert(target.table_id, tx);
+            commits.push((table, commit_meta));
+        }
+
+        let to_senders = |targets: &[InsertTarget]| {
+            targets
+                .iter()
+                .map(|target| senders[&target.table_id].clone())
+                .collect::<Vec<_>>()
+        };
+        let intos = to_senders(&self.plan.intos);
+        let whens = self
+            .plan
+            .whens
+            .iter()
+            .map(|targets| to_senders(targets))
+            .collect::<Vec<_>>();
+        let opt_else = self
+            .plan
+            .opt_else
+            .as_ref()
+            .map(|targets| to_senders(targets));
+        build_res.main_pipeline.add_sink(|input| {
+            Ok(ProcessorPtr::create(MultiTableInsertRouteSink::create(
+                input,
+                num_source_columns,
+                self.plan.is_first,
+                intos.clone(),
+                whens.clone(),
+                opt_else.clone(),
+            )))
+        })?;
+
+        let ctx = self.ctx.clone();
+        let overwrite = self.plan.overwrite;
+        build_res
+            .main_pipeline
+            .set_on_finished(move |may_error| match may_error {
+                None => {
+                    let tables = commits
+                        .into_iter()
+                        .map(|(table, commit_meta)| {
+                            let commit_meta = commit_meta
+                                .lock()
+                                .take()
+                                .ok_or_else(|| ErrorCode::Internal("No commit meta. It's a bug"))?;
+                            Ok((table, commit_meta))
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    GlobalIORuntime::instance().block_on(async move {
+                        FuseTable::commit_multi_table_insertion(ctx, tables, overwrite).await
+                    })
+                }
+                Some(error_code) => Err(error_code.clone()),
+            });
+        hook_consume_streams(self.ctx.clone(), &mut build_res.main_pipeline, &metadata)?;
+
+        Ok(build_res)
+    }
+}
@@ -130,6 +130,9 @@ impl Interpreter for KillInterpreter {
         match id.parse::<u32>() {
             Ok(mysql_conn_id) => match self.ctx.get_id_by_mysql_conn_id(&Some(mysql_conn_id)) {
                 Some(get) => self.execute_kill(&get).await,
+                // The connection may be listed in `system.processes` by another node,
+                // which resolves the connection id to its own session.
+                None if self.proxy_to_cluster => self.kill_cluster_query().await,
                 None => Err(ErrorCode::UnknownSession(format!(
                     "MySQL connection id {} not found session id",
                     mysql_conn_id
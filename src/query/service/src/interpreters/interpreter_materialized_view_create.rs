@@ -0,0 +1,97 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_sql::plans::CreateMaterializedViewPlan;
+use common_sql::plans::Plan;
+use common_sql::Planner;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+
+use crate::interpreters::common::build_refresh_materialized_view_pipeline;
+use crate::interpreters::CreateTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateMaterializedViewPlan,
+}
+
+impl CreateMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateMaterializedViewPlan) -> Result<Self> {
+        Ok(CreateMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "CreateMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        if catalog
+            .exists_table(&self.plan.tenant, &self.plan.database, &self.plan.view_name)
+            .await?
+        {
+            return if self.plan.if_not_exists {
+                Ok(PipelineBuildResult::create())
+            } else {
+                Err(ErrorCode::TableAlreadyExists(format!(
+                    "{}.{} as materialized view Already Exists",
+                    self.plan.database, self.plan.view_name
+                )))
+            };
+        }
+
+        // A materialized view is a fuse table with the schema of its query,
+        // the query is kept in the options of the table.
+        let sql = format!(
+            "CREATE TABLE `{}`.`{}`.`{}` AS {}",
+            self.plan.catalog, self.plan.database, self.plan.view_name, self.plan.subquery
+        );
+        let mut planner = Planner::new(self.ctx.clone());
+        let (plan, _) = planner.plan_sql(&sql).await?;
+        let Plan::CreateTable(mut create_table_plan) = plan else {
+            return Err(ErrorCode::Internal(
+                "Failed to build the table of materialized view",
+            ));
+        };
+        create_table_plan.as_select = None;
+        create_table_plan.options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_QUERY.to_string(),
+            self.plan.subquery.clone(),
+        );
+        CreateTableInterpreter::try_create(self.ctx.clone(), *create_table_plan)?
+            .execute2()
+            .await?;
+
+        // Fills the view, which records the source table to refresh it incrementally.
+        build_refresh_materialized_view_pipeline(
+            self.ctx.clone(),
+            &self.plan.catalog,
+            &self.plan.database,
+            &self.plan.view_name,
+            true,
+        )
+        .await
+    }
+}
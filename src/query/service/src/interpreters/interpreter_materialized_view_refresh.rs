@@ -0,0 +1,55 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_sql::plans::RefreshMaterializedViewPlan;
+
+use crate::interpreters::common::build_refresh_materialized_view_pipeline;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+pub struct RefreshMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshMaterializedViewPlan,
+}
+
+impl RefreshMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshMaterializedViewPlan) -> Result<Self> {
+        Ok(RefreshMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "RefreshMaterializedViewInterpreter"
+    }
+
+    /// Recomputes the whole view, it is the fallback when the view can not be
+    /// refreshed incrementally after writes.
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        build_refresh_materialized_view_pipeline(
+            self.ctx.clone(),
+            &self.plan.catalog,
+            &self.plan.database,
+            &self.plan.view_name,
+            true,
+        )
+        .await
+    }
+}
@@ -66,8 +66,10 @@ use tokio_stream::StreamExt;
 use super::Interpreter;
 use super::InterpreterPtr;
 use crate::interpreters::common::hook_compact;
+use crate::interpreters::common::hook_refresh_agg_index;
 use crate::interpreters::common::CompactHookTraceCtx;
 use crate::interpreters::common::CompactTargetTableDescription;
+use crate::interpreters::common::RefreshAggIndexDesc;
 use crate::interpreters::InterpreterFactory;
 use crate::pipelines::PipelineBuildResult;
 use crate::schedulers::build_query_pipeline_without_render_result_set;
@@ -140,6 +142,22 @@ impl Interpreter for MergeIntoInterpreter {
             .await;
         }
 
+        // generate sync aggregating indexes if `enable_refresh_aggregating_index_after_write` on.
+        {
+            let refresh_agg_index_desc = RefreshAggIndexDesc {
+                catalog: self.plan.catalog.clone(),
+                database: self.plan.database.clone(),
+                table: self.plan.table.clone(),
+            };
+
+            hook_refresh_agg_index(
+                self.ctx.clone(),
+                &mut build_res.main_pipeline,
+                refresh_agg_index_desc,
+            )
+            .await?;
+        }
+
         Ok(build_res)
     }
 }
@@ -584,6 +602,8 @@ impl MergeIntoInterpreter {
             RelOperator::MaterializedCte(_) => {}
             RelOperator::Lambda(_) => {}
             RelOperator::ConstantTableScan(_) => {}
+            RelOperator::RecursiveCte(_) => {}
+            RelOperator::RecursiveCteScan(_) => {}
             RelOperator::Pattern(_) => {}
         }
         Ok(())
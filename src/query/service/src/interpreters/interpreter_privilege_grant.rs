@@ -99,7 +99,10 @@ impl Interpreter for GrantPrivilegeInterpreter {
 /// Some global privileges can not be granted to a database or table, for example,
 /// a KILL statement is meaningless for a table.
 pub fn validate_grant_privileges(object: &GrantObject, privileges: UserPrivilegeSet) -> Result<()> {
-    let available_privileges = object.available_privileges();
+    let mut available_privileges = object.available_privileges();
+    if let GrantObject::Global = object {
+        available_privileges |= UserPrivilegeSet::explicit_privileges_on_global();
+    }
     let ok = privileges
         .iter()
         .all(|p| available_privileges.has_privilege(p));
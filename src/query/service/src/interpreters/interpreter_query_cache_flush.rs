@@ -0,0 +1,61 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_storages_result_cache::gen_result_cache_prefix;
+use common_storages_result_cache::ResultCacheMetaManager;
+use common_users::UserApiProvider;
+use log::info;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct FlushQueryCacheInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl FlushQueryCacheInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(FlushQueryCacheInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for FlushQueryCacheInterpreter {
+    fn name(&self) -> &str {
+        "FlushQueryCacheInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let meta_client = UserApiProvider::instance().get_meta_store_client();
+        let result_cache_mgr = ResultCacheMetaManager::create(meta_client, 0);
+        let tenant = self.ctx.get_tenant();
+
+        // Only the metas are removed, the cached result files become
+        // unreachable and are cleaned up with the other orphan files.
+        let removed = result_cache_mgr
+            .remove_all(&gen_result_cache_prefix(&tenant))
+            .await?;
+        info!(
+            "flushed {} query result cache entries of tenant {}",
+            removed, tenant
+        );
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -47,8 +47,14 @@ use storages_common_table_meta::meta::TableSnapshot;
 
 use crate::interpreters::common::check_deduplicate_label;
 use crate::interpreters::common::hook_compact;
+use crate::interpreters::common::hook_refresh_agg_index;
+use crate::interpreters::common::hook_refresh_materialized_view;
+use crate::interpreters::common::hook_refresh_virtual_column;
 use crate::interpreters::common::CompactHookTraceCtx;
 use crate::interpreters::common::CompactTargetTableDescription;
+use crate::interpreters::common::RefreshAggIndexDesc;
+use crate::interpreters::common::RefreshMaterializedViewDesc;
+use crate::interpreters::common::RefreshVirtualColumnDesc;
 use crate::interpreters::interpreter_copy_into_table::CopyIntoTableInterpreter;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterPtr;
@@ -123,6 +129,54 @@ impl Interpreter for ReplaceInterpreter {
             .await;
         }
 
+        // generate sync aggregating indexes if `enable_refresh_aggregating_index_after_write` on.
+        {
+            let refresh_agg_index_desc = RefreshAggIndexDesc {
+                catalog: self.plan.catalog.clone(),
+                database: self.plan.database.clone(),
+                table: self.plan.table.clone(),
+            };
+
+            hook_refresh_agg_index(
+                self.ctx.clone(),
+                &mut pipeline.main_pipeline,
+                refresh_agg_index_desc,
+            )
+            .await?;
+        }
+
+        // materialize virtual columns if `enable_refresh_virtual_column_after_write` on.
+        {
+            let refresh_virtual_column_desc = RefreshVirtualColumnDesc {
+                catalog: self.plan.catalog.clone(),
+                database: self.plan.database.clone(),
+                table: self.plan.table.clone(),
+            };
+
+            hook_refresh_virtual_column(
+                self.ctx.clone(),
+                &mut pipeline.main_pipeline,
+                refresh_virtual_column_desc,
+            )
+            .await?;
+        }
+
+        // refresh materialized views if `enable_refresh_materialized_view_after_write` on.
+        {
+            let refresh_materialized_view_desc = RefreshMaterializedViewDesc {
+                catalog: self.plan.catalog.clone(),
+                database: self.plan.database.clone(),
+                table: self.plan.table.clone(),
+            };
+
+            hook_refresh_materialized_view(
+                self.ctx.clone(),
+                &mut pipeline.main_pipeline,
+                refresh_materialized_view_desc,
+            )
+            .await?;
+        }
+
         Ok(pipeline)
     }
 }
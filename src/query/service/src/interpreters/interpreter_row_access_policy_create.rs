@@ -0,0 +1,74 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_exception::Result;
+use common_meta_app::principal::RowAccessPolicy;
+use common_sql::plans::CreateRowAccessPolicyPlan;
+use common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CreateRowAccessPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateRowAccessPolicyPlan,
+}
+
+impl CreateRowAccessPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateRowAccessPolicyPlan) -> Result<Self> {
+        Ok(CreateRowAccessPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "CreateRowAccessPolicyInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "create_row_access_policy_execute");
+
+        let plan = self.plan.clone();
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+
+        let row_access_policy = RowAccessPolicy {
+            name: plan.name,
+            args: plan
+                .policy
+                .args
+                .iter()
+                .map(|arg| (arg.arg_name.to_string(), arg.arg_type.to_string()))
+                .collect(),
+            body: plan.policy.body.to_string(),
+            comment: plan.policy.comment.unwrap_or_default(),
+            create_on: Utc::now(),
+            update_on: None,
+        };
+        user_mgr
+            .add_row_access_policy(&tenant, row_access_policy, plan.if_not_exists)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
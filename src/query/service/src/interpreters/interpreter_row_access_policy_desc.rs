@@ -0,0 +1,73 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_sql::plans::DescRowAccessPolicyPlan;
+use common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DescRowAccessPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DescRowAccessPolicyPlan,
+}
+
+impl DescRowAccessPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DescRowAccessPolicyPlan) -> Result<Self> {
+        Ok(DescRowAccessPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DescRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "DescRowAccessPolicyInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+
+        let policy = user_mgr
+            .get_row_access_policy(&tenant, self.plan.name.as_str())
+            .await?;
+
+        let signature = format!(
+            "({})",
+            policy
+                .args
+                .iter()
+                .map(|(arg_name, arg_type)| format!("{} {}", arg_name, arg_type))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![policy.name.as_bytes().to_vec()]),
+            StringType::from_data(vec![policy.create_on.to_string().as_bytes().to_vec()]),
+            StringType::from_data(vec![signature.as_bytes().to_vec()]),
+            StringType::from_data(vec![policy.body.as_bytes().to_vec()]),
+            StringType::from_data(vec![policy.comment.as_bytes().to_vec()]),
+        ])])
+    }
+}
@@ -32,13 +32,13 @@ use common_sql::executor::PhysicalPlan;
 use common_sql::parse_result_scan_args;
 use common_sql::ColumnBinding;
 use common_sql::MetadataRef;
-use common_storages_result_cache::gen_result_cache_key;
 use common_storages_result_cache::ResultCacheReader;
 use common_storages_result_cache::WriteResultCacheSink;
 use common_users::UserApiProvider;
 use log::error;
 use log::info;
 
+use crate::interpreters::common::gen_query_result_cache_key;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::schedulers::build_query_pipeline;
@@ -241,7 +241,8 @@ impl Interpreter for SelectInterpreter {
             query_plan
         );
         if self.ctx.get_settings().get_enable_query_result_cache()? && self.ctx.get_cacheable() {
-            let key = gen_result_cache_key(self.formatted_ast.as_ref().unwrap());
+            let key =
+                gen_query_result_cache_key(self.ctx.clone(), self.formatted_ast.as_ref().unwrap())?;
             // 1. Try to get result from cache.
             let kv_store = UserApiProvider::instance().get_meta_store_client();
 
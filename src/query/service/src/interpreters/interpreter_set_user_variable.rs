@@ -0,0 +1,48 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_sql::plans::SetUserVariablePlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct SetUserVariableInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: SetUserVariablePlan,
+}
+
+impl SetUserVariableInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: SetUserVariablePlan) -> Result<Self> {
+        Ok(SetUserVariableInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for SetUserVariableInterpreter {
+    fn name(&self) -> &str {
+        "SetUserVariableInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        self.ctx
+            .set_variable(self.plan.variable.clone(), self.plan.value.clone());
+        Ok(PipelineBuildResult::create())
+    }
+}
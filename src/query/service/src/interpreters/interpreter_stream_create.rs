@@ -0,0 +1,131 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::CreateTableReq;
+use common_meta_app::schema::TableMeta;
+use common_meta_app::schema::TableNameIdent;
+use common_sql::plans::CreateStreamPlan;
+use common_storages_fuse::FuseTable;
+use common_storages_stream::StreamTable;
+use common_storages_stream::MODE_APPEND_ONLY;
+use common_storages_stream::MODE_STANDARD;
+use common_storages_stream::OPT_KEY_DATABASE_NAME;
+use common_storages_stream::OPT_KEY_MODE;
+use common_storages_stream::OPT_KEY_OFFSET;
+use common_storages_stream::OPT_KEY_TABLE_ID;
+use common_storages_stream::OPT_KEY_TABLE_NAME;
+use common_storages_stream::STREAM_ENGINE;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateStreamInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateStreamPlan,
+}
+
+impl CreateStreamInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateStreamPlan) -> Result<Self> {
+        Ok(CreateStreamInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateStreamInterpreter {
+    fn name(&self) -> &str {
+        "CreateStreamInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let table = self
+            .ctx
+            .get_table(
+                &self.plan.catalog,
+                &self.plan.table_database,
+                &self.plan.table_name,
+            )
+            .await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref()).map_err(|_| {
+            ErrorCode::Unimplemented(format!(
+                "CREATE STREAM on table {}.{} of engine {} is not supported",
+                self.plan.table_database,
+                self.plan.table_name,
+                table.engine()
+            ))
+        })?;
+
+        let source_schema = table.schema();
+        let fields = match &self.plan.columns {
+            Some(columns) => columns
+                .iter()
+                .map(|column| {
+                    source_schema.field_with_name(column).cloned().map_err(|_| {
+                        ErrorCode::UnknownColumn(format!(
+                            "Column {} not found in table {}.{}",
+                            column, self.plan.table_database, self.plan.table_name
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?,
+            None => source_schema.fields().clone(),
+        };
+
+        // The changes are tracked from the current snapshot of the table.
+        let mut options = BTreeMap::new();
+        options.insert(
+            OPT_KEY_DATABASE_NAME.to_string(),
+            self.plan.table_database.clone(),
+        );
+        options.insert(OPT_KEY_TABLE_NAME.to_string(), self.plan.table_name.clone());
+        options.insert(OPT_KEY_TABLE_ID.to_string(), table.get_id().to_string());
+        let mode = if self.plan.append_only {
+            MODE_APPEND_ONLY
+        } else {
+            MODE_STANDARD
+        };
+        options.insert(OPT_KEY_MODE.to_string(), mode.to_string());
+        if let Some(offset) = fuse_table.snapshot_loc().await? {
+            options.insert(OPT_KEY_OFFSET.to_string(), offset);
+        }
+
+        let req = CreateTableReq {
+            if_not_exists: self.plan.if_not_exists,
+            name_ident: TableNameIdent {
+                tenant: self.plan.tenant.clone(),
+                db_name: self.plan.database.clone(),
+                table_name: self.plan.stream_name.clone(),
+            },
+            table_meta: TableMeta {
+                schema: Arc::new(StreamTable::stream_schema(fields)),
+                catalog: self.plan.catalog.clone(),
+                engine: STREAM_ENGINE.to_string(),
+                options,
+                comment: self.plan.comment.clone().unwrap_or_default(),
+                ..Default::default()
+            },
+        };
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        catalog.create_table(req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
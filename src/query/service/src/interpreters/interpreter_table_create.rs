@@ -14,11 +14,13 @@
 
 use std::collections::BTreeMap;
 use std::collections::HashSet;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_expression::TableSchemaRefExt;
 use common_expression::BLOCK_NAME_COL_NAME;
@@ -38,25 +40,39 @@ use common_sql::field_default_value;
 use common_sql::plans::CreateTablePlan;
 use common_sql::plans::PREDICATE_COLUMN_NAME;
 use common_sql::BloomIndexColumns;
+use common_sql::BloomIndexPaths;
 use common_storage::DataOperator;
 use common_storages_fuse::io::MetaReaders;
+use common_storages_fuse::FuseStorageFormat;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::FUSE_OPT_KEY_BLOCK_COMPRESSED_SIZE;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
-use common_storages_fuse::FUSE_TBL_LAST_SNAPSHOT_HINT;
 use common_users::UserApiProvider;
 use log::error;
 use once_cell::sync::Lazy;
+use storages_common_blocks::get_key_management_service;
 use storages_common_cache::LoadParams;
 use storages_common_index::BloomIndex;
+use storages_common_index::InvertedIndex;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::Versioned;
+use storages_common_table_meta::table::ColumnCodecs;
+use storages_common_table_meta::table::EncryptedColumns;
+use storages_common_table_meta::table::TableCompression;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_PATHS;
+use storages_common_table_meta::table::OPT_KEY_COLUMN_COMPRESSION;
+use storages_common_table_meta::table::OPT_KEY_COLUMN_ENCRYPTION;
 use storages_common_table_meta::table::OPT_KEY_COMMENT;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_ENCRYPTION_KEY_ID;
 use storages_common_table_meta::table::OPT_KEY_ENGINE;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
@@ -257,6 +273,7 @@ impl CreateTableInterpreter {
         let mut table_meta = TableMeta {
             schema: schema.clone(),
             engine: self.plan.engine.to_string(),
+            engine_options: self.plan.engine_options.clone(),
             storage_params: self.plan.storage_params.clone(),
             part_prefix: self.plan.part_prefix.clone(),
             options: self.plan.options.clone(),
@@ -273,8 +290,16 @@ impl CreateTableInterpreter {
 
         is_valid_block_per_segment(&table_meta.options)?;
         is_valid_row_per_block(&table_meta.options)?;
+        is_valid_storage_format(&table_meta.options)?;
+        is_valid_table_compression(&table_meta.options)?;
+        is_valid_column_compression(&table_meta.options, &schema)?;
+        is_valid_column_encryption(&table_meta.options, &schema)?;
         // check bloom_index_columns.
-        is_valid_bloom_index_columns(&table_meta.options, schema)?;
+        is_valid_bloom_index_columns(&table_meta.options, schema.clone())?;
+        // check bloom_index_paths.
+        is_valid_bloom_index_paths(&table_meta.options, schema.clone())?;
+        // check inverted_index_columns.
+        is_valid_inverted_index_columns(&table_meta.options, schema)?;
 
         for table_option in table_meta.options.iter() {
             let key = table_option.0.to_lowercase();
@@ -309,12 +334,8 @@ impl CreateTableInterpreter {
         let operator = DataOperator::try_create(sp).await?;
         let operator = operator.operator();
         let reader = MetaReaders::table_snapshot_reader(operator.clone());
-        let hint = format!("{}/{}", storage_prefix, FUSE_TBL_LAST_SNAPSHOT_HINT);
-        let snapshot_loc = operator.read(&hint).await?;
-        let snapshot_loc = String::from_utf8(snapshot_loc)?;
-        let info = operator.info();
-        let root = info.root();
-        let snapshot_loc = snapshot_loc[root.len()..].to_string();
+        let snapshot_loc =
+            FuseTable::read_snapshot_location_hint(&operator, storage_prefix).await?;
         let mut options = self.plan.options.clone();
         options.insert(OPT_KEY_SNAPSHOT_LOCATION.to_string(), snapshot_loc.clone());
 
@@ -377,16 +398,25 @@ pub static CREATE_TABLE_OPTIONS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     r.insert(FUSE_OPT_KEY_BLOCK_PER_SEGMENT);
     r.insert(FUSE_OPT_KEY_ROW_PER_BLOCK);
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
+    r.insert(FUSE_OPT_KEY_BLOCK_COMPRESSED_SIZE);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
 
     r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
+    r.insert(OPT_KEY_BLOOM_INDEX_PATHS);
+    r.insert(OPT_KEY_INVERTED_INDEX_COLUMNS);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
+    r.insert(OPT_KEY_COLUMN_COMPRESSION);
+    r.insert(OPT_KEY_COLUMN_ENCRYPTION);
+    r.insert(OPT_KEY_ENCRYPTION_KEY_ID);
     r.insert(OPT_KEY_STORAGE_FORMAT);
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_COMMENT);
 
     r.insert(OPT_KEY_ENGINE);
 
+    // Reserved, only set by `CREATE MATERIALIZED VIEW`.
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+
     r.insert("transient");
     r
 });
@@ -448,6 +478,73 @@ pub fn is_valid_row_per_block(options: &BTreeMap<String, String>) -> Result<()>
     Ok(())
 }
 
+pub fn is_valid_storage_format(options: &BTreeMap<String, String>) -> Result<()> {
+    // check storage_format is either parquet or native.
+    if let Some(value) = options.get(OPT_KEY_STORAGE_FORMAT) {
+        if let Err(e) = FuseStorageFormat::from_str(value) {
+            error!("{}", e.message());
+            return Err(ErrorCode::TableOptionInvalid(e.message()));
+        }
+    }
+    Ok(())
+}
+
+pub fn is_valid_table_compression(options: &BTreeMap<String, String>) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_TABLE_COMPRESSION) {
+        if let Err(e) = TableCompression::try_from(value.as_str()) {
+            error!("{}", e.message());
+            return Err(ErrorCode::TableOptionInvalid(e.message()));
+        }
+    }
+    Ok(())
+}
+
+pub fn is_valid_column_compression(
+    options: &BTreeMap<String, String>,
+    schema: &TableSchema,
+) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_COLUMN_COMPRESSION) {
+        ColumnCodecs::try_from(value.as_str())?.verify(schema)?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_column_encryption(
+    options: &BTreeMap<String, String>,
+    schema: &TableSchema,
+) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_COLUMN_ENCRYPTION) {
+        let encrypted_columns = EncryptedColumns::try_from(value.as_str())?;
+        encrypted_columns.verify(schema)?;
+        if !encrypted_columns.is_empty() {
+            if options
+                .get(OPT_KEY_STORAGE_FORMAT)
+                .is_some_and(|format| format.eq_ignore_ascii_case("native"))
+            {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "{} is not supported by the native storage format",
+                    OPT_KEY_COLUMN_ENCRYPTION
+                )));
+            }
+            if !options.contains_key(OPT_KEY_ENCRYPTION_KEY_ID) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "{} is required by {}",
+                    OPT_KEY_ENCRYPTION_KEY_ID, OPT_KEY_COLUMN_ENCRYPTION
+                )));
+            }
+        }
+    }
+    is_valid_encryption_key_id(options)
+}
+
+pub fn is_valid_encryption_key_id(options: &BTreeMap<String, String>) -> Result<()> {
+    if let Some(key_id) = options.get(OPT_KEY_ENCRYPTION_KEY_ID) {
+        // make sure the data key can be resolved before any block is written with it.
+        get_key_management_service().get_data_key(key_id)?;
+    }
+    Ok(())
+}
+
 pub fn is_valid_bloom_index_columns(
     options: &BTreeMap<String, String>,
     schema: TableSchemaRef,
@@ -457,3 +554,23 @@ pub fn is_valid_bloom_index_columns(
     }
     Ok(())
 }
+
+pub fn is_valid_bloom_index_paths(
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_BLOOM_INDEX_PATHS) {
+        BloomIndexPaths::verify_definition(value, schema, BloomIndex::supported_type)?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_inverted_index_columns(
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_INVERTED_INDEX_COLUMNS) {
+        BloomIndexColumns::verify_definition(value, schema, InvertedIndex::supported_type)?;
+    }
+    Ok(())
+}
@@ -21,6 +21,7 @@ use common_meta_app::schema::DropTableByIdReq;
 use common_sql::plans::DropTablePlan;
 use common_storages_fuse::FuseTable;
 use common_storages_share::save_share_spec;
+use common_storages_stream::STREAM_ENGINE;
 use common_storages_view::view_table::VIEW_ENGINE;
 
 use crate::interpreters::Interpreter;
@@ -69,6 +70,12 @@ impl Interpreter for DropTableInterpreter {
                     &self.plan.database, &self.plan.table, &self.plan.database, &self.plan.table
                 )));
             }
+            if tbl.get_table_info().engine() == STREAM_ENGINE {
+                return Err(ErrorCode::TableEngineNotSupported(format!(
+                    "{}.{} engine is STREAM that doesn't support drop, use `DROP STREAM {}.{}` instead",
+                    &self.plan.database, &self.plan.table, &self.plan.database, &self.plan.table
+                )));
+            }
             let catalog = self.ctx.get_catalog(catalog_name).await?;
 
             // Although even if data is in READ_ONLY mode,
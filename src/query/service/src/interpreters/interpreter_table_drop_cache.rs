@@ -0,0 +1,56 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_sql::plans::DropTableCachePlan;
+use common_storages_fuse::FuseTable;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropTableCacheInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropTableCachePlan,
+}
+
+impl DropTableCacheInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropTableCachePlan) -> Result<Self> {
+        Ok(DropTableCacheInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropTableCacheInterpreter {
+    fn name(&self) -> &str {
+        "DropTableCacheInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let table = self
+            .ctx
+            .get_table(&self.plan.catalog, &self.plan.database, &self.plan.table)
+            .await?;
+
+        // Only fuse tables keep their metas in the caches of the node.
+        if let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) {
+            fuse_table.evict_caches().await?;
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
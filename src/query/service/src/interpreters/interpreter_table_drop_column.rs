@@ -23,10 +23,14 @@ use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_types::MatchSeq;
 use common_sql::plans::DropTableColumnPlan;
 use common_sql::BloomIndexColumns;
+use common_sql::BloomIndexPaths;
 use common_storages_share::save_share_table_info;
 use common_storages_view::view_table::VIEW_ENGINE;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_PATHS;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_COLUMNS;
 
+use crate::interpreters::common::check_referenced_cluster_key;
 use crate::interpreters::common::check_referenced_computed_columns;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -91,22 +95,34 @@ impl Interpreter for DropTableColumnInterpreter {
             )?;
         }
 
+        // Check if this column is referenced by the cluster key.
+        if let Some(cluster_key) = &table_info.meta.default_cluster_key {
+            check_referenced_cluster_key(cluster_key, &self.plan.column)?;
+        }
+
         let catalog = self.ctx.get_catalog(catalog_name).await?;
         let mut new_table_meta = table.get_table_info().meta.clone();
         new_table_meta.drop_column(&self.plan.column)?;
 
         // update table options
         let opts = &mut new_table_meta.options;
-        if let Some(value) = opts.get_mut(OPT_KEY_BLOOM_INDEX_COLUMNS) {
-            let bloom_index_cols = value.parse::<BloomIndexColumns>()?;
-            if let BloomIndexColumns::Specify(mut cols) = bloom_index_cols {
-                if let Some(pos) = cols.iter().position(|x| *x == self.plan.column) {
-                    // remove from the bloom index columns.
-                    cols.remove(pos);
-                    *value = cols.join(",");
+        for key in [OPT_KEY_BLOOM_INDEX_COLUMNS, OPT_KEY_INVERTED_INDEX_COLUMNS] {
+            if let Some(value) = opts.get_mut(key) {
+                let index_cols = value.parse::<BloomIndexColumns>()?;
+                if let BloomIndexColumns::Specify(mut cols) = index_cols {
+                    if let Some(pos) = cols.iter().position(|x| *x == self.plan.column) {
+                        // remove from the index columns.
+                        cols.remove(pos);
+                        *value = cols.join(",");
+                    }
                 }
             }
         }
+        if let Some(value) = opts.get_mut(OPT_KEY_BLOOM_INDEX_PATHS) {
+            let mut index_paths = value.parse::<BloomIndexPaths>()?;
+            index_paths.drop_column(&self.plan.column);
+            *value = index_paths.to_string();
+        }
 
         let table_id = table_info.ident.table_id;
         let table_version = table_info.ident.seq;
@@ -38,6 +38,8 @@ use common_storages_factory::NavigationPoint;
 use common_storages_fuse::FuseTable;
 use storages_common_table_meta::meta::TableSnapshot;
 
+use crate::interpreters::common::hook_refresh_agg_index;
+use crate::interpreters::common::RefreshAggIndexDesc;
 use crate::interpreters::interpreter_table_recluster::build_recluster_physical_plan;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterClusteringHistory;
@@ -194,8 +196,7 @@ impl OptimizeTableInterpreter {
             if !compact_pipeline.is_empty() {
                 compact_pipeline.set_max_threads(settings.get_max_threads()? as usize);
 
-                let query_id = self.ctx.get_id();
-                let executor_settings = ExecutorSettings::try_create(&settings, query_id)?;
+                let executor_settings = ExecutorSettings::try_create(&self.ctx)?;
                 let executor =
                     PipelineCompleteExecutor::try_create(compact_pipeline, executor_settings)?;
 
@@ -215,6 +216,7 @@ impl OptimizeTableInterpreter {
             {
                 if !mutator.tasks.is_empty() {
                     let reclustered_block_count = mutator.recluster_blocks_count;
+                    let recluster_progress = mutator.recluster_progress();
                     let physical_plan = build_recluster_physical_plan(
                         mutator.tasks,
                         table.get_table_info().clone(),
@@ -223,6 +225,7 @@ impl OptimizeTableInterpreter {
                         mutator.remained_blocks,
                         mutator.removed_segment_indexes,
                         mutator.removed_segment_summary,
+                        recluster_progress,
                     )?;
 
                     build_res = build_query_pipeline_without_render_result_set(
@@ -253,6 +256,23 @@ impl OptimizeTableInterpreter {
             build_res.main_pipeline = compact_pipeline;
         }
 
+        // The compacted blocks are written to new locations, the aggregating indexes of
+        // them need to be generated again.
+        {
+            let refresh_agg_index_desc = RefreshAggIndexDesc {
+                catalog: self.plan.catalog.clone(),
+                database: self.plan.database.clone(),
+                table: self.plan.table.clone(),
+            };
+
+            hook_refresh_agg_index(
+                self.ctx.clone(),
+                &mut build_res.main_pipeline,
+                refresh_agg_index_desc,
+            )
+            .await?;
+        }
+
         let ctx = self.ctx.clone();
         let plan = self.plan.clone();
         if need_purge {
@@ -36,6 +36,7 @@ use log::error;
 use log::info;
 use log::warn;
 use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::ReclusterProgress;
 use storages_common_table_meta::meta::Statistics;
 use storages_common_table_meta::meta::TableSnapshot;
 
@@ -154,6 +155,7 @@ impl Interpreter for ReclusterTableInterpreter {
                 break;
             };
             block_count += mutator.recluster_blocks_count;
+            let recluster_progress = mutator.recluster_progress();
             let physical_plan = build_recluster_physical_plan(
                 mutator.tasks,
                 table.get_table_info().clone(),
@@ -162,6 +164,7 @@ impl Interpreter for ReclusterTableInterpreter {
                 mutator.remained_blocks,
                 mutator.removed_segment_indexes,
                 mutator.removed_segment_summary,
+                recluster_progress,
             )?;
 
             let mut build_res =
@@ -170,8 +173,7 @@ impl Interpreter for ReclusterTableInterpreter {
             assert!(build_res.main_pipeline.is_complete_pipeline()?);
             build_res.set_max_threads(max_threads);
 
-            let query_id = ctx.get_id();
-            let executor_settings = ExecutorSettings::try_create(&settings, query_id)?;
+            let executor_settings = ExecutorSettings::try_create(&ctx)?;
 
             let mut pipelines = build_res.sources_pipelines;
             pipelines.push(build_res.main_pipeline);
@@ -226,6 +228,7 @@ impl Interpreter for ReclusterTableInterpreter {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn build_recluster_physical_plan(
     tasks: Vec<ReclusterTask>,
     table_info: TableInfo,
@@ -234,6 +237,7 @@ pub fn build_recluster_physical_plan(
     remained_blocks: Vec<Arc<BlockMeta>>,
     removed_segment_indexes: Vec<usize>,
     removed_segment_summary: Statistics,
+    recluster_progress: ReclusterProgress,
 ) -> Result<PhysicalPlan> {
     let is_distributed = tasks.len() > 1;
     let mut root = PhysicalPlan::ReclusterSource(Box::new(ReclusterSource {
@@ -260,5 +264,6 @@ pub fn build_recluster_physical_plan(
         remained_blocks,
         removed_segment_indexes,
         removed_segment_summary,
+        recluster_progress,
     })))
 }
@@ -23,11 +23,15 @@ use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_types::MatchSeq;
 use common_sql::plans::RenameTableColumnPlan;
 use common_sql::BloomIndexColumns;
+use common_sql::BloomIndexPaths;
 use common_storages_share::save_share_table_info;
 use common_storages_view::view_table::VIEW_ENGINE;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_PATHS;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::rename_column_in_cluster_key;
 use crate::interpreters::interpreter_table_create::is_valid_column;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -102,18 +106,38 @@ impl Interpreter for RenameTableColumnInterpreter {
 
             new_table_meta.schema = Arc::new(self.plan.schema.clone());
 
+            // The cluster key refers to columns by name, rewrite it in place.
+            // The semantic of the key is unchanged, so blocks clustered by it stay valid.
+            if let Some((cluster_key_id, cluster_key)) = new_table_meta.cluster_key() {
+                if let Some(cluster_key) = rename_column_in_cluster_key(
+                    &cluster_key,
+                    &self.plan.old_column,
+                    &self.plan.new_column,
+                )? {
+                    new_table_meta.cluster_keys[cluster_key_id as usize] = cluster_key.clone();
+                    new_table_meta.default_cluster_key = Some(cluster_key);
+                }
+            }
+
             // update table options
             let opts = &mut new_table_meta.options;
-            if let Some(value) = opts.get_mut(OPT_KEY_BLOOM_INDEX_COLUMNS) {
-                let bloom_index_cols = value.parse::<BloomIndexColumns>()?;
-                if let BloomIndexColumns::Specify(mut cols) = bloom_index_cols {
-                    if let Some(pos) = cols.iter().position(|x| *x == self.plan.old_column) {
-                        // replace the bloom index columns with new column name.
-                        cols[pos] = self.plan.new_column.clone();
-                        *value = cols.join(",");
+            for key in [OPT_KEY_BLOOM_INDEX_COLUMNS, OPT_KEY_INVERTED_INDEX_COLUMNS] {
+                if let Some(value) = opts.get_mut(key) {
+                    let index_cols = value.parse::<BloomIndexColumns>()?;
+                    if let BloomIndexColumns::Specify(mut cols) = index_cols {
+                        if let Some(pos) = cols.iter().position(|x| *x == self.plan.old_column) {
+                            // replace the index columns with new column name.
+                            cols[pos] = self.plan.new_column.clone();
+                            *value = cols.join(",");
+                        }
                     }
                 }
             }
+            if let Some(value) = opts.get_mut(OPT_KEY_BLOOM_INDEX_PATHS) {
+                let mut index_paths = value.parse::<BloomIndexPaths>()?;
+                index_paths.rename_column(&self.plan.old_column, &self.plan.new_column);
+                *value = index_paths.to_string();
+            }
 
             let table_id = table_info.ident.table_id;
             let table_version = table_info.ident.seq;
@@ -23,13 +23,19 @@ use common_meta_types::MatchSeq;
 use common_sql::plans::SetOptionsPlan;
 use common_storages_fuse::TableContext;
 use log::error;
+use storages_common_table_meta::table::OPT_KEY_COLUMN_ENCRYPTION;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 
 use super::interpreter_table_create::is_valid_block_per_segment;
 use super::interpreter_table_create::is_valid_bloom_index_columns;
+use super::interpreter_table_create::is_valid_bloom_index_paths;
+use super::interpreter_table_create::is_valid_column_compression;
 use super::interpreter_table_create::is_valid_create_opt;
+use super::interpreter_table_create::is_valid_encryption_key_id;
+use super::interpreter_table_create::is_valid_inverted_index_columns;
 use super::interpreter_table_create::is_valid_row_per_block;
+use super::interpreter_table_create::is_valid_table_compression;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -59,6 +65,10 @@ impl Interpreter for SetOptionsInterpreter {
         is_valid_block_per_segment(&self.plan.set_options)?;
         // check row_per_block
         is_valid_row_per_block(&self.plan.set_options)?;
+        // check compression
+        is_valid_table_compression(&self.plan.set_options)?;
+        // check encryption_key_id, the blocks written later are encrypted by the new key.
+        is_valid_encryption_key_id(&self.plan.set_options)?;
         // check storage_format
         let error_str = "invalid opt for fuse table in alter table statement";
         if self.plan.set_options.get(OPT_KEY_STORAGE_FORMAT).is_some() {
@@ -68,6 +78,20 @@ impl Interpreter for SetOptionsInterpreter {
                 OPT_KEY_STORAGE_FORMAT
             )));
         }
+        // the statistics of the encrypted columns are not kept, the blocks written before and
+        // after changing the encrypted columns can't be mixed.
+        if self
+            .plan
+            .set_options
+            .get(OPT_KEY_COLUMN_ENCRYPTION)
+            .is_some()
+        {
+            error!("{}", &error_str);
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "can't change {} for alter table statement",
+                OPT_KEY_COLUMN_ENCRYPTION
+            )));
+        }
         if self.plan.set_options.get(OPT_KEY_DATABASE_ID).is_some() {
             error!("{}", &error_str);
             return Err(ErrorCode::TableOptionInvalid(format!(
@@ -106,8 +130,14 @@ impl Interpreter for SetOptionsInterpreter {
             )));
         };
 
+        // check column_compression.
+        is_valid_column_compression(&self.plan.set_options, &table.schema())?;
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&self.plan.set_options, table.schema())?;
+        // check bloom_index_paths.
+        is_valid_bloom_index_paths(&self.plan.set_options, table.schema())?;
+        // check inverted_index_columns.
+        is_valid_inverted_index_columns(&self.plan.set_options, table.schema())?;
 
         let req = UpsertTableOptionReq {
             table_id: table.get_id(),
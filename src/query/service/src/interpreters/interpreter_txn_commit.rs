@@ -0,0 +1,77 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use log::info;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CommitInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl CommitInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(CommitInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CommitInterpreter {
+    fn name(&self) -> &str {
+        "CommitInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let txn_mgr = self.ctx.txn_mgr();
+        let (is_fail, req) = {
+            let txn_mgr = txn_mgr.lock();
+            (txn_mgr.is_fail(), txn_mgr.req())
+        };
+        if is_fail {
+            txn_mgr.lock().clear();
+            return Err(ErrorCode::CurrentTransactionIsAborted(
+                "current transaction is aborted, it has been rolled back",
+            ));
+        }
+
+        if req.update_table_metas.is_empty() {
+            txn_mgr.lock().clear();
+            return Ok(PipelineBuildResult::create());
+        }
+
+        // All the table metas staged in the transaction are published in one meta transaction,
+        // it fails if any of the tables has been changed by others since the transaction read it.
+        let catalog = self.ctx.get_default_catalog()?;
+        let table_ids = req
+            .update_table_metas
+            .iter()
+            .map(|req| req.table_id)
+            .collect::<Vec<_>>();
+        let res = catalog.update_multi_table_meta(req).await;
+        // The transaction ends whether it is committed or not.
+        txn_mgr.lock().clear();
+        res?;
+        info!("transaction committed, mutated table ids: {:?}", table_ids);
+
+        Ok(PipelineBuildResult::create())
+    }
+}
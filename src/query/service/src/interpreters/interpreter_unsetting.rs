@@ -81,14 +81,13 @@ impl Interpreter for UnSettingInterpreter {
                 }
             };
             if ok {
-                // reset the current ctx settings to default val
-                self.ctx
-                    .get_shard_settings()
-                    .set_setting(var.clone(), value.clone())?;
+                // the change of the current ctx settings is dropped with the global setting,
+                // so it falls back to the default level.
                 // set affect
                 keys.push(var);
                 values.push(value);
-                is_globals.push(false);
+                // the global setting is dropped as well.
+                is_globals.push(true);
             }
         }
         self.ctx.set_affect(QueryAffect::ChangeSettings {
@@ -91,7 +91,7 @@ impl Interpreter for UpdateInterpreter {
             let support_row_id = tbl.support_row_id_column();
             if !support_row_id {
                 return Err(ErrorCode::from_string(
-                    "table doesn't support row_id, so it can't use delete with subquery"
+                    "table doesn't support row_id, so it can't use update with subquery"
                         .to_string(),
                 ));
             }
@@ -82,7 +82,7 @@ impl Interpreter for RefreshVirtualColumnInterpreter {
         let fuse_table = FuseTable::try_from_table(table.as_ref())?;
 
         let _ = handler
-            .do_refresh_virtual_column(fuse_table, self.ctx.clone(), virtual_columns)
+            .do_refresh_virtual_column(fuse_table, self.ctx.clone(), virtual_columns, None)
             .await?;
 
         Ok(PipelineBuildResult::create())
@@ -15,6 +15,8 @@
 mod access;
 mod common;
 mod interpreter;
+mod interpreter_bloom_index_create;
+mod interpreter_bloom_index_drop;
 mod interpreter_catalog_create;
 mod interpreter_catalog_drop;
 mod interpreter_catalog_show_create;
@@ -41,7 +43,10 @@ mod interpreter_index_create;
 mod interpreter_index_drop;
 mod interpreter_index_refresh;
 mod interpreter_insert;
+mod interpreter_insert_multi_table;
 mod interpreter_kill;
+mod interpreter_materialized_view_create;
+mod interpreter_materialized_view_refresh;
 mod interpreter_merge_into;
 mod interpreter_metrics;
 mod interpreter_network_policies_show;
@@ -52,6 +57,7 @@ mod interpreter_network_policy_drop;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
+mod interpreter_query_cache_flush;
 mod interpreter_replace;
 mod interpreter_role_create;
 mod interpreter_role_drop;
@@ -59,7 +65,13 @@ mod interpreter_role_grant;
 mod interpreter_role_revoke;
 mod interpreter_role_set;
 mod interpreter_role_show;
+mod interpreter_row_access_policy_create;
+mod interpreter_row_access_policy_desc;
+mod interpreter_row_access_policy_drop;
 mod interpreter_select;
+mod interpreter_sequence_create;
+mod interpreter_sequence_drop;
+mod interpreter_set_user_variable;
 mod interpreter_setting;
 mod interpreter_share_alter_tenants;
 mod interpreter_share_create;
@@ -74,11 +86,14 @@ mod interpreter_share_show;
 mod interpreter_share_show_grant_tenants;
 mod interpreter_show_grants;
 mod interpreter_show_object_grant_privileges;
+mod interpreter_stream_create;
+mod interpreter_stream_drop;
 mod interpreter_table_add_column;
 mod interpreter_table_analyze;
 mod interpreter_table_create;
 mod interpreter_table_describe;
 mod interpreter_table_drop;
+mod interpreter_table_drop_cache;
 mod interpreter_table_drop_column;
 mod interpreter_table_exists;
 mod interpreter_table_modify_column;
@@ -87,6 +102,7 @@ mod interpreter_table_recluster;
 mod interpreter_table_rename;
 mod interpreter_table_rename_column;
 mod interpreter_table_revert;
+mod interpreter_table_row_access_policy;
 mod interpreter_table_set_options;
 mod interpreter_table_show_create;
 mod interpreter_table_truncate;
@@ -98,6 +114,9 @@ mod interpreter_task_describe;
 mod interpreter_task_drop;
 mod interpreter_task_execute;
 mod interpreter_tasks_show;
+mod interpreter_txn_abort;
+mod interpreter_txn_begin;
+mod interpreter_txn_commit;
 mod interpreter_unsetting;
 mod interpreter_update;
 mod interpreter_use_database;
@@ -120,9 +139,18 @@ mod interpreter_virtual_column_drop;
 mod interpreter_virtual_column_refresh;
 
 pub use access::ManagementModeAccess;
+pub use common::build_otlp_logs_request;
+pub use common::create_query_log_sink;
+pub use common::encode_ndjson;
+pub use common::AsyncInsertQueue;
 pub use common::InterpreterQueryLog;
+pub use common::OtlpLogRecord;
+pub use common::QueryLogPersister;
+pub use common::QueryLogSink;
 pub use interpreter::Interpreter;
 pub use interpreter::InterpreterPtr;
+pub use interpreter_bloom_index_create::CreateBloomIndexInterpreter;
+pub use interpreter_bloom_index_drop::DropBloomIndexInterpreter;
 pub use interpreter_cluster_key_alter::AlterTableClusterKeyInterpreter;
 pub use interpreter_cluster_key_drop::DropTableClusterKeyInterpreter;
 pub use interpreter_clustering_history::InterpreterClusteringHistory;
@@ -139,7 +167,10 @@ pub use interpreter_explain::ExplainInterpreter;
 pub use interpreter_factory::InterpreterFactory;
 pub use interpreter_index_refresh::RefreshIndexInterpreter;
 pub use interpreter_insert::InsertInterpreter;
+pub use interpreter_insert_multi_table::InsertMultiTableInterpreter;
 pub use interpreter_kill::KillInterpreter;
+pub use interpreter_materialized_view_create::CreateMaterializedViewInterpreter;
+pub use interpreter_materialized_view_refresh::RefreshMaterializedViewInterpreter;
 pub use interpreter_metrics::InterpreterMetrics;
 pub use interpreter_network_policies_show::ShowNetworkPoliciesInterpreter;
 pub use interpreter_network_policy_alter::AlterNetworkPolicyInterpreter;
@@ -148,13 +179,20 @@ pub use interpreter_network_policy_desc::DescNetworkPolicyInterpreter;
 pub use interpreter_network_policy_drop::DropNetworkPolicyInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
+pub use interpreter_query_cache_flush::FlushQueryCacheInterpreter;
 pub use interpreter_replace::ReplaceInterpreter;
 pub use interpreter_role_create::CreateRoleInterpreter;
 pub use interpreter_role_drop::DropRoleInterpreter;
 pub use interpreter_role_grant::GrantRoleInterpreter;
 pub use interpreter_role_revoke::RevokeRoleInterpreter;
 pub use interpreter_role_set::SetRoleInterpreter;
+pub use interpreter_row_access_policy_create::CreateRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_desc::DescRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_drop::DropRowAccessPolicyInterpreter;
 pub use interpreter_select::SelectInterpreter;
+pub use interpreter_sequence_create::CreateSequenceInterpreter;
+pub use interpreter_sequence_drop::DropSequenceInterpreter;
+pub use interpreter_set_user_variable::SetUserVariableInterpreter;
 pub use interpreter_setting::SettingInterpreter;
 pub use interpreter_share_alter_tenants::AlterShareTenantsInterpreter;
 pub use interpreter_share_create::CreateShareInterpreter;
@@ -168,11 +206,14 @@ pub use interpreter_share_show::ShowSharesInterpreter;
 pub use interpreter_share_show_grant_tenants::ShowGrantTenantsOfShareInterpreter;
 pub use interpreter_show_grants::ShowGrantsInterpreter;
 pub use interpreter_show_object_grant_privileges::ShowObjectGrantPrivilegesInterpreter;
+pub use interpreter_stream_create::CreateStreamInterpreter;
+pub use interpreter_stream_drop::DropStreamInterpreter;
 pub use interpreter_table_add_column::AddTableColumnInterpreter;
 pub use interpreter_table_analyze::AnalyzeTableInterpreter;
 pub use interpreter_table_create::CreateTableInterpreter;
 pub use interpreter_table_describe::DescribeTableInterpreter;
 pub use interpreter_table_drop::DropTableInterpreter;
+pub use interpreter_table_drop_cache::DropTableCacheInterpreter;
 pub use interpreter_table_drop_column::DropTableColumnInterpreter;
 pub use interpreter_table_exists::ExistsTableInterpreter;
 pub use interpreter_table_modify_column::ModifyTableColumnInterpreter;
@@ -180,10 +221,15 @@ pub use interpreter_table_optimize::OptimizeTableInterpreter;
 pub use interpreter_table_recluster::ReclusterTableInterpreter;
 pub use interpreter_table_rename::RenameTableInterpreter;
 pub use interpreter_table_rename_column::RenameTableColumnInterpreter;
+pub use interpreter_table_row_access_policy::AddTableRowAccessPolicyInterpreter;
+pub use interpreter_table_row_access_policy::DropTableRowAccessPolicyInterpreter;
 pub use interpreter_table_show_create::ShowCreateTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
 pub use interpreter_table_undrop::UndropTableInterpreter;
 pub use interpreter_table_vacuum::VacuumTableInterpreter;
+pub use interpreter_txn_abort::AbortInterpreter;
+pub use interpreter_txn_begin::BeginInterpreter;
+pub use interpreter_txn_commit::CommitInterpreter;
 pub use interpreter_unsetting::UnSettingInterpreter;
 pub use interpreter_update::UpdateInterpreter;
 pub use interpreter_use_database::UseDatabaseInterpreter;
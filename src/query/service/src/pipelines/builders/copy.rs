@@ -32,15 +32,18 @@ use common_pipeline_core::Pipeline;
 use common_sql::executor::CopyIntoTablePhysicalPlan;
 use common_sql::plans::CopyIntoTableMode;
 use common_sql::plans::CopyIntoTablePlan;
+use common_storage::init_stage_operator;
 use common_storage::metrics::copy::metrics_inc_copy_purge_files_cost_milliseconds;
 use common_storage::metrics::copy::metrics_inc_copy_purge_files_counter;
 use common_storage::StageFileInfo;
 use common_storages_fuse::io::Files;
+use common_storages_fuse::FuseTable;
 use common_storages_stage::StageTable;
 use log::debug;
 use log::error;
 use log::info;
 
+use crate::interpreters::encode_ndjson;
 use crate::pipelines::builders::build_append2table_without_commit_pipeline;
 use crate::pipelines::processors::transforms::TransformAddConstColumns;
 use crate::pipelines::processors::TransformCastSchema;
@@ -127,6 +130,13 @@ pub async fn build_commit_data_pipeline(
         plan.force,
     )?;
 
+    // merge the small blocks written by the copy before committing them.
+    if ctx.get_settings().get_enable_copy_merge_small_blocks()? {
+        if let Ok(fuse_table) = FuseTable::try_from_table(to_table.as_ref()) {
+            fuse_table.coalesce_appended_blocks(ctx.clone(), main_pipeline)?;
+        }
+    }
+
     to_table.commit_insertion(
         ctx.clone(),
         main_pipeline,
@@ -158,7 +168,6 @@ pub fn set_copy_on_finished(
             None => {
                 GlobalIORuntime::instance().block_on(async move {
                     // 1. log on_error mode errors.
-                    // todo(ariesdevil): persist errors with query_id
                     if let Some(error_map) = ctx.get_maximum_error_per_file() {
                         for (file_name, e) in error_map {
                             error!(
@@ -169,6 +178,10 @@ pub fn set_copy_on_finished(
                             );
                         }
                     }
+                    // and persist the skipped rows with query_id, if asked to.
+                    if let Err(e) = write_copy_error_log(&ctx).await {
+                        error!("copy: fail to write the error log: {}", e);
+                    }
 
                     // 2. Try to purge copied files if purge option is true, if error will skip.
                     // If a file is already copied(status with AlreadyCopied) we will try to purge them.
@@ -197,6 +210,27 @@ pub fn set_copy_on_finished(
     Ok(())
 }
 
+/// Writes the rows skipped by `ON_ERROR` into `<copy_error_log_path>/<query_id>.ndjson`
+/// of the user stage, one JSON object with file, line and error per row.
+async fn write_copy_error_log(ctx: &Arc<QueryContext>) -> Result<()> {
+    let path = ctx.get_settings().get_copy_error_log_path()?;
+    let path = path.trim_matches('/');
+    if path.is_empty() {
+        return Ok(());
+    }
+    let records = ctx.get_copy_status().error_records();
+    if records.is_empty() {
+        return Ok(());
+    }
+
+    let user_stage = StageInfo::new_user_stage(&ctx.get_current_user()?.name);
+    let op = init_stage_operator(&user_stage)?;
+    let file = format!("{path}/{}.ndjson", ctx.get_id());
+    op.write(&file, encode_ndjson(&records)?).await?;
+    info!("copy: wrote {} skipped rows to @~/{}", records.len(), file);
+    Ok(())
+}
+
 pub fn build_upsert_copied_files_to_meta_req(
     ctx: Arc<QueryContext>,
     to_table: &dyn Table,
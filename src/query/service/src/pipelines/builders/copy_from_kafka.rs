@@ -0,0 +1,200 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::table::AppendMode;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_formats::FileFormatOptionsExt;
+use common_meta_app::schema::GetTableCopiedFileReq;
+use common_meta_app::schema::TableCopiedFileInfo;
+use common_meta_app::schema::UpsertTableCopiedFileReq;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::Pipeline;
+use common_pipeline_core::SourcePipeBuilder;
+use common_sql::plans::CopyIntoTablePlan;
+use common_storages_kafka::commit_offsets;
+use common_storages_kafka::fetch_partition_ranges;
+use common_storages_kafka::KafkaInfo;
+use common_storages_kafka::KafkaMessageDecoder;
+use common_storages_kafka::KafkaPartitionRange;
+use common_storages_kafka::KafkaSource;
+use log::error;
+use log::info;
+use log::warn;
+
+use crate::pipelines::builders::build_append2table_without_commit_pipeline;
+use crate::sessions::QueryContext;
+
+/// Collect the messages of each partition to copy into the table.
+///
+/// The consumed ranges are committed into the copied files of the table together with
+/// the table snapshot, keyed by the start offsets, and the offsets of the consumer group
+/// are committed after that. If the copy failed between the two commits, the ranges
+/// starting from the committed offsets of the group are found in the copied files, they
+/// are skipped here so the messages are not loaded twice.
+#[async_backtrace::framed]
+pub async fn collect_kafka_ranges(
+    ctx: &Arc<QueryContext>,
+    plan: &CopyIntoTablePlan,
+    kafka_info: &KafkaInfo,
+) -> Result<Vec<KafkaPartitionRange>> {
+    ctx.set_status_info("begin to fetch kafka offsets");
+    let mut ranges = fetch_partition_ranges(kafka_info).await?;
+
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_catalog(plan.catalog_info.catalog_name()).await?;
+    let table = catalog
+        .get_table(&tenant, &plan.database_name, &plan.table_name)
+        .await?;
+    let table_id = table.get_id();
+    loop {
+        let files = ranges
+            .iter()
+            .filter(|r| !r.is_empty())
+            .map(|r| kafka_info.copied_key(r.partition, r.start))
+            .collect::<Vec<_>>();
+        if files.is_empty() {
+            break;
+        }
+        let copied = catalog
+            .get_table_copied_file_info(&tenant, &plan.database_name, GetTableCopiedFileReq {
+                table_id,
+                files,
+            })
+            .await?
+            .file_info;
+        if copied.is_empty() {
+            break;
+        }
+        for range in ranges.iter_mut() {
+            let key = kafka_info.copied_key(range.partition, range.start);
+            let end = copied
+                .get(&key)
+                .and_then(|info| info.etag.as_ref())
+                .and_then(|etag| etag.parse::<i64>().ok());
+            if let Some(end) = end {
+                info!(
+                    "kafka messages {} until offset {} are already copied, skip them",
+                    key, end
+                );
+                range.start = end.min(range.end);
+            }
+        }
+    }
+
+    ctx.set_status_info(&format!("end fetch kafka offsets: {:?}", ranges));
+    Ok(ranges)
+}
+
+pub async fn build_copy_from_kafka_pipeline(
+    ctx: &Arc<QueryContext>,
+    main_pipeline: &mut Pipeline,
+    plan: &CopyIntoTablePlan,
+    kafka_info: &KafkaInfo,
+    ranges: Vec<KafkaPartitionRange>,
+) -> Result<()> {
+    let to_table = ctx
+        .get_table(
+            plan.catalog_info.catalog_name(),
+            &plan.database_name,
+            &plan.table_name,
+        )
+        .await?;
+    let ranges = ranges
+        .into_iter()
+        .filter(|r| !r.is_empty())
+        .collect::<Vec<_>>();
+
+    let settings = ctx.get_settings();
+    let options = FileFormatOptionsExt::create_from_settings(&settings, false)?;
+    let decoder = Arc::new(KafkaMessageDecoder::try_create(
+        &kafka_info.format,
+        plan.stage_table_info.schema.clone(),
+        plan.stage_table_info.default_values.clone(),
+        &options,
+    )?);
+    let mut source_builder = SourcePipeBuilder::create();
+    for range in &ranges {
+        let output = OutputPort::create();
+        source_builder.add_source(
+            output.clone(),
+            KafkaSource::create(
+                ctx.clone(),
+                output,
+                kafka_info.clone(),
+                range.clone(),
+                decoder.clone(),
+            )?,
+        );
+    }
+    main_pipeline.add_pipe(source_builder.finalize());
+
+    build_append2table_without_commit_pipeline(
+        ctx.clone(),
+        main_pipeline,
+        to_table.clone(),
+        plan.required_values_schema.clone(),
+        AppendMode::Copy,
+    )?;
+
+    // The ranges are always recorded (even in force mode), a concurrent copy of the same
+    // messages fails to commit because of the duplicated keys.
+    let expire_hours = settings.get_load_file_metadata_expire_hours()?;
+    let mut file_info = BTreeMap::new();
+    for range in &ranges {
+        file_info.insert(
+            kafka_info.copied_key(range.partition, range.start),
+            TableCopiedFileInfo {
+                etag: Some(range.end.to_string()),
+                content_length: (range.end - range.start) as u64,
+                last_modified: Some(Utc::now()),
+            },
+        );
+    }
+    let copied_files_meta_req = UpsertTableCopiedFileReq {
+        file_info,
+        expire_at: Some(expire_hours * 60 * 60 + Utc::now().timestamp() as u64),
+        fail_if_duplicated: true,
+    };
+    to_table.commit_insertion(
+        ctx.clone(),
+        main_pipeline,
+        Some(copied_files_meta_req),
+        false,
+        None,
+    )?;
+
+    let kafka_info = kafka_info.clone();
+    main_pipeline.set_on_finished(move |may_error| {
+        match may_error {
+            None => {
+                // The data is committed, a failure here is recovered by the next copy.
+                let result = GlobalIORuntime::instance()
+                    .block_on(async move { commit_offsets(&kafka_info, &ranges).await });
+                if let Err(e) = result {
+                    warn!("copy from kafka: fail to commit offsets, {}", e);
+                }
+            }
+            Some(error) => {
+                error!("copy from kafka failed, reason: {}", error);
+            }
+        }
+        Ok(())
+    });
+    Ok(())
+}
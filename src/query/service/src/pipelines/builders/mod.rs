@@ -13,12 +13,15 @@
 // limitations under the License.
 
 mod copy;
+mod copy_from_kafka;
 mod table;
 
 pub use copy::build_append_data_pipeline;
 pub use copy::build_commit_data_pipeline;
 pub use copy::build_upsert_copied_files_to_meta_req;
 pub use copy::set_copy_on_finished;
+pub use copy_from_kafka::build_copy_from_kafka_pipeline;
+pub use copy_from_kafka::collect_kafka_ranges;
 pub use table::build_append2table_with_commit_pipeline;
 pub use table::build_append2table_without_commit_pipeline;
 pub use table::build_fill_missing_columns_pipeline;
@@ -24,6 +24,8 @@ use common_base::runtime::TrySpawn;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_pipeline_core::processors::processor::EventCause;
+use common_profile::ProcessorMetrics;
+use common_profile::ProcessorMetricsEntry;
 use log::debug;
 use log::trace;
 use minitrace::prelude::*;
@@ -264,7 +266,10 @@ impl ExecutingGraph {
                 if state_guard_cache.is_none() {
                     state_guard_cache = Some(node.state.lock().unwrap());
                 }
-                let event = node.processor.event(event_cause)?;
+                let event = {
+                    let _guard = ProcessorMetrics::track(node.processor.metrics());
+                    node.processor.event(event_cause)?
+                };
                 trace!(
                     "node id: {:?}, name: {:?}, event: {:?}",
                     node.processor.id(),
@@ -425,6 +430,23 @@ impl RunningGraph {
         Ok(schedule_queue)
     }
 
+    pub fn processor_metrics(&self) -> Vec<ProcessorMetricsEntry> {
+        unsafe {
+            self.0
+                .graph
+                .node_indices()
+                .map(|node_index| {
+                    let processor = &self.0.graph[node_index].processor;
+                    ProcessorMetricsEntry {
+                        id: node_index.index(),
+                        name: processor.name(),
+                        metrics: processor.metrics().clone(),
+                    }
+                })
+                .collect()
+        }
+    }
+
     pub fn interrupt_running_nodes(&self) {
         unsafe {
             for node_index in self.0.graph.node_indices() {
@@ -15,21 +15,32 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use common_base::runtime::MemStat;
 use common_exception::Result;
-use common_settings::Settings;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
 
 #[derive(Clone)]
 pub struct ExecutorSettings {
     pub query_id: Arc<String>,
     pub max_execute_time_in_seconds: Duration,
+    /// The memory stat of the query, which the allocations of the executor threads are reported to.
+    pub mem_stat: Arc<MemStat>,
 }
 
 impl ExecutorSettings {
-    pub fn try_create(settings: &Settings, query_id: String) -> Result<ExecutorSettings> {
+    pub fn try_create(ctx: &QueryContext) -> Result<ExecutorSettings> {
+        let settings = ctx.get_settings();
         let max_execute_time_in_seconds = settings.get_max_execute_time_in_seconds()?;
+
+        let mem_stat = ctx.get_query_mem_stat();
+        mem_stat.set_limit(settings.get_max_query_memory_usage()? as i64);
+
         Ok(ExecutorSettings {
-            query_id: Arc::new(query_id),
+            query_id: Arc::new(ctx.get_id()),
             max_execute_time_in_seconds: Duration::from_secs(max_execute_time_in_seconds),
+            mem_stat,
         })
     }
 }
@@ -15,9 +15,11 @@
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::sync::Arc;
+use std::time::Instant;
 
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_profile::ProcessorMetrics;
 use petgraph::prelude::NodeIndex;
 
 use crate::pipelines::executor::executor_condvar::WorkersCondvar;
@@ -79,7 +81,10 @@ impl ExecutorWorkerContext {
     }
 
     unsafe fn execute_sync_task(&mut self, processor: ProcessorPtr) -> Result<Option<NodeIndex>> {
+        let _guard = ProcessorMetrics::track(processor.metrics());
+        let instant = Instant::now();
         processor.process()?;
+        processor.metrics().add_cpu_time(instant.elapsed());
         Ok(Some(processor.id()))
     }
 
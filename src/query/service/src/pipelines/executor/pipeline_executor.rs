@@ -22,9 +22,11 @@ use common_base::runtime::GlobalIORuntime;
 use common_base::runtime::Runtime;
 use common_base::runtime::Thread;
 use common_base::runtime::ThreadJoinHandle;
+use common_base::runtime::ThreadTracker;
 use common_base::runtime::TrySpawn;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_profile::QueryProfileManager;
 use futures::future::select;
 use futures_util::future::Either;
 use log::info;
@@ -169,6 +171,9 @@ impl PipelineExecutor {
         let workers_condvar = WorkersCondvar::create(threads_num);
         let global_tasks_queue = ExecutorTasksQueue::create(threads_num);
 
+        QueryProfileManager::instance()
+            .register_processors(&settings.query_id, graph.processor_metrics());
+
         Ok(Arc::new(PipelineExecutor {
             graph,
             threads_num,
@@ -337,6 +342,8 @@ impl PipelineExecutor {
 
             let span = Span::enter_with_local_parent(full_name!())
                 .with_property(|| ("thread_name", name.clone()));
+            // the spawned thread reports its allocations to a child of the query memory stat
+            let _guard = ThreadTracker::enter(Some(self.settings.mem_stat.clone()));
             thread_join_handles.push(Thread::named_spawn(Some(name), move || unsafe {
                 let _g = span.set_local_parent();
                 let this_clone = this.clone();
@@ -67,6 +67,7 @@ impl ProcessorAsyncTask {
 
         let processor_id = unsafe { processor.id() };
         let processor_name = unsafe { processor.name() };
+        let processor_metrics = processor.metrics().clone();
         let queue_clone = queue.clone();
         let inner = async move {
             let start = Instant::now();
@@ -88,6 +89,7 @@ impl ProcessorAsyncTask {
                         );
                     }
                     Either::Right((res, _)) => {
+                        processor_metrics.add_wait_time(start.elapsed());
                         return res;
                     }
                 }
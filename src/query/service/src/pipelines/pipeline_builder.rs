@@ -67,6 +67,8 @@ use common_pipeline_transforms::processors::profile_wrapper::ProfileStub;
 use common_pipeline_transforms::processors::profile_wrapper::TransformProfileWrapper;
 use common_pipeline_transforms::processors::transforms::build_full_sort_pipeline;
 use common_pipeline_transforms::processors::transforms::create_dummy_item;
+use common_pipeline_transforms::processors::transforms::SortSpillParams;
+use common_pipeline_transforms::processors::transforms::SortSpiller;
 use common_pipeline_transforms::processors::transforms::Transformer;
 use common_profile::SharedProcessorProfiles;
 use common_settings::Settings;
@@ -102,6 +104,8 @@ use common_sql::executor::ProjectSet;
 use common_sql::executor::RangeJoin;
 use common_sql::executor::ReclusterSink;
 use common_sql::executor::ReclusterSource;
+use common_sql::executor::RecursiveCte;
+use common_sql::executor::RecursiveCteScan;
 use common_sql::executor::ReplaceInto;
 use common_sql::executor::RowFetch;
 use common_sql::executor::RuntimeFilterSource;
@@ -163,6 +167,9 @@ use crate::pipelines::processors::transforms::MaterializedCteSource;
 use crate::pipelines::processors::transforms::MaterializedCteState;
 use crate::pipelines::processors::transforms::PartialSingleStateAggregator;
 use crate::pipelines::processors::transforms::RangeJoinState;
+use crate::pipelines::processors::transforms::RecursiveCteScanSource;
+use crate::pipelines::processors::transforms::RecursiveCteSource;
+use crate::pipelines::processors::transforms::RecursiveCteState;
 use crate::pipelines::processors::transforms::RuntimeFilterState;
 use crate::pipelines::processors::transforms::TransformAggregateSpillWriter;
 use crate::pipelines::processors::transforms::TransformGroupBySpillWriter;
@@ -180,6 +187,9 @@ use crate::pipelines::Pipeline;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
+use crate::spillers::Spiller;
+use crate::spillers::SpillerConfig;
+use crate::spillers::SpillerType;
 use crate::sql::executor::MutationKind;
 
 pub struct PipelineBuilder {
@@ -197,6 +207,8 @@ pub struct PipelineBuilder {
 
     // Cte -> state, each cte has it's own state
     pub cte_state: HashMap<IndexType, Arc<MaterializedCteState>>,
+    // Recursive cte -> working table, set by the recursive cte source for its recursive term.
+    pub recursive_cte_state: HashMap<IndexType, Arc<RecursiveCteState>>,
 
     enable_profiling: bool,
     proc_profs: SharedProcessorProfiles,
@@ -223,6 +235,7 @@ impl PipelineBuilder {
             exchange_injector: DefaultExchangeInjector::create(),
             index: None,
             cte_state: HashMap::new(),
+            recursive_cte_state: HashMap::new(),
         }
     }
 
@@ -250,6 +263,8 @@ impl PipelineBuilder {
             PhysicalPlan::TableScan(scan) => self.build_table_scan(scan),
             PhysicalPlan::CteScan(scan) => self.build_cte_scan(scan),
             PhysicalPlan::ConstantTableScan(scan) => self.build_constant_table_scan(scan),
+            PhysicalPlan::RecursiveCte(r_cte) => self.build_recursive_cte(r_cte),
+            PhysicalPlan::RecursiveCteScan(scan) => self.build_recursive_cte_scan(scan),
             PhysicalPlan::Filter(filter) => self.build_filter(filter),
             PhysicalPlan::Project(project) => self.build_project(project),
             PhysicalPlan::EvalScalar(eval_scalar) => self.build_eval_scalar(eval_scalar),
@@ -663,7 +678,8 @@ impl PipelineBuilder {
                 OutputPort::create(),
                 table,
                 cluster_stats_gen.clone(),
-            )?;
+            )?
+            .with_concurrent_write()?;
             pipe_items.push(serialize_block_transform.into_pipe_item());
         }
 
@@ -968,13 +984,13 @@ impl PipelineBuilder {
 
     /// The flow of Pipeline is as follows:
     ///
-    /// +---------------+      +-----------------------+
-    /// |MutationSource1| ---> |SerializeDataTransform1|
-    /// +---------------+      +-----------------------+
-    /// |     ...       | ---> |          ...          |
-    /// +---------------+      +-----------------------+
-    /// |MutationSourceN| ---> |SerializeDataTransformN|
-    /// +---------------+      +-----------------------+
+    /// +---------------+      +------------------------+
+    /// |MutationSource1| ---> |TransformSerializeBlock1|
+    /// +---------------+      +------------------------+
+    /// |     ...       | ---> |          ...           |
+    /// +---------------+      +------------------------+
+    /// |MutationSourceN| ---> |TransformSerializeBlockN|
+    /// +---------------+      +------------------------+
     fn build_delete_source(&mut self, delete: &DeleteSource) -> Result<()> {
         let table =
             self.ctx
@@ -1045,7 +1061,8 @@ impl PipelineBuilder {
                 output,
                 table,
                 cluster_stats_gen.clone(),
-            )?;
+            )?
+            .with_concurrent_write()?;
             proc.into_processor()
         })?;
         let ctx: Arc<dyn TableContext> = self.ctx.clone();
@@ -1132,6 +1149,7 @@ impl PipelineBuilder {
             self.proc_profs.clone(),
         );
         right_side_builder.cte_state = self.cte_state.clone();
+        right_side_builder.recursive_cte_state = self.recursive_cte_state.clone();
         let mut right_res = right_side_builder.finalize(&range_join.right)?;
         right_res.main_pipeline.add_sink(|input| {
             let transform = Sinker::<TransformRangeJoinRight>::create(
@@ -1185,6 +1203,7 @@ impl PipelineBuilder {
             self.proc_profs.clone(),
         );
         build_side_builder.cte_state = self.cte_state.clone();
+        build_side_builder.recursive_cte_state = self.recursive_cte_state.clone();
         let mut build_res = build_side_builder.finalize(build)?;
 
         assert!(build_res.main_pipeline.is_pulling_pipeline()?);
@@ -1383,6 +1402,47 @@ impl PipelineBuilder {
         )
     }
 
+    fn build_recursive_cte(&mut self, r_cte: &RecursiveCte) -> Result<()> {
+        self.main_pipeline.add_source(
+            |output| {
+                RecursiveCteSource::create(
+                    self.ctx.clone(),
+                    output,
+                    r_cte.clone(),
+                    self.func_ctx.clone(),
+                    self.settings.clone(),
+                    self.cte_state.clone(),
+                    self.recursive_cte_state.clone(),
+                )
+            },
+            1,
+        )
+    }
+
+    fn build_recursive_cte_scan(&mut self, scan: &RecursiveCteScan) -> Result<()> {
+        let state = self
+            .recursive_cte_state
+            .get(&scan.cte_idx)
+            .cloned()
+            .ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "Cannot find the working table of recursive cte {}",
+                    scan.cte_idx
+                ))
+            })?;
+        self.main_pipeline.add_source(
+            |output| {
+                RecursiveCteScanSource::create(
+                    self.ctx.clone(),
+                    output,
+                    state.clone(),
+                    scan.offsets.clone(),
+                )
+            },
+            1,
+        )
+    }
+
     fn build_filter(&mut self, filter: &Filter) -> Result<()> {
         self.build_pipeline(&filter.input)?;
 
@@ -2064,6 +2124,24 @@ impl PipelineBuilder {
             None
         };
 
+        let spilling_bytes_threshold =
+            self.settings.get_sort_spilling_bytes_threshold_per_proc()?;
+        let spill_params = if spilling_bytes_threshold > 0 {
+            let ctx = self.ctx.clone();
+            Some(SortSpillParams {
+                spilling_bytes_threshold,
+                create_spiller: Arc::new(move || {
+                    let spill_config = SpillerConfig::create(query_spill_prefix(&ctx.get_tenant()));
+                    let operator = DataOperator::instance().operator();
+                    let spiller =
+                        Spiller::create(ctx.clone(), operator, spill_config, SpillerType::OrderBy);
+                    Ok(Box::new(spiller) as Box<dyn SortSpiller>)
+                }),
+            })
+        } else {
+            None
+        };
+
         build_full_sort_pipeline(
             &mut self.main_pipeline,
             input_schema,
@@ -2073,6 +2151,7 @@ impl PipelineBuilder {
             block_size,
             prof_info,
             after_exchange,
+            spill_params,
         )
     }
 
@@ -2215,6 +2294,7 @@ impl PipelineBuilder {
             self.proc_profs.clone(),
         );
         pipeline_builder.cte_state = self.cte_state.clone();
+        pipeline_builder.recursive_cte_state = self.recursive_cte_state.clone();
         let mut build_res = pipeline_builder.finalize(input)?;
 
         assert!(build_res.main_pipeline.is_pulling_pipeline()?);
@@ -2425,6 +2505,7 @@ impl PipelineBuilder {
             self.proc_profs.clone(),
         );
         left_side_builder.cte_state = self.cte_state.clone();
+        left_side_builder.recursive_cte_state = self.recursive_cte_state.clone();
         let mut left_side_pipeline = left_side_builder.finalize(left_side)?;
         assert!(left_side_pipeline.main_pipeline.is_pulling_pipeline()?);
 
@@ -23,8 +23,11 @@ use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::Processor;
 use futures_util::future::BoxFuture;
 use opendal::Operator;
+use common_storage::TempFileInfo;
+use common_storage::TempFilesRegistry;
 use log::info;
 use common_arrow::arrow::io::flight::{default_ipc_fields, WriteOptions};
+use common_arrow::arrow::io::ipc::write::Compression;
 use common_arrow::arrow::io::ipc::IpcField;
 use common_base::base::{GlobalUniqName, ProgressValues};
 use common_catalog::table_context::TableContext;
@@ -68,6 +71,7 @@ impl<Method: HashMethodBounds> TransformExchangeAggregateSerializer<Method> {
         params: Arc<AggregatorParams>,
         schema: DataSchemaRef,
         local_pos: usize,
+        compression: Option<Compression>,
     ) -> Box<dyn Processor> {
         let arrow_schema = schema.to_arrow();
         let ipc_fields = default_ipc_fields(&arrow_schema.fields);
@@ -82,7 +86,7 @@ impl<Method: HashMethodBounds> TransformExchangeAggregateSerializer<Method> {
             location_prefix,
             local_pos,
             ipc_fields,
-            options: WriteOptions { compression: None },
+            options: WriteOptions { compression },
         })
     }
 }
@@ -229,6 +233,10 @@ fn spilling_aggregate_payload<Method: HashMethodBounds>(
             }
 
             writer.close().await?;
+            TempFilesRegistry::instance().register(&location, TempFileInfo {
+                query_id: ctx.get_id(),
+                spill_type: "Aggregate".to_string(),
+            });
 
             // perf
             {
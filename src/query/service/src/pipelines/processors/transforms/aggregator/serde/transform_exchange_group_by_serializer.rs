@@ -19,6 +19,7 @@ use std::time::Instant;
 
 use common_arrow::arrow::io::flight::default_ipc_fields;
 use common_arrow::arrow::io::flight::WriteOptions;
+use common_arrow::arrow::io::ipc::write::Compression;
 use common_arrow::arrow::io::ipc::IpcField;
 use common_base::base::GlobalUniqName;
 use common_base::base::ProgressValues;
@@ -44,6 +45,8 @@ use common_pipeline_core::processors::Processor;
 use common_pipeline_transforms::processors::transforms::BlockMetaTransform;
 use common_pipeline_transforms::processors::transforms::BlockMetaTransformer;
 use common_pipeline_transforms::processors::transforms::UnknownMode;
+use common_storage::TempFileInfo;
+use common_storage::TempFilesRegistry;
 use futures_util::future::BoxFuture;
 use log::info;
 use opendal::Operator;
@@ -87,6 +90,7 @@ impl<Method: HashMethodBounds> TransformExchangeGroupBySerializer<Method> {
         location_prefix: String,
         schema: DataSchemaRef,
         local_pos: usize,
+        compression: Option<Compression>,
     ) -> Box<dyn Processor> {
         let arrow_schema = schema.to_arrow();
         let ipc_fields = default_ipc_fields(&arrow_schema.fields);
@@ -101,7 +105,7 @@ impl<Method: HashMethodBounds> TransformExchangeGroupBySerializer<Method> {
                 local_pos,
                 ipc_fields,
                 location_prefix,
-                options: WriteOptions { compression: None },
+                options: WriteOptions { compression },
             },
         )
     }
@@ -299,6 +303,10 @@ fn spilling_group_by_payload<Method: HashMethodBounds>(
             }
 
             writer.close().await?;
+            TempFilesRegistry::instance().register(&location, TempFileInfo {
+                query_id: ctx.get_id(),
+                spill_type: "GroupBy".to_string(),
+            });
 
             // perf
             {
@@ -29,6 +29,8 @@ use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::port::OutputPort;
 use common_pipeline_core::processors::processor::Event;
 use common_pipeline_core::processors::Processor;
+use common_storage::TempFileInfo;
+use common_storage::TempFilesRegistry;
 use futures_util::future::BoxFuture;
 use log::info;
 use opendal::Operator;
@@ -247,6 +249,10 @@ pub fn spilling_group_by_payload<Method: HashMethodBounds>(
             }
 
             writer.close().await?;
+            TempFilesRegistry::instance().register(&location, TempFileInfo {
+                query_id: ctx.get_id(),
+                spill_type: "GroupBy".to_string(),
+            });
         }
 
         // perf
@@ -83,7 +83,10 @@ pub struct SerializedKeysGroupColumnsBuilder<'a> {
 impl<'a> SerializedKeysGroupColumnsBuilder<'a> {
     pub fn create(capacity: usize, data_capacity: usize, params: &AggregatorParams) -> Self {
         let (single_builder, data) = if params.group_data_types.len() == 1
-            && (params.group_data_types[0].is_string() || params.group_data_types[0].is_variant())
+            && (params.group_data_types[0].is_string()
+                || params.group_data_types[0].is_variant()
+                || params.group_data_types[0].is_geometry()
+                || params.group_data_types[0].is_binary())
         {
             (
                 Some(StringColumnBuilder::with_capacity(capacity, data_capacity)),
@@ -120,6 +123,8 @@ impl<'a> GroupColumnsBuilder for SerializedKeysGroupColumnsBuilder<'a> {
             match self.group_data_types[0] {
                 DataType::String => return Ok(vec![Column::String(col)]),
                 DataType::Variant => return Ok(vec![Column::Variant(col)]),
+                DataType::Geometry => return Ok(vec![Column::Geometry(col)]),
+                DataType::Binary => return Ok(vec![Column::Binary(col)]),
                 _ => {}
             }
         }
@@ -177,7 +182,11 @@ impl<'a> GroupColumnsBuilder for DictionarySerializedKeysGroupColumnsBuilder<'a>
         let mut index = 0;
         let mut res = Vec::with_capacity(self.group_data_types.len());
         for data_type in self.group_data_types.iter() {
-            if data_type.is_string() || data_type.is_variant() {
+            if data_type.is_string()
+                || data_type.is_variant()
+                || data_type.is_geometry()
+                || data_type.is_binary()
+            {
                 let mut builder = StringColumnBuilder::with_capacity(0, 0);
 
                 for string_type_keys in &self.string_type_data {
@@ -186,9 +195,11 @@ impl<'a> GroupColumnsBuilder for DictionarySerializedKeysGroupColumnsBuilder<'a>
                 }
 
                 index += 1;
-                res.push(match data_type.is_string() {
-                    true => Column::String(builder.build()),
-                    false => Column::Variant(builder.build()),
+                res.push(match data_type {
+                    DataType::String => Column::String(builder.build()),
+                    DataType::Geometry => Column::Geometry(builder.build()),
+                    DataType::Binary => Column::Binary(builder.build()),
+                    _ => Column::Variant(builder.build()),
                 });
             } else {
                 let mut column = ColumnBuilder::with_capacity(data_type, rows);
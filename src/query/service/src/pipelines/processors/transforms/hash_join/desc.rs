@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::atomic::AtomicBool;
+
 use common_exception::Result;
 use common_expression::type_check::check_function;
 use common_expression::Expr;
 use common_expression::RemoteExpr;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_sql::executor::HashJoin;
+use common_sql::IndexType;
 use parking_lot::RwLock;
 
 use crate::sql::plans::JoinType;
@@ -29,11 +32,16 @@ pub const MARKER_KIND_NULL: u8 = 2;
 pub struct MarkJoinDesc {
     // pub(crate) marker_index: Option<IndexType>,
     pub(crate) has_null: RwLock<bool>,
+    /// Whether the subquery side of a left mark join produced any rows.
+    /// `NULL IN (<empty subquery>)` is FALSE rather than NULL.
+    pub(crate) probe_has_rows: AtomicBool,
 }
 
 pub struct HashJoinDesc {
     pub(crate) build_keys: Vec<Expr>,
     pub(crate) probe_keys: Vec<Expr>,
+    /// The probe side table scan column of each key which the runtime filters apply to.
+    pub(crate) probe_keys_rt: Vec<Option<(Expr<String>, IndexType)>>,
     pub(crate) join_type: JoinType,
     pub(crate) other_predicate: Option<Expr>,
     pub(crate) marker_join_desc: MarkJoinDesc,
@@ -55,14 +63,25 @@ impl HashJoinDesc {
             .iter()
             .map(|k| k.as_expr(&BUILTIN_FUNCTIONS))
             .collect();
+        let probe_keys_rt: Vec<Option<(Expr<String>, IndexType)>> = join
+            .probe_keys_rt
+            .iter()
+            .map(|probe_key_rt| {
+                probe_key_rt
+                    .as_ref()
+                    .map(|(probe_key, scan_id)| (probe_key.as_expr(&BUILTIN_FUNCTIONS), *scan_id))
+            })
+            .collect();
 
         Ok(HashJoinDesc {
             join_type: join.join_type.clone(),
             build_keys,
             probe_keys,
+            probe_keys_rt,
             other_predicate,
             marker_join_desc: MarkJoinDesc {
                 has_null: RwLock::new(false),
+                probe_has_rows: AtomicBool::new(false),
                 // marker_index: join.marker_index,
             },
             from_correlated_subquery: join.from_correlated_subquery,
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::sync::atomic::AtomicU32;
 use std::sync::atomic::AtomicU8;
@@ -21,6 +22,7 @@ use std::sync::Arc;
 
 use common_arrow::arrow::bitmap::Bitmap;
 use common_base::base::tokio::sync::Barrier;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
@@ -47,6 +49,7 @@ use common_hashtable::StringRawEntry;
 use common_hashtable::STRING_EARLY_SIZE;
 use common_sql::plans::JoinType;
 use common_sql::ColumnSet;
+use common_sql::IndexType;
 use ethnum::U256;
 use itertools::Itertools;
 use log::info;
@@ -59,6 +62,8 @@ use crate::pipelines::processors::transforms::hash_join::hash_join_state::FixedK
 use crate::pipelines::processors::transforms::hash_join::hash_join_state::HashJoinHashTable;
 use crate::pipelines::processors::transforms::hash_join::hash_join_state::SerializerHashJoinHashTable;
 use crate::pipelines::processors::transforms::hash_join::hash_join_state::SingleStringHashJoinHashTable;
+use crate::pipelines::processors::transforms::hash_join::util::inlist_filter;
+use crate::pipelines::processors::transforms::hash_join::util::min_max_filter;
 use crate::pipelines::processors::HashJoinState;
 use crate::sessions::QueryContext;
 
@@ -238,6 +243,11 @@ impl HashJoinBuildState {
                 return Ok(());
             }
 
+            // With spilling, the build side data of each round is only a part of all the data.
+            if build_num_rows > 0 && self.ctx.get_settings().get_join_spilling_threshold()? == 0 {
+                self.build_runtime_filter()?;
+            }
+
             // Divide the finalize phase into multiple tasks.
             self.generate_finalize_task()?;
 
@@ -334,6 +344,48 @@ impl HashJoinBuildState {
         Ok(())
     }
 
+    /// Collect the runtime filters from the build keys and push them to the probe side table scans,
+    /// so that the blocks which can not match any build key are skipped.
+    fn build_runtime_filter(&self) -> Result<()> {
+        let hash_join_desc = &self.hash_join_state.hash_join_desc;
+        let chunks = unsafe { &*self.hash_join_state.chunks.get() };
+        let mut runtime_filters: HashMap<IndexType, RuntimeFilterInfo> = HashMap::new();
+        for (build_key, probe_key_rt) in hash_join_desc
+            .build_keys
+            .iter()
+            .zip(hash_join_desc.probe_keys_rt.iter())
+        {
+            if let Some((probe_key, scan_id)) = probe_key_rt {
+                let data_type = build_key.data_type();
+                let mut num_rows = 0;
+                let mut columns = Vec::with_capacity(chunks.len());
+                for chunk in chunks.iter() {
+                    let evaluator = Evaluator::new(chunk, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                    let column = evaluator
+                        .run(build_key)?
+                        .convert_to_full_column(data_type, chunk.num_rows());
+                    num_rows += chunk.num_rows();
+                    columns.push(column);
+                }
+                let column = Column::concat_columns(columns.into_iter())?;
+
+                let runtime_filter = runtime_filters.entry(*scan_id).or_default();
+                if let Some(filter) = min_max_filter(&column, num_rows, probe_key)? {
+                    runtime_filter.add_min_max(filter);
+                }
+                if let Some(filter) = inlist_filter(&column, probe_key)? {
+                    runtime_filter.add_inlist(filter);
+                }
+            }
+        }
+        for (scan_id, runtime_filter) in runtime_filters {
+            if !runtime_filter.is_empty() {
+                self.ctx.set_runtime_filter(scan_id, runtime_filter);
+            }
+        }
+        Ok(())
+    }
+
     /// Divide the finalize phase into multiple tasks.
     pub fn generate_finalize_task(&self) -> Result<()> {
         let chunks_len = unsafe { &*self.hash_join_state.chunks.get() }.len();
@@ -195,11 +195,14 @@ impl HashJoinProbeState {
             if input.num_rows() > probe_state.markers.as_ref().unwrap().len() {
                 probe_state.markers = Some(vec![MARKER_KIND_FALSE; input.num_rows()]);
             }
+            // `NULL IN (<empty subquery>)` is FALSE, only mark the NULL keys if the build side has rows.
+            let build_num_rows = unsafe { *self.hash_join_state.build_num_rows.get() };
             if self
                 .hash_join_state
                 .hash_join_desc
                 .other_predicate
                 .is_none()
+                && build_num_rows > 0
             {
                 self.hash_join_state.init_markers(
                     &probe_keys,
@@ -606,6 +609,12 @@ impl HashJoinProbeState {
             .marker_join_desc
             .has_null
             .read();
+        let probe_has_rows = self
+            .hash_join_state
+            .hash_join_desc
+            .marker_join_desc
+            .probe_has_rows
+            .load(Ordering::Relaxed);
 
         let markers_len = markers.len();
         let mut row_index = 0;
@@ -616,6 +625,9 @@ impl HashJoinProbeState {
             while build_indexes_occupied < block_size {
                 let marker = if markers[row_index] == MARKER_KIND_FALSE && has_null {
                     MARKER_KIND_NULL
+                } else if markers[row_index] == MARKER_KIND_NULL && !probe_has_rows {
+                    // Nothing can be compared with an empty subquery, even NULL.
+                    MARKER_KIND_FALSE
                 } else {
                     markers[row_index]
                 };
@@ -47,6 +47,13 @@ impl HashJoinProbeState {
         // `probe_column` is the subquery result column.
         // For sql: select * from t1 where t1.a in (select t2.a from t2); t2.a is the `probe_column`,
         let probe_column = input.get_by_offset(0).value.as_column().unwrap();
+        if input.num_rows() > 0 {
+            self.hash_join_state
+                .hash_join_desc
+                .marker_join_desc
+                .probe_has_rows
+                .store(true, Ordering::Relaxed);
+        }
         // Check if there is any null in the probe column.
         if matches!(probe_column.validity().1, Some(x) if x.unset_bits() > 0) {
             let mut has_null = self
@@ -133,6 +140,13 @@ impl HashJoinProbeState {
         // `probe_column` is the subquery result column.
         // For sql: select * from t1 where t1.a in (select t2.a from t2); t2.a is the `probe_column`,
         let probe_column = input.get_by_offset(0).value.as_column().unwrap();
+        if input.num_rows() > 0 {
+            self.hash_join_state
+                .hash_join_desc
+                .marker_join_desc
+                .probe_has_rows
+                .store(true, Ordering::Relaxed);
+        }
         // Check if there is any null in the probe column.
         if matches!(probe_column.validity().1, Some(x) if x.unset_bits() > 0) {
             let mut has_null = self
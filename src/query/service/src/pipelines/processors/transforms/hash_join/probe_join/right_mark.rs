@@ -105,8 +105,11 @@ impl HashJoinProbeState {
             .map(|c| (c.value.as_column().unwrap().clone(), c.data_type.clone()))
             .collect::<Vec<_>>();
         let markers = probe_state.markers.as_mut().unwrap();
-        self.hash_join_state
-            .init_markers(&cols, input.num_rows(), markers);
+        let build_num_rows = unsafe { &*self.hash_join_state.build_num_rows.get() };
+        if *build_num_rows > 0 {
+            self.hash_join_state
+                .init_markers(&cols, input.num_rows(), markers);
+        }
 
         let other_predicate = self
             .hash_join_state
@@ -124,7 +127,6 @@ impl HashJoinProbeState {
         let build_columns = unsafe { &*self.hash_join_state.build_columns.get() };
         let build_columns_data_type =
             unsafe { &*self.hash_join_state.build_columns_data_type.get() };
-        let build_num_rows = unsafe { &*self.hash_join_state.build_num_rows.get() };
         let is_build_projected = self
             .hash_join_state
             .is_build_projected
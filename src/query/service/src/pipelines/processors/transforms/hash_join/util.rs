@@ -12,9 +12,21 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
+use common_exception::Result;
+use common_expression::type_check::check_function;
+use common_expression::Column;
 use common_expression::DataField;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
+use common_expression::Expr;
+use common_expression::Scalar;
+use common_functions::aggregates::eval_aggr;
+use common_functions::BUILTIN_FUNCTIONS;
+
+// The max number of distinct build keys to generate the inlist runtime filter.
+const INLIST_RUNTIME_FILTER_THRESHOLD: usize = 1024;
 
 pub(crate) fn build_schema_wrap_nullable(build_schema: &DataSchemaRef) -> DataSchemaRef {
     let mut nullable_field = Vec::with_capacity(build_schema.fields().len());
@@ -37,3 +49,89 @@ pub(crate) fn probe_schema_wrap_nullable(probe_schema: &DataSchemaRef) -> DataSc
     }
     DataSchemaRefExt::create(nullable_field)
 }
+
+// Generate `probe_key >= min and probe_key <= max` from the build key column.
+pub(crate) fn min_max_filter(
+    column: &Column,
+    num_rows: usize,
+    probe_key: &Expr<String>,
+) -> Result<Option<Expr<String>>> {
+    let (min, _) = eval_aggr("min", vec![], &[column.clone()], num_rows)?;
+    let (max, _) = eval_aggr("max", vec![], &[column.clone()], num_rows)?;
+    let (min, max) = match (min.index(0), max.index(0)) {
+        (Some(min), Some(max)) if !min.is_null() && !max.is_null() => {
+            (min.to_owned(), max.to_owned())
+        }
+        // All the build keys are null, no probe row can match.
+        _ => return Ok(None),
+    };
+    let left = check_function(
+        None,
+        "gte",
+        &[],
+        &[probe_key.clone(), constant(min, probe_key)],
+        &BUILTIN_FUNCTIONS,
+    )?;
+    let right = check_function(
+        None,
+        "lte",
+        &[],
+        &[probe_key.clone(), constant(max, probe_key)],
+        &BUILTIN_FUNCTIONS,
+    )?;
+    let filter = check_function(None, "and_filters", &[], &[left, right], &BUILTIN_FUNCTIONS)?;
+    Ok(Some(filter))
+}
+
+// Generate `probe_key = v1 or probe_key = v2 ...` from the build key column,
+// if the number of distinct build keys is small enough.
+pub(crate) fn inlist_filter(
+    column: &Column,
+    probe_key: &Expr<String>,
+) -> Result<Option<Expr<String>>> {
+    let mut values = HashSet::new();
+    for value in column.iter() {
+        if value.is_null() {
+            continue;
+        }
+        values.insert(value.to_owned());
+        if values.len() > INLIST_RUNTIME_FILTER_THRESHOLD {
+            return Ok(None);
+        }
+    }
+    let exprs = values
+        .into_iter()
+        .map(|value| {
+            check_function(
+                None,
+                "eq",
+                &[],
+                &[probe_key.clone(), constant(value, probe_key)],
+                &BUILTIN_FUNCTIONS,
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if exprs.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(disjunction(exprs)?))
+}
+
+fn constant(scalar: Scalar, probe_key: &Expr<String>) -> Expr<String> {
+    Expr::Constant {
+        span: None,
+        scalar,
+        data_type: probe_key.data_type().clone(),
+    }
+}
+
+// Combine the expressions with `or` as a balanced tree to keep the expression shallow.
+fn disjunction(mut exprs: Vec<Expr<String>>) -> Result<Expr<String>> {
+    if exprs.len() == 1 {
+        return Ok(exprs.pop().unwrap());
+    }
+    let right = exprs.split_off(exprs.len() / 2);
+    let left = disjunction(exprs)?;
+    let right = disjunction(right)?;
+    check_function(None, "or", &[], &[left, right], &BUILTIN_FUNCTIONS)
+}
@@ -25,6 +25,8 @@ mod transform_create_sets;
 mod transform_limit;
 mod transform_materialized_cte;
 mod transform_merge_block;
+mod transform_multi_table_insert;
+mod transform_recursive_cte;
 mod transform_resort_addon;
 mod transform_resort_addon_without_source_schema;
 mod transform_runtime_cast_schema;
@@ -73,6 +75,11 @@ pub use transform_materialized_cte::MaterializedCteSink;
 pub use transform_materialized_cte::MaterializedCteSource;
 pub use transform_materialized_cte::MaterializedCteState;
 pub use transform_merge_block::TransformMergeBlock;
+pub use transform_multi_table_insert::MultiTableInsertRouteSink;
+pub use transform_multi_table_insert::MultiTableInsertSource;
+pub use transform_recursive_cte::RecursiveCteScanSource;
+pub use transform_recursive_cte::RecursiveCteSource;
+pub use transform_recursive_cte::RecursiveCteState;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_resort_addon_without_source_schema::TransformResortAddOnWithoutSourceSchema;
 pub use transform_runtime_cast_schema::TransformRuntimeCastSchema;
@@ -0,0 +1,186 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use async_channel::Receiver;
+use async_channel::Sender;
+use common_arrow::arrow::bitmap::Bitmap;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::Column;
+use common_expression::DataBlock;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::processors::Processor;
+use common_pipeline_sinks::AsyncSink;
+use common_pipeline_sinks::AsyncSinker;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+
+/// Routes the rows of `INSERT ALL/FIRST` to the pipelines of the target tables.
+///
+/// The input blocks have the columns of the source rows, followed by a boolean
+/// column for the condition of each `WHEN` clause.
+pub struct MultiTableInsertRouteSink {
+    num_source_columns: usize,
+    is_first: bool,
+    intos: Vec<Sender<DataBlock>>,
+    whens: Vec<Vec<Sender<DataBlock>>>,
+    opt_else: Option<Vec<Sender<DataBlock>>>,
+}
+
+impl MultiTableInsertRouteSink {
+    pub fn create(
+        input: Arc<InputPort>,
+        num_source_columns: usize,
+        is_first: bool,
+        intos: Vec<Sender<DataBlock>>,
+        whens: Vec<Vec<Sender<DataBlock>>>,
+        opt_else: Option<Vec<Sender<DataBlock>>>,
+    ) -> Box<dyn Processor> {
+        AsyncSinker::create(input, MultiTableInsertRouteSink {
+            num_source_columns,
+            is_first,
+            intos,
+            whens,
+            opt_else,
+        })
+    }
+
+    async fn send(senders: &[Sender<DataBlock>], data_block: &DataBlock) -> Result<()> {
+        for sender in senders {
+            if sender.send(data_block.clone()).await.is_err() {
+                return Err(ErrorCode::Internal(
+                    "MultiTableInsertRouteSink sender failed",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    async fn send_filtered(
+        senders: &[Sender<DataBlock>],
+        data_block: &DataBlock,
+        filter: &Bitmap,
+    ) -> Result<()> {
+        if senders.is_empty() || filter.unset_bits() == filter.len() {
+            return Ok(());
+        }
+        let data_block = if filter.unset_bits() == 0 {
+            data_block.clone()
+        } else {
+            data_block.clone().filter_with_bitmap(filter)?
+        };
+        Self::send(senders, &data_block).await
+    }
+}
+
+/// Converts the result of a condition to a bitmap, `NULL` is treated as false.
+fn condition_to_bitmap(entry: &BlockEntry, num_rows: usize) -> Bitmap {
+    let column = entry
+        .value
+        .convert_to_full_column(&entry.data_type, num_rows);
+    match column {
+        Column::Boolean(bitmap) => bitmap,
+        Column::Nullable(column) => match &column.column {
+            Column::Boolean(bitmap) => bitmap & &column.validity,
+            _ => Bitmap::new_constant(false, num_rows),
+        },
+        _ => Bitmap::new_constant(false, num_rows),
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSink for MultiTableInsertRouteSink {
+    const NAME: &'static str = "MultiTableInsertRouteSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        // Close the channels, so that the target pipelines can finish.
+        self.intos.clear();
+        self.whens.clear();
+        self.opt_else = None;
+        Ok(())
+    }
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        let num_rows = data_block.num_rows();
+        if num_rows == 0 {
+            return Ok(false);
+        }
+        let source = DataBlock::new(
+            data_block.columns()[..self.num_source_columns].to_vec(),
+            num_rows,
+        );
+
+        Self::send(&self.intos, &source).await?;
+
+        let mut matched = Bitmap::new_constant(false, num_rows);
+        for (i, senders) in self.whens.iter().enumerate() {
+            let entry = data_block.get_by_offset(self.num_source_columns + i);
+            debug_assert!(matches!(
+                entry.data_type.remove_nullable(),
+                DataType::Boolean | DataType::Null
+            ));
+            let condition = condition_to_bitmap(entry, num_rows);
+            let filter = if self.is_first {
+                &condition & &!&matched
+            } else {
+                condition.clone()
+            };
+            Self::send_filtered(senders, &source, &filter).await?;
+            matched = &matched | &condition;
+        }
+
+        if let Some(senders) = &self.opt_else {
+            Self::send_filtered(senders, &source, &!&matched).await?;
+        }
+
+        Ok(false)
+    }
+}
+
+/// Reads the rows routed by [`MultiTableInsertRouteSink`] to a target table.
+pub struct MultiTableInsertSource {
+    receiver: Receiver<DataBlock>,
+}
+
+impl MultiTableInsertSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        receiver: Receiver<DataBlock>,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx, output, MultiTableInsertSource { receiver })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for MultiTableInsertSource {
+    const NAME: &'static str = "MultiTableInsertSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        // The channel is closed after all the route sinks are finished.
+        Ok(self.receiver.recv().await.ok())
+    }
+}
@@ -0,0 +1,276 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_base::runtime::GlobalIORuntime;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use common_pipeline_sources::SyncSource;
+use common_pipeline_sources::SyncSourcer;
+use common_settings::Settings;
+use common_sql::executor::PhysicalPlan;
+use common_sql::executor::RecursiveCte;
+use common_sql::IndexType;
+use parking_lot::RwLock;
+
+use crate::pipelines::executor::ExecutorSettings;
+use crate::pipelines::executor::PipelinePullingExecutor;
+use crate::pipelines::processors::transforms::MaterializedCteState;
+use crate::pipelines::PipelineBuilder;
+use crate::sessions::QueryContext;
+
+/// The working table of a recursive cte, which holds the rows produced
+/// by the last iteration and is read by the recursive term of the next one.
+#[derive(Default)]
+pub struct RecursiveCteState {
+    working_table: RwLock<Vec<DataBlock>>,
+}
+
+impl RecursiveCteState {
+    pub fn working_table(&self) -> Vec<DataBlock> {
+        self.working_table.read().clone()
+    }
+
+    pub fn set_working_table(&self, blocks: Vec<DataBlock>) {
+        *self.working_table.write() = blocks;
+    }
+}
+
+pub struct RecursiveCteSource {
+    ctx: Arc<QueryContext>,
+    plan: RecursiveCte,
+    func_ctx: FunctionContext,
+    settings: Arc<Settings>,
+    cte_state: HashMap<IndexType, Arc<MaterializedCteState>>,
+    recursive_cte_state: HashMap<IndexType, Arc<RecursiveCteState>>,
+    state: Arc<RecursiveCteState>,
+
+    max_iterations: u64,
+    iterations: u64,
+    // Rows emitted so far, only used by `UNION` to remove the duplicated rows.
+    distinct_rows: Option<HashSet<Vec<Scalar>>>,
+    output_blocks: VecDeque<DataBlock>,
+    finished: bool,
+}
+
+impl RecursiveCteSource {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output_port: Arc<OutputPort>,
+        plan: RecursiveCte,
+        func_ctx: FunctionContext,
+        settings: Arc<Settings>,
+        cte_state: HashMap<IndexType, Arc<MaterializedCteState>>,
+        recursive_cte_state: HashMap<IndexType, Arc<RecursiveCteState>>,
+    ) -> Result<ProcessorPtr> {
+        let max_iterations = settings.get_max_recursive_cte_iterations()?;
+        let distinct_rows = if plan.union_all {
+            None
+        } else {
+            Some(HashSet::new())
+        };
+        AsyncSourcer::create(ctx.clone(), output_port, RecursiveCteSource {
+            ctx,
+            plan,
+            func_ctx,
+            settings,
+            cte_state,
+            recursive_cte_state,
+            state: Arc::new(RecursiveCteState::default()),
+            max_iterations,
+            iterations: 0,
+            distinct_rows,
+            output_blocks: VecDeque::new(),
+            finished: false,
+        })
+    }
+
+    // Execute the anchor term at the first time, then the recursive term
+    // against the rows produced by the last execution.
+    #[async_backtrace::framed]
+    async fn execute_next_iteration(&mut self) -> Result<()> {
+        let (plan, is_anchor) = if self.iterations == 0 {
+            (self.plan.anchor.as_ref().clone(), true)
+        } else {
+            if self.iterations > self.max_iterations {
+                return Err(ErrorCode::Overflow(format!(
+                    "Recursive cte `{}` exceeds the max iterations {}, \
+                    please check the termination condition or increase the setting `max_recursive_cte_iterations`",
+                    self.plan.cte_name, self.max_iterations
+                )));
+            }
+            (self.plan.recursive.as_ref().clone(), false)
+        };
+        self.iterations += 1;
+
+        let blocks = self.execute_plan(plan).await?;
+        let schema = if is_anchor {
+            self.plan.anchor.output_schema()?
+        } else {
+            self.plan.recursive.output_schema()?
+        };
+        let offsets = self
+            .plan
+            .pairs
+            .iter()
+            .map(|(left, right)| schema.index_of(if is_anchor { left } else { right }))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut output_blocks = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            let num_rows = block.num_rows();
+            let columns = offsets
+                .iter()
+                .map(|offset| block.get_by_offset(*offset).clone())
+                .collect::<Vec<_>>();
+            let block = self.remove_duplicated_rows(DataBlock::new(columns, num_rows))?;
+            if !block.is_empty() {
+                output_blocks.push(block);
+            }
+        }
+
+        if output_blocks.is_empty() {
+            self.finished = true;
+            return Ok(());
+        }
+        self.state.set_working_table(output_blocks.clone());
+        self.output_blocks.extend(output_blocks);
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn execute_plan(&self, plan: PhysicalPlan) -> Result<Vec<DataBlock>> {
+        let ctx = self.ctx.clone();
+        let func_ctx = self.func_ctx.clone();
+        let settings = self.settings.clone();
+        let cte_state = self.cte_state.clone();
+        let mut recursive_cte_state = self.recursive_cte_state.clone();
+        recursive_cte_state.insert(self.plan.cte_idx, self.state.clone());
+
+        // The sub pipeline is pulled synchronously, so run it out of the async runtime.
+        GlobalIORuntime::instance()
+            .spawn_blocking(move || {
+                let mut builder = PipelineBuilder::create(
+                    func_ctx,
+                    settings.clone(),
+                    QueryContext::create_from(ctx.clone()),
+                    false,
+                    Default::default(),
+                );
+                builder.cte_state = cte_state;
+                builder.recursive_cte_state = recursive_cte_state;
+                let mut build_res = builder.finalize(&plan)?;
+                build_res.set_max_threads(settings.get_max_threads()? as usize);
+
+                let executor_settings = ExecutorSettings::try_create(&ctx)?;
+                let mut executor =
+                    PipelinePullingExecutor::from_pipelines(build_res, executor_settings)?;
+                executor.start();
+                let mut blocks = vec![];
+                while let Some(block) = executor.pull_data()? {
+                    blocks.push(block);
+                }
+                Ok(blocks)
+            })
+            .await
+    }
+
+    fn remove_duplicated_rows(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let Some(distinct_rows) = self.distinct_rows.as_mut() else {
+            return Ok(block);
+        };
+
+        let num_rows = block.num_rows();
+        let mut filter = MutableBitmap::with_capacity(num_rows);
+        for row in 0..num_rows {
+            let values = block
+                .columns()
+                .iter()
+                .map(|entry| entry.value.index(row).unwrap().to_owned())
+                .collect::<Vec<_>>();
+            filter.push(distinct_rows.insert(values));
+        }
+        block.filter_with_bitmap(&filter.into())
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for RecursiveCteSource {
+    const NAME: &'static str = "RecursiveCteSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        loop {
+            if let Some(block) = self.output_blocks.pop_front() {
+                return Ok(Some(block));
+            }
+            if self.finished {
+                return Ok(None);
+            }
+            self.execute_next_iteration().await?;
+        }
+    }
+}
+
+/// Reads the working table of the recursive cte in the recursive term.
+pub struct RecursiveCteScanSource {
+    blocks: VecDeque<DataBlock>,
+}
+
+impl RecursiveCteScanSource {
+    pub fn create(
+        ctx: Arc<QueryContext>,
+        output_port: Arc<OutputPort>,
+        state: Arc<RecursiveCteState>,
+        offsets: Vec<usize>,
+    ) -> Result<ProcessorPtr> {
+        let blocks = state
+            .working_table()
+            .into_iter()
+            .map(|block| {
+                let num_rows = block.num_rows();
+                let columns = offsets
+                    .iter()
+                    .map(|offset| block.get_by_offset(*offset).clone())
+                    .collect::<Vec<BlockEntry>>();
+                DataBlock::new(columns, num_rows)
+            })
+            .collect();
+        SyncSourcer::create(ctx, output_port, RecursiveCteScanSource { blocks })
+    }
+}
+
+impl SyncSource for RecursiveCteScanSource {
+    const NAME: &'static str = "RecursiveCteScanSource";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        Ok(self.blocks.pop_front())
+    }
+}
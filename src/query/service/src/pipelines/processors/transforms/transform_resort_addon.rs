@@ -28,6 +28,8 @@ use crate::pipelines::processors::transforms::transform::Transform;
 use crate::pipelines::processors::transforms::transform::Transformer;
 use crate::sessions::QueryContext;
 
+/// Resorts the input columns as the output schema, and fills the missing columns
+/// with their default values, see [`build_expression_transform`].
 pub struct TransformResortAddOn {
     expression_transform: CompoundBlockOperator,
     input_len: usize,
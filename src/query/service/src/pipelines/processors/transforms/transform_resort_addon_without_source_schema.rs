@@ -17,10 +17,13 @@ use std::sync::Arc;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_expression::BlockMetaInfoDowncast;
+use common_expression::ConstantFolder;
 use common_expression::DataBlock;
+use common_expression::DataField;
 use common_expression::DataSchemaRef;
 use common_expression::Expr;
 use common_expression::Scalar;
+use common_functions::BUILTIN_FUNCTIONS;
 use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
 use common_sql::parse_exprs;
@@ -37,6 +40,47 @@ pub struct TransformResortAddOnWithoutSourceSchema {
     output_schema: DataSchemaRef,
     ctx: Arc<QueryContext>,
     table: Arc<dyn Table>,
+    // The expression transform built for the schema of the last input block,
+    // the blocks of the same source share the schema.
+    expression_transform: Option<(DataSchemaRef, CompoundBlockOperator)>,
+}
+
+/// Builds the expression filling the default value of the field missing in the input.
+///
+/// Deterministic default expressions are folded into constants. The others, such as
+/// `uuid()` or `now()`, are evaluated for each block, so that each row gets its own value.
+fn build_default_expr(
+    field: &DataField,
+    table: Arc<dyn Table>,
+    ctx: Arc<QueryContext>,
+) -> Result<Expr> {
+    let Some(default_expr) = field.default_expr() else {
+        // Nullable fields are filled with NULL.
+        return Ok(Expr::Constant {
+            span: None,
+            scalar: Scalar::default_value(field.data_type()),
+            data_type: field.data_type().clone(),
+        });
+    };
+
+    let mut expr = parse_exprs(ctx.clone(), table, default_expr)?.remove(0);
+    if expr.data_type() != field.data_type() {
+        // The default value of a NOT NULL field must not be NULL, so the cast
+        // from a nullable expression fails on NULL instead of producing it.
+        expr = Expr::Cast {
+            span: None,
+            is_try: field.data_type().is_nullable(),
+            expr: Box::new(expr),
+            dest_type: field.data_type().clone(),
+        };
+    }
+
+    if expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+        let func_ctx = ctx.get_function_context()?;
+        let (folded, _) = ConstantFolder::fold(&expr, &func_ctx, &BUILTIN_FUNCTIONS);
+        expr = folded;
+    }
+    Ok(expr)
 }
 
 pub fn build_expression_transform(
@@ -48,26 +92,7 @@ pub fn build_expression_transform(
     let mut exprs = Vec::with_capacity(output_schema.fields().len());
     for f in output_schema.fields().iter() {
         let expr = if !input_schema.has_field(f.name()) {
-            if let Some(default_expr) = f.default_expr() {
-                let mut expr = parse_exprs(ctx.clone(), table.clone(), default_expr)?;
-                let mut expr = expr.remove(0);
-                if expr.data_type() != f.data_type() {
-                    expr = Expr::Cast {
-                        span: None,
-                        is_try: f.data_type().is_nullable(),
-                        expr: Box::new(expr),
-                        dest_type: f.data_type().clone(),
-                    };
-                }
-                expr
-            } else {
-                let default_value = Scalar::default_value(f.data_type());
-                Expr::Constant {
-                    span: None,
-                    scalar: default_value,
-                    data_type: f.data_type().clone(),
-                }
-            }
+            build_default_expr(f, table.clone(), ctx.clone())?
         } else {
             let field = input_schema.field_with_name(f.name()).unwrap();
             let id = input_schema.index_of(f.name()).unwrap();
@@ -108,6 +133,7 @@ where Self: Transform
                 output_schema,
                 ctx,
                 table,
+                expression_transform: None,
             },
         )))
     }
@@ -119,13 +145,17 @@ impl Transform for TransformResortAddOnWithoutSourceSchema {
     fn transform(&mut self, mut block: DataBlock) -> Result<DataBlock> {
         let input_schema =
             DataSchemaRef::downcast_from(block.clone().get_owned_meta().unwrap()).unwrap();
-        block = build_expression_transform(
-            input_schema.clone(),
-            self.output_schema.clone(),
-            self.table.clone(),
-            self.ctx.clone(),
-        )?
-        .transform(block)?;
+        if !matches!(&self.expression_transform, Some((schema, _)) if schema == &input_schema) {
+            let expression_transform = build_expression_transform(
+                input_schema.clone(),
+                self.output_schema.clone(),
+                self.table.clone(),
+                self.ctx.clone(),
+            )?;
+            self.expression_transform = Some((input_schema.clone(), expression_transform));
+        }
+        let (_, expression_transform) = self.expression_transform.as_mut().unwrap();
+        block = expression_transform.transform(block)?;
         let columns = block.columns()[input_schema.num_fields()..].to_owned();
         Ok(DataBlock::new(columns, block.num_rows()))
     }
@@ -212,6 +212,7 @@ impl QueryFragmentsActions {
         let local_id = self.ctx.get_cluster().local_id.clone();
         let connections_info = self.fragments_connections();
         let statistics_connections = self.statistics_connections();
+        let exchange_credit_bytes = self.ctx.get_settings().get_exchange_credit_bytes()?;
 
         let mut init_nodes_channel_packets = Vec::with_capacity(connections_info.len());
 
@@ -250,6 +251,7 @@ impl QueryFragmentsActions {
                     true => statistics_connections.clone(),
                     false => vec![],
                 },
+                exchange_credit_bytes,
             ));
         }
 
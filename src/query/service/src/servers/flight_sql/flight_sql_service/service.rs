@@ -207,9 +207,38 @@ impl FlightSqlService for FlightSqlServiceImpl {
         query: CommandStatementQuery,
         request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        info!("get_flight_info_sql_info(query={})", query.query);
-        let _session = self.get_session(&request)?;
-        Ok(simple_flight_info(query))
+        let session = self.get_session(&request)?;
+        let handle = Uuid::new_v4();
+        info!(
+            "get_flight_info_statement with handle={handle} query={:?}",
+            query.query
+        );
+
+        let plan = self
+            .plan_sql(&session, &query.query)
+            .await
+            .map_err(|e| status!("Error getting result schema", e))?;
+        let data_schema = if plan.0.has_result_set() {
+            plan.0.schema()
+        } else {
+            Arc::new(DataSchema::empty())
+        };
+        let schema = (&*data_schema).into();
+        self.statements.insert(handle, plan);
+
+        // The query is executed when the client calls DoGet with the ticket,
+        // so the results are streamed as record batches instead of being buffered.
+        let ticket = TicketStatementQuery {
+            statement_handle: handle.as_bytes().to_vec().into(),
+        };
+        let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
+            .try_into()
+            .map_err(|e| status!("Unable to serialize schema", e))?;
+        let IpcMessage(schema_bytes) = message;
+
+        let mut resp = simple_flight_info(ticket);
+        resp.get_mut().schema = schema_bytes;
+        Ok(resp)
     }
 
     #[async_backtrace::framed]
@@ -370,10 +399,24 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_statement(
         &self,
         ticket: TicketStatementQuery,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        info!("do_get_statement({ticket:?}");
-        Err(Status::unimplemented("do_get_statement not implemented"))
+        let session = self.get_session(&request)?;
+        let handle = Uuid::from_slice(ticket.statement_handle.as_ref())
+            .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
+
+        info!("do_get_statement with handle={handle}");
+
+        // A statement ticket can only be consumed once.
+        let (_, (plan, plan_extras)) = self
+            .statements
+            .remove(&handle)
+            .ok_or_else(|| Status::not_found(format!("statement {handle} not found")))?;
+        let stream = self
+            .execute_query(session, &plan, &plan_extras)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(Response::new(stream))
     }
 
     #[async_backtrace::framed]
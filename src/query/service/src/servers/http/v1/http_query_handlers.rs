@@ -38,12 +38,16 @@ use super::query::HttpQueryRequest;
 use super::query::HttpQueryResponseInternal;
 use crate::servers::http::metrics::metrics_incr_http_response_errors_count;
 use crate::servers::http::middleware::MetricsMiddleware;
+use crate::servers::http::v1::query::PersistedPages;
 use crate::servers::http::v1::query::Progresses;
+use crate::servers::http::v1::query::ResponseData;
+use crate::servers::http::v1::query::ResponseState;
 use crate::servers::http::v1::HttpQueryContext;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::servers::http::v1::HttpSessionConf;
 use crate::servers::http::v1::JsonBlock;
 use crate::sessions::QueryAffect;
+use crate::sessions::SessionType;
 
 const HEADER_QUERY_ID: &str = "X-DATABEND-QUERY-ID";
 const HEADER_QUERY_STATE: &str = "X-DATABEND-QUERY-STATE";
@@ -216,7 +220,7 @@ impl QueryResponse {
 
 #[poem::handler]
 async fn query_final_handler(
-    _ctx: &HttpQueryContext,
+    ctx: &HttpQueryContext,
     Path(query_id): Path<String>,
 ) -> PoemResult<impl IntoResponse> {
     let trace_id = query_id_to_trace_id(&query_id);
@@ -237,9 +241,28 @@ async fn query_final_handler(
                         StatusCode::BAD_REQUEST,
                     ));
                 }
+                if let Err(e) = query.remove_persisted_pages().await {
+                    error!(
+                        "fail to remove persisted pages of query {}: {}",
+                        query_id, e
+                    );
+                }
                 Ok(QueryResponse::from_internal(query_id, response, true))
             }
-            None => Err(query_id_not_found(query_id)),
+            None => {
+                // the query may have expired with its result persisted.
+                let tenant = ctx.get_session(SessionType::HTTPQuery).get_current_tenant();
+                if let Err(e) = PersistedPages::create(&tenant, &query_id)
+                    .remove_all()
+                    .await
+                {
+                    error!(
+                        "fail to remove persisted pages of query {}: {}",
+                        query_id, e
+                    );
+                }
+                Err(query_id_not_found(query_id))
+            }
         }
     }
     .in_span(root)
@@ -301,7 +324,7 @@ async fn query_state_handler(
 
 #[poem::handler]
 async fn query_page_handler(
-    _ctx: &HttpQueryContext,
+    ctx: &HttpQueryContext,
     Path((query_id, page_no)): Path<(String, usize)>,
 ) -> PoemResult<impl IntoResponse> {
     let trace_id = query_id_to_trace_id(&query_id);
@@ -321,7 +344,37 @@ async fn query_page_handler(
                 query.update_expire_time(false).await;
                 Ok(QueryResponse::from_internal(query_id, resp, false))
             }
-            None => Err(query_id_not_found(query_id)),
+            None => {
+                // the query may have expired with its result persisted, serve the page from the storage.
+                let session = ctx.get_session(SessionType::HTTPQuery);
+                let persisted = PersistedPages::create(&session.get_current_tenant(), &query_id)
+                    .read_page(page_no)
+                    .await
+                    .map_err(|err| {
+                        poem::Error::from_string(err.message(), StatusCode::INTERNAL_SERVER_ERROR)
+                    })?;
+                match persisted {
+                    Some((page, end)) => {
+                        let resp = HttpQueryResponseInternal {
+                            data: Some(ResponseData {
+                                page,
+                                next_page_no: if end { None } else { Some(page_no + 1) },
+                            }),
+                            session_id: session.get_id(),
+                            session: None,
+                            state: ResponseState {
+                                running_time_ms: 0,
+                                progresses: Progresses::default(),
+                                state: ExecuteStateKind::Succeeded,
+                                affect: None,
+                                error: None,
+                            },
+                        };
+                        Ok(QueryResponse::from_internal(query_id, resp, false))
+                    }
+                    None => Err(query_id_not_found(query_id)),
+                }
+            }
         }
     }
     .in_span(root)
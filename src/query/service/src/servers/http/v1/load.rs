@@ -39,7 +39,10 @@ use poem::error::Result as PoemResult;
 use poem::http::StatusCode;
 use poem::web::Json;
 use poem::web::Multipart;
+use poem::Body;
+use poem::FromRequest;
 use poem::Request;
+use poem::RequestBody;
 use serde::Deserialize;
 use serde::Serialize;
 use tokio::sync::mpsc::Sender;
@@ -91,7 +94,7 @@ fn remove_quote(s: &[u8]) -> &[u8] {
 pub async fn streaming_load(
     ctx: &HttpQueryContext,
     req: &Request,
-    mut multipart: Multipart,
+    body: Body,
 ) -> PoemResult<Json<LoadResponse>> {
     info!(
         "new streaming load request:, headers={:?}",
@@ -180,7 +183,19 @@ pub async fn streaming_load(
                 info!("streaming load with file_format {:?}", input_context);
 
                 let handler = context.spawn(execute_query(context.clone(), plan));
-                let files = read_multi_part(multipart, tx, &input_context).await?;
+                // The data is either uploaded as files of a multipart form,
+                // or sent as the (maybe chunked) request body directly.
+                let is_multipart = req
+                    .content_type()
+                    .map(|content_type| content_type.starts_with("multipart/"))
+                    .unwrap_or(false);
+                let files = if is_multipart {
+                    let multipart =
+                        Multipart::from_request(req, &mut RequestBody::new(body)).await?;
+                    read_multi_part(multipart, tx, &input_context).await?
+                } else {
+                    read_body(body, tx, &input_context).await?
+                };
 
                 match handler.await {
                     Ok(Ok(_)) => Ok(Json(LoadResponse {
@@ -251,43 +266,65 @@ async fn read_multi_part(
             }
             Ok(Some(field)) => {
                 let filename = field.file_name().unwrap_or("file_with_no_name").to_string();
-                let compression = input_context
-                    .get_compression_alg(&filename)
-                    .map_err(BadRequest)?;
                 debug!("Multipart start read {}", &filename);
                 files.push(filename.clone());
-                let mut async_reader = field.into_async_read();
-                let mut is_start = true;
-                loop {
-                    let mut batch = vec![0u8; input_context.read_batch_size];
-                    let n = read_full(&mut async_reader, &mut batch[0..])
-                        .await
-                        .map_err(InternalServerError)?;
-                    if n == 0 {
-                        break;
-                    } else {
-                        batch.truncate(n);
-                        debug!("Multipart read {} bytes", n);
-                        if let Err(e) = tx
-                            .send(Ok(StreamingReadBatch {
-                                data: batch,
-                                path: filename.clone(),
-                                is_start,
-                                compression,
-                            }))
-                            .await
-                        {
-                            warn!(" Multipart fail to send ReadBatch: {}", e);
-                        }
-                        is_start = false;
-                    }
-                }
+                read_stream(field.into_async_read(), filename, &tx, input_context).await?;
             }
         }
     }
     Ok(files)
 }
 
+/// Reads the data sent as the request body, the body is not buffered as a whole,
+/// it is sent to the input pipeline batch by batch while being received.
+async fn read_body(
+    body: Body,
+    tx: Sender<Result<StreamingReadBatch>>,
+    input_context: &Arc<InputContext>,
+) -> poem::Result<Vec<String>> {
+    let filename = "request_body".to_string();
+    debug!("Start read request body");
+    read_stream(body.into_async_read(), filename.clone(), &tx, input_context).await?;
+    Ok(vec![filename])
+}
+
+async fn read_stream<R: AsyncRead + Unpin>(
+    mut async_reader: R,
+    filename: String,
+    tx: &Sender<Result<StreamingReadBatch>>,
+    input_context: &Arc<InputContext>,
+) -> poem::Result<()> {
+    let compression = input_context
+        .get_compression_alg(&filename)
+        .map_err(BadRequest)?;
+    let mut is_start = true;
+    loop {
+        let mut batch = vec![0u8; input_context.read_batch_size];
+        let n = read_full(&mut async_reader, &mut batch[0..])
+            .await
+            .map_err(InternalServerError)?;
+        if n == 0 {
+            break;
+        } else {
+            batch.truncate(n);
+            debug!("Streaming load read {} bytes of {}", n, filename);
+            if let Err(e) = tx
+                .send(Ok(StreamingReadBatch {
+                    data: batch,
+                    path: filename.clone(),
+                    is_start,
+                    compression,
+                }))
+                .await
+            {
+                warn!("Streaming load fail to send ReadBatch: {}", e);
+            }
+            is_start = false;
+        }
+    }
+    Ok(())
+}
+
 #[async_backtrace::framed]
 pub async fn read_full<R: AsyncRead + Unpin>(reader: &mut R, buf: &mut [u8]) -> Result<usize> {
     let mut buf = &mut buf[0..];
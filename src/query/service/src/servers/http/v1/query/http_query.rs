@@ -45,6 +45,7 @@ use crate::servers::http::v1::query::ExecuteState;
 use crate::servers::http::v1::query::ExecuteStateKind;
 use crate::servers::http::v1::query::Executor;
 use crate::servers::http::v1::query::PageManager;
+use crate::servers::http::v1::query::PersistedPages;
 use crate::servers::http::v1::query::ResponseData;
 use crate::servers::http::v1::query::Wait;
 use crate::servers::http::v1::HttpQueryManager;
@@ -149,11 +150,16 @@ impl HttpSessionConf {
             QueryAffect::ChangeSettings {
                 keys,
                 values,
-                is_globals: _,
+                is_globals,
             } => {
                 let settings = ret.settings.get_or_insert_default();
-                for (key, value) in keys.iter().zip(values) {
-                    settings.insert(key.to_string(), value.to_string());
+                for ((key, value), is_global) in keys.iter().zip(values).zip(is_globals) {
+                    if *is_global {
+                        // global settings are persisted, and loaded by each new session.
+                        settings.remove(key);
+                    } else {
+                        settings.insert(key.to_string(), value.to_string());
+                    }
                 }
             }
             _ => {}
@@ -368,12 +374,21 @@ impl HttpQuery {
         )?;
 
         let format_settings = ctx.get_format_settings()?;
+        let persisted_pages = if ctx
+            .get_settings()
+            .get_enable_http_query_result_persistence()?
+        {
+            Some(PersistedPages::create(&ctx.get_tenant(), &query_id))
+        } else {
+            None
+        };
         let data = Arc::new(TokioMutex::new(PageManager::new(
             query_id.clone(),
             request.pagination.max_rows_per_page,
             block_receiver,
             schema,
             format_settings,
+            persisted_pages,
         )));
         let query = HttpQuery {
             id: query_id,
@@ -444,13 +459,43 @@ impl HttpQuery {
         let page = page_manager
             .get_a_page(page_no, &self.request.pagination.get_wait_type())
             .await?;
-        let response = ResponseData {
-            page,
-            next_page_no: page_manager.next_page_no(),
+        // a persisted page may be fetched again, the next page of it is not always the latest one.
+        let next_page_no = if page_no + 1 < page_manager.num_pages() {
+            Some(page_no + 1)
+        } else {
+            page_manager.next_page_no()
         };
+        let response = ResponseData { page, next_page_no };
         Ok(response)
     }
 
+    #[async_backtrace::framed]
+    pub async fn is_result_persisted(&self) -> bool {
+        let page_manager = self.page_manager.lock().await;
+        page_manager.persisted_pages().is_some()
+    }
+
+    /// Collect and persist all the pages not fetched yet, so the client can fetch them
+    /// after the query is removed.
+    #[async_backtrace::framed]
+    pub async fn persist_remaining_pages(&self) -> Result<()> {
+        let mut page_manager = self.page_manager.lock().await;
+        while let Some(page_no) = page_manager.next_page_no() {
+            let wait = Wait::Deadline(Instant::now() + Duration::from_secs(1));
+            page_manager.get_a_page(page_no, &wait).await?;
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn remove_persisted_pages(&self) -> Result<()> {
+        let page_manager = self.page_manager.lock().await;
+        match page_manager.persisted_pages() {
+            Some(persisted_pages) => persisted_pages.remove_all().await,
+            None => Ok(()),
+        }
+    }
+
     #[async_backtrace::framed]
     pub async fn kill(&self) {
         Executor::stop(
@@ -102,8 +102,19 @@ impl HttpQueryManager {
                             warn!("{msg}, but fail to remove");
                         } else {
                             warn!("{msg}");
-                            query.detach().await;
-                            query.kill().await;
+                            if query.is_result_persisted().await {
+                                // keep the query running until all its pages are persisted,
+                                // the client can still fetch them by the page uris.
+                                if let Err(e) = query.persist_remaining_pages().await {
+                                    warn!(
+                                        "fail to persist pages of http query {query_id_clone}: {e}"
+                                    );
+                                    query.kill().await;
+                                }
+                            } else {
+                                query.detach().await;
+                                query.kill().await;
+                            }
                         };
                         break;
                     }
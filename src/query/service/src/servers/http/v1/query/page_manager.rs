@@ -27,6 +27,7 @@ use serde_json::Value as JsonValue;
 
 use crate::servers::http::v1::json_block::block_to_json_value;
 use crate::servers::http::v1::query::sized_spsc::SizedChannelReceiver;
+use crate::servers::http::v1::query::PersistedPages;
 use crate::servers::http::v1::JsonBlock;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -58,6 +59,7 @@ pub struct PageManager {
     row_buffer: VecDeque<Vec<JsonValue>>,
     block_receiver: SizedChannelReceiver<DataBlock>,
     format_settings: FormatSettings,
+    persisted_pages: Option<PersistedPages>,
 }
 
 impl PageManager {
@@ -67,6 +69,7 @@ impl PageManager {
         block_receiver: SizedChannelReceiver<DataBlock>,
         schema: DataSchemaRef,
         format_settings: FormatSettings,
+        persisted_pages: Option<PersistedPages>,
     ) -> PageManager {
         PageManager {
             query_id,
@@ -80,9 +83,18 @@ impl PageManager {
             block_receiver,
             max_rows_per_page,
             format_settings,
+            persisted_pages,
         }
     }
 
+    pub fn num_pages(&self) -> usize {
+        self.total_pages
+    }
+
+    pub fn persisted_pages(&self) -> Option<&PersistedPages> {
+        self.persisted_pages.as_ref()
+    }
+
     pub fn next_page_no(&mut self) -> Option<usize> {
         if self.end {
             None
@@ -102,13 +114,19 @@ impl PageManager {
                 data: block,
                 total_rows: self.total_rows,
             };
+            if let Some(persisted_pages) = &self.persisted_pages {
+                // The empty last page is persisted too, to tell there is no more pages.
+                if num_row > 0 || end {
+                    persisted_pages.write_page(page_no, &page, end).await?;
+                }
+            }
             if num_row > 0 {
                 self.total_pages += 1;
                 self.last_page = Some(page.clone());
             }
             self.end = end;
             Ok(page)
-        } else if page_no == next_no - 1 {
+        } else if page_no + 1 == next_no {
             // later, there may be other ways to ack and drop the last page except collect_new_page.
             // but for now, last_page always exists in this branch, since page_no is unsigned.
             Ok(self
@@ -116,6 +134,11 @@ impl PageManager {
                 .as_ref()
                 .ok_or_else(|| ErrorCode::Internal("last_page is None"))?
                 .clone())
+        } else if let (true, Some(persisted_pages)) = (page_no < next_no, &self.persisted_pages) {
+            let (page, _) = persisted_pages.read_page(page_no).await?.ok_or_else(|| {
+                ErrorCode::HttpNotFound(format!("persisted page {} not found", page_no))
+            })?;
+            Ok(page)
         } else {
             let message = format!("wrong page number {}", page_no,);
             Err(ErrorCode::HttpNotFound(message))
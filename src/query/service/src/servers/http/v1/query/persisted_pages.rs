@@ -0,0 +1,104 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataSchema;
+use common_storage::DataOperator;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+use crate::servers::http::v1::query::Page;
+use crate::servers::http::v1::JsonBlock;
+
+pub fn query_result_prefix(tenant: &str) -> String {
+    format!("_query_result/{}", tenant)
+}
+
+#[derive(Serialize, Deserialize)]
+struct PersistedPage {
+    schema: DataSchema,
+    data: Vec<Vec<JsonValue>>,
+    total_rows: usize,
+    end: bool,
+}
+
+/// The result pages of a http query persisted in the storage.
+///
+/// The pages can be fetched again by their page uris after the client disconnects, even
+/// after the query itself has expired.
+#[derive(Clone)]
+pub struct PersistedPages {
+    operator: Operator,
+    prefix: String,
+}
+
+impl PersistedPages {
+    pub fn create(tenant: &str, query_id: &str) -> Self {
+        PersistedPages {
+            operator: DataOperator::instance().operator(),
+            prefix: format!("{}/{}/", query_result_prefix(tenant), query_id),
+        }
+    }
+
+    fn page_location(&self, page_no: usize) -> String {
+        format!("{}{}.json", self.prefix, page_no)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn write_page(&self, page_no: usize, page: &Page, end: bool) -> Result<()> {
+        let persisted = PersistedPage {
+            schema: page.data.schema.as_ref().clone(),
+            data: page.data.data.clone(),
+            total_rows: page.total_rows,
+            end,
+        };
+        let bytes = serde_json::to_vec(&persisted)?;
+        self.operator
+            .write(&self.page_location(page_no), bytes)
+            .await?;
+        Ok(())
+    }
+
+    /// Returns the page and whether it's the last page.
+    #[async_backtrace::framed]
+    pub async fn read_page(&self, page_no: usize) -> Result<Option<(Page, bool)>> {
+        let bytes = match self.operator.read(&self.page_location(page_no)).await {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let persisted: PersistedPage = serde_json::from_slice(&bytes).map_err(|e| {
+            ErrorCode::Internal(format!("fail to decode persisted page {}: {}", page_no, e))
+        })?;
+        let page = Page {
+            data: JsonBlock {
+                schema: Arc::new(persisted.schema),
+                data: persisted.data,
+            },
+            total_rows: persisted.total_rows,
+        };
+        Ok(Some((page, persisted.end)))
+    }
+
+    #[async_backtrace::framed]
+    pub async fn remove_all(&self) -> Result<()> {
+        self.operator.remove_all(&self.prefix).await?;
+        Ok(())
+    }
+}
@@ -173,9 +173,7 @@ impl MySQLFederated {
     fn federated_mixed_check(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
         #[ctor]
         static MIXED_RULES: Vec<(Regex, Option<(TableSchemaRef, DataBlock)>)> = vec![
-            // Txn.
-            (Regex::new("(?i)^(ROLLBACK(.*))").unwrap(), None),
-            (Regex::new("(?i)^(COMMIT(.*))").unwrap(), None),
+            // Txn, `BEGIN`, `COMMIT` and `ROLLBACK` are handled by the interpreters.
             (Regex::new("(?i)^(START(.*))").unwrap(), None),
             (Regex::new("(?i)^(SET NAMES(.*))").unwrap(), None),
             (Regex::new("(?i)^(SET character_set_results(.*))").unwrap(), None),
@@ -0,0 +1,136 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_config::DATABEND_COMMIT_VERSION;
+use common_expression::types::StringType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_expression::TableSchemaRefExt;
+use ctor::ctor;
+use regex::Regex;
+
+use crate::servers::federated_helper::FederatedHelper;
+use crate::servers::federated_helper::LazyBlockFunc;
+use crate::servers::postgres::POSTGRES_VERSION;
+
+pub struct PostgresFederated {}
+
+impl PostgresFederated {
+    pub fn create() -> Self {
+        PostgresFederated {}
+    }
+
+    // Build block for select function or show variable.
+    // Format:
+    // |name|
+    // |value|
+    fn value_block(name: &str, value: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        let schema = TableSchemaRefExt::create(vec![TableField::new(name, TableDataType::String)]);
+        let block = DataBlock::new_from_columns(vec![StringType::from_data(vec![
+            value.as_bytes().to_vec(),
+        ])]);
+        Some((schema, block))
+    }
+
+    fn select_version_block(_query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        let version = format!(
+            "PostgreSQL {} (Databend Query {})",
+            POSTGRES_VERSION, *DATABEND_COMMIT_VERSION
+        );
+        Self::value_block("version", &version)
+    }
+
+    // Check the functions querying the server version.
+    fn federated_select_check(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        #[ctor]
+        static SELECT_LAZY_RULES: Vec<(Regex, LazyBlockFunc)> = vec![(
+            Regex::new("(?i)^(SELECT (pg_catalog\\.)?version\\(\\)\\s*;?\\s*)$").unwrap(),
+            PostgresFederated::select_version_block,
+        )];
+
+        FederatedHelper::lazy_block_match_rule(query, &SELECT_LAZY_RULES)
+    }
+
+    // Check for SHOW, SET and transaction statements sent by the drivers.
+    fn federated_mixed_check(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        #[ctor]
+        static MIXED_RULES: Vec<(Regex, Option<(TableSchemaRef, DataBlock)>)> = vec![
+            // Txn, every statement is committed on its own.
+            (Regex::new("(?i)^(BEGIN(.*))").unwrap(), None),
+            (Regex::new("(?i)^(START TRANSACTION(.*))").unwrap(), None),
+            (Regex::new("(?i)^(COMMIT(.*))").unwrap(), None),
+            (Regex::new("(?i)^(END(\\s*;)?\\s*)$").unwrap(), None),
+            (Regex::new("(?i)^(ROLLBACK(.*))").unwrap(), None),
+            // psql, JDBC, DBeaver and psycopg.
+            (
+                Regex::new("(?i)^(SET extra_float_digits(.*))").unwrap(),
+                None,
+            ),
+            (Regex::new("(?i)^(SET application_name(.*))").unwrap(), None),
+            (Regex::new("(?i)^(SET client_encoding(.*))").unwrap(), None),
+            (Regex::new("(?i)^(SET datestyle(.*))").unwrap(), None),
+            (Regex::new("(?i)^(SET search_path(.*))").unwrap(), None),
+            (
+                Regex::new("(?i)^(SET SESSION CHARACTERISTICS(.*))").unwrap(),
+                None,
+            ),
+            (Regex::new("(?i)^(SET TRANSACTION(.*))").unwrap(), None),
+            (
+                Regex::new("(?i)^(SET statement_timeout(.*))").unwrap(),
+                None,
+            ),
+            (Regex::new("(?i)^(DISCARD ALL(.*))").unwrap(), None),
+            (
+                Regex::new("(?i)^(SHOW TRANSACTION ISOLATION LEVEL(.*))").unwrap(),
+                PostgresFederated::value_block("transaction_isolation", "read committed"),
+            ),
+            (
+                Regex::new("(?i)^(SHOW server_version(\\s*;)?\\s*)$").unwrap(),
+                PostgresFederated::value_block("server_version", POSTGRES_VERSION),
+            ),
+            (
+                Regex::new("(?i)^(SHOW standard_conforming_strings(.*))").unwrap(),
+                PostgresFederated::value_block("standard_conforming_strings", "off"),
+            ),
+            (
+                Regex::new("(?i)^(SHOW client_encoding(.*))").unwrap(),
+                PostgresFederated::value_block("client_encoding", "UTF8"),
+            ),
+        ];
+
+        FederatedHelper::block_match_rule(query, &MIXED_RULES)
+    }
+
+    // Check the query is a federated or driver setup command.
+    // Here we fake some values for the command which Databend not supported.
+    pub fn check(&self, query: &str) -> Option<(DataSchemaRef, DataBlock)> {
+        let query = query.trim();
+        let select = self
+            .federated_select_check(query)
+            .map(|(schema, chunk)| (Arc::new(DataSchema::from(schema)), chunk));
+        if select.is_some() {
+            return select;
+        }
+
+        self.federated_mixed_check(query)
+            .map(|(schema, chunk)| (Arc::new(DataSchema::from(schema)), chunk))
+    }
+}
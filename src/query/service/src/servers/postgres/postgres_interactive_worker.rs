@@ -0,0 +1,745 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
+
+use chrono_tz::Tz;
+use common_base::base::tokio::io::AsyncRead;
+use common_base::base::tokio::io::AsyncWrite;
+use common_base::runtime::TrySpawn;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_exception::ToErrorCode;
+use common_expression::DataSchemaRef;
+use common_expression::SendableDataBlockStream;
+use common_formats::field_encoder::FieldEncoderValues;
+use common_sql::Planner;
+use futures_util::StreamExt;
+use log::error;
+use log::info;
+use minitrace::full_name;
+use minitrace::prelude::*;
+use rand::Rng;
+
+use crate::auth::AuthMgr;
+use crate::auth::Credential;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterQueryLog;
+use crate::servers::postgres::postgres_protocol::BackendMessage;
+use crate::servers::postgres::postgres_protocol::FieldDescription;
+use crate::servers::postgres::postgres_protocol::FrontendMessage;
+use crate::servers::postgres::postgres_protocol::MessageWriter;
+use crate::servers::postgres::postgres_protocol::StartupMessage;
+use crate::servers::postgres::postgres_protocol::FORMAT_BINARY;
+use crate::servers::postgres::postgres_protocol::FORMAT_TEXT;
+use crate::servers::postgres::postgres_types::param_to_literal;
+use crate::servers::postgres::postgres_types::type_len;
+use crate::servers::postgres::postgres_types::type_oid;
+use crate::servers::postgres::postgres_types::ValueEncoder;
+use crate::servers::postgres::postgres_types::TEXT_OID;
+use crate::servers::postgres::postgres_types::UNSPECIFIED_OID;
+use crate::servers::postgres::PostgresFederated;
+use crate::servers::postgres::POSTGRES_VERSION;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::TableContext;
+use crate::stream::DataBlockStream;
+
+const READY_FOR_QUERY_IDLE: u8 = b'I';
+
+struct PreparedStatement {
+    query: String,
+    param_types: Vec<u32>,
+}
+
+struct Portal {
+    query: String,
+    result_formats: Vec<i16>,
+}
+
+struct QueryResult {
+    blocks: SendableDataBlockStream,
+    // `None` if the query has no result set.
+    schema: Option<DataSchemaRef>,
+    context: Option<Arc<QueryContext>>,
+    timezone: Tz,
+}
+
+/// Serves a PostgreSQL connection: the startup, the simple query protocol and the extended
+/// query protocol (Parse/Bind/Describe/Execute/Sync).
+///
+/// The parameters of the prepared statements (`$1`, `$2`...) are bound by rendering them as
+/// SQL literals into the query, which is planned and executed by the interpreters as usual.
+pub struct InteractiveWorker {
+    session: Arc<Session>,
+    client_addr: String,
+    statements: HashMap<String, PreparedStatement>,
+    portals: HashMap<String, Portal>,
+}
+
+impl InteractiveWorker {
+    pub fn create(session: Arc<Session>, client_addr: String) -> InteractiveWorker {
+        InteractiveWorker {
+            session,
+            client_addr,
+            statements: HashMap::new(),
+            portals: HashMap::new(),
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn run<R, W>(mut self, mut reader: R, mut writer: MessageWriter<W>) -> Result<()>
+    where
+        R: AsyncRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        if !self.startup(&mut reader, &mut writer).await? {
+            return Ok(());
+        }
+
+        // After an error in the extended query protocol, the messages are discarded until Sync.
+        let mut skip_until_sync = false;
+        while let Some(message) = FrontendMessage::read(&mut reader).await? {
+            if self.session.is_aborting() {
+                let error = ErrorCode::AbortedSession(
+                    "Aborting this connection. because we are try aborting server.",
+                );
+                writer.send(error_response(&error)).await?;
+                writer.flush().await?;
+                return Err(error);
+            }
+
+            match message {
+                FrontendMessage::Terminate => break,
+                FrontendMessage::Sync => {
+                    skip_until_sync = false;
+                    self.portals.clear();
+                    writer
+                        .send(BackendMessage::ReadyForQuery(READY_FOR_QUERY_IDLE))
+                        .await?;
+                    writer.flush().await?;
+                }
+                FrontendMessage::Flush => writer.flush().await?,
+                _ if skip_until_sync => {}
+                FrontendMessage::Query(query) => {
+                    let root = Span::root(full_name!(), SpanContext::random());
+                    if let Err(error) = self.simple_query(&query, &mut writer).in_span(root).await {
+                        writer.send(error_response(&error)).await?;
+                    }
+                    writer
+                        .send(BackendMessage::ReadyForQuery(READY_FOR_QUERY_IDLE))
+                        .await?;
+                    writer.flush().await?;
+                }
+                message => {
+                    if let Err(error) = self.extended_query(message, &mut writer).await {
+                        writer.send(error_response(&error)).await?;
+                        skip_until_sync = true;
+                    }
+                }
+            }
+        }
+        writer.flush().await
+    }
+
+    // Returns false if the connection is to be closed.
+    #[async_backtrace::framed]
+    async fn startup<R, W>(&mut self, reader: &mut R, writer: &mut MessageWriter<W>) -> Result<bool>
+    where
+        R: AsyncRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        let params = match read_startup_params(reader, writer).await? {
+            Some(params) => params,
+            None => return Ok(false),
+        };
+
+        let Some(user) = params.get("user").cloned() else {
+            let error = ErrorCode::AuthenticateFailure("No PostgreSQL user name specified");
+            writer.send(error_response(&error)).await?;
+            writer.flush().await?;
+            return Ok(false);
+        };
+
+        writer
+            .send(BackendMessage::AuthenticationCleartextPassword)
+            .await?;
+        writer.flush().await?;
+        let password = match FrontendMessage::read(reader).await? {
+            Some(FrontendMessage::Password(password)) => password,
+            _ => return Ok(false),
+        };
+
+        let client_ip = self.client_addr.split(':').collect::<Vec<_>>()[0].to_string();
+        let credential = Credential::Password {
+            name: user.clone(),
+            password: Some(password),
+            client_ip: Some(client_ip),
+        };
+        if let Err(failure) = AuthMgr::instance()
+            .auth(self.session.clone(), &credential)
+            .await
+        {
+            error!(
+                "PostgreSQL handler authenticate failed, \
+                    user_name: {}, \
+                    client_address: {}, \
+                    failure_cause: {}",
+                user, self.client_addr, failure
+            );
+            let error = ErrorCode::AuthenticateFailure(format!(
+                "password authentication failed for user \"{}\"",
+                user
+            ));
+            writer.send(error_response(&error)).await?;
+            writer.flush().await?;
+            return Ok(false);
+        }
+
+        if let Some(database) = params.get("database").filter(|db| !db.is_empty()) {
+            let query = format!("USE `{}`", database.replace('`', "``"));
+            if let Err(error) = self.drain_query(&query).await {
+                writer.send(error_response(&error)).await?;
+                writer.flush().await?;
+                return Ok(false);
+            }
+        }
+
+        writer.send(BackendMessage::AuthenticationOk).await?;
+        let timezone = self.session.get_settings().get_timezone()?;
+        let application_name = params.get("application_name").cloned().unwrap_or_default();
+        for (name, value) in [
+            ("server_version", POSTGRES_VERSION.to_string()),
+            ("server_encoding", "UTF8".to_string()),
+            ("client_encoding", "UTF8".to_string()),
+            ("DateStyle", "ISO, YMD".to_string()),
+            ("TimeZone", timezone),
+            ("integer_datetimes", "on".to_string()),
+            // The string literals of Databend take backslash escapes.
+            ("standard_conforming_strings", "off".to_string()),
+            ("is_superuser", "off".to_string()),
+            ("session_authorization", user),
+            ("application_name", application_name),
+        ] {
+            writer
+                .send(BackendMessage::ParameterStatus(name, value))
+                .await?;
+        }
+        // Canceling queries by the key is not supported, the key is random.
+        let mut rng = rand::thread_rng();
+        writer
+            .send(BackendMessage::BackendKeyData {
+                process_id: rng.gen(),
+                secret_key: rng.gen(),
+            })
+            .await?;
+        writer
+            .send(BackendMessage::ReadyForQuery(READY_FOR_QUERY_IDLE))
+            .await?;
+        writer.flush().await?;
+        Ok(true)
+    }
+
+    #[async_backtrace::framed]
+    async fn simple_query<W: AsyncWrite + Unpin>(
+        &mut self,
+        query: &str,
+        writer: &mut MessageWriter<W>,
+    ) -> Result<()> {
+        if is_empty_query(query) {
+            return writer.send(BackendMessage::EmptyQueryResponse).await;
+        }
+        let result = self
+            .do_query(query)
+            .await
+            .map_err(|err| err.display_with_sql(query))?;
+        Self::write_result(query, result, &[], true, writer).await
+    }
+
+    #[async_backtrace::framed]
+    async fn extended_query<W: AsyncWrite + Unpin>(
+        &mut self,
+        message: FrontendMessage,
+        writer: &mut MessageWriter<W>,
+    ) -> Result<()> {
+        match message {
+            FrontendMessage::Parse {
+                name,
+                query,
+                param_types,
+            } => {
+                if !name.is_empty() && self.statements.contains_key(&name) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "prepared statement \"{}\" already exists",
+                        name
+                    )));
+                }
+                self.statements
+                    .insert(name, PreparedStatement { query, param_types });
+                writer.send(BackendMessage::ParseComplete).await
+            }
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            } => {
+                let statement = self.get_statement(&statement)?;
+                if param_formats.len() > 1 && param_formats.len() != params.len() {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "bind message has {} parameter formats but {} parameters",
+                        param_formats.len(),
+                        params.len()
+                    )));
+                }
+                let literals = params
+                    .iter()
+                    .enumerate()
+                    .map(|(i, param)| {
+                        let oid = statement
+                            .param_types
+                            .get(i)
+                            .cloned()
+                            .unwrap_or(UNSPECIFIED_OID);
+                        let format = format_at(&param_formats, i);
+                        param_to_literal(oid, format, param.as_deref())
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let query =
+                    replace_placeholders(&statement.query, |n| literals.get(n - 1).cloned());
+                self.portals.insert(portal, Portal {
+                    query,
+                    result_formats,
+                });
+                writer.send(BackendMessage::BindComplete).await
+            }
+            FrontendMessage::Describe { kind: b'S', name } => {
+                let statement = self.get_statement(&name)?;
+                let mut num_params = 0;
+                let query = replace_placeholders(&statement.query, |n| {
+                    num_params = num_params.max(n);
+                    Some("NULL".to_string())
+                });
+                let param_types = (0..num_params.max(statement.param_types.len()))
+                    .map(|i| match statement.param_types.get(i) {
+                        Some(oid) if *oid != UNSPECIFIED_OID => *oid,
+                        _ => TEXT_OID,
+                    })
+                    .collect();
+                writer
+                    .send(BackendMessage::ParameterDescription(param_types))
+                    .await?;
+                let schema = self.describe_query(&query).await?;
+                writer.send(row_description(schema.as_ref(), &[])).await
+            }
+            FrontendMessage::Describe { kind: b'P', name } => {
+                let portal = self.get_portal(&name)?;
+                let (query, result_formats) = (portal.query.clone(), portal.result_formats.clone());
+                let schema = self.describe_query(&query).await?;
+                writer
+                    .send(row_description(schema.as_ref(), &result_formats))
+                    .await
+            }
+            FrontendMessage::Execute { portal, .. } => {
+                // All the rows are returned, the row limit of the portal is ignored.
+                let portal = self.get_portal(&portal)?;
+                let (query, result_formats) = (portal.query.clone(), portal.result_formats.clone());
+                if is_empty_query(&query) {
+                    return writer.send(BackendMessage::EmptyQueryResponse).await;
+                }
+                let root = Span::root(full_name!(), SpanContext::random());
+                let result = self
+                    .do_query(&query)
+                    .in_span(root)
+                    .await
+                    .map_err(|err| err.display_with_sql(&query))?;
+                Self::write_result(&query, result, &result_formats, false, writer).await
+            }
+            FrontendMessage::Close { kind, name } => {
+                if kind == b'S' {
+                    self.statements.remove(&name);
+                } else {
+                    self.portals.remove(&name);
+                }
+                writer.send(BackendMessage::CloseComplete).await
+            }
+            FrontendMessage::Describe { kind, .. } => Err(ErrorCode::BadBytes(format!(
+                "Invalid describe kind {}",
+                kind as char
+            ))),
+            FrontendMessage::Password(_) => Err(ErrorCode::BadBytes(
+                "Unexpected password message, the connection is authenticated",
+            )),
+            FrontendMessage::Unsupported(tag) => Err(ErrorCode::Unimplemented(format!(
+                "Unsupported PostgreSQL message type '{}'",
+                tag as char
+            ))),
+            FrontendMessage::Query(_)
+            | FrontendMessage::Sync
+            | FrontendMessage::Flush
+            | FrontendMessage::Terminate => unreachable!("handled by the message loop"),
+        }
+    }
+
+    fn get_statement(&self, name: &str) -> Result<&PreparedStatement> {
+        self.statements.get(name).ok_or_else(|| {
+            ErrorCode::BadArguments(format!("prepared statement \"{}\" does not exist", name))
+        })
+    }
+
+    fn get_portal(&self, name: &str) -> Result<&Portal> {
+        self.portals
+            .get(name)
+            .ok_or_else(|| ErrorCode::BadArguments(format!("portal \"{}\" does not exist", name)))
+    }
+
+    // Plans the query without executing it, returns the schema of its result set.
+    #[async_backtrace::framed]
+    async fn describe_query(&self, query: &str) -> Result<Option<DataSchemaRef>> {
+        if is_empty_query(query) {
+            return Ok(None);
+        }
+        if let Some((schema, _)) = PostgresFederated::create().check(query) {
+            return Ok(Some(schema).filter(|schema| !schema.fields().is_empty()));
+        }
+
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context);
+        let (plan, _) = planner.plan_sql(query).await?;
+        Ok(plan.has_result_set().then(|| plan.schema()))
+    }
+
+    // Executes the query for its side effects only, such as USE.
+    #[async_backtrace::framed]
+    async fn drain_query(&self, query: &str) -> Result<()> {
+        let mut result = self.do_query(query).await?;
+        while let Some(block) = result.blocks.next().await {
+            block?;
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn do_query(&self, query: &str) -> Result<QueryResult> {
+        if let Some((schema, data_block)) = PostgresFederated::create().check(query) {
+            info!("Federated query: {}", query);
+            return Ok(QueryResult {
+                blocks: DataBlockStream::create(None, vec![data_block]).boxed(),
+                schema: Some(schema).filter(|schema| !schema.fields().is_empty()),
+                context: None,
+                timezone: self.session.get_format_settings().timezone,
+            });
+        }
+
+        info!("Normal query: {}", query);
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context.clone());
+        let (plan, extras) = planner.plan_sql(query).await?;
+
+        context.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
+        let interpreter = match InterpreterFactory::get(context.clone(), &plan).await {
+            Ok(interpreter) => interpreter,
+            Err(e) => {
+                InterpreterQueryLog::fail_to_start(context, e.clone());
+                return Err(e);
+            }
+        };
+        let blocks = Self::exec_query(interpreter, &context).await?;
+        Ok(QueryResult {
+            blocks,
+            schema: plan.has_result_set().then(|| plan.schema()),
+            timezone: context.get_format_settings()?.timezone,
+            context: Some(context),
+        })
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn exec_query(
+        interpreter: Arc<dyn Interpreter>,
+        context: &Arc<QueryContext>,
+    ) -> Result<SendableDataBlockStream> {
+        let query_result = context.try_spawn({
+            let ctx = context.clone();
+            async move { interpreter.execute(ctx).await }
+                .in_span(Span::enter_with_local_parent(full_name!()))
+        })?;
+
+        query_result.await.map_err_to_code(
+            ErrorCode::TokioError,
+            || "Cannot join handle from context's runtime",
+        )?
+    }
+
+    #[async_backtrace::framed]
+    async fn write_result<W: AsyncWrite + Unpin>(
+        query: &str,
+        mut result: QueryResult,
+        result_formats: &[i16],
+        send_description: bool,
+        writer: &mut MessageWriter<W>,
+    ) -> Result<()> {
+        let instant = Instant::now();
+        let Some(schema) = result.schema else {
+            // For statements without result sets, we still need to pull the stream because errors may occur in the stream.
+            while let Some(block) = result.blocks.next().await {
+                block.map_err(|err| err.display_with_sql(query))?;
+            }
+            let affected_rows = result
+                .context
+                .map(|ctx| ctx.get_write_progress_value().rows)
+                .unwrap_or_default();
+            return writer
+                .send(BackendMessage::CommandComplete(command_tag(
+                    query,
+                    affected_rows,
+                )))
+                .await;
+        };
+
+        if send_description {
+            writer
+                .send(row_description(Some(&schema), result_formats))
+                .await?;
+        }
+
+        let oids = schema
+            .fields()
+            .iter()
+            .map(|field| type_oid(field.data_type()))
+            .collect::<Vec<_>>();
+        let mut encoder = ValueEncoder::create(FieldEncoderValues::create_for_postgres_handler(
+            result.timezone,
+        ));
+        let mut num_rows = 0;
+        while let Some(block) = result.blocks.next().await {
+            let block = block.map_err(|err| err.display_with_sql(query))?;
+            let columns = block
+                .convert_to_full()
+                .columns()
+                .iter()
+                .map(|column| column.value.clone().into_column().unwrap())
+                .collect::<Vec<_>>();
+            for row_index in 0..block.num_rows() {
+                let values = columns
+                    .iter()
+                    .enumerate()
+                    .map(|(i, column)| {
+                        encoder.encode(column, row_index, oids[i], format_at(result_formats, i))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                writer.send(BackendMessage::DataRow(values)).await?;
+            }
+            num_rows += block.num_rows();
+        }
+        info!(
+            "PostgreSQL handler sent {} rows in {:?}",
+            num_rows,
+            instant.elapsed()
+        );
+        writer
+            .send(BackendMessage::CommandComplete(format!(
+                "SELECT {}",
+                num_rows
+            )))
+            .await
+    }
+}
+
+#[async_backtrace::framed]
+async fn read_startup_params<R, W>(
+    reader: &mut R,
+    writer: &mut MessageWriter<W>,
+) -> Result<Option<HashMap<String, String>>>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    loop {
+        match StartupMessage::read(reader).await? {
+            // The encryption is not supported, the client may go on unencrypted.
+            StartupMessage::SslRequest | StartupMessage::GssEncRequest => {
+                writer.write_byte(b'N').await?
+            }
+            // Canceling queries is not supported.
+            StartupMessage::CancelRequest { .. } => return Ok(None),
+            StartupMessage::Startup { params } => return Ok(Some(params)),
+        }
+    }
+}
+
+/// Sends an error to a client whose connection is rejected, once it sends the startup message.
+#[async_backtrace::framed]
+pub async fn reject_connection<R, W>(
+    reader: &mut R,
+    writer: &mut MessageWriter<W>,
+    error: &ErrorCode,
+) -> Result<()>
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    if read_startup_params(reader, writer).await?.is_some() {
+        writer.send(error_response(error)).await?;
+        writer.flush().await?;
+    }
+    Ok(())
+}
+
+fn row_description(schema: Option<&DataSchemaRef>, result_formats: &[i16]) -> BackendMessage {
+    match schema {
+        None => BackendMessage::NoData,
+        Some(schema) => BackendMessage::RowDescription(
+            schema
+                .fields()
+                .iter()
+                .enumerate()
+                .map(|(i, field)| {
+                    let type_oid = type_oid(field.data_type());
+                    FieldDescription {
+                        name: field.name().to_string(),
+                        type_oid,
+                        type_len: type_len(type_oid),
+                        format: format_at(result_formats, i),
+                    }
+                })
+                .collect(),
+        ),
+    }
+}
+
+// No format means text, a single format applies to all the columns or parameters.
+fn format_at(formats: &[i16], index: usize) -> i16 {
+    match formats {
+        [] => FORMAT_TEXT,
+        [format] => *format,
+        formats => formats.get(index).cloned().unwrap_or(FORMAT_TEXT),
+    }
+}
+
+fn is_empty_query(query: &str) -> bool {
+    query.trim().trim_matches(';').trim().is_empty()
+}
+
+fn command_tag(query: &str, affected_rows: usize) -> String {
+    let keyword = query
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .trim_end_matches(';')
+        .to_ascii_uppercase();
+    match keyword.as_str() {
+        "INSERT" => format!("INSERT 0 {}", affected_rows),
+        "UPDATE" | "DELETE" | "MERGE" | "REPLACE" | "COPY" => {
+            format!("{} {}", keyword, affected_rows)
+        }
+        _ => keyword,
+    }
+}
+
+fn error_response(error: &ErrorCode) -> BackendMessage {
+    let code = match error.code() {
+        ErrorCode::AUTHENTICATE_FAILURE => "28P01",
+        ErrorCode::UNKNOWN_USER => "28000",
+        ErrorCode::PERMISSION_DENIED => "42501",
+        ErrorCode::SYNTAX_EXCEPTION => "42601",
+        ErrorCode::SEMANTIC_ERROR => "42000",
+        ErrorCode::UNKNOWN_DATABASE => "3D000",
+        ErrorCode::UNKNOWN_TABLE => "42P01",
+        ErrorCode::UNKNOWN_COLUMN => "42703",
+        ErrorCode::TABLE_ALREADY_EXISTS => "42P07",
+        ErrorCode::UNIMPLEMENTED => "0A000",
+        ErrorCode::TOO_MANY_USER_CONNECTIONS => "53300",
+        ErrorCode::ABORTED_QUERY => "57014",
+        ErrorCode::ABORTED_SESSION => "57P01",
+        _ => "XX000",
+    };
+    let severity = match error.code() {
+        ErrorCode::AUTHENTICATE_FAILURE
+        | ErrorCode::UNKNOWN_USER
+        | ErrorCode::TOO_MANY_USER_CONNECTIONS
+        | ErrorCode::ABORTED_SESSION => "FATAL",
+        _ => "ERROR",
+    };
+    if severity == "ERROR" && error.code() != ErrorCode::ABORTED_QUERY {
+        error!("OnQuery Error: {:?}", error);
+    }
+    BackendMessage::ErrorResponse {
+        severity,
+        code,
+        message: error.to_string(),
+    }
+}
+
+/// Replaces the placeholders `$n` outside of the quoted strings, identifiers and comments by
+/// `param(n)`, the placeholders without a replacement are kept, such as the column references
+/// of the staged files (`SELECT $1 FROM @stage`).
+fn replace_placeholders(query: &str, mut param: impl FnMut(usize) -> Option<String>) -> String {
+    let bytes = query.as_bytes();
+    let mut output = String::with_capacity(query.len());
+    let mut copied = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'\'' | b'"' | b'`') => {
+                i += 1;
+                while i < bytes.len() && bytes[i] != quote {
+                    // Backslash escapes.
+                    if bytes[i] == b'\\' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+                i += 1;
+            }
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += 2;
+                while i < bytes.len() && !(bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/')) {
+                    i += 1;
+                }
+                i += 2;
+            }
+            b'$' => {
+                let start = i;
+                i += 1;
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+                if let Some(value) = query[start + 1..i]
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|n| *n > 0)
+                    .and_then(&mut param)
+                {
+                    output.push_str(&query[copied..start]);
+                    output.push_str(&value);
+                    copied = i;
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    output.push_str(&query[copied.min(query.len())..]);
+    output
+}
@@ -0,0 +1,446 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Messages of the PostgreSQL frontend/backend protocol version 3.0.
+//!
+//! https://www.postgresql.org/docs/current/protocol-message-formats.html
+
+use std::collections::HashMap;
+
+use common_base::base::tokio::io::AsyncRead;
+use common_base::base::tokio::io::AsyncReadExt;
+use common_base::base::tokio::io::AsyncWrite;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_exception::ErrorCode;
+use common_exception::Result;
+
+pub const PROTOCOL_VERSION_3: i32 = 196608;
+pub const CANCEL_REQUEST_CODE: i32 = 80877102;
+pub const SSL_REQUEST_CODE: i32 = 80877103;
+pub const GSSENC_REQUEST_CODE: i32 = 80877104;
+
+// Messages larger than this are rejected instead of being buffered.
+const MAX_MESSAGE_LEN: usize = 1 << 30;
+
+pub const FORMAT_TEXT: i16 = 0;
+pub const FORMAT_BINARY: i16 = 1;
+
+pub enum StartupMessage {
+    SslRequest,
+    GssEncRequest,
+    CancelRequest { process_id: i32, secret_key: i32 },
+    Startup { params: HashMap<String, String> },
+}
+
+pub enum FrontendMessage {
+    Query(String),
+    Parse {
+        name: String,
+        query: String,
+        param_types: Vec<u32>,
+    },
+    Bind {
+        portal: String,
+        statement: String,
+        param_formats: Vec<i16>,
+        params: Vec<Option<Vec<u8>>>,
+        result_formats: Vec<i16>,
+    },
+    // `kind` is b'S' for a statement or b'P' for a portal.
+    Describe {
+        kind: u8,
+        name: String,
+    },
+    Execute {
+        portal: String,
+        max_rows: i32,
+    },
+    Close {
+        kind: u8,
+        name: String,
+    },
+    Password(Vec<u8>),
+    Sync,
+    Flush,
+    Terminate,
+    Unsupported(u8),
+}
+
+pub struct FieldDescription {
+    pub name: String,
+    pub type_oid: u32,
+    pub type_len: i16,
+    pub format: i16,
+}
+
+pub enum BackendMessage {
+    AuthenticationOk,
+    AuthenticationCleartextPassword,
+    ParameterStatus(&'static str, String),
+    BackendKeyData {
+        process_id: i32,
+        secret_key: i32,
+    },
+    // `status` is b'I' if idle, b'T' in a transaction block, b'E' in a failed transaction block.
+    ReadyForQuery(u8),
+    ParseComplete,
+    BindComplete,
+    CloseComplete,
+    ParameterDescription(Vec<u32>),
+    RowDescription(Vec<FieldDescription>),
+    NoData,
+    DataRow(Vec<Option<Vec<u8>>>),
+    CommandComplete(String),
+    EmptyQueryResponse,
+    ErrorResponse {
+        severity: &'static str,
+        code: &'static str,
+        message: String,
+    },
+}
+
+struct MessageBody<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> MessageBody<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        MessageBody { buf, pos: 0 }
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.pos + len > self.buf.len() {
+            return Err(ErrorCode::BadBytes(
+                "Invalid PostgreSQL message, unexpected end",
+            ));
+        }
+        let bytes = &self.buf[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn i16(&mut self) -> Result<i16> {
+        let bytes = self.bytes(2)?;
+        Ok(i16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn i32(&mut self) -> Result<i32> {
+        let bytes = self.bytes(4)?;
+        Ok(i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn cstr(&mut self) -> Result<String> {
+        let rest = &self.buf[self.pos..];
+        let len = rest.iter().position(|b| *b == 0).ok_or_else(|| {
+            ErrorCode::BadBytes("Invalid PostgreSQL message, unterminated string")
+        })?;
+        self.pos += len + 1;
+        String::from_utf8(rest[..len].to_vec())
+            .map_err(|_| ErrorCode::BadBytes("Invalid PostgreSQL message, string is not UTF-8"))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.buf.len()
+    }
+}
+
+async fn read_body<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Vec<u8>> {
+    let len = reader.read_i32().await?;
+    if len < 4 || len as usize > MAX_MESSAGE_LEN {
+        return Err(ErrorCode::BadBytes(format!(
+            "Invalid PostgreSQL message length {}",
+            len
+        )));
+    }
+    let mut body = vec![0; len as usize - 4];
+    reader.read_exact(&mut body).await?;
+    Ok(body)
+}
+
+impl StartupMessage {
+    #[async_backtrace::framed]
+    pub async fn read<R: AsyncRead + Unpin>(reader: &mut R) -> Result<StartupMessage> {
+        let body = read_body(reader).await?;
+        let mut body = MessageBody::new(&body);
+        match body.i32()? {
+            SSL_REQUEST_CODE => Ok(StartupMessage::SslRequest),
+            GSSENC_REQUEST_CODE => Ok(StartupMessage::GssEncRequest),
+            CANCEL_REQUEST_CODE => Ok(StartupMessage::CancelRequest {
+                process_id: body.i32()?,
+                secret_key: body.i32()?,
+            }),
+            PROTOCOL_VERSION_3 => {
+                let mut params = HashMap::new();
+                loop {
+                    let name = body.cstr()?;
+                    if name.is_empty() {
+                        break;
+                    }
+                    let value = body.cstr()?;
+                    params.insert(name, value);
+                }
+                Ok(StartupMessage::Startup { params })
+            }
+            version => Err(ErrorCode::Unimplemented(format!(
+                "Unsupported PostgreSQL protocol version {}.{}",
+                version >> 16,
+                version & 0xFFFF
+            ))),
+        }
+    }
+}
+
+impl FrontendMessage {
+    /// Reads the next message, `None` if the client closed the connection.
+    #[async_backtrace::framed]
+    pub async fn read<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Option<FrontendMessage>> {
+        let tag = match reader.read_u8().await {
+            Ok(tag) => tag,
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let body = read_body(reader).await?;
+        let mut body = MessageBody::new(&body);
+        let message = match tag {
+            b'Q' => FrontendMessage::Query(body.cstr()?),
+            b'P' => {
+                let name = body.cstr()?;
+                let query = body.cstr()?;
+                let num_types = body.i16()?;
+                let param_types = (0..num_types)
+                    .map(|_| body.i32().map(|oid| oid as u32))
+                    .collect::<Result<_>>()?;
+                FrontendMessage::Parse {
+                    name,
+                    query,
+                    param_types,
+                }
+            }
+            b'B' => {
+                let portal = body.cstr()?;
+                let statement = body.cstr()?;
+                let num_formats = body.i16()?;
+                let param_formats = (0..num_formats)
+                    .map(|_| body.i16())
+                    .collect::<Result<_>>()?;
+                let num_params = body.i16()?;
+                let mut params = Vec::with_capacity(num_params.max(0) as usize);
+                for _ in 0..num_params {
+                    let len = body.i32()?;
+                    params.push(match len {
+                        -1 => None,
+                        len => Some(body.bytes(len.max(0) as usize)?.to_vec()),
+                    });
+                }
+                let num_formats = body.i16()?;
+                let result_formats = (0..num_formats)
+                    .map(|_| body.i16())
+                    .collect::<Result<_>>()?;
+                FrontendMessage::Bind {
+                    portal,
+                    statement,
+                    param_formats,
+                    params,
+                    result_formats,
+                }
+            }
+            b'D' => FrontendMessage::Describe {
+                kind: body.u8()?,
+                name: body.cstr()?,
+            },
+            b'E' => FrontendMessage::Execute {
+                portal: body.cstr()?,
+                max_rows: if body.is_empty() { 0 } else { body.i32()? },
+            },
+            b'C' => FrontendMessage::Close {
+                kind: body.u8()?,
+                name: body.cstr()?,
+            },
+            b'p' => {
+                let password = body.bytes(body.buf.len())?;
+                // The password is a null-terminated string.
+                let password = password.strip_suffix(&[0]).unwrap_or(password);
+                FrontendMessage::Password(password.to_vec())
+            }
+            b'S' => FrontendMessage::Sync,
+            b'H' => FrontendMessage::Flush,
+            b'X' => FrontendMessage::Terminate,
+            tag => FrontendMessage::Unsupported(tag),
+        };
+        Ok(Some(message))
+    }
+}
+
+impl BackendMessage {
+    pub fn encode(&self, buf: &mut Vec<u8>) {
+        let (tag, start) = (self.tag(), buf.len());
+        buf.push(tag);
+        // The length is filled in after the body is written.
+        buf.extend_from_slice(&[0; 4]);
+
+        match self {
+            BackendMessage::AuthenticationOk => buf.extend_from_slice(&0i32.to_be_bytes()),
+            BackendMessage::AuthenticationCleartextPassword => {
+                buf.extend_from_slice(&3i32.to_be_bytes())
+            }
+            BackendMessage::ParameterStatus(name, value) => {
+                put_cstr(buf, name);
+                put_cstr(buf, value);
+            }
+            BackendMessage::BackendKeyData {
+                process_id,
+                secret_key,
+            } => {
+                buf.extend_from_slice(&process_id.to_be_bytes());
+                buf.extend_from_slice(&secret_key.to_be_bytes());
+            }
+            BackendMessage::ReadyForQuery(status) => buf.push(*status),
+            BackendMessage::ParseComplete
+            | BackendMessage::BindComplete
+            | BackendMessage::CloseComplete
+            | BackendMessage::NoData
+            | BackendMessage::EmptyQueryResponse => {}
+            BackendMessage::ParameterDescription(types) => {
+                buf.extend_from_slice(&(types.len() as i16).to_be_bytes());
+                for oid in types {
+                    buf.extend_from_slice(&oid.to_be_bytes());
+                }
+            }
+            BackendMessage::RowDescription(fields) => {
+                buf.extend_from_slice(&(fields.len() as i16).to_be_bytes());
+                for field in fields {
+                    put_cstr(buf, &field.name);
+                    // table oid and column attribute number, the columns are not from tables.
+                    buf.extend_from_slice(&0i32.to_be_bytes());
+                    buf.extend_from_slice(&0i16.to_be_bytes());
+                    buf.extend_from_slice(&field.type_oid.to_be_bytes());
+                    buf.extend_from_slice(&field.type_len.to_be_bytes());
+                    // type modifier
+                    buf.extend_from_slice(&(-1i32).to_be_bytes());
+                    buf.extend_from_slice(&field.format.to_be_bytes());
+                }
+            }
+            BackendMessage::DataRow(values) => {
+                buf.extend_from_slice(&(values.len() as i16).to_be_bytes());
+                for value in values {
+                    match value {
+                        None => buf.extend_from_slice(&(-1i32).to_be_bytes()),
+                        Some(value) => {
+                            buf.extend_from_slice(&(value.len() as i32).to_be_bytes());
+                            buf.extend_from_slice(value);
+                        }
+                    }
+                }
+            }
+            BackendMessage::CommandComplete(tag) => put_cstr(buf, tag),
+            BackendMessage::ErrorResponse {
+                severity,
+                code,
+                message,
+            } => {
+                buf.push(b'S');
+                put_cstr(buf, severity);
+                buf.push(b'V');
+                put_cstr(buf, severity);
+                buf.push(b'C');
+                put_cstr(buf, code);
+                buf.push(b'M');
+                put_cstr(buf, message);
+                buf.push(0);
+            }
+        }
+
+        let len = (buf.len() - start - 1) as i32;
+        buf[start + 1..start + 5].copy_from_slice(&len.to_be_bytes());
+    }
+
+    fn tag(&self) -> u8 {
+        match self {
+            BackendMessage::AuthenticationOk | BackendMessage::AuthenticationCleartextPassword => {
+                b'R'
+            }
+            BackendMessage::ParameterStatus(_, _) => b'S',
+            BackendMessage::BackendKeyData { .. } => b'K',
+            BackendMessage::ReadyForQuery(_) => b'Z',
+            BackendMessage::ParseComplete => b'1',
+            BackendMessage::BindComplete => b'2',
+            BackendMessage::CloseComplete => b'3',
+            BackendMessage::ParameterDescription(_) => b't',
+            BackendMessage::RowDescription(_) => b'T',
+            BackendMessage::NoData => b'n',
+            BackendMessage::DataRow(_) => b'D',
+            BackendMessage::CommandComplete(_) => b'C',
+            BackendMessage::EmptyQueryResponse => b'I',
+            BackendMessage::ErrorResponse { .. } => b'E',
+        }
+    }
+}
+
+fn put_cstr(buf: &mut Vec<u8>, s: &str) {
+    // A string can't contain the terminator.
+    buf.extend(s.bytes().filter(|b| *b != 0));
+    buf.push(0);
+}
+
+/// Buffers the backend messages, which are sent when flushed.
+pub struct MessageWriter<W: AsyncWrite + Unpin> {
+    inner: W,
+    buf: Vec<u8>,
+    flush_threshold: usize,
+}
+
+impl<W: AsyncWrite + Unpin> MessageWriter<W> {
+    pub fn create(inner: W, flush_threshold: usize) -> Self {
+        MessageWriter {
+            inner,
+            buf: Vec::with_capacity(flush_threshold),
+            flush_threshold,
+        }
+    }
+
+    /// Buffers `message`, writes the buffer out if it grows over the threshold.
+    #[async_backtrace::framed]
+    pub async fn send(&mut self, message: BackendMessage) -> Result<()> {
+        message.encode(&mut self.buf);
+        if self.buf.len() >= self.flush_threshold {
+            self.inner.write_all(&self.buf).await?;
+            self.buf.clear();
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn flush(&mut self) -> Result<()> {
+        if !self.buf.is_empty() {
+            self.inner.write_all(&self.buf).await?;
+            self.buf.clear();
+        }
+        self.inner.flush().await?;
+        Ok(())
+    }
+
+    /// Writes a single byte directly, used to answer the SSL/GSSAPI encryption requests.
+    #[async_backtrace::framed]
+    pub async fn write_byte(&mut self, byte: u8) -> Result<()> {
+        self.flush().await?;
+        self.inner.write_all(&[byte]).await?;
+        self.inner.flush().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,345 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Maps the data types and values of Databend to PostgreSQL.
+
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::Column;
+use common_expression::ScalarRef;
+use common_formats::field_encoder::FieldEncoderRowBased;
+use common_formats::field_encoder::FieldEncoderValues;
+use common_io::escape_string_with_quote;
+
+use crate::servers::postgres::postgres_protocol::FORMAT_BINARY;
+
+pub const UNSPECIFIED_OID: u32 = 0;
+pub const BOOL_OID: u32 = 16;
+pub const INT8_OID: u32 = 20;
+pub const INT2_OID: u32 = 21;
+pub const INT4_OID: u32 = 23;
+pub const TEXT_OID: u32 = 25;
+pub const OID_OID: u32 = 26;
+pub const FLOAT4_OID: u32 = 700;
+pub const FLOAT8_OID: u32 = 701;
+pub const UNKNOWN_OID: u32 = 705;
+pub const BPCHAR_OID: u32 = 1042;
+pub const VARCHAR_OID: u32 = 1043;
+pub const DATE_OID: u32 = 1082;
+pub const TIMESTAMP_OID: u32 = 1114;
+pub const NUMERIC_OID: u32 = 1700;
+
+// The days from 1970-01-01 (the epoch of Databend) to 2000-01-01 (the epoch of PostgreSQL).
+const POSTGRES_EPOCH_DAYS: i32 = 10957;
+
+pub fn type_oid(data_type: &DataType) -> u32 {
+    match data_type.remove_nullable() {
+        DataType::Boolean => BOOL_OID,
+        DataType::Number(num_ty) => match num_ty {
+            NumberDataType::Int8 | NumberDataType::Int16 | NumberDataType::UInt8 => INT2_OID,
+            NumberDataType::Int32 | NumberDataType::UInt16 => INT4_OID,
+            NumberDataType::Int64 | NumberDataType::UInt32 | NumberDataType::UInt64 => INT8_OID,
+            NumberDataType::Float32 => FLOAT4_OID,
+            NumberDataType::Float64 => FLOAT8_OID,
+        },
+        DataType::Decimal(_) => NUMERIC_OID,
+        DataType::Date => DATE_OID,
+        DataType::Timestamp => TIMESTAMP_OID,
+        // The others are sent in their text representations.
+        _ => TEXT_OID,
+    }
+}
+
+pub fn type_len(oid: u32) -> i16 {
+    match oid {
+        BOOL_OID => 1,
+        INT2_OID => 2,
+        INT4_OID | FLOAT4_OID | DATE_OID => 4,
+        INT8_OID | FLOAT8_OID | TIMESTAMP_OID => 8,
+        _ => -1,
+    }
+}
+
+/// Encodes the values of a column in the text or binary format.
+pub struct ValueEncoder {
+    text_encoder: FieldEncoderValues,
+    buf: Vec<u8>,
+}
+
+impl ValueEncoder {
+    pub fn create(text_encoder: FieldEncoderValues) -> Self {
+        ValueEncoder {
+            text_encoder,
+            buf: vec![],
+        }
+    }
+
+    pub fn encode(
+        &mut self,
+        column: &Column,
+        row_index: usize,
+        oid: u32,
+        format: i16,
+    ) -> Result<Option<Vec<u8>>> {
+        let value = unsafe { column.index_unchecked(row_index) };
+        if matches!(value, ScalarRef::Null) {
+            return Ok(None);
+        }
+
+        self.buf.clear();
+        if format != FORMAT_BINARY {
+            self.text_encoder
+                .write_field(column, row_index, &mut self.buf, true);
+            return Ok(Some(self.buf.clone()));
+        }
+
+        let bytes = match (oid, value) {
+            (BOOL_OID, ScalarRef::Boolean(v)) => vec![v as u8],
+            (INT2_OID, ScalarRef::Number(v)) => (number_to_i64(v)? as i16).to_be_bytes().to_vec(),
+            (INT4_OID, ScalarRef::Number(v)) => (number_to_i64(v)? as i32).to_be_bytes().to_vec(),
+            (INT8_OID, ScalarRef::Number(v)) => number_to_i64(v)?.to_be_bytes().to_vec(),
+            (FLOAT4_OID, ScalarRef::Number(NumberScalar::Float32(v))) => v.0.to_be_bytes().to_vec(),
+            (FLOAT8_OID, ScalarRef::Number(NumberScalar::Float64(v))) => v.0.to_be_bytes().to_vec(),
+            (DATE_OID, ScalarRef::Date(v)) => (v - POSTGRES_EPOCH_DAYS).to_be_bytes().to_vec(),
+            (TIMESTAMP_OID, ScalarRef::Timestamp(_)) => {
+                // Encodes the local time displayed in the session timezone.
+                self.text_encoder
+                    .write_field(column, row_index, &mut self.buf, true);
+                let text = String::from_utf8_lossy(&self.buf);
+                let local =
+                    NaiveDateTime::parse_from_str(&text, "%Y-%m-%d %H:%M:%S%.f").map_err(|e| {
+                        ErrorCode::BadBytes(format!("Invalid timestamp {}: {}", text, e))
+                    })?;
+                let micros = (local - postgres_epoch())
+                    .num_microseconds()
+                    .ok_or_else(|| {
+                        ErrorCode::BadBytes(format!("Timestamp {} is out of range", text))
+                    })?;
+                micros.to_be_bytes().to_vec()
+            }
+            (NUMERIC_OID, ScalarRef::Decimal(_)) => {
+                self.text_encoder
+                    .write_field(column, row_index, &mut self.buf, true);
+                encode_numeric(&String::from_utf8_lossy(&self.buf))?
+            }
+            _ => {
+                // The binary format of text is the text itself.
+                self.text_encoder
+                    .write_field(column, row_index, &mut self.buf, true);
+                self.buf.clone()
+            }
+        };
+        Ok(Some(bytes))
+    }
+}
+
+fn postgres_epoch() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2000, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+}
+
+fn number_to_i64(v: NumberScalar) -> Result<i64> {
+    Ok(match v {
+        NumberScalar::Int8(v) => v as i64,
+        NumberScalar::Int16(v) => v as i64,
+        NumberScalar::Int32(v) => v as i64,
+        NumberScalar::Int64(v) => v,
+        NumberScalar::UInt8(v) => v as i64,
+        NumberScalar::UInt16(v) => v as i64,
+        NumberScalar::UInt32(v) => v as i64,
+        NumberScalar::UInt64(v) => i64::try_from(v).map_err(|_| {
+            ErrorCode::BadBytes(format!(
+                "Value {} is out of range of int8 in the binary format",
+                v
+            ))
+        })?,
+        v => {
+            return Err(ErrorCode::BadBytes(format!(
+                "Value {:?} is not an integer",
+                v
+            )));
+        }
+    })
+}
+
+// The binary format of numeric: the number of base-10000 digits, the weight of the first digit,
+// the sign, the display scale, then the digits.
+fn encode_numeric(text: &str) -> Result<Vec<u8>> {
+    let invalid = || ErrorCode::BadBytes(format!("Invalid decimal {}", text));
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    let (int_part, frac_part) = text.split_once('.').unwrap_or((text, ""));
+    if !int_part
+        .bytes()
+        .chain(frac_part.bytes())
+        .all(|b| b.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+
+    // Pads the integer part on the left and the fraction part on the right to groups of 4 digits.
+    let int_part = int_part.trim_start_matches('0');
+    let int_pad = (4 - int_part.len() % 4) % 4;
+    let frac_pad = (4 - frac_part.len() % 4) % 4;
+    let digits = "0".repeat(int_pad) + int_part + frac_part + &"0".repeat(frac_pad);
+    let mut groups = digits
+        .as_bytes()
+        .chunks(4)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .fold(0i16, |acc, digit| acc * 10 + (digit - b'0') as i16)
+        })
+        .collect::<Vec<_>>();
+    let mut weight = ((int_pad + int_part.len()) / 4) as i16 - 1;
+
+    // Strips the leading and the trailing zero groups.
+    let leading = groups.iter().take_while(|group| **group == 0).count();
+    groups.drain(..leading);
+    weight -= leading as i16;
+    while groups.last() == Some(&0) {
+        groups.pop();
+    }
+    if groups.is_empty() {
+        weight = 0;
+    }
+
+    let sign: u16 = if negative && !groups.is_empty() {
+        0x4000
+    } else {
+        0
+    };
+    let mut bytes = Vec::with_capacity(8 + groups.len() * 2);
+    bytes.extend_from_slice(&(groups.len() as i16).to_be_bytes());
+    bytes.extend_from_slice(&weight.to_be_bytes());
+    bytes.extend_from_slice(&sign.to_be_bytes());
+    bytes.extend_from_slice(&(frac_part.len() as u16).to_be_bytes());
+    for group in groups {
+        bytes.extend_from_slice(&group.to_be_bytes());
+    }
+    Ok(bytes)
+}
+
+/// Renders a parameter bound by the client as a SQL literal.
+pub fn param_to_literal(oid: u32, format: i16, value: Option<&[u8]>) -> Result<String> {
+    let Some(value) = value else {
+        return Ok("NULL".to_string());
+    };
+
+    if format == FORMAT_BINARY {
+        let invalid = || ErrorCode::BadBytes(format!("Invalid binary parameter of type {}", oid));
+        return match oid {
+            BOOL_OID => match value {
+                [0] => Ok("FALSE".to_string()),
+                [_] => Ok("TRUE".to_string()),
+                _ => Err(invalid()),
+            },
+            INT2_OID => {
+                Ok(i16::from_be_bytes(value.try_into().map_err(|_| invalid())?).to_string())
+            }
+            INT4_OID | OID_OID => {
+                Ok(i32::from_be_bytes(value.try_into().map_err(|_| invalid())?).to_string())
+            }
+            INT8_OID => {
+                Ok(i64::from_be_bytes(value.try_into().map_err(|_| invalid())?).to_string())
+            }
+            FLOAT4_OID => {
+                float_literal(f32::from_be_bytes(value.try_into().map_err(|_| invalid())?) as f64)
+            }
+            FLOAT8_OID => {
+                float_literal(f64::from_be_bytes(value.try_into().map_err(|_| invalid())?))
+            }
+            DATE_OID => {
+                let days = i32::from_be_bytes(value.try_into().map_err(|_| invalid())?);
+                let date = postgres_epoch().date() + chrono::Duration::days(days as i64);
+                Ok(format!("'{}'::DATE", date.format("%Y-%m-%d")))
+            }
+            TIMESTAMP_OID => {
+                let micros = i64::from_be_bytes(value.try_into().map_err(|_| invalid())?);
+                let ts = postgres_epoch() + chrono::Duration::microseconds(micros);
+                Ok(format!(
+                    "'{}'::TIMESTAMP",
+                    ts.format("%Y-%m-%d %H:%M:%S%.6f")
+                ))
+            }
+            UNSPECIFIED_OID | TEXT_OID | VARCHAR_OID | BPCHAR_OID | UNKNOWN_OID => {
+                string_literal(value)
+            }
+            _ => Err(ErrorCode::Unimplemented(format!(
+                "Binary parameters of type {} are not supported, use the text format",
+                oid
+            ))),
+        };
+    }
+
+    let text = std::str::from_utf8(value)
+        .map_err(|_| ErrorCode::BadBytes("Invalid text parameter, it is not UTF-8"))?;
+    match oid {
+        INT2_OID | INT4_OID | INT8_OID | OID_OID => text
+            .trim()
+            .parse::<i64>()
+            .map(|v| v.to_string())
+            .map_err(|_| ErrorCode::BadBytes(format!("Invalid integer parameter {}", text))),
+        FLOAT4_OID | FLOAT8_OID => text
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| ErrorCode::BadBytes(format!("Invalid float parameter {}", text)))
+            .and_then(float_literal),
+        NUMERIC_OID => {
+            let text = text.trim();
+            match text.parse::<f64>() {
+                Ok(_) if !text.contains(|c: char| c.is_ascii_alphabetic()) => Ok(text.to_string()),
+                _ => Err(ErrorCode::BadBytes(format!(
+                    "Invalid numeric parameter {}",
+                    text
+                ))),
+            }
+        }
+        BOOL_OID => match text.trim().to_ascii_lowercase().as_str() {
+            "t" | "true" | "y" | "yes" | "on" | "1" => Ok("TRUE".to_string()),
+            "f" | "false" | "n" | "no" | "off" | "0" => Ok("FALSE".to_string()),
+            _ => Err(ErrorCode::BadBytes(format!(
+                "Invalid boolean parameter {}",
+                text
+            ))),
+        },
+        DATE_OID => Ok(format!("{}::DATE", string_literal(value)?)),
+        TIMESTAMP_OID => Ok(format!("{}::TIMESTAMP", string_literal(value)?)),
+        // The strings are casted to the expected types by Databend.
+        _ => string_literal(value),
+    }
+}
+
+fn float_literal(v: f64) -> Result<String> {
+    if v.is_finite() {
+        Ok(format!("{:?}", v))
+    } else {
+        Ok(format!("'{}'::DOUBLE", v))
+    }
+}
+
+fn string_literal(value: &[u8]) -> Result<String> {
+    let text = std::str::from_utf8(value)
+        .map_err(|_| ErrorCode::BadBytes("Invalid string parameter, it is not UTF-8"))?;
+    Ok(format!("'{}'", escape_string_with_quote(text, Some('\''))))
+}
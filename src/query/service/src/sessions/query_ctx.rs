@@ -29,9 +29,13 @@ use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
 use chrono_tz::Tz;
+use common_base::base::tokio::runtime::Handle;
+use common_base::base::tokio::task::block_in_place;
 use common_base::base::tokio::task::JoinHandle;
 use common_base::base::Progress;
 use common_base::base::ProgressValues;
+use common_base::runtime::GlobalIORuntime;
+use common_base::runtime::MemStat;
 use common_base::runtime::TrySpawn;
 use common_catalog::plan::DataSourceInfo;
 use common_catalog::plan::DataSourcePlan;
@@ -39,9 +43,12 @@ use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::Partitions;
 use common_catalog::plan::StageTableInfo;
 use common_catalog::query_kind::QueryKind;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use common_catalog::table_args::TableArgs;
 use common_catalog::table_context::MaterializedCtesBlocks;
+use common_catalog::table_context::QueuedQueryInfo;
 use common_catalog::table_context::StageAttachment;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_config::DATABEND_COMMIT_VERSION;
 use common_exception::ErrorCode;
@@ -49,6 +56,8 @@ use common_exception::Result;
 use common_expression::date_helper::TzFactory;
 use common_expression::DataBlock;
 use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::SequenceGenerator;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::OnErrorMode;
@@ -208,6 +217,11 @@ impl QueryContext {
         DataExchangeManager::instance()
     }
 
+    // Get the memory stat of the query.
+    pub fn get_query_mem_stat(&self) -> Arc<MemStat> {
+        self.shared.get_mem_stat()
+    }
+
     // Get the current session.
     pub fn get_current_session(&self) -> Arc<Session> {
         self.shared.session.clone()
@@ -551,6 +565,10 @@ impl TableContext for QueryContext {
             openai_api_embedding_base_url: query_config.openai_api_embedding_base_url.clone(),
             openai_api_embedding_model: query_config.openai_api_embedding_model.clone(),
             openai_api_completion_model: query_config.openai_api_completion_model.clone(),
+
+            sequence_generator: Some(Arc::new(MetaSequenceGenerator {
+                tenant: self.get_tenant(),
+            })),
         })
     }
 
@@ -581,6 +599,10 @@ impl TableContext for QueryContext {
         SessionManager::instance().processes_info()
     }
 
+    fn get_queued_queries(&self) -> Vec<QueuedQueryInfo> {
+        SessionManager::instance().queued_queries()
+    }
+
     // Get Stage Attachment.
     fn get_stage_attachment(&self) -> Option<StageAttachment> {
         self.shared.get_stage_attachment()
@@ -608,6 +630,14 @@ impl TableContext for QueryContext {
             .update_query_ids_results(query_id, Some(result_cache_key))
     }
 
+    fn set_variable(&self, name: String, value: Scalar) {
+        self.shared.session.session_ctx.set_variable(name, value)
+    }
+
+    fn get_variable(&self, name: &str) -> Option<Scalar> {
+        self.shared.session.session_ctx.get_variable(name)
+    }
+
     fn get_on_error_map(&self) -> Option<Arc<DashMap<String, HashMap<u16, InputError>>>> {
         self.shared.get_on_error_map()
     }
@@ -762,6 +792,16 @@ impl TableContext for QueryContext {
         self.shared.materialized_cte_tables.clone()
     }
 
+    fn set_runtime_filter(&self, scan_id: IndexType, filter: RuntimeFilterInfo) {
+        let mut runtime_filters = self.shared.runtime_filters.write();
+        runtime_filters.entry(scan_id).or_default().merge(filter);
+    }
+
+    fn get_runtime_filter(&self, scan_id: IndexType) -> Option<RuntimeFilterInfo> {
+        let runtime_filters = self.shared.runtime_filters.read();
+        runtime_filters.get(&scan_id).cloned()
+    }
+
     fn add_segment_location(&self, segment_loc: Location) -> Result<()> {
         let mut segment_locations = self.inserted_segment_locs.write();
         segment_locations.insert(segment_loc);
@@ -793,6 +833,10 @@ impl TableContext for QueryContext {
             .get_enterprise_license()
             .unwrap_or_default()
     }
+
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.shared.session.session_ctx.txn_mgr()
+    }
 }
 
 impl TrySpawn for QueryContext {
@@ -813,6 +857,30 @@ impl std::fmt::Debug for QueryContext {
     }
 }
 
+/// Serves `nextval` from the sequences stored in the meta service.
+struct MetaSequenceGenerator {
+    tenant: String,
+}
+
+impl SequenceGenerator for MetaSequenceGenerator {
+    fn next_values(&self, name: &str, count: u64) -> Result<Vec<u64>> {
+        let tenant = self.tenant.clone();
+        let name = name.to_string();
+        let fut = async move {
+            UserApiProvider::instance()
+                .next_sequence_values(&tenant, &name, count)
+                .await
+        };
+
+        // Expressions are mostly evaluated in the pipeline executor threads, but constant
+        // expressions may be evaluated inside the async runtime as well.
+        match Handle::try_current() {
+            Ok(handle) => block_in_place(|| handle.block_on(fut)),
+            Err(_) => GlobalIORuntime::instance().block_on(fut),
+        }
+    }
+}
+
 pub fn convert_query_log_timestamp(time: SystemTime) -> i64 {
     time.duration_since(UNIX_EPOCH)
         .unwrap_or(Duration::new(0, 0))
@@ -21,9 +21,12 @@ use std::sync::Weak;
 use std::time::SystemTime;
 
 use common_base::base::Progress;
+use common_base::runtime::MemStat;
 use common_base::runtime::Runtime;
+use common_base::runtime::ThreadTracker;
 use common_catalog::catalog::CatalogManager;
 use common_catalog::query_kind::QueryKind;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use common_catalog::table_context::MaterializedCtesBlocks;
 use common_catalog::table_context::StageAttachment;
 use common_exception::ErrorCode;
@@ -34,6 +37,7 @@ use common_meta_app::principal::UserInfo;
 use common_pipeline_core::InputError;
 use common_settings::ChangeValue;
 use common_settings::Settings;
+use common_sql::IndexType;
 use common_storage::CopyStatus;
 use common_storage::DataOperator;
 use common_storage::StorageMetrics;
@@ -69,6 +73,9 @@ pub struct QueryContextShared {
     pub(in crate::sessions) error: Arc<Mutex<Option<ErrorCode>>>,
     pub(in crate::sessions) session: Arc<Session>,
     pub(in crate::sessions) runtime: Arc<RwLock<Option<Arc<Runtime>>>>,
+    /// The memory stat of the query, the allocations of the query runtime and the pipeline
+    /// executor threads are reported to it.
+    pub(in crate::sessions) mem_stat: Arc<MemStat>,
     pub(in crate::sessions) init_query_id: Arc<RwLock<String>>,
     pub(in crate::sessions) cluster_cache: Arc<Cluster>,
     pub(in crate::sessions) running_query: Arc<RwLock<Option<String>>>,
@@ -101,6 +108,8 @@ pub struct QueryContextShared {
     pub(in crate::sessions) user_agent: Arc<RwLock<String>>,
     /// Key is (cte index, used_count), value contains cte's materialized blocks
     pub(in crate::sessions) materialized_cte_tables: MaterializedCtesBlocks,
+    /// Key is the table index of the probe side scan, value is the runtime filters from hash join build side
+    pub(in crate::sessions) runtime_filters: Arc<RwLock<HashMap<IndexType, RuntimeFilterInfo>>>,
 }
 
 impl QueryContextShared {
@@ -120,6 +129,7 @@ impl QueryContextShared {
             write_progress: Arc::new(Progress::create()),
             error: Arc::new(Mutex::new(None)),
             runtime: Arc::new(RwLock::new(None)),
+            mem_stat: MemStat::create_child(String::from("Query"), None),
             running_query: Arc::new(RwLock::new(None)),
             running_query_kind: Arc::new(RwLock::new(None)),
             aborting: Arc::new(AtomicBool::new(false)),
@@ -138,6 +148,7 @@ impl QueryContextShared {
             status: Arc::new(RwLock::new("null".to_string())),
             user_agent: Arc::new(RwLock::new("null".to_string())),
             materialized_cte_tables: Arc::new(Default::default()),
+            runtime_filters: Arc::new(Default::default()),
             join_spill_progress: Arc::new(Progress::create()),
             agg_spill_progress: Arc::new(Progress::create()),
             group_by_spill_progress: Arc::new(Progress::create()),
@@ -297,7 +308,18 @@ impl QueryContextShared {
         let tenant = self.get_tenant();
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
         let catalog = self.catalog_manager.get_catalog(&tenant, catalog).await?;
-        let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        let mut cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        // The table may be mutated in the explicit transaction of the session,
+        // use the staged table meta so that the mutations are visible.
+        let staged_table_info = self
+            .session
+            .session_ctx
+            .txn_mgr()
+            .lock()
+            .get_table_from_buffer(cache_table.get_id());
+        if let Some(table_info) = staged_table_info {
+            cache_table = catalog.get_table_by_info(&table_info)?;
+        }
 
         let mut tables_refs = self.tables_refs.lock();
 
@@ -321,6 +343,8 @@ impl QueryContextShared {
         match &*query_runtime {
             Some(query_runtime) => Ok(query_runtime.clone()),
             None => {
+                // The memory stat of the runtime is created as a child of the query's.
+                let _guard = ThreadTracker::enter(Some(self.mem_stat.clone()));
                 // To avoid possible deadlock, we should keep at least two threads.
                 let runtime = Arc::new(Runtime::with_worker_threads(
                     2,
@@ -337,6 +361,10 @@ impl QueryContextShared {
         (*query_runtime).clone()
     }
 
+    pub fn get_mem_stat(&self) -> Arc<MemStat> {
+        self.mem_stat.clone()
+    }
+
     pub fn attach_query_str(&self, kind: QueryKind, query: String) {
         {
             let mut running_query = self.running_query.write();
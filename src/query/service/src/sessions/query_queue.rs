@@ -0,0 +1,194 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use common_base::base::tokio;
+use common_base::base::tokio::sync::Notify;
+use common_catalog::table_context::QueuedQueryInfo;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use log::info;
+use parking_lot::Mutex;
+
+/// Admission control of the queries running on this node.
+///
+/// A query runs only if both the global and the per-user running limits allow it,
+/// otherwise it waits in a FIFO queue until a running query finishes or it times out.
+pub struct QueryQueue {
+    max_running_queries: usize,
+    max_running_queries_per_user: usize,
+    timeout: Duration,
+    state: Mutex<QueryQueueState>,
+    notify: Notify,
+}
+
+#[derive(Default)]
+struct QueryQueueState {
+    running: HashSet<String>,
+    running_per_user: HashMap<String, usize>,
+    queued: VecDeque<QueuedQueryInfo>,
+}
+
+/// Holds a running slot of the query queue, the slot is released on drop.
+pub struct QueryPermit {
+    queue: Arc<QueryQueue>,
+    query_id: String,
+    user: String,
+}
+
+impl QueryQueue {
+    pub fn create(
+        max_running_queries: usize,
+        max_running_queries_per_user: usize,
+        timeout: Duration,
+    ) -> Arc<QueryQueue> {
+        Arc::new(QueryQueue {
+            max_running_queries,
+            max_running_queries_per_user,
+            timeout,
+            state: Mutex::new(QueryQueueState::default()),
+            notify: Notify::new(),
+        })
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.max_running_queries > 0 || self.max_running_queries_per_user > 0
+    }
+
+    /// Waits until the query is admitted, fails if it is still queued after the timeout.
+    #[async_backtrace::framed]
+    pub async fn acquire(
+        self: &Arc<Self>,
+        query_id: &str,
+        user: &str,
+        query_text: &str,
+    ) -> Result<QueryPermit> {
+        let deadline = tokio::time::Instant::now() + self.timeout;
+        self.state.lock().queued.push_back(QueuedQueryInfo {
+            query_id: query_id.to_string(),
+            user: user.to_string(),
+            query_text: query_text.to_string(),
+            queued_time: SystemTime::now(),
+        });
+        // Leave the queue however the waiting ends, also if the query is cancelled.
+        let _queued = QueuedEntry {
+            queue: self,
+            query_id,
+        };
+
+        loop {
+            // Create the notified future before checking, so that a release between
+            // the check and the wait is not missed.
+            let notified = self.notify.notified();
+            {
+                let mut state = self.state.lock();
+                if self.can_admit(&state, query_id, user) {
+                    state.queued.retain(|v| v.query_id != query_id);
+                    state.running.insert(query_id.to_string());
+                    *state.running_per_user.entry(user.to_string()).or_default() += 1;
+
+                    // Wake up the others, one of them may be admitted too.
+                    self.notify.notify_waiters();
+                    return Ok(QueryPermit {
+                        queue: self.clone(),
+                        query_id: query_id.to_string(),
+                        user: user.to_string(),
+                    });
+                }
+            }
+
+            if tokio::time::timeout_at(deadline, notified).await.is_err() {
+                info!("query {} timed out in the query queue", query_id);
+                return Err(ErrorCode::QueryQueueTimeout(format!(
+                    "Query {} waited in the query queue for more than {} seconds",
+                    query_id,
+                    self.timeout.as_secs()
+                )));
+            }
+        }
+    }
+
+    /// Whether the query holds a running slot already, e.g. the statements executed
+    /// internally by a running query.
+    pub fn is_running(&self, query_id: &str) -> bool {
+        self.state.lock().running.contains(query_id)
+    }
+
+    pub fn queued_queries(&self) -> Vec<QueuedQueryInfo> {
+        self.state.lock().queued.iter().cloned().collect()
+    }
+
+    fn user_has_capacity(&self, state: &QueryQueueState, user: &str) -> bool {
+        self.max_running_queries_per_user == 0
+            || state.running_per_user.get(user).copied().unwrap_or(0)
+                < self.max_running_queries_per_user
+    }
+
+    fn can_admit(&self, state: &QueryQueueState, query_id: &str, user: &str) -> bool {
+        if self.max_running_queries > 0 && state.running.len() >= self.max_running_queries {
+            return false;
+        }
+
+        if !self.user_has_capacity(state, user) {
+            return false;
+        }
+
+        // Queries queued earlier go first, unless their users are at the per-user limit.
+        state
+            .queued
+            .iter()
+            .take_while(|v| v.query_id != query_id)
+            .all(|v| !self.user_has_capacity(state, &v.user))
+    }
+
+    fn release(&self, query_id: &str, user: &str) {
+        let mut state = self.state.lock();
+        state.running.remove(query_id);
+        if let Some(count) = state.running_per_user.get_mut(user) {
+            *count -= 1;
+            if *count == 0 {
+                state.running_per_user.remove(user);
+            }
+        }
+        self.notify.notify_waiters();
+    }
+}
+
+struct QueuedEntry<'a> {
+    queue: &'a QueryQueue,
+    query_id: &'a str,
+}
+
+impl Drop for QueuedEntry<'_> {
+    fn drop(&mut self) {
+        let mut state = self.queue.state.lock();
+        let len = state.queued.len();
+        state.queued.retain(|v| v.query_id != self.query_id);
+        if state.queued.len() != len {
+            // The head of the queue may change, let the others check again.
+            self.queue.notify.notify_waiters();
+        }
+    }
+}
+
+impl Drop for QueryPermit {
+    fn drop(&mut self) {
+        self.queue.release(&self.query_id, &self.user);
+    }
+}
@@ -20,8 +20,11 @@ use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
 
+use common_catalog::txn::TxnManager;
+use common_catalog::txn::TxnManagerRef;
 use common_config::GlobalConfig;
 use common_exception::Result;
+use common_expression::Scalar;
 use common_meta_app::principal::RoleInfo;
 use common_meta_app::principal::UserInfo;
 use common_settings::ChangeValue;
@@ -57,7 +60,11 @@ pub struct SessionContext {
     // We store `query_id -> query_result_cache_key` to session context, so that we can fetch
     // query result through previous query_id easily.
     query_ids_results: RwLock<Vec<(String, Option<String>)>>,
+    // The user-defined variables set by `SET @x = <expr>`, they last until the session ends.
+    variables: RwLock<HashMap<String, Scalar>>,
     typ: SessionType,
+    // The explicit transaction of the session, it lasts across the queries until `COMMIT` or `ROLLBACK`.
+    txn_mgr: TxnManagerRef,
 }
 
 impl SessionContext {
@@ -75,10 +82,16 @@ impl SessionContext {
             io_shutdown_tx: Default::default(),
             query_context_shared: Default::default(),
             query_ids_results: Default::default(),
+            variables: Default::default(),
             typ,
+            txn_mgr: TxnManager::init(),
         }))
     }
 
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.txn_mgr.clone()
+    }
+
     // Get abort status.
     pub fn get_abort(&self) -> bool {
         self.abort.load(Ordering::Relaxed)
@@ -260,6 +273,16 @@ impl SessionContext {
         lock.push((query_id, value))
     }
 
+    pub fn set_variable(&self, name: String, value: Scalar) {
+        let mut lock = self.variables.write();
+        lock.insert(name, value);
+    }
+
+    pub fn get_variable(&self, name: &str) -> Option<Scalar> {
+        let lock = self.variables.read();
+        lock.get(name).cloned()
+    }
+
     pub fn get_last_query_id(&self, index: i32) -> String {
         let lock = self.query_ids_results.read();
         let query_ids_len = lock.len();
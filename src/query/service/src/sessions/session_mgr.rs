@@ -25,6 +25,8 @@ use common_base::base::tokio;
 use common_base::base::GlobalInstance;
 use common_base::base::SignalStream;
 use common_catalog::table_context::ProcessInfoState;
+use common_catalog::table_context::QueuedQueryInfo;
+use common_catalog::table_context::TableContext;
 use common_config::GlobalConfig;
 use common_config::InnerConfig;
 use common_exception::ErrorCode;
@@ -35,9 +37,12 @@ use futures::StreamExt;
 use log::info;
 use parking_lot::RwLock;
 
+use crate::sessions::query_queue::QueryPermit;
+use crate::sessions::query_queue::QueryQueue;
 use crate::sessions::session::Session;
 use crate::sessions::session_metrics;
 use crate::sessions::ProcessInfo;
+use crate::sessions::QueryContext;
 use crate::sessions::SessionContext;
 use crate::sessions::SessionManagerStatus;
 use crate::sessions::SessionType;
@@ -50,6 +55,7 @@ pub struct SessionManager {
     // When typ is MySQL, insert into this map, key is id, val is MySQL connection id.
     pub(crate) mysql_conn_map: Arc<RwLock<HashMap<Option<u32>, String>>>,
     pub(in crate::sessions) mysql_basic_conn_id: AtomicU32,
+    pub(in crate::sessions) query_queue: Arc<QueryQueue>,
 }
 
 impl SessionManager {
@@ -61,8 +67,14 @@ impl SessionManager {
 
     pub fn create(conf: &InnerConfig) -> Arc<SessionManager> {
         let max_sessions = conf.query.max_active_sessions as usize;
+        let query_queue = QueryQueue::create(
+            conf.query.max_running_queries as usize,
+            conf.query.max_running_queries_per_user as usize,
+            Duration::from_secs(conf.query.query_queue_timeout_secs),
+        );
         Arc::new(SessionManager {
             max_sessions,
+            query_queue,
             mysql_basic_conn_id: AtomicU32::new(9_u32.to_le()),
             status: Arc::new(RwLock::new(SessionManagerStatus::default())),
             mysql_conn_map: Arc::new(RwLock::new(HashMap::with_capacity(max_sessions))),
@@ -249,6 +261,34 @@ impl SessionManager {
             .collect::<Vec<_>>()
     }
 
+    /// Waits in the query queue until the query is admitted to run.
+    ///
+    /// Returns `None` if the query is not subject to admission control.
+    #[async_backtrace::framed]
+    pub async fn acquire_query_permit(&self, ctx: &QueryContext) -> Result<Option<QueryPermit>> {
+        if !self.query_queue.is_enabled() || !ctx.get_current_session().get_type().is_user_session()
+        {
+            return Ok(None);
+        }
+
+        let query_id = ctx.get_id();
+        if self.query_queue.is_running(&query_id) {
+            return Ok(None);
+        }
+
+        let user = ctx.get_current_user()?.name;
+        ctx.set_status_info("waiting in query queue");
+        let permit = self
+            .query_queue
+            .acquire(&query_id, &user, &ctx.get_query_str())
+            .await?;
+        Ok(Some(permit))
+    }
+
+    pub fn queued_queries(&self) -> Vec<QueuedQueryInfo> {
+        self.query_queue.queued_queries()
+    }
+
     fn destroy_idle_sessions(sessions: &Arc<RwLock<HashMap<String, Weak<Session>>>>) -> bool {
         // Read lock does not support reentrant
         // https://github.com/Amanieu/parking_lot::/blob/lock_api-0.4.4/lock_api/src/rwlock.rs#L422
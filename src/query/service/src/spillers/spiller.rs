@@ -25,18 +25,21 @@ use common_exception::Result;
 use common_expression::arrow::deserialize_column;
 use common_expression::arrow::serialize_column;
 use common_expression::DataBlock;
+use common_pipeline_transforms::processors::transforms::SortSpiller;
+use common_storage::TempFileInfo;
+use common_storage::TempFilesRegistry;
 use log::info;
 use opendal::Operator;
 
 use crate::sessions::QueryContext;
 
-/// Spiller type, currently only supports HashJoin
+/// Spiller type, currently supports HashJoin and OrderBy
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum SpillerType {
     HashJoinBuild,
-    HashJoinProbe, /* Todo: Add more spillers type
-                    * OrderBy
-                    * Aggregation */
+    HashJoinProbe,
+    OrderBy, /* Todo: Add more spillers type
+              * Aggregation */
 }
 
 impl Display for SpillerType {
@@ -44,6 +47,7 @@ impl Display for SpillerType {
         match self {
             SpillerType::HashJoinBuild => write!(f, "HashJoinBuild"),
             SpillerType::HashJoinProbe => write!(f, "HashJoinProbe"),
+            SpillerType::OrderBy => write!(f, "OrderBy"),
         }
     }
 }
@@ -121,14 +125,36 @@ impl Spiller {
         worker_id: usize,
     ) -> Result<()> {
         self.spilled_partition_set.insert(*p_id);
-        let unique_name = GlobalUniqName::unique();
-        let location = format!("{}/{}", self.config.location_prefix, unique_name);
+        let location = self.spill_block(data).await?;
         self.partition_location
             .entry(*p_id)
             .and_modify(|locs| {
                 locs.push(location.clone());
             })
             .or_insert(vec![location.clone()]);
+        {
+            let progress_val = ProgressValues {
+                rows: data.num_rows(),
+                bytes: data.memory_size(),
+            };
+            self.ctx.get_join_spill_progress().incr(&progress_val);
+        }
+        info!(
+            "{:?} spilled {:?} rows data into {:?}, partition id is {:?}, worker id is {:?}",
+            self.spiller_type,
+            data.num_rows(),
+            location,
+            p_id,
+            worker_id
+        );
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    /// Spill data block into a new file, return the location of the file
+    pub async fn spill_block(&mut self, data: &DataBlock) -> Result<String> {
+        let unique_name = GlobalUniqName::unique();
+        let location = format!("{}/{}", self.config.location_prefix, unique_name);
         let mut writer = self.operator.writer(location.as_str()).await?;
         let columns = data.columns().to_vec();
         let mut columns_data = Vec::with_capacity(columns.len());
@@ -147,22 +173,25 @@ impl Spiller {
             writer.write(data).await?;
         }
         writer.close().await?;
-        {
-            let progress_val = ProgressValues {
-                rows: data.num_rows(),
-                bytes: data.memory_size(),
-            };
-            self.ctx.get_join_spill_progress().incr(&progress_val);
+        TempFilesRegistry::instance().register(&location, TempFileInfo {
+            query_id: self.ctx.get_id(),
+            spill_type: self.spiller_type.to_string(),
+        });
+        Ok(location)
+    }
+
+    #[async_backtrace::framed]
+    /// Read spilled data block from the file
+    pub async fn read_spilled_file(&self, file: &str) -> Result<DataBlock> {
+        let data = self.operator.read(file).await?;
+        let mut begin = 0;
+        let columns_layout = self.columns_layout.get(file).unwrap();
+        let mut columns = Vec::with_capacity(columns_layout.len());
+        for column_layout in columns_layout.iter() {
+            columns.push(deserialize_column(&data[begin..begin + column_layout]).unwrap());
+            begin += column_layout;
         }
-        info!(
-            "{:?} spilled {:?} rows data into {:?}, partition id is {:?}, worker id is {:?}",
-            self.spiller_type,
-            data.num_rows(),
-            location,
-            p_id,
-            worker_id
-        );
-        Ok(())
+        Ok(DataBlock::new_from_columns(columns))
     }
 
     #[async_backtrace::framed]
@@ -187,15 +216,7 @@ impl Spiller {
         let mut spilled_data = Vec::with_capacity(files.len());
         // Todo: make it parallel
         for file in files.iter() {
-            let data = self.operator.read(file).await?;
-            let mut begin = 0;
-            let mut columns = Vec::with_capacity(self.columns_layout.len());
-            let columns_layout = self.columns_layout.get(file).unwrap();
-            for column_layout in columns_layout.iter() {
-                columns.push(deserialize_column(&data[begin..begin + column_layout]).unwrap());
-                begin += column_layout;
-            }
-            let block = DataBlock::new_from_columns(columns);
+            let block = self.read_spilled_file(file).await?;
             if block.num_rows() != 0 {
                 spilled_data.push(block);
             }
@@ -275,3 +296,25 @@ impl Spiller {
         !self.spilled_partition_set.is_empty()
     }
 }
+
+#[async_trait::async_trait]
+impl SortSpiller for Spiller {
+    #[async_backtrace::framed]
+    async fn spill(&mut self, block: DataBlock) -> Result<String> {
+        let location = self.spill_block(&block).await?;
+        info!(
+            "{:?} spilled {:?} rows data into {:?}",
+            self.spiller_type,
+            block.num_rows(),
+            location
+        );
+        Ok(location)
+    }
+
+    #[async_backtrace::framed]
+    async fn restore(&mut self, location: &str) -> Result<DataBlock> {
+        let block = self.read_spilled_file(location).await?;
+        self.columns_layout.remove(location);
+        Ok(block)
+    }
+}
@@ -114,6 +114,7 @@ impl<'a> BlockWriter<'a> {
             location.1,
             &[block],
             bloom_columns_map,
+            &[],
         )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
@@ -125,6 +126,7 @@ impl<'a> BlockWriter<'a> {
                 vec![index_block],
                 &mut data,
                 TableCompression::None,
+                false,
             )?;
             data_accessor.write(&location.0, data).await?;
             Ok((size, Some(location), Some(meta)))
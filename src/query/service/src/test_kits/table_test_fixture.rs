@@ -179,6 +179,7 @@ impl TestFixture {
             table: self.default_table_name(),
             schema: TestFixture::default_table_schema(),
             engine: Engine::Fuse,
+            engine_options: Default::default(),
             storage_params: None,
             read_only_attach: false,
             part_prefix: "".to_string(),
@@ -203,6 +204,7 @@ impl TestFixture {
             table: self.default_table_name(),
             schema: TestFixture::default_table_schema(),
             engine: Engine::Fuse,
+            engine_options: Default::default(),
             storage_params: None,
             read_only_attach: false,
             part_prefix: "".to_string(),
@@ -238,6 +240,7 @@ impl TestFixture {
             table: self.default_table_name(),
             schema: TestFixture::variant_table_schema(),
             engine: Engine::Fuse,
+            engine_options: Default::default(),
             storage_params: None,
             read_only_attach: false,
             part_prefix: "".to_string(),
@@ -282,6 +285,7 @@ impl TestFixture {
             table: self.default_table_name(),
             schema: TestFixture::computed_table_schema(),
             engine: Engine::Fuse,
+            engine_options: Default::default(),
             storage_params: None,
             read_only_attach: false,
             part_prefix: "".to_string(),
@@ -626,8 +630,7 @@ pub async fn execute_query(ctx: Arc<QueryContext>, query: &str) -> Result<Sendab
 }
 
 pub fn execute_pipeline(ctx: Arc<QueryContext>, mut res: PipelineBuildResult) -> Result<()> {
-    let query_id = ctx.get_id();
-    let executor_settings = ExecutorSettings::try_create(&ctx.get_settings(), query_id)?;
+    let executor_settings = ExecutorSettings::try_create(&ctx)?;
     res.set_max_threads(ctx.get_settings().get_max_threads()? as usize);
     let mut pipelines = res.sources_pipelines;
     pipelines.push(res.main_pipeline);
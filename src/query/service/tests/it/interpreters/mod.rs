@@ -13,3 +13,4 @@
 // limitations under the License.
 
 mod union;
+mod query_log_sink;
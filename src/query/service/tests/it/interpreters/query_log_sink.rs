@@ -0,0 +1,93 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_config::InnerConfig;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::interpreters::build_otlp_logs_request;
+use databend_query::interpreters::create_query_log_sink;
+use databend_query::interpreters::encode_ndjson;
+use databend_query::interpreters::OtlpLogRecord;
+use serde_json::json;
+
+#[test]
+fn test_encode_ndjson() -> Result<()> {
+    let events = vec![json!({"query_id": "q1"}), json!({"query_id": "q2"})];
+    let buf = encode_ndjson(&events)?;
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        "{\"query_id\":\"q1\"}\n{\"query_id\":\"q2\"}\n"
+    );
+
+    assert!(encode_ndjson::<serde_json::Value>(&[])?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_build_otlp_logs_request() -> Result<()> {
+    let resource = vec![("service.name", "databend-query".to_string())];
+    let records = vec![OtlpLogRecord {
+        time_unix_nano: 1_700_000_000_000_000_000,
+        query_id: "q1".to_string(),
+        body: "{\"query_id\":\"q1\"}".to_string(),
+    }];
+
+    let payload = build_otlp_logs_request(&resource, &records);
+    let resource_logs = &payload["resourceLogs"][0];
+    assert_eq!(
+        resource_logs["resource"]["attributes"][0],
+        json!({"key": "service.name", "value": {"stringValue": "databend-query"}})
+    );
+
+    let record = &resource_logs["scopeLogs"][0]["logRecords"][0];
+    assert_eq!(record["timeUnixNano"], json!("1700000000000000000"));
+    assert_eq!(
+        record["body"]["stringValue"],
+        json!("{\"query_id\":\"q1\"}")
+    );
+    assert_eq!(
+        record["attributes"][0],
+        json!({"key": "query_id", "value": {"stringValue": "q1"}})
+    );
+    Ok(())
+}
+
+#[test]
+fn test_create_query_log_sink() -> Result<()> {
+    let mut conf = InnerConfig::default();
+    assert!(create_query_log_sink(&conf)?.is_none());
+
+    conf.query.query_log_sink_type = "table".to_string();
+    conf.query.query_log_sink_target = "audit.query_log".to_string();
+    let sink = create_query_log_sink(&conf)?.unwrap();
+    assert_eq!(sink.name(), "table audit.query_log");
+
+    conf.query.query_log_sink_target = "query_log".to_string();
+    let err = create_query_log_sink(&conf).err().unwrap();
+    assert_eq!(err.code(), ErrorCode::INVALID_CONFIG);
+
+    conf.query.query_log_sink_type = "otlp".to_string();
+    conf.query.query_log_sink_target = "http://127.0.0.1:4318/v1/logs".to_string();
+    let sink = create_query_log_sink(&conf)?.unwrap();
+    assert_eq!(sink.name(), "otlp http://127.0.0.1:4318/v1/logs");
+
+    conf.query.query_log_sink_target = "".to_string();
+    let err = create_query_log_sink(&conf).err().unwrap();
+    assert_eq!(err.code(), ErrorCode::INVALID_CONFIG);
+
+    conf.query.query_log_sink_type = "kafka".to_string();
+    let err = create_query_log_sink(&conf).err().unwrap();
+    assert_eq!(err.code(), ErrorCode::INVALID_CONFIG);
+    Ok(())
+}
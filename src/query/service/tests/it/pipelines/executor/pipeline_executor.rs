@@ -20,6 +20,7 @@ use common_base::base::tokio;
 use common_base::base::tokio::sync::mpsc::channel;
 use common_base::base::tokio::sync::mpsc::Receiver;
 use common_base::base::tokio::sync::mpsc::Sender;
+use common_base::runtime::MemStat;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
@@ -41,6 +42,7 @@ async fn test_always_call_on_finished() -> Result<()> {
     let settings = ExecutorSettings {
         query_id: Arc::new("".to_string()),
         max_execute_time_in_seconds: Default::default(),
+        mem_stat: MemStat::create("test_always_call_on_finished".to_string()),
     };
 
     {
@@ -75,6 +75,18 @@ async fn run_query(
     Ok(res)
 }
 
+async fn run_statement(
+    client: &mut FlightSqlServiceClient<Channel>,
+    sql: &str,
+) -> std::result::Result<String, ArrowError> {
+    let flight_info = client.execute(sql.to_string(), None).await?;
+    let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+    let flight_data = client.do_get(ticket).await?;
+    let flight_data: Vec<FlightData> = flight_data.try_collect().await.unwrap();
+    let batches = flight_data_to_batches(&flight_data)?;
+    Ok(pretty_format_batches(batches.as_slice())?.to_string())
+}
+
 fn prepare_config() -> InnerConfig {
     let hash_method = PasswordHashMethod::DoubleSha1;
     let hash_value = hash_method.hash(TEST_PASSWORD.as_bytes());
@@ -133,6 +145,18 @@ async fn test_query() -> Result<()> {
             };
             writeln!(file, "{}", res).unwrap();
         }
+
+        let statement_cases = ["select * from test1", "select number from numbers(3)"];
+        for case in statement_cases {
+            writeln!(file, "---------- Statement ----------").unwrap();
+            writeln!(file, "{}", case).unwrap();
+            writeln!(file, "---------- Output ---------").unwrap();
+            let res = match run_statement(&mut client, case).await {
+                Ok(s) => s,
+                Err(e) => format!("{e:?}"),
+            };
+            writeln!(file, "{}", res).unwrap();
+        }
     };
     tokio::pin!(serve_future);
 
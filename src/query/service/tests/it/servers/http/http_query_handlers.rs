@@ -479,6 +479,48 @@ async fn test_result_timeout() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_result_persistence() -> Result<()> {
+    let config = ConfigBuilder::create()
+        .http_handler_result_timeout(1u64)
+        .build();
+
+    let _guard = TestGlobalServices::setup(config.clone()).await?;
+
+    let ep = create_endpoint().await?;
+    let sql = "select * from numbers(10)";
+    let json = serde_json::json!({"sql": sql.to_string(), "pagination": {"wait_time_secs": 1, "max_rows_per_page": 2}, "session": { "settings": {"enable_http_query_result_persistence": "1"}}});
+
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert_eq!(result.data.len(), 2, "{:?}", result);
+    let query_id = result.id.clone();
+
+    // the client disconnects until the query expires.
+    sleep(std::time::Duration::from_secs(3)).await;
+
+    // all the pages can be fetched again after the query expires.
+    let final_uri = make_final_uri(&query_id);
+    let mut next_uri = make_page_uri(&query_id, 0);
+    let mut num_rows = 0;
+    while next_uri != final_uri {
+        let (status, result) = get_uri_checked(&ep, &next_uri).await?;
+        assert_eq!(status, StatusCode::OK, "{:?}", result);
+        assert!(result.error.is_none(), "{:?}", result);
+        assert!(result.data.len() <= 2, "{:?}", result);
+        num_rows += result.data.len();
+        next_uri = result.next_uri.clone().unwrap();
+    }
+    assert_eq!(num_rows, 10);
+
+    // the persisted pages are removed by the final uri.
+    let response = get_uri(&ep, &final_uri).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let response = get_uri(&ep, &make_page_uri(&query_id, 0)).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_system_tables() -> Result<()> {
     let config = ConfigBuilder::create().build();
@@ -978,6 +1020,7 @@ async fn test_auth_jwt_with_create_user() -> Result<()> {
             tenant_id: None,
             role: Some("account_admin".to_string()),
             ensure_user: Some(EnsureUser::default()),
+            extra: Default::default(),
         },
     };
 
@@ -1250,6 +1293,22 @@ async fn test_affect() -> Result<()> {
                 )])),
             }),
         ),
+        (
+            serde_json::json!({"sql": "set global max_block_size=1000", "session": {"settings": {"max_block_size": "6", "timezone": "Asia/Shanghai"}}}),
+            Some(QueryAffect::ChangeSettings {
+                keys: vec!["max_block_size".to_string()],
+                values: vec!["1000".to_string()],
+                is_globals: vec![true],
+            }),
+            Some(HttpSessionConf {
+                database: None,
+                keep_server_session_secs: None,
+                settings: Some(BTreeMap::from([(
+                    "timezone".to_string(),
+                    "Asia/Shanghai".to_string(),
+                )])),
+            }),
+        ),
     ];
 
     for (json, affect, session_conf) in sqls {
@@ -0,0 +1,213 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::SocketAddr;
+
+use common_base::base::tokio;
+use common_base::base::tokio::io::AsyncReadExt;
+use common_base::base::tokio::io::AsyncWriteExt;
+use common_base::base::tokio::net::TcpStream;
+use common_exception::Result;
+use databend_query::servers::PostgresHandler;
+use databend_query::test_kits::ConfigBuilder;
+use databend_query::test_kits::TestGlobalServices;
+
+// A minimal client of the PostgreSQL protocol.
+struct Client {
+    stream: TcpStream,
+}
+
+impl Client {
+    async fn connect(port: u16, user: &str) -> Result<Client> {
+        let stream = TcpStream::connect(("127.0.0.1", port)).await?;
+        let mut client = Client { stream };
+
+        let mut body = 196608i32.to_be_bytes().to_vec();
+        body.extend_from_slice(b"user\0");
+        body.extend_from_slice(user.as_bytes());
+        body.extend_from_slice(b"\0\0");
+        client.send_untagged(&body).await?;
+
+        // cleartext password requested.
+        assert_eq!(client.recv().await?, (b'R', 3i32.to_be_bytes().to_vec()));
+        client.send(b'p', b"\0").await?;
+        let messages = client.recv_until_ready().await?;
+        assert_eq!(messages[0], (b'R', 0i32.to_be_bytes().to_vec()));
+        Ok(client)
+    }
+
+    async fn send_untagged(&mut self, body: &[u8]) -> Result<()> {
+        self.stream
+            .write_all(&(body.len() as i32 + 4).to_be_bytes())
+            .await?;
+        self.stream.write_all(body).await?;
+        Ok(())
+    }
+
+    async fn send(&mut self, tag: u8, body: &[u8]) -> Result<()> {
+        self.stream.write_all(&[tag]).await?;
+        self.send_untagged(body).await
+    }
+
+    async fn recv(&mut self) -> Result<(u8, Vec<u8>)> {
+        let tag = self.stream.read_u8().await?;
+        let len = self.stream.read_i32().await?;
+        let mut body = vec![0; len as usize - 4];
+        self.stream.read_exact(&mut body).await?;
+        Ok((tag, body))
+    }
+
+    async fn recv_until_ready(&mut self) -> Result<Vec<(u8, Vec<u8>)>> {
+        let mut messages = vec![];
+        loop {
+            let message = self.recv().await?;
+            let ready = message.0 == b'Z';
+            messages.push(message);
+            if ready {
+                return Ok(messages);
+            }
+        }
+    }
+}
+
+fn tags(messages: &[(u8, Vec<u8>)]) -> String {
+    messages.iter().map(|(tag, _)| *tag as char).collect()
+}
+
+fn data_row(body: &[u8]) -> Vec<Option<String>> {
+    let num_values = i16::from_be_bytes([body[0], body[1]]);
+    let mut pos = 2;
+    (0..num_values)
+        .map(|_| {
+            let len = i32::from_be_bytes(body[pos..pos + 4].try_into().unwrap());
+            pos += 4;
+            if len < 0 {
+                return None;
+            }
+            let value = String::from_utf8(body[pos..pos + len as usize].to_vec()).unwrap();
+            pos += len as usize;
+            Some(value)
+        })
+        .collect()
+}
+
+fn cstr(s: &str) -> Vec<u8> {
+    let mut bytes = s.as_bytes().to_vec();
+    bytes.push(0);
+    bytes
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_simple_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = PostgresHandler::create(120)?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut client = Client::connect(runnable_server.port(), "root").await?;
+
+    client
+        .send(b'Q', &cstr("SELECT 1 + 1 AS a, 'x' AS b, NULL AS c"))
+        .await?;
+    let messages = client.recv_until_ready().await?;
+    assert_eq!(tags(&messages), "TDCZ");
+    assert_eq!(data_row(&messages[1].1), vec![
+        Some("2".to_string()),
+        Some("x".to_string()),
+        None
+    ]);
+    assert_eq!(messages[2].1, cstr("SELECT 1"));
+
+    // the driver setup commands.
+    client
+        .send(b'Q', &cstr("SET extra_float_digits = 3"))
+        .await?;
+    let messages = client.recv_until_ready().await?;
+    assert_eq!(tags(&messages), "CZ");
+    assert_eq!(messages[0].1, cstr("SET"));
+
+    client.send(b'Q', &cstr(" ; ")).await?;
+    let messages = client.recv_until_ready().await?;
+    assert_eq!(tags(&messages), "IZ");
+
+    client
+        .send(b'Q', &cstr("SELECT * FROM t_not_exists"))
+        .await?;
+    let messages = client.recv_until_ready().await?;
+    assert_eq!(tags(&messages), "EZ");
+
+    client.send(b'X', &[]).await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_extended_query() -> Result<()> {
+    let _guard = TestGlobalServices::setup(ConfigBuilder::create().build()).await?;
+
+    let mut handler = PostgresHandler::create(120)?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut client = Client::connect(runnable_server.port(), "root").await?;
+
+    // Parse "s1" with an int4 parameter and an unspecified one.
+    let mut parse = cstr("s1");
+    parse.extend(cstr("SELECT $1 + 1 AS a, $2 AS b, '$1' AS c"));
+    parse.extend(2i16.to_be_bytes());
+    parse.extend(23i32.to_be_bytes());
+    parse.extend(0i32.to_be_bytes());
+    client.send(b'P', &parse).await?;
+
+    let mut describe = vec![b'S'];
+    describe.extend(cstr("s1"));
+    client.send(b'D', &describe).await?;
+
+    // Bind the text parameters to the unnamed portal.
+    let mut bind = cstr("");
+    bind.extend(cstr("s1"));
+    bind.extend(0i16.to_be_bytes());
+    bind.extend(2i16.to_be_bytes());
+    for param in ["41", "it's"] {
+        bind.extend((param.len() as i32).to_be_bytes());
+        bind.extend(param.as_bytes());
+    }
+    bind.extend(0i16.to_be_bytes());
+    client.send(b'B', &bind).await?;
+
+    let mut execute = cstr("");
+    execute.extend(0i32.to_be_bytes());
+    client.send(b'E', &execute).await?;
+    client.send(b'S', &[]).await?;
+
+    let messages = client.recv_until_ready().await?;
+    // ParseComplete, ParameterDescription, RowDescription, BindComplete, DataRow, CommandComplete.
+    assert_eq!(tags(&messages), "1tT2DCZ");
+    assert_eq!(data_row(&messages[4].1), vec![
+        Some("42".to_string()),
+        Some("it's".to_string()),
+        Some("$1".to_string())
+    ]);
+
+    // Errors skip the messages until Sync.
+    let mut bind = cstr("");
+    bind.extend(cstr("s_not_exists"));
+    bind.extend([0, 0, 0, 0, 0, 0]);
+    client.send(b'B', &bind).await?;
+    client.send(b'E', &execute).await?;
+    client.send(b'S', &[]).await?;
+    let messages = client.recv_until_ready().await?;
+    assert_eq!(tags(&messages), "EZ");
+
+    client.send(b'X', &[]).await?;
+    Ok(())
+}
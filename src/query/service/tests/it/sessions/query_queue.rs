@@ -0,0 +1,71 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use databend_query::sessions::QueryQueue;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_query_queue_admission() -> Result<()> {
+    let queue = QueryQueue::create(1, 0, Duration::from_secs(10));
+    assert!(queue.is_enabled());
+
+    let permit = queue.acquire("q1", "u1", "select 1").await?;
+    assert!(queue.is_running("q1"));
+
+    let waiter = {
+        let queue = queue.clone();
+        tokio::spawn(async move { queue.acquire("q2", "u2", "select 2").await })
+    };
+
+    // q2 waits in the queue until q1 finished.
+    while queue.queued_queries().is_empty() {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    let queued = queue.queued_queries();
+    assert_eq!(queued.len(), 1);
+    assert_eq!(queued[0].query_id, "q2");
+    assert_eq!(queued[0].user, "u2");
+
+    drop(permit);
+    let _permit = waiter.await.unwrap()?;
+    assert!(queue.queued_queries().is_empty());
+    assert!(!queue.is_running("q1"));
+    assert!(queue.is_running("q2"));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_query_queue_per_user_limit() -> Result<()> {
+    let queue = QueryQueue::create(0, 1, Duration::from_millis(100));
+
+    let _permit = queue.acquire("q1", "u1", "select 1").await?;
+    // Another user is not limited by u1.
+    let _other = queue.acquire("q2", "u2", "select 2").await?;
+
+    let res = queue.acquire("q3", "u1", "select 3").await;
+    assert_eq!(res.err().unwrap().code(), ErrorCode::QUERY_QUEUE_TIMEOUT);
+    assert!(queue.queued_queries().is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_query_queue_disabled() {
+    let queue = QueryQueue::create(0, 0, Duration::from_secs(1));
+    assert!(!queue.is_enabled());
+}
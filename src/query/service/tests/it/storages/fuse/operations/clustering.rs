@@ -43,6 +43,7 @@ async fn test_fuse_alter_table_cluster_key() -> common_exception::Result<()> {
         table: fixture.default_table_name(),
         schema: TestFixture::default_table_schema(),
         engine: Engine::Fuse,
+        engine_options: Default::default(),
         storage_params: None,
         read_only_attach: false,
         part_prefix: "".to_string(),
@@ -0,0 +1,144 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_storages_factory::Table;
+use common_storages_fuse::FusePartInfo;
+use common_storages_fuse::FuseTable;
+use common_storages_fuse::TableContext;
+use databend_query::test_kits::table_test_fixture::execute_command;
+use databend_query::test_kits::table_test_fixture::execute_query;
+use databend_query::test_kits::table_test_fixture::expects_ok;
+use databend_query::test_kits::table_test_fixture::TestFixture;
+
+const DATA_KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+fn contains(data: &[u8], pattern: &[u8]) -> bool {
+    data.windows(pattern.len()).any(|window| window == pattern)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_column_encryption() -> Result<()> {
+    std::env::set_var("DATABEND_ENCRYPTION_KEY_K1", DATA_KEY);
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    let create = "create table t(id int, s string, p string) storage_format='parquet' \
+                  compression='none' column_encryption='s' encryption_key_id='k1'";
+    execute_command(ctx.clone(), create).await?;
+    let insert = "insert into t values(1, 'secret-value-1', 'plain-value-1'), \
+                  (2, 'secret-value-2', 'plain-value-2')";
+    execute_command(ctx.clone(), insert).await?;
+
+    // the encrypted column is decrypted when it is read.
+    let expected = vec![
+        "+----------+------------------+-----------------+",
+        "| Column 0 | Column 1         | Column 2        |",
+        "+----------+------------------+-----------------+",
+        "| 1        | 'secret-value-1' | 'plain-value-1' |",
+        "| 2        | 'secret-value-2' | 'plain-value-2' |",
+        "+----------+------------------+-----------------+",
+    ];
+    expects_ok(
+        "read_encrypted_column",
+        execute_query(ctx.clone(), "select id, s, p from t").await,
+        expected,
+    )
+    .await?;
+
+    let expected = vec![
+        "+----------+",
+        "| Column 0 |",
+        "+----------+",
+        "| 2        |",
+        "+----------+",
+    ];
+    expects_ok(
+        "filter_encrypted_column",
+        execute_query(ctx.clone(), "select id from t where s = 'secret-value-2'").await,
+        expected,
+    )
+    .await?;
+
+    // only the plain column can be found in the block file.
+    let catalog = ctx.get_catalog("default").await?;
+    let table = catalog
+        .get_table(ctx.get_tenant().as_str(), "default", "t")
+        .await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let (_, parts) = table.read_partitions(ctx.clone(), None, true).await?;
+    assert_eq!(parts.partitions.len(), 1);
+    let part = FusePartInfo::from_part(&parts.partitions[0])?;
+    assert_eq!(part.column_encryptions.len(), 1);
+    let data = fuse_table.get_operator().read(&part.location).await?;
+    assert!(!contains(&data, b"secret-value-1"));
+    assert!(contains(&data, b"plain-value-1"));
+
+    // the encrypted columns can't be changed after the table is created.
+    let res = execute_command(
+        ctx.clone(),
+        "alter table t set options(column_encryption='p')",
+    )
+    .await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::TableOptionInvalid("").code()
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_column_encryption_invalid_options() -> Result<()> {
+    std::env::set_var("DATABEND_ENCRYPTION_KEY_K1", DATA_KEY);
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+
+    // the key id is required.
+    let res = execute_command(
+        ctx.clone(),
+        "create table t1(s string) column_encryption='s'",
+    )
+    .await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::TableOptionInvalid("").code()
+    );
+
+    // the key must be resolvable.
+    let res = execute_command(
+        ctx.clone(),
+        "create table t2(s string) column_encryption='s' encryption_key_id='unknown'",
+    )
+    .await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::ColumnEncryptionError("").code()
+    );
+
+    // the column must exist.
+    let res = execute_command(
+        ctx.clone(),
+        "create table t3(s string) column_encryption='x' encryption_key_id='k1'",
+    )
+    .await;
+    assert_eq!(
+        res.unwrap_err().code(),
+        ErrorCode::TableOptionInvalid("").code()
+    );
+
+    Ok(())
+}
@@ -27,15 +27,19 @@ use common_catalog::plan::DataSourcePlan;
 use common_catalog::plan::PartInfoPtr;
 use common_catalog::plan::Partitions;
 use common_catalog::query_kind::QueryKind;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
 use common_catalog::table::Table;
 use common_catalog::table_context::MaterializedCtesBlocks;
 use common_catalog::table_context::ProcessInfo;
+use common_catalog::table_context::QueuedQueryInfo;
 use common_catalog::table_context::StageAttachment;
 use common_catalog::table_context::TableContext;
+use common_catalog::txn::TxnManagerRef;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
 use common_expression::FunctionContext;
+use common_expression::Scalar;
 use common_io::prelude::FormatSettings;
 use common_meta_app::principal::FileFormatParams;
 use common_meta_app::principal::OnErrorMode;
@@ -542,6 +546,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_queued_queries(&self) -> Vec<QueuedQueryInfo> {
+        todo!()
+    }
+
     fn get_stage_attachment(&self) -> Option<StageAttachment> {
         todo!()
     }
@@ -558,6 +566,12 @@ impl TableContext for CtxDelegation {
     fn set_query_id_result_cache(&self, _query_id: String, _result_cache_key: String) {
         todo!()
     }
+    fn set_variable(&self, _name: String, _value: Scalar) {
+        todo!()
+    }
+    fn get_variable(&self, _name: &str) -> Option<Scalar> {
+        todo!()
+    }
 
     fn get_on_error_map(&self) -> Option<Arc<DashMap<String, HashMap<u16, InputError>>>> {
         todo!()
@@ -630,6 +644,14 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn set_runtime_filter(&self, _scan_id: usize, _filter: RuntimeFilterInfo) {
+        todo!()
+    }
+
+    fn get_runtime_filter(&self, _scan_id: usize) -> Option<RuntimeFilterInfo> {
+        todo!()
+    }
+
     fn add_segment_location(&self, _segment_loc: Location) -> Result<()> {
         todo!()
     }
@@ -649,6 +671,10 @@ impl TableContext for CtxDelegation {
     fn get_license_key(&self) -> String {
         todo!()
     }
+
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.ctx.txn_mgr()
+    }
 }
 
 #[derive(Clone, Debug)]
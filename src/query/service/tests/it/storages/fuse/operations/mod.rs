@@ -16,6 +16,7 @@
 mod alter_table;
 mod analyze;
 mod clustering;
+mod column_encryption;
 mod commit;
 mod gc;
 mod internal_column;
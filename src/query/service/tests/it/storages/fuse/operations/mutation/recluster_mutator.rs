@@ -134,20 +134,45 @@ async fn test_recluster_mutator_block_select() -> Result<()> {
     )
     .await?;
 
+    let snapshot = Arc::new(snapshot);
     let mut mutator = ReclusterMutator::try_create(
-        ctx,
-        Arc::new(snapshot),
-        schema,
+        ctx.clone(),
+        snapshot.clone(),
+        schema.clone(),
         1.0,
         BlockThresholds::default(),
         cluster_key_id,
         1,
     )?;
-    let need_recluster = mutator.target_select(compact_segments).await?;
+    let need_recluster = mutator.target_select(compact_segments.clone()).await?;
     assert!(need_recluster);
     assert_eq!(mutator.tasks.len(), 1);
     let total_block_nums = mutator.tasks.iter().map(|t| t.parts.len()).sum::<usize>();
     assert_eq!(total_block_nums, 3);
+    let progress = mutator.recluster_progress();
+    assert_eq!(progress.runs, 1);
+    assert_eq!(progress.block_count, 3);
+    assert_eq!(progress.byte_size, 3);
+
+    // the io budget only allows the two most overlapping blocks to be rewritten.
+    ctx.get_settings().set_recluster_io_budget(2)?;
+    let threshold = BlockThresholds {
+        max_rows_per_block: 1,
+        min_rows_per_block: 1,
+        max_bytes_per_block: 1,
+    };
+    let mut mutator =
+        ReclusterMutator::try_create(ctx, snapshot, schema, 1.0, threshold, cluster_key_id, 1)?;
+    let need_recluster = mutator.target_select(compact_segments).await?;
+    assert!(need_recluster);
+    assert_eq!(mutator.tasks.len(), 1);
+    let total_block_nums = mutator.tasks.iter().map(|t| t.parts.len()).sum::<usize>();
+    assert_eq!(total_block_nums, 2);
+    assert_eq!(mutator.remained_blocks.len(), 1);
+    let progress = mutator.recluster_progress();
+    assert_eq!(progress.block_count, 2);
+    assert_eq!(progress.byte_size, 2);
+    assert!(progress.average_depth > 1.0);
 
     Ok(())
 }
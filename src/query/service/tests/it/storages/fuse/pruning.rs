@@ -32,6 +32,7 @@ use common_expression::TableSchemaRefExt;
 use common_sql::parse_to_filters;
 use common_sql::plans::CreateTablePlan;
 use common_sql::BloomIndexColumns;
+use common_sql::BloomIndexPaths;
 use common_storages_fuse::pruning::create_segment_location_vector;
 use common_storages_fuse::pruning::FusePruner;
 use common_storages_fuse::FuseTable;
@@ -42,6 +43,7 @@ use databend_query::sessions::TableContext;
 use databend_query::storages::fuse::io::MetaReaders;
 use databend_query::storages::fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
 use databend_query::storages::fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
+use databend_query::test_kits::table_test_fixture::execute_command;
 use databend_query::test_kits::table_test_fixture::TestFixture;
 use opendal::Operator;
 use storages_common_cache::LoadParams;
@@ -62,10 +64,18 @@ async fn apply_block_pruning(
     let ctx: Arc<dyn TableContext> = ctx;
     let segment_locs = table_snapshot.segments.clone();
     let segment_locs = create_segment_location_vector(segment_locs, None);
-    FusePruner::create(&ctx, op, schema, push_down, bloom_index_cols)?
-        .read_pruning(segment_locs)
-        .await
-        .map(|v| v.into_iter().map(|(_, v)| v).collect())
+    FusePruner::create(
+        &ctx,
+        op,
+        schema,
+        push_down,
+        bloom_index_cols,
+        BloomIndexPaths::default(),
+        BloomIndexColumns::None,
+    )?
+    .read_pruning(segment_locs)
+    .await
+    .map(|v| v.into_iter().map(|(_, v)| v).collect())
 }
 
 #[tokio::test(flavor = "multi_thread")]
@@ -92,6 +102,7 @@ async fn test_block_pruner() -> Result<()> {
         table: test_tbl_name.to_string(),
         schema: test_schema.clone(),
         engine: Engine::Fuse,
+        engine_options: Default::default(),
         storage_params: None,
         read_only_attach: false,
         part_prefix: "".to_string(),
@@ -254,3 +265,78 @@ async fn test_block_pruner() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_topn_pruner_by_cluster_stats() -> Result<()> {
+    let fixture = TestFixture::new().await;
+    let ctx = fixture.ctx();
+    ctx.get_settings()
+        .set_setting("enable_recluster_after_write".to_string(), "0".to_string())?;
+
+    execute_command(
+        ctx.clone(),
+        "create table t_topn(a uint64 not null, b uint64 not null) cluster by (a)",
+    )
+    .await?;
+    // 10 blocks, the block `i` contains the values of column a in [i * 10, i * 10 + 9].
+    for i in 0..10 {
+        let insert = format!(
+            "insert into t_topn select number + {}, number from numbers(10)",
+            i * 10
+        );
+        execute_command(ctx.clone(), &insert).await?;
+    }
+
+    let catalog = ctx.get_catalog("default").await?;
+    let table = catalog
+        .get_table(
+            fixture.default_tenant().as_str(),
+            fixture.default_db_name().as_str(),
+            "t_topn",
+        )
+        .await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+    let column_ref = |name: &str| RemoteExpr::ColumnRef {
+        span: None,
+        id: name.to_string(),
+        data_type: UInt64Type::data_type(),
+        display_name: name.to_string(),
+    };
+
+    let cases = vec![
+        // the first 2 blocks contain the first 15 rows.
+        ("a", true, 15, 2),
+        ("a", true, 25, 3),
+        ("a", false, 5, 1),
+        // not the cluster key, at most `limit` blocks are kept by the column statistics.
+        ("b", true, 3, 3),
+    ];
+    for (column, asc, limit, expected_blocks) in cases {
+        let push_down = Some(PushDownInfo {
+            order_by: vec![(column_ref(column), asc, false)],
+            limit: Some(limit),
+            ..Default::default()
+        });
+        let ctx: Arc<dyn TableContext> = ctx.clone();
+        let mut pruner = FusePruner::create(
+            &ctx,
+            fuse_table.get_operator(),
+            table.schema(),
+            &push_down,
+            BloomIndexColumns::All,
+            BloomIndexPaths::default(),
+            BloomIndexColumns::None,
+        )?;
+        pruner.set_cluster_key(fuse_table.cluster_key_id().unwrap(), vec![column_ref("a")]);
+        let segment_locs = create_segment_location_vector(snapshot.segments.clone(), None);
+        let blocks = pruner.read_pruning(segment_locs).await?;
+        assert_eq!(
+            expected_blocks,
+            blocks.len(),
+            "order by {column} limit {limit}"
+        );
+    }
+
+    Ok(())
+}
@@ -20,6 +20,7 @@ use common_catalog::table::Table;
 use common_exception::Result;
 use common_expression::block_debug::box_render;
 use common_expression::block_debug::pretty_format_blocks;
+use common_expression::ScalarRef;
 use common_meta_app::principal::AuthInfo;
 use common_meta_app::principal::AuthType;
 use common_meta_app::principal::RoleInfo;
@@ -33,6 +34,8 @@ use common_sql::executor::table_read_plan::ToReadDataSourcePlan;
 use common_storages_system::BuildOptionsTable;
 use common_storages_system::CachesTable;
 use common_storages_system::CatalogsTable;
+use common_storages_system::ClusteringHistoryLogElement;
+use common_storages_system::ClusteringHistoryQueue;
 use common_storages_system::ClustersTable;
 use common_storages_system::ColumnsTable;
 use common_storages_system::ConfigsTable;
@@ -42,6 +45,7 @@ use common_storages_system::DatabasesTable;
 use common_storages_system::EnginesTable;
 use common_storages_system::FunctionsTable;
 use common_storages_system::MetricsTable;
+use common_storages_system::RoleGrantsTable;
 use common_storages_system::RolesTable;
 use common_storages_system::SettingsTable;
 use common_storages_system::TracingTable;
@@ -282,7 +286,7 @@ async fn test_metrics_table() -> Result<()> {
     let stream = table.read_data_block_stream(ctx, &source_plan).await?;
     let result = stream.try_collect::<Vec<_>>().await?;
     let block = &result[0];
-    assert_eq!(block.num_columns(), 5);
+    assert_eq!(block.num_columns(), 7);
     assert!(block.num_rows() >= 1);
 
     let output = box_render(
@@ -296,6 +300,18 @@ async fn test_metrics_table() -> Result<()> {
     assert!(output.contains("test_metrics_table_count"));
     assert!(output.contains("test_metrics_table_histogram"));
 
+    // Each histogram bucket is a separate row with a typed upper bound.
+    let metric_col = block.get_by_offset(1).value.as_column().unwrap();
+    let le_col = block.get_by_offset(4).value.as_column().unwrap();
+    let histogram_buckets = (0..block.num_rows())
+        .filter(|i| {
+            metric_col.index(*i).unwrap()
+                == ScalarRef::String("test_metrics_table_histogram".as_bytes())
+        })
+        .filter(|i| le_col.index(*i).unwrap() != ScalarRef::Null)
+        .count();
+    assert!(histogram_buckets > 1);
+
     Ok(())
 }
 
@@ -328,6 +344,43 @@ async fn test_roles_table() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn test_role_grants_table() -> Result<()> {
+    let mut mint = Mint::new("tests/it/storages/testdata");
+    let file = &mut mint.new_goldenfile("role_grants_table.txt").unwrap();
+
+    let (_guard, ctx) = databend_query::test_kits::create_query_context().await?;
+    let tenant = ctx.get_tenant();
+    ctx.get_settings().set_max_threads(2)?;
+
+    {
+        let role_info = RoleInfo::new("test");
+        UserApiProvider::instance()
+            .add_role(&tenant, role_info, false)
+            .await?;
+    }
+
+    {
+        let mut role_info = RoleInfo::new("test1");
+        role_info.grants.grant_role("test".to_string());
+        UserApiProvider::instance()
+            .add_role(&tenant, role_info, false)
+            .await?;
+    }
+
+    {
+        let mut role_info = RoleInfo::new("test2");
+        role_info.grants.grant_role("test1".to_string());
+        UserApiProvider::instance()
+            .add_role(&tenant, role_info, false)
+            .await?;
+    }
+    let table = RoleGrantsTable::create(1);
+
+    run_table_tests(file, ctx, table).await?;
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn test_settings_table() -> Result<()> {
     let mut mint = Mint::new("tests/it/storages/testdata");
@@ -418,3 +471,41 @@ async fn test_caches_table() -> Result<()> {
     run_table_tests(file, ctx, table).await?;
     Ok(())
 }
+
+#[test]
+fn test_system_log_queue_events_since() -> Result<()> {
+    let queue = ClusteringHistoryQueue::create(3);
+    let event = |row_count| ClusteringHistoryLogElement {
+        start_time: 0,
+        end_time: 0,
+        database: "db".to_string(),
+        table: "t".to_string(),
+        block_count: 1,
+        byte_size: 1,
+        row_count,
+    };
+
+    let (events, cursor) = queue.events_since(0);
+    assert!(events.is_empty());
+    assert_eq!(cursor, 0);
+
+    queue.append_data(event(1))?;
+    queue.append_data(event(2))?;
+    let (events, cursor) = queue.events_since(0);
+    let rows = events.iter().map(|e| e.row_count).collect::<Vec<_>>();
+    assert_eq!(rows, vec![1, 2]);
+    assert_eq!(cursor, 2);
+
+    // Events overwritten by the ring buffer are skipped.
+    for row_count in 3..=6 {
+        queue.append_data(event(row_count))?;
+    }
+    let (events, cursor) = queue.events_since(2);
+    let rows = events.iter().map(|e| e.row_count).collect::<Vec<_>>();
+    assert_eq!(rows, vec![4, 5, 6]);
+    assert_eq!(cursor, 6);
+
+    let (events, _) = queue.events_since(cursor);
+    assert!(events.is_empty());
+    Ok(())
+}
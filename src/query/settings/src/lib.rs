@@ -21,3 +21,4 @@ pub use settings::ChangeValue;
 pub use settings::ScopeLevel;
 pub use settings::Settings;
 pub use settings_default::ReplaceIntoShuffleStrategy;
+pub use settings_default::SettingRange;
@@ -25,9 +25,13 @@ use itertools::Itertools;
 
 use crate::settings_default::DefaultSettingValue;
 use crate::settings_default::DefaultSettings;
+use crate::settings_default::SettingRange;
 
-#[derive(serde::Serialize, serde::Deserialize, Clone)]
+/// The level a setting value comes from: `Default` if it is not changed, `Global` if it is
+/// changed by `SET GLOBAL` and persisted in the meta service, `Session` if it is changed by `SET`.
+#[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq)]
 pub enum ScopeLevel {
+    Default,
     Global,
     Session,
 }
@@ -35,6 +39,9 @@ pub enum ScopeLevel {
 impl Debug for ScopeLevel {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         match self {
+            ScopeLevel::Default => {
+                write!(f, "DEFAULT")
+            }
             ScopeLevel::Global => {
                 write!(f, "GLOBAL")
             }
@@ -85,7 +92,7 @@ impl Settings {
         }
 
         match DefaultSettings::has_setting(key)? {
-            true => Ok(ScopeLevel::Session),
+            true => Ok(ScopeLevel::Default),
             false => Err(ErrorCode::UnknownVariable(format!(
                 "Unknown variable: {:?}",
                 key
@@ -144,7 +151,7 @@ pub struct SettingsItem {
     pub desc: &'static str,
     pub user_value: UserSettingValue,
     pub default_value: UserSettingValue,
-    pub possible_values: Option<Vec<&'static str>>,
+    pub range: Option<SettingRange>,
     pub display_in_show_settings: bool,
 }
 
@@ -178,11 +185,11 @@ impl<'a> Iterator for SettingsIter<'a> {
             Some((key, default_value)) => Some(match self.settings.changes.get(&key) {
                 None => SettingsItem {
                     name: key,
-                    level: ScopeLevel::Session,
+                    level: ScopeLevel::Default,
                     desc: default_value.desc,
                     user_value: default_value.value.clone(),
                     default_value: default_value.value,
-                    possible_values: default_value.possible_values,
+                    range: default_value.range,
                     display_in_show_settings: default_value.display_in_show_settings,
                 },
                 Some(change_value) => SettingsItem {
@@ -191,7 +198,7 @@ impl<'a> Iterator for SettingsIter<'a> {
                     desc: default_value.desc,
                     user_value: change_value.value.clone(),
                     default_value: default_value.value,
-                    possible_values: default_value.possible_values,
+                    range: default_value.range,
                     display_in_show_settings: default_value.display_in_show_settings,
                 },
             }),
@@ -13,6 +13,9 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::RangeInclusive;
 use std::sync::Arc;
 
 use common_config::GlobalConfig;
@@ -27,10 +30,41 @@ static DEFAULT_SETTINGS: OnceCell<Arc<DefaultSettings>> = OnceCell::new();
 pub struct DefaultSettingValue {
     pub(crate) value: UserSettingValue,
     pub(crate) desc: &'static str,
-    pub(crate) possible_values: Option<Vec<&'static str>>,
+    pub(crate) range: Option<SettingRange>,
     pub(crate) display_in_show_settings: bool,
 }
 
+/// The values allowed by a setting, displayed in the `range` column of `system.settings`.
+#[derive(Clone, Debug)]
+pub enum SettingRange {
+    /// The numeric values within the inclusive range.
+    Numeric(RangeInclusive<u64>),
+    /// The string values, compared case-insensitively.
+    String(Vec<&'static str>),
+}
+
+impl SettingRange {
+    /// Check if `value` is within the range, the value of a numeric setting is parsed already.
+    pub fn is_valid(&self, value: &UserSettingValue) -> bool {
+        match (self, value) {
+            (SettingRange::Numeric(range), UserSettingValue::UInt64(v)) => range.contains(v),
+            (SettingRange::String(values), UserSettingValue::String(v)) => {
+                values.iter().any(|x| x.eq_ignore_ascii_case(v))
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Display for SettingRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettingRange::Numeric(range) => write!(f, "[{}, {}]", range.start(), range.end()),
+            SettingRange::String(values) => write!(f, "{:?}", values),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct DefaultSettings {
     pub(crate) settings: HashMap<String, DefaultSettingValue>,
@@ -48,261 +82,327 @@ impl DefaultSettings {
                 ("max_block_size", DefaultSettingValue {
                     value: UserSettingValue::UInt64(65536),
                     desc: "Sets the maximum byte size of a single data block that can be read.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("max_threads", DefaultSettingValue {
                     value: UserSettingValue::UInt64(num_cpus),
                     desc: "Sets the maximum number of threads to execute a request.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("max_memory_usage", DefaultSettingValue {
                     value: UserSettingValue::UInt64(max_memory_usage),
                     desc: "Sets the maximum memory usage in bytes for processing a single query.",
-                    possible_values: None,
+                    range: None,
+                    display_in_show_settings: true,
+                }),
+                ("max_query_memory_usage", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the hard limit of memory in bytes allocated by a single query, the query fails once exceeding it. Setting it to 0 means no limit.",
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("retention_period", DefaultSettingValue {
                     // unit of retention_period is hour
                     value: UserSettingValue::UInt64(12),
                     desc: "Sets the retention period in hours.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("max_storage_io_requests", DefaultSettingValue {
                     value: UserSettingValue::UInt64(default_max_storage_io_requests),
                     desc: "Sets the maximum number of concurrent I/O requests.",
-                    possible_values: None,
+                    range: None,
+                    display_in_show_settings: true,
+                }),
+                ("min_bytes_per_scan_thread", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(8 * 1024 * 1024),
+                    desc: "Sets the minimum byte size of data to read by each thread when scanning a table, a small scan uses fewer threads than max_threads. 0 disables it.",
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("storage_io_min_bytes_for_seek", DefaultSettingValue {
                     value: UserSettingValue::UInt64(48),
                     desc: "Sets the minimum byte size of data that must be read from storage in a single I/O operation \
                 when seeking a new location in the data file.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("storage_io_max_page_bytes_for_read", DefaultSettingValue {
                     value: UserSettingValue::UInt64(512 * 1024),
                     desc: "Sets the maximum byte size of data pages that can be read from storage in a single I/O operation.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("flight_client_timeout", DefaultSettingValue {
                     value: UserSettingValue::UInt64(60),
                     desc: "Sets the maximum time in seconds that a flight client request can be processed.",
-                    possible_values: None,
+                    range: None,
+                    display_in_show_settings: true,
+                }),
+                ("exchange_compression", DefaultSettingValue {
+                    value: UserSettingValue::String("lz4".to_owned()),
+                    desc: "Sets the compression of data blocks exchanged between cluster nodes. Available values include \"lz4\", \"zstd\" and \"none\".",
+                    range: Some(SettingRange::String(vec!["lz4", "zstd", "none"])),
+                    display_in_show_settings: true,
+                }),
+                ("exchange_credit_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(64 * 1024 * 1024),
+                    desc: "Sets the maximum bytes in flight per exchange channel before the sender waits for the receiver. Setting it to 0 means no limit.",
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("storage_read_buffer_size", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1024 * 1024),
                     desc: "Sets the byte size of the buffer used for reading data into memory.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("input_read_buffer_size", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1024 * 1024),
                     desc: "Sets the memory size in bytes allocated to the buffer used by the buffered reader to read data from storage.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("timezone", DefaultSettingValue {
                     value: UserSettingValue::String("UTC".to_owned()),
                     desc: "Sets the timezone.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("group_by_two_level_threshold", DefaultSettingValue {
                     value: UserSettingValue::UInt64(20000),
                     desc: "Sets the number of keys in a GROUP BY operation that will trigger a two-level aggregation.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("max_inlist_to_or", DefaultSettingValue {
                     value: UserSettingValue::UInt64(3),
                     desc: "Sets the maximum number of values that can be included in an IN expression to be converted to an OR operator.",
-                    possible_values: None,
+                    range: None,
+                    display_in_show_settings: true,
+                }),
+                ("max_recursive_cte_iterations", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1000),
+                    desc: "Sets the maximum number of iterations of the recursive term of a recursive CTE.",
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("unquoted_ident_case_sensitive", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Determines whether Databend treats unquoted identifiers as case-sensitive.",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("quoted_ident_case_sensitive", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Determines whether Databend treats quoted identifiers as case-sensitive.",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("sql_dialect", DefaultSettingValue {
                     value: UserSettingValue::String("PostgreSQL".to_owned()),
                     desc: "Sets the SQL dialect. Available values include \"PostgreSQL\", \"MySQL\", and \"Hive\".",
-                    possible_values: Some(vec!["PostgreSQL", "MySQL", "Hive"]),
+                    range: Some(SettingRange::String(vec!["PostgreSQL", "MySQL", "Hive"])),
                     display_in_show_settings: true,
                 }),
                 ("enable_dphyp", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables dphyp join order algorithm.",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("enable_cbo", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables cost-based optimization.",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("disable_join_reorder", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Disable join reorder optimization.",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: false,}),
                 ("join_spilling_threshold", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Maximum amount of memory can use for hash join, 0 is unlimited.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("enable_runtime_filter", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enables runtime filter optimization for JOIN.",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                    display_in_show_settings: true,
+                }),
+                ("enable_runtime_filter_pruning", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables skipping the blocks of the probe side table that can not match the join keys collected from the hash join build side.",
+                    range: Some(SettingRange::Numeric(0..=1)),
+                    display_in_show_settings: true,
+                }),
+                ("enable_async_insert", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables buffering INSERT ... VALUES statements per table and writing them in batches, the statement returns before the data is written.",
+                    range: Some(SettingRange::Numeric(0..=1)),
+                    display_in_show_settings: true,
+                }),
+                ("async_insert_max_data_size", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1048576),
+                    desc: "Sets the maximum bytes of async inserts buffered per table before they are flushed.",
+                    range: None,
+                    display_in_show_settings: true,
+                }),
+                ("async_insert_busy_timeout_ms", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(200),
+                    desc: "Sets the maximum milliseconds an async insert is buffered before it is flushed.",
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("max_execute_time_in_seconds", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Sets the maximum query execution time in seconds. Setting it to 0 means no limit.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("collation", DefaultSettingValue {
                     value: UserSettingValue::String("binary".to_owned()),
                     desc: "Sets the character collation. Available values include \"binary\" and \"utf8\".",
-                    possible_values: Some(vec!["binary", "utf8"]),
+                    range: Some(SettingRange::String(vec!["binary", "utf8"])),
                     display_in_show_settings: true,
                 }),
                 ("max_result_rows", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Sets the maximum number of rows that can be returned in a query result when no specific row count is specified. Setting it to 0 means no limit.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("prefer_broadcast_join", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables broadcast join.",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("storage_fetch_part_num", DefaultSettingValue {
                     value: UserSettingValue::UInt64(2),
                     desc: "Sets the number of partitions that are fetched in parallel from storage during query execution.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("load_file_metadata_expire_hours", DefaultSettingValue {
                     value: UserSettingValue::UInt64(24 * 7),
                     desc: "Sets the hours that the metadata of files you load data from with COPY INTO will expire in.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("hide_options_in_show_create_table", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Hides table-relevant information, such as SNAPSHOT_LOCATION and STORAGE_FORMAT, at the end of the result of SHOW TABLE CREATE.",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("sandbox_tenant", DefaultSettingValue {
                     value: UserSettingValue::String("".to_string()),
                     desc: "Injects a custom 'sandbox_tenant' into this session. This is only for testing purposes and will take effect only when 'internal_enable_sandbox_tenant' is turned on.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("parquet_uncompressed_buffer_size", DefaultSettingValue {
                     value: UserSettingValue::UInt64(2 * 1024 * 1024),
                     desc: "Sets the byte size of the buffer used for reading Parquet files.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("enable_bushy_join", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enables generating a bushy join plan with the optimizer.",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("enable_query_result_cache", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enables caching query results to improve performance for identical queries.",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                    display_in_show_settings: true,
+                }),
+                ("enable_http_query_result_persistence", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables persisting the result pages of http queries, so they can be fetched after the client disconnects.",
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("query_result_cache_max_bytes", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1048576), // 1MB
                     desc: "Sets the maximum byte size of cache for a single query result.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("query_result_cache_ttl_secs", DefaultSettingValue {
                     value: UserSettingValue::UInt64(300), // seconds
                     desc: "Sets the time-to-live (TTL) in seconds for cached query results. \
                 Once the TTL for a cached result has expired, the result is considered stale and will not be used for new queries.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("query_result_cache_allow_inconsistent", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Determines whether Databend will return cached query results that are inconsistent with the underlying data.",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("enable_hive_parquet_predict_pushdown", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enable hive parquet predict pushdown  by setting this variable to 1, default value: 1",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("hive_parquet_chunk_size", DefaultSettingValue {
                     value: UserSettingValue::UInt64(16384),
                     desc: "the max number of rows each read from parquet to databend processor",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("spilling_bytes_threshold_per_proc", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Sets the maximum amount of memory in bytes that an aggregator can use before spilling data to storage during query execution.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("spilling_memory_ratio", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Sets the maximum memory ratio in bytes that an aggregator can use before spilling data to storage during query execution.",
-                    possible_values: None,
+                    range: None,
+                    display_in_show_settings: true,
+                }),
+                ("sort_spilling_bytes_threshold_per_proc", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum amount of memory in bytes that a sorter can use before spilling data to storage during query execution.",
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("group_by_shuffle_mode", DefaultSettingValue {
                     value: UserSettingValue::String(String::from("before_merge")),
                     desc: "Group by shuffle mode, 'before_partial' is more balanced, but more data needs to exchange.",
-                    possible_values: Some(vec!["before_partial", "before_merge"]),
+                    range: Some(SettingRange::String(vec!["before_partial", "before_merge"])),
                     display_in_show_settings: true,
                 }),
                 ("efficiently_memory_group_by", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Memory is used efficiently, but this may cause performance degradation.",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("lazy_read_threshold", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1000),
                     desc: "Sets the maximum LIMIT in a query to enable lazy read optimization. Setting it to 0 disables the optimization.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("parquet_fast_read_bytes", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Parquet file with smaller size will be read as a whole file, instead of column by column.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
 
@@ -310,148 +410,196 @@ impl DefaultSettings {
                 ("enterprise_license", DefaultSettingValue {
                     value: UserSettingValue::String("".to_owned()),
                     desc: "License key for use enterprise features",
-                    possible_values: None,
+                    range: None,
                     // license key should not be reported
                     display_in_show_settings: false,
                 }),
                 ("enable_table_lock", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables table lock if necessary (enabled by default).",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("table_lock_expire_secs", DefaultSettingValue {
                     value: UserSettingValue::UInt64(5),
                     desc: "Sets the seconds that the table lock will expire in.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("deduplicate_label", DefaultSettingValue {
                     value: UserSettingValue::String("".to_owned()),
                     desc: "Sql duplicate label for deduplication.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: false,
                 }),
                 ("enable_distributed_copy_into", DefaultSettingValue {
-                    value: UserSettingValue::UInt64(0),
-                    desc: "Enable distributed execution of copy into.",
-                    possible_values: None,
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enable distributed execution of copy into, the files are loaded by all nodes of the cluster.",
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("enable_experimental_merge_into", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enable unstable merge into.",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("enable_distributed_replace_into", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enable distributed execution of replace into.",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("enable_distributed_compact", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enable distributed execution of table compaction.",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("enable_aggregating_index_scan", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enable scanning aggregating index data while querying.",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                    display_in_show_settings: true,
+                }),
+                ("copy_error_log_path", DefaultSettingValue {
+                    value: UserSettingValue::String("".to_owned()),
+                    desc: "Sets the path in the user stage where COPY writes the rows skipped by ON_ERROR, one \"<query_id>.ndjson\" file per load. Empty disables it.",
+                    range: None,
+                    display_in_show_settings: true,
+                }),
+                ("enable_copy_merge_small_blocks", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables merging the small blocks written by COPY into target-sized blocks before committing.",
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("enable_recluster_after_write", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables re-clustering after write(copy/replace-into).",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("use_parquet2", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Use parquet2 instead of parquet_rs when infer_schema().",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("enable_replace_into_partitioning", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables partitioning for replace-into statement (if table has cluster keys).",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("enable_replace_into_bloom_pruning", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables bloom pruning for replace-into statement.",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("replace_into_bloom_pruning_max_column_number", DefaultSettingValue {
                     value: UserSettingValue::UInt64(4),
                     desc: "Max number of columns used by bloom pruning for replace-into statement.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("replace_into_shuffle_strategy", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "0 for Block level shuffle, 1 for segment level shuffle",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("recluster_timeout_secs", DefaultSettingValue {
                     value: UserSettingValue::UInt64(12 * 60 * 60),
                     desc: "Sets the seconds that recluster final will be timeout.",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("enable_refresh_aggregating_index_after_write", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Refresh aggregating index after new data written",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                    display_in_show_settings: true,
+                }),
+                ("enable_refresh_virtual_column_after_write", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Refresh virtual column after new data written",
+                    range: Some(SettingRange::Numeric(0..=1)),
+                    display_in_show_settings: true,
+                }),
+                ("enable_refresh_materialized_view_after_write", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Refresh materialized views after new data written to their source tables",
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("ddl_column_type_nullable", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "If columns are default nullable when create or alter table",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
                     display_in_show_settings: true,
                 }),
                 ("enable_query_profiling", DefaultSettingValue {
                         value: UserSettingValue::UInt64(0),
                         desc: "Enables recording query profile",
-                        possible_values: None,
+                        range: Some(SettingRange::Numeric(0..=1)),
                         display_in_show_settings: true,
                 }),
                 ("recluster_block_size", DefaultSettingValue {
                     value: UserSettingValue::UInt64(recluster_block_size),
                     desc: "Sets the maximum byte size of blocks for recluster",
-                    possible_values: None,
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("enable_distributed_recluster", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Enable distributed execution of table recluster.",
-                    possible_values: None,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                    display_in_show_settings: true,
+                }),
+                ("recluster_io_budget", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum byte size of blocks rewritten by a single recluster run, 0 means no limit.",
+                    range: None,
                     display_in_show_settings: true,
                 }),
                 ("enable_parquet_page_index", DefaultSettingValue {
                         value: UserSettingValue::UInt64(1),
                         desc: "Enables parquet page index",
-                        possible_values: None,
+                        range: Some(SettingRange::Numeric(0..=1)),
                         display_in_show_settings: true,
                 }),
                 ("enable_parquet_rowgroup_pruning", DefaultSettingValue {
                         value: UserSettingValue::UInt64(1),
                         desc: "Enables parquet rowgroup pruning",
-                        possible_values: None,
+                        range: Some(SettingRange::Numeric(0..=1)),
                         display_in_show_settings: true,
                 }),
                 ("enable_parquet_prewhere", DefaultSettingValue {
                         value: UserSettingValue::UInt64(0),
                         desc: "Enables parquet prewhere",
-                        possible_values: None,
+                        range: Some(SettingRange::Numeric(0..=1)),
                         display_in_show_settings: true,
                 }),
+                ("deletion_vector_max_ratio", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum percentage of the rows of a block that DELETE marks in a deletion vector instead of rewriting the block. Setting it to 0 disables deletion vectors.",
+                    range: Some(SettingRange::Numeric(0..=100)),
+                    display_in_show_settings: true,
+                }),
+                ("mutation_write_concurrency", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(4),
+                    desc: "Sets the maximum number of blocks that each thread of DELETE, UPDATE and MERGE INTO writes concurrently.",
+                    range: None,
+                    display_in_show_settings: true,
+                }),
+                ("mutation_write_buffer_size", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(100 * 1024 * 1024),
+                    desc: "Sets the maximum byte size of the serialized blocks that DELETE, UPDATE and MERGE INTO buffer before writing.",
+                    range: None,
+                    display_in_show_settings: true,
+                }),
             ]);
 
             Ok(Arc::new(DefaultSettings {
@@ -531,15 +679,7 @@ impl DefaultSettings {
         match default_settings.settings.get(&k) {
             None => Ok((k, None)),
             Some(setting_value) => {
-                if let Some(possible_values) = &setting_value.possible_values {
-                    if !possible_values.iter().any(|x| x.eq_ignore_ascii_case(&v)) {
-                        return Err(ErrorCode::WrongValueForVariable(format!(
-                            "Invalid setting value: {:?} for variable {:?}, possible values: {:?}",
-                            v, k, possible_values
-                        )));
-                    }
-                }
-                match setting_value.value {
+                let value = match setting_value.value {
                     UserSettingValue::UInt64(_) => {
                         // decimal 10 * 1.5 to string may result in string like "15.0"
                         let val = if let Some(p) = v.find('.') {
@@ -552,11 +692,19 @@ impl DefaultSettings {
                             &v[..]
                         };
 
-                        let u64_val = val.parse::<u64>()?;
-                        Ok((k, Some(UserSettingValue::UInt64(u64_val))))
+                        UserSettingValue::UInt64(val.parse::<u64>()?)
+                    }
+                    UserSettingValue::String(_) => UserSettingValue::String(v.clone()),
+                };
+                if let Some(range) = &setting_value.range {
+                    if !range.is_valid(&value) {
+                        return Err(ErrorCode::WrongValueForVariable(format!(
+                            "Invalid setting value: {:?} for variable {:?}, range: {}",
+                            v, k, range
+                        )));
                     }
-                    UserSettingValue::String(_) => Ok((k, Some(UserSettingValue::String(v)))),
                 }
+                Ok((k, Some(value)))
             }
         }
     }
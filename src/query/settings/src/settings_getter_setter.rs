@@ -102,6 +102,10 @@ impl Settings {
         self.try_set_u64("max_memory_usage", val)
     }
 
+    pub fn get_max_query_memory_usage(&self) -> Result<u64> {
+        self.try_get_u64("max_query_memory_usage")
+    }
+
     pub fn set_retention_period(&self, hours: u64) -> Result<()> {
         self.try_set_u64("retention_period", hours)
     }
@@ -114,6 +118,10 @@ impl Settings {
         self.try_get_u64("max_storage_io_requests")
     }
 
+    pub fn get_min_bytes_per_scan_thread(&self) -> Result<u64> {
+        self.try_get_u64("min_bytes_per_scan_thread")
+    }
+
     pub fn set_max_storage_io_requests(&self, val: u64) -> Result<()> {
         if val > 0 {
             self.try_set_u64("max_storage_io_requests", val)
@@ -142,6 +150,14 @@ impl Settings {
         self.try_get_u64("flight_client_timeout")
     }
 
+    pub fn get_exchange_compression(&self) -> Result<String> {
+        Ok(self.try_get_string("exchange_compression")?.to_lowercase())
+    }
+
+    pub fn get_exchange_credit_bytes(&self) -> Result<u64> {
+        self.try_get_u64("exchange_credit_bytes")
+    }
+
     // Get storage read buffer size.
     pub fn get_storage_read_buffer_size(&self) -> Result<u64> {
         self.try_get_u64("storage_read_buffer_size")
@@ -168,6 +184,10 @@ impl Settings {
         self.try_get_u64("max_inlist_to_or")
     }
 
+    pub fn get_max_recursive_cte_iterations(&self) -> Result<u64> {
+        self.try_get_u64("max_recursive_cte_iterations")
+    }
+
     pub fn get_unquoted_ident_case_sensitive(&self) -> Result<bool> {
         Ok(self.try_get_u64("unquoted_ident_case_sensitive")? != 0)
     }
@@ -243,6 +263,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_query_result_cache")? != 0)
     }
 
+    pub fn get_enable_http_query_result_persistence(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_http_query_result_persistence")? != 0)
+    }
+
     pub fn get_query_result_cache_max_bytes(&self) -> Result<usize> {
         Ok(self.try_get_u64("query_result_cache_max_bytes")? as usize)
     }
@@ -259,6 +283,10 @@ impl Settings {
         Ok(self.try_get_u64("spilling_bytes_threshold_per_proc")? as usize)
     }
 
+    pub fn get_sort_spilling_bytes_threshold_per_proc(&self) -> Result<usize> {
+        Ok(self.try_get_u64("sort_spilling_bytes_threshold_per_proc")? as usize)
+    }
+
     pub fn get_spilling_memory_ratio(&self) -> Result<usize> {
         Ok(self.try_get_u64("spilling_memory_ratio")? as usize)
     }
@@ -332,6 +360,14 @@ impl Settings {
         Ok(self.try_get_u64("enable_aggregating_index_scan")? != 0)
     }
 
+    pub fn get_copy_error_log_path(&self) -> Result<String> {
+        self.try_get_string("copy_error_log_path")
+    }
+
+    pub fn get_enable_copy_merge_small_blocks(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_copy_merge_small_blocks")? != 0)
+    }
+
     pub fn get_enable_recluster_after_write(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_recluster_after_write")? != 0)
     }
@@ -377,6 +413,14 @@ impl Settings {
         Ok(self.try_get_u64("enable_distributed_recluster")? != 0)
     }
 
+    pub fn set_recluster_io_budget(&self, val: u64) -> Result<()> {
+        self.try_set_u64("recluster_io_budget", val)
+    }
+
+    pub fn get_recluster_io_budget(&self) -> Result<u64> {
+        self.try_get_u64("recluster_io_budget")
+    }
+
     pub fn get_enable_refresh_aggregating_index_after_write(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_refresh_aggregating_index_after_write")? != 0)
     }
@@ -388,6 +432,14 @@ impl Settings {
         )
     }
 
+    pub fn get_enable_refresh_virtual_column_after_write(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_refresh_virtual_column_after_write")? != 0)
+    }
+
+    pub fn get_enable_refresh_materialized_view_after_write(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_refresh_materialized_view_after_write")? != 0)
+    }
+
     pub fn get_ddl_column_type_nullable(&self) -> Result<bool> {
         Ok(self.try_get_u64("ddl_column_type_nullable")? == 1)
     }
@@ -407,4 +459,32 @@ impl Settings {
     pub fn get_enable_parquet_prewhere(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_parquet_prewhere")? != 0)
     }
+
+    pub fn get_deletion_vector_max_ratio(&self) -> Result<u64> {
+        self.try_get_u64("deletion_vector_max_ratio")
+    }
+
+    pub fn get_mutation_write_concurrency(&self) -> Result<u64> {
+        self.try_get_u64("mutation_write_concurrency")
+    }
+
+    pub fn get_mutation_write_buffer_size(&self) -> Result<u64> {
+        self.try_get_u64("mutation_write_buffer_size")
+    }
+
+    pub fn get_enable_runtime_filter_pruning(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_runtime_filter_pruning")? != 0)
+    }
+
+    pub fn get_enable_async_insert(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_async_insert")? != 0)
+    }
+
+    pub fn get_async_insert_max_data_size(&self) -> Result<u64> {
+        self.try_get_u64("async_insert_max_data_size")
+    }
+
+    pub fn get_async_insert_busy_timeout_ms(&self) -> Result<u64> {
+        self.try_get_u64("async_insert_busy_timeout_ms")
+    }
 }
@@ -96,26 +96,26 @@ impl Settings {
                         continue;
                     }
                     Some(default_setting_value) => {
+                        let value = match &default_setting_value.value {
+                            UserSettingValue::UInt64(_) => {
+                                UserSettingValue::UInt64(val.parse::<u64>()?)
+                            }
+                            UserSettingValue::String(_) => UserSettingValue::String(val.clone()),
+                        };
                         if !default_setting_value
-                            .possible_values
+                            .range
                             .as_ref()
-                            .map(|values| values.iter().any(|v| v.eq_ignore_ascii_case(&val)))
+                            .map(|range| range.is_valid(&value))
                             .unwrap_or(true)
                         {
-                            // the settings may be deprecated
+                            // the range of the settings may be changed
                             warn!("Ignore invalid global setting {} = {}", name, val);
                             continue;
                         }
 
-                        match &default_setting_value.value {
-                            UserSettingValue::UInt64(_) => ChangeValue {
-                                level: ScopeLevel::Global,
-                                value: UserSettingValue::UInt64(val.parse::<u64>()?),
-                            },
-                            UserSettingValue::String(_) => ChangeValue {
-                                level: ScopeLevel::Global,
-                                value: UserSettingValue::String(val.clone()),
-                            },
+                        ChangeValue {
+                            level: ScopeLevel::Global,
+                            value,
                         }
                     }
                 });
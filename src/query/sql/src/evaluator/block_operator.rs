@@ -317,6 +317,17 @@ impl BlockOperator {
                     let expr = func.lambda_expr.as_expr(&BUILTIN_FUNCTIONS);
                     // TODO: Support multi args
                     let input_column = input.get_by_offset(func.arg_indices[0]);
+                    if func.func_name == "array_reduce" {
+                        let col = eval_array_reduce(
+                            input_column,
+                            &expr,
+                            &func.data_type,
+                            input.num_rows(),
+                            func_ctx,
+                        )?;
+                        input.add_column(col);
+                        continue;
+                    }
                     match &input_column.value {
                         Value::Scalar(s) => match s {
                             Scalar::Null => {
@@ -532,3 +543,92 @@ impl Transform for CompoundBlockOperator {
         )
     }
 }
+
+/// Folds each array with the lambda `(acc, x) -> expr` from its first element,
+/// NULL or empty arrays are reduced to NULL.
+///
+/// The arrays are folded together step by step: in the `i`-th step, the lambda
+/// is evaluated once for the `i`-th elements of all the arrays longer than `i`.
+fn eval_array_reduce(
+    input_column: &BlockEntry,
+    expr: &Expr,
+    data_type: &DataType,
+    num_rows: usize,
+    func_ctx: &FunctionContext,
+) -> Result<BlockEntry> {
+    let column = input_column
+        .value
+        .convert_to_full_column(&input_column.data_type, num_rows);
+    let (array_col, validity) = match column {
+        Column::Array(box array_col) => (array_col, None),
+        Column::Nullable(box nullable_col) => match nullable_col.column {
+            Column::Array(box array_col) => (array_col, Some(nullable_col.validity)),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    let values = &array_col.values;
+    let offsets = &array_col.offsets;
+    let elem_ty = values.data_type();
+
+    // The rows still being folded, and the offsets of their next elements.
+    let mut rows = Vec::with_capacity(num_rows);
+    let mut elem_offsets = Vec::with_capacity(num_rows);
+    for row in 0..num_rows {
+        let is_valid = validity.as_ref().map_or(true, |v| v.get_bit(row));
+        if is_valid && offsets[row + 1] > offsets[row] {
+            rows.push(row);
+            elem_offsets.push(offsets[row]);
+        }
+    }
+    let mut string_items_buf = None;
+    let mut acc = values.take(&elem_offsets, &mut string_items_buf);
+
+    let mut results = vec![None; num_rows];
+    loop {
+        let mut next_rows = Vec::with_capacity(rows.len());
+        let mut next_elem_offsets = Vec::with_capacity(rows.len());
+        let mut next_acc_indices = Vec::with_capacity(rows.len());
+        for (i, (row, elem_offset)) in rows.iter().zip(elem_offsets.iter()).enumerate() {
+            if elem_offset + 1 < offsets[row + 1] {
+                next_rows.push(*row);
+                next_elem_offsets.push(elem_offset + 1);
+                next_acc_indices.push(i as u32);
+            } else {
+                results[*row] = Some(acc.index(i).unwrap().to_owned());
+            }
+        }
+        if next_rows.is_empty() {
+            break;
+        }
+
+        let step_rows = next_rows.len();
+        let acc_col = acc.take(&next_acc_indices, &mut string_items_buf);
+        let elem_col = values.take(&next_elem_offsets, &mut string_items_buf);
+        let block = DataBlock::new(
+            vec![
+                BlockEntry::new(elem_ty.clone(), Value::Column(acc_col)),
+                BlockEntry::new(elem_ty.clone(), Value::Column(elem_col)),
+            ],
+            step_rows,
+        );
+        let evaluator = Evaluator::new(&block, func_ctx, &BUILTIN_FUNCTIONS);
+        acc = evaluator
+            .run(expr)?
+            .convert_to_full_column(expr.data_type(), step_rows);
+        rows = next_rows;
+        elem_offsets = next_elem_offsets;
+    }
+
+    let mut builder = ColumnBuilder::with_capacity(data_type, num_rows);
+    for result in results.iter() {
+        match result {
+            Some(scalar) => builder.push(scalar.as_ref()),
+            None => builder.push(ScalarRef::Null),
+        }
+    }
+    Ok(BlockEntry::new(
+        data_type.clone(),
+        Value::Column(builder.build()),
+    ))
+}
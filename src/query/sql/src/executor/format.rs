@@ -45,6 +45,8 @@ use crate::executor::physical_plans::physical_project_set::ProjectSet;
 use crate::executor::physical_plans::physical_range_join::RangeJoin;
 use crate::executor::physical_plans::physical_range_join::RangeJoinType;
 use crate::executor::physical_plans::physical_recluster_sink::ReclusterSink;
+use crate::executor::physical_plans::physical_recursive_cte::RecursiveCte;
+use crate::executor::physical_plans::physical_recursive_cte_scan::RecursiveCteScan;
 use crate::executor::physical_plans::physical_row_fetch::RowFetch;
 use crate::executor::physical_plans::physical_runtime_filter_source::RuntimeFilterSource;
 use crate::executor::physical_plans::physical_sort::Sort;
@@ -217,6 +219,10 @@ fn to_format_tree(
             materialized_cte_to_format_tree(plan, metadata, profs)
         }
         PhysicalPlan::ConstantTableScan(plan) => constant_table_scan_to_format_tree(plan, metadata),
+        PhysicalPlan::RecursiveCte(plan) => recursive_cte_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::RecursiveCteScan(plan) => {
+            recursive_cte_scan_to_format_tree(plan, metadata, profs)
+        }
     }
 }
 
@@ -243,6 +249,10 @@ fn append_profile_info(
             "total wait time: {:.3}ms",
             prof.wait_time.as_secs_f64() * 1000.0
         )));
+        children.push(FormatTreeNode::new(format!(
+            "peak memory usage: {}",
+            prof.memory_usage,
+        )));
     }
 }
 
@@ -1195,6 +1205,56 @@ fn materialized_cte_to_format_tree(
     ))
 }
 
+fn recursive_cte_to_format_tree(
+    plan: &RecursiveCte,
+    metadata: &Metadata,
+    prof_span_set: &SharedProcessorProfiles,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![
+        FormatTreeNode::new(format!("cte: {}", plan.cte_name)),
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("union all: {}", plan.union_all)),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    children.extend(vec![
+        to_format_tree(&plan.anchor, metadata, prof_span_set)?,
+        to_format_tree(&plan.recursive, metadata, prof_span_set)?,
+    ]);
+
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCTE".to_string(),
+        children,
+    ))
+}
+
+fn recursive_cte_scan_to_format_tree(
+    plan: &RecursiveCteScan,
+    metadata: &Metadata,
+    prof_span_set: &SharedProcessorProfiles,
+) -> Result<FormatTreeNode<String>> {
+    let mut children = vec![FormatTreeNode::new(format!(
+        "output columns: [{}]",
+        format_output_columns(plan.output_schema()?, metadata, true)
+    ))];
+
+    append_profile_info(&mut children, prof_span_set, plan.plan_id);
+
+    Ok(FormatTreeNode::with_children(
+        "RecursiveCTEScan".to_string(),
+        children,
+    ))
+}
+
 fn format_output_columns(
     output_schema: DataSchemaRef,
     metadata: &Metadata,
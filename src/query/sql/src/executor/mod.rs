@@ -66,6 +66,8 @@ pub use physical_plans::physical_range_join::RangeJoinType;
 pub use physical_plans::physical_recluster_sink::ReclusterSink;
 pub use physical_plans::physical_recluster_source::ReclusterSource;
 pub use physical_plans::physical_recluster_source::ReclusterTask;
+pub use physical_plans::physical_recursive_cte::RecursiveCte;
+pub use physical_plans::physical_recursive_cte_scan::RecursiveCteScan;
 pub use physical_plans::physical_replace_into::ReplaceInto;
 pub use physical_plans::physical_row_fetch::RowFetch;
 pub use physical_plans::physical_runtime_filter_source::RuntimeFilterSource;
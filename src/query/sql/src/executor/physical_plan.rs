@@ -45,6 +45,8 @@ use crate::executor::physical_plans::physical_project_set::ProjectSet;
 use crate::executor::physical_plans::physical_range_join::RangeJoin;
 use crate::executor::physical_plans::physical_recluster_sink::ReclusterSink;
 use crate::executor::physical_plans::physical_recluster_source::ReclusterSource;
+use crate::executor::physical_plans::physical_recursive_cte::RecursiveCte;
+use crate::executor::physical_plans::physical_recursive_cte_scan::RecursiveCteScan;
 use crate::executor::physical_plans::physical_replace_into::ReplaceInto;
 use crate::executor::physical_plans::physical_row_fetch::RowFetch;
 use crate::executor::physical_plans::physical_runtime_filter_source::RuntimeFilterSource;
@@ -77,6 +79,8 @@ pub enum PhysicalPlan {
     CteScan(CteScan),
     MaterializedCte(MaterializedCte),
     ConstantTableScan(ConstantTableScan),
+    RecursiveCte(RecursiveCte),
+    RecursiveCteScan(RecursiveCteScan),
 
     /// For insert into ... select ... in cluster
     DistributedInsertSelect(Box<DistributedInsertSelect>),
@@ -137,6 +141,8 @@ impl PhysicalPlan {
             PhysicalPlan::CteScan(v) => v.plan_id,
             PhysicalPlan::MaterializedCte(v) => v.plan_id,
             PhysicalPlan::ConstantTableScan(v) => v.plan_id,
+            PhysicalPlan::RecursiveCte(v) => v.plan_id,
+            PhysicalPlan::RecursiveCteScan(v) => v.plan_id,
             PhysicalPlan::DeleteSource(_)
             | PhysicalPlan::MergeInto(_)
             | PhysicalPlan::MergeIntoSource(_)
@@ -179,6 +185,8 @@ impl PhysicalPlan {
             PhysicalPlan::CteScan(plan) => plan.output_schema(),
             PhysicalPlan::MaterializedCte(plan) => plan.output_schema(),
             PhysicalPlan::ConstantTableScan(plan) => plan.output_schema(),
+            PhysicalPlan::RecursiveCte(plan) => plan.output_schema(),
+            PhysicalPlan::RecursiveCteScan(plan) => plan.output_schema(),
             PhysicalPlan::MergeIntoSource(plan) => plan.input.output_schema(),
             PhysicalPlan::AsyncSourcer(_)
             | PhysicalPlan::MergeInto(_)
@@ -228,6 +236,8 @@ impl PhysicalPlan {
             PhysicalPlan::CteScan(_) => "PhysicalCteScan".to_string(),
             PhysicalPlan::MaterializedCte(_) => "PhysicalMaterializedCte".to_string(),
             PhysicalPlan::ConstantTableScan(_) => "PhysicalConstantTableScan".to_string(),
+            PhysicalPlan::RecursiveCte(_) => "PhysicalRecursiveCte".to_string(),
+            PhysicalPlan::RecursiveCteScan(_) => "PhysicalRecursiveCteScan".to_string(),
             PhysicalPlan::ReclusterSource(_) => "ReclusterSource".to_string(),
             PhysicalPlan::ReclusterSink(_) => "ReclusterSink".to_string(),
         }
@@ -238,6 +248,7 @@ impl PhysicalPlan {
             PhysicalPlan::TableScan(_)
             | PhysicalPlan::CteScan(_)
             | PhysicalPlan::ConstantTableScan(_)
+            | PhysicalPlan::RecursiveCteScan(_)
             | PhysicalPlan::ExchangeSource(_)
             | PhysicalPlan::CompactSource(_)
             | PhysicalPlan::DeleteSource(_)
@@ -283,6 +294,10 @@ impl PhysicalPlan {
                 std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
             ),
             PhysicalPlan::ReclusterSink(plan) => Box::new(std::iter::once(plan.input.as_ref())),
+            PhysicalPlan::RecursiveCte(plan) => Box::new(
+                std::iter::once(plan.anchor.as_ref())
+                    .chain(std::iter::once(plan.recursive.as_ref())),
+            ),
         }
     }
 
@@ -322,6 +337,8 @@ impl PhysicalPlan {
             | PhysicalPlan::MergeIntoSource(_)
             | PhysicalPlan::ConstantTableScan(_)
             | PhysicalPlan::CteScan(_)
+            | PhysicalPlan::RecursiveCte(_)
+            | PhysicalPlan::RecursiveCteScan(_)
             | PhysicalPlan::ReclusterSource(_)
             | PhysicalPlan::ReclusterSink(_) => None,
         }
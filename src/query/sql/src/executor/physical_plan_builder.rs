@@ -117,6 +117,12 @@ impl PhysicalPlanBuilder {
             RelOperator::ConstantTableScan(scan) => {
                 self.build_constant_table_scan(scan, required).await
             }
+            RelOperator::RecursiveCte(r_cte) => {
+                self.build_recursive_cte(s_expr, r_cte, stat_info).await
+            }
+            RelOperator::RecursiveCteScan(scan) => {
+                self.build_recursive_cte_scan(scan, required).await
+            }
             _ => Err(ErrorCode::Internal(format!(
                 "Unsupported physical plan: {:?}",
                 s_expr.plan()
@@ -46,6 +46,8 @@ use crate::executor::physical_plans::physical_project_set::ProjectSet;
 use crate::executor::physical_plans::physical_range_join::RangeJoin;
 use crate::executor::physical_plans::physical_recluster_sink::ReclusterSink;
 use crate::executor::physical_plans::physical_recluster_source::ReclusterSource;
+use crate::executor::physical_plans::physical_recursive_cte::RecursiveCte;
+use crate::executor::physical_plans::physical_recursive_cte_scan::RecursiveCteScan;
 use crate::executor::physical_plans::physical_replace_into::ReplaceInto;
 use crate::executor::physical_plans::physical_row_fetch::RowFetch;
 use crate::executor::physical_plans::physical_runtime_filter_source::RuntimeFilterSource;
@@ -105,6 +107,8 @@ impl<'a> Display for PhysicalPlanIndentFormatDisplay<'a> {
             PhysicalPlan::CteScan(cte_scan) => write!(f, "{}", cte_scan)?,
             PhysicalPlan::MaterializedCte(plan) => write!(f, "{}", plan)?,
             PhysicalPlan::ConstantTableScan(scan) => write!(f, "{}", scan)?,
+            PhysicalPlan::RecursiveCte(plan) => write!(f, "{}", plan)?,
+            PhysicalPlan::RecursiveCteScan(scan) => write!(f, "{}", scan)?,
             PhysicalPlan::ReclusterSource(plan) => write!(f, "{}", plan)?,
             PhysicalPlan::ReclusterSink(plan) => write!(f, "{}", plan)?,
         }
@@ -136,6 +140,18 @@ impl Display for MaterializedCte {
     }
 }
 
+impl Display for RecursiveCte {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RecursiveCte: [{}]", self.cte_name)
+    }
+}
+
+impl Display for RecursiveCteScan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RecursiveCteScan: [{}]", self.cte_idx)
+    }
+}
+
 impl Display for ConstantTableScan {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let columns = self
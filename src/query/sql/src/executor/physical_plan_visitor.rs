@@ -44,6 +44,8 @@ use crate::executor::physical_plans::physical_project_set::ProjectSet;
 use crate::executor::physical_plans::physical_range_join::RangeJoin;
 use crate::executor::physical_plans::physical_recluster_sink::ReclusterSink;
 use crate::executor::physical_plans::physical_recluster_source::ReclusterSource;
+use crate::executor::physical_plans::physical_recursive_cte::RecursiveCte;
+use crate::executor::physical_plans::physical_recursive_cte_scan::RecursiveCteScan;
 use crate::executor::physical_plans::physical_replace_into::ReplaceInto;
 use crate::executor::physical_plans::physical_row_fetch::RowFetch;
 use crate::executor::physical_plans::physical_runtime_filter_source::RuntimeFilterSource;
@@ -89,6 +91,8 @@ pub trait PhysicalPlanReplacer {
             PhysicalPlan::MergeIntoSource(plan) => self.replace_merge_into_source(plan),
             PhysicalPlan::MaterializedCte(plan) => self.replace_materialized_cte(plan),
             PhysicalPlan::ConstantTableScan(plan) => self.replace_constant_table_scan(plan),
+            PhysicalPlan::RecursiveCte(plan) => self.replace_recursive_cte(plan),
+            PhysicalPlan::RecursiveCteScan(plan) => self.replace_recursive_cte_scan(plan),
             PhysicalPlan::ReclusterSource(plan) => self.replace_recluster_source(plan),
             PhysicalPlan::ReclusterSink(plan) => self.replace_recluster_sink(plan),
         }
@@ -118,6 +122,10 @@ pub trait PhysicalPlanReplacer {
         Ok(PhysicalPlan::ConstantTableScan(plan.clone()))
     }
 
+    fn replace_recursive_cte_scan(&mut self, plan: &RecursiveCteScan) -> Result<PhysicalPlan> {
+        Ok(PhysicalPlan::RecursiveCteScan(plan.clone()))
+    }
+
     fn replace_filter(&mut self, plan: &Filter) -> Result<PhysicalPlan> {
         let input = self.replace(&plan.input)?;
 
@@ -226,6 +234,7 @@ pub trait PhysicalPlanReplacer {
             probe_to_build: plan.probe_to_build.clone(),
             output_schema: plan.output_schema.clone(),
             contain_runtime_filter: plan.contain_runtime_filter,
+            probe_keys_rt: plan.probe_keys_rt.clone(),
             stat_info: plan.stat_info.clone(),
         }))
     }
@@ -243,6 +252,17 @@ pub trait PhysicalPlanReplacer {
         }))
     }
 
+    fn replace_recursive_cte(&mut self, plan: &RecursiveCte) -> Result<PhysicalPlan> {
+        let anchor = self.replace(&plan.anchor)?;
+        let recursive = self.replace(&plan.recursive)?;
+
+        Ok(PhysicalPlan::RecursiveCte(RecursiveCte {
+            anchor: Box::new(anchor),
+            recursive: Box::new(recursive),
+            ..plan.clone()
+        }))
+    }
+
     fn replace_range_join(&mut self, plan: &RangeJoin) -> Result<PhysicalPlan> {
         let left = self.replace(&plan.left)?;
         let right = self.replace(&plan.right)?;
@@ -489,6 +509,7 @@ impl PhysicalPlan {
                 | PhysicalPlan::AsyncSourcer(_)
                 | PhysicalPlan::CteScan(_)
                 | PhysicalPlan::ConstantTableScan(_)
+                | PhysicalPlan::RecursiveCteScan(_)
                 | PhysicalPlan::ReclusterSource(_)
                 | PhysicalPlan::ExchangeSource(_)
                 | PhysicalPlan::CompactSource(_)
@@ -582,6 +603,10 @@ impl PhysicalPlan {
                     Self::traverse(&plan.left, pre_visit, visit, post_visit);
                     Self::traverse(&plan.right, pre_visit, visit, post_visit);
                 }
+                PhysicalPlan::RecursiveCte(plan) => {
+                    Self::traverse(&plan.anchor, pre_visit, visit, post_visit);
+                    Self::traverse(&plan.recursive, pre_visit, visit, post_visit);
+                }
             }
             post_visit(plan);
         }
@@ -21,6 +21,7 @@ use common_expression::ConstantFolder;
 use common_expression::DataField;
 use common_expression::DataSchemaRef;
 use common_expression::DataSchemaRefExt;
+use common_expression::Expr;
 use common_expression::RemoteExpr;
 use common_functions::BUILTIN_FUNCTIONS;
 
@@ -32,6 +33,8 @@ use crate::optimizer::ColumnSet;
 use crate::optimizer::SExpr;
 use crate::plans::Join;
 use crate::plans::JoinType;
+use crate::BaseTableColumn;
+use crate::ColumnEntry;
 use crate::IndexType;
 use crate::ScalarExpr;
 use crate::TypeCheck;
@@ -62,6 +65,9 @@ pub struct HashJoin {
     pub output_schema: DataSchemaRef,
     // It means that join has a corresponding runtime filter
     pub contain_runtime_filter: bool,
+    // For each probe key, the column of the probe side table scan and the scan's table index,
+    // used to skip the blocks of the scan that can not match the build side keys.
+    pub probe_keys_rt: Vec<Option<(RemoteExpr<String>, IndexType)>>,
 
     // Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
@@ -169,6 +175,11 @@ impl PhysicalPlanBuilder {
         let mut left_join_conditions = Vec::new();
         let mut right_join_conditions = Vec::new();
         let mut probe_to_build_index = Vec::new();
+        let mut probe_keys_rt = Vec::with_capacity(join.left_conditions.len());
+        let enable_runtime_filter_pruning = self
+            .ctx
+            .get_settings()
+            .get_enable_runtime_filter_pruning()?;
         for (left_condition, right_condition) in join
             .left_conditions
             .iter()
@@ -236,6 +247,17 @@ impl PhysicalPlanBuilder {
                 &BUILTIN_FUNCTIONS,
             )?;
 
+            probe_keys_rt.push(if enable_runtime_filter_pruning {
+                self.build_runtime_filter_probe_key(
+                    &join.join_type,
+                    &probe_side,
+                    left_condition,
+                    &common_ty,
+                )
+            } else {
+                None
+            });
+
             let (left_expr, _) =
                 ConstantFolder::fold(&left_expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
             let (right_expr, _) =
@@ -383,7 +405,77 @@ impl PhysicalPlanBuilder {
             probe_to_build,
             output_schema,
             contain_runtime_filter: join.contain_runtime_filter,
+            probe_keys_rt,
             stat_info: Some(stat_info),
         }))
     }
+
+    // Find the column of the probe side table scan that the join key refers to, so that
+    // the blocks of the scan can be skipped by the join keys collected from the build side.
+    fn build_runtime_filter_probe_key(
+        &self,
+        join_type: &JoinType,
+        probe_side: &PhysicalPlan,
+        probe_condition: &ScalarExpr,
+        common_ty: &DataType,
+    ) -> Option<(RemoteExpr<String>, IndexType)> {
+        // Only the joins that drop the probe rows without a match.
+        if !matches!(
+            join_type,
+            JoinType::Inner | JoinType::LeftSemi | JoinType::Right | JoinType::RightSemi
+        ) {
+            return None;
+        }
+        let column_ref = match probe_condition {
+            ScalarExpr::BoundColumnRef(column_ref) => column_ref,
+            _ => return None,
+        };
+        let metadata = self.metadata.read();
+        let (table_index, column_name, data_type) = match metadata.column(column_ref.column.index) {
+            ColumnEntry::BaseTableColumn(BaseTableColumn {
+                table_index,
+                column_name,
+                data_type,
+                path_indices: None,
+                virtual_computed_expr: None,
+                ..
+            }) => (*table_index, column_name, DataType::from(data_type)),
+            _ => return None,
+        };
+        // The values of the build keys are used as constants of the column directly.
+        if data_type.remove_nullable() != common_ty.remove_nullable()
+            || !matches!(
+                data_type.remove_nullable(),
+                DataType::Number(_)
+                    | DataType::Decimal(_)
+                    | DataType::String
+                    | DataType::Date
+                    | DataType::Timestamp
+            )
+            || !can_prune_scan(probe_side, table_index)
+        {
+            return None;
+        }
+        let column = Expr::ColumnRef {
+            span: None,
+            id: column_name.clone(),
+            data_type,
+            display_name: column_name.clone(),
+        };
+        Some((column.as_remote_expr(), table_index))
+    }
+}
+
+// Whether the rows of the scan reach `plan` only filtered, so that skipping
+// some blocks of the scan does not change the rows other than those filtered out.
+fn can_prune_scan(plan: &PhysicalPlan, table_index: IndexType) -> bool {
+    match plan {
+        PhysicalPlan::TableScan(scan) => scan.table_index == table_index,
+        PhysicalPlan::Filter(filter) => can_prune_scan(&filter.input, table_index),
+        PhysicalPlan::EvalScalar(eval_scalar) => can_prune_scan(&eval_scalar.input, table_index),
+        PhysicalPlan::HashJoin(join) if join.join_type == JoinType::Inner => {
+            can_prune_scan(&join.probe, table_index) || can_prune_scan(&join.build, table_index)
+        }
+        _ => false,
+    }
 }
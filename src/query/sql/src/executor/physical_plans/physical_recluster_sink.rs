@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_meta_app::schema::CatalogInfo;
 use common_meta_app::schema::TableInfo;
 use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::ReclusterProgress;
 use storages_common_table_meta::meta::Statistics;
 use storages_common_table_meta::meta::TableSnapshot;
 
@@ -33,4 +34,5 @@ pub struct ReclusterSink {
     pub remained_blocks: Vec<Arc<BlockMeta>>,
     pub removed_segment_indexes: Vec<usize>,
     pub removed_segment_summary: Statistics,
+    pub recluster_progress: ReclusterProgress,
 }
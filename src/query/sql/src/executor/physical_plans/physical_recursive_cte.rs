@@ -0,0 +1,120 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use common_expression::DataField;
+use common_expression::DataSchemaRef;
+use common_expression::DataSchemaRefExt;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::physical_union_all::cast_plan;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::SExpr;
+use crate::ColumnSet;
+use crate::IndexType;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecursiveCte {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    pub cte_idx: IndexType,
+    pub cte_name: String,
+    // The anchor term, executed only once.
+    pub anchor: Box<PhysicalPlan>,
+    // The recursive term, executed once per iteration against the working table.
+    pub recursive: Box<PhysicalPlan>,
+    // Pairs of the columns of the anchor term and the recursive term,
+    // in the order of the columns of the working table.
+    pub pairs: Vec<(String, String)>,
+    pub union_all: bool,
+    pub schema: DataSchemaRef,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl RecursiveCte {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        Ok(self.schema.clone())
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub(crate) async fn build_recursive_cte(
+        &mut self,
+        s_expr: &SExpr,
+        r_cte: &crate::plans::RecursiveCte,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        // 1. Prune unused Columns.
+        // All the columns of the working table are needed by the next iteration,
+        // so none of the paired columns can be pruned.
+        let left_required = r_cte.pairs.iter().map(|(l, _)| *l).collect::<ColumnSet>();
+        let right_required = r_cte.pairs.iter().map(|(_, r)| *r).collect::<ColumnSet>();
+
+        // 2. Build physical plan.
+        let anchor = self.build(s_expr.child(0)?, left_required).await?;
+        let recursive = self.build(s_expr.child(1)?, right_required).await?;
+        let anchor_schema = anchor.output_schema()?;
+        let recursive_schema = recursive.output_schema()?;
+
+        // The columns of the working table take the types of the anchor term, the binder
+        // has casted the recursive term to them already, cast again in case of any mismatch.
+        let left_indexes = r_cte.pairs.iter().map(|(l, _)| *l).collect::<Vec<_>>();
+        let right_indexes = r_cte.pairs.iter().map(|(_, r)| *r).collect::<Vec<_>>();
+        let column_types = left_indexes
+            .iter()
+            .map(|index| {
+                Ok(anchor_schema
+                    .field_with_name(&index.to_string())?
+                    .data_type()
+                    .clone())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let recursive = cast_plan(
+            self,
+            recursive,
+            recursive_schema.as_ref(),
+            &right_indexes,
+            &column_types,
+            stat_info.clone(),
+        )
+        .await?;
+
+        let pairs = r_cte
+            .pairs
+            .iter()
+            .map(|(l, r)| (l.to_string(), r.to_string()))
+            .collect::<Vec<_>>();
+        let fields = left_indexes
+            .iter()
+            .zip(&column_types)
+            .map(|(index, ty)| DataField::new(&index.to_string(), ty.clone()))
+            .collect::<Vec<_>>();
+
+        Ok(PhysicalPlan::RecursiveCte(RecursiveCte {
+            plan_id: self.next_plan_id(),
+            cte_idx: r_cte.cte_idx,
+            cte_name: r_cte.cte_name.clone(),
+            anchor: Box::new(anchor),
+            recursive: Box::new(recursive),
+            pairs,
+            union_all: r_cte.union_all,
+            schema: DataSchemaRefExt::create(fields),
+
+            stat_info: Some(stat_info),
+        }))
+    }
+}
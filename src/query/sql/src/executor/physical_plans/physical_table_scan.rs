@@ -497,6 +497,7 @@ impl PhysicalPlanBuilder {
             virtual_columns,
             lazy_materialization: !metadata.lazy_columns().is_empty(),
             agg_index: None,
+            runtime_filter_scan_id: Some(scan.table_index),
         })
     }
 
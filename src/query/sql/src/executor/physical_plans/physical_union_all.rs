@@ -98,55 +98,6 @@ impl PhysicalPlanBuilder {
             })
         }).collect::<Result<Vec<_>>>()?;
 
-        async fn cast_plan(
-            plan_builder: &mut PhysicalPlanBuilder,
-            plan: PhysicalPlan,
-            plan_schema: &DataSchema,
-            indexes: &[IndexType],
-            common_types: &[DataType],
-            stat_info: PlanStatsInfo,
-        ) -> Result<PhysicalPlan> {
-            debug_assert!(indexes.len() == common_types.len());
-            let scalar_items = indexes
-                .iter()
-                .map(|index| plan_schema.field_with_name(&index.to_string()).unwrap())
-                .zip(common_types)
-                .filter(|(f, common_ty)| f.data_type() != *common_ty)
-                .map(|(f, common_ty)| {
-                    let column = ColumnBindingBuilder::new(
-                        f.name().clone(),
-                        f.name().parse().unwrap(),
-                        Box::new(f.data_type().clone()),
-                        Visibility::Visible,
-                    )
-                    .build();
-                    let cast_expr = wrap_cast(
-                        &ScalarExpr::BoundColumnRef(BoundColumnRef { span: None, column }),
-                        common_ty,
-                    );
-                    ScalarItem {
-                        scalar: cast_expr,
-                        index: f.name().parse().unwrap(),
-                    }
-                })
-                .collect::<Vec<_>>();
-
-            let new_plan = if scalar_items.is_empty() {
-                plan
-            } else {
-                plan_builder.crate_eval_scalar(
-                    &crate::plans::EvalScalar {
-                        items: scalar_items,
-                    },
-                    indexes.to_vec(),
-                    plan,
-                    stat_info,
-                )?
-            };
-
-            Ok(new_plan)
-        }
-
         let left_indexes = union_all.pairs.iter().map(|(l, _)| *l).collect::<Vec<_>>();
         let right_indexes = union_all.pairs.iter().map(|(_, r)| *r).collect::<Vec<_>>();
         let left_plan = cast_plan(
@@ -190,3 +141,52 @@ impl PhysicalPlanBuilder {
         }))
     }
 }
+
+pub(crate) async fn cast_plan(
+    plan_builder: &mut PhysicalPlanBuilder,
+    plan: PhysicalPlan,
+    plan_schema: &DataSchema,
+    indexes: &[IndexType],
+    common_types: &[DataType],
+    stat_info: PlanStatsInfo,
+) -> Result<PhysicalPlan> {
+    debug_assert!(indexes.len() == common_types.len());
+    let scalar_items = indexes
+        .iter()
+        .map(|index| plan_schema.field_with_name(&index.to_string()).unwrap())
+        .zip(common_types)
+        .filter(|(f, common_ty)| f.data_type() != *common_ty)
+        .map(|(f, common_ty)| {
+            let column = ColumnBindingBuilder::new(
+                f.name().clone(),
+                f.name().parse().unwrap(),
+                Box::new(f.data_type().clone()),
+                Visibility::Visible,
+            )
+            .build();
+            let cast_expr = wrap_cast(
+                &ScalarExpr::BoundColumnRef(BoundColumnRef { span: None, column }),
+                common_ty,
+            );
+            ScalarItem {
+                scalar: cast_expr,
+                index: f.name().parse().unwrap(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let new_plan = if scalar_items.is_empty() {
+        plan
+    } else {
+        plan_builder.crate_eval_scalar(
+            &crate::plans::EvalScalar {
+                items: scalar_items,
+            },
+            indexes.to_vec(),
+            plan,
+            stat_info,
+        )?
+    };
+
+    Ok(new_plan)
+}
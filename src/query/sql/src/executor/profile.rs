@@ -504,6 +504,30 @@ fn flatten_plan_node_profile(
             };
             plan_node_profs.push(prof);
         }
+        PhysicalPlan::RecursiveCte(r_cte) => {
+            flatten_plan_node_profile(metadata, &r_cte.anchor, profs, plan_node_profs)?;
+            flatten_plan_node_profile(metadata, &r_cte.recursive, profs, plan_node_profs)?;
+            let proc_prof = profs.get(&r_cte.plan_id).copied().unwrap_or_default();
+            let prof = OperatorProfile {
+                id: r_cte.plan_id,
+                operator_type: OperatorType::RecursiveCte,
+                execution_info: proc_prof.into(),
+                children: vec![r_cte.anchor.get_id(), r_cte.recursive.get_id()],
+                attribute: OperatorAttribute::Empty,
+            };
+            plan_node_profs.push(prof);
+        }
+        PhysicalPlan::RecursiveCteScan(scan) => {
+            let proc_prof = profs.get(&scan.plan_id).copied().unwrap_or_default();
+            let prof = OperatorProfile {
+                id: scan.plan_id,
+                operator_type: OperatorType::RecursiveCteScan,
+                children: vec![],
+                execution_info: proc_prof.into(),
+                attribute: OperatorAttribute::Empty,
+            };
+            plan_node_profs.push(prof);
+        }
         PhysicalPlan::MaterializedCte(_) => todo!(),
         PhysicalPlan::DeleteSource(_)
         | PhysicalPlan::CommitSink(_)
@@ -35,6 +35,8 @@ use common_expression::Scalar;
 use common_expression::TableField;
 use common_license::license::Feature::DataMask;
 use common_license::license_manager::get_license_manager;
+use common_meta_app::principal::GrantObject;
+use common_meta_app::principal::UserPrivilegeType;
 use common_settings::Settings;
 use common_users::UserApiProvider;
 use data_mask_feature::get_datamask_handler;
@@ -180,7 +182,7 @@ impl ToReadDataSourcePlan for dyn Table {
                 let ret = license_manager
                     .manager
                     .check_enterprise_enabled(ctx.get_license_key(), DataMask);
-                if ret.is_err() {
+                if ret.is_err() || has_unmask_privilege(ctx.as_ref()).await? {
                     None
                 } else {
                     let mut mask_policy_map = BTreeMap::new();
@@ -282,3 +284,22 @@ impl ToReadDataSourcePlan for dyn Table {
         })
     }
 }
+
+/// Users holding the global UNMASK privilege, directly or through any of their available
+/// roles, read the original column values instead of the masked ones.
+async fn has_unmask_privilege(ctx: &dyn TableContext) -> Result<bool> {
+    let object = GrantObject::Global;
+    let privileges = vec![UserPrivilegeType::Unmask];
+    if ctx
+        .get_current_user()?
+        .grants
+        .verify_privilege(&object, privileges.clone())
+    {
+        return Ok(true);
+    }
+    Ok(ctx
+        .get_available_roles()
+        .await?
+        .iter()
+        .any(|role| role.grants.verify_privilege(&object, privileges.clone())))
+}
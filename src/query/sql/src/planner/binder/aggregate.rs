@@ -50,7 +50,7 @@ use crate::plans::LambdaFunc;
 use crate::plans::NthValueFunction;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
-use crate::plans::UDFServerCall;
+use crate::plans::UDFCall;
 use crate::plans::WindowFunc;
 use crate::plans::WindowFuncType;
 use crate::plans::WindowOrderBy;
@@ -277,16 +277,16 @@ impl<'a> AggregateRewriter<'a> {
                 }
                 .into())
             }
-            ScalarExpr::UDFServerCall(udf) => {
+            ScalarExpr::UDFCall(udf) => {
                 let new_args = udf
                     .arguments
                     .iter()
                     .map(|arg| self.visit(arg))
                     .collect::<Result<Vec<_>>>()?;
-                Ok(UDFServerCall {
+                Ok(UDFCall {
                     span: udf.span,
                     func_name: udf.func_name.clone(),
-                    server_addr: udf.server_addr.clone(),
+                    udf_type: udf.udf_type.clone(),
                     arg_types: udf.arg_types.clone(),
                     return_type: udf.return_type.clone(),
                     arguments: new_args,
@@ -534,25 +534,53 @@ impl Binder {
                 self.resolve_grouping_sets(bind_context, select_list, sets, &available_aliases)
                     .await
             }
-            // TODO: avoid too many clones.
+            GroupBy::Rollup(_) | GroupBy::Cube(_) | GroupBy::Combined(_) => {
+                let sets = Self::expand_grouping_sets(group_by)?;
+                self.resolve_grouping_sets(bind_context, select_list, &sets, &available_aliases)
+                    .await
+            }
+        }
+    }
+
+    // TODO: avoid too many clones.
+    fn expand_grouping_sets(group_by: &GroupBy) -> Result<Vec<Vec<Expr>>> {
+        let sets = match group_by {
+            GroupBy::Normal(exprs) => vec![exprs.clone()],
+            GroupBy::GroupingSets(sets) => sets.clone(),
             GroupBy::Rollup(exprs) => {
                 // ROLLUP (a,b,c) => GROUPING SETS ((a,b,c), (a,b), (a), ())
                 let mut sets = Vec::with_capacity(exprs.len() + 1);
                 for i in (0..=exprs.len()).rev() {
                     sets.push(exprs[0..i].to_vec());
                 }
-                self.resolve_grouping_sets(bind_context, select_list, &sets, &available_aliases)
-                    .await
+                sets
             }
             GroupBy::Cube(exprs) => {
                 // CUBE (a,b) => GROUPING SETS ((a,b),(a),(b),()) // All subsets
-                let sets = (0..=exprs.len())
+                (0..=exprs.len())
                     .flat_map(|count| exprs.clone().into_iter().combinations(count))
-                    .collect::<Vec<_>>();
-                self.resolve_grouping_sets(bind_context, select_list, &sets, &available_aliases)
-                    .await
+                    .collect::<Vec<_>>()
             }
-        }
+            GroupBy::Combined(items) => {
+                // a, ROLLUP (b) => GROUPING SETS ((a,b), (a)) // Cross product of the items
+                let mut sets = vec![vec![]];
+                for item in items.iter() {
+                    let item_sets = Self::expand_grouping_sets(item)?;
+                    sets = sets
+                        .iter()
+                        .cartesian_product(item_sets.iter())
+                        .map(|(set, item_set)| set.iter().chain(item_set.iter()).cloned().collect())
+                        .collect();
+                }
+                sets
+            }
+            GroupBy::All => {
+                return Err(ErrorCode::SemanticError(
+                    "GROUP BY ALL can not be combined with other group items",
+                ));
+            }
+        };
+        Ok(sets)
     }
 
     #[async_backtrace::framed]
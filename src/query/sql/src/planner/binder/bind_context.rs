@@ -25,6 +25,7 @@ use common_catalog::plan::InternalColumn;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_exception::Span;
+use common_expression::types::DataType;
 use common_expression::ColumnId;
 use common_expression::DataField;
 use common_expression::DataSchemaRef;
@@ -160,6 +161,21 @@ pub struct CteInfo {
     pub stat_info: Option<Arc<StatInfo>>,
     // If cte is materialized, save it's columns
     pub columns: Vec<ColumnBinding>,
+    // If cte is declared by `WITH RECURSIVE`
+    pub recursive: bool,
+    // Only set while binding the recursive term of a recursive cte,
+    // the self references of the cte are bound to the working table.
+    pub working_table: Option<CteWorkingTable>,
+}
+
+/// The working table of a recursive cte, which holds the rows
+/// produced by the previous iteration.
+#[derive(Clone, Debug)]
+pub struct CteWorkingTable {
+    pub cte_idx: IndexType,
+    // Names and types of the columns
+    pub columns: Vec<(String, DataType)>,
+    pub stat_info: Arc<StatInfo>,
 }
 
 impl BindContext {
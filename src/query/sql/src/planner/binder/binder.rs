@@ -252,7 +252,7 @@ impl<'a> Binder {
             Statement::ShowMetrics => {
                 self.bind_rewrite_to_query(
                     bind_context,
-                    "SELECT metric, kind, labels, value FROM system.metrics",
+                    "SELECT metric, kind, labels, le, quantile, value FROM system.metrics",
                     RewriteKind::ShowMetrics,
                 )
                     .await?
@@ -308,6 +308,7 @@ impl<'a> Binder {
             Statement::AlterTable(stmt) => self.bind_alter_table(bind_context, stmt).await?,
             Statement::RenameTable(stmt) => self.bind_rename_table(stmt).await?,
             Statement::TruncateTable(stmt) => self.bind_truncate_table(stmt).await?,
+            Statement::DropTableCache(stmt) => self.bind_drop_table_cache(stmt).await?,
             Statement::OptimizeTable(stmt) => self.bind_optimize_table(bind_context, stmt).await?,
             Statement::VacuumTable(stmt) => self.bind_vacuum_table(bind_context, stmt).await?,
             Statement::VacuumDropTable(stmt) => self.bind_vacuum_drop_table(bind_context, stmt).await?,
@@ -318,11 +319,23 @@ impl<'a> Binder {
             Statement::CreateView(stmt) => self.bind_create_view(stmt).await?,
             Statement::AlterView(stmt) => self.bind_alter_view(stmt).await?,
             Statement::DropView(stmt) => self.bind_drop_view(stmt).await?,
+            Statement::CreateMaterializedView(stmt) => {
+                self.bind_create_materialized_view(stmt).await?
+            }
+            Statement::RefreshMaterializedView(stmt) => {
+                self.bind_refresh_materialized_view(stmt).await?
+            }
+
+            // Streams
+            Statement::CreateStream(stmt) => self.bind_create_stream(stmt).await?,
+            Statement::DropStream(stmt) => self.bind_drop_stream(stmt).await?,
 
             // Indexes
             Statement::CreateIndex(stmt) => self.bind_create_index(bind_context, stmt).await?,
             Statement::DropIndex(stmt) => self.bind_drop_index(stmt).await?,
             Statement::RefreshIndex(stmt) => self.bind_refresh_index(bind_context, stmt).await?,
+            Statement::CreateBloomIndex(stmt) => self.bind_create_bloom_index(stmt).await?,
+            Statement::DropBloomIndex(stmt) => self.bind_drop_bloom_index(stmt).await?,
 
             // Virtual Columns
             Statement::CreateVirtualColumn(stmt) => self.bind_create_virtual_column(stmt).await?,
@@ -386,6 +399,14 @@ impl<'a> Binder {
                 }
                 self.bind_insert(bind_context, stmt).await?
             }
+            Statement::InsertMultiTable(stmt) => {
+                if let Some(hints) = &stmt.hints {
+                    if let Some(e) = self.opt_hints_set_var(bind_context, hints).await.err() {
+                        warn!("In INSERT resolve optimize hints {:?} failed, err: {:?}", hints, e);
+                    }
+                }
+                self.bind_insert_multi_table(bind_context, stmt).await?
+            }
             Statement::Replace(stmt) => {
                 if let Some(hints) = &stmt.hints {
                     if let Some(e) = self.opt_hints_set_var(bind_context, hints).await.err() {
@@ -478,6 +499,11 @@ impl<'a> Binder {
                     .await?
             }
 
+            Statement::SetUserVariable { variable, value } => {
+                self.bind_set_user_variable(bind_context, variable, value)
+                    .await?
+            }
+
             Statement::SetRole {
                 is_default,
                 role_name,
@@ -536,6 +562,17 @@ impl<'a> Binder {
             Statement::DescDatamaskPolicy(stmt) => {
                 self.bind_desc_data_mask_policy(stmt).await?
             }
+            Statement::CreateRowAccessPolicy(stmt) => {
+                self.bind_create_row_access_policy(stmt).await?
+            }
+            Statement::DropRowAccessPolicy(stmt) => {
+                self.bind_drop_row_access_policy(stmt).await?
+            }
+            Statement::DescRowAccessPolicy(stmt) => {
+                self.bind_desc_row_access_policy(stmt).await?
+            }
+            Statement::CreateSequence(stmt) => self.bind_create_sequence(stmt).await?,
+            Statement::DropSequence(stmt) => self.bind_drop_sequence(stmt).await?,
             Statement::CreateNetworkPolicy(stmt) => {
                 self.bind_create_network_policy(stmt).await?
             }
@@ -569,6 +606,10 @@ impl<'a> Binder {
             Statement::ShowTasks(stmt) => {
                 self.bind_show_tasks(stmt).await?
             }
+            Statement::Begin => Plan::Begin,
+            Statement::Commit => Plan::Commit,
+            Statement::Abort => Plan::Abort,
+            Statement::FlushQueryCache => Plan::FlushQueryCache,
         };
         Ok(plan)
     }
@@ -55,6 +55,7 @@ use indexmap::IndexMap;
 use log::debug;
 use parking_lot::RwLock;
 
+use crate::binder::location::parse_kafka_location;
 use crate::binder::location::parse_uri_location;
 use crate::binder::select::MaxColumnPosition;
 use crate::binder::Binder;
@@ -79,6 +80,9 @@ impl<'a> Binder {
                 let plan = self
                     .bind_copy_into_table_common(bind_context, stmt, location)
                     .await?;
+                if plan.kafka_info.is_some() {
+                    return Ok(Plan::CopyIntoTable(Box::new(plan)));
+                }
                 self.bind_copy_into_table_from_location(bind_context, plan)
                     .await
             }
@@ -92,6 +96,11 @@ impl<'a> Binder {
                 let plan = self
                     .bind_copy_into_table_common(bind_context, stmt, location)
                     .await?;
+                if plan.kafka_info.is_some() {
+                    return Err(ErrorCode::Unimplemented(
+                        "copy from kafka with transformation is not supported",
+                    ));
+                }
                 self.bind_copy_from_query_into_table(bind_context, plan, select_list, alias)
                     .await
             }
@@ -118,7 +127,16 @@ impl<'a> Binder {
         let validation_mode = ValidationMode::from_str(stmt.validation_mode.as_str())
             .map_err(ErrorCode::SyntaxException)?;
 
-        let (mut stage_info, path) = resolve_file_location(&self.ctx, location).await?;
+        let mut kafka_info = None;
+        let (mut stage_info, path) = match location {
+            FileLocation::Uri(uri) if uri.protocol.eq_ignore_ascii_case("kafka") => {
+                let mut uri = uri.clone();
+                let default_group_id = format!("databend_copy_{}", table.get_id());
+                kafka_info = Some(parse_kafka_location(&mut uri, default_group_id)?);
+                (StageInfo::default(), "/".to_string())
+            }
+            _ => resolve_file_location(&self.ctx, location).await?,
+        };
         self.apply_copy_into_table_options(stmt, &mut stage_info)
             .await?;
         let files_info = StageFilesInfo {
@@ -160,6 +178,7 @@ impl<'a> Binder {
             required_values_schema: required_values_schema.clone(),
             write_mode: CopyIntoTableMode::Copy,
             query: None,
+            kafka_info,
 
             enable_distributed: false,
         })
@@ -290,6 +309,7 @@ impl<'a> Binder {
             },
             write_mode,
             query: None,
+            kafka_info: None,
             validation_mode: ValidationMode::None,
 
             enable_distributed: false,
@@ -14,7 +14,9 @@
 
 use std::sync::Arc;
 
+use common_ast::ast::CreateBloomIndexStmt;
 use common_ast::ast::CreateIndexStmt;
+use common_ast::ast::DropBloomIndexStmt;
 use common_ast::ast::DropIndexStmt;
 use common_ast::ast::ExplainKind;
 use common_ast::ast::Identifier;
@@ -41,13 +43,16 @@ use crate::binder::Binder;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerConfig;
 use crate::optimizer::OptimizerContext;
+use crate::plans::CreateBloomIndexPlan;
 use crate::plans::CreateIndexPlan;
+use crate::plans::DropBloomIndexPlan;
 use crate::plans::DropIndexPlan;
 use crate::plans::Plan;
 use crate::plans::RefreshIndexPlan;
 use crate::AggregatingIndexChecker;
 use crate::AggregatingIndexRewriter;
 use crate::BindContext;
+use crate::BloomIndexPath;
 use crate::MetadataRef;
 use crate::SUPPORTED_AGGREGATING_INDEX_FUNCTIONS;
 
@@ -223,6 +228,66 @@ impl Binder {
         Ok(Plan::DropIndex(Box::new(plan)))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_bloom_index(
+        &mut self,
+        stmt: &CreateBloomIndexStmt,
+    ) -> Result<Plan> {
+        let CreateBloomIndexStmt {
+            if_not_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+            paths,
+        } = stmt;
+
+        let index_name = self.normalize_object_identifier(index_name);
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let paths = paths
+            .iter()
+            .map(|path| BloomIndexPath::try_from_expr(path, &self.name_resolution_ctx))
+            .collect::<Result<Vec<_>>>()?;
+
+        let plan = CreateBloomIndexPlan {
+            if_not_exists: *if_not_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+            paths,
+        };
+        Ok(Plan::CreateBloomIndex(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_bloom_index(
+        &mut self,
+        stmt: &DropBloomIndexStmt,
+    ) -> Result<Plan> {
+        let DropBloomIndexStmt {
+            if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let index_name = self.normalize_object_identifier(index_name);
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        let plan = DropBloomIndexPlan {
+            if_exists: *if_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+        };
+        Ok(Plan::DropBloomIndex(Box::new(plan)))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_refresh_index(
         &mut self,
@@ -20,8 +20,11 @@ mod database;
 mod index;
 mod network_policy;
 mod role;
+mod row_access_policy;
+mod sequence;
 mod share;
 mod stage;
+mod stream;
 mod table;
 mod task;
 mod view;
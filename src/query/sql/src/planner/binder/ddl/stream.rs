@@ -0,0 +1,208 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_ast::ast::ColumnID;
+use common_ast::ast::CreateStreamStmt;
+use common_ast::ast::DropStreamStmt;
+use common_ast::ast::Expr;
+use common_ast::ast::SelectTarget;
+use common_ast::ast::SetExpr;
+use common_ast::ast::Statement;
+use common_ast::ast::TableReference;
+use common_ast::parser::parse_sql;
+use common_ast::parser::tokenize_sql;
+use common_ast::Dialect;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_storages_view::view_table::QUERY;
+use common_storages_view::view_table::VIEW_ENGINE;
+
+use crate::binder::Binder;
+use crate::plans::CreateStreamPlan;
+use crate::plans::DropStreamPlan;
+use crate::plans::Plan;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_stream(
+        &mut self,
+        stmt: &CreateStreamStmt,
+    ) -> Result<Plan> {
+        let CreateStreamStmt {
+            if_not_exists,
+            catalog,
+            database,
+            stream,
+            table_database,
+            table,
+            append_only,
+            comment,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, stream_name) =
+            self.normalize_object_identifier_triple(catalog, database, stream);
+        // The source table is in the same catalog as the stream.
+        let table_database = table_database
+            .as_ref()
+            .map(|ident| self.normalize_object_identifier(ident))
+            .unwrap_or_else(|| self.ctx.get_current_database());
+        let table_name = self.normalize_object_identifier(table);
+
+        // A stream on a view tracks the changes of the table projected by the view.
+        let source = self
+            .ctx
+            .get_table(&catalog, &table_database, &table_name)
+            .await?;
+        let (table_database, table_name, columns) = if source.engine() == VIEW_ENGINE {
+            let query = source
+                .get_table_info()
+                .options()
+                .get(QUERY)
+                .ok_or_else(|| ErrorCode::Internal("Invalid VIEW object"))?;
+            self.resolve_projection_view(&catalog, query)?
+                .ok_or_else(|| {
+                    ErrorCode::Unimplemented(format!(
+                        "CREATE STREAM on view {}.{} is only supported if the view is a projection of a table",
+                        table_database, table_name
+                    ))
+                })?
+        } else {
+            (table_database, table_name, None)
+        };
+
+        let plan = CreateStreamPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            catalog,
+            database,
+            stream_name,
+            table_database,
+            table_name,
+            columns,
+            append_only: *append_only,
+            comment: comment.clone(),
+        };
+        Ok(Plan::CreateStream(plan.into()))
+    }
+
+    /// Resolves the table and the columns of a view query like `SELECT a, b FROM t`,
+    /// the columns are `None` for `SELECT * FROM t`.
+    ///
+    /// Returns `None` if the query is not a projection of a table, e.g. it has filters,
+    /// aggregations, joins or renamed columns.
+    fn resolve_projection_view(
+        &self,
+        catalog: &str,
+        query: &str,
+    ) -> Result<Option<(String, String, Option<Vec<String>>)>> {
+        let tokens = tokenize_sql(query)?;
+        let (stmt, _) = parse_sql(&tokens, Dialect::PostgreSQL)?;
+        let select = match &stmt {
+            Statement::Query(query)
+                if query.with.is_none()
+                    && query.order_by.is_empty()
+                    && query.limit.is_empty()
+                    && query.offset.is_none() =>
+            {
+                match &query.body {
+                    SetExpr::Select(select) => select,
+                    _ => return Ok(None),
+                }
+            }
+            _ => return Ok(None),
+        };
+        if select.distinct
+            || select.selection.is_some()
+            || select.group_by.is_some()
+            || select.having.is_some()
+            || select.window_list.is_some()
+            || select.from.len() != 1
+        {
+            return Ok(None);
+        }
+
+        let (database, table) = match &select.from[0] {
+            TableReference::Table {
+                catalog: table_catalog,
+                database,
+                table,
+                travel_point: None,
+                pivot: None,
+                unpivot: None,
+                ..
+            } => {
+                if let Some(table_catalog) = table_catalog {
+                    if self.normalize_object_identifier(table_catalog) != catalog {
+                        return Ok(None);
+                    }
+                }
+                // Resolved in the same way as querying the view.
+                let database = database
+                    .as_ref()
+                    .map(|ident| self.normalize_object_identifier(ident))
+                    .unwrap_or_else(|| self.ctx.get_current_database());
+                (database, self.normalize_object_identifier(table))
+            }
+            _ => return Ok(None),
+        };
+
+        if let [target @ SelectTarget::QualifiedName { exclude: None, .. }] =
+            select.select_list.as_slice()
+        {
+            if target.is_star() {
+                return Ok(Some((database, table, None)));
+            }
+        }
+        let mut columns = Vec::with_capacity(select.select_list.len());
+        for target in &select.select_list {
+            match target {
+                SelectTarget::AliasedExpr { expr, alias: None } => match expr.as_ref() {
+                    Expr::ColumnRef {
+                        column: ColumnID::Name(column),
+                        ..
+                    } => columns.push(self.normalize_object_identifier(column)),
+                    _ => return Ok(None),
+                },
+                _ => return Ok(None),
+            }
+        }
+        Ok(Some((database, table, Some(columns))))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_stream(
+        &mut self,
+        stmt: &DropStreamStmt,
+    ) -> Result<Plan> {
+        let DropStreamStmt {
+            if_exists,
+            catalog,
+            database,
+            stream,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, stream_name) =
+            self.normalize_object_identifier_triple(catalog, database, stream);
+        let plan = DropStreamPlan {
+            if_exists: *if_exists,
+            tenant,
+            catalog,
+            database,
+            stream_name,
+        };
+        Ok(Plan::DropStream(plan.into()))
+    }
+}
@@ -27,6 +27,7 @@ use common_ast::ast::CompactTarget;
 use common_ast::ast::CreateTableSource;
 use common_ast::ast::CreateTableStmt;
 use common_ast::ast::DescribeTableStmt;
+use common_ast::ast::DropTableCacheStmt;
 use common_ast::ast::DropTableStmt;
 use common_ast::ast::Engine;
 use common_ast::ast::ExistsTableStmt;
@@ -70,6 +71,7 @@ use common_expression::TableSchemaRefExt;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::storage::StorageParams;
 use common_storage::DataOperator;
+use common_storages_delta::DeltaTable;
 use common_storages_view::view_table::QUERY;
 use common_storages_view::view_table::VIEW_ENGINE;
 use log::debug;
@@ -96,13 +98,16 @@ use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CreateTablePlan;
 use crate::plans::DescribeTablePlan;
+use crate::plans::DropTableCachePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::ModifyColumnAction as ModifyColumnActionInPlan;
 use crate::plans::ModifyTableColumnPlan;
@@ -439,7 +444,22 @@ impl Binder {
         }
 
         // Build table schema
+        let mut engine_options = BTreeMap::new();
         let (schema, field_comments) = match (&source, &as_query) {
+            (None, None) if engine == Engine::Delta => {
+                // The schema of Delta table is loaded from its transaction log.
+                let sp = storage_params.as_ref().ok_or_else(|| {
+                    ErrorCode::BadArguments("Location is required for the Delta table")
+                })?;
+                let (schema, options) = DeltaTable::load_meta(sp).await?;
+                engine_options = options;
+                (Arc::new(schema), vec![])
+            }
+            (_, _) if engine == Engine::Delta => {
+                return Err(ErrorCode::BadArguments(
+                    "Delta table does not support column definitions or AS SELECT",
+                ));
+            }
             (Some(source), None) => {
                 // `CREATE TABLE` without `AS SELECT ...`
                 self.analyze_create_table_schema(source).await?
@@ -576,6 +596,7 @@ impl Binder {
             table,
             schema: schema.clone(),
             engine,
+            engine_options,
             storage_params,
             read_only_attach: false,
             part_prefix,
@@ -655,6 +676,7 @@ impl Binder {
             table,
             schema: Arc::new(TableSchema::default()),
             engine: Engine::Fuse,
+            engine_options: BTreeMap::new(),
             storage_params: Some(sp),
             read_only_attach: stmt.read_only,
             part_prefix,
@@ -929,6 +951,38 @@ impl Binder {
                     table,
                 })))
             }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let schema = self
+                    .ctx
+                    .get_table(&catalog, &database, &table)
+                    .await?
+                    .schema();
+                let mut column_names = Vec::with_capacity(columns.len());
+                for column in columns {
+                    let column = normalize_identifier(column, &self.name_resolution_ctx).name;
+                    schema.field_with_name(&column)?;
+                    column_names.push(column);
+                }
+                Ok(Plan::AddTableRowAccessPolicy(Box::new(
+                    AddTableRowAccessPolicyPlan {
+                        tenant,
+                        catalog,
+                        database,
+                        table,
+                        policy: policy.to_string(),
+                        columns: column_names,
+                    },
+                )))
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => Ok(Plan::DropTableRowAccessPolicy(
+                Box::new(DropTableRowAccessPolicyPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    policy: policy.to_string(),
+                }),
+            )),
         }
     }
 
@@ -992,6 +1046,27 @@ impl Binder {
         })))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_table_cache(
+        &mut self,
+        stmt: &DropTableCacheStmt,
+    ) -> Result<Plan> {
+        let DropTableCacheStmt {
+            catalog,
+            database,
+            table,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+
+        Ok(Plan::DropTableCache(Box::new(DropTableCachePlan {
+            catalog,
+            database,
+            table,
+        })))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_optimize_table(
         &mut self,
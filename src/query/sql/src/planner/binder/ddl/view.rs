@@ -13,17 +13,21 @@
 // limitations under the License.
 
 use common_ast::ast::AlterViewStmt;
+use common_ast::ast::CreateMaterializedViewStmt;
 use common_ast::ast::CreateViewStmt;
 use common_ast::ast::DropViewStmt;
+use common_ast::ast::RefreshMaterializedViewStmt;
 use common_ast::VisitorMut;
 use common_exception::Result;
 
 use crate::binder::Binder;
 use crate::planner::semantic::normalize_identifier;
 use crate::plans::AlterViewPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::DropViewPlan;
 use crate::plans::Plan;
+use crate::plans::RefreshMaterializedViewPlan;
 use crate::ViewRewriter;
 
 impl Binder {
@@ -128,4 +132,60 @@ impl Binder {
         };
         Ok(Plan::DropView(plan.into()))
     }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_materialized_view(
+        &mut self,
+        stmt: &CreateMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let CreateMaterializedViewStmt {
+            if_not_exists,
+            catalog,
+            database,
+            view,
+            query,
+        } = stmt;
+        let mut query = *query.clone();
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let mut visitor = ViewRewriter {
+            current_database: database.clone(),
+        };
+        visitor.visit_query(&mut query);
+        let subquery = format!("{}", query);
+
+        let plan = CreateMaterializedViewPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            catalog,
+            database,
+            view_name,
+            subquery,
+        };
+        Ok(Plan::CreateMaterializedView(plan.into()))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_materialized_view(
+        &mut self,
+        stmt: &RefreshMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let RefreshMaterializedViewStmt {
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let plan = RefreshMaterializedViewPlan {
+            tenant,
+            catalog,
+            database,
+            view_name,
+        };
+        Ok(Plan::RefreshMaterializedView(plan.into()))
+    }
 }
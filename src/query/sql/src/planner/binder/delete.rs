@@ -20,6 +20,7 @@ use common_ast::ast::TableReference;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::DataType;
+use common_expression::Scalar;
 use common_expression::ROW_ID_COL_NAME;
 
 use crate::binder::Binder;
@@ -27,6 +28,7 @@ use crate::binder::ScalarBinder;
 use crate::binder::INTERNAL_COLUMN_FACTORY;
 use crate::optimizer::SExpr;
 use crate::optimizer::SubqueryRewriter;
+use crate::plans::ConstantExpr;
 use crate::plans::DeletePlan;
 use crate::plans::Filter;
 use crate::plans::Operator;
@@ -81,6 +83,8 @@ impl<'a> Binder {
             ));
         };
 
+        self.check_dml_row_access_policy(&catalog_name, &database_name, &table_name)
+            .await?;
         let (table_expr, mut context) = self.bind_single_table(bind_context, table).await?;
 
         context.allow_internal_columns(false);
@@ -94,10 +98,16 @@ impl<'a> Binder {
             self.ctes_map.clone(),
         );
 
-        let (selection, subquery_desc) = self
+        let (mut selection, subquery_desc) = self
             .process_selection(selection, table_expr, &mut scalar_binder)
             .await?;
 
+        // `DELETE FROM t WHERE <always true>` deletes every row, drop the predicate
+        // so that it is executed as a truncation instead of rewriting every block.
+        if selection.as_ref().is_some_and(is_always_true) {
+            selection = None;
+        }
+
         let plan = DeletePlan {
             catalog_name,
             database_name,
@@ -120,7 +130,7 @@ impl Binder {
     ) -> Result<SubqueryDesc> {
         if subquery_expr.data_type() != DataType::Nullable(Box::new(DataType::Boolean)) {
             return Err(ErrorCode::from_string(
-                "subquery data type in delete statement should be boolean".to_string(),
+                "subquery data type in delete or update statement should be boolean".to_string(),
             ));
         }
         let mut outer_columns = Default::default();
@@ -201,7 +211,7 @@ impl Binder {
                     .await?;
                 subquery_desc.push(desc);
             }
-            ScalarExpr::UDFServerCall(scalar) => {
+            ScalarExpr::UDFCall(scalar) => {
                 for arg in scalar.arguments.iter() {
                     self.subquery_desc(arg, table_expr.clone(), subquery_desc)
                         .await?;
@@ -216,3 +226,13 @@ impl Binder {
         Ok(())
     }
 }
+
+fn is_always_true(predicate: &ScalarExpr) -> bool {
+    matches!(
+        predicate,
+        ScalarExpr::ConstantExpr(ConstantExpr {
+            value: Scalar::Boolean(true),
+            ..
+        })
+    )
+}
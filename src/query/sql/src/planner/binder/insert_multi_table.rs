@@ -0,0 +1,233 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_ast::ast::InsertMultiTableStmt;
+use common_ast::ast::IntoClause;
+use common_ast::ast::Statement;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use indexmap::IndexMap;
+
+use crate::binder::Binder;
+use crate::optimizer::optimize;
+use crate::optimizer::OptimizerConfig;
+use crate::optimizer::OptimizerContext;
+use crate::optimizer::SExpr;
+use crate::plans::EvalScalar;
+use crate::plans::InsertMultiTable;
+use crate::plans::InsertTarget;
+use crate::plans::Plan;
+use crate::plans::ScalarItem;
+use crate::BindContext;
+use crate::ScalarBinder;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_insert_multi_table(
+        &mut self,
+        bind_context: &mut BindContext,
+        stmt: &InsertMultiTableStmt,
+    ) -> Result<Plan> {
+        let InsertMultiTableStmt {
+            hints: _,
+            overwrite,
+            is_first,
+            into_clauses,
+            when_clauses,
+            else_clause,
+            source,
+        } = stmt;
+
+        if !into_clauses.is_empty() && (*is_first || !when_clauses.is_empty()) {
+            return Err(ErrorCode::SemanticError(
+                "INSERT FIRST and the INSERT with WHEN clauses can't have unconditional INTO clauses",
+            ));
+        }
+        if into_clauses.is_empty() && when_clauses.is_empty() {
+            return Err(ErrorCode::SemanticError(
+                "INSERT ALL/FIRST requires at least one INTO clause or WHEN clause",
+            ));
+        }
+
+        let statement = Statement::Query(Box::new(source.clone()));
+        let (s_expr, metadata, mut source_context, formatted_ast) =
+            match self.bind_statement(bind_context, &statement).await? {
+                Plan::Query {
+                    s_expr,
+                    metadata,
+                    bind_context,
+                    formatted_ast,
+                    ..
+                } => (s_expr, metadata, bind_context, formatted_ast),
+                _ => unreachable!(),
+            };
+        let num_source_columns = source_context.columns.len();
+
+        // Evaluate the condition of each WHEN clause as an extra output column of the source.
+        let mut items = Vec::with_capacity(when_clauses.len());
+        let mut condition_columns = Vec::with_capacity(when_clauses.len());
+        for (i, when_clause) in when_clauses.iter().enumerate() {
+            let mut scalar_binder = ScalarBinder::new(
+                &mut source_context,
+                self.ctx.clone(),
+                &self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+                HashMap::new(),
+                Box::new(IndexMap::new()),
+            );
+            let (scalar, data_type) = scalar_binder.bind(&when_clause.condition).await?;
+            if !matches!(
+                data_type.remove_nullable(),
+                DataType::Boolean | DataType::Null
+            ) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The condition of WHEN clause must be boolean, but got {data_type}"
+                ))
+                .set_span(when_clause.condition.span()));
+            }
+            let column = self.create_derived_column_binding(format!("when_{i}"), data_type);
+            items.push(ScalarItem {
+                scalar,
+                index: column.index,
+            });
+            condition_columns.push(column);
+        }
+
+        let s_expr = if items.is_empty() {
+            *s_expr
+        } else {
+            SExpr::create_unary(Arc::new(EvalScalar { items }.into()), Arc::new(*s_expr))
+        };
+        source_context.columns.extend(condition_columns);
+
+        let input_source = Plan::Query {
+            s_expr: Box::new(s_expr),
+            metadata,
+            bind_context: source_context,
+            rewrite_kind: None,
+            formatted_ast,
+            ignore_result: false,
+        };
+        let opt_ctx = Arc::new(OptimizerContext::new(OptimizerConfig {
+            enable_distributed_optimization: !self.ctx.get_cluster().is_empty(),
+        }));
+        let input_source = optimize(self.ctx.clone(), opt_ctx, input_source)?;
+
+        let mut intos = Vec::with_capacity(into_clauses.len());
+        for into_clause in into_clauses {
+            intos.push(
+                self.bind_insert_target(into_clause, num_source_columns)
+                    .await?,
+            );
+        }
+        let mut whens = Vec::with_capacity(when_clauses.len());
+        for when_clause in when_clauses {
+            let mut targets = Vec::with_capacity(when_clause.into_clauses.len());
+            for into_clause in &when_clause.into_clauses {
+                targets.push(
+                    self.bind_insert_target(into_clause, num_source_columns)
+                        .await?,
+                );
+            }
+            whens.push(targets);
+        }
+        let opt_else = match else_clause {
+            Some(else_clause) => {
+                let mut targets = Vec::with_capacity(else_clause.into_clauses.len());
+                for into_clause in &else_clause.into_clauses {
+                    targets.push(
+                        self.bind_insert_target(into_clause, num_source_columns)
+                            .await?,
+                    );
+                }
+                Some(targets)
+            }
+            None => None,
+        };
+
+        let plan = InsertMultiTable {
+            overwrite: *overwrite,
+            is_first: *is_first,
+            input_source,
+            intos,
+            whens,
+            opt_else,
+        };
+        // The target tables are committed in one transaction of the meta store.
+        let mut targets = plan.targets();
+        if let Some(first) = targets.next() {
+            if targets.any(|target| target.catalog != first.catalog) {
+                return Err(ErrorCode::SemanticError(
+                    "INSERT ALL/FIRST into tables of different catalogs is not supported",
+                ));
+            }
+        }
+        // The rows into the same table are appended together.
+        let mut schemas = HashMap::new();
+        for target in plan.targets() {
+            let schema = schemas
+                .entry(target.table_id)
+                .or_insert_with(|| target.schema.clone());
+            if *schema != target.schema {
+                return Err(ErrorCode::SemanticError(format!(
+                    "INSERT ALL/FIRST into table {} with different columns is not supported",
+                    target.table
+                )));
+            }
+        }
+
+        Ok(Plan::InsertMultiTable(Box::new(plan)))
+    }
+
+    async fn bind_insert_target(
+        &self,
+        into_clause: &IntoClause,
+        num_source_columns: usize,
+    ) -> Result<InsertTarget> {
+        let IntoClause {
+            catalog,
+            database,
+            table,
+            columns,
+        } = into_clause;
+        let (catalog_name, database_name, table_name) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let table = self
+            .ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?;
+        let schema = self.schema_project(&table.schema(), columns)?;
+        if schema.num_fields() != num_source_columns {
+            return Err(ErrorCode::BadArguments(format!(
+                "Fields in select statement is not equal with expected, select fields: {}, insert fields of table {}: {}",
+                num_source_columns,
+                table_name,
+                schema.num_fields(),
+            )));
+        }
+
+        Ok(InsertTarget {
+            catalog: catalog_name,
+            database: database_name,
+            table: table_name,
+            table_id: table.get_id(),
+            schema,
+        })
+    }
+}
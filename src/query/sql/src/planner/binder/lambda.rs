@@ -29,7 +29,7 @@ use crate::plans::Lambda;
 use crate::plans::LambdaFunc;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
-use crate::plans::UDFServerCall;
+use crate::plans::UDFCall;
 use crate::plans::WindowFunc;
 use crate::plans::WindowOrderBy;
 use crate::BindContext;
@@ -87,16 +87,16 @@ impl<'a> LambdaRewriter<'a> {
             }
             .into()),
 
-            ScalarExpr::UDFServerCall(udf) => {
+            ScalarExpr::UDFCall(udf) => {
                 let new_args = udf
                     .arguments
                     .iter()
                     .map(|arg| self.visit(arg))
                     .collect::<Result<Vec<_>>>()?;
-                Ok(UDFServerCall {
+                Ok(UDFCall {
                     span: udf.span,
                     func_name: udf.func_name.clone(),
-                    server_addr: udf.server_addr.clone(),
+                    udf_type: udf.udf_type.clone(),
                     arg_types: udf.arg_types.clone(),
                     return_type: udf.return_type.clone(),
                     arguments: new_args,
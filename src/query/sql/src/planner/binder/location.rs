@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::io::Error;
 use std::io::ErrorKind;
 use std::io::Result;
@@ -33,6 +34,8 @@ use common_meta_app::storage::STORAGE_GCS_DEFAULT_ENDPOINT;
 use common_meta_app::storage::STORAGE_IPFS_DEFAULT_ENDPOINT;
 use common_meta_app::storage::STORAGE_S3_DEFAULT_ENDPOINT;
 use common_storage::STDIN_FD;
+use common_storages_kafka::KafkaInfo;
+use common_storages_kafka::KafkaMessageFormat;
 use opendal::Scheme;
 use percent_encoding::percent_decode_str;
 
@@ -355,6 +358,85 @@ fn parse_webhdfs_params(l: &mut UriLocation) -> Result<StorageParams> {
     Ok(sp)
 }
 
+/// The librdkafka properties could be set in the connection of a kafka location,
+/// the `_` in the connection keys are replaced by `.`.
+const KAFKA_PROPERTIES: &[&str] = &[
+    "security_protocol",
+    "sasl_mechanism",
+    "sasl_username",
+    "sasl_password",
+    "ssl_ca_location",
+];
+
+/// parse_kafka_location will parse `kafka://<broker>/<topic>` into KafkaInfo.
+///
+/// The consumer group is `default_group_id` if `group_id` is not given in the connection.
+pub fn parse_kafka_location(l: &mut UriLocation, default_group_id: String) -> Result<KafkaInfo> {
+    let topic = l.path.trim_matches('/').to_string();
+    if topic.is_empty() || topic.contains('/') {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            anyhow!("invalid kafka location, expected `kafka://<broker>/<topic>`"),
+        ));
+    }
+
+    let brokers = match l.connection.get("bootstrap_servers") {
+        Some(servers) => format!("{},{}", l.name, servers),
+        None => l.name.clone(),
+    };
+    let group_id = l
+        .connection
+        .get("group_id")
+        .cloned()
+        .unwrap_or(default_group_id);
+    let format = l.connection.get("format").map(|v| v.to_lowercase());
+    let format = match format.as_deref() {
+        None | Some("json") => KafkaMessageFormat::Json,
+        Some("avro") => {
+            if let Some(url) = l.connection.get("schema_registry_url") {
+                KafkaMessageFormat::AvroSchemaRegistry {
+                    url: url.trim_end_matches('/').to_string(),
+                }
+            } else {
+                let schema = l.connection.get("avro_schema").cloned().ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::InvalidInput,
+                        anyhow!(
+                            "avro_schema or schema_registry_url is required for kafka messages of avro format"
+                        ),
+                    )
+                })?;
+                KafkaMessageFormat::Avro { schema }
+            }
+        }
+        Some(other) => {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                anyhow!(
+                    "unsupported kafka message format {}, must be one of json, avro",
+                    other
+                ),
+            ));
+        }
+    };
+    let mut properties = BTreeMap::new();
+    for key in KAFKA_PROPERTIES {
+        if let Some(value) = l.connection.get(key) {
+            properties.insert(key.replace('_', "."), value.clone());
+        }
+    }
+
+    l.connection.check()?;
+
+    Ok(KafkaInfo {
+        brokers,
+        topic,
+        group_id,
+        format,
+        properties,
+    })
+}
+
 /// parse_uri_location will parse given UriLocation into StorageParams and Path.
 pub async fn parse_uri_location(l: &mut UriLocation) -> Result<(StorageParams, String)> {
     // Path endswith `/` means it's a directory, otherwise it's a file.
@@ -100,6 +100,8 @@ impl Binder {
 
         let (catalog_name, database_name, table_name) =
             self.normalize_object_identifier_triple(catalog, database, table_ident);
+        self.check_dml_row_access_policy(&catalog_name, &database_name, &table_name)
+            .await?;
 
         let table = self
             .ctx
@@ -27,6 +27,7 @@ mod delete;
 mod distinct;
 mod having;
 mod insert;
+mod insert_multi_table;
 mod internal_column_factory;
 mod join;
 mod kill;
@@ -37,7 +38,9 @@ mod merge_into;
 mod presign;
 mod project;
 mod project_set;
+mod recursive_cte;
 mod replace;
+mod row_access_policy;
 mod scalar;
 mod scalar_common;
 mod scalar_visitor;
@@ -61,6 +64,7 @@ pub use column_binding::ColumnBinding;
 pub use column_binding::ColumnBindingBuilder;
 pub use copy_into_table::resolve_stage_location;
 pub use internal_column_factory::INTERNAL_COLUMN_FACTORY;
+pub use location::parse_kafka_location;
 pub use location::parse_uri_location;
 pub use scalar::ScalarBinder;
 pub use scalar_common::*;
@@ -18,6 +18,7 @@ use common_ast::ast::Expr;
 use common_ast::ast::Identifier;
 use common_ast::ast::Lambda;
 use common_ast::ast::Literal;
+use common_ast::ast::TableAlias;
 use common_ast::ast::Window;
 use common_ast::Visitor;
 use common_exception::ErrorCode;
@@ -31,8 +32,10 @@ use crate::binder::ExprContext;
 use crate::normalize_identifier;
 use crate::optimizer::SExpr;
 use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
 use crate::plans::ProjectSet;
+use crate::plans::ScalarItem;
 use crate::plans::SrfItem;
 use crate::BindContext;
 use crate::Binder;
@@ -197,3 +200,154 @@ impl Binder {
         ))
     }
 }
+
+/// The names of the columns produced by a set-returning function used as a table.
+/// Each field of the returned tuple is exposed as a separate column.
+fn srf_table_column_names(func_name: &str) -> Option<&'static [&'static str]> {
+    match func_name {
+        "flatten" => Some(&["index", "key", "value"]),
+        _ => None,
+    }
+}
+
+/// Whether the set-returning function should be bound by `bind_srf_table_function`
+/// when it is used as a non-lateral table function.
+pub(crate) fn is_srf_table_function(func_name: &str) -> bool {
+    srf_table_column_names(func_name).is_some()
+}
+
+fn srf_table_function_args(
+    span: Span,
+    func_name: &str,
+    params: &[Expr],
+    named_params: &[(String, Expr)],
+) -> Result<Vec<Expr>> {
+    let mut args = params.to_vec();
+    for (name, value) in named_params {
+        if func_name == "flatten" && name.eq_ignore_ascii_case("input") && args.is_empty() {
+            args.push(value.clone());
+        } else {
+            return Err(ErrorCode::SemanticError(format!(
+                "unsupported argument `{}` for table function {}",
+                name, func_name
+            ))
+            .set_span(span));
+        }
+    }
+    Ok(args)
+}
+
+impl Binder {
+    /// Bind a set-returning function used as a table, e.g. `LATERAL FLATTEN(INPUT => t.c)`,
+    /// into a `ProjectSet` on top of `child`. The arguments are resolved with `child_context`,
+    /// so a lateral function can reference the columns of the preceding tables, and the
+    /// returned bind context contains the columns of `child_context` followed by the
+    /// columns produced by the function.
+    #[allow(clippy::too_many_arguments)]
+    #[async_backtrace::framed]
+    pub(crate) async fn bind_srf_table_function(
+        &mut self,
+        mut child_context: BindContext,
+        child: SExpr,
+        span: Span,
+        func_name: &str,
+        params: &[Expr],
+        named_params: &[(String, Expr)],
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        let args = srf_table_function_args(span, func_name, params, named_params)?;
+
+        let original_context = child_context.expr_context.clone();
+        child_context.set_expr_context(ExprContext::InSetReturningFunction);
+        let mut arguments = Vec::with_capacity(args.len());
+        for arg in args.iter() {
+            let mut scalar_binder = ScalarBinder::new(
+                &mut child_context,
+                self.ctx.clone(),
+                &self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+                self.m_cte_bound_ctx.clone(),
+                self.ctes_map.clone(),
+            );
+            let (scalar, _) = scalar_binder.bind(arg).await?;
+            arguments.push(scalar);
+        }
+        child_context.set_expr_context(original_context);
+
+        let srf_scalar = ScalarExpr::FunctionCall(FunctionCall {
+            span,
+            func_name: func_name.to_string(),
+            params: vec![],
+            arguments,
+        });
+        let srf_type = srf_scalar.data_type()?;
+        let srf_index = self
+            .metadata
+            .write()
+            .add_derived_column(func_name.to_string(), srf_type.clone());
+        let srf_column = ColumnBindingBuilder::new(
+            func_name.to_string(),
+            srf_index,
+            Box::new(srf_type),
+            Visibility::InVisible,
+        )
+        .build();
+        let project_set = ProjectSet {
+            srfs: vec![SrfItem {
+                scalar: srf_scalar,
+                index: srf_index,
+            }],
+        };
+        let s_expr = SExpr::create_unary(Arc::new(project_set.into()), Arc::new(child));
+
+        let srf_result = ScalarExpr::FunctionCall(FunctionCall {
+            span,
+            func_name: "get".to_string(),
+            params: vec![1],
+            arguments: vec![ScalarExpr::BoundColumnRef(BoundColumnRef {
+                span,
+                column: srf_column,
+            })],
+        });
+        let fields = match srf_table_column_names(func_name) {
+            Some(names) => names
+                .iter()
+                .enumerate()
+                .map(|(i, name)| {
+                    let field = ScalarExpr::FunctionCall(FunctionCall {
+                        span,
+                        func_name: "get".to_string(),
+                        params: vec![i + 1],
+                        arguments: vec![srf_result.clone()],
+                    });
+                    (name.to_string(), field)
+                })
+                .collect(),
+            None => vec![(func_name.to_string(), srf_result)],
+        };
+
+        // The alias only applies to the columns of the function.
+        let mut srf_context = BindContext::new();
+        let mut items = Vec::with_capacity(fields.len());
+        for (name, scalar) in fields {
+            let data_type = scalar.data_type()?;
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(name.clone(), data_type.clone());
+            let column =
+                ColumnBindingBuilder::new(name, index, Box::new(data_type), Visibility::Visible)
+                    .build();
+            srf_context.add_column_binding(column);
+            items.push(ScalarItem { scalar, index });
+        }
+        if let Some(alias) = alias {
+            srf_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+        child_context.columns.extend(srf_context.columns);
+
+        let s_expr = SExpr::create_unary(Arc::new(EvalScalar { items }.into()), Arc::new(s_expr));
+        Ok((s_expr, child_context))
+    }
+}
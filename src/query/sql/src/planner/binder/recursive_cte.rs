@@ -0,0 +1,305 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_ast::ast::SetExpr;
+use common_ast::ast::SetOperator;
+use common_ast::ast::TableAlias;
+use common_ast::ast::TableReference;
+use common_ast::walk_table_reference;
+use common_ast::Visitor;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::type_check::common_super_type;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::DataField;
+use common_functions::BUILTIN_FUNCTIONS;
+
+use crate::binder::Binder;
+use crate::binder::ColumnBindingBuilder;
+use crate::binder::CteInfo;
+use crate::binder::CteWorkingTable;
+use crate::binder::Visibility;
+use crate::normalize_identifier;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::optimizer::StatInfo;
+use crate::optimizer::Statistics;
+use crate::plans::RecursiveCte;
+use crate::plans::RecursiveCteScan;
+use crate::BindContext;
+use crate::NameResolutionContext;
+
+// The recursive term is bound with the types of the anchor term at first, if it produces
+// wider types, the working table is widened and the recursive term is bound again.
+const MAX_WIDENING_ROUNDS: usize = 3;
+
+impl Binder {
+    /// Bind the query of a cte declared by `WITH RECURSIVE`.
+    ///
+    /// The query is recursive only if it is in the form of `anchor UNION [ALL] recursive`
+    /// and the recursive term references the cte itself, otherwise it's bound as a normal
+    /// cte query.
+    #[async_backtrace::framed]
+    pub(crate) async fn bind_r_cte_query(
+        &mut self,
+        bind_context: &mut BindContext,
+        cte_name: &str,
+        cte_info: &CteInfo,
+    ) -> Result<(SExpr, BindContext)> {
+        let query = &cte_info.query;
+        let set_operation = match &query.body {
+            SetExpr::SetOperation(set_operation)
+                if set_operation.op == SetOperator::Union
+                    && self.references_cte(&set_operation.right, cte_name) =>
+            {
+                set_operation
+            }
+            _ => return self.bind_query(bind_context, query).await,
+        };
+        if query.with.is_some()
+            || !query.order_by.is_empty()
+            || !query.limit.is_empty()
+            || query.offset.is_some()
+        {
+            return Err(ErrorCode::SemanticError(format!(
+                "WITH, ORDER BY, LIMIT and OFFSET are not supported in recursive cte `{cte_name}`"
+            ))
+            .set_span(set_operation.span));
+        }
+        if self.references_cte(&set_operation.left, cte_name) {
+            return Err(ErrorCode::SemanticError(format!(
+                "recursive reference of cte `{cte_name}` is not allowed in its anchor term"
+            ))
+            .set_span(set_operation.left.span()));
+        }
+
+        // 1. Bind the anchor term.
+        let (left_expr, left_bind_context) = self
+            .bind_set_expr(bind_context, &set_operation.left, &[], 0)
+            .await?;
+        let anchor_stat_info = RelExpr::with_s_expr(&left_expr).derive_cardinality()?;
+        let stat_info = Arc::new(StatInfo {
+            cardinality: anchor_stat_info.cardinality,
+            statistics: Statistics {
+                precise_cardinality: None,
+                column_stats: Default::default(),
+            },
+        });
+        let column_names = left_bind_context
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(idx, column)| {
+                cte_info
+                    .columns_alias
+                    .get(idx)
+                    .cloned()
+                    .unwrap_or_else(|| column.column_name.clone())
+            })
+            .collect::<Vec<_>>();
+        let mut column_types = left_bind_context
+            .columns
+            .iter()
+            .map(|column| widen_integer_type(&column.data_type))
+            .collect::<Vec<_>>();
+
+        // 2. Bind the recursive term, the self references are bound to the working table.
+        let mut round = 0;
+        let (right_expr, right_bind_context) = loop {
+            round += 1;
+            let working_table = CteWorkingTable {
+                cte_idx: cte_info.cte_idx,
+                columns: column_names
+                    .iter()
+                    .cloned()
+                    .zip(column_types.iter().cloned())
+                    .collect(),
+                stat_info: stat_info.clone(),
+            };
+            self.set_cte_working_table(cte_name, Some(working_table));
+            let res = self
+                .bind_set_expr(bind_context, &set_operation.right, &[], 0)
+                .await;
+            self.set_cte_working_table(cte_name, None);
+            let (right_expr, right_bind_context) = res?;
+
+            if right_bind_context.columns.len() != column_types.len() {
+                return Err(ErrorCode::SemanticError(
+                    "SetOperation must have the same number of columns",
+                ));
+            }
+            let mut widened = false;
+            for (column_type, right_col) in column_types
+                .iter_mut()
+                .zip(right_bind_context.columns.iter())
+            {
+                if *column_type == *right_col.data_type {
+                    continue;
+                }
+                let common_type = common_super_type(
+                    column_type.clone(),
+                    *right_col.data_type.clone(),
+                    &BUILTIN_FUNCTIONS.default_cast_rules,
+                )
+                .ok_or_else(|| {
+                    ErrorCode::SemanticError(format!(
+                        "The types of recursive cte `{cte_name}` cannot be matched, anchor column type: {:?}, recursive column {:?}, type: {:?}",
+                        column_type, right_col.column_name, right_col.data_type
+                    ))
+                })?;
+                if common_type != *column_type && round < MAX_WIDENING_ROUNDS {
+                    *column_type = common_type;
+                    widened = true;
+                }
+            }
+            if !widened {
+                break (right_expr, right_bind_context);
+            }
+        };
+
+        // 3. Cast the outputs of both terms to the types of the working table.
+        let (new_bind_context, pairs, left_expr, right_expr) = self.coercion_union_type(
+            set_operation.left.span(),
+            set_operation.right.span(),
+            left_bind_context,
+            right_bind_context,
+            left_expr,
+            right_expr,
+            column_types,
+        )?;
+
+        let r_cte = RecursiveCte {
+            cte_idx: cte_info.cte_idx,
+            cte_name: cte_name.to_string(),
+            pairs,
+            union_all: set_operation.all,
+        };
+        let s_expr = SExpr::create_binary(
+            Arc::new(r_cte.into()),
+            Arc::new(left_expr),
+            Arc::new(right_expr),
+        );
+        Ok((s_expr, new_bind_context))
+    }
+
+    /// Bind the self reference of a recursive cte in its recursive term.
+    pub(crate) fn bind_r_cte_scan(
+        &mut self,
+        bind_context: &BindContext,
+        table_name: &str,
+        alias: &Option<TableAlias>,
+        working_table: &CteWorkingTable,
+    ) -> Result<(SExpr, BindContext)> {
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        let table_name = alias
+            .as_ref()
+            .map(|alias| normalize_identifier(&alias.name, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| table_name.to_string());
+        let columns_alias = alias
+            .as_ref()
+            .map(|alias| alias.columns.as_slice())
+            .unwrap_or_default();
+        if columns_alias.len() > working_table.columns.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "table has {} columns available but {} columns specified",
+                working_table.columns.len(),
+                columns_alias.len()
+            )));
+        }
+
+        let mut fields = Vec::with_capacity(working_table.columns.len());
+        for (idx, (column_name, data_type)) in working_table.columns.iter().enumerate() {
+            let column_name = columns_alias
+                .get(idx)
+                .map(|alias| normalize_identifier(alias, &self.name_resolution_ctx).name)
+                .unwrap_or_else(|| column_name.clone());
+            let index = self
+                .metadata
+                .write()
+                .add_derived_column(column_name.clone(), data_type.clone());
+            let column_binding = ColumnBindingBuilder::new(
+                column_name,
+                index,
+                Box::new(data_type.clone()),
+                Visibility::Visible,
+            )
+            .table_name(Some(table_name.clone()))
+            .build();
+            new_bind_context.add_column_binding(column_binding);
+            fields.push(DataField::new(&index.to_string(), data_type.clone()));
+        }
+
+        let s_expr = SExpr::create_leaf(Arc::new(
+            RecursiveCteScan {
+                cte_idx: working_table.cte_idx,
+                fields,
+                stat: working_table.stat_info.clone(),
+            }
+            .into(),
+        ));
+        Ok((s_expr, new_bind_context))
+    }
+
+    fn set_cte_working_table(&mut self, cte_name: &str, working_table: Option<CteWorkingTable>) {
+        if let Some(cte_info) = self.ctes_map.get_mut(cte_name) {
+            cte_info.working_table = working_table;
+        }
+    }
+
+    fn references_cte(&self, set_expr: &SetExpr, cte_name: &str) -> bool {
+        let mut finder = CteReferenceFinder {
+            cte_name,
+            name_resolution_ctx: &self.name_resolution_ctx,
+            found: false,
+        };
+        finder.visit_set_expr(set_expr);
+        finder.found
+    }
+}
+
+// Integers of the anchor term are widened to 64 bits at first, so that the recursive
+// term like `n + 1` does not need to widen the working table again.
+fn widen_integer_type(data_type: &DataType) -> DataType {
+    match data_type {
+        DataType::Nullable(inner) => widen_integer_type(inner).wrap_nullable(),
+        DataType::Number(num_ty) if num_ty.is_integer() => {
+            if num_ty.is_signed() {
+                DataType::Number(NumberDataType::Int64)
+            } else {
+                DataType::Number(NumberDataType::UInt64)
+            }
+        }
+        _ => data_type.clone(),
+    }
+}
+
+struct CteReferenceFinder<'a> {
+    cte_name: &'a str,
+    name_resolution_ctx: &'a NameResolutionContext,
+    found: bool,
+}
+
+impl<'ast> Visitor<'ast> for CteReferenceFinder<'_> {
+    fn visit_table_reference(&mut self, table_ref: &'ast TableReference) {
+        if let TableReference::Table { table, .. } = table_ref {
+            if normalize_identifier(table, self.name_resolution_ctx).name == self.cte_name {
+                self.found = true;
+            }
+        }
+        walk_table_reference(self, table_ref);
+    }
+}
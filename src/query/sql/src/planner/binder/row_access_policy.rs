@@ -0,0 +1,149 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use common_ast::parser::parse_expr;
+use common_ast::parser::tokenize_sql;
+use common_ast::Dialect;
+use common_catalog::table::Table;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_users::UserApiProvider;
+use storages_common_table_meta::table::TableRowAccessPolicy;
+
+use crate::optimizer::SExpr;
+use crate::plans::BoundColumnRef;
+use crate::plans::Filter;
+use crate::BindContext;
+use crate::Binder;
+use crate::ScalarBinder;
+use crate::ScalarExpr;
+
+impl Binder {
+    /// Wrap the scan of `table` with the predicate of the row access policy
+    /// attached to it, if there is one.
+    ///
+    /// The policy is resolved when the query is bound, so functions such as
+    /// `current_role()` in the policy body are evaluated for the current
+    /// session. A policy that can not be loaded fails the query instead of
+    /// returning unfiltered rows.
+    #[async_backtrace::framed]
+    pub(crate) async fn bind_row_access_policy(
+        &mut self,
+        bind_context: &BindContext,
+        table: &dyn Table,
+        s_expr: SExpr,
+    ) -> Result<SExpr> {
+        let Some(attached) = TableRowAccessPolicy::from_options(table.options())? else {
+            return Ok(s_expr);
+        };
+        if bind_context.planning_agg_index {
+            return Ok(s_expr);
+        }
+
+        let tenant = self.ctx.get_tenant();
+        let policy = UserApiProvider::instance()
+            .get_row_access_policy(&tenant, &attached.policy)
+            .await
+            .map_err(|e| {
+                e.add_message_back(format!(
+                    " (while applying row access policy of table {})",
+                    table.name()
+                ))
+            })?;
+        if policy.args.len() != attached.columns.len() {
+            return Err(ErrorCode::IllegalRowAccessPolicy(format!(
+                "row access policy {} expects {} arguments, but table {} binds {} columns",
+                policy.name,
+                policy.args.len(),
+                table.name(),
+                attached.columns.len()
+            )));
+        }
+
+        let mut aliases = Vec::with_capacity(policy.args.len());
+        for ((arg_name, _), column_name) in policy.args.iter().zip(attached.columns.iter()) {
+            let column = bind_context
+                .columns
+                .iter()
+                .find(|column| &column.column_name == column_name)
+                .ok_or_else(|| {
+                    ErrorCode::IllegalRowAccessPolicy(format!(
+                        "column {} bound to row access policy {} doesn't exist in table {}",
+                        column_name,
+                        policy.name,
+                        table.name()
+                    ))
+                })?;
+            let scalar = ScalarExpr::BoundColumnRef(BoundColumnRef {
+                span: None,
+                column: column.clone(),
+            });
+            aliases.push((arg_name.clone(), scalar));
+        }
+
+        let tokens = tokenize_sql(&policy.body)?;
+        let ast_expr = parse_expr(&tokens, Dialect::PostgreSQL)?;
+        // The body can only see the policy arguments, not the other table columns.
+        let mut policy_context = BindContext::new();
+        let mut scalar_binder = ScalarBinder::new(
+            &mut policy_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &aliases,
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let (predicate, data_type) = scalar_binder.bind(&ast_expr).await?;
+        if data_type.remove_nullable() != DataType::Boolean {
+            return Err(ErrorCode::IllegalRowAccessPolicy(format!(
+                "row access policy {} must return BOOLEAN, but got {}",
+                policy.name, data_type
+            )));
+        }
+
+        // The filtered rows depend on the session, don't share them through the result cache.
+        self.ctx.set_cacheable(false);
+
+        let filter = Filter {
+            predicates: vec![predicate],
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(filter.into()),
+            Arc::new(s_expr),
+        ))
+    }
+
+    /// DML statements bind their target table to a plain scan, so a row
+    /// access policy can't be enforced there. Reject them rather than let
+    /// them touch rows the policy hides.
+    #[async_backtrace::framed]
+    pub(crate) async fn check_dml_row_access_policy(
+        &self,
+        catalog: &str,
+        database: &str,
+        table_name: &str,
+    ) -> Result<()> {
+        let table = self.ctx.get_table(catalog, database, table_name).await?;
+        if let Some(attached) = TableRowAccessPolicy::from_options(table.options())? {
+            return Err(ErrorCode::IllegalRowAccessPolicy(format!(
+                "table {}.{} is protected by row access policy {}, modifying it is not supported",
+                database, table_name, attached.policy
+            )));
+        }
+        Ok(())
+    }
+}
@@ -161,7 +161,7 @@ pub fn contain_subquery(scalar: &ScalarExpr) -> bool {
         }
         ScalarExpr::FunctionCall(func) => func.arguments.iter().any(contain_subquery),
         ScalarExpr::CastExpr(CastExpr { argument, .. }) => contain_subquery(argument),
-        ScalarExpr::UDFServerCall(udf) => udf.arguments.iter().any(contain_subquery),
+        ScalarExpr::UDFCall(udf) => udf.arguments.iter().any(contain_subquery),
         _ => false,
     }
 }
@@ -213,7 +213,7 @@ pub fn prune_by_children(scalar: &ScalarExpr, columns: &HashSet<ScalarExpr>) ->
             .all(|arg| prune_by_children(arg, columns)),
         ScalarExpr::CastExpr(expr) => prune_by_children(expr.argument.as_ref(), columns),
         ScalarExpr::SubqueryExpr(_) => false,
-        ScalarExpr::UDFServerCall(udf) => udf
+        ScalarExpr::UDFCall(udf) => udf
             .arguments
             .iter()
             .all(|arg| prune_by_children(arg, columns)),
@@ -233,8 +233,11 @@ pub fn wrap_cast_scalar(
             | DataType::Decimal(_)
             | DataType::Timestamp
             | DataType::Date
+            | DataType::Time
             | DataType::Bitmap
-            | DataType::Variant => wrap_cast(scalar, target_type),
+            | DataType::Variant
+            | DataType::Geometry
+            | DataType::Binary => wrap_cast(scalar, target_type),
             DataType::String => {
                 // parse string to JSON value
                 let func = ScalarExpr::FunctionCall(FunctionCall {
@@ -98,7 +98,7 @@ pub trait ScalarVisitor: Sized {
                                     stack.push(RecursionProcessing::Call(&cast.argument))
                                 }
                                 ScalarExpr::SubqueryExpr(_) => {}
-                                ScalarExpr::UDFServerCall(udf) => {
+                                ScalarExpr::UDFCall(udf) => {
                                     for arg in udf.arguments.iter() {
                                         stack.push(RecursionProcessing::Call(arg));
                                     }
@@ -352,6 +352,8 @@ impl Binder {
                     used_count: 0,
                     stat_info: None,
                     columns: vec![],
+                    recursive: with.recursive,
+                    working_table: None,
                 };
                 self.ctes_map.insert(table_name.clone(), cte_info.clone());
                 bind_context.cte_map_ref.insert(table_name, cte_info);
@@ -673,7 +675,7 @@ impl Binder {
 
     #[allow(clippy::type_complexity)]
     #[allow(clippy::too_many_arguments)]
-    fn coercion_union_type(
+    pub(super) fn coercion_union_type(
         &self,
         left_span: Span,
         right_span: Span,
@@ -27,6 +27,7 @@ use super::BindContext;
 use super::Binder;
 use crate::planner::semantic::TypeChecker;
 use crate::plans::Plan;
+use crate::plans::SetUserVariablePlan;
 use crate::plans::SettingPlan;
 use crate::plans::UnSettingPlan;
 use crate::plans::VarValue;
@@ -71,6 +72,44 @@ impl Binder {
         }
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_set_user_variable(
+        &mut self,
+        bind_context: &mut BindContext,
+        variable: &Identifier,
+        value: &Expr,
+    ) -> Result<Plan> {
+        let mut type_checker = TypeChecker::new(
+            bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            false,
+            false,
+        );
+        let variable = variable.name.clone();
+
+        let (scalar, _) = *type_checker.resolve(value).await?;
+        let expr = scalar.as_expr()?;
+
+        // The value is evaluated once when it's set, the later references of the
+        // variable are bound as the constant.
+        let (new_expr, _) =
+            ConstantFolder::fold(&expr, &self.ctx.get_function_context()?, &BUILTIN_FUNCTIONS);
+        match new_expr {
+            common_expression::Expr::Constant { scalar, .. } => {
+                Ok(Plan::SetUserVariable(Box::new(SetUserVariablePlan {
+                    variable,
+                    value: scalar,
+                })))
+            }
+            _ => Err(ErrorCode::SemanticError(format!(
+                "value of user variable @{variable} must be constant value"
+            ))),
+        }
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_unset_variable(
         &mut self,
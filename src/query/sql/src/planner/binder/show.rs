@@ -87,7 +87,7 @@ impl Binder {
             .map(|s| format!("WHERE name LIKE '{s}'"))
             .unwrap_or_else(|| "".to_string());
         let query = format!(
-            "SELECT name, value, default, level, description, type FROM system.settings {} ORDER BY name",
+            "SELECT name, value, default, `range`, level, description, type FROM system.settings {} ORDER BY name",
             sub_query
         );
 
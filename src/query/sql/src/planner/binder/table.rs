@@ -22,9 +22,13 @@ use async_recursion::async_recursion;
 use chrono::TimeZone;
 use chrono::Utc;
 use common_ast::ast::Connection;
+use common_ast::ast::Expr;
 use common_ast::ast::FileLocation;
+use common_ast::ast::Identifier;
 use common_ast::ast::Indirection;
 use common_ast::ast::Join;
+use common_ast::ast::JoinCondition;
+use common_ast::ast::JoinOperator;
 use common_ast::ast::SelectStmt;
 use common_ast::ast::SelectTarget;
 use common_ast::ast::Statement;
@@ -79,6 +83,7 @@ use log::info;
 use parking_lot::RwLock;
 
 use crate::binder::copy_into_table::resolve_file_location;
+use crate::binder::project_set::is_srf_table_function;
 use crate::binder::scalar::ScalarBinder;
 use crate::binder::table_args::bind_table_args;
 use crate::binder::Binder;
@@ -183,6 +188,14 @@ impl Binder {
                 } else {
                     None
                 };
+                // Self reference in the recursive term of a recursive cte
+                if let Some(working_table) = self
+                    .ctes_map
+                    .get(&table_name)
+                    .and_then(|cte_info| cte_info.working_table.clone())
+                {
+                    return self.bind_r_cte_scan(bind_context, &table_name, alias, &working_table);
+                }
                 let mut bind_cte = true;
                 if let Some(cte_name) = &bind_context.cte_name {
                     // If table name equals to cte name, then skip bind cte and find table from catalog
@@ -307,7 +320,7 @@ impl Binder {
                         let table_index = self.metadata.write().add_table(
                             catalog,
                             database.clone(),
-                            table_meta,
+                            table_meta.clone(),
                             table_alias_name,
                             bind_context.view_info.is_some(),
                             bind_context.planning_agg_index,
@@ -316,6 +329,9 @@ impl Binder {
                         let (s_expr, mut bind_context) = self
                             .bind_base_table(bind_context, database.as_str(), table_index)
                             .await?;
+                        let s_expr = self
+                            .bind_row_access_policy(&bind_context, table_meta.as_ref(), s_expr)
+                            .await?;
                         if let Some(alias) = alias {
                             bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
                         }
@@ -329,7 +345,26 @@ impl Binder {
                 params,
                 named_params,
                 alias,
+                ..
             } => {
+                let func_name = normalize_identifier(name, &self.name_resolution_ctx);
+
+                if is_srf_table_function(&func_name.name) {
+                    let (one_expr, _) = self.bind_one_table(bind_context, &vec![]).await?;
+                    let srf_context = BindContext::with_parent(Box::new(bind_context.clone()));
+                    return self
+                        .bind_srf_table_function(
+                            srf_context,
+                            one_expr,
+                            *span,
+                            &func_name.name,
+                            params,
+                            named_params,
+                            alias,
+                        )
+                        .await;
+                }
+
                 let mut scalar_binder = ScalarBinder::new(
                     bind_context,
                     self.ctx.clone(),
@@ -341,8 +376,6 @@ impl Binder {
                 );
                 let table_args = bind_table_args(&mut scalar_binder, params, named_params).await?;
 
-                let func_name = normalize_identifier(name, &self.name_resolution_ctx);
-
                 if func_name.name.eq_ignore_ascii_case("result_scan") {
                     let query_id = parse_result_scan_args(&table_args)?;
                     if query_id.is_empty() {
@@ -416,10 +449,10 @@ impl Binder {
                         hints: None,
                         distinct: false,
                         select_list: vec![SelectTarget::AliasedExpr {
-                            expr: Box::new(common_ast::ast::Expr::FunctionCall {
+                            expr: Box::new(Expr::FunctionCall {
                                 span: *span,
                                 distinct: false,
-                                name: common_ast::ast::Identifier {
+                                name: Identifier {
                                     span: *span,
                                     name: func_name.name.clone(),
                                     quote: None,
@@ -524,6 +557,28 @@ impl Binder {
             TableReference::Join { join, .. } => {
                 let (left_expr, left_bind_ctx) =
                     self.bind_table_reference(bind_context, &join.left).await?;
+                if let TableReference::TableFunction {
+                    span,
+                    lateral: true,
+                    name,
+                    params,
+                    named_params,
+                    alias,
+                } = join.right.as_ref()
+                {
+                    return self
+                        .bind_lateral_table_function(
+                            left_bind_ctx,
+                            left_expr,
+                            join,
+                            *span,
+                            name,
+                            params,
+                            named_params,
+                            alias,
+                        )
+                        .await;
+                }
                 let (right_expr, right_bind_ctx) =
                     self.bind_table_reference(bind_context, &join.right).await?;
                 self.bind_join(
@@ -539,6 +594,56 @@ impl Binder {
         }
     }
 
+    /// Bind `<left> [CROSS | INNER] JOIN LATERAL <srf>(...)`, the set-returning function is
+    /// evaluated for each row of the left side.
+    #[allow(clippy::too_many_arguments)]
+    #[async_backtrace::framed]
+    async fn bind_lateral_table_function(
+        &mut self,
+        left_context: BindContext,
+        left_child: SExpr,
+        join: &Join,
+        span: Span,
+        name: &Identifier,
+        params: &[Expr],
+        named_params: &[(String, Expr)],
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        match (&join.op, &join.condition) {
+            (JoinOperator::CrossJoin, _) | (JoinOperator::Inner, JoinCondition::None) => (),
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "LATERAL table function only supports cross join without join conditions",
+                )
+                .set_span(span));
+            }
+        }
+
+        let func_name = normalize_identifier(name, &self.name_resolution_ctx);
+        let is_srf = BUILTIN_FUNCTIONS
+            .get_property(&func_name.name)
+            .map(|p| p.kind == FunctionKind::SRF)
+            .unwrap_or(false);
+        if !is_srf {
+            return Err(ErrorCode::SemanticError(format!(
+                "LATERAL is only supported for set-returning functions, but got {}",
+                func_name.name
+            ))
+            .set_span(span));
+        }
+
+        self.bind_srf_table_function(
+            left_context,
+            left_child,
+            span,
+            &func_name.name,
+            params,
+            named_params,
+            alias,
+        )
+        .await
+    }
+
     #[async_backtrace::framed]
     pub(crate) async fn bind_stage_table(
         &mut self,
@@ -794,9 +899,13 @@ impl Binder {
             window_definitions: DashMap::new(),
         };
 
-        let (s_expr, mut res_bind_context) = self
-            .bind_query(&mut new_bind_context, &cte_info.query)
-            .await?;
+        let (s_expr, mut res_bind_context) = if cte_info.recursive {
+            self.bind_r_cte_query(&mut new_bind_context, table_name, cte_info)
+                .await?
+        } else {
+            self.bind_query(&mut new_bind_context, &cte_info.query)
+                .await?
+        };
         let mut cols_alias = cte_info.columns_alias.clone();
         if let Some(alias) = alias {
             for (idx, col_alias) in alias.columns.iter().enumerate() {
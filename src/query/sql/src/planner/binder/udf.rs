@@ -12,20 +12,28 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_ast::ast::AlterUDFStmt;
 use common_ast::ast::CreateUDFStmt;
 use common_ast::ast::Identifier;
+use common_ast::ast::Lambda;
 use common_ast::ast::UDFDefinition;
 use common_config::GlobalConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::types::DataType;
 use common_expression::udf_client::UDFFlightClient;
+use common_expression::udf_wasm::check_wasm_udf;
+use common_expression::udf_wasm::decode_wasm_code;
+use common_meta_app::principal::LambdaUDAF;
 use common_meta_app::principal::LambdaUDF;
 use common_meta_app::principal::UDFDefinition as PlanUDFDefinition;
+use common_meta_app::principal::UDFScript;
 use common_meta_app::principal::UDFServer;
 use common_meta_app::principal::UserDefinedFunction;
 
+use crate::normalize_identifier;
 use crate::planner::resolve_type_name;
 use crate::planner::udf_validator::UDFValidator;
 use crate::plans::AlterUDFPlan;
@@ -106,7 +114,112 @@ impl Binder {
                     }),
                 })
             }
+            UDFDefinition::UDFScript {
+                arg_types,
+                return_type,
+                code,
+                handler,
+                language,
+            } => {
+                if !language.eq_ignore_ascii_case("wasm") {
+                    return Err(ErrorCode::Unimplemented(format!(
+                        "Unsupported UDF language '{language}', only wasm is supported"
+                    )));
+                }
+
+                let mut arg_datatypes = Vec::with_capacity(arg_types.len());
+                for arg_type in arg_types {
+                    arg_datatypes.push(DataType::from(&resolve_type_name(arg_type, true)?));
+                }
+                let return_type = DataType::from(&resolve_type_name(return_type, true)?);
+
+                let module = Arc::new(decode_wasm_code(code));
+                check_wasm_udf(&module, handler, &arg_datatypes, &return_type)?;
+
+                Ok(UserDefinedFunction {
+                    name: udf_name.to_string(),
+                    description: udf_description.clone().unwrap_or_default(),
+                    definition: PlanUDFDefinition::UDFScript(UDFScript {
+                        code: code.clone(),
+                        arg_types: arg_datatypes,
+                        return_type,
+                        handler: handler.clone(),
+                        language: language.to_lowercase(),
+                    }),
+                })
+            }
+            UDFDefinition::LambdaUDAF {
+                state_type,
+                init,
+                accumulate,
+                merge,
+                finalize,
+            } => {
+                let state_type = DataType::from(&resolve_type_name(state_type, true)?);
+
+                // The initial state can not refer to any column.
+                let mut validator = UDFValidator {
+                    name: udf_name.to_string(),
+                    ..Default::default()
+                };
+                validator.verify_definition_expr(init)?;
+
+                if accumulate.params.len() < 2 {
+                    return Err(ErrorCode::SyntaxException(
+                        "ACCUMULATE of aggregate function requires the state and at least one argument as parameters",
+                    ));
+                }
+                let accumulate = self.bind_udaf_lambda(udf_name, "ACCUMULATE", accumulate, None)?;
+                let merge = self.bind_udaf_lambda(udf_name, "MERGE", merge, Some(2))?;
+                let finalize = self.bind_udaf_lambda(udf_name, "FINALIZE", finalize, Some(1))?;
+
+                Ok(UserDefinedFunction {
+                    name: udf_name.to_string(),
+                    description: udf_description.clone().unwrap_or_default(),
+                    definition: PlanUDFDefinition::LambdaUDAF(LambdaUDAF {
+                        state_type,
+                        init: init.to_string(),
+                        accumulate,
+                        merge,
+                        finalize,
+                    }),
+                })
+            }
+        }
+    }
+
+    fn bind_udaf_lambda(
+        &self,
+        udf_name: &Identifier,
+        clause: &str,
+        lambda: &Lambda,
+        expected_params: Option<usize>,
+    ) -> Result<LambdaUDF> {
+        if let Some(expected) = expected_params {
+            if lambda.params.len() != expected {
+                return Err(ErrorCode::SyntaxException(format!(
+                    "{clause} of aggregate function requires {expected} parameters, but got: {}",
+                    lambda.params.len()
+                )));
+            }
         }
+
+        let mut validator = UDFValidator {
+            name: udf_name.to_string(),
+            parameters: lambda.params.iter().map(|v| v.to_string()).collect(),
+            allow_unused_parameters: true,
+            ..Default::default()
+        };
+        validator.verify_definition_expr(&lambda.expr)?;
+
+        Ok(LambdaUDF {
+            parameters: lambda
+                .params
+                .iter()
+                .map(|param| normalize_identifier(param, &self.name_resolution_ctx).name)
+                .collect(),
+            definition: lambda.expr.to_string(),
+        })
     }
 
     pub(in crate::planner::binder) async fn bind_create_udf(
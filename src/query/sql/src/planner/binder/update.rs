@@ -65,6 +65,8 @@ impl Binder {
             ));
         };
 
+        self.check_dml_row_access_policy(&catalog_name, &database_name, &table_name)
+            .await?;
         let (table_expr, mut context) = self.bind_single_table(bind_context, table).await?;
 
         let table = self
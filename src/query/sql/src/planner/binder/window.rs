@@ -33,7 +33,7 @@ use crate::plans::LambdaFunc;
 use crate::plans::NthValueFunction;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
-use crate::plans::UDFServerCall;
+use crate::plans::UDFCall;
 use crate::plans::Window;
 use crate::plans::WindowFunc;
 use crate::plans::WindowFuncFrame;
@@ -307,16 +307,16 @@ impl<'a> WindowRewriter<'a> {
                 self.in_window = false;
                 Ok(scalar)
             }
-            ScalarExpr::UDFServerCall(udf) => {
+            ScalarExpr::UDFCall(udf) => {
                 let new_args = udf
                     .arguments
                     .iter()
                     .map(|arg| self.visit(arg))
                     .collect::<Result<Vec<_>>>()?;
-                Ok(UDFServerCall {
+                Ok(UDFCall {
                     span: udf.span,
                     func_name: udf.func_name.clone(),
-                    server_addr: udf.server_addr.clone(),
+                    udf_type: udf.udf_type.clone(),
                     arg_types: udf.arg_types.clone(),
                     return_type: udf.return_type.clone(),
                     arguments: new_args,
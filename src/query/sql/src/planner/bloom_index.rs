@@ -13,9 +13,18 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
 use std::str::FromStr;
 
+use common_ast::ast::ColumnID;
+use common_ast::ast::Expr;
+use common_ast::ast::JsonOperator;
+use common_ast::ast::Literal;
+use common_ast::ast::MapAccessor;
+use common_ast::parser::parse_comma_separated_exprs;
 use common_ast::parser::parse_comma_separated_idents;
+use common_ast::parser::quote::quote_ident;
 use common_ast::parser::tokenize_sql;
 use common_ast::Dialect;
 use common_exception::ErrorCode;
@@ -24,6 +33,7 @@ use common_expression::ComputedExpr;
 use common_expression::FieldIndex;
 use common_expression::TableDataType;
 use common_expression::TableField;
+use common_expression::TableSchema;
 use common_expression::TableSchemaRef;
 use common_settings::Settings;
 
@@ -144,3 +154,268 @@ impl BloomIndexColumns {
         Ok(fields_map)
     }
 }
+
+/// A json path of a variant column or a key of a map column, like `data['user_id']`.
+/// The secondary bloom filter is built on the values extracted by the path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BloomIndexPath {
+    pub column: String,
+    pub keys: Vec<String>,
+}
+
+impl BloomIndexPath {
+    /// Extract the path from expressions like `col['a']['b']`, `col:a:b` or `col->'a'`.
+    pub fn try_from_expr(expr: &Expr, name_resolution_ctx: &NameResolutionContext) -> Result<Self> {
+        let mut path = Self::extract_path(expr, name_resolution_ctx)?;
+        if path.keys.is_empty() {
+            return Err(ErrorCode::SemanticError(format!(
+                "bloom index path `{}` must access a json path or a map key of the column",
+                expr
+            )));
+        }
+        path.keys.shrink_to_fit();
+        Ok(path)
+    }
+
+    fn extract_path(expr: &Expr, name_resolution_ctx: &NameResolutionContext) -> Result<Self> {
+        let (inner, key) = match expr {
+            Expr::ColumnRef {
+                database: None,
+                table: None,
+                column: ColumnID::Name(ident),
+                ..
+            } => {
+                return Ok(BloomIndexPath {
+                    column: normalize_identifier(ident, name_resolution_ctx).name,
+                    keys: vec![],
+                });
+            }
+            Expr::MapAccess {
+                expr: inner,
+                accessor,
+                ..
+            } => match accessor {
+                MapAccessor::Bracket {
+                    key:
+                        box Expr::Literal {
+                            lit: Literal::String(key),
+                            ..
+                        },
+                } => (inner, key.clone()),
+                MapAccessor::Dot { key } | MapAccessor::Colon { key } => (inner, key.name.clone()),
+                _ => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "bloom index path `{}` only supports string keys",
+                        expr
+                    )));
+                }
+            },
+            Expr::JsonOp {
+                op: JsonOperator::Arrow,
+                left,
+                right:
+                    box Expr::Literal {
+                        lit: Literal::String(key),
+                        ..
+                    },
+                ..
+            } => (left, key.clone()),
+            _ => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "unsupported bloom index path `{}`, expect paths like `col['key']`",
+                    expr
+                )));
+            }
+        };
+        let mut path = Self::extract_path(inner, name_resolution_ctx)?;
+        path.keys.push(key);
+        Ok(path)
+    }
+}
+
+impl Display for BloomIndexPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", quote_ident(&self.column, '"', false))?;
+        for key in &self.keys {
+            write!(f, "[{}]", Literal::String(key.clone()))?;
+        }
+        Ok(())
+    }
+}
+
+/// Secondary bloom indexes declared by `CREATE INDEX <name> ON <table> (<path>, ...)`.
+///
+/// They are kept in the table option `bloom_index_paths` as `<name>(<path>, ...), ...`.
+#[derive(Clone, Debug, Default)]
+pub struct BloomIndexPaths {
+    pub indexes: Vec<(String, Vec<BloomIndexPath>)>,
+}
+
+impl FromStr for BloomIndexPaths {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Ok(BloomIndexPaths::default());
+        }
+
+        let sql_dialect = Dialect::PostgreSQL;
+        let tokens = tokenize_sql(s)?;
+        let exprs = parse_comma_separated_exprs(&tokens, sql_dialect)?;
+
+        let settings = Settings::create("".to_string());
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+
+        let mut indexes = Vec::with_capacity(exprs.len());
+        for expr in exprs.iter() {
+            match expr {
+                Expr::FunctionCall { name, args, .. } => {
+                    let paths = args
+                        .iter()
+                        .map(|arg| BloomIndexPath::try_from_expr(arg, &name_resolution_ctx))
+                        .collect::<Result<Vec<_>>>()?;
+                    indexes.push((name.name.clone(), paths));
+                }
+                _ => {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "invalid bloom index paths definition `{}`",
+                        expr
+                    )));
+                }
+            }
+        }
+        Ok(BloomIndexPaths { indexes })
+    }
+}
+
+impl Display for BloomIndexPaths {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, (name, paths)) in self.indexes.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}(", quote_ident(name, '"', false))?;
+            for (j, path) in paths.iter().enumerate() {
+                if j > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{path}")?;
+            }
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+impl BloomIndexPaths {
+    pub fn is_empty(&self) -> bool {
+        self.indexes.is_empty()
+    }
+
+    pub fn contains_index(&self, name: &str) -> bool {
+        self.indexes.iter().any(|(index, _)| index == name)
+    }
+
+    /// Verify the definition based on schema.
+    pub fn verify_definition<F>(
+        definition: &str,
+        schema: TableSchemaRef,
+        verify_type: F,
+    ) -> Result<()>
+    where
+        F: Fn(&TableDataType) -> bool,
+    {
+        let index_paths = definition.parse::<BloomIndexPaths>()?;
+        for (_, paths) in index_paths.indexes.iter() {
+            for path in paths {
+                Self::verify_path(path, &schema, &verify_type)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Verify the path based on schema, returns the index and the field of the path column.
+    ///
+    /// The path must access a variant column, or a map column with string keys
+    /// whose values can be indexed.
+    pub fn verify_path<F>(
+        path: &BloomIndexPath,
+        schema: &TableSchema,
+        verify_type: F,
+    ) -> Result<(FieldIndex, TableField)>
+    where
+        F: Fn(&TableDataType) -> bool,
+    {
+        let field_index = schema.index_of(&path.column)?;
+        let field = schema.fields[field_index].clone();
+        if matches!(field.computed_expr(), Some(ComputedExpr::Virtual(_))) {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "The value specified for computed column '{}' is not allowed for bloom index",
+                path.column
+            )));
+        }
+
+        match field.data_type().remove_nullable() {
+            TableDataType::Variant => Ok((field_index, field)),
+            TableDataType::Map(box TableDataType::Tuple { fields_type, .. })
+                if path.keys.len() == 1
+                    && fields_type[0].remove_nullable() == TableDataType::String
+                    && verify_type(&fields_type[1]) =>
+            {
+                Ok((field_index, field))
+            }
+            data_type => Err(ErrorCode::TableOptionInvalid(format!(
+                "Unsupported bloom index path `{}` on data type '{}'",
+                path, data_type
+            ))),
+        }
+    }
+
+    /// Get the paths to be indexed, with the index and the field of the path column.
+    ///
+    /// The paths that are no longer valid for the schema are ignored.
+    pub fn bloom_index_path_fields<F>(
+        &self,
+        schema: TableSchemaRef,
+        verify_type: F,
+    ) -> Vec<(FieldIndex, TableField, Vec<String>)>
+    where
+        F: Fn(&TableDataType) -> bool,
+    {
+        let source_schema = schema.remove_virtual_computed_fields();
+        let mut path_fields: Vec<(FieldIndex, TableField, Vec<String>)> = vec![];
+        for (_, paths) in self.indexes.iter() {
+            for path in paths {
+                if let Ok((field_index, field)) =
+                    Self::verify_path(path, &source_schema, &verify_type)
+                {
+                    if !path_fields
+                        .iter()
+                        .any(|(i, _, keys)| *i == field_index && *keys == path.keys)
+                    {
+                        path_fields.push((field_index, field, path.keys.clone()));
+                    }
+                }
+            }
+        }
+        path_fields
+    }
+
+    /// Remove the paths of the dropped column, and the indexes left with no paths.
+    pub fn drop_column(&mut self, column: &str) {
+        for (_, paths) in self.indexes.iter_mut() {
+            paths.retain(|path| path.column != column);
+        }
+        self.indexes.retain(|(_, paths)| !paths.is_empty());
+    }
+
+    pub fn rename_column(&mut self, old_column: &str, new_column: &str) {
+        for (_, paths) in self.indexes.iter_mut() {
+            for path in paths.iter_mut() {
+                if path.column == old_column {
+                    path.column = new_column.to_string();
+                }
+            }
+        }
+    }
+}
@@ -359,32 +359,43 @@ pub fn parse_lambda_expr(
     column_name: &str,
     data_type: &DataType,
     ast: &AExpr,
+) -> Result<Box<(ScalarExpr, DataType)>> {
+    parse_lambda_params_expr(ctx, &[(column_name.to_string(), data_type.clone())], ast)
+}
+
+/// Resolve the body of a lambda, the `i`-th parameter is bound to the column with index `i`.
+pub fn parse_lambda_params_expr(
+    ctx: Arc<dyn TableContext>,
+    params: &[(String, DataType)],
+    ast: &AExpr,
 ) -> Result<Box<(ScalarExpr, DataType)>> {
     let settings = Settings::create("".to_string());
     let mut bind_context = BindContext::new();
     let mut metadata = Metadata::default();
 
     bind_context.set_expr_context(ExprContext::InLambdaFunction);
-    bind_context.add_column_binding(
-        ColumnBindingBuilder::new(
+    for (index, (column_name, data_type)) in params.iter().enumerate() {
+        bind_context.add_column_binding(
+            ColumnBindingBuilder::new(
+                column_name.to_string(),
+                index,
+                Box::new(data_type.clone()),
+                Visibility::Visible,
+            )
+            .build(),
+        );
+
+        let table_type = infer_schema_type(data_type)?;
+        metadata.add_base_table_column(
             column_name.to_string(),
+            table_type,
             0,
-            Box::new(data_type.clone()),
-            Visibility::Visible,
-        )
-        .build(),
-    );
-
-    let table_type = infer_schema_type(data_type)?;
-    metadata.add_base_table_column(
-        column_name.to_string(),
-        table_type,
-        0,
-        None,
-        None,
-        None,
-        None,
-    );
+            None,
+            None,
+            None,
+            None,
+        );
+    }
 
     let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
     let mut type_checker = TypeChecker::new(
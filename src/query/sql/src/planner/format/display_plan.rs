@@ -89,6 +89,7 @@ impl Plan {
             }
             Plan::ReclusterTable(recluster_table) => Ok(format!("{:?}", recluster_table)),
             Plan::TruncateTable(truncate_table) => Ok(format!("{:?}", truncate_table)),
+            Plan::DropTableCache(drop_table_cache) => Ok(format!("{:?}", drop_table_cache)),
             Plan::OptimizeTable(optimize_table) => Ok(format!("{:?}", optimize_table)),
             Plan::VacuumTable(vacuum_table) => Ok(format!("{:?}", vacuum_table)),
             Plan::VacuumDropTable(vacuum_drop_table) => Ok(format!("{:?}", vacuum_drop_table)),
@@ -99,10 +100,22 @@ impl Plan {
             Plan::CreateView(create_view) => Ok(format!("{:?}", create_view)),
             Plan::AlterView(alter_view) => Ok(format!("{:?}", alter_view)),
             Plan::DropView(drop_view) => Ok(format!("{:?}", drop_view)),
+            Plan::CreateMaterializedView(create_materialized_view) => {
+                Ok(format!("{:?}", create_materialized_view))
+            }
+            Plan::RefreshMaterializedView(refresh_materialized_view) => {
+                Ok(format!("{:?}", refresh_materialized_view))
+            }
+
+            // Streams
+            Plan::CreateStream(create_stream) => Ok(format!("{:?}", create_stream)),
+            Plan::DropStream(drop_stream) => Ok(format!("{:?}", drop_stream)),
 
             // Indexes
             Plan::CreateIndex(index) => Ok(format!("{:?}", index)),
             Plan::DropIndex(index) => Ok(format!("{:?}", index)),
+            Plan::CreateBloomIndex(index) => Ok(format!("{:?}", index)),
+            Plan::DropBloomIndex(index) => Ok(format!("{:?}", index)),
             Plan::RefreshIndex(index) => Ok(format!("{index:?}")),
 
             // Virtual Columns
@@ -121,6 +134,7 @@ impl Plan {
 
             // Insert
             Plan::Insert(insert) => Ok(format!("{:?}", insert)),
+            Plan::InsertMultiTable(insert) => Ok(format!("{:?}", insert)),
             Plan::Replace(replace) => Ok(format!("{:?}", replace)),
             Plan::MergeInto(merge_into) => Ok(format!("{:?}", merge_into)),
             Plan::Delete(delete) => format_delete(delete),
@@ -154,6 +168,7 @@ impl Plan {
 
             Plan::SetVariable(p) => Ok(format!("{:?}", p)),
             Plan::UnSetVariable(p) => Ok(format!("{:?}", p)),
+            Plan::SetUserVariable(p) => Ok(format!("{:?}", p)),
             Plan::SetRole(p) => Ok(format!("{:?}", p)),
             Plan::UseDatabase(p) => Ok(format!("{:?}", p)),
             Plan::Kill(p) => Ok(format!("{:?}", p)),
@@ -178,6 +193,17 @@ impl Plan {
             Plan::DropDatamaskPolicy(p) => Ok(format!("{:?}", p)),
             Plan::DescDatamaskPolicy(p) => Ok(format!("{:?}", p)),
 
+            // row access policy
+            Plan::CreateRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DescRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::AddTableRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+            Plan::DropTableRowAccessPolicy(p) => Ok(format!("{:?}", p)),
+
+            // sequence
+            Plan::CreateSequence(p) => Ok(format!("{:?}", p)),
+            Plan::DropSequence(p) => Ok(format!("{:?}", p)),
+
             // network policy
             Plan::CreateNetworkPolicy(p) => Ok(format!("{:?}", p)),
             Plan::AlterNetworkPolicy(p) => Ok(format!("{:?}", p)),
@@ -192,6 +218,14 @@ impl Plan {
             Plan::DescribeTask(p) => Ok(format!("{:?}", p)),
             Plan::ExecuteTask(p) => Ok(format!("{:?}", p)),
             Plan::ShowTasks(p) => Ok(format!("{:?}", p)),
+
+            // transaction
+            Plan::Begin => Ok("Begin".to_string()),
+            Plan::Commit => Ok("Commit".to_string()),
+            Plan::Abort => Ok("Abort".to_string()),
+
+            // cache
+            Plan::FlushQueryCache => Ok("FlushQueryCache".to_string()),
         }
     }
 }
@@ -80,6 +80,8 @@ impl Display for FormatContext {
                 RelOperator::MaterializedCte(_) => write!(f, "MaterializedCte"),
                 RelOperator::Lambda(_) => write!(f, "Lambda"),
                 RelOperator::ConstantTableScan(_) => write!(f, "ConstantTableScan"),
+                RelOperator::RecursiveCte(_) => write!(f, "RecursiveCte"),
+                RelOperator::RecursiveCteScan(_) => write!(f, "RecursiveCteScan"),
             },
             Self::Text(text) => write!(f, "{}", text),
         }
@@ -124,7 +126,7 @@ pub fn format_scalar(scalar: &ScalarExpr) -> String {
             )
         }
         ScalarExpr::SubqueryExpr(_) => "SUBQUERY".to_string(),
-        ScalarExpr::UDFServerCall(udf) => {
+        ScalarExpr::UDFCall(udf) => {
             format!(
                 "{}({})",
                 &udf.func_name,
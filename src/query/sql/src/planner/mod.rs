@@ -36,6 +36,8 @@ pub use binder::ScalarVisitor;
 pub use binder::SelectBuilder;
 pub use binder::Visibility;
 pub use bloom_index::BloomIndexColumns;
+pub use bloom_index::BloomIndexPath;
+pub use bloom_index::BloomIndexPaths;
 pub use expression_parser::*;
 pub use format::format_scalar;
 pub use metadata::*;
@@ -42,9 +42,12 @@ fn compute_cost_impl(memo: &Memo, m_expr: &MExpr) -> Result<Cost> {
         RelOperator::Scan(plan) => compute_cost_scan(memo, m_expr, plan),
         RelOperator::DummyTableScan(_)
         | RelOperator::CteScan(_)
+        | RelOperator::RecursiveCteScan(_)
         | RelOperator::ConstantTableScan(_) => Ok(Cost(0.0)),
         RelOperator::Join(plan) => compute_cost_join(memo, m_expr, plan),
-        RelOperator::UnionAll(_) => compute_cost_union_all(memo, m_expr),
+        RelOperator::UnionAll(_) | RelOperator::RecursiveCte(_) => {
+            compute_cost_union_all(memo, m_expr)
+        }
         RelOperator::Aggregate(_) => compute_aggregate(memo, m_expr),
         RelOperator::MaterializedCte(_) => compute_materialized_cte(memo, m_expr),
 
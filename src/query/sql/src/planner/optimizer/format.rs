@@ -53,6 +53,8 @@ pub fn display_rel_op(rel_op: &RelOperator) -> String {
         RelOperator::MaterializedCte(_) => "MaterializedCte".to_string(),
         RelOperator::Lambda(_) => "LambdaFunc".to_string(),
         RelOperator::ConstantTableScan(_) => "ConstantTableScan".to_string(),
+        RelOperator::RecursiveCte(_) => "RecursiveCte".to_string(),
+        RelOperator::RecursiveCteScan(_) => "RecursiveCteScan".to_string(),
     }
 }
 
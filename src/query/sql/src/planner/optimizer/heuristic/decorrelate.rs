@@ -48,7 +48,7 @@ use crate::plans::ScalarItem;
 use crate::plans::Scan;
 use crate::plans::SubqueryExpr;
 use crate::plans::SubqueryType;
-use crate::plans::UDFServerCall;
+use crate::plans::UDFCall;
 use crate::BaseTableColumn;
 use crate::ColumnEntry;
 use crate::DerivedColumn;
@@ -287,8 +287,13 @@ impl SubqueryRewriter {
         match subquery.typ {
             SubqueryType::Scalar => {
                 let correlated_columns = subquery.outer_columns.clone();
-                let flatten_plan =
-                    self.flatten(&subquery.subquery, &correlated_columns, flatten_info, false)?;
+                let flatten_plan = self.flatten(
+                    left,
+                    &subquery.subquery,
+                    &correlated_columns,
+                    flatten_info,
+                    false,
+                )?;
                 // Construct single join
                 let mut left_conditions = Vec::with_capacity(correlated_columns.len());
                 let mut right_conditions = Vec::with_capacity(correlated_columns.len());
@@ -321,8 +326,13 @@ impl SubqueryRewriter {
                     }
                 }
                 let correlated_columns = subquery.outer_columns.clone();
-                let flatten_plan =
-                    self.flatten(&subquery.subquery, &correlated_columns, flatten_info, false)?;
+                let flatten_plan = self.flatten(
+                    left,
+                    &subquery.subquery,
+                    &correlated_columns,
+                    flatten_info,
+                    false,
+                )?;
                 // Construct mark join
                 let mut left_conditions = Vec::with_capacity(correlated_columns.len());
                 let mut right_conditions = Vec::with_capacity(correlated_columns.len());
@@ -359,8 +369,13 @@ impl SubqueryRewriter {
             }
             SubqueryType::Any => {
                 let correlated_columns = subquery.outer_columns.clone();
-                let flatten_plan =
-                    self.flatten(&subquery.subquery, &correlated_columns, flatten_info, false)?;
+                let flatten_plan = self.flatten(
+                    left,
+                    &subquery.subquery,
+                    &correlated_columns,
+                    flatten_info,
+                    false,
+                )?;
                 let mut left_conditions = Vec::with_capacity(correlated_columns.len());
                 let mut right_conditions = Vec::with_capacity(correlated_columns.len());
                 self.add_equi_conditions(
@@ -424,6 +439,7 @@ impl SubqueryRewriter {
 
     fn flatten(
         &mut self,
+        outer: &SExpr,
         plan: &SExpr,
         correlated_columns: &ColumnSet,
         flatten_info: &mut FlattenInfo,
@@ -435,65 +451,9 @@ impl SubqueryRewriter {
             if !need_cross_join {
                 return Ok(plan.clone());
             }
-            // Construct a LogicalGet plan by correlated columns.
-            // Finally generate a cross join, so we finish flattening the subquery.
-            let mut metadata = self.metadata.write();
-            // Currently, we don't support left plan's from clause contains subquery.
-            // Such as: select t2.a from (select a + 1 as a from t) as t2 where (select sum(a) from t as t1 where t1.a < t2.a) = 1;
-            let table_index = metadata
-                .table_index_by_column_indexes(correlated_columns)
-                .unwrap();
-            let mut data_types = Vec::with_capacity(correlated_columns.len());
-            for correlated_column in correlated_columns.iter() {
-                let column_entry = metadata.column(*correlated_column).clone();
-                let name = column_entry.name();
-                let data_type = column_entry.data_type();
-                data_types.push(data_type.clone());
-                self.derived_columns.insert(
-                    *correlated_column,
-                    metadata.add_derived_column(name.to_string(), data_type),
-                );
-            }
-            let logical_get = SExpr::create_leaf(Arc::new(
-                Scan {
-                    table_index,
-                    columns: self.derived_columns.values().cloned().collect(),
-                    ..Default::default()
-                }
-                .into(),
-            ));
-            // Wrap logical get with distinct to eliminate duplicates rows.
-            let mut group_items = Vec::with_capacity(self.derived_columns.len());
-            for (index, column_index) in self.derived_columns.values().cloned().enumerate() {
-                group_items.push(ScalarItem {
-                    scalar: ScalarExpr::BoundColumnRef(BoundColumnRef {
-                        span: None,
-                        column: ColumnBindingBuilder::new(
-                            "".to_string(),
-                            column_index,
-                            Box::new(data_types[index].clone()),
-                            Visibility::Visible,
-                        )
-                        .table_index(Some(table_index))
-                        .build(),
-                    }),
-                    index: column_index,
-                });
-            }
-            let duplicate_delete_get = SExpr::create_unary(
-                Arc::new(
-                    Aggregate {
-                        mode: AggregateMode::Initial,
-                        group_items,
-                        aggregate_functions: vec![],
-                        from_distinct: false,
-                        limit: None,
-                        grouping_sets: None,
-                    }
-                    .into(),
-                ),
-                Arc::new(logical_get),
-            );
+            // Construct a plan which produces the distinct values of correlated columns,
+            // then generate a cross join, so we finish flattening the subquery.
+            let domain = self.build_correlated_domain(outer, correlated_columns)?;
             let cross_join = Join {
                 left_conditions: vec![],
                 right_conditions: vec![],
@@ -506,7 +466,7 @@ impl SubqueryRewriter {
             .into();
             return Ok(SExpr::create_binary(
                 Arc::new(cross_join),
-                Arc::new(duplicate_delete_get),
+                Arc::new(domain),
                 Arc::new(plan.clone()),
             ));
         }
@@ -521,6 +481,7 @@ impl SubqueryRewriter {
                     need_cross_join = true;
                 }
                 let flatten_plan = self.flatten(
+                    outer,
                     plan.child(0)?,
                     correlated_columns,
                     flatten_info,
@@ -566,6 +527,7 @@ impl SubqueryRewriter {
                     }
                 }
                 let flatten_plan = self.flatten(
+                    outer,
                     plan.child(0)?,
                     correlated_columns,
                     flatten_info,
@@ -591,12 +553,14 @@ impl SubqueryRewriter {
                     need_cross_join = true;
                 }
                 let left_flatten_plan = self.flatten(
+                    outer,
                     plan.child(0)?,
                     correlated_columns,
                     flatten_info,
                     need_cross_join,
                 )?;
                 let right_flatten_plan = self.flatten(
+                    outer,
                     plan.child(1)?,
                     correlated_columns,
                     flatten_info,
@@ -628,6 +592,7 @@ impl SubqueryRewriter {
                     need_cross_join = true;
                 }
                 let flatten_plan = self.flatten(
+                    outer,
                     plan.child(0)?,
                     correlated_columns,
                     flatten_info,
@@ -715,6 +680,7 @@ impl SubqueryRewriter {
             RelOperator::Sort(_) => {
                 // Currently, we don't support sort contain subquery.
                 let flatten_plan = self.flatten(
+                    outer,
                     plan.child(0)?,
                     correlated_columns,
                     flatten_info,
@@ -729,6 +695,7 @@ impl SubqueryRewriter {
             RelOperator::Limit(_) => {
                 // Currently, we don't support limit contain subquery.
                 let flatten_plan = self.flatten(
+                    outer,
                     plan.child(0)?,
                     correlated_columns,
                     flatten_info,
@@ -749,12 +716,14 @@ impl SubqueryRewriter {
                     need_cross_join = true;
                 }
                 let left_flatten_plan = self.flatten(
+                    outer,
                     plan.child(0)?,
                     correlated_columns,
                     flatten_info,
                     need_cross_join,
                 )?;
                 let right_flatten_plan = self.flatten(
+                    outer,
                     plan.child(1)?,
                     correlated_columns,
                     flatten_info,
@@ -835,16 +804,16 @@ impl SubqueryRewriter {
                     target_type: cast_expr.target_type.clone(),
                 }))
             }
-            ScalarExpr::UDFServerCall(udf) => {
+            ScalarExpr::UDFCall(udf) => {
                 let arguments = udf
                     .arguments
                     .iter()
                     .map(|arg| self.flatten_scalar(arg, correlated_columns))
                     .collect::<Result<Vec<_>>>()?;
-                Ok(ScalarExpr::UDFServerCall(UDFServerCall {
+                Ok(ScalarExpr::UDFCall(UDFCall {
                     span: udf.span,
                     func_name: udf.func_name.clone(),
-                    server_addr: udf.server_addr.clone(),
+                    udf_type: udf.udf_type.clone(),
                     arg_types: udf.arg_types.clone(),
                     return_type: udf.return_type.clone(),
                     arguments,
@@ -856,6 +825,99 @@ impl SubqueryRewriter {
         }
     }
 
+    // Build the distinct values of correlated columns, they are mapped to derived columns.
+    // If all the correlated columns come from the same base table, scan that table directly,
+    // otherwise (e.g. the outer query reads from a derived table or a join), project them from
+    // the outer plan.
+    fn build_correlated_domain(
+        &mut self,
+        outer: &SExpr,
+        correlated_columns: &ColumnSet,
+    ) -> Result<SExpr> {
+        let mut metadata = self.metadata.write();
+        let mut table_index = None;
+        for correlated_column in correlated_columns.iter() {
+            match metadata.column(*correlated_column) {
+                ColumnEntry::BaseTableColumn(BaseTableColumn {
+                    table_index: index, ..
+                }) if table_index.map_or(true, |t| t == *index) => table_index = Some(*index),
+                _ => {
+                    table_index = None;
+                    break;
+                }
+            }
+        }
+
+        let mut items = Vec::with_capacity(correlated_columns.len());
+        let mut group_items = Vec::with_capacity(correlated_columns.len());
+        for correlated_column in correlated_columns.iter() {
+            let column_entry = metadata.column(*correlated_column).clone();
+            let name = column_entry.name();
+            let data_type = column_entry.data_type();
+            let derived_column = metadata.add_derived_column(name.clone(), data_type.clone());
+            self.derived_columns
+                .insert(*correlated_column, derived_column);
+            items.push(ScalarItem {
+                scalar: ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span: None,
+                    column: ColumnBindingBuilder::new(
+                        name,
+                        *correlated_column,
+                        Box::new(data_type.clone()),
+                        Visibility::Visible,
+                    )
+                    .build(),
+                }),
+                index: derived_column,
+            });
+            group_items.push(ScalarItem {
+                scalar: ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span: None,
+                    column: ColumnBindingBuilder::new(
+                        "".to_string(),
+                        derived_column,
+                        Box::new(data_type),
+                        Visibility::Visible,
+                    )
+                    .table_index(table_index)
+                    .build(),
+                }),
+                index: derived_column,
+            });
+        }
+
+        let input = match table_index {
+            Some(table_index) => SExpr::create_leaf(Arc::new(
+                Scan {
+                    table_index,
+                    columns: group_items.iter().map(|item| item.index).collect(),
+                    ..Default::default()
+                }
+                .into(),
+            )),
+            None => SExpr::create_unary(
+                Arc::new(EvalScalar { items }.into()),
+                Arc::new(outer.clone()),
+            ),
+        };
+
+        // Wrap the input with distinct to eliminate duplicates rows.
+        Ok(SExpr::create_unary(
+            Arc::new(
+                Aggregate {
+                    mode: AggregateMode::Initial,
+                    group_items,
+                    aggregate_functions: vec![],
+                    from_distinct: false,
+                    limit: None,
+                    grouping_sets: None,
+                }
+                .into(),
+            ),
+            Arc::new(input),
+        ))
+    }
+
     fn add_equi_conditions(
         &self,
         span: Span,
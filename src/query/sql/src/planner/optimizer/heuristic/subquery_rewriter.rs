@@ -47,7 +47,7 @@ use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::SubqueryExpr;
 use crate::plans::SubqueryType;
-use crate::plans::UDFServerCall;
+use crate::plans::UDFCall;
 use crate::plans::WindowFuncType;
 use crate::IndexType;
 use crate::MetadataRef;
@@ -156,13 +156,14 @@ impl SubqueryRewriter {
                 Ok(SExpr::create_unary(Arc::new(plan.into()), Arc::new(input)))
             }
 
-            RelOperator::Join(_) | RelOperator::UnionAll(_) | RelOperator::MaterializedCte(_) => {
-                Ok(SExpr::create_binary(
-                    Arc::new(s_expr.plan().clone()),
-                    Arc::new(self.rewrite(s_expr.child(0)?)?),
-                    Arc::new(self.rewrite(s_expr.child(1)?)?),
-                ))
-            }
+            RelOperator::Join(_)
+            | RelOperator::UnionAll(_)
+            | RelOperator::MaterializedCte(_)
+            | RelOperator::RecursiveCte(_) => Ok(SExpr::create_binary(
+                Arc::new(s_expr.plan().clone()),
+                Arc::new(self.rewrite(s_expr.child(0)?)?),
+                Arc::new(self.rewrite(s_expr.child(1)?)?),
+            )),
 
             RelOperator::Limit(_) | RelOperator::Sort(_) => Ok(SExpr::create_unary(
                 Arc::new(s_expr.plan().clone()),
@@ -172,7 +173,8 @@ impl SubqueryRewriter {
             RelOperator::DummyTableScan(_)
             | RelOperator::Scan(_)
             | RelOperator::CteScan(_)
-            | RelOperator::ConstantTableScan(_) => Ok(s_expr.clone()),
+            | RelOperator::ConstantTableScan(_)
+            | RelOperator::RecursiveCteScan(_) => Ok(s_expr.clone()),
 
             _ => Err(ErrorCode::Internal("Invalid plan type")),
         }
@@ -340,7 +342,7 @@ impl SubqueryRewriter {
 
                 Ok((scalar, s_expr))
             }
-            ScalarExpr::UDFServerCall(udf) => {
+            ScalarExpr::UDFCall(udf) => {
                 let mut args = vec![];
                 let mut s_expr = s_expr.clone();
                 for arg in udf.arguments.iter() {
@@ -349,10 +351,10 @@ impl SubqueryRewriter {
                     args.push(res.0);
                 }
 
-                let expr: ScalarExpr = UDFServerCall {
+                let expr: ScalarExpr = UDFCall {
                     span: udf.span,
                     func_name: udf.func_name.clone(),
-                    server_addr: udf.server_addr.clone(),
+                    udf_type: udf.udf_type.clone(),
                     arg_types: udf.arg_types.clone(),
                     return_type: udf.return_type.clone(),
                     arguments: args,
@@ -38,6 +38,9 @@ use crate::MetadataRef;
 use crate::ScalarExpr;
 
 const RELATION_THRESHOLD: usize = 10;
+// If the number of relations is greater than or equal to this threshold,
+// dphyp will be skipped and the join order will be decided by greedy algorithm.
+const GREEDY_THRESHOLD: usize = 20;
 
 // The join reorder algorithm follows the paper: Dynamic Programming Strikes Back
 // See the paper for more details.
@@ -259,6 +262,8 @@ impl DPhpy {
             | RelOperator::ConstantTableScan(_)
             | RelOperator::CteScan(_)
             | RelOperator::MaterializedCte(_) => Ok((s_expr, true)),
+            // Skip join reordering for the plans with recursive cte.
+            RelOperator::RecursiveCte(_) | RelOperator::RecursiveCteScan(_) => Ok((s_expr, false)),
         }
     }
 
@@ -322,11 +327,23 @@ impl DPhpy {
         for (_, neighbors) in self.query_graph.cached_neighbors.iter_mut() {
             neighbors.sort();
         }
-        let optimized = self.solve()?;
+        let mut optimized = if self.join_relations.len() >= GREEDY_THRESHOLD {
+            self.solve_greedily()?
+        } else {
+            self.solve()?
+        };
         // Get all join relations in `relation_set_tree`
         let all_relations = self
             .relation_set_tree
             .get_relation_set(&(0..self.join_relations.len()).collect())?;
+        if optimized
+            && !self.dp_table.contains_key(&all_relations)
+            && (RELATION_THRESHOLD..GREEDY_THRESHOLD).contains(&self.join_relations.len())
+        {
+            // The search space of dphyp has been pruned, which may miss the complete plan.
+            // Fall back to greedy algorithm.
+            optimized = self.solve_greedily()?;
+        }
         if optimized {
             if let Some(final_plan) = self.dp_table.get(&all_relations) {
                 self.join_reorder(final_plan, &s_expr)
@@ -339,9 +356,8 @@ impl DPhpy {
         }
     }
 
-    // This method will run dynamic programming algorithm to find the optimal join order
-    fn solve(&mut self) -> Result<bool> {
-        // Initial `dp_table` with plan for single relation
+    // Initial `dp_table` with plan for single relation
+    fn init_dp_table(&mut self) -> Result<()> {
         for (idx, relation) in self.join_relations.iter().enumerate() {
             // Get nodes  in `relation_set_tree`
             let nodes = self.relation_set_tree.get_relation_set_by_index(idx)?;
@@ -357,6 +373,12 @@ impl DPhpy {
             };
             let _ = self.dp_table.insert(nodes, join);
         }
+        Ok(())
+    }
+
+    // This method will run dynamic programming algorithm to find the optimal join order
+    fn solve(&mut self) -> Result<bool> {
+        self.init_dp_table()?;
 
         // Choose all nodes as enumeration start node once (desc order)
         for idx in (0..self.join_relations.len()).rev() {
@@ -378,6 +400,50 @@ impl DPhpy {
         Ok(true)
     }
 
+    // This method will run greedy operator ordering algorithm to find a join order,
+    // it's used when there are too many relations to run dynamic programming.
+    // Each round joins the two connected relation sets with the lowest cost,
+    // until all relations are joined or no connected relation sets are left.
+    fn solve_greedily(&mut self) -> Result<bool> {
+        self.init_dp_table()?;
+
+        let mut relation_sets = Vec::with_capacity(self.join_relations.len());
+        for idx in 0..self.join_relations.len() {
+            relation_sets.push(self.relation_set_tree.get_relation_set_by_index(idx)?);
+        }
+        while relation_sets.len() > 1 {
+            // (left index, right index, cost)
+            let mut best_pair: Option<(usize, usize, f64)> = None;
+            for i in 0..relation_sets.len() {
+                for j in (i + 1)..relation_sets.len() {
+                    let join_conditions = self
+                        .query_graph
+                        .is_connected(&relation_sets[i], &relation_sets[j])?;
+                    if join_conditions.is_empty() {
+                        continue;
+                    }
+                    if !self.emit_csg_cmp(&relation_sets[i], &relation_sets[j], join_conditions)? {
+                        return Ok(false);
+                    }
+                    let parent_set = union(&relation_sets[i], &relation_sets[j]);
+                    let cost = self.dp_table[&parent_set].cost;
+                    if best_pair.map_or(true, |(_, _, best_cost)| cost < best_cost) {
+                        best_pair = Some((i, j, cost));
+                    }
+                }
+            }
+            let Some((i, j, _)) = best_pair else {
+                // Maybe exist cross join, which make graph disconnected
+                break;
+            };
+            // `j` is always greater than `i`, so remove `j` first.
+            let right = relation_sets.remove(j);
+            let left = relation_sets.remove(i);
+            relation_sets.push(union(&left, &right));
+        }
+        Ok(true)
+    }
+
     // EmitCsg will take a non-empty subset of hyper_graph's nodes(V) which contains a connected subgraph.
     // Then it will possibly generate a connected complement which will combine `nodes` to be a csg-cmp-pair.
     fn emit_csg(&mut self, nodes: &[IndexType]) -> Result<bool> {
@@ -116,8 +116,10 @@ pub fn optimize(
             }))
         }
         Plan::CopyIntoTable(mut plan) if !plan.no_file_to_copy => {
+            // The kafka topic is always consumed by the local node.
             plan.enable_distributed = opt_ctx.config.enable_distributed_optimization
-                && ctx.get_settings().get_enable_distributed_copy()?;
+                && ctx.get_settings().get_enable_distributed_copy()?
+                && plan.kafka_info.is_none();
             info!(
                 "after optimization enable_distributed_copy? : {}",
                 plan.enable_distributed
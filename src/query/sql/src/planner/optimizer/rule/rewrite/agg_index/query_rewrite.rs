@@ -38,7 +38,7 @@ use crate::plans::EvalScalar;
 use crate::plans::FunctionCall;
 use crate::plans::RelOperator;
 use crate::plans::ScalarItem;
-use crate::plans::UDFServerCall;
+use crate::plans::UDFCall;
 use crate::ColumnEntry;
 use crate::ColumnSet;
 use crate::IndexType;
@@ -361,7 +361,7 @@ fn rewrite_scalar_index(
         ScalarExpr::CastExpr(cast) => {
             rewrite_scalar_index(table_index, columns, &mut cast.argument);
         }
-        ScalarExpr::UDFServerCall(udf) => {
+        ScalarExpr::UDFCall(udf) => {
             udf.arguments
                 .iter_mut()
                 .for_each(|arg| rewrite_scalar_index(table_index, columns, arg));
@@ -713,7 +713,7 @@ impl RewriteInfomartion<'_> {
                         .join(", ")
                 )
             }
-            ScalarExpr::UDFServerCall(udf) => format!(
+            ScalarExpr::UDFCall(udf) => format!(
                 "{}({})",
                 &udf.func_name,
                 udf.arguments
@@ -1009,17 +1009,17 @@ fn rewrite_query_item(
                 .into(),
             )
         }
-        ScalarExpr::UDFServerCall(udf) => {
+        ScalarExpr::UDFCall(udf) => {
             let mut new_args = Vec::with_capacity(udf.arguments.len());
             for arg in udf.arguments.iter() {
                 let new_arg = rewrite_by_selection(query_info, arg, index_selection)?;
                 new_args.push(new_arg);
             }
             Some(
-                UDFServerCall {
+                UDFCall {
                     span: udf.span,
                     func_name: udf.func_name.clone(),
-                    server_addr: udf.server_addr.clone(),
+                    udf_type: udf.udf_type.clone(),
                     arg_types: udf.arg_types.clone(),
                     return_type: udf.return_type.clone(),
                     arguments: new_args,
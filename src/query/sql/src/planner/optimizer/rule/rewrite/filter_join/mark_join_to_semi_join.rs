@@ -34,6 +34,7 @@ pub fn convert_mark_to_semi_join(s_expr: &SExpr) -> Result<SExpr> {
 
     let mark_index = join.marker_index.unwrap();
     let mut find_mark_index = false;
+    let mut is_anti = false;
 
     // remove mark index filter
     for (idx, predicate) in filter.predicates.iter().enumerate() {
@@ -44,10 +45,17 @@ pub fn convert_mark_to_semi_join(s_expr: &SExpr) -> Result<SExpr> {
                 break;
             }
             ScalarExpr::FunctionCall(func) if func.func_name == "not" => {
-                // Check if the argument is mark index, if so, we won't convert it to semi join
+                // Check if the argument is mark index, if so, we can only convert it to anti join
+                // if the marker will never be NULL, otherwise keep the NULL-aware mark join.
                 if let ScalarExpr::BoundColumnRef(col) = &func.arguments[0] {
                     if col.column.index == mark_index {
-                        return Ok(s_expr.clone());
+                        if !can_convert_to_anti_join(&join)? {
+                            return Ok(s_expr.clone());
+                        }
+                        find_mark_index = true;
+                        is_anti = true;
+                        filter.predicates.remove(idx);
+                        break;
                     }
                 }
             }
@@ -60,9 +68,11 @@ pub fn convert_mark_to_semi_join(s_expr: &SExpr) -> Result<SExpr> {
         return Ok(s_expr.clone());
     }
 
-    join.join_type = match join.join_type {
-        JoinType::LeftMark => JoinType::RightSemi,
-        JoinType::RightMark => JoinType::LeftSemi,
+    join.join_type = match (&join.join_type, is_anti) {
+        (JoinType::LeftMark, false) => JoinType::RightSemi,
+        (JoinType::RightMark, false) => JoinType::LeftSemi,
+        (JoinType::LeftMark, true) => JoinType::RightAnti,
+        (JoinType::RightMark, true) => JoinType::LeftAnti,
         _ => unreachable!(),
     };
 
@@ -76,3 +86,23 @@ pub fn convert_mark_to_semi_join(s_expr: &SExpr) -> Result<SExpr> {
     result = SExpr::create_unary(Arc::new(filter.into()), Arc::new(result));
     Ok(result)
 }
+
+// `x NOT IN (subquery)` is NULL when `x` is NULL or the subquery contains NULL and there is no match,
+// which an anti join can not express. So only convert if the marker can't be NULL: the join is
+// uncorrelated, has no non-equi conditions and none of the join keys are nullable.
+fn can_convert_to_anti_join(join: &Join) -> Result<bool> {
+    if join.from_correlated_subquery || !join.non_equi_conditions.is_empty() {
+        return Ok(false);
+    }
+    for condition in join
+        .left_conditions
+        .iter()
+        .chain(join.right_conditions.iter())
+    {
+        let data_type = condition.data_type()?;
+        if data_type.is_nullable_or_null() {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
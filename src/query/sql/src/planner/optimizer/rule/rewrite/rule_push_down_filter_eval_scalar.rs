@@ -33,7 +33,7 @@ use crate::plans::PatternPlan;
 use crate::plans::RelOp;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
-use crate::plans::UDFServerCall;
+use crate::plans::UDFCall;
 use crate::plans::WindowFunc;
 use crate::plans::WindowFuncType;
 use crate::plans::WindowOrderBy;
@@ -221,17 +221,17 @@ impl RulePushDownFilterEvalScalar {
                     target_type: cast.target_type.clone(),
                 }))
             }
-            ScalarExpr::UDFServerCall(udf) => {
+            ScalarExpr::UDFCall(udf) => {
                 let arguments = udf
                     .arguments
                     .iter()
                     .map(|arg| Self::replace_predicate(arg, items))
                     .collect::<Result<Vec<ScalarExpr>>>()?;
 
-                Ok(ScalarExpr::UDFServerCall(UDFServerCall {
+                Ok(ScalarExpr::UDFCall(UDFCall {
                     span: udf.span,
                     func_name: udf.func_name.clone(),
-                    server_addr: udf.server_addr.clone(),
+                    udf_type: udf.udf_type.clone(),
                     arg_types: udf.arg_types.clone(),
                     return_type: udf.return_type.clone(),
                     arguments,
@@ -34,7 +34,7 @@ use crate::plans::NthValueFunction;
 use crate::plans::PatternPlan;
 use crate::plans::RelOp;
 use crate::plans::ScalarExpr;
-use crate::plans::UDFServerCall;
+use crate::plans::UDFCall;
 use crate::plans::UnionAll;
 use crate::plans::WindowFunc;
 use crate::plans::WindowFuncType;
@@ -271,17 +271,17 @@ fn replace_column_binding(
         ScalarExpr::SubqueryExpr(_) => Err(ErrorCode::Unimplemented(
             "replace_column_binding: don't support subquery",
         )),
-        ScalarExpr::UDFServerCall(udf) => {
+        ScalarExpr::UDFCall(udf) => {
             let arguments = udf
                 .arguments
                 .into_iter()
                 .map(|arg| replace_column_binding(index_pairs, arg))
                 .collect::<Result<Vec<_>>>()?;
 
-            Ok(ScalarExpr::UDFServerCall(UDFServerCall {
+            Ok(ScalarExpr::UDFCall(UDFCall {
                 span: udf.span,
                 func_name: udf.func_name,
-                server_addr: udf.server_addr,
+                udf_type: udf.udf_type,
                 arg_types: udf.arg_types,
                 return_type: udf.return_type,
                 arguments,
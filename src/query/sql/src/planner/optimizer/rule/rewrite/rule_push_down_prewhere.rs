@@ -89,7 +89,7 @@ impl RulePushDownPrewhere {
                 Self::collect_columns_impl(table_index, schema, cast.argument.as_ref(), columns)?;
             }
             ScalarExpr::ConstantExpr(_) => {}
-            ScalarExpr::UDFServerCall(udf) => {
+            ScalarExpr::UDFCall(udf) => {
                 for arg in udf.arguments.iter() {
                     Self::collect_columns_impl(table_index, schema, arg, columns)?;
                 }
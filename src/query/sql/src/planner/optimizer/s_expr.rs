@@ -254,7 +254,9 @@ fn find_subquery(rel_op: &RelOperator) -> bool {
         | RelOperator::RuntimeFilterSource(_)
         | RelOperator::Pattern(_)
         | RelOperator::MaterializedCte(_)
-        | RelOperator::ConstantTableScan(_) => false,
+        | RelOperator::ConstantTableScan(_)
+        | RelOperator::RecursiveCte(_)
+        | RelOperator::RecursiveCteScan(_) => false,
         RelOperator::Join(op) => {
             op.left_conditions.iter().any(find_subquery_in_expr)
                 || op.right_conditions.iter().any(find_subquery_in_expr)
@@ -314,6 +316,6 @@ fn find_subquery_in_expr(expr: &ScalarExpr) -> bool {
         ScalarExpr::FunctionCall(expr) => expr.arguments.iter().any(find_subquery_in_expr),
         ScalarExpr::CastExpr(expr) => find_subquery_in_expr(&expr.argument),
         ScalarExpr::SubqueryExpr(_) => true,
-        ScalarExpr::UDFServerCall(expr) => expr.arguments.iter().any(find_subquery_in_expr),
+        ScalarExpr::UDFCall(expr) => expr.arguments.iter().any(find_subquery_in_expr),
     }
 }
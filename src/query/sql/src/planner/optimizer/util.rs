@@ -16,15 +16,16 @@ use super::SExpr;
 use crate::plans::RelOperator;
 use crate::MetadataRef;
 
-/// Check if a query will read data from local tables(e.g. system tables).
+/// Check if a query will read data from local tables(e.g. system tables, or the
+/// working table of recursive cte).
 pub fn contains_local_table_scan(s_expr: &SExpr, metadata: &MetadataRef) -> bool {
     s_expr
         .children()
         .iter()
         .any(|s_expr| contains_local_table_scan(s_expr, metadata))
-        || if let RelOperator::Scan(get) = s_expr.plan() {
-            metadata.read().table(get.table_index).table().is_local()
-        } else {
-            false
+        || match s_expr.plan() {
+            RelOperator::Scan(get) => metadata.read().table(get.table_index).table().is_local(),
+            RelOperator::RecursiveCte(_) | RelOperator::RecursiveCteScan(_) => true,
+            _ => false,
         }
 }
@@ -139,6 +139,9 @@ impl Planner {
                 {
                     maybe_partial_insert = true;
                 }
+                if let Ok((Plan::InsertMultiTable(_), _)) = &res {
+                    maybe_partial_insert = true;
+                }
             }
 
             if maybe_partial_insert || (res.is_err() && matches!(tokenizer.peek(), Some(Ok(_)))) {
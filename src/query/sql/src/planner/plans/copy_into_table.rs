@@ -33,6 +33,7 @@ use common_storage::init_stage_operator;
 use common_storage::metrics::copy::metrics_inc_collect_files_get_all_source_files_milliseconds;
 use common_storage::metrics::copy::metrics_inc_filter_out_copied_files_entire_milliseconds;
 use common_storage::StageFileInfo;
+use common_storages_kafka::KafkaInfo;
 use log::info;
 
 use crate::plans::Plan;
@@ -102,6 +103,8 @@ pub struct CopyIntoTablePlan {
 
     pub stage_table_info: StageTableInfo,
     pub query: Option<Box<Plan>>,
+    /// Copy from a kafka topic instead of the files of the stage.
+    pub kafka_info: Option<KafkaInfo>,
 
     pub enable_distributed: bool,
 }
@@ -206,6 +209,7 @@ impl Debug for CopyIntoTablePlan {
             force,
             stage_table_info,
             query,
+            kafka_info,
             ..
         } = self;
         write!(
@@ -215,7 +219,10 @@ impl Debug for CopyIntoTablePlan {
         )?;
         write!(f, ", no_file_to_copy: {no_file_to_copy:?}")?;
         write!(f, ", validation_mode: {validation_mode:?}")?;
-        write!(f, ", from: {stage_table_info:?}")?;
+        match kafka_info {
+            Some(kafka_info) => write!(f, ", from: {kafka_info:?}")?,
+            None => write!(f, ", from: {stage_table_info:?}")?,
+        }
         write!(f, " force: {force}")?;
         write!(f, " is_from: {force}")?;
         write!(f, " query: {query:?}")?;
@@ -19,6 +19,7 @@ use common_meta_types::MetaId;
 use storages_common_table_meta::meta::Location;
 
 use crate::plans::Plan;
+use crate::BloomIndexPath;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateIndexPlan {
@@ -48,3 +49,23 @@ pub struct RefreshIndexPlan {
     pub user_defined_block_name: bool,
     pub segment_locs: Option<Vec<Location>>,
 }
+
+/// Create secondary bloom filters on the json paths or map keys of a table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateBloomIndexPlan {
+    pub if_not_exists: bool,
+    pub index_name: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub paths: Vec<BloomIndexPath>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropBloomIndexPlan {
+    pub if_exists: bool,
+    pub index_name: String,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
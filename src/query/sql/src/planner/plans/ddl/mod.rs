@@ -18,6 +18,7 @@ mod database;
 mod file_format;
 mod index;
 mod stage;
+mod stream;
 mod table;
 mod task;
 mod udf;
@@ -30,6 +31,7 @@ pub use database::*;
 pub use file_format::*;
 pub use index::*;
 pub use stage::*;
+pub use stream::*;
 pub use table::*;
 pub use task::*;
 pub use udf::*;
@@ -44,6 +44,7 @@ pub struct CreateTablePlan {
 
     pub schema: TableSchemaRef,
     pub engine: Engine,
+    pub engine_options: TableOptions,
     pub storage_params: Option<StorageParams>,
     pub read_only_attach: bool,
     pub part_prefix: String,
@@ -333,6 +334,21 @@ impl TruncateTablePlan {
     }
 }
 
+/// Drop the cached objects of a table.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropTableCachePlan {
+    pub catalog: String,
+    pub database: String,
+    /// The table name
+    pub table: String,
+}
+
+impl DropTableCachePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 /// Undrop.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UndropTablePlan {
@@ -41,3 +41,21 @@ pub struct DropViewPlan {
     pub database: String,
     pub view_name: String,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateMaterializedViewPlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+    pub subquery: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshMaterializedViewPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+}
@@ -0,0 +1,68 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_expression::TableSchemaRef;
+use common_meta_types::MetaId;
+
+use super::Plan;
+
+/// `INSERT { ALL | FIRST }`, which inserts the rows of one query into several tables.
+#[derive(Clone)]
+pub struct InsertMultiTable {
+    pub overwrite: bool,
+    pub is_first: bool,
+    /// The query of the source rows. Its output columns are the columns of the source
+    /// rows, followed by a boolean column for the condition of each `WHEN` clause.
+    pub input_source: Plan,
+    /// The targets of the unconditional `INSERT ALL INTO ... INTO ...`.
+    pub intos: Vec<InsertTarget>,
+    /// The targets of each `WHEN` clause, in the order of the clauses.
+    pub whens: Vec<Vec<InsertTarget>>,
+    pub opt_else: Option<Vec<InsertTarget>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct InsertTarget {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub table_id: MetaId,
+    pub schema: TableSchemaRef,
+}
+
+impl InsertMultiTable {
+    /// The number of the columns of the source rows in the output of `input_source`.
+    pub fn num_source_columns(&self) -> usize {
+        self.input_source.schema().num_fields() - self.whens.len()
+    }
+
+    pub fn targets(&self) -> impl Iterator<Item = &InsertTarget> {
+        self.intos
+            .iter()
+            .chain(self.whens.iter().flatten())
+            .chain(self.opt_else.iter().flatten())
+    }
+}
+
+impl std::fmt::Debug for InsertMultiTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InsertMultiTable")
+            .field("overwrite", &self.overwrite)
+            .field("is_first", &self.is_first)
+            .field("intos", &self.intos)
+            .field("whens", &self.whens)
+            .field("opt_else", &self.opt_else)
+            .finish()
+    }
+}
@@ -25,6 +25,7 @@ mod eval_scalar;
 mod exchange;
 mod filter;
 pub mod insert;
+mod insert_multi_table;
 mod join;
 mod kill;
 mod lambda;
@@ -39,11 +40,15 @@ mod plan;
 mod presign;
 mod project_set;
 mod recluster_table;
+mod recursive_cte;
+mod recursive_cte_scan;
 mod replace;
 mod revert_table;
+mod row_access_policy;
 mod runtime_filter_source;
 mod scalar_expr;
 mod scan;
+mod sequence;
 mod setting;
 pub mod share;
 mod sort;
@@ -67,6 +72,8 @@ pub use exchange::*;
 pub use filter::*;
 pub use insert::Insert;
 pub use insert::InsertInputSource;
+pub use insert_multi_table::InsertMultiTable;
+pub use insert_multi_table::InsertTarget;
 pub use join::*;
 pub use kill::KillPlan;
 pub use lambda::*;
@@ -81,12 +88,16 @@ pub use plan::*;
 pub use presign::*;
 pub use project_set::*;
 pub use recluster_table::ReclusterTablePlan;
+pub use recursive_cte::RecursiveCte;
+pub use recursive_cte_scan::RecursiveCteScan;
 pub use replace::Replace;
 pub use revert_table::RevertTablePlan;
+pub use row_access_policy::*;
 pub use runtime_filter_source::RuntimeFilterId;
 pub use runtime_filter_source::RuntimeFilterSource;
 pub use scalar_expr::*;
 pub use scan::*;
+pub use sequence::*;
 pub use setting::*;
 pub use share::*;
 pub use sort::*;
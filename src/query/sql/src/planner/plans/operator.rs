@@ -40,6 +40,8 @@ use crate::plans::CteScan;
 use crate::plans::Exchange;
 use crate::plans::Lambda;
 use crate::plans::ProjectSet;
+use crate::plans::RecursiveCte;
+use crate::plans::RecursiveCteScan;
 use crate::plans::Window;
 
 pub trait Operator {
@@ -84,6 +86,8 @@ pub enum RelOp {
     MaterializedCte,
     Lambda,
     ConstantTableScan,
+    RecursiveCte,
+    RecursiveCteScan,
 
     // Pattern
     Pattern,
@@ -109,6 +113,8 @@ pub enum RelOperator {
     MaterializedCte(MaterializedCte),
     Lambda(Lambda),
     ConstantTableScan(ConstantTableScan),
+    RecursiveCte(RecursiveCte),
+    RecursiveCteScan(RecursiveCteScan),
     Pattern(PatternPlan),
 }
 
@@ -133,6 +139,8 @@ impl Operator for RelOperator {
             RelOperator::MaterializedCte(rel_op) => rel_op.rel_op(),
             RelOperator::Lambda(rel_op) => rel_op.rel_op(),
             RelOperator::ConstantTableScan(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveCte(rel_op) => rel_op.rel_op(),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.rel_op(),
         }
     }
 
@@ -156,6 +164,8 @@ impl Operator for RelOperator {
             RelOperator::MaterializedCte(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::Lambda(rel_op) => rel_op.derive_relational_prop(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveCte(rel_op) => rel_op.derive_relational_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_relational_prop(rel_expr),
         }
     }
 
@@ -179,6 +189,8 @@ impl Operator for RelOperator {
             RelOperator::MaterializedCte(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::Lambda(rel_op) => rel_op.derive_physical_prop(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveCte(rel_op) => rel_op.derive_physical_prop(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_physical_prop(rel_expr),
         }
     }
 
@@ -202,6 +214,8 @@ impl Operator for RelOperator {
             RelOperator::MaterializedCte(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::Lambda(rel_op) => rel_op.derive_cardinality(rel_expr),
             RelOperator::ConstantTableScan(rel_op) => rel_op.derive_cardinality(rel_expr),
+            RelOperator::RecursiveCte(rel_op) => rel_op.derive_cardinality(rel_expr),
+            RelOperator::RecursiveCteScan(rel_op) => rel_op.derive_cardinality(rel_expr),
         }
     }
 
@@ -267,6 +281,12 @@ impl Operator for RelOperator {
             RelOperator::ConstantTableScan(rel_op) => {
                 rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
             }
+            RelOperator::RecursiveCte(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
+            RelOperator::RecursiveCteScan(rel_op) => {
+                rel_op.compute_required_prop_child(ctx, rel_expr, child_index, required)
+            }
         }
     }
 }
@@ -331,6 +351,46 @@ impl TryFrom<RelOperator> for MaterializedCte {
     }
 }
 
+impl From<RecursiveCte> for RelOperator {
+    fn from(value: RecursiveCte) -> Self {
+        Self::RecursiveCte(value)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveCte {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::RecursiveCte(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveCte",
+            ))
+        }
+    }
+}
+
+impl From<RecursiveCteScan> for RelOperator {
+    fn from(value: RecursiveCteScan) -> Self {
+        Self::RecursiveCteScan(value)
+    }
+}
+
+impl TryFrom<RelOperator> for RecursiveCteScan {
+    type Error = ErrorCode;
+
+    fn try_from(value: RelOperator) -> Result<Self> {
+        if let RelOperator::RecursiveCteScan(value) = value {
+            Ok(value)
+        } else {
+            Err(ErrorCode::Internal(
+                "Cannot downcast RelOperator to RecursiveCteScan",
+            ))
+        }
+    }
+}
+
 impl From<Join> for RelOperator {
     fn from(v: Join) -> Self {
         Self::Join(v)
@@ -27,6 +27,7 @@ use common_expression::DataSchemaRefExt;
 use crate::optimizer::SExpr;
 use crate::plans::copy_into_location::CopyIntoLocationPlan;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterNetworkPolicyPlan;
 use crate::plans::AlterShareTenantsPlan;
 use crate::plans::AlterTableClusterKeyPlan;
@@ -38,6 +39,7 @@ use crate::plans::AlterVirtualColumnPlan;
 use crate::plans::AnalyzeTablePlan;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::CopyIntoTablePlan;
+use crate::plans::CreateBloomIndexPlan;
 use crate::plans::CreateCatalogPlan;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::CreateDatamaskPolicyPlan;
@@ -45,21 +47,27 @@ use crate::plans::CreateFileFormatPlan;
 use crate::plans::CreateIndexPlan;
 use crate::plans::CreateNetworkPolicyPlan;
 use crate::plans::CreateRolePlan;
+use crate::plans::CreateRowAccessPolicyPlan;
+use crate::plans::CreateSequencePlan;
 use crate::plans::CreateShareEndpointPlan;
 use crate::plans::CreateSharePlan;
 use crate::plans::CreateStagePlan;
+use crate::plans::CreateStreamPlan;
 use crate::plans::CreateTablePlan;
 use crate::plans::CreateTaskPlan;
 use crate::plans::CreateUDFPlan;
 use crate::plans::CreateUserPlan;
+use crate::plans::CreateMaterializedViewPlan;
 use crate::plans::CreateViewPlan;
 use crate::plans::CreateVirtualColumnPlan;
 use crate::plans::DeletePlan;
 use crate::plans::DescDatamaskPolicyPlan;
 use crate::plans::DescNetworkPolicyPlan;
+use crate::plans::DescRowAccessPolicyPlan;
 use crate::plans::DescSharePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DescribeTaskPlan;
+use crate::plans::DropBloomIndexPlan;
 use crate::plans::DropCatalogPlan;
 use crate::plans::DropDatabasePlan;
 use crate::plans::DropDatamaskPolicyPlan;
@@ -67,12 +75,17 @@ use crate::plans::DropFileFormatPlan;
 use crate::plans::DropIndexPlan;
 use crate::plans::DropNetworkPolicyPlan;
 use crate::plans::DropRolePlan;
+use crate::plans::DropRowAccessPolicyPlan;
+use crate::plans::DropSequencePlan;
 use crate::plans::DropShareEndpointPlan;
 use crate::plans::DropSharePlan;
 use crate::plans::DropStagePlan;
+use crate::plans::DropStreamPlan;
+use crate::plans::DropTableCachePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
 use crate::plans::DropTaskPlan;
 use crate::plans::DropUDFPlan;
 use crate::plans::DropUserPlan;
@@ -84,6 +97,7 @@ use crate::plans::GrantPrivilegePlan;
 use crate::plans::GrantRolePlan;
 use crate::plans::GrantShareObjectPlan;
 use crate::plans::Insert;
+use crate::plans::InsertMultiTable;
 use crate::plans::KillPlan;
 use crate::plans::MergeInto;
 use crate::plans::ModifyTableColumnPlan;
@@ -91,6 +105,7 @@ use crate::plans::OptimizeTablePlan;
 use crate::plans::PresignPlan;
 use crate::plans::ReclusterTablePlan;
 use crate::plans::RefreshIndexPlan;
+use crate::plans::RefreshMaterializedViewPlan;
 use crate::plans::RefreshVirtualColumnPlan;
 use crate::plans::RemoveStagePlan;
 use crate::plans::RenameDatabasePlan;
@@ -103,6 +118,7 @@ use crate::plans::RevokeRolePlan;
 use crate::plans::RevokeShareObjectPlan;
 use crate::plans::SetOptionsPlan;
 use crate::plans::SetRolePlan;
+use crate::plans::SetUserVariablePlan;
 use crate::plans::SettingPlan;
 use crate::plans::ShowCreateCatalogPlan;
 use crate::plans::ShowCreateDatabasePlan;
@@ -189,6 +205,7 @@ pub enum Plan {
     ReclusterTable(Box<ReclusterTablePlan>),
     RevertTable(Box<RevertTablePlan>),
     TruncateTable(Box<TruncateTablePlan>),
+    DropTableCache(Box<DropTableCachePlan>),
     OptimizeTable(Box<OptimizeTablePlan>),
     VacuumTable(Box<VacuumTablePlan>),
     VacuumDropTable(Box<VacuumDropTablePlan>),
@@ -198,6 +215,7 @@ pub enum Plan {
 
     // Insert
     Insert(Box<Insert>),
+    InsertMultiTable(Box<InsertMultiTable>),
     Replace(Box<Replace>),
     Delete(Box<DeletePlan>),
     Update(Box<UpdatePlan>),
@@ -206,10 +224,18 @@ pub enum Plan {
     CreateView(Box<CreateViewPlan>),
     AlterView(Box<AlterViewPlan>),
     DropView(Box<DropViewPlan>),
+    CreateMaterializedView(Box<CreateMaterializedViewPlan>),
+    RefreshMaterializedView(Box<RefreshMaterializedViewPlan>),
+
+    // Streams
+    CreateStream(Box<CreateStreamPlan>),
+    DropStream(Box<DropStreamPlan>),
 
     // Indexes
     CreateIndex(Box<CreateIndexPlan>),
     DropIndex(Box<DropIndexPlan>),
+    CreateBloomIndex(Box<CreateBloomIndexPlan>),
+    DropBloomIndex(Box<DropBloomIndexPlan>),
     RefreshIndex(Box<RefreshIndexPlan>),
 
     // Virtual Columns
@@ -255,6 +281,7 @@ pub enum Plan {
     // Set
     SetVariable(Box<SettingPlan>),
     UnSetVariable(Box<UnSettingPlan>),
+    SetUserVariable(Box<SetUserVariablePlan>),
     Kill(Box<KillPlan>),
 
     // Share
@@ -276,6 +303,17 @@ pub enum Plan {
     DropDatamaskPolicy(Box<DropDatamaskPolicyPlan>),
     DescDatamaskPolicy(Box<DescDatamaskPolicyPlan>),
 
+    // Row access policy
+    CreateRowAccessPolicy(Box<CreateRowAccessPolicyPlan>),
+    DropRowAccessPolicy(Box<DropRowAccessPolicyPlan>),
+    DescRowAccessPolicy(Box<DescRowAccessPolicyPlan>),
+    AddTableRowAccessPolicy(Box<AddTableRowAccessPolicyPlan>),
+    DropTableRowAccessPolicy(Box<DropTableRowAccessPolicyPlan>),
+
+    // Sequence
+    CreateSequence(Box<CreateSequencePlan>),
+    DropSequence(Box<DropSequencePlan>),
+
     // Network policy
     CreateNetworkPolicy(Box<CreateNetworkPolicyPlan>),
     AlterNetworkPolicy(Box<AlterNetworkPolicyPlan>),
@@ -290,6 +328,14 @@ pub enum Plan {
     DescribeTask(Box<DescribeTaskPlan>),
     ShowTasks(Box<ShowTasksPlan>),
     ExecuteTask(Box<ExecuteTaskPlan>),
+
+    // Transactions
+    Begin,
+    Commit,
+    Abort,
+
+    // Caches
+    FlushQueryCache,
 }
 
 #[derive(Clone, Debug)]
@@ -330,7 +376,7 @@ impl Plan {
             | Plan::ExplainAnalyze { .. }
             | Plan::ExplainAst { .. }
             | Plan::ExplainSyntax { .. } => QueryKind::Explain,
-            Plan::Insert(_) => QueryKind::Insert,
+            Plan::Insert(_) | Plan::InsertMultiTable(_) => QueryKind::Insert,
             Plan::Replace(_)
             | Plan::Delete(_)
             | Plan::MergeInto(_)
@@ -339,6 +385,34 @@ impl Plan {
             _ => QueryKind::Other,
         }
     }
+
+    /// Whether the plan can be executed in an explicit transaction, only the reads and
+    /// the DML statements whose table meta changes can be staged in the session are allowed.
+    pub fn allowed_in_txn(&self) -> bool {
+        matches!(
+            self,
+            Plan::Query { .. }
+                | Plan::Explain { .. }
+                | Plan::ExplainAst { .. }
+                | Plan::ExplainSyntax { .. }
+                | Plan::ExplainAnalyze { .. }
+                | Plan::Insert(_)
+                | Plan::InsertMultiTable(_)
+                | Plan::Replace(_)
+                | Plan::Delete(_)
+                | Plan::Update(_)
+                | Plan::MergeInto(_)
+                | Plan::UseDatabase(_)
+                | Plan::ShowCreateTable(_)
+                | Plan::DescribeTable(_)
+                | Plan::SetVariable(_)
+                | Plan::UnSetVariable(_)
+                | Plan::SetUserVariable(_)
+                | Plan::Begin
+                | Plan::Commit
+                | Plan::Abort
+        )
+    }
 }
 
 impl Display for Plan {
@@ -384,6 +458,7 @@ impl Plan {
             Plan::CreateDatamaskPolicy(plan) => plan.schema(),
             Plan::DropDatamaskPolicy(plan) => plan.schema(),
             Plan::DescDatamaskPolicy(plan) => plan.schema(),
+            Plan::DescRowAccessPolicy(plan) => plan.schema(),
             Plan::CreateNetworkPolicy(plan) => plan.schema(),
             Plan::AlterNetworkPolicy(plan) => plan.schema(),
             Plan::DropNetworkPolicy(plan) => plan.schema(),
@@ -426,6 +501,7 @@ impl Plan {
                 | Plan::VacuumTable(_)
                 | Plan::VacuumDropTable(_)
                 | Plan::DescDatamaskPolicy(_)
+                | Plan::DescRowAccessPolicy(_)
                 | Plan::DescNetworkPolicy(_)
                 | Plan::ShowNetworkPolicies(_)
                 | Plan::CopyIntoTable(_)
@@ -0,0 +1,121 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::Distribution;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::StatInfo;
+use crate::optimizer::Statistics;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::IndexType;
+
+/// Recursive common table expression declared by `WITH RECURSIVE`.
+///
+/// The left child is the anchor term, which is evaluated only once. The right child is
+/// the recursive term, which is evaluated repeatedly against the rows produced by the
+/// previous iteration (the working table, read by `RecursiveCteScan`) until no new rows
+/// are produced.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RecursiveCte {
+    pub cte_idx: IndexType,
+    pub cte_name: String,
+    // Pairs of the columns of the anchor term and the recursive term, in the order of the
+    // columns of the working table. The columns of the anchor term are the output columns.
+    pub pairs: Vec<(IndexType, IndexType)>,
+    // `UNION ALL` keeps the duplicated rows, `UNION` discards them.
+    pub union_all: bool,
+}
+
+impl RecursiveCte {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        let mut used_columns = ColumnSet::new();
+        for (left, right) in &self.pairs {
+            used_columns.insert(*left);
+            used_columns.insert(*right);
+        }
+        Ok(used_columns)
+    }
+}
+
+impl Operator for RecursiveCte {
+    fn rel_op(&self) -> RelOp {
+        RelOp::RecursiveCte
+    }
+
+    fn derive_relational_prop(&self, rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        let left_prop = rel_expr.derive_relational_prop_child(0)?;
+        let right_prop = rel_expr.derive_relational_prop_child(1)?;
+
+        // Derive output columns
+        let output_columns = self.pairs.iter().map(|(left, _)| *left).collect();
+
+        // Derive outer columns
+        let outer_columns = left_prop
+            .outer_columns
+            .union(&right_prop.outer_columns)
+            .cloned()
+            .collect();
+
+        // Derive used columns
+        let mut used_columns = self.used_columns()?;
+        used_columns.extend(left_prop.used_columns.clone());
+        used_columns.extend(right_prop.used_columns.clone());
+
+        Ok(Arc::new(RelationalProperty {
+            output_columns,
+            outer_columns,
+            used_columns,
+        }))
+    }
+
+    fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        Ok(PhysicalProperty {
+            distribution: Distribution::Serial,
+        })
+    }
+
+    fn derive_cardinality(&self, rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        // The number of iterations is unknown before execution, take the
+        // cardinality of the anchor term as an estimation.
+        let anchor_stat_info = rel_expr.derive_cardinality_child(0)?;
+        Ok(Arc::new(StatInfo {
+            cardinality: anchor_stat_info.cardinality,
+            statistics: Statistics {
+                precise_cardinality: None,
+                column_stats: Default::default(),
+            },
+        }))
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        let mut required = required.clone();
+        required.distribution = Distribution::Serial;
+        Ok(required)
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::Hash;
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::DataField;
+
+use crate::optimizer::ColumnSet;
+use crate::optimizer::Distribution;
+use crate::optimizer::PhysicalProperty;
+use crate::optimizer::RelExpr;
+use crate::optimizer::RelationalProperty;
+use crate::optimizer::RequiredProperty;
+use crate::optimizer::StatInfo;
+use crate::plans::Operator;
+use crate::plans::RelOp;
+use crate::IndexType;
+
+/// Scan of the working table of a recursive cte, i.e. the rows produced by
+/// the previous iteration of the `RecursiveCte`.
+#[derive(Clone, Debug)]
+pub struct RecursiveCteScan {
+    pub cte_idx: IndexType,
+    // Fields in the order of the columns of the working table.
+    pub fields: Vec<DataField>,
+    pub stat: Arc<StatInfo>,
+}
+
+impl RecursiveCteScan {
+    pub fn used_columns(&self) -> Result<ColumnSet> {
+        let mut used_columns = ColumnSet::new();
+        for field in self.fields.iter() {
+            used_columns.insert(field.name().parse()?);
+        }
+        Ok(used_columns)
+    }
+}
+
+// Each reference of the working table has its own columns, which
+// tells the scans of the same working table apart.
+impl PartialEq for RecursiveCteScan {
+    fn eq(&self, other: &Self) -> bool {
+        self.cte_idx == other.cte_idx
+            && self
+                .fields
+                .iter()
+                .map(|field| field.name())
+                .eq(other.fields.iter().map(|field| field.name()))
+    }
+}
+
+impl Eq for RecursiveCteScan {}
+
+impl Hash for RecursiveCteScan {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.cte_idx.hash(state);
+        for field in self.fields.iter() {
+            field.name().hash(state);
+        }
+    }
+}
+
+impl Operator for RecursiveCteScan {
+    fn rel_op(&self) -> RelOp {
+        RelOp::RecursiveCteScan
+    }
+
+    fn derive_relational_prop(&self, _rel_expr: &RelExpr) -> Result<Arc<RelationalProperty>> {
+        Ok(Arc::new(RelationalProperty {
+            output_columns: self.used_columns()?,
+            outer_columns: ColumnSet::new(),
+            used_columns: self.used_columns()?,
+        }))
+    }
+
+    fn derive_physical_prop(&self, _rel_expr: &RelExpr) -> Result<PhysicalProperty> {
+        Ok(PhysicalProperty {
+            distribution: Distribution::Serial,
+        })
+    }
+
+    fn derive_cardinality(&self, _rel_expr: &RelExpr) -> Result<Arc<StatInfo>> {
+        Ok(self.stat.clone())
+    }
+
+    fn compute_required_prop_child(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _rel_expr: &RelExpr,
+        _child_index: usize,
+        _required: &RequiredProperty,
+    ) -> Result<RequiredProperty> {
+        unreachable!()
+    }
+}
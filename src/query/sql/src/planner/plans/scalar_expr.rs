@@ -22,6 +22,7 @@ use common_exception::Result;
 use common_exception::Span;
 use common_expression::types::DataType;
 use common_expression::Scalar;
+use common_expression::UDFType;
 use educe::Educe;
 use itertools::Itertools;
 
@@ -43,7 +44,7 @@ pub enum ScalarExpr {
     FunctionCall(FunctionCall),
     CastExpr(CastExpr),
     SubqueryExpr(SubqueryExpr),
-    UDFServerCall(UDFServerCall),
+    UDFCall(UDFCall),
 }
 
 impl ScalarExpr {
@@ -88,7 +89,7 @@ impl ScalarExpr {
             }
             ScalarExpr::CastExpr(scalar) => scalar.argument.used_columns(),
             ScalarExpr::SubqueryExpr(scalar) => scalar.outer_columns.clone(),
-            ScalarExpr::UDFServerCall(scalar) => {
+            ScalarExpr::UDFCall(scalar) => {
                 let mut result = ColumnSet::new();
                 for scalar in &scalar.arguments {
                     result = result.union(&scalar.used_columns()).cloned().collect();
@@ -136,7 +137,7 @@ impl ScalarExpr {
                     "SubqueryExpr/WindowFunction doesn't support used_tables method".to_string(),
                 ))
             }
-            ScalarExpr::UDFServerCall(scalar) => {
+            ScalarExpr::UDFCall(scalar) => {
                 let mut result = vec![];
                 for scalar in &scalar.arguments {
                     result.append(&mut scalar.used_tables(metadata.clone())?);
@@ -162,7 +163,7 @@ impl ScalarExpr {
             }),
             ScalarExpr::CastExpr(expr) => expr.span.or(expr.argument.span()),
             ScalarExpr::SubqueryExpr(expr) => expr.span,
-            ScalarExpr::UDFServerCall(expr) => expr.span,
+            ScalarExpr::UDFCall(expr) => expr.span,
             _ => None,
         }
     }
@@ -174,7 +175,7 @@ impl ScalarExpr {
             ScalarExpr::WindowFunction(_)
             | ScalarExpr::AggregateFunction(_)
             | ScalarExpr::SubqueryExpr(_)
-            | ScalarExpr::UDFServerCall(_) => false,
+            | ScalarExpr::UDFCall(_) => false,
             ScalarExpr::FunctionCall(func) => func.arguments.iter().all(|arg| arg.evaluable()),
             ScalarExpr::LambdaFunction(func) => func.args.iter().all(|arg| arg.evaluable()),
             ScalarExpr::CastExpr(expr) => expr.argument.evaluable(),
@@ -330,21 +331,19 @@ impl TryFrom<ScalarExpr> for SubqueryExpr {
     }
 }
 
-impl From<UDFServerCall> for ScalarExpr {
-    fn from(v: UDFServerCall) -> Self {
-        Self::UDFServerCall(v)
+impl From<UDFCall> for ScalarExpr {
+    fn from(v: UDFCall) -> Self {
+        Self::UDFCall(v)
     }
 }
 
-impl TryFrom<ScalarExpr> for UDFServerCall {
+impl TryFrom<ScalarExpr> for UDFCall {
     type Error = ErrorCode;
     fn try_from(value: ScalarExpr) -> Result<Self> {
-        if let ScalarExpr::UDFServerCall(value) = value {
+        if let ScalarExpr::UDFCall(value) = value {
             Ok(value)
         } else {
-            Err(ErrorCode::Internal(
-                "Cannot downcast Scalar to UDFServerCall",
-            ))
+            Err(ErrorCode::Internal("Cannot downcast Scalar to UDFCall"))
         }
     }
 }
@@ -572,11 +571,11 @@ fn hash_column_set<H: Hasher>(columns: &ColumnSet, state: &mut H) {
 
 #[derive(Clone, Debug, Educe)]
 #[educe(PartialEq, Eq, Hash)]
-pub struct UDFServerCall {
+pub struct UDFCall {
     #[educe(Hash(ignore), PartialEq(ignore), Eq(ignore))]
     pub span: Span,
     pub func_name: String,
-    pub server_addr: String,
+    pub udf_type: UDFType,
     pub arg_types: Vec<DataType>,
     pub return_type: Box<DataType>,
     pub arguments: Vec<ScalarExpr>,
@@ -199,13 +199,17 @@ impl Operator for Scan {
                 let min = col_stat.min.unwrap();
                 let max = col_stat.max.unwrap();
                 let ndv = col_stat.ndv.unwrap();
-                let histogram = histogram_from_ndv(
-                    ndv,
-                    num_rows,
-                    Some((min.clone(), max.clone())),
-                    DEFAULT_HISTOGRAM_BUCKETS,
-                )
-                .ok();
+                // Prefer the histogram collected by `ANALYZE TABLE`.
+                let histogram = match col_stat.histogram {
+                    Some(histogram) => Some(histogram),
+                    None => histogram_from_ndv(
+                        ndv,
+                        num_rows,
+                        Some((min.clone(), max.clone())),
+                        DEFAULT_HISTOGRAM_BUCKETS,
+                    )
+                    .ok(),
+                };
                 let column_stat = ColumnStat {
                     min,
                     max,
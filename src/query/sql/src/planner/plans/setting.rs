@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common_expression::Scalar;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct VarValue {
     pub is_global: bool,
@@ -28,3 +30,9 @@ pub struct SettingPlan {
 pub struct UnSettingPlan {
     pub vars: Vec<String>,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetUserVariablePlan {
+    pub variable: String,
+    pub value: Scalar,
+}
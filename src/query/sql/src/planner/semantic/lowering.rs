@@ -127,10 +127,10 @@ fn update_column_type<ColumnID: ColumnIndex, TP: TypeProvider<ColumnID>>(
             })
         }
         RawExpr::Constant { .. } => Ok(raw_expr.clone()),
-        RawExpr::UDFServerCall {
+        RawExpr::UDFCall {
             span,
             func_name,
-            server_addr,
+            udf_type,
             arg_types,
             return_type,
             args,
@@ -139,10 +139,10 @@ fn update_column_type<ColumnID: ColumnIndex, TP: TypeProvider<ColumnID>>(
                 .iter()
                 .map(|arg| update_column_type(arg, type_provider))
                 .collect::<Result<Vec<_>>>()?;
-            Ok(RawExpr::UDFServerCall {
+            Ok(RawExpr::UDFCall {
                 span: *span,
                 func_name: func_name.clone(),
-                server_addr: server_addr.clone(),
+                udf_type: udf_type.clone(),
                 arg_types: arg_types.clone(),
                 return_type: return_type.clone(),
                 args,
@@ -248,10 +248,10 @@ impl ScalarExpr {
                 data_type: subquery.data_type(),
                 display_name: "DUMMY".to_string(),
             },
-            ScalarExpr::UDFServerCall(udf) => RawExpr::UDFServerCall {
+            ScalarExpr::UDFCall(udf) => RawExpr::UDFCall {
                 span: udf.span,
                 func_name: udf.func_name.clone(),
-                server_addr: udf.server_addr.clone(),
+                udf_type: udf.udf_type.clone(),
                 arg_types: udf.arg_types.clone(),
                 return_type: (*udf.return_type).clone(),
                 args: udf.arguments.iter().map(ScalarExpr::as_raw_expr).collect(),
@@ -52,6 +52,7 @@ use common_expression::types::decimal::DecimalSize;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
 use common_expression::types::NumberScalar;
+use common_expression::udf_wasm::decode_wasm_code;
 use common_expression::ColumnIndex;
 use common_expression::ConstantFolder;
 use common_expression::Expr as EExpr;
@@ -60,16 +61,21 @@ use common_expression::FunctionKind;
 use common_expression::RawExpr;
 use common_expression::Scalar;
 use common_expression::TableDataType;
+use common_expression::UDFType;
 use common_functions::aggregates::AggregateCountFunction;
 use common_functions::aggregates::AggregateFunctionFactory;
+use common_functions::aggregates::LambdaUDAFExprs;
+use common_functions::aggregates::LAMBDA_UDAF_FUNC_NAME;
 use common_functions::is_builtin_function;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_functions::GENERAL_LAMBDA_FUNCTIONS;
 use common_functions::GENERAL_WINDOW_FUNCTIONS;
 use common_license::license::Feature::VirtualColumn;
 use common_license::license_manager::get_license_manager;
+use common_meta_app::principal::LambdaUDAF;
 use common_meta_app::principal::LambdaUDF;
 use common_meta_app::principal::UDFDefinition;
+use common_meta_app::principal::UDFScript;
 use common_meta_app::principal::UDFServer;
 use common_users::UserApiProvider;
 use indexmap::IndexMap;
@@ -87,6 +93,7 @@ use crate::binder::ExprContext;
 use crate::binder::NameResolutionResult;
 use crate::optimizer::RelExpr;
 use crate::parse_lambda_expr;
+use crate::parse_lambda_params_expr;
 use crate::planner::metadata::optimize_remove_count_args;
 use crate::plans::AggregateFunction;
 use crate::plans::BoundColumnRef;
@@ -101,7 +108,7 @@ use crate::plans::NtileFunction;
 use crate::plans::ScalarExpr;
 use crate::plans::SubqueryExpr;
 use crate::plans::SubqueryType;
-use crate::plans::UDFServerCall;
+use crate::plans::UDFCall;
 use crate::plans::WindowFunc;
 use crate::plans::WindowFuncFrame;
 use crate::plans::WindowFuncFrameBound;
@@ -700,7 +707,7 @@ impl<'a> TypeChecker<'a> {
                 if !is_builtin_function(func_name)
                     && !Self::all_sugar_functions().contains(&func_name)
                 {
-                    if let Some(udf) = self.resolve_udf(*span, func_name, args).await? {
+                    if let Some(udf) = self.resolve_udf(*span, func_name, expr, args).await? {
                         return Ok(udf);
                     } else {
                         // Function not found, try to find and suggest similar function name.
@@ -845,7 +852,10 @@ impl<'a> TypeChecker<'a> {
                         // aggregate function
                         Box::new((new_agg_func.into(), data_type))
                     }
-                } else if GENERAL_LAMBDA_FUNCTIONS.contains(&name.as_str()) {
+                } else if GENERAL_LAMBDA_FUNCTIONS.contains(&name.as_str())
+                    // `array_reduce` without a lambda is an alias of `array_aggregate`.
+                    && (lambda.is_some() || name != "array_reduce")
+                {
                     if matches!(
                         self.bind_context.expr_context,
                         ExprContext::InLambdaFunction
@@ -868,10 +878,11 @@ impl<'a> TypeChecker<'a> {
                         .map(|param| param.name.clone())
                         .collect::<Vec<_>>();
 
-                    // TODO: support multiple params
-                    if params.len() != 1 {
+                    let expect_params = if name == "array_reduce" { 2 } else { 1 };
+                    if params.len() != expect_params {
                         return Err(ErrorCode::SemanticError(format!(
-                            "incorrect number of parameters in lambda function, {name} expects 1 parameter",
+                            "incorrect number of parameters in lambda function, {name} expects {expect_params} parameter{}",
+                            if expect_params > 1 { "s" } else { "" }
                         )));
                     }
 
@@ -882,6 +893,15 @@ impl<'a> TypeChecker<'a> {
                     }
                     let box (arg, arg_type) = self.resolve(args[0]).await?;
                     match arg_type.remove_nullable() {
+                        // Reduce an empty array returns NULL
+                        DataType::EmptyArray if name == "array_reduce" => Box::new((
+                            ConstantExpr {
+                                span: *span,
+                                value: Scalar::Null,
+                            }
+                            .into(),
+                            DataType::Null,
+                        )),
                         // Empty array will always return an Empty array
                         DataType::EmptyArray => Box::new((
                             ConstantExpr {
@@ -891,6 +911,46 @@ impl<'a> TypeChecker<'a> {
                             .into(),
                             DataType::EmptyArray,
                         )),
+                        DataType::Array(box inner_ty) if name == "array_reduce" => {
+                            // The accumulator starts from the first element, so it has the
+                            // type of the elements, and so does the result of the lambda.
+                            let lambda_params = vec![
+                                (params[0].clone(), inner_ty.clone()),
+                                (params[1].clone(), inner_ty.clone()),
+                            ];
+                            let box (lambda_expr, lambda_type) = parse_lambda_params_expr(
+                                self.ctx.clone(),
+                                &lambda_params,
+                                &lambda.expr,
+                            )?;
+                            let lambda_expr = if lambda_type != inner_ty {
+                                CastExpr {
+                                    span: lambda.expr.span(),
+                                    is_try: false,
+                                    argument: Box::new(lambda_expr),
+                                    target_type: Box::new(inner_ty.clone()),
+                                }
+                                .into()
+                            } else {
+                                lambda_expr
+                            };
+
+                            // NULL or empty arrays are reduced to NULL.
+                            let return_type = inner_ty.wrap_nullable();
+                            Box::new((
+                                LambdaFunc {
+                                    span: *span,
+                                    func_name: name.clone(),
+                                    display_name: format!("{:#}", expr),
+                                    args: vec![arg],
+                                    params: lambda_params,
+                                    lambda_expr: Box::new(lambda_expr),
+                                    return_type: Box::new(return_type.clone()),
+                                }
+                                .into(),
+                                return_type,
+                            ))
+                        }
                         DataType::Array(box inner_ty) => {
                             let box (lambda_expr, lambda_type) = parse_lambda_expr(
                                 self.ctx.clone(),
@@ -1106,6 +1166,12 @@ impl<'a> TypeChecker<'a> {
             Expr::DateTrunc {
                 span, unit, date, ..
             } => self.resolve_date_trunc(*span, date, unit).await?,
+            Expr::UserVariable { span, name } => {
+                // An undefined user variable is NULL, the same as MySQL.
+                let value = self.ctx.get_variable(&name.name).unwrap_or(Scalar::Null);
+                let data_type = value.as_ref().infer_data_type();
+                Box::new((ConstantExpr { span: *span, value }.into(), data_type))
+            }
             Expr::Trim {
                 span,
                 expr,
@@ -2774,6 +2840,7 @@ impl<'a> TypeChecker<'a> {
         &mut self,
         span: Span,
         udf_name: &str,
+        expr: &Expr,
         arguments: &[Expr],
     ) -> Result<Option<Box<(ScalarExpr, DataType)>>> {
         if self.forbid_udf {
@@ -2797,6 +2864,13 @@ impl<'a> TypeChecker<'a> {
             UDFDefinition::UDFServer(udf_def) => Ok(Some(
                 self.resolve_udf_server(span, arguments, udf_def).await?,
             )),
+            UDFDefinition::UDFScript(udf_def) => Ok(Some(
+                self.resolve_udf_script(span, arguments, udf_def).await?,
+            )),
+            UDFDefinition::LambdaUDAF(udf_def) => Ok(Some(
+                self.resolve_lambda_udaf(span, expr, arguments, udf_def)
+                    .await?,
+            )),
         }
     }
 
@@ -2832,10 +2906,10 @@ impl<'a> TypeChecker<'a> {
         }
 
         let raw_expr_args = args.iter().map(|arg| arg.as_raw_expr()).collect_vec();
-        let raw_expr = RawExpr::UDFServerCall {
+        let raw_expr = RawExpr::UDFCall {
             span,
             func_name: udf_definition.handler.clone(),
-            server_addr: udf_definition.address.clone(),
+            udf_type: UDFType::Server(udf_definition.address.clone()),
             arg_types: udf_definition.arg_types.clone(),
             return_type: udf_definition.return_type.clone(),
             args: raw_expr_args,
@@ -2845,10 +2919,51 @@ impl<'a> TypeChecker<'a> {
 
         self.ctx.set_cacheable(false);
         Ok(Box::new((
-            UDFServerCall {
+            UDFCall {
+                span,
+                func_name: udf_definition.handler,
+                udf_type: UDFType::Server(udf_definition.address),
+                arg_types: udf_definition.arg_types,
+                return_type: Box::new(udf_definition.return_type.clone()),
+                arguments: args,
+            }
+            .into(),
+            udf_definition.return_type.clone(),
+        )))
+    }
+
+    #[async_recursion::async_recursion]
+    #[async_backtrace::framed]
+    async fn resolve_udf_script(
+        &mut self,
+        span: Span,
+        arguments: &[Expr],
+        udf_definition: UDFScript,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let mut args = Vec::with_capacity(arguments.len());
+        for argument in arguments {
+            let box (arg, _) = self.resolve(argument).await?;
+            args.push(arg);
+        }
+
+        let udf_type = UDFType::Wasm(Arc::new(decode_wasm_code(&udf_definition.code)));
+        let raw_expr_args = args.iter().map(|arg| arg.as_raw_expr()).collect_vec();
+        let raw_expr = RawExpr::UDFCall {
+            span,
+            func_name: udf_definition.handler.clone(),
+            udf_type: udf_type.clone(),
+            arg_types: udf_definition.arg_types.clone(),
+            return_type: udf_definition.return_type.clone(),
+            args: raw_expr_args,
+        };
+
+        type_check::check(&raw_expr, &BUILTIN_FUNCTIONS)?;
+
+        Ok(Box::new((
+            UDFCall {
                 span,
                 func_name: udf_definition.handler,
-                server_addr: udf_definition.address,
+                udf_type,
                 arg_types: udf_definition.arg_types,
                 return_type: Box::new(udf_definition.return_type.clone()),
                 arguments: args,
@@ -2899,6 +3014,156 @@ impl<'a> TypeChecker<'a> {
         self.resolve(&udf_expr).await
     }
 
+    /// Resolve a call of user-defined aggregate function, the lambdas are compiled
+    /// against the argument types and executed by the `_lambda_udaf` aggregate function.
+    #[async_recursion::async_recursion]
+    #[async_backtrace::framed]
+    async fn resolve_lambda_udaf(
+        &mut self,
+        span: Span,
+        expr: &Expr,
+        arguments: &[Expr],
+        udf_definition: LambdaUDAF,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if let Expr::FunctionCall {
+            distinct, window, ..
+        } = expr
+        {
+            if *distinct || window.is_some() {
+                return Err(ErrorCode::SemanticError(
+                    "DISTINCT and window are not supported by user-defined aggregate functions",
+                )
+                .set_span(span));
+            }
+        }
+        if matches!(
+            self.bind_context.expr_context,
+            ExprContext::InLambdaFunction
+        ) {
+            return Err(ErrorCode::SemanticError(
+                "aggregate functions can not be used in lambda function".to_string(),
+            )
+            .set_span(span));
+        }
+        if self.in_aggregate_function {
+            self.in_aggregate_function = false;
+            return Err(ErrorCode::SemanticError(
+                "aggregate function calls cannot be nested".to_string(),
+            )
+            .set_span(span));
+        }
+
+        let LambdaUDAF {
+            state_type,
+            init,
+            accumulate,
+            merge,
+            finalize,
+        } = udf_definition;
+        if accumulate.parameters.len() != arguments.len() + 1 {
+            return Err(ErrorCode::SyntaxException(format!(
+                "Require {} parameters, but got: {}",
+                accumulate.parameters.len() - 1,
+                arguments.len()
+            ))
+            .set_span(span));
+        }
+
+        self.in_aggregate_function = true;
+        let mut args = Vec::with_capacity(arguments.len());
+        let mut arg_types = Vec::with_capacity(arguments.len());
+        for argument in arguments {
+            let box (arg, arg_type) = self.resolve(argument).await?;
+            args.push(arg);
+            arg_types.push(arg_type);
+        }
+        self.in_aggregate_function = false;
+
+        // NULL values are skipped before accumulating, so the lambdas only see
+        // the non-nullable argument types.
+        let mut accumulate_params = vec![(accumulate.parameters[0].clone(), state_type.clone())];
+        for (param, arg_type) in accumulate.parameters[1..].iter().zip(arg_types.iter()) {
+            accumulate_params.push((param.clone(), arg_type.remove_nullable()));
+        }
+        let merge_params = merge
+            .parameters
+            .iter()
+            .map(|param| (param.clone(), state_type.clone()))
+            .collect::<Vec<_>>();
+        let finalize_params = vec![(finalize.parameters[0].clone(), state_type.clone())];
+
+        let init = match self.resolve_udaf_expr(span, &init, &[], Some(&state_type))? {
+            EExpr::Constant { scalar, .. } => scalar,
+            _ => {
+                return Err(ErrorCode::SemanticError(
+                    "INIT of user-defined aggregate function must be a constant expression",
+                )
+                .set_span(span));
+            }
+        };
+        let exprs = LambdaUDAFExprs {
+            init,
+            accumulate: self
+                .resolve_udaf_expr(
+                    span,
+                    &accumulate.definition,
+                    &accumulate_params,
+                    Some(&state_type),
+                )?
+                .as_remote_expr(),
+            merge: self
+                .resolve_udaf_expr(span, &merge.definition, &merge_params, Some(&state_type))?
+                .as_remote_expr(),
+            finalize: self
+                .resolve_udaf_expr(span, &finalize.definition, &finalize_params, None)?
+                .as_remote_expr(),
+            state_type,
+        };
+
+        let func_name = LAMBDA_UDAF_FUNC_NAME.to_string();
+        let params = exprs.to_params()?;
+        let agg_func = AggregateFunctionFactory::instance()
+            .get(&func_name, params.clone(), arg_types)
+            .map_err(|e| e.set_span(span))?;
+        let data_type = agg_func.return_type()?;
+
+        let new_agg_func = AggregateFunction {
+            display_name: format!("{:#}", expr),
+            func_name,
+            distinct: false,
+            params,
+            args,
+            return_type: Box::new(data_type.clone()),
+        };
+        Ok(Box::new((new_agg_func.into(), data_type)))
+    }
+
+    /// Compile a lambda of user-defined aggregate function, the `i`-th parameter
+    /// is the `i`-th column of the input.
+    fn resolve_udaf_expr(
+        &self,
+        span: Span,
+        definition: &str,
+        params: &[(String, DataType)],
+        result_type: Option<&DataType>,
+    ) -> Result<EExpr> {
+        let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+        let sql_tokens = tokenize_sql(definition)?;
+        let ast = parse_expr(&sql_tokens, sql_dialect)?;
+        let box (scalar, _) = parse_lambda_params_expr(self.ctx.clone(), params, &ast)
+            .map_err(|e| e.set_span(span))?;
+
+        let expr = scalar.as_expr()?.project_column_ref(|col| col.index);
+        let expr = match result_type {
+            Some(result_type) if expr.data_type() != result_type => {
+                type_check::check_cast(span, false, expr, result_type, &BUILTIN_FUNCTIONS)?
+            }
+            _ => expr,
+        };
+        let (expr, _) = ConstantFolder::fold(&expr, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        Ok(expr)
+    }
+
     #[async_recursion::async_recursion]
     #[async_backtrace::framed]
     async fn resolve_cast_to_variant(
@@ -3726,6 +3991,7 @@ pub fn resolve_type_name_inner(type_name: &TypeName) -> Result<TableDataType> {
         TypeName::String => TableDataType::String,
         TypeName::Timestamp => TableDataType::Timestamp,
         TypeName::Date => TableDataType::Date,
+        TypeName::Time => TableDataType::Time,
         TypeName::Array(item_type) => {
             TableDataType::Array(Box::new(resolve_type_name_inner(item_type)?))
         }
@@ -3776,6 +4042,8 @@ pub fn resolve_type_name_inner(type_name: &TypeName) -> Result<TableDataType> {
             TableDataType::Nullable(Box::new(resolve_type_name_inner(inner_type)?))
         }
         TypeName::Variant => TableDataType::Variant,
+        TypeName::Geometry => TableDataType::Geometry,
+        TypeName::Binary => TableDataType::Binary,
     };
 
     Ok(data_type)
@@ -19,7 +19,7 @@ use crate::binder::ColumnBindingBuilder;
 use crate::plans::BoundColumnRef;
 use crate::plans::CastExpr;
 use crate::plans::FunctionCall;
-use crate::plans::UDFServerCall;
+use crate::plans::UDFCall;
 use crate::BindContext;
 use crate::ScalarExpr;
 use crate::Visibility;
@@ -98,16 +98,16 @@ impl<'a> WindowChecker<'a> {
             }
 
             ScalarExpr::AggregateFunction(_) => unreachable!(),
-            ScalarExpr::UDFServerCall(udf) => {
+            ScalarExpr::UDFCall(udf) => {
                 let new_args = udf
                     .arguments
                     .iter()
                     .map(|arg| self.resolve(arg))
                     .collect::<Result<Vec<ScalarExpr>>>()?;
-                Ok(UDFServerCall {
+                Ok(UDFCall {
                     span: udf.span,
                     func_name: udf.func_name.clone(),
-                    server_addr: udf.server_addr.clone(),
+                    udf_type: udf.udf_type.clone(),
                     arg_types: udf.arg_types.clone(),
                     return_type: udf.return_type.clone(),
                     arguments: new_args,
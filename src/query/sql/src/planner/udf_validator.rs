@@ -31,6 +31,9 @@ use common_functions::is_builtin_function;
 pub struct UDFValidator {
     pub name: String,
     pub parameters: Vec<String>,
+    /// Lambdas of aggregate functions may ignore some of their parameters,
+    /// e.g. `(s, x) -> s + 1` to count rows.
+    pub allow_unused_parameters: bool,
 
     pub expr_params: HashSet<String>,
     pub has_recursive: bool,
@@ -49,7 +52,11 @@ impl UDFValidator {
         let parameters = self.parameters.iter().cloned().collect::<HashSet<_>>();
 
         let params_not_declared: HashSet<_> = expr_params.difference(&parameters).collect();
-        let params_not_used: HashSet<_> = parameters.difference(expr_params).collect();
+        let params_not_used: HashSet<_> = if self.allow_unused_parameters {
+            HashSet::new()
+        } else {
+            parameters.difference(expr_params).collect()
+        };
 
         if params_not_declared.is_empty() && params_not_used.is_empty() {
             return Ok(());
@@ -36,7 +36,10 @@ use common_meta_app::storage::StorageWebhdfsConfig;
 use common_meta_app::storage::STORAGE_GCS_DEFAULT_ENDPOINT;
 use common_meta_app::storage::STORAGE_IPFS_DEFAULT_ENDPOINT;
 use common_meta_app::storage::STORAGE_S3_DEFAULT_ENDPOINT;
+use common_sql::planner::binder::parse_kafka_location;
 use common_sql::planner::binder::parse_uri_location;
+use common_storages_kafka::KafkaInfo;
+use common_storages_kafka::KafkaMessageFormat;
 
 #[tokio::test]
 async fn test_parse_uri_location() -> Result<()> {
@@ -424,3 +427,73 @@ async fn test_parse_uri_location() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_parse_kafka_location() -> Result<()> {
+    let mut l = UriLocation::new(
+        "kafka".to_string(),
+        "127.0.0.1:9092".to_string(),
+        "/events".to_string(),
+        "".to_string(),
+        vec![
+            ("bootstrap_servers", "127.0.0.2:9092"),
+            ("format", "AVRO"),
+            ("avro_schema", r#"{"type": "long"}"#),
+            ("security_protocol", "SASL_SSL"),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect::<BTreeMap<_, _>>(),
+    );
+    let info = parse_kafka_location(&mut l, "default_group".to_string())?;
+    assert_eq!(info, KafkaInfo {
+        brokers: "127.0.0.1:9092,127.0.0.2:9092".to_string(),
+        topic: "events".to_string(),
+        group_id: "default_group".to_string(),
+        format: KafkaMessageFormat::Avro {
+            schema: r#"{"type": "long"}"#.to_string(),
+        },
+        properties: BTreeMap::from([("security.protocol".to_string(), "SASL_SSL".to_string())]),
+    });
+
+    // avro schemas from the schema registry
+    let mut l = UriLocation::new(
+        "kafka".to_string(),
+        "127.0.0.1:9092".to_string(),
+        "/events".to_string(),
+        "".to_string(),
+        BTreeMap::from([
+            ("format".to_string(), "avro".to_string()),
+            (
+                "schema_registry_url".to_string(),
+                "http://127.0.0.1:8081/".to_string(),
+            ),
+        ]),
+    );
+    let info = parse_kafka_location(&mut l, "default_group".to_string())?;
+    assert_eq!(info.format, KafkaMessageFormat::AvroSchemaRegistry {
+        url: "http://127.0.0.1:8081".to_string(),
+    });
+
+    // topic is required
+    let mut l = UriLocation::new(
+        "kafka".to_string(),
+        "127.0.0.1:9092".to_string(),
+        "/".to_string(),
+        "".to_string(),
+        BTreeMap::new(),
+    );
+    assert!(parse_kafka_location(&mut l, "default_group".to_string()).is_err());
+
+    // unknown connection options
+    let mut l = UriLocation::new(
+        "kafka".to_string(),
+        "127.0.0.1:9092".to_string(),
+        "/events".to_string(),
+        "".to_string(),
+        BTreeMap::from([("endpoint_url".to_string(), "x".to_string())]),
+    );
+    assert!(parse_kafka_location(&mut l, "default_group".to_string()).is_err());
+
+    Ok(())
+}
@@ -12,63 +12,169 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use common_arrow::arrow::chunk::Chunk;
+use common_arrow::arrow::compute::cast::cast;
+use common_arrow::arrow::compute::cast::CastOptions;
 use common_arrow::arrow::datatypes::DataType as ArrowDataType;
+use common_arrow::arrow::datatypes::IntegerType;
+use common_arrow::arrow::error::Error as ArrowError;
+use common_arrow::arrow::error::Result as ArrowResult;
+use common_arrow::arrow::io::parquet::write::array_to_columns;
+use common_arrow::arrow::io::parquet::write::to_parquet_schema;
 use common_arrow::arrow::io::parquet::write::transverse;
+use common_arrow::arrow::io::parquet::write::CompressionOptions;
+use common_arrow::arrow::io::parquet::write::Compressor;
+use common_arrow::arrow::io::parquet::write::DynIter;
+use common_arrow::arrow::io::parquet::write::DynStreamingIterator;
+use common_arrow::arrow::io::parquet::write::FallibleStreamingIterator;
+use common_arrow::arrow::io::parquet::write::RowGroupIter;
 use common_arrow::arrow::io::parquet::write::RowGroupIterator;
 use common_arrow::arrow::io::parquet::write::WriteOptions;
 use common_arrow::parquet::encoding::Encoding;
+use common_arrow::parquet::error::Error as ParquetError;
+use common_arrow::parquet::metadata::SchemaDescriptor;
 use common_arrow::parquet::metadata::ThriftFileMetaData;
 use common_arrow::parquet::write::Version;
 use common_arrow::write_parquet_file;
+use common_arrow::ArrayRef;
 use common_config::DATABEND_COMMIT_VERSION;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::DataBlock;
+use common_expression::FieldIndex;
 use common_expression::TableSchema;
+use storages_common_table_meta::table::ColumnCodec;
+use storages_common_table_meta::table::ColumnEncoding;
 use storages_common_table_meta::table::TableCompression;
 
 /// Serialize data blocks to parquet format.
+///
+/// If `enable_page_index` is true, the statistics of pages are written as the page indexes
+/// (column index and offset index), right after the column chunks and before the footer.
 pub fn blocks_to_parquet(
     schema: impl AsRef<TableSchema>,
     blocks: Vec<DataBlock>,
     write_buffer: &mut Vec<u8>,
     compression: TableCompression,
+    enable_page_index: bool,
+) -> Result<(u64, ThriftFileMetaData)> {
+    blocks_to_parquet_with_codecs(
+        schema,
+        blocks,
+        write_buffer,
+        compression,
+        &BTreeMap::new(),
+        enable_page_index,
+    )
+}
+
+/// Serialize data blocks to parquet format, the fields in `column_codecs` are written with the
+/// encodings and compressions of their codecs, other fields are compressed by `compression`.
+pub fn blocks_to_parquet_with_codecs(
+    schema: impl AsRef<TableSchema>,
+    blocks: Vec<DataBlock>,
+    write_buffer: &mut Vec<u8>,
+    compression: TableCompression,
+    column_codecs: &BTreeMap<FieldIndex, ColumnCodec>,
+    enable_page_index: bool,
 ) -> Result<(u64, ThriftFileMetaData)> {
-    let arrow_schema = schema.as_ref().to_arrow();
+    let mut arrow_schema = schema.as_ref().to_arrow();
 
     let row_group_write_options = WriteOptions {
-        write_statistics: false,
+        write_statistics: enable_page_index,
         version: Version::V2,
         compression: compression.into(),
         data_pagesize_limit: None,
     };
-    let batches = blocks
+    let mut batches = blocks
         .into_iter()
         .map(Chunk::try_from)
         .collect::<Result<Vec<_>>>()?;
 
+    // fields of dictionary encoding are written as dictionary arrays.
+    let dictionary_fields = column_codecs
+        .iter()
+        .filter(|(_, codec)| codec.encoding == Some(ColumnEncoding::Dictionary))
+        .map(|(index, _)| *index)
+        .collect::<Vec<_>>();
+    if !dictionary_fields.is_empty() {
+        for index in &dictionary_fields {
+            let field = &mut arrow_schema.fields[*index];
+            field.data_type = ArrowDataType::Dictionary(
+                IntegerType::UInt32,
+                Box::new(field.data_type.clone()),
+                false,
+            );
+        }
+        batches = batches
+            .into_iter()
+            .map(|batch| {
+                let mut arrays = batch.into_arrays();
+                for index in &dictionary_fields {
+                    let data_type = &arrow_schema.fields[*index].data_type;
+                    arrays[*index] =
+                        cast(arrays[*index].as_ref(), data_type, CastOptions::default())?;
+                }
+                Ok(Chunk::new(arrays))
+            })
+            .collect::<Result<Vec<_>>>()?;
+    }
+
     let encoding_map = |data_type: &ArrowDataType| match data_type {
         ArrowDataType::Dictionary(..) => Encoding::RleDictionary,
         _ => col_encoding(data_type),
     };
 
-    let encodings: Vec<Vec<_>> = arrow_schema
+    let mut encodings: Vec<Vec<_>> = arrow_schema
         .fields
         .iter()
         .map(|f| transverse(&f.data_type, encoding_map))
         .collect::<Vec<_>>();
+    for (index, codec) in column_codecs {
+        if codec.encoding == Some(ColumnEncoding::Delta) {
+            encodings[*index] = vec![Encoding::DeltaBinaryPacked];
+        }
+    }
 
-    let row_groups = RowGroupIterator::try_new(
-        batches.into_iter().map(Ok),
-        &arrow_schema,
-        row_group_write_options,
-        encodings,
-    )?;
+    let row_groups: Box<dyn Iterator<Item = ArrowResult<RowGroupIter<'static, ArrowError>>>> =
+        if column_codecs
+            .values()
+            .all(|codec| codec.compression.is_none())
+        {
+            Box::new(RowGroupIterator::try_new(
+                batches.into_iter().map(Ok),
+                &arrow_schema,
+                row_group_write_options,
+                encodings,
+            )?)
+        } else {
+            let compressions = (0..arrow_schema.fields.len())
+                .map(|index| {
+                    column_codecs
+                        .get(&index)
+                        .and_then(|codec| codec.compression)
+                        .unwrap_or(compression)
+                        .into()
+                })
+                .collect::<Vec<CompressionOptions>>();
+            let parquet_schema = to_parquet_schema(&arrow_schema)?;
+            Box::new(batches.into_iter().map(move |batch| {
+                row_group_with_compressions(
+                    batch,
+                    &parquet_schema,
+                    row_group_write_options,
+                    &encodings,
+                    &compressions,
+                )
+            }))
+        };
 
     use common_arrow::parquet::write::WriteOptions as FileWriteOption;
+    // the page indexes are only written if the statistics are written.
     let options = FileWriteOption {
-        write_statistics: false,
+        write_statistics: enable_page_index,
         version: Version::V2,
     };
 
@@ -90,6 +196,39 @@ pub fn blocks_to_parquet(
     }
 }
 
+/// Encode and compress the columns of a row group, each field with its own compression.
+fn row_group_with_compressions(
+    batch: Chunk<ArrayRef>,
+    parquet_schema: &SchemaDescriptor,
+    options: WriteOptions,
+    encodings: &[Vec<Encoding>],
+    compressions: &[CompressionOptions],
+) -> ArrowResult<RowGroupIter<'static, ArrowError>> {
+    let columns = batch
+        .into_arrays()
+        .into_iter()
+        .zip(parquet_schema.fields().to_vec())
+        .zip(encodings.iter().zip(compressions.iter()))
+        .map(|((array, type_), (encoding, compression))| {
+            let compression = *compression;
+            let options = WriteOptions {
+                compression,
+                ..options
+            };
+            let columns = array_to_columns(array, type_, options, encoding)?;
+            Ok(columns.into_iter().map(move |pages| {
+                let pages = DynIter::new(
+                    pages.map(|page| page.map_err(|e| ParquetError::OutOfSpec(e.to_string()))),
+                );
+                let compressed_pages =
+                    Compressor::new(pages, compression, vec![]).map_err(ArrowError::from);
+                Ok(DynStreamingIterator::new(compressed_pages))
+            }))
+        })
+        .collect::<ArrowResult<Vec<_>>>()?;
+    Ok(DynIter::new(columns.into_iter().flatten()))
+}
+
 fn col_encoding(_data_type: &ArrowDataType) -> Encoding {
     // Although encoding does work, parquet2 has not implemented decoding of DeltaLengthByteArray yet, we fallback to Plain
     // From parquet2: Decoding "DeltaLengthByteArray"-encoded required V2 pages is not yet implemented for Binary.
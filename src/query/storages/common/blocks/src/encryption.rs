@@ -0,0 +1,150 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::base::GlobalInstance;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnId;
+use ring::aead::Aad;
+use ring::aead::LessSafeKey;
+use ring::aead::Nonce;
+use ring::aead::UnboundKey;
+use ring::aead::AES_256_GCM;
+use ring::aead::NONCE_LEN;
+use ring::rand::SecureRandom;
+use ring::rand::SystemRandom;
+
+/// KeyManagementService resolves the data keys used to encrypt the columns at rest.
+///
+/// The keys are resolved every time a block is written or read, implementations backed
+/// by a remote service should cache them.
+pub trait KeyManagementService: Sync + Send {
+    /// Get the 256-bit data key of `key_id`.
+    fn get_data_key(&self, key_id: &str) -> Result<Vec<u8>>;
+}
+
+/// The default key management service, which reads the data key of `key_id` from the
+/// environment variable `DATABEND_ENCRYPTION_KEY_<KEY_ID>` as a hex string.
+pub struct EnvKeyManagementService;
+
+impl KeyManagementService for EnvKeyManagementService {
+    fn get_data_key(&self, key_id: &str) -> Result<Vec<u8>> {
+        let name = format!(
+            "DATABEND_ENCRYPTION_KEY_{}",
+            key_id
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() {
+                    c.to_ascii_uppercase()
+                } else {
+                    '_'
+                })
+                .collect::<String>()
+        );
+        let value = std::env::var(&name).map_err(|_| {
+            ErrorCode::ColumnEncryptionError(format!(
+                "data key '{}' not found, expects environment variable {}",
+                key_id, name
+            ))
+        })?;
+        hex::decode(value.trim()).map_err(|e| {
+            ErrorCode::ColumnEncryptionError(format!("invalid data key '{}': {}", key_id, e))
+        })
+    }
+}
+
+/// The wrapper for KeyManagementService.
+pub struct KeyManagementServiceWrapper {
+    service: Box<dyn KeyManagementService>,
+}
+
+impl KeyManagementServiceWrapper {
+    pub fn new(service: Box<dyn KeyManagementService>) -> Self {
+        Self { service }
+    }
+
+    pub fn get_data_key(&self, key_id: &str) -> Result<Vec<u8>> {
+        self.service.get_data_key(key_id)
+    }
+}
+
+/// Fetch the KeyManagementServiceWrapper from the global instance, fallback to
+/// [`EnvKeyManagementService`] if none is registered.
+pub fn get_key_management_service() -> Arc<KeyManagementServiceWrapper> {
+    GlobalInstance::try_get().unwrap_or_else(|| {
+        Arc::new(KeyManagementServiceWrapper::new(Box::new(
+            EnvKeyManagementService,
+        )))
+    })
+}
+
+fn aead_key(key: &[u8]) -> Result<LessSafeKey> {
+    let key = UnboundKey::new(&AES_256_GCM, key).map_err(|_| {
+        ErrorCode::ColumnEncryptionError(format!(
+            "invalid data key length {}, expects 32 bytes",
+            key.len()
+        ))
+    })?;
+    Ok(LessSafeKey::new(key))
+}
+
+/// Encrypt the column chunk in place by AES-256-GCM, the column id is authenticated as
+/// the associated data. Returns the nonce and the authentication tag.
+pub fn encrypt_column_chunk(
+    key: &[u8],
+    column_id: ColumnId,
+    chunk: &mut [u8],
+) -> Result<(Vec<u8>, Vec<u8>)> {
+    let key = aead_key(key)?;
+    let mut nonce = [0u8; NONCE_LEN];
+    SystemRandom::new()
+        .fill(&mut nonce)
+        .map_err(|_| ErrorCode::ColumnEncryptionError("fail to generate nonce"))?;
+    let tag = key
+        .seal_in_place_separate_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::from(column_id.to_le_bytes()),
+            chunk,
+        )
+        .map_err(|_| ErrorCode::ColumnEncryptionError("fail to encrypt column chunk"))?;
+    Ok((nonce.to_vec(), tag.as_ref().to_vec()))
+}
+
+/// Decrypt the column chunk encrypted by [`encrypt_column_chunk`].
+pub fn decrypt_column_chunk(
+    key: &[u8],
+    column_id: ColumnId,
+    nonce: &[u8],
+    tag: &[u8],
+    chunk: &[u8],
+) -> Result<Vec<u8>> {
+    let key = aead_key(key)?;
+    let nonce = Nonce::try_assume_unique_for_key(nonce)
+        .map_err(|_| ErrorCode::ColumnEncryptionError("invalid nonce of column chunk"))?;
+    let mut in_out = Vec::with_capacity(chunk.len() + tag.len());
+    in_out.extend_from_slice(chunk);
+    in_out.extend_from_slice(tag);
+    let len = key
+        .open_in_place(nonce, Aad::from(column_id.to_le_bytes()), &mut in_out)
+        .map_err(|_| {
+            ErrorCode::ColumnEncryptionError(format!(
+                "fail to decrypt the chunk of column {}, the data key may be wrong",
+                column_id
+            ))
+        })?
+        .len();
+    in_out.truncate(len);
+    Ok(in_out)
+}
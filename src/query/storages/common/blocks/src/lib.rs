@@ -15,5 +15,13 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod block;
+mod encryption;
 
 pub use block::blocks_to_parquet;
+pub use block::blocks_to_parquet_with_codecs;
+pub use encryption::decrypt_column_chunk;
+pub use encryption::encrypt_column_chunk;
+pub use encryption::get_key_management_service;
+pub use encryption::EnvKeyManagementService;
+pub use encryption::KeyManagementService;
+pub use encryption::KeyManagementServiceWrapper;
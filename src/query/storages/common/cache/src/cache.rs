@@ -14,6 +14,8 @@
 
 use std::hash::BuildHasher;
 use std::hash::Hash;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use common_cache::Count;
@@ -50,17 +52,44 @@ where Self: Sized
         NamedCache {
             name: name.into(),
             cache: self,
+            statistics: Arc::new(CacheStatistics::default()),
         }
     }
 }
 
 impl<T> Named for T where T: Sized + Clone {}
 
+/// The hits and misses of a cache since it was created.
+#[derive(Default)]
+pub struct CacheStatistics {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl CacheStatistics {
+    pub fn inc_hit(&self) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn inc_miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn hit_count(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    pub fn miss_count(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+}
+
 /// A named cache that with embedded metrics logging
 #[derive(Clone)]
 pub struct NamedCache<C> {
     name: String,
     cache: C,
+    statistics: Arc<CacheStatistics>,
 }
 
 impl<C> NamedCache<C> {
@@ -68,6 +97,11 @@ impl<C> NamedCache<C> {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    #[inline]
+    pub fn statistics(&self) -> &CacheStatistics {
+        &self.statistics
+    }
 }
 
 impl<K, V, S, M, C> CacheAccessor<K, V, S, M> for NamedCache<C>
@@ -82,10 +116,12 @@ where
         match self.cache.get(k) {
             None => {
                 metrics_inc_cache_miss_count(1, &self.name);
+                self.statistics.inc_miss();
                 None
             }
             v @ Some(_) => {
                 metrics_inc_cache_hit_count(1, &self.name);
+                self.statistics.inc_hit();
                 v
             }
         }
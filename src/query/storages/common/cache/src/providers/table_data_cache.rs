@@ -32,6 +32,7 @@ use crate::metrics_inc_cache_population_overflow_count;
 use crate::metrics_inc_cache_population_pending_count;
 use crate::providers::LruDiskCacheHolder;
 use crate::CacheAccessor;
+use crate::CacheStatistics;
 use crate::LruDiskCacheBuilder;
 
 struct CacheItem {
@@ -69,6 +70,13 @@ pub struct TableDataCache<T = LruDiskCacheHolder> {
     external_cache: T,
     population_queue: crossbeam_channel::Sender<CacheItem>,
     _cache_populator: DiskCachePopulator,
+    statistics: Arc<CacheStatistics>,
+}
+
+impl<T> TableDataCache<T> {
+    pub fn statistics(&self) -> &CacheStatistics {
+        &self.statistics
+    }
 }
 
 const TABLE_DATA_CACHE_NAME: &str = "table_data";
@@ -87,6 +95,7 @@ impl TableDataCacheBuilder {
             external_cache: disk_cache.clone(),
             population_queue: rx,
             _cache_populator: DiskCachePopulator::new(tx, disk_cache, num_population_thread)?,
+            statistics: Arc::new(CacheStatistics::default()),
         })
     }
 }
@@ -97,9 +106,11 @@ impl CacheAccessor<String, Bytes, DefaultHashBuilder, Count> for TableDataCache
         let k = k.as_ref();
         if let Some(item) = self.external_cache.get(k) {
             metrics_inc_cache_hit_count(1, TABLE_DATA_CACHE_NAME);
+            self.statistics.inc_hit();
             Some(item)
         } else {
             metrics_inc_cache_miss_count(1, TABLE_DATA_CACHE_NAME);
+            self.statistics.inc_miss();
             None
         }
     }
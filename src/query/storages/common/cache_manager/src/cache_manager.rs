@@ -34,6 +34,7 @@ use crate::caches::BloomIndexMetaCache;
 use crate::caches::ColumnArrayCache;
 use crate::caches::CompactSegmentInfoCache;
 use crate::caches::FileMetaDataCache;
+use crate::caches::SnapshotHistoryCache;
 use crate::caches::TableSnapshotCache;
 use crate::caches::TableSnapshotStatisticCache;
 use crate::BloomIndexFilterMeter;
@@ -42,10 +43,12 @@ use crate::CompactSegmentInfoMeter;
 use crate::PrunePartitionsCache;
 
 static DEFAULT_FILE_META_DATA_CACHE_ITEMS: u64 = 3000;
+static DEFAULT_SNAPSHOT_HISTORY_CACHE_ITEMS: u64 = 1000;
 
 /// Where all the caches reside
 pub struct CacheManager {
     table_snapshot_cache: Option<TableSnapshotCache>,
+    snapshot_history_cache: Option<SnapshotHistoryCache>,
     table_statistic_cache: Option<TableSnapshotStatisticCache>,
     segment_info_cache: Option<CompactSegmentInfoCache>,
     bloom_index_filter_cache: Option<BloomIndexFilterCache>,
@@ -104,6 +107,7 @@ impl CacheManager {
         if !config.enable_table_meta_cache {
             GlobalInstance::set(Arc::new(Self {
                 table_snapshot_cache: None,
+                snapshot_history_cache: None,
                 segment_info_cache: None,
                 bloom_index_filter_cache: None,
                 bloom_index_meta_cache: None,
@@ -116,6 +120,8 @@ impl CacheManager {
         } else {
             let table_snapshot_cache =
                 Self::new_item_cache(config.table_meta_snapshot_count, "table_snapshot");
+            let snapshot_history_cache =
+                Self::new_item_cache(DEFAULT_SNAPSHOT_HISTORY_CACHE_ITEMS, "snapshot_history");
             let table_statistic_cache =
                 Self::new_item_cache(config.table_meta_statistic_count, "table_statistics");
             let segment_info_cache = Self::new_in_memory_cache(
@@ -139,6 +145,7 @@ impl CacheManager {
                 Self::new_item_cache(DEFAULT_FILE_META_DATA_CACHE_ITEMS, "parquet_file_meta");
             GlobalInstance::set(Arc::new(Self {
                 table_snapshot_cache,
+                snapshot_history_cache,
                 segment_info_cache,
                 bloom_index_filter_cache,
                 bloom_index_meta_cache,
@@ -161,6 +168,10 @@ impl CacheManager {
         self.table_snapshot_cache.clone()
     }
 
+    pub fn get_snapshot_history_cache(&self) -> Option<SnapshotHistoryCache> {
+        self.snapshot_history_cache.clone()
+    }
+
     pub fn get_table_snapshot_statistics_cache(&self) -> Option<TableSnapshotStatisticCache> {
         self.table_statistic_cache.clone()
     }
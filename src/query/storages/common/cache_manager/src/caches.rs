@@ -30,6 +30,7 @@ use storages_common_index::filters::Xor8Filter;
 use storages_common_index::BloomIndexMeta;
 use storages_common_table_meta::meta::CompactSegmentInfo;
 use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::SnapshotHistory;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 
@@ -42,6 +43,8 @@ pub type CompactSegmentInfoCache = NamedCache<
 
 /// In memory object cache of TableSnapshot
 pub type TableSnapshotCache = NamedCache<InMemoryItemCacheHolder<TableSnapshot>>;
+/// In memory object cache of the history walked from a snapshot, keyed by the snapshot location
+pub type SnapshotHistoryCache = NamedCache<InMemoryItemCacheHolder<SnapshotHistory>>;
 /// In memory object cache of TableSnapshotStatistics
 pub type TableSnapshotStatisticCache = NamedCache<InMemoryItemCacheHolder<TableSnapshotStatistics>>;
 /// In memory object cache of bloom filter.
@@ -101,6 +104,13 @@ impl CachedObject<TableSnapshot> for TableSnapshot {
     }
 }
 
+impl CachedObject<SnapshotHistory> for SnapshotHistory {
+    type Cache = SnapshotHistoryCache;
+    fn cache() -> Option<Self::Cache> {
+        CacheManager::instance().get_snapshot_history_cache()
+    }
+}
+
 impl CachedObject<TableSnapshotStatistics> for TableSnapshotStatistics {
     type Cache = TableSnapshotStatisticCache;
     fn cache() -> Option<Self::Cache> {
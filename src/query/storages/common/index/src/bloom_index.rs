@@ -178,6 +178,7 @@ impl BloomIndex {
         version: u64,
         data_blocks_tobe_indexed: &[&DataBlock],
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        bloom_path_fields: &[(FieldIndex, TableField, Vec<String>)],
     ) -> Result<Option<Self>> {
         if data_blocks_tobe_indexed.is_empty() {
             return Err(ErrorCode::BadArguments("block is empty"));
@@ -235,23 +236,8 @@ impl BloomIndex {
                 }
             };
 
-            let (column, validity) =
-                Self::calculate_nullable_column_digest(&func_ctx, &column, &data_type)?;
-
             // create filter per column
-            let mut filter_builder = Xor8Builder::create();
-            if validity.as_ref().map(|v| v.unset_bits()).unwrap_or(0) > 0 {
-                let validity = validity.unwrap();
-                let it = column.deref().iter().zip(validity.iter()).map(
-                    |(v, b)| {
-                        if !b { &0 } else { v }
-                    },
-                );
-                filter_builder.add_digests(it);
-            } else {
-                filter_builder.add_digests(column.deref());
-            }
-            let filter = filter_builder.build()?;
+            let filter = Self::build_filter(&func_ctx, &column, &data_type)?;
 
             if let Some(len) = filter.len() {
                 match field.data_type() {
@@ -267,6 +253,50 @@ impl BloomIndex {
             filters.push(Arc::new(filter));
         }
 
+        // Secondary filters on the values extracted by json paths or map keys.
+        let num_rows = data_blocks_tobe_indexed
+            .iter()
+            .map(|block| block.num_rows())
+            .sum::<usize>();
+        for (index, field, keys) in bloom_path_fields {
+            let field_type = &data_blocks_tobe_indexed[0].get_by_offset(*index).data_type;
+            let source_columns_iter = data_blocks_tobe_indexed.iter().map(|block| {
+                let value = &block.get_by_offset(*index).value;
+                value.convert_to_full_column(field_type, block.num_rows())
+            });
+            let mut column = Column::concat_columns(source_columns_iter)?;
+            let mut data_type = field_type.clone();
+            for key in keys {
+                let (value, return_type) = eval_function(
+                    None,
+                    "get",
+                    [
+                        (Value::Column(column), data_type),
+                        (
+                            Value::Scalar(Scalar::String(key.as_bytes().to_vec())),
+                            DataType::String,
+                        ),
+                    ],
+                    &func_ctx,
+                    num_rows,
+                    &BUILTIN_FUNCTIONS,
+                )?;
+                column = value.convert_to_full_column(&return_type, num_rows);
+                data_type = return_type;
+            }
+
+            if !Self::supported_path_type(&data_type)
+                || Self::check_large_string(&column.remove_nullable())
+            {
+                continue;
+            }
+
+            let filter = Self::build_filter(&func_ctx, &column, &data_type)?;
+            let filter_name = Self::build_path_filter_column_name(version, field, keys)?;
+            filter_fields.push(TableField::new(&filter_name, TableDataType::String));
+            filters.push(Arc::new(filter));
+        }
+
         if filter_fields.is_empty() {
             return Ok(None);
         }
@@ -282,6 +312,30 @@ impl BloomIndex {
         }))
     }
 
+    fn build_filter(
+        func_ctx: &FunctionContext,
+        column: &Column,
+        data_type: &DataType,
+    ) -> Result<Xor8Filter> {
+        let (column, validity) =
+            Self::calculate_nullable_column_digest(func_ctx, column, data_type)?;
+
+        let mut filter_builder = Xor8Builder::create();
+        if validity.as_ref().map(|v| v.unset_bits()).unwrap_or(0) > 0 {
+            let validity = validity.unwrap();
+            let it =
+                column
+                    .deref()
+                    .iter()
+                    .zip(validity.iter())
+                    .map(|(v, b)| if !b { &0 } else { v });
+            filter_builder.add_digests(it);
+        } else {
+            filter_builder.add_digests(column.deref());
+        }
+        filter_builder.build()
+    }
+
     pub fn serialize_to_data_block(&self) -> Result<DataBlock> {
         let fields = self.filter_schema.fields();
         let mut filter_columns = Vec::with_capacity(fields.len());
@@ -305,6 +359,28 @@ impl BloomIndex {
         scalar_map: &HashMap<Scalar, u64>,
         data_schema: TableSchemaRef,
     ) -> Result<FilterEvalResult> {
+        visit_expr_path_eq_constant(
+            &mut expr,
+            &mut |span, col_name, keys, scalar, ty, return_type| {
+                let filter_column = &Self::build_path_filter_column_name(
+                    self.version,
+                    data_schema.field_with_name(col_name)?,
+                    keys,
+                )?;
+
+                if self.find(filter_column, scalar, ty, scalar_map)? == FilterEvalResult::MustFalse
+                {
+                    Ok(Some(Expr::Constant {
+                        span,
+                        scalar: Scalar::Boolean(false),
+                        data_type: return_type.clone(),
+                    }))
+                } else {
+                    Ok(None)
+                }
+            },
+        )?;
+
         visit_expr_column_eq_constant(
             &mut expr,
             &mut |span, col_name, scalar, ty, return_type| {
@@ -423,6 +499,28 @@ impl BloomIndex {
         Ok(cols)
     }
 
+    /// Find all the json paths or map keys that match the pattern of `col[<key>] = <constant>`
+    /// in the expression.
+    pub fn find_eq_paths(
+        expr: &Expr<String>,
+        path_fields: &[(FieldIndex, TableField, Vec<String>)],
+    ) -> Result<Vec<(TableField, Vec<String>, Scalar, DataType)>> {
+        let mut paths = Vec::new();
+        visit_expr_path_eq_constant(
+            &mut expr.clone(),
+            &mut |_, col_name, keys, scalar, ty, _| {
+                if let Some((_, field, _)) = path_fields
+                    .iter()
+                    .find(|(_, field, path)| field.name() == col_name && path == keys)
+                {
+                    paths.push((field.clone(), keys.to_vec(), scalar.clone(), ty.clone()));
+                }
+                Ok(None)
+            },
+        )?;
+        Ok(paths)
+    }
+
     /// For every applicable column, we will create a filter.
     /// The filter will be stored with field name 'Bloom(column_name)'
     pub fn build_filter_column_name(version: u64, field: &TableField) -> Result<String> {
@@ -438,6 +536,30 @@ impl BloomIndex {
         }
     }
 
+    /// The filter of a json path or map key is stored with field name 'Bloom(column_name["key"])'
+    pub fn build_path_filter_column_name(
+        version: u64,
+        field: &TableField,
+        keys: &[String],
+    ) -> Result<String> {
+        let path = keys
+            .iter()
+            .map(|key| format!("[{:?}]", key))
+            .collect::<String>();
+        let index_version = BlockBloomFilterIndexVersion::try_from(version)?;
+        match index_version {
+            BlockBloomFilterIndexVersion::V0(_) => Err(ErrorCode::DeprecatedIndexFormat(
+                "bloom filter index version(v0) is deprecated",
+            )),
+            BlockBloomFilterIndexVersion::V2(_) | BlockBloomFilterIndexVersion::V3(_) => {
+                Ok(format!("Bloom({}{})", field.name(), path))
+            }
+            BlockBloomFilterIndexVersion::V4(_) => {
+                Ok(format!("Bloom({}{})", field.column_id(), path))
+            }
+        }
+    }
+
     fn find(
         &self,
         filter_column: &str,
@@ -446,7 +568,7 @@ impl BloomIndex {
         scalar_map: &HashMap<Scalar, u64>,
     ) -> Result<FilterEvalResult> {
         if !self.filter_schema.has_field(filter_column)
-            || !Xor8Filter::supported_type(ty)
+            || !Self::supported_path_type(ty)
             || target.is_null()
         {
             // The column doesn't have a filter.
@@ -488,10 +610,16 @@ impl BloomIndex {
         Xor8Filter::supported_type(data_type)
     }
 
+    /// The values extracted by json paths are variants, they are indexed by the digests of
+    /// the encoded jsonb values.
+    fn supported_path_type(data_type: &DataType) -> bool {
+        data_type.remove_nullable() == DataType::Variant || Xor8Filter::supported_type(data_type)
+    }
+
     /// Checks if the average length of a string column exceeds 256 bytes.
     /// If it does, the bloom index for the column will not be established.
     fn check_large_string(column: &Column) -> bool {
-        if let Column::String(v) = &column {
+        if let Column::String(v) | Column::Variant(v) = column {
             let bytes_per_row = v.data().len() / v.len().max(1);
             if bytes_per_row > 256 {
                 return true;
@@ -612,3 +740,104 @@ fn visit_map_column(
     }
     Ok(None)
 }
+
+fn visit_expr_path_eq_constant(
+    expr: &mut Expr<String>,
+    visitor: &mut impl FnMut(
+        Span,
+        &str,
+        &[String],
+        &Scalar,
+        &DataType,
+        &DataType,
+    ) -> Result<Option<Expr<String>>>,
+) -> Result<()> {
+    // Find patterns like `Column[<key>]... = <constant>` or `<constant> = Column[<key>]...`,
+    // the access of json paths or map keys are desugared into `get` functions.
+    match expr {
+        Expr::FunctionCall {
+            span,
+            function,
+            args,
+            return_type,
+            ..
+        } if function.signature.name == "eq" => match args.as_slice() {
+            [
+                path_expr @ Expr::FunctionCall { .. },
+                Expr::Constant { scalar, .. },
+            ]
+            | [
+                Expr::Constant { scalar, .. },
+                path_expr @ Expr::FunctionCall { .. },
+            ] => {
+                if let Some((col_name, keys, ty)) = extract_path(path_expr) {
+                    // Only the json strings are compared by their encoded bytes,
+                    // other json values like `1` and `1.0` may be equal.
+                    let comparable = match scalar {
+                        Scalar::Variant(value) => jsonb::as_str(value).is_some(),
+                        Scalar::Null => false,
+                        _ => ty != DataType::Variant,
+                    };
+                    if comparable {
+                        if let Some(new_expr) =
+                            visitor(*span, col_name, &keys, scalar, &ty, return_type)?
+                        {
+                            *expr = new_expr;
+                            return Ok(());
+                        }
+                    }
+                }
+            }
+            _ => (),
+        },
+        _ => (),
+    }
+
+    // Otherwise, rewrite sub expressions.
+    match expr {
+        Expr::Cast { expr, .. } => {
+            visit_expr_path_eq_constant(expr, visitor)?;
+        }
+        Expr::FunctionCall { args, .. } => {
+            for arg in args.iter_mut() {
+                visit_expr_path_eq_constant(arg, visitor)?;
+            }
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+/// Extract the column, the keys and the value type from `get(get(Column, <key>), <key>)`.
+fn extract_path(expr: &Expr<String>) -> Option<(&str, Vec<String>, DataType)> {
+    match expr {
+        Expr::FunctionCall {
+            id,
+            args,
+            return_type,
+            ..
+        } if id.name() == "get" && args.len() == 2 => {
+            let key = match &args[1] {
+                Expr::Constant {
+                    scalar: Scalar::String(key),
+                    ..
+                } => String::from_utf8(key.clone()).ok()?,
+                _ => return None,
+            };
+            let (col_name, mut keys) = match &args[0] {
+                Expr::ColumnRef { id, data_type, .. } => match data_type.remove_nullable() {
+                    DataType::Variant | DataType::Map(_) => (id.as_str(), vec![]),
+                    _ => return None,
+                },
+                inner => {
+                    let (col_name, keys, _) = extract_path(inner)?;
+                    (col_name, keys)
+                }
+            };
+            keys.push(key);
+            Some((col_name, keys, return_type.remove_nullable()))
+        }
+        _ => None,
+    }
+}
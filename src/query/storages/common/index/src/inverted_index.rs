@@ -0,0 +1,225 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use common_arrow::arrow::io::parquet::read as pread;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::string::StringColumnBuilder;
+use common_expression::types::BitmapType;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::UInt32Type;
+use common_expression::types::ValueType;
+use common_expression::ColumnId;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::Expr;
+use common_expression::FieldIndex;
+use common_expression::FromData;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_expression::TableSchemaRef;
+use common_functions::scalars::tokenize_text;
+use roaring::RoaringTreemap;
+
+/// Per-block inverted index of string columns.
+///
+/// For every indexed column, each term produced by [`tokenize_text`] is mapped to the
+/// posting list of the rows (offsets inside the block) containing the term.
+///
+/// The index is persisted as a parquet file with the layout of [`InvertedIndex::schema`],
+/// one row per `(column_id, term)`.
+#[derive(Debug, Default)]
+pub struct InvertedIndex {
+    postings: BTreeMap<ColumnId, BTreeMap<String, RoaringTreemap>>,
+}
+
+impl InvertedIndex {
+    pub fn supported_type(data_type: &TableDataType) -> bool {
+        matches!(data_type.remove_nullable(), TableDataType::String)
+    }
+
+    pub fn schema() -> TableSchemaRef {
+        Arc::new(TableSchema::new(vec![
+            TableField::new("column_id", TableDataType::Number(NumberDataType::UInt32)),
+            TableField::new("term", TableDataType::String),
+            TableField::new("rows", TableDataType::Bitmap),
+        ]))
+    }
+
+    /// Build the inverted index of the given columns of the block.
+    ///
+    /// Returns None if there is nothing to be indexed.
+    pub fn try_create(
+        block: &DataBlock,
+        index_columns_map: &BTreeMap<FieldIndex, TableField>,
+    ) -> Result<Option<Self>> {
+        if block.num_rows() == 0 || index_columns_map.is_empty() {
+            return Ok(None);
+        }
+
+        let mut postings = BTreeMap::new();
+        for (index, field) in index_columns_map.iter() {
+            if !Self::supported_type(field.data_type()) {
+                continue;
+            }
+
+            let entry = block.get_by_offset(*index);
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, block.num_rows());
+
+            let mut terms: BTreeMap<String, RoaringTreemap> = BTreeMap::new();
+            for (row, value) in column.iter().enumerate() {
+                if let ScalarRef::String(text) = value {
+                    for term in tokenize_text(text) {
+                        terms.entry(term).or_default().insert(row as u64);
+                    }
+                }
+            }
+            postings.insert(field.column_id(), terms);
+        }
+
+        if postings.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Self { postings }))
+    }
+
+    pub fn serialize_to_data_block(&self) -> Result<DataBlock> {
+        let num_terms = self.postings.values().map(|terms| terms.len()).sum();
+
+        let mut column_ids = Vec::with_capacity(num_terms);
+        let mut terms = StringColumnBuilder::with_capacity(num_terms, 0);
+        let mut rows = StringColumnBuilder::with_capacity(num_terms, 0);
+        for (column_id, column_terms) in self.postings.iter() {
+            for (term, posting) in column_terms.iter() {
+                column_ids.push(*column_id);
+                terms.put_str(term);
+                terms.commit_row();
+                posting.serialize_into(&mut rows.data)?;
+                rows.commit_row();
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            UInt32Type::from_data(column_ids),
+            StringType::upcast_column(terms.build()),
+            BitmapType::upcast_column(rows.build()),
+        ]))
+    }
+
+    /// Restore the index from the parquet file written from [`InvertedIndex::serialize_to_data_block`].
+    pub fn from_parquet_bytes(data: Vec<u8>) -> Result<Self> {
+        let mut reader = Cursor::new(data);
+        let meta = pread::read_metadata(&mut reader)?;
+        let arrow_schema = pread::infer_schema(&meta)?;
+        let schema = DataSchema::from(Self::schema());
+
+        let mut postings: BTreeMap<ColumnId, BTreeMap<String, RoaringTreemap>> = BTreeMap::new();
+        let chunks =
+            pread::FileReader::new(reader, meta.row_groups, arrow_schema, None, None, None);
+        for chunk in chunks {
+            let block = DataBlock::from_arrow_chunk(&chunk?, &schema)?;
+            let num_rows = block.num_rows();
+            let columns = block
+                .columns()
+                .iter()
+                .map(|entry| {
+                    entry
+                        .value
+                        .convert_to_full_column(&entry.data_type, num_rows)
+                })
+                .collect::<Vec<_>>();
+
+            let column_ids = UInt32Type::try_downcast_column(&columns[0]);
+            let terms = StringType::try_downcast_column(&columns[1]);
+            let rows = BitmapType::try_downcast_column(&columns[2]);
+            let (Some(column_ids), Some(terms), Some(rows)) = (column_ids, terms, rows) else {
+                return Err(ErrorCode::StorageOther("invalid inverted index data"));
+            };
+
+            for ((column_id, term), posting) in column_ids.iter().zip(terms.iter()).zip(rows.iter())
+            {
+                let posting = RoaringTreemap::deserialize_from(posting)?;
+                postings
+                    .entry(*column_id)
+                    .or_default()
+                    .insert(String::from_utf8_lossy(term).into_owned(), posting);
+            }
+        }
+        Ok(Self { postings })
+    }
+
+    /// Returns the rows of the column matching all the terms of the query.
+    ///
+    /// None is returned if the index can not answer the query, e.g. the column is not
+    /// indexed or the query has no terms at all.
+    pub fn search(&self, column_id: ColumnId, query: &str) -> Option<RoaringTreemap> {
+        let terms = self.postings.get(&column_id)?;
+
+        let mut result: Option<RoaringTreemap> = None;
+        for term in tokenize_text(query.as_bytes()) {
+            let Some(posting) = terms.get(&term) else {
+                return Some(RoaringTreemap::new());
+            };
+            result = Some(match result {
+                Some(rows) => rows & posting,
+                None => posting.clone(),
+            });
+        }
+        result
+    }
+
+    /// Find out the `match(column, 'query')` conditions which must all be satisfied by the filter,
+    /// i.e. the ones that are connected by `AND` at the top level.
+    pub fn find_match_columns(expr: &Expr<String>) -> Vec<(String, String)> {
+        let mut result = Vec::new();
+        Self::visit_conjunctions(expr, &mut result);
+        result
+    }
+
+    fn visit_conjunctions(expr: &Expr<String>, result: &mut Vec<(String, String)>) {
+        if let Expr::FunctionCall { function, args, .. } = expr {
+            match function.signature.name.as_str() {
+                "and" | "and_filters" => {
+                    for arg in args {
+                        Self::visit_conjunctions(arg, result);
+                    }
+                }
+                "is_true" => Self::visit_conjunctions(&args[0], result),
+                "match" => {
+                    if let [
+                        Expr::ColumnRef { id, .. },
+                        Expr::Constant {
+                            scalar: Scalar::String(query),
+                            ..
+                        },
+                    ] = args.as_slice()
+                    {
+                        result.push((id.clone(), String::from_utf8_lossy(query).into_owned()));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
@@ -25,6 +25,7 @@ use common_expression::types::DateType;
 use common_expression::types::NumberDataType;
 use common_expression::types::NumberType;
 use common_expression::types::StringType;
+use common_expression::types::TimeType;
 use common_expression::types::TimestampType;
 use common_expression::types::ValueType;
 use common_expression::with_number_mapped_type;
@@ -119,6 +120,12 @@ impl RangeIndex {
     }
 }
 
+/// Convert the statistics of the leaf columns of `data_type` into a domain.
+///
+/// A NULL min or max means the column consists entirely of NULLs, so a nullable
+/// column gets a domain without value, e.g. `IS NOT NULL` folds to false on it.
+/// Sources that can't tell an all-null column from missing min/max must not
+/// provide the statistics at all, in which case the full domain is used.
 pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataType) -> Domain {
     if stats.len() != data_type.num_leaf_columns() {
         return Domain::full(data_type);
@@ -173,6 +180,10 @@ pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataT
         }
         _ => {
             let stat = stats[0];
+            if stat.min().is_null() || stat.max().is_null() {
+                // A non-nullable column can't be all-null, the statistics are incomplete.
+                return Domain::full(data_type);
+            }
             with_number_mapped_type!(|NUM_TYPE| match data_type {
                 DataType::Number(NumberDataType::NUM_TYPE) => {
                     NumberType::<NUM_TYPE>::upcast_domain(SimpleDomain {
@@ -200,6 +211,10 @@ pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataT
                     min: DateType::try_downcast_scalar(&stat.min().as_ref()).unwrap(),
                     max: DateType::try_downcast_scalar(&stat.max().as_ref()).unwrap(),
                 }),
+                DataType::Time => TimeType::upcast_domain(SimpleDomain {
+                    min: TimeType::try_downcast_scalar(&stat.min().as_ref()).unwrap(),
+                    max: TimeType::try_downcast_scalar(&stat.max().as_ref()).unwrap(),
+                }),
                 DataType::Decimal(dec) => match dec {
                     DecimalDataType::Decimal128(sz) => Domain::Decimal(DecimalDomain::Decimal128(
                         SimpleDomain {
@@ -28,6 +28,7 @@ use common_expression::types::AnyType;
 use common_expression::types::DataType;
 use common_expression::types::NumberDataType;
 use common_expression::types::StringType;
+use common_expression::types::VariantType;
 use common_expression::BlockEntry;
 use common_expression::Column;
 use common_expression::ConstantFolder;
@@ -118,6 +119,7 @@ fn test_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        &[],
     )?
     .unwrap();
 
@@ -251,6 +253,7 @@ fn test_specify_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        &[],
     )?
     .unwrap();
 
@@ -291,6 +294,7 @@ fn test_string_bloom_filter() -> Result<()> {
         LatestBloom::VERSION,
         &blocks_ref,
         bloom_columns,
+        &[],
     )?
     .unwrap();
 
@@ -309,6 +313,142 @@ fn test_string_bloom_filter() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_bloom_path_filter() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![
+        TableField::new("0", TableDataType::Variant),
+        TableField::new(
+            "1",
+            TableDataType::Map(Box::new(TableDataType::Tuple {
+                fields_name: vec!["key".to_string(), "value".to_string()],
+                fields_type: vec![TableDataType::String, TableDataType::String],
+            })),
+        ),
+    ]));
+    let map_ty = DataType::Map(Box::new(DataType::Tuple(vec![
+        DataType::String,
+        DataType::String,
+    ])));
+
+    let variant = |s: &str| jsonb::parse_value(s.as_bytes()).unwrap().to_vec();
+    let blocks = vec![DataBlock::new_from_columns(vec![
+        VariantType::from_data(vec![
+            variant(r#"{"user_id":"u1","age":10}"#),
+            variant(r#"{"user_id":"u2","tags":{"k":"v"}}"#),
+        ]),
+        Column::Map(Box::new(
+            ArrayColumn::<KvPair<AnyType, AnyType>> {
+                values: KvColumn {
+                    keys: StringType::from_data(vec!["k1", "k2", "k1"]),
+                    values: StringType::from_data(vec!["a", "b", "c"]),
+                },
+                offsets: Buffer::<u64>::from(vec![0, 2, 3]),
+            }
+            .upcast(),
+        )),
+    ])];
+    let blocks_ref = blocks.iter().collect::<Vec<_>>();
+
+    let path_fields = vec![
+        (0, schema.fields[0].clone(), vec!["user_id".to_string()]),
+        (0, schema.fields[0].clone(), vec![
+            "tags".to_string(),
+            "k".to_string(),
+        ]),
+        (1, schema.fields[1].clone(), vec!["k2".to_string()]),
+    ];
+    let index = BloomIndex::try_create(
+        FunctionContext::default(),
+        LatestBloom::VERSION,
+        &blocks_ref,
+        BTreeMap::new(),
+        &path_fields,
+    )?
+    .unwrap();
+    assert_eq!(3, index.filter_schema.fields().len());
+
+    let cases = vec![
+        (
+            0,
+            DataType::Variant,
+            vec!["user_id"],
+            r#""u2""#,
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            0,
+            DataType::Variant,
+            vec!["user_id"],
+            r#""u3""#,
+            FilterEvalResult::MustFalse,
+        ),
+        (
+            0,
+            DataType::Variant,
+            vec!["tags", "k"],
+            r#""v""#,
+            FilterEvalResult::Uncertain,
+        ),
+        (
+            0,
+            DataType::Variant,
+            vec!["tags", "k"],
+            r#""x""#,
+            FilterEvalResult::MustFalse,
+        ),
+        // Only json strings are compared, `10` may be equal to `10.0`.
+        (
+            0,
+            DataType::Variant,
+            vec!["user_id"],
+            "10",
+            FilterEvalResult::Uncertain,
+        ),
+        // The path is not indexed.
+        (
+            0,
+            DataType::Variant,
+            vec!["age"],
+            r#""u3""#,
+            FilterEvalResult::Uncertain,
+        ),
+    ];
+    for (i, col_ty, keys, val, expected) in cases {
+        let val = Scalar::Variant(variant(val));
+        let result = eval_path_index(
+            &index,
+            &path_fields,
+            schema.clone(),
+            i,
+            col_ty,
+            &keys,
+            val,
+            DataType::Variant,
+        );
+        assert_eq!(expected, result, "{:?} = {}", keys, val);
+    }
+
+    let cases = vec![
+        ("b", FilterEvalResult::Uncertain),
+        ("c", FilterEvalResult::MustFalse),
+    ];
+    for (val, expected) in cases {
+        let result = eval_path_index(
+            &index,
+            &path_fields,
+            schema.clone(),
+            1,
+            map_ty.clone(),
+            &["k2"],
+            Scalar::String(val.as_bytes().to_vec()),
+            DataType::String,
+        );
+        assert_eq!(expected, result, "k2 = {}", val);
+    }
+
+    Ok(())
+}
+
 fn eval_index(
     index: &BloomIndex,
     col_name: &str,
@@ -413,6 +553,66 @@ fn eval_map_index(
     index.apply(expr, &scalar_map, schema).unwrap()
 }
 
+#[allow(clippy::too_many_arguments)]
+fn eval_path_index(
+    index: &BloomIndex,
+    path_fields: &[(FieldIndex, TableField, Vec<String>)],
+    schema: Arc<TableSchema>,
+    i: FieldIndex,
+    col_ty: DataType,
+    keys: &[&str],
+    val: Scalar,
+    ty: DataType,
+) -> FilterEvalResult {
+    let col_name = &schema.fields[i].name;
+    let func_ctx = FunctionContext::default();
+    let mut path_expr = Expr::ColumnRef {
+        span: None,
+        id: col_name.to_string(),
+        data_type: col_ty,
+        display_name: col_name.to_string(),
+    };
+    for key in keys {
+        path_expr = check_function(
+            None,
+            "get",
+            &[],
+            &[path_expr, Expr::Constant {
+                span: None,
+                scalar: Scalar::String(key.as_bytes().to_vec()),
+                data_type: DataType::String,
+            }],
+            &BUILTIN_FUNCTIONS,
+        )
+        .unwrap();
+    }
+    let expr = check_function(
+        None,
+        "eq",
+        &[],
+        &[path_expr, Expr::Constant {
+            span: None,
+            scalar: val,
+            data_type: ty,
+        }],
+        &BUILTIN_FUNCTIONS,
+    )
+    .unwrap();
+    let (expr, _) = ConstantFolder::fold(&expr, &func_ctx, &BUILTIN_FUNCTIONS);
+
+    let point_query_paths = BloomIndex::find_eq_paths(&expr, path_fields).unwrap();
+
+    let mut scalar_map = HashMap::<Scalar, u64>::new();
+    for (_, _, scalar, ty) in point_query_paths.iter() {
+        if !scalar_map.contains_key(scalar) {
+            let digest = BloomIndex::calculate_scalar_digest(&func_ctx, scalar, ty).unwrap();
+            scalar_map.insert(scalar.clone(), digest);
+        }
+    }
+
+    index.apply(expr, &scalar_map, schema).unwrap()
+}
+
 fn bloom_columns_map(
     schema: TableSchemaRef,
     cols: Vec<FieldIndex>,
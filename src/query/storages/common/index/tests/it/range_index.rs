@@ -0,0 +1,124 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use common_exception::Result;
+use common_expression::type_check::check_function;
+use common_expression::types::nullable::NullableDomain;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::number::SimpleDomain;
+use common_expression::types::DataType;
+use common_expression::types::NumberDataType;
+use common_expression::types::NumberDomain;
+use common_expression::Domain;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_functions::BUILTIN_FUNCTIONS;
+use storages_common_index::statistics_to_domain;
+use storages_common_index::RangeIndex;
+use storages_common_table_meta::meta::ColumnStatistics;
+
+fn int32_stats(min: Option<i32>, max: Option<i32>, null_count: u64) -> ColumnStatistics {
+    let scalar =
+        |v: Option<i32>| v.map_or(Scalar::Null, |v| Scalar::Number(NumberScalar::Int32(v)));
+    ColumnStatistics::new(scalar(min), scalar(max), null_count, 0, None)
+}
+
+#[test]
+fn test_statistics_to_domain_nullable() {
+    let ty = DataType::Nullable(Box::new(DataType::Number(NumberDataType::Int32)));
+    let int32_domain = |min, max| {
+        Some(Box::new(Domain::Number(NumberDomain::Int32(
+            SimpleDomain { min, max },
+        ))))
+    };
+
+    // All values are NULL.
+    let stats = int32_stats(None, None, 10);
+    assert_eq!(
+        statistics_to_domain(vec![&stats], &ty),
+        Domain::Nullable(NullableDomain {
+            has_null: true,
+            value: None,
+        })
+    );
+
+    // Some values are NULL.
+    let stats = int32_stats(Some(1), Some(5), 3);
+    assert_eq!(
+        statistics_to_domain(vec![&stats], &ty),
+        Domain::Nullable(NullableDomain {
+            has_null: true,
+            value: int32_domain(1, 5),
+        })
+    );
+
+    // No values are NULL.
+    let stats = int32_stats(Some(1), Some(5), 0);
+    assert_eq!(
+        statistics_to_domain(vec![&stats], &ty),
+        Domain::Nullable(NullableDomain {
+            has_null: false,
+            value: int32_domain(1, 5),
+        })
+    );
+
+    // Incomplete statistics of a non-nullable column.
+    let ty = DataType::Number(NumberDataType::Int32);
+    let stats = int32_stats(None, None, 0);
+    assert_eq!(statistics_to_domain(vec![&stats], &ty), Domain::full(&ty));
+}
+
+#[test]
+fn test_range_index_prune_all_null() -> Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![TableField::new(
+        "a",
+        TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::Int32))),
+    )]));
+    let column = Expr::ColumnRef {
+        span: None,
+        id: "a".to_string(),
+        data_type: DataType::Nullable(Box::new(DataType::Number(NumberDataType::Int32))),
+        display_name: "a".to_string(),
+    };
+
+    let is_not_null = check_function(None, "is_not_null", &[], &[column], &BUILTIN_FUNCTIONS)?;
+    let index = RangeIndex::try_create(
+        FunctionContext::default(),
+        &is_not_null,
+        schema.clone(),
+        HashMap::new(),
+    )?;
+    let all_null = HashMap::from([(0, int32_stats(None, None, 10))]);
+    let some_null = HashMap::from([(0, int32_stats(Some(1), Some(5), 3))]);
+    assert!(!index.apply(&all_null, |_| false)?);
+    assert!(index.apply(&some_null, |_| false)?);
+    // Missing statistics can't prune anything.
+    assert!(index.apply(&HashMap::new(), |_| false)?);
+
+    // `a IS NULL` is bound as `NOT is_not_null(a)`.
+    let is_null = check_function(None, "not", &[], &[is_not_null], &BUILTIN_FUNCTIONS)?;
+    let index =
+        RangeIndex::try_create(FunctionContext::default(), &is_null, schema, HashMap::new())?;
+    let no_null = HashMap::from([(0, int32_stats(Some(1), Some(5), 0))]);
+    assert!(index.apply(&all_null, |_| false)?);
+    assert!(!index.apply(&no_null, |_| false)?);
+    Ok(())
+}
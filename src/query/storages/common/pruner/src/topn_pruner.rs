@@ -17,6 +17,7 @@ use std::sync::Arc;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::RemoteExpr;
+use common_expression::Scalar;
 use common_expression::TableDataType;
 use common_expression::TableSchemaRef;
 use storages_common_table_meta::meta::BlockMeta;
@@ -25,10 +26,15 @@ use crate::BlockMetaIndex;
 
 /// TopN pruner.
 /// Pruning for order by x limit N.
+///
+/// If the sort columns are a prefix of the cluster key, the blocks are pruned by the
+/// cluster statistics, only the blocks that may contain the first N rows are kept.
 pub struct TopNPrunner {
     schema: TableSchemaRef,
     sort: Vec<(RemoteExpr<String>, bool, bool)>,
     limit: usize,
+    // The cluster key id and the cluster key expressions of the table.
+    cluster_key: Option<(u32, Vec<RemoteExpr<String>>)>,
 }
 
 impl TopNPrunner {
@@ -36,11 +42,13 @@ impl TopNPrunner {
         schema: TableSchemaRef,
         sort: Vec<(RemoteExpr<String>, bool, bool)>,
         limit: usize,
+        cluster_key: Option<(u32, Vec<RemoteExpr<String>>)>,
     ) -> Self {
         Self {
             schema,
             sort,
             limit,
+            cluster_key,
         }
     }
 }
@@ -50,6 +58,10 @@ impl TopNPrunner {
         &self,
         metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
     ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        if let Some(metas) = self.prune_by_cluster_stats(&metas) {
+            return Ok(metas);
+        }
+
         if self.sort.len() != 1 {
             return Ok(metas);
         }
@@ -115,3 +127,90 @@ impl TopNPrunner {
             .collect())
     }
 }
+
+impl TopNPrunner {
+    /// Returns `None` if the cluster statistics can't be used.
+    ///
+    /// For ascending order, the blocks are sorted by the minimum of the cluster statistics,
+    /// the first blocks containing at least N rows bound the first N rows with the largest
+    /// maximum of them, then the blocks whose minimum is larger than the bound are pruned.
+    /// The descending order is symmetric.
+    fn prune_by_cluster_stats(
+        &self,
+        metas: &[(BlockMetaIndex, Arc<BlockMeta>)],
+    ) -> Option<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let (cluster_key_id, cluster_keys) = self.cluster_key.as_ref()?;
+        let asc = self.sort.first()?.1;
+        if self.sort.len() > cluster_keys.len() {
+            return None;
+        }
+        for (i, ((expr, expr_asc, _), cluster_key)) in
+            self.sort.iter().zip(cluster_keys).enumerate()
+        {
+            // The nulls of the first column are at the ends of a sorted block, which are
+            // checked by the statistics below, but the nulls of other columns are not tracked.
+            match (expr, cluster_key) {
+                (
+                    RemoteExpr::ColumnRef { id, data_type, .. },
+                    RemoteExpr::ColumnRef { id: key, .. },
+                ) if *expr_asc == asc
+                    && id == key
+                    && (i == 0 || !data_type.is_nullable_or_null()) => {}
+                _ => return None,
+            }
+        }
+
+        // The range of the sort keys of each block.
+        let len = self.sort.len();
+        let mut ranges = Vec::with_capacity(metas.len());
+        for (index, (_, meta)) in metas.iter().enumerate() {
+            let stats = meta.cluster_stats.as_ref()?;
+            if stats.cluster_key_id != *cluster_key_id
+                || stats.min.len() < len
+                || stats.max.len() < len
+            {
+                return None;
+            }
+            let (min, max) = (&stats.min[..len], &stats.max[..len]);
+            // String values are truncated in the cluster statistics, the maximum is
+            // not an upper bound.
+            if min
+                .iter()
+                .chain(max)
+                .any(|v| matches!(v, Scalar::Null | Scalar::String(_)))
+            {
+                return None;
+            }
+            ranges.push((index, min, max));
+        }
+
+        if asc {
+            ranges.sort_by(|a, b| a.1.cmp(b.1));
+        } else {
+            ranges.sort_by(|a, b| b.2.cmp(a.2));
+        }
+
+        let mut rows = 0;
+        let mut bound = None;
+        for (index, min, max) in &ranges {
+            bound = match bound {
+                None => Some(if asc { max } else { min }),
+                Some(bound) if asc => Some(std::cmp::max(bound, max)),
+                Some(bound) => Some(std::cmp::min(bound, min)),
+            };
+            rows += metas[*index].1.live_row_count() as usize;
+            if rows >= self.limit {
+                break;
+            }
+        }
+        let bound = bound?;
+
+        Some(
+            ranges
+                .iter()
+                .filter(|(_, min, max)| if asc { min <= bound } else { max >= bound })
+                .map(|(index, _, _)| metas[*index].clone())
+                .collect(),
+        )
+    }
+}
@@ -202,6 +202,11 @@ fn build_test_segment_info(num_blocks_per_seg: usize) -> common_exception::Resul
         bloom_filter_index_size: 0,
         compression: Compression::Lz4,
         create_on: Some(Utc::now()),
+        deletion_vector_location: None,
+        deleted_row_count: 0,
+        page_index_offset: None,
+        column_compressions: HashMap::new(),
+        column_encryptions: HashMap::new(),
     };
 
     let block_metas = (0..num_blocks_per_seg)
@@ -13,9 +13,12 @@
 // limitations under the License.
 
 pub use v0::ColumnMeta as SingleColumnMeta;
+pub use v1::ColumnHistogram;
+pub use v1::ColumnHistogramBucket;
 pub use v1::TableSnapshotStatistics;
 pub use v2::BlockMeta;
 pub use v2::ClusterStatistics;
+pub use v2::ColumnEncryption;
 pub use v2::ColumnMeta;
 pub use v2::ColumnStatistics;
 pub use v2::Statistics;
@@ -17,6 +17,7 @@
 mod compression;
 mod current;
 mod format;
+mod snapshot_history;
 mod statistics;
 mod utils;
 mod v0;
@@ -34,6 +35,8 @@ pub(crate) use format::load_json;
 pub(crate) use format::MetaCompression;
 pub(crate) use format::MetaEncoding;
 pub use format::NUM_BLOCK_ID_BITS;
+pub use snapshot_history::SnapshotHistory;
+pub use snapshot_history::SnapshotHistoryEntry;
 pub use statistics::*;
 // export legacy versioned table meta types locally,
 // currently, used by versioned readers only
@@ -0,0 +1,57 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::meta::FormatVersion;
+use crate::meta::SnapshotId;
+
+/// A snapshot of the table history, only the fields used to navigate the history are kept.
+#[derive(Clone, Debug)]
+pub struct SnapshotHistoryEntry {
+    pub snapshot_id: SnapshotId,
+    pub timestamp: Option<DateTime<Utc>>,
+    pub location: String,
+    pub format_version: FormatVersion,
+}
+
+/// The snapshots reachable from a snapshot by following the `prev_snapshot_id`, ordered by
+/// timestamp DESC.
+///
+/// The history of a snapshot never changes once the snapshot is written, so the history
+/// walked from a newer snapshot can share the cached history of an older one as its tail.
+#[derive(Debug)]
+pub struct SnapshotHistory {
+    entries: Vec<SnapshotHistoryEntry>,
+    tail: Option<Arc<SnapshotHistory>>,
+}
+
+impl SnapshotHistory {
+    pub fn new(entries: Vec<SnapshotHistoryEntry>, tail: Option<Arc<SnapshotHistory>>) -> Self {
+        SnapshotHistory { entries, tail }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &SnapshotHistoryEntry> {
+        let mut history = Some(self);
+        std::iter::from_fn(move || {
+            let current = history?;
+            history = current.tail.as_deref();
+            Some(current.entries.iter())
+        })
+        .flatten()
+    }
+}
@@ -26,6 +26,19 @@ pub type Location = (String, FormatVersion);
 pub type ClusterKey = (u32, String);
 pub type StatisticsOfColumns = HashMap<ColumnId, ColumnStatistics>;
 
+/// Progress of the incremental recluster, recorded in the table snapshot by each recluster run.
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct ReclusterProgress {
+    /// Number of recluster runs committed since the table was last written by other operations.
+    pub runs: u64,
+    /// Average depth of the blocks examined by the latest run, before they were rewritten.
+    pub average_depth: f64,
+    /// Number of blocks rewritten by the latest run.
+    pub block_count: u64,
+    /// Bytes rewritten by the latest run.
+    pub byte_size: u64,
+}
+
 // Assigned to executors, describes that which blocks of given segment, an executor should take care of
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize, PartialEq)]
 pub struct BlockSlotDescription {
@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 
 use common_expression::ColumnId;
+use common_expression::Scalar;
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -31,14 +32,22 @@ pub struct TableSnapshotStatistics {
     pub snapshot_id: SnapshotId,
 
     pub column_distinct_values: HashMap<ColumnId, u64>,
+
+    /// equi-height histograms of the columns, collected by `ANALYZE TABLE`
+    #[serde(default)]
+    pub column_histograms: HashMap<ColumnId, ColumnHistogram>,
 }
 
 impl TableSnapshotStatistics {
-    pub fn new(column_distinct_values: HashMap<ColumnId, u64>) -> Self {
+    pub fn new(
+        column_distinct_values: HashMap<ColumnId, u64>,
+        column_histograms: HashMap<ColumnId, ColumnHistogram>,
+    ) -> Self {
         Self {
             format_version: TableSnapshotStatistics::VERSION,
             snapshot_id: SnapshotId::new_v4(),
             column_distinct_values,
+            column_histograms,
         }
     }
 
@@ -49,4 +58,24 @@ impl TableSnapshotStatistics {
     pub fn get_column_distinct_values(&self) -> &HashMap<ColumnId, u64> {
         &self.column_distinct_values
     }
+
+    pub fn get_column_histograms(&self) -> &HashMap<ColumnId, ColumnHistogram> {
+        &self.column_histograms
+    }
+}
+
+/// Equi-height histogram of a column.
+///
+/// The first bucket only records the min value of the column, the values of
+/// the other buckets are in the range of (upper bound of previous bucket, upper bound].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ColumnHistogram {
+    pub buckets: Vec<ColumnHistogramBucket>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ColumnHistogramBucket {
+    pub upper_bound: Scalar,
+    pub num_values: f64,
+    pub num_distinct: f64,
 }
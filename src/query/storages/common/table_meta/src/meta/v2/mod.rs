@@ -17,6 +17,7 @@ mod snapshot;
 pub mod statistics;
 
 pub use segment::BlockMeta;
+pub use segment::ColumnEncryption;
 pub use segment::ColumnMeta;
 pub use segment::SegmentInfo;
 pub use snapshot::TableSnapshot;
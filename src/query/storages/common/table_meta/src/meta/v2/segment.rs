@@ -80,6 +80,36 @@ pub struct BlockMeta {
 
     // block create_on
     pub create_on: Option<DateTime<Utc>>,
+
+    /// location of the deletion vector, which marks the rows deleted from the block
+    /// without rewriting it.
+    #[serde(default)]
+    pub deletion_vector_location: Option<Location>,
+    /// number of rows marked as deleted by the deletion vector,
+    /// `row_count` still counts the rows physically stored in the block.
+    #[serde(default)]
+    pub deleted_row_count: u64,
+    /// offset of the parquet page indexes in the block file, which are followed by the footer,
+    /// `None` if the page indexes are not written (native format or blocks written by old versions).
+    #[serde(default)]
+    pub page_index_offset: Option<u64>,
+    /// compression of the columns compressed differently from `compression`, which are
+    /// specified by the column codecs of the table.
+    #[serde(default)]
+    pub column_compressions: HashMap<ColumnId, Compression>,
+    /// encryption of the column chunks encrypted at rest, which are specified by the
+    /// table option `column_encryption`.
+    #[serde(default)]
+    pub column_encryptions: HashMap<ColumnId, ColumnEncryption>,
+}
+
+/// Encryption of a column chunk, the chunk is encrypted in place by AES-256-GCM with the
+/// data key `key_id`, the authentication tag is kept here instead of in the block file.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ColumnEncryption {
+    pub key_id: String,
+    pub nonce: Vec<u8>,
+    pub tag: Vec<u8>,
 }
 
 impl BlockMeta {
@@ -109,6 +139,11 @@ impl BlockMeta {
             bloom_filter_index_size,
             compression,
             create_on,
+            deletion_vector_location: None,
+            deleted_row_count: 0,
+            page_index_offset: None,
+            column_compressions: HashMap::new(),
+            column_encryptions: HashMap::new(),
         }
     }
 
@@ -116,6 +151,19 @@ impl BlockMeta {
         self.compression
     }
 
+    /// Get the compression of the column.
+    pub fn column_compression(&self, column_id: &ColumnId) -> Compression {
+        self.column_compressions
+            .get(column_id)
+            .copied()
+            .unwrap_or(self.compression)
+    }
+
+    /// Number of the rows that are not marked as deleted by the deletion vector.
+    pub fn live_row_count(&self) -> u64 {
+        self.row_count - self.deleted_row_count
+    }
+
     /// Get the page size of the block.
     /// - If the format is parquet, its page size is its row count.
     /// - If the format is native, its page size is the row count of each page.
@@ -254,6 +302,11 @@ impl BlockMeta {
             bloom_filter_index_size: 0,
             compression: Compression::Lz4,
             create_on: None,
+            deletion_vector_location: None,
+            deleted_row_count: 0,
+            page_index_offset: None,
+            column_compressions: HashMap::new(),
+            column_encryptions: HashMap::new(),
         }
     }
 
@@ -285,6 +338,11 @@ impl BlockMeta {
             bloom_filter_index_size: s.bloom_filter_index_size,
             compression: s.compression,
             create_on: None,
+            deletion_vector_location: None,
+            deleted_row_count: 0,
+            page_index_offset: None,
+            column_compressions: HashMap::new(),
+            column_encryptions: HashMap::new(),
         }
     }
 }
@@ -35,6 +35,7 @@ use crate::meta::ClusterKey;
 use crate::meta::FormatVersion;
 use crate::meta::Location;
 use crate::meta::MetaEncoding;
+use crate::meta::ReclusterProgress;
 use crate::meta::SnapshotId;
 use crate::meta::Statistics;
 use crate::meta::Versioned;
@@ -83,6 +84,11 @@ pub struct TableSnapshot {
     // The metadata of the cluster keys.
     pub cluster_key_meta: Option<ClusterKey>,
     pub table_statistics_location: Option<String>,
+
+    /// Progress of the incremental recluster, only kept by the snapshots generated by recluster.
+    //  for backward compatibility, `serde(default)` is used
+    #[serde(default)]
+    pub recluster_progress: Option<ReclusterProgress>,
 }
 
 impl TableSnapshot {
@@ -114,6 +120,7 @@ impl TableSnapshot {
             segments,
             cluster_key_meta,
             table_statistics_location,
+            recluster_progress: None,
         }
     }
 
@@ -221,6 +228,7 @@ impl From<v2::TableSnapshot> for TableSnapshot {
             segments: s.segments,
             cluster_key_meta: s.cluster_key_meta,
             table_statistics_location: s.table_statistics_location,
+            recluster_progress: None,
         }
     }
 }
@@ -242,6 +250,7 @@ where T: Into<v3::TableSnapshot>
             segments: s.segments,
             cluster_key_meta: s.cluster_key_meta,
             table_statistics_location: s.table_statistics_location,
+            recluster_progress: None,
         }
     }
 }
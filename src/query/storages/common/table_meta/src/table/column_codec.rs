@@ -0,0 +1,208 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ComputedExpr;
+use common_expression::FieldIndex;
+use common_expression::TableDataType;
+use common_expression::TableSchema;
+
+use crate::table::TableCompression;
+
+/// Encoding of the pages of a column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnEncoding {
+    Plain,
+    /// Delta encoding of integers, suits sorted or slowly changing values like timestamps.
+    Delta,
+    /// Dictionary encoding, suits low-cardinality strings.
+    Dictionary,
+}
+
+impl ColumnEncoding {
+    fn supports(&self, data_type: &TableDataType) -> bool {
+        match self {
+            ColumnEncoding::Plain => true,
+            ColumnEncoding::Delta => match data_type.remove_nullable() {
+                TableDataType::Number(num_ty) => num_ty.is_integer(),
+                TableDataType::Date | TableDataType::Timestamp | TableDataType::Time => true,
+                _ => false,
+            },
+            ColumnEncoding::Dictionary => {
+                matches!(data_type.remove_nullable(), TableDataType::String)
+            }
+        }
+    }
+}
+
+impl Display for ColumnEncoding {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColumnEncoding::Plain => write!(f, "plain"),
+            ColumnEncoding::Delta => write!(f, "delta"),
+            ColumnEncoding::Dictionary => write!(f, "dictionary"),
+        }
+    }
+}
+
+/// Codec of a column, an optional encoding and an optional compression joined by `+`,
+/// e.g. `delta+zstd`, `dictionary` or `lz4`.
+///
+/// The default encoding and the table compression are used for the parts not specified.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ColumnCodec {
+    pub encoding: Option<ColumnEncoding>,
+    pub compression: Option<TableCompression>,
+}
+
+impl TryFrom<&str> for ColumnCodec {
+    type Error = ErrorCode;
+
+    fn try_from(value: &str) -> Result<Self> {
+        let mut codec = ColumnCodec::default();
+        for part in value.split('+') {
+            let part = part.trim().to_lowercase();
+            let encoding = match part.as_str() {
+                "plain" => Some(ColumnEncoding::Plain),
+                "delta" => Some(ColumnEncoding::Delta),
+                "dictionary" => Some(ColumnEncoding::Dictionary),
+                _ => None,
+            };
+            match encoding {
+                Some(_) if codec.encoding.is_some() => {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "column codec '{}' specifies more than one encoding",
+                        value
+                    )));
+                }
+                Some(encoding) => codec.encoding = Some(encoding),
+                None if part.is_empty() => {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "invalid column codec '{}'",
+                        value
+                    )));
+                }
+                None if codec.compression.is_some() => {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "column codec '{}' specifies more than one compression",
+                        value
+                    )));
+                }
+                None => {
+                    let compression = TableCompression::try_from(part.as_str())
+                        .map_err(|e| ErrorCode::TableOptionInvalid(e.message()))?;
+                    codec.compression = Some(compression);
+                }
+            }
+        }
+        Ok(codec)
+    }
+}
+
+/// Codecs of the columns, parsed from the table option `column_compression`,
+/// e.g. `created_at:delta+zstd, tag:dictionary`.
+///
+/// Codecs only apply to the blocks of parquet format.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ColumnCodecs {
+    codecs: BTreeMap<String, ColumnCodec>,
+}
+
+impl TryFrom<&str> for ColumnCodecs {
+    type Error = ErrorCode;
+
+    fn try_from(value: &str) -> Result<Self> {
+        let mut codecs = BTreeMap::new();
+        for item in value.split(',') {
+            if item.trim().is_empty() {
+                continue;
+            }
+            let (column, codec) = item.split_once(':').ok_or_else(|| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "invalid column codec '{}', expects '<column>:<codec>'",
+                    item.trim()
+                ))
+            })?;
+            let column = column.trim().to_string();
+            let codec = ColumnCodec::try_from(codec)?;
+            if codecs.insert(column.clone(), codec).is_some() {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "codec of column '{}' is specified more than once",
+                    column
+                )));
+            }
+        }
+        Ok(ColumnCodecs { codecs })
+    }
+}
+
+impl ColumnCodecs {
+    pub fn is_empty(&self) -> bool {
+        self.codecs.is_empty()
+    }
+
+    /// Verify that the columns exist in `schema`, and the encodings support their types.
+    pub fn verify(&self, schema: &TableSchema) -> Result<()> {
+        for (column, codec) in &self.codecs {
+            let field = schema.field_with_name(column).map_err(|_| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "column '{}' of the column codec does not exist",
+                    column
+                ))
+            })?;
+            if matches!(field.computed_expr(), Some(ComputedExpr::Virtual(_))) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "codec is not allowed for virtual computed column '{}'",
+                    column
+                )));
+            }
+            if let Some(encoding) = codec.encoding {
+                if !encoding.supports(field.data_type()) {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "{} encoding is not supported by column '{}' of type {}",
+                        encoding,
+                        column,
+                        field.data_type()
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the codecs of the fields of `schema`, keyed by the field index.
+    ///
+    /// Columns that no longer exist or no longer support their encodings are ignored.
+    pub fn field_codecs(&self, schema: &TableSchema) -> BTreeMap<FieldIndex, ColumnCodec> {
+        let mut field_codecs = BTreeMap::new();
+        for (column, codec) in &self.codecs {
+            if let Ok(index) = schema.index_of(column) {
+                let mut codec = *codec;
+                if codec
+                    .encoding
+                    .is_some_and(|encoding| !encoding.supports(schema.fields[index].data_type()))
+                {
+                    codec.encoding = None;
+                }
+                field_codecs.insert(index, codec);
+            }
+        }
+        field_codecs
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ComputedExpr;
+use common_expression::FieldIndex;
+use common_expression::TableSchema;
+
+/// Columns encrypted at rest, parsed from the table option `column_encryption`,
+/// e.g. `ssn, email`.
+///
+/// The data key is referenced by the table option `encryption_key_id`, encryption only
+/// applies to the blocks of parquet format.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EncryptedColumns {
+    columns: BTreeSet<String>,
+}
+
+impl TryFrom<&str> for EncryptedColumns {
+    type Error = ErrorCode;
+
+    fn try_from(value: &str) -> Result<Self> {
+        let mut columns = BTreeSet::new();
+        for column in value.split(',') {
+            let column = column.trim();
+            if column.is_empty() {
+                continue;
+            }
+            if !columns.insert(column.to_string()) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "column '{}' is specified more than once in column_encryption",
+                    column
+                )));
+            }
+        }
+        Ok(EncryptedColumns { columns })
+    }
+}
+
+impl EncryptedColumns {
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+
+    /// Verify that the columns exist in `schema` and are stored.
+    pub fn verify(&self, schema: &TableSchema) -> Result<()> {
+        for column in &self.columns {
+            let field = schema.field_with_name(column).map_err(|_| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "column '{}' of the column encryption does not exist",
+                    column
+                ))
+            })?;
+            if matches!(field.computed_expr(), Some(ComputedExpr::Virtual(_))) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "encryption is not allowed for virtual computed column '{}'",
+                    column
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Get the indexes of the encrypted fields of `schema`, columns that no longer exist
+    /// are ignored.
+    pub fn field_indexes(&self, schema: &TableSchema) -> BTreeSet<FieldIndex> {
+        self.columns
+            .iter()
+            .filter_map(|column| schema.index_of(column).ok())
+            .collect()
+    }
+}
@@ -12,10 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod column_codec;
+mod column_encryption;
 mod table_compression;
 mod table_keys;
 mod table_prefix;
+mod table_row_access_policy;
 
+pub use column_codec::ColumnCodec;
+pub use column_codec::ColumnCodecs;
+pub use column_codec::ColumnEncoding;
+pub use column_encryption::EncryptedColumns;
 pub use table_compression::TableCompression;
 pub use table_keys::*;
 pub use table_prefix::*;
+pub use table_row_access_policy::TableRowAccessPolicy;
@@ -18,7 +18,7 @@ use common_exception::ErrorCode;
 
 use crate::meta;
 
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum TableCompression {
     None,
     LZ4,
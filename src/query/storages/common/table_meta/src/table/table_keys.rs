@@ -20,15 +20,30 @@ pub const OPT_KEY_STORAGE_PREFIX: &str = "storage_prefix";
 pub const OPT_KEY_SNAPSHOT_LOCATION: &str = "snapshot_location";
 pub const OPT_KEY_STORAGE_FORMAT: &str = "storage_format";
 pub const OPT_KEY_TABLE_COMPRESSION: &str = "compression";
+pub const OPT_KEY_COLUMN_COMPRESSION: &str = "column_compression";
+pub const OPT_KEY_COLUMN_ENCRYPTION: &str = "column_encryption";
+pub const OPT_KEY_ENCRYPTION_KEY_ID: &str = "encryption_key_id";
 pub const OPT_KEY_COMMENT: &str = "comment";
 pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
+pub const OPT_KEY_INVERTED_INDEX_COLUMNS: &str = "inverted_index_columns";
+pub const OPT_KEY_BLOOM_INDEX_PATHS: &str = "bloom_index_paths";
+pub const OPT_KEY_ROW_ACCESS_POLICY: &str = "row_access_policy";
 
 // Attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
 // Read only attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_READ_ONLY: &str = "read_only_attached";
 
+// Materialized view options.
+pub const OPT_KEY_MATERIALIZED_VIEW_QUERY: &str = "materialized_view_query";
+// The source table of a materialized view which can be refreshed incrementally.
+pub const OPT_KEY_MATERIALIZED_VIEW_SOURCE_DATABASE: &str = "materialized_view_source_database";
+pub const OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE: &str = "materialized_view_source_table";
+pub const OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID: &str = "materialized_view_source_table_id";
+// The snapshot location of the source table, up to which the materialized view is refreshed.
+pub const OPT_KEY_MATERIALIZED_VIEW_OFFSET: &str = "materialized_view_offset";
+
 /// Legacy table snapshot location key
 ///
 /// # Deprecated
@@ -46,6 +61,12 @@ pub static RESERVED_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_ROW_ACCESS_POLICY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE_DATABASE);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_OFFSET);
     r
 });
 
@@ -54,6 +75,12 @@ pub static INTERNAL_TABLE_OPTION_KEYS: Lazy<HashSet<&'static str>> = Lazy::new(|
     let mut r = HashSet::new();
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_ROW_ACCESS_POLICY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE_DATABASE);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_SOURCE_TABLE_ID);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_OFFSET);
     r
 });
 
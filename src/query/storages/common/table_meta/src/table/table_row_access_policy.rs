@@ -0,0 +1,54 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::table::OPT_KEY_ROW_ACCESS_POLICY;
+
+/// The row access policy attached to a table, kept in the table options
+/// under [`OPT_KEY_ROW_ACCESS_POLICY`].
+///
+/// `columns` are bound to the policy arguments by position.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct TableRowAccessPolicy {
+    pub policy: String,
+    pub columns: Vec<String>,
+}
+
+impl TableRowAccessPolicy {
+    pub fn from_options(options: &BTreeMap<String, String>) -> Result<Option<Self>> {
+        match options.get(OPT_KEY_ROW_ACCESS_POLICY) {
+            None => Ok(None),
+            Some(value) => serde_json::from_str(value).map(Some).map_err(|e| {
+                ErrorCode::IllegalRowAccessPolicy(format!(
+                    "invalid row access policy option {}: {}",
+                    value, e
+                ))
+            }),
+        }
+    }
+
+    pub fn to_option_value(&self) -> Result<String> {
+        serde_json::to_string(self).map_err(|e| {
+            ErrorCode::IllegalRowAccessPolicy(format!(
+                "cannot serialize row access policy option: {}",
+                e
+            ))
+        })
+    }
+}
@@ -0,0 +1,43 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This is the read-only Delta Lake table engine for databend.
+//!
+//! A Delta table is created on the location of an existing Delta Lake table,
+//! the schema and partition columns are loaded from its transaction log:
+//!
+//! ```sql
+//! CREATE TABLE delta_tbl ENGINE = DELTA 's3://bkt/path/to/delta_tbl/' CONNECTION = ( ... );
+//! ```
+//!
+//! Every scan replays the `_delta_log` of the table (the latest parquet checkpoint
+//! and the JSON commits after it) to find the data files of the latest version,
+//! so the changes made by other writers are visible without re-creating the table.
+//!
+//! # Limitations
+//!
+//! - Only the tables without column mapping and deletion vectors (reader version 1 and 2)
+//!   are supported.
+//! - The data files must be stored under the table location.
+
+mod partition;
+mod schema;
+mod snapshot;
+mod table;
+mod table_source;
+
+pub use snapshot::DeltaSnapshot;
+pub use table::DeltaTable;
+pub use table::DELTA_ENGINE;
+pub use table::OPT_KEY_PARTITION_COLUMNS;
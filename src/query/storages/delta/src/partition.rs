@@ -0,0 +1,76 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use common_catalog::plan::PartInfo;
+use common_catalog::plan::PartInfoPtr;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::Scalar;
+
+/// A data file of the Delta table.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct DeltaPartInfo {
+    /// The location relative to the table location.
+    pub location: String,
+    pub size: u64,
+    /// The values of the partition columns, in the order of the partition columns.
+    pub partition_values: Vec<Scalar>,
+}
+
+#[typetag::serde(name = "delta")]
+impl PartInfo for DeltaPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        info.as_any()
+            .downcast_ref::<DeltaPartInfo>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn hash(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        self.location.hash(&mut s);
+        s.finish()
+    }
+}
+
+impl DeltaPartInfo {
+    pub fn create(
+        location: String,
+        size: u64,
+        partition_values: Vec<Scalar>,
+    ) -> Arc<Box<dyn PartInfo>> {
+        Arc::new(Box::new(DeltaPartInfo {
+            location,
+            size,
+            partition_values,
+        }))
+    }
+
+    pub fn from_part(info: &PartInfoPtr) -> Result<&DeltaPartInfo> {
+        info.as_any()
+            .downcast_ref::<DeltaPartInfo>()
+            .ok_or(ErrorCode::Internal(
+                "Cannot downcast from PartInfo to DeltaPartInfo.",
+            ))
+    }
+}
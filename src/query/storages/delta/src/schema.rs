@@ -0,0 +1,152 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DecimalDataType;
+use common_expression::types::DecimalSize;
+use common_expression::types::NumberDataType;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use serde::Deserialize;
+
+/// The schema of a Delta table, which is stored in `metaData.schemaString` as JSON.
+#[derive(Deserialize, Debug)]
+struct DeltaStructType {
+    fields: Vec<DeltaField>,
+}
+
+#[derive(Deserialize, Debug)]
+struct DeltaField {
+    name: String,
+    #[serde(rename = "type")]
+    data_type: DeltaDataType,
+    nullable: bool,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+enum DeltaDataType {
+    Primitive(String),
+    Complex(Box<DeltaComplexType>),
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum DeltaComplexType {
+    Struct {
+        fields: Vec<DeltaField>,
+    },
+    Array {
+        #[serde(rename = "elementType")]
+        element_type: DeltaDataType,
+        #[serde(rename = "containsNull")]
+        contains_null: bool,
+    },
+    Map {
+        #[serde(rename = "keyType")]
+        key_type: DeltaDataType,
+        #[serde(rename = "valueType")]
+        value_type: DeltaDataType,
+        #[serde(rename = "valueContainsNull")]
+        value_contains_null: bool,
+    },
+}
+
+/// Converts the `schemaString` of the Delta table metadata to the table schema.
+pub fn parse_schema_string(schema_string: &str) -> Result<TableSchema> {
+    let schema: DeltaStructType = serde_json::from_str(schema_string)
+        .map_err(|e| ErrorCode::ReadTableDataError(format!("Invalid Delta table schema: {e}")))?;
+    let fields = schema
+        .fields
+        .iter()
+        .map(|f| {
+            Ok(TableField::new(
+                &f.name,
+                to_table_type(&f.data_type, f.nullable)?,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(TableSchema::new(fields))
+}
+
+fn to_table_type(data_type: &DeltaDataType, nullable: bool) -> Result<TableDataType> {
+    let ty = match data_type {
+        DeltaDataType::Primitive(name) => to_primitive_type(name)?,
+        DeltaDataType::Complex(complex) => match complex.as_ref() {
+            DeltaComplexType::Struct { fields } => TableDataType::Tuple {
+                fields_name: fields.iter().map(|f| f.name.clone()).collect(),
+                fields_type: fields
+                    .iter()
+                    .map(|f| to_table_type(&f.data_type, f.nullable))
+                    .collect::<Result<_>>()?,
+            },
+            DeltaComplexType::Array {
+                element_type,
+                contains_null,
+            } => TableDataType::Array(Box::new(to_table_type(element_type, *contains_null)?)),
+            DeltaComplexType::Map {
+                key_type,
+                value_type,
+                value_contains_null,
+            } => TableDataType::Map(Box::new(TableDataType::Tuple {
+                fields_name: vec!["key".to_string(), "value".to_string()],
+                fields_type: vec![
+                    to_table_type(key_type, false)?,
+                    to_table_type(value_type, *value_contains_null)?,
+                ],
+            })),
+        },
+    };
+    if nullable {
+        Ok(ty.wrap_nullable())
+    } else {
+        Ok(ty)
+    }
+}
+
+fn to_primitive_type(name: &str) -> Result<TableDataType> {
+    let ty = match name {
+        "boolean" => TableDataType::Boolean,
+        "byte" => TableDataType::Number(NumberDataType::Int8),
+        "short" => TableDataType::Number(NumberDataType::Int16),
+        "integer" => TableDataType::Number(NumberDataType::Int32),
+        "long" => TableDataType::Number(NumberDataType::Int64),
+        "float" => TableDataType::Number(NumberDataType::Float32),
+        "double" => TableDataType::Number(NumberDataType::Float64),
+        "string" | "binary" => TableDataType::String,
+        "date" => TableDataType::Date,
+        "timestamp" | "timestamp_ntz" => TableDataType::Timestamp,
+        _ => match parse_decimal(name) {
+            Some(size) => TableDataType::Decimal(DecimalDataType::from_size(size)?),
+            None => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "Delta table column type {name} is not supported"
+                )));
+            }
+        },
+    };
+    Ok(ty)
+}
+
+/// Parses the decimal type in the form of `decimal(<precision>,<scale>)`.
+fn parse_decimal(name: &str) -> Option<DecimalSize> {
+    let args = name.strip_prefix("decimal(")?.strip_suffix(')')?;
+    let (precision, scale) = args.split_once(',')?;
+    Some(DecimalSize {
+        precision: precision.trim().parse().ok()?,
+        scale: scale.trim().parse().ok()?,
+    })
+}
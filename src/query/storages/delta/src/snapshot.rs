@@ -0,0 +1,355 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use bytes::Bytes;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::TableSchema;
+use futures::TryStreamExt;
+use opendal::ErrorKind;
+use opendal::Operator;
+use parquet::file::reader::FileReader;
+use parquet::file::serialized_reader::SerializedFileReader;
+use parquet::record::Field;
+use parquet::record::Row;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::schema::parse_schema_string;
+
+const DELTA_LOG_DIR: &str = "_delta_log/";
+const LAST_CHECKPOINT: &str = "_delta_log/_last_checkpoint";
+const MAX_READER_VERSION: i32 = 2;
+
+/// The latest version of a Delta table, replayed from its transaction log.
+#[derive(Debug, Clone)]
+pub struct DeltaSnapshot {
+    pub version: i64,
+    pub schema: TableSchema,
+    pub partition_columns: Vec<String>,
+    pub files: Vec<DeltaDataFile>,
+}
+
+/// The data file added by an `add` action and not removed yet.
+#[derive(Debug, Clone)]
+pub struct DeltaDataFile {
+    /// The path relative to the table location.
+    pub path: String,
+    pub size: u64,
+    pub num_records: Option<u64>,
+    pub partition_values: HashMap<String, Option<String>>,
+}
+
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+struct Action {
+    add: Option<AddAction>,
+    remove: Option<RemoveAction>,
+    meta_data: Option<MetadataAction>,
+    protocol: Option<ProtocolAction>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct AddAction {
+    path: String,
+    #[serde(default)]
+    partition_values: HashMap<String, Option<String>>,
+    size: i64,
+    stats: Option<String>,
+    deletion_vector: Option<Value>,
+}
+
+#[derive(Deserialize, Debug)]
+struct RemoveAction {
+    path: String,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct MetadataAction {
+    schema_string: String,
+    #[serde(default)]
+    partition_columns: Vec<String>,
+    #[serde(default)]
+    configuration: HashMap<String, Option<String>>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct ProtocolAction {
+    min_reader_version: i32,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct FileStats {
+    num_records: Option<u64>,
+}
+
+#[derive(Deserialize, Debug)]
+struct LastCheckpoint {
+    version: i64,
+    parts: Option<u32>,
+}
+
+impl LastCheckpoint {
+    fn paths(&self) -> Vec<String> {
+        match self.parts {
+            None | Some(1) => vec![format!(
+                "{DELTA_LOG_DIR}{:020}.checkpoint.parquet",
+                self.version
+            )],
+            Some(parts) => (1..=parts)
+                .map(|part| {
+                    format!(
+                        "{DELTA_LOG_DIR}{:020}.checkpoint.{part:010}.{parts:010}.parquet",
+                        self.version
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Applies the actions in the order of the log, the files removed later are dropped.
+#[derive(Default)]
+struct LogReplay {
+    files: BTreeMap<String, AddAction>,
+    metadata: Option<MetadataAction>,
+}
+
+impl LogReplay {
+    fn apply(&mut self, action: Action) -> Result<()> {
+        if let Some(protocol) = action.protocol {
+            if protocol.min_reader_version > MAX_READER_VERSION {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "Delta table of reader version {} is not supported",
+                    protocol.min_reader_version
+                )));
+            }
+        }
+        if let Some(metadata) = action.meta_data {
+            self.metadata = Some(metadata);
+        }
+        if let Some(remove) = action.remove {
+            self.files.remove(&remove.path);
+        }
+        if let Some(add) = action.add {
+            if add.deletion_vector.as_ref().is_some_and(|v| !v.is_null()) {
+                return Err(ErrorCode::Unimplemented(
+                    "Delta table with deletion vectors is not supported",
+                ));
+            }
+            self.files.insert(add.path.clone(), add);
+        }
+        Ok(())
+    }
+
+    /// Applies a JSON commit file, which has one action per line.
+    fn apply_commit(&mut self, path: &str, data: &[u8]) -> Result<()> {
+        for line in data.split(|b| *b == b'\n') {
+            if line.iter().all(|b| b.is_ascii_whitespace()) {
+                continue;
+            }
+            let action: Action = serde_json::from_slice(line).map_err(|e| {
+                ErrorCode::ReadTableDataError(format!("Invalid Delta log {path}: {e}"))
+            })?;
+            self.apply(action)?;
+        }
+        Ok(())
+    }
+
+    /// Applies a parquet checkpoint file, which has one action per row.
+    fn apply_checkpoint(&mut self, path: &str, data: Vec<u8>) -> Result<()> {
+        let invalid = |e: String| {
+            ErrorCode::ReadTableDataError(format!("Invalid Delta checkpoint {path}: {e}"))
+        };
+        let reader =
+            SerializedFileReader::new(Bytes::from(data)).map_err(|e| invalid(e.to_string()))?;
+        let rows = reader
+            .get_row_iter(None)
+            .map_err(|e| invalid(e.to_string()))?;
+        for row in rows {
+            let row = row.map_err(|e| invalid(e.to_string()))?;
+            let action: Action =
+                serde_json::from_value(row_to_json(&row)).map_err(|e| invalid(e.to_string()))?;
+            self.apply(action)?;
+        }
+        Ok(())
+    }
+
+    fn finish(self, version: i64) -> Result<DeltaSnapshot> {
+        let metadata = self.metadata.ok_or_else(|| {
+            ErrorCode::ReadTableDataError("Delta table metadata is not found in _delta_log")
+        })?;
+        if let Some(Some(mode)) = metadata.configuration.get("delta.columnMapping.mode") {
+            if mode != "none" {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "Delta table with column mapping mode {mode} is not supported"
+                )));
+            }
+        }
+        let schema = parse_schema_string(&metadata.schema_string)?;
+        for column in metadata.partition_columns.iter() {
+            if schema.field_with_name(column).is_err() {
+                return Err(ErrorCode::ReadTableDataError(format!(
+                    "Delta table partition column {column} is not found in schema"
+                )));
+            }
+        }
+
+        let files = self
+            .files
+            .into_values()
+            .map(|add| {
+                let num_records = add
+                    .stats
+                    .as_deref()
+                    .and_then(|stats| serde_json::from_str::<FileStats>(stats).ok())
+                    .and_then(|stats| stats.num_records);
+                DeltaDataFile {
+                    path: add.path,
+                    size: add.size as u64,
+                    num_records,
+                    partition_values: add.partition_values,
+                }
+            })
+            .collect();
+
+        Ok(DeltaSnapshot {
+            version,
+            schema,
+            partition_columns: metadata.partition_columns,
+            files,
+        })
+    }
+}
+
+impl DeltaSnapshot {
+    /// Loads the latest snapshot from the `_delta_log` under the root of the operator.
+    #[async_backtrace::framed]
+    pub async fn load(op: &Operator) -> Result<DeltaSnapshot> {
+        let mut replay = LogReplay::default();
+
+        let mut version = -1;
+        if let Some(checkpoint) = read_last_checkpoint(op).await? {
+            for path in checkpoint.paths() {
+                let data = op.read(&path).await?;
+                replay.apply_checkpoint(&path, data)?;
+            }
+            version = checkpoint.version;
+        }
+
+        let commits = list_commits(op, version + 1).await?;
+        let contents =
+            futures::future::try_join_all(commits.iter().map(|(_, path)| op.read(path))).await?;
+        for ((commit_version, path), data) in commits.iter().zip(contents) {
+            if *commit_version != version + 1 {
+                return Err(ErrorCode::ReadTableDataError(format!(
+                    "Delta log of version {} is missing",
+                    version + 1
+                )));
+            }
+            replay.apply_commit(path, &data)?;
+            version = *commit_version;
+        }
+
+        if version < 0 {
+            return Err(ErrorCode::ReadTableDataError(
+                "Not a Delta table, no commit is found in _delta_log",
+            ));
+        }
+        replay.finish(version)
+    }
+}
+
+async fn read_last_checkpoint(op: &Operator) -> Result<Option<LastCheckpoint>> {
+    match op.read(LAST_CHECKPOINT).await {
+        Ok(data) => {
+            let checkpoint = serde_json::from_slice(&data).map_err(|e| {
+                ErrorCode::ReadTableDataError(format!("Invalid Delta {LAST_CHECKPOINT}: {e}"))
+            })?;
+            Ok(Some(checkpoint))
+        }
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Lists the JSON commits since `start_version`, ordered by version.
+async fn list_commits(op: &Operator, start_version: i64) -> Result<Vec<(i64, String)>> {
+    let mut commits = vec![];
+    let mut lister = op.lister(DELTA_LOG_DIR).await?;
+    while let Some(entry) = lister.try_next().await? {
+        let name = entry.name();
+        let Some(version) = name
+            .strip_suffix(".json")
+            .filter(|v| v.len() == 20)
+            .and_then(|v| v.parse::<i64>().ok())
+        else {
+            continue;
+        };
+        if version >= start_version {
+            commits.push((version, entry.path().to_string()));
+        }
+    }
+    commits.sort();
+    Ok(commits)
+}
+
+fn row_to_json(row: &Row) -> Value {
+    Value::Object(
+        row.get_column_iter()
+            .map(|(name, field)| (name.clone(), field_to_json(field)))
+            .collect(),
+    )
+}
+
+fn field_to_json(field: &Field) -> Value {
+    match field {
+        Field::Null => Value::Null,
+        Field::Bool(v) => Value::Bool(*v),
+        Field::Byte(v) => Value::from(*v),
+        Field::Short(v) => Value::from(*v),
+        Field::Int(v) => Value::from(*v),
+        Field::Long(v) => Value::from(*v),
+        Field::UByte(v) => Value::from(*v),
+        Field::UShort(v) => Value::from(*v),
+        Field::UInt(v) => Value::from(*v),
+        Field::ULong(v) => Value::from(*v),
+        Field::Str(v) => Value::String(v.clone()),
+        Field::Group(row) => row_to_json(row),
+        Field::ListInternal(list) => {
+            Value::Array(list.elements().iter().map(field_to_json).collect())
+        }
+        // The keys of the maps in checkpoints, such as `partitionValues`, are all strings.
+        Field::MapInternal(map) => Value::Object(
+            map.entries()
+                .iter()
+                .map(|(key, value)| {
+                    let key = match key {
+                        Field::Str(key) => key.clone(),
+                        other => other.to_string(),
+                    };
+                    (key, field_to_json(value))
+                })
+                .collect(),
+        ),
+        other => Value::String(other.to_string()),
+    }
+}
@@ -0,0 +1,405 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono_tz::Tz;
+use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::ParquetReadOptions;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_args::TableArgs;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::date::string_to_date;
+use common_expression::types::number::NumberScalar;
+use common_expression::types::timestamp::string_to_timestamp;
+use common_expression::types::NumberDataType;
+use common_expression::types::F32;
+use common_expression::types::F64;
+use common_expression::utils::serialize::uniform_date;
+use common_expression::DataSchema;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::storage::StorageParams;
+use common_pipeline_core::Pipeline;
+use common_storage::DataOperator;
+use common_storages_parquet::ParquetRSPruner;
+use common_storages_parquet::ParquetRSReaderBuilder;
+use percent_encoding::percent_decode_str;
+use storages_common_pruner::RangePrunerCreator;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+
+use crate::partition::DeltaPartInfo;
+use crate::snapshot::DeltaDataFile;
+use crate::snapshot::DeltaSnapshot;
+use crate::table_source::DeltaTableSource;
+
+pub const DELTA_ENGINE: &str = "DELTA";
+
+/// The engine option keeping the partition columns of the Delta table, as a JSON array.
+pub const OPT_KEY_PARTITION_COLUMNS: &str = "partition_columns";
+
+/// A read-only table on the location of an existing Delta Lake table.
+pub struct DeltaTable {
+    info: TableInfo,
+    op: DataOperator,
+    partition_columns: Vec<String>,
+}
+
+impl DeltaTable {
+    pub fn try_create(info: TableInfo) -> Result<Box<dyn Table>> {
+        let sp = info.meta.storage_params.as_ref().ok_or_else(|| {
+            ErrorCode::Internal(format!("Delta table {} has no location", info.desc))
+        })?;
+        let op = DataOperator::try_new(sp)?;
+        let partition_columns = match info.meta.engine_options.get(OPT_KEY_PARTITION_COLUMNS) {
+            Some(columns) => serde_json::from_str(columns).map_err(|e| {
+                ErrorCode::Internal(format!("Invalid partition columns of Delta table: {e}"))
+            })?,
+            None => vec![],
+        };
+        Ok(Box::new(DeltaTable {
+            info,
+            op,
+            partition_columns,
+        }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: DELTA_ENGINE.to_string(),
+            comment: "DELTA Storage Engine".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Loads the schema and the engine options of the Delta table at the location,
+    /// which are kept in the table meta when the table is created.
+    #[async_backtrace::framed]
+    pub async fn load_meta(sp: &StorageParams) -> Result<(TableSchema, BTreeMap<String, String>)> {
+        let op = DataOperator::try_create(sp).await?;
+        let snapshot = DeltaSnapshot::load(&op.operator()).await?;
+
+        let mut engine_options = BTreeMap::new();
+        if !snapshot.partition_columns.is_empty() {
+            let columns = serde_json::to_string(&snapshot.partition_columns).map_err(|e| {
+                ErrorCode::Internal(format!("Cannot serialize partition columns: {e}"))
+            })?;
+            engine_options.insert(OPT_KEY_PARTITION_COLUMNS.to_string(), columns);
+        }
+        Ok((snapshot.schema, engine_options))
+    }
+
+    /// The schema of the columns stored in the data files, the partition columns are excluded.
+    fn data_schema(&self) -> TableSchema {
+        let fields = self
+            .schema()
+            .fields()
+            .iter()
+            .filter(|f| !self.partition_columns.contains(f.name()))
+            .cloned()
+            .collect();
+        TableSchema::new(fields)
+    }
+
+    fn do_read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let parts_len = plan.parts.len();
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(parts_len, max_threads);
+
+        let data_schema = Arc::new(self.data_schema());
+        let arrow_schema = data_schema.to_arrow();
+        let arrow_fields = arrow_schema
+            .fields
+            .into_iter()
+            .map(|f| f.into())
+            .collect::<Vec<arrow_schema::Field>>();
+        let arrow_schema = arrow_schema::Schema::new(arrow_fields);
+
+        let mut builder = ParquetRSReaderBuilder::create(
+            ctx.clone(),
+            self.op.operator(),
+            data_schema.clone(),
+            &arrow_schema,
+        )?;
+
+        // The partition columns are not stored in the data files, so the push downs
+        // are only applied to the tables without partition columns.
+        if self.partition_columns.is_empty() {
+            let mut read_options = ParquetReadOptions::default();
+            if !ctx.get_settings().get_enable_parquet_page_index()? {
+                read_options = read_options.with_prune_pages(false);
+            }
+            if !ctx.get_settings().get_enable_parquet_rowgroup_pruning()? {
+                read_options = read_options.with_prune_row_groups(false);
+            }
+            if !ctx.get_settings().get_enable_parquet_prewhere()? {
+                read_options = read_options.with_do_prewhere(false);
+            }
+
+            let leaf_fields = Arc::new(data_schema.leaf_fields());
+            let pruner = ParquetRSPruner::try_create(
+                ctx.get_function_context()?,
+                data_schema.clone(),
+                leaf_fields,
+                &plan.push_downs,
+                read_options,
+            )?;
+            builder = builder
+                .with_options(read_options)
+                .with_push_downs(plan.push_downs.as_ref())
+                .with_pruner(Some(pruner));
+        }
+
+        let parquet_reader = Arc::new(builder.build_full_reader()?);
+
+        let output_schema = Arc::new(DataSchema::from(plan.schema()));
+        let data_schema = Arc::new(DataSchema::from(data_schema));
+        let partition_columns = Arc::new(self.partition_columns.clone());
+        pipeline.add_source(
+            |output| {
+                DeltaTableSource::create(
+                    ctx.clone(),
+                    output,
+                    output_schema.clone(),
+                    data_schema.clone(),
+                    partition_columns.clone(),
+                    parquet_reader.clone(),
+                )
+            },
+            max_threads.max(1),
+        )
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn do_read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let snapshot = DeltaSnapshot::load(&self.op.operator()).await?;
+        if snapshot.partition_columns != self.partition_columns {
+            return Err(ErrorCode::TableSchemaMismatch(format!(
+                "Partition columns of Delta table {} have been changed to {:?}, please re-create the table",
+                self.info.desc, snapshot.partition_columns
+            )));
+        }
+
+        let schema = self.schema();
+        let partition_fields = self
+            .partition_columns
+            .iter()
+            .map(|column| schema.field_with_name(column))
+            .collect::<Result<Vec<_>>>()?;
+
+        let filter = push_downs.as_ref().and_then(|extra| {
+            extra
+                .filters
+                .as_ref()
+                .map(|f| f.filter.as_expr(&BUILTIN_FUNCTIONS))
+        });
+        let pruner =
+            RangePrunerCreator::try_create(ctx.get_function_context()?, &schema, filter.as_ref())?;
+
+        let mut read_rows = 0;
+        let mut read_bytes = 0;
+        let total_files = snapshot.files.len();
+        let mut parts = Vec::with_capacity(total_files);
+        for file in snapshot.files.iter() {
+            let partition_values = partition_fields
+                .iter()
+                .map(|field| get_partition_value(file, field))
+                .collect::<Result<Vec<_>>>()?;
+
+            // Prune the files by the values of partition columns.
+            if !partition_fields.is_empty() {
+                let stats = get_partition_stats(&partition_fields, &partition_values);
+                if !pruner.should_keep(&stats, None) {
+                    continue;
+                }
+            }
+
+            read_rows += file.num_records.unwrap_or_default() as usize;
+            read_bytes += file.size as usize;
+            parts.push(DeltaPartInfo::create(
+                get_location(file)?,
+                file.size,
+                partition_values,
+            ));
+        }
+
+        Ok((
+            PartStatistics::new_estimated(None, read_rows, read_bytes, parts.len(), total_files),
+            Partitions::create_nolazy(PartitionsShuffleKind::Mod, parts),
+        ))
+    }
+}
+
+#[async_trait]
+impl Table for DeltaTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn is_local(&self) -> bool {
+        false
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.info
+    }
+
+    fn name(&self) -> &str {
+        &self.get_table_info().name
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        self.do_read_partitions(ctx, push_downs).await
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        self.do_read_data(ctx, plan, pipeline)
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        None
+    }
+
+    fn support_column_projection(&self) -> bool {
+        true
+    }
+
+    fn support_prewhere(&self) -> bool {
+        self.partition_columns.is_empty()
+    }
+}
+
+/// The paths in the Delta log are URI encoded, and relative to the table location.
+fn get_location(file: &DeltaDataFile) -> Result<String> {
+    let location = percent_decode_str(&file.path)
+        .decode_utf8()
+        .map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Invalid Delta data file {}: {e}", file.path))
+        })?
+        .to_string();
+    if location.contains("://") {
+        return Err(ErrorCode::Unimplemented(format!(
+            "Delta data file {location} out of the table location is not supported"
+        )));
+    }
+    Ok(location)
+}
+
+fn get_partition_value(file: &DeltaDataFile, field: &TableField) -> Result<Scalar> {
+    match file.partition_values.get(field.name()) {
+        Some(Some(value)) => parse_partition_value(value, field),
+        _ if field.is_nullable() => Ok(Scalar::Null),
+        _ => Err(ErrorCode::ReadTableDataError(format!(
+            "Value of partition column {} is missing in Delta data file {}",
+            field.name(),
+            file.path
+        ))),
+    }
+}
+
+/// Parses the partition value, which is serialized as string in the Delta log.
+fn parse_partition_value(value: &str, field: &TableField) -> Result<Scalar> {
+    let scalar = match field.data_type().remove_nullable() {
+        TableDataType::Boolean => value.parse().ok().map(Scalar::Boolean),
+        TableDataType::String => Some(Scalar::String(value.as_bytes().to_vec())),
+        TableDataType::Number(ty) => match ty {
+            NumberDataType::UInt8 => value.parse().ok().map(NumberScalar::UInt8),
+            NumberDataType::UInt16 => value.parse().ok().map(NumberScalar::UInt16),
+            NumberDataType::UInt32 => value.parse().ok().map(NumberScalar::UInt32),
+            NumberDataType::UInt64 => value.parse().ok().map(NumberScalar::UInt64),
+            NumberDataType::Int8 => value.parse().ok().map(NumberScalar::Int8),
+            NumberDataType::Int16 => value.parse().ok().map(NumberScalar::Int16),
+            NumberDataType::Int32 => value.parse().ok().map(NumberScalar::Int32),
+            NumberDataType::Int64 => value.parse().ok().map(NumberScalar::Int64),
+            NumberDataType::Float32 => value
+                .parse::<f32>()
+                .ok()
+                .map(|v| NumberScalar::Float32(F32::from(v))),
+            NumberDataType::Float64 => value
+                .parse::<f64>()
+                .ok()
+                .map(|v| NumberScalar::Float64(F64::from(v))),
+        }
+        .map(Scalar::Number),
+        TableDataType::Date => {
+            string_to_date(value, Tz::UTC).map(|date| Scalar::Date(uniform_date(date)))
+        }
+        TableDataType::Timestamp => {
+            string_to_timestamp(value, Tz::UTC).map(|ts| Scalar::Timestamp(ts.timestamp_micros()))
+        }
+        ty => {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Delta table partition column of type {ty} is not supported"
+            )));
+        }
+    };
+    scalar.ok_or_else(|| {
+        ErrorCode::ReadTableDataError(format!(
+            "Invalid value {value} of Delta table partition column {}",
+            field.name()
+        ))
+    })
+}
+
+/// All the rows of a data file have the same partition values.
+fn get_partition_stats(fields: &[&TableField], values: &[Scalar]) -> StatisticsOfColumns {
+    let mut stats = HashMap::with_capacity(fields.len());
+    for (field, value) in fields.iter().zip(values.iter()) {
+        if !value.is_null() {
+            stats.insert(
+                field.column_id,
+                ColumnStatistics::new(value.clone(), value.clone(), 0, 0, None),
+            );
+        }
+    }
+    stats
+}
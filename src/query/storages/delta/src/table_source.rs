@@ -0,0 +1,230 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_base::base::Progress;
+use common_base::base::ProgressValues;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::DataSchemaRef;
+use common_expression::Scalar;
+use common_expression::Value;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::processors::Processor;
+use common_storages_parquet::ParquetRSFullReader;
+use opendal::Reader;
+use parquet::arrow::async_reader::ParquetRecordBatchStream;
+
+use crate::partition::DeltaPartInfo;
+
+pub struct DeltaTableSource {
+    // Source processor related fields.
+    output: Arc<OutputPort>,
+    scan_progress: Arc<Progress>,
+    // Used for event transforming.
+    ctx: Arc<dyn TableContext>,
+    generated_data: Option<DataBlock>,
+    is_finished: bool,
+
+    // Used to read parquet.
+    output_schema: DataSchemaRef,
+    data_schema: DataSchemaRef,
+    partition_columns: Arc<Vec<String>>,
+    parquet_reader: Arc<ParquetRSFullReader>,
+    stream: Option<ParquetRecordBatchStream<Reader>>,
+    // The partition values of the file being read.
+    partition_values: Vec<Scalar>,
+}
+
+impl DeltaTableSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        output_schema: DataSchemaRef,
+        data_schema: DataSchemaRef,
+        partition_columns: Arc<Vec<String>>,
+        parquet_reader: Arc<ParquetRSFullReader>,
+    ) -> Result<ProcessorPtr> {
+        let scan_progress = ctx.get_scan_progress();
+        Ok(ProcessorPtr::create(Box::new(DeltaTableSource {
+            output,
+            scan_progress,
+            ctx,
+            parquet_reader,
+            output_schema,
+            data_schema,
+            partition_columns,
+            stream: None,
+            partition_values: vec![],
+            generated_data: None,
+            is_finished: false,
+        })))
+    }
+
+    /// Fills the partition columns, which are not stored in the data files.
+    fn fill_partition_columns(&self, block: DataBlock) -> Result<DataBlock> {
+        if self.partition_columns.is_empty() {
+            return Ok(block);
+        }
+
+        let num_rows = block.num_rows();
+        let columns = self
+            .output_schema
+            .fields()
+            .iter()
+            .map(|field| {
+                match self
+                    .partition_columns
+                    .iter()
+                    .position(|c| c == field.name())
+                {
+                    Some(i) => {
+                        let value = self.partition_values[i].clone();
+                        let data_type = if value.is_null() {
+                            field.data_type().clone()
+                        } else {
+                            field.data_type().remove_nullable()
+                        };
+                        Ok(BlockEntry::new(data_type, Value::Scalar(value)))
+                    }
+                    None => {
+                        let offset = self.data_schema.index_of(field.name())?;
+                        Ok(block.get_by_offset(offset).clone())
+                    }
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(DataBlock::new(columns, num_rows))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for DeltaTableSource {
+    fn name(&self) -> String {
+        "DeltaSource".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.is_finished {
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        if self.output.is_finished() {
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        match self.generated_data.take() {
+            None => Ok(Event::Async),
+            Some(data_block) => {
+                let progress_values = ProgressValues {
+                    rows: data_block.num_rows(),
+                    bytes: data_block.memory_size(),
+                };
+                self.scan_progress.incr(&progress_values);
+                self.output.push_data(Ok(data_block));
+                Ok(Event::NeedConsume)
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        if let Some(mut stream) = self.stream.take() {
+            if let Some(block) = self
+                .parquet_reader
+                .read_block_from_stream(&mut stream)
+                .await?
+                .map(|b| self.fill_partition_columns(b))
+                .transpose()?
+                .map(|b| check_block_schema(&self.output_schema, b))
+                .transpose()?
+            {
+                self.generated_data = Some(block);
+                self.stream = Some(stream);
+            }
+            // else:
+            // If `read_block` returns `None`, it means the stream is finished.
+            // And we should try to build another stream (in next event loop).
+        } else if let Some(part) = self.ctx.get_partition() {
+            let part = DeltaPartInfo::from_part(&part)?;
+            let stream = self
+                .parquet_reader
+                .prepare_data_stream(&part.location)
+                .await?;
+            self.partition_values = part.partition_values.clone();
+            self.stream = Some(stream);
+        } else {
+            self.is_finished = true;
+        }
+
+        Ok(())
+    }
+}
+
+fn check_block_schema(schema: &DataSchema, mut block: DataBlock) -> Result<DataBlock> {
+    // Check if the schema of the data block is matched with the schema of the table.
+    if block.num_columns() != schema.num_fields() {
+        return Err(ErrorCode::TableSchemaMismatch(format!(
+            "Data schema mismatched. Data columns length: {}, schema fields length: {}",
+            block.num_columns(),
+            schema.num_fields()
+        )));
+    }
+
+    for (col, field) in block.columns_mut().iter_mut().zip(schema.fields().iter()) {
+        // If the actual data is nullable, the field must be nullbale.
+        if col.data_type.is_nullable_or_null() && !field.is_nullable() {
+            return Err(ErrorCode::TableSchemaMismatch(format!(
+                "Data schema mismatched (col name: {}). Data column is nullable, but schema field is not nullable",
+                field.name()
+            )));
+        }
+        // The inner type of the data and field should be the same.
+        let data_type = col.data_type.remove_nullable();
+        let schema_type = field.data_type().remove_nullable();
+        if data_type != schema_type {
+            return Err(ErrorCode::TableSchemaMismatch(format!(
+                "Data schema mismatched (col name: {}). Data column type is {:?}, but schema field type is {:?}",
+                field.name(),
+                col.data_type,
+                field.data_type()
+            )));
+        }
+        // If the field is nullable but the actual data is not nullable,
+        // we should wrap nullable for the data.
+        if field.is_nullable() && !col.data_type.is_nullable_or_null() {
+            col.data_type = col.data_type.wrap_nullable();
+            col.value = col.value.clone().wrap_nullable(None);
+        }
+    }
+
+    Ok(block)
+}
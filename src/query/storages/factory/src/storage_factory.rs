@@ -19,9 +19,11 @@ use common_config::InnerConfig;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::TableInfo;
+use common_storages_delta::DeltaTable;
 use common_storages_memory::MemoryTable;
 use common_storages_null::NullTable;
 use common_storages_random::RandomTable;
+use common_storages_stream::StreamTable;
 use common_storages_view::view_table::ViewTable;
 use dashmap::DashMap;
 
@@ -102,6 +104,18 @@ impl StorageFactory {
             descriptor: Arc::new(RandomTable::description),
         });
 
+        // Register DELTA table engine
+        creators.insert("DELTA".to_string(), Storage {
+            creator: Arc::new(DeltaTable::try_create),
+            descriptor: Arc::new(DeltaTable::description),
+        });
+
+        // Register STREAM table engine
+        creators.insert("STREAM".to_string(), Storage {
+            creator: Arc::new(StreamTable::try_create),
+            descriptor: Arc::new(StreamTable::description),
+        });
+
         StorageFactory { storages: creators }
     }
 
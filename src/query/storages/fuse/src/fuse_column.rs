@@ -18,6 +18,9 @@ use common_catalog::statistics::BasicColumnStatistics;
 use common_catalog::table::ColumnStatisticsProvider;
 use common_expression::ColumnId;
 use common_storage::Datum;
+use common_storage::Histogram;
+use common_storage::HistogramBucket;
+use storages_common_table_meta::meta::ColumnHistogram;
 use storages_common_table_meta::meta::ColumnStatistics as FuseColumnStatistics;
 
 /// A column statistics provider for fuse table.
@@ -30,6 +33,7 @@ impl FuseTableColumnStatisticsProvider {
     pub fn new(
         column_stats: HashMap<ColumnId, FuseColumnStatistics>,
         column_distinct_values: Option<HashMap<ColumnId, u64>>,
+        column_histograms: HashMap<ColumnId, ColumnHistogram>,
         row_count: u64,
     ) -> Self {
         let column_stats = column_stats
@@ -43,6 +47,7 @@ impl FuseTableColumnStatisticsProvider {
                     max: Datum::from_scalar(stat.max().clone()),
                     ndv: Some(ndv),
                     null_count: stat.null_count,
+                    histogram: column_histograms.get(&column_id).and_then(to_histogram),
                 };
                 (column_id, stat.get_useful_stat(row_count))
             })
@@ -51,6 +56,21 @@ impl FuseTableColumnStatisticsProvider {
     }
 }
 
+/// Convert the persisted histogram to the one used by the optimizer,
+/// returns `None` if any of the upper bounds can not be converted.
+fn to_histogram(histogram: &ColumnHistogram) -> Option<Histogram> {
+    let buckets = histogram
+        .buckets
+        .iter()
+        .map(|bucket| {
+            Datum::from_scalar(bucket.upper_bound.clone()).map(|upper_bound| {
+                HistogramBucket::new(upper_bound, bucket.num_values, bucket.num_distinct)
+            })
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(Histogram::new(buckets))
+}
+
 impl ColumnStatisticsProvider for FuseTableColumnStatisticsProvider {
     fn column_statistics(&self, column_id: ColumnId) -> Option<&BasicColumnStatistics> {
         self.column_stats.get(&column_id).and_then(|s| s.as_ref())
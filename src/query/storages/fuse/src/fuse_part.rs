@@ -29,9 +29,11 @@ use common_exception::Result;
 use common_expression::ColumnId;
 use common_expression::Scalar;
 use storages_common_pruner::BlockMetaIndex;
+use storages_common_table_meta::meta::ColumnEncryption;
 use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Compression;
 use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::StatisticsOfColumns;
 
 /// Fuse table partition information.
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
@@ -42,9 +44,37 @@ pub struct FusePartInfo {
     pub nums_rows: usize,
     pub columns_meta: HashMap<ColumnId, ColumnMeta>,
     pub compression: Compression,
+    /// compression of the columns compressed differently from `compression`.
+    pub column_compressions: HashMap<ColumnId, Compression>,
+    /// encryption of the columns encrypted at rest.
+    pub column_encryptions: HashMap<ColumnId, ColumnEncryption>,
 
     pub sort_min_max: Option<(Scalar, Scalar)>,
     pub block_meta_index: Option<BlockMetaIndex>,
+    /// location of the deletion vector of the block, if any.
+    pub deletion_vector_location: Option<String>,
+    /// statistics of the columns, used to prune the block by runtime filters.
+    pub columns_stat: Option<StatisticsOfColumns>,
+    /// location and size of the bloom index of the block, used to prune the block by runtime filters.
+    pub bloom_index: Option<(Location, u64)>,
+    /// byte range of the parquet page indexes and the footer of the block file, if any.
+    pub page_index: Option<Range<u64>>,
+    /// rows of the block selected by the page indexes, `None` means all the rows are read.
+    pub page_selection: Option<PageSelection>,
+}
+
+/// Rows of a parquet block selected by the page indexes, see [`ParquetPagePruner`].
+///
+/// Only the pages overlapping the selected rows are read, since the page boundaries differ
+/// between columns, the rows read of each column may start before and end after `rows`.
+///
+/// [`ParquetPagePruner`]: crate::pruning::ParquetPagePruner
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Clone, Debug)]
+pub struct PageSelection {
+    /// the selected rows of the block.
+    pub rows: Range<usize>,
+    /// rows covered by the pages read of each column, which contain `rows`.
+    pub column_rows: HashMap<ColumnId, Range<usize>>,
 }
 
 #[typetag::serde(name = "fuse")]
@@ -67,14 +97,21 @@ impl PartInfo for FusePartInfo {
 }
 
 impl FusePartInfo {
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         location: String,
         rows_count: u64,
         columns_meta: HashMap<ColumnId, ColumnMeta>,
         compression: Compression,
+        column_compressions: HashMap<ColumnId, Compression>,
+        column_encryptions: HashMap<ColumnId, ColumnEncryption>,
         sort_min_max: Option<(Scalar, Scalar)>,
         block_meta_index: Option<BlockMetaIndex>,
         create_on: Option<DateTime<Utc>>,
+        deletion_vector_location: Option<String>,
+        columns_stat: Option<StatisticsOfColumns>,
+        bloom_index: Option<(Location, u64)>,
+        page_index: Option<Range<u64>>,
     ) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(FusePartInfo {
             location,
@@ -82,8 +119,15 @@ impl FusePartInfo {
             columns_meta,
             nums_rows: rows_count as usize,
             compression,
+            column_compressions,
+            column_encryptions,
             sort_min_max,
             block_meta_index,
+            deletion_vector_location,
+            columns_stat,
+            bloom_index,
+            page_index,
+            page_selection: None,
         }))
     }
 
@@ -105,6 +149,31 @@ impl FusePartInfo {
         self.block_meta_index.as_ref()
     }
 
+    /// Create a partition reading the pages of `selection` only, `columns_meta` are the byte
+    /// ranges of the pages of each column.
+    pub fn with_page_selection(
+        &self,
+        columns_meta: HashMap<ColumnId, ColumnMeta>,
+        selection: PageSelection,
+    ) -> PartInfoPtr {
+        Arc::new(Box::new(FusePartInfo {
+            location: self.location.clone(),
+            create_on: self.create_on,
+            nums_rows: self.nums_rows,
+            columns_meta,
+            compression: self.compression,
+            column_compressions: self.column_compressions.clone(),
+            column_encryptions: self.column_encryptions.clone(),
+            sort_min_max: self.sort_min_max.clone(),
+            block_meta_index: self.block_meta_index.clone(),
+            deletion_vector_location: self.deletion_vector_location.clone(),
+            columns_stat: self.columns_stat.clone(),
+            bloom_index: self.bloom_index.clone(),
+            page_index: self.page_index.clone(),
+            page_selection: Some(selection),
+        }))
+    }
+
     pub fn page_size(&self) -> usize {
         self.block_meta_index
             .as_ref()
@@ -14,6 +14,7 @@
 
 use std::any::Any;
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::str;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -40,6 +41,7 @@ use common_meta_app::schema::UpsertTableCopiedFileReq;
 use common_sharing::create_share_table_operator;
 use common_sql::parse_exprs;
 use common_sql::BloomIndexColumns;
+use common_sql::BloomIndexPaths;
 use common_storage::init_operator;
 use common_storage::DataOperator;
 use common_storage::ShareTableConfig;
@@ -56,9 +58,16 @@ use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
 use storages_common_table_meta::meta::Versioned;
 use storages_common_table_meta::table::table_storage_prefix;
+use storages_common_table_meta::table::ColumnCodecs;
+use storages_common_table_meta::table::EncryptedColumns;
 use storages_common_table_meta::table::TableCompression;
 use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_PATHS;
+use storages_common_table_meta::table::OPT_KEY_COLUMN_COMPRESSION;
+use storages_common_table_meta::table::OPT_KEY_COLUMN_ENCRYPTION;
 use storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use storages_common_table_meta::table::OPT_KEY_ENCRYPTION_KEY_ID;
+use storages_common_table_meta::table::OPT_KEY_INVERTED_INDEX_COLUMNS;
 use storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
@@ -96,7 +105,12 @@ pub struct FuseTable {
     pub(crate) cluster_key_meta: Option<ClusterKey>,
     pub(crate) storage_format: FuseStorageFormat,
     pub(crate) table_compression: TableCompression,
+    pub(crate) column_codecs: ColumnCodecs,
+    pub(crate) encrypted_columns: EncryptedColumns,
+    pub(crate) encryption_key_id: Option<String>,
     pub(crate) bloom_index_cols: BloomIndexColumns,
+    pub(crate) bloom_index_paths: BloomIndexPaths,
+    pub(crate) inverted_index_cols: BloomIndexColumns,
 
     pub(crate) operator: Operator,
     pub(crate) data_metrics: Arc<StorageMetrics>,
@@ -166,12 +180,39 @@ impl FuseTable {
             .cloned()
             .unwrap_or_default();
 
+        let column_codecs = table_info
+            .options()
+            .get(OPT_KEY_COLUMN_COMPRESSION)
+            .cloned()
+            .unwrap_or_default();
+
+        let encrypted_columns = table_info
+            .options()
+            .get(OPT_KEY_COLUMN_ENCRYPTION)
+            .cloned()
+            .unwrap_or_default();
+
+        let encryption_key_id = table_info.options().get(OPT_KEY_ENCRYPTION_KEY_ID).cloned();
+
         let bloom_index_cols = table_info
             .options()
             .get(OPT_KEY_BLOOM_INDEX_COLUMNS)
             .and_then(|s| s.parse::<BloomIndexColumns>().ok())
             .unwrap_or(BloomIndexColumns::All);
 
+        let bloom_index_paths = table_info
+            .options()
+            .get(OPT_KEY_BLOOM_INDEX_PATHS)
+            .and_then(|s| s.parse::<BloomIndexPaths>().ok())
+            .unwrap_or_default();
+
+        // Unlike bloom index, inverted index is only built for the specified columns.
+        let inverted_index_cols = table_info
+            .options()
+            .get(OPT_KEY_INVERTED_INDEX_COLUMNS)
+            .and_then(|s| s.parse::<BloomIndexColumns>().ok())
+            .unwrap_or(BloomIndexColumns::None);
+
         let part_prefix = table_info.meta.part_prefix.clone();
 
         let meta_location_generator =
@@ -182,10 +223,15 @@ impl FuseTable {
             meta_location_generator,
             cluster_key_meta,
             bloom_index_cols,
+            bloom_index_paths,
+            inverted_index_cols,
             operator,
             data_metrics,
             storage_format: FuseStorageFormat::from_str(storage_format.as_str())?,
             table_compression: table_compression.as_str().try_into()?,
+            column_codecs: column_codecs.as_str().try_into()?,
+            encrypted_columns: encrypted_columns.as_str().try_into()?,
+            encryption_key_id,
             table_type,
         }))
     }
@@ -260,7 +306,7 @@ impl FuseTable {
 
     #[minitrace::trace]
     #[async_backtrace::framed]
-    pub(crate) async fn read_table_snapshot_statistics(
+    pub async fn read_table_snapshot_statistics(
         &self,
         snapshot: Option<&Arc<TableSnapshot>>,
     ) -> Result<Option<Arc<TableSnapshotStatistics>>> {
@@ -340,13 +386,8 @@ impl FuseTable {
                 if options.get(OPT_KEY_TABLE_ATTACHED_READ_ONLY).is_some() {
                     // if table is read-only attached, parse snapshot location from hint
                     let storage_prefix = options.get(OPT_KEY_STORAGE_PREFIX).unwrap();
-                    let hint = format!("{}/{}", storage_prefix, FUSE_TBL_LAST_SNAPSHOT_HINT);
-                    let snapshot_loc = {
-                        let hint_content = self.operator.read(&hint).await?;
-                        let snapshot_full_path = String::from_utf8(hint_content)?;
-                        let operator_info = self.operator.info();
-                        snapshot_full_path[operator_info.root().len()..].to_string()
-                    };
+                    let snapshot_loc =
+                        Self::read_snapshot_location_hint(&self.operator, storage_prefix).await?;
                     Ok(Some(snapshot_loc))
                 } else {
                     Ok(options
@@ -359,6 +400,46 @@ impl FuseTable {
         }
     }
 
+    /// Resolve the location of the latest snapshot of the table stored under `storage_prefix`,
+    /// by reading its last snapshot hint.
+    ///
+    /// The hint keeps the full path of the snapshot, prefixed with the root of the deployment
+    /// which wrote it. That root may differ from the root of `operator` if the table is attached
+    /// from another deployment, so the location is located by the storage prefix instead.
+    #[async_backtrace::framed]
+    pub async fn read_snapshot_location_hint(
+        operator: &Operator,
+        storage_prefix: &str,
+    ) -> Result<String> {
+        let hint = format!("{}/{}", storage_prefix, FUSE_TBL_LAST_SNAPSHOT_HINT);
+        let hint_content = match operator.read(&hint).await {
+            Ok(content) => content,
+            Err(e) if e.kind() == opendal::ErrorKind::NotFound => {
+                return Err(ErrorCode::StorageNotFound(format!(
+                    "no fuse table found under '{}', last snapshot hint '{}' does not exist",
+                    storage_prefix, hint
+                )));
+            }
+            Err(e) => return Err(e.into()),
+        };
+        let snapshot_full_path = String::from_utf8(hint_content)?;
+        let snapshot_full_path = snapshot_full_path.trim();
+
+        let operator_info = operator.info();
+        if let Some(loc) = snapshot_full_path.strip_prefix(operator_info.root()) {
+            if loc.starts_with(storage_prefix) {
+                return Ok(loc.to_string());
+            }
+        }
+        match snapshot_full_path.rfind(&format!("{}/", storage_prefix)) {
+            Some(pos) => Ok(snapshot_full_path[pos..].to_string()),
+            None => Err(ErrorCode::StorageOther(format!(
+                "invalid last snapshot hint '{}', snapshot location '{}' is not under '{}'",
+                hint, snapshot_full_path, storage_prefix
+            ))),
+        }
+    }
+
     pub fn get_operator(&self) -> Operator {
         self.operator.clone()
     }
@@ -392,6 +473,14 @@ impl FuseTable {
         self.bloom_index_cols.clone()
     }
 
+    pub fn bloom_index_paths(&self) -> BloomIndexPaths {
+        self.bloom_index_paths.clone()
+    }
+
+    pub fn inverted_index_cols(&self) -> BloomIndexColumns {
+        self.inverted_index_cols.clone()
+    }
+
     // Check if table is attached.
     fn is_table_attached(table_meta_options: &BTreeMap<String, String>) -> bool {
         table_meta_options
@@ -684,12 +773,14 @@ impl Table for FuseTable {
                 FuseTableColumnStatisticsProvider::new(
                     stats.clone(),
                     Some(table_statistics.column_distinct_values.clone()),
+                    table_statistics.column_histograms.clone(),
                     snapshot.summary.row_count,
                 )
             } else {
                 FuseTableColumnStatisticsProvider::new(
                     stats.clone(),
                     None,
+                    HashMap::new(),
                     snapshot.summary.row_count,
                 )
             }
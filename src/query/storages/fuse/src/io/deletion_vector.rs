@@ -0,0 +1,121 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_arrow::arrow::bitmap::MutableBitmap;
+use common_exception::Result;
+use common_expression::DataBlock;
+use opendal::Operator;
+use roaring::RoaringBitmap;
+
+/// Deletion vector of a block.
+///
+/// Marks the rows (offsets inside the block) that have been deleted, so that deleting
+/// a small fraction of the rows does not need to rewrite the whole block.
+///
+/// It is persisted as a sidecar file of the serialized roaring bitmap, see
+/// [`BlockMeta::deletion_vector_location`](storages_common_table_meta::meta::BlockMeta).
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct DeletionVector {
+    deleted: RoaringBitmap,
+}
+
+impl DeletionVector {
+    pub fn insert(&mut self, offset: usize) {
+        self.deleted.insert(offset as u32);
+    }
+
+    pub fn union_with(&mut self, other: &DeletionVector) {
+        self.deleted |= &other.deleted;
+    }
+
+    pub fn contains(&self, offset: usize) -> bool {
+        self.deleted.contains(offset as u32)
+    }
+
+    pub fn deleted_rows(&self) -> u64 {
+        self.deleted.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.deleted.is_empty()
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut data = Vec::with_capacity(self.deleted.serialized_size());
+        self.deleted.serialize_into(&mut data)?;
+        Ok(data)
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Result<Self> {
+        let deleted = RoaringBitmap::deserialize_from(data)?;
+        Ok(Self { deleted })
+    }
+
+    #[async_backtrace::framed]
+    pub async fn read(dal: &Operator, location: &str) -> Result<Self> {
+        let data = dal.read(location).await?;
+        Self::from_bytes(&data)
+    }
+
+    pub fn blocking_read(dal: &Operator, location: &str) -> Result<Self> {
+        let data = dal.blocking().read(location)?;
+        Self::from_bytes(&data)
+    }
+
+    /// The filter of the rows still alive, `true` means the row is kept.
+    pub fn live_filter(&self, num_rows: usize) -> Bitmap {
+        self.live_filter_of(0..num_rows)
+    }
+
+    /// The filter of the rows still alive in the range of rows of the block,
+    /// the first bit refers to the row `rows.start`.
+    pub fn live_filter_of(&self, rows: Range<usize>) -> Bitmap {
+        let mut filter = MutableBitmap::from_len_set(rows.len());
+        for offset in self.deleted.iter() {
+            let offset = offset as usize;
+            if rows.contains(&offset) {
+                filter.set(offset - rows.start, false);
+            }
+        }
+        filter.into()
+    }
+
+    /// Offsets of the rows still alive.
+    pub fn live_offsets(&self, num_rows: usize) -> Vec<usize> {
+        self.live_offsets_of(0..num_rows)
+    }
+
+    /// Offsets of the rows still alive in the range of rows of the block.
+    pub fn live_offsets_of(&self, rows: Range<usize>) -> Vec<usize> {
+        rows.filter(|offset| !self.contains(*offset)).collect()
+    }
+
+    /// Remove the deleted rows from the block read from the data file.
+    pub fn apply(&self, block: DataBlock) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        self.apply_of(block, 0..num_rows)
+    }
+
+    /// Remove the deleted rows from the block of the range of rows read from the data file.
+    pub fn apply_of(&self, block: DataBlock, rows: Range<usize>) -> Result<DataBlock> {
+        if self.is_empty() {
+            return Ok(block);
+        }
+        let filter = self.live_filter_of(rows);
+        block.filter_with_bitmap(&filter)
+    }
+}
@@ -30,6 +30,8 @@ use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
 use crate::constants::FUSE_TBL_VIRTUAL_BLOCK_PREFIX;
 use crate::index::filters::BlockFilter;
 use crate::FUSE_TBL_AGG_INDEX_PREFIX;
+use crate::FUSE_TBL_DELETION_VECTOR_PREFIX;
+use crate::FUSE_TBL_INVERTED_INDEX_PREFIX;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 
@@ -96,6 +98,19 @@ impl TableMetaLocationGenerator {
         )
     }
 
+    pub fn gen_deletion_vector_location(&self) -> Location {
+        let dv_uuid = Uuid::new_v4();
+        (
+            format!(
+                "{}/{}/{}.bin",
+                &self.prefix,
+                FUSE_TBL_DELETION_VECTOR_PREFIX,
+                dv_uuid.as_simple(),
+            ),
+            0,
+        )
+    }
+
     pub fn gen_segment_info_location(&self) -> String {
         let segment_uuid = Uuid::new_v4().simple().to_string();
         format!(
@@ -154,6 +169,14 @@ impl TableMetaLocationGenerator {
         let block_name = splits[len - 1];
         format!("{prefix}/{FUSE_TBL_AGG_INDEX_PREFIX}/{index_id}/{block_name}")
     }
+
+    pub fn gen_inverted_index_location_from_block_location(loc: &str) -> String {
+        let splits = loc.split('/').collect::<Vec<_>>();
+        let len = splits.len();
+        let prefix = splits[..len - 2].join("/");
+        let block_name = splits[len - 1];
+        format!("{prefix}/{FUSE_TBL_INVERTED_INDEX_PREFIX}/{block_name}")
+    }
 }
 
 trait SnapshotLocationCreator {
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_arrow::arrow::chunk::Chunk;
@@ -51,6 +52,12 @@ impl AggIndexReader {
                     num_rows,
                     columns_meta,
                     self.compression.into(),
+                    HashMap::new(),
+                    HashMap::new(),
+                    None,
+                    None,
+                    None,
+                    None,
                     None,
                     None,
                     None,
@@ -102,6 +109,12 @@ impl AggIndexReader {
                     num_rows,
                     columns_meta,
                     self.compression.into(),
+                    HashMap::new(),
+                    HashMap::new(),
+                    None,
+                    None,
+                    None,
+                    None,
                     None,
                     None,
                     None,
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use common_arrow::arrow::io::parquet::read as pread;
@@ -48,6 +49,12 @@ impl AggIndexReader {
                     row_group.num_rows() as u64,
                     columns_meta,
                     self.compression.into(),
+                    HashMap::new(),
+                    HashMap::new(),
+                    None,
+                    None,
+                    None,
+                    None,
                     None,
                     None,
                     None,
@@ -97,6 +104,12 @@ impl AggIndexReader {
                     row_group.num_rows() as u64,
                     columns_meta,
                     self.compression.into(),
+                    HashMap::new(),
+                    HashMap::new(),
+                    None,
+                    None,
+                    None,
+                    None,
                     None,
                     None,
                     None,
@@ -126,8 +139,11 @@ impl AggIndexReader {
             &part.location,
             part.nums_rows,
             &part.compression,
+            &part.column_compressions,
+            &part.column_encryptions,
             &part.columns_meta,
             columns_chunks,
+            None,
             Some(buffer),
         )?;
 
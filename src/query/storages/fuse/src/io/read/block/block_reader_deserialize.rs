@@ -23,6 +23,7 @@ use common_expression::ColumnId;
 use common_expression::DataBlock;
 use storages_common_cache_manager::SizedColumnArray;
 use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::ColumnEncryption;
 use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Compression;
 
@@ -33,6 +34,7 @@ use crate::io::UncompressedBuffer;
 use crate::FusePartInfo;
 use crate::FuseStorageFormat;
 use crate::MergeIOReadResult;
+use crate::PageSelection;
 
 pub enum DeserializedArray<'a> {
     Cached(&'a Arc<SizedColumnArray>),
@@ -45,8 +47,12 @@ pub struct FieldDeserializationContext<'a> {
     pub(crate) column_chunks: &'a HashMap<ColumnId, DataItem<'a>>,
     pub(crate) num_rows: usize,
     pub(crate) compression: &'a Compression,
+    /// compression of the columns compressed differently from `compression`.
+    pub(crate) column_compressions: &'a HashMap<ColumnId, Compression>,
     pub(crate) uncompressed_buffer: &'a Option<Arc<UncompressedBuffer>>,
     pub(crate) parquet_schema_descriptor: &'a Option<SchemaDescriptor>,
+    /// rows selected by the page indexes, see [`PageSelection`].
+    pub(crate) page_selection: Option<&'a PageSelection>,
 }
 
 impl BlockReader {
@@ -62,17 +68,22 @@ impl BlockReader {
             &part.location,
             part.nums_rows,
             &part.compression,
+            &part.column_compressions,
+            &part.column_encryptions,
             &part.columns_meta,
             chunks,
             storage_format,
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn deserialize_chunks(
         &self,
         block_path: &str,
         num_rows: usize,
         compression: &Compression,
+        column_compressions: &HashMap<ColumnId, Compression>,
+        column_encryptions: &HashMap<ColumnId, ColumnEncryption>,
         column_metas: &HashMap<ColumnId, ColumnMeta>,
         column_chunks: HashMap<ColumnId, DataItem>,
         storage_format: &FuseStorageFormat,
@@ -82,6 +93,8 @@ impl BlockReader {
                 block_path,
                 num_rows,
                 compression,
+                column_compressions,
+                column_encryptions,
                 column_metas,
                 column_chunks,
             ),
@@ -127,9 +140,12 @@ impl BlockReader {
                 &meta.location.0,
                 num_rows,
                 &meta.compression,
+                &meta.column_compressions,
+                &meta.column_encryptions,
                 &meta.col_metas,
                 column_chunks,
                 None,
+                None,
             ),
             FuseStorageFormat::Native => self.deserialize_native_chunks_with_buffer(
                 &meta.location.0,
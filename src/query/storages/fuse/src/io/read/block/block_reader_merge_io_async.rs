@@ -43,6 +43,7 @@ impl BlockReader {
     /// It will *NOT* merge two requests:
     /// if the last io request size is larger than storage_io_page_bytes_for_read(Default is 512KB).
     #[async_backtrace::framed]
+    #[minitrace::trace]
     pub async fn merge_io_read(
         read_settings: &ReadSettings,
         op: Operator,
@@ -131,6 +132,7 @@ impl BlockReader {
     }
 
     #[async_backtrace::framed]
+    #[minitrace::trace]
     pub async fn read_columns_data_by_merge_io(
         &self,
         settings: &ReadSettings,
@@ -94,6 +94,7 @@ impl BlockReader {
         Ok(read_res)
     }
 
+    #[minitrace::trace]
     pub fn sync_read_columns_data_by_merge_io(
         &self,
         settings: &ReadSettings,
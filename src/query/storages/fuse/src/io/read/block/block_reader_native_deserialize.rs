@@ -107,8 +107,10 @@ impl BlockReader {
             column_chunks: &column_chunks,
             num_rows,
             compression,
+            column_compressions: &HashMap::new(),
             uncompressed_buffer: &uncompressed_buffer,
             parquet_schema_descriptor: &None::<SchemaDescriptor>,
+            page_selection: None,
         };
 
         for column_node in &self.project_column_nodes {
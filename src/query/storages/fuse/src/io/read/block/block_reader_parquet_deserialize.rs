@@ -16,6 +16,8 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
+use bytes::Bytes;
+use common_arrow::arrow::array::Array;
 use common_arrow::arrow::chunk::Chunk;
 use common_arrow::arrow::datatypes::Field;
 use common_arrow::arrow::io::parquet::read::column_iter_to_arrays;
@@ -32,9 +34,12 @@ use common_exception::Result;
 use common_expression::ColumnId;
 use common_expression::DataBlock;
 use common_storage::ColumnNode;
+use storages_common_blocks::decrypt_column_chunk;
+use storages_common_blocks::get_key_management_service;
 use storages_common_cache::CacheAccessor;
 use storages_common_cache::TableDataCacheKey;
 use storages_common_cache_manager::CacheManager;
+use storages_common_table_meta::meta::ColumnEncryption;
 use storages_common_table_meta::meta::ColumnMeta;
 use storages_common_table_meta::meta::Compression;
 
@@ -45,14 +50,18 @@ use crate::io::read::block::decompressor::BuffedBasicDecompressor;
 use crate::io::BlockReader;
 use crate::io::UncompressedBuffer;
 use crate::metrics::*;
+use crate::PageSelection;
 
 impl BlockReader {
     /// Deserialize column chunks data from parquet format to DataBlock.
+    #[allow(clippy::too_many_arguments)]
     pub(super) fn deserialize_parquet_chunks(
         &self,
         block_path: &str,
         num_rows: usize,
         compression: &Compression,
+        column_compressions: &HashMap<ColumnId, Compression>,
+        column_encryptions: &HashMap<ColumnId, ColumnEncryption>,
         column_metas: &HashMap<ColumnId, ColumnMeta>,
         column_chunks: HashMap<ColumnId, DataItem>,
     ) -> Result<DataBlock> {
@@ -66,9 +75,12 @@ impl BlockReader {
             block_path,
             num_rows,
             compression,
+            column_compressions,
+            column_encryptions,
             column_metas,
             column_chunks,
             None,
+            None,
         );
 
         // Perf.
@@ -79,6 +91,39 @@ impl BlockReader {
         deserialized_res
     }
 
+    /// Decrypt the raw chunks of the columns encrypted at rest, the cached arrays are
+    /// decrypted already.
+    fn decrypt_column_chunks(
+        column_chunks: &mut HashMap<ColumnId, DataItem>,
+        column_encryptions: &HashMap<ColumnId, ColumnEncryption>,
+    ) -> Result<()> {
+        if column_encryptions.is_empty() {
+            return Ok(());
+        }
+        let key_management_service = get_key_management_service();
+        let mut keys: HashMap<&str, Vec<u8>> = HashMap::new();
+        for (column_id, item) in column_chunks.iter_mut() {
+            let (Some(encryption), DataItem::RawData(data)) =
+                (column_encryptions.get(column_id), &*item)
+            else {
+                continue;
+            };
+            if !keys.contains_key(encryption.key_id.as_str()) {
+                let key = key_management_service.get_data_key(&encryption.key_id)?;
+                keys.insert(&encryption.key_id, key);
+            }
+            let decrypted = decrypt_column_chunk(
+                &keys[encryption.key_id.as_str()],
+                *column_id,
+                &encryption.nonce,
+                &encryption.tag,
+                data,
+            )?;
+            *item = DataItem::RawData(Bytes::from(decrypted));
+        }
+        Ok(())
+    }
+
     pub fn build_default_values_block(&self, num_rows: usize) -> Result<DataBlock> {
         let data_schema = self.data_schema();
         let default_vals = self.default_vals.clone();
@@ -86,29 +131,41 @@ impl BlockReader {
     }
 
     /// Deserialize column chunks data from parquet format to DataBlock with a uncompressed buffer.
+    ///
+    /// If `page_selection` is given, the column chunks are the pages selected by the page indexes,
+    /// and only the selected rows are kept.
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn deserialize_parquet_chunks_with_buffer(
         &self,
         block_path: &str,
         num_rows: usize,
         compression: &Compression,
+        column_compressions: &HashMap<ColumnId, Compression>,
+        column_encryptions: &HashMap<ColumnId, ColumnEncryption>,
         column_metas: &HashMap<ColumnId, ColumnMeta>,
-        column_chunks: HashMap<ColumnId, DataItem>,
+        mut column_chunks: HashMap<ColumnId, DataItem>,
+        page_selection: Option<&PageSelection>,
         uncompressed_buffer: Option<Arc<UncompressedBuffer>>,
     ) -> Result<DataBlock> {
+        let selected_rows = page_selection.map_or(num_rows, |selection| selection.rows.len());
         if column_chunks.is_empty() {
-            return self.build_default_values_block(num_rows);
+            return self.build_default_values_block(selected_rows);
         }
+        Self::decrypt_column_chunks(&mut column_chunks, column_encryptions)?;
 
         let mut need_default_vals = Vec::with_capacity(self.project_column_nodes.len());
         let mut need_to_fill_default_val = false;
         let mut deserialized_column_arrays = Vec::with_capacity(self.projection.len());
+        let mut deserialized_column_ids = Vec::with_capacity(self.projection.len());
         let field_deserialization_ctx = FieldDeserializationContext {
             column_metas,
             column_chunks: &column_chunks,
             num_rows,
             compression,
+            column_compressions,
             uncompressed_buffer: &uncompressed_buffer,
             parquet_schema_descriptor: &None::<SchemaDescriptor>,
+            page_selection,
         };
         for column_node in &self.project_column_nodes {
             match self.deserialize_field(&field_deserialization_ctx, column_node)? {
@@ -118,12 +175,14 @@ impl BlockReader {
                 }
                 Some(v) => {
                     deserialized_column_arrays.push(v);
+                    deserialized_column_ids.push(column_node.leaf_column_ids[0]);
                     need_default_vals.push(false);
                 }
             }
         }
 
         // assembly the arrays
+        let sliced_arrays: Vec<Box<dyn Array>>;
         let mut chunk_arrays = vec![];
         for array in &deserialized_column_arrays {
             match array {
@@ -139,6 +198,22 @@ impl BlockReader {
             }
         }
 
+        // the pages read of each column cover different rows, keep the selected rows only.
+        if let Some(selection) = page_selection {
+            sliced_arrays = chunk_arrays
+                .iter()
+                .zip(deserialized_column_ids.iter())
+                .map(|(array, column_id)| {
+                    let start = selection
+                        .column_rows
+                        .get(column_id)
+                        .map_or(0, |rows| rows.start);
+                    array.sliced(selection.rows.start - start, selected_rows)
+                })
+                .collect();
+            chunk_arrays = sliced_arrays.iter().collect();
+        }
+
         // build data block
         let chunk = Chunk::try_new(chunk_arrays)?;
         let data_block = if !need_to_fill_default_val {
@@ -157,7 +232,7 @@ impl BlockReader {
                 &data_schema,
                 &chunk,
                 &default_vals,
-                num_rows,
+                selected_rows,
             )?
         };
 
@@ -186,13 +261,14 @@ impl BlockReader {
         column_descriptors: Vec<&ColumnDescriptor>,
         field: Field,
         init: Vec<InitNested>,
-        compression: &Compression,
+        compressions: Vec<&Compression>,
         uncompressed_buffer: Arc<UncompressedBuffer>,
     ) -> Result<ArrayIter<'a>> {
         let columns = metas
             .iter()
             .zip(chunks.into_iter().zip(column_descriptors.iter()))
-            .map(|(meta, (chunk, column_descriptor))| {
+            .zip(compressions)
+            .map(|((meta, (chunk, column_descriptor)), compression)| {
                 let meta = meta.as_parquet().unwrap();
 
                 let page_meta_data = PageMetaData {
@@ -244,6 +320,7 @@ impl BlockReader {
         let mut field_column_metas = Vec::with_capacity(estimated_cap);
         let mut field_column_data = Vec::with_capacity(estimated_cap);
         let mut field_column_descriptors = Vec::with_capacity(estimated_cap);
+        let mut field_column_compressions = Vec::with_capacity(estimated_cap);
         let mut field_uncompressed_size = 0;
 
         for (i, leaf_index) in indices.iter().enumerate() {
@@ -262,6 +339,12 @@ impl BlockReader {
                             field_column_metas.push(column_meta);
                             field_column_data.push(data.as_ref());
                             field_column_descriptors.push(column_descriptor);
+                            field_column_compressions.push(
+                                deserialization_context
+                                    .column_compressions
+                                    .get(&column_id)
+                                    .unwrap_or(compression),
+                            );
                             field_uncompressed_size += data.len();
                         }
                         DataItem::ColumnArray(column_array) => {
@@ -287,7 +370,11 @@ impl BlockReader {
             }
         }
 
-        let num_rows = deserialization_context.num_rows;
+        // the pages read of each column cover different rows, see [`PageSelection`].
+        let num_rows = deserialization_context
+            .page_selection
+            .and_then(|selection| selection.column_rows.get(&column.leaf_column_ids[0]))
+            .map_or(deserialization_context.num_rows, |rows| rows.len());
         if !field_column_metas.is_empty() {
             let field_name = column.field.name.to_owned();
             let mut array_iter = Self::chunks_to_parquet_array_iter(
@@ -297,7 +384,7 @@ impl BlockReader {
                 field_column_descriptors,
                 column.field.clone(),
                 column.init.clone(),
-                compression,
+                field_column_compressions,
                 uncompressed_buffer
                     .clone()
                     .unwrap_or_else(|| UncompressedBuffer::new(0)),
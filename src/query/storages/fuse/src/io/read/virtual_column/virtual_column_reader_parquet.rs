@@ -95,6 +95,12 @@ impl VirtualColumnReader {
                 row_group.num_rows() as u64,
                 columns_meta,
                 self.compression.into(),
+                HashMap::new(),
+                HashMap::new(),
+                None,
+                None,
+                None,
+                None,
                 None,
                 None,
                 None,
@@ -136,6 +142,12 @@ impl VirtualColumnReader {
                 row_group.num_rows() as u64,
                 columns_meta,
                 self.compression.into(),
+                HashMap::new(),
+                HashMap::new(),
+                None,
+                None,
+                None,
+                None,
                 None,
                 None,
                 None,
@@ -215,8 +227,10 @@ impl VirtualColumnReader {
                 column_chunks: &columns_chunks,
                 num_rows: part.nums_rows,
                 compression: &part.compression,
+                column_compressions: &part.column_compressions,
                 uncompressed_buffer: &uncompressed_buffer,
                 parquet_schema_descriptor: &Some(parquet_schema_descriptor),
+                page_selection: None,
             };
             for (index, virtual_column) in self.virtual_column_infos.iter().enumerate() {
                 for (i, f) in schema.fields.iter().enumerate() {
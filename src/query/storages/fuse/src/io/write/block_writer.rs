@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -20,6 +21,7 @@ use chrono::Utc;
 use common_arrow::arrow::chunk::Chunk as ArrowChunk;
 use common_arrow::native::write::NativeWriter;
 use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::ColumnId;
 use common_expression::DataBlock;
@@ -30,11 +32,19 @@ use common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
 use opendal::Operator;
 use storages_common_blocks::blocks_to_parquet;
+use storages_common_blocks::blocks_to_parquet_with_codecs;
+use storages_common_blocks::encrypt_column_chunk;
+use storages_common_blocks::get_key_management_service;
 use storages_common_index::BloomIndex;
+use storages_common_index::InvertedIndex;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
+use storages_common_table_meta::meta::ColumnEncryption;
 use storages_common_table_meta::meta::ColumnMeta;
+use storages_common_table_meta::meta::Compression;
 use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::StatisticsOfColumns;
+use storages_common_table_meta::table::ColumnCodec;
 use storages_common_table_meta::table::TableCompression;
 
 use crate::io::write::WriteSettings;
@@ -50,12 +60,32 @@ pub fn serialize_block(
     schema: &TableSchemaRef,
     block: DataBlock,
     buf: &mut Vec<u8>,
+) -> Result<(u64, HashMap<ColumnId, ColumnMeta>)> {
+    serialize_block_with_codecs(write_settings, schema, block, buf, &BTreeMap::new(), true)
+}
+
+/// Serialize the block with the column codecs of the table, which are keyed by the field index
+/// of `schema` without virtual computed fields. The codecs and `enable_page_index` are ignored
+/// by the native format.
+fn serialize_block_with_codecs(
+    write_settings: &WriteSettings,
+    schema: &TableSchemaRef,
+    block: DataBlock,
+    buf: &mut Vec<u8>,
+    column_codecs: &BTreeMap<FieldIndex, ColumnCodec>,
+    enable_page_index: bool,
 ) -> Result<(u64, HashMap<ColumnId, ColumnMeta>)> {
     let schema = Arc::new(schema.remove_virtual_computed_fields());
     match write_settings.storage_format {
         FuseStorageFormat::Parquet => {
-            let result =
-                blocks_to_parquet(&schema, vec![block], buf, write_settings.table_compression)?;
+            let result = blocks_to_parquet_with_codecs(
+                &schema,
+                vec![block],
+                buf,
+                write_settings.table_compression,
+                column_codecs,
+                enable_page_index,
+            )?;
             let meta = util::column_parquet_metas(&result.1, &schema)?;
             Ok((result.0, meta))
         }
@@ -99,12 +129,18 @@ pub fn serialize_block(
 
 /// Take ownership here to avoid extra copy.
 #[async_backtrace::framed]
+#[minitrace::trace]
 pub async fn write_data(data: Vec<u8>, data_accessor: &Operator, location: &str) -> Result<()> {
     data_accessor.write(location, data).await?;
 
     Ok(())
 }
 
+/// The serialized bloom index of a block.
+///
+/// Each indexed column is filtered by a xor8 filter, which is smaller and faster to probe
+/// than a classic bloom filter, the indexed columns are chosen by the table option
+/// `bloom_index_columns`.
 pub struct BloomIndexState {
     pub(crate) data: Vec<u8>,
     pub(crate) size: u64,
@@ -118,6 +154,7 @@ impl BloomIndexState {
         block: &DataBlock,
         location: Location,
         bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+        bloom_path_fields: &[(FieldIndex, TableField, Vec<String>)],
     ) -> Result<Option<Self>> {
         // write index
         let maybe_bloom_index = BloomIndex::try_create(
@@ -125,6 +162,7 @@ impl BloomIndexState {
             location.1,
             &[block],
             bloom_columns_map,
+            bloom_path_fields,
         )?;
         if let Some(bloom_index) = maybe_bloom_index {
             let index_block = bloom_index.serialize_to_data_block()?;
@@ -137,6 +175,7 @@ impl BloomIndexState {
                 vec![index_block],
                 &mut data,
                 TableCompression::None,
+                false,
             )?;
             Ok(Some(Self {
                 data,
@@ -150,11 +189,40 @@ impl BloomIndexState {
     }
 }
 
+pub struct InvertedIndexState {
+    pub(crate) data: Vec<u8>,
+    pub(crate) location: String,
+}
+
+impl InvertedIndexState {
+    pub fn try_create(
+        block: &DataBlock,
+        location: String,
+        inverted_columns_map: &BTreeMap<FieldIndex, TableField>,
+    ) -> Result<Option<Self>> {
+        if let Some(inverted_index) = InvertedIndex::try_create(block, inverted_columns_map)? {
+            let index_block = inverted_index.serialize_to_data_block()?;
+            let mut data = Vec::with_capacity(DEFAULT_BLOCK_INDEX_BUFFER_SIZE);
+            blocks_to_parquet(
+                &InvertedIndex::schema(),
+                vec![index_block],
+                &mut data,
+                TableCompression::LZ4,
+                false,
+            )?;
+            Ok(Some(Self { data, location }))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
 pub struct BlockSerialization {
     pub block_raw_data: Vec<u8>,
     pub size: u64, // TODO redundancy
     pub block_meta: BlockMeta,
     pub bloom_index_state: Option<BloomIndexState>,
+    pub inverted_index_state: Option<InvertedIndexState>,
 }
 
 #[derive(Clone)]
@@ -165,6 +233,11 @@ pub struct BlockBuilder {
     pub write_settings: WriteSettings,
     pub cluster_stats_gen: ClusterStatsGenerator,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub bloom_path_fields: Vec<(FieldIndex, TableField, Vec<String>)>,
+    pub inverted_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub column_codecs: BTreeMap<FieldIndex, ColumnCodec>,
+    pub encrypted_fields: BTreeSet<FieldIndex>,
+    pub encryption_key_id: Option<String>,
 }
 
 impl BlockBuilder {
@@ -180,23 +253,58 @@ impl BlockBuilder {
             &data_block,
             bloom_index_location,
             self.bloom_columns_map.clone(),
+            &self.bloom_path_fields,
         )?;
         let column_distinct_count = bloom_index_state
             .as_ref()
             .map(|i| i.column_distinct_count.clone());
 
+        // the location of inverted index is derived from the block location.
+        let inverted_index_location =
+            TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                &block_location.0,
+            );
+        let inverted_index_state = InvertedIndexState::try_create(
+            &data_block,
+            inverted_index_location,
+            &self.inverted_columns_map,
+        )?;
+
         let row_count = data_block.num_rows() as u64;
         let block_size = data_block.memory_size() as u64;
-        let col_stats =
+        let mut col_stats =
             gen_columns_statistics(&data_block, column_distinct_count, &self.source_schema)?;
 
+        // the statistics of pages would leak the values of the encrypted columns.
+        let enable_page_index = self.encrypted_fields.is_empty();
         let mut buffer = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
-        let (file_size, col_metas) = serialize_block(
+        let (file_size, col_metas) = serialize_block_with_codecs(
             &self.write_settings,
             &self.source_schema,
             data_block,
             &mut buffer,
+            &self.column_codecs,
+            enable_page_index,
         )?;
+        let column_encryptions = self.encrypt_columns(&mut buffer, &col_metas, &mut col_stats)?;
+
+        // the page indexes are written right after the column chunks.
+        let page_index_offset = match self.write_settings.storage_format {
+            FuseStorageFormat::Parquet if enable_page_index => col_metas
+                .values()
+                .map(|meta| {
+                    let (offset, len) = meta.offset_length();
+                    offset + len
+                })
+                .max(),
+            _ => None,
+        };
+
+        let compression = self.write_settings.table_compression.try_into()?;
+        let column_compressions = match self.write_settings.storage_format {
+            FuseStorageFormat::Parquet => self.column_compressions(compression),
+            FuseStorageFormat::Native => HashMap::new(),
+        };
 
         let block_meta = BlockMeta {
             row_count,
@@ -211,8 +319,13 @@ impl BlockBuilder {
                 .as_ref()
                 .map(|v| v.size)
                 .unwrap_or_default(),
-            compression: self.write_settings.table_compression.try_into()?,
+            compression,
             create_on: Some(Utc::now()),
+            deletion_vector_location: None,
+            deleted_row_count: 0,
+            page_index_offset,
+            column_compressions,
+            column_encryptions,
         };
 
         let serialized = BlockSerialization {
@@ -220,7 +333,58 @@ impl BlockBuilder {
             size: file_size,
             block_meta,
             bloom_index_state,
+            inverted_index_state,
         };
         Ok(serialized)
     }
+    /// Compression of the leaf columns whose codecs are compressed differently from the table.
+    fn column_compressions(&self, compression: Compression) -> HashMap<ColumnId, Compression> {
+        let mut column_compressions = HashMap::new();
+        for (index, codec) in &self.column_codecs {
+            if let Some(column_compression) = codec.compression {
+                let column_compression = Compression::from(column_compression);
+                if column_compression != compression {
+                    for column_id in self.source_schema.fields[*index].leaf_column_ids() {
+                        column_compressions.insert(column_id, column_compression);
+                    }
+                }
+            }
+        }
+        column_compressions
+    }
+
+    /// Encrypt the chunks of the encrypted leaf columns in place, the lengths of the chunks are
+    /// kept as the authentication tags are stored in the block meta. The statistics of these
+    /// columns are dropped, so the blocks are never pruned by them.
+    fn encrypt_columns(
+        &self,
+        buffer: &mut [u8],
+        col_metas: &HashMap<ColumnId, ColumnMeta>,
+        col_stats: &mut StatisticsOfColumns,
+    ) -> Result<HashMap<ColumnId, ColumnEncryption>> {
+        let mut column_encryptions = HashMap::new();
+        if self.encrypted_fields.is_empty() {
+            return Ok(column_encryptions);
+        }
+        let key_id = self.encryption_key_id.as_ref().ok_or_else(|| {
+            ErrorCode::ColumnEncryptionError("encryption_key_id of the table is not set")
+        })?;
+        let key = get_key_management_service().get_data_key(key_id)?;
+        for index in &self.encrypted_fields {
+            for column_id in self.source_schema.fields[*index].leaf_column_ids() {
+                col_stats.remove(&column_id);
+                if let Some(meta) = col_metas.get(&column_id) {
+                    let (offset, len) = meta.offset_length();
+                    let chunk = &mut buffer[offset as usize..(offset + len) as usize];
+                    let (nonce, tag) = encrypt_column_chunk(&key, column_id, chunk)?;
+                    column_encryptions.insert(column_id, ColumnEncryption {
+                        key_id: key_id.clone(),
+                        nonce,
+                        tag,
+                    });
+                }
+            }
+        }
+        Ok(column_encryptions)
+    }
 }
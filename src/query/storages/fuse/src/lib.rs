@@ -43,6 +43,7 @@ pub use constants::*;
 pub use fuse_column::FuseTableColumnStatisticsProvider;
 pub use fuse_part::FuseLazyPartInfo;
 pub use fuse_part::FusePartInfo;
+pub use fuse_part::PageSelection;
 pub use fuse_table::FuseTable;
 pub use fuse_type::FuseStorageFormat;
 pub use fuse_type::FuseTableType;
@@ -12,24 +12,54 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::sync::Arc;
 use std::time::Instant;
 
+use common_catalog::plan::Projection;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::Column;
+use common_expression::ColumnId;
+use common_expression::ComputedExpr;
+use common_expression::DataBlock;
+use common_expression::Scalar;
+use common_expression::ScalarRef;
 use log::warn;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+use storages_common_index::Index;
+use storages_common_index::RangeIndex;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::ColumnHistogram;
+use storages_common_table_meta::meta::ColumnHistogramBucket;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::TableSnapshot;
 use storages_common_table_meta::meta::TableSnapshotStatistics;
+use streaming_algorithms::HyperLogLog;
 
+use crate::io::BlockReader;
+use crate::io::DeletionVector;
+use crate::io::ReadSettings;
 use crate::io::SegmentsIO;
+use crate::operations::read_block;
 use crate::statistics::reduce_block_statistics;
 use crate::statistics::reduce_cluster_statistics;
 use crate::FuseTable;
 
+/// Max number of the values sampled from each column to build the histogram.
+const HISTOGRAM_SAMPLE_SIZE: usize = 10_000;
+/// Number of the buckets of the histogram, the dummy bucket of the min value excluded.
+const HISTOGRAM_BUCKETS: usize = 100;
+/// Relative error of the HyperLogLog used to estimate the NDV.
+const NDV_ERROR_RATE: f64 = 0.01;
+
 impl FuseTable {
     #[async_backtrace::framed]
     pub async fn do_analyze(&self, ctx: &Arc<dyn TableContext>) -> Result<()> {
@@ -59,6 +89,7 @@ impl FuseTable {
             let mut read_segment_count = 0;
             let mut col_stats = HashMap::new();
             let mut cluster_stats = None;
+            let mut blocks = Vec::new();
 
             let start = Instant::now();
             let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
@@ -80,7 +111,6 @@ impl FuseTable {
                     stats_of_columns.push(segment.summary.col_stats.clone());
                     blocks_cluster_stats.push(segment.summary.cluster_stats.clone());
                     segment.blocks.iter().for_each(|block| {
-                        let block = block.as_ref();
                         let row_count = block.row_count;
                         if row_count != 0 {
                             block_count_sum += 1;
@@ -99,6 +129,7 @@ impl FuseTable {
                                     }
                                 }
                             }
+                            blocks.push(block.clone());
                         }
                     });
                 }
@@ -121,14 +152,26 @@ impl FuseTable {
                 }
             }
 
+            // The NDV of the columns not scanned are estimated from the block statistics.
             let mut ndv_map = HashMap::new();
             for (i, sum) in sum_map.iter() {
                 let density_avg = *sum / block_count_sum as f64;
                 ndv_map.insert(*i, (density_avg * row_count_sum as f64) as u64);
             }
 
-            // 3. Generate new table statistics
-            let table_statistics = TableSnapshotStatistics::new(ndv_map);
+            // 3. Scan the blocks to collect the NDV and the histograms of the columns.
+            let mut histograms = HashMap::new();
+            let collectors = self.collect_columns(ctx, &blocks).await?;
+            for (column_id, collector) in collectors {
+                let ndv = collector.ndv();
+                ndv_map.insert(column_id, ndv);
+                if let Some(histogram) = collector.histogram(ndv) {
+                    histograms.insert(column_id, histogram);
+                }
+            }
+
+            // 4. Generate new table statistics
+            let table_statistics = TableSnapshotStatistics::new(ndv_map, histograms);
             let table_statistics_location = self
                 .meta_location_generator
                 .snapshot_statistics_location_from_uuid(
@@ -136,7 +179,7 @@ impl FuseTable {
                     table_statistics.format_version(),
                 )?;
 
-            // 4. Save table statistics
+            // 5. Save table statistics
             let mut new_snapshot = TableSnapshot::from_previous(&snapshot);
             new_snapshot.summary.col_stats = col_stats;
             new_snapshot.summary.cluster_stats = cluster_stats;
@@ -155,4 +198,190 @@ impl FuseTable {
 
         Ok(())
     }
+    // Read all the live rows of the columns which support min/max statistics,
+    // and feed them into the collectors.
+    #[async_backtrace::framed]
+    async fn collect_columns(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        blocks: &[Arc<BlockMeta>],
+    ) -> Result<Vec<(ColumnId, ColumnCollector)>> {
+        let schema = self.schema();
+        let (field_indices, column_ids): (Vec<_>, Vec<_>) = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| {
+                !matches!(f.computed_expr(), Some(ComputedExpr::Virtual(_)))
+                    && RangeIndex::supported_type(&f.data_type().into())
+            })
+            .map(|(i, f)| (i, f.column_id()))
+            .unzip();
+        if field_indices.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let block_reader = self.create_block_reader(
+            ctx.clone(),
+            Projection::Columns(field_indices),
+            false,
+            false,
+        )?;
+        let read_settings = ReadSettings::from_ctx(ctx)?;
+        let mut collectors = column_ids
+            .iter()
+            .map(|_| ColumnCollector::new())
+            .collect::<Vec<_>>();
+        let mut rng = StdRng::from_entropy();
+
+        let start = Instant::now();
+        let chunk_size = ctx.get_settings().get_max_threads()? as usize;
+        let number_blocks = blocks.len();
+        let mut read_block_count = 0;
+        for chunk in blocks.chunks(chunk_size) {
+            let data_blocks = futures::future::try_join_all(
+                chunk
+                    .iter()
+                    .map(|block| self.read_live_rows(&block_reader, block, &read_settings)),
+            )
+            .await?;
+            for data_block in data_blocks {
+                let data_block = data_block.convert_to_full();
+                for (collector, entry) in collectors.iter_mut().zip(data_block.columns()) {
+                    let column = entry.value.as_column().unwrap();
+                    collector.add_column(column, &mut rng);
+                }
+            }
+
+            // Status.
+            {
+                read_block_count += chunk.len();
+                let status = format!(
+                    "analyze: read blocks:{}/{}, cost:{} sec",
+                    read_block_count,
+                    number_blocks,
+                    start.elapsed().as_secs()
+                );
+                ctx.set_status_info(&status);
+            }
+        }
+
+        Ok(column_ids.into_iter().zip(collectors).collect())
+    }
+
+    #[async_backtrace::framed]
+    async fn read_live_rows(
+        &self,
+        block_reader: &BlockReader,
+        block: &BlockMeta,
+        read_settings: &ReadSettings,
+    ) -> Result<DataBlock> {
+        let data_block =
+            read_block(self.storage_format, block_reader, block, read_settings).await?;
+        match &block.deletion_vector_location {
+            Some((location, _)) => {
+                let deletion_vector = DeletionVector::read(&self.operator, location).await?;
+                deletion_vector.apply(data_block)
+            }
+            None => Ok(data_block),
+        }
+    }
+}
+
+/// Collects the NDV and the sampled values of a column.
+struct ColumnCollector {
+    hll: HyperLogLog<u64>,
+    /// Number of the non-null values.
+    num_values: u64,
+    /// Uniformly sampled non-null values, by reservoir sampling.
+    samples: Vec<Scalar>,
+}
+
+impl ColumnCollector {
+    fn new() -> Self {
+        Self {
+            hll: HyperLogLog::new(NDV_ERROR_RATE),
+            num_values: 0,
+            samples: Vec::with_capacity(HISTOGRAM_SAMPLE_SIZE),
+        }
+    }
+
+    fn add_column(&mut self, column: &Column, rng: &mut StdRng) {
+        for value in column.iter() {
+            if matches!(value, ScalarRef::Null) {
+                continue;
+            }
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            self.hll.push(&hasher.finish());
+
+            self.num_values += 1;
+            if self.samples.len() < HISTOGRAM_SAMPLE_SIZE {
+                self.samples.push(value.to_owned());
+            } else {
+                let idx = rng.gen_range(0..self.num_values) as usize;
+                if idx < HISTOGRAM_SAMPLE_SIZE {
+                    self.samples[idx] = value.to_owned();
+                }
+            }
+        }
+    }
+
+    fn ndv(&self) -> u64 {
+        (self.hll.len().round() as u64).min(self.num_values)
+    }
+
+    /// Build the equi-height histogram from the sampled values, the number of values
+    /// of the buckets are scaled to the number of the non-null values of the column,
+    /// and the number of distinct values are scaled to `ndv`.
+    fn histogram(mut self, ndv: u64) -> Option<ColumnHistogram> {
+        if self.samples.is_empty() {
+            return None;
+        }
+        self.samples.sort();
+
+        let num_samples = self.samples.len();
+        let sample_ndv = 1 + self
+            .samples
+            .windows(2)
+            .filter(|pair| pair[0] != pair[1])
+            .count();
+        let values_scale = self.num_values as f64 / num_samples as f64;
+        let distinct_scale = ndv.max(1) as f64 / sample_ndv as f64;
+        let num_buckets = HISTOGRAM_BUCKETS.min(sample_ndv);
+
+        let mut buckets = Vec::with_capacity(num_buckets + 1);
+        // The first bucket is a dummy bucket which records the min value.
+        buckets.push(ColumnHistogramBucket {
+            upper_bound: self.samples[0].clone(),
+            num_values: 1.0,
+            num_distinct: 1.0,
+        });
+
+        let mut start = 0;
+        for idx in 1..=num_buckets {
+            if start >= num_samples {
+                break;
+            }
+            // The same values never span buckets.
+            let mut end = (idx * num_samples / num_buckets).max(start + 1);
+            while end < num_samples && self.samples[end] == self.samples[end - 1] {
+                end += 1;
+            }
+            let bucket_samples = &self.samples[start..end];
+            let bucket_ndv = 1 + bucket_samples
+                .windows(2)
+                .filter(|pair| pair[0] != pair[1])
+                .count();
+            let num_values = bucket_samples.len() as f64 * values_scale;
+            buckets.push(ColumnHistogramBucket {
+                upper_bound: bucket_samples[bucket_samples.len() - 1].clone(),
+                num_values,
+                num_distinct: (bucket_ndv as f64 * distinct_scale).clamp(1.0, num_values),
+            });
+            start = end;
+        }
+
+        Some(ColumnHistogram { buckets })
+    }
 }
@@ -29,12 +29,14 @@ use common_pipeline_core::processors::processor::ProcessorPtr;
 use common_pipeline_core::Pipeline;
 use common_pipeline_transforms::processors::transforms::create_dummy_items;
 use common_pipeline_transforms::processors::transforms::transform_block_compact_for_copy::BlockCompactorForCopy;
+use common_pipeline_transforms::processors::transforms::AsyncAccumulatingTransformer;
 use common_pipeline_transforms::processors::transforms::BlockCompactor;
 use common_pipeline_transforms::processors::transforms::TransformCompact;
 use common_pipeline_transforms::processors::transforms::TransformSortPartial;
 use common_sql::evaluator::BlockOperator;
 use common_sql::evaluator::CompoundBlockOperator;
 
+use crate::operations::common::AppendBlockCoalescer;
 use crate::operations::common::TransformSerializeBlock;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
@@ -87,6 +89,27 @@ impl FuseTable {
         Ok(())
     }
 
+    /// Merges the small blocks appended by the `pipeline` before they are committed.
+    ///
+    /// The blocks of a clustered table are sorted one by one, they are left to the re-cluster.
+    pub fn coalesce_appended_blocks(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        if self.cluster_key_meta.is_some() || pipeline.is_empty() {
+            return Ok(());
+        }
+
+        pipeline.try_resize(1)?;
+        pipeline.add_transform(|input, output| {
+            let coalescer = AppendBlockCoalescer::try_create(ctx.clone(), self)?;
+            Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
+                input, output, coalescer,
+            )))
+        })
+    }
+
     pub fn cluster_gen_for_append_with_specified_last_len(
         &self,
         ctx: Arc<dyn TableContext>,
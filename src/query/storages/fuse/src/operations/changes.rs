@@ -0,0 +1,219 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use storages_common_cache::LoadParams;
+use storages_common_table_meta::meta::BlockMeta;
+use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::TableSnapshot;
+
+use crate::io::BlockReader;
+use crate::io::MetaReaders;
+use crate::io::ReadSettings;
+use crate::io::SegmentsIO;
+use crate::io::TableMetaLocationGenerator;
+use crate::operations::common::ConflictResolveContext;
+use crate::FuseTable;
+use crate::Table;
+
+/// The blocks of a table changed between two snapshots.
+///
+/// A block rewritten by a mutation shows up as a deleted block and an inserted block,
+/// the blocks only moved into other segments (e.g. by segment compaction) are not changes.
+#[derive(Default)]
+pub struct ChangedBlocks {
+    pub inserted: Vec<Arc<BlockMeta>>,
+    pub deleted: Vec<Arc<BlockMeta>>,
+}
+
+impl FuseTable {
+    /// Collects the blocks changed since the snapshot at `base_location`, which is `None`
+    /// if the table had no data.
+    #[async_backtrace::framed]
+    pub async fn changed_blocks(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        base_location: Option<String>,
+    ) -> Result<ChangedBlocks> {
+        let latest_location = self.snapshot_loc().await?;
+        if latest_location == base_location {
+            return Ok(ChangedBlocks::default());
+        }
+
+        let latest_segments = match self.read_table_snapshot().await? {
+            Some(snapshot) => snapshot.segments.clone(),
+            None => vec![],
+        };
+        let base_segments = match base_location {
+            Some(location) => self
+                .read_snapshot_by_location(location)
+                .await?
+                .segments
+                .clone(),
+            None => vec![],
+        };
+
+        let latest_set = latest_segments.iter().collect::<HashSet<_>>();
+        let base_set = base_segments.iter().collect::<HashSet<_>>();
+        let added_segments = latest_segments
+            .iter()
+            .filter(|location| !base_set.contains(location))
+            .cloned()
+            .collect::<Vec<_>>();
+        let removed_segments = base_segments
+            .iter()
+            .filter(|location| !latest_set.contains(location))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let added_blocks = self
+            .read_segment_blocks(ctx.clone(), &added_segments)
+            .await?;
+        let removed_blocks = self.read_segment_blocks(ctx, &removed_segments).await?;
+
+        let added_locations = added_blocks
+            .iter()
+            .map(|block| &block.location.0)
+            .collect::<HashSet<_>>();
+        let removed_locations = removed_blocks
+            .iter()
+            .map(|block| &block.location.0)
+            .collect::<HashSet<_>>();
+        Ok(ChangedBlocks {
+            inserted: added_blocks
+                .iter()
+                .filter(|block| !removed_locations.contains(&block.location.0))
+                .cloned()
+                .collect(),
+            deleted: removed_blocks
+                .iter()
+                .filter(|block| !added_locations.contains(&block.location.0))
+                .cloned()
+                .collect(),
+        })
+    }
+
+    /// Collects the blocks appended since the snapshot at `base_location`, which is `None`
+    /// if the table had no data.
+    ///
+    /// The history is walked back from the latest snapshot, only the segments added by the
+    /// append-only commits are read, so the blocks deleted or rewritten by mutations are
+    /// neither tracked nor loaded.
+    #[async_backtrace::framed]
+    pub async fn appended_blocks(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        base_location: Option<String>,
+    ) -> Result<Vec<Arc<BlockMeta>>> {
+        let Some(latest_location) = self.snapshot_loc().await? else {
+            return Ok(vec![]);
+        };
+
+        let mut appended_segments = vec![];
+        let mut location = latest_location;
+        let mut snapshot = self.read_snapshot_by_location(location.clone()).await?;
+        while base_location.as_ref() != Some(&location) {
+            let Some((prev_id, prev_version)) = snapshot.prev_snapshot_id else {
+                if base_location.is_some() {
+                    return Err(ErrorCode::StorageOther(format!(
+                        "Cannot find the snapshot {} in the history of table {}",
+                        location, self.table_info.desc
+                    )));
+                }
+                // The first snapshot of the table.
+                appended_segments.extend(snapshot.segments.iter().cloned());
+                break;
+            };
+            let prev_location = self
+                .meta_location_generator
+                .snapshot_location_from_uuid(&prev_id, prev_version)?;
+            let prev_snapshot = self
+                .read_snapshot_by_location(prev_location.clone())
+                .await?;
+            if let Some(range) =
+                ConflictResolveContext::is_latest_snapshot_append_only(&prev_snapshot, &snapshot)
+            {
+                appended_segments.extend(snapshot.segments[range].iter().cloned());
+            }
+            location = prev_location;
+            snapshot = prev_snapshot;
+        }
+
+        // Appended earlier, read in the order of the history.
+        appended_segments.reverse();
+        self.read_segment_blocks(ctx, &appended_segments).await
+    }
+
+    /// Reads the data of a block of the table.
+    #[async_backtrace::framed]
+    pub async fn read_block_by_meta(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        block_reader: &BlockReader,
+        meta: &BlockMeta,
+    ) -> Result<DataBlock> {
+        let settings = ReadSettings::from_ctx(&ctx)?;
+        block_reader
+            .read_by_meta(&settings, meta, &self.storage_format)
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn read_snapshot_by_location(&self, location: String) -> Result<Arc<TableSnapshot>> {
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        let ver = TableMetaLocationGenerator::snapshot_version(location.as_str());
+        let params = LoadParams {
+            location: location.clone(),
+            len_hint: None,
+            ver,
+            put_cache: true,
+        };
+        reader.read(&params).await.map_err(|e| {
+            ErrorCode::StorageOther(format!(
+                "Cannot read the snapshot {} of table {}, it may have been purged: {}",
+                location,
+                self.table_info.desc,
+                e.message()
+            ))
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn read_segment_blocks(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        segments: &[Location],
+    ) -> Result<Vec<Arc<BlockMeta>>> {
+        if segments.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let segments_io = SegmentsIO::create(ctx, self.get_operator(), self.schema());
+        let mut blocks = vec![];
+        for segment in segments_io
+            .read_segments::<SegmentInfo>(segments, false)
+            .await?
+        {
+            blocks.extend(segment?.blocks);
+        }
+        Ok(blocks)
+    }
+}
@@ -27,6 +27,7 @@ use common_exception::ErrorCode;
 use common_exception::Result;
 use common_expression::TableSchemaRef;
 use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
 use common_meta_app::schema::TableStatistics;
 use common_meta_app::schema::UpdateTableMetaReq;
 use common_meta_app::schema::UpsertTableCopiedFileReq;
@@ -62,6 +63,8 @@ use crate::operations::common::AbortOperation;
 use crate::operations::common::AppendGenerator;
 use crate::operations::common::CommitSink;
 use crate::operations::common::ConflictResolveContext;
+use crate::operations::common::PendingCommitMeta;
+use crate::operations::common::PendingCommitSink;
 use crate::operations::common::TableMutationAggregator;
 use crate::operations::common::TransformSerializeSegment;
 use crate::statistics::merge_statistics;
@@ -81,23 +84,7 @@ impl FuseTable {
         overwrite: bool,
         prev_snapshot_id: Option<SnapshotId>,
     ) -> Result<()> {
-        let block_thresholds = self.get_block_thresholds();
-
-        pipeline.try_resize(1)?;
-
-        pipeline.add_transform(|input, output| {
-            let proc =
-                TransformSerializeSegment::new(ctx.clone(), input, output, self, block_thresholds);
-            proc.into_processor()
-        })?;
-
-        pipeline.add_transform(|input, output| {
-            let aggregator =
-                TableMutationAggregator::new(self, ctx.clone(), vec![], MutationKind::Insert);
-            Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
-                input, output, aggregator,
-            )))
-        })?;
+        self.add_commit_meta_transforms(ctx.clone(), pipeline)?;
 
         let snapshot_gen = AppendGenerator::new(ctx.clone(), overwrite);
         pipeline.add_sink(|input| {
@@ -116,6 +103,43 @@ impl FuseTable {
         Ok(())
     }
 
+    /// Builds the pipeline like [`FuseTable::do_commit`], but keeps the [`CommitMeta`] in
+    /// `commit_meta` instead of committing it, the caller is responsible for committing it.
+    pub fn do_pending_commit(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        commit_meta: PendingCommitMeta,
+    ) -> Result<()> {
+        self.add_commit_meta_transforms(ctx, pipeline)?;
+        pipeline.add_sink(|input| PendingCommitSink::try_create(input, commit_meta.clone()))
+    }
+
+    // Serializes the appended blocks into segments, and aggregates them into one commit meta.
+    fn add_commit_meta_transforms(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+    ) -> Result<()> {
+        let block_thresholds = self.get_block_thresholds();
+
+        pipeline.try_resize(1)?;
+
+        pipeline.add_transform(|input, output| {
+            let proc =
+                TransformSerializeSegment::new(ctx.clone(), input, output, self, block_thresholds);
+            proc.into_processor()
+        })?;
+
+        pipeline.add_transform(|input, output| {
+            let aggregator =
+                TableMutationAggregator::new(self, ctx.clone(), vec![], MutationKind::Insert);
+            Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
+                input, output, aggregator,
+            )))
+        })
+    }
+
     #[async_backtrace::framed]
     pub async fn commit_to_meta_server(
         ctx: &dyn TableContext,
@@ -184,27 +208,7 @@ impl FuseTable {
         operator: &Operator,
     ) -> Result<()> {
         // 1. prepare table meta
-        let mut new_table_meta = table_info.meta.clone();
-        // 1.1 set new snapshot location
-        new_table_meta.options.insert(
-            OPT_KEY_SNAPSHOT_LOCATION.to_owned(),
-            snapshot_location.clone(),
-        );
-        // remove legacy options
-        Self::remove_legacy_options(&mut new_table_meta.options);
-
-        // 1.2 setup table statistics
-        let stats = &snapshot.summary;
-        // update statistics
-        new_table_meta.statistics = TableStatistics {
-            number_of_rows: stats.row_count,
-            data_bytes: stats.uncompressed_byte_size,
-            compressed_data_bytes: stats.compressed_byte_size,
-            index_data_bytes: stats.index_size,
-            number_of_segments: Some(snapshot.segments.len() as u64),
-            number_of_blocks: Some(stats.block_count),
-        };
-        new_table_meta.updated_on = Utc::now();
+        let new_table_meta = Self::new_table_meta(table_info, &snapshot, &snapshot_location);
 
         // 2. prepare the request
         let catalog = ctx.get_catalog(table_info.catalog()).await?;
@@ -219,6 +223,18 @@ impl FuseTable {
             deduplicated_label: ctx.get_settings().get_deduplicate_label()?,
         };
 
+        // In an explicit transaction, the new table meta is staged in the session,
+        // and published to the meta service when the transaction is committed.
+        {
+            let txn_mgr = ctx.txn_mgr();
+            let mut txn_mgr = txn_mgr.lock();
+            if txn_mgr.is_active() {
+                txn_mgr.upsert_table_meta(table_info, req);
+                TableSnapshot::cache().put(snapshot_location, Arc::new(snapshot));
+                return Ok(());
+            }
+        }
+
         // 3. let's roll
         let reply = catalog.update_table_meta(table_info, req).await;
         match reply {
@@ -246,6 +262,35 @@ impl FuseTable {
         }
     }
 
+    pub(crate) fn new_table_meta(
+        table_info: &TableInfo,
+        snapshot: &TableSnapshot,
+        snapshot_location: &str,
+    ) -> TableMeta {
+        let mut new_table_meta = table_info.meta.clone();
+        // set new snapshot location
+        new_table_meta.options.insert(
+            OPT_KEY_SNAPSHOT_LOCATION.to_owned(),
+            snapshot_location.to_owned(),
+        );
+        // remove legacy options
+        Self::remove_legacy_options(&mut new_table_meta.options);
+
+        // setup table statistics
+        let stats = &snapshot.summary;
+        // update statistics
+        new_table_meta.statistics = TableStatistics {
+            number_of_rows: stats.row_count,
+            data_bytes: stats.uncompressed_byte_size,
+            compressed_data_bytes: stats.compressed_byte_size,
+            index_data_bytes: stats.index_size,
+            number_of_segments: Some(snapshot.segments.len() as u64),
+            number_of_blocks: Some(stats.block_count),
+        };
+        new_table_meta.updated_on = Utc::now();
+        new_table_meta
+    }
+
     // Left a hint file which indicates the location of the latest snapshot
     #[async_backtrace::framed]
     pub async fn write_last_snapshot_hint(
@@ -0,0 +1,202 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use backoff::backoff::Backoff;
+use common_catalog::table::Table;
+use common_catalog::table::TableExt;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_meta_app::schema::UpdateMultiTableMetaReq;
+use common_meta_app::schema::UpdateTableMetaReq;
+use common_meta_types::MatchSeq;
+use log::debug;
+use log::info;
+use storages_common_cache::CacheAccessor;
+use storages_common_cache_manager::CachedObject;
+use storages_common_table_meta::meta::SegmentInfo;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::meta::Versioned;
+
+use crate::metrics::metrics_inc_commit_aborts;
+use crate::metrics::metrics_inc_commit_mutation_success;
+use crate::operations::common::AppendGenerator;
+use crate::operations::common::CommitMeta;
+use crate::operations::common::SnapshotGenerator;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Commits the data appended to several tables in one meta transaction,
+    /// the data is visible in all of the tables or none of them.
+    ///
+    /// The commit metas are produced by the pipelines built with [`FuseTable::do_pending_commit`].
+    #[async_backtrace::framed]
+    pub async fn commit_multi_table_insertion(
+        ctx: Arc<dyn TableContext>,
+        mut tables: Vec<(Arc<dyn Table>, CommitMeta)>,
+        overwrite: bool,
+    ) -> Result<()> {
+        let Some((first, _)) = tables.first() else {
+            return Ok(());
+        };
+        let catalog = ctx.get_catalog(first.get_table_info().catalog()).await?;
+        let deduplicated_label = ctx.get_settings().get_deduplicate_label()?;
+
+        let start_time = Instant::now();
+        let mut backoff = Self::set_backoff(None);
+        let mut retries = 0;
+        ctx.set_status_info("begin commit multi-table insertion");
+
+        loop {
+            let mut update_table_metas = Vec::with_capacity(tables.len());
+            let mut snapshots = Vec::with_capacity(tables.len());
+            for (table, commit_meta) in tables.iter() {
+                let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+                let schema = fuse_table.schema().as_ref().clone();
+                let previous = fuse_table.read_table_snapshot().await?;
+
+                let mut snapshot_gen = AppendGenerator::new(ctx.clone(), overwrite);
+                snapshot_gen
+                    .set_conflict_resolve_context(commit_meta.conflict_resolve_context.clone());
+                snapshot_gen
+                    .fill_default_values(schema.clone(), &previous)
+                    .await?;
+                let snapshot = snapshot_gen.generate_new_snapshot(
+                    schema,
+                    fuse_table.cluster_key_meta.clone(),
+                    previous,
+                )?;
+
+                let location = fuse_table
+                    .meta_location_generator
+                    .snapshot_location_from_uuid(&snapshot.snapshot_id, TableSnapshot::VERSION)?;
+                fuse_table
+                    .operator
+                    .write(&location, snapshot.to_bytes()?)
+                    .await?;
+
+                let table_info = &fuse_table.table_info;
+                update_table_metas.push(UpdateTableMetaReq {
+                    table_id: table_info.ident.table_id,
+                    seq: MatchSeq::Exact(table_info.ident.seq),
+                    new_table_meta: Self::new_table_meta(table_info, &snapshot, &location),
+                    copied_files: None,
+                    deduplicated_label: deduplicated_label.clone(),
+                });
+                snapshots.push((location, snapshot));
+            }
+
+            {
+                // In an explicit transaction, the new table metas are staged in the session,
+                // and published to the meta service when the transaction is committed.
+                let txn_mgr = ctx.txn_mgr();
+                let mut txn_mgr = txn_mgr.lock();
+                if txn_mgr.is_active() {
+                    for ((table, _), (req, (location, snapshot))) in tables
+                        .iter()
+                        .zip(update_table_metas.into_iter().zip(snapshots.into_iter()))
+                    {
+                        txn_mgr.upsert_table_meta(table.get_table_info(), req);
+                        TableSnapshot::cache().put(location, Arc::new(snapshot));
+                    }
+                    return Ok(());
+                }
+            }
+
+            let res = catalog
+                .update_multi_table_meta(UpdateMultiTableMetaReq { update_table_metas })
+                .await;
+            match res {
+                Ok(_) => {
+                    for ((table, commit_meta), (location, snapshot)) in
+                        tables.iter().zip(snapshots.into_iter())
+                    {
+                        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+                        TableSnapshot::cache().put(location.clone(), Arc::new(snapshot));
+                        Self::write_last_snapshot_hint(
+                            &fuse_table.operator,
+                            &fuse_table.meta_location_generator,
+                            location,
+                        )
+                        .await;
+                        for segment in commit_meta.abort_operation.segments.iter() {
+                            ctx.add_segment_location((segment.to_string(), SegmentInfo::VERSION))?;
+                        }
+                    }
+                    metrics_inc_commit_mutation_success();
+                    ctx.set_status_info(&format!(
+                        "commit multi-table insertion success after {} retries, which took {} ms",
+                        retries,
+                        start_time.elapsed().as_millis()
+                    ));
+                    return Ok(());
+                }
+                Err(e) => {
+                    if !Self::no_side_effects_in_meta_store(&e) {
+                        // we are not sure about if the table state has been modified or not,
+                        // just propagate the error without aborting anything.
+                        return Err(e);
+                    }
+
+                    for ((table, _), (location, _)) in tables.iter().zip(snapshots.iter()) {
+                        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+                        info!(
+                            "removing uncommitted table snapshot at location {}, of table {}",
+                            location, fuse_table.table_info.ident
+                        );
+                        let _ = fuse_table.operator.delete(location).await;
+                    }
+
+                    let next_backoff = if Self::is_error_recoverable(&e, false) {
+                        backoff.next_backoff()
+                    } else {
+                        None
+                    };
+                    match next_backoff {
+                        Some(d) => {
+                            debug!(
+                                "got error TableVersionMismatched, multi-table insertion will be retried {} ms later",
+                                d.as_millis()
+                            );
+                            common_base::base::tokio::time::sleep(d).await;
+                            retries += 1;
+                            for (table, _) in tables.iter_mut() {
+                                *table = table.refresh(ctx.as_ref()).await?;
+                            }
+                        }
+                        None => {
+                            metrics_inc_commit_aborts();
+                            for (table, commit_meta) in tables {
+                                let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+                                commit_meta
+                                    .abort_operation
+                                    .abort(ctx.clone(), fuse_table.operator.clone())
+                                    .await?;
+                            }
+                            return Err(ErrorCode::OCCRetryFailure(format!(
+                                "can not fulfill the multi-table insertion after retries({} times, {} ms), aborted: {}",
+                                retries,
+                                start_time.elapsed().as_millis(),
+                                e
+                            )));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+
+use common_exception::Result;
+use common_expression::DataBlock;
+
+/// Regroups the appended blocks into blocks of about `target_compressed_size` bytes once written.
+///
+/// The written size of a block is estimated from its in-memory size and the compression ratio
+/// observed on the blocks serialized so far. Small blocks are merged and large blocks are split,
+/// so that the blocks of a table cover similar amounts of data in storage whatever its
+/// compressibility, which keeps the pruning granularity consistent.
+pub struct AdaptiveBlockSizer {
+    target_compressed_size: usize,
+    max_rows_per_block: usize,
+
+    // The sizes of the blocks serialized so far.
+    memory_bytes: u64,
+    compressed_bytes: u64,
+
+    buffered: Vec<DataBlock>,
+    buffered_rows: usize,
+    buffered_bytes: usize,
+}
+
+impl AdaptiveBlockSizer {
+    pub fn new(target_compressed_size: usize, max_rows_per_block: usize) -> Self {
+        AdaptiveBlockSizer {
+            target_compressed_size: target_compressed_size.max(1),
+            max_rows_per_block: max_rows_per_block.max(1),
+            memory_bytes: 0,
+            compressed_bytes: 0,
+            buffered: vec![],
+            buffered_rows: 0,
+            buffered_bytes: 0,
+        }
+    }
+
+    /// Records the in-memory size and the written size of a serialized block.
+    pub fn observe(&mut self, memory_size: u64, compressed_size: u64) {
+        self.memory_bytes += memory_size;
+        self.compressed_bytes += compressed_size;
+    }
+
+    /// Estimates the written size of `memory_size` bytes of data.
+    ///
+    /// Until a block is serialized, the data is assumed not to compress at all, so the first
+    /// blocks are never larger than the target.
+    pub fn estimate(&self, memory_size: usize) -> usize {
+        if self.memory_bytes == 0 {
+            return memory_size;
+        }
+        let ratio = self.compressed_bytes as f64 / self.memory_bytes as f64;
+        (memory_size as f64 * ratio) as usize
+    }
+
+    /// Buffers `block`, and pushes the blocks ready to be serialized to `output`.
+    pub fn push(&mut self, block: DataBlock, output: &mut VecDeque<DataBlock>) -> Result<()> {
+        if block.is_empty() {
+            return Ok(());
+        }
+        self.buffer(block);
+
+        let estimated = self.estimate(self.buffered_bytes);
+        if estimated < self.target_compressed_size && self.buffered_rows < self.max_rows_per_block {
+            return Ok(());
+        }
+
+        let block = self.take()?;
+        let num_rows = block.num_rows();
+        let rows_per_block = (num_rows as f64 * self.target_compressed_size as f64
+            / estimated.max(1) as f64) as usize;
+        let rows_per_block = rows_per_block.clamp(1, self.max_rows_per_block);
+
+        let mut start = 0;
+        while num_rows - start >= rows_per_block {
+            output.push_back(block.slice(start..start + rows_per_block));
+            start += rows_per_block;
+        }
+        if start < num_rows {
+            // Waits for more data to fill the remaining rows up.
+            self.buffer(block.slice(start..num_rows));
+        }
+        Ok(())
+    }
+
+    /// Takes the buffered data, if any.
+    pub fn flush(&mut self) -> Result<Option<DataBlock>> {
+        if self.buffered.is_empty() {
+            return Ok(None);
+        }
+        self.take().map(Some)
+    }
+
+    fn buffer(&mut self, block: DataBlock) {
+        self.buffered_rows += block.num_rows();
+        self.buffered_bytes += block.memory_size();
+        self.buffered.push(block);
+    }
+
+    fn take(&mut self) -> Result<DataBlock> {
+        self.buffered_rows = 0;
+        self.buffered_bytes = 0;
+        let blocks = std::mem::take(&mut self.buffered);
+        if blocks.len() == 1 {
+            return Ok(blocks.into_iter().next().unwrap());
+        }
+        DataBlock::concat(&blocks)
+    }
+}
@@ -12,13 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod adaptive_block_size;
 mod fill_internal_columns;
 mod sink_commit;
+mod sink_pending_commit;
+mod transform_coalesce_blocks;
 mod transform_mutation_aggregator;
 mod transform_serialize_block;
 mod transform_serialize_segment;
+pub use adaptive_block_size::AdaptiveBlockSizer;
 pub use fill_internal_columns::FillInternalColumnProcessor;
 pub use sink_commit::CommitSink;
+pub use sink_pending_commit::PendingCommitMeta;
+pub use sink_pending_commit::PendingCommitSink;
+pub use transform_coalesce_blocks::AppendBlockCoalescer;
 pub use transform_mutation_aggregator::TableMutationAggregator;
 pub use transform_serialize_block::TransformSerializeBlock;
 pub use transform_serialize_segment::TransformSerializeSegment;
@@ -0,0 +1,62 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::BlockMetaInfoDowncast;
+use common_expression::DataBlock;
+use common_pipeline_core::processors::port::InputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sinks::Sink;
+use common_pipeline_sinks::Sinker;
+use parking_lot::Mutex;
+
+use crate::operations::common::CommitMeta;
+
+/// The commit meta of a table, which is committed later together with other tables.
+pub type PendingCommitMeta = Arc<Mutex<Option<CommitMeta>>>;
+
+// Keeps the commit meta instead of committing it, see `FuseTable::commit_multi_table_insertion`.
+pub struct PendingCommitSink {
+    commit_meta: PendingCommitMeta,
+}
+
+impl PendingCommitSink {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        commit_meta: PendingCommitMeta,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(Sinker::create(
+            input,
+            PendingCommitSink { commit_meta },
+        )))
+    }
+}
+
+impl Sink for PendingCommitSink {
+    const NAME: &'static str = "PendingCommitSink";
+
+    fn consume(&mut self, data_block: DataBlock) -> Result<()> {
+        let meta = data_block
+            .get_meta()
+            .cloned()
+            .ok_or(ErrorCode::Internal("No block meta. It's a bug"))?;
+        let meta = CommitMeta::downcast_from(meta)
+            .ok_or(ErrorCode::Internal("No commit meta. It's a bug"))?;
+        *self.commit_meta.lock() = Some(meta);
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::Projection;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::BlockMetaInfoDowncast;
+use common_expression::BlockThresholds;
+use common_expression::DataBlock;
+use common_pipeline_transforms::processors::transforms::AsyncAccumulatingTransform;
+use futures::future::try_join_all;
+use log::info;
+use opendal::Operator;
+use storages_common_table_meta::meta::BlockMeta;
+
+use crate::io::BlockBuilder;
+use crate::io::BlockReader;
+use crate::io::ReadSettings;
+use crate::operations::common::AbortOperation;
+use crate::operations::common::TransformSerializeBlock;
+use crate::statistics::ClusterStatsGenerator;
+use crate::FuseStorageFormat;
+use crate::FuseTable;
+
+/// Merges the small blocks appended by a load into blocks of the target size, before they are
+/// committed.
+///
+/// The blocks of a load are compacted before they are written, but each thread (and each node
+/// of a distributed load) still writes its own tail block. The small blocks are read back,
+/// concatenated and rewritten, the merged ones are removed since they are not committed yet.
+pub struct AppendBlockCoalescer {
+    ctx: Arc<dyn TableContext>,
+    dal: Operator,
+    thresholds: BlockThresholds,
+    storage_format: FuseStorageFormat,
+    read_settings: ReadSettings,
+    block_reader: Arc<BlockReader>,
+    block_builder: BlockBuilder,
+
+    small_blocks: Vec<BlockMeta>,
+    small_rows: usize,
+    small_bytes: usize,
+}
+
+impl AppendBlockCoalescer {
+    pub fn try_create(ctx: Arc<dyn TableContext>, table: &FuseTable) -> Result<Self> {
+        let projection = Projection::Columns(table.all_column_indices());
+        let block_reader = table.create_block_reader(ctx.clone(), projection, false, false)?;
+        let block_builder = TransformSerializeBlock::create_block_builder(
+            ctx.clone(),
+            table,
+            ClusterStatsGenerator::default(),
+        )?;
+        Ok(AppendBlockCoalescer {
+            read_settings: ReadSettings::from_ctx(&ctx)?,
+            ctx,
+            dal: table.get_operator(),
+            thresholds: table.get_block_thresholds(),
+            storage_format: table.storage_format,
+            block_reader,
+            block_builder,
+            small_blocks: vec![],
+            small_rows: 0,
+            small_bytes: 0,
+        })
+    }
+
+    #[async_backtrace::framed]
+    async fn merge(&mut self) -> Result<Option<DataBlock>> {
+        let small_blocks = std::mem::take(&mut self.small_blocks);
+        self.small_rows = 0;
+        self.small_bytes = 0;
+
+        if small_blocks.len() < 2 {
+            return Ok(small_blocks
+                .into_iter()
+                .next()
+                .map(|block_meta| DataBlock::empty_with_meta(Box::new(block_meta))));
+        }
+
+        let blocks = try_join_all(small_blocks.iter().map(|block_meta| {
+            self.block_reader
+                .read_by_meta(&self.read_settings, block_meta, &self.storage_format)
+        }))
+        .await?;
+        let block = DataBlock::concat(&blocks)?;
+
+        let serialized = self.block_builder.build(block, |block, generator| {
+            generator.gen_stats_for_append(block)
+        })?;
+        let block_meta = TransformSerializeBlock::write_block(&self.dal, serialized).await?;
+        info!(
+            "merged {} small appended blocks into {}",
+            small_blocks.len(),
+            block_meta.location.0
+        );
+
+        // The merged blocks are not referenced by any snapshot, remove them.
+        let mut abort_operation = AbortOperation::default();
+        for block_meta in &small_blocks {
+            abort_operation.add_block(block_meta);
+        }
+        abort_operation
+            .abort(self.ctx.clone(), self.dal.clone())
+            .await?;
+
+        Ok(Some(DataBlock::empty_with_meta(Box::new(block_meta))))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncAccumulatingTransform for AppendBlockCoalescer {
+    const NAME: &'static str = "AppendBlockCoalescer";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        let Some(block_meta) = data.get_meta().and_then(BlockMeta::downcast_ref_from) else {
+            return Ok(Some(data));
+        };
+
+        let num_rows = block_meta.row_count as usize;
+        let num_bytes = block_meta.block_size as usize;
+        if self.thresholds.check_large_enough(num_rows, num_bytes) {
+            return Ok(Some(data));
+        }
+
+        self.small_blocks.push(block_meta.clone());
+        self.small_rows += num_rows;
+        self.small_bytes += num_bytes;
+        if self
+            .thresholds
+            .check_large_enough(self.small_rows, self.small_bytes)
+        {
+            return self.merge().await;
+        }
+        Ok(None)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self, _output: bool) -> Result<Option<DataBlock>> {
+        self.merge().await
+    }
+}
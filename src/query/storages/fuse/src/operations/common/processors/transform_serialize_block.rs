@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::BTreeSet;
+use std::collections::VecDeque;
 use std::sync::Arc;
 use std::time::Instant;
 
@@ -25,8 +27,11 @@ use common_expression::DataBlock;
 use common_pipeline_core::pipe::PipeItem;
 use common_pipeline_core::processors::port::InputPort;
 use common_pipeline_core::processors::processor::ProcessorPtr;
+use futures::future::try_join_all;
 use opendal::Operator;
 use storages_common_index::BloomIndex;
+use storages_common_index::InvertedIndex;
+use storages_common_table_meta::meta::BlockMeta;
 
 use crate::io::write_data;
 use crate::io::BlockBuilder;
@@ -37,16 +42,21 @@ use crate::metrics::metrics_inc_block_index_write_nums;
 use crate::metrics::metrics_inc_block_write_bytes;
 use crate::metrics::metrics_inc_block_write_milliseconds;
 use crate::metrics::metrics_inc_block_write_nums;
+use crate::operations::common::AdaptiveBlockSizer;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::common::MutationLogEntry;
 use crate::operations::common::MutationLogs;
 use crate::operations::mutation::ClusterStatsGenType;
 use crate::operations::mutation::SerializeDataMeta;
+use crate::operations::mutation::SerializeDeletionVector;
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::processor::Event;
 use crate::pipelines::processors::Processor;
 use crate::statistics::ClusterStatsGenerator;
+use crate::FuseStorageFormat;
 use crate::FuseTable;
+use crate::Table;
+use crate::FUSE_OPT_KEY_BLOCK_COMPRESSED_SIZE;
 
 enum State {
     Consume,
@@ -55,20 +65,30 @@ enum State {
         stats_type: ClusterStatsGenType,
         index: Option<BlockMetaIndex>,
     },
-    Serialized {
-        serialized: BlockSerialization,
-        index: Option<BlockMetaIndex>,
-    },
+    // Write the buffered blocks.
+    Flush,
+    NeedWriteDeletionVector(SerializeDeletionVector),
 }
 
 pub struct TransformSerializeBlock {
     state: State,
     input: Arc<InputPort>,
     output: Arc<OutputPort>,
-    output_data: Option<DataBlock>,
+    output_data: VecDeque<DataBlock>,
 
     block_builder: BlockBuilder,
     dal: Operator,
+
+    // The serialized blocks waiting to be written, they are written concurrently once
+    // `max_concurrency` blocks or `max_buffer_bytes` bytes are buffered.
+    pending: Vec<(BlockSerialization, Option<BlockMetaIndex>)>,
+    pending_bytes: usize,
+    max_concurrency: usize,
+    max_buffer_bytes: usize,
+
+    // Regroups the appended blocks by their written size, if the table sets `block_compressed_size`.
+    block_sizer: Option<AdaptiveBlockSizer>,
+    sized_blocks: VecDeque<DataBlock>,
 }
 
 impl TransformSerializeBlock {
@@ -79,28 +99,82 @@ impl TransformSerializeBlock {
         table: &FuseTable,
         cluster_stats_gen: ClusterStatsGenerator,
     ) -> Result<Self> {
+        // The blocks of a clustered table are sorted one by one, they can't be merged.
+        let target_compressed_size = table.get_option(FUSE_OPT_KEY_BLOCK_COMPRESSED_SIZE, 0usize);
+        let block_sizer =
+            (target_compressed_size > 0 && table.cluster_key_meta.is_none()).then(|| {
+                let max_rows_per_block = table.get_block_thresholds().max_rows_per_block;
+                AdaptiveBlockSizer::new(target_compressed_size, max_rows_per_block)
+            });
+        let block_builder = Self::create_block_builder(ctx, table, cluster_stats_gen)?;
+        Ok(TransformSerializeBlock {
+            state: State::Consume,
+            input,
+            output,
+            output_data: VecDeque::new(),
+            block_builder,
+            dal: table.get_operator(),
+            pending: vec![],
+            pending_bytes: 0,
+            max_concurrency: 1,
+            max_buffer_bytes: usize::MAX,
+            block_sizer,
+            sized_blocks: VecDeque::new(),
+        })
+    }
+
+    pub(crate) fn create_block_builder(
+        ctx: Arc<dyn TableContext>,
+        table: &FuseTable,
+        cluster_stats_gen: ClusterStatsGenerator,
+    ) -> Result<BlockBuilder> {
         let source_schema = Arc::new(table.table_info.schema().remove_virtual_computed_fields());
-        let bloom_columns_map = table
+        let mut bloom_columns_map = table
             .bloom_index_cols
             .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
-        let block_builder = BlockBuilder {
+        let mut bloom_path_fields = table
+            .bloom_index_paths
+            .bloom_index_path_fields(source_schema.clone(), BloomIndex::supported_type);
+        let mut inverted_columns_map = table
+            .inverted_index_cols
+            .bloom_index_fields(source_schema.clone(), InvertedIndex::supported_type)?;
+        let column_codecs = table.column_codecs.field_codecs(&source_schema);
+        let encrypted_fields = match table.storage_format {
+            FuseStorageFormat::Parquet => table.encrypted_columns.field_indexes(&source_schema),
+            FuseStorageFormat::Native => BTreeSet::new(),
+        };
+        // the indexes would leak the values of the encrypted columns.
+        bloom_columns_map.retain(|index, _| !encrypted_fields.contains(index));
+        bloom_path_fields.retain(|(index, _, _)| !encrypted_fields.contains(index));
+        inverted_columns_map.retain(|index, _| !encrypted_fields.contains(index));
+        Ok(BlockBuilder {
             ctx,
             meta_locations: table.meta_location_generator().clone(),
             source_schema,
             write_settings: table.get_write_settings(),
             cluster_stats_gen,
             bloom_columns_map,
-        };
-        Ok(TransformSerializeBlock {
-            state: State::Consume,
-            input,
-            output,
-            output_data: None,
-            block_builder,
-            dal: table.get_operator(),
+            bloom_path_fields,
+            inverted_columns_map,
+            column_codecs,
+            encrypted_fields,
+            encryption_key_id: table.encryption_key_id.clone(),
         })
     }
 
+    /// Writes multiple blocks concurrently, up to `mutation_write_concurrency` blocks, while
+    /// the serialized blocks buffered by all the threads are bounded by `mutation_write_buffer_size`.
+    ///
+    /// Used by the mutations rewriting lots of blocks, such as DELETE and UPDATE,
+    /// which are otherwise bounded by the latency of writing one block at a time.
+    pub fn with_concurrent_write(mut self) -> Result<Self> {
+        let settings = self.block_builder.ctx.get_settings();
+        let max_threads = settings.get_max_threads()?.max(1) as usize;
+        self.max_concurrency = settings.get_mutation_write_concurrency()?.max(1) as usize;
+        self.max_buffer_bytes = settings.get_mutation_write_buffer_size()? as usize / max_threads;
+        Ok(self)
+    }
+
     pub fn into_processor(self) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(self)))
     }
@@ -122,6 +196,54 @@ impl TransformSerializeBlock {
         };
         DataBlock::empty_with_meta(Box::new(meta))
     }
+
+    #[async_backtrace::framed]
+    pub(crate) async fn write_block(
+        dal: &Operator,
+        serialized: BlockSerialization,
+    ) -> Result<BlockMeta> {
+        let start = Instant::now();
+        // write block data.
+        let raw_block_data = serialized.block_raw_data;
+        let data_size = raw_block_data.len();
+        let path = serialized.block_meta.location.0.as_str();
+        write_data(raw_block_data, dal, path).await?;
+
+        // Perf.
+        {
+            metrics_inc_block_write_nums(1);
+            metrics_inc_block_write_bytes(data_size as u64);
+            metrics_inc_block_write_milliseconds(start.elapsed().as_millis() as u64);
+        }
+
+        // write index data.
+        let bloom_index_state = serialized.bloom_index_state;
+        if let Some(bloom_index_state) = bloom_index_state {
+            let index_size = bloom_index_state.data.len();
+            write_data(bloom_index_state.data, dal, &bloom_index_state.location.0).await?;
+            // Perf.
+            {
+                metrics_inc_block_index_write_nums(1);
+                metrics_inc_block_index_write_bytes(index_size as u64);
+                metrics_inc_block_index_write_milliseconds(start.elapsed().as_millis() as u64);
+            }
+        }
+        if let Some(inverted_index_state) = serialized.inverted_index_state {
+            let index_size = inverted_index_state.data.len();
+            write_data(
+                inverted_index_state.data,
+                dal,
+                &inverted_index_state.location,
+            )
+            .await?;
+            // Perf.
+            {
+                metrics_inc_block_index_write_nums(1);
+                metrics_inc_block_index_write_bytes(index_size as u64);
+            }
+        }
+        Ok(serialized.block_meta)
+    }
 }
 
 #[async_trait::async_trait]
@@ -139,7 +261,7 @@ impl Processor for TransformSerializeBlock {
             return Ok(Event::Sync);
         }
 
-        if matches!(self.state, State::Serialized { .. }) {
+        if matches!(self.state, State::Flush | State::NeedWriteDeletionVector(_)) {
             return Ok(Event::Async);
         }
 
@@ -151,12 +273,39 @@ impl Processor for TransformSerializeBlock {
             return Ok(Event::NeedConsume);
         }
 
-        if let Some(data_block) = self.output_data.take() {
+        if let Some(data_block) = self.output_data.pop_front() {
             self.output.push_data(Ok(data_block));
             return Ok(Event::NeedConsume);
         }
 
+        if let Some(block) = self.sized_blocks.pop_front() {
+            self.state = State::NeedSerialize {
+                block,
+                stats_type: ClusterStatsGenType::Generally,
+                index: None,
+            };
+            return Ok(Event::Sync);
+        }
+
         if self.input.is_finished() {
+            if let Some(block) = self
+                .block_sizer
+                .as_mut()
+                .map(|s| s.flush())
+                .transpose()?
+                .flatten()
+            {
+                self.state = State::NeedSerialize {
+                    block,
+                    stats_type: ClusterStatsGenType::Generally,
+                    index: None,
+                };
+                return Ok(Event::Sync);
+            }
+            if !self.pending.is_empty() {
+                self.state = State::Flush;
+                return Ok(Event::Async);
+            }
             self.output.finish();
             return Ok(Event::Finished);
         }
@@ -205,12 +354,34 @@ impl Processor for TransformSerializeBlock {
                     self.output.push_data(Ok(data_block));
                     Ok(Event::NeedConsume)
                 }
+                SerializeDataMeta::SerializeDeletionVector(serialize_deletion_vector) => {
+                    // mark the rows of the old block as deleted
+                    self.state = State::NeedWriteDeletionVector(serialize_deletion_vector);
+                    Ok(Event::Async)
+                }
             }
         } else if input_data.is_empty() {
             // do nothing
             let data_block = Self::mutation_logs(MutationLogEntry::DoNothing);
             self.output.push_data(Ok(data_block));
             Ok(Event::NeedConsume)
+        } else if let Some(block_sizer) = self.block_sizer.as_mut() {
+            // append block, regrouped by the written size
+            block_sizer.push(input_data, &mut self.sized_blocks)?;
+            match self.sized_blocks.pop_front() {
+                Some(block) => {
+                    self.state = State::NeedSerialize {
+                        block,
+                        stats_type: ClusterStatsGenType::Generally,
+                        index: None,
+                    };
+                    Ok(Event::Sync)
+                }
+                None => {
+                    self.input.set_need_data();
+                    Ok(Event::NeedData)
+                }
+            }
         } else {
             // append block
             self.state = State::NeedSerialize {
@@ -240,7 +411,28 @@ impl Processor for TransformSerializeBlock {
                             }
                         })?;
 
-                self.state = State::Serialized { serialized, index };
+                if let Some(block_sizer) = self.block_sizer.as_mut() {
+                    block_sizer.observe(
+                        serialized.block_meta.block_size,
+                        serialized.block_meta.file_size,
+                    );
+                }
+
+                self.pending_bytes += serialized.size as usize
+                    + serialized
+                        .bloom_index_state
+                        .as_ref()
+                        .map_or(0, |state| state.data.len())
+                    + serialized
+                        .inverted_index_state
+                        .as_ref()
+                        .map_or(0, |state| state.data.len());
+                self.pending.push((serialized, index));
+                if self.pending.len() >= self.max_concurrency
+                    || self.pending_bytes >= self.max_buffer_bytes
+                {
+                    self.state = State::Flush;
+                }
             }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
@@ -250,59 +442,57 @@ impl Processor for TransformSerializeBlock {
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Consume) {
-            State::Serialized { serialized, index } => {
-                let start = Instant::now();
-                // write block data.
-                let raw_block_data = serialized.block_raw_data;
-                let data_size = raw_block_data.len();
-                let path = serialized.block_meta.location.0.as_str();
-                write_data(raw_block_data, &self.dal, path).await?;
-
-                // Perf.
-                {
-                    metrics_inc_block_write_nums(1);
-                    metrics_inc_block_write_bytes(data_size as u64);
-                    metrics_inc_block_write_milliseconds(start.elapsed().as_millis() as u64);
-                }
+            State::Flush => {
+                let pending = std::mem::take(&mut self.pending);
+                self.pending_bytes = 0;
+                let (serialized, indexes): (Vec<_>, Vec<_>) = pending.into_iter().unzip();
+                let block_metas = try_join_all(
+                    serialized
+                        .into_iter()
+                        .map(|serialized| Self::write_block(&self.dal, serialized)),
+                )
+                .await?;
 
-                // write index data.
-                let bloom_index_state = serialized.bloom_index_state;
-                if let Some(bloom_index_state) = bloom_index_state {
-                    let index_size = bloom_index_state.data.len();
-                    write_data(
-                        bloom_index_state.data,
-                        &self.dal,
-                        &bloom_index_state.location.0,
-                    )
-                    .await?;
-                    // Perf.
-                    {
-                        metrics_inc_block_index_write_nums(1);
-                        metrics_inc_block_index_write_bytes(index_size as u64);
-                        metrics_inc_block_index_write_milliseconds(
-                            start.elapsed().as_millis() as u64
-                        );
-                    }
-                }
+                for (block_meta, index) in block_metas.into_iter().zip(indexes) {
+                    let data_block = if let Some(index) = index {
+                        Self::mutation_logs(MutationLogEntry::ReplacedBlock {
+                            index,
+                            block_meta: Arc::new(block_meta),
+                        })
+                    } else {
+                        let progress_values = ProgressValues {
+                            rows: block_meta.row_count as usize,
+                            bytes: block_meta.block_size as usize,
+                        };
+                        self.block_builder
+                            .ctx
+                            .get_write_progress()
+                            .incr(&progress_values);
 
-                let data_block = if let Some(index) = index {
-                    Self::mutation_logs(MutationLogEntry::ReplacedBlock {
-                        index,
-                        block_meta: Arc::new(serialized.block_meta),
-                    })
-                } else {
-                    let progress_values = ProgressValues {
-                        rows: serialized.block_meta.row_count as usize,
-                        bytes: serialized.block_meta.block_size as usize,
+                        DataBlock::empty_with_meta(Box::new(block_meta))
                     };
-                    self.block_builder
-                        .ctx
-                        .get_write_progress()
-                        .incr(&progress_values);
+                    self.output_data.push_back(data_block);
+                }
+            }
+            State::NeedWriteDeletionVector(SerializeDeletionVector {
+                index,
+                block_meta,
+                deletion_vector,
+            }) => {
+                let location = self
+                    .block_builder
+                    .meta_locations
+                    .gen_deletion_vector_location();
+                write_data(deletion_vector.to_bytes()?, &self.dal, &location.0).await?;
 
-                    DataBlock::empty_with_meta(Box::new(serialized.block_meta))
-                };
-                self.output_data = Some(data_block);
+                let mut new_block_meta = block_meta.as_ref().clone();
+                new_block_meta.deletion_vector_location = Some(location);
+                new_block_meta.deleted_row_count = deletion_vector.deleted_rows();
+                self.output_data
+                    .push_back(Self::mutation_logs(MutationLogEntry::ReplacedBlock {
+                        index,
+                        block_meta: Arc::new(new_block_meta),
+                    }));
             }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
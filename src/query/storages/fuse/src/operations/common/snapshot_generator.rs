@@ -29,6 +29,7 @@ use log::info;
 use storages_common_table_meta::meta::ClusterKey;
 use storages_common_table_meta::meta::ColumnStatistics;
 use storages_common_table_meta::meta::Location;
+use storages_common_table_meta::meta::ReclusterProgress;
 use storages_common_table_meta::meta::Statistics;
 use storages_common_table_meta::meta::TableSnapshot;
 use uuid::Uuid;
@@ -203,6 +204,7 @@ impl ConflictResolveContext {
 pub struct MutationGenerator {
     base_snapshot: Arc<TableSnapshot>,
     conflict_resolve_ctx: Option<ConflictResolveContext>,
+    recluster_progress: Option<ReclusterProgress>,
 }
 
 impl MutationGenerator {
@@ -210,8 +212,14 @@ impl MutationGenerator {
         MutationGenerator {
             base_snapshot,
             conflict_resolve_ctx: None,
+            recluster_progress: None,
         }
     }
+
+    pub fn with_recluster_progress(mut self, recluster_progress: ReclusterProgress) -> Self {
+        self.recluster_progress = Some(recluster_progress);
+        self
+    }
 }
 
 impl SnapshotGenerator for MutationGenerator {
@@ -258,7 +266,7 @@ impl SnapshotGenerator for MutationGenerator {
                         default_cluster_key_id,
                     );
                     deduct_statistics_mut(&mut new_summary, &ctx.removed_statistics);
-                    let new_snapshot = TableSnapshot::new(
+                    let mut new_snapshot = TableSnapshot::new(
                         Uuid::new_v4(),
                         &previous.timestamp,
                         Some((previous.snapshot_id, previous.format_version)),
@@ -268,6 +276,7 @@ impl SnapshotGenerator for MutationGenerator {
                         cluster_key_meta,
                         previous.table_statistics_location.clone(),
                     );
+                    new_snapshot.recluster_progress = self.recluster_progress.clone();
                     return Ok(new_snapshot);
                 }
             }
@@ -274,6 +274,8 @@ impl FuseTable {
             self.table_info.schema(),
             &push_down,
             self.bloom_index_cols(),
+            self.bloom_index_paths(),
+            self.inverted_index_cols(),
         )?;
 
         if let Some(inverse) = filters.map(|f| f.inverted_filter) {
@@ -352,6 +354,7 @@ impl FuseTable {
                             cluster_stats,
                             inner_part,
                             whole_block_mutation,
+                            block_meta,
                         })));
                     part_info_ptr
                 })
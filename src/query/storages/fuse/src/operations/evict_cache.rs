@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_exception::Result;
+use log::info;
+use storages_common_cache::CacheAccessor;
+use storages_common_cache_manager::CachedObject;
+use storages_common_index::filters::Xor8Filter;
+use storages_common_index::BloomIndexMeta;
+use storages_common_table_meta::meta::CompactSegmentInfo;
+use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_table_meta::meta::TableSnapshotStatistics;
+
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Evicts the metas of the current snapshot from the in-memory caches of this node,
+    /// including the snapshot, the table statistics, the segments and the bloom indexes
+    /// of the cached segments. They will be loaded from the storage on the next access.
+    #[async_backtrace::framed]
+    pub async fn evict_caches(&self) -> Result<()> {
+        let Some(snapshot_location) = self.snapshot_loc().await? else {
+            return Ok(());
+        };
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(());
+        };
+
+        let mut num_segments = 0;
+        let mut num_bloom_indexes = 0;
+        if let Some(segment_cache) = CompactSegmentInfo::cache() {
+            for (segment_location, _) in snapshot.segments.iter() {
+                let Some(segment) = segment_cache.get(segment_location) else {
+                    continue;
+                };
+                for block_meta in segment.block_metas()? {
+                    if let Some((index_location, _)) = &block_meta.bloom_filter_index_location {
+                        Self::evict_bloom_index(index_location);
+                        num_bloom_indexes += 1;
+                    }
+                }
+                segment_cache.evict(segment_location);
+                num_segments += 1;
+            }
+        }
+
+        if let Some(statistics_location) = &snapshot.table_statistics_location {
+            if let Some(cache) = TableSnapshotStatistics::cache() {
+                cache.evict(statistics_location);
+            }
+        }
+        if let Some(cache) = TableSnapshot::cache() {
+            cache.evict(&snapshot_location);
+        }
+
+        info!(
+            "evicted caches of table {}, snapshot {}, {} segments, {} bloom indexes",
+            self.table_info.desc, snapshot_location, num_segments, num_bloom_indexes
+        );
+        Ok(())
+    }
+
+    fn evict_bloom_index(index_location: &str) {
+        let Some(meta_cache) = BloomIndexMeta::cache() else {
+            return;
+        };
+        if let Some(index_meta) = meta_cache.get(index_location) {
+            // The filters are cached by the index location and the position of the column.
+            if let Some(filter_cache) = Xor8Filter::cache() {
+                for idx in 0..index_meta.columns.len() {
+                    filter_cache.evict(&format!("{index_location}-{idx}"));
+                }
+            }
+            meta_cache.evict(index_location);
+        }
+    }
+}
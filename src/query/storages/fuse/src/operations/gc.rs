@@ -23,6 +23,7 @@ use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
 use common_meta_app::schema::ListIndexesByIdReq;
+use common_sql::BloomIndexColumns;
 use log::error;
 use log::warn;
 use storages_common_cache::CacheAccessor;
@@ -335,13 +336,7 @@ impl FuseTable {
                     continue;
                 }
                 purge_files.push(loc.to_string());
-                for index_id in table_agg_index_ids {
-                    purge_files.push(
-                        TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
-                            loc, *index_id,
-                        ),
-                    )
-                }
+                purge_files.extend(self.block_index_locations(loc, table_agg_index_ids));
             }
 
             for loc in &locations.bloom_location {
@@ -351,6 +346,16 @@ impl FuseTable {
                 purge_files.push(loc.to_string())
             }
 
+            for loc in &locations.deletion_vector_location {
+                if locations_referenced_by_root
+                    .deletion_vector_location
+                    .contains(loc)
+                {
+                    continue;
+                }
+                purge_files.push(loc.to_string())
+            }
+
             purge_files.extend(chunk.iter().map(|loc| loc.0.clone()));
         }
         purge_files.extend(ts_to_be_purged.iter().map(|loc| loc.to_string()));
@@ -387,13 +392,8 @@ impl FuseTable {
                     continue;
                 }
                 blocks_to_be_purged.insert(loc.to_string());
-                for index_id in table_agg_index_ids {
-                    agg_indexes_to_be_purged.insert(
-                        TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
-                            loc, *index_id,
-                        ),
-                    );
-                }
+                agg_indexes_to_be_purged
+                    .extend(self.block_index_locations(loc, table_agg_index_ids));
             }
 
             let mut blooms_to_be_purged = HashSet::new();
@@ -404,6 +404,17 @@ impl FuseTable {
                 blooms_to_be_purged.insert(loc.to_string());
             }
 
+            // deletion vectors are purged along with the blocks.
+            for loc in &locations.deletion_vector_location {
+                if locations_referenced_by_root
+                    .deletion_vector_location
+                    .contains(loc)
+                {
+                    continue;
+                }
+                blocks_to_be_purged.insert(loc.to_string());
+            }
+
             let segment_locations_to_be_purged = HashSet::from_iter(
                 chunk
                     .iter()
@@ -456,18 +467,18 @@ impl FuseTable {
         );
 
         let mut agg_indexes_to_be_purged = HashSet::new();
-        for index_id in table_agg_index_ids {
-            agg_indexes_to_be_purged.extend(root_location_tuple.block_location.iter().map(|loc| {
-                TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
-                    loc, *index_id,
-                )
-            }));
+        for loc in &root_location_tuple.block_location {
+            agg_indexes_to_be_purged.extend(self.block_index_locations(loc, table_agg_index_ids));
         }
 
+        // deletion vectors are purged along with the blocks.
+        let mut blocks_to_be_purged = root_location_tuple.block_location;
+        blocks_to_be_purged.extend(root_location_tuple.deletion_vector_location);
+
         self.purge_block_segments(
             ctx,
             counter,
-            root_location_tuple.block_location,
+            blocks_to_be_purged,
             agg_indexes_to_be_purged,
             root_location_tuple.bloom_location,
             segment_locations_to_be_purged,
@@ -487,6 +498,32 @@ impl FuseTable {
         .await
     }
 
+    // The aggregating index and inverted index files are generated along with the block,
+    // they should be purged together with it.
+    fn block_index_locations(
+        &self,
+        block_location: &str,
+        table_agg_index_ids: &[u64],
+    ) -> Vec<String> {
+        let mut locations = table_agg_index_ids
+            .iter()
+            .map(|index_id| {
+                TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
+                    block_location,
+                    *index_id,
+                )
+            })
+            .collect::<Vec<_>>();
+        if !matches!(self.inverted_index_cols, BloomIndexColumns::None) {
+            locations.push(
+                TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                    block_location,
+                ),
+            );
+        }
+        locations
+    }
+
     async fn purge_block_segments(
         &self,
         ctx: &Arc<dyn TableContext>,
@@ -623,6 +660,7 @@ impl FuseTable {
     ) -> Result<LocationTuple> {
         let mut blocks = HashSet::new();
         let mut blooms = HashSet::new();
+        let mut deletion_vectors = HashSet::new();
 
         let fuse_segments = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
@@ -646,12 +684,14 @@ impl FuseTable {
                 };
                 blocks.extend(location_tuple.block_location.into_iter());
                 blooms.extend(location_tuple.bloom_location.into_iter());
+                deletion_vectors.extend(location_tuple.deletion_vector_location.into_iter());
             }
         }
 
         Ok(LocationTuple {
             block_location: blocks,
             bloom_location: blooms,
+            deletion_vector_location: deletion_vectors,
         })
     }
 
@@ -675,6 +715,7 @@ struct RootSnapshotInfo {
 pub struct LocationTuple {
     pub block_location: HashSet<String>,
     pub bloom_location: HashSet<String>,
+    pub deletion_vector_location: HashSet<String>,
 }
 
 impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
@@ -682,16 +723,21 @@ impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
     fn try_from(value: Arc<CompactSegmentInfo>) -> Result<Self> {
         let mut block_location = HashSet::new();
         let mut bloom_location = HashSet::new();
+        let mut deletion_vector_location = HashSet::new();
         let block_metas = value.block_metas()?;
         for block_meta in block_metas.into_iter() {
             block_location.insert(block_meta.location.0.clone());
             if let Some(bloom_loc) = &block_meta.bloom_filter_index_location {
                 bloom_location.insert(bloom_loc.0.clone());
             }
+            if let Some(deletion_vector_loc) = &block_meta.deletion_vector_location {
+                deletion_vector_location.insert(deletion_vector_loc.0.clone());
+            }
         }
         Ok(Self {
             block_location,
             bloom_location,
+            deletion_vector_location,
         })
     }
 }
@@ -54,6 +54,7 @@ use crate::io::write_data;
 use crate::io::BlockBuilder;
 use crate::io::BlockReader;
 use crate::io::CompactSegmentInfoReader;
+use crate::io::DeletionVector;
 use crate::io::MetaReaders;
 use crate::io::ReadSettings;
 use crate::io::WriteSettings;
@@ -299,10 +300,15 @@ impl AggregationContext {
         )
         .await?;
         let origin_num_rows = origin_data_block.num_rows();
+        // rows marked as deleted by the deletion vector are purged as well
+        let deletion_vector = match &block_meta.deletion_vector_location {
+            Some((location, _)) => DeletionVector::read(&self.data_accessor, location).await?,
+            None => DeletionVector::default(),
+        };
         // apply delete
         let mut bitmap = MutableBitmap::new();
         for row in 0..origin_num_rows {
-            if modified_offsets.contains(&row) {
+            if modified_offsets.contains(&row) || deletion_vector.contains(row) {
                 bitmap.push(false);
             } else {
                 bitmap.push(true);
@@ -15,10 +15,13 @@
 mod agg_index_sink;
 mod analyze;
 mod append;
+mod changes;
 mod commit;
+mod commit_multi_table;
 pub mod common;
 mod compact;
 mod delete;
+mod evict_cache;
 mod gc;
 pub mod merge;
 pub mod merge_into;
@@ -35,6 +38,7 @@ mod truncate;
 mod update;
 pub mod util;
 pub use agg_index_sink::AggIndexSink;
+pub use changes::ChangedBlocks;
 pub use common::BlockMetaIndex;
 pub use common::FillInternalColumnProcessor;
 pub use common::TransformSerializeBlock;
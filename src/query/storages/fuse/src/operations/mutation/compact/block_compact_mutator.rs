@@ -417,7 +417,7 @@ impl CompactTaskBuilder {
             }
         }
 
-        let total_rows = self.total_rows + block.row_count as usize;
+        let total_rows = self.total_rows + block.live_row_count() as usize;
         let total_size = self.total_size + block.block_size as usize;
         if !thresholds.check_large_enough(total_rows, total_size) {
             // blocks < N
@@ -453,6 +453,11 @@ impl CompactTaskBuilder {
     }
 
     fn check_compact(&self, block: &Arc<BlockMeta>) -> bool {
+        // purge the rows marked as deleted by the deletion vector.
+        if block.deletion_vector_location.is_some() {
+            return true;
+        }
+
         let column_ids: HashSet<ColumnId> = block.col_metas.keys().cloned().collect();
         if self.column_ids == column_ids {
             // Check if the block needs to be resort.
@@ -546,7 +551,7 @@ impl CompactTaskBuilder {
 
                 let (total_rows, total_size) =
                     blocks.iter().chain(tail.iter()).fold((0, 0), |mut acc, x| {
-                        acc.0 += x.row_count as usize;
+                        acc.0 += x.live_row_count() as usize;
                         acc.1 += x.block_size as usize;
                         acc
                     });
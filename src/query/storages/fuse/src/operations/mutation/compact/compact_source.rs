@@ -26,6 +26,7 @@ use common_pipeline_core::processors::processor::ProcessorPtr;
 use storages_common_table_meta::meta::BlockMeta;
 
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::io::ReadSettings;
 use crate::metrics::*;
 use crate::operations::mutation::compact::compact_part::CompactPartInfo;
@@ -42,7 +43,7 @@ use crate::MergeIOReadResult;
 enum State {
     ReadData(Option<PartInfoPtr>),
     Concat {
-        read_res: Vec<MergeIOReadResult>,
+        read_res: Vec<(MergeIOReadResult, Option<DeletionVector>)>,
         metas: Vec<Arc<BlockMeta>>,
         index: BlockMetaIndex,
     },
@@ -133,12 +134,17 @@ impl Processor for CompactSource {
                 let blocks = read_res
                     .into_iter()
                     .zip(metas.into_iter())
-                    .map(|(data, meta)| {
-                        self.block_reader.deserialize_chunks_with_meta(
+                    .map(|((data, deletion_vector), meta)| {
+                        let block = self.block_reader.deserialize_chunks_with_meta(
                             &meta,
                             &self.storage_format,
                             data,
-                        )
+                        )?;
+                        // purge the rows marked as deleted.
+                        match deletion_vector {
+                            Some(deletion_vector) => deletion_vector.apply(block),
+                            None => Ok(block),
+                        }
                     })
                     .collect::<Result<Vec<_>>>()?;
 
@@ -194,14 +200,22 @@ impl Processor for CompactSource {
                                     metrics_inc_compact_block_read_bytes(block.block_size);
                                 }
 
-                                block_reader
+                                let data = block_reader
                                     .read_columns_data_by_merge_io(
                                         &settings,
                                         &block.location.0,
                                         &block.col_metas,
                                         &None,
                                     )
-                                    .await
+                                    .await?;
+                                let deletion_vector = match &block.deletion_vector_location {
+                                    Some((location, _)) => Some(
+                                        DeletionVector::read(&block_reader.operator, location)
+                                            .await?,
+                                    ),
+                                    None => None,
+                                };
+                                Ok::<_, ErrorCode>((data, deletion_vector))
                             });
                         }
 
@@ -30,6 +30,7 @@ pub use compact::SegmentCompactionState;
 pub use compact::SegmentCompactor;
 pub use mutation_meta::ClusterStatsGenType;
 pub use mutation_meta::SerializeDataMeta;
+pub use mutation_meta::SerializeDeletionVector;
 pub use mutation_part::DeletedSegmentInfo;
 pub use mutation_part::Mutation;
 pub use mutation_part::MutationPartInfo;
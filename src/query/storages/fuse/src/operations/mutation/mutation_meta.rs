@@ -12,10 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use common_expression::BlockMetaInfo;
 use common_expression::BlockMetaInfoDowncast;
+use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::ClusterStatistics;
 
+use crate::io::DeletionVector;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::mutation::compact::CompactExtraInfo;
 use crate::operations::mutation::DeletedSegmentInfo;
@@ -25,6 +29,7 @@ pub enum SerializeDataMeta {
     SerializeBlock(SerializeBlock),
     DeletedSegment(DeletedSegmentInfo),
     CompactExtras(CompactExtraInfo),
+    SerializeDeletionVector(SerializeDeletionVector),
 }
 
 #[typetag::serde(name = "serialize_data_meta")]
@@ -55,3 +60,27 @@ impl SerializeBlock {
         SerializeBlock { index, stats_type }
     }
 }
+
+/// Mark the rows of the block as deleted with the deletion vector, instead of rewriting the block.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct SerializeDeletionVector {
+    pub index: BlockMetaIndex,
+    /// the origin block meta.
+    pub block_meta: Arc<BlockMeta>,
+    /// all the rows deleted from the block, including the ones deleted before.
+    pub deletion_vector: DeletionVector,
+}
+
+impl SerializeDeletionVector {
+    pub fn create(
+        index: BlockMetaIndex,
+        block_meta: Arc<BlockMeta>,
+        deletion_vector: DeletionVector,
+    ) -> Self {
+        SerializeDeletionVector {
+            index,
+            block_meta,
+            deletion_vector,
+        }
+    }
+}
@@ -16,6 +16,7 @@ use std::any::Any;
 use std::ops::Not;
 use std::sync::Arc;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_base::base::ProgressValues;
 use common_catalog::plan::InternalColumn;
 use common_catalog::plan::InternalColumnMeta;
@@ -34,15 +35,18 @@ use common_expression::Value;
 use common_expression::ROW_ID_COL_NAME;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_sql::evaluator::BlockOperator;
+use storages_common_table_meta::meta::BlockMeta;
 
 use super::mutation_meta::SerializeBlock;
 use crate::fuse_part::FusePartInfo;
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::io::ReadSettings;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::mutation::mutation_meta::ClusterStatsGenType;
 use crate::operations::mutation::Mutation;
 use crate::operations::mutation::SerializeDataMeta;
+use crate::operations::mutation::SerializeDeletionVector;
 use crate::pipelines::processors::port::OutputPort;
 use crate::pipelines::processors::processor::Event;
 use crate::pipelines::processors::processor::ProcessorPtr;
@@ -89,6 +93,9 @@ pub struct MutationSource {
 
     index: BlockMetaIndex,
     stats_type: ClusterStatsGenType,
+    block_meta: Option<Arc<BlockMeta>>,
+    deletion_vector: Option<DeletionVector>,
+    deletion_vector_max_ratio: u64,
 }
 
 impl MutationSource {
@@ -104,6 +111,7 @@ impl MutationSource {
         storage_format: FuseStorageFormat,
         query_row_id_col: bool,
     ) -> Result<ProcessorPtr> {
+        let deletion_vector_max_ratio = ctx.get_settings().get_deletion_vector_max_ratio()?;
         Ok(ProcessorPtr::create(Box::new(MutationSource {
             state: State::ReadData(None),
             output,
@@ -117,8 +125,42 @@ impl MutationSource {
             query_row_id_col,
             index: BlockMetaIndex::default(),
             stats_type: ClusterStatsGenType::Generally,
+            block_meta: None,
+            deletion_vector: None,
+            deletion_vector_max_ratio,
         })))
     }
+
+    /// Mark the rows matching the predicate as deleted in the deletion vector of the block.
+    ///
+    /// Returns None if the block should be rewritten instead, i.e. deletion vector is disabled,
+    /// or too many rows of the block are deleted.
+    fn build_deletion_vector(
+        &self,
+        predicate: &Bitmap,
+        offsets: Option<&[usize]>,
+    ) -> Option<DeletionVector> {
+        if self.deletion_vector_max_ratio == 0
+            || !matches!(self.storage_format, FuseStorageFormat::Parquet)
+        {
+            return None;
+        }
+        let block_meta = self.block_meta.as_ref()?;
+
+        let mut deletion_vector = self.deletion_vector.clone().unwrap_or_default();
+        for (row, deleted) in predicate.iter().enumerate() {
+            if deleted {
+                deletion_vector.insert(offsets.map_or(row, |offsets| offsets[row]));
+            }
+        }
+
+        if deletion_vector.deleted_rows() * 100
+            > block_meta.row_count * self.deletion_vector_max_ratio
+        {
+            return None;
+        }
+        Some(deletion_vector)
+    }
 }
 
 #[async_trait::async_trait]
@@ -179,6 +221,17 @@ impl Processor for MutationSource {
                     chunks,
                     &self.storage_format,
                 )?;
+
+                // Remove the rows already marked as deleted, keeping the offsets of
+                // the remaining rows in the block.
+                let offsets = match &self.deletion_vector {
+                    Some(deletion_vector) => {
+                        let offsets = deletion_vector.live_offsets(data_block.num_rows());
+                        data_block = deletion_vector.apply(data_block)?;
+                        Some(offsets)
+                    }
+                    None => None,
+                };
                 let num_rows = data_block.num_rows();
 
                 if let Some(filter) = self.filter.as_ref() {
@@ -192,7 +245,7 @@ impl Processor for MutationSource {
                             block_location: block_meta.block_location.clone(),
                             segment_location: block_meta.segment_location.clone(),
                             snapshot_location: None,
-                            offsets: None,
+                            offsets: offsets.clone(),
                         };
                         let internal_col = InternalColumn {
                             column_name: ROW_ID_COL_NAME.to_string(),
@@ -250,6 +303,23 @@ impl Processor for MutationSource {
                                         self.ctx.get_partition(),
                                         DataBlock::empty_with_meta(meta),
                                     );
+                                } else if let Some(deletion_vector) = self.build_deletion_vector(
+                                    predicates.as_column().unwrap(),
+                                    offsets.as_deref(),
+                                ) {
+                                    // mark the rows as deleted, without rewriting the block.
+                                    let meta =
+                                        Box::new(SerializeDataMeta::SerializeDeletionVector(
+                                            SerializeDeletionVector::create(
+                                                self.index.clone(),
+                                                self.block_meta.clone().unwrap(),
+                                                deletion_vector,
+                                            ),
+                                        ));
+                                    self.state = State::Output(
+                                        self.ctx.get_partition(),
+                                        DataBlock::empty_with_meta(meta),
+                                    );
                                 } else {
                                     let predicate_col = predicates.into_column().unwrap();
                                     let filter = predicate_col.not();
@@ -309,6 +379,10 @@ impl Processor for MutationSource {
                         chunks,
                         &self.storage_format,
                     )?;
+                    let remain_block = match &self.deletion_vector {
+                        Some(deletion_vector) => deletion_vector.apply(remain_block)?,
+                        None => remain_block,
+                    };
 
                     let remain_block = if let Some(filter) = filter {
                         // for deletion.
@@ -372,6 +446,9 @@ impl Processor for MutationSource {
                                 ClusterStatsGenType::WithOrigin(part.cluster_stats.clone());
                         }
 
+                        self.block_meta = Some(part.block_meta.clone());
+                        self.deletion_vector = None;
+
                         let inner_part = part.inner_part.clone();
                         let fuse_part = FusePartInfo::from_part(&inner_part)?;
 
@@ -392,6 +469,12 @@ impl Processor for MutationSource {
                                 DataBlock::empty_with_meta(meta),
                             );
                         } else {
+                            if let Some(location) = &fuse_part.deletion_vector_location {
+                                self.deletion_vector = Some(
+                                    DeletionVector::read(&self.block_reader.operator, location)
+                                        .await?,
+                                );
+                            }
                             let read_res = self
                                 .block_reader
                                 .read_columns_data_by_merge_io(
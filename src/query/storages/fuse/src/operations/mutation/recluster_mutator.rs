@@ -36,6 +36,7 @@ use minitrace::Span;
 use storages_common_pruner::BlockMetaIndex;
 use storages_common_table_meta::meta::BlockMeta;
 use storages_common_table_meta::meta::CompactSegmentInfo;
+use storages_common_table_meta::meta::ReclusterProgress;
 use storages_common_table_meta::meta::Statistics;
 use storages_common_table_meta::meta::TableSnapshot;
 
@@ -56,6 +57,8 @@ pub struct ReclusterMutator {
     pub snapshot: Arc<TableSnapshot>,
     pub tasks: Vec<ReclusterTask>,
     pub recluster_blocks_count: u64,
+    pub recluster_bytes: u64,
+    pub average_depth: f64,
     pub remained_blocks: Vec<Arc<BlockMeta>>,
     pub removed_segment_indexes: Vec<usize>,
     pub removed_segment_summary: Statistics,
@@ -82,6 +85,8 @@ impl ReclusterMutator {
             tasks: Vec::new(),
             remained_blocks: Vec::new(),
             recluster_blocks_count: 0,
+            recluster_bytes: 0,
+            average_depth: 0.0,
             removed_segment_indexes: Vec::new(),
             removed_segment_summary: Statistics::default(),
         })
@@ -110,6 +115,9 @@ impl ReclusterMutator {
         let recluster_block_size = self.ctx.get_settings().get_recluster_block_size()? as usize;
         let memory_threshold = recluster_block_size.min(mem_info.avail as usize * 1024 * 40 / 100);
 
+        // The total bytes of blocks rewritten by this run, 0 means no limit.
+        let io_budget = self.ctx.get_settings().get_recluster_io_budget()? as usize;
+
         let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
         let max_blocks_num = std::cmp::max(
             memory_threshold / self.block_thresholds.max_bytes_per_block,
@@ -164,7 +172,7 @@ impl ReclusterMutator {
                 continue;
             }
 
-            let selected_idx =
+            let (selected_idx, average_depth) =
                 Self::fetch_max_depth(points_map, self.depth_threshold, max_blocks_num)?;
             if selected_idx.is_empty() {
                 remained_blocks.extend(block_metas.into_iter());
@@ -175,6 +183,7 @@ impl ReclusterMutator {
             let diff = blocks_idx.difference(&selected_idx);
             diff.into_iter()
                 .for_each(|v| remained_blocks.push(block_metas[*v].clone()));
+            self.average_depth = average_depth;
 
             let mut over_memory = false;
             let mut task_bytes = 0;
@@ -189,6 +198,19 @@ impl ReclusterMutator {
 
                 let block_size = block_meta.block_size as usize;
                 let row_count = block_meta.row_count as usize;
+                // The selected blocks are ordered from the most overlapping point outwards,
+                // so the blocks beyond the io budget are left to the next run. At least two
+                // blocks are kept, otherwise the run makes no progress.
+                let selected_count = self.recluster_blocks_count as usize + selected_blocks.len();
+                if io_budget > 0
+                    && selected_count >= 2
+                    && self.recluster_bytes as usize + task_bytes + block_size > io_budget
+                {
+                    remained_blocks.push(block_meta);
+                    over_memory = true;
+                    continue;
+                }
+
                 if task_bytes + block_size > memory_threshold {
                     self.generate_task(
                         &selected_blocks,
@@ -263,6 +285,22 @@ impl ReclusterMutator {
         };
         self.tasks.push(task);
         self.recluster_blocks_count += block_metas.len() as u64;
+        self.recluster_bytes += total_bytes as u64;
+    }
+
+    /// The recluster progress to be recorded in the snapshot generated by this run.
+    pub fn recluster_progress(&self) -> ReclusterProgress {
+        let runs = self
+            .snapshot
+            .recluster_progress
+            .as_ref()
+            .map_or(0, |v| v.runs);
+        ReclusterProgress {
+            runs: runs + 1,
+            average_depth: self.average_depth,
+            block_count: self.recluster_blocks_count,
+            byte_size: self.recluster_bytes,
+        }
     }
 
     pub fn select_segments(
@@ -301,7 +339,8 @@ impl ReclusterMutator {
             return Ok(indices);
         }
 
-        ReclusterMutator::fetch_max_depth(points_map, 1.0, max_len)
+        let (selected_idx, _) = ReclusterMutator::fetch_max_depth(points_map, 1.0, max_len)?;
+        Ok(selected_idx)
     }
 
     pub fn segment_can_recluster(
@@ -364,7 +403,7 @@ impl ReclusterMutator {
         points_map: HashMap<Vec<Scalar>, (Vec<usize>, Vec<usize>)>,
         depth_threshold: f64,
         max_len: usize,
-    ) -> Result<IndexSet<usize>> {
+    ) -> Result<(IndexSet<usize>, f64)> {
         let mut max_depth = 0;
         let mut max_point = 0;
         let mut block_depths = Vec::new();
@@ -459,7 +498,7 @@ impl ReclusterMutator {
         }
 
         selected_idx.truncate(max_len);
-        Ok(selected_idx)
+        Ok((selected_idx, average_depth))
     }
 
     // block1: [1, 2], block2: [2, 3]. The depth of point '2' is 1.
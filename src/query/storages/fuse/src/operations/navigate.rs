@@ -26,12 +26,15 @@ use futures::TryStreamExt;
 use log::warn;
 use opendal::EntryMode;
 use opendal::Metakey;
+use storages_common_cache::CacheAccessor;
 use storages_common_cache::LoadParams;
-use storages_common_table_meta::meta::TableSnapshot;
+use storages_common_cache_manager::CachedObject;
+use storages_common_table_meta::meta::FormatVersion;
+use storages_common_table_meta::meta::SnapshotHistory;
+use storages_common_table_meta::meta::SnapshotHistoryEntry;
 use storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 
 use crate::io::MetaReaders;
-use crate::io::SnapshotHistoryReader;
 use crate::io::TableMetaLocationGenerator;
 use crate::FuseTable;
 use crate::FUSE_TBL_SNAPSHOT_PREFIX;
@@ -72,27 +75,34 @@ impl FuseTable {
 
     #[async_backtrace::framed]
     pub async fn find<P>(&self, location: String, mut pred: P) -> Result<Arc<FuseTable>>
-    where P: FnMut(&TableSnapshot) -> bool {
+    where P: FnMut(&SnapshotHistoryEntry) -> bool {
         let snapshot_version = TableMetaLocationGenerator::snapshot_version(location.as_str());
-        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
-        // grab the table history as stream
         // snapshots are order by timestamp DESC.
-        let mut snapshot_stream = reader.snapshot_history(
-            location,
-            snapshot_version,
-            self.meta_location_generator().clone(),
-        );
+        let history = self.snapshot_history(location, snapshot_version).await?;
 
         // Find the instant which matches the given `time_point`.
-        let mut instant = None;
-        while let Some(snapshot_with_version) = snapshot_stream.try_next().await? {
-            if pred(snapshot_with_version.0.as_ref()) {
-                instant = Some(snapshot_with_version);
-                break;
+        let instant = history.iter().find(|entry| pred(entry)).cloned();
+
+        let snapshot = match instant {
+            Some(entry) => {
+                let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+                let load_params = LoadParams {
+                    location: entry.location.clone(),
+                    len_hint: None,
+                    ver: entry.format_version,
+                    put_cache: true,
+                };
+                match reader.read(&load_params).await {
+                    Ok(snapshot) => Some((snapshot, entry.location)),
+                    // The snapshot has been purged since its history was cached.
+                    Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => None,
+                    Err(e) => return Err(e),
+                }
             }
-        }
+            None => None,
+        };
 
-        if let Some((snapshot, format_version)) = instant {
+        if let Some((snapshot, loc)) = snapshot {
             // Load the table instance by the snapshot
 
             // The `seq` of ident that we cloned here is JUST a place holder
@@ -108,9 +118,6 @@ impl FuseTable {
             table_info.meta.schema = Arc::new(snapshot.schema.clone());
 
             // 2. the table option `snapshot_location`
-            let loc = self
-                .meta_location_generator
-                .snapshot_location_from_uuid(&snapshot.snapshot_id, format_version)?;
             table_info
                 .meta
                 .options
@@ -137,6 +144,68 @@ impl FuseTable {
         }
     }
 
+    /// Walks the history of the snapshot at `location`.
+    ///
+    /// The walk stops at the first snapshot whose history is cached, so only the snapshots
+    /// committed since the last navigation of the table are read.
+    #[async_backtrace::framed]
+    pub async fn snapshot_history(
+        &self,
+        location: String,
+        format_version: FormatVersion,
+    ) -> Result<Arc<SnapshotHistory>> {
+        let cache = SnapshotHistory::cache();
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+
+        let mut entries = vec![];
+        let mut tail = None;
+        let mut next = Some((location.clone(), format_version));
+        while let Some((loc, ver)) = next.take() {
+            if let Some(history) = cache.as_ref().and_then(|cache| cache.get(&loc)) {
+                tail = Some(history);
+                break;
+            }
+
+            // Only the navigated snapshot is put into the snapshot cache.
+            let load_params = LoadParams {
+                location: loc.clone(),
+                len_hint: None,
+                ver,
+                put_cache: false,
+            };
+            let snapshot = match reader.read(&load_params).await {
+                Ok(snapshot) => snapshot,
+                // The older snapshots have been purged.
+                Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => break,
+                Err(e) => return Err(e),
+            };
+            if let Some((prev_id, prev_version)) = snapshot.prev_snapshot_id {
+                let prev_loc = self
+                    .meta_location_generator
+                    .snapshot_location_from_uuid(&prev_id, prev_version)?;
+                next = Some((prev_loc, prev_version));
+            }
+            entries.push(SnapshotHistoryEntry {
+                snapshot_id: snapshot.snapshot_id,
+                timestamp: snapshot.timestamp,
+                location: loc,
+                format_version: ver,
+            });
+        }
+
+        if entries.is_empty() {
+            if let Some(tail) = tail {
+                return Ok(tail);
+            }
+        }
+
+        let history = Arc::new(SnapshotHistory::new(entries, tail));
+        if let Some(cache) = &cache {
+            cache.put(location, history.clone());
+        }
+        Ok(history)
+    }
+
     #[async_backtrace::framed]
     pub async fn navigate_for_purge(
         &self,
@@ -37,6 +37,10 @@ use crate::operations::read::native_data_source_deserializer::NativeDeserializeD
 use crate::operations::read::native_data_source_reader::ReadNativeDataSource;
 use crate::operations::read::parquet_data_source_deserializer::DeserializeDataTransform;
 use crate::operations::read::parquet_data_source_reader::ReadParquetDataSource;
+use crate::operations::read::parquet_prewhere::ParquetPrewhere;
+use crate::operations::read::scan_limit::ScanLimit;
+use crate::pruning::ParquetPagePruner;
+use crate::pruning::RuntimeFilterPruner;
 
 #[allow(clippy::too_many_arguments)]
 pub fn build_fuse_native_source_pipeline(
@@ -49,15 +53,23 @@ pub fn build_fuse_native_source_pipeline(
     mut max_io_requests: usize,
     index_reader: Arc<Option<AggIndexReader>>,
     virtual_reader: Arc<Option<VirtualColumnReader>>,
+    runtime_filter_pruner: Option<Arc<RuntimeFilterPruner>>,
 ) -> Result<()> {
-    (max_threads, max_io_requests) =
-        adjust_threads_and_request(true, max_threads, max_io_requests, plan);
+    let min_bytes_per_thread = ctx.get_settings().get_min_bytes_per_scan_thread()?;
+    (max_threads, max_io_requests) = adjust_threads_and_request(
+        true,
+        max_threads,
+        max_io_requests,
+        min_bytes_per_thread,
+        plan,
+    );
 
     if topk.is_some() {
         max_threads = max_threads.min(16);
         max_io_requests = max_io_requests.min(16);
     }
 
+    let scan_limit = ScanLimit::try_create(plan);
     let mut source_builder = SourcePipeBuilder::create();
 
     match block_reader.support_blocking_api() {
@@ -81,6 +93,8 @@ pub fn build_fuse_native_source_pipeline(
                         partitions.clone(),
                         index_reader.clone(),
                         virtual_reader.clone(),
+                        runtime_filter_pruner.clone(),
+                        scan_limit.clone(),
                     )?,
                 );
             }
@@ -106,6 +120,8 @@ pub fn build_fuse_native_source_pipeline(
                         partitions.clone(),
                         index_reader.clone(),
                         virtual_reader.clone(),
+                        runtime_filter_pruner.clone(),
+                        scan_limit.clone(),
                     )?,
                 );
             }
@@ -124,6 +140,7 @@ pub fn build_fuse_native_source_pipeline(
             transform_output,
             index_reader.clone(),
             virtual_reader.clone(),
+            scan_limit.clone(),
         )
     })?;
 
@@ -140,10 +157,20 @@ pub fn build_fuse_parquet_source_pipeline(
     mut max_io_requests: usize,
     index_reader: Arc<Option<AggIndexReader>>,
     virtual_reader: Arc<Option<VirtualColumnReader>>,
+    runtime_filter_pruner: Option<Arc<RuntimeFilterPruner>>,
+    page_pruner: Option<Arc<ParquetPagePruner>>,
 ) -> Result<()> {
-    (max_threads, max_io_requests) =
-        adjust_threads_and_request(false, max_threads, max_io_requests, plan);
-
+    let min_bytes_per_thread = ctx.get_settings().get_min_bytes_per_scan_thread()?;
+    (max_threads, max_io_requests) = adjust_threads_and_request(
+        false,
+        max_threads,
+        max_io_requests,
+        min_bytes_per_thread,
+        plan,
+    );
+
+    let prewhere = ParquetPrewhere::try_create(ctx.clone(), &block_reader, plan)?;
+    let scan_limit = ScanLimit::try_create(plan);
     let mut source_builder = SourcePipeBuilder::create();
 
     match block_reader.support_blocking_api() {
@@ -163,6 +190,10 @@ pub fn build_fuse_parquet_source_pipeline(
                         partitions.clone(),
                         index_reader.clone(),
                         virtual_reader.clone(),
+                        runtime_filter_pruner.clone(),
+                        page_pruner.clone(),
+                        prewhere.clone(),
+                        scan_limit.clone(),
                     )?,
                 );
             }
@@ -186,6 +217,10 @@ pub fn build_fuse_parquet_source_pipeline(
                         partitions.clone(),
                         index_reader.clone(),
                         virtual_reader.clone(),
+                        runtime_filter_pruner.clone(),
+                        page_pruner.clone(),
+                        prewhere.clone(),
+                        scan_limit.clone(),
                     )?,
                 );
             }
@@ -209,6 +244,8 @@ pub fn build_fuse_parquet_source_pipeline(
             transform_output,
             index_reader.clone(),
             virtual_reader.clone(),
+            prewhere.clone(),
+            scan_limit.clone(),
         )
     })
 }
@@ -250,6 +287,7 @@ pub fn adjust_threads_and_request(
     is_native: bool,
     mut max_threads: usize,
     mut max_io_requests: usize,
+    min_bytes_per_thread: u64,
     plan: &DataSourcePlan,
 ) -> (usize, usize) {
     if !plan.parts.is_lazy {
@@ -280,6 +318,25 @@ pub fn adjust_threads_and_request(
         block_nums = std::cmp::max(block_nums, plan.parts.partitions.len() / 8);
         block_nums = std::cmp::max(block_nums, 1);
 
+        // Each thread reads at least `min_bytes_per_thread` bytes after pruning, so that a small
+        // scan does not pay the scheduling overhead of many threads.
+        if min_bytes_per_thread > 0 {
+            let read_bytes = plan
+                .parts
+                .partitions
+                .iter()
+                .filter_map(|part| part.as_any().downcast_ref::<FusePartInfo>())
+                .map(|part| {
+                    part.columns_meta
+                        .values()
+                        .map(|meta| meta.offset_length().1)
+                        .sum::<u64>()
+                })
+                .sum::<u64>();
+            let threads = std::cmp::max(read_bytes.div_ceil(min_bytes_per_thread), 1);
+            block_nums = std::cmp::min(block_nums, threads as usize);
+        }
+
         max_threads = std::cmp::min(max_threads, block_nums);
         max_io_requests = std::cmp::min(max_io_requests, block_nums);
     }
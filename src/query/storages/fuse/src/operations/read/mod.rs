@@ -21,7 +21,9 @@ mod native_rows_fetcher;
 mod parquet_data_source;
 mod parquet_data_source_deserializer;
 mod parquet_data_source_reader;
+mod parquet_prewhere;
 mod parquet_rows_fetcher;
+mod scan_limit;
 
 pub use fuse_rows_fetcher::build_row_fetcher_pipeline;
 pub use fuse_source::build_fuse_parquet_source_pipeline;
@@ -65,6 +65,7 @@ use crate::io::BlockReader;
 use crate::io::VirtualColumnReader;
 use crate::metrics::metrics_inc_pruning_prewhere_nums;
 use crate::operations::read::native_data_source::NativeDataSourceMeta;
+use crate::operations::read::scan_limit::ScanLimit;
 
 pub struct NativeDeserializeDataTransform {
     func_ctx: FunctionContext,
@@ -113,6 +114,7 @@ pub struct NativeDeserializeDataTransform {
 
     index_reader: Arc<Option<AggIndexReader>>,
     virtual_reader: Arc<Option<VirtualColumnReader>>,
+    scan_limit: Option<Arc<ScanLimit>>,
 }
 
 impl NativeDeserializeDataTransform {
@@ -126,6 +128,7 @@ impl NativeDeserializeDataTransform {
         output: Arc<OutputPort>,
         index_reader: Arc<Option<AggIndexReader>>,
         virtual_reader: Arc<Option<VirtualColumnReader>>,
+        scan_limit: Option<Arc<ScanLimit>>,
     ) -> Result<ProcessorPtr> {
         let scan_progress = ctx.get_scan_progress();
 
@@ -245,6 +248,7 @@ impl NativeDeserializeDataTransform {
 
                 index_reader,
                 virtual_reader,
+                scan_limit,
             },
         )))
     }
@@ -519,10 +523,24 @@ impl Processor for NativeDeserializeDataTransform {
         }
 
         if let Some(data_block) = self.output_data.take() {
+            if let Some(limit) = &self.scan_limit {
+                limit.add_rows(data_block.num_rows());
+            }
             self.output.push_data(Ok(data_block));
             return Ok(Event::NeedConsume);
         }
 
+        // The parts not started are dropped once enough rows are produced.
+        if !self.inited
+            && self
+                .scan_limit
+                .as_ref()
+                .is_some_and(|limit| limit.reached())
+        {
+            self.parts.clear();
+            self.chunks.clear();
+        }
+
         if !self.chunks.is_empty() {
             if !self.input.has_data() {
                 self.input.set_need_data();
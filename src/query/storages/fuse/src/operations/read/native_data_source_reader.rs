@@ -35,6 +35,8 @@ use crate::io::BlockReader;
 use crate::io::TableMetaLocationGenerator;
 use crate::io::VirtualColumnReader;
 use crate::operations::read::native_data_source::NativeDataSourceMeta;
+use crate::operations::read::scan_limit::ScanLimit;
+use crate::pruning::RuntimeFilterPruner;
 use crate::FusePartInfo;
 
 pub struct ReadNativeDataSource<const BLOCKING_IO: bool> {
@@ -49,9 +51,12 @@ pub struct ReadNativeDataSource<const BLOCKING_IO: bool> {
 
     index_reader: Arc<Option<AggIndexReader>>,
     virtual_reader: Arc<Option<VirtualColumnReader>>,
+    runtime_filter_pruner: Option<Arc<RuntimeFilterPruner>>,
+    scan_limit: Option<Arc<ScanLimit>>,
 }
 
 impl ReadNativeDataSource<true> {
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         id: usize,
         ctx: Arc<dyn TableContext>,
@@ -60,6 +65,8 @@ impl ReadNativeDataSource<true> {
         partitions: StealablePartitions,
         index_reader: Arc<Option<AggIndexReader>>,
         virtual_reader: Arc<Option<VirtualColumnReader>>,
+        runtime_filter_pruner: Option<Arc<RuntimeFilterPruner>>,
+        scan_limit: Option<Arc<ScanLimit>>,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
         SyncSourcer::create(ctx.clone(), output.clone(), ReadNativeDataSource::<true> {
@@ -72,11 +79,33 @@ impl ReadNativeDataSource<true> {
             partitions,
             index_reader,
             virtual_reader,
+            runtime_filter_pruner,
+            scan_limit,
         })
     }
+
+    // Steal one part which can not be pruned by the runtime filters, only the column statistics
+    // are checked here, the bloom index is checked in the async reader.
+    fn steal_one(&self) -> Option<PartInfoPtr> {
+        loop {
+            if self
+                .scan_limit
+                .as_ref()
+                .is_some_and(|limit| limit.reached())
+            {
+                return None;
+            }
+            let part = self.partitions.steal_one(self.id)?;
+            match &self.runtime_filter_pruner {
+                Some(pruner) if !pruner.should_keep_by_stats(&part) => continue,
+                _ => return Some(part),
+            }
+        }
+    }
 }
 
 impl ReadNativeDataSource<false> {
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         id: usize,
         ctx: Arc<dyn TableContext>,
@@ -85,6 +114,8 @@ impl ReadNativeDataSource<false> {
         partitions: StealablePartitions,
         index_reader: Arc<Option<AggIndexReader>>,
         virtual_reader: Arc<Option<VirtualColumnReader>>,
+        runtime_filter_pruner: Option<Arc<RuntimeFilterPruner>>,
+        scan_limit: Option<Arc<ScanLimit>>,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
         Ok(ProcessorPtr::create(Box::new(ReadNativeDataSource::<
@@ -99,6 +130,8 @@ impl ReadNativeDataSource<false> {
             partitions,
             index_reader,
             virtual_reader,
+            runtime_filter_pruner,
+            scan_limit,
         })))
     }
 }
@@ -107,7 +140,7 @@ impl SyncSource for ReadNativeDataSource<true> {
     const NAME: &'static str = "SyncReadNativeDataSource";
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
-        match self.partitions.steal_one(self.id) {
+        match self.steal_one() {
             None => Ok(None),
             Some(part) => {
                 if let Some(index_reader) = self.index_reader.as_ref() {
@@ -194,7 +227,27 @@ impl Processor for ReadNativeDataSource<false> {
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
-        let parts = self.partitions.steal(self.id, self.batch_size);
+        if self
+            .scan_limit
+            .as_ref()
+            .is_some_and(|limit| limit.reached())
+        {
+            self.finished = true;
+            return Ok(());
+        }
+
+        let parts = loop {
+            let parts = self.partitions.steal(self.id, self.batch_size);
+            match &self.runtime_filter_pruner {
+                Some(pruner) if !parts.is_empty() => {
+                    let parts = pruner.prune(parts).await;
+                    if !parts.is_empty() {
+                        break parts;
+                    }
+                }
+                _ => break parts,
+            }
+        };
 
         if !parts.is_empty() {
             let mut chunks = Vec::with_capacity(parts.len());
@@ -204,8 +257,8 @@ impl Processor for ReadNativeDataSource<false> {
                 let index_reader = self.index_reader.clone();
                 let virtual_reader = self.virtual_reader.clone();
                 let ctx = self.partitions.ctx.clone();
-                chunks.push(async move {
-                    let handler = tokio::spawn(async_backtrace::location!().frame(async move {
+                chunks.push(tokio::spawn(async_backtrace::location!().frame(
+                    async move {
                         let fuse_part = FusePartInfo::from_part(&part)?;
                         if let Some(index_reader) = index_reader.as_ref() {
                             let loc =
@@ -241,12 +294,34 @@ impl Processor for ReadNativeDataSource<false> {
                                 .async_read_native_columns_data(&part, &ctx, &None)
                                 .await?,
                         ))
-                    }));
-                    handler.await.unwrap()
-                });
+                    },
+                )));
             }
 
-            self.output_data = Some((parts, futures::future::try_join_all(chunks).await?));
+            let abort_handles = chunks
+                .iter()
+                .map(|chunk| chunk.abort_handle())
+                .collect::<Vec<_>>();
+            let sources = futures::future::try_join_all(
+                chunks
+                    .into_iter()
+                    .map(|chunk| async { chunk.await.unwrap() }),
+            );
+            let sources = match &self.scan_limit {
+                Some(limit) => {
+                    tokio::select! {
+                        sources = sources => sources?,
+                        _ = limit.wait_reached() => {
+                            // Enough rows are produced by the other readers.
+                            abort_handles.iter().for_each(|handle| handle.abort());
+                            self.finished = true;
+                            return Ok(());
+                        }
+                    }
+                }
+                None => sources.await?,
+            };
+            self.output_data = Some((parts, sources));
             return Ok(());
         }
 
@@ -15,19 +15,33 @@
 use std::fmt::Debug;
 use std::fmt::Formatter;
 
+use common_arrow::arrow::bitmap::Bitmap;
 use common_catalog::plan::PartInfoPtr;
 use common_exception::Result;
 use common_expression::BlockMetaInfo;
 use common_expression::BlockMetaInfoPtr;
+use common_expression::DataBlock;
 use serde::Deserializer;
 use serde::Serializer;
 
+use crate::io::DeletionVector;
 use crate::io::MergeIOReadResult;
 use crate::io::VirtualMergeIOReadResult;
 
 pub enum DataSource {
     AggIndex((PartInfoPtr, MergeIOReadResult)),
-    Normal((MergeIOReadResult, Option<VirtualMergeIOReadResult>)),
+    Normal(
+        (
+            MergeIOReadResult,
+            Option<VirtualMergeIOReadResult>,
+            Option<DeletionVector>,
+        ),
+    ),
+    /// The deserialized prewhere columns, the selection of the rows
+    /// and the remain columns, see [`ParquetPrewhere`].
+    ///
+    /// [`ParquetPrewhere`]: crate::operations::read::parquet_prewhere::ParquetPrewhere
+    Prewhere((DataBlock, Bitmap, MergeIOReadResult)),
 }
 
 pub struct DataSourceMeta {
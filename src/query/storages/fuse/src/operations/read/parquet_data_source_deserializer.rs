@@ -42,6 +42,8 @@ use crate::io::UncompressedBuffer;
 use crate::io::VirtualColumnReader;
 use crate::metrics::metrics_inc_remote_io_deserialize_milliseconds;
 use crate::operations::read::parquet_data_source::DataSourceMeta;
+use crate::operations::read::parquet_prewhere::ParquetPrewhere;
+use crate::operations::read::scan_limit::ScanLimit;
 
 pub struct DeserializeDataTransform {
     scan_progress: Arc<Progress>,
@@ -58,11 +60,14 @@ pub struct DeserializeDataTransform {
 
     index_reader: Arc<Option<AggIndexReader>>,
     virtual_reader: Arc<Option<VirtualColumnReader>>,
+    prewhere: Option<Arc<ParquetPrewhere>>,
+    scan_limit: Option<Arc<ScanLimit>>,
 }
 
 unsafe impl Send for DeserializeDataTransform {}
 
 impl DeserializeDataTransform {
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         ctx: Arc<dyn TableContext>,
         block_reader: Arc<BlockReader>,
@@ -71,6 +76,8 @@ impl DeserializeDataTransform {
         output: Arc<OutputPort>,
         index_reader: Arc<Option<AggIndexReader>>,
         virtual_reader: Arc<Option<VirtualColumnReader>>,
+        prewhere: Option<Arc<ParquetPrewhere>>,
+        scan_limit: Option<Arc<ScanLimit>>,
     ) -> Result<ProcessorPtr> {
         let buffer_size = ctx.get_settings().get_parquet_uncompressed_buffer_size()? as usize;
         let scan_progress = ctx.get_scan_progress();
@@ -105,6 +112,8 @@ impl DeserializeDataTransform {
             uncompressed_buffer: UncompressedBuffer::new(buffer_size),
             index_reader,
             virtual_reader,
+            prewhere,
+            scan_limit,
         })))
     }
 }
@@ -132,10 +141,23 @@ impl Processor for DeserializeDataTransform {
         }
 
         if let Some(data_block) = self.output_data.take() {
+            if let Some(limit) = &self.scan_limit {
+                limit.add_rows(data_block.num_rows());
+            }
             self.output.push_data(Ok(data_block));
             return Ok(Event::NeedConsume);
         }
 
+        // The parts read are dropped once enough rows are produced.
+        if self
+            .scan_limit
+            .as_ref()
+            .is_some_and(|limit| limit.reached())
+        {
+            self.parts.clear();
+            self.chunks.clear();
+        }
+
         if !self.chunks.is_empty() {
             if !self.input.has_data() {
                 self.input.set_need_data();
@@ -188,7 +210,42 @@ impl Processor for DeserializeDataTransform {
 
                     self.output_data = Some(block);
                 }
-                DataSource::Normal((data, virtual_data)) => {
+                DataSource::Prewhere((prewhere_block, filter, data)) => {
+                    let start = Instant::now();
+                    let part = FusePartInfo::from_part(&part)?;
+                    let prewhere = self.prewhere.as_ref().unwrap();
+
+                    let (data_block, offsets) = prewhere.deserialize(
+                        part,
+                        prewhere_block,
+                        &filter,
+                        data,
+                        self.uncompressed_buffer.clone(),
+                    )?;
+
+                    // Perf.
+                    {
+                        metrics_inc_remote_io_deserialize_milliseconds(
+                            start.elapsed().as_millis() as u64
+                        );
+                    }
+
+                    let progress_values = ProgressValues {
+                        rows: data_block.num_rows(),
+                        bytes: data_block.memory_size(),
+                    };
+                    self.scan_progress.incr(&progress_values);
+
+                    let data_block = data_block.resort(prewhere.schema(), &self.output_schema)?;
+                    if self.block_reader.query_internal_columns() {
+                        let data_block =
+                            fill_internal_column_meta(data_block, part, Some(offsets))?;
+                        self.output_data = Some(data_block);
+                    } else {
+                        self.output_data = Some(data_block);
+                    };
+                }
+                DataSource::Normal((data, virtual_data, deletion_vector)) => {
                     let start = Instant::now();
                     let columns_chunks = data.columns_chunks()?;
                     let part = FusePartInfo::from_part(&part)?;
@@ -197,8 +254,11 @@ impl Processor for DeserializeDataTransform {
                         &part.location,
                         part.nums_rows,
                         &part.compression,
+                        &part.column_compressions,
+                        &part.column_encryptions,
                         &part.columns_meta,
                         columns_chunks,
+                        part.page_selection.as_ref(),
                         Some(self.uncompressed_buffer.clone()),
                     )?;
 
@@ -211,6 +271,22 @@ impl Processor for DeserializeDataTransform {
                         )?;
                     }
 
+                    // Remove the rows marked as deleted, keeping the offsets of the remaining
+                    // rows to generate the internal columns.
+                    let rows = match &part.page_selection {
+                        Some(selection) => selection.rows.clone(),
+                        None => 0..data_block.num_rows(),
+                    };
+                    let offsets = match &deletion_vector {
+                        Some(deletion_vector) => {
+                            let offsets = deletion_vector.live_offsets_of(rows.clone());
+                            data_block = deletion_vector.apply_of(data_block, rows)?;
+                            Some(offsets)
+                        }
+                        None if part.page_selection.is_some() => Some(rows.collect()),
+                        None => None,
+                    };
+
                     // Perf.
                     {
                         metrics_inc_remote_io_deserialize_milliseconds(
@@ -229,7 +305,7 @@ impl Processor for DeserializeDataTransform {
                     // Fill `BlockMetaIndex` as `DataBlock.meta` if query internal columns,
                     // `FillInternalColumnProcessor` will generate internal columns using `BlockMetaIndex` in next pipeline.
                     if self.block_reader.query_internal_columns() {
-                        let data_block = fill_internal_column_meta(data_block, part, None)?;
+                        let data_block = fill_internal_column_meta(data_block, part, offsets)?;
                         self.output_data = Some(data_block);
                     } else {
                         self.output_data = Some(data_block);
@@ -33,10 +33,15 @@ use super::parquet_data_source::DataSource;
 use crate::fuse_part::FusePartInfo;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::io::ReadSettings;
 use crate::io::TableMetaLocationGenerator;
 use crate::io::VirtualColumnReader;
 use crate::operations::read::parquet_data_source::DataSourceMeta;
+use crate::operations::read::parquet_prewhere::ParquetPrewhere;
+use crate::operations::read::scan_limit::ScanLimit;
+use crate::pruning::ParquetPagePruner;
+use crate::pruning::RuntimeFilterPruner;
 
 pub struct ReadParquetDataSource<const BLOCKING_IO: bool> {
     id: usize,
@@ -50,9 +55,14 @@ pub struct ReadParquetDataSource<const BLOCKING_IO: bool> {
 
     index_reader: Arc<Option<AggIndexReader>>,
     virtual_reader: Arc<Option<VirtualColumnReader>>,
+    runtime_filter_pruner: Option<Arc<RuntimeFilterPruner>>,
+    page_pruner: Option<Arc<ParquetPagePruner>>,
+    prewhere: Option<Arc<ParquetPrewhere>>,
+    scan_limit: Option<Arc<ScanLimit>>,
 }
 
 impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         id: usize,
         ctx: Arc<dyn TableContext>,
@@ -61,6 +71,10 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
         partitions: StealablePartitions,
         index_reader: Arc<Option<AggIndexReader>>,
         virtual_reader: Arc<Option<VirtualColumnReader>>,
+        runtime_filter_pruner: Option<Arc<RuntimeFilterPruner>>,
+        page_pruner: Option<Arc<ParquetPagePruner>>,
+        prewhere: Option<Arc<ParquetPrewhere>>,
+        scan_limit: Option<Arc<ScanLimit>>,
     ) -> Result<ProcessorPtr> {
         let batch_size = ctx.get_settings().get_storage_fetch_part_num()? as usize;
 
@@ -75,6 +89,10 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
                 partitions,
                 index_reader,
                 virtual_reader,
+                runtime_filter_pruner,
+                page_pruner,
+                prewhere,
+                scan_limit,
             })
         } else {
             Ok(ProcessorPtr::create(Box::new(ReadParquetDataSource::<
@@ -89,69 +107,135 @@ impl<const BLOCKING_IO: bool> ReadParquetDataSource<BLOCKING_IO> {
                 partitions,
                 index_reader,
                 virtual_reader,
+                runtime_filter_pruner,
+                page_pruner,
+                prewhere,
+                scan_limit,
             })))
         }
     }
 }
 
+impl ReadParquetDataSource<true> {
+    // Steal one part which can not be pruned by the runtime filters, only the column statistics
+    // are checked here, the bloom index is checked in the async reader.
+    //
+    // The pages of the part are also selected by the page indexes if possible.
+    fn steal_one(&self) -> Option<PartInfoPtr> {
+        loop {
+            if self
+                .scan_limit
+                .as_ref()
+                .is_some_and(|limit| limit.reached())
+            {
+                return None;
+            }
+            let part = self.partitions.steal_one(self.id)?;
+            if let Some(pruner) = &self.runtime_filter_pruner {
+                if !pruner.should_keep_by_stats(&part) {
+                    continue;
+                }
+            }
+            match &self.page_pruner {
+                Some(pruner) => match pruner.blocking_prune_part(part) {
+                    Some(part) => return Some(part),
+                    None => continue,
+                },
+                None => return Some(part),
+            }
+        }
+    }
+}
+
 impl SyncSource for ReadParquetDataSource<true> {
     const NAME: &'static str = "SyncReadParquetDataSource";
 
     fn generate(&mut self) -> Result<Option<DataBlock>> {
-        match self.partitions.steal_one(self.id) {
-            None => Ok(None),
-            Some(part) => {
-                if let Some(index_reader) = self.index_reader.as_ref() {
-                    let fuse_part = FusePartInfo::from_part(&part)?;
-                    let loc =
-                        TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
-                            &fuse_part.location,
-                            index_reader.index_id(),
-                        );
-                    if let Some(data) = index_reader.sync_read_parquet_data_by_merge_io(
-                        &ReadSettings::from_ctx(&self.partitions.ctx)?,
-                        &loc,
-                    ) {
-                        // Read from aggregating index.
+        while let Some(part) = self.steal_one() {
+            let fuse_part = FusePartInfo::from_part(&part)?;
+            // The aggregating index is built from all the rows of the block,
+            // it can not be used once some of the rows are deleted.
+            let index_reader = self
+                .index_reader
+                .as_ref()
+                .as_ref()
+                .filter(|_| fuse_part.deletion_vector_location.is_none());
+            if let Some(index_reader) = index_reader {
+                let loc = TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
+                    &fuse_part.location,
+                    index_reader.index_id(),
+                );
+                if let Some(data) = index_reader.sync_read_parquet_data_by_merge_io(
+                    &ReadSettings::from_ctx(&self.partitions.ctx)?,
+                    &loc,
+                ) {
+                    // Read from aggregating index.
+                    return Ok(Some(DataBlock::empty_with_meta(DataSourceMeta::create(
+                        vec![part.clone()],
+                        vec![DataSource::AggIndex(data)],
+                    ))));
+                }
+            }
+
+            let deletion_vector = fuse_part
+                .deletion_vector_location
+                .as_ref()
+                .map(|loc| DeletionVector::blocking_read(&self.block_reader.operator, loc))
+                .transpose()?;
+
+            // Read the columns of the filter first, the part is skipped
+            // if none of its rows is selected by the filter.
+            if let Some(prewhere) = &self.prewhere {
+                match prewhere.sync_read(
+                    &ReadSettings::from_ctx(&self.partitions.ctx)?,
+                    &part,
+                    deletion_vector.as_ref(),
+                )? {
+                    Some(source) => {
                         return Ok(Some(DataBlock::empty_with_meta(DataSourceMeta::create(
-                            vec![part.clone()],
-                            vec![DataSource::AggIndex(data)],
+                            vec![part],
+                            vec![source],
                         ))));
                     }
+                    None => continue,
                 }
+            }
 
-                // If virtual column file exists, read the data from the virtual columns directly.
-                let virtual_source = if let Some(virtual_reader) = self.virtual_reader.as_ref() {
-                    let fuse_part = FusePartInfo::from_part(&part)?;
-                    let loc =
-                        TableMetaLocationGenerator::gen_virtual_block_location(&fuse_part.location);
-
-                    virtual_reader.sync_read_parquet_data_by_merge_io(
-                        &ReadSettings::from_ctx(&self.partitions.ctx)?,
-                        &loc,
-                    )
-                } else {
-                    None
-                };
-
-                let ignore_column_ids = if let Some(virtual_source) = &virtual_source {
-                    &virtual_source.ignore_column_ids
-                } else {
-                    &None
-                };
-
-                let source = self.block_reader.sync_read_columns_data_by_merge_io(
-                    &ReadSettings::from_ctx(&self.partitions.ctx)?,
-                    &part,
-                    ignore_column_ids,
-                )?;
+            // If virtual column file exists, read the data from the virtual columns directly.
+            let virtual_source = if let Some(virtual_reader) = self.virtual_reader.as_ref() {
+                let loc =
+                    TableMetaLocationGenerator::gen_virtual_block_location(&fuse_part.location);
 
-                Ok(Some(DataBlock::empty_with_meta(DataSourceMeta::create(
-                    vec![part],
-                    vec![DataSource::Normal((source, virtual_source))],
-                ))))
-            }
+                virtual_reader.sync_read_parquet_data_by_merge_io(
+                    &ReadSettings::from_ctx(&self.partitions.ctx)?,
+                    &loc,
+                )
+            } else {
+                None
+            };
+
+            let ignore_column_ids = if let Some(virtual_source) = &virtual_source {
+                &virtual_source.ignore_column_ids
+            } else {
+                &None
+            };
+
+            let source = self.block_reader.sync_read_columns_data_by_merge_io(
+                &ReadSettings::from_ctx(&self.partitions.ctx)?,
+                &part,
+                ignore_column_ids,
+            )?;
+
+            return Ok(Some(DataBlock::empty_with_meta(DataSourceMeta::create(
+                vec![part],
+                vec![DataSource::Normal((
+                    source,
+                    virtual_source,
+                    deletion_vector,
+                ))],
+            ))));
         }
+        Ok(None)
     }
 }
 
@@ -191,7 +275,30 @@ impl Processor for ReadParquetDataSource<false> {
 
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
-        let parts = self.partitions.steal(self.id, self.batch_size);
+        if self
+            .scan_limit
+            .as_ref()
+            .is_some_and(|limit| limit.reached())
+        {
+            self.finished = true;
+            return Ok(());
+        }
+
+        let parts = loop {
+            let mut parts = self.partitions.steal(self.id, self.batch_size);
+            if parts.is_empty() {
+                break parts;
+            }
+            if let Some(pruner) = &self.runtime_filter_pruner {
+                parts = pruner.prune(parts).await;
+            }
+            if let Some(pruner) = &self.page_pruner {
+                parts = pruner.prune(parts).await;
+            }
+            if !parts.is_empty() {
+                break parts;
+            }
+        };
 
         if !parts.is_empty() {
             let mut chunks = Vec::with_capacity(parts.len());
@@ -201,12 +308,19 @@ impl Processor for ReadParquetDataSource<false> {
                 let settings = ReadSettings::from_ctx(&self.partitions.ctx)?;
                 let index_reader = self.index_reader.clone();
                 let virtual_reader = self.virtual_reader.clone();
+                let prewhere = self.prewhere.clone();
 
-                chunks.push(async move {
-                    tokio::spawn(async_backtrace::location!().frame(async move {
+                chunks.push(tokio::spawn(async_backtrace::location!().frame(
+                    async move {
                         let part = FusePartInfo::from_part(&part)?;
 
-                        if let Some(index_reader) = index_reader.as_ref() {
+                        // The aggregating index is built from all the rows of the block,
+                        // it can not be used once some of the rows are deleted.
+                        let index_reader = index_reader
+                            .as_ref()
+                            .as_ref()
+                            .filter(|_| part.deletion_vector_location.is_none());
+                        if let Some(index_reader) = index_reader {
                             let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &part.location,
@@ -217,10 +331,25 @@ impl Processor for ReadParquetDataSource<false> {
                                 .await
                             {
                                 // Read from aggregating index.
-                                return Ok::<_, ErrorCode>(DataSource::AggIndex(data));
+                                return Ok::<_, ErrorCode>(Some(DataSource::AggIndex(data)));
                             }
                         }
 
+                        let deletion_vector = match &part.deletion_vector_location {
+                            Some(loc) => {
+                                Some(DeletionVector::read(&block_reader.operator, loc).await?)
+                            }
+                            None => None,
+                        };
+
+                        // Read the columns of the filter first, the part is skipped
+                        // if none of its rows is selected by the filter.
+                        if let Some(prewhere) = prewhere.as_ref() {
+                            return prewhere
+                                .read(&settings, part, deletion_vector.as_ref())
+                                .await;
+                        }
+
                         // If virtual column file exists, read the data from the virtual columns directly.
                         let virtual_source = if let Some(virtual_reader) = virtual_reader.as_ref() {
                             let loc = TableMetaLocationGenerator::gen_virtual_block_location(
@@ -249,14 +378,48 @@ impl Processor for ReadParquetDataSource<false> {
                             )
                             .await?;
 
-                        Ok(DataSource::Normal((source, virtual_source)))
-                    }))
-                    .await
-                    .unwrap()
-                });
+                        Ok(Some(DataSource::Normal((
+                            source,
+                            virtual_source,
+                            deletion_vector,
+                        ))))
+                    },
+                )));
             }
 
-            self.output_data = Some((parts, futures::future::try_join_all(chunks).await?));
+            let abort_handles = chunks
+                .iter()
+                .map(|chunk| chunk.abort_handle())
+                .collect::<Vec<_>>();
+            let sources = futures::future::try_join_all(
+                chunks
+                    .into_iter()
+                    .map(|chunk| async { chunk.await.unwrap() }),
+            );
+            let sources = match &self.scan_limit {
+                Some(limit) => {
+                    tokio::select! {
+                        sources = sources => sources?,
+                        _ = limit.wait_reached() => {
+                            // Enough rows are produced by the other readers.
+                            abort_handles.iter().for_each(|handle| handle.abort());
+                            self.finished = true;
+                            return Ok(());
+                        }
+                    }
+                }
+                None => sources.await?,
+            };
+
+            // The parts skipped by the filter are removed.
+            let (parts, sources): (Vec<_>, Vec<_>) = parts
+                .into_iter()
+                .zip(sources)
+                .filter_map(|(part, source)| source.map(|source| (part, source)))
+                .unzip();
+            if !parts.is_empty() {
+                self.output_data = Some((parts, sources));
+            }
             return Ok(());
         }
 
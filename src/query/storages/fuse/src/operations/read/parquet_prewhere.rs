@@ -0,0 +1,302 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::ops::Range;
+use std::sync::Arc;
+
+use common_arrow::arrow::bitmap::Bitmap;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartInfoPtr;
+use common_catalog::plan::Projection;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::filter_helper::FilterHelpers;
+use common_expression::types::BooleanType;
+use common_expression::DataBlock;
+use common_expression::DataSchema;
+use common_expression::Evaluator;
+use common_expression::Expr;
+use common_expression::FunctionContext;
+use common_expression::TableSchema;
+use common_functions::BUILTIN_FUNCTIONS;
+
+use super::parquet_data_source::DataSource;
+use crate::fuse_part::FusePartInfo;
+use crate::io::BlockReader;
+use crate::io::DeletionVector;
+use crate::io::MergeIOReadResult;
+use crate::io::ReadSettings;
+use crate::io::UncompressedBuffer;
+use crate::metrics::metrics_inc_pruning_prewhere_nums;
+
+/// Late materialization of the parquet blocks.
+///
+/// The columns referred by the pushed down filter are read and deserialized first, and the
+/// filter is evaluated to the selection of the rows. The remaining projected columns are only
+/// read if any row of the block is selected, and only the selected rows are kept after they
+/// are deserialized.
+///
+/// Unlike the prewhere of the native format, the filter is not taken out of the plan, so it
+/// is still evaluated by the filter above the scan.
+pub struct ParquetPrewhere {
+    func_ctx: FunctionContext,
+    prewhere_reader: Arc<BlockReader>,
+    remain_reader: Arc<BlockReader>,
+    filter: Expr,
+    // The prewhere columns followed by the remain columns.
+    schema: DataSchema,
+}
+
+impl ParquetPrewhere {
+    /// Returns `None` if the late materialization does not save any IO, that is, the filter
+    /// refers to all the projected columns, or the filter can not be evaluated on the
+    /// columns read from the blocks (internal columns, virtual columns).
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        block_reader: &BlockReader,
+        plan: &DataSourcePlan,
+    ) -> Result<Option<Arc<ParquetPrewhere>>> {
+        let Some(push_downs) = &plan.push_downs else {
+            return Ok(None);
+        };
+        let Some(filters) = push_downs.filters.as_ref().filter(|_| {
+            push_downs.is_deterministic
+                && push_downs.agg_index.is_none()
+                && push_downs.virtual_columns.is_none()
+        }) else {
+            return Ok(None);
+        };
+
+        let filter = filters.filter.as_expr(&BUILTIN_FUNCTIONS);
+        let filter_columns: HashSet<String> = filter.column_refs().into_keys().collect();
+        let schema = block_reader.schema();
+        if filter_columns.is_empty()
+            || filter_columns.len() >= schema.num_fields()
+            || filter_columns
+                .iter()
+                .any(|name| schema.index_of(name).is_err())
+        {
+            return Ok(None);
+        }
+
+        let (prewhere_columns, remain_columns) =
+            split_projection(&block_reader.projection, &schema, &filter_columns);
+        let table_schema = plan.source_info.schema();
+        let prewhere_reader = BlockReader::create(
+            ctx.clone(),
+            block_reader.operator.clone(),
+            table_schema.clone(),
+            prewhere_columns,
+            false,
+            block_reader.put_cache,
+        )?;
+        let remain_reader = BlockReader::create(
+            ctx.clone(),
+            block_reader.operator.clone(),
+            table_schema,
+            remain_columns,
+            false,
+            block_reader.put_cache,
+        )?;
+
+        let prewhere_schema = prewhere_reader.data_schema();
+        let filter = filter.project_column_ref(|name| prewhere_schema.index_of(name).unwrap());
+        let mut fields = prewhere_schema.fields().clone();
+        fields.extend(remain_reader.data_fields());
+
+        Ok(Some(Arc::new(ParquetPrewhere {
+            func_ctx: ctx.get_function_context()?,
+            prewhere_reader,
+            remain_reader,
+            filter,
+            schema: DataSchema::new(fields),
+        })))
+    }
+
+    /// The schema of the blocks deserialized by [`ParquetPrewhere::deserialize`].
+    pub fn schema(&self) -> &DataSchema {
+        &self.schema
+    }
+
+    /// Reads the part in two stages, returns `None` if no row of the part is selected.
+    #[async_backtrace::framed]
+    pub async fn read(
+        &self,
+        settings: &ReadSettings,
+        part: &FusePartInfo,
+        deletion_vector: Option<&DeletionVector>,
+    ) -> Result<Option<DataSource>> {
+        let data = self
+            .prewhere_reader
+            .read_columns_data_by_merge_io(settings, &part.location, &part.columns_meta, &None)
+            .await?;
+        let Some((block, filter)) = self.evaluate(part, data, deletion_vector)? else {
+            metrics_inc_pruning_prewhere_nums(1);
+            return Ok(None);
+        };
+
+        let data = self
+            .remain_reader
+            .read_columns_data_by_merge_io(settings, &part.location, &part.columns_meta, &None)
+            .await?;
+        Ok(Some(DataSource::Prewhere((block, filter, data))))
+    }
+
+    /// Reads the part in two stages, returns `None` if no row of the part is selected.
+    pub fn sync_read(
+        &self,
+        settings: &ReadSettings,
+        part: &PartInfoPtr,
+        deletion_vector: Option<&DeletionVector>,
+    ) -> Result<Option<DataSource>> {
+        let data = self
+            .prewhere_reader
+            .sync_read_columns_data_by_merge_io(settings, part, &None)?;
+        let fuse_part = FusePartInfo::from_part(part)?;
+        let Some((block, filter)) = self.evaluate(fuse_part, data, deletion_vector)? else {
+            metrics_inc_pruning_prewhere_nums(1);
+            return Ok(None);
+        };
+
+        let data = self
+            .remain_reader
+            .sync_read_columns_data_by_merge_io(settings, part, &None)?;
+        Ok(Some(DataSource::Prewhere((block, filter, data))))
+    }
+
+    /// Deserializes the remain columns and keeps the selected rows of the block,
+    /// the offsets of the selected rows are returned to generate the internal columns.
+    pub fn deserialize(
+        &self,
+        part: &FusePartInfo,
+        prewhere_block: DataBlock,
+        filter: &Bitmap,
+        data: MergeIOReadResult,
+        uncompressed_buffer: Arc<UncompressedBuffer>,
+    ) -> Result<(DataBlock, Vec<usize>)> {
+        let remain_block = self.remain_reader.deserialize_parquet_chunks_with_buffer(
+            &part.location,
+            part.nums_rows,
+            &part.compression,
+            &part.column_compressions,
+            &part.column_encryptions,
+            &part.columns_meta,
+            data.columns_chunks()?,
+            part.page_selection.as_ref(),
+            Some(uncompressed_buffer),
+        )?;
+
+        let num_rows = prewhere_block.num_rows();
+        let mut columns = prewhere_block.columns().to_vec();
+        columns.extend_from_slice(remain_block.columns());
+        let block = DataBlock::new(columns, num_rows).filter_with_bitmap(filter)?;
+
+        let offsets = selected_offsets(selected_rows(part, num_rows), filter);
+        Ok((block, offsets))
+    }
+
+    fn evaluate(
+        &self,
+        part: &FusePartInfo,
+        data: MergeIOReadResult,
+        deletion_vector: Option<&DeletionVector>,
+    ) -> Result<Option<(DataBlock, Bitmap)>> {
+        let block = self
+            .prewhere_reader
+            .deserialize_parquet_chunks_with_buffer(
+                &part.location,
+                part.nums_rows,
+                &part.compression,
+                &part.column_compressions,
+                &part.column_encryptions,
+                &part.columns_meta,
+                data.columns_chunks()?,
+                part.page_selection.as_ref(),
+                None,
+            )?;
+
+        let evaluator = Evaluator::new(&block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let predicate = evaluator
+            .run(&self.filter)
+            .map_err(|e| e.add_message("eval prewhere filter failed:"))?
+            .try_downcast::<BooleanType>()
+            .unwrap();
+        let mut filter = Bitmap::from(FilterHelpers::filter_to_bitmap(predicate, block.num_rows()));
+
+        // The rows marked as deleted are not selected either.
+        if let Some(deletion_vector) = deletion_vector.filter(|v| !v.is_empty()) {
+            let rows = selected_rows(part, block.num_rows());
+            filter = &filter & &deletion_vector.live_filter_of(rows);
+        }
+
+        if filter.unset_bits() == filter.len() {
+            return Ok(None);
+        }
+        Ok(Some((block, filter)))
+    }
+}
+
+// The rows of the block read from the data file.
+fn selected_rows(part: &FusePartInfo, num_rows: usize) -> Range<usize> {
+    match &part.page_selection {
+        Some(selection) => selection.rows.clone(),
+        None => 0..num_rows,
+    }
+}
+
+// The offsets of the rows kept by the filter, the first bit of the filter refers to the row
+// `rows.start`.
+fn selected_offsets(rows: Range<usize>, filter: &Bitmap) -> Vec<usize> {
+    rows.zip(filter.iter())
+        .filter_map(|(offset, selected)| selected.then_some(offset))
+        .collect()
+}
+
+// Splits the projection into the columns referred by the filter and the others,
+// the order of the columns in each part is kept.
+fn split_projection(
+    projection: &Projection,
+    projected_schema: &TableSchema,
+    filter_columns: &HashSet<String>,
+) -> (Projection, Projection) {
+    let is_filter_column = |i: usize| filter_columns.contains(projected_schema.field(i).name());
+    match projection {
+        Projection::Columns(indices) => {
+            let (prewhere, remain): (Vec<_>, Vec<_>) = indices
+                .iter()
+                .enumerate()
+                .partition(|(i, _)| is_filter_column(*i));
+            (
+                Projection::Columns(prewhere.into_iter().map(|(_, idx)| *idx).collect()),
+                Projection::Columns(remain.into_iter().map(|(_, idx)| *idx).collect()),
+            )
+        }
+        Projection::InnerColumns(path_indices) => {
+            let mut prewhere = BTreeMap::new();
+            let mut remain = BTreeMap::new();
+            for (i, (index, path)) in path_indices.iter().enumerate() {
+                if is_filter_column(i) {
+                    prewhere.insert(*index, path.clone());
+                } else {
+                    remain.insert(*index, path.clone());
+                }
+            }
+            (
+                Projection::InnerColumns(prewhere),
+                Projection::InnerColumns(remain),
+            )
+        }
+    }
+}
@@ -268,8 +268,11 @@ impl<const BLOCKING_IO: bool> ParquetRowsFetcher<BLOCKING_IO> {
             &part.location,
             part.nums_rows,
             &part.compression,
+            &part.column_compressions,
+            &part.column_encryptions,
             &part.columns_meta,
             columns_chunks,
+            None,
             Some(uncompressed_buffer),
         )
     }
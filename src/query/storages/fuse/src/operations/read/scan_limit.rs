@@ -0,0 +1,69 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use common_base::base::tokio::sync::Notify;
+use common_catalog::plan::DataSourcePlan;
+
+/// The limit of rows shared by all the pipelines of a fuse scan.
+///
+/// It applies to the `LIMIT n` pushed down without ORDER BY and filters, then every row
+/// read is a row of the result. Once `n` rows are produced by the deserializers, the
+/// readers stop stealing partitions and abort the reads in flight.
+pub struct ScanLimit {
+    limit: usize,
+    produced_rows: AtomicUsize,
+    notify: Notify,
+}
+
+impl ScanLimit {
+    pub fn try_create(plan: &DataSourcePlan) -> Option<Arc<ScanLimit>> {
+        let limit = plan
+            .push_downs
+            .as_ref()
+            .filter(|p| p.order_by.is_empty() && p.filters.is_none() && p.agg_index.is_none())
+            .and_then(|p| p.limit)?;
+        Some(Arc::new(ScanLimit {
+            limit,
+            produced_rows: AtomicUsize::new(0),
+            notify: Notify::new(),
+        }))
+    }
+
+    /// Adds the rows pushed to the downstream of the scan.
+    pub fn add_rows(&self, rows: usize) {
+        let produced = self.produced_rows.fetch_add(rows, Ordering::Relaxed) + rows;
+        if produced >= self.limit && produced - rows < self.limit {
+            self.notify.notify_waiters();
+        }
+    }
+
+    pub fn reached(&self) -> bool {
+        self.produced_rows.load(Ordering::Relaxed) >= self.limit
+    }
+
+    /// Waits until the limit is reached.
+    pub async fn wait_reached(&self) {
+        loop {
+            let notified = self.notify.notified();
+            if self.reached() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
@@ -35,6 +35,8 @@ use crate::io::BlockReader;
 use crate::io::VirtualColumnReader;
 use crate::operations::read::build_fuse_parquet_source_pipeline;
 use crate::operations::read::fuse_source::build_fuse_native_source_pipeline;
+use crate::pruning::ParquetPagePruner;
+use crate::pruning::RuntimeFilterPruner;
 use crate::pruning::SegmentLocation;
 use crate::FuseLazyPartInfo;
 use crate::FuseStorageFormat;
@@ -236,6 +238,27 @@ impl FuseTable {
                 .transpose()?,
         );
 
+        let runtime_filter_pruner = RuntimeFilterPruner::try_create(
+            ctx.clone(),
+            plan,
+            self.table_info.schema(),
+            self.operator.clone(),
+            self.bloom_index_cols(),
+        )?;
+
+        // The pages are only selected for the columns read from the block files.
+        let page_pruner = if self.is_native() || index_reader.is_some() || virtual_reader.is_some()
+        {
+            None
+        } else {
+            ParquetPagePruner::try_create(
+                ctx.clone(),
+                plan,
+                self.table_info.schema(),
+                self.operator.clone(),
+            )?
+        };
+
         Self::build_fuse_source_pipeline(
             ctx.clone(),
             pipeline,
@@ -246,6 +269,8 @@ impl FuseTable {
             max_io_requests,
             index_reader,
             virtual_reader,
+            runtime_filter_pruner,
+            page_pruner,
         )?;
 
         // replace the column which has data mask if needed
@@ -265,6 +290,8 @@ impl FuseTable {
         max_io_requests: usize,
         index_reader: Arc<Option<AggIndexReader>>,
         virtual_reader: Arc<Option<VirtualColumnReader>>,
+        runtime_filter_pruner: Option<Arc<RuntimeFilterPruner>>,
+        page_pruner: Option<Arc<ParquetPagePruner>>,
     ) -> Result<()> {
         let max_threads = ctx.get_settings().get_max_threads()? as usize;
 
@@ -279,6 +306,7 @@ impl FuseTable {
                 max_io_requests,
                 index_reader,
                 virtual_reader,
+                runtime_filter_pruner,
             ),
             FuseStorageFormat::Parquet => build_fuse_parquet_source_pipeline(
                 ctx,
@@ -289,6 +317,8 @@ impl FuseTable {
                 max_io_requests,
                 index_reader,
                 virtual_reader,
+                runtime_filter_pruner,
+                page_pruner,
             ),
         }
     }
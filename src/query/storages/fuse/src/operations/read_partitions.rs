@@ -166,6 +166,7 @@ impl FuseTable {
             }
         }
 
+        let cluster_keys = self.cluster_keys(ctx.clone());
         let mut pruner = if !self.is_native() || self.cluster_key_meta.is_none() {
             FusePruner::create(
                 &ctx,
@@ -173,20 +174,25 @@ impl FuseTable {
                 table_info.schema(),
                 &push_downs,
                 self.bloom_index_cols(),
+                self.bloom_index_paths(),
+                self.inverted_index_cols(),
             )?
         } else {
-            let cluster_keys = self.cluster_keys(ctx.clone());
-
             FusePruner::create_with_pages(
                 &ctx,
                 dal.clone(),
                 table_info.schema(),
                 &push_downs,
                 self.cluster_key_meta.clone(),
-                cluster_keys,
+                cluster_keys.clone(),
                 self.bloom_index_cols(),
+                self.bloom_index_paths(),
+                self.inverted_index_cols(),
             )?
         };
+        if let Some((cluster_key_id, _)) = &self.cluster_key_meta {
+            pruner.set_cluster_key(*cluster_key_id, cluster_keys);
+        }
 
         let block_metas = pruner.read_pruning(segments_location).await?;
         let pruning_stats = pruner.pruning_stats();
@@ -350,7 +356,7 @@ impl FuseTable {
 
         let mut remaining = limit;
         for (block_meta_index, block_meta) in block_metas.iter() {
-            let rows = block_meta.row_count as usize;
+            let rows = block_meta.live_row_count() as usize;
             partitions.partitions.push(Self::all_columns_part(
                 schema,
                 block_meta_index,
@@ -400,7 +406,7 @@ impl FuseTable {
                 projection,
             ));
 
-            let rows = block_meta.row_count as usize;
+            let rows = block_meta.live_row_count() as usize;
 
             statistics.read_rows += rows;
             for column in &columns {
@@ -451,6 +457,10 @@ impl FuseTable {
         let rows_count = meta.row_count;
         let location = meta.location.0.clone();
         let create_on = meta.create_on;
+        let deletion_vector_location = meta
+            .deletion_vector_location
+            .as_ref()
+            .map(|location| location.0.clone());
 
         let sort_min_max = top_k.as_ref().map(|(top_k, default)| {
             meta.col_stats
@@ -459,14 +469,27 @@ impl FuseTable {
                 .unwrap_or((default.clone(), default.clone()))
         });
 
+        let columns_stat = meta.col_stats.clone();
+        let bloom_index = meta
+            .bloom_filter_index_location
+            .clone()
+            .map(|location| (location, meta.bloom_filter_index_size));
+        let page_index = meta.page_index_offset.map(|offset| offset..meta.file_size);
+
         FusePartInfo::create(
             location,
             rows_count,
             columns_meta,
             meta.compression(),
+            meta.column_compressions.clone(),
+            meta.column_encryptions.clone(),
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            deletion_vector_location,
+            Some(columns_stat),
+            bloom_index,
+            page_index,
         )
     }
 
@@ -478,6 +501,7 @@ impl FuseTable {
         projection: &Projection,
     ) -> PartInfoPtr {
         let mut columns_meta = HashMap::with_capacity(projection.len());
+        let mut columns_stat = HashMap::with_capacity(projection.len());
 
         let columns = projection.project_column_nodes(column_nodes).unwrap();
         for column in &columns {
@@ -486,12 +510,19 @@ impl FuseTable {
                 if let Some(column_meta) = meta.col_metas.get(column_id) {
                     columns_meta.insert(*column_id, column_meta.clone());
                 }
+                if let Some(column_stat) = meta.col_stats.get(column_id) {
+                    columns_stat.insert(*column_id, column_stat.clone());
+                }
             }
         }
 
         let rows_count = meta.row_count;
         let location = meta.location.0.clone();
         let create_on = meta.create_on;
+        let deletion_vector_location = meta
+            .deletion_vector_location
+            .as_ref()
+            .map(|location| location.0.clone());
 
         let sort_min_max = top_k.map(|(top_k, default)| {
             let stat = meta.col_stats.get(&top_k.field.column_id);
@@ -499,6 +530,12 @@ impl FuseTable {
                 .unwrap_or((default.clone(), default))
         });
 
+        let bloom_index = meta
+            .bloom_filter_index_location
+            .clone()
+            .map(|location| (location, meta.bloom_filter_index_size));
+        let page_index = meta.page_index_offset.map(|offset| offset..meta.file_size);
+
         // TODO
         // row_count should be a hint value of  LIMIT,
         // not the count the rows in this partition
@@ -507,9 +544,15 @@ impl FuseTable {
             rows_count,
             columns_meta,
             meta.compression(),
+            meta.column_compressions.clone(),
+            meta.column_encryptions.clone(),
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            deletion_vector_location,
+            Some(columns_stat),
+            bloom_index,
+            page_index,
         )
     }
 }
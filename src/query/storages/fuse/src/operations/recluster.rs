@@ -34,6 +34,7 @@ use common_sql::evaluator::CompoundBlockOperator;
 use common_sql::executor::ReclusterSink;
 use common_sql::executor::ReclusterTask;
 use common_sql::BloomIndexColumns;
+use common_sql::BloomIndexPaths;
 use log::warn;
 use opendal::Operator;
 use storages_common_table_meta::meta::CompactSegmentInfo;
@@ -148,10 +149,12 @@ impl FuseTable {
             }
 
             // select the segments with the highest depth.
+            // In distributed mode, every node is expected to take one task,
+            // so scale the number of selected segments with the task count.
             let selected_segs = ReclusterMutator::select_segments(
                 &compact_segments,
                 block_per_seg,
-                max_threads * 2,
+                max_threads * 2 * max_tasks,
                 default_cluster_key_id,
             )?;
             // select the blocks with the highest depth.
@@ -315,7 +318,8 @@ impl FuseTable {
             )))
         })?;
 
-        let snapshot_gen = MutationGenerator::new(plan.snapshot.clone());
+        let snapshot_gen = MutationGenerator::new(plan.snapshot.clone())
+            .with_recluster_progress(plan.recluster_progress.clone());
         pipeline.add_sink(|input| {
             CommitSink::try_create(
                 self,
@@ -358,6 +362,8 @@ impl FuseTable {
             None,
             vec![],
             BloomIndexColumns::None,
+            BloomIndexPaths::default(),
+            BloomIndexColumns::None,
             max_concurrency,
         )?;
 
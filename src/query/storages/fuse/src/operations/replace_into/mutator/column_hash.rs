@@ -69,9 +69,11 @@ pub fn row_hash_of_columns(
                 NumberScalar::Float32(v) => sip.write_u32(v.to_bits()),
                 NumberScalar::Float64(v) => sip.write_u64(v.to_bits()),
             },
-            ScalarRef::Timestamp(v) => sip.write_i64(v),
+            ScalarRef::Timestamp(v) | ScalarRef::Time(v) => sip.write_i64(v),
             ScalarRef::String(v) => sip.write(v),
             ScalarRef::Bitmap(v) => sip.write(v),
+            ScalarRef::Geometry(v) => sip.write(v),
+            ScalarRef::Binary(v) => sip.write(v),
             ScalarRef::Decimal(v) => match v {
                 DecimalScalar::Decimal128(i, DecimalSize { precision, scale }) => {
                     sip.write_i128(i);
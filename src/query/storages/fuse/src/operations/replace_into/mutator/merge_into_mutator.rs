@@ -52,6 +52,7 @@ use crate::io::write_data;
 use crate::io::BlockBuilder;
 use crate::io::BlockReader;
 use crate::io::CompactSegmentInfoReader;
+use crate::io::DeletionVector;
 use crate::io::MetaReaders;
 use crate::io::ReadSettings;
 use crate::io::WriteSettings;
@@ -411,6 +412,16 @@ impl AggregationContext {
         )
         .await?;
 
+        // rows already marked as deleted are not taken into account
+        let deletion_vector = match &block_meta.deletion_vector_location {
+            Some((location, _)) => Some(DeletionVector::read(&self.data_accessor, location).await?),
+            None => None,
+        };
+        let key_columns_data = match &deletion_vector {
+            Some(deletion_vector) => deletion_vector.apply(key_columns_data)?,
+            None => key_columns_data,
+        };
+
         let num_rows = key_columns_data.num_rows();
 
         let on_conflict_fields = &self.on_conflict_fields;
@@ -464,12 +475,11 @@ impl AggregationContext {
             .incr(&progress_values);
 
         // shortcut: whole block deletion
-        if delete_nums == block_meta.row_count as usize {
+        if delete_nums == num_rows {
             info!("whole block deletion");
             metrics_inc_replace_whole_block_deletion(1);
             metrics_inc_replace_deleted_blocks_rows(num_rows as u64);
             // whole block deletion
-            // NOTE that if deletion marker is enabled, check the real meaning of `row_count`
             let mutation = MutationLogEntry::DeletedBlock {
                 index: BlockMetaIndex {
                     segment_idx: segment_index,
@@ -492,6 +502,10 @@ impl AggregationContext {
                 // read the remaining columns
                 let remain_columns_data =
                     self.read_block(remain_columns_reader, block_meta).await?;
+                let remain_columns_data = match &deletion_vector {
+                    Some(deletion_vector) => deletion_vector.apply(remain_columns_data)?,
+                    None => remain_columns_data,
+                };
 
                 // remove the deleted rows
                 let remain_columns_data_after_deletion =
@@ -544,6 +558,9 @@ impl AggregationContext {
         if let Some(index_state) = serialized.bloom_index_state {
             write_data(index_state.data, &data_accessor, &index_state.location.0).await?;
         }
+        if let Some(index_state) = serialized.inverted_index_state {
+            write_data(index_state.data, &data_accessor, &index_state.location).await?;
+        }
 
         // generate log
         let mutation = MutationLogEntry::ReplacedBlock {
@@ -620,6 +637,8 @@ impl AggregationContext {
                     block_meta_ptr.location.0.as_str(),
                     block_meta_ptr.row_count as usize,
                     &block_meta_ptr.compression,
+                    &block_meta_ptr.column_compressions,
+                    &block_meta_ptr.column_encryptions,
                     &block_meta_ptr.col_metas,
                     column_chunks,
                     &storage_format,
@@ -271,6 +271,8 @@ impl ReplaceIntoMutator {
             ScalarRef::Bitmap(_) => "[BITMAP]".to_owned(),
             ScalarRef::Tuple(_) => "[TUPLE]".to_owned(),
             ScalarRef::Variant(_) => "[VARIANT]".to_owned(),
+            ScalarRef::Geometry(_) => "[GEOMETRY]".to_owned(),
+            ScalarRef::Binary(_) => "[BINARY]".to_owned(),
             // for string, return the first 5 chars
             ScalarRef::String(s) => {
                 let val = String::from_utf8_lossy(s).to_string();
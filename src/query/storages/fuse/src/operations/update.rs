@@ -110,7 +110,8 @@ impl FuseTable {
                 output,
                 self,
                 cluster_stats_gen.clone(),
-            )?;
+            )?
+            .with_concurrent_write()?;
             proc.into_processor()
         })?;
 
@@ -122,6 +122,8 @@ pub async fn read_block(
                 block_meta_ptr.location.0.as_str(),
                 block_meta_ptr.row_count as usize,
                 &block_meta_ptr.compression,
+                &block_meta_ptr.column_compressions,
+                &block_meta_ptr.column_encryptions,
                 &block_meta_ptr.col_metas,
                 column_chunks,
                 &storage_format,
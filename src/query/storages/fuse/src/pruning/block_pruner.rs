@@ -30,7 +30,6 @@ use storages_common_table_meta::meta::CompactSegmentInfo;
 
 use super::SegmentLocation;
 use crate::metrics::*;
-use crate::pruning::BloomPruner;
 use crate::pruning::PruningContext;
 
 pub struct BlockPruner {
@@ -48,9 +47,10 @@ impl BlockPruner {
         segment_location: SegmentLocation,
         segment_info: &CompactSegmentInfo,
     ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
-        if let Some(bloom_pruner) = &self.pruning_ctx.bloom_pruner {
-            self.block_pruning(bloom_pruner, segment_location, segment_info)
-                .await
+        if self.pruning_ctx.bloom_pruner.is_some()
+            || self.pruning_ctx.inverted_index_pruner.is_some()
+        {
+            self.block_pruning(segment_location, segment_info).await
         } else {
             // if no available filter pruners, just prune the blocks by
             // using zone map index, and do not spawn async tasks
@@ -58,11 +58,10 @@ impl BlockPruner {
         }
     }
 
-    // async pruning with bloom index.
+    // async pruning with bloom index and inverted index.
     #[async_backtrace::framed]
     async fn block_pruning(
         &self,
-        bloom_pruner: &Arc<dyn BloomPruner + Send + Sync>,
         segment_location: SegmentLocation,
         segment_info: &CompactSegmentInfo,
     ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
@@ -72,6 +71,8 @@ impl BlockPruner {
         let limit_pruner = self.pruning_ctx.limit_pruner.clone();
         let range_pruner = self.pruning_ctx.range_pruner.clone();
         let page_pruner = self.pruning_ctx.page_pruner.clone();
+        let bloom_pruner = self.pruning_ctx.bloom_pruner.clone();
+        let inverted_index_pruner = self.pruning_ctx.inverted_index_pruner.clone();
 
         let segment_block_metas = segment_info.block_metas()?;
 
@@ -111,7 +112,7 @@ impl BlockPruner {
                 }
 
                 let block_meta = block_meta.clone();
-                let row_count = block_meta.row_count;
+                let row_count = block_meta.live_row_count();
                 if range_pruner.should_keep(&block_meta.col_stats, Some(&block_meta.col_metas)) {
                     // Perf.
                     {
@@ -123,6 +124,7 @@ impl BlockPruner {
 
                     // not pruned by block zone map index,
                     let bloom_pruner = bloom_pruner.clone();
+                    let inverted_index_pruner = inverted_index_pruner.clone();
                     let limit_pruner = limit_pruner.clone();
                     let page_pruner = page_pruner.clone();
                     let index_location = block_meta.bloom_filter_index_location.clone();
@@ -140,10 +142,22 @@ impl BlockPruner {
                             }
 
                             let _permit = permit;
-                            let keep = bloom_pruner
-                                .should_keep(&index_location, index_size, column_ids)
-                                .await
-                                && limit_pruner.within_limit(row_count);
+                            let mut keep = match &bloom_pruner {
+                                Some(bloom_pruner) => {
+                                    bloom_pruner
+                                        .should_keep(&index_location, index_size, column_ids)
+                                        .await
+                                }
+                                None => true,
+                            };
+                            if let (true, Some(inverted_index_pruner)) =
+                                (keep, &inverted_index_pruner)
+                            {
+                                keep = inverted_index_pruner
+                                    .should_keep(&block_meta.location.0)
+                                    .await;
+                            }
+                            let keep = keep && limit_pruner.within_limit(row_count);
 
                             if keep {
                                 // Perf.
@@ -260,7 +274,7 @@ impl BlockPruner {
             if limit_pruner.exceeded() {
                 break;
             }
-            let row_count = block_meta.row_count;
+            let row_count = block_meta.live_row_count();
             if range_pruner.should_keep(&block_meta.col_stats, Some(&block_meta.col_metas))
                 && limit_pruner.within_limit(row_count)
             {
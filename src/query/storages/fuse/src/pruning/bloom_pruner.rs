@@ -25,6 +25,7 @@ use common_expression::Scalar;
 use common_expression::TableField;
 use common_expression::TableSchemaRef;
 use common_sql::BloomIndexColumns;
+use common_sql::BloomIndexPaths;
 use log::warn;
 use opendal::Operator;
 use storages_common_index::BloomIndex;
@@ -50,6 +51,9 @@ pub struct BloomPrunerCreator {
     /// indices that should be loaded from filter block
     index_fields: Vec<TableField>,
 
+    /// json paths or map keys whose indices should be loaded from filter block
+    index_paths: Vec<(TableField, Vec<String>)>,
+
     /// the expression that would be evaluate
     filter_expression: Expr<String>,
 
@@ -70,6 +74,7 @@ impl BloomPrunerCreator {
         dal: Operator,
         filter_expr: Option<&Expr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        bloom_index_paths: BloomIndexPaths,
     ) -> Result<Option<Arc<dyn BloomPruner + Send + Sync>>> {
         if let Some(expr) = filter_expr {
             let bloom_columns_map =
@@ -77,9 +82,14 @@ impl BloomPrunerCreator {
             let bloom_column_fields = bloom_columns_map.values().cloned().collect::<Vec<_>>();
             let point_query_cols = BloomIndex::find_eq_columns(expr, bloom_column_fields)?;
 
-            if !point_query_cols.is_empty() {
+            let bloom_path_fields = bloom_index_paths
+                .bloom_index_path_fields(schema.clone(), BloomIndex::supported_type);
+            let point_query_paths = BloomIndex::find_eq_paths(expr, &bloom_path_fields)?;
+
+            if !point_query_cols.is_empty() || !point_query_paths.is_empty() {
                 // convert to filter column names
                 let mut filter_fields = Vec::with_capacity(point_query_cols.len());
+                let mut filter_paths = Vec::with_capacity(point_query_paths.len());
                 let mut scalar_map = HashMap::<Scalar, u64>::new();
                 for (field, scalar, ty) in point_query_cols.into_iter() {
                     filter_fields.push(field);
@@ -88,10 +98,18 @@ impl BloomPrunerCreator {
                         e.insert(digest);
                     }
                 }
+                for (field, keys, scalar, ty) in point_query_paths.into_iter() {
+                    filter_paths.push((field, keys));
+                    if let Entry::Vacant(e) = scalar_map.entry(scalar.clone()) {
+                        let digest = BloomIndex::calculate_scalar_digest(&func_ctx, &scalar, &ty)?;
+                        e.insert(digest);
+                    }
+                }
 
                 let creator = BloomPrunerCreator {
                     func_ctx,
                     index_fields: filter_fields,
+                    index_paths: filter_paths,
                     filter_expression: expr.clone(),
                     scalar_map,
                     dal,
@@ -114,8 +132,8 @@ impl BloomPrunerCreator {
         let version = index_location.1;
 
         // filter out columns that no longer exist in the indexed block
-        let index_columns = self.index_fields.iter().try_fold(
-            Vec::with_capacity(self.index_fields.len()),
+        let mut index_columns = self.index_fields.iter().try_fold(
+            Vec::with_capacity(self.index_fields.len() + self.index_paths.len()),
             |mut acc, field| {
                 if column_ids_of_indexed_block.contains(&field.column_id()) {
                     acc.push(BloomIndex::build_filter_column_name(version, field)?);
@@ -123,6 +141,15 @@ impl BloomPrunerCreator {
                 Ok::<_, ErrorCode>(acc)
             },
         )?;
+        // the blocks written before the path is indexed have no such filters, they are skipped
+        // while loading the filter block.
+        for (field, keys) in self.index_paths.iter() {
+            if column_ids_of_indexed_block.contains(&field.column_id()) {
+                index_columns.push(BloomIndex::build_path_filter_column_name(
+                    version, field, keys,
+                )?);
+            }
+        }
         // load the relevant index columns
         let maybe_filter = index_location
             .read_block_filter(self.dal.clone(), &index_columns, index_length)
@@ -27,6 +27,7 @@ use common_expression::SEGMENT_NAME_COL_NAME;
 use common_functions::BUILTIN_FUNCTIONS;
 use common_sql::field_default_value;
 use common_sql::BloomIndexColumns;
+use common_sql::BloomIndexPaths;
 use log::warn;
 use opendal::Operator;
 use storages_common_index::RangeIndex;
@@ -50,6 +51,8 @@ use crate::pruning::BlockPruner;
 use crate::pruning::BloomPruner;
 use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
+use crate::pruning::InvertedIndexPruner;
+use crate::pruning::InvertedIndexPrunerCreator;
 use crate::pruning::SegmentLocation;
 
 pub struct PruningContext {
@@ -61,6 +64,7 @@ pub struct PruningContext {
     pub limit_pruner: Arc<dyn Limiter + Send + Sync>,
     pub range_pruner: Arc<dyn RangePruner + Send + Sync>,
     pub bloom_pruner: Option<Arc<dyn BloomPruner + Send + Sync>>,
+    pub inverted_index_pruner: Option<Arc<dyn InvertedIndexPruner + Send + Sync>>,
     pub page_pruner: Arc<dyn PagePruner + Send + Sync>,
     pub internal_column_pruner: Option<Arc<InternalColumnPruner>>,
 
@@ -77,6 +81,8 @@ impl PruningContext {
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        bloom_index_paths: BloomIndexPaths,
+        inverted_index_cols: BloomIndexColumns,
         max_concurrency: usize,
     ) -> Result<Arc<PruningContext>> {
         let func_ctx = ctx.get_function_context()?;
@@ -129,6 +135,16 @@ impl PruningContext {
             dal.clone(),
             filter_expr.as_ref(),
             bloom_index_cols,
+            bloom_index_paths,
+        )?;
+
+        // Inverted index pruner.
+        // None will be returned, if there are no `match` conditions on the columns with inverted index.
+        let inverted_index_pruner = InvertedIndexPrunerCreator::create(
+            &table_schema,
+            dal.clone(),
+            filter_expr.as_ref(),
+            inverted_index_cols,
         )?;
 
         // Page pruner, used in native format
@@ -164,6 +180,7 @@ impl PruningContext {
             limit_pruner,
             range_pruner,
             bloom_pruner,
+            inverted_index_pruner,
             page_pruner,
             internal_column_pruner,
             pruning_stats,
@@ -179,6 +196,8 @@ pub struct FusePruner {
     pub push_down: Option<PushDownInfo>,
     pub inverse_range_index: Option<RangeIndex>,
     pub deleted_segments: Vec<DeletedSegmentInfo>,
+    // The cluster key id and the cluster key expressions, used by the topn pruner.
+    pub cluster_key: Option<(u32, Vec<RemoteExpr<String>>)>,
 }
 
 impl FusePruner {
@@ -189,6 +208,8 @@ impl FusePruner {
         table_schema: TableSchemaRef,
         push_down: &Option<PushDownInfo>,
         bloom_index_cols: BloomIndexColumns,
+        bloom_index_paths: BloomIndexPaths,
+        inverted_index_cols: BloomIndexColumns,
     ) -> Result<Self> {
         Self::create_with_pages(
             ctx,
@@ -198,10 +219,13 @@ impl FusePruner {
             None,
             vec![],
             bloom_index_cols,
+            bloom_index_paths,
+            inverted_index_cols,
         )
     }
 
     // Create fuse pruner with pages.
+    #[allow(clippy::too_many_arguments)]
     pub fn create_with_pages(
         ctx: &Arc<dyn TableContext>,
         dal: Operator,
@@ -210,6 +234,8 @@ impl FusePruner {
         cluster_key_meta: Option<ClusterKey>,
         cluster_keys: Vec<RemoteExpr<String>>,
         bloom_index_cols: BloomIndexColumns,
+        bloom_index_paths: BloomIndexPaths,
+        inverted_index_cols: BloomIndexColumns,
     ) -> Result<Self> {
         let max_concurrency = {
             let max_io_requests = ctx.get_settings().get_max_storage_io_requests()? as usize;
@@ -232,6 +258,8 @@ impl FusePruner {
             cluster_key_meta,
             cluster_keys,
             bloom_index_cols,
+            bloom_index_paths,
+            inverted_index_cols,
             max_concurrency,
         )?;
 
@@ -242,6 +270,7 @@ impl FusePruner {
             pruning_ctx,
             inverse_range_index: None,
             deleted_segments: vec![],
+            cluster_key: None,
         })
     }
 
@@ -388,7 +417,7 @@ impl FusePruner {
             let push_down = push_down.as_ref().unwrap();
             let limit = push_down.limit.unwrap();
             let sort = push_down.order_by.clone();
-            let topn_pruner = TopNPrunner::create(schema, sort, limit);
+            let topn_pruner = TopNPrunner::create(schema, sort, limit, self.cluster_key.clone());
             return Ok(topn_pruner.prune(metas.clone()).unwrap_or(metas));
         }
         Ok(metas)
@@ -424,4 +453,8 @@ impl FusePruner {
     pub fn get_inverse_range_index(&self) -> Option<RangeIndex> {
         self.inverse_range_index.clone()
     }
+
+    pub fn set_cluster_key(&mut self, cluster_key_id: u32, cluster_keys: Vec<RemoteExpr<String>>) {
+        self.cluster_key = Some((cluster_key_id, cluster_keys))
+    }
 }
@@ -0,0 +1,109 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnId;
+use common_expression::Expr;
+use common_expression::TableSchemaRef;
+use common_sql::BloomIndexColumns;
+use log::warn;
+use opendal::Operator;
+use storages_common_index::InvertedIndex;
+
+use crate::io::TableMetaLocationGenerator;
+
+#[async_trait::async_trait]
+pub trait InvertedIndexPruner {
+    // returns true, if target should NOT be pruned (false positive allowed)
+    async fn should_keep(&self, block_location: &str) -> bool;
+}
+
+pub struct InvertedIndexPrunerCreator {
+    /// the `match` conditions that must all be satisfied, as (column id, query)
+    match_columns: Vec<(ColumnId, String)>,
+
+    /// the data accessor
+    dal: Operator,
+}
+
+impl InvertedIndexPrunerCreator {
+    pub fn create(
+        schema: &TableSchemaRef,
+        dal: Operator,
+        filter_expr: Option<&Expr<String>>,
+        inverted_index_cols: BloomIndexColumns,
+    ) -> Result<Option<Arc<dyn InvertedIndexPruner + Send + Sync>>> {
+        if let Some(expr) = filter_expr {
+            let inverted_columns_map = inverted_index_cols
+                .bloom_index_fields(schema.clone(), InvertedIndex::supported_type)?;
+            let match_columns = InvertedIndex::find_match_columns(expr)
+                .into_iter()
+                .filter_map(|(name, query)| {
+                    inverted_columns_map
+                        .values()
+                        .find(|field| field.name() == &name)
+                        .map(|field| (field.column_id(), query))
+                })
+                .collect::<Vec<_>>();
+
+            if !match_columns.is_empty() {
+                let creator = InvertedIndexPrunerCreator { match_columns, dal };
+                return Ok(Some(Arc::new(creator)));
+            }
+        }
+        Ok(None)
+    }
+
+    // Check whether the block may contain rows matching all the queries.
+    #[async_backtrace::framed]
+    pub async fn apply(&self, index_location: &str) -> Result<bool> {
+        let data = self.dal.read(index_location).await?;
+        let index = InvertedIndex::from_parquet_bytes(data)?;
+        for (column_id, query) in &self.match_columns {
+            if let Some(rows) = index.search(*column_id, query) {
+                if rows.is_empty() {
+                    return Ok(false);
+                }
+            }
+        }
+        Ok(true)
+    }
+}
+
+#[async_trait::async_trait]
+impl InvertedIndexPruner for InvertedIndexPrunerCreator {
+    #[async_backtrace::framed]
+    async fn should_keep(&self, block_location: &str) -> bool {
+        let index_location =
+            TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                block_location,
+            );
+        match self.apply(&index_location).await {
+            Ok(v) => v,
+            // blocks written before the inverted index is enabled have no index.
+            Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => true,
+            Err(e) => {
+                // swallow exceptions intentionally, corrupted index should not prevent execution
+                warn!(
+                    "failed to apply inverted index pruner, returning true. {}",
+                    e
+                );
+                true
+            }
+        }
+    }
+}
@@ -0,0 +1,504 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::io::Cursor;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::ops::Range;
+use std::sync::Arc;
+
+use common_arrow::arrow::datatypes::Field as ArrowField;
+use common_arrow::arrow::io::parquet::read::indexes::compute_page_row_intervals;
+use common_arrow::arrow::io::parquet::read::indexes::read_columns_indexes;
+use common_arrow::arrow::io::parquet::read::indexes::FieldPageStatistics;
+use common_arrow::parquet::indexes::Interval;
+use common_arrow::parquet::metadata::ColumnChunkMetaData;
+use common_arrow::parquet::metadata::FileMetaData;
+use common_arrow::parquet::metadata::ThriftFileMetaData;
+use common_arrow::parquet::read::read_pages_locations;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartInfoPtr;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::Column;
+use common_expression::ColumnId;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRef;
+use common_functions::BUILTIN_FUNCTIONS;
+use log::warn;
+use opendal::Operator;
+use parquet_format_safe::thrift::protocol::TCompactInputProtocol;
+use storages_common_pruner::RangePruner;
+use storages_common_pruner::RangePrunerCreator;
+use storages_common_table_meta::meta::ColumnMeta;
+use storages_common_table_meta::meta::ColumnStatistics;
+use storages_common_table_meta::meta::SingleColumnMeta;
+
+use crate::fuse_part::PageSelection;
+use crate::FusePartInfo;
+
+/// Prunes the pages of parquet blocks by the page indexes and the filter of the table scan.
+///
+/// The rows of the pages kept by all the filter columns are selected, and only the pages
+/// overlapping the selected rows are read, see [`PageSelection`]. A block is skipped if
+/// none of its rows is selected.
+pub struct ParquetPagePruner {
+    dal: Operator,
+    range_pruner: Arc<dyn RangePruner + Send + Sync>,
+    /// columns of the filter whose page statistics can be used.
+    filter_fields: HashMap<ColumnId, TableField>,
+    /// columns that are not nested, only their pages could be selected.
+    flat_columns: HashSet<ColumnId>,
+}
+
+impl ParquetPagePruner {
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        schema: TableSchemaRef,
+        dal: Operator,
+    ) -> Result<Option<Arc<ParquetPagePruner>>> {
+        if !ctx.get_settings().get_enable_parquet_page_index()? {
+            return Ok(None);
+        }
+        let filter = match plan
+            .push_downs
+            .as_ref()
+            .and_then(|push_downs| push_downs.filters.as_ref())
+        {
+            Some(filters) => filters.filter.as_expr(&BUILTIN_FUNCTIONS),
+            None => return Ok(None),
+        };
+
+        let filter_fields = filter
+            .column_refs()
+            .into_keys()
+            .filter_map(|name| schema.field_with_name(&name).ok().cloned())
+            .filter(|field| {
+                matches!(
+                    field.data_type().remove_nullable(),
+                    TableDataType::Number(_)
+                        | TableDataType::String
+                        | TableDataType::Date
+                        | TableDataType::Timestamp
+                )
+            })
+            .map(|field| (field.column_id(), field))
+            .collect::<HashMap<_, _>>();
+        if filter_fields.is_empty() {
+            return Ok(None);
+        }
+
+        let flat_columns = schema
+            .fields()
+            .iter()
+            .filter(|field| {
+                !matches!(
+                    field.data_type().remove_nullable(),
+                    TableDataType::Tuple { .. } | TableDataType::Array(_) | TableDataType::Map(_)
+                )
+            })
+            .map(|field| field.column_id())
+            .collect();
+
+        let func_ctx = ctx.get_function_context()?;
+        let range_pruner = RangePrunerCreator::try_create(func_ctx, &schema, Some(&filter))?;
+        Ok(Some(Arc::new(ParquetPagePruner {
+            dal,
+            range_pruner,
+            filter_fields,
+            flat_columns,
+        })))
+    }
+
+    /// Returns the part reading the selected pages only, or `None` if the block should be pruned.
+    #[async_backtrace::framed]
+    pub async fn prune_part(&self, part: PartInfoPtr) -> Option<PartInfoPtr> {
+        let (location, page_index) = match self.page_index_of(&part) {
+            Some(v) => v,
+            None => return Some(part),
+        };
+        let tail = self
+            .dal
+            .read_with(&location)
+            .range(page_index.clone())
+            .await
+            .map_err(ErrorCode::from);
+        self.prune_part_with_tail(part, page_index.start, tail)
+    }
+
+    pub fn blocking_prune_part(&self, part: PartInfoPtr) -> Option<PartInfoPtr> {
+        let (location, page_index) = match self.page_index_of(&part) {
+            Some(v) => v,
+            None => return Some(part),
+        };
+        let tail = self
+            .dal
+            .blocking()
+            .read_with(&location)
+            .range(page_index.clone())
+            .call()
+            .map_err(ErrorCode::from);
+        self.prune_part_with_tail(part, page_index.start, tail)
+    }
+
+    /// Keep the parts that should NOT be pruned, reading the selected pages only.
+    #[async_backtrace::framed]
+    pub async fn prune(&self, parts: Vec<PartInfoPtr>) -> Vec<PartInfoPtr> {
+        let mut kept = Vec::with_capacity(parts.len());
+        for part in parts {
+            if let Some(part) = self.prune_part(part).await {
+                kept.push(part);
+            }
+        }
+        kept
+    }
+
+    /// The location and the byte range of the page indexes of the block, if the pages of the block
+    /// could be pruned.
+    fn page_index_of(&self, part: &PartInfoPtr) -> Option<(String, Range<u64>)> {
+        let part = FusePartInfo::from_part(part).ok()?;
+        if part.page_selection.is_some()
+            || !part
+                .columns_meta
+                .keys()
+                .any(|column_id| self.filter_fields.contains_key(column_id))
+        {
+            return None;
+        }
+        part.page_index
+            .clone()
+            .map(|page_index| (part.location.clone(), page_index))
+    }
+
+    fn prune_part_with_tail(
+        &self,
+        part: PartInfoPtr,
+        offset: u64,
+        tail: Result<Vec<u8>>,
+    ) -> Option<PartInfoPtr> {
+        let fuse_part = match FusePartInfo::from_part(&part) {
+            Ok(fuse_part) => fuse_part,
+            Err(_) => return Some(part),
+        };
+        let result = tail.and_then(|tail| self.select_pages(fuse_part, offset, tail));
+        match result {
+            Ok(Some(pages)) if pages.selection.rows == (0..fuse_part.nums_rows) => Some(part),
+            Ok(Some(pages)) => {
+                let columns_meta = fuse_part
+                    .columns_meta
+                    .iter()
+                    .map(|(column_id, meta)| {
+                        let meta = pages
+                            .column_metas
+                            .get(column_id)
+                            .cloned()
+                            .unwrap_or_else(|| meta.clone());
+                        (*column_id, meta)
+                    })
+                    .collect();
+                Some(fuse_part.with_page_selection(columns_meta, pages.selection))
+            }
+            Ok(None) => None,
+            Err(e) => {
+                // swallow exceptions intentionally, the page indexes are only used for pruning
+                warn!(
+                    "failed to prune pages of block {}, ignored. {}",
+                    fuse_part.location, e
+                );
+                Some(part)
+            }
+        }
+    }
+
+    /// Select the pages of the block by the page indexes, `tail` is the bytes of the block file
+    /// starting from `offset`, which contains the page indexes and the footer.
+    ///
+    /// Returns `None` if no rows are selected.
+    fn select_pages(
+        &self,
+        part: &FusePartInfo,
+        offset: u64,
+        tail: Vec<u8>,
+    ) -> Result<Option<SelectedPages>> {
+        let file_meta = decode_footer(&tail)?;
+        if file_meta.row_groups.len() != 1 {
+            return Err(ErrorCode::ParquetFileInvalid(format!(
+                "invalid parquet file, expects only one row group, but got {}",
+                file_meta.row_groups.len()
+            )));
+        }
+        let chunks = file_meta.row_groups[0].columns();
+        let num_rows = part.nums_rows;
+
+        let mut reader = TailReader {
+            offset,
+            cursor: Cursor::new(tail),
+        };
+        let locations = read_pages_locations(&mut reader, chunks)?;
+
+        // the columns of the part, with the index of its column chunk in the block file.
+        let mut columns = Vec::with_capacity(part.columns_meta.len());
+        for (column_id, meta) in part.columns_meta.iter() {
+            let meta = match meta.as_parquet() {
+                Some(meta) => meta,
+                None => continue,
+            };
+            if let Some(idx) = chunks
+                .iter()
+                .position(|chunk| chunk.byte_range().0 == meta.offset)
+            {
+                columns.push((*column_id, idx));
+            }
+        }
+
+        // rows of the pages kept by each filter column.
+        let mut row_selections = vec![];
+        for (column_id, idx) in columns.iter() {
+            let field = match self.filter_fields.get(column_id) {
+                Some(field) => field,
+                None => continue,
+            };
+            let chunk = &chunks[*idx];
+            let intervals = compute_page_row_intervals(&locations[*idx], num_rows)?;
+            let stats = self.page_statistics(&mut reader, chunk, field)?;
+            if stats.len() != intervals.len() {
+                return Err(ErrorCode::ParquetFileInvalid(format!(
+                    "invalid page index of column {}, {} pages but {} statistics",
+                    field.name(),
+                    intervals.len(),
+                    stats.len()
+                )));
+            }
+            let row_selection = intervals
+                .into_iter()
+                .zip(stats)
+                .filter(|(_, stat)| {
+                    self.range_pruner
+                        .should_keep(&HashMap::from([(*column_id, stat.clone())]), None)
+                })
+                .map(|(interval, _)| interval)
+                .collect::<Vec<_>>();
+            row_selections.push(row_selection);
+        }
+        if row_selections.is_empty() {
+            return Ok(Some(SelectedPages::all(num_rows)));
+        }
+
+        let selected = combine_intervals(row_selections);
+        let rows = match (selected.first(), selected.last()) {
+            (Some(first), Some(last)) => first.start..last.start + last.length,
+            _ => return Ok(None),
+        };
+
+        // read the pages overlapping the selected rows of each column.
+        let mut column_rows = HashMap::with_capacity(part.columns_meta.len());
+        let mut column_metas = HashMap::with_capacity(columns.len());
+        for column_id in part.columns_meta.keys() {
+            column_rows.insert(*column_id, 0..num_rows);
+            let idx = match columns.iter().find(|(id, _)| id == column_id) {
+                Some((_, idx)) if self.flat_columns.contains(column_id) => *idx,
+                _ => continue,
+            };
+            let chunk = &chunks[idx];
+            if chunk.dictionary_page_offset().is_some() {
+                continue;
+            }
+            let pages = &locations[idx];
+            let intervals = compute_page_row_intervals(pages, num_rows)?;
+            let selected_pages = intervals
+                .iter()
+                .enumerate()
+                .filter(|(_, interval)| {
+                    interval.start < rows.end && interval.start + interval.length > rows.start
+                })
+                .map(|(page, _)| page)
+                .collect::<Vec<_>>();
+            let (first, last) = match (selected_pages.first(), selected_pages.last()) {
+                (Some(first), Some(last)) => (*first, *last),
+                _ => continue,
+            };
+            let start = pages[first].offset as u64;
+            let end = (pages[last].offset + pages[last].compressed_page_size as i64) as u64;
+            let page_rows = intervals[first].start..intervals[last].start + intervals[last].length;
+            column_metas.insert(
+                *column_id,
+                ColumnMeta::Parquet(SingleColumnMeta {
+                    offset: start,
+                    len: end - start,
+                    num_values: page_rows.len() as u64,
+                }),
+            );
+            column_rows.insert(*column_id, page_rows);
+        }
+
+        Ok(Some(SelectedPages {
+            selection: PageSelection { rows, column_rows },
+            column_metas,
+        }))
+    }
+
+    /// Statistics of each page of the column chunk, read from the column index.
+    fn page_statistics(
+        &self,
+        reader: &mut TailReader,
+        chunk: &ColumnChunkMetaData,
+        field: &TableField,
+    ) -> Result<Vec<ColumnStatistics>> {
+        // the column chunk is found by the name of the field in the block file,
+        // which could be different from the current one if the column is renamed.
+        let mut arrow_field = ArrowField::from(field);
+        arrow_field.name = chunk.descriptor().path_in_schema[0].clone();
+        let stats = read_columns_indexes(reader, std::slice::from_ref(chunk), &[arrow_field])?;
+        match stats.into_iter().next() {
+            Some(FieldPageStatistics::Single(stats)) => {
+                let data_type = DataType::from(field.data_type());
+                let min_values = Column::from_arrow(&*stats.min, &data_type);
+                let max_values = Column::from_arrow(&*stats.max, &data_type);
+                let null_count = stats.null_count.values();
+                let mut page_stats = Vec::with_capacity(min_values.len());
+                for (i, null_count) in null_count.iter().enumerate().take(min_values.len()) {
+                    match (min_values.index(i), max_values.index(i)) {
+                        (Some(min), Some(max)) => page_stats.push(ColumnStatistics::new(
+                            min.to_owned(),
+                            max.to_owned(),
+                            *null_count,
+                            0,
+                            None,
+                        )),
+                        _ => {
+                            return Err(ErrorCode::ParquetFileInvalid(format!(
+                                "invalid page index of column {}",
+                                field.name()
+                            )));
+                        }
+                    }
+                }
+                Ok(page_stats)
+            }
+            _ => Err(ErrorCode::Internal(
+                "Only non-nested types are supported in page filter.",
+            )),
+        }
+    }
+}
+
+/// The pages selected of a block, with the byte ranges of the pages of each column.
+struct SelectedPages {
+    selection: PageSelection,
+    column_metas: HashMap<ColumnId, ColumnMeta>,
+}
+
+impl SelectedPages {
+    fn all(num_rows: usize) -> Self {
+        SelectedPages {
+            selection: PageSelection {
+                rows: 0..num_rows,
+                column_rows: HashMap::new(),
+            },
+            column_metas: HashMap::new(),
+        }
+    }
+}
+
+/// Decode the footer at the end of the block file.
+fn decode_footer(tail: &[u8]) -> Result<FileMetaData> {
+    const FOOTER_SIZE: usize = 8;
+    const PARQUET_MAGIC: [u8; 4] = [b'P', b'A', b'R', b'1'];
+
+    let len = tail.len();
+    if len < FOOTER_SIZE || tail[len - 4..] != PARQUET_MAGIC {
+        return Err(ErrorCode::ParquetFileInvalid(
+            "Invalid Parquet file. Corrupt footer",
+        ));
+    }
+    let metadata_len = i32::from_le_bytes(tail[len - 8..len - 4].try_into().unwrap());
+    let metadata_len = usize::try_from(metadata_len)
+        .ok()
+        .filter(|metadata_len| metadata_len + FOOTER_SIZE <= len)
+        .ok_or_else(|| {
+            ErrorCode::ParquetFileInvalid(format!(
+                "Invalid Parquet file. The footer size {} is larger than the page indexes",
+                metadata_len
+            ))
+        })?;
+    let metadata = &tail[len - FOOTER_SIZE - metadata_len..len - FOOTER_SIZE];
+
+    // a highly nested but sparse struct could result in many allocations
+    let max_size = metadata.len() * 2 + 1024;
+    let mut prot = TCompactInputProtocol::new(metadata, max_size);
+    let meta = ThriftFileMetaData::read_from_in_protocol(&mut prot)
+        .map_err(|e| ErrorCode::ParquetFileInvalid(e.to_string()))?;
+    Ok(FileMetaData::try_from_thrift(meta)?)
+}
+
+/// Reads the tail of the block file in memory by the positions in the whole file.
+struct TailReader {
+    offset: u64,
+    cursor: Cursor<Vec<u8>>,
+}
+
+impl Read for TailReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.cursor.read(buf)
+    }
+}
+
+impl Seek for TailReader {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) => {
+                SeekFrom::Start(pos.checked_sub(self.offset).ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidInput,
+                        format!("seek to {} before the page indexes", pos),
+                    )
+                })?)
+            }
+            other => other,
+        };
+        Ok(self.cursor.seek(pos)? + self.offset)
+    }
+}
+
+/// Combine row selection of each column into a final selection of the whole block.
+fn combine_intervals(row_selections: Vec<Vec<Interval>>) -> Vec<Interval> {
+    let mut selections = row_selections.into_iter();
+    let mut selection = selections.next().unwrap_or_default();
+    for sel in selections {
+        selection = selection
+            .iter()
+            .flat_map(|probe| intersect(*probe, &sel))
+            .collect();
+    }
+    selection
+}
+
+/// The overlapping parts of `probe` in `intervals`.
+fn intersect(probe: Interval, intervals: &[Interval]) -> Vec<Interval> {
+    intervals
+        .iter()
+        .filter_map(|interval| {
+            let start = interval.start.max(probe.start);
+            let end = (interval.start + interval.length).min(probe.start + probe.length);
+            (start < end).then(|| Interval::new(start, end - start))
+        })
+        .collect()
+}
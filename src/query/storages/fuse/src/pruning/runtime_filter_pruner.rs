@@ -0,0 +1,197 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartInfoPtr;
+use common_catalog::runtime_filter_info::RuntimeFilterInfo;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::TableSchemaRef;
+use common_sql::BloomIndexColumns;
+use common_sql::BloomIndexPaths;
+use log::warn;
+use opendal::Operator;
+use parking_lot::RwLock;
+use storages_common_pruner::RangePruner;
+use storages_common_pruner::RangePrunerCreator;
+
+use crate::pruning::BloomPruner;
+use crate::pruning::BloomPrunerCreator;
+use crate::FusePartInfo;
+
+/// Prunes the blocks of a table scan by the runtime filters pushed down from the build side
+/// of hash joins, see [`RuntimeFilterInfo`].
+///
+/// The filters are only ready after the hash join build finished, the blocks read before that
+/// are not pruned.
+pub struct RuntimeFilterPruner {
+    ctx: Arc<dyn TableContext>,
+    scan_id: usize,
+    schema: TableSchemaRef,
+    dal: Operator,
+    bloom_index_cols: BloomIndexColumns,
+
+    pruners: RwLock<Option<Arc<RuntimeFilterPruners>>>,
+}
+
+struct RuntimeFilterPruners {
+    range_pruners: Vec<Arc<dyn RangePruner + Send + Sync>>,
+    bloom_pruners: Vec<Arc<dyn BloomPruner + Send + Sync>>,
+}
+
+impl RuntimeFilterPruners {
+    fn should_keep_by_stats(&self, part: &FusePartInfo) -> bool {
+        match &part.columns_stat {
+            Some(stats) => self
+                .range_pruners
+                .iter()
+                .all(|pruner| pruner.should_keep(stats, Some(&part.columns_meta))),
+            None => true,
+        }
+    }
+}
+
+impl RuntimeFilterPruner {
+    pub fn try_create(
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        schema: TableSchemaRef,
+        dal: Operator,
+        bloom_index_cols: BloomIndexColumns,
+    ) -> Result<Option<Arc<RuntimeFilterPruner>>> {
+        if !ctx.get_settings().get_enable_runtime_filter_pruning()? {
+            return Ok(None);
+        }
+        let scan_id = match plan
+            .push_downs
+            .as_ref()
+            .and_then(|push_downs| push_downs.runtime_filter_scan_id)
+        {
+            Some(scan_id) => scan_id,
+            None => return Ok(None),
+        };
+        Ok(Some(Arc::new(RuntimeFilterPruner {
+            ctx,
+            scan_id,
+            schema,
+            dal,
+            bloom_index_cols,
+            pruners: RwLock::new(None),
+        })))
+    }
+
+    /// Returns true, if the block should NOT be pruned by the column statistics.
+    pub fn should_keep_by_stats(&self, part: &PartInfoPtr) -> bool {
+        match self.pruners_of_part(part) {
+            Some((pruners, part)) => pruners.should_keep_by_stats(part),
+            None => true,
+        }
+    }
+
+    /// Returns true, if the block should NOT be pruned by the column statistics and bloom index.
+    #[async_backtrace::framed]
+    pub async fn should_keep(&self, part: &PartInfoPtr) -> bool {
+        let (pruners, part) = match self.pruners_of_part(part) {
+            Some(v) => v,
+            None => return true,
+        };
+        if !pruners.should_keep_by_stats(part) {
+            return false;
+        }
+        if let Some((location, size)) = &part.bloom_index {
+            let index_location = Some(location.clone());
+            let column_ids = part.columns_meta.keys().cloned().collect::<Vec<_>>();
+            for pruner in pruners.bloom_pruners.iter() {
+                if !pruner
+                    .should_keep(&index_location, *size, column_ids.clone())
+                    .await
+                {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Keep the blocks that should NOT be pruned.
+    #[async_backtrace::framed]
+    pub async fn prune(&self, parts: Vec<PartInfoPtr>) -> Vec<PartInfoPtr> {
+        let mut kept = Vec::with_capacity(parts.len());
+        for part in parts {
+            if self.should_keep(&part).await {
+                kept.push(part);
+            }
+        }
+        kept
+    }
+
+    fn pruners_of_part<'a>(
+        &self,
+        part: &'a PartInfoPtr,
+    ) -> Option<(Arc<RuntimeFilterPruners>, &'a FusePartInfo)> {
+        let part = FusePartInfo::from_part(part).ok()?;
+        match self.pruners() {
+            Ok(pruners) => pruners.map(|pruners| (pruners, part)),
+            Err(e) => {
+                // swallow exceptions intentionally, the runtime filters are only used for pruning
+                warn!("failed to create runtime filter pruner, ignored. {}", e);
+                None
+            }
+        }
+    }
+
+    fn pruners(&self) -> Result<Option<Arc<RuntimeFilterPruners>>> {
+        if let Some(pruners) = self.pruners.read().as_ref() {
+            return Ok(Some(pruners.clone()));
+        }
+        let runtime_filter = match self.ctx.get_runtime_filter(self.scan_id) {
+            Some(runtime_filter) => runtime_filter,
+            None => return Ok(None),
+        };
+        let pruners = Arc::new(self.create_pruners(runtime_filter)?);
+        *self.pruners.write() = Some(pruners.clone());
+        Ok(Some(pruners))
+    }
+
+    fn create_pruners(&self, runtime_filter: RuntimeFilterInfo) -> Result<RuntimeFilterPruners> {
+        let func_ctx = self.ctx.get_function_context()?;
+        let mut range_pruners = Vec::with_capacity(runtime_filter.min_max.len());
+        for expr in runtime_filter.min_max.iter() {
+            range_pruners.push(RangePrunerCreator::try_create(
+                func_ctx.clone(),
+                &self.schema,
+                Some(expr),
+            )?);
+        }
+        let mut bloom_pruners = Vec::with_capacity(runtime_filter.inlist.len());
+        for expr in runtime_filter.inlist.iter() {
+            if let Some(pruner) = BloomPrunerCreator::create(
+                func_ctx.clone(),
+                &self.schema,
+                self.dal.clone(),
+                Some(expr),
+                self.bloom_index_cols.clone(),
+                BloomIndexPaths::default(),
+            )? {
+                bloom_pruners.push(pruner);
+            }
+        }
+        Ok(RuntimeFilterPruners {
+            range_pruners,
+            bloom_pruners,
+        })
+    }
+}
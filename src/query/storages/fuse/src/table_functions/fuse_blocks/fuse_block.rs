@@ -33,6 +33,8 @@ use common_expression::TableSchema;
 use common_expression::TableSchemaRefExt;
 use common_expression::Value;
 use futures_util::TryStreamExt;
+use itertools::Itertools;
+use storages_common_table_meta::meta::ClusterStatistics;
 use storages_common_table_meta::meta::SegmentInfo;
 use storages_common_table_meta::meta::TableSnapshot;
 
@@ -112,6 +114,7 @@ impl<'a> FuseBlock<'a> {
         let mut row_count = Vec::with_capacity(len);
         let mut bloom_filter_location = vec![];
         let mut bloom_filter_size = Vec::with_capacity(len);
+        let mut cluster_stats = Vec::with_capacity(len);
 
         let segments_io = SegmentsIO::create(
             self.ctx.clone(),
@@ -144,6 +147,12 @@ impl<'a> FuseBlock<'a> {
                             .map(|s| s.0.as_bytes().to_vec()),
                     );
                     bloom_filter_size.push(block.bloom_filter_index_size);
+                    cluster_stats.push(
+                        block
+                            .cluster_stats
+                            .as_ref()
+                            .map(|stats| Self::format_cluster_stats(stats).into_bytes()),
+                    );
 
                     row_num += 1;
                     if row_num >= limit {
@@ -192,11 +201,25 @@ impl<'a> FuseBlock<'a> {
                     DataType::Number(NumberDataType::UInt64),
                     Value::Column(UInt64Type::from_data(bloom_filter_size)),
                 ),
+                BlockEntry::new(
+                    DataType::String.wrap_nullable(),
+                    Value::Column(StringType::from_opt_data(cluster_stats)),
+                ),
             ],
             row_num,
         ))
     }
 
+    fn format_cluster_stats(stats: &ClusterStatistics) -> String {
+        format!(
+            "{{cluster_key_id: {}, min: [{}], max: [{}], level: {}}}",
+            stats.cluster_key_id,
+            stats.min.iter().join(", "),
+            stats.max.iter().join(", "),
+            stats.level
+        )
+    }
+
     pub fn schema() -> Arc<TableSchema> {
         TableSchemaRefExt::create(vec![
             TableField::new("snapshot_id", TableDataType::String),
@@ -213,6 +236,7 @@ impl<'a> FuseBlock<'a> {
                 "bloom_filter_size",
                 TableDataType::Number(NumberDataType::UInt64),
             ),
+            TableField::new("cluster_stats", TableDataType::String.wrap_nullable()),
         ])
     }
 }
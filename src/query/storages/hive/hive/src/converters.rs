@@ -74,18 +74,25 @@ pub fn try_into_table_info(
     };
     let schema = Arc::new(try_into_schema(fields)?);
 
-    let location = if let Some(storage) = &hms_table.sd {
-        storage
-            .location
-            .as_ref()
-            .map(|location| location.to_string())
+    let (location, input_format) = if let Some(storage) = &hms_table.sd {
+        (
+            storage
+                .location
+                .as_ref()
+                .map(|location| location.to_string()),
+            storage
+                .input_format
+                .as_ref()
+                .map(|input_format| input_format.to_string()),
+        )
     } else {
-        None
+        (None, None)
     };
 
     let table_options = HiveTableOptions {
         partition_keys,
         location,
+        input_format,
     };
 
     let meta = TableMeta {
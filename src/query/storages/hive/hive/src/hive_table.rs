@@ -518,6 +518,17 @@ impl HiveTable {
         ctx: Arc<dyn TableContext>,
         push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
+        if !self.table_options.is_parquet() {
+            return Err(ErrorCode::Unimplemented(format!(
+                "hive table {} is stored by {}, only parquet is supported",
+                self.table_info.desc,
+                self.table_options
+                    .input_format
+                    .as_deref()
+                    .unwrap_or_default()
+            )));
+        }
+
         let start = Instant::now();
         let dirs = self.get_query_locations(ctx.clone(), &push_downs).await?;
         trace!("{} query locations: {:?}", dirs.len(), dirs);
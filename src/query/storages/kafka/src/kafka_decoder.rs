@@ -0,0 +1,201 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use apache_avro::from_avro_datum;
+use apache_avro::Schema as AvroSchema;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::ColumnBuilder;
+use common_expression::Scalar;
+use common_expression::TableSchemaRef;
+use common_formats::FieldJsonAstDecoder;
+use common_formats::FileFormatOptionsExt;
+use parking_lot::RwLock;
+
+use crate::KafkaMessageFormat;
+
+/// Decode the payloads of the messages into the rows of the table.
+///
+/// Avro datums are converted into JSON values first, so both of the formats share the
+/// decoding of the values with the NDJSON input format.
+pub struct KafkaMessageDecoder {
+    schema: TableSchemaRef,
+    default_values: Option<Vec<Scalar>>,
+    field_decoder: FieldJsonAstDecoder,
+    avro_schema: Option<AvroSchema>,
+    schema_registry: Option<SchemaRegistry>,
+}
+
+/// The schemas fetched from the schema registry, by schema id.
+struct SchemaRegistry {
+    url: String,
+    schemas: RwLock<HashMap<u32, Arc<AvroSchema>>>,
+}
+
+impl SchemaRegistry {
+    fn get(&self, id: u32) -> Option<Arc<AvroSchema>> {
+        self.schemas.read().get(&id).cloned()
+    }
+
+    async fn fetch(&self, id: u32) -> Result<()> {
+        if self.schemas.read().contains_key(&id) {
+            return Ok(());
+        }
+        let url = format!("{}/schemas/ids/{}", self.url, id);
+        let registry_error = |e: String| {
+            ErrorCode::BadArguments(format!("fail to fetch schema from {}: {}", url, e))
+        };
+        let response = reqwest::get(&url)
+            .await
+            .map_err(|e| registry_error(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(registry_error(response.status().to_string()));
+        }
+        let body = response
+            .text()
+            .await
+            .map_err(|e| registry_error(e.to_string()))?;
+        let body: serde_json::Value =
+            serde_json::from_str(&body).map_err(|e| registry_error(e.to_string()))?;
+        let schema = body["schema"]
+            .as_str()
+            .ok_or_else(|| registry_error("schema not found in the response".to_string()))?;
+        let schema = AvroSchema::parse_str(schema)
+            .map_err(|e| ErrorCode::BadArguments(format!("invalid avro schema: {}", e)))?;
+        self.schemas.write().insert(id, Arc::new(schema));
+        Ok(())
+    }
+}
+
+// The id of the schema of the message, and the avro datum.
+fn split_schema_id(payload: &[u8]) -> Result<(u32, &[u8])> {
+    match payload {
+        [0, a, b, c, d, datum @ ..] => Ok((u32::from_be_bytes([*a, *b, *c, *d]), datum)),
+        _ => Err(ErrorCode::BadBytes(
+            "invalid avro message: schema id not found",
+        )),
+    }
+}
+
+impl KafkaMessageDecoder {
+    pub fn try_create(
+        format: &KafkaMessageFormat,
+        schema: TableSchemaRef,
+        default_values: Option<Vec<Scalar>>,
+        options: &FileFormatOptionsExt,
+    ) -> Result<Self> {
+        let avro_schema = match format {
+            KafkaMessageFormat::Avro { schema } => Some(
+                AvroSchema::parse_str(schema)
+                    .map_err(|e| ErrorCode::BadArguments(format!("invalid avro schema: {}", e)))?,
+            ),
+            _ => None,
+        };
+        let schema_registry = match format {
+            KafkaMessageFormat::AvroSchemaRegistry { url } => Some(SchemaRegistry {
+                url: url.clone(),
+                schemas: RwLock::new(HashMap::new()),
+            }),
+            _ => None,
+        };
+        Ok(Self {
+            schema,
+            default_values,
+            field_decoder: FieldJsonAstDecoder::create(options),
+            avro_schema,
+            schema_registry,
+        })
+    }
+
+    /// Make sure the schema of the payload is fetched from the schema registry before decoding it.
+    pub async fn prepare(&self, payload: &[u8]) -> Result<()> {
+        if let Some(registry) = &self.schema_registry {
+            let (id, _) = split_schema_id(payload)?;
+            registry.fetch(id).await?;
+        }
+        Ok(())
+    }
+
+    pub fn create_column_builders(&self, capacity: usize) -> Vec<ColumnBuilder> {
+        self.schema
+            .fields()
+            .iter()
+            .map(|f| ColumnBuilder::with_capacity(&f.data_type().into(), capacity))
+            .collect()
+    }
+
+    /// Decode the payload as one row and append it to the columns.
+    pub fn decode(&self, payload: &[u8], columns: &mut [ColumnBuilder]) -> Result<()> {
+        let mut json = match (&self.avro_schema, &self.schema_registry) {
+            (Some(schema), _) => Self::decode_avro(schema, payload)?,
+            (None, Some(registry)) => {
+                let (id, datum) = split_schema_id(payload)?;
+                let schema = registry.get(id).ok_or_else(|| {
+                    ErrorCode::Internal(format!("avro schema {} is not fetched", id))
+                })?;
+                Self::decode_avro(&schema, datum)?
+            }
+            (None, None) => serde_json::from_slice::<serde_json::Value>(payload)
+                .map_err(|e| ErrorCode::BadBytes(format!("invalid json message: {}", e)))?,
+        };
+
+        // if it's not case_sensitive, we convert to lowercase
+        if !self.field_decoder.ident_case_sensitive {
+            if let serde_json::Value::Object(x) = json {
+                let y = x.into_iter().map(|(k, v)| (k.to_lowercase(), v)).collect();
+                json = serde_json::Value::Object(y);
+            }
+        }
+
+        for ((column_index, field), column) in self
+            .schema
+            .fields()
+            .iter()
+            .enumerate()
+            .zip(columns.iter_mut())
+        {
+            let field_name = if self.field_decoder.ident_case_sensitive {
+                field.name().to_owned()
+            } else {
+                field.name().to_lowercase()
+            };
+            let value = &json[field_name];
+            if value == &serde_json::Value::Null {
+                match &self.default_values {
+                    None => column.push_default(),
+                    Some(values) => column.push(values[column_index].as_ref()),
+                }
+            } else {
+                self.field_decoder.read_field(column, value).map_err(|e| {
+                    ErrorCode::BadBytes(format!(
+                        "fail to decode column {} ({}): {}",
+                        field.name(),
+                        field.data_type(),
+                        e
+                    ))
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    fn decode_avro(schema: &AvroSchema, datum: &[u8]) -> Result<serde_json::Value> {
+        let value = from_avro_datum(schema, &mut &datum[..], None)
+            .map_err(|e| ErrorCode::BadBytes(format!("invalid avro message: {}", e)))?;
+        serde_json::Value::try_from(value)
+            .map_err(|e| ErrorCode::BadBytes(format!("invalid avro message: {}", e)))
+    }
+}
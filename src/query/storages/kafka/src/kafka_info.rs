@@ -0,0 +1,86 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+
+use rdkafka::ClientConfig;
+
+/// Encoding of the payloads of the messages in a topic.
+#[derive(Clone, PartialEq, Eq)]
+pub enum KafkaMessageFormat {
+    /// Each message is a JSON object, the keys are the column names.
+    Json,
+    /// Each message is an Avro datum (without the container header) of the record schema.
+    Avro { schema: String },
+    /// Each message is an Avro datum prefixed with the id of its schema in the schema registry,
+    /// i.e. a zero byte followed by the 4 bytes big-endian schema id.
+    AvroSchemaRegistry { url: String },
+}
+
+/// Where and how to consume a kafka topic, built from the location of
+/// `COPY INTO <table> FROM 'kafka://<broker>/<topic>' CONNECTION = (...)`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct KafkaInfo {
+    pub brokers: String,
+    pub topic: String,
+    /// The consumer group whose committed offsets track the progress of the ingestion.
+    pub group_id: String,
+    pub format: KafkaMessageFormat,
+    /// Extra librdkafka properties, e.g. `security.protocol`.
+    pub properties: BTreeMap<String, String>,
+}
+
+impl KafkaInfo {
+    pub fn client_config(&self) -> ClientConfig {
+        let mut config = ClientConfig::new();
+        config
+            .set("bootstrap.servers", &self.brokers)
+            .set("group.id", &self.group_id)
+            // Offsets are only committed after the data is committed into the table.
+            .set("enable.auto.commit", "false")
+            .set("enable.auto.offset.store", "false")
+            .set("isolation.level", "read_committed");
+        for (key, value) in &self.properties {
+            config.set(key, value);
+        }
+        config
+    }
+
+    /// The key of the messages of `partition` starting from `offset` in the copied files of the table.
+    ///
+    /// The key is committed together with the table snapshot, it is used to detect the
+    /// messages already loaded but whose offsets have not been committed to kafka.
+    pub fn copied_key(&self, partition: i32, offset: i64) -> String {
+        format!("kafka/{}/{}/{}", self.topic, partition, offset)
+    }
+}
+
+impl Debug for KafkaInfo {
+    // The properties may contain credentials, do not print them.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let format = match &self.format {
+            KafkaMessageFormat::Json => "json",
+            KafkaMessageFormat::Avro { .. } | KafkaMessageFormat::AvroSchemaRegistry { .. } => {
+                "avro"
+            }
+        };
+        f.debug_struct("KafkaInfo")
+            .field("brokers", &self.brokers)
+            .field("topic", &self.topic)
+            .field("group_id", &self.group_id)
+            .field("format", &format)
+            .finish()
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::time::Duration;
+
+use common_base::runtime::GlobalIORuntime;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use log::info;
+use rdkafka::consumer::BaseConsumer;
+use rdkafka::consumer::CommitMode;
+use rdkafka::consumer::Consumer;
+use rdkafka::Offset;
+use rdkafka::TopicPartitionList;
+
+use crate::KafkaInfo;
+
+const KAFKA_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Messages of a partition to consume, in the offsets range of `[start, end)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KafkaPartitionRange {
+    pub partition: i32,
+    pub start: i64,
+    pub end: i64,
+}
+
+impl KafkaPartitionRange {
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+}
+
+/// Fetch the messages to consume of each partition of the topic.
+///
+/// A range starts from the offset committed by the consumer group, or from the low
+/// watermark if the group has not committed one, and ends at the current high watermark,
+/// so the messages produced while copying are left to the next copy.
+#[async_backtrace::framed]
+pub async fn fetch_partition_ranges(info: &KafkaInfo) -> Result<Vec<KafkaPartitionRange>> {
+    let info = info.clone();
+    GlobalIORuntime::instance()
+        .spawn_blocking(move || {
+            let consumer = create_consumer(&info)?;
+            let metadata = consumer
+                .fetch_metadata(Some(&info.topic), KAFKA_REQUEST_TIMEOUT)
+                .map_err(|e| kafka_error(&info, e))?;
+            let topic = metadata
+                .topics()
+                .iter()
+                .find(|t| t.name() == info.topic)
+                .filter(|t| t.error().is_none() && !t.partitions().is_empty())
+                .ok_or_else(|| {
+                    ErrorCode::StorageOther(format!("kafka topic {} not found", info.topic))
+                })?;
+
+            let mut partitions = TopicPartitionList::new();
+            for partition in topic.partitions() {
+                partitions.add_partition(&info.topic, partition.id());
+            }
+            let committed = consumer
+                .committed_offsets(partitions, KAFKA_REQUEST_TIMEOUT)
+                .map_err(|e| kafka_error(&info, e))?;
+
+            let mut ranges = Vec::with_capacity(topic.partitions().len());
+            for elem in committed.elements() {
+                let (low, high) = consumer
+                    .fetch_watermarks(&info.topic, elem.partition(), KAFKA_REQUEST_TIMEOUT)
+                    .map_err(|e| kafka_error(&info, e))?;
+                let start = match elem.offset() {
+                    // The committed offset may have been removed by the retention.
+                    Offset::Offset(offset) => offset.max(low),
+                    _ => low,
+                };
+                ranges.push(KafkaPartitionRange {
+                    partition: elem.partition(),
+                    start,
+                    end: high,
+                });
+            }
+            ranges.sort_by_key(|r| r.partition);
+            Ok(ranges)
+        })
+        .await
+}
+
+/// Commit the end offsets of the consumed ranges for the consumer group.
+#[async_backtrace::framed]
+pub async fn commit_offsets(info: &KafkaInfo, ranges: &[KafkaPartitionRange]) -> Result<()> {
+    let mut offsets = TopicPartitionList::new();
+    for range in ranges.iter().filter(|r| !r.is_empty()) {
+        offsets
+            .add_partition_offset(&info.topic, range.partition, Offset::Offset(range.end))
+            .map_err(|e| kafka_error(info, e))?;
+    }
+    if offsets.count() == 0 {
+        return Ok(());
+    }
+
+    let info = info.clone();
+    GlobalIORuntime::instance()
+        .spawn_blocking(move || {
+            let consumer = create_consumer(&info)?;
+            consumer
+                .commit(&offsets, CommitMode::Sync)
+                .map_err(|e| kafka_error(&info, e))?;
+            info!(
+                "committed offsets of kafka topic {} for group {}: {:?}",
+                info.topic, info.group_id, offsets
+            );
+            Ok(())
+        })
+        .await
+}
+
+fn create_consumer(info: &KafkaInfo) -> Result<BaseConsumer> {
+    info.client_config()
+        .create()
+        .map_err(|e| kafka_error(info, e))
+}
+
+pub(crate) fn kafka_error(info: &KafkaInfo, e: impl std::fmt::Display) -> ErrorCode {
+    ErrorCode::StorageOther(format!(
+        "kafka error, brokers: {}, topic: {}, cause: {}",
+        info.brokers, info.topic, e
+    ))
+}
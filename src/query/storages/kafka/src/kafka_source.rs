@@ -0,0 +1,170 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_base::base::tokio::time::timeout;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataBlock;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_sources::AsyncSource;
+use common_pipeline_sources::AsyncSourcer;
+use common_storage::FileStatus;
+use rdkafka::consumer::Consumer;
+use rdkafka::consumer::StreamConsumer;
+use rdkafka::Message;
+use rdkafka::Offset;
+use rdkafka::TopicPartitionList;
+
+use crate::kafka_offsets::kafka_error;
+use crate::KafkaInfo;
+use crate::KafkaMessageDecoder;
+use crate::KafkaPartitionRange;
+
+const KAFKA_POLL_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Consume the messages of a range of offsets of one partition and decode them into blocks.
+pub struct KafkaSource {
+    ctx: Arc<dyn TableContext>,
+    info: KafkaInfo,
+    range: KafkaPartitionRange,
+    decoder: Arc<KafkaMessageDecoder>,
+    consumer: Option<StreamConsumer>,
+    next_offset: i64,
+    max_block_size: usize,
+}
+
+impl KafkaSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        info: KafkaInfo,
+        range: KafkaPartitionRange,
+        decoder: Arc<KafkaMessageDecoder>,
+    ) -> Result<ProcessorPtr> {
+        let max_block_size = ctx.get_settings().get_max_block_size()? as usize;
+        let next_offset = range.start;
+        AsyncSourcer::create(ctx.clone(), output, KafkaSource {
+            ctx,
+            info,
+            range,
+            decoder,
+            consumer: None,
+            next_offset,
+            max_block_size,
+        })
+    }
+
+    fn create_consumer(&self) -> Result<StreamConsumer> {
+        let consumer: StreamConsumer = self
+            .info
+            .client_config()
+            .create()
+            .map_err(|e| kafka_error(&self.info, e))?;
+        let mut assignment = TopicPartitionList::new();
+        assignment
+            .add_partition_offset(
+                &self.info.topic,
+                self.range.partition,
+                Offset::Offset(self.range.start),
+            )
+            .map_err(|e| kafka_error(&self.info, e))?;
+        consumer
+            .assign(&assignment)
+            .map_err(|e| kafka_error(&self.info, e))?;
+        Ok(consumer)
+    }
+
+    // The offsets of a partition are not contiguous: the transaction markers and the
+    // messages of the aborted transactions are skipped by the consumer, so the end of
+    // the range is checked by the position of the consumer if no message arrives.
+    fn position(&self) -> Result<i64> {
+        let position = self
+            .consumer
+            .as_ref()
+            .unwrap()
+            .position()
+            .map_err(|e| kafka_error(&self.info, e))?;
+        let offset = position
+            .find_partition(&self.info.topic, self.range.partition)
+            .map(|elem| elem.offset());
+        match offset {
+            Some(Offset::Offset(offset)) => Ok(offset),
+            _ => Ok(self.next_offset),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for KafkaSource {
+    const NAME: &'static str = "KafkaSource";
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.next_offset >= self.range.end {
+            return Ok(None);
+        }
+
+        if self.consumer.is_none() {
+            self.consumer = Some(self.create_consumer()?);
+        }
+        let consumer = self.consumer.as_ref().unwrap();
+
+        let mut columns = self.decoder.create_column_builders(self.max_block_size);
+        let mut num_rows = 0;
+        while self.next_offset < self.range.end && num_rows < self.max_block_size {
+            let message = match timeout(KAFKA_POLL_TIMEOUT, consumer.recv()).await {
+                Ok(message) => message.map_err(|e| kafka_error(&self.info, e))?,
+                Err(_) => {
+                    self.next_offset = self.next_offset.max(self.position()?);
+                    continue;
+                }
+            };
+            let offset = message.offset();
+            if offset >= self.range.end {
+                // Produced after the copy started, left to the next copy.
+                self.next_offset = self.range.end;
+                break;
+            }
+            self.next_offset = offset + 1;
+            if let Some(payload) = message.payload() {
+                let with_position = |e: ErrorCode| {
+                    e.add_message_back(format!(
+                        " (topic: {}, partition: {}, offset: {})",
+                        self.info.topic, self.range.partition, offset
+                    ))
+                };
+                self.decoder.prepare(payload).await.map_err(with_position)?;
+                self.decoder
+                    .decode(payload, &mut columns)
+                    .map_err(with_position)?;
+                num_rows += 1;
+            }
+        }
+
+        self.ctx.get_copy_status().add_chunk(
+            &self.info.copied_key(self.range.partition, self.range.start),
+            FileStatus {
+                num_rows_loaded: num_rows,
+                error: None,
+            },
+        );
+        let columns = columns.into_iter().map(|c| c.build()).collect();
+        Ok(Some(DataBlock::new_from_columns(columns)))
+    }
+}
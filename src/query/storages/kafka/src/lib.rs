@@ -0,0 +1,27 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![allow(clippy::uninlined_format_args)]
+
+mod kafka_decoder;
+mod kafka_info;
+mod kafka_offsets;
+mod kafka_source;
+
+pub use kafka_decoder::KafkaMessageDecoder;
+pub use kafka_info::KafkaInfo;
+pub use kafka_info::KafkaMessageFormat;
+pub use kafka_offsets::commit_offsets;
+pub use kafka_offsets::fetch_partition_ranges;
+pub use kafka_offsets::KafkaPartitionRange;
+pub use kafka_source::KafkaSource;
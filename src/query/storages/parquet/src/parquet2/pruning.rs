@@ -182,9 +182,9 @@ impl PartitionPruner {
                     .as_ref()
                     .filter(|(tk, _)| tk.leaf_id == *index)
                     .zip(row_group_stats.as_ref())
-                    .map(|((_, offset), stats)| {
-                        let stat = stats[rg_idx].get(&(*offset as u32)).unwrap();
-                        (stat.min().clone(), stat.max().clone())
+                    .and_then(|((_, offset), stats)| {
+                        let stat = stats[rg_idx].get(&(*offset as u32))?;
+                        Some((stat.min().clone(), stat.max().clone()))
                     });
 
                 column_metas.insert(*index, ColumnMeta {
@@ -53,10 +53,17 @@ pub fn collect_row_group_stats(
         );
     }
 
-    for (rg_idx, _) in rgs.iter().enumerate() {
+    for (rg_idx, rg) in rgs.iter().enumerate() {
         let mut cols_stats = HashMap::with_capacity(stats.capacity());
         for index in 0..column_nodes.column_nodes.len() {
             let col_stats = stats_of_row_groups[&index].get(rg_idx);
+            // NULL min/max stand for an all-null column, skip the statistics
+            // if they are just missing.
+            if (col_stats.min().is_null() || col_stats.max().is_null())
+                && col_stats.null_count != rg.num_rows() as u64
+            {
+                continue;
+            }
             cols_stats.insert(index as u32, col_stats);
         }
         stats.push(cols_stats);
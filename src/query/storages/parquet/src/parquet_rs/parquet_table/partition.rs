@@ -27,6 +27,7 @@ use common_catalog::query_kind::QueryKind;
 use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
+use common_expression::Scalar;
 use common_storage::CopyStatus;
 use common_storage::FileStatus;
 use parquet::arrow::arrow_reader::RowSelector;
@@ -406,9 +407,10 @@ fn prune_and_generate_partitions(
             let sort_min_max =
                 topk.as_ref()
                     .zip(row_group_level_stats.as_ref())
-                    .map(|(t, stats)| {
-                        let stat = &stats[rg][&(t.leaf_id as u32)];
-                        (stat.min.clone(), stat.max.clone())
+                    .map(|(t, stats)| match stats[rg].get(&(t.leaf_id as u32)) {
+                        Some(stat) => (stat.min.clone(), stat.max.clone()),
+                        // The min/max of the row group are unknown.
+                        None => (Scalar::Null, Scalar::Null),
                     });
 
             parts.push(ParquetRSRowGroupPart {
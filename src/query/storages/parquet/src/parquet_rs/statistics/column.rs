@@ -27,7 +27,14 @@ use super::utils::decode_decimal128_from_bytes;
 use super::utils::decode_decimal256_from_bytes;
 
 /// according to https://github.com/apache/parquet-format/blob/master/LogicalTypes.md
-pub fn convert_column_statistics(s: &Statistics, typ: &TableDataType) -> ColumnStatistics {
+///
+/// Returns [None] if min/max are unavailable and the column is not entirely NULL,
+/// as NULL min/max in [ColumnStatistics] stand for an all-null column.
+pub fn convert_column_statistics(
+    s: &Statistics,
+    typ: &TableDataType,
+    num_rows: u64,
+) -> Option<ColumnStatistics> {
     let (max, min) = if s.has_min_max_set() {
         match s {
             Statistics::Boolean(s) => (Scalar::Boolean(*s.max()), Scalar::Boolean(*s.min())),
@@ -137,11 +144,14 @@ pub fn convert_column_statistics(s: &Statistics, typ: &TableDataType) -> ColumnS
     } else {
         (Scalar::Null, Scalar::Null)
     };
-    ColumnStatistics::new(
+    if (min.is_null() || max.is_null()) && s.null_count() != num_rows {
+        return None;
+    }
+    Some(ColumnStatistics::new(
         min,
         max,
         s.null_count(),
         0, // this field is not used.
         s.distinct_count(),
-    )
+    ))
 }
@@ -51,20 +51,26 @@ pub fn collect_row_group_stats(
                 let column = rg.column(*col_idx);
                 let field = &leaf_fields[*col_idx];
                 let column_stats = column.statistics().unwrap();
-                stats_of_columns.insert(
-                    *col_idx as u32,
-                    convert_column_statistics(column_stats, &field.data_type().remove_nullable()),
-                );
+                if let Some(stats) = convert_column_statistics(
+                    column_stats,
+                    &field.data_type().remove_nullable(),
+                    rg.num_rows() as u64,
+                ) {
+                    stats_of_columns.insert(*col_idx as u32, stats);
+                }
             }
         } else {
             for (col_idx, (column, field)) in
                 rg.columns().iter().zip(leaf_fields.iter()).enumerate()
             {
                 let column_stats = column.statistics().unwrap();
-                stats_of_columns.insert(
-                    col_idx as u32,
-                    convert_column_statistics(column_stats, &field.data_type().remove_nullable()),
-                );
+                if let Some(stats) = convert_column_statistics(
+                    column_stats,
+                    &field.data_type().remove_nullable(),
+                    rg.num_rows() as u64,
+                ) {
+                    stats_of_columns.insert(col_idx as u32, stats);
+                }
             }
         }
 
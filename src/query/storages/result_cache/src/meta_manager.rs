@@ -84,6 +84,25 @@ impl ResultCacheMetaManager {
         Ok(r)
     }
 
+    /// Removes all the result cache entries under the prefix, returns the number of removed entries.
+    #[async_backtrace::framed]
+    pub async fn remove_all(&self, prefix: &str) -> Result<usize> {
+        let result = self.inner.prefix_list_kv(prefix).await?;
+        let num_entries = result.len();
+        for (key, _) in result {
+            let _ = self
+                .inner
+                .upsert_kv(UpsertKV {
+                    key,
+                    seq: MatchSeq::GE(0),
+                    value: Operation::Delete,
+                    value_meta: None,
+                })
+                .await?;
+        }
+        Ok(num_entries)
+    }
+
     pub fn get_ttl(&self) -> u64 {
         self.ttl
     }
@@ -18,6 +18,7 @@ use common_catalog::plan::StageTableInfo;
 use common_catalog::table_context::TableContext;
 use common_exception::Result;
 use common_formats::FileFormatOptionsExt;
+use common_meta_app::principal::FileFormatParams;
 use common_pipeline_core::Pipeline;
 use common_pipeline_sources::input_formats::InputContext;
 use opendal::Operator;
@@ -40,7 +41,7 @@ pub(crate) fn append_data_to_row_based_files(
     uuid: String,
     group_id: &std::sync::atomic::AtomicUsize,
 ) -> Result<()> {
-    let mut options_ext = FileFormatOptionsExt::create_from_settings(&ctx.get_settings(), false)?;
+    let mut options_ext = create_options_ext(&ctx, &table_info)?;
     let output_format = options_ext.get_output_format(
         table_info.schema(),
         table_info.stage_info.file_format_params.clone(),
@@ -53,8 +54,7 @@ pub(crate) fn append_data_to_row_based_files(
     let prefix = output_format.serialize_prefix()?;
 
     pipeline.add_transform(|input, output| {
-        let mut options_ext =
-            FileFormatOptionsExt::create_from_settings(&ctx.get_settings(), false)?;
+        let mut options_ext = create_options_ext(&ctx, &table_info)?;
         let output_format = options_ext.get_output_format(
             table_info.schema(),
             table_info.stage_info.file_format_params.clone(),
@@ -85,3 +85,18 @@ pub(crate) fn append_data_to_row_based_files(
     })?;
     Ok(())
 }
+
+fn create_options_ext(
+    ctx: &Arc<dyn TableContext>,
+    table_info: &StageTableInfo,
+) -> Result<FileFormatOptionsExt> {
+    let mut options_ext = FileFormatOptionsExt::create_from_settings(&ctx.get_settings(), false)?;
+    // Each unloaded file starts with a line of column names if SKIP_HEADER is set,
+    // so the files can be loaded back with the same file format.
+    options_ext.headers = match &table_info.stage_info.file_format_params {
+        FileFormatParams::Csv(params) if params.headers > 0 => 1,
+        FileFormatParams::Tsv(params) if params.headers > 0 => 1,
+        _ => 0,
+    };
+    Ok(options_ext)
+}
@@ -0,0 +1,62 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This is the stream (table change tracking) engine for databend.
+//!
+//! A stream is created on a fuse table, and records an offset, which is the
+//! snapshot of the table when the stream is created or last consumed:
+//!
+//! ```sql
+//! CREATE STREAM s ON TABLE t;
+//! ```
+//!
+//! Reading a stream produces the rows changed since the offset, with the
+//! `change$action` column telling if a row is `INSERT`ed or `DELETE`d. The
+//! offset is moved forward when a DML statement (e.g. `INSERT INTO ... SELECT
+//! ... FROM s`) reading the stream succeeds.
+//!
+//! An append-only stream only tracks the inserted rows, it's cheaper since the
+//! deleted blocks are not diffed:
+//!
+//! ```sql
+//! CREATE STREAM s ON TABLE t APPEND_ONLY = true;
+//! ```
+//!
+//! A stream can also be created on a view projecting some columns of a fuse
+//! table (e.g. `CREATE VIEW v AS SELECT a, b FROM t`), it tracks the changes of
+//! the table and contains the projected columns only.
+//!
+//! # Limitations
+//!
+//! - The changes are tracked at the granularity of blocks, so the rows of a block
+//!   rewritten by a mutation show up as both `DELETE` and `INSERT` changes.
+//! - The snapshot of the offset must not be purged from the source table, neither
+//!   the snapshots after it for an append-only stream.
+
+mod stream_part;
+mod stream_source;
+mod stream_table;
+
+pub use stream_part::ChangeAction;
+pub use stream_part::StreamPartInfo;
+pub use stream_table::StreamTable;
+pub use stream_table::CHANGE_ACTION_COLUMN;
+pub use stream_table::MODE_APPEND_ONLY;
+pub use stream_table::MODE_STANDARD;
+pub use stream_table::OPT_KEY_DATABASE_NAME;
+pub use stream_table::OPT_KEY_MODE;
+pub use stream_table::OPT_KEY_OFFSET;
+pub use stream_table::OPT_KEY_TABLE_ID;
+pub use stream_table::OPT_KEY_TABLE_NAME;
+pub use stream_table::STREAM_ENGINE;
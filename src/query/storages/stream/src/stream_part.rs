@@ -0,0 +1,82 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use common_catalog::plan::PartInfo;
+use common_catalog::plan::PartInfoPtr;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use storages_common_table_meta::meta::BlockMeta;
+
+/// How the rows are changed since the offset of a stream.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash, Debug, Clone, Copy)]
+pub enum ChangeAction {
+    Insert,
+    Delete,
+}
+
+impl ChangeAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChangeAction::Insert => "INSERT",
+            ChangeAction::Delete => "DELETE",
+        }
+    }
+}
+
+/// A changed block of the source table of a stream.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct StreamPartInfo {
+    pub action: ChangeAction,
+    pub block_meta: BlockMeta,
+}
+
+#[typetag::serde(name = "stream")]
+impl PartInfo for StreamPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        info.as_any()
+            .downcast_ref::<StreamPartInfo>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn hash(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        self.action.hash(&mut s);
+        self.block_meta.location.0.hash(&mut s);
+        s.finish()
+    }
+}
+
+impl StreamPartInfo {
+    pub fn create(action: ChangeAction, block_meta: BlockMeta) -> Arc<Box<dyn PartInfo>> {
+        Arc::new(Box::new(StreamPartInfo { action, block_meta }))
+    }
+
+    pub fn from_part(info: &PartInfoPtr) -> Result<&StreamPartInfo> {
+        info.as_any()
+            .downcast_ref::<StreamPartInfo>()
+            .ok_or(ErrorCode::Internal(
+                "Cannot downcast from PartInfo to StreamPartInfo.",
+            ))
+    }
+}
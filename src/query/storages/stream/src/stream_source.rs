@@ -0,0 +1,197 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use common_base::base::Progress;
+use common_base::base::ProgressValues;
+use common_catalog::plan::Projection;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::DataType;
+use common_expression::BlockEntry;
+use common_expression::DataBlock;
+use common_expression::DataSchemaRef;
+use common_expression::Scalar;
+use common_expression::Value;
+use common_pipeline_core::processors::port::OutputPort;
+use common_pipeline_core::processors::processor::Event;
+use common_pipeline_core::processors::processor::ProcessorPtr;
+use common_pipeline_core::processors::Processor;
+use common_storages_fuse::io::BlockReader;
+use common_storages_fuse::FuseTable;
+
+use crate::stream_part::StreamPartInfo;
+use crate::stream_table::SourceTableIdent;
+use crate::stream_table::CHANGE_ACTION_COLUMN;
+
+/// Reads the changed blocks of the source table, and fills the change columns.
+pub struct StreamTableSource {
+    // Source processor related fields.
+    output: Arc<OutputPort>,
+    scan_progress: Arc<Progress>,
+    // Used for event transforming.
+    ctx: Arc<dyn TableContext>,
+    generated_data: Option<DataBlock>,
+    is_finished: bool,
+
+    output_schema: DataSchemaRef,
+    source: Arc<SourceTableIdent>,
+    put_cache: bool,
+    // The source table and the reader of its columns, initialized on the first read.
+    reader: Option<(Arc<dyn Table>, Arc<BlockReader>)>,
+}
+
+impl StreamTableSource {
+    pub(crate) fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        output_schema: DataSchemaRef,
+        source: Arc<SourceTableIdent>,
+        put_cache: bool,
+    ) -> Result<ProcessorPtr> {
+        let scan_progress = ctx.get_scan_progress();
+        Ok(ProcessorPtr::create(Box::new(StreamTableSource {
+            output,
+            scan_progress,
+            ctx,
+            generated_data: None,
+            is_finished: false,
+            output_schema,
+            source,
+            put_cache,
+            reader: None,
+        })))
+    }
+
+    /// Creates the reader of the source columns in the output schema.
+    async fn create_reader(&self) -> Result<(Arc<dyn Table>, Arc<BlockReader>)> {
+        let table = self.source.get_table(self.ctx.clone()).await?;
+        let source_schema = table.schema();
+        let mut indices = vec![];
+        for field in self.output_schema.fields() {
+            if field.name() == CHANGE_ACTION_COLUMN {
+                continue;
+            }
+            let index = source_schema.index_of(field.name()).map_err(|_| {
+                ErrorCode::TableSchemaMismatch(format!(
+                    "Column {} of the stream is dropped from the source table, please re-create the stream",
+                    field.name()
+                ))
+            })?;
+            let data_type = DataType::from(source_schema.field(index).data_type());
+            if &data_type != field.data_type() {
+                return Err(ErrorCode::TableSchemaMismatch(format!(
+                    "Column {} of the stream is changed to {} in the source table, please re-create the stream",
+                    field.name(),
+                    data_type
+                )));
+            }
+            indices.push(index);
+        }
+
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let reader = fuse_table.create_block_reader(
+            self.ctx.clone(),
+            Projection::Columns(indices),
+            false,
+            self.put_cache,
+        )?;
+        Ok((table, reader))
+    }
+
+    async fn read_part(&mut self, part: &StreamPartInfo) -> Result<DataBlock> {
+        if self.reader.is_none() {
+            self.reader = Some(self.create_reader().await?);
+        }
+        let (table, reader) = self.reader.as_ref().unwrap();
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let block = fuse_table
+            .read_block_by_meta(self.ctx.clone(), reader, &part.block_meta)
+            .await?;
+
+        let num_rows = block.num_rows();
+        let mut source_columns = block.columns().iter();
+        let columns = self
+            .output_schema
+            .fields()
+            .iter()
+            .map(|field| {
+                if field.name() == CHANGE_ACTION_COLUMN {
+                    BlockEntry::new(
+                        DataType::String,
+                        Value::Scalar(Scalar::String(part.action.as_str().as_bytes().to_vec())),
+                    )
+                } else {
+                    source_columns.next().unwrap().clone()
+                }
+            })
+            .collect();
+        Ok(DataBlock::new(columns, num_rows))
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for StreamTableSource {
+    fn name(&self) -> String {
+        "StreamSource".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.is_finished {
+            self.output.finish();
+            return Ok(Event::Finished);
+        }
+
+        if self.output.is_finished() {
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        match self.generated_data.take() {
+            None => Ok(Event::Async),
+            Some(data_block) => {
+                let progress_values = ProgressValues {
+                    rows: data_block.num_rows(),
+                    bytes: data_block.memory_size(),
+                };
+                self.scan_progress.incr(&progress_values);
+                self.output.push_data(Ok(data_block));
+                Ok(Event::NeedConsume)
+            }
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match self.ctx.get_partition() {
+            Some(part) => {
+                let part = StreamPartInfo::from_part(&part)?;
+                self.generated_data = Some(self.read_part(part).await?);
+            }
+            None => self.is_finished = true,
+        }
+        Ok(())
+    }
+}
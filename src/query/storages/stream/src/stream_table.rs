@@ -0,0 +1,287 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use common_catalog::catalog::StorageDescription;
+use common_catalog::plan::DataSourcePlan;
+use common_catalog::plan::PartStatistics;
+use common_catalog::plan::Partitions;
+use common_catalog::plan::PartitionsShuffleKind;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::DataSchema;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchema;
+use common_meta_app::schema::TableInfo;
+use common_pipeline_core::Pipeline;
+use common_storages_fuse::operations::ChangedBlocks;
+use common_storages_fuse::FuseTable;
+
+use crate::stream_part::ChangeAction;
+use crate::stream_part::StreamPartInfo;
+use crate::stream_source::StreamTableSource;
+
+pub const STREAM_ENGINE: &str = "STREAM";
+
+pub const OPT_KEY_DATABASE_NAME: &str = "table_database";
+pub const OPT_KEY_TABLE_NAME: &str = "table_name";
+pub const OPT_KEY_TABLE_ID: &str = "table_id";
+/// The snapshot location of the source table, up to which the changes are consumed.
+/// It is absent if the source table had no data at that time.
+pub const OPT_KEY_OFFSET: &str = "offset";
+/// How the changes are tracked, `standard` or `append_only`. It is absent for the streams
+/// created before the mode is introduced, which are standard streams.
+pub const OPT_KEY_MODE: &str = "mode";
+
+pub const MODE_STANDARD: &str = "standard";
+/// Only the inserted rows are tracked, the deletes and updates are ignored.
+pub const MODE_APPEND_ONLY: &str = "append_only";
+
+/// The column telling how a row is changed, `INSERT` or `DELETE`.
+pub const CHANGE_ACTION_COLUMN: &str = "change$action";
+
+/// A stream tracking the changes of a fuse table since its offset.
+pub struct StreamTable {
+    info: TableInfo,
+    table_database: String,
+    table_name: String,
+    table_id: u64,
+    offset: Option<String>,
+    append_only: bool,
+}
+
+impl StreamTable {
+    pub fn try_create(info: TableInfo) -> Result<Box<dyn Table>> {
+        let options = info.options();
+        let get_option = |key: &str| {
+            options.get(key).cloned().ok_or_else(|| {
+                ErrorCode::Internal(format!("Need `{key}` when creating StreamTable"))
+            })
+        };
+        let table_database = get_option(OPT_KEY_DATABASE_NAME)?;
+        let table_name = get_option(OPT_KEY_TABLE_NAME)?;
+        let table_id = get_option(OPT_KEY_TABLE_ID)?
+            .parse::<u64>()
+            .map_err(|e| ErrorCode::Internal(format!("Invalid source table id of stream: {e}")))?;
+        let offset = options.get(OPT_KEY_OFFSET).cloned();
+        let append_only = match options.get(OPT_KEY_MODE).map(|mode| mode.as_str()) {
+            None | Some(MODE_STANDARD) => false,
+            Some(MODE_APPEND_ONLY) => true,
+            Some(mode) => {
+                return Err(ErrorCode::Internal(format!(
+                    "Invalid mode of stream: {mode}"
+                )));
+            }
+        };
+        Ok(Box::new(StreamTable {
+            info,
+            table_database,
+            table_name,
+            table_id,
+            offset,
+            append_only,
+        }))
+    }
+
+    pub fn description() -> StorageDescription {
+        StorageDescription {
+            engine_name: STREAM_ENGINE.to_string(),
+            comment: "STREAM Storage Engine".to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn try_from_table(tbl: &dyn Table) -> Result<&StreamTable> {
+        tbl.as_any().downcast_ref::<StreamTable>().ok_or_else(|| {
+            ErrorCode::Internal(format!(
+                "expects table of engine STREAM, but got {}",
+                tbl.engine()
+            ))
+        })
+    }
+
+    /// The schema of a stream, the tracked columns of the source table followed by the
+    /// change columns.
+    pub fn stream_schema(mut fields: Vec<TableField>) -> TableSchema {
+        fields.push(TableField::new(CHANGE_ACTION_COLUMN, TableDataType::String));
+        TableSchema::new(fields)
+    }
+
+    pub fn offset(&self) -> Option<&str> {
+        self.offset.as_deref()
+    }
+
+    pub fn append_only(&self) -> bool {
+        self.append_only
+    }
+
+    /// Gets the source table, which is consistent for multiple calls in a same query.
+    #[async_backtrace::framed]
+    pub async fn source_table(&self, ctx: Arc<dyn TableContext>) -> Result<Arc<dyn Table>> {
+        get_source_table(
+            ctx,
+            self.info.catalog(),
+            &self.table_database,
+            &self.table_name,
+            self.table_id,
+        )
+        .await
+    }
+
+    #[async_backtrace::framed]
+    async fn do_read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let table = self.source_table(ctx.clone()).await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let changes = if self.append_only {
+            ChangedBlocks {
+                inserted: fuse_table.appended_blocks(ctx, self.offset.clone()).await?,
+                deleted: vec![],
+            }
+        } else {
+            fuse_table.changed_blocks(ctx, self.offset.clone()).await?
+        };
+
+        let mut read_rows = 0;
+        let mut read_bytes = 0;
+        let mut parts = Vec::with_capacity(changes.deleted.len() + changes.inserted.len());
+        for (action, blocks) in [
+            (ChangeAction::Delete, changes.deleted),
+            (ChangeAction::Insert, changes.inserted),
+        ] {
+            for block in blocks {
+                read_rows += block.row_count as usize;
+                read_bytes += block.block_size as usize;
+                parts.push(StreamPartInfo::create(action, block.as_ref().clone()));
+            }
+        }
+
+        Ok((
+            PartStatistics::new_exact(read_rows, read_bytes, parts.len(), parts.len()),
+            Partitions::create_nolazy(PartitionsShuffleKind::Mod, parts),
+        ))
+    }
+
+    fn do_read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        put_cache: bool,
+    ) -> Result<()> {
+        let parts_len = plan.parts.len();
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let max_threads = std::cmp::min(parts_len, max_threads);
+
+        let output_schema = Arc::new(DataSchema::from(plan.schema()));
+        let source = Arc::new(SourceTableIdent {
+            catalog: self.info.catalog().to_string(),
+            database: self.table_database.clone(),
+            table: self.table_name.clone(),
+            table_id: self.table_id,
+        });
+        pipeline.add_source(
+            |output| {
+                StreamTableSource::create(
+                    ctx.clone(),
+                    output,
+                    output_schema.clone(),
+                    source.clone(),
+                    put_cache,
+                )
+            },
+            max_threads.max(1),
+        )
+    }
+}
+
+/// Identifies the source table of a stream.
+pub(crate) struct SourceTableIdent {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub table_id: u64,
+}
+
+impl SourceTableIdent {
+    #[async_backtrace::framed]
+    pub async fn get_table(&self, ctx: Arc<dyn TableContext>) -> Result<Arc<dyn Table>> {
+        get_source_table(
+            ctx,
+            &self.catalog,
+            &self.database,
+            &self.table,
+            self.table_id,
+        )
+        .await
+    }
+}
+
+async fn get_source_table(
+    ctx: Arc<dyn TableContext>,
+    catalog: &str,
+    database: &str,
+    table: &str,
+    table_id: u64,
+) -> Result<Arc<dyn Table>> {
+    let source = ctx.get_table(catalog, database, table).await?;
+    // The source table may be dropped and re-created with the same name.
+    if source.get_id() != table_id {
+        return Err(ErrorCode::UnknownTable(format!(
+            "The source table `{}`.`{}` of the stream has been dropped",
+            database, table
+        )));
+    }
+    Ok(source)
+}
+
+#[async_trait]
+impl Table for StreamTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        self.do_read_partitions(ctx).await
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        put_cache: bool,
+    ) -> Result<()> {
+        self.do_read_data(ctx, plan, pipeline, put_cache)
+    }
+}
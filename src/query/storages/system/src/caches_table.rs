@@ -54,10 +54,13 @@ impl SyncSystemTable for CachesTable {
         let mut names = Vec::new();
         let mut num_items = Vec::new();
         let mut size = Vec::new();
+        let mut hits = Vec::new();
+        let mut misses = Vec::new();
 
         let cache_manager = CacheManager::instance();
 
         let table_snapshot_cache = cache_manager.get_table_snapshot_cache();
+        let snapshot_history_cache = cache_manager.get_snapshot_history_cache();
         let table_snapshot_statistic_cache = cache_manager.get_table_snapshot_statistics_cache();
         let segment_info_cache = cache_manager.get_table_segment_cache();
         let bloom_index_filter_cache = cache_manager.get_bloom_index_filter_cache();
@@ -72,12 +75,24 @@ impl SyncSystemTable for CachesTable {
             names.push("table_snapshot_cache");
             num_items.push(table_snapshot_cache.len() as u64);
             size.push(table_snapshot_cache.size());
+            hits.push(table_snapshot_cache.statistics().hit_count());
+            misses.push(table_snapshot_cache.statistics().miss_count());
+        }
+        if let Some(snapshot_history_cache) = snapshot_history_cache {
+            nodes.push(local_node.clone().into_bytes());
+            names.push("snapshot_history_cache");
+            num_items.push(snapshot_history_cache.len() as u64);
+            size.push(snapshot_history_cache.size());
+            hits.push(snapshot_history_cache.statistics().hit_count());
+            misses.push(snapshot_history_cache.statistics().miss_count());
         }
         if let Some(table_snapshot_statistic_cache) = table_snapshot_statistic_cache {
             nodes.push(local_node.clone().into_bytes());
             names.push("table_snapshot_statistic_cache");
             num_items.push(table_snapshot_statistic_cache.len() as u64);
             size.push(table_snapshot_statistic_cache.size());
+            hits.push(table_snapshot_statistic_cache.statistics().hit_count());
+            misses.push(table_snapshot_statistic_cache.statistics().miss_count());
         }
 
         if let Some(segment_info_cache) = segment_info_cache {
@@ -85,6 +100,8 @@ impl SyncSystemTable for CachesTable {
             names.push("segment_info_cache");
             num_items.push(segment_info_cache.len() as u64);
             size.push(segment_info_cache.size());
+            hits.push(segment_info_cache.statistics().hit_count());
+            misses.push(segment_info_cache.statistics().miss_count());
         }
 
         if let Some(bloom_index_filter_cache) = bloom_index_filter_cache {
@@ -92,6 +109,8 @@ impl SyncSystemTable for CachesTable {
             names.push("bloom_index_filter_cache");
             num_items.push(bloom_index_filter_cache.len() as u64);
             size.push(bloom_index_filter_cache.size());
+            hits.push(bloom_index_filter_cache.statistics().hit_count());
+            misses.push(bloom_index_filter_cache.statistics().miss_count());
         }
 
         if let Some(bloom_index_meta_cache) = bloom_index_meta_cache {
@@ -99,6 +118,8 @@ impl SyncSystemTable for CachesTable {
             names.push("bloom_index_meta_cache");
             num_items.push(bloom_index_meta_cache.len() as u64);
             size.push(bloom_index_meta_cache.size());
+            hits.push(bloom_index_meta_cache.statistics().hit_count());
+            misses.push(bloom_index_meta_cache.statistics().miss_count());
         }
 
         if let Some(prune_partitions_cache) = prune_partitions_cache {
@@ -106,6 +127,8 @@ impl SyncSystemTable for CachesTable {
             names.push("prune_partitions_cache");
             num_items.push(prune_partitions_cache.len() as u64);
             size.push(prune_partitions_cache.size());
+            hits.push(prune_partitions_cache.statistics().hit_count());
+            misses.push(prune_partitions_cache.statistics().miss_count());
         }
 
         if let Some(file_meta_data_cache) = file_meta_data_cache {
@@ -113,6 +136,8 @@ impl SyncSystemTable for CachesTable {
             names.push("file_meta_data_cache");
             num_items.push(file_meta_data_cache.len() as u64);
             size.push(file_meta_data_cache.size());
+            hits.push(file_meta_data_cache.statistics().hit_count());
+            misses.push(file_meta_data_cache.statistics().miss_count());
         }
 
         if let Some(table_data_cache) = table_data_cache {
@@ -120,6 +145,8 @@ impl SyncSystemTable for CachesTable {
             names.push("table_data_cache");
             num_items.push(table_data_cache.len() as u64);
             size.push(table_data_cache.size());
+            hits.push(table_data_cache.statistics().hit_count());
+            misses.push(table_data_cache.statistics().miss_count());
         }
 
         if let Some(table_column_array_cache) = table_column_array_cache {
@@ -127,6 +154,8 @@ impl SyncSystemTable for CachesTable {
             names.push("table_column_array_cache");
             num_items.push(table_column_array_cache.len() as u64);
             size.push(table_column_array_cache.size());
+            hits.push(table_column_array_cache.statistics().hit_count());
+            misses.push(table_column_array_cache.statistics().miss_count());
         }
 
         let names: Vec<_> = names.iter().map(|x| x.as_bytes().to_vec()).collect();
@@ -135,6 +164,8 @@ impl SyncSystemTable for CachesTable {
             StringType::from_data(names),
             UInt64Type::from_data(num_items),
             UInt64Type::from_data(size),
+            UInt64Type::from_data(hits),
+            UInt64Type::from_data(misses),
         ]))
     }
 }
@@ -146,6 +177,8 @@ impl CachesTable {
             TableField::new("name", TableDataType::String),
             TableField::new("num_items", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("size", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("hit", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("miss", TableDataType::Number(NumberDataType::UInt64)),
         ]);
 
         let table_info = TableInfo {
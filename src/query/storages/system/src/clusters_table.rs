@@ -54,6 +54,26 @@ impl SyncSystemTable for ClustersTable {
             cluster_nodes.len(),
         );
         let mut versions = ColumnBuilder::with_capacity(&DataType::String, cluster_nodes.len());
+        let mut cpu_usages = ColumnBuilder::with_capacity(
+            &DataType::Number(NumberDataType::UInt32),
+            cluster_nodes.len(),
+        );
+        let mut memory_usages = ColumnBuilder::with_capacity(
+            &DataType::Number(NumberDataType::UInt64),
+            cluster_nodes.len(),
+        );
+        let mut running_queries = ColumnBuilder::with_capacity(
+            &DataType::Number(NumberDataType::UInt64),
+            cluster_nodes.len(),
+        );
+        let mut data_cache_sizes = ColumnBuilder::with_capacity(
+            &DataType::Number(NumberDataType::UInt64),
+            cluster_nodes.len(),
+        );
+        let mut meta_cache_items = ColumnBuilder::with_capacity(
+            &DataType::Number(NumberDataType::UInt64),
+            cluster_nodes.len(),
+        );
 
         for cluster_node in &cluster_nodes {
             let (ip, port) = cluster_node.ip_port()?;
@@ -62,6 +82,17 @@ impl SyncSystemTable for ClustersTable {
             addresses.push(Scalar::String(ip.as_bytes().to_vec()).as_ref());
             addresses_port.push(Scalar::Number(NumberScalar::UInt16(port)).as_ref());
             versions.push(Scalar::String(cluster_node.binary_version.as_bytes().to_vec()).as_ref());
+
+            let resources = &cluster_node.resources;
+            cpu_usages.push(Scalar::Number(NumberScalar::UInt32(resources.cpu_usage)).as_ref());
+            memory_usages
+                .push(Scalar::Number(NumberScalar::UInt64(resources.memory_usage)).as_ref());
+            running_queries
+                .push(Scalar::Number(NumberScalar::UInt64(resources.running_queries)).as_ref());
+            data_cache_sizes
+                .push(Scalar::Number(NumberScalar::UInt64(resources.data_cache_size)).as_ref());
+            meta_cache_items
+                .push(Scalar::Number(NumberScalar::UInt64(resources.meta_cache_items)).as_ref());
         }
 
         Ok(DataBlock::new_from_columns(vec![
@@ -69,6 +100,11 @@ impl SyncSystemTable for ClustersTable {
             addresses.build(),
             addresses_port.build(),
             versions.build(),
+            cpu_usages.build(),
+            memory_usages.build(),
+            running_queries.build(),
+            data_cache_sizes.build(),
+            meta_cache_items.build(),
         ]))
     }
 }
@@ -80,6 +116,23 @@ impl ClustersTable {
             TableField::new("host", TableDataType::String),
             TableField::new("port", TableDataType::Number(NumberDataType::UInt16)),
             TableField::new("version", TableDataType::String),
+            TableField::new("cpu_usage", TableDataType::Number(NumberDataType::UInt32)),
+            TableField::new(
+                "memory_usage",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "running_queries",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "data_cache_size",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "meta_cache_items",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
         ]);
 
         let table_info = TableInfo {
@@ -27,6 +27,7 @@ use common_expression::TableField;
 use common_expression::TableSchemaRefExt;
 use common_functions::aggregates::AggregateFunctionFactory;
 use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::principal::UDFDefinition;
 use common_meta_app::principal::UserDefinedFunction;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
@@ -85,7 +86,15 @@ impl AsyncSystemTable for FunctionsTable {
             .collect::<Vec<bool>>();
 
         let is_aggregate = (0..names.len())
-            .map(|i| i >= scalar_func_names.len() && i < builtin_func_len)
+            .map(|i| {
+                if i < builtin_func_len {
+                    i >= scalar_func_names.len()
+                } else {
+                    udfs.get(i - builtin_func_len).map_or(false, |udf| {
+                        matches!(udf.definition, UDFDefinition::LambdaUDAF(_))
+                    })
+                }
+            })
             .collect::<Vec<bool>>();
 
         let definitions = (0..names.len())
@@ -37,18 +37,23 @@ mod indexes_table;
 mod log_queue;
 mod malloc_stats_table;
 mod malloc_stats_totals_table;
+mod memory_breakdown_table;
 mod metrics_table;
 mod one_table;
 mod processes_table;
+mod processor_profile_table;
 mod query_cache_table;
 mod query_log_table;
 mod query_profile_table;
+mod query_queue_table;
 mod query_summary_table;
+mod role_grants_table;
 mod roles_table;
 mod settings_table;
 mod stages_table;
 mod table;
 mod table_functions_table;
+mod table_statistics_table;
 mod tables_table;
 mod tasks_table;
 mod temp_files_table;
@@ -74,27 +79,33 @@ pub use databases_table::DatabasesTable;
 pub use engines_table::EnginesTable;
 pub use functions_table::FunctionsTable;
 pub use indexes_table::IndexesTable;
+pub use log_queue::build_log_data_block;
 pub use log_queue::SystemLogElement;
 pub use log_queue::SystemLogQueue;
 pub use log_queue::SystemLogTable;
 pub use malloc_stats_table::MallocStatsTable;
 pub use malloc_stats_totals_table::MallocStatsTotalsTable;
+pub use memory_breakdown_table::MemoryBreakdownTable;
 pub use metrics_table::MetricsTable;
 pub use one_table::OneTable;
 pub use processes_table::ProcessesTable;
+pub use processor_profile_table::ProcessorProfileTable;
 pub use query_cache_table::QueryCacheTable;
 pub use query_log_table::LogType;
 pub use query_log_table::QueryLogElement;
 pub use query_log_table::QueryLogQueue;
 pub use query_log_table::QueryLogTable;
 pub use query_profile_table::QueryProfileTable;
+pub use query_queue_table::QueryQueueTable;
 pub use query_summary_table::QuerySummaryTable;
+pub use role_grants_table::RoleGrantsTable;
 pub use roles_table::RolesTable;
 pub use settings_table::SettingsTable;
 pub use stages_table::StagesTable;
 pub use table::SyncOneBlockSystemTable;
 pub use table::SyncSystemTable;
 pub use table_functions_table::TableFunctionsTable;
+pub use table_statistics_table::TableStatisticsTable;
 pub use tables_table::TablesTable;
 pub use tables_table::TablesTableWithHistory;
 pub use tables_table::TablesTableWithoutHistory;
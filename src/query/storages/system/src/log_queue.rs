@@ -54,6 +54,8 @@ pub trait SystemLogElement: Send + Sync + Clone {
 
 struct Data<Event: SystemLogElement> {
     index: usize,
+    // Total number of events ever appended, used as a cursor by log consumers.
+    sequence: u64,
     event_queue: Vec<Option<Event>>,
 }
 
@@ -61,6 +63,7 @@ impl<Event: SystemLogElement> Data<Event> {
     pub fn new(size: usize) -> Data<Event> {
         Data::<Event> {
             index: 0,
+            sequence: 0,
             event_queue: vec![None; size],
         }
     }
@@ -111,6 +114,7 @@ impl<Event: SystemLogElement + 'static> SystemLogQueue<Event> {
         let cur_index = write_guard.index;
         write_guard.event_queue[cur_index] = Some(event);
         write_guard.index += 1;
+        write_guard.sequence += 1;
 
         if write_guard.index == self.max_rows {
             write_guard.index = 0;
@@ -118,6 +122,45 @@ impl<Event: SystemLogElement + 'static> SystemLogQueue<Event> {
 
         Ok(())
     }
+
+    /// Returns the events appended after `sequence` that are still kept in the
+    /// queue, in append order, together with the sequence of the latest event.
+    ///
+    /// Events that have already been overwritten by newer ones are skipped.
+    pub fn events_since(&self, sequence: u64) -> (Vec<Event>, u64) {
+        let read_guard = self.data.read();
+        let latest = read_guard.sequence;
+        let pending = latest.saturating_sub(sequence).min(self.max_rows as u64) as usize;
+
+        let mut events = Vec::with_capacity(pending);
+        for offset in (1..=pending).rev() {
+            let index = (read_guard.index + self.max_rows - offset) % self.max_rows;
+            if let Some(event) = &read_guard.event_queue[index] {
+                events.push(event.clone());
+            }
+        }
+        (events, latest)
+    }
+}
+
+/// Builds a data block with the schema of `Event` from the given events.
+pub fn build_log_data_block<Event: SystemLogElement>(events: &[Event]) -> Result<DataBlock> {
+    let schema = Event::schema();
+    let mut mutable_columns: Vec<ColumnBuilder> = Vec::with_capacity(schema.num_fields());
+    for column_field in schema.fields() {
+        let data_type: DataType = column_field.data_type().into();
+        mutable_columns.push(ColumnBuilder::with_capacity(&data_type, events.len()));
+    }
+
+    for event in events {
+        event.fill_to_data_block(&mut mutable_columns)?;
+    }
+
+    let columns = mutable_columns
+        .into_iter()
+        .map(|builder| builder.build())
+        .collect::<Vec<_>>();
+    Ok(DataBlock::new_from_columns(columns))
 }
 
 pub struct SystemLogTable<Event: SystemLogElement> {
@@ -181,36 +224,20 @@ impl<Event: SystemLogElement + 'static> Table for SystemLogTable<Event> {
         pipeline: &mut Pipeline,
         _put_cache: bool,
     ) -> Result<()> {
-        let schema = Event::schema();
-        let mut mutable_columns: Vec<ColumnBuilder> = Vec::with_capacity(schema.num_fields());
-        let mut data_types: Vec<DataType> = Vec::with_capacity(schema.num_fields());
-
-        for column_field in schema.fields() {
-            let data_type: DataType = column_field.data_type().into();
-            let mutable_column = ColumnBuilder::with_capacity(&data_type, 0);
-            mutable_columns.push(mutable_column);
-            data_types.push(data_type);
-        }
-
         let log_queue = SystemLogQueue::<Event>::instance()?;
-        for event in log_queue.data.read().event_queue.iter().flatten() {
-            event.fill_to_data_block(&mut mutable_columns)?;
-        }
-
-        let mut columns = Vec::with_capacity(mutable_columns.len());
-        for mutable_column in mutable_columns.into_iter() {
-            columns.push(mutable_column.build());
-        }
+        let events = log_queue
+            .data
+            .read()
+            .event_queue
+            .iter()
+            .flatten()
+            .cloned()
+            .collect::<Vec<_>>();
+        let block = build_log_data_block(&events)?;
 
         // Add source pipe.
         pipeline.add_source(
-            move |output| {
-                SystemLogSource::<Event>::create(
-                    ctx.clone(),
-                    output,
-                    DataBlock::new_from_columns(columns.clone()),
-                )
-            },
+            move |output| SystemLogSource::<Event>::create(ctx.clone(), output, block.clone()),
             1,
         )
     }
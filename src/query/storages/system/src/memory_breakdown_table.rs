@@ -0,0 +1,196 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_base::runtime::GLOBAL_MEM_STAT;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_expression::types::Int64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use storages_common_cache::CacheAccessor;
+use storages_common_cache_manager::CacheManager;
+use tikv_jemalloc_ctl::arenas;
+use tikv_jemalloc_ctl::epoch;
+use tikv_jemalloc_ctl::raw;
+use tikv_jemalloc_ctl::stats;
+
+use crate::SyncOneBlockSystemTable;
+use crate::SyncSystemTable;
+
+/// Answers "where is the memory going" on each node: the allocator view from jemalloc,
+/// the memory tracked by the global and per session trackers, and the in-memory caches
+/// which are metered by bytes.
+pub struct MemoryBreakdownTable {
+    table_info: TableInfo,
+}
+
+#[derive(Default)]
+struct MemoryBreakdownRows {
+    categories: Vec<&'static str>,
+    names: Vec<String>,
+    bytes: Vec<i64>,
+}
+
+impl MemoryBreakdownRows {
+    fn push(&mut self, category: &'static str, name: impl Into<String>, bytes: i64) {
+        self.categories.push(category);
+        self.names.push(name.into());
+        self.bytes.push(bytes);
+    }
+}
+
+impl SyncSystemTable for MemoryBreakdownTable {
+    const NAME: &'static str = "system.memory_breakdown";
+
+    // Allow distributed query.
+    const IS_LOCAL: bool = false;
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let local_node = ctx.get_cluster().local_id.clone();
+        let mut rows = MemoryBreakdownRows::default();
+
+        Self::collect_allocator(&mut rows).map_err(convert_je_err)?;
+        Self::collect_trackers(&ctx, &mut rows);
+        Self::collect_caches(&mut rows);
+
+        let nodes = vec![local_node.into_bytes(); rows.names.len()];
+        let categories = rows
+            .categories
+            .iter()
+            .map(|x| x.as_bytes().to_vec())
+            .collect::<Vec<_>>();
+        let names = rows
+            .names
+            .into_iter()
+            .map(|x| x.into_bytes())
+            .collect::<Vec<_>>();
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(nodes),
+            StringType::from_data(categories),
+            StringType::from_data(names),
+            Int64Type::from_data(rows.bytes),
+        ]))
+    }
+}
+
+impl MemoryBreakdownTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("node", TableDataType::String),
+            TableField::new("category", TableDataType::String),
+            TableField::new("name", TableDataType::String),
+            TableField::new("bytes", TableDataType::Number(NumberDataType::Int64)),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'memory_breakdown'".to_string(),
+            name: "memory_breakdown".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemMemoryBreakdown".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        SyncOneBlockSystemTable::create(MemoryBreakdownTable { table_info })
+    }
+
+    fn collect_allocator(
+        rows: &mut MemoryBreakdownRows,
+    ) -> std::result::Result<(), tikv_jemalloc_ctl::Error> {
+        // The statistics of jemalloc are cached, refresh them first.
+        epoch::advance()?;
+
+        rows.push("allocator", "allocated", stats::allocated::read()? as i64);
+        rows.push("allocator", "active", stats::active::read()? as i64);
+        rows.push("allocator", "metadata", stats::metadata::read()? as i64);
+        rows.push("allocator", "resident", stats::resident::read()? as i64);
+        rows.push("allocator", "mapped", stats::mapped::read()? as i64);
+        rows.push("allocator", "retained", stats::retained::read()? as i64);
+
+        // Safety: the names are null terminated and all these mallctl values are size_t.
+        let page_size = unsafe { raw::read::<usize>(b"arenas.page\0")? };
+        let narenas = arenas::narenas::read()?;
+        for arena in 0..narenas {
+            // Arenas which are not initialized yet have no statistics.
+            let active_pages = format!("stats.arenas.{}.pactive\0", arena);
+            if let Ok(pages) = unsafe { raw::read::<usize>(active_pages.as_bytes()) } {
+                let name = format!("arena.{}.active", arena);
+                rows.push("allocator", name, (pages * page_size) as i64);
+            }
+
+            let resident = format!("stats.arenas.{}.resident\0", arena);
+            if let Ok(bytes) = unsafe { raw::read::<usize>(resident.as_bytes()) } {
+                let name = format!("arena.{}.resident", arena);
+                rows.push("allocator", name, bytes as i64);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn collect_trackers(ctx: &Arc<dyn TableContext>, rows: &mut MemoryBreakdownRows) {
+        rows.push("tracker", "global", GLOBAL_MEM_STAT.get_memory_usage());
+        rows.push(
+            "tracker",
+            "global_peak",
+            GLOBAL_MEM_STAT.get_peak_memory_usage(),
+        );
+
+        // The pipelines of a query are tracked by the memory tracker of its session.
+        for process_info in ctx.get_processes_info() {
+            rows.push("session", process_info.id, process_info.memory_usage);
+        }
+    }
+
+    fn collect_caches(rows: &mut MemoryBreakdownRows) {
+        let cache_manager = CacheManager::instance();
+
+        // Only the caches metered by bytes, the others are bounded by the number of items.
+        if let Some(cache) = cache_manager.get_table_segment_cache() {
+            rows.push("cache", "segment_info_cache", cache.size() as i64);
+        }
+
+        if let Some(cache) = cache_manager.get_bloom_index_filter_cache() {
+            rows.push("cache", "bloom_index_filter_cache", cache.size() as i64);
+        }
+
+        if let Some(cache) = cache_manager.get_table_data_array_cache() {
+            rows.push("cache", "table_column_array_cache", cache.size() as i64);
+        }
+    }
+}
+
+fn convert_je_err(je_err: tikv_jemalloc_ctl::Error) -> ErrorCode {
+    ErrorCode::Internal(format!("{}", je_err))
+}
@@ -20,9 +20,12 @@ use common_catalog::table::Table;
 use common_catalog::table_context::TableContext;
 use common_exception::ErrorCode;
 use common_exception::Result;
+use common_expression::types::Float64Type;
+use common_expression::types::NumberDataType;
 use common_expression::types::StringType;
 use common_expression::utils::FromData;
 use common_expression::DataBlock;
+use common_expression::FromOptData;
 use common_expression::TableDataType;
 use common_expression::TableField;
 use common_expression::TableSchemaRefExt;
@@ -63,13 +66,21 @@ impl SyncSystemTable for MetricsTable {
         let mut metrics: Vec<Vec<u8>> = Vec::with_capacity(samples.len());
         let mut labels: Vec<Vec<u8>> = Vec::with_capacity(samples.len());
         let mut kinds: Vec<Vec<u8>> = Vec::with_capacity(samples.len());
-        let mut values: Vec<Vec<u8>> = Vec::with_capacity(samples.len());
+        let mut les: Vec<Option<f64>> = Vec::with_capacity(samples.len());
+        let mut quantiles: Vec<Option<f64>> = Vec::with_capacity(samples.len());
+        let mut values: Vec<f64> = Vec::with_capacity(samples.len());
         for sample in samples.into_iter() {
-            nodes.push(local_id.clone().into_bytes());
-            metrics.push(sample.name.clone().into_bytes());
-            kinds.push(sample.value.kind().into_bytes());
-            labels.push(self.display_sample_labels(&sample.labels)?.into_bytes());
-            values.push(self.display_sample_value(&sample.value)?.into_bytes());
+            let sample_labels = self.display_sample_labels(&sample.labels)?.into_bytes();
+            // Histogram and summary samples are emitted as one row per bucket or quantile.
+            for (le, quantile, value) in Self::sample_rows(&sample.value) {
+                nodes.push(local_id.clone().into_bytes());
+                metrics.push(sample.name.clone().into_bytes());
+                kinds.push(sample.value.kind().into_bytes());
+                labels.push(sample_labels.clone());
+                les.push(le);
+                quantiles.push(quantile);
+                values.push(value);
+            }
         }
 
         Ok(DataBlock::new_from_columns(vec![
@@ -77,7 +88,9 @@ impl SyncSystemTable for MetricsTable {
             StringType::from_data(metrics),
             StringType::from_data(kinds),
             StringType::from_data(labels),
-            StringType::from_data(values),
+            Float64Type::from_opt_data(les),
+            Float64Type::from_opt_data(quantiles),
+            Float64Type::from_data(values),
         ]))
     }
 
@@ -94,7 +107,15 @@ impl MetricsTable {
             TableField::new("metric", TableDataType::String),
             TableField::new("kind", TableDataType::String),
             TableField::new("labels", TableDataType::String),
-            TableField::new("value", TableDataType::String),
+            TableField::new(
+                "le",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::Float64))),
+            ),
+            TableField::new(
+                "quantile",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::Float64))),
+            ),
+            TableField::new("value", TableDataType::Number(NumberDataType::Float64)),
         ]);
 
         let table_info = TableInfo {
@@ -121,20 +142,21 @@ impl MetricsTable {
         })
     }
 
-    fn display_sample_value(&self, value: &MetricValue) -> Result<String> {
+    /// Returns the `(le, quantile, value)` of each row of the sample.
+    fn sample_rows(value: &MetricValue) -> Vec<(Option<f64>, Option<f64>, f64)> {
         match value {
-            MetricValue::Counter(v) => serde_json::to_string(v),
-            MetricValue::Gauge(v) => serde_json::to_string(v),
-            MetricValue::Untyped(v) => serde_json::to_string(v),
-            MetricValue::Histogram(v) => serde_json::to_string(v),
-            MetricValue::Summary(v) => serde_json::to_string(v),
+            MetricValue::Counter(v) | MetricValue::Gauge(v) | MetricValue::Untyped(v) => {
+                vec![(None, None, *v)]
+            }
+            MetricValue::Histogram(buckets) => buckets
+                .iter()
+                .map(|bucket| (Some(bucket.less_than), None, bucket.count))
+                .collect(),
+            MetricValue::Summary(quantiles) => quantiles
+                .iter()
+                .map(|q| (None, Some(q.quantile), q.count))
+                .collect(),
         }
-        .map_err(|err| {
-            ErrorCode::Internal(format!(
-                "Dump prometheus metrics failed on display values: {}",
-                err
-            ))
-        })
     }
 
     /// Custom metrics that are not collected by prometheus.
@@ -0,0 +1,132 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::DataBlock;
+use common_expression::FromData;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_profile::QueryProfileManager;
+
+use crate::SyncOneBlockSystemTable;
+use crate::SyncSystemTable;
+
+/// Runtime metrics of every pipeline processor of the running and recently finished queries,
+/// collected by the pipeline scheduler.
+pub struct ProcessorProfileTable {
+    table_info: TableInfo,
+}
+
+impl ProcessorProfileTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("query_id", TableDataType::String),
+            TableField::new(
+                "processor_id",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("processor_name", TableDataType::String),
+            TableField::new("input_rows", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("input_bytes", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("output_rows", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "output_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("cpu_time_us", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "wait_time_us",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'processor_profile'".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            name: "processor_profile".to_string(),
+            meta: TableMeta {
+                schema,
+                engine: "ProcessorProfileTable".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        SyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
+
+impl SyncSystemTable for ProcessorProfileTable {
+    const NAME: &'static str = "system.processor_profile";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, _ctx: Arc<dyn TableContext>) -> common_exception::Result<DataBlock> {
+        let profile_mgr = QueryProfileManager::instance();
+        let query_processors = profile_mgr.list_processor_metrics();
+        let num_rows = query_processors
+            .iter()
+            .map(|(_, processors)| processors.len())
+            .sum();
+
+        let mut query_ids: Vec<Vec<u8>> = Vec::with_capacity(num_rows);
+        let mut processor_ids: Vec<u64> = Vec::with_capacity(num_rows);
+        let mut processor_names: Vec<Vec<u8>> = Vec::with_capacity(num_rows);
+        let mut input_rows: Vec<u64> = Vec::with_capacity(num_rows);
+        let mut input_bytes: Vec<u64> = Vec::with_capacity(num_rows);
+        let mut output_rows: Vec<u64> = Vec::with_capacity(num_rows);
+        let mut output_bytes: Vec<u64> = Vec::with_capacity(num_rows);
+        let mut cpu_times: Vec<u64> = Vec::with_capacity(num_rows);
+        let mut wait_times: Vec<u64> = Vec::with_capacity(num_rows);
+
+        for (query_id, processors) in query_processors.iter() {
+            for processor in processors.iter() {
+                let prof = processor.metrics.snapshot();
+                query_ids.push(query_id.clone().into_bytes());
+                processor_ids.push(processor.id as u64);
+                processor_names.push(processor.name.clone().into_bytes());
+                input_rows.push(prof.input_rows as u64);
+                input_bytes.push(prof.input_bytes as u64);
+                output_rows.push(prof.output_rows as u64);
+                output_bytes.push(prof.output_bytes as u64);
+                cpu_times.push(prof.cpu_time.as_micros() as u64);
+                wait_times.push(prof.wait_time.as_micros() as u64);
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(query_ids),
+            UInt64Type::from_data(processor_ids),
+            StringType::from_data(processor_names),
+            UInt64Type::from_data(input_rows),
+            UInt64Type::from_data(input_bytes),
+            UInt64Type::from_data(output_rows),
+            UInt64Type::from_data(output_bytes),
+            UInt64Type::from_data(cpu_times),
+            UInt64Type::from_data(wait_times),
+        ]))
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+use std::time::Duration;
+
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+
+use crate::SyncOneBlockSystemTable;
+use crate::SyncSystemTable;
+
+pub struct QueryQueueTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for QueryQueueTable {
+    const NAME: &'static str = "system.query_queue";
+
+    const IS_LOCAL: bool = false;
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let queued_queries = ctx.get_queued_queries();
+        let local_node = ctx.get_cluster().local_id.clone();
+
+        let mut nodes = Vec::with_capacity(queued_queries.len());
+        let mut positions = Vec::with_capacity(queued_queries.len());
+        let mut query_ids = Vec::with_capacity(queued_queries.len());
+        let mut users = Vec::with_capacity(queued_queries.len());
+        let mut query_texts = Vec::with_capacity(queued_queries.len());
+        let mut waiting_times = Vec::with_capacity(queued_queries.len());
+
+        for (position, queued_query) in queued_queries.into_iter().enumerate() {
+            let waiting_time = queued_query
+                .queued_time
+                .elapsed()
+                .unwrap_or(Duration::from_secs(0))
+                .as_secs();
+
+            nodes.push(local_node.clone().into_bytes());
+            positions.push(position as u64 + 1);
+            query_ids.push(queued_query.query_id.into_bytes());
+            users.push(queued_query.user.into_bytes());
+            query_texts.push(queued_query.query_text.into_bytes());
+            waiting_times.push(waiting_time);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(nodes),
+            UInt64Type::from_data(positions),
+            StringType::from_data(query_ids),
+            StringType::from_data(users),
+            StringType::from_data(query_texts),
+            UInt64Type::from_data(waiting_times),
+        ]))
+    }
+}
+
+impl QueryQueueTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("node", TableDataType::String),
+            TableField::new("position", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("user", TableDataType::String),
+            TableField::new("query_text", TableDataType::String),
+            TableField::new(
+                "waiting_time",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'query_queue'".to_string(),
+            name: "query_queue".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemQueryQueue".to_string(),
+
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        SyncOneBlockSystemTable::create(QueryQueueTable { table_info })
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::number::UInt64Type;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_meta_app::principal::RoleInfo;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// Lists every role a role inherits privileges from, either granted to it
+/// directly (depth 1) or reached through the roles granted to it.
+pub struct RoleGrantsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for RoleGrantsTable {
+    const NAME: &'static str = "system.role_grants";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let roles = UserApiProvider::instance().get_roles(&tenant).await?;
+
+        let mut names: Vec<String> = vec![];
+        let mut granted_roles: Vec<String> = vec![];
+        let mut depths: Vec<u64> = vec![];
+        for (role, granted_role, depth) in resolve_role_grants(&roles) {
+            names.push(role);
+            granted_roles.push(granted_role);
+            depths.push(depth);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(granted_roles),
+            UInt64Type::from_data(depths),
+        ]))
+    }
+}
+
+impl RoleGrantsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("granted_role", TableDataType::String),
+            TableField::new("depth", TableDataType::Number(NumberDataType::UInt64)),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'role_grants'".to_string(),
+            name: "role_grants".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemRoleGrants".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(RoleGrantsTable { table_info })
+    }
+}
+
+// Walk the role graph from each role in a BFS manner, returning
+// (role, granted role, shortest distance) for each reachable role.
+fn resolve_role_grants(roles: &[RoleInfo]) -> Vec<(String, String, u64)> {
+    let graph: HashMap<&str, &RoleInfo> = roles.iter().map(|r| (r.name.as_str(), r)).collect();
+
+    let mut result = vec![];
+    for role in roles {
+        let mut visited: HashSet<String> = HashSet::from([role.name.clone()]);
+        let mut q: VecDeque<(String, u64)> = VecDeque::new();
+        let mut direct = role.grants.roles();
+        direct.sort();
+        q.extend(direct.into_iter().map(|r| (r, 1)));
+
+        let mut grants = vec![];
+        while let Some((granted_role, depth)) = q.pop_front() {
+            if !visited.insert(granted_role.clone()) {
+                continue;
+            }
+            if let Some(info) = graph.get(granted_role.as_str()) {
+                let mut next = info.grants.roles();
+                next.sort();
+                q.extend(next.into_iter().map(|r| (r, depth + 1)));
+            }
+            grants.push((granted_role, depth));
+        }
+
+        grants.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+        result.extend(
+            grants
+                .into_iter()
+                .map(|(granted_role, depth)| (role.name.clone(), granted_role, depth)),
+        );
+    }
+    result
+}
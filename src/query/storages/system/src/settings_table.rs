@@ -49,6 +49,7 @@ impl SyncSystemTable for SettingsTable {
         let mut names: Vec<String> = vec![];
         let mut values: Vec<String> = vec![];
         let mut defaults: Vec<String> = vec![];
+        let mut ranges: Vec<String> = vec![];
         let mut levels: Vec<String> = vec![];
         let mut descs: Vec<String> = vec![];
         let mut types: Vec<String> = vec![];
@@ -62,6 +63,12 @@ impl SyncSystemTable for SettingsTable {
             values.push(escape(format!("{:?}", item.user_value).as_str()).to_string());
             // Default Value.
             defaults.push(escape(format!("{:?}", item.default_value).as_str()).to_string());
+            // Range.
+            ranges.push(
+                item.range
+                    .map(|range| range.to_string())
+                    .unwrap_or_else(|| "None".to_string()),
+            );
             // Scope level.
             levels.push(format!("{:?}", item.level));
             // Desc.
@@ -78,6 +85,7 @@ impl SyncSystemTable for SettingsTable {
         let names: Vec<Vec<u8>> = names.iter().map(|x| x.as_bytes().to_vec()).collect();
         let values: Vec<Vec<u8>> = values.iter().map(|x| x.as_bytes().to_vec()).collect();
         let defaults: Vec<Vec<u8>> = defaults.iter().map(|x| x.as_bytes().to_vec()).collect();
+        let ranges: Vec<Vec<u8>> = ranges.iter().map(|x| x.as_bytes().to_vec()).collect();
         let levels: Vec<Vec<u8>> = levels.iter().map(|x| x.as_bytes().to_vec()).collect();
         let descs: Vec<Vec<u8>> = descs.iter().map(|x| x.as_bytes().to_vec()).collect();
         let types: Vec<Vec<u8>> = types.iter().map(|x| x.as_bytes().to_vec()).collect();
@@ -86,6 +94,7 @@ impl SyncSystemTable for SettingsTable {
             StringType::from_data(names),
             StringType::from_data(values),
             StringType::from_data(defaults),
+            StringType::from_data(ranges),
             StringType::from_data(levels),
             StringType::from_data(descs),
             StringType::from_data(types),
@@ -99,6 +108,7 @@ impl SettingsTable {
             TableField::new("name", TableDataType::String),
             TableField::new("value", TableDataType::String),
             TableField::new("default", TableDataType::String),
+            TableField::new("range", TableDataType::String),
             TableField::new("level", TableDataType::String),
             TableField::new("description", TableDataType::String),
             TableField::new("type", TableDataType::String),
@@ -0,0 +1,209 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use common_catalog::catalog_kind::CATALOG_DEFAULT;
+use common_catalog::plan::PushDownInfo;
+use common_catalog::table::Table;
+use common_catalog::table_context::TableContext;
+use common_exception::Result;
+use common_expression::types::NumberDataType;
+use common_expression::types::StringType;
+use common_expression::types::UInt64Type;
+use common_expression::utils::FromData;
+use common_expression::DataBlock;
+use common_expression::FromOptData;
+use common_expression::Scalar;
+use common_expression::TableDataType;
+use common_expression::TableField;
+use common_expression::TableSchemaRefExt;
+use common_functions::BUILTIN_FUNCTIONS;
+use common_meta_app::schema::TableIdent;
+use common_meta_app::schema::TableInfo;
+use common_meta_app::schema::TableMeta;
+use common_storages_fuse::FuseTable;
+use storages_common_table_meta::meta::ColumnHistogram;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+use crate::util::find_eq_filter;
+
+/// The column statistics of the fuse tables collected by `ANALYZE TABLE`.
+pub struct TableStatisticsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for TableStatisticsTable {
+    const NAME: &'static str = "system.table_statistics";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let catalog = ctx.get_catalog(CATALOG_DEFAULT).await?;
+
+        let mut databases = Vec::new();
+        let mut tables = Vec::new();
+        if let Some(filter) = push_downs
+            .as_ref()
+            .and_then(|p| p.filters.as_ref())
+            .map(|f| &f.filter)
+        {
+            let expr = filter.as_expr(&BUILTIN_FUNCTIONS);
+            find_eq_filter(&expr, &mut |col_name, scalar| {
+                if let Scalar::String(s) = scalar {
+                    if let Ok(value) = String::from_utf8(s.clone()) {
+                        if col_name == "database" && !databases.contains(&value) {
+                            databases.push(value);
+                        } else if col_name == "table" && !tables.contains(&value) {
+                            tables.push(value);
+                        }
+                    }
+                }
+            });
+        }
+
+        if databases.is_empty() {
+            for db in catalog.list_databases(tenant.as_str()).await? {
+                databases.push(db.name().to_string());
+            }
+        }
+
+        let visibility_checker = ctx.get_visibility_checker().await?;
+
+        let mut database_names: Vec<Vec<u8>> = vec![];
+        let mut table_names: Vec<Vec<u8>> = vec![];
+        let mut column_names: Vec<Vec<u8>> = vec![];
+        let mut ndvs: Vec<u64> = vec![];
+        let mut histograms: Vec<Option<Vec<u8>>> = vec![];
+        for database in databases
+            .iter()
+            .filter(|db| visibility_checker.check_database_visibility(CATALOG_DEFAULT, db))
+        {
+            let candidates = if tables.is_empty() {
+                catalog
+                    .list_tables(tenant.as_str(), database)
+                    .await
+                    .unwrap_or_default()
+            } else {
+                let mut res = Vec::new();
+                for table in &tables {
+                    if let Ok(table) = catalog.get_table(tenant.as_str(), database, table).await {
+                        res.push(table);
+                    }
+                }
+                res
+            };
+
+            for table in candidates {
+                if !visibility_checker.check_table_visibility(
+                    CATALOG_DEFAULT,
+                    database,
+                    table.name(),
+                ) {
+                    continue;
+                }
+                let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+                    continue;
+                };
+                let snapshot = fuse_table.read_table_snapshot().await?;
+                let Some(statistics) = fuse_table
+                    .read_table_snapshot_statistics(snapshot.as_ref())
+                    .await?
+                else {
+                    continue;
+                };
+
+                for field in table.schema().fields() {
+                    let column_id = field.column_id();
+                    let Some(ndv) = statistics.column_distinct_values.get(&column_id) else {
+                        continue;
+                    };
+                    database_names.push(database.as_bytes().to_vec());
+                    table_names.push(table.name().as_bytes().to_vec());
+                    column_names.push(field.name().as_bytes().to_vec());
+                    ndvs.push(*ndv);
+                    histograms.push(
+                        statistics
+                            .column_histograms
+                            .get(&column_id)
+                            .map(|h| display_histogram(h).into_bytes()),
+                    );
+                }
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(database_names),
+            StringType::from_data(table_names),
+            StringType::from_data(column_names),
+            UInt64Type::from_data(ndvs),
+            StringType::from_opt_data(histograms),
+        ]))
+    }
+}
+
+impl TableStatisticsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("column_name", TableDataType::String),
+            TableField::new("ndv", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "histogram",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'table_statistics'".to_string(),
+            name: "table_statistics".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemTableStatistics".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(TableStatisticsTable { table_info })
+    }
+}
+
+// Each bucket is displayed as `[upper_bound: num_values, num_distinct]`,
+// the first bucket only records the min value.
+fn display_histogram(histogram: &ColumnHistogram) -> String {
+    histogram
+        .buckets
+        .iter()
+        .map(|bucket| {
+            format!(
+                "[{}: {}, {}]",
+                bucket.upper_bound, bucket.num_values, bucket.num_distinct
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
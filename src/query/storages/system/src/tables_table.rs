@@ -36,6 +36,7 @@ use common_functions::BUILTIN_FUNCTIONS;
 use common_meta_app::schema::TableIdent;
 use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
+use common_storages_fuse::FuseTable;
 use log::warn;
 
 use crate::table::AsyncOneBlockSystemTable;
@@ -188,6 +189,17 @@ where TablesTable<T>: HistoryAware
         let mut data_size: Vec<Option<u64>> = Vec::new();
         let mut data_compressed_size: Vec<Option<u64>> = Vec::new();
         let mut index_size: Vec<Option<u64>> = Vec::new();
+        let mut number_of_snapshots: Vec<Option<u64>> = Vec::new();
+
+        // Counting the snapshots needs to list the storage of the tables, only do it if the column is required.
+        let need_snapshots = push_downs
+            .as_ref()
+            .and_then(|p| p.projection.as_ref())
+            .map_or(true, |p| {
+                p.project_schema(&Self::schema())
+                    .index_of("number_of_snapshots")
+                    .is_ok()
+            });
 
         for tbl in &database_tables {
             owner.push(
@@ -204,6 +216,10 @@ where TablesTable<T>: HistoryAware
             data_size.push(stats.as_ref().and_then(|v| v.data_size));
             data_compressed_size.push(stats.as_ref().and_then(|v| v.data_size_compressed));
             index_size.push(stats.as_ref().and_then(|v| v.index_size));
+            number_of_snapshots.push(match need_snapshots {
+                true => Self::number_of_snapshots(tbl.as_ref()).await,
+                false => None,
+            });
         }
 
         let names: Vec<Vec<u8>> = database_tables
@@ -277,6 +293,7 @@ where TablesTable<T>: HistoryAware
             UInt64Type::from_opt_data(number_of_segments),
             UInt64Type::from_opt_data(number_of_blocks),
             StringType::from_opt_data(owner),
+            UInt64Type::from_opt_data(number_of_snapshots),
         ]))
     }
 }
@@ -328,9 +345,25 @@ where TablesTable<T>: HistoryAware
                 "owner",
                 TableDataType::Nullable(Box::new(TableDataType::String)),
             ),
+            TableField::new(
+                "number_of_snapshots",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
         ])
     }
 
+    // The snapshot files of the fuse table, including the ones not purged yet.
+    async fn number_of_snapshots(table: &dyn Table) -> Option<u64> {
+        let fuse_table = FuseTable::try_from_table(table).ok()?;
+        match fuse_table.list_snapshot_files().await {
+            Ok(files) => Some(files.len() as u64),
+            Err(err) => {
+                warn!("list snapshots of table {} failed: {}", table.name(), err);
+                None
+            }
+        }
+    }
+
     pub fn create(table_id: u64) -> Arc<dyn Table> {
         let name = Self::TABLE_NAME;
         let table_info = TableInfo {
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use common_catalog::plan::PushDownInfo;
@@ -37,6 +38,7 @@ use common_meta_app::schema::TableInfo;
 use common_meta_app::schema::TableMeta;
 use common_pipeline_core::query_spill_prefix;
 use common_storage::DataOperator;
+use common_storage::TempFilesRegistry;
 use futures::StreamExt;
 use futures::TryStreamExt;
 use opendal::Metakey;
@@ -68,6 +70,10 @@ impl AsyncSystemTable for TempFilesTable {
         let mut temp_files_name = vec![];
         let mut temp_files_content_length = vec![];
         let mut temp_files_last_modified = vec![];
+        let mut temp_files_query_id = vec![];
+        let mut temp_files_spill_type = vec![];
+
+        let registry = TempFilesRegistry::instance();
 
         let location_prefix = format!("{}/", query_spill_prefix(&tenant));
         if let Ok(lister) = operator
@@ -75,8 +81,9 @@ impl AsyncSystemTable for TempFilesTable {
             .metakey(Metakey::LastModified | Metakey::ContentLength)
             .await
         {
-            let limit = push_downs.and_then(|x| x.limit).unwrap_or(usize::MAX);
-            let mut lister = lister.take(limit);
+            let limit = push_downs.and_then(|x| x.limit);
+            let mut lister = lister.take(limit.unwrap_or(usize::MAX));
+            let mut listed_files = HashSet::new();
 
             while let Some(entry) = lister.try_next().await? {
                 let metadata = entry.metadata();
@@ -87,8 +94,23 @@ impl AsyncSystemTable for TempFilesTable {
                     temp_files_last_modified
                         .push(metadata.last_modified().map(|x| x.timestamp_micros()));
                     temp_files_content_length.push(metadata.content_length());
+
+                    // The files spilled by the other nodes are not known by the registry of this node.
+                    let info = registry.get(entry.path());
+                    temp_files_query_id
+                        .push(info.as_ref().map(|info| info.query_id.as_bytes().to_vec()));
+                    temp_files_spill_type.push(
+                        info.as_ref()
+                            .map(|info| info.spill_type.as_bytes().to_vec()),
+                    );
+                    listed_files.insert(entry.path().to_string());
                 }
             }
+
+            // Forget the files which have been removed from the storage.
+            if limit.is_none() {
+                registry.retain(|location| listed_files.contains(location));
+            }
         }
 
         let num_rows = temp_files_name.len();
@@ -110,6 +132,14 @@ impl AsyncSystemTable for TempFilesTable {
                     DataType::Timestamp.wrap_nullable(),
                     Value::Column(TimestampType::from_opt_data(temp_files_last_modified)),
                 ),
+                BlockEntry::new(
+                    DataType::String.wrap_nullable(),
+                    Value::Column(StringType::from_opt_data(temp_files_query_id)),
+                ),
+                BlockEntry::new(
+                    DataType::String.wrap_nullable(),
+                    Value::Column(StringType::from_opt_data(temp_files_spill_type)),
+                ),
             ],
             num_rows,
         );
@@ -131,6 +161,8 @@ impl TempFilesTable {
                 "file_last_modified_time",
                 TableDataType::Timestamp.wrap_nullable(),
             ),
+            TableField::new("query_id", TableDataType::String.wrap_nullable()),
+            TableField::new("spill_type", TableDataType::String.wrap_nullable()),
         ]);
 
         let table_info = TableInfo {
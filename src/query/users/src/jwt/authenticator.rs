@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
+
 use common_exception::ErrorCode;
 use common_exception::Result;
 use jwt_simple::algorithms::ECDSAP256PublicKeyLike;
@@ -19,9 +21,12 @@ use jwt_simple::algorithms::ES256PublicKey;
 use jwt_simple::algorithms::RS256PublicKey;
 use jwt_simple::algorithms::RSAPublicKeyLike;
 use jwt_simple::prelude::JWTClaims;
+use jwt_simple::prelude::VerificationOptions;
 use jwt_simple::token::Token;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
 
 use super::jwk;
 
@@ -32,8 +37,14 @@ pub enum PubKey {
 }
 
 pub struct JwtAuthenticator {
-    // Todo(youngsofun): verify settings, like issuer
     key_stores: Vec<jwk::JwkKeyStore>,
+    // the `iss` and `aud` claims must match these if set
+    issuer: Option<String>,
+    audience: Option<String>,
+    // the claim holding the user name, `sub` if not set
+    user_claim: Option<String>,
+    // the claim holding the role names granted to the session
+    roles_claim: Option<String>,
 }
 
 #[derive(Debug, Default, Deserialize, Serialize)]
@@ -46,6 +57,9 @@ pub struct CustomClaims {
     pub tenant_id: Option<String>,
     pub role: Option<String>,
     pub ensure_user: Option<EnsureUser>,
+    /// Claims not known by databend, used by the claim based user and role mapping.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
 impl CustomClaims {
@@ -54,6 +68,7 @@ impl CustomClaims {
             tenant_id: None,
             role: None,
             ensure_user: None,
+            extra: Map::new(),
         }
     }
 
@@ -87,7 +102,78 @@ impl JwtAuthenticator {
         for u in jwt_key_files {
             key_stores.push(jwk::JwkKeyStore::new(u))
         }
-        Some(JwtAuthenticator { key_stores })
+        Some(JwtAuthenticator {
+            key_stores,
+            issuer: None,
+            audience: None,
+            user_claim: None,
+            roles_claim: None,
+        })
+    }
+
+    pub fn with_issuer(mut self, issuer: String) -> Self {
+        self.issuer = (!issuer.is_empty()).then_some(issuer);
+        self
+    }
+
+    pub fn with_audience(mut self, audience: String) -> Self {
+        self.audience = (!audience.is_empty()).then_some(audience);
+        self
+    }
+
+    pub fn with_user_claim(mut self, user_claim: String) -> Self {
+        self.user_claim = (!user_claim.is_empty() && user_claim != "sub").then_some(user_claim);
+        self
+    }
+
+    pub fn with_roles_claim(mut self, roles_claim: String) -> Self {
+        self.roles_claim = (!roles_claim.is_empty()).then_some(roles_claim);
+        self
+    }
+
+    fn verification_options(&self) -> VerificationOptions {
+        VerificationOptions {
+            allowed_issuers: self.issuer.as_ref().map(|s| HashSet::from([s.to_string()])),
+            allowed_audiences: self
+                .audience
+                .as_ref()
+                .map(|s| HashSet::from([s.to_string()])),
+            ..Default::default()
+        }
+    }
+
+    /// The user name of the claims, from `sub` or the configured user claim.
+    pub fn user_name(&self, claims: &JWTClaims<CustomClaims>) -> Result<String> {
+        let user_name = match &self.user_claim {
+            None => claims.subject.clone(),
+            Some(claim) => claims
+                .custom
+                .extra
+                .get(claim)
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string()),
+        };
+        user_name.ok_or_else(|| {
+            ErrorCode::AuthenticateFailure(format!(
+                "missing field `{}` in jwt",
+                self.user_claim.as_deref().unwrap_or("subject")
+            ))
+        })
+    }
+
+    /// The roles in the configured roles claim, which is a role name or an array of role names.
+    pub fn roles(&self, claims: &JWTClaims<CustomClaims>) -> Vec<String> {
+        let Some(claim) = &self.roles_claim else {
+            return vec![];
+        };
+        match claims.custom.extra.get(claim) {
+            Some(Value::String(role)) => vec![role.clone()],
+            Some(Value::Array(roles)) => roles
+                .iter()
+                .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                .collect(),
+            _ => vec![],
+        }
     }
 
     // parse jwt claims from single source, if custom claim is not matching on desired, claim parsed would be empty
@@ -100,17 +186,14 @@ impl JwtAuthenticator {
         let metadata = Token::decode_metadata(token);
         let key_id = metadata.map_or(None, |e| e.key_id().map(|s| s.to_string()));
         let pub_key = key_store.get_key(key_id).await?;
+        let options = Some(self.verification_options());
         let r = match &pub_key {
-            PubKey::RSA256(pk) => pk.verify_token::<CustomClaims>(token, None),
-            PubKey::ES256(pk) => pk.verify_token::<CustomClaims>(token, None),
+            PubKey::RSA256(pk) => pk.verify_token::<CustomClaims>(token, options),
+            PubKey::ES256(pk) => pk.verify_token::<CustomClaims>(token, options),
         };
         let c = r.map_err(|err| ErrorCode::AuthenticateFailure(err.to_string()))?;
-        match c.subject {
-            None => Err(ErrorCode::AuthenticateFailure(
-                "missing field `subject` in jwt",
-            )),
-            Some(_) => Ok(c),
-        }
+        self.user_name(&c)?;
+        Ok(c)
     }
     #[async_backtrace::framed]
     pub async fn parse_jwt_claims(&self, token: &str) -> Result<JWTClaims<CustomClaims>> {
@@ -32,6 +32,8 @@ use serde::Serialize;
 use super::PubKey;
 
 const JWK_REFRESH_INTERVAL: u64 = 15;
+// An unknown key id forces a refresh to pick up rotated keys, at most once per this many seconds.
+const JWK_FORCE_REFRESH_INTERVAL: u64 = 10;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct JwkKey {
@@ -97,16 +99,19 @@ pub struct JwkKeyStore {
     keys: Arc<RwLock<HashMap<String, PubKey>>>,
     pub(crate) last_refreshed_at: RwLock<Option<Instant>>,
     pub(crate) refresh_interval: Duration,
+    pub(crate) force_refresh_interval: Duration,
 }
 
 impl JwkKeyStore {
     pub fn new(url: String) -> Self {
         let refresh_interval = Duration::from_secs(JWK_REFRESH_INTERVAL * 60);
+        let force_refresh_interval = Duration::from_secs(JWK_FORCE_REFRESH_INTERVAL);
         let keys = Arc::new(RwLock::new(HashMap::new()));
         Self {
             url,
             keys,
             refresh_interval,
+            force_refresh_interval,
             last_refreshed_at: RwLock::new(None),
         }
     }
@@ -134,11 +139,10 @@ impl JwkKeyStore {
     }
 
     #[async_backtrace::framed]
-    async fn maybe_reload_keys(&self) -> Result<()> {
+    async fn maybe_reload_keys(&self, interval: Duration) -> Result<()> {
         let need_reload = {
             let last_refreshed_at = *self.last_refreshed_at.read();
-            last_refreshed_at.is_none()
-                || last_refreshed_at.unwrap().elapsed() > self.refresh_interval
+            last_refreshed_at.is_none() || last_refreshed_at.unwrap().elapsed() > interval
         };
         if need_reload {
             self.load_keys().await?;
@@ -149,7 +153,13 @@ impl JwkKeyStore {
 
     #[async_backtrace::framed]
     pub(super) async fn get_key(&self, key_id: Option<String>) -> Result<PubKey> {
-        self.maybe_reload_keys().await?;
+        self.maybe_reload_keys(self.refresh_interval).await?;
+        if let Some(kid) = &key_id {
+            // the key may have been rotated by the provider since the last refresh
+            if !self.keys.read().contains_key(kid) {
+                self.maybe_reload_keys(self.force_refresh_interval).await?;
+            }
+        }
         let keys = self.keys.read();
         match key_id {
             Some(kid) => keys
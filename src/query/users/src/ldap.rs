@@ -0,0 +1,213 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use ldap3::dn_escape;
+use ldap3::ldap_escape;
+use ldap3::Ldap;
+use ldap3::LdapConnAsync;
+use ldap3::LdapConnSettings;
+use ldap3::LdapError;
+use ldap3::Scope;
+use ldap3::SearchEntry;
+use log::warn;
+use parking_lot::Mutex;
+
+const LDAP_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+// LDAP result code of a failed bind, see RFC 4511.
+const LDAP_INVALID_CREDENTIALS: u32 = 49;
+
+/// Verifies the password of `AuthInfo::Ldap` users by binding to an LDAP server,
+/// and maps the LDAP groups of the user to roles.
+pub struct LdapAuthenticator {
+    url: String,
+    bind_dn_template: String,
+    group_search_base: String,
+    group_search_filter: String,
+    // lowercase group cn -> role name
+    group_role_mapping: HashMap<String, String>,
+    pool_size: usize,
+    idle_conns: Mutex<Vec<Ldap>>,
+}
+
+impl LdapAuthenticator {
+    pub fn create(
+        url: String,
+        bind_dn_template: String,
+        group_search_base: String,
+        group_search_filter: String,
+        group_role_mapping: Vec<String>,
+        pool_size: u64,
+    ) -> Result<Option<Self>> {
+        if url.is_empty() {
+            return Ok(None);
+        }
+        if !bind_dn_template.contains("{user}") {
+            return Err(ErrorCode::InvalidConfig(format!(
+                "ldap_bind_dn_template must contain {{user}}, got: '{}'",
+                bind_dn_template
+            )));
+        }
+
+        let mut mapping = HashMap::with_capacity(group_role_mapping.len());
+        for entry in group_role_mapping {
+            match entry.split_once('=') {
+                Some((group, role)) if !group.trim().is_empty() && !role.trim().is_empty() => {
+                    mapping.insert(group.trim().to_lowercase(), role.trim().to_string());
+                }
+                _ => {
+                    return Err(ErrorCode::InvalidConfig(format!(
+                        "invalid ldap_group_role_mapping entry '{}', expect '<group>=<role>'",
+                        entry
+                    )));
+                }
+            }
+        }
+
+        Ok(Some(LdapAuthenticator {
+            url,
+            bind_dn_template,
+            group_search_base,
+            group_search_filter,
+            group_role_mapping: mapping,
+            pool_size: pool_size as usize,
+            idle_conns: Mutex::new(vec![]),
+        }))
+    }
+
+    /// Bind as `user` with `password`, returns the roles mapped from the groups of the user.
+    #[async_backtrace::framed]
+    pub async fn authenticate(&self, user: &str, password: &[u8]) -> Result<Vec<String>> {
+        // An empty password is an unauthenticated bind in LDAP, which always succeeds.
+        if password.is_empty() {
+            return Err(ErrorCode::AuthenticateFailure("password required"));
+        }
+        let password = std::str::from_utf8(password)
+            .map_err(|_| ErrorCode::AuthenticateFailure("wrong password"))?;
+        let dn = self.bind_dn_template.replace("{user}", &dn_escape(user));
+
+        let mut conn = self.acquire().await?;
+        match self.bind_and_search(&mut conn, user, &dn, password).await {
+            Ok(groups) => {
+                self.release(conn);
+                Ok(self.roles_of_groups(&groups))
+            }
+            Err(LdapAuthError::WrongPassword) => {
+                self.release(conn);
+                Err(ErrorCode::AuthenticateFailure("wrong password"))
+            }
+            Err(LdapAuthError::Ldap(e)) => Err(ErrorCode::AuthenticateFailure(format!(
+                "ldap server {} error: {}",
+                self.url, e
+            ))),
+        }
+    }
+
+    /// The roles of the given group names, groups without a mapping are ignored.
+    pub fn roles_of_groups(&self, groups: &[String]) -> Vec<String> {
+        let mut roles = groups
+            .iter()
+            .filter_map(|g| self.group_role_mapping.get(&g.to_lowercase()).cloned())
+            .collect::<Vec<_>>();
+        roles.sort();
+        roles.dedup();
+        roles
+    }
+
+    async fn bind_and_search(
+        &self,
+        conn: &mut Ldap,
+        user: &str,
+        dn: &str,
+        password: &str,
+    ) -> std::result::Result<Vec<String>, LdapAuthError> {
+        let res = conn.simple_bind(dn, password).await?;
+        if res.rc == LDAP_INVALID_CREDENTIALS {
+            return Err(LdapAuthError::WrongPassword);
+        }
+        res.success()?;
+
+        if self.group_search_base.is_empty() || self.group_role_mapping.is_empty() {
+            return Ok(vec![]);
+        }
+        let filter = self
+            .group_search_filter
+            .replace("{dn}", &ldap_escape(dn))
+            .replace("{user}", &ldap_escape(user));
+        let (entries, _) = conn
+            .search(&self.group_search_base, Scope::Subtree, &filter, vec!["cn"])
+            .await?
+            .success()?;
+        Ok(entries
+            .into_iter()
+            .flat_map(|e| {
+                SearchEntry::construct(e)
+                    .attrs
+                    .remove("cn")
+                    .unwrap_or_default()
+            })
+            .collect())
+    }
+
+    async fn acquire(&self) -> Result<Ldap> {
+        loop {
+            let conn = self.idle_conns.lock().pop();
+            match conn {
+                Some(mut conn) if !conn.is_closed() => return Ok(conn),
+                Some(_) => continue,
+                None => break,
+            }
+        }
+
+        let settings = LdapConnSettings::new().set_conn_timeout(LDAP_CONNECT_TIMEOUT);
+        let (driver, conn) = LdapConnAsync::with_settings(settings, &self.url)
+            .await
+            .map_err(|e| {
+                ErrorCode::AuthenticateFailure(format!(
+                    "cannot connect to ldap server {}: {}",
+                    self.url, e
+                ))
+            })?;
+        let url = self.url.clone();
+        tokio::spawn(async move {
+            if let Err(e) = driver.drive().await {
+                warn!("ldap connection to {} closed: {}", url, e);
+            }
+        });
+        Ok(conn)
+    }
+
+    fn release(&self, conn: Ldap) {
+        let mut idle_conns = self.idle_conns.lock();
+        if idle_conns.len() < self.pool_size {
+            idle_conns.push(conn);
+        }
+    }
+}
+
+enum LdapAuthError {
+    WrongPassword,
+    Ldap(LdapError),
+}
+
+impl From<LdapError> for LdapAuthError {
+    fn from(e: LdapError) -> Self {
+        LdapAuthError::Ldap(e)
+    }
+}
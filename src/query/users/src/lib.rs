@@ -19,8 +19,11 @@
 extern crate core;
 
 mod jwt;
+mod ldap;
 mod network_policy;
 mod role_mgr;
+mod row_access_policy;
+mod sequence;
 mod user;
 mod user_api;
 mod user_mgr;
@@ -35,6 +38,7 @@ pub mod role_cache_mgr;
 pub mod role_util;
 
 pub use jwt::*;
+pub use ldap::LdapAuthenticator;
 pub use role_cache_mgr::RoleCacheManager;
 pub use role_mgr::BUILTIN_ROLE_ACCOUNT_ADMIN;
 pub use role_mgr::BUILTIN_ROLE_PUBLIC;
@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::RowAccessPolicyApi;
+use common_meta_app::principal::RowAccessPolicy;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+impl UserApiProvider {
+    // Add a new row access policy.
+    #[async_backtrace::framed]
+    pub async fn add_row_access_policy(
+        &self,
+        tenant: &str,
+        row_access_policy: RowAccessPolicy,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        if if_not_exists
+            && self
+                .exists_row_access_policy(tenant, row_access_policy.name.as_str())
+                .await?
+        {
+            return Ok(0);
+        }
+
+        let client = self.get_row_access_policy_api_client(tenant)?;
+        let add_row_access_policy = client.add_row_access_policy(row_access_policy);
+        match add_row_access_policy.await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::ROW_ACCESS_POLICY_ALREADY_EXISTS {
+                    Ok(0)
+                } else {
+                    Err(e.add_message_back("(while add row access policy)"))
+                }
+            }
+        }
+    }
+
+    // Drop a row access policy by name.
+    #[async_backtrace::framed]
+    pub async fn drop_row_access_policy(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let client = self.get_row_access_policy_api_client(tenant)?;
+        match client.drop_row_access_policy(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_ROW_ACCESS_POLICY {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop row access policy)"))
+                }
+            }
+        }
+    }
+
+    // Check whether a row access policy is exist.
+    #[async_backtrace::framed]
+    pub async fn exists_row_access_policy(&self, tenant: &str, name: &str) -> Result<bool> {
+        match self.get_row_access_policy(tenant, name).await {
+            Ok(_) => Ok(true),
+            Err(e) => {
+                if e.code() == ErrorCode::UNKNOWN_ROW_ACCESS_POLICY {
+                    Ok(false)
+                } else {
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    // Get a row access policy by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_row_access_policy(&self, tenant: &str, name: &str) -> Result<RowAccessPolicy> {
+        let client = self.get_row_access_policy_api_client(tenant)?;
+        let row_access_policy = client
+            .get_row_access_policy(name, MatchSeq::GE(0))
+            .await?
+            .data;
+        Ok(row_access_policy)
+    }
+
+    // Get all row access policies by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_row_access_policies(&self, tenant: &str) -> Result<Vec<RowAccessPolicy>> {
+        let client = self.get_row_access_policy_api_client(tenant)?;
+        let row_access_policies = client
+            .get_row_access_policies()
+            .await
+            .map_err(|e| e.add_message_back(" (while get row access policies)."))?;
+        Ok(row_access_policies)
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_management::SequenceApi;
+use common_meta_app::principal::Sequence;
+use common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// The number of values a node reserves from the meta service at a time, so that
+/// `nextval` does not need a round trip for every value.
+const SEQUENCE_CACHE_SIZE: u64 = 1000;
+
+/// Values of a sequence that are reserved by this node but not handed out yet.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct SequenceRange {
+    next: u64,
+    remaining: u64,
+    increment: u64,
+}
+
+impl SequenceRange {
+    fn take(&mut self, count: u64, values: &mut Vec<u64>) {
+        let count = count.min(self.remaining);
+        for _ in 0..count {
+            values.push(self.next);
+            self.next = self.next.wrapping_add(self.increment);
+        }
+        self.remaining -= count;
+    }
+}
+
+pub(crate) type SequenceCache = HashMap<String, SequenceRange>;
+
+fn sequence_cache_key(tenant: &str, name: &str) -> String {
+    format!("{}/{}", tenant, name)
+}
+
+impl UserApiProvider {
+    // Add a new sequence.
+    #[async_backtrace::framed]
+    pub async fn add_sequence(
+        &self,
+        tenant: &str,
+        sequence: Sequence,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        let client = self.get_sequence_api_client(tenant)?;
+        match client.add_sequence(sequence).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::SEQUENCE_ALREADY_EXISTS {
+                    Ok(0)
+                } else {
+                    Err(e.add_message_back(" (while add sequence)"))
+                }
+            }
+        }
+    }
+
+    // Drop a sequence by name.
+    #[async_backtrace::framed]
+    pub async fn drop_sequence(&self, tenant: &str, name: &str, if_exists: bool) -> Result<()> {
+        self.sequence_cache
+            .lock()
+            .remove(&sequence_cache_key(tenant, name));
+
+        let client = self.get_sequence_api_client(tenant)?;
+        match client.drop_sequence(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_SEQUENCE {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop sequence)"))
+                }
+            }
+        }
+    }
+
+    // Get a sequence by name.
+    #[async_backtrace::framed]
+    pub async fn get_sequence(&self, tenant: &str, name: &str) -> Result<Sequence> {
+        let client = self.get_sequence_api_client(tenant)?;
+        let sequence = client.get_sequence(name, MatchSeq::GE(0)).await?.data;
+        Ok(sequence)
+    }
+
+    // Get all sequences by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_sequences(&self, tenant: &str) -> Result<Vec<Sequence>> {
+        let client = self.get_sequence_api_client(tenant)?;
+        let sequences = client
+            .get_sequences()
+            .await
+            .map_err(|e| e.add_message_back(" (while get sequences)."))?;
+        Ok(sequences)
+    }
+
+    // Get the next `count` values of a sequence, served from the values this node has
+    // reserved, reserving a new range from the meta service when they run out.
+    #[async_backtrace::framed]
+    pub async fn next_sequence_values(
+        &self,
+        tenant: &str,
+        name: &str,
+        count: u64,
+    ) -> Result<Vec<u64>> {
+        let key = sequence_cache_key(tenant, name);
+        let mut values = Vec::with_capacity(count as usize);
+        if let Some(range) = self.sequence_cache.lock().get_mut(&key) {
+            range.take(count, &mut values);
+        }
+
+        let missing = count - values.len() as u64;
+        if missing == 0 {
+            return Ok(values);
+        }
+
+        let reserve = missing.max(SEQUENCE_CACHE_SIZE);
+        let client = self.get_sequence_api_client(tenant)?;
+        let (next, increment) = client
+            .allocate_sequence(name, reserve)
+            .await
+            .map_err(|e| e.add_message_back(" (while get next sequence values)"))?;
+
+        let mut range = SequenceRange {
+            next,
+            remaining: reserve,
+            increment,
+        };
+        range.take(missing, &mut values);
+        // The previous range is used up, unless a concurrent query refilled it. Then the
+        // values of one of the two ranges are skipped, which keeps them unique.
+        self.sequence_cache.lock().insert(key, range);
+        Ok(values)
+    }
+}
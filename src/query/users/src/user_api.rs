@@ -26,6 +26,10 @@ use common_management::QuotaApi;
 use common_management::QuotaMgr;
 use common_management::RoleApi;
 use common_management::RoleMgr;
+use common_management::RowAccessPolicyApi;
+use common_management::RowAccessPolicyMgr;
+use common_management::SequenceApi;
+use common_management::SequenceMgr;
 use common_management::SettingApi;
 use common_management::SettingMgr;
 use common_management::StageApi;
@@ -41,13 +45,16 @@ use common_meta_store::MetaStore;
 use common_meta_store::MetaStoreProvider;
 use common_meta_types::MatchSeq;
 use common_meta_types::MetaError;
+use parking_lot::Mutex;
 
 use crate::idm_config::IDMConfig;
+use crate::sequence::SequenceCache;
 
 pub struct UserApiProvider {
     meta: MetaStore,
     client: Arc<dyn kvapi::KVApi<Error = MetaError> + Send + Sync>,
     idm_config: IDMConfig,
+    pub(crate) sequence_cache: Mutex<SequenceCache>,
 }
 
 impl UserApiProvider {
@@ -77,6 +84,7 @@ impl UserApiProvider {
             meta: client.clone(),
             client: client.arc(),
             idm_config,
+            sequence_cache: Mutex::new(SequenceCache::new()),
         }))
     }
 
@@ -130,6 +138,20 @@ impl UserApiProvider {
         )?))
     }
 
+    pub fn get_row_access_policy_api_client(
+        &self,
+        tenant: &str,
+    ) -> Result<Arc<impl RowAccessPolicyApi>> {
+        Ok(Arc::new(RowAccessPolicyMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
+    pub fn get_sequence_api_client(&self, tenant: &str) -> Result<Arc<impl SequenceApi>> {
+        Ok(Arc::new(SequenceMgr::create(self.client.clone(), tenant)?))
+    }
+
     pub fn get_meta_store_client(&self) -> Arc<MetaStore> {
         Arc::new(self.meta.clone())
     }
@@ -67,3 +67,94 @@ async fn test_parse_non_custom_claim() -> Result<()> {
     assert_eq!(res.custom.role, None);
     Ok(())
 }
+
+#[derive(Serialize, Deserialize)]
+struct OidcClaims {
+    email: String,
+    groups: Vec<String>,
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_verify_issuer_and_audience() -> Result<()> {
+    let (pair1, pbkey1) = get_jwks_file_rs256("test_kid");
+    let template1 = ResponseTemplate::new(200).set_body_raw(pbkey1, "application/json");
+    let server = MockServer::start().await;
+    let json_path = "/jwks.json";
+    Mock::given(method("GET"))
+        .and(path(json_path))
+        .respond_with(template1)
+        .expect(1..)
+        .mount(&server)
+        .await;
+    let url = format!("http://{}{}", server.address(), json_path);
+    let auth = JwtAuthenticator::create(url, vec![])
+        .unwrap()
+        .with_issuer("https://idp.example.com".to_string())
+        .with_audience("databend".to_string());
+
+    let claims = Claims::create(Duration::from_hours(2))
+        .with_subject("test-user")
+        .with_issuer("https://idp.example.com")
+        .with_audience("databend");
+    let token = pair1.sign(claims)?;
+    let res = auth.parse_jwt_claims(token.as_str()).await?;
+    assert_eq!(res.subject, Some("test-user".to_string()));
+
+    let claims = Claims::create(Duration::from_hours(2))
+        .with_subject("test-user")
+        .with_issuer("https://other.example.com")
+        .with_audience("databend");
+    let token = pair1.sign(claims)?;
+    assert!(auth.parse_jwt_claims(token.as_str()).await.is_err());
+
+    let claims = Claims::create(Duration::from_hours(2))
+        .with_subject("test-user")
+        .with_issuer("https://idp.example.com");
+    let token = pair1.sign(claims)?;
+    assert!(auth.parse_jwt_claims(token.as_str()).await.is_err());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_claim_based_user_and_roles() -> Result<()> {
+    let (pair1, pbkey1) = get_jwks_file_rs256("test_kid");
+    let template1 = ResponseTemplate::new(200).set_body_raw(pbkey1, "application/json");
+    let server = MockServer::start().await;
+    let json_path = "/jwks.json";
+    Mock::given(method("GET"))
+        .and(path(json_path))
+        .respond_with(template1)
+        .expect(1..)
+        .mount(&server)
+        .await;
+    let url = format!("http://{}{}", server.address(), json_path);
+    let auth = JwtAuthenticator::create(url.clone(), vec![])
+        .unwrap()
+        .with_user_claim("email".to_string())
+        .with_roles_claim("groups".to_string());
+
+    // no subject is needed when the user name comes from another claim
+    let oidc_claims = OidcClaims {
+        email: "alice@example.com".to_string(),
+        groups: vec!["analyst".to_string(), "writer".to_string()],
+    };
+    let claims = Claims::with_custom_claims(oidc_claims, Duration::from_hours(2));
+    let token = pair1.sign(claims)?;
+    let res = auth.parse_jwt_claims(token.as_str()).await?;
+    assert_eq!(auth.user_name(&res)?, "alice@example.com");
+    assert_eq!(auth.roles(&res), vec![
+        "analyst".to_string(),
+        "writer".to_string()
+    ]);
+
+    // the default user claim is `sub`
+    let auth = JwtAuthenticator::create(url, vec![]).unwrap();
+    let res = auth.parse_jwt_claims(token.as_str()).await;
+    assert!(
+        res.err()
+            .unwrap()
+            .message()
+            .contains("missing field `subject` in jwt")
+    );
+    Ok(())
+}
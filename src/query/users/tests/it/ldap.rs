@@ -0,0 +1,87 @@
+// Copyright 2021 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use common_base::base::tokio;
+use common_exception::ErrorCode;
+use common_exception::Result;
+use common_users::LdapAuthenticator;
+
+fn create_authenticator(url: &str, mapping: Vec<&str>) -> Result<Option<LdapAuthenticator>> {
+    LdapAuthenticator::create(
+        url.to_string(),
+        "uid={user},ou=people,dc=example,dc=com".to_string(),
+        "ou=groups,dc=example,dc=com".to_string(),
+        "(member={dn})".to_string(),
+        mapping.into_iter().map(|s| s.to_string()).collect(),
+        4,
+    )
+}
+
+#[test]
+fn test_ldap_authenticator_create() -> Result<()> {
+    assert!(create_authenticator("", vec![])?.is_none());
+    assert!(create_authenticator("ldap://127.0.0.1:389", vec![])?.is_some());
+
+    let res = LdapAuthenticator::create(
+        "ldap://127.0.0.1:389".to_string(),
+        "ou=people,dc=example,dc=com".to_string(),
+        "".to_string(),
+        "(member={dn})".to_string(),
+        vec![],
+        4,
+    );
+    assert_eq!(res.err().unwrap().code(), ErrorCode::INVALID_CONFIG);
+
+    let res = create_authenticator("ldap://127.0.0.1:389", vec!["analysts"]);
+    assert_eq!(res.err().unwrap().code(), ErrorCode::INVALID_CONFIG);
+    Ok(())
+}
+
+#[test]
+fn test_ldap_group_role_mapping() -> Result<()> {
+    let auth = create_authenticator("ldap://127.0.0.1:389", vec![
+        "Analysts=analyst",
+        "dba = account_admin",
+        "readers=analyst",
+    ])?
+    .unwrap();
+
+    let groups = vec![
+        "analysts".to_string(),
+        "DBA".to_string(),
+        "readers".to_string(),
+        "others".to_string(),
+    ];
+    assert_eq!(auth.roles_of_groups(&groups), vec![
+        "account_admin".to_string(),
+        "analyst".to_string()
+    ]);
+    assert!(auth.roles_of_groups(&[]).is_empty());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_ldap_authenticate_failure() -> Result<()> {
+    // nothing listens on port 1, no connection is needed to reject an empty password
+    let auth = create_authenticator("ldap://127.0.0.1:1", vec![])?.unwrap();
+
+    let res = auth.authenticate("alice", b"").await;
+    assert_eq!(res.err().unwrap().code(), ErrorCode::AUTHENTICATE_FAILURE);
+
+    let res = auth.authenticate("alice", b"secret").await;
+    let err = res.err().unwrap();
+    assert_eq!(err.code(), ErrorCode::AUTHENTICATE_FAILURE);
+    assert!(err.message().contains("cannot connect to ldap server"));
+    Ok(())
+}
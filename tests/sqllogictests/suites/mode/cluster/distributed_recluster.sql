@@ -22,6 +22,11 @@ set enable_distributed_recluster = 1
 statement ok
 alter table t_recluster recluster
 
+query II
+select count(), sum(a) from t_recluster
+----
+60 30
+
 # query I
 # select count()>1 from system.metrics where metric='fuse_recluster_block_nums_to_read_total'
 # ----
@@ -29,5 +29,18 @@ TIMEPOINT=$(echo "select timestamp from fuse_snapshot('default', 't12_0004') whe
 echo "planner_v2: counting the data set of first insertion by timestamp, which should contains 2 rows"
 echo "select count(t.c) from t12_0004 at (TIMESTAMP => '$TIMEPOINT'::TIMESTAMP) as t" | $MYSQL_CLIENT_CONNECT
 
+## The history navigated above is cached, navigate again after one more insertion.
+echo "insert into t12_0004 values(4)" | $MYSQL_CLIENT_CONNECT
+
+echo "counting the data set of first insertion after another insertion, which should contain 2 rows"
+echo "select count(*) from t12_0004 at (snapshot => '$SNAPSHOT_ID')" | $MYSQL_CLIENT_CONNECT
+
+echo "counting the data set of first insertion by timestamp after another insertion, which should contain 2 rows"
+echo "select count(*) from t12_0004 at (TIMESTAMP => '$TIMEPOINT'::TIMESTAMP)" | $MYSQL_CLIENT_CONNECT
+
+SNAPSHOT_ID=$(echo "select snapshot_id from fuse_snapshot('default','t12_0004') where row_count=3 " | $MYSQL_CLIENT_CONNECT)
+echo "counting the data set of second insertion, which should contain 3 rows"
+echo "select count(*) from t12_0004 at (snapshot => '$SNAPSHOT_ID')" | $MYSQL_CLIENT_CONNECT
+
 ## Drop table.
 echo "drop table t12_0004" | $MYSQL_CLIENT_CONNECT
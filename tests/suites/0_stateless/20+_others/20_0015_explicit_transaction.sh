@@ -0,0 +1,41 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists t_txn;" | $MYSQL_CLIENT_CONNECT
+echo "drop table if exists t_txn2;" | $MYSQL_CLIENT_CONNECT
+echo "create table t_txn(a int);" | $MYSQL_CLIENT_CONNECT
+echo "create table t_txn2(a int);" | $MYSQL_CLIENT_CONNECT
+
+# mutations are visible in the transaction and published at commit
+cat <<EOF | $MYSQL_CLIENT_CONNECT
+begin;
+insert into t_txn values(1), (2);
+update t_txn set a = a + 10 where a = 1;
+insert into t_txn2 select a from t_txn;
+select a from t_txn order by a;
+commit;
+EOF
+echo "select a from t_txn order by a;" | $MYSQL_CLIENT_CONNECT
+echo "select a from t_txn2 order by a;" | $MYSQL_CLIENT_CONNECT
+
+# rollback discards the staged mutations
+cat <<EOF | $MYSQL_CLIENT_CONNECT
+begin transaction;
+delete from t_txn where a = 2;
+select count(*) from t_txn;
+rollback;
+select count(*) from t_txn;
+EOF
+
+# the transaction is discarded if the session ends before commit
+cat <<EOF | $MYSQL_CLIENT_CONNECT
+begin;
+insert into t_txn values(3);
+select count(*) from t_txn;
+EOF
+echo "select count(*) from t_txn;" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table t_txn;" | $MYSQL_CLIENT_CONNECT
+echo "drop table t_txn2;" | $MYSQL_CLIENT_CONNECT
@@ -0,0 +1,17 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists streaming_load_body;" | $MYSQL_CLIENT_CONNECT
+echo "create table streaming_load_body(a int, b string);" | $MYSQL_CLIENT_CONNECT
+
+# load ndjson from a chunked request body
+printf '{"a":1,"b":"x"}\n{"a":2,"b":"y"}\n' | curl -H "insert_sql:insert into streaming_load_body file_format = (type = NdJson)" -H "Transfer-Encoding: chunked" -T - -u root: -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+echo "select * from streaming_load_body order by a;" | $MYSQL_CLIENT_CONNECT
+
+# load csv from a request body
+printf '3,z\n4,w\n' | curl -H "insert_sql:insert into streaming_load_body file_format = (type = CSV)" --data-binary @- -u root: -XPUT "http://localhost:${QUERY_HTTP_HANDLER_PORT}/v1/streaming_load" > /dev/null 2>&1
+echo "select * from streaming_load_body order by a;" | $MYSQL_CLIENT_CONNECT
+
+echo "drop table streaming_load_body;" | $MYSQL_CLIENT_CONNECT